@@ -36,6 +36,7 @@ pub(crate) fn build_hnsw_params(
         max_elements: request.hnsw_max_elements.unwrap_or(base.max_elements),
         storage_mode,
         alpha: request.hnsw_alpha.unwrap_or(base.alpha),
+        ..base
     }
 }
 
@@ -321,10 +321,7 @@ mod tests {
             .expect("test: adaptive should succeed");
         assert_eq!(
             adaptive,
-            Some(velesdb_core::SearchQuality::Adaptive {
-                min_ef: 32,
-                max_ef: 512,
-            })
+            Some(velesdb_core::SearchQuality::adaptive(32, 512))
         );
     }
 
@@ -1,10 +1,11 @@
 //! CLI argument types for clap `ValueEnum` derivation.
 //!
-//! Contains `MetricArg`, `StorageModeArg`, and `IndexTypeArg` plus their
-//! `From` conversions into the core domain types.
+//! Contains `MetricArg`, `StorageModeArg`, `IndexTypeArg` and the `import`
+//! options `VectorEncodingArg` and `OnErrorArg`, plus their `From`
+//! conversions into the core domain types.
 
 use clap::ValueEnum;
-use velesdb_core::{DistanceMetric, StorageMode};
+use velesdb_core::{DistanceMetric, OnBadRow, StorageMode, VectorEncoding};
 
 /// CLI metric option
 #[derive(Debug, Clone, Copy, ValueEnum, Default)]
@@ -52,6 +53,44 @@ impl From<StorageModeArg> for StorageMode {
     }
 }
 
+/// CLI vector encoding option for `import`
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum VectorEncodingArg {
+    /// JSON array or comma-separated floats
+    #[default]
+    Json,
+    /// Base64 of little-endian f32 bytes
+    Base64,
+}
+
+impl From<VectorEncodingArg> for VectorEncoding {
+    fn from(e: VectorEncodingArg) -> Self {
+        match e {
+            VectorEncodingArg::Json => VectorEncoding::Json,
+            VectorEncodingArg::Base64 => VectorEncoding::Base64F32,
+        }
+    }
+}
+
+/// CLI bad-row handling option for `import`
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum OnErrorArg {
+    /// Skip the row and keep importing
+    #[default]
+    Skip,
+    /// Stop at the first bad row
+    Abort,
+}
+
+impl From<OnErrorArg> for OnBadRow {
+    fn from(e: OnErrorArg) -> Self {
+        match e {
+            OnErrorArg::Skip => OnBadRow::Skip,
+            OnErrorArg::Abort => OnBadRow::Abort,
+        }
+    }
+}
+
 /// CLI index type option
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum IndexTypeArg {
@@ -7,7 +7,7 @@ use clap::Subcommand;
 use clap_complete::Shell;
 use std::path::PathBuf;
 
-use crate::cli_types::{IndexTypeArg, MetricArg, StorageModeArg};
+use crate::cli_types::{IndexTypeArg, MetricArg, OnErrorArg, StorageModeArg, VectorEncodingArg};
 use crate::graph;
 
 /// Top-level CLI commands for VelesDB CLI - High-performance vector database.
@@ -215,14 +215,26 @@ pub enum DataCommands {
         #[arg(long, value_enum, default_value = "full")]
         storage_mode: StorageModeArg,
 
-        /// ID column name (for CSV)
+        /// ID column name (CSV header or JSONL key)
         #[arg(long, default_value = "id")]
         id_column: String,
 
-        /// Vector column name (for CSV)
+        /// Vector column name (CSV header or JSONL key)
         #[arg(long, default_value = "vector")]
         vector_column: String,
 
+        /// Encoding of string vector cells (json, base64)
+        #[arg(long, value_enum, default_value = "json")]
+        vector_encoding: VectorEncodingArg,
+
+        /// Comma-separated columns to store as payload (default: all others)
+        #[arg(long, value_delimiter = ',')]
+        payload_columns: Option<Vec<String>>,
+
+        /// What to do with a bad row (skip, abort)
+        #[arg(long, value_enum, default_value = "skip")]
+        on_error: OnErrorArg,
+
         /// Batch size for insertion
         #[arg(long, default_value = "1000")]
         batch_size: usize,
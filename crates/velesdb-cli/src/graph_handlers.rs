@@ -111,6 +111,8 @@ pub(crate) fn handle_traverse(
         limit,
         rel_types: rel_vec,
         deadline: None,
+        valid_at: None,
+        max_cost: None,
     };
     let algo_label = match algorithm {
         TraverseAlgo::Bfs => "BFS",
@@ -5,8 +5,6 @@ use std::path::{Path, PathBuf};
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::cli_types::MetricArg;
-use crate::cli_types::StorageModeArg;
 use crate::import;
 
 /// Handles the `export` subcommand: exports a vector collection to JSON.
@@ -77,30 +75,8 @@ fn collect_export_records(
 }
 
 /// Handles the `import` subcommand: imports data from CSV or JSONL.
-#[allow(clippy::too_many_arguments)] // Reason: mirrors clap subcommand field count directly
-pub fn handle_import(
-    file: &Path,
-    database: &Path,
-    collection: String,
-    dimension: Option<usize>,
-    metric: MetricArg,
-    storage_mode: StorageModeArg,
-    id_column: String,
-    vector_column: String,
-    batch_size: usize,
-    progress: bool,
-) -> Result<()> {
+pub fn handle_import(file: &Path, database: &Path, config: import::ImportConfig) -> Result<()> {
     let db = crate::helpers::open_database(database)?;
-    let config = import::ImportConfig {
-        collection,
-        dimension,
-        metric: metric.into(),
-        storage_mode: storage_mode.into(),
-        batch_size,
-        id_column,
-        vector_column,
-        show_progress: progress,
-    };
 
     let ext = file.extension().and_then(|e| e.to_str()).unwrap_or("");
 
@@ -127,6 +103,9 @@ fn print_import_summary(stats: &import::ImportStats) {
     println!("  Imported:         {}", stats.imported.to_string().green());
     if stats.errors > 0 {
         println!("  Errors:           {}", stats.errors.to_string().red());
+        for row in stats.skipped_rows.iter().take(5) {
+            println!("    line {}: {}", row.line, row.message);
+        }
     }
     println!("  Duration:         {} ms", stats.duration_ms);
     println!(
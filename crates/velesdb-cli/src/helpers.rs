@@ -10,7 +10,7 @@ use std::sync::OnceLock;
 
 use anyhow::Result;
 use indicatif::{ProgressBar, ProgressStyle};
-use velesdb_core::{Database, Point, VectorCollection};
+use velesdb_core::Database;
 
 // ---------------------------------------------------------------------------
 // Global `--config` wiring (issue #1549)
@@ -99,77 +99,9 @@ pub fn open_database_with_config(path: &Path, config_path: Option<&Path>) -> Res
 }
 
 // ---------------------------------------------------------------------------
-// Import batch helpers
+// Import progress helpers
 // ---------------------------------------------------------------------------
 
-/// Manages batched upsert of points with progress tracking.
-///
-/// Encapsulates the batch-accumulate-flush loop shared by `import_jsonl`
-/// and `import_csv`. Callers push individual points; the importer flushes
-/// to the collection automatically when the batch reaches capacity.
-pub struct BatchImporter<'a> {
-    collection: &'a VectorCollection,
-    batch: Vec<Point>,
-    batch_size: usize,
-    pub stats: ImportAccumulator,
-}
-
-/// Mutable counters accumulated during an import run.
-#[derive(Debug, Default)]
-pub struct ImportAccumulator {
-    /// Successfully imported records.
-    pub imported: usize,
-    /// Records skipped due to parse/dimension errors.
-    pub errors: usize,
-}
-
-impl<'a> BatchImporter<'a> {
-    /// Creates a new batch importer targeting `collection`.
-    pub fn new(collection: &'a VectorCollection, batch_size: usize) -> Self {
-        Self {
-            collection,
-            batch: Vec::with_capacity(batch_size),
-            batch_size,
-            stats: ImportAccumulator::default(),
-        }
-    }
-
-    /// Pushes a valid point into the current batch.
-    ///
-    /// When the batch reaches capacity it is flushed via `upsert_bulk`.
-    ///
-    /// # Errors
-    ///
-    /// Propagates any error from `upsert_bulk`.
-    pub fn push(&mut self, point: Point) -> Result<()> {
-        self.batch.push(point);
-        self.stats.imported += 1;
-
-        if self.batch.len() >= self.batch_size {
-            self.collection.upsert_bulk(&self.batch)?;
-            self.batch.clear();
-        }
-        Ok(())
-    }
-
-    /// Records a skipped/errored record.
-    pub fn record_error(&mut self) {
-        self.stats.errors += 1;
-    }
-
-    /// Flushes any remaining points in the batch.
-    ///
-    /// # Errors
-    ///
-    /// Propagates any error from `upsert_bulk`.
-    pub fn flush(self) -> Result<ImportAccumulator> {
-        if !self.batch.is_empty() {
-            self.collection.upsert_bulk(&self.batch)?;
-        }
-        Ok(self.stats)
-    }
-}
-
 /// Creates a progress bar, hidden when `show` is false.
 #[must_use]
 pub fn create_progress_bar(total: usize, show: bool) -> ProgressBar {
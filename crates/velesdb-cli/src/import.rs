@@ -2,8 +2,8 @@
 //!
 //! Supports importing vectors from CSV and JSON Lines files.
 //!
-//! The batch-accumulate-flush loop is shared between `import_jsonl` and
-//! `import_csv` via [`crate::helpers::BatchImporter`].
+//! JSONL and CSV parsing, validation and batching are done by the
+//! collection importers in `velesdb-core` (`import_jsonl` / `import_csv`).
 
 #![allow(
     clippy::cast_possible_truncation,
@@ -14,13 +14,15 @@
 )]
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
-use velesdb_core::{Database, DistanceMetric, Point, StorageMode};
+use velesdb_core::{
+    Database, DistanceMetric, ImportMapping, ImportProgress, ImportReport, ImportRowError,
+    OnBadRow, StorageMode, VectorEncoding,
+};
 
-use crate::helpers::{self, BatchImporter};
+use crate::helpers;
 
 /// Import configuration
 pub struct ImportConfig {
@@ -31,6 +33,10 @@ pub struct ImportConfig {
     pub batch_size: usize,
     pub id_column: String,
     pub vector_column: String,
+    pub vector_encoding: VectorEncoding,
+    /// Columns copied into the payload (`None` = every other column).
+    pub payload_columns: Option<Vec<String>>,
+    pub on_bad_row: OnBadRow,
     pub show_progress: bool,
 }
 
@@ -44,52 +50,55 @@ impl Default for ImportConfig {
             batch_size: 1000,
             id_column: "id".to_string(),
             vector_column: "vector".to_string(),
+            vector_encoding: VectorEncoding::Json,
+            payload_columns: None,
+            on_bad_row: OnBadRow::Skip,
             show_progress: true,
         }
     }
 }
 
-/// JSON Lines record structure
-#[derive(Debug, Deserialize)]
-struct JsonRecord {
-    id: u64,
-    vector: Vec<f32>,
-    #[serde(default)]
-    payload: Option<serde_json::Value>,
+impl ImportConfig {
+    /// Column mapping handed to the collection importer.
+    fn mapping(&self) -> ImportMapping {
+        ImportMapping {
+            id_column: self.id_column.clone(),
+            vector_column: self.vector_column.clone(),
+            vector_encoding: self.vector_encoding,
+            payload_columns: self.payload_columns.clone(),
+            on_bad_row: self.on_bad_row,
+            batch_size: self.batch_size,
+        }
+    }
 }
 
 /// Import from JSON Lines file
 ///
+/// Parsing, validation and batching are done by
+/// [`VectorCollection::import_jsonl_with_progress`]; this wrapper detects the
+/// dimension, creates the collection and drives the progress bar.
+///
 /// # Performance
 ///
-/// Optimized for high-throughput import:
 /// - **Streaming parse**: Processes file line-by-line (no full file in memory)
 /// - **Parallel HNSW insert**: Uses rayon for CPU-bound indexing
 /// - **Batch flush**: Single I/O flush per batch
 /// - Target: ~3-5K vectors/sec at 768D with batch_size=1000
 pub fn import_jsonl(db: &Database, path: &Path, config: &ImportConfig) -> Result<ImportStats> {
     let file = File::open(path).context("Failed to open JSONL file")?;
-    let file_size = file.metadata()?.len();
 
     // Perf: Streaming - count lines without loading all in memory
-    let total = BufReader::with_capacity(64 * 1024, &file).lines().count();
-
-    if total == 0 {
-        anyhow::bail!("Empty file");
-    }
-
-    // Reopen file for actual processing
-    let file = File::open(path)?;
-    let mut reader = BufReader::with_capacity(128 * 1024, file);
-
-    // Perf: Read first line to detect dimension
-    let mut first_line = String::new();
-    reader.read_line(&mut first_line)?;
-    let first_record: JsonRecord =
-        serde_json::from_str(&first_line).context("Failed to parse first line")?;
-    let dimension = config.dimension.unwrap_or(first_record.vector.len());
-
-    // Create or get collection
+    let mut lines = BufReader::with_capacity(64 * 1024, &file)
+        .lines()
+        .map_while(std::result::Result::ok)
+        .filter(|line| !line.trim().is_empty());
+    let first_line = lines.next().context("Empty file")?;
+    let total = 1 + lines.count();
+
+    let dimension = match config.dimension {
+        Some(dimension) => dimension,
+        None => detect_jsonl_dimension(&first_line, config)?,
+    };
     let collection = get_or_create_collection(
         db,
         &config.collection,
@@ -98,71 +107,30 @@ pub fn import_jsonl(db: &Database, path: &Path, config: &ImportConfig) -> Result
         config.storage_mode,
     )?;
 
-    let progress = helpers::create_progress_bar(total, config.show_progress);
-    helpers::set_import_message(&progress, total, file_size, config.show_progress);
-
-    let start = std::time::Instant::now();
-    let mut importer = BatchImporter::new(&collection, config.batch_size);
-
-    // Process first record (already parsed)
-    if first_record.vector.len() == dimension {
-        importer.push(Point::new(
-            first_record.id,
-            first_record.vector,
-            first_record.payload,
-        ))?;
-    } else {
-        importer.record_error();
-    }
-    progress.inc(1);
-
-    // Perf: Streaming parse - process line by line
-    let mut line = String::with_capacity(dimension * 10); // Pre-allocate line buffer
-    while reader.read_line(&mut line)? > 0 {
-        match serde_json::from_str::<JsonRecord>(&line) {
-            Ok(record) => {
-                if record.vector.len() != dimension {
-                    importer.record_error();
-                } else {
-                    importer.push(Point::new(record.id, record.vector, record.payload))?;
-                }
-            }
-            Err(_) => {
-                importer.record_error();
-            }
-        }
-        line.clear(); // Reuse buffer
-        progress.inc(1);
-    }
-
-    finalize_import(importer, &progress, total, start)
+    run_import(path, total, config, |mapping, on_progress| {
+        collection.import_jsonl_with_progress(path, mapping, on_progress)
+    })
 }
 
 /// Import from CSV file
 ///
+/// Parsing, validation and batching are done by
+/// [`VectorCollection::import_csv_with_progress`]; this wrapper detects the
+/// dimension, creates the collection and drives the progress bar.
+///
 /// # Performance
 ///
-/// Optimized for high-throughput import:
 /// - **Streaming parse**: Processes records one at a time
 /// - **Parallel HNSW insert**: Uses rayon for CPU-bound indexing
 /// - **Batch flush**: Single I/O flush per batch
 /// - Target: ~3-5K vectors/sec at 768D with batch_size=1000
-#[allow(clippy::too_many_lines)]
 pub fn import_csv(db: &Database, path: &Path, config: &ImportConfig) -> Result<ImportStats> {
     let file = File::open(path).context("Failed to open CSV file")?;
-    let file_size = file.metadata()?.len();
 
     // Perf: Use large buffer for reduced syscalls
-    let buffered = BufReader::with_capacity(128 * 1024, file);
-    let mut reader = csv::Reader::from_reader(buffered);
-
-    // Get headers
-    let headers = reader.headers()?.clone();
-    let id_idx = headers
-        .iter()
-        .position(|h| h == config.id_column)
-        .context(format!("ID column '{}' not found", config.id_column))?;
-    let vector_idx = headers
+    let mut reader = csv::Reader::from_reader(BufReader::with_capacity(128 * 1024, file));
+    let vector_idx = reader
+        .headers()?
         .iter()
         .position(|h| h == config.vector_column)
         .context(format!(
@@ -170,24 +138,14 @@ pub fn import_csv(db: &Database, path: &Path, config: &ImportConfig) -> Result<I
             config.vector_column
         ))?;
 
-    // Count records for progress bar (streaming count)
-    let total = reader.records().count();
-    if total == 0 {
-        anyhow::bail!("Empty file");
-    }
-
-    // Reopen for processing
-    let file = File::open(path)?;
-    let buffered = BufReader::with_capacity(128 * 1024, file);
-    let mut reader = csv::Reader::from_reader(buffered);
-
-    // Detect dimension from first record
-    let first_record = reader.records().next().context("No records in CSV")??;
-    let vector_str = &first_record[vector_idx];
-    let first_vector = parse_vector(vector_str)?;
-    let dimension = config.dimension.unwrap_or(first_vector.len());
-
-    // Create or get collection
+    // Detect dimension from first record, then count the rest (streaming)
+    let mut records = reader.records();
+    let first_record = records.next().context("Empty file")??;
+    let total = 1 + records.count();
+    let dimension = match config.dimension {
+        Some(dimension) => dimension,
+        None => parse_vector(&first_record[vector_idx], config.vector_encoding)?.len(),
+    };
     let collection = get_or_create_collection(
         db,
         &config.collection,
@@ -196,60 +154,59 @@ pub fn import_csv(db: &Database, path: &Path, config: &ImportConfig) -> Result<I
         config.storage_mode,
     )?;
 
-    // Reopen for final processing
-    let file = File::open(path)?;
-    let buffered = BufReader::with_capacity(128 * 1024, file);
-    let mut reader = csv::Reader::from_reader(buffered);
-
-    let progress = helpers::create_progress_bar(total, config.show_progress);
-    helpers::set_import_message(&progress, total, file_size, config.show_progress);
-
-    let start = std::time::Instant::now();
-    let mut importer = BatchImporter::new(&collection, config.batch_size);
-
-    // Perf: Streaming parse - process record by record
-    for result in reader.records() {
-        match result {
-            Ok(record) => {
-                process_csv_record(
-                    &record,
-                    &headers,
-                    id_idx,
-                    vector_idx,
-                    dimension,
-                    &mut importer,
-                )?;
-            }
-            Err(_) => {
-                importer.record_error();
-            }
-        }
-        progress.inc(1);
-    }
-
-    finalize_import(importer, &progress, total, start)
+    run_import(path, total, config, |mapping, on_progress| {
+        collection.import_csv_with_progress(path, mapping, on_progress)
+    })
 }
 
-/// Flush the batch importer, finish the progress bar, and build [`ImportStats`].
+/// Runs a collection import behind a progress bar and builds [`ImportStats`].
 ///
-/// Shared finalization tail for `import_jsonl` and `import_csv`.
-fn finalize_import(
-    importer: BatchImporter<'_>,
-    progress: &indicatif::ProgressBar,
+/// Shared by `import_jsonl` and `import_csv`.
+fn run_import(
+    path: &Path,
     total: usize,
-    start: std::time::Instant,
+    config: &ImportConfig,
+    import: impl FnOnce(
+        &ImportMapping,
+        &mut dyn FnMut(ImportProgress),
+    ) -> velesdb_core::Result<ImportReport>,
 ) -> Result<ImportStats> {
-    let acc = importer.flush()?;
+    let file_size = std::fs::metadata(path)?.len();
+    let progress = helpers::create_progress_bar(total, config.show_progress);
+    helpers::set_import_message(&progress, total, file_size, config.show_progress);
+
+    let start = std::time::Instant::now();
+    let report = import(&config.mapping(), &mut |p| {
+        progress.set_position(p.rows as u64)
+    })
+    .context("Import aborted")?;
     progress.finish_with_message("Import complete");
 
     Ok(ImportStats {
-        total,
-        imported: acc.imported,
-        errors: acc.errors,
+        total: report.rows,
+        imported: report.imported,
+        errors: report.skipped,
         duration_ms: start.elapsed().as_millis() as u64,
+        skipped_rows: report.errors,
     })
 }
 
+/// Vector length of the first JSONL record, used to size a new collection.
+fn detect_jsonl_dimension(first_line: &str, config: &ImportConfig) -> Result<usize> {
+    let record: serde_json::Value =
+        serde_json::from_str(first_line).context("Failed to parse first line")?;
+    match record.get(&config.vector_column) {
+        Some(serde_json::Value::Array(values)) => Ok(values.len()),
+        Some(serde_json::Value::String(text)) => {
+            Ok(parse_vector(text, config.vector_encoding)?.len())
+        }
+        _ => anyhow::bail!(
+            "Failed to parse first line: no '{}' vector",
+            config.vector_column
+        ),
+    }
+}
+
 /// Import from a VRB1 binary file (`.bin` / `.vrb1`).
 ///
 /// Reads the whole file, decodes the shared VRB1 wire format, and forwards the
@@ -279,77 +236,13 @@ pub fn import_raw_bulk(db: &Database, path: &Path, config: &ImportConfig) -> Res
         imported,
         errors: 0,
         duration_ms: start.elapsed().as_millis() as u64,
+        skipped_rows: Vec::new(),
     })
 }
 
-/// Process a single CSV record and push it into the batch importer.
-fn process_csv_record(
-    record: &csv::StringRecord,
-    headers: &csv::StringRecord,
-    id_idx: usize,
-    vector_idx: usize,
-    dimension: usize,
-    importer: &mut BatchImporter<'_>,
-) -> Result<()> {
-    let id: u64 = match record[id_idx].parse() {
-        Ok(id) => id,
-        Err(_) => {
-            importer.record_error();
-            return Ok(());
-        }
-    };
-
-    match parse_vector(&record[vector_idx]) {
-        Ok(vector) => {
-            if vector.len() != dimension {
-                importer.record_error();
-                return Ok(());
-            }
-            let payload_val = build_csv_payload(headers, record, id_idx, vector_idx);
-            importer.push(Point::new(id, vector, payload_val))?;
-        }
-        Err(_) => {
-            importer.record_error();
-        }
-    }
-    Ok(())
-}
-
-/// Builds a JSON payload from non-id, non-vector CSV columns.
-fn build_csv_payload(
-    headers: &csv::StringRecord,
-    record: &csv::StringRecord,
-    id_idx: usize,
-    vector_idx: usize,
-) -> Option<serde_json::Value> {
-    let mut payload = serde_json::Map::new();
-    for (i, header) in headers.iter().enumerate() {
-        if i != id_idx && i != vector_idx {
-            payload.insert(
-                header.to_string(),
-                serde_json::Value::String(record[i].to_string()),
-            );
-        }
-    }
-    if payload.is_empty() {
-        None
-    } else {
-        Some(serde_json::Value::Object(payload))
-    }
-}
-
-/// Parse vector from string (comma-separated or JSON array)
-fn parse_vector(s: &str) -> Result<Vec<f32>> {
-    let s = s.trim();
-    if s.starts_with('[') {
-        // JSON array format
-        serde_json::from_str(s).context("Invalid JSON vector")
-    } else {
-        // Comma-separated format
-        s.split(',')
-            .map(|v| v.trim().parse::<f32>().context("Invalid float value"))
-            .collect()
-    }
+/// Parse a vector cell (JSON array, comma-separated or base64 f32).
+fn parse_vector(s: &str, encoding: VectorEncoding) -> Result<Vec<f32>> {
+    encoding.parse(s).map_err(anyhow::Error::msg)
 }
 
 /// Get or create collection
@@ -376,6 +269,8 @@ pub struct ImportStats {
     pub imported: usize,
     pub errors: usize,
     pub duration_ms: u64,
+    /// Line and reason of the first skipped rows.
+    pub skipped_rows: Vec<ImportRowError>,
 }
 
 impl ImportStats {
@@ -47,42 +47,42 @@ fn run_jsonl_import(db_path: &Path, jsonl_path: &Path) -> (Database, ImportStats
 #[test]
 fn test_parse_vector_json_array() {
     let input = "[1.0, 2.0, 3.0]";
-    let result = parse_vector(input).unwrap();
+    let result = parse_vector(input, VectorEncoding::Json).unwrap();
     assert_eq!(result, vec![1.0, 2.0, 3.0]);
 }
 
 #[test]
 fn test_parse_vector_json_array_with_whitespace() {
     let input = "  [ 1.0 , 2.0 , 3.0 ]  ";
-    let result = parse_vector(input).unwrap();
+    let result = parse_vector(input, VectorEncoding::Json).unwrap();
     assert_eq!(result, vec![1.0, 2.0, 3.0]);
 }
 
 #[test]
 fn test_parse_vector_comma_separated() {
     let input = "1.0, 2.0, 3.0";
-    let result = parse_vector(input).unwrap();
+    let result = parse_vector(input, VectorEncoding::Json).unwrap();
     assert_eq!(result, vec![1.0, 2.0, 3.0]);
 }
 
 #[test]
 fn test_parse_vector_comma_separated_no_spaces() {
     let input = "1.0,2.0,3.0";
-    let result = parse_vector(input).unwrap();
+    let result = parse_vector(input, VectorEncoding::Json).unwrap();
     assert_eq!(result, vec![1.0, 2.0, 3.0]);
 }
 
 #[test]
 fn test_parse_vector_invalid_json() {
     let input = "[1.0, 2.0, invalid]";
-    let result = parse_vector(input);
+    let result = parse_vector(input, VectorEncoding::Json);
     assert!(result.is_err());
 }
 
 #[test]
 fn test_parse_vector_invalid_csv() {
     let input = "1.0, not_a_number, 3.0";
-    let result = parse_vector(input);
+    let result = parse_vector(input, VectorEncoding::Json);
     assert!(result.is_err());
 }
 
@@ -97,6 +97,7 @@ fn test_import_stats_records_per_sec() {
         imported: 1000,
         errors: 0,
         duration_ms: 500,
+        ..Default::default()
     };
     assert!((stats.records_per_sec() - 2000.0).abs() < 0.001);
 }
@@ -108,6 +109,7 @@ fn test_import_stats_records_per_sec_zero_duration() {
         imported: 1000,
         errors: 0,
         duration_ms: 0,
+        ..Default::default()
     };
     assert_eq!(stats.records_per_sec(), 0.0);
 }
@@ -328,6 +330,60 @@ fn test_import_csv_custom_columns() {
     assert_eq!(stats.imported, 2);
 }
 
+#[test]
+fn test_import_csv_base64_vectors_and_payload_columns() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db");
+    let csv_path = dir.path().join("data.csv");
+    let vector = velesdb_core::wire::f32_base64::encode(&[0.0, 1.0, 0.0]);
+
+    write_lines(
+        &csv_path,
+        &["id,vector,title,internal", &format!("1,{vector},first,x")],
+    );
+
+    let db = Database::open(&db_path).unwrap();
+    let config = ImportConfig {
+        collection: "test".to_string(),
+        vector_encoding: VectorEncoding::Base64F32,
+        payload_columns: Some(vec!["title".to_string()]),
+        show_progress: false,
+        ..Default::default()
+    };
+
+    let stats = import_csv(&db, &csv_path, &config).unwrap();
+
+    assert_eq!(stats.imported, 1);
+    let col = db.get_vector_collection("test").unwrap();
+    let point = col.get(&[1])[0].clone().unwrap();
+    assert_eq!(point.vector, vec![0.0, 1.0, 0.0]);
+    assert_eq!(point.payload, Some(serde_json::json!({"title": "first"})));
+}
+
+#[test]
+fn test_import_jsonl_abort_on_bad_row() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db");
+    let jsonl_path = dir.path().join("data.jsonl");
+
+    write_lines(
+        &jsonl_path,
+        &[
+            r#"{"id": 1, "vector": [1.0, 0.0, 0.0]}"#,
+            r#"{"id": 2, "vector": [0.0, 1.0]}"#,
+            r#"{"id": 3, "vector": [0.0, 0.0, 1.0]}"#,
+        ],
+    );
+
+    let (db, mut config) = open_db_and_test_config(&db_path);
+    config.on_bad_row = OnBadRow::Abort;
+
+    let err = import_jsonl(&db, &jsonl_path, &config).unwrap_err();
+
+    assert!(format!("{err:#}").contains("line 2"));
+    assert_eq!(db.get_vector_collection("test").unwrap().len(), 1);
+}
+
 // =========================================================================
 // Integration tests for VRB1 binary import
 // =========================================================================
@@ -46,6 +46,7 @@ mod repl_data_cmds;
 mod repl_execute;
 #[allow(clippy::pedantic)]
 mod repl_graph_cmds;
+mod repl_input; // pedantic-clean ✓
 #[allow(clippy::pedantic)]
 mod repl_output;
 #[allow(clippy::pedantic)]
@@ -172,19 +173,27 @@ fn dispatch_data(action: DataCommands) -> anyhow::Result<()> {
             storage_mode,
             id_column,
             vector_column,
+            vector_encoding,
+            payload_columns,
+            on_error,
             batch_size,
             progress,
         } => handlers::handle_import(
             &file,
             &database,
-            collection,
-            dimension,
-            metric,
-            storage_mode,
-            id_column,
-            vector_column,
-            batch_size,
-            progress,
+            import::ImportConfig {
+                collection,
+                dimension,
+                metric: metric.into(),
+                storage_mode: storage_mode.into(),
+                batch_size,
+                id_column,
+                vector_column,
+                vector_encoding: vector_encoding.into(),
+                payload_columns,
+                on_bad_row: on_error.into(),
+                show_progress: progress,
+            },
         ),
         DataCommands::Export {
             path,
@@ -9,7 +9,8 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use rustyline::error::ReadlineError;
 use rustyline::history::DefaultHistory;
-use rustyline::{Completer, Editor, Helper, Highlighter, Hinter, Validator};
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Completer, Editor, Helper, Highlighter, Hinter};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use velesdb_core::Database;
@@ -68,9 +69,22 @@ pub struct QueryResult {
     pub kind: QueryKind,
 }
 
-#[derive(Completer, Helper, Highlighter, Hinter, Validator)]
+#[derive(Completer, Helper, Highlighter, Hinter)]
 struct ReplHelper;
 
+/// Keeps reading continuation lines until the statement is complete, so a
+/// multi-line statement is edited (and recalled from history) as one entry.
+/// Rustyline applies this to piped input too.
+impl Validator for ReplHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        Ok(if crate::repl_input::is_complete(ctx.input()) {
+            ValidationResult::Valid(None)
+        } else {
+            ValidationResult::Incomplete
+        })
+    }
+}
+
 /// Run the interactive REPL
 #[allow(clippy::needless_pass_by_value)] // PathBuf ownership required for Database::open
 pub fn run(path: PathBuf) -> Result<()> {
@@ -146,7 +160,8 @@ fn prompt() -> String {
     }
 }
 
-/// Handle a single line of input: dot-commands vs. `VelesQL` queries.
+/// Handle one input entry (possibly multi-line): dot-commands vs. `VelesQL`
+/// queries.
 fn handle_input(
     db: &Database,
     rl: &mut Editor<ReplHelper, DefaultHistory>,
@@ -163,7 +178,7 @@ fn handle_input(
     if line.starts_with('.') || line.starts_with('\\') {
         handle_dot_command(db, line, config)
     } else {
-        run_query(db, line, config);
+        run_query(db, &crate::repl_input::join_lines(line), config);
         LoopAction::Continue
     }
 }
@@ -331,6 +346,7 @@ mod tests {
     fn test_contains_vector_search_with_vector() {
         let condition = Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Literal(vec![0.1, 0.2]),
+            avoid: Vec::new(),
         });
         assert!(contains_vector_search(&condition));
     }
@@ -349,6 +365,7 @@ mod tests {
     fn test_contains_vector_search_nested_and() {
         let vector_cond = Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Literal(vec![0.1]),
+            avoid: Vec::new(),
         });
         let other_cond = Condition::Comparison(Comparison {
             column: "x".to_string(),
@@ -363,6 +380,7 @@ mod tests {
     fn test_contains_vector_search_nested_or() {
         let vector_cond = Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Literal(vec![0.1]),
+            avoid: Vec::new(),
         });
         let other_cond = Condition::Comparison(Comparison {
             column: "x".to_string(),
@@ -377,6 +395,7 @@ mod tests {
     fn test_contains_vector_search_group() {
         let vector_cond = Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Literal(vec![0.1]),
+            avoid: Vec::new(),
         });
         let grouped = Condition::Group(Box::new(vector_cond));
         assert!(contains_vector_search(&grouped));
@@ -406,6 +425,7 @@ mod tests {
     fn test_contains_param_vector_vector_search_param() {
         let cond = Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         });
         assert!(contains_param_vector(&cond));
     }
@@ -414,6 +434,7 @@ mod tests {
     fn test_contains_param_vector_vector_search_literal() {
         let cond = Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Literal(vec![0.1, 0.2]),
+            avoid: Vec::new(),
         });
         assert!(!contains_param_vector(&cond));
     }
@@ -487,6 +508,7 @@ mod tests {
     fn test_contains_param_vector_not_recurses() {
         let inner = Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         });
         let cond = Condition::Not(Box::new(inner));
         assert!(contains_param_vector(&cond));
@@ -3,12 +3,15 @@
 //! Covers: `.timing`, `.format`, `.clear`, `\set`, `\show`, `\reset`,
 //! `\use`, `\info`.
 
+use std::path::Path;
+
 use colored::Colorize;
 use velesdb_core::Database;
 
 use crate::collection_helpers;
 use crate::repl::{OutputFormat, ReplConfig};
 use crate::repl_commands::CommandResult;
+use crate::repl_input;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -65,10 +68,14 @@ pub(crate) fn cmd_set(config: &mut ReplConfig, parts: &[&str]) -> CommandResult
     if parts.len() < 3 {
         println!("Usage: \\set <setting> <value>\n");
         println!("Settings: mode, ef_search, timeout_ms, rerank, max_results\n");
+        println!("Bind a query parameter from a JSON file: \\set <name> @<file.json>\n");
         return CommandResult::Continue;
     }
     let key = parts[1];
     let value = parts[2];
+    if let Some(path) = value.strip_prefix('@') {
+        return bind_param(config, key, path);
+    }
     match config.session.set(key, value) {
         Ok(()) => {
             println!("{} = {}", key.cyan(), value.green());
@@ -80,6 +87,23 @@ pub(crate) fn cmd_set(config: &mut ReplConfig, parts: &[&str]) -> CommandResult
     CommandResult::Continue
 }
 
+/// Binds `$name` to the JSON value stored in `path` (`\set v @query.json`).
+fn bind_param(config: &mut ReplConfig, name: &str, path: &str) -> CommandResult {
+    match repl_input::load_param_file(Path::new(path)) {
+        Ok(value) => {
+            let name = name.trim_start_matches('$');
+            println!(
+                "{} = {}\n",
+                format!("${name}").cyan(),
+                repl_input::describe_param(&value).green()
+            );
+            config.session.set_param(name, value);
+            CommandResult::Continue
+        }
+        Err(e) => CommandResult::Error(e),
+    }
+}
+
 /// Warns that a stored setting is display-only because it has no channel into
 /// `Database::execute_query` yet, so `\set` does not silently claim it applies.
 fn warn_if_unwired(key: &str) {
@@ -100,10 +124,27 @@ pub(crate) fn cmd_show(config: &ReplConfig, parts: &[&str]) -> CommandResult {
         for (key, value) in config.session.all_settings() {
             println!("  {} = {}", key.cyan(), value.green());
         }
+        let mut params: Vec<_> = config.session.params().iter().collect();
+        if !params.is_empty() {
+            params.sort_by_key(|(name, _)| *name);
+            println!("\n{}", "Query Parameters".bold().underline());
+            for (name, value) in params {
+                println!(
+                    "  {} = {}",
+                    format!("${name}").cyan(),
+                    repl_input::describe_param(value).green()
+                );
+            }
+        }
         println!();
     } else {
         let key = parts[1];
-        match config.session.get(key) {
+        let param = config
+            .session
+            .params()
+            .get(key.trim_start_matches('$'))
+            .map(repl_input::describe_param);
+        match config.session.get(key).or(param) {
             Some(value) => println!("{} = {}\n", key.cyan(), value.green()),
             None => return CommandResult::Error(format!("Unknown setting: {key}")),
         }
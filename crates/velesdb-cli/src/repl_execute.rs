@@ -16,7 +16,9 @@ use crate::session::SessionSettings;
 /// MATCH queries are routed through [`route_match_query`], which also goes
 /// through [`Database::execute_query`] so cross-collection `@collection`
 /// annotations are enriched (the active collection set via `.use` is injected
-/// as the `_collection` param when the query has no explicit `FROM`).
+/// as the `_collection` param when the query has no explicit `FROM`). Query
+/// parameters bound in the session with `\set <name> @<file>` are passed to
+/// every statement.
 pub fn execute_query(
     db: &Database,
     query: &str,
@@ -29,10 +31,12 @@ pub fn execute_query(
     let mut parsed = velesdb_core::velesql::Parser::parse(query)
         .map_err(|e| anyhow::anyhow!("Parse error: {}", e.message))?;
 
-    // $parameter vectors can't be supplied from the REPL (use the REST API).
-    // Returning Err (not Ok-empty) makes the REPL print a red error and scripts
-    // exit non-zero instead of silently treating 0 rows as success.
-    if has_param_vector(&parsed) {
+    // $parameter vectors need a session binding (`\set v @file.json`); without
+    // one, returning Err (not Ok-empty) makes the REPL print a red error and
+    // scripts exit non-zero instead of silently treating 0 rows as success.
+    let empty = HashMap::new();
+    let bound = session.map_or(&empty, SessionSettings::params);
+    if bound.is_empty() && has_param_vector(&parsed) {
         return Err(param_vector_unsupported_error());
     }
 
@@ -46,10 +50,10 @@ pub fn execute_query(
     // aggregate engine; the standard SELECT projection path returns empty rows
     // for aggregate columns, so without this the REPL would print raw rows.
     if !parsed.is_match_query() && parsed.select.is_aggregation_query() {
-        return run_aggregation_query(db, &parsed, active_collection, start);
+        return run_aggregation_query(db, &parsed, active_collection, bound, start);
     }
 
-    run_row_query(db, &parsed, active_collection, start)
+    run_row_query(db, &parsed, active_collection, bound, start)
 }
 
 /// Applies REPL session settings to a parsed query before execution.
@@ -119,13 +123,13 @@ fn has_param_vector(parsed: &velesdb_core::velesql::Query) -> bool {
             .is_some_and(contains_param_vector)
 }
 
-/// The error returned when a query needs a `$parameter` vector the REPL cannot
-/// supply. Surfaced as an `Err` so the REPL prints it red and scripts exit
+/// The error returned when a query needs a `$parameter` vector and none is
+/// bound. Surfaced as an `Err` so the REPL prints it red and scripts exit
 /// non-zero rather than seeing an empty (silently-successful) result.
 fn param_vector_unsupported_error() -> anyhow::Error {
     anyhow::anyhow!(
-        "Vector search with $parameter requires the REST API. \
-         Use literal vectors or metadata-only queries."
+        "Vector search with $parameter needs a bound value. \
+         In the REPL, bind it from a JSON file: \\set v @query.json"
     )
 }
 
@@ -137,15 +141,16 @@ fn run_row_query(
     db: &Database,
     parsed: &velesdb_core::velesql::Query,
     active_collection: Option<&str>,
+    bound: &HashMap<String, serde_json::Value>,
     start: Instant,
 ) -> Result<QueryResult> {
     let kind = query_kind(parsed);
     let rows = if parsed.is_match_query() {
-        let results = route_match_query(db, parsed, active_collection)?;
+        let results = route_match_query(db, parsed, active_collection, bound)?;
         results.into_iter().map(result_to_row).collect()
     } else {
         let results = db
-            .execute_query(parsed, &HashMap::new())
+            .execute_query(parsed, bound)
             .map_err(|e| anyhow::anyhow!("Query error: {e}"))?;
         if matches!(kind, QueryKind::Select) {
             project_select_rows(&results, &parsed.select.columns)
@@ -203,26 +208,29 @@ fn route_match_query(
     db: &Database,
     parsed: &velesdb_core::velesql::Query,
     active_collection: Option<&str>,
+    bound: &HashMap<String, serde_json::Value>,
 ) -> Result<Vec<velesdb_core::SearchResult>> {
     let params = params_with_active_collection(
         parsed,
         active_collection,
+        bound,
         "MATCH queries require an active collection. Use: .use <collection_name>",
     )?;
     db.execute_query(parsed, &params)
         .map_err(|e| anyhow::anyhow!("Query error: {e}"))
 }
 
-/// Builds the params map for a query, injecting the active collection as the
-/// `_collection` key when the query has no explicit `FROM` (the REPL selects the
-/// target via `.use <collection>`). `requires_msg` is the error shown when no
-/// active collection is set.
+/// Builds the params map for a query from the `bound` session parameters,
+/// injecting the active collection as the `_collection` key when the query has
+/// no explicit `FROM` (the REPL selects the target via `.use <collection>`).
+/// `requires_msg` is the error shown when no active collection is set.
 fn params_with_active_collection(
     parsed: &velesdb_core::velesql::Query,
     active_collection: Option<&str>,
+    bound: &HashMap<String, serde_json::Value>,
     requires_msg: &str,
 ) -> Result<HashMap<String, serde_json::Value>> {
-    let mut params = HashMap::new();
+    let mut params = bound.clone();
     if parsed.select.from.is_empty() {
         let col_name = active_collection.ok_or_else(|| anyhow::anyhow!("{requires_msg}"))?;
         params.insert(
@@ -239,11 +247,13 @@ fn run_aggregation_query(
     db: &Database,
     parsed: &velesdb_core::velesql::Query,
     active_collection: Option<&str>,
+    bound: &HashMap<String, serde_json::Value>,
     start: Instant,
 ) -> Result<QueryResult> {
     let params = params_with_active_collection(
         parsed,
         active_collection,
+        bound,
         "Aggregation queries require an active collection. Use: .use <collection_name>",
     )?;
     let value = db
@@ -290,7 +300,9 @@ fn result_to_row(r: velesdb_core::SearchResult) -> HashMap<String, serde_json::V
 pub(crate) fn contains_param_vector(condition: &velesdb_core::velesql::Condition) -> bool {
     use velesdb_core::velesql::{Condition, SparseVectorExpr, VectorExpr};
     match condition {
-        Condition::VectorSearch(vs) => matches!(vs.vector, VectorExpr::Parameter(_)),
+        Condition::VectorSearch(vs) => std::iter::once(&vs.vector)
+            .chain(&vs.avoid)
+            .any(|v| matches!(v, VectorExpr::Parameter(_))),
         Condition::VectorFusedSearch(vfs) => vfs
             .vectors
             .iter()
@@ -234,3 +234,24 @@ fn test_unwired_set_keys_are_flagged() {
         "max_results IS wired and must not warn"
     );
 }
+
+/// `$parameter` vectors and scalars resolve from the session bindings made
+/// with `\set <name> @<file>`; without a binding the REPL still refuses the
+/// vector search with an error naming the fix.
+#[test]
+fn test_execute_query_binds_session_params() {
+    let dir = TempDir::new().expect("temp dir");
+    let db = seed_docs(&dir, 3);
+    let query = "SELECT * FROM docs WHERE vector NEAR $v AND category = $cat LIMIT 2";
+
+    let err = execute_query(&db, query, None, Some(&SessionSettings::new()))
+        .expect_err("unbound vector parameter");
+    assert!(err.to_string().contains("\\set v @"), "{err}");
+
+    let mut session = SessionSettings::new();
+    session.set_param("v", serde_json::json!([1.0, 3.0]));
+    session.set_param("cat", serde_json::json!("x"));
+    let result = execute_query(&db, query, None, Some(&session)).expect("bound params");
+    assert_eq!(result.rows.len(), 2);
+    assert_eq!(result.rows[0]["id"], serde_json::json!(3));
+}
@@ -180,6 +180,8 @@ fn cmd_graph_traverse(db: &Database, parts: &[&str]) -> CommandResult {
         limit,
         rel_types,
         deadline: None,
+        valid_at: None,
+        max_cost: None,
     };
 
     let algo_label = match algo.as_str() {
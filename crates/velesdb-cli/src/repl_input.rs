@@ -0,0 +1,128 @@
+//! Multi-line input and parameter files for the REPL.
+//!
+//! A `VelesQL` statement continues on the next line while a bracket or quote
+//! is left open, or when the line ends with `\`. Dot/backslash commands are
+//! always single-line. Parameter files hold the JSON value bound with
+//! `\set <name> @<file>`.
+
+use std::path::Path;
+
+/// Returns `true` when `input` is ready to run, `false` when the REPL should
+/// keep reading continuation lines.
+pub fn is_complete(input: &str) -> bool {
+    let trimmed = input.trim();
+    if trimmed.is_empty() || trimmed.starts_with('.') || trimmed.starts_with('\\') {
+        return true;
+    }
+    if trimmed.ends_with('\\') {
+        return false;
+    }
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    for c in trimmed.chars() {
+        match quote {
+            // A doubled quote ('') closes and reopens, so toggling is enough.
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                _ => {}
+            },
+        }
+    }
+    depth == 0 && quote.is_none()
+}
+
+/// Joins a multi-line statement into the text handed to the parser, dropping
+/// the `\` continuation markers.
+pub fn join_lines(input: &str) -> String {
+    input
+        .lines()
+        .map(|line| {
+            line.trim_end()
+                .strip_suffix('\\')
+                .unwrap_or(line)
+                .trim_end()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Reads the JSON value of a `\set <name> @<file>` binding.
+pub fn load_param_file(path: &Path) -> Result<serde_json::Value, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+    serde_json::from_str(&text).map_err(|e| format!("Invalid JSON in {}: {e}", path.display()))
+}
+
+/// Short display form of a bound parameter: vectors show their length
+/// instead of every component.
+pub fn describe_param(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Array(items) if items.iter().all(serde_json::Value::is_number) => {
+            format!("[{} floats]", items.len())
+        }
+        other => {
+            let text = other.to_string();
+            if text.chars().count() > 60 {
+                format!("{}...", text.chars().take(57).collect::<String>())
+            } else {
+                text
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_is_complete_single_line_and_commands() {
+        assert!(is_complete("SELECT * FROM docs LIMIT 10"));
+        assert!(is_complete("SELECT * FROM docs LIMIT 10;"));
+        assert!(is_complete(".help"));
+        assert!(is_complete("\\set v @query.json"));
+        assert!(is_complete(""));
+    }
+
+    #[test]
+    fn test_is_complete_waits_for_open_brackets_quotes_and_backslash() {
+        assert!(!is_complete("SELECT * FROM docs \\"));
+        assert!(!is_complete("SELECT * FROM docs WHERE vector NEAR [0.1,"));
+        assert!(!is_complete("SELECT * FROM docs WHERE title = 'it''s"));
+        assert!(is_complete("SELECT * FROM docs WHERE title = 'it''s'"));
+        assert!(is_complete(
+            "SELECT * FROM docs WHERE vector NEAR [0.1,\n0.2] LIMIT 5"
+        ));
+        assert!(is_complete("SELECT * FROM docs WHERE title = '(' LIMIT 1"));
+    }
+
+    #[test]
+    fn test_join_lines_drops_continuation_markers() {
+        assert_eq!(
+            join_lines("SELECT * \\\nFROM docs \\\nLIMIT 10"),
+            "SELECT *\nFROM docs\nLIMIT 10"
+        );
+    }
+
+    #[test]
+    fn test_load_param_file_and_describe() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("query.json");
+        std::fs::write(&path, "[0.1, 0.2, 0.3]").unwrap();
+        let value = load_param_file(&path).unwrap();
+        assert_eq!(value, json!([0.1, 0.2, 0.3]));
+        assert_eq!(describe_param(&value), "[3 floats]");
+        assert_eq!(describe_param(&json!("tech")), "\"tech\"");
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(load_param_file(&path).unwrap_err().contains("Invalid JSON"));
+        assert!(load_param_file(&dir.path().join("missing.json")).is_err());
+    }
+}
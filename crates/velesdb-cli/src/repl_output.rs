@@ -229,6 +229,10 @@ pub fn print_help() {
         "  {}   Set session parameter",
         "\\set <key> <value>".yellow()
     );
+    println!(
+        "  {}  Bind $name from a JSON file",
+        "\\set <name> @<file>".yellow()
+    );
     println!("  {}       Show session settings", "\\show [key]".yellow());
     println!("  {}      Reset settings", "\\reset [key]".yellow());
     println!(
@@ -304,7 +308,11 @@ pub fn print_help() {
     );
     println!(
         "  {}",
-        "Note: $parameter vectors require REST API. Use literal [0.1, ...] in REPL.".dimmed()
+        "Tip: Bind $parameters from JSON files: \\set v @query.json, then ... NEAR $v".dimmed()
+    );
+    println!(
+        "  {}",
+        "Tip: Leave a bracket or quote open, or end a line with \\, to continue a query.".dimmed()
     );
     println!();
 }
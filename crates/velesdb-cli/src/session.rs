@@ -1,6 +1,7 @@
 //! Session configuration for VelesDB REPL.
 //!
-//! Manages session-level settings that can be modified with `\set` and viewed with `\show`.
+//! Manages session-level settings that can be modified with `\set` and viewed with `\show`,
+//! plus the `$name` query parameters bound with `\set <name> @<file>`.
 
 use std::collections::HashMap;
 use velesdb_core::SearchQuality;
@@ -22,6 +23,8 @@ pub struct SessionSettings {
     active_collection: Option<String>,
     /// Custom settings.
     custom: HashMap<String, String>,
+    /// Query parameters bound to `$name` placeholders.
+    params: HashMap<String, serde_json::Value>,
 }
 
 impl Default for SessionSettings {
@@ -34,6 +37,7 @@ impl Default for SessionSettings {
             max_results: 100,
             active_collection: None,
             custom: HashMap::new(),
+            params: HashMap::new(),
         }
     }
 }
@@ -158,6 +162,19 @@ impl SessionSettings {
         }
     }
 
+    /// Binds the value of the `$name` query parameter (a leading `$` is
+    /// ignored), replacing any previous binding.
+    pub fn set_param(&mut self, name: &str, value: serde_json::Value) {
+        self.params
+            .insert(name.trim_start_matches('$').to_string(), value);
+    }
+
+    /// Gets the bound query parameters, passed to every query.
+    #[must_use]
+    pub fn params(&self) -> &HashMap<String, serde_json::Value> {
+        &self.params
+    }
+
     /// Sets the active collection.
     pub fn use_collection(&mut self, name: Option<String>) {
         self.active_collection = name;
@@ -178,6 +195,7 @@ impl SessionSettings {
                 "collection" => self.active_collection = None,
                 _ => {
                     self.custom.remove(k);
+                    self.params.remove(k.trim_start_matches('$'));
                 }
             },
         }
@@ -244,8 +262,16 @@ fn format_quality(q: SearchQuality) -> String {
         SearchQuality::Perfect => "perfect".to_string(),
         SearchQuality::AutoTune => "autotune".to_string(),
         SearchQuality::Custom(ef) => format!("custom:{ef}"),
-        SearchQuality::Adaptive { min_ef, max_ef } => {
-            format!("adaptive:{min_ef}:{max_ef}")
+        SearchQuality::Adaptive {
+            min_ef,
+            max_ef,
+            patience,
+        } => {
+            if patience == SearchQuality::DEFAULT_ADAPTIVE_PATIENCE {
+                format!("adaptive:{min_ef}:{max_ef}")
+            } else {
+                format!("adaptive:{min_ef}:{max_ef}:{patience}")
+            }
         }
         _ => format!("{q:?}").to_lowercase(),
     }
@@ -263,7 +289,7 @@ fn parse_mode(value: &str) -> Result<SearchQuality, String> {
     }
 }
 
-/// Parses `custom:<ef>` and `adaptive:<min>:<max>` mode strings.
+/// Parses `custom:<ef>` and `adaptive:<min>:<max>[:<patience>]` mode strings.
 fn parse_parameterized_mode(value: &str) -> Result<SearchQuality, String> {
     if let Some(ef_str) = value.strip_prefix("custom:") {
         let ef = ef_str
@@ -272,9 +298,9 @@ fn parse_parameterized_mode(value: &str) -> Result<SearchQuality, String> {
         return Ok(SearchQuality::Custom(ef));
     }
     if let Some(rest) = value.strip_prefix("adaptive:") {
-        let parts: Vec<&str> = rest.splitn(2, ':').collect();
-        if parts.len() != 2 {
-            return Err("adaptive format: adaptive:<min_ef>:<max_ef>".to_string());
+        let parts: Vec<&str> = rest.split(':').collect();
+        if parts.len() != 2 && parts.len() != 3 {
+            return Err("adaptive format: adaptive:<min_ef>:<max_ef>[:<patience>]".to_string());
         }
         let min_ef = parts[0]
             .parse::<usize>()
@@ -282,11 +308,21 @@ fn parse_parameterized_mode(value: &str) -> Result<SearchQuality, String> {
         let max_ef = parts[1]
             .parse::<usize>()
             .map_err(|_| format!("Invalid max_ef in '{value}'"))?;
-        return Ok(SearchQuality::Adaptive { min_ef, max_ef });
+        let patience = match parts.get(2) {
+            Some(p) => p
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid patience in '{value}'"))?,
+            None => SearchQuality::DEFAULT_ADAPTIVE_PATIENCE,
+        };
+        return Ok(SearchQuality::Adaptive {
+            min_ef,
+            max_ef,
+            patience,
+        });
     }
     Err(format!(
         "Invalid mode '{value}'. Valid: fast, balanced, accurate, \
-         perfect, autotune, custom:<ef>, adaptive:<min>:<max>"
+         perfect, autotune, custom:<ef>, adaptive:<min>:<max>[:<patience>]"
     ))
 }
 
@@ -415,6 +451,19 @@ mod tests {
         assert_eq!(session.get("custom_key"), Some("custom_value".to_string()));
     }
 
+    #[test]
+    fn test_params_bind_and_reset() {
+        let mut session = SessionSettings::new();
+        session.set_param("$v", serde_json::json!([0.1, 0.2]));
+        session.set_param("cat", serde_json::json!("tech"));
+        assert_eq!(session.params()["v"], serde_json::json!([0.1, 0.2]));
+
+        session.reset(Some("$v"));
+        assert!(!session.params().contains_key("v"));
+        session.reset(None);
+        assert!(session.params().is_empty());
+    }
+
     #[test]
     fn test_set_mode_autotune() {
         let mut session = SessionSettings::new();
@@ -434,11 +483,15 @@ mod tests {
     fn test_set_mode_adaptive() {
         let mut session = SessionSettings::new();
         session.set("mode", "adaptive:32:512").unwrap();
+        assert_eq!(session.mode(), SearchQuality::adaptive(32, 512));
+
+        session.set("mode", "adaptive:32:512:16").unwrap();
         assert_eq!(
             session.mode(),
             SearchQuality::Adaptive {
                 min_ef: 32,
-                max_ef: 512
+                max_ef: 512,
+                patience: 16
             }
         );
     }
@@ -362,6 +362,29 @@ fn test_repl_velesql_select_metadata() {
         .stdout(predicate::str::contains("id").and(predicate::str::contains("rows")));
 }
 
+#[test]
+fn test_repl_velesql_multi_line_with_bound_param() {
+    let (db_path, temp) = setup_vector("vecs", 4);
+    let vector_file = temp.path().join("query.json");
+    std::fs::write(&vector_file, "[0.05, 0.06, 0.07, 0.08]").unwrap();
+    let bind = format!("\\set v @{}", vector_file.display());
+
+    repl_run(
+        &db_path,
+        &[
+            &bind,
+            "SELECT * FROM vecs \\",
+            "WHERE vector NEAR $v \\",
+            "LIMIT 2;",
+        ],
+    )
+    .stdout(
+        predicate::str::contains("[4 floats]")
+            .and(predicate::str::contains("vec_5"))
+            .and(predicate::str::contains("2 rows")),
+    );
+}
+
 // ============================================================================
 // .graph subcommands via REPL
 // ============================================================================
@@ -0,0 +1,92 @@
+//! Benchmark for parsing `POST /collections/{name}/points` bodies
+//!
+//! Compares the row layout (`{"points": [{id, vector, payload}, ...]}`) with
+//! the columnar layout (`{"ids", "vectors", "payloads"}`), with float-array
+//! and base64 vectors. Each iteration deserializes the body and decodes the
+//! vectors, i.e. everything the server does before `upsert_bulk`.
+
+#![allow(clippy::cast_precision_loss)]
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde_json::{json, Value};
+use velesdb_core::api_types::UpsertPointsRequest;
+use velesdb_core::wire::f32_base64;
+
+const POINTS: usize = 1_000;
+
+fn vector(i: usize, dimension: usize) -> Vec<f32> {
+    (0..dimension)
+        .map(|j| ((i * dimension + j) % 1000) as f32 / 1000.0 + 0.000_123)
+        .collect()
+}
+
+fn payload(i: usize) -> Value {
+    json!({ "title": format!("Document {i}"), "category": "tech" })
+}
+
+fn row_body(dimension: usize, base64: bool) -> String {
+    let points: Vec<Value> = (0..POINTS)
+        .map(|i| {
+            let v = vector(i, dimension);
+            let v = if base64 {
+                json!({ "$bytes": f32_base64::encode(&v) })
+            } else {
+                json!(v)
+            };
+            json!({ "id": i, "vector": v, "payload": payload(i) })
+        })
+        .collect();
+    json!({ "points": points }).to_string()
+}
+
+fn columnar_body(dimension: usize, base64: bool) -> String {
+    let vectors: Vec<Vec<f32>> = (0..POINTS).map(|i| vector(i, dimension)).collect();
+    let vectors = if base64 {
+        json!(f32_base64::encode(&vectors.concat()))
+    } else {
+        json!(vectors)
+    };
+    json!({
+        "ids": (0..POINTS).collect::<Vec<_>>(),
+        "vectors": vectors,
+        "payloads": (0..POINTS).map(payload).collect::<Vec<_>>(),
+    })
+    .to_string()
+}
+
+fn parse(body: &str, dimension: usize) -> Vec<Vec<f32>> {
+    let req: UpsertPointsRequest = serde_json::from_str(body).unwrap();
+    if req.is_columnar() {
+        let count = req.ids.len();
+        req.vectors.unwrap().into_rows(count, dimension).unwrap()
+    } else {
+        req.points
+            .into_iter()
+            .map(|p| p.vector.into_vector(dimension).unwrap())
+            .collect()
+    }
+}
+
+fn bench_upsert_body(c: &mut Criterion) {
+    let mut group = c.benchmark_group("upsert_body_parse");
+    group.sample_size(20);
+    group.throughput(Throughput::Elements(POINTS as u64));
+
+    for dimension in [384, 768] {
+        let bodies = [
+            ("rows_floats", row_body(dimension, false)),
+            ("rows_bytes", row_body(dimension, true)),
+            ("columnar_floats", columnar_body(dimension, false)),
+            ("columnar_base64", columnar_body(dimension, true)),
+        ];
+        for (layout, body) in &bodies {
+            group.bench_with_input(BenchmarkId::new(*layout, dimension), body, |b, body| {
+                b.iter(|| black_box(parse(body, dimension)));
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_upsert_body);
+criterion_main!(benches);
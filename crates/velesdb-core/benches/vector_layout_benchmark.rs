@@ -0,0 +1,88 @@
+//! Benchmark exact-scan layouts and cache-blocked batch kernels on
+//! high-dimensional vectors.
+//!
+//! - `exact_scan`: one query against every vector, AoS (one dispatched
+//!   kernel call per vector) vs blocked struct-of-arrays
+//!   (`VectorLayout::BlockedSoa`), with and without the regrouping a
+//!   brute-force scan performs first.
+//! - `batch_high_dim`: per-pair kernel calls vs the cache-blocked batch
+//!   kernels used from 2048 dimensions up.
+//!
+//! Run with: `cargo bench --bench vector_layout_benchmark`
+
+#![allow(clippy::cast_precision_loss)]
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use velesdb_core::simd_native::{batch_cosine_native, cosine_similarity_native};
+use velesdb_core::vector_layout::BlockedSoaVectors;
+use velesdb_core::DistanceMetric;
+
+const SCAN_VECTORS: usize = 2048;
+const BATCH_CANDIDATES: usize = 256;
+
+fn generate_flat(count: usize, dim: usize) -> Vec<f32> {
+    (0..count * dim).map(|i| (i as f32 * 0.618).sin()).collect()
+}
+
+fn bench_exact_scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("exact_scan");
+    group.sample_size(20);
+
+    for dim in [768usize, 3072, 8192] {
+        let flat = generate_flat(SCAN_VECTORS, dim);
+        let query: Vec<f32> = flat[..dim].iter().map(|v| v * 0.5).collect();
+        let blocked = BlockedSoaVectors::from_flat(&flat, dim);
+        group.throughput(Throughput::Elements(SCAN_VECTORS as u64));
+
+        group.bench_with_input(BenchmarkId::new("aos", dim), &dim, |b, _| {
+            b.iter(|| {
+                flat.chunks_exact(dim)
+                    .map(|v| cosine_similarity_native(black_box(&query), v))
+                    .collect::<Vec<f32>>()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("blocked_soa", dim), &dim, |b, _| {
+            b.iter(|| blocked.scores(black_box(&query), DistanceMetric::Cosine));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("blocked_soa_with_build", dim),
+            &dim,
+            |b, _| {
+                b.iter(|| {
+                    BlockedSoaVectors::from_flat(black_box(&flat), dim)
+                        .scores(&query, DistanceMetric::Cosine)
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_batch_high_dim(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_high_dim");
+
+    for dim in [1024usize, 2048, 4096, 8192] {
+        let flat = generate_flat(BATCH_CANDIDATES, dim);
+        let candidates: Vec<&[f32]> = flat.chunks_exact(dim).collect();
+        let query: Vec<f32> = flat[..dim].iter().map(|v| v * 0.5).collect();
+        group.throughput(Throughput::Elements(BATCH_CANDIDATES as u64));
+
+        group.bench_with_input(BenchmarkId::new("per_pair", dim), &dim, |b, _| {
+            b.iter(|| {
+                candidates
+                    .iter()
+                    .map(|c| cosine_similarity_native(c, black_box(&query)))
+                    .collect::<Vec<f32>>()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("batch", dim), &dim, |b, _| {
+            b.iter(|| batch_cosine_native(&candidates, black_box(&query)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_exact_scan, bench_batch_high_dim);
+criterion_main!(benches);
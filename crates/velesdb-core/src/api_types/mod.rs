@@ -59,6 +59,12 @@ pub fn default_fusion_strategy() -> String {
     "rrf".to_string()
 }
 
+/// Default recommend strategy: average vector.
+#[must_use]
+pub fn default_recommend_strategy() -> String {
+    "average_vector".to_string()
+}
+
 /// Default RRF k parameter.
 #[must_use]
 pub const fn default_rrf_k() -> u32 {
@@ -95,6 +101,18 @@ pub const fn default_sparse_weight() -> f32 {
     0.5
 }
 
+/// Default for response projection flags: include the field.
+#[must_use]
+pub const fn default_true() -> bool {
+    true
+}
+
+/// Default per-candidate text length for `rerank_preview`.
+#[must_use]
+pub const fn default_rerank_max_chars() -> usize {
+    512
+}
+
 /// Default index type: hash.
 #[must_use]
 pub fn default_index_type() -> String {
@@ -106,7 +124,10 @@ pub fn default_index_type() -> String {
 /// Supports all named modes including `"autotune"` which adapts ef
 /// automatically based on collection statistics, plus advanced modes:
 /// - `"custom:<ef>"` for a custom `ef_search` value
-/// - `"adaptive:<min_ef>:<max_ef>"` for two-phase adaptive search
+/// - `"adaptive:<min_ef>:<max_ef>[:<patience>]"` for early-terminating
+///   adaptive search
+/// - `"multi_entry:<ef>:<probes>"` for a custom `ef_search` with an explicit
+///   number of HNSW entry points
 #[cfg(feature = "persistence")]
 #[must_use]
 pub fn mode_to_search_quality(mode: &str) -> Option<crate::SearchQuality> {
@@ -120,7 +141,8 @@ pub fn mode_to_search_quality(mode: &str) -> Option<crate::SearchQuality> {
     }
 }
 
-/// Parses advanced search quality modes: `custom:<ef>` and `adaptive:<min_ef>:<max_ef>`.
+/// Parses advanced search quality modes: `custom:<ef>`,
+/// `adaptive:<min_ef>:<max_ef>[:<patience>]` and `multi_entry:<ef>:<probes>`.
 #[cfg(feature = "persistence")]
 fn parse_advanced_quality(mode: &str) -> Option<crate::SearchQuality> {
     if let Some(ef_str) = mode.strip_prefix("custom:") {
@@ -129,13 +151,29 @@ fn parse_advanced_quality(mode: &str) -> Option<crate::SearchQuality> {
     }
     if let Some(params) = mode.strip_prefix("adaptive:") {
         let parts: Vec<&str> = params.split(':').collect();
-        if parts.len() == 2 {
+        if parts.len() == 2 || parts.len() == 3 {
             let min_ef = parts[0].parse::<usize>().ok()?;
             let max_ef = parts[1].parse::<usize>().ok()?;
+            let patience = match parts.get(2) {
+                Some(p) => p.parse::<usize>().ok()?,
+                None => crate::SearchQuality::DEFAULT_ADAPTIVE_PATIENCE,
+            };
             if min_ef <= max_ef {
-                return Some(crate::SearchQuality::Adaptive { min_ef, max_ef });
+                return Some(crate::SearchQuality::Adaptive {
+                    min_ef,
+                    max_ef,
+                    patience,
+                });
             }
         }
     }
+    if let Some(params) = mode.strip_prefix("multi_entry:") {
+        let (ef, probes) = params.split_once(':')?;
+        let ef_search = ef.parse::<usize>().ok()?;
+        let probes = probes.parse::<usize>().ok()?;
+        if probes >= 1 {
+            return Some(crate::SearchQuality::MultiEntry { ef_search, probes });
+        }
+    }
     None
 }
@@ -9,8 +9,10 @@ use utoipa::ToSchema;
 
 use super::{
     default_avg_weight, default_collection_type, default_dense_weight, default_fusion_strategy,
-    default_hit_weight, default_index_type, default_max_weight, default_metric, default_rrf_k,
-    default_sparse_weight, default_storage_mode, default_top_k, default_vector_weight, serde_id,
+    default_hit_weight, default_index_type, default_max_weight, default_metric,
+    default_recommend_strategy, default_rerank_max_chars, default_rrf_k, default_sparse_weight,
+    default_storage_mode, default_top_k, default_true, default_vector_weight, serde_id,
+    RerankCandidate, ResponseVector,
 };
 
 /// `OpenAPI` schema for the free-form metadata `filter` fields: a generic JSON
@@ -32,6 +34,34 @@ fn metadata_filter_schema() -> utoipa::openapi::schema::Object {
         .build()
 }
 
+/// `OpenAPI` schema for the `VelesQL` `params` maps: each bound value is a
+/// vector, a string, a number, a boolean or a JSON object, so generated
+/// clients get a typed union instead of an opaque object.
+#[cfg(feature = "openapi")]
+#[allow(deprecated)] // singular `example`, see `metadata_filter_schema`.
+fn query_params_schema() -> utoipa::openapi::schema::Object {
+    use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, OneOfBuilder, Type};
+    let value = OneOfBuilder::new()
+        .description(Some(
+            "Value bound to a `$name` placeholder. A vector may also be sent as \
+             `{\"$bytes\": \"<base64 f32>\"}`.",
+        ))
+        .item(ArrayBuilder::new().items(ObjectBuilder::new().schema_type(Type::Number)))
+        .item(ObjectBuilder::new().schema_type(Type::String))
+        .item(ObjectBuilder::new().schema_type(Type::Number))
+        .item(ObjectBuilder::new().schema_type(Type::Boolean))
+        .item(ObjectBuilder::new().schema_type(Type::Object));
+    ObjectBuilder::new()
+        .schema_type(Type::Object)
+        .description(Some("Named parameters for the query."))
+        .additional_properties(Some(utoipa::openapi::RefOr::T(value.into())))
+        .property_names(Some(ObjectBuilder::new().schema_type(Type::String)))
+        .example(Some(
+            serde_json::json!({"v": [0.1, 0.2, 0.3], "cat": "tech"}),
+        ))
+        .build()
+}
+
 // ============================================================================
 // Collection Types
 // ============================================================================
@@ -75,6 +105,19 @@ pub struct CreateCollectionRequest {
     #[serde(default)]
     #[cfg_attr(feature = "openapi", schema(example = 1.2, nullable))]
     pub hnsw_alpha: Option<f32>,
+    /// HNSW neighbor-selection heuristic: `simple`, `heuristic` or
+    /// `heuristic_keep_pruned` (default).
+    ///
+    /// `simple` keeps the closest candidates (fastest build, lowest recall
+    /// on clustered data); `heuristic` applies the `hnsw_alpha` diversity
+    /// test only; `heuristic_keep_pruned` refills the free slots with the
+    /// pruned candidates.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "openapi",
+        schema(example = "heuristic_keep_pruned", nullable)
+    )]
+    pub hnsw_neighbor_selection: Option<String>,
     /// Initial HNSW capacity (grows automatically if exceeded).
     ///
     /// Pre-sizing matters for bulk imports: the engine avoids repeated
@@ -116,6 +159,267 @@ pub struct CreateCollectionRequest {
     /// used when the field is absent.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub graph_schema: Option<serde_json::Value>,
+    /// Embedding model that produced the vectors, recorded in `config.json`.
+    ///
+    /// Once recorded, write and search requests that declare a different
+    /// model through the `X-Embedding-Model` header are rejected
+    /// (`VELES-017`) instead of silently mixing vector spaces.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "openapi",
+        schema(example = "text-embedding-3-small", nullable)
+    )]
+    pub embedding_model: Option<String>,
+    /// Optional version pin for `embedding_model`.
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(example = "2024-01", nullable))]
+    pub embedding_model_version: Option<String>,
+}
+
+/// Request to record (or clear) the embedding model of a vector collection.
+///
+/// The model's output dimension is taken from the collection. Sending
+/// `"model": null` clears the recorded model and disables the check.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SetEmbeddingInfoRequest {
+    /// Embedding model name, or `null` to clear.
+    #[cfg_attr(
+        feature = "openapi",
+        schema(example = "text-embedding-3-small", nullable)
+    )]
+    pub model: Option<String>,
+    /// Optional model version pin.
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(example = "2024-01", nullable))]
+    pub version: Option<String>,
+}
+
+/// Request to create a filtered view over a collection.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CreateViewRequest {
+    /// View name; shares the namespace of collection names.
+    #[cfg_attr(feature = "openapi", schema(example = "published_articles"))]
+    pub name: String,
+    /// Collection (or view) the view reads from.
+    #[cfg_attr(feature = "openapi", schema(example = "articles"))]
+    pub base: String,
+    /// `VelesQL` predicate rows must match (a WHERE clause without `WHERE`).
+    #[cfg_attr(feature = "openapi", schema(example = "status = 'published'"))]
+    pub filter: String,
+}
+
+/// Request to set a collection's row-security policy.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SetRowSecurityRequest {
+    /// `VelesQL` predicate over the payload; `$ctx.<attribute>` parameters
+    /// are bound from the API key's request context.
+    #[cfg_attr(feature = "openapi", schema(example = "tenant_id = $ctx.tenant"))]
+    pub filter: String,
+}
+
+/// Request to set a collection's latency SLO.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SetLatencySloRequest {
+    /// Percentile the objective applies to, in `(0, 100]`.
+    #[cfg_attr(feature = "openapi", schema(example = 99.0))]
+    pub percentile: f64,
+    /// Latency the percentile must stay under, in milliseconds.
+    #[cfg_attr(feature = "openapi", schema(example = 20.0))]
+    pub threshold_ms: f64,
+    /// Sliding window length in seconds (default 60).
+    #[serde(default)]
+    pub window_secs: Option<u64>,
+    /// Seconds the objective must be missed before alerting (default 60).
+    #[serde(default)]
+    pub sustain_secs: Option<u64>,
+    /// Fewer samples than this never count as a violation (default 20).
+    #[serde(default)]
+    pub min_samples: Option<usize>,
+}
+
+// ============================================================================
+// Dense Vector Input
+// ============================================================================
+
+/// Object key marking a base64-encoded `f32` vector: `{"$bytes": "..."}`.
+pub const BYTES_KEY: &str = "$bytes";
+
+/// Input format for dense vectors, supporting two JSON representations:
+///
+/// - **Float array**: `[0.1, 0.2, 0.3]`
+/// - **Base64 bytes**: `{"$bytes": "zczMPc3MTD6amZk+"}`, the standard base64
+///   of the little-endian `f32` values (see [`crate::wire::f32_base64`]),
+///   about a tenth of the size of JSON numbers for large embeddings.
+///
+/// Deserialized by hand rather than as an untagged enum so float arrays are
+/// read straight into the `Vec` without buffering.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum VectorInput {
+    /// Plain JSON float array.
+    Floats(Vec<f32>),
+    /// Base64 text of a `{"$bytes": ...}` object, not yet decoded.
+    Bytes(String),
+}
+
+impl VectorInput {
+    /// Decodes this input into a vector, checking a decoded `$bytes` vector
+    /// against `dimension`.
+    ///
+    /// Float arrays are returned as-is; their dimension is validated by the
+    /// collection on upsert.
+    ///
+    /// # Errors
+    ///
+    /// Returns a descriptive error string if the base64 is invalid, is not a
+    /// whole number of `f32` values, or decodes to the wrong dimension.
+    pub fn into_vector(self, dimension: usize) -> Result<Vec<f32>, String> {
+        match self {
+            Self::Floats(vector) => Ok(vector),
+            Self::Bytes(text) => {
+                let vector = crate::wire::f32_base64::decode(&text)
+                    .map_err(|e| format!("Invalid {BYTES_KEY} vector: {e}"))?;
+                if vector.len() != dimension {
+                    return Err(format!(
+                        "{BYTES_KEY} vector decodes to {} floats, expected dimension {dimension} \
+                         (vectors must be little-endian f32)",
+                        vector.len()
+                    ));
+                }
+                Ok(vector)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VectorInput {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(VectorInputVisitor)
+    }
+}
+
+struct VectorInputVisitor;
+
+impl<'de> serde::de::Visitor<'de> for VectorInputVisitor {
+    type Value = VectorInput;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "an array of floats or {{\"{BYTES_KEY}\": \"<base64>\"}}")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<VectorInput, A::Error> {
+        // Cap the preallocation: the hint comes from the client.
+        let mut vector = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(value) = seq.next_element::<f32>()? {
+            vector.push(value);
+        }
+        Ok(VectorInput::Floats(vector))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<VectorInput, A::Error> {
+        read_bytes_object(map).map(VectorInput::Bytes)
+    }
+}
+
+/// Reads the base64 text of a `{"$bytes": "..."}` object.
+fn read_bytes_object<'de, A: serde::de::MapAccess<'de>>(mut map: A) -> Result<String, A::Error> {
+    let mut bytes = None;
+    while let Some(key) = map.next_key::<String>()? {
+        if key != BYTES_KEY || bytes.is_some() {
+            return Err(serde::de::Error::unknown_field(&key, &[BYTES_KEY]));
+        }
+        bytes = Some(map.next_value::<String>()?);
+    }
+    bytes.ok_or_else(|| serde::de::Error::missing_field(BYTES_KEY))
+}
+
+/// `OpenAPI` schema for [`VectorInput`] fields.
+#[cfg(feature = "openapi")]
+#[allow(deprecated)] // singular `example`, see `metadata_filter_schema`.
+fn vector_input_schema() -> utoipa::openapi::schema::Schema {
+    use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, OneOfBuilder, Schema, Type};
+    let one_of = OneOfBuilder::new()
+        .description(Some(
+            "Vector as a float array, or as `{\"$bytes\": \"<base64>\"}` holding the \
+             base64 of the little-endian f32 values.",
+        ))
+        .item(ArrayBuilder::new().items(ObjectBuilder::new().schema_type(Type::Number)))
+        .item(
+            ObjectBuilder::new()
+                .schema_type(Type::Object)
+                .property(BYTES_KEY, ObjectBuilder::new().schema_type(Type::String))
+                .required(BYTES_KEY),
+        )
+        .example(Some(serde_json::json!([0.1, 0.2, 0.3])))
+        .build();
+    Schema::OneOf(one_of)
+}
+
+/// Precision of the vectors returned with search hits (`vector_precision`).
+///
+/// Only applies when the request sets `with_vector`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseVectorPrecision {
+    /// Full `f32` float arrays.
+    #[default]
+    F32,
+    /// Components rounded to f16 and sent as `{"$f16": "<base64>"}`, a
+    /// quarter of the size of JSON numbers (see [`crate::wire::f16_base64`]).
+    F16,
+    /// Vectors are dropped, as if `with_vector` were false.
+    None,
+}
+
+impl ResponseVectorPrecision {
+    /// Converts a hit's vector for the response; `None` drops it.
+    #[must_use]
+    pub fn render(self, vector: Vec<f32>) -> Option<ResponseVector> {
+        match self {
+            Self::F32 => Some(ResponseVector::F32(vector)),
+            Self::F16 => Some(ResponseVector::F16(crate::wire::f16_base64::encode(
+                &vector,
+            ))),
+            Self::None => None,
+        }
+    }
+}
+
+/// Replaces every `{"$bytes": "<base64>"}` value in `VelesQL` params with
+/// the float array it encodes, so `$v` can be bound without spelling out
+/// each component.
+///
+/// Only top-level values are decoded; the vector's dimension is checked
+/// where the parameter is used (`NEAR $v` against the collection).
+///
+/// # Errors
+///
+/// Returns a message naming the parameter if its base64 is invalid or not
+/// a whole number of `f32` values.
+pub fn decode_bytes_params(
+    params: &mut std::collections::HashMap<String, serde_json::Value>,
+) -> Result<(), String> {
+    for (name, value) in params.iter_mut() {
+        let Some(text) = value
+            .as_object()
+            .filter(|object| object.len() == 1)
+            .and_then(|object| object.get(BYTES_KEY))
+        else {
+            continue;
+        };
+        let text = text
+            .as_str()
+            .ok_or_else(|| format!("Parameter ${name}: {BYTES_KEY} must be a base64 string"))?;
+        let vector =
+            crate::wire::f32_base64::decode(text).map_err(|e| format!("Parameter ${name}: {e}"))?;
+        *value = serde_json::Value::from(vector);
+    }
+    Ok(())
 }
 
 // ============================================================================
@@ -253,11 +557,175 @@ pub struct FusionRequest {
 // ============================================================================
 
 /// Request to upsert points.
+///
+/// Points come either as `points`, one object per point, or in the columnar
+/// layout: parallel `ids`, `vectors` and optional `payloads` arrays. Sent
+/// with base64 `vectors`, the columnar form skips float parsing entirely and
+/// deserializes about 6x faster than float-array points
+/// (`benches/upsert_body_benchmark.rs`).
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct UpsertPointsRequest {
-    /// Points to upsert.
+    /// Points to upsert (row layout).
+    #[serde(default)]
     pub points: Vec<PointRequest>,
+    /// Point IDs (columnar layout).
+    #[serde(
+        default,
+        deserialize_with = "serde_id::deserialize_ids_from_string_or_number"
+    )]
+    #[cfg_attr(feature = "openapi", schema(schema_with = serde_id::ids_array_schema))]
+    pub ids: Vec<u64>,
+    /// Vectors for `ids`, in the same order (columnar layout).
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(schema_with = columnar_vectors_schema))]
+    pub vectors: Option<ColumnarVectors>,
+    /// Payloads for `ids`, in the same order; `null` entries store no
+    /// payload (columnar layout).
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<Vec<Object>>))]
+    pub payloads: Option<Vec<Option<serde_json::Value>>>,
+    /// Respond only once the points are visible to search. Only matters for
+    /// collections with deferred indexing or an async index builder, which
+    /// otherwise index in the background.
+    #[serde(default)]
+    pub wait_for_index: bool,
+}
+
+impl UpsertPointsRequest {
+    /// Whether the request uses the columnar layout (`ids`/`vectors`/`payloads`).
+    #[must_use]
+    pub fn is_columnar(&self) -> bool {
+        !self.ids.is_empty() || self.vectors.is_some() || self.payloads.is_some()
+    }
+
+    /// Number of points in the request, in either layout.
+    #[must_use]
+    pub fn point_count(&self) -> usize {
+        self.points.len() + self.ids.len()
+    }
+}
+
+/// The `vectors` column of a columnar upsert.
+///
+/// - **Float arrays**: `[[0.1, 0.2], [0.3, 0.4]]`, one per ID.
+/// - **Base64 bytes**: `"<base64>"` or `{"$bytes": "<base64>"}`, the
+///   little-endian `f32` values of all vectors back to back (see
+///   [`crate::wire::f32_base64`]).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ColumnarVectors {
+    /// One float array per point.
+    Rows(Vec<Vec<f32>>),
+    /// Base64 text of the concatenated vectors, not yet decoded.
+    Bytes(String),
+}
+
+impl ColumnarVectors {
+    /// Splits the column into `count` vectors, decoding base64 input as
+    /// vectors of `dimension` floats.
+    ///
+    /// Float arrays are returned as-is; their dimension is validated by the
+    /// collection on upsert.
+    ///
+    /// # Errors
+    ///
+    /// Returns a descriptive error string if the column does not hold
+    /// exactly `count` vectors or its base64 is invalid.
+    pub fn into_rows(self, count: usize, dimension: usize) -> Result<Vec<Vec<f32>>, String> {
+        match self {
+            Self::Rows(rows) => {
+                if rows.len() != count {
+                    return Err(format!(
+                        "vectors has {} entries, expected one per id ({count})",
+                        rows.len()
+                    ));
+                }
+                Ok(rows)
+            }
+            Self::Bytes(text) => {
+                let flat = crate::wire::f32_base64::decode(&text)
+                    .map_err(|e| format!("Invalid base64 vectors: {e}"))?;
+                if dimension == 0 || flat.len() != count * dimension {
+                    return Err(format!(
+                        "base64 vectors decode to {} floats, expected {count} ids x dimension \
+                         {dimension} (vectors must be little-endian f32)",
+                        flat.len()
+                    ));
+                }
+                Ok(flat.chunks_exact(dimension).map(<[f32]>::to_vec).collect())
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ColumnarVectors {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ColumnarVectorsVisitor)
+    }
+}
+
+struct ColumnarVectorsVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ColumnarVectorsVisitor {
+    type Value = ColumnarVectors;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "an array of float arrays, a base64 string or {{\"{BYTES_KEY}\": \"<base64>\"}}"
+        )
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> Result<ColumnarVectors, A::Error> {
+        // Cap the preallocation: the hint comes from the client.
+        let mut rows = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(row) = seq.next_element::<Vec<f32>>()? {
+            rows.push(row);
+        }
+        Ok(ColumnarVectors::Rows(rows))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<ColumnarVectors, E> {
+        Ok(ColumnarVectors::Bytes(text.to_owned()))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, text: String) -> Result<ColumnarVectors, E> {
+        Ok(ColumnarVectors::Bytes(text))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<ColumnarVectors, A::Error> {
+        read_bytes_object(map).map(ColumnarVectors::Bytes)
+    }
+}
+
+/// `OpenAPI` schema for [`ColumnarVectors`] fields.
+#[cfg(feature = "openapi")]
+#[allow(deprecated)] // singular `example`, see `metadata_filter_schema`.
+fn columnar_vectors_schema() -> utoipa::openapi::schema::Schema {
+    use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, OneOfBuilder, Schema, Type};
+    let one_of = OneOfBuilder::new()
+        .description(Some(
+            "Vectors for `ids`: one float array per id, or the base64 of all vectors' \
+             little-endian f32 values back to back, as a string or `{\"$bytes\": \"<base64>\"}`.",
+        ))
+        .item(
+            ArrayBuilder::new()
+                .items(ArrayBuilder::new().items(ObjectBuilder::new().schema_type(Type::Number))),
+        )
+        .item(ObjectBuilder::new().schema_type(Type::String))
+        .item(
+            ObjectBuilder::new()
+                .schema_type(Type::Object)
+                .property(BYTES_KEY, ObjectBuilder::new().schema_type(Type::String))
+                .required(BYTES_KEY),
+        )
+        .example(Some(serde_json::json!([[0.1, 0.2], [0.3, 0.4]])))
+        .build();
+    Schema::OneOf(one_of)
 }
 
 /// A point in an upsert request.
@@ -268,8 +736,9 @@ pub struct PointRequest {
     #[serde(deserialize_with = "serde_id::deserialize_id_from_string_or_number")]
     #[cfg_attr(feature = "openapi", schema(schema_with = serde_id::id_input_schema))]
     pub id: u64,
-    /// Vector data.
-    pub vector: Vec<f32>,
+    /// Vector data: a float array or `{"$bytes": "<base64 f32>"}`.
+    #[cfg_attr(feature = "openapi", schema(schema_with = vector_input_schema))]
+    pub vector: VectorInput,
     /// Optional payload.
     pub payload: Option<serde_json::Value>,
     /// Single sparse vector (convenience, stored under default name `""`).
@@ -280,6 +749,31 @@ pub struct PointRequest {
     pub sparse_vectors: Option<BTreeMap<String, SparseVectorInput>>,
 }
 
+/// Request to fetch several points by ID in one call.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GetPointsRequest {
+    /// Point IDs to fetch. Unknown IDs are skipped.
+    #[serde(deserialize_with = "serde_id::deserialize_ids_from_string_or_number")]
+    #[cfg_attr(feature = "openapi", schema(schema_with = serde_id::ids_array_schema))]
+    pub ids: Vec<u64>,
+    /// Include each point's vector (default: true).
+    #[serde(default = "default_true")]
+    pub with_vector: bool,
+    /// Include each point's payload (default: true).
+    #[serde(default = "default_true")]
+    pub with_payload: bool,
+}
+
+/// Request to count the points matching an optional filter.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CountPointsRequest {
+    /// Optional filter expression (same shape as scroll). Omit to count all points.
+    #[serde(default)]
+    pub filter: Option<serde_json::Value>,
+}
+
 /// Request body for the streaming insert endpoint (single point).
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -370,6 +864,61 @@ pub struct SearchRequest {
     /// Fusion configuration for hybrid search.
     #[serde(default)]
     pub fusion: Option<FusionRequest>,
+    /// Include each hit's vector in the response (default: false).
+    #[serde(default)]
+    pub with_vector: bool,
+    /// Precision of returned vectors: `f32` (default), `f16` or `none`.
+    #[serde(default)]
+    pub vector_precision: ResponseVectorPrecision,
+    /// Include each hit's payload in the response (default: true).
+    #[serde(default = "default_true")]
+    pub with_payload: bool,
+    /// Attach a per-hit `score_breakdown` to the response (default: false).
+    #[serde(default)]
+    pub explain_scores: bool,
+    /// Add a `candidates` array of `{id, text}` pairs built from payload
+    /// fields, ready to send to a cross-encoder reranker.
+    #[serde(default)]
+    pub rerank_preview: Option<RerankPreviewRequest>,
+}
+
+/// Payload fields a search's `rerank_preview` turns into candidate texts.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RerankPreviewRequest {
+    /// Payload fields (dot paths such as `meta.title`) whose values are
+    /// joined, one per line and in this order, into each candidate's text.
+    #[cfg_attr(feature = "openapi", schema(example = json!(["title", "body"])))]
+    pub fields: Vec<String>,
+    /// Maximum characters kept per candidate text (default: 512).
+    #[serde(default = "default_rerank_max_chars")]
+    pub max_chars: usize,
+}
+
+impl RerankPreviewRequest {
+    /// Builds the candidate for point `id` from its payload.
+    ///
+    /// String fields are used verbatim, other values as their JSON text;
+    /// missing and `null` fields are skipped. The text is cut at
+    /// `max_chars` characters.
+    #[must_use]
+    pub fn candidate(&self, id: u64, payload: Option<&serde_json::Value>) -> RerankCandidate {
+        let parts: Vec<String> = self
+            .fields
+            .iter()
+            .filter_map(|field| crate::filter::lookup_path(payload?, field))
+            .filter_map(|value| match value {
+                serde_json::Value::Null => None,
+                serde_json::Value::String(s) => Some(s.clone()),
+                other => Some(other.to_string()),
+            })
+            .collect();
+        let mut text = parts.join("\n");
+        if let Some((end, _)) = text.char_indices().nth(self.max_chars) {
+            text.truncate(end);
+        }
+        RerankCandidate { id, text }
+    }
 }
 
 /// Request for batch vector search.
@@ -395,6 +944,18 @@ pub struct TextSearchRequest {
     #[serde(default)]
     #[cfg_attr(feature = "openapi", schema(schema_with = metadata_filter_schema))]
     pub filter: Option<serde_json::Value>,
+    /// Include each hit's vector in the response (default: false).
+    #[serde(default)]
+    pub with_vector: bool,
+    /// Precision of returned vectors: `f32` (default), `f16` or `none`.
+    #[serde(default)]
+    pub vector_precision: ResponseVectorPrecision,
+    /// Include each hit's payload in the response (default: true).
+    #[serde(default = "default_true")]
+    pub with_payload: bool,
+    /// Attach a per-hit `score_breakdown` to the response (default: false).
+    #[serde(default)]
+    pub explain_scores: bool,
 }
 
 /// Request for hybrid search (vector + text).
@@ -418,6 +979,18 @@ pub struct HybridSearchRequest {
     #[serde(default)]
     #[cfg_attr(feature = "openapi", schema(schema_with = metadata_filter_schema))]
     pub filter: Option<serde_json::Value>,
+    /// Include each hit's vector in the response (default: false).
+    #[serde(default)]
+    pub with_vector: bool,
+    /// Precision of returned vectors: `f32` (default), `f16` or `none`.
+    #[serde(default)]
+    pub vector_precision: ResponseVectorPrecision,
+    /// Include each hit's payload in the response (default: true).
+    #[serde(default = "default_true")]
+    pub with_payload: bool,
+    /// Attach a per-hit `score_breakdown` to the response (default: false).
+    #[serde(default)]
+    pub explain_scores: bool,
 }
 
 /// Request for multi-query vector search with fusion.
@@ -462,6 +1035,54 @@ pub struct MultiQuerySearchRequest {
     #[serde(default)]
     #[cfg_attr(feature = "openapi", schema(schema_with = metadata_filter_schema))]
     pub filter: Option<serde_json::Value>,
+    /// Include each hit's vector in the response (default: false).
+    #[serde(default)]
+    pub with_vector: bool,
+    /// Precision of returned vectors: `f32` (default), `f16` or `none`.
+    #[serde(default)]
+    pub vector_precision: ResponseVectorPrecision,
+    /// Include each hit's payload in the response (default: true).
+    #[serde(default = "default_true")]
+    pub with_payload: bool,
+}
+
+/// Request for recommendations from example points.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RecommendRequest {
+    /// IDs of points the results should resemble (at least one).
+    #[serde(deserialize_with = "serde_id::deserialize_ids_from_string_or_number")]
+    #[cfg_attr(feature = "openapi", schema(schema_with = serde_id::ids_array_schema))]
+    pub positive: Vec<u64>,
+    /// IDs of points the results should steer away from.
+    #[serde(
+        default,
+        deserialize_with = "serde_id::deserialize_ids_from_string_or_number"
+    )]
+    #[cfg_attr(feature = "openapi", schema(schema_with = serde_id::ids_array_schema))]
+    pub negative: Vec<u64>,
+    /// Number of results to return. The example points are never returned.
+    #[serde(default = "default_top_k")]
+    #[cfg_attr(feature = "openapi", schema(example = 10))]
+    pub top_k: usize,
+    /// Strategy: "`average_vector`" (one search from the averaged examples)
+    /// or "`best_score`" (rank by the best match against each example).
+    #[serde(default = "default_recommend_strategy")]
+    #[cfg_attr(feature = "openapi", schema(example = "average_vector"))]
+    pub strategy: String,
+    /// Optional metadata filter.
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(schema_with = metadata_filter_schema))]
+    pub filter: Option<serde_json::Value>,
+    /// Include each hit's vector in the response (default: false).
+    #[serde(default)]
+    pub with_vector: bool,
+    /// Precision of returned vectors: `f32` (default), `f16` or `none`.
+    #[serde(default)]
+    pub vector_precision: ResponseVectorPrecision,
+    /// Include each hit's payload in the response (default: true).
+    #[serde(default = "default_true")]
+    pub with_payload: bool,
 }
 
 // ============================================================================
@@ -473,13 +1094,24 @@ pub struct MultiQuerySearchRequest {
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct QueryRequest {
     /// The `VelesQL` query string.
+    #[cfg_attr(
+        feature = "openapi",
+        schema(
+            example = "SELECT id, title FROM docs WHERE vector NEAR $v AND category = $cat LIMIT 10"
+        )
+    )]
     pub query: String,
     /// Named parameters for the query.
     #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(schema_with = query_params_schema))]
     pub params: std::collections::HashMap<String, serde_json::Value>,
     /// Optional collection name (required for top-level MATCH queries via `/query`).
     #[serde(default)]
     pub collection: Option<String>,
+    /// Report the points an INSERT, UPSERT, UPDATE or DELETE would change
+    /// instead of executing it.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 /// Request for query EXPLAIN.
@@ -494,6 +1126,7 @@ pub struct ExplainRequest {
     pub query: String,
     /// Named parameters for the query.
     #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(schema_with = query_params_schema))]
     pub params: std::collections::HashMap<String, serde_json::Value>,
     /// When true, execute the query and return actual statistics alongside the plan.
     #[serde(default)]
@@ -28,6 +28,10 @@ pub struct CollectionResponse {
     pub point_count: usize,
     /// Storage mode (full, sq8, binary).
     pub storage_mode: String,
+    /// Search-option defaults (`ef_search`, `rerank`, `fusion`) applied to
+    /// queries that omit them — absent when none are set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_defaults: Option<serde_json::Value>,
 }
 
 /// Response with detailed collection configuration.
@@ -74,12 +78,137 @@ pub struct CollectionConfigResponse {
     /// the feature is disabled for this collection.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub async_index_builder: Option<serde_json::Value>,
+    /// Recorded embedding model (`{model, version?, dimension}`) — `None`
+    /// when no model has been recorded for this collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_info: Option<serde_json::Value>,
+    /// Per-collection override of `limits.max_query_limit` — `None` when the
+    /// database-wide limit applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_query_limit: Option<usize>,
+}
+
+/// A filtered view over a collection.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ViewResponse {
+    /// View name.
+    #[cfg_attr(feature = "openapi", schema(example = "published_articles"))]
+    pub name: String,
+    /// Collection the view reads from (views over views are flattened).
+    #[cfg_attr(feature = "openapi", schema(example = "articles"))]
+    pub base: String,
+    /// `VelesQL` predicate applied to every read.
+    #[cfg_attr(feature = "openapi", schema(example = "status = 'published'"))]
+    pub filter: String,
+}
+
+/// A collection's row-security policy.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RowSecurityResponse {
+    /// Protected collection.
+    #[cfg_attr(feature = "openapi", schema(example = "documents"))]
+    pub collection: String,
+    /// `VelesQL` predicate every read through an API key must match.
+    #[cfg_attr(feature = "openapi", schema(example = "tenant_id = $ctx.tenant"))]
+    pub filter: String,
+}
+
+/// A collection's latency SLO and where it stands over its window.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SloStatusResponse {
+    /// Collection the objective applies to.
+    #[cfg_attr(feature = "openapi", schema(example = "documents"))]
+    pub collection: String,
+    /// Percentile the objective applies to.
+    #[cfg_attr(feature = "openapi", schema(example = 99.0))]
+    pub percentile: f64,
+    /// Latency the percentile must stay under, in milliseconds.
+    #[cfg_attr(feature = "openapi", schema(example = 20.0))]
+    pub threshold_ms: f64,
+    /// Sliding window length in seconds.
+    #[cfg_attr(feature = "openapi", schema(example = 60))]
+    pub window_secs: u64,
+    /// Seconds the objective must be missed before alerting.
+    #[cfg_attr(feature = "openapi", schema(example = 60))]
+    pub sustain_secs: u64,
+    /// Minimum samples for a violation.
+    #[cfg_attr(feature = "openapi", schema(example = 20))]
+    pub min_samples: usize,
+    /// `ok`, `violating` (missed, not yet for `sustain_secs`) or `breached`.
+    #[cfg_attr(feature = "openapi", schema(example = "ok"))]
+    pub state: String,
+    /// Observed latency at the percentile, in milliseconds; null without samples.
+    #[cfg_attr(feature = "openapi", schema(example = 12.4))]
+    pub observed_ms: Option<f64>,
+    /// Searches in the window.
+    #[cfg_attr(feature = "openapi", schema(example = 1500))]
+    pub samples: usize,
+    /// Seconds the objective has been missed without interruption.
+    pub violating_for_secs: Option<u64>,
 }
 
 // ============================================================================
 // Search Responses
 // ============================================================================
 
+/// Object key marking a base64-encoded f16 vector: `{"$f16": "..."}`.
+pub const F16_KEY: &str = "$f16";
+
+/// Vector of a search hit, in the precision the request asked for.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ResponseVector {
+    /// Plain float array.
+    F32(Vec<f32>),
+    /// Base64 of the little-endian f16 components, serialized as
+    /// `{"$f16": "..."}` (see [`crate::wire::f16_base64`]).
+    F16(String),
+}
+
+impl From<Vec<f32>> for ResponseVector {
+    fn from(vector: Vec<f32>) -> Self {
+        Self::F32(vector)
+    }
+}
+
+impl Serialize for ResponseVector {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        match self {
+            Self::F32(vector) => vector.serialize(serializer),
+            Self::F16(text) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(F16_KEY, text)?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// `OpenAPI` schema for [`ResponseVector`] fields.
+#[cfg(feature = "openapi")]
+fn response_vector_schema() -> utoipa::openapi::schema::Schema {
+    use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, OneOfBuilder, Schema, Type};
+    let one_of = OneOfBuilder::new()
+        .description(Some(
+            "Point vector; omitted unless the request set `with_vector`. A float array, \
+             or `{\"$f16\": \"<base64>\"}` holding the little-endian f16 values when the \
+             request set `vector_precision: \"f16\"`.",
+        ))
+        .item(ArrayBuilder::new().items(ObjectBuilder::new().schema_type(Type::Number)))
+        .item(
+            ObjectBuilder::new()
+                .schema_type(Type::Object)
+                .property(F16_KEY, ObjectBuilder::new().schema_type(Type::String))
+                .required(F16_KEY),
+        )
+        .build();
+    Schema::OneOf(one_of)
+}
+
 /// A single search result.
 #[derive(Debug, Clone, Serialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -90,8 +219,18 @@ pub struct SearchResultResponse {
     pub id: u64,
     /// Similarity score.
     pub score: f32,
-    /// Point payload.
+    /// Point vector; omitted unless the request set `with_vector`, in the
+    /// request's `vector_precision`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(schema_with = response_vector_schema))]
+    pub vector: Option<ResponseVector>,
+    /// Point payload; `null` when the point has none or the request set
+    /// `with_payload: false`.
     pub payload: Option<serde_json::Value>,
+    /// Score components (raw distance, normalized similarity, BM25 score,
+    /// fusion weights); present only when the request set `explain_scores`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_breakdown: Option<serde_json::Value>,
 }
 
 /// Response from vector search.
@@ -100,6 +239,23 @@ pub struct SearchResultResponse {
 pub struct SearchResponse {
     /// Search results.
     pub results: Vec<SearchResultResponse>,
+    /// One reranker candidate per result, in result order; present only
+    /// when the request set `rerank_preview`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candidates: Option<Vec<RerankCandidate>>,
+}
+
+/// A search hit's text for an external cross-encoder, built by
+/// `rerank_preview`.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RerankCandidate {
+    /// Point ID.
+    #[serde(serialize_with = "serde_id::serialize_id_as_string")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub id: u64,
+    /// Configured payload fields joined by newlines, cut at `max_chars`.
+    pub text: String,
 }
 
 /// Response from batch search.
@@ -144,6 +300,10 @@ pub struct ErrorResponse {
     /// Structured error code (e.g., `"VELES-004"` for dimension mismatch).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
+    /// Whether retrying the same request later may succeed (transient
+    /// errors such as a locked database or shed load). Set alongside `code`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retryable: Option<bool>,
 }
 
 // ============================================================================
@@ -279,6 +439,10 @@ pub struct VelesqlErrorDetail {
     /// Optional additional details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
+    /// Whether retrying the same query later may succeed; set for execution
+    /// errors raised by the engine.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retryable: Option<bool>,
 }
 
 /// Standardized `VelesQL` semantic/runtime error response.
@@ -397,6 +561,41 @@ pub struct ScrollPoint {
     pub payload: Option<serde_json::Value>,
 }
 
+/// Response from the point count endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CountPointsResponse {
+    /// Number of live points matching the filter.
+    pub count: usize,
+}
+
+/// Response from the batch get endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GetPointsResponse {
+    /// Found points, in request order. Unknown IDs are omitted.
+    pub points: Vec<RetrievedPoint>,
+}
+
+/// A single point in a batch get response.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RetrievedPoint {
+    /// Point ID.
+    #[serde(
+        serialize_with = "serde_id::serialize_id_as_string",
+        deserialize_with = "serde_id::deserialize_id_from_string_or_number"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub id: u64,
+    /// Vector data; omitted when `with_vector` is false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector: Option<Vec<f32>>,
+    /// Payload; omitted when `with_payload` is false or the point has none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+}
+
 // ============================================================================
 // Collection Statistics Responses
 // ============================================================================
@@ -431,6 +630,121 @@ pub struct CollectionStatsResponse {
     /// Per-index statistics.
     #[cfg_attr(feature = "openapi", schema(value_type = Object))]
     pub index_stats: std::collections::HashMap<String, IndexStatsResponse>,
+    /// Latency SLO status; omitted when the collection has no SLO.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slo: Option<SloStatusResponse>,
+}
+
+/// Response with vector-space statistics computed on a sample.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct VectorStatsResponse {
+    /// Number of vectors the statistics were computed on.
+    #[cfg_attr(feature = "openapi", schema(example = 1000))]
+    pub sample_size: usize,
+    /// Vector dimension.
+    #[cfg_attr(feature = "openapi", schema(example = 768))]
+    pub dimension: usize,
+    /// Component-wise mean of the sampled vectors.
+    pub centroid: Vec<f32>,
+    /// Mean L2 norm.
+    pub norm_mean: f32,
+    /// Smallest L2 norm.
+    pub norm_min: f32,
+    /// Median L2 norm.
+    pub norm_p50: f32,
+    /// 90th percentile L2 norm.
+    pub norm_p90: f32,
+    /// 99th percentile L2 norm.
+    pub norm_p99: f32,
+    /// Largest L2 norm.
+    pub norm_max: f32,
+    /// Mean cosine similarity over all sampled pairs, or null with fewer than
+    /// two vectors.
+    #[cfg_attr(feature = "openapi", schema(nullable))]
+    pub mean_pairwise_cosine: Option<f32>,
+    /// `TwoNN` intrinsic-dimensionality estimate, or null when the sample is
+    /// too small or degenerate.
+    #[cfg_attr(feature = "openapi", schema(nullable))]
+    pub intrinsic_dimension: Option<f64>,
+}
+
+/// Response with per-point access counts and payload cache counters.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct AccessStatsResponse {
+    /// Reads counted since the counts were created (each point returned by a
+    /// get or a search is one read).
+    #[cfg_attr(feature = "openapi", schema(example = 120_000))]
+    pub total_accesses: u64,
+    /// Most read live points, most read first. Counts are approximate and
+    /// never below the true count.
+    pub top: Vec<PointAccessCountResponse>,
+    /// Payload cache counters.
+    pub payload_cache: PayloadCacheStatsResponse,
+}
+
+/// Approximate read count of one point.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PointAccessCountResponse {
+    /// Point ID.
+    #[cfg_attr(feature = "openapi", schema(example = 42))]
+    pub id: u64,
+    /// Estimated number of reads.
+    #[cfg_attr(feature = "openapi", schema(example = 310))]
+    pub count: u32,
+}
+
+/// Counters of the payload LFU cache (`storage.payload_cache_entries`).
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PayloadCacheStatsResponse {
+    /// Maximum number of cached payloads; 0 when the cache is disabled.
+    pub capacity: usize,
+    /// Payloads currently cached.
+    pub entries: usize,
+    /// Reads served from the cache.
+    pub hits: u64,
+    /// Reads that went to storage.
+    pub misses: u64,
+    /// Payloads evicted to make room.
+    pub evictions: u64,
+}
+
+/// Response from the 2-D projection endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Projection2dResponse {
+    /// Layout algorithm used (`pca` or `umap_approx`).
+    #[cfg_attr(feature = "openapi", schema(example = "pca"))]
+    pub method: String,
+    /// Projected points, in sample order.
+    pub points: Vec<ProjectedPointResponse>,
+    /// Number of distinct cluster labels.
+    pub cluster_count: usize,
+    /// Share of the total variance captured by each axis (PCA only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explained_variance_ratio: Option<[f32; 2]>,
+}
+
+/// A single point of a 2-D projection.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ProjectedPointResponse {
+    /// Point ID.
+    #[serde(
+        serialize_with = "serde_id::serialize_id_as_string",
+        deserialize_with = "serde_id::deserialize_id_from_string_or_number"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub id: u64,
+    /// Horizontal coordinate.
+    pub x: f32,
+    /// Vertical coordinate.
+    pub y: f32,
+    /// k-means cluster label.
+    pub cluster: usize,
 }
 
 /// Per-column statistics in a collection stats response.
@@ -77,6 +77,25 @@ fn deserialize_search_request_defaults() {
     assert!(req.sparse_vectors.is_none());
     assert_eq!(req.sparse_index, None);
     assert!(req.fusion.is_none());
+    assert!(req.rerank_preview.is_none());
+}
+
+#[test]
+fn rerank_preview_joins_fields_and_truncates() {
+    let input =
+        json!({ "rerank_preview": { "fields": ["title", "meta.year", "missing", "body"] } });
+    let req: SearchRequest = serde_json::from_value(input).unwrap();
+    let mut preview = req.rerank_preview.unwrap();
+    assert_eq!(preview.max_chars, 512);
+
+    let payload = json!({ "title": "Café", "meta": { "year": 2024 }, "body": "long text" });
+    let candidate = preview.candidate(7, Some(&payload));
+    assert_eq!(candidate.id, 7);
+    assert_eq!(candidate.text, "Café\n2024\nlong text");
+
+    preview.max_chars = 4;
+    assert_eq!(preview.candidate(7, Some(&payload)).text, "Café");
+    assert_eq!(preview.candidate(8, None).text, "");
 }
 
 #[test]
@@ -179,7 +198,7 @@ fn deserialize_upsert_points_request() {
     let req: UpsertPointsRequest = serde_json::from_value(input).unwrap();
     assert_eq!(req.points.len(), 1);
     assert_eq!(req.points[0].id, 42);
-    assert_eq!(req.points[0].vector, vec![0.1, 0.2]);
+    assert_eq!(req.points[0].vector, VectorInput::Floats(vec![0.1, 0.2]));
     assert!(req.points[0].payload.is_some());
 }
 
@@ -219,6 +238,8 @@ fn round_trip_collection_config_response() {
         hnsw_params: None,
         deferred_indexing: None,
         async_index_builder: None,
+        embedding_info: None,
+        max_query_limit: None,
     };
     let serialized = serde_json::to_value(&resp).unwrap();
     let deserialized: CollectionConfigResponse = serde_json::from_value(serialized).unwrap();
@@ -348,9 +369,13 @@ fn missing_optional_fields_deserialize_to_none() {
 
 #[test]
 fn serialize_search_response_empty_results() {
-    let resp = SearchResponse { results: vec![] };
+    let resp = SearchResponse {
+        results: vec![],
+        candidates: None,
+    };
     let json = serde_json::to_value(&resp).unwrap();
     assert_eq!(json["results"], json!([]));
+    assert!(json.get("candidates").is_none());
 }
 
 #[test]
@@ -511,13 +536,18 @@ fn test_mode_to_search_quality_custom() {
 fn test_mode_to_search_quality_adaptive() {
     use super::mode_to_search_quality;
     let q = mode_to_search_quality("adaptive:32:512");
+    assert_eq!(q, Some(crate::SearchQuality::adaptive(32, 512)));
+    let q = mode_to_search_quality("adaptive:32:512:16");
     assert!(matches!(
         q,
         Some(crate::SearchQuality::Adaptive {
             min_ef: 32,
-            max_ef: 512
+            max_ef: 512,
+            patience: 16
         })
     ));
+    assert!(mode_to_search_quality("adaptive:32:512:x").is_none());
+    assert!(mode_to_search_quality("adaptive:32:512:16:1").is_none());
 }
 
 #[cfg(feature = "persistence")]
@@ -538,6 +568,22 @@ fn test_mode_to_search_quality_invalid_adaptive() {
     assert!(mode_to_search_quality("adaptive:512:32").is_none());
 }
 
+#[cfg(feature = "persistence")]
+#[test]
+fn test_mode_to_search_quality_multi_entry() {
+    use super::mode_to_search_quality;
+    assert_eq!(
+        mode_to_search_quality("multi_entry:256:8"),
+        Some(crate::SearchQuality::MultiEntry {
+            ef_search: 256,
+            probes: 8
+        })
+    );
+    assert!(mode_to_search_quality("multi_entry:256").is_none());
+    assert!(mode_to_search_quality("multi_entry:256:0").is_none());
+    assert!(mode_to_search_quality("multi_entry:256:x").is_none());
+}
+
 #[cfg(feature = "persistence")]
 #[test]
 fn test_mode_to_search_quality_unknown() {
@@ -555,6 +601,7 @@ fn serialize_error_response() {
     let resp = ErrorResponse {
         error: "collection not found".to_string(),
         code: None,
+        retryable: None,
     };
     let json = serde_json::to_value(&resp).unwrap();
     assert_eq!(json["error"], "collection not found");
@@ -567,10 +614,12 @@ fn serialize_error_response_with_code() {
     let resp = ErrorResponse {
         error: "Dimension mismatch".to_string(),
         code: Some("VELES-004".to_string()),
+        retryable: Some(false),
     };
     let json = serde_json::to_value(&resp).unwrap();
     assert_eq!(json["error"], "Dimension mismatch");
     assert_eq!(json["code"], "VELES-004");
+    assert_eq!(json["retryable"], false);
 }
 
 #[test]
@@ -589,6 +638,8 @@ fn serialize_collection_config_skips_none_graph_schema() {
         hnsw_params: None,
         deferred_indexing: None,
         async_index_builder: None,
+        embedding_info: None,
+        max_query_limit: None,
     };
     let json = serde_json::to_value(&resp).unwrap();
     // `skip_serializing_if = "Option::is_none"` omits the key entirely
@@ -686,7 +737,9 @@ fn id_serialization_above_max_safe_integer() {
     let result = SearchResultResponse {
         id: above_safe,
         score: 0.99,
+        vector: None,
         payload: None,
+        score_breakdown: None,
     };
     let json = serde_json::to_value(&result).unwrap();
     assert_eq!(json["id"], json!("9007199254740993"));
@@ -698,7 +751,9 @@ fn id_serialization_small_id() {
     let result = SearchResultResponse {
         id: 42,
         score: 0.5,
+        vector: None,
         payload: None,
+        score_breakdown: None,
     };
     let json = serde_json::to_value(&result).unwrap();
     assert_eq!(json["id"], json!("42"));
@@ -710,7 +765,9 @@ fn id_serialization_zero() {
     let result = SearchResultResponse {
         id: 0,
         score: 0.0,
+        vector: None,
         payload: None,
+        score_breakdown: None,
     };
     let json = serde_json::to_value(&result).unwrap();
     assert_eq!(json["id"], json!("0"));
@@ -722,7 +779,9 @@ fn id_serialization_u64_max() {
     let result = SearchResultResponse {
         id: u64::MAX,
         score: 1.0,
+        vector: None,
         payload: None,
+        score_breakdown: None,
     };
     let json = serde_json::to_value(&result).unwrap();
     assert_eq!(json["id"], json!("18446744073709551615"));
@@ -919,3 +978,199 @@ fn scroll_request_cursor_accepts_null() {
     let req: ScrollRequest = serde_json::from_value(input).unwrap();
     assert_eq!(req.cursor, None);
 }
+
+// ============================================================================
+// H. Batch get request/response
+// ============================================================================
+
+/// `GetPointsRequest` defaults both projection flags to true and accepts
+/// string IDs.
+#[test]
+fn get_points_request_defaults_and_string_ids() {
+    let req: GetPointsRequest =
+        serde_json::from_value(json!({ "ids": ["9007199254740993", 2] })).unwrap();
+    assert_eq!(req.ids, vec![(1_u64 << 53) + 1, 2]);
+    assert!(req.with_vector);
+    assert!(req.with_payload);
+
+    let req: GetPointsRequest =
+        serde_json::from_value(json!({ "ids": [1], "with_vector": false })).unwrap();
+    assert!(!req.with_vector);
+    assert!(req.with_payload);
+}
+
+/// Excluded fields are omitted from a `RetrievedPoint`, and the ID is a string.
+#[test]
+fn retrieved_point_omits_excluded_fields() {
+    let point = RetrievedPoint {
+        id: 7,
+        vector: None,
+        payload: Some(json!({"k": 1})),
+    };
+    let json = serde_json::to_value(&point).unwrap();
+    assert_eq!(json, json!({ "id": "7", "payload": {"k": 1} }));
+}
+
+/// Search projection flags default to "payload, no vector", matching the
+/// response shape from before the flags existed.
+#[test]
+fn search_request_projection_flags_default() {
+    let req: SearchRequest = serde_json::from_value(json!({ "vector": [0.1] })).unwrap();
+    assert!(!req.with_vector);
+    assert!(req.with_payload);
+    assert!(!req.explain_scores);
+
+    let req: HybridSearchRequest = serde_json::from_value(
+        json!({ "vector": [0.1], "query": "q", "with_vector": true, "with_payload": false }),
+    )
+    .unwrap();
+    assert!(req.with_vector);
+    assert!(!req.with_payload);
+}
+
+/// `SearchResultResponse::vector` is omitted unless set.
+#[test]
+fn search_result_response_vector_omitted_when_none() {
+    let mut result = SearchResultResponse {
+        id: 1,
+        score: 0.5,
+        vector: None,
+        payload: None,
+        score_breakdown: None,
+    };
+    let json = serde_json::to_value(&result).unwrap();
+    assert!(json.get("vector").is_none());
+    assert!(json["payload"].is_null());
+
+    result.vector = Some(vec![1.0, 2.0].into());
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["vector"], json!([1.0, 2.0]));
+}
+
+/// `vector_precision` defaults to `f32`; `f16` renders a `$f16` object and
+/// `none` drops the vector.
+#[test]
+fn response_vector_precision_renders_hit_vectors() {
+    let req: SearchRequest = serde_json::from_value(json!({ "vector": [0.1] })).unwrap();
+    assert_eq!(req.vector_precision, ResponseVectorPrecision::F32);
+    let req: TextSearchRequest =
+        serde_json::from_value(json!({ "query": "q", "vector_precision": "f16" })).unwrap();
+    assert_eq!(req.vector_precision, ResponseVectorPrecision::F16);
+    assert!(serde_json::from_value::<SearchRequest>(
+        json!({ "vector": [0.1], "vector_precision": "f64" })
+    )
+    .is_err());
+
+    let vector = vec![0.5, -1.0, 0.1];
+    let f32_json =
+        serde_json::to_value(ResponseVectorPrecision::F32.render(vector.clone())).unwrap();
+    assert_eq!(f32_json, json!([0.5, -1.0, 0.1_f32]));
+
+    let f16_json =
+        serde_json::to_value(ResponseVectorPrecision::F16.render(vector.clone())).unwrap();
+    let text = f16_json[F16_KEY].as_str().expect("$f16 string");
+    let decoded = crate::wire::f16_base64::decode(text).unwrap();
+    assert_eq!(decoded[..2], [0.5, -1.0]);
+    assert!((decoded[2] - 0.1).abs() < 1e-3);
+
+    assert_eq!(ResponseVectorPrecision::None.render(vector), None);
+}
+
+/// `PointRequest.vector` accepts a float array or a `$bytes` object.
+#[test]
+fn point_request_vector_accepts_floats_and_bytes() {
+    let floats: PointRequest =
+        serde_json::from_value(json!({"id": 1, "vector": [0.5, 1.0]})).unwrap();
+    assert_eq!(floats.vector, VectorInput::Floats(vec![0.5, 1.0]));
+
+    let encoded = crate::wire::f32_base64::encode(&[0.5, 1.0]);
+    let bytes: PointRequest =
+        serde_json::from_value(json!({"id": 1, "vector": {"$bytes": encoded}})).unwrap();
+    assert_eq!(bytes.vector.into_vector(2).unwrap(), vec![0.5, 1.0]);
+
+    let unknown = json!({"id": 1, "vector": {"$base64": encoded}});
+    assert!(serde_json::from_value::<PointRequest>(unknown).is_err());
+}
+
+/// A `$bytes` vector is checked against the collection dimension.
+#[test]
+fn vector_input_bytes_validates_dimension_and_encoding() {
+    let encoded = crate::wire::f32_base64::encode(&[0.5, 1.0, 2.0]);
+    let err = VectorInput::Bytes(encoded).into_vector(2).unwrap_err();
+    assert!(err.contains("decodes to 3 floats, expected dimension 2"));
+
+    let err = VectorInput::Bytes("AAAA".to_string())
+        .into_vector(1)
+        .unwrap_err();
+    assert!(err.contains("not a whole number of f32 values"));
+}
+
+/// `$bytes` params decode to float arrays; other values are untouched.
+#[test]
+fn decode_bytes_params_replaces_bytes_objects() {
+    let encoded = crate::wire::f32_base64::encode(&[0.25, -1.0]);
+    let mut params: std::collections::HashMap<String, serde_json::Value> = [
+        ("v".to_string(), json!({"$bytes": encoded})),
+        ("meta".to_string(), json!({"$bytes": "x", "other": 1})),
+        ("cat".to_string(), json!("tech")),
+    ]
+    .into();
+
+    decode_bytes_params(&mut params).unwrap();
+
+    assert_eq!(params["v"], json!([0.25, -1.0]));
+    assert_eq!(params["meta"], json!({"$bytes": "x", "other": 1}));
+    assert_eq!(params["cat"], json!("tech"));
+
+    let mut bad: std::collections::HashMap<String, serde_json::Value> =
+        [("v".to_string(), json!({"$bytes": "!!"}))].into();
+    assert!(decode_bytes_params(&mut bad)
+        .unwrap_err()
+        .starts_with("Parameter $v"));
+}
+
+/// The columnar upsert layout accepts float rows, bare base64 and `$bytes`.
+#[test]
+fn upsert_request_accepts_columnar_layout() {
+    let rows: UpsertPointsRequest = serde_json::from_value(json!({
+        "ids": [1, "2"],
+        "vectors": [[0.5, 1.0], [1.5, 2.0]],
+        "payloads": [{"k": 1}, null]
+    }))
+    .unwrap();
+    assert!(rows.is_columnar());
+    assert_eq!(rows.point_count(), 2);
+    assert_eq!(rows.ids, vec![1, 2]);
+    assert_eq!(rows.payloads, Some(vec![Some(json!({"k": 1})), None]));
+    assert_eq!(
+        rows.vectors.unwrap().into_rows(2, 2).unwrap(),
+        vec![vec![0.5, 1.0], vec![1.5, 2.0]]
+    );
+
+    let encoded = crate::wire::f32_base64::encode(&[0.5, 1.0, 1.5, 2.0]);
+    for vectors in [json!(encoded), json!({"$bytes": encoded})] {
+        let req: UpsertPointsRequest =
+            serde_json::from_value(json!({"ids": [1, 2], "vectors": vectors})).unwrap();
+        assert_eq!(
+            req.vectors.unwrap().into_rows(2, 2).unwrap(),
+            vec![vec![0.5, 1.0], vec![1.5, 2.0]]
+        );
+    }
+
+    let rows_only: UpsertPointsRequest =
+        serde_json::from_value(json!({"points": [{"id": 1, "vector": [0.5]}]})).unwrap();
+    assert!(!rows_only.is_columnar());
+}
+
+/// Columnar vectors must hold exactly one vector per id.
+#[test]
+fn columnar_vectors_validate_count() {
+    let err = ColumnarVectors::Rows(vec![vec![0.5]])
+        .into_rows(2, 1)
+        .unwrap_err();
+    assert!(err.contains("expected one per id (2)"), "{err}");
+
+    let encoded = crate::wire::f32_base64::encode(&[0.5, 1.0, 1.5]);
+    let err = ColumnarVectors::Bytes(encoded).into_rows(2, 2).unwrap_err();
+    assert!(err.contains("decode to 3 floats"), "{err}");
+}
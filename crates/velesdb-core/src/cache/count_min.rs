@@ -0,0 +1,106 @@
+//! Count-min sketch for approximate frequency counts.
+//!
+//! Counts any number of distinct items in fixed memory (`width × depth`
+//! `u32` counters). Estimates never undercount; with conservative update
+//! they overcount by at most `e / width × total` with probability
+//! `1 - e^-depth`.
+
+// Reason: hash → index casts are bounded by `width` (a usize).
+#![allow(clippy::cast_possible_truncation)]
+
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+/// Approximate frequency counter.
+///
+/// Not synchronised: wrap it in a lock to share it between threads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountMinSketch {
+    /// Counters per row.
+    width: usize,
+    /// Number of rows (hash functions).
+    depth: u32,
+    /// `depth` rows of `width` counters, row-major.
+    counters: Vec<u32>,
+    /// Number of increments.
+    total: u64,
+}
+
+impl CountMinSketch {
+    /// Creates a sketch with `width` counters in each of `depth` rows (both
+    /// at least 1).
+    #[must_use]
+    pub fn new(width: usize, depth: u32) -> Self {
+        let width = width.max(1);
+        let depth = depth.max(1);
+        Self {
+            width,
+            depth,
+            counters: vec![0; width * depth as usize],
+            total: 0,
+        }
+    }
+
+    /// Counts one occurrence of `item` and returns its new estimate.
+    ///
+    /// Conservative update: only the counters at the current minimum are
+    /// raised, which keeps overestimates lower than incrementing every row.
+    pub fn increment<T: Hash>(&mut self, item: &T) -> u32 {
+        self.total += 1;
+        let slots: Vec<usize> = (0..self.depth).map(|row| self.slot(item, row)).collect();
+        let estimate = slots
+            .iter()
+            .map(|&slot| self.counters[slot])
+            .min()
+            .unwrap_or(0)
+            .saturating_add(1);
+        for slot in slots {
+            if self.counters[slot] < estimate {
+                self.counters[slot] = estimate;
+            }
+        }
+        estimate
+    }
+
+    /// Estimated number of occurrences of `item` (never below the true
+    /// count).
+    #[must_use]
+    pub fn estimate<T: Hash>(&self, item: &T) -> u32 {
+        (0..self.depth)
+            .map(|row| self.counters[self.slot(item, row)])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Number of increments since creation or the last [`clear`](Self::clear).
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Resets every counter.
+    pub fn clear(&mut self) {
+        self.counters.fill(0);
+        self.total = 0;
+    }
+
+    /// Counter index of `item` in `row`.
+    #[inline]
+    fn slot<T: Hash>(&self, item: &T, row: u32) -> usize {
+        let mut hasher = FxHasher::default();
+        row.hash(&mut hasher);
+        item.hash(&mut hasher);
+        row as usize * self.width + (hasher.finish() as usize) % self.width
+    }
+}
+
+impl Default for CountMinSketch {
+    fn default() -> Self {
+        // 2048 × 4 counters (32 KiB): ~0.13 % of all increments worst-case
+        // overcount with 98 % confidence.
+        Self::new(2048, 4)
+    }
+}
+
+// Tests in count_min_tests.rs per project rules
@@ -0,0 +1,55 @@
+//! Tests for `count_min` module - count-min sketch implementation.
+
+use super::count_min::*;
+
+#[test]
+fn test_count_min_counts_exactly_without_collisions() {
+    let mut sketch = CountMinSketch::new(1024, 4);
+
+    for _ in 0..5 {
+        sketch.increment(&1_u64);
+    }
+    assert_eq!(sketch.increment(&2_u64), 1);
+
+    assert_eq!(sketch.estimate(&1_u64), 5);
+    assert_eq!(sketch.estimate(&2_u64), 1);
+    assert_eq!(sketch.estimate(&3_u64), 0);
+    assert_eq!(sketch.total(), 6);
+}
+
+#[test]
+fn test_count_min_never_undercounts() {
+    // A tiny sketch forces collisions.
+    let mut sketch = CountMinSketch::new(8, 2);
+    for id in 0_u64..200 {
+        for _ in 0..=(id % 5) {
+            sketch.increment(&id);
+        }
+    }
+    for id in 0_u64..200 {
+        let truth = u32::try_from(id % 5).unwrap() + 1;
+        assert!(sketch.estimate(&id) >= truth);
+    }
+}
+
+#[test]
+fn test_count_min_clear_and_degenerate_sizes() {
+    let mut sketch = CountMinSketch::new(0, 0);
+    sketch.increment(&"a");
+    assert_eq!(sketch.estimate(&"a"), 1);
+
+    sketch.clear();
+
+    assert_eq!(sketch.estimate(&"a"), 0);
+    assert_eq!(sketch.total(), 0);
+}
+
+#[test]
+fn test_count_min_serde_round_trip() {
+    let mut sketch = CountMinSketch::default();
+    sketch.increment(&42_u64);
+    let bytes = postcard::to_allocvec(&sketch).unwrap();
+    let restored: CountMinSketch = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(restored.estimate(&42_u64), 1);
+    assert_eq!(restored.total(), 1);
+}
@@ -0,0 +1,179 @@
+//! LFU cache implementation for `VelesDB`.
+//!
+//! Evicts the least frequently used entry, breaking ties by recency, so a
+//! burst of one-off reads cannot flush entries that are read again and
+//! again (the weakness of [`LruCache`](super::LruCache) for skewed access).
+//!
+//! | Operation | Complexity |
+//! |-----------|------------|
+//! | insert | O(log n) |
+//! | get | O(log n) |
+//! | remove | O(log n) |
+
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::CacheStats;
+
+struct Slot<V> {
+    value: V,
+    /// Hits since insertion (starts at 1).
+    frequency: u64,
+    /// Tick of the last insert or hit; orders entries of equal frequency.
+    tick: u64,
+}
+
+struct Inner<K, V> {
+    entries: FxHashMap<K, Slot<V>>,
+    /// `(frequency, tick)` of every entry; the first one is evicted next.
+    order: BTreeMap<(u64, u64), K>,
+    next_tick: u64,
+}
+
+impl<K: Hash + Eq + Clone, V> Inner<K, V> {
+    fn tick(&mut self) -> u64 {
+        self.next_tick += 1;
+        self.next_tick
+    }
+}
+
+/// Thread-safe LFU cache.
+pub struct LfuCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Maximum capacity; 0 disables the cache.
+    capacity: usize,
+    inner: Mutex<Inner<K, V>>,
+    /// Statistics (atomic for lock-free reads).
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl<K, V> LfuCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Create a new LFU cache with the given capacity.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(Inner {
+                entries: FxHashMap::default(),
+                order: BTreeMap::new(),
+                next_tick: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the capacity of the cache.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the current number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.lock().entries.len()
+    }
+
+    /// Check if the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.lock().entries.is_empty()
+    }
+
+    /// Insert or replace a value, evicting the least frequently used entry
+    /// if at capacity. A replaced entry keeps its frequency.
+    pub fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.inner.lock();
+        let tick = inner.tick();
+        if let Some(slot) = inner.entries.get_mut(&key) {
+            let old = (slot.frequency, slot.tick);
+            slot.value = value;
+            slot.tick = tick;
+            let frequency = slot.frequency;
+            inner.order.remove(&old);
+            inner.order.insert((frequency, tick), key);
+            return;
+        }
+        if inner.entries.len() >= self.capacity {
+            if let Some((_, evicted)) = inner.order.pop_first() {
+                inner.entries.remove(&evicted);
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        inner.order.insert((1, tick), key.clone());
+        inner.entries.insert(
+            key,
+            Slot {
+                value,
+                frequency: 1,
+                tick,
+            },
+        );
+    }
+
+    /// Get a value by key, counting the hit.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut inner = self.inner.lock();
+        let tick = inner.tick();
+        let Some(slot) = inner.entries.get_mut(key) else {
+            drop(inner);
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+        let old = (slot.frequency, slot.tick);
+        slot.frequency += 1;
+        slot.tick = tick;
+        let (frequency, value) = (slot.frequency, slot.value.clone());
+        if let Some(owned_key) = inner.order.remove(&old) {
+            inner.order.insert((frequency, tick), owned_key);
+        }
+        drop(inner);
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(value)
+    }
+
+    /// Remove a key from the cache.
+    pub fn remove(&self, key: &K) {
+        let mut inner = self.inner.lock();
+        if let Some(slot) = inner.entries.remove(key) {
+            inner.order.remove(&(slot.frequency, slot.tick));
+        }
+    }
+
+    /// Clear all entries.
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock();
+        inner.entries.clear();
+        inner.order.clear();
+    }
+
+    /// Get cache statistics.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+        }
+    }
+}
+
+// Tests in lfu_tests.rs per project rules
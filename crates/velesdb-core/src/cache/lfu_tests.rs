@@ -0,0 +1,67 @@
+//! Tests for `lfu` module - LFU cache implementation.
+
+use super::lfu::*;
+
+#[test]
+fn test_lfu_evicts_least_frequently_used() {
+    let cache = LfuCache::new(2);
+    cache.insert(1, "one");
+    cache.insert(2, "two");
+    assert_eq!(cache.get(&1), Some("one"));
+    assert_eq!(cache.get(&1), Some("one"));
+
+    // 2 was read least often: it goes, even though 1 is older.
+    cache.insert(3, "three");
+
+    assert_eq!(cache.get(&2), None);
+    assert_eq!(cache.get(&1), Some("one"));
+    assert_eq!(cache.get(&3), Some("three"));
+    assert_eq!(cache.len(), 2);
+    let stats = cache.stats();
+    assert_eq!(stats.evictions, 1);
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hits, 4);
+}
+
+#[test]
+fn test_lfu_breaks_frequency_ties_by_recency() {
+    let cache = LfuCache::new(2);
+    cache.insert(1, 10);
+    cache.insert(2, 20);
+
+    cache.insert(3, 30);
+
+    assert_eq!(cache.get(&1), None);
+    assert_eq!(cache.get(&2), Some(20));
+}
+
+#[test]
+fn test_lfu_replace_keeps_frequency() {
+    let cache = LfuCache::new(2);
+    cache.insert(1, 10);
+    let _ = cache.get(&1);
+    cache.insert(1, 11);
+    cache.insert(2, 20);
+
+    cache.insert(3, 30);
+
+    assert_eq!(cache.get(&1), Some(11));
+    assert_eq!(cache.get(&2), None);
+}
+
+#[test]
+fn test_lfu_remove_clear_and_zero_capacity() {
+    let cache = LfuCache::new(4);
+    cache.insert("a", 1);
+    cache.insert("b", 2);
+    cache.remove(&"a");
+    assert_eq!(cache.get(&"a"), None);
+    assert_eq!(cache.len(), 1);
+    cache.clear();
+    assert!(cache.is_empty());
+
+    let disabled = LfuCache::new(0);
+    disabled.insert("a", 1);
+    assert!(disabled.is_empty());
+    assert_eq!(disabled.capacity(), 0);
+}
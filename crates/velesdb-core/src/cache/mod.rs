@@ -2,7 +2,9 @@
 //!
 //! Based on arXiv:2310.11703v2 recommendations:
 //! - LRU cache for metadata-only collections
+//! - LFU cache for skewed (long-tail) read patterns
 //! - Bloom filter for existence checks
+//! - Count-min sketch for approximate frequency counts
 //! - Cache statistics and monitoring
 //!
 //! # Thread-Safety & Lock Ordering
@@ -17,6 +19,12 @@
 mod bloom;
 #[cfg(test)]
 mod bloom_tests;
+mod count_min;
+#[cfg(test)]
+mod count_min_tests;
+mod lfu;
+#[cfg(test)]
+mod lfu_tests;
 mod lockfree;
 #[cfg(test)]
 mod lockfree_tests;
@@ -27,6 +35,8 @@ pub mod plan_cache;
 mod plan_cache_tests;
 
 pub use bloom::BloomFilter;
+pub use count_min::CountMinSketch;
+pub use lfu::LfuCache;
 pub use lockfree::{LockFreeCacheStats, LockFreeLruCache};
 pub use lru::{CacheStats, LruCache};
 #[cfg(feature = "persistence")]
@@ -400,6 +400,7 @@ fn select_query(collection: &str) -> crate::velesql::Query {
             from_alias: Vec::new(),
             where_clause: Some(Condition::VectorSearch(VectorSearch {
                 vector: VectorExpr::Literal(vec![1.0, 0.0, 0.0, 0.0]),
+                avoid: Vec::new(),
             })),
             limit: Some(5),
             offset: None,
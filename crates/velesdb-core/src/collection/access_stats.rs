@@ -0,0 +1,321 @@
+//! Per-point access frequency and the payload LFU cache.
+//!
+//! Every point returned by `get` or a vector search counts as one access in
+//! a [`CountMinSketch`]: approximate counts in fixed memory, whatever the
+//! number of points. The most accessed points are tracked alongside it
+//! (top [`TOP_TRACKED`]). The counts feed the archive tier (a policy can
+//! keep frequently read points hot, see
+//! [`ArchivePolicy::keep_frequent`](crate::collection::ArchivePolicy::keep_frequent))
+//! and product analytics through
+//! [`access_stats`](crate::VectorCollection::access_stats). They are
+//! persisted to `access_counts.bin` on flush.
+//!
+//! Search hydration reads payloads through an optional [`LfuCache`] sized
+//! by `storage.payload_cache_entries`. Entries are tagged with the
+//! collection's write generation they were read at and only served to
+//! readers of that generation, so a cached payload is never older than the
+//! last write; the cache empties itself when the generation moves.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{CountMinSketch, LfuCache};
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::storage::{PayloadStorage, VectorStorage};
+
+/// Number of most accessed points tracked exactly (by id).
+pub const TOP_TRACKED: usize = 100;
+
+/// Persisted counts, in the collection directory.
+const ACCESS_COUNTS_FILE: &str = "access_counts.bin";
+
+/// Approximate access count of one point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointAccessCount {
+    /// Point id.
+    pub id: u64,
+    /// Estimated number of accesses (never below the true count).
+    pub count: u32,
+}
+
+/// Payload cache counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PayloadCacheStats {
+    /// Maximum number of cached payloads; 0 when the cache is disabled.
+    pub capacity: usize,
+    /// Payloads currently cached.
+    pub entries: usize,
+    /// Reads served from the cache.
+    pub hits: u64,
+    /// Reads that went to storage.
+    pub misses: u64,
+    /// Payloads evicted to make room.
+    pub evictions: u64,
+}
+
+/// Access statistics of a collection.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AccessStats {
+    /// Accesses counted since the counts were created.
+    pub total_accesses: u64,
+    /// Most accessed live points, most accessed first.
+    pub top: Vec<PointAccessCount>,
+    /// Payload cache counters.
+    pub payload_cache: PayloadCacheStats,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Counts {
+    sketch: CountMinSketch,
+    /// Estimates of the (at most [`TOP_TRACKED`]) most accessed ids.
+    top: HashMap<u64, u32>,
+}
+
+#[derive(Debug, Default)]
+struct CounterState {
+    /// Whether `counts` has been read from disk yet.
+    loaded: bool,
+    dirty: bool,
+    counts: Counts,
+    /// Smallest estimate in a full `top`; lower estimates cannot enter it.
+    top_floor: u32,
+}
+
+impl CounterState {
+    fn load(&mut self, path: &Path) {
+        if self.loaded {
+            return;
+        }
+        self.loaded = true;
+        let Ok(bytes) = std::fs::read(path) else {
+            return;
+        };
+        match postcard::from_bytes(&bytes) {
+            Ok(counts) => {
+                self.counts = counts;
+                self.refresh_floor();
+            }
+            // Losing the counts only restarts them from zero.
+            Err(e) => tracing::warn!(
+                path = %path.display(),
+                error = %e,
+                "ignoring unreadable access counts"
+            ),
+        }
+    }
+
+    fn refresh_floor(&mut self) {
+        self.top_floor = if self.counts.top.len() < TOP_TRACKED {
+            0
+        } else {
+            self.counts.top.values().copied().min().unwrap_or(0)
+        };
+    }
+
+    fn record(&mut self, id: u64) {
+        let estimate = self.counts.sketch.increment(&id);
+        let top = &mut self.counts.top;
+        if let Some(count) = top.get_mut(&id) {
+            *count = estimate;
+        } else if top.len() < TOP_TRACKED {
+            top.insert(id, estimate);
+        } else if estimate > self.top_floor {
+            if let Some((&coldest, _)) = top.iter().min_by_key(|(_, &count)| count) {
+                top.remove(&coldest);
+            }
+            top.insert(id, estimate);
+        } else {
+            return;
+        }
+        self.refresh_floor();
+    }
+}
+
+/// Payloads tagged with the write generation they were read at.
+struct GenerationCache {
+    /// Newest generation seen by a reader.
+    generation: AtomicU64,
+    entries: LfuCache<u64, (u64, Option<serde_json::Value>)>,
+}
+
+/// A collection's access counters and payload cache, shared by its clones.
+///
+/// The counter mutex is a leaf; the cache slot lock is held only to swap or
+/// clone the cache handle.
+#[derive(Clone, Default)]
+pub(crate) struct AccessTracker {
+    counters: Arc<Mutex<CounterState>>,
+    payload_cache: Arc<RwLock<Option<Arc<GenerationCache>>>>,
+}
+
+/// Payload reads for search hydration, served from the collection's payload
+/// cache when one is configured.
+pub(crate) struct PayloadReader<'a> {
+    storage: &'a dyn PayloadStorage,
+    cache: Option<(Arc<GenerationCache>, u64)>,
+}
+
+impl<'a> PayloadReader<'a> {
+    /// Reads straight from `storage`.
+    pub(crate) fn uncached(storage: &'a dyn PayloadStorage) -> Self {
+        Self {
+            storage,
+            cache: None,
+        }
+    }
+
+    /// The payload of `id`, `None` when it has none.
+    pub(crate) fn retrieve(&self, id: u64) -> Option<serde_json::Value> {
+        let Some((cache, generation)) = &self.cache else {
+            return self.storage.retrieve(id).ok().flatten();
+        };
+        if let Some((tag, payload)) = cache.entries.get(&id) {
+            if tag == *generation {
+                return payload;
+            }
+        }
+        let payload = self.storage.retrieve(id).ok().flatten();
+        cache.entries.insert(id, (*generation, payload.clone()));
+        payload
+    }
+}
+
+impl Collection {
+    /// Resizes (or, with 0, disables) the payload cache.
+    ///
+    /// Called by the `Database` registration paths with the live
+    /// [`StorageConfig::payload_cache_entries`](crate::config::StorageConfig::payload_cache_entries).
+    /// **Not persisted** — each open re-pushes it.
+    pub(crate) fn set_payload_cache_capacity(&self, capacity: usize) {
+        let mut slot = self.runtime.access.payload_cache.write();
+        if slot.as_ref().map_or(0, |c| c.entries.capacity()) == capacity {
+            return;
+        }
+        *slot = (capacity > 0).then(|| {
+            Arc::new(GenerationCache {
+                generation: AtomicU64::new(self.write_generation()),
+                entries: LfuCache::new(capacity),
+            })
+        });
+    }
+
+    /// Payload reader for search hydration over the locked `storage`.
+    pub(crate) fn payload_reader<'a>(&self, storage: &'a dyn PayloadStorage) -> PayloadReader<'a> {
+        let Some(cache) = self.runtime.access.payload_cache.read().clone() else {
+            return PayloadReader::uncached(storage);
+        };
+        let generation = self.write_generation();
+        // Entries of older generations can no longer be served: drop them.
+        if cache.generation.fetch_max(generation, Ordering::AcqRel) < generation {
+            cache.entries.clear();
+        }
+        PayloadReader {
+            storage,
+            cache: Some((cache, generation)),
+        }
+    }
+
+    /// Counts one read of each of `ids`, for the access statistics and the
+    /// archive policy.
+    pub(crate) fn record_reads(&self, ids: impl IntoIterator<Item = u64>) {
+        let ids: Vec<u64> = ids.into_iter().collect();
+        if ids.is_empty() {
+            return;
+        }
+        {
+            let mut state = self.runtime.access.counters.lock();
+            state.load(&self.storage.path.join(ACCESS_COUNTS_FILE));
+            for &id in &ids {
+                state.record(id);
+            }
+            state.dirty = true;
+        }
+        self.record_access(ids);
+    }
+
+    /// Drops from `ids` the points read at least `min_reads` times.
+    pub(crate) fn retain_rarely_read(&self, ids: &mut Vec<u64>, min_reads: u32) {
+        let mut state = self.runtime.access.counters.lock();
+        state.load(&self.storage.path.join(ACCESS_COUNTS_FILE));
+        ids.retain(|id| state.counts.sketch.estimate(id) < min_reads);
+    }
+
+    /// Persists the access counts recorded since the last call.
+    pub(crate) fn persist_access_counts(&self) -> Result<()> {
+        let mut state = self.runtime.access.counters.lock();
+        if !state.dirty {
+            return Ok(());
+        }
+        let bytes = postcard::to_allocvec(&state.counts)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        crate::storage::atomic_write::atomic_write(
+            &self.storage.path.join(ACCESS_COUNTS_FILE),
+            &bytes,
+        )?;
+        state.dirty = false;
+        Ok(())
+    }
+
+    /// Copies the access counts into `dir`, the directory of a rebuilt copy
+    /// of this collection.
+    pub(crate) fn copy_access_counts(&self, dir: &Path) -> Result<()> {
+        self.persist_access_counts()?;
+        match std::fs::copy(
+            self.storage.path.join(ACCESS_COUNTS_FILE),
+            dir.join(ACCESS_COUNTS_FILE),
+        ) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Access statistics: total reads, the `top` most read live points and
+    /// the payload cache counters.
+    pub(crate) fn access_stats(&self, top: usize) -> AccessStats {
+        let (total_accesses, mut ranked) = {
+            let mut state = self.runtime.access.counters.lock();
+            state.load(&self.storage.path.join(ACCESS_COUNTS_FILE));
+            let ranked: Vec<PointAccessCount> = state
+                .counts
+                .top
+                .iter()
+                .map(|(&id, &count)| PointAccessCount { id, count })
+                .collect();
+            (state.counts.sketch.total(), ranked)
+        };
+        {
+            let vector_storage = self.storage.vector_storage.read();
+            ranked.retain(|p| vector_storage.contains(p.id));
+        }
+        ranked.sort_unstable_by(|a, b| b.count.cmp(&a.count).then(a.id.cmp(&b.id)));
+        ranked.truncate(top);
+
+        let payload_cache = self
+            .runtime
+            .access
+            .payload_cache
+            .read()
+            .as_ref()
+            .map_or_else(PayloadCacheStats::default, |cache| {
+                let stats = cache.entries.stats();
+                PayloadCacheStats {
+                    capacity: cache.entries.capacity(),
+                    entries: cache.entries.len(),
+                    hits: stats.hits,
+                    misses: stats.misses,
+                    evictions: stats.evictions,
+                }
+            });
+        AccessStats {
+            total_accesses,
+            top: ranked,
+            payload_cache,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Tests for per-point access counts and the payload cache (`access_stats`).
+
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::collection::expiry::now_unix_secs;
+use crate::collection::{ArchivePolicy, PointAccessCount, VectorCollection};
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+use crate::quantization::StorageMode;
+
+const DAY: u64 = 24 * 60 * 60;
+
+fn create(path: &Path) -> VectorCollection {
+    let coll = VectorCollection::create(
+        path.to_path_buf(),
+        "c",
+        2,
+        DistanceMetric::Cosine,
+        StorageMode::Full,
+    )
+    .expect("create collection");
+    coll.upsert(vec![
+        Point::new(1, vec![1.0, 0.0], Some(json!({ "n": 1 }))),
+        Point::new(2, vec![0.0, 1.0], Some(json!({ "n": 2 }))),
+        Point::new(3, vec![0.7, 0.7], Some(json!({ "n": 3 }))),
+    ])
+    .expect("upsert");
+    coll
+}
+
+#[test]
+fn test_access_stats_rank_reads_and_survive_reopen() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("c");
+    {
+        let coll = create(&path);
+        assert_eq!(coll.access_stats(10).total_accesses, 0);
+
+        for _ in 0..3 {
+            let _ = coll.get(&[1]);
+        }
+        let _ = coll.search(&[0.0, 1.0], 1).expect("search");
+        let _ = coll.get(&[3, 3]);
+
+        let stats = coll.access_stats(2);
+        assert_eq!(stats.total_accesses, 6);
+        assert_eq!(
+            stats.top,
+            vec![
+                PointAccessCount { id: 1, count: 3 },
+                PointAccessCount { id: 3, count: 2 },
+            ]
+        );
+        coll.flush().expect("flush");
+    }
+
+    let coll = VectorCollection::open(path).expect("reopen");
+    coll.delete(&[1]).expect("delete");
+    let stats = coll.access_stats(10);
+    assert_eq!(stats.total_accesses, 6);
+    // Deleted points leave the ranking.
+    assert_eq!(
+        stats.top,
+        vec![
+            PointAccessCount { id: 3, count: 2 },
+            PointAccessCount { id: 2, count: 1 },
+        ]
+    );
+}
+
+#[test]
+fn test_payload_cache_serves_hits_and_drops_stale_payloads() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"));
+    assert_eq!(coll.access_stats(0).payload_cache.capacity, 0);
+    coll.inner.set_payload_cache_capacity(3);
+
+    let first = coll.search(&[1.0, 0.0], 3).expect("search");
+    let second = coll.search(&[1.0, 0.0], 3).expect("search");
+
+    assert_eq!(first.len(), 3);
+    assert_eq!(
+        first.iter().map(|r| &r.point.payload).collect::<Vec<_>>(),
+        second.iter().map(|r| &r.point.payload).collect::<Vec<_>>()
+    );
+    let cache = coll.access_stats(0).payload_cache;
+    assert_eq!(cache.capacity, 3);
+    assert_eq!(cache.entries, 3);
+    assert_eq!((cache.hits, cache.misses), (3, 3));
+
+    coll.upsert(vec![Point::new(
+        1,
+        vec![1.0, 0.0],
+        Some(json!({ "n": 10 })),
+    )])
+    .expect("upsert");
+    let after = coll.search(&[1.0, 0.0], 1).expect("search");
+
+    assert_eq!(after[0].point.id, 1);
+    assert_eq!(after[0].point.payload, Some(json!({ "n": 10 })));
+}
+
+#[test]
+fn test_archive_policy_keeps_frequently_read_points_hot() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"));
+    let policy = ArchivePolicy::idle_days(30).keep_frequent(3);
+    coll.set_archive_policy(Some(policy)).expect("set policy");
+    for _ in 0..3 {
+        let _ = coll.get(&[2]);
+    }
+
+    let report = coll
+        .inner
+        .archive_cold_points_at(now_unix_secs() + 31 * DAY)
+        .expect("archive");
+
+    assert_eq!(report.archived, 2);
+    assert_eq!(coll.len(), 1);
+    assert!(coll.get(&[2])[0].is_some());
+    assert_eq!(coll.archive_policy(), Some(policy));
+}
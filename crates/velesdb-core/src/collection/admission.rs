@@ -0,0 +1,294 @@
+//! Per-collection query admission control.
+//!
+//! Each collection caps how many searches and how many scans run on it at
+//! once ([`LimitsConfig::max_concurrent_searches`] and
+//! [`LimitsConfig::max_concurrent_scans`]). A query arriving while every slot
+//! of its kind is busy waits in a bounded queue for up to
+//! `admission_queue_timeout_ms`. When the queue is full or the wait times out
+//! the query is rejected with [`GuardRailViolation::Overloaded`] (HTTP 503 on
+//! the server) instead of piling onto an already saturated collection.
+//!
+//! A limit of `0` disables admission control for that kind; queries are
+//! still counted so [`AdmissionStats`] reflects the live concurrency.
+//!
+//! Permits are taken at the public API boundary (`VectorCollection` search
+//! methods and `Collection::execute_query_with_client`), never by internal
+//! helpers, so a query holds at most one permit and cannot wait on itself.
+//!
+//! [`LimitsConfig::max_concurrent_searches`]: crate::config::LimitsConfig::max_concurrent_searches
+//! [`LimitsConfig::max_concurrent_scans`]: crate::config::LimitsConfig::max_concurrent_scans
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::{Condvar, Mutex};
+use serde::Serialize;
+
+use crate::collection::types::{Collection, RuntimeLimits};
+use crate::error::Result;
+use crate::guardrails::GuardRailViolation;
+
+/// Kind of query competing for a collection's concurrency slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdmissionKind {
+    /// Vector, text, hybrid and sparse searches.
+    Search,
+    /// VelesQL queries without a vector search, MATCH, aggregations,
+    /// scroll and sampling.
+    Scan,
+}
+
+impl AdmissionKind {
+    /// Lower-case name, as used in errors and metric labels.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Search => "search",
+            Self::Scan => "scan",
+        }
+    }
+
+    /// Classifies a VelesQL query: a `NEAR` / `NEAR_FUSED` / `SPARSE_NEAR`
+    /// in the WHERE clause makes it a search, anything else a scan.
+    pub(crate) fn for_query(query: &crate::velesql::Query) -> Self {
+        let has_vector_search = !query.is_match_query()
+            && query
+                .select
+                .where_clause
+                .as_ref()
+                .is_some_and(crate::velesql::Condition::has_vector_search);
+        if has_vector_search {
+            Self::Search
+        } else {
+            Self::Scan
+        }
+    }
+}
+
+/// Admission counters of one query kind on one collection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AdmissionKindStats {
+    /// Configured concurrency limit (`0` = unlimited).
+    pub max_concurrent: usize,
+    /// Queries currently running.
+    pub in_flight: usize,
+    /// Queries currently waiting for a slot.
+    pub queue_depth: usize,
+    /// Queries admitted since the collection was opened.
+    pub admitted_total: u64,
+    /// Admitted queries that had to wait for a slot first.
+    pub queued_total: u64,
+    /// Queries rejected, either because the queue was full or because they
+    /// timed out in it.
+    pub rejected_total: u64,
+    /// Subset of `rejected_total` that timed out in the queue.
+    pub timed_out_total: u64,
+}
+
+/// Admission counters of a collection, per query kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AdmissionStats {
+    /// Vector, text, hybrid and sparse searches.
+    pub searches: AdmissionKindStats,
+    /// Scans (see [`AdmissionKind::Scan`]).
+    pub scans: AdmissionKindStats,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LimiterSettings {
+    max_concurrent: usize,
+    max_queue: usize,
+    queue_timeout: Duration,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    settings: LimiterSettings,
+    in_flight: usize,
+    waiting: usize,
+}
+
+/// Counting semaphore with a bounded, timed wait queue.
+#[derive(Debug)]
+struct Limiter {
+    kind: AdmissionKind,
+    state: Mutex<LimiterState>,
+    released: Condvar,
+    admitted: AtomicU64,
+    queued: AtomicU64,
+    rejected: AtomicU64,
+    timed_out: AtomicU64,
+}
+
+impl Limiter {
+    fn new(kind: AdmissionKind, settings: LimiterSettings) -> Self {
+        Self {
+            kind,
+            state: Mutex::new(LimiterState {
+                settings,
+                in_flight: 0,
+                waiting: 0,
+            }),
+            released: Condvar::new(),
+            admitted: AtomicU64::new(0),
+            queued: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+            timed_out: AtomicU64::new(0),
+        }
+    }
+
+    fn has_slot(state: &LimiterState) -> bool {
+        state.settings.max_concurrent == 0 || state.in_flight < state.settings.max_concurrent
+    }
+
+    fn acquire(&self) -> std::result::Result<AdmissionPermit<'_>, GuardRailViolation> {
+        let mut state = self.state.lock();
+        if Self::has_slot(&state) {
+            state.in_flight += 1;
+            self.admitted.fetch_add(1, Ordering::Relaxed);
+            return Ok(AdmissionPermit { limiter: self });
+        }
+        if state.waiting >= state.settings.max_queue {
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            return Err(self.overloaded(&state, Duration::ZERO));
+        }
+
+        state.waiting += 1;
+        let started = Instant::now();
+        let deadline = started + state.settings.queue_timeout;
+        loop {
+            let timed_out = self.released.wait_until(&mut state, deadline).timed_out();
+            // A slot freed right at the deadline is still taken: the
+            // notification that woke us must not be lost.
+            if Self::has_slot(&state) {
+                state.waiting -= 1;
+                state.in_flight += 1;
+                self.admitted.fetch_add(1, Ordering::Relaxed);
+                self.queued.fetch_add(1, Ordering::Relaxed);
+                return Ok(AdmissionPermit { limiter: self });
+            }
+            if timed_out {
+                state.waiting -= 1;
+                self.rejected.fetch_add(1, Ordering::Relaxed);
+                self.timed_out.fetch_add(1, Ordering::Relaxed);
+                return Err(self.overloaded(&state, started.elapsed()));
+            }
+        }
+    }
+
+    fn overloaded(&self, state: &LimiterState, waited: Duration) -> GuardRailViolation {
+        GuardRailViolation::Overloaded {
+            kind: self.kind.as_str(),
+            max_concurrent: state.settings.max_concurrent,
+            waited_ms: u64::try_from(waited.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock();
+        state.in_flight = state.in_flight.saturating_sub(1);
+        drop(state);
+        self.released.notify_one();
+    }
+
+    fn configure(&self, settings: LimiterSettings) {
+        self.state.lock().settings = settings;
+        // A raised limit may admit several waiters at once.
+        self.released.notify_all();
+    }
+
+    fn stats(&self) -> AdmissionKindStats {
+        let state = self.state.lock();
+        AdmissionKindStats {
+            max_concurrent: state.settings.max_concurrent,
+            in_flight: state.in_flight,
+            queue_depth: state.waiting,
+            admitted_total: self.admitted.load(Ordering::Relaxed),
+            queued_total: self.queued.load(Ordering::Relaxed),
+            rejected_total: self.rejected.load(Ordering::Relaxed),
+            timed_out_total: self.timed_out.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A concurrency slot, released when dropped.
+#[must_use = "the slot is released as soon as the permit is dropped"]
+#[derive(Debug)]
+pub(crate) struct AdmissionPermit<'a> {
+    limiter: &'a Limiter,
+}
+
+impl Drop for AdmissionPermit<'_> {
+    fn drop(&mut self) {
+        self.limiter.release();
+    }
+}
+
+/// The search and scan limiters of one collection.
+#[derive(Debug)]
+pub(crate) struct AdmissionControl {
+    searches: Limiter,
+    scans: Limiter,
+}
+
+impl AdmissionControl {
+    pub(crate) fn new(limits: &RuntimeLimits) -> Self {
+        let (searches, scans) = Self::settings(limits);
+        Self {
+            searches: Limiter::new(AdmissionKind::Search, searches),
+            scans: Limiter::new(AdmissionKind::Scan, scans),
+        }
+    }
+
+    fn settings(limits: &RuntimeLimits) -> (LimiterSettings, LimiterSettings) {
+        let with_max = |max_concurrent| LimiterSettings {
+            max_concurrent,
+            max_queue: limits.max_admission_queue,
+            queue_timeout: Duration::from_millis(limits.admission_queue_timeout_ms),
+        };
+        (
+            with_max(limits.max_concurrent_searches),
+            with_max(limits.max_concurrent_scans),
+        )
+    }
+
+    /// Applies new limits; waiters re-check them immediately.
+    pub(crate) fn configure(&self, limits: &RuntimeLimits) {
+        let (searches, scans) = Self::settings(limits);
+        self.searches.configure(searches);
+        self.scans.configure(scans);
+    }
+
+    fn limiter(&self, kind: AdmissionKind) -> &Limiter {
+        match kind {
+            AdmissionKind::Search => &self.searches,
+            AdmissionKind::Scan => &self.scans,
+        }
+    }
+
+    pub(crate) fn stats(&self) -> AdmissionStats {
+        AdmissionStats {
+            searches: self.searches.stats(),
+            scans: self.scans.stats(),
+        }
+    }
+}
+
+impl Collection {
+    /// Takes a concurrency slot for a query of `kind`, waiting in the
+    /// admission queue if every slot is busy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GuardRail`](crate::error::Error::GuardRail) when the
+    /// queue is full or the wait exceeds `admission_queue_timeout_ms`.
+    pub(crate) fn admit(&self, kind: AdmissionKind) -> Result<AdmissionPermit<'_>> {
+        Ok(self.runtime.admission.limiter(kind).acquire()?)
+    }
+
+    /// Returns the admission counters of this collection.
+    pub(crate) fn admission_stats(&self) -> AdmissionStats {
+        self.runtime.admission.stats()
+    }
+}
@@ -0,0 +1,173 @@
+//! Tests for per-collection query admission control.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::collection::types::RuntimeLimits;
+use crate::collection::{AdmissionKind, VectorCollection};
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+use crate::quantization::StorageMode;
+use crate::velesql::Parser;
+
+fn collection() -> (tempfile::TempDir, VectorCollection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = VectorCollection::create(
+        dir.path().join("c"),
+        "c",
+        2,
+        DistanceMetric::Cosine,
+        StorageMode::Full,
+    )
+    .expect("create collection");
+    col.upsert(vec![
+        Point::new(1, vec![1.0, 0.0], Some(serde_json::json!({"g": 1}))),
+        Point::new(2, vec![0.0, 1.0], Some(serde_json::json!({"g": 2}))),
+    ])
+    .expect("upsert");
+    (dir, col)
+}
+
+fn limit(col: &VectorCollection, searches: usize, scans: usize, queue: usize, timeout_ms: u64) {
+    col.inner.set_runtime_limits(RuntimeLimits {
+        max_concurrent_searches: searches,
+        max_concurrent_scans: scans,
+        max_admission_queue: queue,
+        admission_queue_timeout_ms: timeout_ms,
+        ..RuntimeLimits::default()
+    });
+}
+
+fn assert_overloaded(err: &Error) {
+    assert!(
+        matches!(err, Error::GuardRail(msg) if msg.contains("overloaded")),
+        "expected an overload rejection, got {err:?}"
+    );
+    assert_eq!(err.code(), "VELES-027");
+}
+
+#[test]
+fn test_unlimited_by_default_but_counted() {
+    let (_dir, col) = collection();
+    let a = col.inner.admit(AdmissionKind::Search).expect("admit");
+    let b = col.inner.admit(AdmissionKind::Search).expect("admit");
+    let stats = col.admission_stats().searches;
+    assert_eq!(stats.max_concurrent, 0);
+    assert_eq!(stats.in_flight, 2);
+    drop((a, b));
+    let stats = col.admission_stats().searches;
+    assert_eq!(stats.in_flight, 0);
+    assert_eq!(stats.admitted_total, 2);
+}
+
+#[test]
+fn test_full_queue_rejects_immediately() {
+    let (_dir, col) = collection();
+    limit(&col, 1, 0, 0, 10_000);
+    let _held = col.inner.admit(AdmissionKind::Search).expect("first slot");
+
+    let err = col
+        .search(&[1.0, 0.0], 1)
+        .expect_err("second search must be rejected");
+    assert_overloaded(&err);
+
+    let stats = col.admission_stats().searches;
+    assert_eq!(stats.rejected_total, 1);
+    assert_eq!(stats.timed_out_total, 0);
+}
+
+#[test]
+fn test_queued_query_times_out() {
+    let (_dir, col) = collection();
+    limit(&col, 1, 0, 4, 20);
+    let _held = col.inner.admit(AdmissionKind::Search).expect("first slot");
+
+    let err = col
+        .hybrid_search(&[1.0, 0.0], "x", 1, None)
+        .expect_err("no slot frees up");
+    assert_overloaded(&err);
+    let stats = col.admission_stats().searches;
+    assert_eq!(stats.timed_out_total, 1);
+    assert_eq!(stats.queue_depth, 0);
+}
+
+#[test]
+fn test_queued_query_runs_when_slot_frees() {
+    let (_dir, col) = collection();
+    limit(&col, 1, 0, 4, 10_000);
+    let held = col.inner.admit(AdmissionKind::Search).expect("first slot");
+
+    let (tx, rx) = mpsc::channel();
+    let waiter = {
+        let col = col.clone();
+        thread::spawn(move || {
+            tx.send(()).expect("send");
+            col.search(&[1.0, 0.0], 1).map(|r| r.len())
+        })
+    };
+    rx.recv().expect("waiter started");
+    while col.admission_stats().searches.queue_depth == 0 {
+        thread::sleep(Duration::from_millis(1));
+    }
+    drop(held);
+
+    assert_eq!(waiter.join().expect("join").expect("admitted"), 1);
+    let stats = col.admission_stats().searches;
+    assert_eq!(stats.queued_total, 1);
+    assert_eq!(stats.rejected_total, 0);
+}
+
+#[test]
+fn test_raising_limit_admits_waiter() {
+    let (_dir, col) = collection();
+    limit(&col, 0, 1, 4, 10_000);
+    let _held = col.inner.admit(AdmissionKind::Scan).expect("first slot");
+
+    let waiter = {
+        let col = col.clone();
+        thread::spawn(move || col.scroll_batch(None, 10, None).map(|b| b.points.len()))
+    };
+    while col.admission_stats().scans.queue_depth == 0 {
+        thread::sleep(Duration::from_millis(1));
+    }
+    limit(&col, 0, 2, 4, 10_000);
+    assert_eq!(waiter.join().expect("join").expect("admitted"), 2);
+}
+
+#[test]
+fn test_velesql_queries_are_classified_by_kind() {
+    let (_dir, col) = collection();
+    limit(&col, 1, 1, 0, 0);
+    let params = HashMap::from([("v".to_string(), serde_json::json!([1.0, 0.0]))]);
+    let scan = Parser::parse("SELECT * FROM c WHERE g = 1 LIMIT 5").expect("parse");
+    let search = Parser::parse("SELECT * FROM c WHERE vector NEAR $v LIMIT 5").expect("parse");
+
+    let held_scan = col.inner.admit(AdmissionKind::Scan).expect("scan slot");
+    assert_overloaded(
+        &col.execute_query(&scan, &params)
+            .expect_err("scan slot busy"),
+    );
+    assert!(col.scroll_batch(None, 10, None).is_err());
+    assert_eq!(
+        col.execute_query(&search, &params)
+            .expect("search slot free")
+            .len(),
+        2
+    );
+    drop(held_scan);
+
+    let _held_search = col.inner.admit(AdmissionKind::Search).expect("search slot");
+    assert_overloaded(
+        &col.execute_query(&search, &params)
+            .expect_err("search slot busy"),
+    );
+    assert_eq!(
+        col.execute_query(&scan, &params)
+            .expect("scan slot free")
+            .len(),
+        1
+    );
+}
@@ -118,6 +118,27 @@ impl AnyCollection {
         self.inner().is_empty()
     }
 
+    /// Returns the sequence number of the last committed write.
+    ///
+    /// Starts at 0 when the collection is opened and increases by one per
+    /// upsert batch or delete. Scans and aggregations read one committed
+    /// sequence (see `docs/CONCURRENCY_MODEL.md`, "Read isolation").
+    #[must_use]
+    pub fn snapshot_sequence(&self) -> u64 {
+        self.inner().snapshot_sequence()
+    }
+
+    /// Subscribes to inserts, updates and deletes of the points matching
+    /// `filter` (every point when `None`). See
+    /// [`VectorCollection::subscribe`].
+    #[must_use]
+    pub fn subscribe(
+        &self,
+        filter: Option<crate::filter::Filter>,
+    ) -> crate::collection::ChangeSubscription {
+        self.inner().subscribe(filter)
+    }
+
     /// Returns `true` if this is a metadata-only collection.
     ///
     /// Equivalent to [`is_metadata`](Self::is_metadata) — kept for backward
@@ -163,6 +184,142 @@ impl AnyCollection {
         self.inner().execute_aggregate(query, params)
     }
 
+    /// Returns the most rows a single query against this collection may
+    /// request: its override when set, `limits.max_query_limit` otherwise.
+    #[must_use]
+    pub fn max_query_limit(&self) -> usize {
+        self.inner().max_query_limit()
+    }
+
+    /// Overrides `limits.max_query_limit` for this collection (`None` clears
+    /// the override) and persists it to `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `limit` is `Some(0)` or the config cannot be written.
+    pub fn set_max_query_limit(&self, limit: Option<usize>) -> Result<()> {
+        self.inner().set_max_query_limit(limit)
+    }
+
+    /// Returns the admission counters (running and queued queries,
+    /// rejections) for searches and scans on this collection.
+    #[must_use]
+    pub fn admission_stats(&self) -> crate::collection::AdmissionStats {
+        self.inner().admission_stats()
+    }
+
+    /// Claims an idempotency key for a write, identified by its full
+    /// `request` bytes (a retry must send the same bytes to be replayed).
+    ///
+    /// Keys are remembered for [`IDEMPOTENCY_WINDOW`](crate::collection::idempotency::IDEMPOTENCY_WINDOW)
+    /// and persisted with the collection; see [`IdempotencyOutcome`](crate::IdempotencyOutcome).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` is empty, longer than 255 bytes or not
+    /// printable ASCII.
+    pub fn claim_idempotency_key(
+        &self,
+        key: &str,
+        request: &[u8],
+    ) -> Result<crate::collection::IdempotencyOutcome> {
+        self.inner().claim_idempotency_key(key, request)
+    }
+
+    /// Returns the search-option defaults applied to queries that omit them.
+    #[must_use]
+    pub fn query_defaults(&self) -> crate::collection::QueryDefaults {
+        self.inner().query_defaults()
+    }
+
+    /// Replaces the collection's search-option defaults and persists them to
+    /// `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ef_search` is `Some(0)` or the config cannot be
+    /// written.
+    pub fn set_query_defaults(&self, defaults: crate::collection::QueryDefaults) -> Result<()> {
+        self.inner().set_query_defaults(defaults)
+    }
+
+    /// Returns the collection's scoring profiles, by name.
+    #[must_use]
+    pub fn scoring_profiles(
+        &self,
+    ) -> std::collections::BTreeMap<String, crate::collection::ScoringProfile> {
+        self.inner().scoring_profiles()
+    }
+
+    /// Stores a scoring profile under `name` and persists it to
+    /// `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an invalid name or profile, or if the config
+    /// cannot be written.
+    pub fn set_scoring_profile(
+        &self,
+        name: &str,
+        profile: crate::collection::ScoringProfile,
+    ) -> Result<()> {
+        self.inner().set_scoring_profile(name, profile)
+    }
+
+    /// Removes the scoring profile stored under `name`; `false` when no
+    /// such profile exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be written.
+    pub fn remove_scoring_profile(&self, name: &str) -> Result<bool> {
+        self.inner().remove_scoring_profile(name)
+    }
+
+    /// Runs `queries` under two scoring profiles and compares latency,
+    /// agreement and, given `ground_truth`, recall / MRR / NDCG.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an empty query set, an unknown profile, a
+    /// ground truth of the wrong length or a failing query.
+    pub fn compare_profiles(
+        &self,
+        queries: &[crate::collection::ProfileQuery],
+        profile_a: &str,
+        profile_b: &str,
+        ground_truth: Option<&[Vec<u64>]>,
+    ) -> Result<crate::collection::ProfileComparison> {
+        self.inner()
+            .compare_profiles(queries, profile_a, profile_b, ground_truth)
+    }
+
+    /// Builds an HNSW graph over the points matching `filter`, kept in
+    /// step with writes and searched by filtered queries it covers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an invalid or already used name, on a
+    /// metadata-only collection, or if the config cannot be written.
+    pub fn create_partial_index(&self, name: &str, filter: crate::filter::Filter) -> Result<()> {
+        self.inner().create_partial_index(name, filter)
+    }
+
+    /// Drops the partial index `name`; `false` when no such index exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be written.
+    pub fn drop_partial_index(&self, name: &str) -> Result<bool> {
+        self.inner().drop_partial_index(name)
+    }
+
+    /// Lists the partial indexes with their size and routed searches.
+    #[must_use]
+    pub fn partial_indexes(&self) -> Vec<crate::collection::PartialIndexInfo> {
+        self.inner().partial_indexes()
+    }
+
     /// Returns collection diagnostics.
     #[must_use]
     pub fn diagnostics(&self) -> crate::collection::CollectionDiagnostics {
@@ -0,0 +1,603 @@
+//! Archival tier for cold points.
+//!
+//! Long-tail data that is almost never read still costs RAM in the hot tier:
+//! HNSW graph nodes, vector storage and payloads. A collection with an
+//! [`ArchivePolicy`] records, per point, the day it was last read or written
+//! (one `u32` per point). [`archive_cold_points`](crate::VectorCollection::archive_cold_points)
+//! moves every point idle for at least `idle_days` out of the hot tier into
+//! `archive.bin`: vectors rounded to f16 with
+//! [`half_precision`](crate::half_precision), payloads kept as JSON text, the
+//! whole segment postcard-encoded.
+//!
+//! The segment is never held in memory (only its id set is). Archived points
+//! are invisible to `get`, `search` and VelesQL; they stay reachable through
+//! an exact scan of the segment on demand
+//! ([`search_archived`](crate::VectorCollection::search_archived)), and
+//! [`restore_archived`](crate::VectorCollection::restore_archived) moves them
+//! back. Upserting an archived id replaces its archived copy, deleting it
+//! removes the copy too.
+//!
+//! Access days are loaded from `access.bin` once, when the policy is set or
+//! the collection is opened with one, and kept in a sharded map: a read takes
+//! no collection-wide lock and touches the map only the first time a point
+//! is read on a given day. They are persisted on flush and after each
+//! archival run. Points without a recorded access count from the day the
+//! policy was enabled, so enabling it never archives anything before
+//! `idle_days` have passed. With
+//! [`keep_frequent`](ArchivePolicy::keep_frequent), points whose total read
+//! count reaches the threshold are never archived.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use parking_lot::{Mutex, MutexGuard};
+use serde::{Deserialize, Serialize};
+
+use crate::collection::expiry::{is_payload_expired, now_unix_secs};
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::half_precision::{VectorData, VectorPrecision};
+use crate::point::{Point, SearchResult};
+use crate::storage::VectorStorage;
+
+/// Archive segment file, in the collection directory.
+pub const ARCHIVE_FILE: &str = "archive.bin";
+
+/// Persisted access days, in the collection directory.
+const ACCESS_FILE: &str = "access.bin";
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// When points are moved to the archive tier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivePolicy {
+    /// Points not read or written for this many days are archived.
+    pub idle_days: u32,
+    /// Points read at least this many times in total stay hot, however long
+    /// they have been idle (see [`crate::collection::access_stats`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep_min_reads: Option<u32>,
+}
+
+impl ArchivePolicy {
+    /// Archives points idle for `idle_days` days.
+    #[must_use]
+    pub const fn idle_days(idle_days: u32) -> Self {
+        Self {
+            idle_days,
+            keep_min_reads: None,
+        }
+    }
+
+    /// Keeps points read at least `min_reads` times hot.
+    #[must_use]
+    pub const fn keep_frequent(mut self, min_reads: u32) -> Self {
+        self.keep_min_reads = Some(min_reads);
+        self
+    }
+}
+
+/// Outcome of an archival run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ArchiveReport {
+    /// Points moved to the archive by this run.
+    pub archived: usize,
+    /// Points in the archive after the run.
+    pub total_archived: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ArchivedPoint {
+    vector: VectorData,
+    /// Payload as JSON text (postcard cannot encode `serde_json::Value`).
+    payload: Option<String>,
+}
+
+impl ArchivedPoint {
+    fn from_point(point: &Point) -> Result<Self> {
+        let payload = point
+            .payload
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        Ok(Self {
+            vector: VectorData::from_f32_slice(&point.vector, VectorPrecision::F16),
+            payload,
+        })
+    }
+
+    fn into_point(self, id: u64) -> Point {
+        Point {
+            id,
+            vector: self.vector.to_f32_vec(),
+            payload: self
+                .payload
+                .and_then(|text| serde_json::from_str(&text).ok()),
+            sparse_vectors: None,
+        }
+    }
+}
+
+type Segment = BTreeMap<u64, ArchivedPoint>;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AccessLog {
+    /// Day tracking started; the access day of points not in `last_access`.
+    tracking_since: u32,
+    last_access: HashMap<u64, u32>,
+}
+
+/// Last access day of each point, recorded on every read and write.
+#[derive(Debug, Default)]
+struct AccessDays {
+    /// Whether the log is loaded and recording; true while a policy is set.
+    enabled: AtomicBool,
+    dirty: AtomicBool,
+    /// See [`AccessLog::tracking_since`].
+    tracking_since: AtomicU32,
+    last_access: DashMap<u64, u32>,
+    /// Serializes loading, clearing and persisting the log.
+    io: Mutex<()>,
+}
+
+#[derive(Debug, Default)]
+struct SegmentState {
+    /// Whether `ids` has been read from disk yet.
+    loaded: bool,
+    ids: HashSet<u64>,
+}
+
+/// A collection's archive tier, shared by its clones.
+///
+/// `segment` is held across a whole archival run and may be held while
+/// storage locks are taken; it is never acquired while a storage lock is
+/// held. The access log's `io` mutex is a leaf.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ArchiveTier {
+    access: Arc<AccessDays>,
+    segment: Arc<Mutex<SegmentState>>,
+}
+
+fn day_of(now_secs: u64) -> u32 {
+    u32::try_from(now_secs / SECS_PER_DAY).unwrap_or(u32::MAX)
+}
+
+fn read_segment(path: &Path) -> Result<Segment> {
+    match std::fs::read(path) {
+        Ok(bytes) => postcard::from_bytes(&bytes)
+            .map_err(|e| Error::Serialization(format!("corrupt archive {}: {e}", path.display()))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Segment::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn write_segment(path: &Path, segment: &Segment) -> Result<()> {
+    if segment.is_empty() {
+        return match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        };
+    }
+    let bytes = postcard::to_allocvec(segment).map_err(|e| Error::Serialization(e.to_string()))?;
+    crate::storage::atomic_write::atomic_write(path, &bytes)?;
+    Ok(())
+}
+
+impl AccessDays {
+    /// Loads the persisted log and starts recording. A no-op when already
+    /// recording.
+    fn enable(&self, path: &Path) {
+        let _io = self.io.lock();
+        if self.enabled.load(Ordering::Acquire) {
+            return;
+        }
+        let log = match std::fs::read(path) {
+            Ok(bytes) => match postcard::from_bytes::<AccessLog>(&bytes) {
+                Ok(log) => Some(log),
+                // Losing the log only restarts the idle clock.
+                Err(e) => {
+                    tracing::warn!(
+                        path = %path.display(),
+                        error = %e,
+                        "ignoring unreadable access log"
+                    );
+                    None
+                }
+            },
+            Err(_) => None,
+        };
+        let log = log.unwrap_or_else(|| {
+            self.dirty.store(true, Ordering::Release);
+            AccessLog {
+                tracking_since: day_of(now_unix_secs()),
+                last_access: HashMap::new(),
+            }
+        });
+        self.tracking_since
+            .store(log.tracking_since, Ordering::Relaxed);
+        self.last_access.clear();
+        for (id, day) in log.last_access {
+            self.last_access.insert(id, day);
+        }
+        self.enabled.store(true, Ordering::Release);
+    }
+
+    /// Stops recording and deletes the persisted log at `path`.
+    fn disable(&self, path: &Path) -> Result<()> {
+        let _io = self.io.lock();
+        self.enabled.store(false, Ordering::Release);
+        self.dirty.store(false, Ordering::Release);
+        self.last_access.clear();
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn record(&self, ids: impl IntoIterator<Item = u64>, today: u32) {
+        if !self.enabled.load(Ordering::Acquire) {
+            return;
+        }
+        let mut changed = false;
+        for id in ids {
+            // Only the first access of the day writes to the map.
+            if self.last_access.get(&id).is_some_and(|day| *day >= today) {
+                continue;
+            }
+            self.last_access.insert(id, today);
+            changed = true;
+        }
+        if changed {
+            self.dirty.store(true, Ordering::Release);
+        }
+    }
+
+    fn forget(&self, ids: &[u64]) {
+        for id in ids {
+            self.last_access.remove(id);
+        }
+        self.dirty.store(true, Ordering::Release);
+    }
+
+    fn last_access(&self, id: u64) -> u32 {
+        self.last_access
+            .get(&id)
+            .map_or_else(|| self.tracking_since.load(Ordering::Relaxed), |day| *day)
+    }
+
+    /// Writes the log to `path` if anything was recorded since the last call.
+    fn persist(&self, path: &Path) -> Result<()> {
+        let _io = self.io.lock();
+        if !self.enabled.load(Ordering::Acquire) || !self.dirty.swap(false, Ordering::AcqRel) {
+            return Ok(());
+        }
+        let log = AccessLog {
+            tracking_since: self.tracking_since.load(Ordering::Relaxed),
+            last_access: self
+                .last_access
+                .iter()
+                .map(|entry| (*entry.key(), *entry.value()))
+                .collect(),
+        };
+        let written = postcard::to_allocvec(&log)
+            .map_err(|e| Error::Serialization(e.to_string()))
+            .and_then(|bytes| Ok(crate::storage::atomic_write::atomic_write(path, &bytes)?));
+        if written.is_err() {
+            self.dirty.store(true, Ordering::Release);
+        }
+        written
+    }
+}
+
+impl Collection {
+    fn archive_path(&self) -> PathBuf {
+        self.storage.path.join(ARCHIVE_FILE)
+    }
+
+    fn access_log_path(&self) -> PathBuf {
+        self.storage.path.join(ACCESS_FILE)
+    }
+
+    fn archive_policy(&self) -> Option<ArchivePolicy> {
+        self.storage.config.read().archive_policy
+    }
+
+    /// Locks the segment state, loading the archived ids on first use.
+    ///
+    /// Archived ids that are live in the hot tier were restored or upserted
+    /// again before a crash could rewrite the segment: the hot copy wins and
+    /// the stale archived one is dropped.
+    fn lock_segment(&self) -> Result<MutexGuard<'_, SegmentState>> {
+        let mut state = self.runtime.archive.segment.lock();
+        if !state.loaded {
+            let path = self.archive_path();
+            let mut segment = read_segment(&path)?;
+            let before = segment.len();
+            {
+                let vector_storage = self.storage.vector_storage.read();
+                segment.retain(|id, _| !vector_storage.contains(*id));
+            }
+            if segment.len() != before {
+                write_segment(&path, &segment)?;
+            }
+            state.ids = segment.into_keys().collect();
+            state.loaded = true;
+        }
+        Ok(state)
+    }
+
+    /// Sets (or, with `None`, clears) the archive policy and persists it to
+    /// `config.json`. Clearing it stops access tracking; points already
+    /// archived stay archived.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] when `idle_days` is 0 or the collection is
+    ///   metadata-only.
+    /// - I/O errors from persisting the config (the in-memory value is
+    ///   rolled back).
+    pub(crate) fn set_archive_policy(&self, policy: Option<ArchivePolicy>) -> Result<()> {
+        if policy.is_some_and(|p| p.idle_days == 0) {
+            return Err(Error::Config(
+                "archive policy idle_days must be greater than 0".to_string(),
+            ));
+        }
+        if policy.is_some() && self.storage.config.read().metadata_only {
+            return Err(Error::Config(
+                "metadata-only collections have no vectors to archive".to_string(),
+            ));
+        }
+        let previous = std::mem::replace(&mut self.storage.config.write().archive_policy, policy);
+        if let Err(e) = self.save_config() {
+            self.storage.config.write().archive_policy = previous;
+            return Err(e);
+        }
+        if policy.is_some() {
+            // Start the idle clock now rather than at the first access.
+            self.runtime.archive.access.enable(&self.access_log_path());
+            self.persist_access_log()
+        } else {
+            self.runtime.archive.access.disable(&self.access_log_path())
+        }
+    }
+
+    /// Starts access tracking on open when the persisted config has a policy.
+    pub(crate) fn restore_access_log(&self) {
+        if self.archive_policy().is_some() {
+            self.runtime.archive.access.enable(&self.access_log_path());
+        }
+    }
+
+    /// Records a read or write of `ids` for the archive policy. A no-op when
+    /// no policy is set.
+    pub(crate) fn record_access(&self, ids: impl IntoIterator<Item = u64>) {
+        self.record_access_at(ids, now_unix_secs());
+    }
+
+    /// [`record_access`](Self::record_access) at an explicit time.
+    pub(crate) fn record_access_at(&self, ids: impl IntoIterator<Item = u64>, now_secs: u64) {
+        self.runtime.archive.access.record(ids, day_of(now_secs));
+    }
+
+    /// Persists the access days recorded since the last call.
+    pub(crate) fn persist_access_log(&self) -> Result<()> {
+        self.runtime.archive.access.persist(&self.access_log_path())
+    }
+
+    /// Copies the access days into `dir`, the directory of a rebuilt copy of
+    /// this collection.
+    pub(crate) fn copy_access_log(&self, dir: &Path) -> Result<()> {
+        self.persist_access_log()?;
+        match std::fs::copy(self.access_log_path(), dir.join(ACCESS_FILE)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Hook for the upsert paths: records the access and replaces archived
+    /// copies of the upserted ids.
+    ///
+    /// An archival run racing this upsert may have removed the freshly
+    /// written hot point after archiving its previous value; such points are
+    /// written again so the upsert is never lost.
+    pub(crate) fn note_upserted(&self, points: &[Point]) -> Result<()> {
+        self.record_access(points.iter().map(|p| p.id));
+        let mut state = self.lock_segment()?;
+        if !points.iter().any(|p| state.ids.contains(&p.id)) {
+            return Ok(());
+        }
+        let ids: Vec<u64> = points.iter().map(|p| p.id).collect();
+        self.drop_archived(&mut state, &ids)?;
+        drop(state);
+        let lost: Vec<Point> = {
+            let vector_storage = self.storage.vector_storage.read();
+            points
+                .iter()
+                .filter(|p| !vector_storage.contains(p.id))
+                .cloned()
+                .collect()
+        };
+        if lost.is_empty() {
+            Ok(())
+        } else {
+            self.upsert(lost)
+        }
+    }
+
+    /// Hook for `delete`: removes archived copies of the deleted ids.
+    pub(crate) fn forget_archived(&self, ids: &[u64]) -> Result<()> {
+        let mut state = self.lock_segment()?;
+        if !ids.iter().any(|id| state.ids.contains(id)) {
+            return Ok(());
+        }
+        self.drop_archived(&mut state, ids)
+    }
+
+    fn drop_archived(&self, state: &mut SegmentState, ids: &[u64]) -> Result<()> {
+        let path = self.archive_path();
+        let mut segment = read_segment(&path)?;
+        for id in ids {
+            segment.remove(id);
+        }
+        write_segment(&path, &segment)?;
+        for id in ids {
+            state.ids.remove(id);
+        }
+        Ok(())
+    }
+
+    /// Number of points in the archive tier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive segment cannot be read.
+    pub(crate) fn archived_count(&self) -> Result<usize> {
+        Ok(self.lock_segment()?.ids.len())
+    }
+
+    /// Moves points idle since before `now_secs - idle_days` to the archive.
+    ///
+    /// The segment is written before the points leave the hot tier, so a
+    /// crash in between leaves them in both; the hot copy wins on reopen.
+    /// Graph edges of archived points are kept.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] when no policy is set or the collection has
+    ///   sparse vectors (which the archive does not carry).
+    /// - Storage and I/O errors.
+    pub(crate) fn archive_cold_points_at(&self, now_secs: u64) -> Result<ArchiveReport> {
+        let Some(policy) = self.archive_policy() else {
+            return Err(Error::Config(
+                "no archive policy is set for this collection".to_string(),
+            ));
+        };
+        if !self.query.sparse_indexes.read().is_empty() {
+            return Err(Error::Config(
+                "collections with sparse vectors cannot be archived".to_string(),
+            ));
+        }
+        let today = day_of(now_secs);
+        let mut state = self.lock_segment()?;
+        let access = &self.runtime.archive.access;
+        access.enable(&self.access_log_path());
+        let mut cold: Vec<u64> = self
+            .all_point_ids()
+            .into_iter()
+            .filter(|&id| today.saturating_sub(access.last_access(id)) >= policy.idle_days)
+            .collect();
+        if let Some(min_reads) = policy.keep_min_reads {
+            self.retain_rarely_read(&mut cold, min_reads);
+        }
+        // `get_raw` rather than `get`: reading them here is not an access.
+        let now = now_unix_secs();
+        let points: Vec<Point> = self
+            .get_raw(&cold)
+            .into_iter()
+            .flatten()
+            .filter(|p| !is_payload_expired(p.payload.as_ref(), now))
+            .collect();
+        if points.is_empty() {
+            return Ok(ArchiveReport {
+                archived: 0,
+                total_archived: state.ids.len(),
+            });
+        }
+
+        let path = self.archive_path();
+        let mut segment = read_segment(&path)?;
+        for point in &points {
+            segment.insert(point.id, ArchivedPoint::from_point(point)?);
+        }
+        write_segment(&path, &segment)?;
+
+        let ids: Vec<u64> = points.iter().map(|p| p.id).collect();
+        self.remove_stored_points(&ids)?;
+        self.bump_generation_with_mirror_deletes(&ids);
+        state.ids.extend(ids.iter().copied());
+        // Archived points leave the log; a restore or upsert re-adds them.
+        access.forget(&ids);
+        self.persist_access_log()?;
+        Ok(ArchiveReport {
+            archived: ids.len(),
+            total_archived: state.ids.len(),
+        })
+    }
+
+    /// Exact k-NN scan of the archive segment.
+    ///
+    /// Vectors are compared at their archived (f16) precision; returned
+    /// points carry those vectors. TTL-expired points are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on a dimension mismatch or an unreadable segment.
+    pub(crate) fn search_archived(&self, query: &[f32], k: usize) -> Result<Vec<SearchResult>> {
+        let metric = self.validate_query_and_read_metric(query)?;
+        let state = self.lock_segment()?;
+        if state.ids.is_empty() || k == 0 {
+            return Ok(Vec::new());
+        }
+        let now_secs = now_unix_secs();
+        let mut scored: Vec<(Point, f32)> = read_segment(&self.archive_path())?
+            .into_iter()
+            .filter(|(id, _)| state.ids.contains(id))
+            .map(|(id, archived)| archived.into_point(id))
+            .filter(|point| !is_payload_expired(point.payload.as_ref(), now_secs))
+            .map(|point| {
+                let score = metric.calculate(query, &point.vector);
+                (point, score)
+            })
+            .collect();
+        drop(state);
+        metric.sort_results(&mut scored);
+        scored.truncate(k);
+        Ok(scored
+            .into_iter()
+            .map(|(point, score)| SearchResult::new(point, score))
+            .collect())
+    }
+
+    /// Moves archived points back to the hot tier.
+    ///
+    /// Returns how many of `ids` were archived. Restored vectors keep their
+    /// archived (f16) precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns storage and I/O errors.
+    pub(crate) fn restore_archived(&self, ids: &[u64]) -> Result<usize> {
+        let points: Vec<Point> = {
+            let mut state = self.lock_segment()?;
+            let mut segment = read_segment(&self.archive_path())?;
+            let points: Vec<Point> = ids
+                .iter()
+                .filter(|id| state.ids.contains(id))
+                .filter_map(|&id| segment.remove(&id).map(|archived| archived.into_point(id)))
+                .collect();
+            // Forget them first so the upsert hook leaves the segment alone;
+            // the file is rewritten once they are safely back in the hot tier.
+            for point in &points {
+                state.ids.remove(&point.id);
+            }
+            points
+        };
+        if points.is_empty() {
+            return Ok(0);
+        }
+        let restored: Vec<u64> = points.iter().map(|p| p.id).collect();
+        self.upsert(points)?;
+        let state = self.lock_segment()?;
+        let path = self.archive_path();
+        let mut segment = read_segment(&path)?;
+        for id in restored.iter().filter(|id| !state.ids.contains(id)) {
+            segment.remove(id);
+        }
+        write_segment(&path, &segment)?;
+        Ok(restored.len())
+    }
+}
@@ -0,0 +1,157 @@
+//! Tests for the archive tier (`archive_cold_points`).
+
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::collection::archive::ARCHIVE_FILE;
+use crate::collection::expiry::now_unix_secs;
+use crate::collection::{ArchivePolicy, VectorCollection};
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+use crate::quantization::StorageMode;
+
+const DAY: u64 = 24 * 60 * 60;
+
+fn create(path: &Path) -> VectorCollection {
+    let coll = VectorCollection::create(
+        path.to_path_buf(),
+        "c",
+        2,
+        DistanceMetric::Cosine,
+        StorageMode::Full,
+    )
+    .expect("create collection");
+    coll.set_archive_policy(Some(ArchivePolicy::idle_days(30)))
+        .expect("set policy");
+    coll.upsert(vec![
+        Point::new(1, vec![1.0, 0.0], Some(json!({ "n": 1 }))),
+        Point::new(2, vec![0.0, 1.0], Some(json!({ "n": 2 }))),
+        Point::new(3, vec![0.7, 0.7], Some(json!({ "n": 3 }))),
+        Point::new(4, vec![-1.0, 0.1], None),
+    ])
+    .expect("upsert");
+    coll
+}
+
+fn hot_ids(coll: &VectorCollection, query: &[f32]) -> Vec<u64> {
+    let mut ids: Vec<u64> = coll
+        .search(query, 10)
+        .expect("search")
+        .into_iter()
+        .map(|r| r.point.id)
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn test_archive_moves_idle_points_out_of_the_hot_tier() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("c");
+    let now = now_unix_secs();
+    {
+        let coll = create(&path);
+        assert_eq!(coll.inner.archive_cold_points_at(now).unwrap().archived, 0);
+        coll.inner.record_access_at([1], now + 20 * DAY);
+
+        let report = coll.inner.archive_cold_points_at(now + 31 * DAY).unwrap();
+
+        assert_eq!(report.archived, 3);
+        assert_eq!(report.total_archived, 3);
+        assert_eq!(coll.len(), 1);
+        assert!(coll.get(&[2])[0].is_none());
+        assert_eq!(hot_ids(&coll, &[0.0, 1.0]), vec![1]);
+        assert!(path.join(ARCHIVE_FILE).exists());
+
+        let archived = coll.search_archived(&[0.0, 1.0], 2).unwrap();
+        assert_eq!(archived.len(), 2);
+        assert_eq!(archived[0].point.id, 2);
+        assert_eq!(archived[0].point.payload, Some(json!({ "n": 2 })));
+        assert_eq!(archived[1].point.id, 3);
+    }
+
+    let coll = VectorCollection::open(path).expect("reopen");
+    assert_eq!(coll.archived_count().unwrap(), 3);
+    assert_eq!(coll.archive_policy(), Some(ArchivePolicy::idle_days(30)));
+    // Point 1's access day survived the reopen: 25 idle days is not enough.
+    let report = coll.inner.archive_cold_points_at(now + 45 * DAY).unwrap();
+    assert_eq!(report.archived, 0);
+    assert_eq!(
+        coll.search_archived(&[-1.0, 0.1], 1).unwrap()[0].point.id,
+        4
+    );
+}
+
+#[test]
+fn test_restore_upsert_and_delete_replace_archived_copies() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"));
+    let later = now_unix_secs() + 31 * DAY;
+    assert_eq!(
+        coll.inner.archive_cold_points_at(later).unwrap().archived,
+        4
+    );
+
+    assert_eq!(coll.restore_archived(&[1, 99]).unwrap(), 1);
+    let restored = coll.get(&[1])[0].clone().expect("restored point");
+    assert_eq!(restored.vector, vec![1.0, 0.0]);
+    assert_eq!(restored.payload, Some(json!({ "n": 1 })));
+
+    coll.upsert(vec![Point::new(2, vec![0.5, 0.5], None)])
+        .unwrap();
+    coll.delete(&[3]).unwrap();
+
+    assert_eq!(coll.archived_count().unwrap(), 1);
+    assert_eq!(coll.get(&[2])[0].clone().unwrap().vector, vec![0.5, 0.5]);
+    let archived: Vec<u64> = coll
+        .search_archived(&[1.0, 0.0], 10)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.point.id)
+        .collect();
+    assert_eq!(archived, vec![4]);
+
+    // The last archived point leaves an empty segment: the file goes away.
+    assert_eq!(coll.restore_archived(&[4]).unwrap(), 1);
+    assert!(!dir.path().join("c").join(ARCHIVE_FILE).exists());
+    assert_eq!(coll.len(), 3);
+}
+
+#[test]
+fn test_archive_policy_validation() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"));
+
+    assert!(matches!(
+        coll.set_archive_policy(Some(ArchivePolicy::idle_days(0))),
+        Err(Error::Config(_))
+    ));
+    coll.flush().unwrap();
+    assert!(dir.path().join("c").join("access.bin").exists());
+
+    coll.set_archive_policy(None).unwrap();
+    assert!(!dir.path().join("c").join("access.bin").exists());
+    assert!(matches!(coll.archive_cold_points(), Err(Error::Config(_))));
+    assert_eq!(coll.archived_count().unwrap(), 0);
+}
+
+#[test]
+fn test_access_days_are_loaded_once_and_kept_in_memory() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("c");
+    let now = now_unix_secs();
+    let coll = create(&path);
+    coll.flush().unwrap();
+
+    // The log on disk is not read again after the policy was set.
+    std::fs::write(path.join("access.bin"), b"not a log").unwrap();
+    coll.inner.record_access_at([1, 2], now + 20 * DAY);
+    coll.inner.record_access_at([1], now + 10 * DAY);
+
+    let report = coll.inner.archive_cold_points_at(now + 31 * DAY).unwrap();
+    assert_eq!(report.archived, 2);
+    assert!(coll.get(&[1])[0].is_some());
+    assert!(coll.get(&[2])[0].is_some());
+}
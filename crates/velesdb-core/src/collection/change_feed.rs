@@ -0,0 +1,298 @@
+//! Change feed and filtered subscriptions.
+//!
+//! Every committed write to a collection — `upsert`, `upsert_metadata`, the
+//! bulk upserts and `delete` — is published on the collection's change feed
+//! as one batch holding the before and after payload of each point.
+//! [`ChangeSubscription`]s follow the feed through an optional [`Filter`] and
+//! report changes as seen through it:
+//!
+//! - a point that starts matching (inserted, or updated into the filter) is
+//!   an [`ChangeKind::Insert`],
+//! - a point that matches before and after the write is an
+//!   [`ChangeKind::Update`],
+//! - a point that stops matching (updated out of the filter, or deleted) is
+//!   a [`ChangeKind::Delete`] carrying its last matching payload.
+//!
+//! A UI keeping a filtered list live can therefore apply events as-is.
+//!
+//! Events are not replayed: a subscription sees writes committed after it
+//! was created, and one that falls more than [`FEED_CAPACITY`] writes behind
+//! skips the writes it missed (counted by [`ChangeSubscription::skipped`]).
+//! While nobody subscribes, publishing costs one atomic load per write.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+
+use crate::collection::types::Collection;
+use crate::filter::Filter;
+use crate::storage::{PayloadStorage, VectorStorage};
+
+/// Write batches buffered per collection before slow subscribers skip.
+pub const FEED_CAPACITY: usize = 1024;
+
+/// What happened to a point, as seen through a subscription's filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ChangeKind {
+    /// The point started matching: it was inserted, or updated into the filter.
+    Insert,
+    /// The point matched before and after the write.
+    Update,
+    /// The point stopped matching: it was deleted, or updated out of the filter.
+    Delete,
+}
+
+impl ChangeKind {
+    /// Snake-case name, as serialized.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Insert => "insert",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// One point change delivered to a [`ChangeSubscription`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeEvent {
+    /// Kind of change.
+    pub kind: ChangeKind,
+    /// Point id.
+    pub id: u64,
+    /// Payload after the write; for [`ChangeKind::Delete`], the last payload
+    /// that matched.
+    pub payload: Option<Value>,
+}
+
+/// State of a point on one side of a write.
+#[derive(Debug, Clone)]
+pub(crate) enum PointState {
+    /// No such point.
+    Absent,
+    /// The point exists, with this payload.
+    Present(Option<Value>),
+}
+
+impl PointState {
+    fn payload(&self) -> Option<&Value> {
+        match self {
+            Self::Absent => None,
+            Self::Present(payload) => payload.as_ref(),
+        }
+    }
+}
+
+/// Before and after state of one point in a write.
+#[derive(Debug, Clone)]
+pub(crate) struct PointChange {
+    id: u64,
+    before: PointState,
+    after: PointState,
+}
+
+type ChangeBatch = Arc<[PointChange]>;
+
+/// Pre-write state of the points a write touches, captured only while the
+/// feed has subscribers.
+pub(crate) type FeedSnapshot = HashMap<u64, PointState>;
+
+/// Broadcast channel of committed write batches, shared by every clone of a
+/// collection. **Not persisted.**
+#[derive(Clone)]
+pub(crate) struct ChangeFeed {
+    sender: broadcast::Sender<ChangeBatch>,
+}
+
+impl Default for ChangeFeed {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(FEED_CAPACITY).0,
+        }
+    }
+}
+
+impl ChangeFeed {
+    /// Whether any subscription is listening.
+    pub(crate) fn is_active(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    fn publish(&self, batch: Vec<PointChange>) {
+        if !batch.is_empty() {
+            // No receiver is not an error: the last subscriber just left.
+            let _ = self.sender.send(batch.into());
+        }
+    }
+}
+
+/// Live stream of the changes to points matching a filter.
+///
+/// Created by `subscribe` on a collection; dropping it unsubscribes.
+pub struct ChangeSubscription {
+    receiver: broadcast::Receiver<ChangeBatch>,
+    filter: Option<Filter>,
+    pending: VecDeque<ChangeEvent>,
+    skipped: u64,
+}
+
+impl ChangeSubscription {
+    /// Waits for the next change. `None` once the collection is gone.
+    pub async fn recv(&mut self) -> Option<ChangeEvent> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(event);
+            }
+            match self.receiver.recv().await {
+                Ok(batch) => self.enqueue(&batch),
+                Err(RecvError::Lagged(missed)) => self.skipped += missed,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Returns the next change already committed, without waiting.
+    pub fn try_recv(&mut self) -> Option<ChangeEvent> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(event);
+            }
+            match self.receiver.try_recv() {
+                Ok(batch) => self.enqueue(&batch),
+                Err(TryRecvError::Lagged(missed)) => self.skipped += missed,
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Number of writes skipped because this subscription fell behind.
+    #[must_use]
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+
+    fn enqueue(&mut self, batch: &[PointChange]) {
+        for change in batch {
+            let before = self.visible(&change.before);
+            let after = self.visible(&change.after);
+            let (kind, payload) = match (before, after) {
+                (false, false) => continue,
+                (false, true) => (ChangeKind::Insert, &change.after),
+                (true, true) => (ChangeKind::Update, &change.after),
+                (true, false) => (ChangeKind::Delete, &change.before),
+            };
+            self.pending.push_back(ChangeEvent {
+                kind,
+                id: change.id,
+                payload: payload.payload().cloned(),
+            });
+        }
+    }
+
+    /// Whether a point in `state` is visible through the filter. Points
+    /// without a payload are matched against `null`.
+    fn visible(&self, state: &PointState) -> bool {
+        match state {
+            PointState::Absent => false,
+            PointState::Present(payload) => self
+                .filter
+                .as_ref()
+                .is_none_or(|f| f.matches(payload.as_ref().unwrap_or(&Value::Null))),
+        }
+    }
+}
+
+impl Collection {
+    /// Subscribes to changes of the points matching `filter` (every point
+    /// when `None`). See the [module docs](self) for the event semantics.
+    pub(crate) fn subscribe(&self, filter: Option<Filter>) -> ChangeSubscription {
+        ChangeSubscription {
+            receiver: self.streaming.change_feed.sender.subscribe(),
+            filter,
+            pending: VecDeque::new(),
+            skipped: 0,
+        }
+    }
+
+    /// Captures the pre-write state of `ids` for the change feed; `None`,
+    /// without touching storage, while nobody subscribes.
+    pub(crate) fn feed_snapshot(&self, ids: impl IntoIterator<Item = u64>) -> Option<FeedSnapshot> {
+        if !self.streaming.change_feed.is_active() {
+            return None;
+        }
+        let metadata_only = self.storage.config.read().metadata_only;
+        // LOCK ORDER: vector_storage(2) → payload_storage(3), both read.
+        let vectors = self.storage.vector_storage.read();
+        let payloads = self.storage.payload_storage.read();
+        let snapshot = ids
+            .into_iter()
+            .map(|id| {
+                let payload = payloads.retrieve(id).ok().flatten();
+                let exists = payload.is_some() || (!metadata_only && vectors.contains(id));
+                let state = if exists {
+                    PointState::Present(payload)
+                } else {
+                    PointState::Absent
+                };
+                (id, state)
+            })
+            .collect();
+        Some(snapshot)
+    }
+
+    /// Publishes an upsert of `(id, payload)` pairs in write order.
+    ///
+    /// With `keep_payload_when_none`, a `None` payload leaves the stored
+    /// payload unchanged (raw bulk import) instead of clearing it.
+    pub(crate) fn publish_upserts<'a>(
+        &self,
+        snapshot: Option<FeedSnapshot>,
+        points: impl IntoIterator<Item = (u64, Option<&'a Value>)>,
+        keep_payload_when_none: bool,
+    ) {
+        let Some(mut state) = snapshot else {
+            return;
+        };
+        let batch = points
+            .into_iter()
+            .map(|(id, payload)| {
+                let before = state.get(&id).cloned().unwrap_or(PointState::Absent);
+                let payload = match payload {
+                    Some(payload) => Some(payload.clone()),
+                    None if keep_payload_when_none => before.payload().cloned(),
+                    None => None,
+                };
+                let after = PointState::Present(payload);
+                // Later duplicates of `id` in the batch start from this write.
+                state.insert(id, after.clone());
+                PointChange { id, before, after }
+            })
+            .collect();
+        self.streaming.change_feed.publish(batch);
+    }
+
+    /// Publishes the deletion of `ids`; ids that did not exist are skipped.
+    pub(crate) fn publish_deletes(&self, snapshot: Option<FeedSnapshot>, ids: &[u64]) {
+        let Some(mut state) = snapshot else {
+            return;
+        };
+        let batch = ids
+            .iter()
+            .filter_map(|id| {
+                let before = state.insert(*id, PointState::Absent)?;
+                matches!(before, PointState::Present(_)).then_some(PointChange {
+                    id: *id,
+                    before,
+                    after: PointState::Absent,
+                })
+            })
+            .collect();
+        self.streaming.change_feed.publish(batch);
+    }
+}
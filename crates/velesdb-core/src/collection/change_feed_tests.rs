@@ -0,0 +1,123 @@
+//! Tests for the change feed and filtered subscriptions.
+
+use serde_json::json;
+
+use crate::collection::change_feed::FEED_CAPACITY;
+use crate::collection::{ChangeEvent, ChangeKind, ChangeSubscription, MetadataCollection};
+use crate::filter::{Condition, Filter};
+use crate::point::Point;
+use crate::test_fixtures::fixtures::{make_point_with_payload, setup_collection};
+
+fn point(id: u64, status: &str) -> Point {
+    make_point_with_payload(id, vec![1.0, 0.0], json!({ "status": status }))
+}
+
+fn open_only() -> Filter {
+    Filter::new(Condition::eq("status", "open"))
+}
+
+fn drain(sub: &mut ChangeSubscription) -> Vec<(ChangeKind, u64)> {
+    std::iter::from_fn(|| sub.try_recv())
+        .map(|e| (e.kind, e.id))
+        .collect()
+}
+
+#[test]
+fn test_subscription_sees_changes_through_filter() {
+    let (_dir, col) = setup_collection(2);
+    col.upsert(vec![point(1, "open")]).expect("upsert");
+    let mut sub = col.subscribe(Some(open_only()));
+
+    col.upsert(vec![point(2, "open"), point(3, "closed")])
+        .expect("upsert");
+    // 1 stays open, 2 is updated out of the filter, 3 into it.
+    col.upsert(vec![point(1, "open"), point(2, "closed"), point(3, "open")])
+        .expect("upsert");
+    col.delete(&[1, 2, 99]).expect("delete");
+
+    assert_eq!(
+        drain(&mut sub),
+        vec![
+            (ChangeKind::Insert, 2),
+            (ChangeKind::Update, 1),
+            (ChangeKind::Delete, 2),
+            (ChangeKind::Insert, 3),
+            (ChangeKind::Delete, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_delete_event_carries_last_matching_payload() {
+    let (_dir, col) = setup_collection(2);
+    col.upsert(vec![point(1, "open")]).expect("upsert");
+    let mut sub = col.subscribe(Some(open_only()));
+    col.upsert(vec![point(1, "closed")]).expect("upsert");
+    assert_eq!(
+        sub.try_recv(),
+        Some(ChangeEvent {
+            kind: ChangeKind::Delete,
+            id: 1,
+            payload: Some(json!({ "status": "open" })),
+        })
+    );
+}
+
+#[test]
+fn test_unfiltered_subscription_and_batch_duplicates() {
+    let (_dir, col) = setup_collection(2);
+    let mut sub = col.subscribe(None);
+    col.upsert_bulk(&[point(1, "a"), point(1, "b")])
+        .expect("bulk upsert");
+    col.upsert_bulk_from_raw(&[0.0, 1.0], &[1], 2, None)
+        .expect("raw upsert");
+
+    let events: Vec<_> = std::iter::from_fn(|| sub.try_recv()).collect();
+    let kinds: Vec<_> = events.iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![ChangeKind::Insert, ChangeKind::Update, ChangeKind::Update]
+    );
+    // A raw upsert without payloads keeps the stored payload.
+    assert_eq!(events[2].payload, Some(json!({ "status": "b" })));
+}
+
+#[test]
+fn test_metadata_collection_publishes_changes() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = MetadataCollection::create(dir.path().join("m"), "m").expect("create");
+    let mut sub = col.inner.subscribe(Some(open_only()));
+    col.upsert(vec![Point::metadata_only(1, json!({ "status": "open" }))])
+        .expect("upsert");
+    col.delete(&[1]).expect("delete");
+    assert_eq!(
+        drain(&mut sub),
+        vec![(ChangeKind::Insert, 1), (ChangeKind::Delete, 1)]
+    );
+}
+
+#[test]
+fn test_lagging_subscription_counts_skipped_writes() {
+    let (_dir, col) = setup_collection(2);
+    let mut sub = col.subscribe(None);
+    let writes = FEED_CAPACITY as u64 + 5;
+    for id in 0..writes {
+        col.upsert(vec![point(id, "open")]).expect("upsert");
+    }
+    let received = drain(&mut sub).len() as u64;
+    assert_eq!(sub.skipped(), 5);
+    assert_eq!(received, writes - 5);
+}
+
+#[tokio::test]
+async fn test_recv_waits_for_next_write() {
+    let (_dir, col) = setup_collection(2);
+    let mut sub = col.subscribe(None);
+    let writer = col.clone();
+    tokio::task::spawn_blocking(move || writer.upsert(vec![point(7, "open")]))
+        .await
+        .expect("join")
+        .expect("upsert");
+    let event = sub.recv().await.expect("event");
+    assert_eq!((event.kind, event.id), (ChangeKind::Insert, 7));
+}
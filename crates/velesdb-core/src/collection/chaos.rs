@@ -0,0 +1,483 @@
+//! Fault injection and concurrent upsert stress runs (`chaos` feature).
+//!
+//! [`VectorCollection::with_fault_injection`](crate::VectorCollection::with_fault_injection)
+//! installs a [`FaultPolicy`] that delays or fails chosen storage and index
+//! steps ([`FaultPoint`]), so tests and CI can check what a crash or a slow
+//! disk at that step leaves behind: a failed [`FaultPoint::StoreVectors`]
+//! leaves the batch's payloads next to the previous vectors, a failed
+//! [`FaultPoint::IndexInsert`] leaves stored vectors that only the gap
+//! detection of the next open puts back in the HNSW index.
+//!
+//! [`VectorCollection::run_upsert_stress`](crate::VectorCollection::run_upsert_stress)
+//! races writers upserting overlapping ids against readers, then checks the
+//! invariants every interleaving must keep (see [`StressReport`]).
+//!
+//! The policy is runtime-only and shared by every handle of the collection.
+//! Never enable the `chaos` feature in production builds.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::{Mutex, RwLock};
+use serde_json::json;
+
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::point::Point;
+
+/// A storage or index step where a fault can be injected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FaultPoint {
+    /// Before an upsert writes its payloads.
+    StorePayloads,
+    /// Before an upsert writes its vectors, after the payloads are stored.
+    StoreVectors,
+    /// Before stored vectors are inserted into the HNSW index.
+    IndexInsert,
+    /// Before a delete removes points from storage.
+    Delete,
+    /// Before a flush writes storage and indexes to disk.
+    Flush,
+}
+
+impl FaultPoint {
+    /// Every fault point.
+    pub const ALL: [Self; 5] = [
+        Self::StorePayloads,
+        Self::StoreVectors,
+        Self::IndexInsert,
+        Self::Delete,
+        Self::Flush,
+    ];
+
+    /// Stable name, used in injected error messages.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::StorePayloads => "store_payloads",
+            Self::StoreVectors => "store_vectors",
+            Self::IndexInsert => "index_insert",
+            Self::Delete => "delete",
+            Self::Flush => "flush",
+        }
+    }
+
+    const fn slot(self) -> usize {
+        self as usize
+    }
+}
+
+impl std::fmt::Display for FaultPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What happens when a rule fires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FaultAction {
+    Delay(Duration),
+    Fail,
+}
+
+/// When a rule fires, counted in hits of its fault point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Trigger {
+    Always,
+    /// The zero-based hit, once.
+    Nth(u64),
+    Probability(f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FaultRule {
+    point: FaultPoint,
+    action: FaultAction,
+    trigger: Trigger,
+}
+
+/// Faults to inject, built rule by rule.
+///
+/// Rules are checked in the order they were added; delays accumulate and
+/// the first failing rule aborts the step with an [`Error::Io`].
+///
+/// ```ignore
+/// use std::time::Duration;
+/// use velesdb_core::collection::chaos::{FaultPoint, FaultPolicy};
+///
+/// let policy = FaultPolicy::new()
+///     .delay(FaultPoint::StoreVectors, Duration::from_millis(5))
+///     .fail_nth(FaultPoint::IndexInsert, 2)
+///     .fail_with_probability(FaultPoint::StorePayloads, 0.1)
+///     .with_seed(7);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaultPolicy {
+    rules: Vec<FaultRule>,
+    seed: u64,
+}
+
+impl Default for FaultPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FaultPolicy {
+    /// A policy without rules.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            seed: 0x5EED,
+        }
+    }
+
+    /// Sleeps for `delay` every time `point` is reached.
+    #[must_use]
+    pub fn delay(self, point: FaultPoint, delay: Duration) -> Self {
+        self.rule(point, FaultAction::Delay(delay), Trigger::Always)
+    }
+
+    /// Fails every time `point` is reached.
+    #[must_use]
+    pub fn fail_always(self, point: FaultPoint) -> Self {
+        self.rule(point, FaultAction::Fail, Trigger::Always)
+    }
+
+    /// Fails the `nth` (zero-based) time `point` is reached, once.
+    #[must_use]
+    pub fn fail_nth(self, point: FaultPoint, nth: u64) -> Self {
+        self.rule(point, FaultAction::Fail, Trigger::Nth(nth))
+    }
+
+    /// Fails each time `point` is reached with `probability` (clamped to
+    /// `[0, 1]`), drawn from the policy's seeded generator.
+    #[must_use]
+    pub fn fail_with_probability(self, point: FaultPoint, probability: f64) -> Self {
+        let probability = probability.clamp(0.0, 1.0);
+        self.rule(point, FaultAction::Fail, Trigger::Probability(probability))
+    }
+
+    /// Seeds the generator behind probabilistic rules, for reproducible runs.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    fn rule(mut self, point: FaultPoint, action: FaultAction, trigger: Trigger) -> Self {
+        self.rules.push(FaultRule {
+            point,
+            action,
+            trigger,
+        });
+        self
+    }
+}
+
+/// An installed policy with its hit counters and generator state.
+struct ActivePolicy {
+    policy: FaultPolicy,
+    hits: [AtomicU64; FaultPoint::ALL.len()],
+    rng: Mutex<u64>,
+}
+
+impl ActivePolicy {
+    fn new(policy: FaultPolicy) -> Self {
+        // xorshift state must not be zero.
+        let rng = Mutex::new(policy.seed.max(1));
+        Self {
+            policy,
+            hits: Default::default(),
+            rng,
+        }
+    }
+
+    /// Uniform draw in `[0, 1)` (xorshift64).
+    fn next_unit(&self) -> f64 {
+        let mut state = self.rng.lock();
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        #[allow(clippy::cast_precision_loss)] // Reason: top 53 bits fit an f64 mantissa
+        let unit = (*state >> 11) as f64 / (1u64 << 53) as f64;
+        unit
+    }
+
+    fn hit(&self, point: FaultPoint) -> Result<()> {
+        let hit = self.hits[point.slot()].fetch_add(1, Ordering::Relaxed);
+        for rule in self.policy.rules.iter().filter(|r| r.point == point) {
+            let fires = match rule.trigger {
+                Trigger::Always => true,
+                Trigger::Nth(nth) => hit == nth,
+                Trigger::Probability(p) => self.next_unit() < p,
+            };
+            if !fires {
+                continue;
+            }
+            match rule.action {
+                FaultAction::Delay(delay) => std::thread::sleep(delay),
+                FaultAction::Fail => {
+                    return Err(Error::Io(std::io::Error::other(format!(
+                        "fault injected at {point} (chaos feature)"
+                    ))));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Slot for the collection's fault policy, shared by its clones.
+///
+/// The lock is a leaf: it is only held to clone or replace the policy,
+/// never while a delay sleeps.
+#[derive(Clone, Default)]
+pub(crate) struct FaultInjector {
+    active: Arc<RwLock<Option<Arc<ActivePolicy>>>>,
+}
+
+impl FaultInjector {
+    /// Installs `policy` with fresh hit counters.
+    pub(crate) fn set(&self, policy: FaultPolicy) {
+        *self.active.write() = Some(Arc::new(ActivePolicy::new(policy)));
+    }
+
+    /// Removes the installed policy.
+    pub(crate) fn clear(&self) {
+        *self.active.write() = None;
+    }
+
+    /// Times `point` was reached since the policy was installed.
+    pub(crate) fn hits(&self, point: FaultPoint) -> u64 {
+        self.active.read().as_ref().map_or(0, |active| {
+            active.hits[point.slot()].load(Ordering::Relaxed)
+        })
+    }
+
+    /// Applies the policy at `point`; always `Ok` without a policy.
+    pub(crate) fn hit(&self, point: FaultPoint) -> Result<()> {
+        let active = self.active.read().clone();
+        active.map_or(Ok(()), |active| active.hit(point))
+    }
+}
+
+/// Shape of a [`run_upsert_stress`](crate::VectorCollection::run_upsert_stress) run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StressOptions {
+    /// Threads upserting concurrently.
+    pub writers: usize,
+    /// Threads reading concurrently.
+    pub readers: usize,
+    /// Upsert batches per writer.
+    pub batches_per_writer: usize,
+    /// Points per batch.
+    pub batch_size: usize,
+    /// Writers upsert ids `0..id_space`; a small space forces overlapping
+    /// writes to the same ids.
+    pub id_space: u64,
+}
+
+impl Default for StressOptions {
+    fn default() -> Self {
+        Self {
+            writers: 4,
+            readers: 2,
+            batches_per_writer: 25,
+            batch_size: 16,
+            id_space: 64,
+        }
+    }
+}
+
+/// Outcome of a stress run.
+///
+/// Every point a writer stores carries the same stamp in its payload
+/// (`_stress_stamp`) and in the ratio of its first two vector components;
+/// a point whose two stamps differ mixes two writes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StressReport {
+    /// Batches that committed.
+    pub batches_committed: usize,
+    /// Batches rejected by an injected fault.
+    pub batches_failed: usize,
+    /// Point reads and scans performed by the readers.
+    pub reads: usize,
+    /// Points read with a payload from one write and a vector from another.
+    pub torn_reads: usize,
+    /// Stored points mixing two writes after the run; only expected when
+    /// [`FaultPoint::StoreVectors`] faults fired.
+    pub torn_points: usize,
+    /// Stored points absent from the HNSW index (deferred indexing, or a
+    /// [`FaultPoint::IndexInsert`] fault before the next open).
+    pub missing_from_index: usize,
+    /// Points stored at the end of the run.
+    pub point_count: usize,
+    /// Wall-clock duration of the run.
+    pub elapsed: Duration,
+}
+
+impl StressReport {
+    /// Whether no reader and no stored point ever saw a torn write.
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.torn_reads == 0 && self.torn_points == 0
+    }
+}
+
+const STAMP_KEY: &str = "_stress_stamp";
+
+/// Relative tolerance of the vector stamp (quantized and normalized storage).
+const STAMP_TOLERANCE: f64 = 1e-3;
+
+fn stress_point(id: u64, stamp: u32, dimension: usize) -> Point {
+    let mut vector = vec![1.0; dimension];
+    #[allow(clippy::cast_precision_loss)] // Reason: stamps stay far below 2^24
+    let stamp_f = stamp as f32;
+    vector[0] = stamp_f;
+    Point::new(id, vector, Some(json!({ STAMP_KEY: stamp })))
+}
+
+/// Whether `point`'s payload stamp disagrees with its vector stamp. Points
+/// without a stress payload are not stress writes and never torn.
+fn is_torn(point: &Point) -> bool {
+    let Some(stamp) = point
+        .payload
+        .as_ref()
+        .and_then(|p| p.get(STAMP_KEY))
+        .and_then(serde_json::Value::as_f64)
+    else {
+        return false;
+    };
+    let (Some(&first), Some(&second)) = (point.vector.first(), point.vector.get(1)) else {
+        return true;
+    };
+    if second == 0.0 {
+        return true;
+    }
+    let ratio = f64::from(first) / f64::from(second);
+    (ratio - stamp).abs() > STAMP_TOLERANCE * stamp.max(1.0)
+}
+
+impl Collection {
+    /// Installs `policy`, replacing any previous one.
+    pub(crate) fn set_fault_policy(&self, policy: FaultPolicy) {
+        self.runtime.faults.set(policy);
+    }
+
+    /// Applies the installed fault policy at `point`.
+    pub(crate) fn inject_fault(&self, point: FaultPoint) -> Result<()> {
+        self.runtime.faults.hit(point)
+    }
+
+    /// Races writers against readers; see [`StressReport`].
+    pub(crate) fn run_upsert_stress(&self, options: &StressOptions) -> Result<StressReport> {
+        let dimension = self.config().dimension;
+        if dimension < 2 {
+            return Err(Error::Config(
+                "upsert stress needs a dimension of at least 2".to_string(),
+            ));
+        }
+        let id_space = options.id_space.max(1);
+        let start = Instant::now();
+        let committed = AtomicUsize::new(0);
+        let failed = AtomicUsize::new(0);
+        let reads = AtomicUsize::new(0);
+        let torn_reads = AtomicUsize::new(0);
+        let writers_done = AtomicBool::new(false);
+        let error: Mutex<Option<Error>> = Mutex::new(None);
+
+        std::thread::scope(|s| {
+            let writers: Vec<_> = (0..options.writers)
+                .map(|writer| {
+                    let (committed, failed, error) = (&committed, &failed, &error);
+                    s.spawn(move || {
+                        for batch in 0..options.batches_per_writer {
+                            let stamp = stress_stamp(writer, batch, options.batches_per_writer);
+                            let points = (0..options.batch_size)
+                                .map(|i| {
+                                    let offset = u64::try_from(i).unwrap_or(u64::MAX);
+                                    let id = (u64::from(stamp) * 7).wrapping_add(offset) % id_space;
+                                    stress_point(id, stamp, dimension)
+                                })
+                                .collect::<Vec<_>>();
+                            match self.upsert(points) {
+                                Ok(()) => committed.fetch_add(1, Ordering::Relaxed),
+                                Err(e) if is_injected(&e) => failed.fetch_add(1, Ordering::Relaxed),
+                                Err(e) => {
+                                    error.lock().get_or_insert(e);
+                                    return;
+                                }
+                            };
+                        }
+                    })
+                })
+                .collect();
+            for _ in 0..options.readers {
+                s.spawn(|| {
+                    let ids: Vec<u64> = (0..id_space).collect();
+                    while !writers_done.load(Ordering::Acquire) {
+                        let torn = self
+                            .get(&ids)
+                            .iter()
+                            .flatten()
+                            .filter(|p| is_torn(p))
+                            .count();
+                        torn_reads.fetch_add(torn, Ordering::Relaxed);
+                        reads.fetch_add(1, Ordering::Relaxed);
+                        std::thread::yield_now();
+                    }
+                });
+            }
+            for writer in writers {
+                let _ = writer.join();
+            }
+            writers_done.store(true, Ordering::Release);
+        });
+
+        if let Some(e) = error.into_inner() {
+            return Err(e);
+        }
+        Ok(self.verify_stress_state(StressReport {
+            batches_committed: committed.into_inner(),
+            batches_failed: failed.into_inner(),
+            reads: reads.into_inner(),
+            torn_reads: torn_reads.into_inner(),
+            torn_points: 0,
+            missing_from_index: 0,
+            point_count: 0,
+            elapsed: start.elapsed(),
+        }))
+    }
+
+    /// Fills the post-run fields of `report` from the stored points.
+    fn verify_stress_state(&self, mut report: StressReport) -> StressReport {
+        let snapshot = self.read_snapshot();
+        let ids = crate::storage::VectorStorage::ids(&*snapshot.vectors);
+        drop(snapshot);
+        let points = self.get(&ids);
+        for point in points.iter().flatten() {
+            if is_torn(point) {
+                report.torn_points += 1;
+            }
+            if !self.storage.index.mappings.contains(point.id) {
+                report.missing_from_index += 1;
+            }
+        }
+        report.point_count = ids.len();
+        report
+    }
+}
+
+/// Distinct, non-zero stamp of one writer batch.
+fn stress_stamp(writer: usize, batch: usize, batches_per_writer: usize) -> u32 {
+    u32::try_from(writer * batches_per_writer + batch + 1).unwrap_or(u32::MAX)
+}
+
+/// Whether `error` was produced by the fault injector.
+fn is_injected(error: &Error) -> bool {
+    matches!(error, Error::Io(e) if e.to_string().contains("(chaos feature)"))
+}
@@ -0,0 +1,134 @@
+//! Tests for fault injection and the upsert stress run (`chaos` feature).
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::collection::chaos::{FaultPoint, FaultPolicy, StressOptions};
+use crate::collection::VectorCollection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+use crate::quantization::StorageMode;
+
+fn create(path: &Path) -> VectorCollection {
+    VectorCollection::create(
+        path.to_path_buf(),
+        "c",
+        2,
+        DistanceMetric::Euclidean,
+        StorageMode::Full,
+    )
+    .expect("create collection")
+}
+
+#[test]
+fn test_failed_store_vectors_fires_once_and_leaves_new_payload() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"));
+    coll.upsert(vec![Point::new(1, vec![1.0, 0.0], Some(json!({ "v": 1 })))])
+        .expect("seed");
+
+    let coll = coll.with_fault_injection(FaultPolicy::new().fail_nth(FaultPoint::StoreVectors, 0));
+    let err = coll
+        .upsert(vec![Point::new(1, vec![2.0, 0.0], Some(json!({ "v": 2 })))])
+        .expect_err("injected failure");
+    assert!(matches!(err, Error::Io(_)), "{err}");
+    assert_eq!(coll.fault_hits(FaultPoint::StoreVectors), 1);
+
+    // The payload write committed before the vector write failed.
+    let point = coll.get(&[1]).remove(0).expect("point 1");
+    assert_eq!(point.payload, Some(json!({ "v": 2 })));
+    assert_eq!(point.vector, vec![1.0, 0.0]);
+
+    // `fail_nth` fires once: the retry goes through.
+    coll.upsert(vec![Point::new(1, vec![2.0, 0.0], Some(json!({ "v": 2 })))])
+        .expect("retry");
+    assert_eq!(
+        coll.get(&[1]).remove(0).expect("point 1").vector,
+        vec![2.0, 0.0]
+    );
+}
+
+#[test]
+fn test_index_insert_fault_is_repaired_on_reopen() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("c");
+    {
+        let coll = create(&path)
+            .with_fault_injection(FaultPolicy::new().fail_always(FaultPoint::IndexInsert));
+        coll.upsert(vec![Point::new(7, vec![0.5, 0.5], None)])
+            .expect_err("injected failure");
+        assert!(
+            coll.get(&[7])[0].is_some(),
+            "vector stored before the fault"
+        );
+        assert!(!coll.inner.storage.index.mappings.contains(7));
+        coll.clear_fault_injection();
+        assert_eq!(coll.fault_hits(FaultPoint::IndexInsert), 0);
+        coll.flush().expect("flush");
+    }
+    let coll = VectorCollection::open(path).expect("reopen");
+    assert!(coll.inner.storage.index.mappings.contains(7));
+}
+
+#[test]
+fn test_flush_and_delete_faults_abort_before_any_write() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c")).with_fault_injection(
+        FaultPolicy::new()
+            .fail_always(FaultPoint::Delete)
+            .fail_always(FaultPoint::Flush),
+    );
+    coll.upsert(vec![Point::new(1, vec![1.0, 0.0], None)])
+        .expect("upsert");
+    assert!(coll.delete(&[1]).is_err());
+    assert!(coll.flush().is_err());
+    assert!(coll.get(&[1])[0].is_some());
+}
+
+#[test]
+fn test_upsert_stress_with_delays_and_payload_faults_stays_consistent() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c")).with_fault_injection(
+        FaultPolicy::new()
+            .delay(FaultPoint::StoreVectors, Duration::from_millis(1))
+            .fail_with_probability(FaultPoint::StorePayloads, 0.2)
+            .with_seed(42),
+    );
+    let options = StressOptions {
+        writers: 3,
+        readers: 2,
+        batches_per_writer: 10,
+        batch_size: 8,
+        id_space: 16,
+    };
+    let report = coll.run_upsert_stress(&options).expect("stress run");
+
+    assert!(report.is_consistent(), "{report:?}");
+    assert_eq!(report.batches_committed + report.batches_failed, 30);
+    assert!(report.batches_failed > 0, "{report:?}");
+    assert!(report.reads > 0);
+    assert_eq!(report.missing_from_index, 0);
+    assert!(report.point_count <= 16);
+}
+
+#[test]
+fn test_upsert_stress_reports_torn_points_after_store_vectors_fault() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"))
+        .with_fault_injection(FaultPolicy::new().fail_nth(FaultPoint::StoreVectors, 5));
+    let options = StressOptions {
+        writers: 1,
+        readers: 0,
+        batches_per_writer: 6,
+        batch_size: 8,
+        id_space: 16,
+    };
+    let report = coll.run_upsert_stress(&options).expect("stress run");
+
+    assert_eq!(report.batches_failed, 1);
+    assert!(report.torn_points > 0, "{report:?}");
+    assert!(!report.is_consistent());
+}
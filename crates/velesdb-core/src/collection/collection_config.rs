@@ -1,12 +1,16 @@
 //! Collection configuration and schema versioning.
 
 use crate::collection::auto_reindex::AutoReindexConfig;
+use crate::collection::embedding_info::EmbeddingInfo;
+use crate::collection::query_defaults::QueryDefaults;
+use crate::collection::scoring_profile::ScoringProfile;
 use crate::collection::streaming::AsyncIndexBuilderConfig;
 use crate::distance::DistanceMetric;
 use crate::index::hnsw::HnswParams;
 use crate::quantization::StorageMode;
+use crate::vector_layout::VectorLayout;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::collection::graph::GraphSchema;
 
@@ -178,6 +182,77 @@ pub struct CollectionConfig {
     /// exhaustive fallback); no schema-version bump guards it, by design.
     #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
     pub indexed_fields: BTreeSet<String>,
+
+    /// Embedding model that produced this collection's vectors.
+    ///
+    /// Set via `VectorCollection::set_embedding_info`; consulted by
+    /// `check_embedding_model` when a client declares the model it used, so a
+    /// silent model switch (same dimension, different vector space) is
+    /// rejected instead of returning meaningless neighbours.
+    ///
+    /// Backward compatible: configs written before this field deserialize to
+    /// `None` (no model recorded, every declaration accepted).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_info: Option<EmbeddingInfo>,
+
+    /// Per-collection override of `limits.max_query_limit`: the most rows a
+    /// single query against this collection may request.
+    ///
+    /// Set via `Collection::set_max_query_limit`. `None` (and configs
+    /// written before this field existed) falls back to the database-wide
+    /// limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_query_limit: Option<usize>,
+
+    /// Search-option defaults (`ef_search`, `rerank`, fusion strategy)
+    /// applied to every SELECT that does not set them itself.
+    ///
+    /// Set via `Collection::set_query_defaults` or `ALTER COLLECTION ... SET`.
+    /// Configs written before this field existed deserialize to no defaults,
+    /// and an empty value is not serialized.
+    #[serde(default, skip_serializing_if = "QueryDefaults::is_empty")]
+    pub query_defaults: QueryDefaults,
+
+    /// Named scoring profiles a query selects with `WITH (profile = '...')`.
+    ///
+    /// Set via `Collection::set_scoring_profile`. Configs written before this
+    /// field existed deserialize to no profiles, and an empty map is not
+    /// serialized.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scoring_profiles: BTreeMap<String, ScoringProfile>,
+
+    /// Partial vector indexes, by name: each keeps an HNSW graph over the
+    /// points matching its filter.
+    ///
+    /// Set via `Collection::create_partial_index`; the graphs are rebuilt
+    /// from storage on open. Configs written before this field existed
+    /// deserialize to no partial indexes, and an empty map is not serialized.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub partial_indexes: BTreeMap<String, crate::filter::Filter>,
+
+    /// When idle points move to the archive tier.
+    ///
+    /// Set via `Collection::set_archive_policy`. `None` (and configs written
+    /// before this field existed) disables access tracking and archival.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_policy: Option<crate::collection::ArchivePolicy>,
+
+    /// Layout exact scans score vectors in.
+    ///
+    /// Set via `Collection::set_vector_layout`. Configs written before this
+    /// field existed deserialize to the AoS default, which is not serialized.
+    #[serde(default, skip_serializing_if = "VectorLayout::is_default")]
+    pub vector_layout: VectorLayout,
+
+    /// Keeps graph edge properties in an on-disk log under
+    /// `edge_properties/` instead of RAM; only the edge topology stays
+    /// resident.
+    ///
+    /// Set via `Collection::set_edge_properties_on_disk`. Configs written
+    /// before this field existed deserialize to `false`, which is not
+    /// serialized.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub edge_properties_on_disk: bool,
 }
 
 #[cfg(test)]
@@ -206,6 +281,14 @@ mod rescore_config_tests {
             #[cfg(feature = "persistence")]
             streaming_config: None,
             indexed_fields: BTreeSet::new(),
+            embedding_info: None,
+            max_query_limit: None,
+            query_defaults: QueryDefaults::default(),
+            scoring_profiles: BTreeMap::new(),
+            partial_indexes: BTreeMap::new(),
+            archive_policy: None,
+            vector_layout: VectorLayout::default(),
+            edge_properties_on_disk: false,
         }
     }
 
@@ -4,11 +4,15 @@
 //! These methods accept flat contiguous slices (zero-copy from numpy / FFI)
 //! instead of `Point` structs, avoiding per-row `Vec<f32>` allocation.
 
+use crate::collection::index_progress::IndexBuildPhase::StoringVectors;
 use crate::collection::types::Collection;
+use crate::config::NonFiniteVectorPolicy;
 use crate::error::{Error, Result};
+use crate::point::Point;
 use crate::storage::{PayloadStorage, VectorStorage};
-use crate::validation::validate_dimension_match;
+use crate::validation::{validate_dimension_match, validate_vector_finite, validate_vector_norm};
 
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 impl Collection {
@@ -26,8 +30,12 @@ impl Collection {
     ///
     /// # Errors
     ///
-    /// - Returns [`crate::error::Error::InvalidVector`] if `vectors.len() != ids.len() * dimension`.
+    /// - Returns [`crate::error::Error::InvalidVector`] if `vectors.len() != ids.len() * dimension`,
+    ///   or if a row holds non-finite components (they are zeroed instead when
+    ///   `limits.non_finite_vectors = "sanitize_to_zero"`) or is all zeros on a
+    ///   cosine collection with `limits.reject_zero_norm_vectors` set.
     /// - Returns [`crate::error::Error::DimensionMismatch`] if `dimension` does not match the collection.
+    /// - Returns the validator's error if the upsert validator rejects a row.
     pub fn upsert_bulk_from_raw(
         &self,
         vectors: &[f32],
@@ -47,6 +55,8 @@ impl Collection {
         // Validate inputs and enforce the runtime ingest limits (parity item E)
         // BEFORE any state mutation, so the caps are not bypassable on this
         // zero-copy path (the dominant Python/REST bulk upsert surface).
+        let vectors = self.apply_raw_non_finite_policy(vectors);
+        let vectors: &[f32] = &vectors;
         self.validate_raw_inputs(vectors, ids, dimension, payloads)?;
 
         // Build (id, &[f32]) pairs by slicing the flat buffer -- zero copy.
@@ -56,6 +66,8 @@ impl Collection {
             .map(|(i, &id)| (id, &vectors[i * dimension..(i + 1) * dimension]))
             .collect();
 
+        let feed = self.feed_snapshot(ids.iter().copied());
+
         // Collect pre-batch payloads BEFORE overwriting -- for histogram decrements.
         // Bug #46: deduplicate by ID -- only the first occurrence retrieves the
         // pre-batch value; duplicates get None so the old value is decremented
@@ -92,13 +104,21 @@ impl Collection {
         self.update_label_index_from_raw(ids, payloads);
         self.update_secondary_indexes_from_raw(ids, payloads, &old_payloads);
 
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::IndexInsert)?;
         let inserted = self.bulk_index_or_defer(&vector_refs);
         self.storage.config.write().point_count = self.storage.vector_storage.read().len();
 
         self.maintain_histograms_for_raw(ids, payloads, &old_payloads);
 
         self.invalidate_caches_and_bump_generation();
+        self.sync_partial_indexes(ids.iter().copied());
 
+        let new_payloads = ids
+            .iter()
+            .enumerate()
+            .map(|(i, &id)| (id, payloads.and_then(|ps| ps[i].as_ref())));
+        self.publish_upserts(feed, new_payloads, true);
         Ok(inserted)
     }
 
@@ -162,6 +182,23 @@ impl Collection {
         Ok(())
     }
 
+    /// Raw-path counterpart of `apply_non_finite_policy`: zeroes NaN and
+    /// ±infinity in a copy of the flat buffer under `SanitizeToZero`, and
+    /// borrows it unchanged otherwise.
+    fn apply_raw_non_finite_policy<'a>(&self, vectors: &'a [f32]) -> Cow<'a, [f32]> {
+        let sanitize =
+            self.runtime_limits().non_finite_vectors == NonFiniteVectorPolicy::SanitizeToZero;
+        if !sanitize || vectors.iter().all(|v| v.is_finite()) {
+            return Cow::Borrowed(vectors);
+        }
+        Cow::Owned(
+            vectors
+                .iter()
+                .map(|&v| if v.is_finite() { v } else { 0.0 })
+                .collect(),
+        )
+    }
+
     /// Validates raw bulk-insert inputs before any state mutation.
     fn validate_raw_inputs(
         &self,
@@ -190,10 +227,48 @@ impl Collection {
                 )));
             }
         }
-        let collection_dim = self.storage.config.read().dimension;
+        let (collection_dim, metric) = {
+            let config = self.storage.config.read();
+            (config.dimension, config.metric)
+        };
         validate_dimension_match(collection_dim, dimension)?;
+        if dimension > 0 {
+            let check_norm = self.rejects_zero_norm(metric);
+            for (&id, row) in ids.iter().zip(vectors.chunks_exact(dimension)) {
+                validate_vector_finite(id, row)?;
+                if check_norm {
+                    validate_vector_norm(id, row)?;
+                }
+            }
+        }
         self.enforce_raw_upsert_limits(ids, payloads)?;
-        Ok(())
+        self.validate_raw_points(vectors, ids, dimension, payloads)
+    }
+
+    /// Runs the upsert validator on the raw rows. Rows are copied into
+    /// `Point`s only when a validator is registered, so the zero-copy path
+    /// stays allocation-free otherwise.
+    fn validate_raw_points(
+        &self,
+        vectors: &[f32],
+        ids: &[u64],
+        dimension: usize,
+        payloads: Option<&[Option<serde_json::Value>]>,
+    ) -> Result<()> {
+        let validator = &self.runtime.upsert_validator;
+        if !validator.is_set() {
+            return Ok(());
+        }
+        let points: Vec<Point> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, &id)| {
+                let vector = vectors[i * dimension..(i + 1) * dimension].to_vec();
+                let payload = payloads.and_then(|ps| ps[i].clone());
+                Point::new(id, vector, payload)
+            })
+            .collect();
+        validator.validate(&points)
     }
 
     /// Stores pre-built payload entries via batch WAL write + flush.
@@ -213,6 +288,8 @@ impl Collection {
         if entries.is_empty() {
             return Ok(());
         }
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::StorePayloads)?;
         if fsync {
             self.storage.payload_storage.write().store_batch(entries)?;
         } else {
@@ -230,8 +307,13 @@ impl Collection {
         vector_refs: &[(u64, &[f32])],
         payload_entries: &[(u64, &serde_json::Value)],
     ) -> Result<()> {
-        // LOCK ORDER: vector_storage(2, write, parallel) ‖ payload_storage(3, write, parallel).
-        //   Each rayon closure acquires only one lock — no ordering dependency between them.
+        let progress = &self.streaming.progress;
+        progress.emit(StoringVectors, 0, vector_refs.len());
+
+        // LOCK ORDER: commit gate(1c) → vector_storage(2, write, parallel) ‖
+        //   payload_storage(3, write, parallel). Each rayon closure acquires
+        //   only one storage lock — no ordering dependency between them.
+        let commit = self.storage.commits.begin_commit();
         #[cfg(feature = "persistence")]
         {
             let (vec_result, pay_result) = rayon::join(
@@ -247,7 +329,9 @@ impl Collection {
             self.bulk_store_vectors(vector_refs)?;
             self.bulk_store_payload_entries(payload_entries)?;
         }
+        drop(commit);
 
+        progress.emit(StoringVectors, vector_refs.len(), vector_refs.len());
         Ok(())
     }
 
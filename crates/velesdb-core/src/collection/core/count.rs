@@ -0,0 +1,173 @@
+//! Filtered point counting.
+//!
+//! Provides `Collection::count`, the backing for the REST count endpoint and
+//! for `SELECT COUNT(*)` queries that carry no other aggregate. Equality
+//! filters on indexed fields are answered from the index postings alone.
+
+use crate::collection::expiry::{is_payload_expired, now_unix_secs};
+use crate::collection::types::Collection;
+use crate::filter::{Collation, Condition, Filter};
+use crate::index::SecondaryIndex;
+use crate::storage::{PayloadStorage, VectorStorage};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+/// Number of ids checked per rayon task in the counting scan.
+const COUNT_CHUNK_SIZE: usize = 1024;
+
+impl Collection {
+    /// Counts live points matching `filter` (every live point when `None`).
+    ///
+    /// When the secondary indexes answer the filter exactly (see
+    /// [`index_answers_exactly`](Self::index_answers_exactly)) the count is
+    /// the cardinality of the index bitmap and no payload is read. Otherwise,
+    /// when the filter resolves through secondary indexes only the indexed
+    /// candidates are checked, and without one all points are scanned in
+    /// parallel chunks; those candidates are re-matched against their payload
+    /// because the index bitmap can be a superset of the true matches, and
+    /// TTL-expired points are never counted.
+    ///
+    /// A point without a payload is matched as `null`, the same rule the
+    /// VelesQL aggregation path applies, so `COUNT(*) WHERE x IS NULL` keeps
+    /// its meaning when routed here.
+    #[must_use]
+    pub fn count(&self, filter: Option<&Filter>) -> usize {
+        let is_metadata_only = self.storage.config.read().metadata_only;
+        // Resolved up front so the secondary-index lock is released before
+        // the storage locks are taken.
+        let index_only = filter.is_some_and(|f| self.index_answers_exactly(f));
+        let bitmap = filter.and_then(|f| self.build_prefilter_bitmap(f));
+
+        // One committed snapshot of both storages (see `collection::read_snapshot`).
+        let snapshot = self.read_snapshot();
+        let (vector_storage, payload_storage) = (&*snapshot.vectors, &*snapshot.payloads);
+
+        if let (true, Some(bitmap)) = (index_only, &bitmap) {
+            if is_metadata_only {
+                return usize::try_from(bitmap.len()).unwrap_or(usize::MAX);
+            }
+            return bitmap
+                .iter()
+                .filter(|&id| vector_storage.contains(u64::from(id)))
+                .count();
+        }
+
+        let ids =
+            Self::filter_candidate_ids(bitmap, is_metadata_only, vector_storage, payload_storage);
+
+        let now_secs = now_unix_secs();
+        ids.par_chunks(COUNT_CHUNK_SIZE)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .filter(|&&id| {
+                        let payload = payload_storage.retrieve(id).ok().flatten();
+                        Self::is_live_match(payload.as_ref(), filter, now_secs, is_metadata_only)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Whether the secondary-index bitmap of `filter` is exactly its match
+    /// set, so a count needs no payload.
+    ///
+    /// Holds when every leaf is an `=` or `IN` on an indexed top-level field
+    /// with index-exact values (see [`value_is_index_exact`]), leaves are
+    /// combined by AND/OR only, and no indexed point carries a TTL (an
+    /// expired point stays in the index until it is swept).
+    pub(crate) fn index_answers_exactly(&self, filter: &Filter) -> bool {
+        if self.query.indexed_ttl_points.load(Ordering::Relaxed) != 0 {
+            return false;
+        }
+        let indexes = self.query.secondary_indexes.read();
+        condition_is_index_exact(&indexes, &filter.condition, filter.effective_collation())
+    }
+
+    /// Returns the ids that can match a filter whose index bitmap is `bitmap`.
+    ///
+    /// With a bitmap, its ids are kept if they still name a point; without
+    /// one, every point id is a candidate. Callers resolve the bitmap before
+    /// taking the storage locks and re-check each candidate with
+    /// [`is_live_match`](Self::is_live_match).
+    pub(super) fn filter_candidate_ids(
+        bitmap: Option<roaring::RoaringBitmap>,
+        is_metadata_only: bool,
+        vector_storage: &dyn VectorStorage,
+        payload_storage: &dyn PayloadStorage,
+    ) -> Vec<u64> {
+        match bitmap {
+            Some(bitmap) if is_metadata_only => bitmap.iter().map(u64::from).collect(),
+            Some(bitmap) => bitmap
+                .iter()
+                .map(u64::from)
+                .filter(|&id| vector_storage.contains(id))
+                .collect(),
+            None if is_metadata_only => payload_storage.ids(),
+            None => vector_storage.ids(),
+        }
+    }
+
+    /// Whether a candidate with `payload` is unexpired and passes `filter`.
+    ///
+    /// `require_payload` is set for metadata-only collections, where a point
+    /// exists only through its payload entry.
+    pub(super) fn is_live_match(
+        payload: Option<&serde_json::Value>,
+        filter: Option<&Filter>,
+        now_secs: u64,
+        require_payload: bool,
+    ) -> bool {
+        if require_payload && payload.is_none() {
+            return false;
+        }
+        if is_payload_expired(payload, now_secs) {
+            return false;
+        }
+        filter.is_none_or(|f| f.matches(payload.unwrap_or(&serde_json::Value::Null)))
+    }
+}
+
+/// See [`Collection::index_answers_exactly`].
+fn condition_is_index_exact(
+    indexes: &HashMap<String, SecondaryIndex>,
+    cond: &Condition,
+    collation: Collation,
+) -> bool {
+    // The index reads top-level keys; the filter resolves dotted paths.
+    let is_indexed = |field: &str| !field.contains('.') && indexes.contains_key(field);
+    match cond {
+        Condition::Eq { field, value } => {
+            is_indexed(field) && value_is_index_exact(value, collation)
+        }
+        Condition::In { field, values } => {
+            is_indexed(field) && values.iter().all(|v| value_is_index_exact(v, collation))
+        }
+        Condition::And { conditions } | Condition::Or { conditions } => {
+            !conditions.is_empty()
+                && conditions
+                    .iter()
+                    .all(|c| condition_is_index_exact(indexes, c, collation))
+        }
+        _ => false,
+    }
+}
+
+/// Whether the index key of `value` matches exactly the payload values the
+/// filter accepts as equal.
+///
+/// Strings (binary collation) and booleans compare by identity on both
+/// sides. Numbers are equal within `f64::EPSILON` in the filter but by bit
+/// pattern in the index; the two agree for integers with `1 <= |n| <= 2^53`.
+fn value_is_index_exact(value: &serde_json::Value, collation: Collation) -> bool {
+    const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992.0;
+    match value {
+        serde_json::Value::String(_) => collation.is_binary(),
+        serde_json::Value::Bool(_) => true,
+        serde_json::Value::Number(n) => n
+            .as_f64()
+            .is_some_and(|f| f.fract() == 0.0 && (1.0..=MAX_EXACT_INTEGER).contains(&f.abs())),
+        _ => false,
+    }
+}
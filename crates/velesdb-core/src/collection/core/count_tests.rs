@@ -0,0 +1,245 @@
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::filter::{Condition, Filter};
+use crate::point::Point;
+use crate::velesql::Parser;
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Helper: a 2-dim collection with 10 points, `category` alternating
+/// `tech`/`science` and `rank` equal to the id.
+fn seeded_collection() -> (tempfile::TempDir, Collection) {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(temp.path()), 2, DistanceMetric::Cosine)
+        .expect("collection created");
+    let points: Vec<Point> = (0..10u64)
+        .map(|i| {
+            let category = if i % 2 == 0 { "tech" } else { "science" };
+            Point::new(
+                i,
+                vec![1.0, 0.0],
+                Some(json!({"category": category, "rank": i})),
+            )
+        })
+        .collect();
+    col.upsert(points).expect("upsert");
+    (temp, col)
+}
+
+fn eq(field: &str, value: serde_json::Value) -> Condition {
+    Condition::Eq {
+        field: field.to_string(),
+        value,
+    }
+}
+
+#[test]
+fn test_count_without_filter_counts_every_point() {
+    let (_temp, col) = seeded_collection();
+    assert_eq!(col.count(None), 10);
+}
+
+#[test]
+fn test_count_scan_and_index_paths_agree() {
+    let (_temp, col) = seeded_collection();
+    let tech = Filter::new(eq("category", json!("tech")));
+    // `rank` is not indexed, so the AND bitmap is a superset of the matches.
+    let tech_high_rank = Filter::new(Condition::And {
+        conditions: vec![
+            eq("category", json!("tech")),
+            Condition::Gte {
+                field: "rank".to_string(),
+                value: json!(6),
+            },
+        ],
+    });
+    assert_eq!(col.count(Some(&tech)), 5);
+    assert_eq!(col.count(Some(&tech_high_rank)), 2);
+
+    col.create_index("category").expect("index");
+    assert!(col.build_prefilter_bitmap(&tech).is_some());
+    assert_eq!(col.count(Some(&tech)), 5);
+    assert_eq!(col.count(Some(&tech_high_rank)), 2);
+
+    col.delete(&[0, 2]).expect("delete");
+    assert_eq!(col.count(Some(&tech)), 3);
+}
+
+#[test]
+fn test_count_excludes_expired_points() {
+    let (_temp, col) = seeded_collection();
+    col.upsert(vec![Point::new(
+        42,
+        vec![0.0, 1.0],
+        Some(json!({"category": "tech", "_veles_expires_at": 1_000_u64})),
+    )])
+    .expect("upsert");
+
+    assert_eq!(col.count(None), 10);
+    assert_eq!(
+        col.count(Some(&Filter::new(eq("category", json!("tech"))))),
+        5
+    );
+}
+
+#[test]
+fn test_count_metadata_only_collection() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create_metadata_only(PathBuf::from(temp.path()), "meta")
+        .expect("collection created");
+    col.upsert(vec![
+        Point::metadata_only(1, json!({"kind": "a"})),
+        Point::metadata_only(2, json!({"kind": "b"})),
+        Point::metadata_only(3, json!({"kind": "a"})),
+    ])
+    .expect("upsert");
+
+    assert_eq!(col.count(None), 3);
+    assert_eq!(col.count(Some(&Filter::new(eq("kind", json!("a"))))), 2);
+}
+
+#[test]
+fn test_select_count_star_matches_count() {
+    let (_temp, col) = seeded_collection();
+    col.upsert(vec![Point::new(
+        42,
+        vec![0.0, 1.0],
+        Some(json!({"category": "tech", "_veles_expires_at": 1_000_u64})),
+    )])
+    .expect("upsert");
+    let params = HashMap::new();
+
+    let query = Parser::parse("SELECT COUNT(*) FROM docs WHERE category = 'tech'").expect("parse");
+    let result = col.execute_aggregate(&query, &params).expect("aggregate");
+    assert_eq!(
+        result["count"],
+        json!(5),
+        "expired point must not be counted"
+    );
+
+    let query = Parser::parse("SELECT COUNT(*) AS total FROM docs").expect("parse");
+    let result = col.execute_aggregate(&query, &params).expect("aggregate");
+    assert_eq!(result["total"], json!(10));
+}
+
+#[test]
+fn test_count_answers_indexed_equality_from_index() {
+    let (_temp, col) = seeded_collection();
+    col.create_index("category").expect("index");
+    col.create_index("rank").expect("index");
+    let tech = Filter::new(eq("category", json!("tech")));
+    let tech_or_rank_3 = Filter::new(Condition::Or {
+        conditions: vec![eq("category", json!("tech")), eq("rank", json!(3))],
+    });
+    let both = Filter::new(Condition::In {
+        field: "category".to_string(),
+        values: vec![json!("science"), json!("tech")],
+    });
+
+    for filter in [&tech, &tech_or_rank_3, &both] {
+        assert!(col.index_answers_exactly(filter));
+    }
+    assert_eq!(col.count(Some(&tech)), 5);
+    assert_eq!(col.count(Some(&tech_or_rank_3)), 6);
+    assert_eq!(col.count(Some(&both)), 10);
+
+    // Index postings follow deletes and payload rewrites.
+    col.delete(&[0]).expect("delete");
+    col.upsert(vec![Point::new(
+        1,
+        vec![1.0, 0.0],
+        Some(json!({"category": "tech", "rank": 1})),
+    )])
+    .expect("upsert");
+    assert_eq!(col.count(Some(&tech)), 5);
+}
+
+#[test]
+fn test_count_falls_back_when_index_is_not_exact() {
+    let (_temp, col) = seeded_collection();
+    col.create_index("category").expect("index");
+    col.create_index("rank").expect("index");
+
+    // Range leaves, non-indexed fields, zero and fractional numbers are
+    // re-checked against payloads.
+    let not_exact = [
+        Filter::new(Condition::Gte {
+            field: "rank".to_string(),
+            value: json!(6),
+        }),
+        Filter::new(eq("missing", json!("x"))),
+        Filter::new(eq("rank", json!(0))),
+        Filter::new(eq("rank", json!(1.5))),
+        Filter::new(Condition::And {
+            conditions: vec![eq("category", json!("tech")), eq("missing", json!("x"))],
+        }),
+    ];
+    for filter in &not_exact {
+        assert!(!col.index_answers_exactly(filter));
+    }
+    assert_eq!(col.count(Some(&not_exact[0])), 4);
+    assert_eq!(col.count(Some(&not_exact[2])), 1);
+    assert_eq!(col.count(Some(&not_exact[4])), 0);
+}
+
+#[test]
+fn test_count_ttl_points_disable_index_only_counts() {
+    let (_temp, col) = seeded_collection();
+    col.create_index("category").expect("index");
+    let tech = Filter::new(eq("category", json!("tech")));
+    assert!(col.index_answers_exactly(&tech));
+
+    col.upsert(vec![Point::new(
+        42,
+        vec![0.0, 1.0],
+        Some(json!({"category": "tech", "_veles_expires_at": 1_000_u64})),
+    )])
+    .expect("upsert");
+    assert!(!col.index_answers_exactly(&tech));
+    assert_eq!(
+        col.count(Some(&tech)),
+        5,
+        "expired point must not be counted"
+    );
+
+    col.delete(&[42]).expect("delete");
+    assert!(col.index_answers_exactly(&tech));
+    assert_eq!(col.count(Some(&tech)), 5);
+}
+
+#[test]
+fn test_count_index_backfill_sees_existing_ttl_points() {
+    let (_temp, col) = seeded_collection();
+    col.upsert(vec![Point::new(
+        42,
+        vec![0.0, 1.0],
+        Some(json!({"category": "tech", "_veles_expires_at": 1_000_u64})),
+    )])
+    .expect("upsert");
+    col.create_index("category").expect("index");
+
+    let tech = Filter::new(eq("category", json!("tech")));
+    assert!(!col.index_answers_exactly(&tech));
+    assert_eq!(col.count(Some(&tech)), 5);
+}
+
+#[test]
+fn test_count_metadata_only_collection_from_index() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create_metadata_only(PathBuf::from(temp.path()), "meta")
+        .expect("collection created");
+    col.create_index("kind").expect("index");
+    col.upsert(vec![
+        Point::metadata_only(1, json!({"kind": "a"})),
+        Point::metadata_only(2, json!({"kind": "b"})),
+        Point::metadata_only(3, json!({"kind": "a"})),
+    ])
+    .expect("upsert");
+
+    let kind_a = Filter::new(eq("kind", json!("a")));
+    assert!(col.index_answers_exactly(&kind_a));
+    assert_eq!(col.count(Some(&kind_a)), 2);
+}
@@ -6,13 +6,17 @@
 //! `DedupMap`, and `QuantizationGuards` are in `crud_helpers.rs`.
 
 use super::crud_helpers::{DedupMap, QuantizationGuards};
+use crate::collection::index_progress::IndexBuildPhase::StoringVectors;
 use crate::collection::types::Collection;
+use crate::config::NonFiniteVectorPolicy;
+use crate::distance::DistanceMetric;
 use crate::error::{Error, Result};
 use crate::point::Point;
 use crate::quantization::StorageMode;
 use crate::storage::{LogPayloadStorage, PayloadStorage, VectorStorage};
-use crate::validation::validate_dimension_match;
+use crate::validation::{validate_dimension_match, validate_vector_finite, validate_vector_norm};
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 
 impl Collection {
@@ -22,12 +26,16 @@ impl Collection {
     ///
     /// # Errors
     ///
-    /// Returns an error if any point has a mismatched dimension, or if
+    /// Returns an error if any point has a mismatched dimension, if a vector
+    /// holds NaN/infinite components (unless `limits.non_finite_vectors` is
+    /// `sanitize_to_zero`), if it is all zeros on a cosine collection with
+    /// `limits.reject_zero_norm_vectors` set, or if
     /// attempting to insert vectors into a metadata-only collection.
     pub fn upsert(&self, points: impl IntoIterator<Item = Point>) -> Result<()> {
         let points: Vec<Point> = points.into_iter().collect();
         let config = self.storage.config.read();
         let dimension = config.dimension;
+        let metric = config.metric;
         let storage_mode = config.storage_mode;
 
         if config.metadata_only {
@@ -45,8 +53,10 @@ impl Collection {
 
         // Parity item E + dimension validation at the cold boundary, before any
         // storage lock or WAL write — a violation rejects the whole batch.
-        self.validate_vector_upsert_batch(&points, dimension)?;
+        let points = self.apply_non_finite_policy(&points);
+        self.validate_vector_upsert_batch(&points, dimension, metric)?;
 
+        let feed = self.feed_snapshot(points.iter().map(|p| p.id));
         let (sparse_batch, old_payloads) = self.upsert_storage_and_index(&points, storage_mode)?;
 
         self.apply_sparse_batch_upsert(&sparse_batch)?;
@@ -57,28 +67,67 @@ impl Collection {
         self.apply_histogram_replace_dedup(&points, &old_payloads);
 
         self.bump_generation_with_mirror_upserts(&points);
-        Ok(())
+        self.publish_upserts(
+            feed,
+            points.iter().map(|p| (p.id, p.payload.as_ref())),
+            false,
+        );
+        self.note_upserted(&points)
+    }
+
+    /// Applies `limits.non_finite_vectors` to a vector upsert batch.
+    ///
+    /// Under `SanitizeToZero` any point carrying NaN or ±infinity is cloned
+    /// with those components zeroed; otherwise the batch is borrowed as-is and
+    /// [`Self::validate_vector_upsert_batch`] rejects non-finite values.
+    pub(super) fn apply_non_finite_policy<'a>(&self, points: &'a [Point]) -> Cow<'a, [Point]> {
+        let sanitize =
+            self.runtime_limits().non_finite_vectors == NonFiniteVectorPolicy::SanitizeToZero;
+        if !sanitize || points.iter().flat_map(|p| &p.vector).all(|v| v.is_finite()) {
+            return Cow::Borrowed(points);
+        }
+        let mut sanitized = points.to_vec();
+        for point in &mut sanitized {
+            point.sanitize_non_finite();
+        }
+        Cow::Owned(sanitized)
+    }
+
+    /// Whether `limits.reject_zero_norm_vectors` applies to this collection's
+    /// metric (cosine only).
+    pub(super) fn rejects_zero_norm(&self, metric: DistanceMetric) -> bool {
+        metric == DistanceMetric::Cosine && self.runtime_limits().reject_zero_norm_vectors
     }
 
     /// Validates a vector-collection upsert batch at the cold boundary:
-    /// runtime ingest limits (parity item E) followed by the per-point
-    /// dimension check. Shared by [`Self::upsert`] and `upsert_bulk_inner`
-    /// so both ingest paths apply the identical pre-storage validation.
+    /// runtime ingest limits (parity item E), the per-point dimension and
+    /// value checks, then the user-defined upsert validator. Shared by
+    /// [`Self::upsert`] and `upsert_bulk_inner` so both ingest paths apply
+    /// the identical pre-storage validation.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::GuardRail`] on a limit violation, or the dimension
-    /// mismatch error from [`validate_dimension_match`].
+    /// Returns [`Error::GuardRail`] on a limit violation, the dimension
+    /// mismatch error from [`validate_dimension_match`], the
+    /// [`Error::InvalidVector`] from [`validate_vector_finite`] /
+    /// [`validate_vector_norm`], or the error of a point rejected by the
+    /// upsert validator.
     pub(super) fn validate_vector_upsert_batch(
         &self,
         points: &[Point],
         dimension: usize,
+        metric: DistanceMetric,
     ) -> Result<()> {
         self.enforce_upsert_limits(points)?;
+        let check_norm = self.rejects_zero_norm(metric);
         for point in points {
             validate_dimension_match(dimension, point.dimension())?;
+            validate_vector_finite(point.id, &point.vector)?;
+            if check_norm {
+                validate_vector_norm(point.id, &point.vector)?;
+            }
         }
-        Ok(())
+        self.runtime.upsert_validator.validate(points)
     }
 
     /// Stores vectors, payloads, and indexes for a batch of points.
@@ -112,6 +161,8 @@ impl Collection {
         let sparse_batch = self.per_point_updates(points, &old_payloads, storage_mode)?;
 
         // Phase 3: Batch HNSW insert
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::IndexInsert)?;
         let vector_refs: Vec<(u64, &[f32])> =
             points.iter().map(|p| (p.id, p.vector.as_slice())).collect();
         self.bulk_index_or_defer(&vector_refs);
@@ -179,6 +230,8 @@ impl Collection {
     ///
     /// Returns the old payloads for Phase 2.
     fn batch_store_all(&self, points: &[Point]) -> Result<Vec<Option<serde_json::Value>>> {
+        let progress = &self.streaming.progress;
+        progress.emit(StoringVectors, 0, points.len());
         // Collect old payloads under the payload write lock, then release.
         // The write lock prevents concurrent payload mutations during the read.
         let old_payloads = {
@@ -193,10 +246,20 @@ impl Collection {
         let dedup_map = Self::build_dedup_map(points);
 
         // Sequential: see the "Sequential I/O" doc section above for why this
-        // is no longer `rayon::join` (deadlock under concurrent load).
+        // is no longer `rayon::join` (deadlock under concurrent load). The
+        // commit guard keeps scans from seeing the payloads without the
+        // vectors; it is released before `config`(1) is written.
+        let commit = self.storage.commits.begin_commit();
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::StorePayloads)?;
         self.write_and_flush_payloads(points, &dedup_map)?;
-        self.write_deduped_vectors(points, &dedup_map)?;
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::StoreVectors)?;
+        let point_count = self.write_deduped_vectors(points, &dedup_map)?;
+        drop(commit);
+        self.storage.config.write().point_count = point_count;
 
+        progress.emit(StoringVectors, points.len(), points.len());
         Ok(old_payloads)
     }
 
@@ -278,11 +341,12 @@ impl Collection {
         Ok(())
     }
 
-    /// Writes only the last vector per ID to vector storage.
+    /// Writes only the last vector per ID to vector storage and returns the
+    /// new vector count.
     ///
     /// Issue #425: Accepts a pre-computed `dedup_map` instead of building
     /// its own, consolidating the two redundant maps into one.
-    fn write_deduped_vectors(&self, points: &[Point], dedup_map: &DedupMap) -> Result<()> {
+    fn write_deduped_vectors(&self, points: &[Point], dedup_map: &DedupMap) -> Result<usize> {
         let deduped: Vec<(u64, &[f32])> = points
             .iter()
             .enumerate()
@@ -294,10 +358,7 @@ impl Collection {
         vector_storage.store_batch(&deduped)?;
         let point_count = vector_storage.len();
         vector_storage.flush()?;
-        drop(vector_storage);
-
-        self.storage.config.write().point_count = point_count;
-        Ok(())
+        Ok(point_count)
     }
 
     /// Returns `true` when Phase 2 processing can be skipped entirely.
@@ -436,7 +497,8 @@ impl Collection {
     ///
     /// # Errors
     ///
-    /// Returns an error if storage operations fail.
+    /// Returns an error if storage operations fail or the upsert validator
+    /// rejects a point.
     pub fn upsert_metadata(&self, points: impl IntoIterator<Item = Point>) -> Result<()> {
         let points: Vec<Point> = points.into_iter().collect();
 
@@ -444,8 +506,13 @@ impl Collection {
         // entry for the metadata-only path (`MetadataCollection::upsert` and
         // `upsert_metadata` route here directly, bypassing `Collection::upsert`).
         self.enforce_upsert_limits(&points)?;
+        self.runtime.upsert_validator.validate(&points)?;
+        let feed = self.feed_snapshot(points.iter().map(|p| p.id));
 
-        // LOCK ORDER: payload_storage(3) → label_index(7).
+        // LOCK ORDER: commit gate(1c) → payload_storage(3) → label_index(7).
+        let commit = self.storage.commits.begin_commit();
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::StorePayloads)?;
         let mut payload_storage = self.storage.payload_storage.write();
         let mut label_idx = self.graph.label_index.write();
 
@@ -464,6 +531,7 @@ impl Collection {
         let point_count = payload_storage.ids().len();
         payload_storage.flush()?;
         drop(payload_storage);
+        drop(commit);
 
         // config(1) only — payload_storage(3) and label_index(7) both released above.
         self.storage.config.write().point_count = point_count;
@@ -474,6 +542,11 @@ impl Collection {
         self.apply_histogram_replace_dedup(&points, &old_payloads_for_hist);
 
         self.bump_generation_with_mirror_upserts(&points);
+        self.publish_upserts(
+            feed,
+            points.iter().map(|p| (p.id, p.payload.as_ref())),
+            false,
+        );
         Ok(())
     }
 
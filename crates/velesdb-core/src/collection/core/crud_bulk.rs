@@ -9,6 +9,7 @@
 //! `ContiguousVectors` and `AsyncIndexBuilder` defers HNSW construction for
 //! higher throughput.
 
+use crate::collection::index_progress::IndexBuildPhase::StoringVectors;
 use crate::collection::types::Collection;
 use crate::error::{Error, Result};
 use crate::index::hnsw::direct_writer::DirectVectorWriter;
@@ -72,12 +73,18 @@ impl Collection {
 
         // Parity item E + dimension validation at the cold boundary, before any
         // storage lock / WAL write (shared with the single-upsert path).
-        let dimension = self.storage.config.read().dimension;
-        self.validate_vector_upsert_batch(points, dimension)?;
+        let (dimension, metric) = {
+            let config = self.storage.config.read();
+            (config.dimension, config.metric)
+        };
+        let points = self.apply_non_finite_policy(points);
+        let points: &[Point] = &points;
+        self.validate_vector_upsert_batch(points, dimension, metric)?;
 
         let vector_refs: Vec<(u64, &[f32])> =
             points.iter().map(|p| (p.id, p.vector.as_slice())).collect();
         let sparse_batch = Self::collect_sparse_batch(points);
+        let feed = self.feed_snapshot(points.iter().map(|p| p.id));
 
         // The V2 fast path writes vectors directly into the graph store and
         // bypasses RaBitQPrecisionHnsw::insert — on a RaBitQ backend that
@@ -98,6 +105,12 @@ impl Collection {
         // attachment and is left to the external consumer.
         self.notify_auto_reindex_after_bulk();
 
+        self.publish_upserts(
+            feed,
+            points.iter().map(|p| (p.id, p.payload.as_ref())),
+            false,
+        );
+        self.note_upserted(points)?;
         Ok(count)
     }
 
@@ -127,6 +140,8 @@ impl Collection {
         // WAL + payload write (same durability guarantees as standard path).
         self.store_vectors_and_payloads_inner(vector_refs, points, fsync)?;
 
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::IndexInsert)?;
         // Write directly to the graph's ContiguousVectors so vectors are
         // immediately visible to rerank/brute-force while HNSW construction
         // is deferred.
@@ -176,6 +191,8 @@ impl Collection {
 
         self.store_vectors_and_payloads_inner(vector_refs, points, fsync)?;
 
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::IndexInsert)?;
         let inserted = self.bulk_index_or_defer(vector_refs);
         self.finalize_bulk_upsert(points, &old_payloads, sparse_batch)?;
 
@@ -212,6 +229,11 @@ impl Collection {
         points: &[Point],
         fsync: bool,
     ) -> Result<()> {
+        let progress = &self.streaming.progress;
+        progress.emit(StoringVectors, 0, vector_refs.len());
+        // Scans wait for both stores; see `collection::read_snapshot`.
+        let commit = self.storage.commits.begin_commit();
+
         #[cfg(feature = "persistence")]
         {
             let (vec_result, pay_result) = rayon::join(
@@ -227,7 +249,9 @@ impl Collection {
             self.bulk_store_vectors_inner(vector_refs, fsync)?;
             self.bulk_store_payloads_inner(points, fsync)?;
         }
+        drop(commit);
 
+        progress.emit(StoringVectors, vector_refs.len(), vector_refs.len());
         Ok(())
     }
 
@@ -261,6 +285,8 @@ impl Collection {
     /// `BufWriter` but `flush()` is skipped entirely. The mmap write is
     /// still performed so the data is immediately readable in-process.
     fn bulk_store_vectors_inner(&self, vectors: &[(u64, &[f32])], fsync: bool) -> Result<()> {
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::StoreVectors)?;
         let mut storage = self.storage.vector_storage.write();
         storage.store_batch(vectors)?;
         if fsync {
@@ -277,6 +303,8 @@ impl Collection {
     /// When `fsync` is `false`, WAL entries are written and the buffer is
     /// flushed to the OS kernel, but `sync_all()` is skipped.
     fn bulk_store_payloads_inner(&self, points: &[Point], fsync: bool) -> Result<()> {
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::StorePayloads)?;
         let entries: Vec<(u64, &serde_json::Value)> = points
             .iter()
             .filter_map(|p| p.payload.as_ref().map(|pl| (p.id, pl)))
@@ -11,6 +11,7 @@ use parking_lot::RwLockWriteGuard;
 #[cfg(feature = "persistence")]
 use rayon::prelude::*;
 use std::collections::HashMap;
+use std::sync::atomic::Ordering;
 
 const PQ_TRAINING_SAMPLES: usize = 128;
 
@@ -145,6 +146,15 @@ impl Collection {
         old_payload: Option<&serde_json::Value>,
         new_payload: Option<&serde_json::Value>,
     ) {
+        match (carries_ttl(old_payload), carries_ttl(new_payload)) {
+            (false, true) => {
+                self.query
+                    .indexed_ttl_points
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            (true, false) => self.release_ttl_point(),
+            _ => {}
+        }
         let indexes = self.query.secondary_indexes.read();
         for (field, index) in indexes.iter() {
             if let Some(old_value) = old_payload
@@ -171,6 +181,9 @@ impl Collection {
         let Some(payload) = old_payload else {
             return;
         };
+        if carries_ttl(Some(payload)) {
+            self.release_ttl_point();
+        }
         let indexes = self.query.secondary_indexes.read();
         for (field, index) in indexes.iter() {
             if let Some(old_value) = payload.get(field).and_then(JsonValue::from_json) {
@@ -179,6 +192,18 @@ impl Collection {
         }
     }
 
+    /// Decrements `indexed_ttl_points`, saturating at zero: the counter is
+    /// only rebuilt on index backfill, so a TTL point written while no index
+    /// existed may be removed without ever having been counted.
+    fn release_ttl_point(&self) {
+        let _ =
+            self.query
+                .indexed_ttl_points
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                    Some(n.saturating_sub(1))
+                });
+    }
+
     // These methods take `&self` for consistency with the impl block calling convention,
     // but the operations are logically index-directed and do not need instance state.
     #[allow(clippy::unused_self)]
@@ -286,3 +311,8 @@ impl Collection {
         self.update_histograms_replace(old_payloads, &new_payloads);
     }
 }
+
+/// Whether `payload` carries a TTL key (expired or not).
+pub(super) fn carries_ttl(payload: Option<&serde_json::Value>) -> bool {
+    payload.is_some_and(|p| p.get(crate::collection::EXPIRES_AT_KEY).is_some())
+}
@@ -2,6 +2,7 @@
 //!
 //! Extracted from `crud.rs` to reduce NLOC.
 
+use crate::collection::index_progress::{IndexBuildPhase, LINK_PROGRESS_STEPS};
 use crate::collection::types::Collection;
 use crate::error::Result;
 use crate::point::Point;
@@ -257,20 +258,24 @@ impl Collection {
     }
 
     /// Like [`Self::invalidate_caches_and_bump_generation`], but keeps the
-    /// payload mirror warm by applying the upserted points incrementally.
+    /// payload mirror warm by applying the upserted points incrementally, and
+    /// re-syncs the partial indexes with them.
     pub(super) fn bump_generation_with_mirror_upserts(&self, points: &[crate::point::Point]) {
         *self.query.cached_stats.lock() = None;
         self.storage.payload_mirror.apply_upserts(points);
+        self.sync_partial_indexes(points.iter().map(|p| p.id));
         self.generations
             .write_generation
             .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
     }
 
     /// Like [`Self::invalidate_caches_and_bump_generation`], but keeps the
-    /// payload mirror warm by tombstoning the deleted ids incrementally.
-    pub(super) fn bump_generation_with_mirror_deletes(&self, ids: &[u64]) {
+    /// payload mirror warm by tombstoning the deleted ids incrementally, and
+    /// drops them from the partial indexes.
+    pub(crate) fn bump_generation_with_mirror_deletes(&self, ids: &[u64]) {
         *self.query.cached_stats.lock() = None;
         self.storage.payload_mirror.apply_deletes(ids);
+        self.sync_partial_indexes(ids.iter().copied());
         self.generations
             .write_generation
             .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
@@ -323,10 +328,19 @@ impl Collection {
                 .fetch_add(count as u64, std::sync::atomic::Ordering::Relaxed);
             return count;
         }
-        let inserted = self
-            .storage
-            .index
-            .insert_batch_parallel(vector_refs.iter().copied());
+        let progress = &self.streaming.progress;
+        let inserted = if progress.is_set() {
+            progress.emit(IndexBuildPhase::LinkingLayers, 0, count);
+            self.storage.index.insert_batch_parallel_with_progress(
+                vector_refs.iter().copied(),
+                LINK_PROGRESS_STEPS,
+                &mut |linked, total| progress.emit(IndexBuildPhase::LinkingLayers, linked, total),
+            )
+        } else {
+            self.storage
+                .index
+                .insert_batch_parallel(vector_refs.iter().copied())
+        };
         #[allow(clippy::cast_possible_truncation)]
         self.generations
             .inserts_since_last_hnsw_save
@@ -2,13 +2,14 @@
 //!
 //! Extracted from `crud.rs` to keep each file under 500 NLOC.
 //! - `get()` — point retrieval by ID
+//! - `get_projected()` — retrieval by ID without vector and/or payload
 //! - `delete()` — point deletion (vector + metadata paths)
 //! - `len()`, `is_empty()`, `all_ids()` — collection-level accessors
 
 use crate::collection::expiry::{is_payload_expired, now_unix_secs};
 use crate::collection::types::Collection;
 use crate::error::Result;
-use crate::point::Point;
+use crate::point::{Point, PointProjection};
 use crate::storage::{PayloadStorage, VectorStorage};
 
 impl Collection {
@@ -22,10 +23,56 @@ impl Collection {
     #[must_use]
     pub fn get(&self, ids: &[u64]) -> Vec<Option<Point>> {
         let now_secs = now_unix_secs();
-        self.get_raw(ids)
+        let points: Vec<Option<Point>> = self
+            .get_raw(ids)
             .into_iter()
             .map(|point| point.filter(|p| !is_payload_expired(p.payload.as_ref(), now_secs)))
-            .collect()
+            .collect();
+        self.record_reads(points.iter().flatten().map(|p| p.id));
+        points
+    }
+
+    /// Retrieves points by their IDs, keeping only the parts selected by
+    /// `projection`.
+    ///
+    /// Visibility is identical to [`get`](Self::get). When the vector is
+    /// excluded it is never copied out of storage: existence is checked
+    /// against the vector index and only the payload is read (it is still
+    /// needed for the TTL check even when the payload itself is excluded).
+    #[must_use]
+    pub fn get_projected(&self, ids: &[u64], projection: PointProjection) -> Vec<Option<Point>> {
+        if projection.with_vector || self.storage.config.read().metadata_only {
+            let mut points = self.get(ids);
+            for point in points.iter_mut().flatten() {
+                projection.apply(point);
+            }
+            return points;
+        }
+
+        let now_secs = now_unix_secs();
+        // LOCK ORDER: vector_storage(2) → payload_storage(3), as in `get_raw`.
+        let vector_storage = self.storage.vector_storage.read();
+        let payload_storage = self.storage.payload_storage.read();
+        let points: Vec<Option<Point>> = ids
+            .iter()
+            .map(|&id| {
+                if !vector_storage.contains(id) {
+                    return None;
+                }
+                let payload = payload_storage.retrieve(id).ok().flatten();
+                if is_payload_expired(payload.as_ref(), now_secs) {
+                    return None;
+                }
+                Some(Point {
+                    id,
+                    vector: Vec::new(),
+                    payload: payload.filter(|_| projection.with_payload),
+                    sparse_vectors: None,
+                })
+            })
+            .collect();
+        self.record_reads(points.iter().flatten().map(|p| p.id));
+        points
     }
 
     /// Retrieves points by their IDs **without** the TTL-expiry filter.
@@ -69,10 +116,12 @@ impl Collection {
                 })
                 .collect()
         } else {
-            // For vector collections, retrieve both vector and payload —
-            // vector_storage acquired first, see the lock-order note above.
-            let vector_storage = self.storage.vector_storage.read();
-            let payload_storage = self.storage.payload_storage.read();
+            // For vector collections, retrieve both vector and payload from
+            // one committed snapshot (vector_storage acquired first, see the
+            // lock-order note above) so no point pairs a new payload with
+            // the vector it replaces.
+            let snapshot = self.read_snapshot();
+            let (vector_storage, payload_storage) = (&*snapshot.vectors, &*snapshot.payloads);
             ids.iter()
                 .map(|&id| {
                     let vector = vector_storage.retrieve(id).ok().flatten()?;
@@ -94,6 +143,27 @@ impl Collection {
     ///
     /// Returns an error if storage operations fail.
     pub fn delete(&self, ids: &[u64]) -> Result<()> {
+        let feed = self.feed_snapshot(ids.iter().copied());
+        self.remove_stored_points(ids)?;
+
+        // Issue #900: deleting a node must cascade to its edges. Otherwise the
+        // edge store retains dangling edges pointing at (or from) a node that
+        // no longer exists, silently corrupting the graph. First-class on
+        // every collection type (agent-memory relations live on vector
+        // collections); collections without edges return immediately.
+        self.cascade_delete_node_edges(ids)?;
+
+        self.bump_generation_with_mirror_deletes(ids);
+        self.publish_deletes(feed, ids);
+        self.forget_archived(ids)
+    }
+
+    /// Removes points from storage, indexes and histograms: the part of
+    /// [`delete`](Self::delete) shared with archival, which keeps the
+    /// archived points' edges and leaves cache invalidation to the caller.
+    pub(crate) fn remove_stored_points(&self, ids: &[u64]) -> Result<()> {
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::Delete)?;
         // Collect old payloads for incremental histogram maintenance.
         let old_payloads = self.collect_payloads_for_histogram(ids);
 
@@ -105,15 +175,6 @@ impl Collection {
 
         // Decrement histogram buckets BEFORE cache invalidation.
         self.update_histograms_on_delete(&old_payloads);
-
-        // Issue #900: deleting a node must cascade to its edges. Otherwise the
-        // edge store retains dangling edges pointing at (or from) a node that
-        // no longer exists, silently corrupting the graph. First-class on
-        // every collection type (agent-memory relations live on vector
-        // collections); collections without edges return immediately.
-        self.cascade_delete_node_edges(ids)?;
-
-        self.bump_generation_with_mirror_deletes(ids);
         Ok(())
     }
 
@@ -180,7 +241,8 @@ impl Collection {
 
     /// Deletes metadata-only points.
     fn delete_metadata_only(&self, ids: &[u64]) -> Result<()> {
-        // LOCK ORDER: payload_storage(3) → label_index(7).
+        // LOCK ORDER: commit gate(1c) → payload_storage(3) → label_index(7).
+        let commit = self.storage.commits.begin_commit();
         let mut payload_storage = self.storage.payload_storage.write();
         let mut label_idx = self.graph.label_index.write();
         for &id in ids {
@@ -199,6 +261,7 @@ impl Collection {
         let point_count = payload_storage.ids().len();
         drop(label_idx);
         drop(payload_storage);
+        drop(commit);
         self.storage.config.write().point_count = point_count;
         Ok(())
     }
@@ -213,7 +276,9 @@ impl Collection {
 
     /// Removes points from vector/payload storage, HNSW index, caches, and label index.
     fn delete_vector_core_stores(&self, ids: &[u64]) -> Result<()> {
-        // LOCK ORDER: vector_storage(2) → payload_storage(3) → caches(4) → label_index(7).
+        // LOCK ORDER: commit gate(1c) → vector_storage(2) → payload_storage(3)
+        // → caches(4) → label_index(7).
+        let commit = self.storage.commits.begin_commit();
         let mut vector_storage = self.storage.vector_storage.write();
         let mut payload_storage = self.storage.payload_storage.write();
         let mut sq8_cache = self.storage.sq8_cache.write();
@@ -247,6 +312,7 @@ impl Collection {
         drop(sq8_cache);
         drop(binary_cache);
         drop(pq_cache);
+        drop(commit);
         self.storage.config.write().point_count = point_count;
         Ok(())
     }
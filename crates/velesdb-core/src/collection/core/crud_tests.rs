@@ -1537,6 +1537,7 @@ fn test_full_scan_fallback_filters_by_labels() {
     let match_clause = crate::velesql::MatchClause {
         patterns: vec![crate::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![crate::velesql::NodePattern::new()
                 .with_alias("n")
                 .with_label("Person")],
@@ -1550,6 +1551,7 @@ fn test_full_scan_fallback_filters_by_labels() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
     let params = std::collections::HashMap::new();
@@ -1578,6 +1580,7 @@ fn person_order_by_age_clause(descending: bool, limit: Option<u64>) -> crate::ve
     crate::velesql::MatchClause {
         patterns: vec![crate::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![crate::velesql::NodePattern::new()
                 .with_alias("n")
                 .with_label("Person")],
@@ -1594,6 +1597,7 @@ fn person_order_by_age_clause(descending: bool, limit: Option<u64>) -> crate::ve
                 descending,
             }]),
             limit,
+            limit_per: None,
         },
     }
 }
@@ -1682,3 +1686,128 @@ fn test_execute_match_with_similarity_order_by_overrides_score() {
         "ORDER BY n.age DESC must override the vector score sort (which would be 3,2,1)"
     );
 }
+
+#[test]
+fn test_get_projected_strips_requested_parts() {
+    let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+    let collection = Collection::create(PathBuf::from(temp_dir.path()), 3, DistanceMetric::Cosine)
+        .expect("collection should be created");
+    collection
+        .upsert(vec![
+            Point::new(1, vec![1.0, 0.0, 0.0], Some(serde_json::json!({"a": 1}))),
+            Point::without_payload(2, vec![0.0, 1.0, 0.0]),
+        ])
+        .expect("upsert should succeed");
+
+    let full = collection.get_projected(&[1, 2], crate::point::PointProjection::FULL);
+    let point = full[0].as_ref().expect("point 1 exists");
+    assert_eq!(point.vector, vec![1.0, 0.0, 0.0]);
+    assert_eq!(point.payload, Some(serde_json::json!({"a": 1})));
+
+    let payload_only =
+        collection.get_projected(&[1, 2, 3], crate::point::PointProjection::new(false, true));
+    let point = payload_only[0].as_ref().expect("point 1 exists");
+    assert!(point.vector.is_empty());
+    assert_eq!(point.payload, Some(serde_json::json!({"a": 1})));
+    let point = payload_only[1]
+        .as_ref()
+        .expect("point without payload still exists");
+    assert!(point.payload.is_none());
+    assert!(payload_only[2].is_none(), "unknown id must be None");
+
+    let vector_only =
+        collection.get_projected(&[1], crate::point::PointProjection::new(true, false));
+    let point = vector_only[0].as_ref().expect("point 1 exists");
+    assert_eq!(point.vector, vec![1.0, 0.0, 0.0]);
+    assert!(point.payload.is_none());
+}
+
+// === Non-finite / zero-norm vector validation ===
+
+#[test]
+fn test_upsert_rejects_non_finite_vectors_on_every_ingest_path() {
+    let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+    let collection =
+        Collection::create(PathBuf::from(temp_dir.path()), 2, DistanceMetric::Euclidean)
+            .expect("collection should be created");
+
+    let err = collection
+        .upsert(vec![
+            Point::without_payload(1, vec![1.0, 0.0]),
+            Point::without_payload(2, vec![f32::NAN, 1.0]),
+        ])
+        .expect_err("NaN component must be rejected");
+    assert_eq!(err.code(), "VELES-005");
+    assert!(err.to_string().contains("point 2"), "{err}");
+
+    let inf = [Point::without_payload(3, vec![1.0, f32::INFINITY])];
+    assert!(collection.upsert_bulk(&inf).is_err());
+    assert!(collection
+        .upsert_bulk_from_raw(&[1.0, 2.0, f32::NEG_INFINITY, 0.0], &[4, 5], 2, None)
+        .is_err());
+
+    // The whole batch is rejected, including its valid points.
+    assert!(collection.get(&[1, 3, 4]).iter().all(Option::is_none));
+}
+
+#[test]
+fn test_upsert_sanitizes_non_finite_vectors_when_configured() {
+    use crate::collection::RuntimeLimits;
+    use crate::config::NonFiniteVectorPolicy;
+
+    let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+    let collection =
+        Collection::create(PathBuf::from(temp_dir.path()), 2, DistanceMetric::Euclidean)
+            .expect("collection should be created");
+    collection.set_runtime_limits(RuntimeLimits {
+        non_finite_vectors: NonFiniteVectorPolicy::SanitizeToZero,
+        ..RuntimeLimits::default()
+    });
+
+    collection
+        .upsert(vec![Point::without_payload(1, vec![f32::NAN, 0.5])])
+        .expect("upsert");
+    collection
+        .upsert_bulk(&[Point::without_payload(2, vec![2.0, f32::INFINITY])])
+        .expect("upsert_bulk");
+    collection
+        .upsert_bulk_from_raw(&[f32::NEG_INFINITY, 3.0], &[3], 2, None)
+        .expect("upsert_bulk_from_raw");
+
+    let stored: Vec<Vec<f32>> = collection
+        .get(&[1, 2, 3])
+        .into_iter()
+        .map(|p| p.expect("point stored").vector)
+        .collect();
+    assert_eq!(stored, vec![vec![0.0, 0.5], vec![2.0, 0.0], vec![0.0, 3.0]]);
+
+    let results = collection.search(&[0.0, 0.5], 3).expect("search");
+    assert!(results.iter().all(|r| r.score.is_finite()));
+}
+
+#[test]
+fn test_cosine_collection_rejects_zero_norm_vectors_when_configured() {
+    use crate::collection::RuntimeLimits;
+
+    let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+    let collection = Collection::create(PathBuf::from(temp_dir.path()), 3, DistanceMetric::Cosine)
+        .expect("collection should be created");
+
+    // Off by default.
+    collection
+        .upsert(vec![Point::without_payload(6, vec![0.0; 3])])
+        .expect("zero vector accepted by default");
+
+    collection.set_runtime_limits(RuntimeLimits {
+        reject_zero_norm_vectors: true,
+        ..RuntimeLimits::default()
+    });
+    let err = collection
+        .upsert(vec![Point::without_payload(7, vec![0.0; 3])])
+        .expect_err("zero vector must be rejected on cosine");
+    assert_eq!(err.code(), "VELES-005");
+    assert!(err.to_string().contains("zero-norm"), "{err}");
+    assert!(collection
+        .upsert_bulk_from_raw(&[0.0; 3], &[8], 3, None)
+        .is_err());
+}
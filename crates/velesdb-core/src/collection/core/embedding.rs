@@ -0,0 +1,96 @@
+//! Embedding model metadata: persistence and declared-model compatibility checks.
+
+use crate::collection::embedding_info::EmbeddingInfo;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+
+impl Collection {
+    /// Records (or clears, with `None`) the embedding model that produced
+    /// this collection's vectors and persists it to `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::VectorNotAllowed`] on a metadata-only collection.
+    /// - [`Error::Config`] when the model name is blank or `info.dimension`
+    ///   differs from the collection's vector dimension.
+    /// - I/O errors from persisting the config (the in-memory value is
+    ///   rolled back so memory and disk never disagree).
+    pub fn set_embedding_info(&self, info: Option<EmbeddingInfo>) -> Result<()> {
+        let previous = {
+            let mut config = self.storage.config.write();
+            if let Some(ref info) = info {
+                Self::validate_embedding_info(&config, info)?;
+            }
+            std::mem::replace(&mut config.embedding_info, info)
+        };
+        if let Err(e) = self.save_config() {
+            self.storage.config.write().embedding_info = previous;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Returns the recorded embedding model metadata, if any.
+    #[must_use]
+    pub fn embedding_info(&self) -> Option<EmbeddingInfo> {
+        self.storage.config.read().embedding_info.clone()
+    }
+
+    /// Checks a client-declared embedding model against the recorded one.
+    ///
+    /// Succeeds when no model is recorded (nothing to compare against) or
+    /// when [`EmbeddingInfo::matches_declared`] accepts `declared`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SchemaValidation`] naming both models on mismatch.
+    pub fn check_embedding_model(&self, declared: &str) -> Result<()> {
+        let config = self.storage.config.read();
+        let Some(info) = config.embedding_info.as_ref() else {
+            return Ok(());
+        };
+        if info.matches_declared(declared) {
+            return Ok(());
+        }
+        tracing::warn!(
+            collection = %config.name,
+            expected = %info.qualified_name(),
+            declared = %declared,
+            "embedding model mismatch"
+        );
+        Err(Error::SchemaValidation(format!(
+            "embedding model mismatch on collection '{}': vectors were produced by '{}', \
+             request declares '{}'. Re-embed with the recorded model or reindex into a new collection.",
+            config.name,
+            info.qualified_name(),
+            declared.trim()
+        )))
+    }
+
+    /// Validates `info` against the collection's shape before it is stored.
+    fn validate_embedding_info(
+        config: &crate::collection::CollectionConfig,
+        info: &EmbeddingInfo,
+    ) -> Result<()> {
+        if config.metadata_only {
+            return Err(Error::VectorNotAllowed(config.name.clone()));
+        }
+        if info.model.trim().is_empty() {
+            return Err(Error::Config(
+                "embedding model name must not be empty".to_string(),
+            ));
+        }
+        let expected = if config.graph_schema.is_some() {
+            config.embedding_dimension.unwrap_or(0)
+        } else {
+            config.dimension
+        };
+        if info.dimension != expected {
+            return Err(Error::Config(format!(
+                "embedding model '{}' produces {}-dimensional vectors but collection '{}' stores {expected}",
+                info.model, info.dimension, config.name
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::collection::embedding_info::EmbeddingInfo;
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use std::path::PathBuf;
+
+fn temp_collection(dimension: usize) -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), dimension, DistanceMetric::Cosine)
+        .expect("collection created");
+    (dir, col)
+}
+
+#[test]
+fn test_embedding_info_persists_across_reopen() {
+    let (dir, col) = temp_collection(4);
+    let info = EmbeddingInfo::new("bge-m3", 4).with_version("1.5");
+    col.set_embedding_info(Some(info.clone())).expect("set");
+    assert_eq!(col.embedding_info(), Some(info.clone()));
+    drop(col);
+
+    let reopened = Collection::open(PathBuf::from(dir.path())).expect("reopen");
+    assert_eq!(reopened.embedding_info(), Some(info));
+}
+
+#[test]
+fn test_embedding_info_dimension_mismatch_rejected() {
+    let (_dir, col) = temp_collection(4);
+    let err = col
+        .set_embedding_info(Some(EmbeddingInfo::new("bge-m3", 1024)))
+        .expect_err("dimension mismatch must be rejected");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+    assert!(col.embedding_info().is_none());
+}
+
+#[test]
+fn test_embedding_info_metadata_only_rejected() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create_metadata_only(PathBuf::from(dir.path()), "meta").expect("create");
+    let err = col
+        .set_embedding_info(Some(EmbeddingInfo::new("bge-m3", 0)))
+        .expect_err("metadata-only must reject embedding info");
+    assert!(matches!(err, Error::VectorNotAllowed(_)), "got {err:?}");
+}
+
+#[test]
+fn test_check_embedding_model_without_info_accepts_anything() {
+    let (_dir, col) = temp_collection(4);
+    assert!(col.check_embedding_model("any-model").is_ok());
+}
+
+#[test]
+fn test_check_embedding_model_detects_switch() {
+    let (_dir, col) = temp_collection(4);
+    col.set_embedding_info(Some(EmbeddingInfo::new("text-embedding-3-small", 4)))
+        .expect("set");
+
+    assert!(col.check_embedding_model("text-embedding-3-small").is_ok());
+    assert!(col
+        .check_embedding_model(" Text-Embedding-3-Small ")
+        .is_ok());
+    let err = col
+        .check_embedding_model("text-embedding-3-large")
+        .expect_err("switched model must be rejected");
+    assert_eq!(err.code(), "VELES-017");
+    assert!(err.to_string().contains("text-embedding-3-large"));
+}
+
+#[test]
+fn test_check_embedding_model_version_pin() {
+    let (_dir, col) = temp_collection(4);
+    col.set_embedding_info(Some(EmbeddingInfo::new("bge-m3", 4).with_version("2")))
+        .expect("set");
+
+    assert!(col.check_embedding_model("bge-m3@2").is_ok());
+    assert!(col.check_embedding_model("bge-m3").is_ok());
+    assert!(col.check_embedding_model("bge-m3@1").is_err());
+}
+
+#[test]
+fn test_clearing_embedding_info_disables_check() {
+    let (_dir, col) = temp_collection(4);
+    col.set_embedding_info(Some(EmbeddingInfo::new("bge-m3", 4)))
+        .expect("set");
+    col.set_embedding_info(None).expect("clear");
+    assert!(col.check_embedding_model("other").is_ok());
+}
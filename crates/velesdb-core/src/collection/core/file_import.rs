@@ -0,0 +1,371 @@
+//! Streaming JSONL/CSV import into a collection.
+//!
+//! Rows are parsed with an [`ImportMapping`], validated against the
+//! collection's dimension and written in `upsert_bulk` batches. The options
+//! and report types live in `collection::file_import`.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::collection::file_import::{
+    ImportMapping, ImportProgress, ImportReport, ImportRowError, OnBadRow, MAX_REPORTED_ROW_ERRORS,
+};
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::point::Point;
+
+/// Read buffer for import files.
+const READ_BUFFER_BYTES: usize = 128 * 1024;
+
+impl Collection {
+    /// Imports a JSON Lines file, one object per line.
+    ///
+    /// See [`Self::import_jsonl_with_progress`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::import_jsonl_with_progress`].
+    pub fn import_jsonl(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+    ) -> Result<ImportReport> {
+        self.import_jsonl_with_progress(path, mapping, |_| {})
+    }
+
+    /// Imports a JSON Lines file, calling `on_progress` after each batch.
+    ///
+    /// Each non-blank line is an object with the mapping's id and vector
+    /// keys. The id is a number or a numeric string; the vector is a JSON
+    /// array or a string in the mapping's [`VectorEncoding`]. The other keys
+    /// become payload fields (see [`ImportMapping::payload_columns`]).
+    ///
+    /// [`VectorEncoding`]: crate::collection::file_import::VectorEncoding
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Io` if the file cannot be read.
+    /// - `Error::Serialization` naming the line of the first bad row under
+    ///   [`OnBadRow::Abort`]; the rows before it are written.
+    /// - Storage and validation errors from the batch writes.
+    pub fn import_jsonl_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+        on_progress: impl FnMut(ImportProgress),
+    ) -> Result<ImportReport> {
+        let file = File::open(path)?;
+        let total_bytes = file.metadata()?.len();
+        let mut reader = BufReader::with_capacity(READ_BUFFER_BYTES, file);
+        let mut sink = ImportSink::new(self, mapping, total_bytes, on_progress);
+
+        let mut line = String::new();
+        let mut line_no = 0u64;
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            line_no += 1;
+            sink.bytes_read += read as u64;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = parse_json_row(mapping, &line, sink.dimension);
+            sink.accept(line_no, row)?;
+        }
+        sink.finish()
+    }
+
+    /// Imports a CSV file with a header row.
+    ///
+    /// See [`Self::import_csv_with_progress`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::import_csv_with_progress`].
+    pub fn import_csv(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+    ) -> Result<ImportReport> {
+        self.import_csv_with_progress(path, mapping, |_| {})
+    }
+
+    /// Imports a CSV file with a header row, calling `on_progress` after
+    /// each batch.
+    ///
+    /// The id cell is a `u64` and the vector cell is parsed with the
+    /// mapping's encoding. Payload cells are stored as strings.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Io` if the file cannot be read.
+    /// - `Error::Config` if a mapped column is missing from the header.
+    /// - `Error::Serialization` if the header cannot be read, or naming the
+    ///   line of the first bad row under [`OnBadRow::Abort`]; the rows
+    ///   before it are written.
+    /// - Storage and validation errors from the batch writes.
+    pub fn import_csv_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+        on_progress: impl FnMut(ImportProgress),
+    ) -> Result<ImportReport> {
+        let file = File::open(path)?;
+        let total_bytes = file.metadata()?.len();
+        let mut reader =
+            csv::Reader::from_reader(BufReader::with_capacity(READ_BUFFER_BYTES, file));
+        let mut sink = ImportSink::new(self, mapping, total_bytes, on_progress);
+        let columns = CsvColumns::resolve(
+            mapping,
+            reader
+                .headers()
+                .map_err(|e| Error::Serialization(format!("invalid CSV header: {e}")))?,
+            sink.dimension.is_some(),
+        )?;
+
+        let mut record = csv::StringRecord::new();
+        loop {
+            let (line, row) = match reader.read_record(&mut record) {
+                Ok(false) => break,
+                Ok(true) => (
+                    record.position().map_or(0, csv::Position::line),
+                    columns.parse_row(mapping, &record, sink.dimension),
+                ),
+                Err(e) if !e.is_io_error() => (
+                    e.position().map_or(0, csv::Position::line),
+                    Err(e.to_string()),
+                ),
+                Err(e) => match e.into_kind() {
+                    csv::ErrorKind::Io(e) => return Err(Error::Io(e)),
+                    kind => return Err(Error::Serialization(format!("{kind:?}"))),
+                },
+            };
+            sink.bytes_read = reader.position().byte();
+            sink.accept(line, row)?;
+        }
+        sink.finish()
+    }
+}
+
+/// Batches parsed rows into the collection and tallies the report.
+struct ImportSink<'a, F> {
+    collection: &'a Collection,
+    mapping: &'a ImportMapping,
+    /// Expected vector length; `None` for metadata-only collections.
+    dimension: Option<usize>,
+    batch: Vec<Point>,
+    report: ImportReport,
+    bytes_read: u64,
+    total_bytes: u64,
+    on_progress: F,
+}
+
+impl<'a, F: FnMut(ImportProgress)> ImportSink<'a, F> {
+    fn new(
+        collection: &'a Collection,
+        mapping: &'a ImportMapping,
+        total_bytes: u64,
+        on_progress: F,
+    ) -> Self {
+        let config = collection.config();
+        let batch_size = mapping.batch_size.max(1);
+        Self {
+            collection,
+            mapping,
+            dimension: (!config.metadata_only).then_some(config.dimension),
+            batch: Vec::with_capacity(batch_size),
+            report: ImportReport::default(),
+            bytes_read: 0,
+            total_bytes,
+            on_progress,
+        }
+    }
+
+    fn accept(&mut self, line: u64, row: std::result::Result<Point, String>) -> Result<()> {
+        self.report.rows += 1;
+        match row {
+            Ok(point) => {
+                self.batch.push(point);
+                if self.batch.len() >= self.mapping.batch_size.max(1) {
+                    self.write_batch()?;
+                    self.report_progress();
+                }
+            }
+            Err(message) if self.mapping.on_bad_row == OnBadRow::Abort => {
+                self.write_batch()?;
+                return Err(Error::Serialization(format!("line {line}: {message}")));
+            }
+            Err(message) => {
+                self.report.skipped += 1;
+                if self.report.errors.len() < MAX_REPORTED_ROW_ERRORS {
+                    self.report.errors.push(ImportRowError { line, message });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_batch(&mut self) -> Result<()> {
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+        self.report.imported += if self.dimension.is_some() {
+            self.collection.upsert_bulk(&self.batch)?
+        } else {
+            let count = self.batch.len();
+            self.collection.upsert(self.batch.drain(..))?;
+            count
+        };
+        self.batch.clear();
+        Ok(())
+    }
+
+    fn report_progress(&mut self) {
+        (self.on_progress)(ImportProgress {
+            rows: self.report.rows,
+            imported: self.report.imported,
+            skipped: self.report.skipped,
+            bytes_read: self.bytes_read,
+            total_bytes: self.total_bytes,
+        });
+    }
+
+    fn finish(mut self) -> Result<ImportReport> {
+        self.write_batch()?;
+        self.report_progress();
+        Ok(self.report)
+    }
+}
+
+/// Parses one JSONL line into a point.
+fn parse_json_row(
+    mapping: &ImportMapping,
+    line: &str,
+    dimension: Option<usize>,
+) -> std::result::Result<Point, String> {
+    let Value::Object(mut fields) =
+        serde_json::from_str::<Value>(line).map_err(|e| format!("invalid JSON: {e}"))?
+    else {
+        return Err("expected a JSON object".to_string());
+    };
+    let id = match fields.remove(&mapping.id_column) {
+        Some(Value::Number(n)) => n.as_u64(),
+        Some(Value::String(s)) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| format!("missing or invalid id '{}'", mapping.id_column))?;
+
+    let cell = fields.remove(&mapping.vector_column);
+    let vector = match dimension {
+        None => Vec::new(),
+        Some(dimension) => {
+            let vector = match cell {
+                Some(array @ Value::Array(_)) => serde_json::from_value(array)
+                    .map_err(|e| format!("invalid JSON vector: {e}"))?,
+                Some(Value::String(text)) => mapping.vector_encoding.parse(&text)?,
+                _ => return Err(format!("missing vector '{}'", mapping.vector_column)),
+            };
+            check_vector(&vector, dimension)?;
+            vector
+        }
+    };
+
+    let mut payload = Map::new();
+    for (column, value) in fields {
+        if mapping.is_payload_column(&column) {
+            ImportMapping::insert_payload_field(&mut payload, &column, value);
+        }
+    }
+    Ok(build_point(id, vector, payload))
+}
+
+/// Header positions of the mapped CSV columns.
+struct CsvColumns {
+    id: usize,
+    vector: Option<usize>,
+    payload: Vec<(usize, String)>,
+}
+
+impl CsvColumns {
+    fn resolve(
+        mapping: &ImportMapping,
+        headers: &csv::StringRecord,
+        needs_vector: bool,
+    ) -> Result<Self> {
+        let position = |name: &str| {
+            headers
+                .iter()
+                .position(|h| h == name)
+                .ok_or_else(|| Error::Config(format!("column '{name}' not found in CSV header")))
+        };
+        if let Some(columns) = &mapping.payload_columns {
+            for column in columns {
+                position(column)?;
+            }
+        }
+        Ok(Self {
+            id: position(&mapping.id_column)?,
+            vector: if needs_vector {
+                Some(position(&mapping.vector_column)?)
+            } else {
+                None
+            },
+            payload: headers
+                .iter()
+                .enumerate()
+                .filter(|(_, h)| mapping.is_payload_column(h))
+                .map(|(i, h)| (i, h.to_string()))
+                .collect(),
+        })
+    }
+
+    fn parse_row(
+        &self,
+        mapping: &ImportMapping,
+        record: &csv::StringRecord,
+        dimension: Option<usize>,
+    ) -> std::result::Result<Point, String> {
+        let cell = |index: usize| record.get(index).unwrap_or_default();
+        let id = cell(self.id)
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| format!("invalid id '{}'", cell(self.id)))?;
+        let vector = match (self.vector, dimension) {
+            (Some(index), Some(dimension)) => {
+                let vector = mapping.vector_encoding.parse(cell(index))?;
+                check_vector(&vector, dimension)?;
+                vector
+            }
+            _ => Vec::new(),
+        };
+        let mut payload = Map::new();
+        for (index, column) in &self.payload {
+            payload.insert(column.clone(), Value::String(cell(*index).to_string()));
+        }
+        Ok(build_point(id, vector, payload))
+    }
+}
+
+fn check_vector(vector: &[f32], dimension: usize) -> std::result::Result<(), String> {
+    if vector.len() != dimension {
+        return Err(format!(
+            "vector has dimension {}, expected {dimension}",
+            vector.len()
+        ));
+    }
+    if vector.iter().any(|v| !v.is_finite()) {
+        return Err("vector has a non-finite component".to_string());
+    }
+    Ok(())
+}
+
+fn build_point(id: u64, vector: Vec<f32>, payload: Map<String, Value>) -> Point {
+    let payload = (!payload.is_empty()).then_some(Value::Object(payload));
+    Point::new(id, vector, payload)
+}
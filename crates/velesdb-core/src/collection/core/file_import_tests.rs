@@ -0,0 +1,188 @@
+//! Tests for JSONL/CSV file import (`import_jsonl` / `import_csv`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::path::PathBuf;
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::collection::file_import::{ImportMapping, OnBadRow, VectorEncoding};
+use crate::collection::types::Collection;
+use crate::error::Error;
+use crate::wire::f32_base64;
+use crate::DistanceMetric;
+
+fn create_collection() -> (Collection, TempDir) {
+    let dir = TempDir::new().expect("test: temp dir");
+    let col = Collection::create(dir.path().join("col"), 3, DistanceMetric::Cosine)
+        .expect("test: create collection");
+    (col, dir)
+}
+
+fn write_file(dir: &TempDir, name: &str, contents: &str) -> PathBuf {
+    let path = dir.path().join(name);
+    std::fs::write(&path, contents).expect("test: write file");
+    path
+}
+
+fn payload(col: &Collection, id: u64) -> serde_json::Value {
+    col.get(&[id])[0]
+        .as_ref()
+        .and_then(|p| p.payload.clone())
+        .expect("test: payload")
+}
+
+#[test]
+fn test_import_jsonl_maps_columns_and_flattens_payload() {
+    let (col, dir) = create_collection();
+    let path = write_file(
+        &dir,
+        "points.jsonl",
+        concat!(
+            r#"{"id": 1, "vector": [1.0, 0.0, 0.0], "payload": {"title": "a"}, "lang": "en"}"#,
+            "\n\n",
+            r#"{"id": "2", "vector": "0,1,0", "title": "b"}"#,
+            "\n",
+        ),
+    );
+
+    let report = col
+        .import_jsonl(&path, &ImportMapping::default())
+        .expect("test: import");
+
+    assert_eq!((report.rows, report.imported, report.skipped), (2, 2, 0));
+    assert_eq!(payload(&col, 1), json!({"title": "a", "lang": "en"}));
+    assert_eq!(payload(&col, 2), json!({"title": "b"}));
+}
+
+#[test]
+fn test_import_jsonl_custom_columns_and_payload_selection() {
+    let (col, dir) = create_collection();
+    let vector = f32_base64::encode(&[0.0, 0.0, 1.0]);
+    let path = write_file(
+        &dir,
+        "points.jsonl",
+        &format!(r#"{{"doc_id": 7, "emb": "{vector}", "title": "t", "noise": 1}}"#),
+    );
+    let mapping = ImportMapping {
+        id_column: "doc_id".to_string(),
+        vector_column: "emb".to_string(),
+        vector_encoding: VectorEncoding::Base64F32,
+        payload_columns: Some(vec!["title".to_string()]),
+        ..ImportMapping::default()
+    };
+
+    let report = col.import_jsonl(&path, &mapping).expect("test: import");
+
+    assert_eq!(report.imported, 1);
+    let point = col.get(&[7])[0].clone().expect("test: point");
+    assert_eq!(point.vector, vec![0.0, 0.0, 1.0]);
+    assert_eq!(point.payload, Some(json!({"title": "t"})));
+}
+
+#[test]
+fn test_import_jsonl_skips_bad_rows_with_line_numbers() {
+    let (col, dir) = create_collection();
+    let path = write_file(
+        &dir,
+        "points.jsonl",
+        concat!(
+            r#"{"id": 1, "vector": [1.0, 0.0, 0.0]}"#,
+            "\nnot json\n",
+            r#"{"id": 3, "vector": [1.0, 0.0]}"#,
+            "\n",
+            r#"{"vector": [1.0, 0.0, 0.0]}"#,
+            "\n",
+            r#"{"id": 5, "vector": [0.0, 1.0, 0.0]}"#,
+            "\n",
+        ),
+    );
+
+    let report = col
+        .import_jsonl(&path, &ImportMapping::default())
+        .expect("test: import");
+
+    assert_eq!((report.rows, report.imported, report.skipped), (5, 2, 3));
+    let lines: Vec<u64> = report.errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, vec![2, 3, 4]);
+    assert!(report.errors[1].message.contains("dimension 2, expected 3"));
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn test_import_jsonl_abort_keeps_rows_before_the_bad_one() {
+    let (col, dir) = create_collection();
+    let path = write_file(
+        &dir,
+        "points.jsonl",
+        concat!(
+            r#"{"id": 1, "vector": [1.0, 0.0, 0.0]}"#,
+            "\n",
+            r#"{"id": 2, "vector": [0.0, 1.0, 0.0]}"#,
+            "\n",
+            r#"{"id": 3, "vector": [0.0, 1.0]}"#,
+            "\n",
+            r#"{"id": 4, "vector": [0.0, 0.0, 1.0]}"#,
+            "\n",
+        ),
+    );
+    let mapping = ImportMapping {
+        on_bad_row: OnBadRow::Abort,
+        ..ImportMapping::default()
+    };
+
+    let err = col.import_jsonl(&path, &mapping).unwrap_err();
+
+    assert!(matches!(&err, Error::Serialization(m) if m.starts_with("line 3:")));
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn test_import_csv_with_mapping_and_progress() {
+    let (col, dir) = create_collection();
+    let path = write_file(
+        &dir,
+        "points.csv",
+        concat!(
+            "key,embedding,title,skip_me\n",
+            "1,\"[1.0, 0.0, 0.0]\",first,x\n",
+            "2,\"0,1,0\",second,x\n",
+            "oops,\"0,0,1\",third,x\n",
+            "4,\"0,0,1\",fourth,x\n",
+        ),
+    );
+    let mapping = ImportMapping {
+        id_column: "key".to_string(),
+        vector_column: "embedding".to_string(),
+        payload_columns: Some(vec!["title".to_string()]),
+        batch_size: 2,
+        ..ImportMapping::default()
+    };
+    let mut events = Vec::new();
+
+    let report = col
+        .import_csv_with_progress(&path, &mapping, |p| events.push(p))
+        .expect("test: import");
+
+    assert_eq!((report.rows, report.imported, report.skipped), (4, 3, 1));
+    assert_eq!(report.errors[0].line, 4);
+    assert_eq!(payload(&col, 2), json!({"title": "second"}));
+    let last = events.last().expect("test: progress");
+    assert_eq!(last.imported, 3);
+    assert_eq!(last.bytes_read, last.total_bytes);
+    assert!(events.len() >= 2);
+}
+
+#[test]
+fn test_import_csv_missing_column_is_an_error() {
+    let (col, dir) = create_collection();
+    let path = write_file(&dir, "points.csv", "id,vec\n1,\"1,0,0\"\n");
+
+    let err = col
+        .import_csv(&path, &ImportMapping::default())
+        .unwrap_err();
+
+    assert!(matches!(&err, Error::Config(m) if m.contains("'vector'")));
+    assert!(col.is_empty());
+}
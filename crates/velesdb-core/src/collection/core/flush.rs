@@ -6,6 +6,7 @@
 //! - Delta/deferred buffer draining into HNSW
 //! - Secondary index and sparse index persistence
 
+use crate::collection::index_progress::IndexBuildPhase;
 use crate::collection::types::Collection;
 use crate::error::{Error, Result};
 use crate::storage::{PayloadStorage, VectorStorage};
@@ -17,6 +18,10 @@ impl Collection {
     /// saves the HNSW graph as a safety measure to limit recovery time.
     const HNSW_SAVE_THRESHOLD: u64 = 10_000;
 
+    /// Progress steps reported by `flush`/`flush_full`: storage, HNSW
+    /// graph, derived indexes.
+    const FLUSH_STEPS: usize = 3;
+
     /// Fast durability flush — persists WAL + mmap but defers HNSW save.
     ///
     /// Issue #423 Component 3: `index.save()` is skipped unless the insert
@@ -29,12 +34,17 @@ impl Collection {
     ///
     /// Returns an error if storage operations fail.
     pub fn flush(&self) -> Result<()> {
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::Flush)?;
+        let progress = &self.streaming.progress;
+        progress.emit(IndexBuildPhase::Flushing, 0, Self::FLUSH_STEPS);
         self.save_config()?;
         // Issue #423: vector_storage.flush() is now a fast path (WAL + mmap
         // only, no vectors.idx serialization). The WAL provides crash recovery
         // even with a stale index file.
         self.storage.vector_storage.write().flush()?;
         self.storage.payload_storage.write().flush()?;
+        progress.emit(IndexBuildPhase::Flushing, 1, Self::FLUSH_STEPS);
         // Drain delta buffer into HNSW before persisting the index.
         // Lock order: delta_buffer(10) is acquired after vector_storage(2)
         // and payload_storage(3) — both already released above.
@@ -48,8 +58,13 @@ impl Collection {
         // Issue #423 Component 3: Save HNSW only when insert threshold
         // exceeded. Otherwise defer to flush_full() (shutdown/compaction).
         self.save_hnsw_if_threshold_exceeded()?;
+        progress.emit(IndexBuildPhase::Flushing, 2, Self::FLUSH_STEPS);
         self.flush_secondary_indexes()?;
-        self.flush_sparse_indexes()
+        self.flush_sparse_indexes()?;
+        self.persist_access_log()?;
+        self.persist_access_counts()?;
+        progress.emit(IndexBuildPhase::Flushing, 3, Self::FLUSH_STEPS);
+        Ok(())
     }
 
     /// Full durability flush including HNSW save and `vectors.idx`.
@@ -63,10 +78,18 @@ impl Collection {
     ///
     /// Returns an error if storage operations fail.
     pub fn flush_full(&self) -> Result<()> {
+        #[cfg(feature = "chaos")]
+        self.inject_fault(crate::collection::chaos::FaultPoint::Flush)?;
+        let progress = &self.streaming.progress;
+        progress.emit(IndexBuildPhase::Flushing, 0, Self::FLUSH_STEPS);
         self.flush_core_storage()?;
+        progress.emit(IndexBuildPhase::Flushing, 2, Self::FLUSH_STEPS);
         self.flush_derived_indexes()?;
+        self.persist_access_log()?;
+        self.persist_access_counts()?;
         // Write the deferred vectors.idx AFTER all other flush steps.
         self.storage.vector_storage.read().flush_index()?;
+        progress.emit(IndexBuildPhase::Flushing, 3, Self::FLUSH_STEPS);
         Ok(())
     }
 
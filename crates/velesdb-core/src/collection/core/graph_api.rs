@@ -5,7 +5,10 @@
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::collection::graph::{GraphEdge, GraphSchema, TraversalConfig, TraversalResult};
+use crate::collection::graph::{
+    BfsCursor, GraphEdge, GraphSchema, StreamingConfig, TraversalConfig, TraversalResult,
+    CURSOR_MAX_VISITED_SIZE,
+};
 use crate::collection::types::Collection;
 use crate::error::{Error, Result};
 use crate::index::VectorIndex;
@@ -657,6 +660,7 @@ impl Collection {
         // Node payload writes bypass the upsert mirror hooks — drop the
         // payload mirror so it can never serve stale columnar data.
         self.storage.payload_mirror.invalidate();
+        self.sync_partial_indexes([node_id]);
 
         // Bump write generation so any cached plan for this collection is
         // invalidated on the next query (CACHE-01).
@@ -682,6 +686,7 @@ impl Collection {
     /// BFS traversal using the core `concurrent_bfs_stream` iterator.
     ///
     /// Wraps [`Self::traverse_bfs_config_inner`] with traversal metrics timing.
+    /// `config.limit` is capped at [`Self::max_query_limit`].
     #[must_use]
     pub fn traverse_bfs_config(
         &self,
@@ -689,7 +694,8 @@ impl Collection {
         config: &TraversalConfig,
     ) -> Vec<TraversalResult> {
         let start = std::time::Instant::now();
-        let results = self.traverse_bfs_config_inner(source_id, config);
+        let config = self.capped_traversal_config(config);
+        let results = self.traverse_bfs_config_inner(source_id, &config);
         self.graph
             .edge_store
             .metrics()
@@ -704,15 +710,23 @@ impl Collection {
         source_id: u64,
         config: &TraversalConfig,
     ) -> Vec<TraversalResult> {
-        use crate::collection::graph::{concurrent_bfs_stream, StreamingConfig, MAX_VISITED_SIZE};
+        use crate::collection::graph::{concurrent_bfs_stream, MAX_VISITED_SIZE};
 
+        if config.max_cost.is_some() {
+            return self.traverse_cost_bounded(source_id, config);
+        }
         // Issue #905 debounce: only pay for the O(N+E) CSR rebuild when the
         // snapshot is already authoritative (no pending writes) or enough
         // writes have accumulated to make the rebuild worthwhile. While the
         // snapshot is stale-but-below-threshold we serve from the
         // authoritative per-shard streaming path instead of rebuilding on
         // every read — correct results, no stale data, no rebuild.
-        if self.graph.edge_store.csr_is_authoritative() || self.graph.edge_store.csr_rebuild_due() {
+        // The CSR snapshot carries no edge properties, so `valid_at` filters
+        // always take the streaming path.
+        if config.valid_at.is_none()
+            && (self.graph.edge_store.csr_is_authoritative()
+                || self.graph.edge_store.csr_rebuild_due())
+        {
             // Prefer the lock-free CSR snapshot path when available (Issue #491).
             let snapshot = self.graph.edge_store.get_csr_snapshot();
             // Guard: only use the CSR path when the snapshot has been populated
@@ -731,6 +745,8 @@ impl Collection {
             limit: Some(config.limit),
             max_visited_size: MAX_VISITED_SIZE,
             deadline: config.deadline,
+            valid_at: config.valid_at,
+            max_cost: None,
         };
         concurrent_bfs_stream(&self.graph.edge_store, source_id, streaming)
             .filter(|result| result.depth >= config.min_depth)
@@ -738,9 +754,51 @@ impl Collection {
             .collect()
     }
 
+    /// Starts a resumable BFS from `source_id` for paged traversals.
+    ///
+    /// Depth range, rel-type filter, `valid_at` and `max_cost` come from
+    /// `config`; `config.limit` is ignored since each
+    /// [`Self::next_traversal_page`] call sizes its own page. The cursor
+    /// always walks the per-shard edge store, never the CSR snapshot, so
+    /// pages stay consistent with writes made between them.
+    #[must_use]
+    pub fn traversal_cursor(&self, source_id: u64, config: &TraversalConfig) -> BfsCursor {
+        let streaming = StreamingConfig {
+            max_depth: config.max_depth,
+            rel_types: config.rel_types.clone(),
+            limit: None,
+            max_visited_size: CURSOR_MAX_VISITED_SIZE,
+            deadline: None,
+            valid_at: config.valid_at,
+            max_cost: config.max_cost,
+        };
+        BfsCursor::new(source_id, streaming).with_min_depth(config.min_depth)
+    }
+
+    /// Returns the next page of at most `limit` results from `cursor`, and
+    /// whether more results remain.
+    ///
+    /// `limit` is capped at [`Self::max_query_limit`].
+    pub fn next_traversal_page(
+        &self,
+        cursor: &mut BfsCursor,
+        limit: usize,
+    ) -> (Vec<TraversalResult>, bool) {
+        let start = std::time::Instant::now();
+        let edge_store = &self.graph.edge_store;
+        let page = cursor.next_page(edge_store, limit.min(self.max_query_limit()));
+        let has_more = cursor.has_more(edge_store);
+        edge_store
+            .metrics()
+            .record_traversal(start.elapsed(), page.len() as u64);
+        (page, has_more)
+    }
+
     /// DFS traversal (iterative) using `TraversalConfig`.
     ///
     /// Wraps [`Self::traverse_dfs_config_inner`] with traversal metrics timing.
+    /// `config.limit` is capped at [`Self::max_query_limit`]. With
+    /// `config.max_cost` the traversal is cost-ordered, as for BFS.
     #[must_use]
     pub fn traverse_dfs_config(
         &self,
@@ -748,7 +806,8 @@ impl Collection {
         config: &TraversalConfig,
     ) -> Vec<TraversalResult> {
         let start = std::time::Instant::now();
-        let results = self.traverse_dfs_config_inner(source_id, config);
+        let config = self.capped_traversal_config(config);
+        let results = self.traverse_dfs_config_inner(source_id, &config);
         self.graph
             .edge_store
             .metrics()
@@ -765,6 +824,10 @@ impl Collection {
         source_id: u64,
         config: &TraversalConfig,
     ) -> Vec<TraversalResult> {
+        // A cost budget needs cheapest-first order, which DFS cannot give.
+        if config.max_cost.is_some() {
+            return self.traverse_cost_bounded(source_id, config);
+        }
         let rel_filter: FxHashSet<&str> = config.rel_types.iter().map(String::as_str).collect();
 
         let mut results = Vec::new();
@@ -815,6 +878,7 @@ impl Collection {
                     node_id,
                     depth,
                     &rel_filter,
+                    config.valid_at,
                     &visited,
                     &mut frontier,
                 );
@@ -823,13 +887,56 @@ impl Collection {
         results
     }
 
+    /// Cost-ordered (Dijkstra) traversal used when `config.max_cost` is set.
+    fn traverse_cost_bounded(
+        &self,
+        source_id: u64,
+        config: &TraversalConfig,
+    ) -> Vec<TraversalResult> {
+        crate::collection::graph::CostBoundedSearch::for_traversal(source_id, config).collect(
+            |node| self.graph.edge_store.get_outgoing_for_traversal(node),
+            config.limit,
+        )
+    }
+
+    /// Shortest directed path from `source_id` to `target_id`.
+    ///
+    /// Runs a bidirectional BFS, which explores about `2·b^(d/2)` nodes
+    /// instead of `b^d` for a path of length `d` and branching factor `b`.
+    /// Paths are at most `config.max_depth` hops and follow only edges
+    /// passing `config.rel_types` and `valid_at`. Returns `None` when no such
+    /// path exists or the deadline expires first.
+    #[must_use]
+    pub fn shortest_path(
+        &self,
+        source_id: u64,
+        target_id: u64,
+        config: &TraversalConfig,
+    ) -> Option<TraversalResult> {
+        let start = std::time::Instant::now();
+        let result = crate::collection::graph::bidirectional_bfs(
+            source_id,
+            target_id,
+            config,
+            |node| self.graph.edge_store.get_outgoing_for_traversal(node),
+            |node| self.graph.edge_store.get_incoming_for_traversal(node),
+        );
+        self.graph
+            .edge_store
+            .metrics()
+            .record_traversal(start.elapsed(), u64::from(result.is_some()));
+        result
+    }
+
     /// Parallel BFS traversal from multiple start nodes using rayon.
     ///
     /// When `start_nodes` exceeds the parallel threshold (100), rayon distributes
     /// independent per-start-node BFS traversals across CPU cores. Below the
     /// threshold, falls back to sequential execution.
     ///
-    /// Results are deduplicated by path signature and truncated to `config.limit`.
+    /// Results are deduplicated by path signature and truncated to `config.limit`,
+    /// itself capped at [`Self::max_query_limit`]. With `config.max_cost` each
+    /// start node runs a cost-ordered search instead.
     #[must_use]
     pub fn traverse_bfs_parallel(
         &self,
@@ -840,6 +947,16 @@ impl Collection {
             ParallelConfig, ParallelTraverser,
         };
 
+        let config = self.capped_traversal_config(config);
+        if config.max_cost.is_some() {
+            use rayon::prelude::*;
+            let mut results: Vec<TraversalResult> = start_nodes
+                .par_iter()
+                .flat_map_iter(|&source| self.traverse_cost_bounded(source, &config))
+                .collect();
+            results.truncate(config.limit);
+            return results;
+        }
         let par_config = ParallelConfig::new()
             .with_max_depth(config.max_depth)
             .with_limit(config.limit);
@@ -850,9 +967,10 @@ impl Collection {
         let rel_types = &config.rel_types;
         let adjacency = |node: u64| -> Vec<(u64, u64)> {
             edge_store
-                .get_outgoing(node)
+                .get_outgoing_for_traversal(node)
                 .into_iter()
                 .filter(|e| rel_types.is_empty() || rel_types.contains(&e.label().to_string()))
+                .filter(|e| config.follows(e))
                 .map(|e| (e.target(), e.id()))
                 .collect()
         };
@@ -336,6 +336,8 @@ mod tests {
             rel_types: vec![],
             limit: 100,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_bfs_config(1, &config);
         assert_eq!(
@@ -356,6 +358,8 @@ mod tests {
             rel_types: vec![],
             limit: 100,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_bfs_config(1, &config);
         // min_depth=2 so only nodes at depth >= 2 are returned
@@ -373,6 +377,8 @@ mod tests {
             rel_types: vec![],
             limit: 100,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_dfs_config(1, &config);
         assert_eq!(
@@ -394,12 +400,68 @@ mod tests {
             rel_types: vec!["NEXT".to_string()],
             limit: 100,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_dfs_config(1, &config);
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].target_id, 2);
     }
 
+    #[test]
+    fn test_config_traversals_honour_valid_at() {
+        let (collection, _temp) = create_test_collection();
+        // 1 -> 2 valid in [10, 20), 1 -> 3 always, 3 -> 4 valid from 30.
+        let edges = [
+            make_edge(1, 1, 2, "NEXT").with_validity(Some(10), Some(20)),
+            make_edge(2, 1, 3, "NEXT"),
+            make_edge(3, 3, 4, "NEXT").with_validity(Some(30), None),
+        ];
+        for edge in edges {
+            add_edge_with_nodes(&collection, edge).unwrap();
+        }
+
+        let sorted = |results: Vec<crate::collection::TraversalResult>| {
+            let mut ids: Vec<u64> = results.iter().map(|r| r.target_id).collect();
+            ids.sort_unstable();
+            ids
+        };
+        let config = TraversalConfig::with_range(1, 3).with_valid_at(15);
+        assert_eq!(sorted(collection.traverse_bfs_config(1, &config)), [2, 3]);
+        assert_eq!(sorted(collection.traverse_dfs_config(1, &config)), [2, 3]);
+        assert_eq!(
+            sorted(collection.traverse_bfs_parallel(&[1], &config)),
+            [2, 3]
+        );
+
+        let config = TraversalConfig::with_range(1, 3).with_valid_at(30);
+        assert_eq!(sorted(collection.traverse_bfs_config(1, &config)), [3, 4]);
+        assert_eq!(sorted(collection.traverse_dfs_config(1, &config)), [3, 4]);
+    }
+
+    #[test]
+    fn test_config_traversals_honour_max_cost() {
+        let (collection, _temp) = create_test_collection();
+        // 1 -> 2 (weight 5), 1 -> 3 (weight 1), 3 -> 4 (weight 1.5).
+        for (id, source, target, weight) in [(1, 1, 2, 5.0), (2, 1, 3, 1.0), (3, 3, 4, 1.5)] {
+            let edge = make_edge(id, source, target, "ROAD")
+                .with_properties([("weight".to_string(), serde_json::json!(weight))].into());
+            add_edge_with_nodes(&collection, edge).unwrap();
+        }
+
+        let ids = |results: Vec<crate::collection::TraversalResult>| -> Vec<u64> {
+            results.iter().map(|r| r.target_id).collect()
+        };
+        let config = TraversalConfig::with_range(1, 3).with_max_cost(3.0);
+        // Cheapest first, whatever the traversal entry point.
+        assert_eq!(ids(collection.traverse_bfs_config(1, &config)), [3, 4]);
+        assert_eq!(ids(collection.traverse_dfs_config(1, &config)), [3, 4]);
+        assert_eq!(ids(collection.traverse_bfs_parallel(&[1], &config)), [3, 4]);
+
+        let config = TraversalConfig::with_range(1, 3).with_max_cost(5.0);
+        assert_eq!(ids(collection.traverse_bfs_config(1, &config)), [3, 4, 2]);
+    }
+
     // =========================================================================
     // Wall-clock deadline on config traversal + GraphMetrics wiring
     // =========================================================================
@@ -513,6 +575,8 @@ mod tests {
             rel_types: vec![],
             limit: 100,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_bfs_parallel(&[1], &config);
         assert!(!results.is_empty(), "parallel BFS should find neighbors");
@@ -539,6 +603,8 @@ mod tests {
             rel_types: vec![],
             limit: 100,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_bfs_parallel(&[1, 10], &config);
 
@@ -579,6 +645,8 @@ mod tests {
             rel_types: vec![],
             limit: 100,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_bfs_parallel(&[1], &config);
         assert!(results.iter().all(|r| r.depth <= 1));
@@ -737,6 +805,8 @@ mod tests {
             rel_types: vec![],
             limit: usize::MAX,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_dfs_config(0, &config);
 
@@ -798,6 +868,7 @@ mod tests {
             0,
             0,
             &rel_filter,
+            None,
             &visited,
             &mut frontier,
         );
@@ -841,6 +912,8 @@ mod tests {
             rel_types: vec![],
             limit: usize::MAX,
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
         let results = collection.traverse_dfs_config(0, &config);
         assert!(
@@ -0,0 +1,64 @@
+//! Where graph edge properties live (`edge_properties_on_disk`).
+
+use crate::collection::types::Collection;
+use crate::error::Result;
+
+/// Directory, inside the collection directory, of the edge property log.
+pub(super) const EDGE_PROPERTIES_DIR: &str = "edge_properties";
+
+impl Collection {
+    /// Moves graph edge properties between RAM and an on-disk log under
+    /// `edge_properties/`, and persists the choice to `config.json`.
+    ///
+    /// With `on_disk` set, only the edge topology (id, endpoints, label)
+    /// stays in memory and `edge_store.bin` no longer carries properties;
+    /// reads that return edges load their properties from the log.
+    ///
+    /// # Errors
+    ///
+    /// I/O errors from moving the properties or persisting the config. The
+    /// edges are moved back on a config error so memory and disk never
+    /// disagree.
+    pub(crate) fn set_edge_properties_on_disk(&self, on_disk: bool) -> Result<()> {
+        if self.edge_properties_on_disk() == on_disk {
+            return Ok(());
+        }
+        let dir = self.storage.path.join(EDGE_PROPERTIES_DIR);
+        let edge_store_path = self.storage.path.join("edge_store.bin");
+        if on_disk {
+            self.graph.edge_store.attach_property_log(&dir)?;
+        } else {
+            self.graph.edge_store.detach_property_log()?;
+            // The persisted snapshot holds topology only: rewrite it with the
+            // properties before the log can go away.
+            self.graph.edge_store.save_to_file(&edge_store_path)?;
+        }
+
+        self.storage.config.write().edge_properties_on_disk = on_disk;
+        if let Err(e) = self.save_config() {
+            self.storage.config.write().edge_properties_on_disk = !on_disk;
+            let undo = if on_disk {
+                self.graph.edge_store.detach_property_log()
+            } else {
+                self.graph.edge_store.attach_property_log(&dir)
+            };
+            if let Err(undo) = undo {
+                tracing::error!("failed to restore edge property storage: {undo}");
+            }
+            return Err(e);
+        }
+
+        if !on_disk {
+            if let Err(e) = std::fs::remove_dir_all(&dir) {
+                tracing::warn!("failed to remove edge property log {dir:?}: {e}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether graph edge properties are kept on disk instead of in RAM.
+    #[must_use]
+    pub(crate) fn edge_properties_on_disk(&self) -> bool {
+        self.storage.config.read().edge_properties_on_disk
+    }
+}
@@ -0,0 +1,191 @@
+//! Tests for on-disk graph edge properties (`edge_properties_on_disk`).
+
+use super::graph_edge_properties::EDGE_PROPERTIES_DIR;
+use crate::collection::graph::{GraphEdge, GraphSchema, TraversalConfig};
+use crate::collection::types::Collection;
+use crate::DistanceMetric;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+const MARKER: &str = "edge-property-marker";
+
+fn create_graph(path: PathBuf) -> Collection {
+    let coll = Collection::create_graph_collection(
+        path,
+        "kg",
+        GraphSchema::schemaless(),
+        None,
+        DistanceMetric::Cosine,
+    )
+    .expect("create graph collection");
+    for id in [100, 200, 300] {
+        coll.store_node_payload(id, &json!({})).expect("store node");
+    }
+    coll
+}
+
+fn edge_with_note(id: u64, source: u64, target: u64) -> GraphEdge {
+    GraphEdge::new(id, source, target, "KNOWS")
+        .expect("valid edge")
+        .with_properties([("note".to_string(), json!(format!("{MARKER}-{id}")))].into())
+}
+
+fn note(edge: &GraphEdge) -> Option<&str> {
+    edge.property("note").and_then(serde_json::Value::as_str)
+}
+
+fn edge_store_mentions_marker(path: &Path) -> bool {
+    let bytes = std::fs::read(path.join("edge_store.bin")).expect("read edge_store.bin");
+    bytes.windows(MARKER.len()).any(|w| w == MARKER.as_bytes())
+}
+
+#[test]
+fn test_edge_properties_on_disk_survive_reopen() {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().to_path_buf();
+    {
+        let coll = create_graph(path.clone());
+        coll.set_edge_properties_on_disk(true).expect("enable");
+        coll.add_edge(edge_with_note(1, 100, 200)).expect("add");
+        coll.add_edges_batch(vec![edge_with_note(2, 200, 300)])
+            .expect("add batch");
+        coll.flush().expect("flush");
+        // Added after the flush: only the edge WAL has it.
+        coll.add_edge(edge_with_note(3, 100, 300)).expect("add");
+    }
+    assert!(
+        !edge_store_mentions_marker(&path),
+        "edge_store.bin must hold topology only"
+    );
+
+    let reopened = Collection::open(path).expect("reopen");
+    assert!(reopened.edge_properties_on_disk());
+    assert_eq!(reopened.edge_count(), 3);
+    let outgoing = reopened.get_outgoing_edges(100);
+    let mut notes: Vec<_> = outgoing.iter().filter_map(note).collect();
+    notes.sort_unstable();
+    assert_eq!(
+        notes,
+        [format!("{MARKER}-1"), format!("{MARKER}-3")],
+        "properties of flushed and WAL-replayed edges are read back"
+    );
+    let incoming = reopened.get_incoming_edges(300);
+    assert_eq!(incoming.len(), 2);
+    assert!(incoming.iter().all(|e| note(e).is_some()));
+    assert_eq!(
+        reopened.get_edges_by_label("KNOWS").len(),
+        3,
+        "label lookups still see every edge"
+    );
+    assert!(reopened
+        .get_all_edges()
+        .iter()
+        .all(|e| note(e) == Some(format!("{MARKER}-{}", e.id())).as_deref()));
+}
+
+#[test]
+fn test_enabling_moves_existing_properties_and_disabling_restores_them() {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().to_path_buf();
+    {
+        let coll = create_graph(path.clone());
+        coll.add_edge(edge_with_note(1, 100, 200)).expect("add");
+        coll.flush().expect("flush");
+        assert!(edge_store_mentions_marker(&path));
+
+        coll.set_edge_properties_on_disk(true).expect("enable");
+        coll.flush().expect("flush");
+        assert!(!edge_store_mentions_marker(&path));
+        assert_eq!(
+            coll.get_outgoing_edges(100).first().and_then(note),
+            Some(format!("{MARKER}-1").as_str())
+        );
+    }
+    {
+        let coll = Collection::open(path.clone()).expect("reopen");
+        assert_eq!(
+            coll.get_outgoing_edges(100).first().and_then(note),
+            Some(format!("{MARKER}-1").as_str())
+        );
+        coll.set_edge_properties_on_disk(false).expect("disable");
+        assert!(!path.join(EDGE_PROPERTIES_DIR).exists());
+        assert!(edge_store_mentions_marker(&path));
+    }
+
+    let reopened = Collection::open(path).expect("reopen");
+    assert!(!reopened.edge_properties_on_disk());
+    assert_eq!(
+        reopened.get_outgoing_edges(100).first().and_then(note),
+        Some(format!("{MARKER}-1").as_str())
+    );
+}
+
+#[test]
+fn test_removed_edge_properties_do_not_come_back() {
+    let temp = TempDir::new().expect("temp dir");
+    let coll = create_graph(temp.path().to_path_buf());
+    coll.set_edge_properties_on_disk(true).expect("enable");
+    coll.add_edge(edge_with_note(1, 100, 200)).expect("add");
+
+    assert!(coll.remove_edge(1));
+    let reused = GraphEdge::new(1, 100, 200, "KNOWS").expect("valid edge");
+    coll.add_edge(reused).expect("re-add");
+
+    let edges = coll.get_outgoing_edges(100);
+    assert_eq!(edges.len(), 1);
+    assert!(
+        edges[0].properties().is_empty(),
+        "a reused id must not inherit the removed edge's properties"
+    );
+}
+
+#[test]
+fn test_traversal_properties_stay_in_memory() {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().to_path_buf();
+    {
+        let coll = create_graph(path.clone());
+        coll.set_edge_properties_on_disk(true).expect("enable");
+        let weighted = GraphEdge::new(1, 100, 200, "KNOWS")
+            .expect("valid edge")
+            .with_properties(
+                [
+                    ("note".to_string(), json!(format!("{MARKER}-1"))),
+                    ("weight".to_string(), json!(5.0)),
+                ]
+                .into(),
+            );
+        let bounded = edge_with_note(2, 200, 300).with_validity(Some(0), Some(10));
+        coll.add_edges_batch(vec![weighted, bounded]).expect("add");
+        coll.flush().expect("flush");
+    }
+    assert!(!edge_store_mentions_marker(&path));
+
+    let coll = Collection::open(path).expect("reopen");
+    let resident = coll.graph.edge_store.get_outgoing_for_traversal(200);
+    assert_eq!(resident.len(), 1);
+    assert_eq!(resident[0].valid_to(), Some(10));
+    assert!(
+        note(&resident[0]).is_none(),
+        "traversal getters do not read the property log"
+    );
+    let full = coll.get_outgoing_edges(200);
+    assert_eq!(full[0].valid_to(), Some(10));
+    assert_eq!(note(&full[0]), Some(format!("{MARKER}-2").as_str()));
+
+    let targets = |config: TraversalConfig| {
+        let mut ids: Vec<u64> = coll
+            .traverse_bfs_config(100, &config)
+            .iter()
+            .map(|r| r.target_id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    };
+    let hops = || TraversalConfig::with_range(1, 3);
+    assert_eq!(targets(hops().with_max_cost(5.0)), [200]);
+    assert_eq!(targets(hops().with_max_cost(6.0)), [200, 300]);
+    assert_eq!(targets(hops().with_valid_at(5)), [200, 300]);
+    assert_eq!(targets(hops().with_valid_at(20)), [200]);
+}
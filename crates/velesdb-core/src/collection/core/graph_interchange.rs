@@ -0,0 +1,120 @@
+//! Graph export/import in interchange formats (GraphML, JSON Lines).
+//!
+//! Round-trips node payloads and edges with NetworkX, Neo4j and other
+//! graph tooling. The text formats live in `graph::interchange`.
+
+use serde_json::Value;
+
+use crate::collection::graph::{
+    read_graph, write_graph, EdgeRecord, GraphDocument, GraphEdge, GraphFormat, GraphImportStats,
+    NodeRecord,
+};
+use crate::collection::types::Collection;
+use crate::error::Result;
+
+use super::graph_property_index_wiring::extract_labels;
+
+/// Payload key holding a node's labels.
+const LABELS_KEY: &str = "_labels";
+
+impl Collection {
+    /// Serializes the graph (every node payload and every edge) as `format`.
+    ///
+    /// Nodes are the points with a payload, in id order; their `_labels`
+    /// become node labels and the other payload fields node properties.
+    /// Vectors are not exported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a payload cannot be read from storage.
+    pub fn export_graph(&self, format: GraphFormat) -> Result<String> {
+        let mut ids = self.all_ids();
+        ids.sort_unstable();
+        let mut nodes = Vec::with_capacity(ids.len());
+        for id in ids {
+            let Some(payload) = self.get_node_payload(id)? else {
+                continue;
+            };
+            let labels = extract_labels(&payload);
+            let properties = match payload {
+                Value::Object(mut map) => {
+                    map.remove(LABELS_KEY);
+                    map
+                }
+                _ => serde_json::Map::new(),
+            };
+            nodes.push(NodeRecord {
+                id,
+                labels,
+                properties,
+            });
+        }
+
+        let mut edges = self.get_all_edges();
+        edges.sort_unstable_by_key(GraphEdge::id);
+        let edges = edges
+            .into_iter()
+            .map(|edge| EdgeRecord {
+                id: Some(edge.id()),
+                source: edge.source(),
+                target: edge.target(),
+                label: edge.label().to_string(),
+                properties: edge.properties().clone(),
+            })
+            .collect();
+        Ok(write_graph(&GraphDocument { nodes, edges }, format))
+    }
+
+    /// Imports a graph serialized as `format` (see [`Self::export_graph`]).
+    ///
+    /// Each node's payload is replaced by its properties plus `_labels`.
+    /// Edges are then added in one batch: edges without an id get fresh ids
+    /// after the current maximum, and edges whose id already exists are
+    /// skipped, so re-importing an export is idempotent.
+    ///
+    /// The document is parsed and every edge validated before anything is
+    /// written; a storage or schema error while writing nodes may leave the
+    /// nodes written so far in place.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Serialization` if `data` is not valid `format`.
+    /// - `Error::InvalidEdgeLabel` if an edge has an empty label.
+    /// - `Error::NodeNotFound` if an edge endpoint is neither in the
+    ///   document nor already stored.
+    /// - Schema and storage errors from the node and edge writes.
+    pub fn import_graph(&self, format: GraphFormat, data: &str) -> Result<GraphImportStats> {
+        let GraphDocument { nodes, edges } = read_graph(data, format)?;
+
+        let mut next_id = self.max_edge_id().map_or(1, |max| max.saturating_add(1));
+        // Fresh ids must not collide with ids used later in the document.
+        if let Some(max) = edges.iter().filter_map(|e| e.id).max() {
+            next_id = next_id.max(max.saturating_add(1));
+        }
+        let edges = edges
+            .into_iter()
+            .map(|record| {
+                let id = record.id.unwrap_or_else(|| {
+                    next_id = next_id.saturating_add(1);
+                    next_id - 1
+                });
+                Ok(
+                    GraphEdge::new(id, record.source, record.target, &record.label)?
+                        .with_properties(record.properties),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for node in &nodes {
+            let mut payload = node.properties.clone();
+            if !node.labels.is_empty() {
+                payload.insert(LABELS_KEY.to_string(), node.labels.clone().into());
+            }
+            self.store_node_payload(node.id, &Value::Object(payload))?;
+        }
+        Ok(GraphImportStats {
+            nodes: nodes.len(),
+            edges: self.add_edges_batch(edges)?,
+        })
+    }
+}
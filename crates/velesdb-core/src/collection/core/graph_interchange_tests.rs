@@ -0,0 +1,110 @@
+//! Tests for graph export/import (`export_graph` / `import_graph`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::collection::graph::{GraphEdge, GraphFormat};
+use crate::collection::types::Collection;
+use crate::error::Error;
+use crate::DistanceMetric;
+
+fn create_collection() -> (Collection, TempDir) {
+    let dir = TempDir::new().expect("test: temp dir");
+    let col = Collection::create(dir.path().to_path_buf(), 4, DistanceMetric::Cosine)
+        .expect("test: create collection");
+    (col, dir)
+}
+
+/// Alice -[KNOWS {since: 2020}]-> Bob, Bob -[LIKES]-> Carol.
+fn create_social_graph() -> (Collection, TempDir) {
+    let (col, dir) = create_collection();
+    col.store_node_payload(1, &json!({"_labels": ["Person"], "name": "Alice"}))
+        .unwrap();
+    col.store_node_payload(2, &json!({"_labels": ["Person"], "name": "Bob", "age": 41}))
+        .unwrap();
+    col.store_node_payload(3, &json!({"name": "Carol"}))
+        .unwrap();
+    let knows = GraphEdge::new(10, 1, 2, "KNOWS")
+        .unwrap()
+        .with_properties([("since".to_string(), json!(2020))].into());
+    col.add_edge(knows).unwrap();
+    col.add_edge(GraphEdge::new(11, 2, 3, "LIKES").unwrap())
+        .unwrap();
+    (col, dir)
+}
+
+fn sorted_edges(col: &Collection) -> Vec<(u64, u64, u64, String)> {
+    let mut edges: Vec<_> = col
+        .get_all_edges()
+        .iter()
+        .map(|e| (e.id(), e.source(), e.target(), e.label().to_string()))
+        .collect();
+    edges.sort_unstable();
+    edges
+}
+
+#[test]
+fn test_export_import_round_trip_in_both_formats() {
+    let (source, _source_dir) = create_social_graph();
+
+    for format in [GraphFormat::GraphMl, GraphFormat::Jsonl] {
+        let text = source.export_graph(format).expect("test: export");
+        let (target, _dir) = create_collection();
+        let stats = target.import_graph(format, &text).expect("test: import");
+
+        assert_eq!((stats.nodes, stats.edges), (3, 2), "{format}");
+        assert_eq!(sorted_edges(&target), sorted_edges(&source), "{format}");
+        for id in [1, 2, 3] {
+            assert_eq!(
+                target.get_node_payload(id).unwrap(),
+                source.get_node_payload(id).unwrap(),
+                "{format}: node {id}"
+            );
+        }
+        let knows = &target.get_outgoing_edges(1)[0];
+        assert_eq!(knows.property("since"), Some(&json!(2020)));
+        // Labels are indexed again, so MATCH-style lookups keep working.
+        assert_eq!(
+            target
+                .traverse_bfs(1, 2, Some(&["KNOWS", "LIKES"]), 10)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
+
+#[test]
+fn test_reimport_is_idempotent_and_assigns_missing_edge_ids() {
+    let (col, _dir) = create_social_graph();
+    let text = col.export_graph(GraphFormat::Jsonl).unwrap();
+    let stats = col.import_graph(GraphFormat::Jsonl, &text).unwrap();
+    assert_eq!((stats.nodes, stats.edges), (3, 0));
+
+    let extra = r#"{"type":"edge","source":3,"target":1,"label":"KNOWS"}"#;
+    let stats = col.import_graph(GraphFormat::Jsonl, extra).unwrap();
+    assert_eq!(stats.edges, 1);
+    assert_eq!(col.get_outgoing_edges(3)[0].id(), 12);
+}
+
+#[test]
+fn test_import_rejects_dangling_edges_before_writing_nodes() {
+    let (col, _dir) = create_collection();
+    let text = r#"{"type":"edge","id":1,"source":1,"target":2,"label":""}
+{"type":"node","id":1}"#;
+    let err = col
+        .import_graph(GraphFormat::Jsonl, text)
+        .expect_err("empty label");
+    assert!(matches!(err, Error::InvalidEdgeLabel(_)), "{err:?}");
+    assert!(col.all_ids().is_empty());
+
+    let text = r#"{"type":"node","id":1}
+{"type":"edge","id":1,"source":1,"target":2,"label":"KNOWS"}"#;
+    let err = col
+        .import_graph(GraphFormat::Jsonl, text)
+        .expect_err("dangling");
+    assert!(matches!(err, Error::NodeNotFound(2)), "{err:?}");
+    assert_eq!(col.edge_count(), 0);
+}
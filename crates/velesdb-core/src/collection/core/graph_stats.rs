@@ -0,0 +1,49 @@
+//! Graph shape statistics (`Collection::graph_stats`).
+
+use crate::collection::graph::GraphStats;
+use crate::collection::types::Collection;
+
+impl Collection {
+    /// Returns node/edge counts per label, the degree distribution and the
+    /// highest-degree nodes of the graph.
+    ///
+    /// Walks every adjacency list once (O(nodes + labels)), without
+    /// materializing edges. Node label counts come from the label index,
+    /// which skips node ids above `u32::MAX`.
+    #[must_use]
+    pub fn graph_stats(&self) -> GraphStats {
+        let degrees = self.graph.edge_store.degree_counts();
+        let isolated = self
+            .all_ids()
+            .into_iter()
+            .filter(|id| !degrees.contains_key(id))
+            .count();
+
+        let nodes_by_label = self
+            .graph
+            .label_index
+            .read()
+            .label_counts()
+            .map(|(label, count)| (label.to_string(), count))
+            .collect();
+        let edges_by_label = self
+            .graph
+            .edge_store
+            .edge_counts_by_label()
+            .into_iter()
+            .collect();
+
+        GraphStats {
+            edge_count: self.graph.edge_store.edge_count(),
+            nodes_by_label,
+            edges_by_label,
+            ..GraphStats::default()
+        }
+        .with_degrees(
+            degrees
+                .into_iter()
+                .map(|(id, (out_degree, in_degree))| (id, out_degree, in_degree)),
+            isolated,
+        )
+    }
+}
@@ -0,0 +1,111 @@
+//! Tests for `Collection::graph_stats`.
+
+#![cfg(all(test, feature = "persistence"))]
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::collection::graph::{DegreeBucket, GraphEdge, HubNode, HUB_DEGREE_THRESHOLD, TOP_HUBS};
+use crate::collection::types::Collection;
+use crate::DistanceMetric;
+
+fn create_collection() -> (Collection, TempDir) {
+    let dir = TempDir::new().expect("test: temp dir");
+    let col = Collection::create(dir.path().to_path_buf(), 4, DistanceMetric::Cosine)
+        .expect("test: create collection");
+    (col, dir)
+}
+
+#[test]
+fn test_graph_stats_counts_labels_and_degrees() {
+    let (col, _dir) = create_collection();
+    for (id, label) in [(1, "Person"), (2, "Person"), (3, "Company"), (4, "Person")] {
+        col.store_node_payload(id, &json!({"_labels": [label]}))
+            .unwrap();
+    }
+    let edges = vec![
+        GraphEdge::new(10, 1, 2, "KNOWS").unwrap(),
+        GraphEdge::new(11, 1, 3, "WORKS_AT").unwrap(),
+        GraphEdge::new(12, 2, 3, "WORKS_AT").unwrap(),
+        GraphEdge::new(13, 3, 1, "KNOWS").unwrap(),
+    ];
+    col.add_edges_batch(edges).unwrap();
+
+    let stats = col.graph_stats();
+
+    assert_eq!((stats.node_count, stats.edge_count), (4, 4));
+    assert_eq!(stats.nodes_by_label["Person"], 3);
+    assert_eq!(stats.nodes_by_label["Company"], 1);
+    assert_eq!(stats.edges_by_label["KNOWS"], 2);
+    assert_eq!(stats.edges_by_label["WORKS_AT"], 2);
+    // Degrees: 1 -> 3, 2 -> 2, 3 -> 3, 4 -> 0 (isolated).
+    assert_eq!(
+        stats.degree_histogram,
+        vec![
+            DegreeBucket {
+                min_degree: 0,
+                max_degree: 0,
+                nodes: 1
+            },
+            DegreeBucket {
+                min_degree: 2,
+                max_degree: 3,
+                nodes: 3
+            },
+        ]
+    );
+    assert_eq!(stats.max_degree, 3);
+    assert!((stats.mean_degree - 2.0).abs() < f64::EPSILON);
+    assert_eq!(stats.hub_count, 0);
+    let hub_ids: Vec<u64> = stats.top_hubs.iter().map(|h| h.id).collect();
+    assert_eq!(hub_ids, vec![1, 3, 2]);
+    assert_eq!(
+        stats.top_hubs[1],
+        HubNode {
+            id: 3,
+            out_degree: 1,
+            in_degree: 2
+        }
+    );
+}
+
+#[test]
+fn test_graph_stats_reports_hubs_and_follows_removals() {
+    let (col, _dir) = create_collection();
+    let leaves = HUB_DEGREE_THRESHOLD as u64;
+    for id in 0..=leaves {
+        col.store_node_payload(id, &json!({})).unwrap();
+    }
+    let star = (1..=leaves)
+        .map(|leaf| GraphEdge::new(leaf, 0, leaf, "LINKS").unwrap())
+        .collect();
+    col.add_edges_batch(star).unwrap();
+
+    let stats = col.graph_stats();
+    assert_eq!(stats.hub_count, 1);
+    assert_eq!(stats.max_degree, HUB_DEGREE_THRESHOLD);
+    assert_eq!(stats.top_hubs.len(), TOP_HUBS);
+    assert_eq!(stats.top_hubs[0].id, 0);
+    assert_eq!(stats.top_hubs[0].degree(), HUB_DEGREE_THRESHOLD);
+    let last = stats.degree_histogram.last().unwrap();
+    assert!(last.min_degree <= HUB_DEGREE_THRESHOLD && HUB_DEGREE_THRESHOLD <= last.max_degree);
+    assert_eq!(stats.degree_histogram[0].nodes, HUB_DEGREE_THRESHOLD);
+
+    assert!(col.remove_edge(1));
+    let stats = col.graph_stats();
+    assert_eq!(stats.hub_count, 0);
+    assert_eq!(stats.edges_by_label["LINKS"], HUB_DEGREE_THRESHOLD - 1);
+    assert_eq!(stats.degree_histogram[0].nodes, 1, "leaf 1 is now isolated");
+}
+
+#[test]
+fn test_graph_stats_on_empty_collection() {
+    let (col, _dir) = create_collection();
+
+    let stats = col.graph_stats();
+
+    assert_eq!((stats.node_count, stats.edge_count), (0, 0));
+    assert!(stats.degree_histogram.is_empty());
+    assert!(stats.top_hubs.is_empty());
+    assert!(stats.mean_degree.abs() < f64::EPSILON);
+}
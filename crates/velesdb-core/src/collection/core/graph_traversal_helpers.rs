@@ -67,7 +67,8 @@ pub(super) struct DfsFrontier<'a> {
     pub(super) max_pending: usize,
 }
 
-/// Pushes unvisited, rel-type-filtered neighbors onto the DFS stack.
+/// Pushes unvisited neighbors passing the rel-type and `valid_at` filters
+/// onto the DFS stack.
 ///
 /// Records parent pointers for lazy path reconstruction (G4).
 ///
@@ -86,10 +87,11 @@ pub(super) fn expand_dfs_neighbors(
     node_id: u64,
     depth: u32,
     rel_filter: &FxHashSet<&str>,
+    valid_at: Option<i64>,
     visited: &FxHashSet<u64>,
     frontier: &mut DfsFrontier<'_>,
 ) {
-    let outgoing = store.get_outgoing(node_id);
+    let outgoing = store.get_outgoing_for_traversal(node_id);
     for edge in outgoing.iter().rev() {
         // `parent_map` is monotonic (one entry per ever-queued node, never
         // removed), so its length is an upper bound on the live `stack` size;
@@ -100,6 +102,9 @@ pub(super) fn expand_dfs_neighbors(
         if !rel_filter.is_empty() && !rel_filter.contains(edge.label()) {
             continue;
         }
+        if valid_at.is_some_and(|t| !edge.is_valid_at(t)) {
+            continue;
+        }
         if visited.contains(&edge.target()) {
             continue;
         }
@@ -151,7 +156,7 @@ pub(super) fn traverse_with_frontier<F>(
             continue;
         }
 
-        let outgoing = params.store.get_outgoing(node);
+        let outgoing = params.store.get_outgoing_for_traversal(node);
         let neighbors = collect_neighbor_expansions(
             &outgoing,
             node,
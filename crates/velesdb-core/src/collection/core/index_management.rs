@@ -97,9 +97,14 @@ impl Collection {
         };
         let SecondaryIndex::BTree(ref tree) = index;
         let mut tree_guard = tree.write();
+        let mut ttl_points = 0;
         for id in ids {
-            Self::backfill_single_payload(&*payload_storage, id, field_name, &mut tree_guard);
+            ttl_points +=
+                Self::backfill_single_payload(&*payload_storage, id, field_name, &mut tree_guard);
         }
+        self.query
+            .indexed_ttl_points
+            .store(ttl_points, std::sync::atomic::Ordering::Relaxed);
         // Deduplicate each bucket in one O(k log k) pass rather than checking
         // contains() per-insertion (was O(k) per insert → O(k²) total for a
         // bucket of k IDs, e.g. low-cardinality fields like status/category).
@@ -117,20 +122,22 @@ impl Collection {
 
     /// Indexes a single payload entry for the given field, if present.
     ///
-    /// Callers are responsible for deduplication (see `backfill_secondary_index`).
+    /// Returns 1 when the payload carries a TTL (for `indexed_ttl_points`),
+    /// 0 otherwise. Callers are responsible for deduplication (see
+    /// `backfill_secondary_index`).
     fn backfill_single_payload(
         payload_storage: &dyn crate::storage::PayloadStorage,
         id: u64,
         field_name: &str,
         tree_guard: &mut std::collections::BTreeMap<JsonValue, Vec<u64>>,
-    ) {
-        if let Ok(Some(payload)) = payload_storage.retrieve(id) {
-            if let Some(val) = payload.get(field_name) {
-                if let Some(key) = JsonValue::from_json(val) {
-                    tree_guard.entry(key).or_default().push(id);
-                }
-            }
+    ) -> usize {
+        let Ok(Some(payload)) = payload_storage.retrieve(id) else {
+            return 0;
+        };
+        if let Some(key) = payload.get(field_name).and_then(JsonValue::from_json) {
+            tree_guard.entry(key).or_default().push(id);
         }
+        usize::from(super::crud_helpers::carries_ttl(Some(&payload)))
     }
 
     /// Drops a secondary metadata index for a payload field.
@@ -329,12 +336,20 @@ impl Collection {
     /// children resolve). Returns `None` when the condition cannot be resolved
     /// via indexes (e.g., `Not`, non-indexed fields), signalling the caller to
     /// fall back to post-filter.
+    ///
+    /// Under a non-binary [`Collation`](crate::filter::Collation), `Eq` and
+    /// `In` on string literals are not resolved: the index keys are the raw
+    /// strings, so an exact lookup would miss collation-equal values.
     #[must_use]
     pub(crate) fn build_prefilter_bitmap(
         &self,
         filter: &crate::filter::Filter,
     ) -> Option<roaring::RoaringBitmap> {
-        Self::bitmap_from_condition(&self.query.secondary_indexes, &filter.condition)
+        Self::bitmap_from_condition(
+            &self.query.secondary_indexes,
+            &filter.condition,
+            filter.effective_collation(),
+        )
     }
 
     /// Recursively extracts bitmaps from conditions backed by secondary indexes.
@@ -354,8 +369,19 @@ impl Collection {
             parking_lot::RwLock<std::collections::HashMap<String, SecondaryIndex>>,
         >,
         cond: &crate::filter::Condition,
+        collation: crate::filter::Collation,
     ) -> Option<roaring::RoaringBitmap> {
         match cond {
+            crate::filter::Condition::Eq { value, .. }
+                if !collation.is_binary() && value.is_string() =>
+            {
+                None
+            }
+            crate::filter::Condition::In { values, .. }
+                if !collation.is_binary() && values.iter().any(serde_json::Value::is_string) =>
+            {
+                None
+            }
             crate::filter::Condition::Eq { field, value } => {
                 Self::bitmap_for_eq_field(indexes, field, value)
             }
@@ -384,10 +410,10 @@ impl Collection {
                 Self::bitmap_for_not_in(indexes, condition)
             }
             crate::filter::Condition::And { conditions } => {
-                Self::bitmap_from_and(indexes, conditions)
+                Self::bitmap_from_and(indexes, conditions, collation)
             }
             crate::filter::Condition::Or { conditions } => {
-                Self::bitmap_from_or(indexes, conditions)
+                Self::bitmap_from_or(indexes, conditions, collation)
             }
             _ => None,
         }
@@ -487,10 +513,11 @@ impl Collection {
             parking_lot::RwLock<std::collections::HashMap<String, SecondaryIndex>>,
         >,
         conditions: &[crate::filter::Condition],
+        collation: crate::filter::Collation,
     ) -> Option<roaring::RoaringBitmap> {
         let mut result: Option<roaring::RoaringBitmap> = None;
         for cond in conditions {
-            if let Some(bm) = Self::bitmap_from_condition(indexes, cond) {
+            if let Some(bm) = Self::bitmap_from_condition(indexes, cond, collation) {
                 result = Some(match result {
                     Some(existing) => existing & &bm,
                     None => bm,
@@ -510,10 +537,11 @@ impl Collection {
             parking_lot::RwLock<std::collections::HashMap<String, SecondaryIndex>>,
         >,
         conditions: &[crate::filter::Condition],
+        collation: crate::filter::Collation,
     ) -> Option<roaring::RoaringBitmap> {
         let mut result = roaring::RoaringBitmap::new();
         for cond in conditions {
-            let bm = Self::bitmap_from_condition(indexes, cond)?;
+            let bm = Self::bitmap_from_condition(indexes, cond, collation)?;
             result |= bm;
         }
         Some(result)
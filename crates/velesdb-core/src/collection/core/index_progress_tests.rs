@@ -0,0 +1,113 @@
+//! Tests for index build progress reporting (`Collection::on_progress`).
+
+#![allow(clippy::cast_precision_loss)]
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::collection::auto_reindex::{AutoReindexConfig, AutoReindexManager};
+use crate::collection::index_progress::{IndexBuildPhase, IndexProgress};
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::index::hnsw::HnswParams;
+use crate::point::Point;
+
+fn points(n: u64) -> Vec<Point> {
+    (0..n)
+        .map(|i| {
+            let f = i as f32;
+            Point::without_payload(i, vec![f, f * 0.5, 1.0, -f])
+        })
+        .collect()
+}
+
+fn record(coll: &Collection) -> Arc<Mutex<Vec<IndexProgress>>> {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&seen);
+    coll.on_progress(move |p| sink.lock().push(p));
+    seen
+}
+
+/// Reports of `phase`, in order.
+fn of_phase(seen: &[IndexProgress], phase: IndexBuildPhase) -> Vec<IndexProgress> {
+    seen.iter().copied().filter(|p| p.phase == phase).collect()
+}
+
+#[test]
+fn test_bulk_upsert_reports_storing_then_linking() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = Collection::create(dir.path().join("c"), 4, DistanceMetric::Euclidean).unwrap();
+    let seen = record(&coll);
+
+    assert_eq!(coll.upsert_bulk(&points(250)).unwrap(), 250);
+
+    let seen = seen.lock();
+    assert_eq!(seen[0].phase, IndexBuildPhase::StoringVectors);
+    assert_eq!(seen[0].percent, 0);
+    let storing = of_phase(&seen, IndexBuildPhase::StoringVectors);
+    assert_eq!(storing.last().unwrap().percent, 100);
+
+    let linking = of_phase(&seen, IndexBuildPhase::LinkingLayers);
+    assert!(linking.len() > 2, "chunked linking: {linking:?}");
+    assert!(linking.windows(2).all(|w| w[0].processed <= w[1].processed));
+    let last = linking.last().unwrap();
+    assert_eq!((last.processed, last.total, last.percent), (250, 250, 100));
+    let first_link = seen
+        .iter()
+        .position(|p| p.phase == IndexBuildPhase::LinkingLayers)
+        .unwrap();
+    assert!(seen[..first_link]
+        .iter()
+        .all(|p| p.phase == IndexBuildPhase::StoringVectors));
+
+    let hits = coll.search(&[100.0, 50.0, 1.0, -100.0], 1).unwrap();
+    assert_eq!(hits[0].point.id, 100);
+}
+
+#[test]
+fn test_flush_reports_steps_and_clear_stops_reports() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = Collection::create(dir.path().join("c"), 4, DistanceMetric::Euclidean).unwrap();
+    coll.upsert(points(10)).unwrap();
+    let seen = record(&coll);
+
+    coll.flush().unwrap();
+    let percents: Vec<u8> = of_phase(&seen.lock(), IndexBuildPhase::Flushing)
+        .iter()
+        .map(|p| p.percent)
+        .collect();
+    assert_eq!(percents, vec![0, 33, 66, 100]);
+
+    coll.clear_progress();
+    seen.lock().clear();
+    coll.upsert(points(5)).unwrap();
+    coll.flush_full().unwrap();
+    assert!(seen.lock().is_empty());
+}
+
+#[test]
+fn test_reindex_reports_linking_and_flushing() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = Collection::create(dir.path().join("c"), 4, DistanceMetric::Euclidean).unwrap();
+    coll.upsert(points(200)).unwrap();
+    let seen = record(&coll);
+    let manager = AutoReindexManager::new(AutoReindexConfig {
+        max_latency_regression_percent: f64::MAX,
+        ..AutoReindexConfig::default()
+    });
+    let params = HnswParams {
+        max_connections: 8,
+        ef_construction: 100,
+        ..HnswParams::auto(4)
+    };
+
+    assert!(coll.reindex_with_params(params, &manager).unwrap());
+
+    let seen = seen.lock();
+    let linking = of_phase(&seen, IndexBuildPhase::LinkingLayers);
+    assert_eq!(linking.last().unwrap().processed, 200);
+    assert_eq!(linking.last().unwrap().percent, 100);
+    assert_eq!(seen.last().unwrap().phase, IndexBuildPhase::Flushing);
+    assert_eq!(seen.last().unwrap().percent, 100);
+}
@@ -0,0 +1,178 @@
+//! kNN-graph builder: derives graph edges from vector similarity.
+//!
+//! Connects every node to its nearest neighbours so MATCH and path queries
+//! can walk semantic proximity without an external pipeline.
+
+use rayon::prelude::*;
+use roaring::RoaringBitmap;
+use rustc_hash::FxHashSet;
+use serde_json::json;
+
+use crate::collection::graph::GraphEdge;
+use crate::collection::search::SearchOptions;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::storage::{PayloadStorage, VectorStorage};
+
+impl Collection {
+    /// Adds a `label` edge from every node to each of its (up to) `k`
+    /// nearest neighbours and returns the number of edges inserted.
+    ///
+    /// Nodes are the points that have both a vector and a payload (MATCH
+    /// resolves nodes from payloads). Neighbours come from the vector index,
+    /// searched among nodes only, so the graph is as approximate as a
+    /// regular search. With a
+    /// `threshold`, neighbours scoring worse than it are dropped: below it
+    /// for similarity metrics, above it for distance metrics.
+    ///
+    /// Each edge stores its score in the `score` property. Pairs already
+    /// linked by a `label` edge are skipped, so rebuilding after new inserts
+    /// only adds the missing edges.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidEdgeLabel`] if `label` is empty.
+    /// - [`Error::SearchNotSupported`] on a metadata-only collection.
+    /// - Storage errors from reading vectors or writing the edges.
+    pub fn build_knn_graph(&self, k: usize, threshold: Option<f32>, label: &str) -> Result<usize> {
+        // Validates the label before any work is done.
+        let label = GraphEdge::new(0, 0, 0, label)?.label().to_string();
+        let (metric, name, metadata_only) = {
+            let config = self.storage.config.read();
+            (config.metric, config.name.clone(), config.metadata_only)
+        };
+        if metadata_only {
+            return Err(Error::SearchNotSupported(name));
+        }
+        if k == 0 {
+            return Ok(0);
+        }
+
+        let (node_ids, vector_only) = self.knn_graph_node_ids();
+        let nodes = KnnNodes::new(&node_ids);
+        let passes = |score: f32| {
+            threshold.is_none_or(|t| {
+                if metric.higher_is_better() {
+                    score >= t
+                } else {
+                    score <= t
+                }
+            })
+        };
+
+        // One search per node. Vectors are read as each node is processed
+        // rather than all up front.
+        let neighbours: Vec<(u64, Vec<(u64, f32)>)> = node_ids
+            .par_iter()
+            .map(|&id| {
+                let Some(vector) = self.storage.vector_storage.read().retrieve(id)? else {
+                    return Ok((id, Vec::new()));
+                };
+                let kept = self
+                    .knn_node_neighbours(id, &vector, k, vector_only, &nodes)?
+                    .into_iter()
+                    .filter(|&(_, score)| passes(score))
+                    .take(k)
+                    .collect();
+                Ok((id, kept))
+            })
+            .collect::<Result<_>>()?;
+
+        let existing: FxHashSet<(u64, u64)> = self
+            .get_edges_by_label(&label)
+            .iter()
+            .map(|edge| (edge.source(), edge.target()))
+            .collect();
+        let mut next_id = self.max_edge_id().map_or(1, |max| max.saturating_add(1));
+        let mut edges = Vec::new();
+        for (source, hits) in neighbours {
+            for (target, score) in hits {
+                if existing.contains(&(source, target)) {
+                    continue;
+                }
+                let properties = [("score".to_string(), json!(score))].into();
+                edges.push(
+                    GraphEdge::new(next_id, source, target, &label)?.with_properties(properties),
+                );
+                next_id = next_id.saturating_add(1);
+            }
+        }
+        self.add_edges_batch(edges)
+    }
+
+    /// Nearest neighbours of node `id`, best first, restricted to other
+    /// nodes.
+    ///
+    /// When every node id fits the index's id bitmap, the search is
+    /// restricted to nodes inside the HNSW traversal, so vector-only points
+    /// cannot crowd nodes out of the `k` results. Otherwise it over-fetches
+    /// past the vector-only points, bounded by the query limit.
+    fn knn_node_neighbours(
+        &self,
+        id: u64,
+        vector: &[f32],
+        k: usize,
+        vector_only: usize,
+        nodes: &KnnNodes,
+    ) -> Result<Vec<(u64, f32)>> {
+        let hits = match &nodes.bitmap {
+            Some(bitmap) => {
+                let options = SearchOptions {
+                    include_ids: Some(bitmap.clone()),
+                    exclude_ids: u32::try_from(id).ok().map(|id| [id].into_iter().collect()),
+                };
+                self.search_with_options(vector, k, &options)?
+            }
+            None => {
+                // Room for the node itself and for every point that is not a node.
+                let fetch = k
+                    .saturating_add(1)
+                    .saturating_add(vector_only)
+                    .min(self.max_query_limit());
+                self.search(vector, fetch)?
+            }
+        };
+        Ok(hits
+            .into_iter()
+            .map(|hit| (hit.point.id, hit.score))
+            .filter(|&(target, _)| target != id && nodes.ids.contains(&target))
+            .collect())
+    }
+
+    /// Sorted ids of the points that have both a vector and a payload, and
+    /// the number of vector-only points.
+    fn knn_graph_node_ids(&self) -> (Vec<u64>, usize) {
+        let with_payload: FxHashSet<u64> = self
+            .storage
+            .payload_storage
+            .read()
+            .ids()
+            .into_iter()
+            .collect();
+        let all = self.storage.vector_storage.read().ids();
+        let total = all.len();
+        let mut nodes: Vec<u64> = all
+            .into_iter()
+            .filter(|id| with_payload.contains(id))
+            .collect();
+        nodes.sort_unstable();
+        let vector_only = total - nodes.len();
+        (nodes, vector_only)
+    }
+}
+
+/// The node set of a kNN-graph build.
+struct KnnNodes {
+    ids: FxHashSet<u64>,
+    /// The same ids as a search allowlist; `None` when one exceeds `u32`.
+    bitmap: Option<RoaringBitmap>,
+}
+
+impl KnnNodes {
+    fn new(ids: &[u64]) -> Self {
+        Self {
+            ids: ids.iter().copied().collect(),
+            bitmap: ids.iter().map(|&id| u32::try_from(id).ok()).collect(),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Tests for the kNN-graph builder (`build_knn_graph`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::collection::types::Collection;
+use crate::error::Error;
+use crate::test_fixtures::fixtures::{make_point, make_point_with_payload, setup_collection};
+
+/// Two clusters (x and y axes) plus a payload-less point next to node 1.
+fn setup_graph_collection() -> (tempfile::TempDir, Collection) {
+    let mut points: Vec<_> = [
+        (1, [1.0, 0.0, 0.0]),
+        (2, [0.9, 0.1, 0.0]),
+        (3, [0.0, 1.0, 0.0]),
+        (4, [0.1, 0.9, 0.0]),
+    ]
+    .into_iter()
+    .map(|(id, vector)| {
+        make_point_with_payload(id, vector.to_vec(), serde_json::json!({ "name": id }))
+    })
+    .collect();
+    points.push(make_point(5, vec![0.95, 0.05, 0.0]));
+    let (dir, col) = setup_collection(3);
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+fn edge_pairs(col: &Collection, label: &str) -> Vec<(u64, u64)> {
+    let mut pairs: Vec<_> = col
+        .get_edges_by_label(label)
+        .iter()
+        .map(|e| (e.source(), e.target()))
+        .collect();
+    pairs.sort_unstable();
+    pairs
+}
+
+#[test]
+fn test_knn_graph_links_nearest_nodes_with_scores() {
+    let (_dir, col) = setup_graph_collection();
+    let added = col.build_knn_graph(1, None, "SIMILAR").expect("build");
+
+    assert_eq!(added, 4);
+    // Point 5 has no payload: it is neither a source nor a target.
+    assert_eq!(
+        edge_pairs(&col, "SIMILAR"),
+        vec![(1, 2), (2, 1), (3, 4), (4, 3)]
+    );
+    let edge = &col.get_outgoing_edges(1)[0];
+    let score = edge.property("score").and_then(serde_json::Value::as_f64);
+    assert!(score.is_some_and(|s| s > 0.9), "{edge:?}");
+
+    let reached = col
+        .traverse_bfs(1, 2, Some(&["SIMILAR"]), 10)
+        .expect("traverse");
+    assert!(reached.iter().any(|r| r.target_id == 2));
+    assert!(reached.iter().all(|r| r.target_id != 3));
+}
+
+#[test]
+fn test_knn_graph_threshold_and_rebuild() {
+    let (_dir, col) = setup_graph_collection();
+    // k = 3 would link across clusters; the threshold keeps only close pairs.
+    let added = col.build_knn_graph(3, Some(0.5), "NEAR").expect("build");
+    assert_eq!(added, 4);
+    assert_eq!(
+        edge_pairs(&col, "NEAR"),
+        vec![(1, 2), (2, 1), (3, 4), (4, 3)]
+    );
+
+    // Rebuilding adds only the missing pairs.
+    let added = col.build_knn_graph(3, None, "NEAR").expect("rebuild");
+    assert_eq!(added, 8);
+    assert_eq!(col.get_edges_by_label("NEAR").len(), 12);
+    assert_eq!(col.build_knn_graph(3, None, "NEAR").expect("noop"), 0);
+}
+
+#[test]
+fn test_knn_graph_rejects_empty_label() {
+    let (_dir, col) = setup_graph_collection();
+    let err = col.build_knn_graph(2, None, "  ").expect_err("empty label");
+    assert!(matches!(err, Error::InvalidEdgeLabel(_)), "got {err:?}");
+    assert_eq!(col.build_knn_graph(0, None, "SIMILAR").expect("k = 0"), 0);
+}
+
+#[test]
+fn test_knn_graph_sees_past_many_vector_only_points() {
+    let (_dir, col) = setup_collection(3);
+    let nodes = [
+        (1, [1.0, 0.0, 0.0]),
+        (2, [0.0, 1.0, 0.0]),
+        (3, [0.0, 0.0, 1.0]),
+    ]
+    .into_iter()
+    .map(|(id, vector)| {
+        make_point_with_payload(id, vector.to_vec(), serde_json::json!({ "name": id }))
+    });
+    // Far more vector-only points around every node than one search returns.
+    let vector_only = (0..60u64).map(|i| {
+        #[allow(clippy::cast_precision_loss)]
+        let jitter = (i / 3) as f32 * 0.001;
+        let mut vector = [jitter; 3];
+        vector[usize::try_from(i % 3).expect("test: axis")] = 1.0;
+        make_point(100 + i, vector.to_vec())
+    });
+    col.upsert(nodes.chain(vector_only)).expect("test: upsert");
+    col.set_max_query_limit(Some(10)).expect("test: limit");
+
+    let added = col.build_knn_graph(2, None, "NEAR").expect("build");
+
+    assert_eq!(added, 6);
+    assert_eq!(
+        edge_pairs(&col, "NEAR"),
+        vec![(1, 2), (1, 3), (2, 1), (2, 3), (3, 1), (3, 2)]
+    );
+}
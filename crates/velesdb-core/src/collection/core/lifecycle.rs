@@ -94,6 +94,7 @@ impl Collection {
                 payload_mirror: Arc::new(
                     crate::collection::payload_mirror::PayloadMirror::default(),
                 ),
+                commits: Arc::new(crate::collection::read_snapshot::CommitSequence::default()),
             },
             graph: Arc::new(crate::collection::types::GraphStore {
                 property_index: Arc::new(RwLock::new(parts.property_index)),
@@ -110,6 +111,8 @@ impl Collection {
             query: crate::collection::types::QueryState {
                 sparse_indexes: Arc::new(RwLock::new(parts.sparse_indexes)),
                 secondary_indexes: Arc::new(RwLock::new(HashMap::new())),
+                indexed_ttl_points: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                partial_indexes: Arc::new(RwLock::new(BTreeMap::new())),
                 order_by_advisor: Arc::new(RwLock::new(
                     crate::collection::order_by_advisor::OrderByIndexAdvisor::default(),
                 )),
@@ -117,6 +120,7 @@ impl Collection {
                 query_cache: Arc::new(QueryCache::new(256)),
                 cached_stats: Arc::new(Mutex::new(None)),
                 stats_io_mutex: Arc::new(Mutex::new(())),
+                search_cursors: Arc::default(),
             },
             generations: crate::collection::types::GenerationCounters {
                 write_generation: Arc::new(std::sync::atomic::AtomicU64::new(0)),
@@ -132,12 +136,23 @@ impl Collection {
                 deferred_indexer,
                 async_index_builder,
                 auto_reindex: Arc::new(RwLock::new(None)),
+                progress: crate::collection::index_progress::ProgressListener::default(),
+                change_feed: crate::collection::change_feed::ChangeFeed::default(),
             },
             runtime: crate::collection::types::RuntimeGuards {
                 guard_rails: Arc::new(GuardRails::default()),
                 runtime_limits: Arc::new(RwLock::new(
                     crate::collection::types::RuntimeLimits::default(),
                 )),
+                admission: Arc::new(crate::collection::admission::AdmissionControl::new(
+                    &crate::collection::types::RuntimeLimits::default(),
+                )),
+                upsert_validator: crate::collection::upsert_validator::UpsertValidator::default(),
+                idempotency: crate::collection::idempotency::IdempotencyLog::default(),
+                archive: crate::collection::archive::ArchiveTier::default(),
+                access: crate::collection::access_stats::AccessTracker::default(),
+                #[cfg(feature = "chaos")]
+                faults: crate::collection::chaos::FaultInjector::default(),
             },
         }
     }
@@ -212,7 +227,9 @@ impl Collection {
         let mut params =
             hnsw_params.unwrap_or_else(|| crate::index::hnsw::HnswParams::auto(config.dimension));
         params.storage_mode = config.storage_mode;
-        HnswIndex::with_params(config.dimension, config.metric, params)
+        let index = HnswIndex::with_params(config.dimension, config.metric, params)?;
+        index.set_vector_layout(config.vector_layout);
+        Ok(index)
     }
 
     /// Rebuilds the BM25 full-text index from persisted payloads.
@@ -313,7 +330,7 @@ impl Collection {
         let property_index = Self::load_property_index(&path);
         let label_index = Self::rebuild_label_index(&payload_storage);
         let range_index = Self::load_range_index(&path);
-        let edge_store = Self::load_edge_store(&path);
+        let edge_store = Self::load_edge_store(&path, &config)?;
         let sparse_indexes = Self::load_named_sparse_indexes(&path);
 
         config.point_count =
@@ -337,6 +354,8 @@ impl Collection {
 
         collection.restore_auto_reindex_from_config();
         collection.restore_secondary_indexes_from_config();
+        collection.restore_partial_indexes_from_config()?;
+        collection.restore_access_log();
 
         #[cfg(feature = "persistence")]
         collection.run_post_open_hooks()?;
@@ -535,6 +554,10 @@ impl Collection {
             );
             return None;
         }
+        if let Some(seed) = config.hnsw_params.and_then(|p| p.deterministic_seed()) {
+            idx.inner.write().set_deterministic_build(Some(seed));
+        }
+        idx.set_vector_layout(config.vector_layout);
         Some(idx)
     }
 
@@ -641,13 +664,24 @@ impl Collection {
         default()
     }
 
-    fn load_edge_store(path: &std::path::Path) -> ConcurrentEdgeStore {
-        Self::load_or_default(
+    /// Loads `edge_store.bin` and, when `edge_properties_on_disk` is set,
+    /// attaches the edge property log before the edge WAL is replayed.
+    fn load_edge_store(
+        path: &std::path::Path,
+        config: &CollectionConfig,
+    ) -> Result<ConcurrentEdgeStore> {
+        let edge_store = Self::load_or_default(
             path,
             "edge_store.bin",
             ConcurrentEdgeStore::load_from_file,
             ConcurrentEdgeStore::new,
-        )
+        );
+        if config.edge_properties_on_disk {
+            edge_store.attach_property_log(
+                &path.join(super::graph_edge_properties::EDGE_PROPERTIES_DIR),
+            )?;
+        }
+        Ok(edge_store)
     }
 
     fn load_property_index(path: &std::path::Path) -> PropertyIndex {
@@ -60,6 +60,14 @@ impl Collection {
             #[cfg(feature = "persistence")]
             streaming_config: None,
             indexed_fields: std::collections::BTreeSet::new(),
+            embedding_info: None,
+            max_query_limit: None,
+            query_defaults: crate::collection::QueryDefaults::default(),
+            scoring_profiles: std::collections::BTreeMap::new(),
+            partial_indexes: std::collections::BTreeMap::new(),
+            archive_policy: None,
+            vector_layout: crate::vector_layout::VectorLayout::default(),
+            edge_properties_on_disk: false,
         }
     }
 
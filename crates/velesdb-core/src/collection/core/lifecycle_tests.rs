@@ -736,9 +736,12 @@ fn test_runtime_limits_default_permissive_and_setter_overwrites() {
         max_vectors_per_collection: 10,
         max_payload_size: 64,
         max_perfect_mode_vectors: 5,
+        max_query_limit: 7,
+        ..RuntimeLimits::default()
     });
     let updated = collection.runtime_limits();
     assert_eq!(updated.max_vectors_per_collection, 10);
     assert_eq!(updated.max_payload_size, 64);
     assert_eq!(updated.max_perfect_mode_vectors, 5);
+    assert_eq!(updated.max_query_limit, 7);
 }
@@ -7,6 +7,9 @@
 //!   `list_indexes`, `drop_index`
 
 mod bulk_import;
+mod count;
+#[cfg(all(test, feature = "persistence"))]
+mod count_tests;
 mod crud;
 mod crud_bulk;
 mod crud_helpers;
@@ -15,39 +18,96 @@ mod crud_indexing;
 mod crud_read_delete;
 #[cfg(test)]
 mod crud_tests;
+mod embedding;
+#[cfg(all(test, feature = "persistence"))]
+mod embedding_tests;
+mod file_import;
+#[cfg(all(test, feature = "persistence"))]
+mod file_import_tests;
 mod flush;
 #[cfg(all(test, feature = "persistence"))]
 mod flush_defer_tests;
 mod graph_api;
 #[cfg(test)]
 mod graph_api_tests;
+mod graph_edge_properties;
+#[cfg(all(test, feature = "persistence"))]
+mod graph_edge_properties_tests;
 #[cfg(all(test, feature = "persistence"))]
 mod graph_edge_wal_recovery_tests;
+mod graph_interchange;
+#[cfg(test)]
+mod graph_interchange_tests;
 mod graph_property_index_wiring;
+mod graph_stats;
+#[cfg(test)]
+mod graph_stats_tests;
 mod graph_traversal_helpers;
 mod index_management;
 #[cfg(test)]
 mod index_management_tests;
+#[cfg(test)]
+mod index_progress_tests;
+mod knn_graph;
+#[cfg(test)]
+mod knn_graph_tests;
 mod lifecycle;
 mod lifecycle_create;
 #[cfg(test)]
 mod lifecycle_tests;
 #[cfg(all(test, feature = "persistence"))]
 mod open_reload_tests;
+mod partial_indexes;
+#[cfg(all(test, feature = "persistence"))]
+mod partial_indexes_tests;
+mod profile_comparison;
+#[cfg(all(test, feature = "persistence"))]
+mod profile_comparison_tests;
+mod projection;
 #[cfg(feature = "persistence")]
 mod quantizer_restore;
+mod query_defaults;
+#[cfg(all(test, feature = "persistence"))]
+mod query_defaults_tests;
+mod query_limit;
+#[cfg(all(test, feature = "persistence"))]
+mod query_limit_tests;
 mod recovery;
 #[cfg(all(test, feature = "persistence"))]
 mod recovery_tests;
+mod refresh;
+#[cfg(all(test, feature = "persistence"))]
+mod refresh_tests;
+mod reindex;
+#[cfg(all(test, feature = "persistence"))]
+mod reindex_tests;
+mod residency;
+#[cfg(all(test, feature = "persistence"))]
+mod residency_tests;
+mod sample;
+#[cfg(all(test, feature = "persistence"))]
+mod sample_tests;
+mod scoring_profiles;
+#[cfg(all(test, feature = "persistence"))]
+mod scoring_profiles_tests;
 mod scroll;
 #[cfg(all(test, feature = "persistence"))]
 mod scroll_tests;
 mod statistics;
 #[cfg(all(test, feature = "persistence"))]
 mod ttl_read_tests;
+mod vector_layout;
+#[cfg(all(test, feature = "persistence"))]
+mod vector_layout_tests;
+#[cfg(feature = "persistence")]
+mod verify;
+#[cfg(all(test, feature = "persistence"))]
+mod verify_tests;
 
 pub use crate::validation::{MAX_DIMENSION, MIN_DIMENSION};
 pub use index_management::IndexInfo;
+pub use profile_comparison::{ProfileComparison, ProfileQuery, ProfileRunReport};
+pub use refresh::UpsertOptions;
 pub use scroll::ScrollBatch;
 
 // All implementations are in submodules, no re-exports needed here
@@ -227,3 +227,37 @@ fn test_alpha_round_trip_through_reopen() {
         "custom alpha must survive the save/load round-trip"
     );
 }
+
+/// The neighbor-selection strategy is persisted in `config.json` and in the
+/// v3 `.graph` header, so it survives `flush_full` + reopen.
+#[test]
+fn test_neighbor_selection_round_trip_through_reopen() {
+    use crate::index::hnsw::NeighborSelection;
+
+    let temp = tempfile::tempdir().expect("temp dir");
+    let params = crate::index::hnsw::HnswParams::auto(4)
+        .with_neighbor_selection(NeighborSelection::Heuristic);
+
+    {
+        let coll = Collection::create_with_hnsw_params(
+            PathBuf::from(temp.path()),
+            4,
+            DistanceMetric::Cosine,
+            crate::quantization::StorageMode::Full,
+            params,
+        )
+        .expect("create");
+        assert_eq!(
+            coll.storage.index.inner.read().neighbor_selection(),
+            NeighborSelection::Heuristic
+        );
+        coll.upsert(make_points(0, 3)).expect("upsert");
+        coll.flush_full().expect("flush_full");
+    }
+
+    let reopened = Collection::open(PathBuf::from(temp.path())).expect("reopen");
+    assert_eq!(
+        reopened.storage.index.inner.read().neighbor_selection(),
+        NeighborSelection::Heuristic
+    );
+}
@@ -0,0 +1,177 @@
+//! Partial vector indexes (see [`crate::collection::partial_index`]):
+//! creation, open-time rebuild, incremental maintenance and routing.
+
+use std::collections::btree_map::Entry;
+use std::sync::Arc;
+
+use crate::collection::partial_index::{validate_name, Coverage, PartialIndex, PartialIndexInfo};
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::filter::Filter;
+use crate::storage::{PayloadStorage, VectorStorage};
+
+impl Collection {
+    /// Builds an HNSW graph over the points whose payload matches `filter`
+    /// and persists its definition to `config.json`.
+    ///
+    /// The graph follows every later upsert and delete, and filtered
+    /// searches whose filter includes `filter` as a whole or as one of its
+    /// AND conditions search it instead of the full index.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] for an invalid or already used name, or on a
+    ///   metadata-only collection.
+    /// - I/O errors from persisting the config (the index is dropped again).
+    pub fn create_partial_index(&self, name: &str, filter: Filter) -> Result<()> {
+        validate_name(name)?;
+        let (dimension, metric) = {
+            let config = self.storage.config.read();
+            if config.metadata_only {
+                return Err(Error::Config(format!(
+                    "collection '{}' is metadata-only and has no vectors to index",
+                    config.name
+                )));
+            }
+            (config.dimension, config.metric)
+        };
+        let partial = Arc::new(PartialIndex::new(filter.clone(), dimension, metric)?);
+        match self.query.partial_indexes.write().entry(name.to_string()) {
+            Entry::Occupied(_) => {
+                return Err(Error::Config(format!(
+                    "partial index '{name}' already exists"
+                )));
+            }
+            // Registered before the backfill so writes racing it are synced.
+            Entry::Vacant(slot) => slot.insert(Arc::clone(&partial)),
+        };
+        self.backfill_partial_index(&partial);
+
+        self.storage
+            .config
+            .write()
+            .partial_indexes
+            .insert(name.to_string(), filter);
+        if let Err(e) = self.save_config() {
+            self.storage.config.write().partial_indexes.remove(name);
+            self.query.partial_indexes.write().remove(name);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Drops the partial index `name` and persists the change. Returns
+    /// `false` when no such index exists.
+    ///
+    /// # Errors
+    ///
+    /// I/O errors from persisting the config (the index is restored).
+    pub fn drop_partial_index(&self, name: &str) -> Result<bool> {
+        let Some(partial) = self.query.partial_indexes.write().remove(name) else {
+            return Ok(false);
+        };
+        let filter = self.storage.config.write().partial_indexes.remove(name);
+        if let Err(e) = self.save_config() {
+            if let Some(filter) = filter {
+                self.storage
+                    .config
+                    .write()
+                    .partial_indexes
+                    .insert(name.to_string(), filter);
+            }
+            self.query
+                .partial_indexes
+                .write()
+                .insert(name.to_string(), partial);
+            return Err(e);
+        }
+        Ok(true)
+    }
+
+    /// Lists the partial indexes with their size and routed searches.
+    #[must_use]
+    pub fn partial_indexes(&self) -> Vec<PartialIndexInfo> {
+        self.query
+            .partial_indexes
+            .read()
+            .iter()
+            .map(|(name, partial)| partial.info(name))
+            .collect()
+    }
+
+    /// Rebuilds the partial indexes listed in the config from storage.
+    ///
+    /// Called on open, after WAL replay; does not rewrite `config.json`.
+    pub(super) fn restore_partial_indexes_from_config(&self) -> Result<()> {
+        let (definitions, dimension, metric) = {
+            let config = self.storage.config.read();
+            (
+                config.partial_indexes.clone(),
+                config.dimension,
+                config.metric,
+            )
+        };
+        for (name, filter) in definitions {
+            let partial = Arc::new(PartialIndex::new(filter, dimension, metric)?);
+            self.backfill_partial_index(&partial);
+            self.query.partial_indexes.write().insert(name, partial);
+        }
+        Ok(())
+    }
+
+    /// Inserts every stored point matching the index filter.
+    fn backfill_partial_index(&self, partial: &PartialIndex) {
+        // LOCK ORDER: vector_storage(2) → payload_storage(3), both read and
+        // held through the insert so a concurrent delete cannot be undone.
+        let vectors = self.storage.vector_storage.read();
+        let payloads = self.storage.payload_storage.read();
+        let matching: Vec<(u64, Vec<f32>)> = VectorStorage::ids(&*vectors)
+            .into_iter()
+            .filter(|&id| partial.admits(payloads.retrieve(id).ok().flatten().as_ref()))
+            .filter_map(|id| Some((id, vectors.retrieve(id).ok().flatten()?)))
+            .collect();
+        partial
+            .index
+            .insert_batch_parallel(matching.iter().map(|(id, v)| (*id, v.as_slice())));
+    }
+
+    /// Re-syncs the partial indexes with the stored state of `ids` after a
+    /// write. A no-op while the collection has none.
+    pub(crate) fn sync_partial_indexes(&self, ids: impl IntoIterator<Item = u64>) {
+        let partials: Vec<Arc<PartialIndex>> = self
+            .query
+            .partial_indexes
+            .read()
+            .values()
+            .cloned()
+            .collect();
+        if partials.is_empty() {
+            return;
+        }
+        // LOCK ORDER: vector_storage(2) → payload_storage(3), both read and
+        // held through the graph updates so two writers of one id cannot
+        // apply their reads out of order.
+        let vectors = self.storage.vector_storage.read();
+        let payloads = self.storage.payload_storage.read();
+        for id in ids {
+            let vector = vectors.retrieve(id).ok().flatten();
+            let payload = payloads.retrieve(id).ok().flatten();
+            for partial in &partials {
+                partial.sync_point(id, vector.as_deref(), payload.as_ref());
+            }
+        }
+    }
+
+    /// The smallest partial index whose filter `filter` implies, if any.
+    pub(crate) fn partial_index_for(
+        &self,
+        filter: &Filter,
+    ) -> Option<(Arc<PartialIndex>, Coverage)> {
+        self.query
+            .partial_indexes
+            .read()
+            .values()
+            .filter_map(|partial| Some((Arc::clone(partial), partial.coverage(filter)?)))
+            .min_by_key(|(partial, _)| partial.index.len())
+    }
+}
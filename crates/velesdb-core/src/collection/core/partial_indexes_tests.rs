@@ -0,0 +1,169 @@
+#![cfg(all(test, feature = "persistence"))]
+#![allow(clippy::cast_precision_loss)]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::filter::{Condition, Filter};
+use crate::point::Point;
+
+/// Twenty points fanning out from `[1, 0]`; even ids are `news`, odd ids
+/// `sports`.
+fn temp_collection() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 2, DistanceMetric::Cosine)
+        .expect("collection created");
+    let points: Vec<Point> = (0..20u64)
+        .map(|i| {
+            let category = if i % 2 == 0 { "news" } else { "sports" };
+            Point::new(
+                i,
+                vec![1.0, 0.05 * i as f32],
+                Some(json!({ "category": category, "lang": if i < 10 { "en" } else { "fr" } })),
+            )
+        })
+        .collect();
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn news() -> Filter {
+    Filter::new(Condition::eq("category", "news"))
+}
+
+fn searches(col: &Collection) -> u64 {
+    col.partial_indexes()[0].searches
+}
+
+fn ids(col: &Collection, filter: &Filter, k: usize) -> Vec<u64> {
+    col.search_with_filter(&[1.0, 0.0], k, filter)
+        .expect("search")
+        .iter()
+        .map(|r| r.point.id)
+        .collect()
+}
+
+#[test]
+fn test_partial_index_serves_covered_filters() {
+    let (_dir, col) = temp_collection();
+    col.create_partial_index("news", news()).expect("create");
+    let info = &col.partial_indexes()[0];
+    assert_eq!(info.name, "news");
+    assert_eq!(info.points, 10);
+    assert_eq!(info.searches, 0);
+
+    assert_eq!(ids(&col, &news(), 3), vec![0, 2, 4]);
+    assert_eq!(searches(&col), 1);
+
+    // A narrower filter routes too; the extra condition is still applied.
+    let news_fr = Filter::new(Condition::and(vec![
+        Condition::eq("lang", "fr"),
+        Condition::eq("category", "news"),
+    ]));
+    assert_eq!(ids(&col, &news_fr, 2), vec![10, 12]);
+    assert_eq!(searches(&col), 2);
+
+    // Filters that do not imply `category = 'news'` use the full index.
+    let either = Filter::new(Condition::or(vec![
+        Condition::eq("category", "news"),
+        Condition::eq("lang", "en"),
+    ]));
+    assert_eq!(ids(&col, &either, 2), vec![0, 1]);
+    assert_eq!(
+        ids(&col, &Filter::new(Condition::eq("lang", "en")), 1),
+        vec![0]
+    );
+    assert_eq!(searches(&col), 2);
+
+    // VelesQL filtered searches take the same route.
+    let params = HashMap::from([("v".to_string(), json!([1.0, 0.0]))]);
+    let rows = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v AND category = 'news' LIMIT 2",
+            &params,
+        )
+        .expect("query");
+    let rows: Vec<u64> = rows.iter().map(|r| r.point.id).collect();
+    assert_eq!(rows, vec![0, 2]);
+    assert_eq!(searches(&col), 3);
+}
+
+#[test]
+fn test_partial_index_follows_writes() {
+    let (_dir, col) = temp_collection();
+    col.create_partial_index("news", news()).expect("create");
+
+    // Point 1 becomes news, point 0 leaves it, point 4 is deleted.
+    col.upsert(vec![
+        Point::new(1, vec![1.0, 0.05], Some(json!({ "category": "news" }))),
+        Point::new(0, vec![1.0, 0.0], Some(json!({ "category": "sports" }))),
+    ])
+    .expect("upsert");
+    col.delete(&[4]).expect("delete");
+    assert_eq!(col.partial_indexes()[0].points, 9);
+    assert_eq!(ids(&col, &news(), 3), vec![1, 2, 6]);
+
+    // Bulk upserts are synced as well.
+    col.upsert_bulk(&[Point::new(
+        30,
+        vec![1.0, 0.0],
+        Some(json!({ "category": "news" })),
+    )])
+    .expect("bulk upsert");
+    assert_eq!(ids(&col, &news(), 1), vec![30]);
+}
+
+#[test]
+fn test_partial_index_rebuilt_on_open_and_dropped() {
+    let (dir, col) = temp_collection();
+    col.create_partial_index("news", news()).expect("create");
+    drop(col);
+
+    let raw = std::fs::read_to_string(dir.path().join("config.json")).expect("config.json");
+    let stored: serde_json::Value = serde_json::from_str(&raw).expect("json");
+    assert_eq!(
+        stored["partial_indexes"]["news"]["condition"]["value"],
+        json!("news")
+    );
+
+    let reopened = Collection::open(PathBuf::from(dir.path())).expect("reopen");
+    assert_eq!(reopened.partial_indexes()[0].points, 10);
+    assert_eq!(ids(&reopened, &news(), 2), vec![0, 2]);
+    assert_eq!(searches(&reopened), 1);
+
+    assert!(reopened.drop_partial_index("news").expect("drop"));
+    assert!(!reopened
+        .drop_partial_index("news")
+        .expect("already dropped"));
+    assert!(reopened.partial_indexes().is_empty());
+    assert!(reopened.config().partial_indexes.is_empty());
+    assert_eq!(ids(&reopened, &news(), 2), vec![0, 2]);
+}
+
+#[test]
+fn test_invalid_partial_indexes_rejected() {
+    let (_dir, col) = temp_collection();
+    for name in ["", "news index", &"n".repeat(65)] {
+        let err = col.create_partial_index(name, news()).expect_err(name);
+        assert!(matches!(err, Error::Config(_)), "{name}: {err:?}");
+    }
+    col.create_partial_index("news", news()).expect("create");
+    let err = col
+        .create_partial_index("news", Filter::new(Condition::eq("lang", "en")))
+        .expect_err("duplicate");
+    assert!(err.to_string().contains("already exists"), "{err}");
+    assert_eq!(col.partial_indexes().len(), 1);
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let meta = Collection::create_metadata_only(PathBuf::from(dir.path()), "meta")
+        .expect("metadata collection");
+    let err = meta
+        .create_partial_index("news", news())
+        .expect_err("metadata-only");
+    assert!(matches!(err, Error::Config(_)), "{err:?}");
+}
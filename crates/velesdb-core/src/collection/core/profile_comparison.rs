@@ -0,0 +1,215 @@
+//! A/B comparison of two scoring profiles over a query set.
+//!
+//! [`Collection::compare_profiles`] runs every query once under each profile
+//! (alternating which goes first, so cache warm-up favours neither) and reports latency
+//! percentiles, how much the two rankings agree and — given the relevant ids
+//! of each query — recall, MRR and NDCG, all computed with
+//! [`crate::metrics`].
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::collection::scoring_profile::PROFILE_OPTION;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::metrics::{compute_latency_percentiles, mrr, ndcg_at_k, recall_at_k, LatencyStats};
+use crate::velesql::{Query, WithValue};
+
+/// A VelesQL query and its parameters, run under each compared profile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileQuery {
+    /// VelesQL SELECT; its own `profile` option is replaced.
+    pub sql: String,
+    /// Query parameters (`$v`, ...).
+    pub params: HashMap<String, serde_json::Value>,
+}
+
+impl ProfileQuery {
+    /// A query without parameters.
+    #[must_use]
+    pub fn new(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            params: HashMap::new(),
+        }
+    }
+
+    /// Binds `$name` to `value`.
+    #[must_use]
+    pub fn with_param(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.params.insert(name.into(), value);
+        self
+    }
+}
+
+/// Measurements of one profile over the query set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileRunReport {
+    /// Profile name.
+    pub profile: String,
+    /// Per-query latency percentiles.
+    pub latency: LatencyStats,
+    /// Mean rows returned per query.
+    pub mean_results: f64,
+    /// Mean recall of the relevant ids; `None` without ground truth.
+    pub recall: Option<f64>,
+    /// Mean reciprocal rank of the first relevant id; `None` without
+    /// ground truth.
+    pub mrr: Option<f64>,
+    /// Mean binary-relevance NDCG at the number of rows returned; `None`
+    /// without ground truth.
+    pub ndcg: Option<f64>,
+}
+
+/// Result of [`Collection::compare_profiles`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileComparison {
+    /// Number of queries run under each profile.
+    pub queries: usize,
+    /// Measurements of the first profile.
+    pub a: ProfileRunReport,
+    /// Measurements of the second profile.
+    pub b: ProfileRunReport,
+    /// Mean share of profile A's ids that profile B returns too (1.0 when
+    /// both return the same rows, in any order, or none).
+    pub overlap: f64,
+}
+
+/// Ids and latency of each query under one profile.
+#[derive(Default)]
+struct ProfileRun {
+    ids: Vec<Vec<u64>>,
+    latencies: Vec<Duration>,
+}
+
+impl ProfileRun {
+    fn report(&self, profile: &str, ground_truth: Option<&[Vec<u64>]>) -> ProfileRunReport {
+        let per_query = |metric: fn(&[u64], &[u64]) -> f64| {
+            ground_truth
+                .map(|truth| mean(truth.iter().zip(&self.ids).map(|(t, ids)| metric(t, ids))))
+        };
+        ProfileRunReport {
+            profile: profile.to_string(),
+            latency: compute_latency_percentiles(&self.latencies),
+            #[allow(clippy::cast_precision_loss)]
+            // Reason: row counts are bounded by the query limit, far below 2^53.
+            mean_results: mean(self.ids.iter().map(|ids| ids.len() as f64)),
+            recall: per_query(recall_at_k),
+            mrr: per_query(mrr),
+            ndcg: per_query(binary_ndcg),
+        }
+    }
+}
+
+/// NDCG of `ids` when exactly the `relevant` ids have relevance 1.
+///
+/// Relevant ids the query missed are appended after the returned rows, so
+/// the ideal ranking [`ndcg_at_k`] derives counts them too.
+fn binary_ndcg(relevant: &[u64], ids: &[u64]) -> f64 {
+    let mut relevances: Vec<f64> = ids
+        .iter()
+        .map(|id| if relevant.contains(id) { 1.0 } else { 0.0 })
+        .collect();
+    let missed = relevant.iter().filter(|id| !ids.contains(id)).count();
+    relevances.extend(std::iter::repeat_n(1.0, missed));
+    ndcg_at_k(&relevances, ids.len())
+}
+
+/// Mean of `values`; 0.0 when empty.
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, n) = values.fold((0.0, 0_u32), |(sum, n), v| (sum + v, n + 1));
+    if n == 0 {
+        0.0
+    } else {
+        sum / f64::from(n)
+    }
+}
+
+/// `query` with its `profile` option set to `profile`.
+fn with_profile(query: &Query, profile: &str) -> Query {
+    let mut query = query.clone();
+    let mut clause = query.select.with_clause.take().unwrap_or_default();
+    clause
+        .options
+        .retain(|opt| !opt.key.eq_ignore_ascii_case(PROFILE_OPTION));
+    query.select.with_clause =
+        Some(clause.with_option(PROFILE_OPTION, WithValue::String(profile.to_string())));
+    query
+}
+
+impl Collection {
+    /// Runs every query under scoring profile `profile_a` and `profile_b`
+    /// and compares latency, agreement and — when `ground_truth` lists the
+    /// relevant ids of each query — recall, MRR and NDCG.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when `queries` is empty, a profile does not
+    /// exist, `ground_truth` does not hold one entry per query, or a query
+    /// fails.
+    pub fn compare_profiles(
+        &self,
+        queries: &[ProfileQuery],
+        profile_a: &str,
+        profile_b: &str,
+        ground_truth: Option<&[Vec<u64>]>,
+    ) -> Result<ProfileComparison> {
+        if queries.is_empty() {
+            return Err(Error::Query(
+                "compare_profiles needs at least one query".to_string(),
+            ));
+        }
+        if let Some(truth) = ground_truth.filter(|t| t.len() != queries.len()) {
+            return Err(Error::Query(format!(
+                "ground truth has {} entries for {} queries",
+                truth.len(),
+                queries.len()
+            )));
+        }
+        for profile in [profile_a, profile_b] {
+            if self.scoring_profile(profile).is_none() {
+                return Err(Error::Query(format!(
+                    "Unknown scoring profile '{profile}' on collection '{}'",
+                    self.storage.config.read().name
+                )));
+            }
+        }
+
+        let profiles = [profile_a, profile_b];
+        let mut runs = [ProfileRun::default(), ProfileRun::default()];
+        for (i, pq) in queries.iter().enumerate() {
+            let query = self
+                .query
+                .query_cache
+                .parse(&pq.sql)
+                .map_err(|e| Error::Query(e.to_string()))?;
+            // Alternate which profile runs first so neither always meets
+            // the caches the other warmed.
+            let order = if i % 2 == 0 { [0, 1] } else { [1, 0] };
+            for side in order {
+                let query = with_profile(&query, profiles[side]);
+                let started = Instant::now();
+                let results = self.execute_query(&query, &pq.params)?;
+                runs[side].latencies.push(started.elapsed());
+                runs[side]
+                    .ids
+                    .push(results.iter().map(|r| r.point.id).collect());
+            }
+        }
+
+        let [a, b] = runs;
+        let overlap = mean(a.ids.iter().zip(&b.ids).map(|(a_ids, b_ids)| {
+            if a_ids.is_empty() && b_ids.is_empty() {
+                1.0
+            } else {
+                recall_at_k(a_ids, b_ids)
+            }
+        }));
+        Ok(ProfileComparison {
+            queries: queries.len(),
+            a: a.report(profile_a, ground_truth),
+            b: b.report(profile_b, ground_truth),
+            overlap,
+        })
+    }
+}
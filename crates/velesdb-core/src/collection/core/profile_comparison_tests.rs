@@ -0,0 +1,122 @@
+#![cfg(all(test, feature = "persistence"))]
+#![allow(clippy::cast_precision_loss, clippy::float_cmp)]
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::collection::types::Collection;
+use crate::collection::{ProfileQuery, ScoringProfile};
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+use crate::velesql::{FusionClause, FusionStrategyType};
+
+/// Ten unviewed points close to the query, then one popular point further
+/// off the query direction; profile `popular` boosts it to the top.
+fn temp_collection() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 2, DistanceMetric::Cosine)
+        .expect("collection created");
+    let mut points: Vec<Point> = (0..10u64)
+        .map(|i| {
+            let y = 0.01 * (i + 1) as f32;
+            Point::new(i, vec![1.0, y], Some(json!({ "views": 0 })))
+        })
+        .collect();
+    points.push(Point::new(
+        10,
+        vec![1.0, 0.3],
+        Some(json!({ "views": 10_000 })),
+    ));
+    col.upsert(points).expect("upsert");
+
+    let plain = ScoringProfile {
+        with: BTreeMap::from([("rerank".to_string(), json!(false))]),
+        fusion: None,
+    };
+    let popular = ScoringProfile {
+        with: BTreeMap::new(),
+        fusion: Some(FusionClause {
+            strategy: FusionStrategyType::FieldBoost,
+            boost_field: Some("views".to_string()),
+            boost_alpha: Some(0.2),
+            ..FusionClause::default()
+        }),
+    };
+    col.set_scoring_profile("plain", plain).expect("plain");
+    col.set_scoring_profile("popular", popular)
+        .expect("popular");
+    (dir, col)
+}
+
+fn queries() -> Vec<ProfileQuery> {
+    vec![
+        ProfileQuery::new("SELECT * FROM c WHERE vector NEAR $v LIMIT 3")
+            .with_param("v", json!([1.0, 0.0])),
+        // The query's own profile is replaced by the compared ones.
+        ProfileQuery::new(
+            "SELECT * FROM c WHERE vector NEAR $v LIMIT 3 WITH (profile = 'popular')",
+        )
+        .with_param("v", json!([1.0, 0.0])),
+    ]
+}
+
+#[test]
+fn test_compare_profiles_scores_against_ground_truth() {
+    let (_dir, col) = temp_collection();
+    let truth = vec![vec![10, 0, 1], vec![10, 0, 1]];
+    let report = col
+        .compare_profiles(&queries(), "plain", "popular", Some(&truth))
+        .expect("comparison");
+
+    assert_eq!(report.queries, 2);
+    assert_eq!(report.a.profile, "plain");
+    assert_eq!(report.b.profile, "popular");
+    assert_eq!(report.a.mean_results, 3.0);
+    assert!(report.a.latency.max > std::time::Duration::ZERO);
+
+    // plain returns [0, 1, 2]: two of three relevant ids, first at rank 1.
+    assert!((report.a.recall.expect("recall") - 2.0 / 3.0).abs() < 1e-12);
+    assert_eq!(report.a.mrr, Some(1.0));
+    // popular returns [10, 0, 1]: the ideal ranking.
+    assert_eq!(report.b.recall, Some(1.0));
+    assert_eq!(report.b.mrr, Some(1.0));
+    assert!((report.b.ndcg.expect("ndcg") - 1.0).abs() < 1e-12);
+    let ndcg_a = report.a.ndcg.expect("ndcg");
+    assert!(ndcg_a > 0.0 && ndcg_a < 1.0, "{ndcg_a}");
+
+    // Both profiles return points 0 and 1.
+    assert!((report.overlap - 2.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_compare_profiles_without_ground_truth() {
+    let (_dir, col) = temp_collection();
+    let report = col
+        .compare_profiles(&queries(), "plain", "plain", None)
+        .expect("comparison");
+    assert_eq!(report.a.recall, None);
+    assert_eq!(report.b.ndcg, None);
+    assert_eq!(report.overlap, 1.0);
+}
+
+#[test]
+fn test_compare_profiles_rejects_bad_input() {
+    let (_dir, col) = temp_collection();
+    let err = col
+        .compare_profiles(&[], "plain", "popular", None)
+        .expect_err("no queries");
+    assert!(matches!(err, Error::Query(_)), "{err:?}");
+
+    let err = col
+        .compare_profiles(&queries(), "plain", "missing", None)
+        .expect_err("unknown profile");
+    assert!(err.to_string().contains("missing"), "{err}");
+
+    let err = col
+        .compare_profiles(&queries(), "plain", "popular", Some(&[vec![10]]))
+        .expect_err("short ground truth");
+    assert!(err.to_string().contains("ground truth"), "{err}");
+}
@@ -0,0 +1,42 @@
+//! 2-D projection export for visualization.
+
+use crate::collection::projection::{
+    project, Projection2d, ProjectionMethod, MAX_PROJECTION_SAMPLE,
+};
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::filter::Filter;
+use crate::simd_native::normalize_inplace_native;
+
+impl Collection {
+    /// Lays out a uniform sample of up to `sample_size` vectors (capped at
+    /// [`MAX_PROJECTION_SAMPLE`]) in 2-D with `method`, labelling each point
+    /// with one of up to `clusters` k-means clusters. With a `filter`, only
+    /// matching points are sampled.
+    ///
+    /// Cosine collections are normalized first so the layout reflects angular
+    /// distance. The sample is random on each call; the layout of a given
+    /// sample is deterministic. Metadata-only collections yield no points.
+    #[must_use]
+    pub fn project_2d(
+        &self,
+        method: ProjectionMethod,
+        sample_size: usize,
+        clusters: usize,
+        filter: Option<&Filter>,
+    ) -> Projection2d {
+        let metric = self.storage.config.read().metric;
+        let (ids, vectors): (Vec<u64>, Vec<Vec<f32>>) = self
+            .sample(sample_size.min(MAX_PROJECTION_SAMPLE), filter, None)
+            .into_iter()
+            .filter(|point| !point.vector.is_empty())
+            .map(|mut point| {
+                if metric == DistanceMetric::Cosine {
+                    normalize_inplace_native(&mut point.vector);
+                }
+                (point.id, point.vector)
+            })
+            .unzip();
+        project(&ids, &vectors, method, clusters)
+    }
+}
@@ -0,0 +1,74 @@
+//! Collection-level search-option defaults (`WITH` / `USING FUSION`) and
+//! the default filter collation.
+
+use std::borrow::Cow;
+
+use crate::collection::query_defaults::QueryDefaults;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::filter::{Collation, Condition, Filter};
+
+impl Collection {
+    /// Returns the search-option defaults applied to queries that omit them.
+    #[must_use]
+    pub fn query_defaults(&self) -> QueryDefaults {
+        self.storage.config.read().query_defaults
+    }
+
+    /// Replaces the collection's search-option defaults and persists them to
+    /// `config.json`. Pass [`QueryDefaults::default()`] to clear them.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] when `ef_search` is `Some(0)`.
+    /// - I/O errors from persisting the config (the in-memory value is
+    ///   rolled back so memory and disk never disagree).
+    pub fn set_query_defaults(&self, defaults: QueryDefaults) -> Result<()> {
+        if defaults.ef_search == Some(0) {
+            return Err(Error::Config(
+                "default ef_search must be greater than 0".to_string(),
+            ));
+        }
+        let previous = std::mem::replace(&mut self.storage.config.write().query_defaults, defaults);
+        if let Err(e) = self.save_config() {
+            self.storage.config.write().query_defaults = previous;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of `query` with the collection defaults filled into its
+    /// SELECT, or `None` when the query already sets every defaulted option.
+    pub(crate) fn apply_query_defaults(
+        &self,
+        query: &crate::velesql::Query,
+    ) -> Option<crate::velesql::Query> {
+        let select = self.query_defaults().apply_to(&query.select)?;
+        let mut query = query.clone();
+        query.select = select;
+        Some(query)
+    }
+
+    /// Collation applied to filters that do not set one.
+    pub(crate) fn default_collation(&self) -> Collation {
+        self.query_defaults().collation.unwrap_or_default()
+    }
+
+    /// Returns `filter` with the collection's default collation filled in
+    /// when it sets none, borrowing it when nothing changes.
+    pub(crate) fn collated<'a>(&self, filter: &'a Filter) -> Cow<'a, Filter> {
+        if filter.collation.is_some() {
+            return Cow::Borrowed(filter);
+        }
+        match self.default_collation() {
+            Collation::Binary => Cow::Borrowed(filter),
+            collation => Cow::Owned(filter.clone().with_collation(collation)),
+        }
+    }
+
+    /// Builds the filter for a VelesQL condition, evaluated under the
+    /// collection's default collation.
+    pub(crate) fn where_filter(&self, condition: crate::velesql::Condition) -> Filter {
+        Filter::new(Condition::from(condition)).with_collation(self.default_collation())
+    }
+}
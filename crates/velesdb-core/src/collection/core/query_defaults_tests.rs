@@ -0,0 +1,148 @@
+#![cfg(all(test, feature = "persistence"))]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::collection::types::Collection;
+use crate::collection::QueryDefaults;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::filter::Collation;
+use crate::point::Point;
+use crate::velesql::{FusionStrategyType, Parser, Query, WithValue};
+
+fn temp_collection() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 4, DistanceMetric::Cosine)
+        .expect("collection created");
+    let points: Vec<Point> = (1u64..=5)
+        .map(|id| Point {
+            id,
+            vector: vec![0.1, 0.2, 0.3, 0.4],
+            payload: Some(serde_json::json!({ "n": id })),
+            sparse_vectors: None,
+        })
+        .collect();
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn all_defaults() -> QueryDefaults {
+    QueryDefaults {
+        ef_search: Some(200),
+        rerank: Some(true),
+        fusion: Some(FusionStrategyType::Rsf),
+        collation: Some(Collation::CaseInsensitive),
+    }
+}
+
+fn parse(sql: &str) -> Query {
+    Parser::parse(sql).expect("valid query")
+}
+
+#[test]
+fn test_query_defaults_persist_across_reopen() {
+    let (dir, col) = temp_collection();
+    assert!(col.query_defaults().is_empty());
+
+    col.set_query_defaults(all_defaults())
+        .expect("set defaults");
+    drop(col);
+
+    let raw = std::fs::read_to_string(dir.path().join("config.json")).expect("config.json");
+    let json: serde_json::Value = serde_json::from_str(&raw).expect("json");
+    assert_eq!(
+        json["query_defaults"],
+        serde_json::json!({
+            "ef_search": 200,
+            "rerank": true,
+            "fusion": "rsf",
+            "collation": "case_insensitive"
+        })
+    );
+
+    let reopened = Collection::open(PathBuf::from(dir.path())).expect("reopen");
+    assert_eq!(reopened.query_defaults(), all_defaults());
+
+    reopened
+        .set_query_defaults(QueryDefaults::default())
+        .expect("clear defaults");
+    assert!(reopened.config().query_defaults.is_empty());
+}
+
+#[test]
+fn test_zero_ef_search_default_rejected() {
+    let (_dir, col) = temp_collection();
+    let err = col
+        .set_query_defaults(QueryDefaults {
+            ef_search: Some(0),
+            ..QueryDefaults::default()
+        })
+        .expect_err("zero");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+    assert!(col.query_defaults().is_empty());
+}
+
+#[test]
+fn test_defaults_fill_options_the_query_omits() {
+    let (_dir, col) = temp_collection();
+    col.set_query_defaults(all_defaults())
+        .expect("set defaults");
+
+    let query = parse("SELECT * FROM c WHERE vector NEAR $v LIMIT 5");
+    let applied = col.apply_query_defaults(&query).expect("defaults applied");
+    let with = applied.select.with_clause.expect("WITH filled");
+    assert_eq!(with.get_ef_search(), Some(200));
+    assert_eq!(with.get_rerank(), Some(true));
+    let fusion = applied.select.fusion_clause.expect("fusion filled");
+    assert_eq!(fusion.strategy, FusionStrategyType::Rsf);
+}
+
+#[test]
+fn test_query_options_take_precedence_over_defaults() {
+    let (_dir, col) = temp_collection();
+    col.set_query_defaults(all_defaults())
+        .expect("set defaults");
+
+    let query = parse(
+        "SELECT * FROM c WHERE vector NEAR $v AND content MATCH 'x' LIMIT 5 \
+         WITH (ef_search = 32, mode = 'fast') USING FUSION(strategy = 'maximum')",
+    );
+    let applied = col.apply_query_defaults(&query).expect("rerank filled");
+    let with = applied.select.with_clause.expect("WITH kept");
+    assert_eq!(with.get_ef_search(), Some(32));
+    assert_eq!(with.get_mode(), Some("fast"));
+    assert_eq!(with.get("rerank"), Some(&WithValue::Boolean(true)));
+    assert_eq!(
+        applied.select.fusion_clause.expect("fusion kept").strategy,
+        FusionStrategyType::Maximum
+    );
+
+    let moded = parse("SELECT * FROM c WHERE vector NEAR $v LIMIT 5 WITH (mode = 'accurate')");
+    let applied = col.apply_query_defaults(&moded).expect("rerank filled");
+    let with = applied.select.with_clause.expect("WITH kept");
+    assert_eq!(with.get_ef_search(), None, "mode is not overridden");
+    assert_eq!(with.get_mode(), Some("accurate"));
+
+    let full = parse(
+        "SELECT * FROM c WHERE vector NEAR $v AND content MATCH 'x' LIMIT 5 \
+         WITH (ef_search = 32, rerank = false) USING FUSION(strategy = 'rrf')",
+    );
+    assert!(
+        col.apply_query_defaults(&full).is_none(),
+        "nothing left to fill"
+    );
+}
+
+#[test]
+fn test_query_with_defaults_executes() {
+    let (_dir, col) = temp_collection();
+    col.set_query_defaults(all_defaults())
+        .expect("set defaults");
+
+    let params = HashMap::from([("v".to_string(), serde_json::json!([0.1, 0.2, 0.3, 0.4]))]);
+    let rows = col
+        .execute_query_str("SELECT * FROM c WHERE vector NEAR $v LIMIT 3", &params)
+        .expect("defaults do not break a plain vector query");
+    assert_eq!(rows.len(), 3);
+}
@@ -0,0 +1,87 @@
+//! Per-query row ceiling (`limits.max_query_limit`) and its per-collection override.
+
+use crate::collection::graph::TraversalConfig;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+
+impl Collection {
+    /// Overrides (or, with `None`, clears the override of) the database-wide
+    /// `limits.max_query_limit` for this collection and persists it to
+    /// `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] when `limit` is `Some(0)`.
+    /// - I/O errors from persisting the config (the in-memory value is
+    ///   rolled back so memory and disk never disagree).
+    pub fn set_max_query_limit(&self, limit: Option<usize>) -> Result<()> {
+        if limit == Some(0) {
+            return Err(Error::Config(
+                "max_query_limit must be greater than 0".to_string(),
+            ));
+        }
+        let previous = std::mem::replace(&mut self.storage.config.write().max_query_limit, limit);
+        if let Err(e) = self.save_config() {
+            self.storage.config.write().max_query_limit = previous;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Returns the most rows a single query against this collection may
+    /// request: the collection override when set, the database-wide
+    /// `limits.max_query_limit` otherwise.
+    #[must_use]
+    pub fn max_query_limit(&self) -> usize {
+        self.storage
+            .config
+            .read()
+            .max_query_limit
+            .unwrap_or_else(|| self.runtime_limits().max_query_limit)
+    }
+
+    /// Rejects a requested row count above [`max_query_limit`](Self::max_query_limit).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::QueryLimitExceeded`] when `requested` is too large.
+    pub fn check_query_limit(&self, requested: u64) -> Result<()> {
+        let max = self.max_query_limit();
+        if usize::try_from(requested).map_or(true, |r| r > max) {
+            return Err(Error::QueryLimitExceeded { requested, max });
+        }
+        Ok(())
+    }
+
+    /// Checks every explicit limit a VelesQL query carries: the SELECT
+    /// `LIMIT` and, for MATCH queries, the RETURN `LIMIT`.
+    pub(crate) fn check_query_limits(&self, query: &crate::velesql::Query) -> Result<()> {
+        if let Some(limit) = query.select.limit {
+            self.check_query_limit(limit)?;
+        }
+        if let Some(limit) = query
+            .match_clause
+            .as_ref()
+            .and_then(|m| m.return_clause.limit)
+        {
+            self.check_query_limit(limit)?;
+        }
+        Ok(())
+    }
+
+    /// Caps a traversal `limit` at [`max_query_limit`](Self::max_query_limit)
+    /// for the traversal APIs, which return results rather than errors.
+    pub(crate) fn capped_traversal_config<'a>(
+        &self,
+        config: &'a TraversalConfig,
+    ) -> std::borrow::Cow<'a, TraversalConfig> {
+        let max = self.max_query_limit();
+        if config.limit <= max {
+            return std::borrow::Cow::Borrowed(config);
+        }
+        std::borrow::Cow::Owned(TraversalConfig {
+            limit: max,
+            ..config.clone()
+        })
+    }
+}
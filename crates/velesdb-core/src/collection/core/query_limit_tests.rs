@@ -0,0 +1,159 @@
+#![cfg(all(test, feature = "persistence"))]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::collection::graph::{GraphEdge, TraversalConfig};
+use crate::collection::types::{Collection, RuntimeLimits};
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+
+fn temp_collection() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 4, DistanceMetric::Cosine)
+        .expect("collection created");
+    let points: Vec<Point> = (1u64..=5)
+        .map(|id| Point {
+            id,
+            vector: vec![0.1, 0.2, 0.3, 0.4],
+            payload: Some(serde_json::json!({ "n": id })),
+            sparse_vectors: None,
+        })
+        .collect();
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn set_database_limit(col: &Collection, max_query_limit: usize) {
+    col.set_runtime_limits(RuntimeLimits {
+        max_query_limit,
+        ..RuntimeLimits::default()
+    });
+}
+
+fn assert_limit_exceeded(err: &Error, requested: u64, max: usize) {
+    assert!(
+        matches!(err, Error::QueryLimitExceeded { requested: r, max: m } if *r == requested && *m == max),
+        "expected QueryLimitExceeded({requested}, {max}), got {err:?}"
+    );
+    assert_eq!(err.code(), "VELES-037");
+}
+
+#[test]
+fn test_max_query_limit_defaults_to_database_limit() {
+    let (_dir, col) = temp_collection();
+    assert_eq!(col.max_query_limit(), 100_000);
+
+    set_database_limit(&col, 3);
+    assert_eq!(col.max_query_limit(), 3);
+}
+
+#[test]
+fn test_override_wins_and_persists_across_reopen() {
+    let (dir, col) = temp_collection();
+    set_database_limit(&col, 3);
+    col.set_max_query_limit(Some(4)).expect("set override");
+    assert_eq!(col.max_query_limit(), 4);
+    drop(col);
+
+    let reopened = Collection::open(PathBuf::from(dir.path())).expect("reopen");
+    assert_eq!(reopened.config().max_query_limit, Some(4));
+    assert_eq!(reopened.max_query_limit(), 4);
+
+    reopened.set_max_query_limit(None).expect("clear override");
+    assert_eq!(reopened.max_query_limit(), 100_000);
+}
+
+#[test]
+fn test_zero_override_rejected() {
+    let (_dir, col) = temp_collection();
+    let err = col.set_max_query_limit(Some(0)).expect_err("zero");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+    assert_eq!(col.config().max_query_limit, None);
+}
+
+#[test]
+fn test_select_limit_above_max_rejected() {
+    let (_dir, col) = temp_collection();
+    set_database_limit(&col, 3);
+
+    let err = col
+        .execute_query_str("SELECT * FROM c LIMIT 4", &HashMap::new())
+        .expect_err("LIMIT 4 > 3");
+    assert_limit_exceeded(&err, 4, 3);
+
+    let rows = col
+        .execute_query_str("SELECT * FROM c LIMIT 3", &HashMap::new())
+        .expect("LIMIT at the maximum");
+    assert_eq!(rows.len(), 3);
+}
+
+#[test]
+fn test_select_without_limit_clamped_to_max() {
+    let (_dir, col) = temp_collection();
+    col.set_max_query_limit(Some(2)).expect("override");
+
+    let rows = col
+        .execute_query_str("SELECT * FROM c", &HashMap::new())
+        .expect("default LIMIT is clamped, not rejected");
+    assert_eq!(rows.len(), 2);
+}
+
+#[test]
+fn test_compound_limit_above_max_rejected() {
+    let (_dir, col) = temp_collection();
+    col.set_max_query_limit(Some(3)).expect("override");
+
+    let err = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE n = 1 UNION SELECT * FROM c WHERE n = 2 LIMIT 10",
+            &HashMap::new(),
+        )
+        .expect_err("LIMIT 10 > 3");
+    assert_limit_exceeded(&err, 10, 3);
+}
+
+#[test]
+fn test_match_limit_above_max_rejected() {
+    let (_dir, col) = temp_collection();
+    col.set_max_query_limit(Some(3)).expect("override");
+
+    let err = col
+        .execute_query_str("MATCH (a) RETURN a LIMIT 5", &HashMap::new())
+        .expect_err("RETURN LIMIT 5 > 3");
+    assert_limit_exceeded(&err, 5, 3);
+
+    let rows = col
+        .execute_query_str("MATCH (a) RETURN a", &HashMap::new())
+        .expect("no LIMIT");
+    assert_eq!(rows.len(), 3, "unbounded MATCH stops at the maximum");
+}
+
+#[test]
+fn test_scroll_batch_above_max_rejected() {
+    let (_dir, col) = temp_collection();
+    col.set_max_query_limit(Some(2)).expect("override");
+
+    let err = col.scroll_batch(None, 3, None).expect_err("batch 3 > 2");
+    assert_limit_exceeded(&err, 3, 2);
+    assert_eq!(
+        col.scroll_batch(None, 2, None).expect("batch").points.len(),
+        2
+    );
+}
+
+#[test]
+fn test_traversal_limit_capped_at_max() {
+    let (_dir, col) = temp_collection();
+    for target in 2u64..=5 {
+        col.add_edge(GraphEdge::new(target, 1, target, "LINKS").expect("edge"))
+            .expect("add edge");
+    }
+    col.set_max_query_limit(Some(2)).expect("override");
+
+    let config = TraversalConfig::with_range(1, 1).with_limit(10);
+    assert_eq!(col.traverse_bfs_config(1, &config).len(), 2);
+    assert_eq!(col.traverse_dfs_config(1, &config).len(), 2);
+    assert_limit_exceeded(&col.check_query_limit(10).expect_err("10 > 2"), 10, 2);
+}
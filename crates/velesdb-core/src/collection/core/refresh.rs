@@ -0,0 +1,99 @@
+//! Read-your-writes visibility for search after upsert.
+//!
+//! An upsert is durable and readable by id (`get`) as soon as it returns,
+//! but it is not always in the HNSW graph yet:
+//!
+//! - **Default collections** insert into HNSW before `upsert` /
+//!   `upsert_bulk` return, so searches see the write immediately.
+//! - **Deferred indexing** buffers vectors until `merge_threshold` is
+//!   reached. Plain vector search brute-forces the buffer, but search
+//!   paths that only consult the graph miss it until the merge.
+//! - **Async index builder** (`upsert_bulk` V2 path) queues vectors for
+//!   HNSW construction until `merge_threshold` is reached; ANN search does
+//!   not see them before that.
+//!
+//! [`Collection::refresh`] closes that gap without any disk I/O, and
+//! [`UpsertOptions::wait_for_index`] does it as part of the write.
+
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::point::Point;
+
+/// Per-call options for [`Collection::upsert_with_options`] and
+/// [`Collection::upsert_bulk_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpsertOptions {
+    /// Refresh before returning, so that searches issued after the call
+    /// see the upserted points (see [`Collection::refresh`]).
+    pub wait_for_index: bool,
+}
+
+impl UpsertOptions {
+    /// Options that wait for the points to be searchable.
+    #[must_use]
+    pub fn wait_for_index() -> Self {
+        Self {
+            wait_for_index: true,
+        }
+    }
+}
+
+impl Collection {
+    /// Makes every acknowledged write visible to every search path.
+    ///
+    /// Merges the deferred-indexing buffer and the async index builder
+    /// queue into the HNSW graph, waiting for a build already in progress
+    /// on another thread. Unlike [`flush`](Self::flush), nothing is written
+    /// to disk. A no-op on collections that index synchronously.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the async index builder fails to insert.
+    pub fn refresh(&self) -> Result<()> {
+        if let Some(ref di) = self.streaming.deferred_indexer {
+            self.merge_deferred_batch(di);
+        }
+        if let Some(ref aib) = self.streaming.async_index_builder {
+            let count = aib.flush_and_wait(&self.storage.index)?;
+            if count > 0 {
+                tracing::debug!("refresh: indexed {count} queued vectors");
+            }
+        }
+        Ok(())
+    }
+
+    /// [`upsert`](Self::upsert) with per-call options.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`upsert`](Self::upsert) and [`refresh`](Self::refresh).
+    pub fn upsert_with_options(
+        &self,
+        points: impl IntoIterator<Item = Point>,
+        options: UpsertOptions,
+    ) -> Result<()> {
+        self.upsert(points)?;
+        if options.wait_for_index {
+            self.refresh()?;
+        }
+        Ok(())
+    }
+
+    /// [`upsert_bulk`](Self::upsert_bulk) with per-call options.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`upsert_bulk`](Self::upsert_bulk) and
+    /// [`refresh`](Self::refresh).
+    pub fn upsert_bulk_with_options(
+        &self,
+        points: &[Point],
+        options: UpsertOptions,
+    ) -> Result<usize> {
+        let count = self.upsert_bulk(points)?;
+        if options.wait_for_index {
+            self.refresh()?;
+        }
+        Ok(count)
+    }
+}
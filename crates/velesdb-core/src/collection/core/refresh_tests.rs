@@ -0,0 +1,127 @@
+//! Tests for read-your-writes visibility (`refresh`, `UpsertOptions`).
+
+#![allow(clippy::cast_precision_loss)]
+
+use super::UpsertOptions;
+use crate::collection::streaming::{AsyncIndexBuilderConfig, DeferredIndexerConfig};
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+use std::path::PathBuf;
+
+fn make_points(n: u64) -> Vec<Point> {
+    (0..n)
+        .map(|i| {
+            let f = i as f32;
+            Point::without_payload(i, vec![f + 1.0, 1.0, 0.5, 0.25])
+        })
+        .collect()
+}
+
+/// A collection whose `upsert_bulk` queues vectors for async HNSW build,
+/// seeded through `upsert` with enough points (> 100) that search goes
+/// through the graph rather than the small-collection brute-force scan.
+fn async_builder_collection(path: PathBuf) -> Collection {
+    let coll = Collection::create_with_async_builder(
+        path,
+        4,
+        DistanceMetric::Euclidean,
+        AsyncIndexBuilderConfig {
+            merge_threshold: 1_000,
+            segment_count: Some(1),
+        },
+    )
+    .expect("create");
+    coll.upsert(make_points(150)).expect("seed");
+    coll
+}
+
+/// Bulk points placed between the seeded ones, with ids from 1000.
+fn interleaved(n: u64) -> Vec<Point> {
+    (0..n)
+        .map(|i| Point::without_payload(1_000 + i, vec![50.5 + i as f32, 1.0, 0.5, 0.25]))
+        .collect()
+}
+
+/// Exactly on `interleaved(_)[0]`.
+const QUERY: [f32; 4] = [50.5, 1.0, 0.5, 0.25];
+
+#[test]
+fn test_refresh_makes_queued_bulk_writes_searchable() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let coll = async_builder_collection(temp.path().to_path_buf());
+    coll.upsert_bulk(&interleaved(5)).expect("upsert_bulk");
+
+    // Stored and readable by id, but not in the graph yet.
+    assert_eq!(coll.len(), 155);
+    assert!(coll.get(&[1_000])[0].is_some());
+    let before = coll.search(&QUERY, 3).expect("search");
+    assert!(before.iter().all(|r| r.point.id < 1_000));
+
+    coll.refresh().expect("refresh");
+
+    let after = coll.search(&QUERY, 3).expect("search");
+    assert_eq!(after[0].point.id, 1_000);
+}
+
+#[test]
+fn test_upsert_bulk_wait_for_index_is_visible_on_return() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let coll = async_builder_collection(temp.path().to_path_buf());
+
+    let count = coll
+        .upsert_bulk_with_options(&interleaved(5), UpsertOptions::wait_for_index())
+        .expect("upsert_bulk_with_options");
+
+    assert_eq!(count, 5);
+    for i in 0..5 {
+        let query = [50.5 + i as f32, 1.0, 0.5, 0.25];
+        let results = coll.search(&query, 1).expect("search");
+        assert_eq!(results[0].point.id, 1_000 + i);
+    }
+}
+
+#[test]
+fn test_upsert_wait_for_index_merges_deferred_buffer() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let path = temp.path().to_path_buf();
+    {
+        let coll = Collection::create(path.clone(), 4, DistanceMetric::Euclidean).expect("create");
+        let deferred = DeferredIndexerConfig {
+            enabled: true,
+            merge_threshold: 1_000,
+            ..DeferredIndexerConfig::default()
+        };
+        coll.apply_advanced_config(None, Some(Some(deferred)), None)
+            .expect("enable deferred indexing");
+    }
+    // The deferred indexer is built when the collection is opened.
+    let coll = Collection::open(path).expect("reopen");
+    let di = coll
+        .streaming
+        .deferred_indexer
+        .clone()
+        .expect("deferred indexer configured");
+
+    coll.upsert(make_points(3)).expect("upsert");
+    assert_eq!(di.pending_count(), 3);
+
+    coll.upsert_with_options(make_points(4), UpsertOptions::wait_for_index())
+        .expect("upsert_with_options");
+
+    assert_eq!(di.pending_count(), 0);
+    assert_eq!(coll.storage.index.len(), 4);
+}
+
+#[test]
+fn test_refresh_is_noop_for_synchronous_collections() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let coll =
+        Collection::create(temp.path().to_path_buf(), 4, DistanceMetric::Cosine).expect("create");
+    coll.upsert(make_points(4)).expect("upsert");
+    assert_eq!(coll.storage.index.len(), 4);
+
+    coll.refresh().expect("refresh");
+    assert_eq!(coll.storage.index.len(), 4);
+    assert!(!UpsertOptions::default().wait_for_index);
+}
@@ -0,0 +1,91 @@
+//! Online HNSW reindex with benchmark validation and rollback.
+
+use std::time::Instant;
+
+use crate::collection::auto_reindex::{AutoReindexManager, BenchmarkResult, ReindexReason};
+use crate::collection::index_progress::{IndexBuildPhase, IndexProgress};
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::index::hnsw::HnswParams;
+
+impl Collection {
+    /// Rebuilds the HNSW graph with `params`, driving `manager` through
+    /// Started → Progress → Validating → Completed/RolledBack.
+    ///
+    /// The new graph is built beside the live one and benchmarked against it
+    /// with the manager's regression thresholds; it is only swapped in (and
+    /// `params` persisted) when validation passes. Returns `true` when the
+    /// new graph was installed, `false` when it was rolled back.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Config` for invalid `params`, `Error::Index` if a
+    /// reindex is already running on `manager` or the rebuild fails (the
+    /// manager is rolled back in that case), and I/O errors from persisting.
+    pub(crate) fn reindex_with_params(
+        &self,
+        mut params: HnswParams,
+        manager: &AutoReindexManager,
+    ) -> Result<bool> {
+        let config = self.config();
+        params.storage_mode = config.storage_mode;
+        params.validate()?;
+        let old_params = config
+            .hnsw_params
+            .unwrap_or_else(|| HnswParams::auto(config.dimension));
+
+        if !manager.start_reindex(ReindexReason::Manual, old_params, params) {
+            return Err(Error::Index("a reindex is already in progress".to_string()));
+        }
+        let started = Instant::now();
+        let index = &self.storage.index;
+
+        let progress = &self.streaming.progress;
+        let mut on_linked = |linked, total| {
+            let report = IndexProgress::new(IndexBuildPhase::LinkingLayers, linked, total);
+            manager.report_progress(report.percent);
+            progress.emit(report.phase, linked, total);
+        };
+        let candidate = match index.build_rebuild_candidate(&params, &mut on_linked) {
+            Ok(c) => c,
+            Err(e) => {
+                manager.rollback(e.to_string());
+                return Err(Error::Index(format!("reindex failed: {e}")));
+            }
+        };
+
+        let old = index.benchmark_current(&candidate);
+        let new = candidate.benchmark();
+        manager.start_validation(old.latency_p99_us, new.latency_p99_us);
+        let old = BenchmarkResult {
+            latency_p99_us: old.latency_p99_us,
+            recall_estimate: old.recall_estimate,
+            query_count: old.query_count,
+        };
+        let new = BenchmarkResult {
+            latency_p99_us: new.latency_p99_us,
+            recall_estimate: new.recall_estimate,
+            query_count: new.query_count,
+        };
+        if let Err(reason) = manager.validate_benchmark(&old, &new) {
+            manager.rollback(reason);
+            return Ok(false);
+        }
+
+        if let Err(e) = index.install_rebuild_candidate(candidate) {
+            manager.rollback(e.to_string());
+            return Err(Error::Index(format!("reindex failed: {e}")));
+        }
+        progress.emit(IndexBuildPhase::Flushing, 0, 1);
+        self.storage.config.write().hnsw_params = Some(params);
+        self.save_config()?;
+        index.save(&self.storage.path)?;
+        progress.emit(IndexBuildPhase::Flushing, 1, 1);
+        self.generations
+            .inserts_since_last_hnsw_save
+            .store(0, std::sync::atomic::Ordering::Relaxed);
+
+        manager.complete_reindex(started.elapsed());
+        Ok(true)
+    }
+}
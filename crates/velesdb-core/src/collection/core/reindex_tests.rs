@@ -0,0 +1,142 @@
+//! Tests for the online HNSW reindex (`reindex_with_params`).
+
+#![allow(clippy::cast_precision_loss)]
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::collection::auto_reindex::{AutoReindexConfig, AutoReindexManager, ReindexEvent};
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::index::hnsw::HnswParams;
+use crate::point::Point;
+
+fn seeded(path: std::path::PathBuf, n: u64) -> Collection {
+    let coll = Collection::create(path, 4, DistanceMetric::Euclidean).expect("create");
+    let points = (0..n)
+        .map(|i| {
+            let f = i as f32;
+            Point::without_payload(i, vec![f, f * 0.5, 1.0, -f])
+        })
+        .collect::<Vec<_>>();
+    coll.upsert(points).expect("seed");
+    coll
+}
+
+/// A manager whose latency gate never trips — timings are too noisy on
+/// small test graphs to be meaningful.
+fn lenient_manager() -> AutoReindexManager {
+    AutoReindexManager::new(AutoReindexConfig {
+        max_latency_regression_percent: f64::MAX,
+        ..AutoReindexConfig::default()
+    })
+}
+
+fn record_events(manager: &AutoReindexManager) -> Arc<Mutex<Vec<&'static str>>> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&events);
+    manager.on_event(move |event| {
+        let name = match event {
+            ReindexEvent::Started { .. } => "Started",
+            ReindexEvent::Progress { .. } => "Progress",
+            ReindexEvent::Validating { .. } => "Validating",
+            ReindexEvent::Completed { .. } => "Completed",
+            ReindexEvent::RolledBack { .. } => "RolledBack",
+        };
+        let mut events = sink.lock();
+        if events.last() != Some(&name) {
+            events.push(name);
+        }
+    });
+    events
+}
+
+fn params(m: usize) -> HnswParams {
+    HnswParams {
+        max_connections: m,
+        ef_construction: 100,
+        ..HnswParams::auto(4)
+    }
+}
+
+#[test]
+fn test_reindex_installs_and_persists_new_params() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("c");
+    let coll = seeded(path.clone(), 300);
+    let manager = lenient_manager();
+    let events = record_events(&manager);
+
+    assert!(coll.reindex_with_params(params(8), &manager).unwrap());
+
+    assert_eq!(
+        *events.lock(),
+        vec!["Started", "Progress", "Validating", "Completed"]
+    );
+    assert_eq!(coll.config().hnsw_params.unwrap().max_connections, 8);
+    let hits = coll.search(&[150.0, 75.0, 1.0, -150.0], 1).unwrap();
+    assert_eq!(hits[0].point.id, 150);
+
+    drop(coll);
+    let reopened = Collection::open(path).unwrap();
+    assert_eq!(reopened.config().hnsw_params.unwrap().max_connections, 8);
+    assert_eq!(reopened.len(), 300);
+    let hits = reopened.search(&[42.0, 21.0, 1.0, -42.0], 1).unwrap();
+    assert_eq!(hits[0].point.id, 42);
+}
+
+#[test]
+fn test_reindex_rolls_back_when_validation_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = seeded(dir.path().join("c"), 200);
+    // Any recall change exceeds a negative tolerance.
+    let manager = AutoReindexManager::new(AutoReindexConfig {
+        max_latency_regression_percent: f64::MAX,
+        max_recall_regression_percent: -1.0,
+        ..AutoReindexConfig::default()
+    });
+    let events = record_events(&manager);
+
+    assert!(!coll.reindex_with_params(params(8), &manager).unwrap());
+
+    assert_eq!(events.lock().last(), Some(&"RolledBack"));
+    assert!(coll.config().hnsw_params.is_none());
+    let hits = coll.search(&[10.0, 5.0, 1.0, -10.0], 1).unwrap();
+    assert_eq!(hits[0].point.id, 10);
+}
+
+#[test]
+fn test_reindex_rejected_while_another_is_running() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = seeded(dir.path().join("c"), 10);
+    let manager = lenient_manager();
+    assert!(manager.trigger_manual_reindex());
+
+    let err = coll.reindex_with_params(params(8), &manager).unwrap_err();
+    assert!(err.to_string().contains("already in progress"), "{err}");
+}
+
+#[test]
+fn test_install_carries_over_writes_made_during_build() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = seeded(dir.path().join("c"), 200);
+    let index = &coll.storage.index;
+
+    let candidate = index
+        .build_rebuild_candidate(&params(8), &mut |_, _| {})
+        .unwrap();
+    coll.upsert(vec![Point::without_payload(
+        5_000,
+        vec![50.0, 25.0, 5.0, -50.0],
+    )])
+    .unwrap();
+    coll.delete(&[7]).unwrap();
+    let indexed = index.install_rebuild_candidate(candidate).unwrap();
+
+    assert_eq!(indexed, 200);
+    let hits = coll.search(&[50.0, 25.0, 5.0, -50.0], 1).unwrap();
+    assert_eq!(hits[0].point.id, 5_000);
+    let hits = coll.search(&[7.0, 3.5, 1.0, -7.0], 3).unwrap();
+    assert!(hits.iter().all(|h| h.point.id != 7));
+}
@@ -0,0 +1,52 @@
+//! Memory residency (`storage.residency`) and growth (`storage.growth`) of
+//! the collection's mmapped vectors.
+
+use crate::collection::types::Collection;
+use crate::config::{MmapGrowth, MmapResidency};
+use crate::error::Result;
+
+impl Collection {
+    /// Applies `madvise`/`mlock` settings to the vector storage mapping.
+    ///
+    /// Called by the `Database` registration paths with the live
+    /// [`StorageConfig::residency_for`](crate::config::StorageConfig::residency_for)
+    /// value. **Not persisted** — each open re-pushes it. The settings
+    /// survive remaps caused by growth and compaction.
+    pub(crate) fn set_mmap_residency(&self, residency: MmapResidency) {
+        // LOCK ORDER: vector_storage(2, write) — held alone.
+        self.storage.vector_storage.write().set_residency(residency);
+    }
+
+    /// Residency settings applied to the vector storage mapping.
+    pub(crate) fn mmap_residency(&self) -> MmapResidency {
+        self.storage.vector_storage.read().residency()
+    }
+
+    /// Whether the vector storage mapping is currently pinned in RAM.
+    ///
+    /// `false` when locking was not requested or the OS refused it.
+    pub(crate) fn is_memory_locked(&self) -> bool {
+        self.storage.vector_storage.read().is_memory_locked()
+    }
+
+    /// Applies a growth policy to the vector data file, resizing it to the
+    /// policy's preallocation.
+    ///
+    /// Pushed by the `Database` registration paths with
+    /// [`StorageConfig::growth_for`](crate::config::StorageConfig::growth_for).
+    /// **Not persisted** — each open re-pushes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy is invalid or the resize fails.
+    pub(crate) fn set_mmap_growth(&self, growth: MmapGrowth) -> Result<()> {
+        // LOCK ORDER: vector_storage(2, write) — held alone.
+        self.storage.vector_storage.write().set_growth(growth)?;
+        Ok(())
+    }
+
+    /// Growth policy applied to the vector data file.
+    pub(crate) fn mmap_growth(&self) -> MmapGrowth {
+        self.storage.vector_storage.read().growth()
+    }
+}
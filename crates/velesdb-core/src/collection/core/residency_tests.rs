@@ -0,0 +1,136 @@
+//! Tests for vector mapping residency and growth (`Collection::set_mmap_residency`,
+//! `Collection::set_mmap_growth`).
+
+use crate::collection::Collection;
+use crate::config::{MmapAdvice, MmapGrowth, MmapResidency};
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+
+fn collection_with_points(dir: &tempfile::TempDir, n: u64) -> Collection {
+    let coll = Collection::create(dir.path().join("c"), 4, DistanceMetric::Euclidean).unwrap();
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<Point> = (0..n)
+        .map(|i| Point::without_payload(i, vec![i as f32, 1.0, 0.0, -1.0]))
+        .collect();
+    coll.upsert(points).unwrap();
+    coll
+}
+
+#[test]
+fn test_default_residency_leaves_mapping_untouched() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = collection_with_points(&dir, 3);
+
+    assert!(coll.mmap_residency().is_default());
+    assert!(!coll.is_memory_locked());
+}
+
+#[test]
+fn test_residency_survives_growth_and_compaction() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = collection_with_points(&dir, 50);
+    let residency = MmapResidency {
+        advice: MmapAdvice::Random,
+        lock: true,
+    };
+
+    coll.set_mmap_residency(residency);
+    // mlock may be refused (RLIMIT_MEMLOCK); whatever the OS decided must
+    // hold again after each remap.
+    let locked = coll.is_memory_locked();
+
+    coll.storage
+        .vector_storage
+        .write()
+        .reserve_capacity(2_000_000)
+        .unwrap();
+    assert_eq!(coll.mmap_residency(), residency);
+    assert_eq!(coll.is_memory_locked(), locked);
+
+    coll.delete(&(0..25).collect::<Vec<_>>()).unwrap();
+    assert!(coll.compact_vector_storage().unwrap() > 0);
+    assert_eq!(coll.mmap_residency(), residency);
+    assert_eq!(coll.is_memory_locked(), locked);
+
+    let hits = coll.search(&[40.0, 1.0, 0.0, -1.0], 1).unwrap();
+    assert_eq!(hits[0].point.id, 40);
+}
+
+#[test]
+fn test_clearing_lock_unpins_mapping() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = collection_with_points(&dir, 5);
+
+    coll.set_mmap_residency(MmapResidency {
+        advice: MmapAdvice::WillNeed,
+        lock: true,
+    });
+    coll.set_mmap_residency(MmapResidency::default());
+
+    assert!(!coll.is_memory_locked());
+    assert_eq!(coll.get(&[4])[0].as_ref().unwrap().id, 4);
+}
+
+fn data_file_len(dir: &tempfile::TempDir) -> u64 {
+    std::fs::metadata(dir.path().join("c").join("vectors.dat"))
+        .unwrap()
+        .len()
+}
+
+#[test]
+fn test_growth_preallocates_and_steps_by_policy() {
+    const MIB: u64 = 1024 * 1024;
+    let dir = tempfile::tempdir().unwrap();
+    let coll = Collection::create(dir.path().join("c"), 4, DistanceMetric::Euclidean).unwrap();
+    assert_eq!(data_file_len(&dir), 16 * MIB);
+
+    let growth = MmapGrowth {
+        preallocate_mb: 1,
+        growth_factor: 1.5,
+        min_growth_mb: 1,
+        max_growth_mb: 4,
+        sparse: false,
+    };
+    // An empty collection shrinks to the preallocation.
+    coll.set_mmap_growth(growth).unwrap();
+    assert_eq!(coll.mmap_growth(), growth);
+    assert_eq!(data_file_len(&dir), MIB);
+
+    // A write far past the growth step still gets room for it, plus the
+    // minimum step.
+    let mut storage = coll.storage.vector_storage.write();
+    storage
+        .ensure_capacity(usize::try_from(10 * MIB).unwrap())
+        .unwrap();
+    // 11 MiB * 1.5 would add 5.5 MiB; `max_growth_mb` caps the step at 4.
+    storage
+        .ensure_capacity(usize::try_from(11 * MIB).unwrap() + 1)
+        .unwrap();
+    drop(storage);
+    assert_eq!(data_file_len(&dir), 15 * MIB);
+
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<Point> = (0..10)
+        .map(|i| Point::without_payload(i, vec![i as f32, 1.0, 0.0, -1.0]))
+        .collect();
+    coll.upsert(points).unwrap();
+    // A file holding vectors is never shrunk by a smaller preallocation.
+    coll.set_mmap_growth(growth).unwrap();
+    assert_eq!(data_file_len(&dir), 15 * MIB);
+    assert_eq!(
+        coll.search(&[7.0, 1.0, 0.0, -1.0], 1).unwrap()[0].point.id,
+        7
+    );
+}
+
+#[test]
+fn test_invalid_growth_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = collection_with_points(&dir, 3);
+    let invalid = MmapGrowth {
+        growth_factor: 0.5,
+        ..MmapGrowth::default()
+    };
+    assert!(coll.set_mmap_growth(invalid).is_err());
+    assert!(coll.mmap_growth().is_default());
+}
@@ -0,0 +1,96 @@
+//! Uniform random sampling of points.
+//!
+//! Provides `Collection::sample`, the backing for VelesQL
+//! `ORDER BY random() LIMIT n`.
+
+use crate::collection::expiry::now_unix_secs;
+use crate::collection::types::Collection;
+use crate::filter::Filter;
+use crate::point::Point;
+use crate::storage::{PayloadStorage, VectorStorage};
+use rand::{RngExt, SeedableRng};
+
+impl Collection {
+    /// Returns up to `n` points drawn uniformly at random, without
+    /// replacement, from the live points matching `filter`.
+    ///
+    /// Candidates come from secondary indexes when the filter resolves
+    /// through them, otherwise from every point id. They are visited in a
+    /// lazily shuffled order (a partial Fisher–Yates pass) and checked one
+    /// by one, so the walk stops as soon as `n` matches are found instead of
+    /// reading the whole collection. The same `seed` over the same data
+    /// yields the same sample; `None` draws a fresh seed.
+    ///
+    /// Points are returned in sample order. Fewer than `n` are returned when
+    /// fewer match; TTL-expired points are never sampled.
+    #[must_use]
+    pub fn sample(&self, n: usize, filter: Option<&Filter>, seed: Option<u64>) -> Vec<Point> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let is_metadata_only = self.storage.config.read().metadata_only;
+        let bitmap = filter.and_then(|f| self.build_prefilter_bitmap(f));
+
+        // LOCK ORDER: vector_storage(2) before payload_storage(3).
+        let vector_storage = self.storage.vector_storage.read();
+        let payload_storage = self.storage.payload_storage.read();
+
+        let mut ids = Self::filter_candidate_ids(
+            bitmap,
+            is_metadata_only,
+            &*vector_storage,
+            &*payload_storage,
+        );
+        // Storage id order is not stable across reopen; sort so a seed always
+        // walks the same permutation.
+        ids.sort_unstable();
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed.unwrap_or_else(rand::random));
+        let now_secs = now_unix_secs();
+        let mut sample = Vec::with_capacity(n.min(ids.len()));
+        for i in 0..ids.len() {
+            let j = rng.random_range(i..ids.len());
+            ids.swap(i, j);
+            if let Some(point) = Self::sampled_point(
+                ids[i],
+                filter,
+                now_secs,
+                is_metadata_only,
+                &*vector_storage,
+                &*payload_storage,
+            ) {
+                sample.push(point);
+                if sample.len() == n {
+                    break;
+                }
+            }
+        }
+        sample
+    }
+
+    /// Hydrates candidate `id` if it is a live match for `filter`.
+    fn sampled_point(
+        id: u64,
+        filter: Option<&Filter>,
+        now_secs: u64,
+        is_metadata_only: bool,
+        vector_storage: &dyn VectorStorage,
+        payload_storage: &dyn PayloadStorage,
+    ) -> Option<Point> {
+        let payload = payload_storage.retrieve(id).ok().flatten();
+        if !Self::is_live_match(payload.as_ref(), filter, now_secs, is_metadata_only) {
+            return None;
+        }
+        let vector = if is_metadata_only {
+            Vec::new()
+        } else {
+            vector_storage.retrieve(id).ok().flatten()?
+        };
+        Some(Point {
+            id,
+            vector,
+            payload,
+            sparse_vectors: None,
+        })
+    }
+}
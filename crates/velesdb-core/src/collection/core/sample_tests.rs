@@ -0,0 +1,103 @@
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::filter::{Condition, Filter};
+use crate::point::Point;
+use serde_json::json;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// Helper: a 2-dim collection with 100 points, `even` set on even ids.
+fn seeded_collection() -> (tempfile::TempDir, Collection) {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(temp.path()), 2, DistanceMetric::Cosine)
+        .expect("collection created");
+    let points: Vec<Point> = (0..100u64)
+        .map(|i| Point::new(i, vec![1.0, 0.0], Some(json!({"even": i % 2 == 0}))))
+        .collect();
+    col.upsert(points).expect("upsert");
+    (temp, col)
+}
+
+fn even_filter() -> Filter {
+    Filter::new(Condition::Eq {
+        field: "even".to_string(),
+        value: json!(true),
+    })
+}
+
+fn ids(points: &[Point]) -> Vec<u64> {
+    points.iter().map(|p| p.id).collect()
+}
+
+#[test]
+fn test_sample_returns_distinct_hydrated_points() {
+    let (_temp, col) = seeded_collection();
+    let sample = col.sample(10, None, Some(7));
+
+    assert_eq!(sample.len(), 10);
+    let unique: HashSet<u64> = ids(&sample).into_iter().collect();
+    assert_eq!(unique.len(), 10, "sampling is without replacement");
+    assert!(sample
+        .iter()
+        .all(|p| p.vector.len() == 2 && p.payload.is_some()));
+}
+
+#[test]
+fn test_sample_is_reproducible_with_seed() {
+    let (_temp, col) = seeded_collection();
+    assert_eq!(
+        ids(&col.sample(15, None, Some(42))),
+        ids(&col.sample(15, None, Some(42)))
+    );
+    assert_ne!(
+        ids(&col.sample(15, None, Some(42))),
+        ids(&col.sample(15, None, Some(43)))
+    );
+}
+
+#[test]
+fn test_sample_honours_filter_on_scan_and_index_paths() {
+    let (_temp, col) = seeded_collection();
+    let filter = even_filter();
+    let scanned = col.sample(20, Some(&filter), Some(1));
+    assert_eq!(scanned.len(), 20);
+    assert!(scanned.iter().all(|p| p.id % 2 == 0));
+
+    col.create_index("even").expect("index");
+    let indexed = col.sample(20, Some(&filter), Some(1));
+    assert_eq!(indexed.len(), 20);
+    assert!(indexed.iter().all(|p| p.id % 2 == 0));
+}
+
+#[test]
+fn test_sample_larger_than_matches_returns_all_live_matches() {
+    let (_temp, col) = seeded_collection();
+    col.upsert(vec![Point::new(
+        1_000,
+        vec![0.0, 1.0],
+        Some(json!({"even": true, "_veles_expires_at": 1_000_u64})),
+    )])
+    .expect("upsert");
+
+    let sample = col.sample(500, Some(&even_filter()), None);
+    assert_eq!(sample.len(), 50, "the expired point must not be sampled");
+    assert!(col.sample(0, None, None).is_empty());
+}
+
+#[test]
+fn test_sample_is_roughly_uniform() {
+    let (_temp, col) = seeded_collection();
+    let mut hits = [0u32; 100];
+    for seed in 0..400 {
+        for point in col.sample(10, None, Some(seed)) {
+            hits[usize::try_from(point.id).expect("small id")] += 1;
+        }
+    }
+    // 4000 draws over 100 ids: 40 expected per id.
+    assert!(
+        hits.iter().all(|&h| (10..=80).contains(&h)),
+        "skewed sample counts: {hits:?}"
+    );
+}
@@ -0,0 +1,108 @@
+//! Named scoring profiles (`WITH (profile = '...')`) stored in `config.json`.
+
+use std::collections::BTreeMap;
+
+use crate::collection::scoring_profile::{ScoringProfile, PROFILE_OPTION};
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+
+impl Collection {
+    /// Returns the collection's scoring profiles, by name.
+    #[must_use]
+    pub fn scoring_profiles(&self) -> BTreeMap<String, ScoringProfile> {
+        self.storage.config.read().scoring_profiles.clone()
+    }
+
+    /// Returns the scoring profile stored under `name`, if any.
+    #[must_use]
+    pub fn scoring_profile(&self, name: &str) -> Option<ScoringProfile> {
+        self.storage
+            .config
+            .read()
+            .scoring_profiles
+            .get(name)
+            .cloned()
+    }
+
+    /// Stores `profile` under `name`, replacing any profile of that name, and
+    /// persists it to `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] for an invalid name or profile
+    ///   (see [`ScoringProfile`]).
+    /// - I/O errors from persisting the config (the in-memory value is
+    ///   rolled back so memory and disk never disagree).
+    pub fn set_scoring_profile(&self, name: &str, profile: ScoringProfile) -> Result<()> {
+        profile.validate(name)?;
+        let previous = self
+            .storage
+            .config
+            .write()
+            .scoring_profiles
+            .insert(name.to_string(), profile);
+        self.persist_scoring_profile(name, previous)
+    }
+
+    /// Removes the scoring profile stored under `name` and persists the
+    /// change. Returns `false` when no such profile exists.
+    ///
+    /// # Errors
+    ///
+    /// I/O errors from persisting the config (the profile is restored).
+    pub fn remove_scoring_profile(&self, name: &str) -> Result<bool> {
+        let Some(previous) = self.storage.config.write().scoring_profiles.remove(name) else {
+            return Ok(false);
+        };
+        self.persist_scoring_profile(name, Some(previous))?;
+        Ok(true)
+    }
+
+    /// Saves the config after `name` changed, restoring `previous` on error.
+    fn persist_scoring_profile(&self, name: &str, previous: Option<ScoringProfile>) -> Result<()> {
+        if let Err(e) = self.save_config() {
+            let mut config = self.storage.config.write();
+            match previous {
+                Some(profile) => config.scoring_profiles.insert(name.to_string(), profile),
+                None => config.scoring_profiles.remove(name),
+            };
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of `query` with the options of the scoring profile its
+    /// SELECT names filled in, or `None` when it names no profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when the profile name isn't a string or no
+    /// profile of that name exists.
+    pub(crate) fn apply_scoring_profile(
+        &self,
+        query: &crate::velesql::Query,
+    ) -> Result<Option<crate::velesql::Query>> {
+        let Some(value) = query
+            .select
+            .with_clause
+            .as_ref()
+            .and_then(|w| w.get(PROFILE_OPTION))
+        else {
+            return Ok(None);
+        };
+        let name = value.as_str().ok_or_else(|| {
+            Error::Query(format!(
+                "WITH (profile = ...) expects a profile name, got {value:?}"
+            ))
+        })?;
+        let profile = self.scoring_profile(name).ok_or_else(|| {
+            Error::Query(format!(
+                "Unknown scoring profile '{name}' on collection '{}'",
+                self.storage.config.read().name
+            ))
+        })?;
+        let mut query = query.clone();
+        query.select = profile.apply_to(&query.select);
+        Ok(Some(query))
+    }
+}
@@ -0,0 +1,194 @@
+#![cfg(all(test, feature = "persistence"))]
+#![allow(clippy::cast_precision_loss)]
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::collection::types::Collection;
+use crate::collection::{QueryDefaults, ScoringProfile};
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+use crate::velesql::{FusionClause, FusionStrategyType, Parser, Query, WithValue};
+
+const DAY: i64 = 86_400;
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock")
+        .as_secs()
+        .try_into()
+        .expect("epoch fits i64")
+}
+
+/// Ten old, unviewed points matching the query, then one fresh and popular
+/// point slightly off the query direction.
+fn temp_collection() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 2, DistanceMetric::Cosine)
+        .expect("collection created");
+    let old = now() - 70 * DAY;
+    let mut points: Vec<Point> = (0..10u64)
+        .map(|i| {
+            let y = 0.01 * (i + 1) as f32;
+            Point::new(
+                i,
+                vec![1.0, y],
+                Some(json!({ "published_at": old, "views": 0 })),
+            )
+        })
+        .collect();
+    points.push(Point::new(
+        10,
+        vec![1.0, 0.3],
+        Some(json!({ "published_at": now(), "views": 10_000 })),
+    ));
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn news_ranking() -> ScoringProfile {
+    ScoringProfile {
+        with: BTreeMap::from([
+            ("decay_field".to_string(), json!("published_at")),
+            ("half_life".to_string(), json!("7d")),
+            ("rerank".to_string(), json!(true)),
+        ]),
+        fusion: Some(FusionClause {
+            strategy: FusionStrategyType::FieldBoost,
+            boost_field: Some("views".to_string()),
+            boost_alpha: Some(0.2),
+            ..FusionClause::default()
+        }),
+    }
+}
+
+fn parse(sql: &str) -> Query {
+    Parser::parse(sql).expect("valid query")
+}
+
+#[test]
+fn test_scoring_profiles_persist_across_reopen() {
+    let (dir, col) = temp_collection();
+    assert!(col.scoring_profiles().is_empty());
+
+    col.set_scoring_profile("news_ranking", news_ranking())
+        .expect("set profile");
+    drop(col);
+
+    let raw = std::fs::read_to_string(dir.path().join("config.json")).expect("config.json");
+    let json: serde_json::Value = serde_json::from_str(&raw).expect("json");
+    let stored = &json["scoring_profiles"]["news_ranking"];
+    assert_eq!(stored["with"]["half_life"], json!("7d"));
+    assert_eq!(stored["fusion"]["boost_field"], json!("views"));
+
+    let reopened = Collection::open(PathBuf::from(dir.path())).expect("reopen");
+    assert_eq!(
+        reopened.scoring_profile("news_ranking"),
+        Some(news_ranking())
+    );
+
+    assert!(reopened
+        .remove_scoring_profile("news_ranking")
+        .expect("remove"));
+    assert!(!reopened
+        .remove_scoring_profile("news_ranking")
+        .expect("already removed"));
+    assert!(reopened.config().scoring_profiles.is_empty());
+}
+
+#[test]
+fn test_invalid_scoring_profiles_rejected() {
+    let (_dir, col) = temp_collection();
+    let with = |key: &str, value: serde_json::Value| ScoringProfile {
+        with: BTreeMap::from([(key.to_string(), value)]),
+        fusion: None,
+    };
+    for (name, profile) in [
+        ("", news_ranking()),
+        ("news ranking", news_ranking()),
+        ("empty", ScoringProfile::default()),
+        ("nested", with("profile", json!("news_ranking"))),
+        ("object", with("rerank", json!({ "on": true }))),
+        ("zero_ef", with("ef_search", json!(0))),
+        ("half_decay", with("decay_field", json!("published_at"))),
+    ] {
+        let err = col.set_scoring_profile(name, profile).expect_err(name);
+        assert!(matches!(err, Error::Config(_)), "{name}: {err:?}");
+    }
+    assert!(col.scoring_profiles().is_empty());
+}
+
+#[test]
+fn test_profile_fills_options_the_query_omits() {
+    let (_dir, col) = temp_collection();
+    col.set_scoring_profile("news_ranking", news_ranking())
+        .expect("set profile");
+
+    let query = parse(
+        "SELECT * FROM c WHERE vector NEAR $v LIMIT 5 \
+         WITH (profile = 'news_ranking', rerank = false, half_life = '1d')",
+    );
+    let applied = col
+        .apply_scoring_profile(&query)
+        .expect("profile exists")
+        .expect("profile applied");
+    let with = applied.select.with_clause.expect("WITH kept");
+    assert_eq!(with.get_rerank(), Some(false), "query option wins");
+    assert_eq!(
+        with.get("half_life").and_then(WithValue::as_str),
+        Some("1d")
+    );
+    assert_eq!(
+        with.get("decay_field").and_then(WithValue::as_str),
+        Some("published_at")
+    );
+    let fusion = applied.select.fusion_clause.expect("fusion filled");
+    assert_eq!(fusion.strategy, FusionStrategyType::FieldBoost);
+
+    let plain = parse("SELECT * FROM c WHERE vector NEAR $v LIMIT 5");
+    assert!(col
+        .apply_scoring_profile(&plain)
+        .expect("no profile")
+        .is_none());
+}
+
+#[test]
+fn test_query_with_profile_reranks_results() {
+    let (_dir, col) = temp_collection();
+    col.set_scoring_profile("news_ranking", news_ranking())
+        .expect("set profile");
+    // Collection defaults still fill what neither the query nor the profile sets.
+    col.set_query_defaults(QueryDefaults {
+        ef_search: Some(64),
+        ..QueryDefaults::default()
+    })
+    .expect("set defaults");
+
+    let params = HashMap::from([("v".to_string(), json!([1.0, 0.0]))]);
+    let plain = col
+        .execute_query_str("SELECT * FROM c WHERE vector NEAR $v LIMIT 3", &params)
+        .expect("plain");
+    assert!(plain.iter().all(|r| r.point.id != 10));
+
+    let ranked = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v LIMIT 3 WITH (profile = 'news_ranking')",
+            &params,
+        )
+        .expect("profiled");
+    assert_eq!(ranked.len(), 3);
+    assert_eq!(ranked[0].point.id, 10);
+
+    let err = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v LIMIT 3 WITH (profile = 'missing')",
+            &params,
+        )
+        .expect_err("unknown profile");
+    assert!(matches!(err, Error::Query(_)), "got {err:?}");
+    assert!(err.to_string().contains("missing"), "{err}");
+}
@@ -35,7 +35,9 @@ impl Collection {
     ///
     /// # Errors
     ///
-    /// Returns `Error::Config` if `batch_size` is 0.
+    /// Returns `Error::Config` if `batch_size` is 0, and
+    /// `Error::QueryLimitExceeded` if it exceeds
+    /// [`max_query_limit`](Self::max_query_limit).
     pub fn scroll_batch(
         &self,
         cursor: Option<u64>,
@@ -47,6 +49,8 @@ impl Collection {
                 "batch_size must be greater than 0".to_string(),
             ));
         }
+        self.check_query_limit(u64::try_from(batch_size).unwrap_or(u64::MAX))?;
+        let _permit = self.admit(crate::collection::AdmissionKind::Scan)?;
 
         // all_point_ids() returns IDs pre-sorted via BTreeSet (see crud_read_delete.rs).
         // Binary search via partition_point is O(log N) per batch.
@@ -4,7 +4,9 @@
 //! to support cost-based query planning.
 
 use crate::collection::query_cost::cost_model::{calibrate_cost_factors, OperationCostFactors};
-use crate::collection::stats::{CollectionStats, IndexStats, StatsCollector};
+use crate::collection::stats::{
+    CollectionStats, IndexStats, StatsCollector, VectorStats, MAX_VECTOR_STATS_SAMPLE,
+};
 use crate::collection::Collection;
 use crate::error::Error;
 use crate::storage::PayloadStorage;
@@ -97,6 +99,26 @@ impl Collection {
         Ok(stats)
     }
 
+    /// Computes vector-space statistics on a uniform sample of up to
+    /// `sample_size` live vectors (capped at [`MAX_VECTOR_STATS_SAMPLE`]).
+    ///
+    /// Reports the centroid, norm distribution, mean pairwise cosine
+    /// similarity and an intrinsic-dimensionality estimate — see
+    /// [`VectorStats`] for how to read them when picking a metric,
+    /// quantization or HNSW parameters. Nothing is cached or persisted;
+    /// metadata-only collections yield an empty report.
+    #[must_use]
+    pub fn vector_stats(&self, sample_size: usize) -> VectorStats {
+        let dimension = self.storage.config.read().dimension;
+        let vectors: Vec<Vec<f32>> = self
+            .sample(sample_size.min(MAX_VECTOR_STATS_SAMPLE), None, None)
+            .into_iter()
+            .map(|point| point.vector)
+            .filter(|vector| !vector.is_empty())
+            .collect();
+        VectorStats::compute(&vectors, dimension)
+    }
+
     /// Samples up to 1000 payloads to compute size, distinct values, and null counts.
     fn sample_payload_stats(
         &self,
@@ -318,6 +340,35 @@ mod tests {
         assert!(stats.index_stats.get("hnsw_primary").unwrap().entry_count >= 10);
     }
 
+    #[test]
+    fn test_vector_stats_on_sample() {
+        use crate::point::Point;
+
+        let temp_dir = TempDir::new().unwrap();
+        let collection =
+            Collection::create(temp_dir.path().to_path_buf(), 3, DistanceMetric::Cosine).unwrap();
+        let points: Vec<Point> = (0..30u64)
+            .map(|i| {
+                let axis = usize::try_from(i % 3).unwrap();
+                let mut v = vec![0.0; 3];
+                v[axis] = 2.0;
+                Point::new(i, v, None)
+            })
+            .collect();
+        collection.upsert(points).unwrap();
+
+        let stats = collection.vector_stats(10_000);
+        assert_eq!(stats.sample_size, 30);
+        assert_eq!(stats.dimension, 3);
+        assert!(stats.centroid.iter().all(|c| (c - 2.0 / 3.0).abs() < 1e-5));
+        assert!((stats.norm_p50 - 2.0).abs() < 1e-5);
+        // Pairs on the same axis score 1, the rest 0: 135 of 435 pairs.
+        let cosine = stats.mean_pairwise_cosine.unwrap();
+        assert!((cosine - 135.0 / 435.0).abs() < 1e-4, "got {cosine}");
+
+        assert_eq!(collection.vector_stats(5).sample_size, 5);
+    }
+
     #[test]
     fn test_get_stats_returns_defaults_on_error() {
         let temp_dir = TempDir::new().unwrap();
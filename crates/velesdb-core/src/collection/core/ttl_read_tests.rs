@@ -85,6 +85,25 @@ fn test_get_filters_expired_but_get_raw_returns_it() {
     );
 }
 
+#[test]
+fn test_get_projected_without_vector_still_filters_expired() {
+    let (_dir, col) = setup_ttl_collection();
+
+    for projection in [
+        crate::point::PointProjection::new(false, true),
+        crate::point::PointProjection::ID_ONLY,
+    ] {
+        let got = col.get_projected(&[1, 2, 3, 99], projection);
+        assert!(got[0].is_some(), "live point visible ({projection:?})");
+        assert!(got[1].is_none(), "expired point hidden ({projection:?})");
+        assert!(
+            got[2].is_some(),
+            "future-TTL point visible ({projection:?})"
+        );
+        assert!(got[3].is_none(), "unknown id is None ({projection:?})");
+    }
+}
+
 #[test]
 fn test_get_boundary_expiry_equal_to_now_is_expired() {
     let (_dir, col) = setup_collection(4);
@@ -0,0 +1,30 @@
+//! Layout of the collection's exact scans (`VectorLayout`).
+
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::vector_layout::VectorLayout;
+
+impl Collection {
+    /// Sets the layout exact scans (`SearchQuality::Perfect`, small
+    /// collections) score vectors in and persists it to `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// I/O errors from persisting the config (the previous layout is
+    /// restored so memory and disk never disagree).
+    pub(crate) fn set_vector_layout(&self, layout: VectorLayout) -> Result<()> {
+        let previous = std::mem::replace(&mut self.storage.config.write().vector_layout, layout);
+        if let Err(e) = self.save_config() {
+            self.storage.config.write().vector_layout = previous;
+            return Err(e);
+        }
+        self.storage.index.set_vector_layout(layout);
+        Ok(())
+    }
+
+    /// Layout exact scans score vectors in.
+    #[must_use]
+    pub(crate) fn vector_layout(&self) -> VectorLayout {
+        self.storage.config.read().vector_layout
+    }
+}
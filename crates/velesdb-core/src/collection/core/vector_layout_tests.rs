@@ -0,0 +1,76 @@
+//! Tests for the exact-scan layout (`Collection::set_vector_layout`) and
+//! high-dimensional collections.
+
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+use crate::vector_layout::VectorLayout;
+use crate::SearchQuality;
+
+const DIM: usize = 8192;
+
+fn vector(seed: u64) -> Vec<f32> {
+    #[allow(clippy::cast_precision_loss)]
+    (0..DIM)
+        .map(|i| ((i as u64 * 7 + seed * 131) % 61) as f32 / 61.0 - 0.5)
+        .collect()
+}
+
+fn ids(results: &[crate::point::SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+#[test]
+fn test_8192_dim_collection_scans_and_reopens_in_both_layouts() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("c");
+    let query = vector(7);
+    let aos = {
+        let coll = Collection::create(path.clone(), DIM, DistanceMetric::Cosine).unwrap();
+        coll.upsert(
+            (0..40)
+                .map(|id| Point::without_payload(id, vector(id)))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        let aos = coll
+            .search_with_quality(&query, 5, SearchQuality::Perfect)
+            .unwrap();
+        assert_eq!(aos[0].point.id, 7);
+
+        coll.set_vector_layout(VectorLayout::BlockedSoa).unwrap();
+        let soa = coll
+            .search_with_quality(&query, 5, SearchQuality::Perfect)
+            .unwrap();
+        assert_eq!(ids(&soa), ids(&aos));
+        coll.flush().unwrap();
+        ids(&aos)
+    };
+
+    let reopened = Collection::open(path).unwrap();
+    assert_eq!(reopened.vector_layout(), VectorLayout::BlockedSoa);
+    assert_eq!(
+        reopened.storage.index.vector_layout(),
+        VectorLayout::BlockedSoa
+    );
+    let stored = reopened.get(&[3]);
+    assert_eq!(stored[0].as_ref().unwrap().vector, vector(3));
+    let soa = reopened
+        .search_with_quality(&query, 5, SearchQuality::Perfect)
+        .unwrap();
+    assert_eq!(ids(&soa), aos);
+}
+
+#[test]
+fn test_default_layout_is_not_persisted() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = Collection::create(dir.path().join("c"), 4, DistanceMetric::Euclidean).unwrap();
+    assert_eq!(coll.vector_layout(), VectorLayout::Aos);
+    let config = std::fs::read_to_string(dir.path().join("c").join("config.json")).unwrap();
+    assert!(!config.contains("vector_layout"));
+
+    coll.set_vector_layout(VectorLayout::BlockedSoa).unwrap();
+    let config = std::fs::read_to_string(dir.path().join("c").join("config.json")).unwrap();
+    assert!(config.contains("\"blocked_soa\""));
+}
@@ -0,0 +1,74 @@
+//! Consistency checks and repairs used by `Database::verify` / `Database::repair`.
+
+use std::sync::atomic::Ordering;
+
+use crate::collection::diagnostics::pending_index_ops;
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::storage::VectorStorage;
+
+use super::recovery::run_crash_recovery;
+
+impl Collection {
+    /// Returns `(stored, indexed, pending)`: vectors in storage, vectors in
+    /// the HNSW graph, and vectors still buffered for indexing.
+    pub(crate) fn index_counts(&self) -> (usize, usize, usize) {
+        let stored = self.storage.vector_storage.read().len();
+        (stored, self.storage.index.len(), pending_index_ops(self))
+    }
+
+    /// Runs the structural check of the HNSW graph.
+    pub(crate) fn verify_index_graph(&self) -> crate::index::HnswIntegrityReport {
+        self.storage.index.verify()
+    }
+
+    /// Reconciles the HNSW graph with vector storage and re-saves it.
+    ///
+    /// Runs the same gap and orphan passes as open-time recovery, without a
+    /// WAL to narrow them. Returns whether the graph changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if re-indexing a vector or saving the index fails.
+    pub(crate) fn repair_index(&self) -> Result<bool> {
+        let changed = run_crash_recovery(
+            &self.config(),
+            &self.storage.vector_storage,
+            &self.storage.index,
+            &[],
+        )?;
+        self.storage.index.save(&self.storage.path)?;
+        self.generations
+            .inserts_since_last_hnsw_save
+            .store(0, Ordering::Relaxed);
+        Ok(changed)
+    }
+
+    /// Checkpoints vector storage and truncates its WAL, dropping any records
+    /// that failed their CRC at open.
+    ///
+    /// The index is re-saved first: with the WAL gone it is the only record
+    /// of writes since its last save.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saving the index or checkpointing storage fails.
+    pub(crate) fn checkpoint_vector_wal(&self) -> Result<()> {
+        self.storage.index.save(&self.storage.path)?;
+        self.storage.vector_storage.write().checkpoint_wal()?;
+        self.generations
+            .inserts_since_last_hnsw_save
+            .store(0, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Rewrites `payloads.snapshot` from the in-memory payload index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot cannot be written.
+    pub(crate) fn rewrite_payload_snapshot(&self) -> Result<()> {
+        self.storage.payload_storage.write().create_snapshot()?;
+        Ok(())
+    }
+}
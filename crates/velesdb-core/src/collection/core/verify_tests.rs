@@ -0,0 +1,38 @@
+//! Tests for the `Database::verify` / `Database::repair` collection hooks.
+
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+
+fn seeded(path: std::path::PathBuf) -> Collection {
+    let coll = Collection::create(path, 4, DistanceMetric::Euclidean).expect("create");
+    let points: Vec<_> = (0..20u8)
+        .map(|i| {
+            let f = f32::from(i);
+            Point::without_payload(u64::from(i), vec![f, f + 1.0, f + 2.0, f + 3.0])
+        })
+        .collect();
+    coll.upsert(points).expect("seed");
+    coll
+}
+
+#[test]
+fn test_index_counts_match_after_upsert() {
+    let dir = tempfile::tempdir().unwrap();
+    let coll = seeded(dir.path().join("c"));
+    assert_eq!(coll.index_counts(), (20, 20, 0));
+}
+
+#[test]
+fn test_repair_index_reinserts_missing_vectors_and_saves() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("c");
+    let coll = seeded(path.clone());
+    assert!(coll.storage.index.remove(3));
+    assert_eq!(coll.index_counts(), (20, 19, 0));
+
+    assert!(coll.repair_index().unwrap());
+    assert_eq!(coll.index_counts(), (20, 20, 0));
+    assert!(path.join("native_meta.bin").exists());
+    assert!(!coll.repair_index().unwrap());
+}
@@ -36,6 +36,11 @@ pub struct CollectionDiagnostics {
     pub point_count: usize,
     /// Health status of the primary search index.
     pub index_health: IndexHealth,
+    /// Vectors accepted but not yet merged into the HNSW index (deferred
+    /// indexer and async index builder buffers).
+    pub pending_index_ops: usize,
+    /// Size of the vector WAL in bytes — what a restart would have to replay.
+    pub wal_backlog_bytes: u64,
 }
 
 impl CollectionDiagnostics {
@@ -58,6 +63,8 @@ impl CollectionDiagnostics {
             dimension_configured,
             point_count,
             index_health,
+            pending_index_ops: pending_index_ops(coll),
+            wal_backlog_bytes: wal_backlog_bytes(coll),
         }
     }
 
@@ -75,10 +82,32 @@ impl CollectionDiagnostics {
             } else {
                 IndexHealth::Healthy
             },
+            pending_index_ops: 0,
+            wal_backlog_bytes: wal_backlog_bytes(coll),
         }
     }
 }
 
+/// Counts vectors waiting in the background indexing buffers.
+pub(crate) fn pending_index_ops(coll: &Collection) -> usize {
+    let deferred = coll
+        .streaming
+        .deferred_indexer
+        .as_ref()
+        .map_or(0, |di| di.pending_count());
+    let async_built = coll
+        .streaming
+        .async_index_builder
+        .as_ref()
+        .map_or(0, |aib| aib.buffer_len());
+    deferred + async_built
+}
+
+/// Size of `vectors.wal`, or 0 when the collection has none.
+fn wal_backlog_bytes(coll: &Collection) -> u64 {
+    std::fs::metadata(coll.storage.path.join("vectors.wal")).map_or(0, |m| m.len())
+}
+
 impl VectorCollection {
     /// Returns diagnostic information about this collection.
     #[must_use]
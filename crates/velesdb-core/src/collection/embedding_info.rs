@@ -0,0 +1,82 @@
+//! Embedding model metadata persisted alongside a collection.
+//!
+//! A collection's dimension and metric are immutable, but nothing stops a
+//! client from switching embedding models with the same output dimension —
+//! the vectors then live in incompatible spaces and search silently returns
+//! nonsense. Recording the producing model in `config.json` lets every
+//! ingest/query boundary compare it against the model a client *declares*
+//! (REST `X-Embedding-Model` header, SDK parameter) and reject the mismatch.
+
+use serde::{Deserialize, Serialize};
+
+/// Separator between model name and version in a declared model string
+/// (`"text-embedding-3-small@2024-01"`).
+pub const EMBEDDING_VERSION_SEPARATOR: char = '@';
+
+/// Embedding model that produced a collection's vectors.
+///
+/// Stored in [`CollectionConfig::embedding_info`](crate::collection::CollectionConfig)
+/// via `VectorCollection::set_embedding_info`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingInfo {
+    /// Model name (e.g. `"text-embedding-3-small"`, `"bge-m3"`).
+    pub model: String,
+    /// Optional model version or revision. When `None`, any declared
+    /// version of `model` is accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Output dimension the model produces. Must equal the collection's
+    /// vector dimension.
+    pub dimension: usize,
+}
+
+impl EmbeddingInfo {
+    /// Creates embedding metadata without a version pin.
+    #[must_use]
+    pub fn new(model: impl Into<String>, dimension: usize) -> Self {
+        Self {
+            model: model.into(),
+            version: None,
+            dimension,
+        }
+    }
+
+    /// Pins the model version.
+    #[must_use]
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Returns `true` when a client-declared model string is compatible.
+    ///
+    /// `declared` is either a bare model name (`"bge-m3"`) or
+    /// `"<model>@<version>"`. Names compare case-insensitively after
+    /// trimming. A declared version is only checked when this metadata pins
+    /// one; a bare declared name never fails on version alone.
+    #[must_use]
+    pub fn matches_declared(&self, declared: &str) -> bool {
+        let (name, version) = match declared.split_once(EMBEDDING_VERSION_SEPARATOR) {
+            Some((n, v)) => (n.trim(), Some(v.trim())),
+            None => (declared.trim(), None),
+        };
+        if !name.eq_ignore_ascii_case(self.model.trim()) {
+            return false;
+        }
+        match (self.version.as_deref(), version) {
+            (Some(pinned), Some(declared_version)) => {
+                pinned.trim().eq_ignore_ascii_case(declared_version)
+            }
+            _ => true,
+        }
+    }
+
+    /// Renders the metadata as a declared-model string (`model[@version]`).
+    #[must_use]
+    pub fn qualified_name(&self) -> String {
+        match &self.version {
+            Some(v) => format!("{}{EMBEDDING_VERSION_SEPARATOR}{v}", self.model),
+            None => self.model.clone(),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! File import options and reports.
+//!
+//! [`Collection::import_jsonl`](crate::Collection::import_jsonl) and
+//! [`Collection::import_csv`](crate::Collection::import_csv) stream a file
+//! into a collection in batches. An [`ImportMapping`] names the id and vector
+//! columns, how vectors are encoded, which columns become payload fields and
+//! what happens to a row that cannot be imported.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::wire::f32_base64;
+
+/// Maximum number of row errors kept in an [`ImportReport`].
+pub const MAX_REPORTED_ROW_ERRORS: usize = 100;
+
+/// Text encoding of the vector column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VectorEncoding {
+    /// A JSON array (`[0.1, 0.2]`) or comma-separated floats (`0.1,0.2`).
+    #[default]
+    Json,
+    /// Base64 of the little-endian `f32` bytes (see [`crate::wire::f32_base64`]).
+    Base64F32,
+}
+
+impl VectorEncoding {
+    /// Parses one vector cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if `text` is not a vector in
+    /// this encoding.
+    pub fn parse(self, text: &str) -> Result<Vec<f32>, String> {
+        let text = text.trim();
+        match self {
+            Self::Json if text.starts_with('[') => {
+                serde_json::from_str(text).map_err(|e| format!("invalid JSON vector: {e}"))
+            }
+            Self::Json => text
+                .split(',')
+                .map(|v| {
+                    v.trim()
+                        .parse::<f32>()
+                        .map_err(|_| format!("invalid float value '{}'", v.trim()))
+                })
+                .collect(),
+            Self::Base64F32 => f32_base64::decode(text).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// What to do with a row that cannot be imported (unparsable line, missing
+/// or invalid id, bad vector, wrong dimension).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OnBadRow {
+    /// Count the row in [`ImportReport::skipped`] and keep going.
+    #[default]
+    Skip,
+    /// Stop at the first bad row. Every row before it is written.
+    Abort,
+}
+
+/// Maps file columns (CSV headers or JSONL object keys) to point fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportMapping {
+    /// Column holding the `u64` point id (default `"id"`).
+    pub id_column: String,
+    /// Column holding the vector (default `"vector"`). Ignored by
+    /// metadata-only collections.
+    pub vector_column: String,
+    /// Encoding of string vector cells. JSONL arrays are always accepted.
+    pub vector_encoding: VectorEncoding,
+    /// Columns copied into the payload; `None` copies every other column.
+    /// A JSONL `payload` object is merged into the payload, not nested.
+    pub payload_columns: Option<Vec<String>>,
+    /// Handling of rows that cannot be imported.
+    pub on_bad_row: OnBadRow,
+    /// Points written per `upsert_bulk` call.
+    pub batch_size: usize,
+}
+
+impl Default for ImportMapping {
+    fn default() -> Self {
+        Self {
+            id_column: "id".to_string(),
+            vector_column: "vector".to_string(),
+            vector_encoding: VectorEncoding::Json,
+            payload_columns: None,
+            on_bad_row: OnBadRow::Skip,
+            batch_size: 1000,
+        }
+    }
+}
+
+impl ImportMapping {
+    /// Returns `true` if `column` becomes a payload field.
+    pub(crate) fn is_payload_column(&self, column: &str) -> bool {
+        column != self.id_column
+            && column != self.vector_column
+            && self
+                .payload_columns
+                .as_ref()
+                .is_none_or(|columns| columns.iter().any(|c| c == column))
+    }
+
+    /// Adds `value` under `column` to `payload`, merging a `payload` object.
+    pub(crate) fn insert_payload_field(
+        payload: &mut Map<String, Value>,
+        column: &str,
+        value: Value,
+    ) {
+        match value {
+            Value::Object(fields) if column == "payload" => payload.extend(fields),
+            value => {
+                payload.insert(column.to_string(), value);
+            }
+        }
+    }
+}
+
+/// A row that was skipped or aborted the import.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportRowError {
+    /// 1-based line number in the file.
+    pub line: u64,
+    /// What was wrong with the row.
+    pub message: String,
+}
+
+/// Outcome of a file import.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ImportReport {
+    /// Non-empty rows read.
+    pub rows: usize,
+    /// Points written.
+    pub imported: usize,
+    /// Rows skipped under [`OnBadRow::Skip`].
+    pub skipped: usize,
+    /// The first [`MAX_REPORTED_ROW_ERRORS`] skipped rows.
+    pub errors: Vec<ImportRowError>,
+}
+
+/// Progress of a file import, reported after each batch is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ImportProgress {
+    /// Non-empty rows read so far.
+    pub rows: usize,
+    /// Points written so far.
+    pub imported: usize,
+    /// Rows skipped so far.
+    pub skipped: usize,
+    /// Bytes of the file consumed so far.
+    pub bytes_read: u64,
+    /// Size of the file in bytes.
+    pub total_bytes: u64,
+}
@@ -355,6 +355,8 @@ mod property_tests {
                 limit,
                 rel_types: Vec::new(),
                 deadline: None,
+                valid_at: None,
+                max_cost: None,
             }
         })
     }
@@ -0,0 +1,142 @@
+//! Graph shape statistics: label counts, degree distribution and hubs.
+//!
+//! Built by [`Collection::graph_stats`](crate::collection::types::Collection::graph_stats)
+//! for capacity planning. The degree histogram uses power-of-two buckets on
+//! the total degree (out + in): `0`, `1`, `2..=3`, `4..=7`, ...
+
+// Reason: the mean degree is an approximate statistic; precision loss on
+// usize -> f64 only matters beyond 2^53 edges.
+#![allow(clippy::cast_precision_loss)]
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Total degree at or above which a node counts as a hub.
+pub const HUB_DEGREE_THRESHOLD: usize = 1_000;
+
+/// Number of highest-degree nodes reported in [`GraphStats::top_hubs`].
+pub const TOP_HUBS: usize = 10;
+
+/// One bucket of the degree histogram (bounds inclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DegreeBucket {
+    /// Smallest total degree in the bucket.
+    pub min_degree: usize,
+    /// Largest total degree in the bucket.
+    pub max_degree: usize,
+    /// Number of nodes whose total degree falls in the bucket.
+    pub nodes: usize,
+}
+
+/// A high-degree node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HubNode {
+    /// Node id.
+    pub id: u64,
+    /// Number of outgoing edges.
+    pub out_degree: usize,
+    /// Number of incoming edges.
+    pub in_degree: usize,
+}
+
+impl HubNode {
+    /// Total degree (out + in).
+    #[must_use]
+    pub fn degree(&self) -> usize {
+        self.out_degree + self.in_degree
+    }
+}
+
+/// Node/edge counts and degree distribution of a graph.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GraphStats {
+    /// Nodes: points with a payload plus edge endpoints without one.
+    pub node_count: usize,
+    /// Edges.
+    pub edge_count: usize,
+    /// Nodes per `_labels` entry (a node with two labels counts twice).
+    pub nodes_by_label: BTreeMap<String, u64>,
+    /// Edges per label.
+    pub edges_by_label: BTreeMap<String, usize>,
+    /// Non-empty power-of-two buckets of the total degree, ascending.
+    pub degree_histogram: Vec<DegreeBucket>,
+    /// Largest total degree.
+    pub max_degree: usize,
+    /// Mean total degree over all nodes.
+    pub mean_degree: f64,
+    /// Nodes whose total degree is at least [`HUB_DEGREE_THRESHOLD`].
+    pub hub_count: usize,
+    /// The [`TOP_HUBS`] highest-degree nodes, highest first.
+    pub top_hubs: Vec<HubNode>,
+}
+
+impl GraphStats {
+    /// Fills the degree fields from per-node `(id, out, in)` degrees plus
+    /// `isolated` nodes of degree zero.
+    pub(crate) fn with_degrees(
+        mut self,
+        degrees: impl IntoIterator<Item = (u64, usize, usize)>,
+        isolated: usize,
+    ) -> Self {
+        // Bucket 0 holds degree 0; bucket k >= 1 holds [2^(k-1), 2^k - 1].
+        let mut buckets = vec![isolated];
+        let mut nodes = isolated;
+        let mut degree_sum = 0usize;
+        let mut hubs = Vec::new();
+        for (id, out_degree, in_degree) in degrees {
+            let hub = HubNode {
+                id,
+                out_degree,
+                in_degree,
+            };
+            let degree = hub.degree();
+            let bucket = (usize::BITS - degree.leading_zeros()) as usize;
+            if buckets.len() <= bucket {
+                buckets.resize(bucket + 1, 0);
+            }
+            buckets[bucket] += 1;
+            nodes += 1;
+            degree_sum += degree;
+            self.max_degree = self.max_degree.max(degree);
+            if degree >= HUB_DEGREE_THRESHOLD {
+                self.hub_count += 1;
+            }
+            hubs.push(hub);
+        }
+
+        self.degree_histogram = buckets
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, count)| count > 0)
+            .map(|(bucket, count)| match bucket {
+                0 => DegreeBucket {
+                    min_degree: 0,
+                    max_degree: 0,
+                    nodes: count,
+                },
+                _ => DegreeBucket {
+                    min_degree: 1 << (bucket - 1),
+                    max_degree: (1 << (bucket - 1)) * 2 - 1,
+                    nodes: count,
+                },
+            })
+            .collect();
+        self.node_count = nodes;
+        self.mean_degree = if nodes == 0 {
+            0.0
+        } else {
+            degree_sum as f64 / nodes as f64
+        };
+
+        let by_degree =
+            |a: &HubNode, b: &HubNode| b.degree().cmp(&a.degree()).then(a.id.cmp(&b.id));
+        if hubs.len() > TOP_HUBS {
+            hubs.select_nth_unstable_by(TOP_HUBS - 1, by_degree);
+            hubs.truncate(TOP_HUBS);
+        }
+        hubs.sort_unstable_by(by_degree);
+        self.top_hubs = hubs;
+        self
+    }
+}
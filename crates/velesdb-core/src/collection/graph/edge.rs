@@ -41,9 +41,21 @@ pub struct GraphEdge {
     source: u64,
     target: u64,
     label: String,
+    #[serde(with = "properties_serde")]
     properties: HashMap<String, Value>,
 }
 
+/// Reserved property holding the start of an edge's validity window.
+pub const VALID_FROM_PROPERTY: &str = "valid_from";
+/// Reserved property holding the (exclusive) end of an edge's validity window.
+pub const VALID_TO_PROPERTY: &str = "valid_to";
+/// Property read as the edge's traversal cost by cost-bounded traversals.
+pub const WEIGHT_PROPERTY: &str = "weight";
+/// Properties traversals read: kept in memory even when edge properties
+/// live on disk, so cost- and time-bounded traversals never touch the disk.
+pub(crate) const TRAVERSAL_PROPERTIES: [&str; 3] =
+    [WEIGHT_PROPERTY, VALID_FROM_PROPERTY, VALID_TO_PROPERTY];
+
 impl GraphEdge {
     /// Creates a new edge with the given ID, endpoints, and label.
     ///
@@ -108,6 +120,123 @@ impl GraphEdge {
     pub fn property(&self, name: &str) -> Option<&Value> {
         self.properties.get(name)
     }
+
+    /// Removes and returns the properties traversals never read, keeping
+    /// the [`TRAVERSAL_PROPERTIES`] on the edge.
+    pub(super) fn take_spillable_properties(&mut self) -> HashMap<String, Value> {
+        let (kept, spilled) = std::mem::take(&mut self.properties)
+            .into_iter()
+            .partition(|(key, _)| TRAVERSAL_PROPERTIES.contains(&key.as_str()));
+        self.properties = kept;
+        spilled
+    }
+
+    /// Returns `true` when every property of this edge is a traversal
+    /// property, i.e. it has nothing [`Self::take_spillable_properties`]
+    /// would move.
+    pub(super) fn has_only_traversal_properties(&self) -> bool {
+        self.properties
+            .keys()
+            .all(|key| TRAVERSAL_PROPERTIES.contains(&key.as_str()))
+    }
+
+    /// Adds `properties` to this edge, overwriting keys it already has.
+    pub(super) fn merge_properties(
+        &mut self,
+        properties: impl IntoIterator<Item = (String, Value)>,
+    ) {
+        self.properties.extend(properties);
+    }
+
+    /// Sets the validity window of this edge (builder pattern).
+    ///
+    /// Bounds are Unix timestamps; `None` leaves that side open. They are
+    /// stored as the [`VALID_FROM_PROPERTY`] and [`VALID_TO_PROPERTY`]
+    /// properties, so temporal edges need no change to the on-disk formats.
+    #[must_use]
+    pub fn with_validity(mut self, valid_from: Option<i64>, valid_to: Option<i64>) -> Self {
+        for (key, bound) in [
+            (VALID_FROM_PROPERTY, valid_from),
+            (VALID_TO_PROPERTY, valid_to),
+        ] {
+            match bound {
+                Some(t) => self.properties.insert(key.to_string(), Value::from(t)),
+                None => self.properties.remove(key),
+            };
+        }
+        self
+    }
+
+    /// Returns the start of the validity window, if bounded.
+    #[must_use]
+    pub fn valid_from(&self) -> Option<i64> {
+        self.property(VALID_FROM_PROPERTY).and_then(Value::as_i64)
+    }
+
+    /// Returns the (exclusive) end of the validity window, if bounded.
+    #[must_use]
+    pub fn valid_to(&self) -> Option<i64> {
+        self.property(VALID_TO_PROPERTY).and_then(Value::as_i64)
+    }
+
+    /// Returns the traversal cost of this edge: its [`WEIGHT_PROPERTY`]
+    /// when that is a finite, non-negative number, `1.0` otherwise (so
+    /// unweighted edges cost one hop each).
+    #[must_use]
+    pub fn weight(&self) -> f64 {
+        self.property(WEIGHT_PROPERTY)
+            .and_then(Value::as_f64)
+            .filter(|w| w.is_finite() && *w >= 0.0)
+            .unwrap_or(1.0)
+    }
+
+    /// Returns `true` if the edge is valid at time `t`, i.e. `t` falls in
+    /// `[valid_from, valid_to)`. Edges without bounds are always valid.
+    #[must_use]
+    pub fn is_valid_at(&self, t: i64) -> bool {
+        self.valid_from().is_none_or(|from| from <= t) && self.valid_to().is_none_or(|to| t < to)
+    }
+}
+
+/// Serde adapter for edge properties.
+///
+/// `serde_json::Value` needs a self-describing format, which the postcard
+/// snapshot (`edge_store.bin`) is not. Binary formats therefore carry the
+/// properties as a JSON string; an empty string is an empty map, which keeps
+/// the encoding of property-less edges byte-identical to earlier snapshots.
+/// Human-readable formats (REST, WAL) keep the plain map.
+mod properties_serde {
+    use super::{HashMap, Value};
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        properties: &HashMap<String, Value>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return properties.serialize(serializer);
+        }
+        if properties.is_empty() {
+            return serializer.serialize_str("");
+        }
+        let json = serde_json::to_string(properties).map_err(S::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<String, Value>, D::Error> {
+        if deserializer.is_human_readable() {
+            return HashMap::deserialize(deserializer);
+        }
+        let json = String::deserialize(deserializer)?;
+        if json.is_empty() {
+            return Ok(HashMap::new());
+        }
+        serde_json::from_str(&json).map_err(D::Error::custom)
+    }
 }
 
 /// Storage for graph edges with bidirectional indexing.
@@ -95,14 +95,15 @@ impl ConcurrentEdgeStore {
         }
     }
 
-    /// Removes edge IDs from the global registry, deduplicating.
+    /// Removes edge IDs from the global registry, deduplicating, and returns
+    /// the removed IDs.
     #[allow(clippy::unused_self)] // Reason: method on ConcurrentEdgeStore for API consistency
     pub(super) fn deregister_edge_ids(
         &self,
         ids: &mut FxHashMap<u64, u64>,
         outgoing: &[(u64, u64)],
         incoming: &[(u64, u64)],
-    ) {
+    ) -> FxHashSet<u64> {
         let mut removed: FxHashSet<u64> = FxHashSet::default();
         for (edge_id, _) in outgoing {
             if removed.insert(*edge_id) {
@@ -114,5 +115,6 @@ impl ConcurrentEdgeStore {
                 ids.remove(edge_id);
             }
         }
+        removed
     }
 }
@@ -3,7 +3,8 @@
 //! This module provides `ConcurrentEdgeStore`, a thread-safe wrapper around
 //! `EdgeStore` that uses sharding to reduce lock contention.
 //!
-//! Read-only queries and traversal are in `query.rs`.
+//! Read-only queries and traversal are in `query.rs`; the optional on-disk
+//! edge property log is in `properties.rs`.
 
 // Reason: Numeric casts in edge store sharding are intentional:
 // - u64->usize for node ID hashing: Node IDs are generated sequentially and fit in usize
@@ -12,6 +13,7 @@
 
 mod cascade;
 mod persistence;
+mod properties;
 mod query;
 mod snapshot;
 
@@ -21,9 +23,10 @@ use super::edge::{EdgeStore, GraphEdge};
 use super::label_table::LabelTable;
 use super::metrics::GraphMetrics;
 use crate::error::{Error, Result};
+use crate::storage::LogPayloadStorage;
 use arc_swap::ArcSwap;
 use parking_lot::RwLock;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::time::Instant;
 
@@ -108,6 +111,13 @@ pub struct ConcurrentEdgeStore {
     /// Atomic counters and histograms only; observed on the Ok tail of each
     /// mutation after all shard locks have been released.
     metrics: GraphMetrics,
+    /// On-disk log holding edge properties, keyed by edge id.
+    ///
+    /// `None` (the default) keeps properties in the shards. When attached
+    /// (see `properties.rs`) the shards hold topology only and getters
+    /// returning `GraphEdge` read the properties back from the log.
+    /// Lock ordering: after `edge_ids`, before any shard.
+    property_log: RwLock<Option<LogPayloadStorage>>,
 }
 
 impl ConcurrentEdgeStore {
@@ -148,6 +158,7 @@ impl ConcurrentEdgeStore {
             pending_writes: AtomicU64::new(0),
             label_table: RwLock::new(LabelTable::new()),
             metrics: GraphMetrics::new(),
+            property_log: RwLock::new(None),
         })
     }
 
@@ -199,7 +210,7 @@ impl ConcurrentEdgeStore {
     /// # Errors
     ///
     /// Returns `Error::EdgeExists` if an edge with the same ID already exists.
-    pub fn add_edge(&self, edge: GraphEdge) -> Result<()> {
+    pub fn add_edge(&self, mut edge: GraphEdge) -> Result<()> {
         let edge_id = edge.id();
         let start = Instant::now();
 
@@ -211,6 +222,7 @@ impl ConcurrentEdgeStore {
             if ids.contains_key(&edge_id) {
                 return Err(Error::EdgeExists(edge_id));
             }
+            self.spill_properties(std::slice::from_mut(&mut edge));
 
             let source_id = edge.source();
             let source_shard = self.shard_index(source_id);
@@ -277,12 +289,17 @@ impl ConcurrentEdgeStore {
         {
             let mut ids = self.edge_ids.write();
 
+            // Drop duplicates up front (first occurrence wins) so only edges
+            // that will be inserted reach the property log.
+            let mut batch_ids = FxHashSet::default();
+            let mut edges: Vec<GraphEdge> = edges
+                .into_iter()
+                .filter(|e| !ids.contains_key(&e.id()) && batch_ids.insert(e.id()))
+                .collect();
+            self.spill_properties(&mut edges);
+
             for edge in edges {
                 let edge_id = edge.id();
-                if ids.contains_key(&edge_id) {
-                    continue;
-                }
-
                 let source_id = edge.source();
                 let ok = self.insert_edge_into_shards(edge);
 
@@ -394,6 +411,7 @@ impl ConcurrentEdgeStore {
             }
 
             ids.remove(&edge_id);
+            self.drop_properties([edge_id]);
         } // All locks dropped here.
         self.invalidate_snapshot();
         self.rebuild_snapshot_best_effort();
@@ -430,7 +448,9 @@ impl ConcurrentEdgeStore {
                 &incoming_edges,
             );
 
-            self.deregister_edge_ids(&mut ids, &outgoing_edges, &incoming_edges);
+            drop(guards);
+            let removed = self.deregister_edge_ids(&mut ids, &outgoing_edges, &incoming_edges);
+            self.drop_properties(removed);
         }
         self.invalidate_snapshot();
         self.rebuild_snapshot_best_effort();
@@ -36,10 +36,15 @@ impl ConcurrentEdgeStore {
 
     /// Saves the concurrent edge store to a file.
     ///
+    /// With a property log attached the file holds topology only, and the
+    /// log is synced first so the properties are durable before the caller
+    /// truncates the edge WAL.
+    ///
     /// # Errors
     ///
     /// Returns an error if serialization or file I/O fails.
     pub fn save_to_file(&self, path: &std::path::Path) -> std::io::Result<()> {
+        self.sync_property_log()?;
         self.to_merged_edge_store().save_to_file(path)
     }
 
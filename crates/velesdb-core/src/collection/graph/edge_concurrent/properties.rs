@@ -0,0 +1,210 @@
+//! On-disk edge properties for `ConcurrentEdgeStore`.
+//!
+//! By default every edge keeps its properties in the shards, so the whole
+//! graph — topology and properties — must fit in RAM. With a property log
+//! attached ([`ConcurrentEdgeStore::attach_property_log`]) the shards keep
+//! the topology (id, endpoints, label) and the [`TRAVERSAL_PROPERTIES`]
+//! (`weight`, `valid_from`, `valid_to`); every other property lives in a
+//! [`LogPayloadStorage`] keyed by edge id. `get_outgoing`, `get_edge` and
+//! the other getters returning full `GraphEdge`s read those back from the
+//! log. Traversals use the `*_for_traversal` getters, which never touch it,
+//! as do neighbour lookups, degree counts and CSR traversals.
+
+use super::super::edge::{GraphEdge, TRAVERSAL_PROPERTIES};
+use super::ConcurrentEdgeStore;
+use crate::storage::{DurabilityMode, LogPayloadStorage, PayloadStorage};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+impl ConcurrentEdgeStore {
+    /// Moves every edge's properties, except the [`TRAVERSAL_PROPERTIES`],
+    /// into a log under `dir` and keeps new edges' properties there too.
+    ///
+    /// Entries already in `dir` are kept for the edges still in the store —
+    /// after a reopen `edge_store.bin` holds only topology and the log is the
+    /// sole copy of their properties — and deleted for edges that are gone.
+    /// No-op when a log is already attached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be opened or written; the store is
+    /// left unchanged.
+    pub fn attach_property_log(&self, dir: &Path) -> io::Result<()> {
+        // Lock ordering: edge_ids FIRST (blocks writers), then the log, then shards.
+        let ids = self.edge_ids.write();
+        let mut slot = self.property_log.write();
+        if slot.is_some() {
+            return Ok(());
+        }
+        let mut log = LogPayloadStorage::new_with_durability(dir, DurabilityMode::FlushOnly)?;
+
+        // Cross-shard edges are stored twice; collect each edge once.
+        let mut spilled: FxHashMap<u64, Value> = FxHashMap::default();
+        for shard in &self.shards {
+            for edge in shard.read().edges.values() {
+                if !edge.has_only_traversal_properties() {
+                    spilled
+                        .entry(edge.id())
+                        .or_insert_with(|| spillable_value(edge.properties()));
+                }
+            }
+        }
+        for stale in log.ids() {
+            if !ids.contains_key(&stale) {
+                log.delete(stale)?;
+            }
+        }
+        let entries: Vec<(u64, &Value)> = spilled.iter().map(|(&id, v)| (id, v)).collect();
+        log.store_batch(&entries)?;
+        log.create_snapshot()?;
+
+        for shard in &self.shards {
+            for edge in shard.write().edges.values_mut() {
+                edge.take_spillable_properties();
+            }
+        }
+        *slot = Some(log);
+        Ok(())
+    }
+
+    /// Moves every edge's properties from the log back into the shards and
+    /// stops using the log. The log files are left on disk for the caller to
+    /// remove once the merged store has been persisted.
+    ///
+    /// No-op when no log is attached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a property cannot be read back; the store is left
+    /// unchanged.
+    pub fn detach_property_log(&self) -> io::Result<()> {
+        let _ids = self.edge_ids.write();
+        let mut slot = self.property_log.write();
+        let Some(log) = slot.as_ref() else {
+            return Ok(());
+        };
+
+        let mut restored: FxHashMap<u64, serde_json::Map<String, Value>> = FxHashMap::default();
+        for id in log.ids() {
+            if let Some(Value::Object(map)) = log.retrieve(id)? {
+                restored.insert(id, map);
+            }
+        }
+        for shard in &self.shards {
+            for edge in shard.write().edges.values_mut() {
+                if let Some(properties) = restored.get(&edge.id()) {
+                    edge.merge_properties(properties.clone());
+                }
+            }
+        }
+        *slot = None;
+        Ok(())
+    }
+
+    /// Returns `true` when edge properties are kept in an on-disk log.
+    #[must_use]
+    pub fn has_property_log(&self) -> bool {
+        self.property_log.read().is_some()
+    }
+
+    /// Writes the properties of `edges` to the log (when attached) and strips
+    /// them from the edges, which then carry only their topology and
+    /// [`TRAVERSAL_PROPERTIES`].
+    ///
+    /// If the log write fails the edges keep their properties in the shards:
+    /// they are persisted with `edge_store.bin` and moved to the log on the
+    /// next attach, so a disk error never loses an edge. Callers hold the
+    /// `edge_ids` write lock.
+    pub(super) fn spill_properties(&self, edges: &mut [GraphEdge]) {
+        let mut slot = self.property_log.write();
+        let Some(log) = slot.as_mut() else {
+            return;
+        };
+        let mut spilled: Vec<(u64, Value)> = Vec::with_capacity(edges.len());
+        for edge in edges.iter() {
+            if edge.has_only_traversal_properties() {
+                // Clears any entry a failed `drop_properties` left for a reused id.
+                if let Err(e) = log.delete(edge.id()) {
+                    tracing::warn!("failed to clear properties of edge {}: {e}", edge.id());
+                }
+            } else {
+                spilled.push((edge.id(), spillable_value(edge.properties())));
+            }
+        }
+        let entries: Vec<(u64, &Value)> = spilled.iter().map(|(id, v)| (*id, v)).collect();
+        if let Err(e) = log.store_batch(&entries) {
+            tracing::warn!(
+                "failed to write properties of {} edge(s) to disk, keeping them in memory: {e}",
+                entries.len()
+            );
+            return;
+        }
+        for edge in edges.iter_mut() {
+            edge.take_spillable_properties();
+        }
+    }
+
+    /// Deletes the logged properties of removed edges (no-op without a log).
+    ///
+    /// Best-effort: the edges are already gone from the shards, so a
+    /// leftover entry is only reachable if the id is reused, and
+    /// `spill_properties` overwrites or clears it then.
+    pub(super) fn drop_properties(&self, edge_ids: impl IntoIterator<Item = u64>) {
+        let mut slot = self.property_log.write();
+        let Some(log) = slot.as_mut() else {
+            return;
+        };
+        for edge_id in edge_ids {
+            if let Err(e) = log.delete(edge_id) {
+                tracing::warn!("failed to delete properties of edge {edge_id}: {e}");
+            }
+        }
+    }
+
+    /// Restores the logged properties onto edges cloned out of the shards.
+    ///
+    /// Edges still carrying their other properties in the shards (see
+    /// `spill_properties`) are returned as they are.
+    pub(super) fn hydrate_properties(&self, mut edges: Vec<GraphEdge>) -> Vec<GraphEdge> {
+        let slot = self.property_log.read();
+        let Some(log) = slot.as_ref() else {
+            return edges;
+        };
+        for edge in edges
+            .iter_mut()
+            .filter(|e| e.has_only_traversal_properties())
+        {
+            match log.retrieve(edge.id()) {
+                Ok(Some(Value::Object(map))) => edge.merge_properties(map),
+                Ok(_) => {}
+                Err(e) => tracing::warn!("failed to read properties of edge {}: {e}", edge.id()),
+            }
+        }
+        edges
+    }
+
+    /// Syncs the property log to disk and snapshots its index, so it is
+    /// durable before `edge_store.bin` (which holds only topology) replaces
+    /// the edge WAL.
+    pub(super) fn sync_property_log(&self) -> io::Result<()> {
+        match self.property_log.write().as_mut() {
+            Some(log) => log.create_snapshot(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Encodes the edge properties that go to the log — all but the
+/// [`TRAVERSAL_PROPERTIES`] — as the JSON object stored there.
+fn spillable_value(properties: &HashMap<String, Value>) -> Value {
+    Value::Object(
+        properties
+            .iter()
+            .filter(|(k, _)| !TRAVERSAL_PROPERTIES.contains(&k.as_str()))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+    )
+}
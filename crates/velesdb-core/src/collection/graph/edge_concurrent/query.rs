@@ -10,7 +10,7 @@ use super::super::traversal::{TraversalConfig, TraversalResult};
 use super::super::traversal_csr::{bfs_traverse_csr, bfs_traverse_csr_filtered};
 use super::{ConcurrentEdgeStore, GraphEdge};
 use arc_swap::Guard;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 use std::sync::Arc;
 
@@ -18,16 +18,29 @@ impl ConcurrentEdgeStore {
     /// Gets all outgoing edges from a node (thread-safe).
     #[must_use]
     pub fn get_outgoing(&self, node_id: u64) -> Vec<GraphEdge> {
-        let shard = &self.shards[self.shard_index(node_id)];
-        let guard = shard.read();
-        guard.get_outgoing(node_id).into_iter().cloned().collect()
+        self.hydrate_properties(self.get_outgoing_for_traversal(node_id))
     }
 
     /// Gets all incoming edges to a node (thread-safe).
     #[must_use]
     pub fn get_incoming(&self, node_id: u64) -> Vec<GraphEdge> {
-        let shard = &self.shards[self.shard_index(node_id)];
-        let guard = shard.read();
+        self.hydrate_properties(self.get_incoming_for_traversal(node_id))
+    }
+
+    /// Like [`get_outgoing`](Self::get_outgoing), but with a property log
+    /// attached the edges carry only the properties traversals read
+    /// (`weight`, `valid_from`, `valid_to`): nothing is read from disk.
+    #[must_use]
+    pub fn get_outgoing_for_traversal(&self, node_id: u64) -> Vec<GraphEdge> {
+        let guard = self.shards[self.shard_index(node_id)].read();
+        guard.get_outgoing(node_id).into_iter().cloned().collect()
+    }
+
+    /// Like [`get_incoming`](Self::get_incoming), without reading the
+    /// property log (see [`get_outgoing_for_traversal`](Self::get_outgoing_for_traversal)).
+    #[must_use]
+    pub fn get_incoming_for_traversal(&self, node_id: u64) -> Vec<GraphEdge> {
+        let guard = self.shards[self.shard_index(node_id)].read();
         guard.get_incoming(node_id).into_iter().cloned().collect()
     }
 
@@ -44,8 +57,16 @@ impl ConcurrentEdgeStore {
             return idx.get_neighbors(node_id).to_vec();
         }
         drop(snapshot);
-        self.get_outgoing(node_id)
-            .iter()
+        self.outgoing_targets(node_id)
+    }
+
+    /// Targets of `node_id`'s outgoing edges, read from its shard without
+    /// loading edge properties.
+    fn outgoing_targets(&self, node_id: u64) -> Vec<u64> {
+        let guard = self.shards[self.shard_index(node_id)].read();
+        guard
+            .get_outgoing(node_id)
+            .into_iter()
             .map(GraphEdge::target)
             .collect()
     }
@@ -68,12 +89,7 @@ impl ConcurrentEdgeStore {
             return f(idx.get_neighbors(node_id));
         }
         drop(snapshot);
-        let fallback: Vec<u64> = self
-            .get_outgoing(node_id)
-            .iter()
-            .map(GraphEdge::target)
-            .collect();
-        f(&fallback)
+        f(&self.outgoing_targets(node_id))
     }
 
     /// Gets outgoing edges filtered by label (thread-safe).
@@ -85,8 +101,20 @@ impl ConcurrentEdgeStore {
     /// when available (EPIC-019 US-003). Falls back to filtering if index not populated.
     #[must_use]
     pub fn get_outgoing_by_label(&self, node_id: u64, label: &str) -> Vec<GraphEdge> {
-        let shard_idx = self.shard_index(node_id);
-        let shard = self.shards[shard_idx].read();
+        self.hydrate_properties(self.get_outgoing_by_label_for_traversal(node_id, label))
+    }
+
+    /// Gets incoming edges filtered by label (thread-safe).
+    #[must_use]
+    pub fn get_incoming_by_label(&self, node_id: u64, label: &str) -> Vec<GraphEdge> {
+        self.hydrate_properties(self.get_incoming_by_label_for_traversal(node_id, label))
+    }
+
+    /// Like [`get_outgoing_by_label`](Self::get_outgoing_by_label), without
+    /// reading the property log (see [`get_outgoing_for_traversal`](Self::get_outgoing_for_traversal)).
+    #[must_use]
+    pub fn get_outgoing_by_label_for_traversal(&self, node_id: u64, label: &str) -> Vec<GraphEdge> {
+        let shard = self.shards[self.shard_index(node_id)].read();
         shard
             .get_outgoing_by_label(node_id, label)
             .into_iter()
@@ -94,10 +122,11 @@ impl ConcurrentEdgeStore {
             .collect()
     }
 
-    /// Gets incoming edges filtered by label (thread-safe).
+    /// Like [`get_incoming_by_label`](Self::get_incoming_by_label), without
+    /// reading the property log (see [`get_outgoing_for_traversal`](Self::get_outgoing_for_traversal)).
     #[must_use]
-    pub fn get_incoming_by_label(&self, node_id: u64, label: &str) -> Vec<GraphEdge> {
-        self.get_incoming(node_id)
+    pub fn get_incoming_by_label_for_traversal(&self, node_id: u64, label: &str) -> Vec<GraphEdge> {
+        self.get_incoming_for_traversal(node_id)
             .into_iter()
             .filter(|e| e.label() == label)
             .collect()
@@ -113,7 +142,8 @@ impl ConcurrentEdgeStore {
     /// the source node, which is O(k) instead of O(shards × edges_per_label).
     #[must_use]
     pub fn get_edges_by_label(&self, label: &str) -> Vec<GraphEdge> {
-        self.shards
+        let edges = self
+            .shards
             .iter()
             .flat_map(|shard| {
                 shard
@@ -123,7 +153,8 @@ impl ConcurrentEdgeStore {
                     .cloned()
                     .collect::<Vec<_>>()
             })
-            .collect()
+            .collect();
+        self.hydrate_properties(edges)
     }
 
     /// Checks if an edge with the given ID exists.
@@ -148,7 +179,8 @@ impl ConcurrentEdgeStore {
         // Get source_id from registry for direct shard lookup
         let source_id = *self.edge_ids.read().get(&edge_id)?;
         let shard_idx = self.shard_index(source_id);
-        self.shards[shard_idx].read().get_edge(edge_id).cloned()
+        let edge = self.shards[shard_idx].read().get_edge(edge_id).cloned()?;
+        self.hydrate_properties(vec![edge]).pop()
     }
 
     /// Traverses the graph using BFS from a starting node.
@@ -226,16 +258,19 @@ impl ConcurrentEdgeStore {
     /// targeted queries (`get_outgoing`, `get_edges_by_label`).
     #[must_use]
     pub fn all_edges(&self) -> Vec<GraphEdge> {
-        let ids = self.edge_ids.read();
-        let mut result = Vec::with_capacity(ids.len());
-        for (&edge_id, &source_id) in ids.iter() {
-            let shard_idx = self.shard_index(source_id);
-            let guard = self.shards[shard_idx].read();
-            if let Some(edge) = guard.get_edge(edge_id) {
-                result.push(edge.clone());
+        let edges = {
+            let ids = self.edge_ids.read();
+            let mut result = Vec::with_capacity(ids.len());
+            for (&edge_id, &source_id) in ids.iter() {
+                let shard_idx = self.shard_index(source_id);
+                let guard = self.shards[shard_idx].read();
+                if let Some(edge) = guard.get_edge(edge_id) {
+                    result.push(edge.clone());
+                }
             }
-        }
-        result
+            result
+        };
+        self.hydrate_properties(edges)
     }
 
     /// Returns the out-degree of a node without materializing edge vectors.
@@ -261,6 +296,45 @@ impl ConcurrentEdgeStore {
         self.shards[shard_idx].read().incoming_degree(node_id)
     }
 
+    /// Returns `(out_degree, in_degree)` for every node with at least one edge.
+    ///
+    /// Each node's outgoing and incoming lists live in its own shard, so a
+    /// single pass over the shards sees every list exactly once. Shards are
+    /// read-locked one at a time: the result is not a point-in-time snapshot
+    /// under concurrent writes.
+    #[must_use]
+    pub fn degree_counts(&self) -> FxHashMap<u64, (usize, usize)> {
+        let mut degrees: FxHashMap<u64, (usize, usize)> = FxHashMap::default();
+        for shard in &self.shards {
+            let guard = shard.read();
+            for (&node_id, edge_ids) in &guard.outgoing {
+                degrees.entry(node_id).or_default().0 += edge_ids.len();
+            }
+            for (&node_id, edge_ids) in &guard.incoming {
+                degrees.entry(node_id).or_default().1 += edge_ids.len();
+            }
+        }
+        degrees.retain(|_, &mut (out, inc)| out + inc > 0);
+        degrees
+    }
+
+    /// Returns the number of edges per label.
+    ///
+    /// Label indices are kept by the source shard only, so cross-shard edges
+    /// are counted once.
+    #[must_use]
+    pub fn edge_counts_by_label(&self) -> FxHashMap<String, usize> {
+        let mut counts: FxHashMap<String, usize> = FxHashMap::default();
+        for shard in &self.shards {
+            for (label, edge_ids) in &shard.read().by_label {
+                if !edge_ids.is_empty() {
+                    *counts.entry(label.clone()).or_default() += edge_ids.len();
+                }
+            }
+        }
+        counts
+    }
+
     /// Rebuilds the CSR snapshot if the dirty flag is set.
     ///
     /// Uses `swap(false, AcqRel)` to atomically clear the flag and check
@@ -1385,6 +1385,8 @@ fn test_csr_debounce_rebuilds_after_threshold() {
         rel_types: vec![],
         limit: 100,
         deadline: None,
+        valid_at: None,
+        max_cost: None,
     };
     let results = store.traverse_bfs_csr(0, &config);
     let targets: std::collections::HashSet<u64> = results.iter().map(|r| r.target_id).collect();
@@ -1435,6 +1437,8 @@ fn test_add_edges_batch_counts_each_edge_toward_csr_debounce() {
         rel_types: vec![],
         limit: 100,
         deadline: None,
+        valid_at: None,
+        max_cost: None,
     };
     let results = store.traverse_bfs_csr(0, &config);
     let targets: std::collections::HashSet<u64> = results.iter().map(|r| r.target_id).collect();
@@ -846,3 +846,47 @@ fn test_csr_has_label() {
     assert!(snapshot.has_label("FOLLOWS"));
     assert!(!snapshot.has_label("LIKES"));
 }
+
+#[test]
+fn test_edge_validity_window_is_half_open() {
+    let edge = GraphEdge::new(1, 100, 200, "WORKS_AT")
+        .expect("valid")
+        .with_validity(Some(10), Some(20));
+
+    assert_eq!(edge.valid_from(), Some(10));
+    assert_eq!(edge.valid_to(), Some(20));
+    assert_eq!(edge.property(VALID_FROM_PROPERTY), Some(&json!(10)));
+    assert!(!edge.is_valid_at(9));
+    assert!(edge.is_valid_at(10));
+    assert!(edge.is_valid_at(19));
+    assert!(!edge.is_valid_at(20));
+
+    let open = edge.with_validity(None, Some(20));
+    assert_eq!(open.valid_from(), None);
+    assert!(open.is_valid_at(i64::MIN));
+    assert!(GraphEdge::new(2, 1, 2, "X").expect("valid").is_valid_at(0));
+}
+
+#[test]
+fn test_edge_store_bytes_keep_edge_properties() {
+    let mut props = HashMap::new();
+    props.insert("weight".to_string(), json!(0.5));
+    let mut store = EdgeStore::new();
+    store
+        .add_edge(
+            GraphEdge::new(1, 100, 200, "KNOWS")
+                .expect("valid")
+                .with_properties(props)
+                .with_validity(Some(10), None),
+        )
+        .expect("add");
+    store
+        .add_edge(GraphEdge::new(2, 200, 300, "KNOWS").expect("valid"))
+        .expect("add");
+
+    let loaded = EdgeStore::from_bytes(&store.to_bytes().expect("to bytes")).expect("from bytes");
+    let edge = loaded.get_edge(1).expect("edge 1");
+    assert_eq!(edge.property("weight"), Some(&json!(0.5)));
+    assert_eq!(edge.valid_from(), Some(10));
+    assert!(loaded.get_edge(2).expect("edge 2").properties().is_empty());
+}
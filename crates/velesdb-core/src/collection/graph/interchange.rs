@@ -0,0 +1,248 @@
+//! Graph interchange formats: GraphML and JSON Lines.
+//!
+//! Serializes a graph (node payloads + edges) to text that NetworkX, Neo4j
+//! (APOC) and similar tooling can read, and parses it back. Nodes carry
+//! their `_labels` and the remaining payload fields as properties; edges
+//! carry their id, endpoints, label and properties. Vectors are not part of
+//! either format.
+//!
+//! JSON Lines writes one object per line:
+//!
+//! ```text
+//! {"type":"node","id":1,"labels":["Person"],"properties":{"name":"Alice"}}
+//! {"type":"edge","id":10,"source":1,"target":2,"label":"KNOWS","properties":{}}
+//! ```
+//!
+//! and also reads APOC's `"type":"relationship"` lines (`start`/`end`
+//! objects, string ids). GraphML is handled in `interchange_graphml`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use super::interchange_graphml;
+use crate::error::{Error, Result};
+
+/// Label given to imported edges that carry none (plain NetworkX graphs).
+pub const DEFAULT_IMPORT_EDGE_LABEL: &str = "RELATED_TO";
+
+/// Text format for [`Collection::export_graph`](crate::collection::types::Collection::export_graph)
+/// and `import_graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    /// GraphML XML document (`.graphml`).
+    #[serde(alias = "graph_ml")]
+    GraphMl,
+    /// One JSON object per node and per edge (`.jsonl`).
+    #[serde(alias = "json_lines", alias = "ndjson")]
+    Jsonl,
+}
+
+impl GraphFormat {
+    /// MIME type of documents in this format.
+    #[must_use]
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::GraphMl => "application/graphml+xml",
+            Self::Jsonl => "application/x-ndjson",
+        }
+    }
+}
+
+impl fmt::Display for GraphFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::GraphMl => "graphml",
+            Self::Jsonl => "jsonl",
+        })
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "graphml" | "graph_ml" => Ok(Self::GraphMl),
+            "jsonl" | "json_lines" | "ndjson" => Ok(Self::Jsonl),
+            other => Err(Error::Config(format!(
+                "unknown graph format '{other}' (expected 'graphml' or 'jsonl')"
+            ))),
+        }
+    }
+}
+
+/// Counts reported by a graph import.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphImportStats {
+    /// Node payloads written (inserted or replaced).
+    pub nodes: usize,
+    /// Edges added; edges whose id already exists are skipped.
+    pub edges: usize,
+}
+
+/// A node as exchanged: its id, labels and payload properties.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NodeRecord {
+    pub id: u64,
+    pub labels: Vec<String>,
+    pub properties: Map<String, Value>,
+}
+
+/// An edge as exchanged. `id` is `None` when the source document has none.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EdgeRecord {
+    pub id: Option<u64>,
+    pub source: u64,
+    pub target: u64,
+    pub label: String,
+    pub properties: HashMap<String, Value>,
+}
+
+/// A whole graph as exchanged.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct GraphDocument {
+    pub nodes: Vec<NodeRecord>,
+    pub edges: Vec<EdgeRecord>,
+}
+
+/// Serializes `doc` in `format`.
+pub(crate) fn write_graph(doc: &GraphDocument, format: GraphFormat) -> String {
+    match format {
+        GraphFormat::GraphMl => interchange_graphml::write_graphml(doc),
+        GraphFormat::Jsonl => write_jsonl(doc),
+    }
+}
+
+/// Parses `data` in `format`.
+///
+/// # Errors
+///
+/// Returns `Error::Serialization` if `data` is malformed.
+pub(crate) fn read_graph(data: &str, format: GraphFormat) -> Result<GraphDocument> {
+    match format {
+        GraphFormat::GraphMl => interchange_graphml::read_graphml(data),
+        GraphFormat::Jsonl => read_jsonl(data),
+    }
+}
+
+fn write_jsonl(doc: &GraphDocument) -> String {
+    let mut out = String::new();
+    for node in &doc.nodes {
+        let line = json!({
+            "type": "node",
+            "id": node.id,
+            "labels": node.labels,
+            "properties": node.properties,
+        });
+        out.push_str(&line.to_string());
+        out.push('\n');
+    }
+    for edge in &doc.edges {
+        let line = json!({
+            "type": "edge",
+            "id": edge.id,
+            "source": edge.source,
+            "target": edge.target,
+            "label": edge.label,
+            "properties": edge.properties,
+        });
+        out.push_str(&line.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+fn read_jsonl(data: &str) -> Result<GraphDocument> {
+    let mut doc = GraphDocument::default();
+    for (index, line) in data.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let at = |msg: String| Error::Serialization(format!("JSONL line {}: {msg}", index + 1));
+        let value: Value = serde_json::from_str(line).map_err(|e| at(e.to_string()))?;
+        match value.get("type").and_then(Value::as_str) {
+            Some("node") => doc.nodes.push(jsonl_node(&value).map_err(at)?),
+            Some("edge" | "relationship") => doc.edges.push(jsonl_edge(&value).map_err(at)?),
+            other => return Err(at(format!("unknown record type {other:?}"))),
+        }
+    }
+    Ok(doc)
+}
+
+fn jsonl_node(value: &Value) -> std::result::Result<NodeRecord, String> {
+    let id = value
+        .get("id")
+        .and_then(parse_id)
+        .ok_or("missing node id")?;
+    let labels = match value.get("labels") {
+        None | Some(Value::Null) => Vec::new(),
+        Some(Value::Array(labels)) => labels
+            .iter()
+            .map(|l| {
+                l.as_str()
+                    .map(str::to_string)
+                    .ok_or("labels must be strings")
+            })
+            .collect::<std::result::Result<_, _>>()?,
+        Some(_) => return Err("labels must be an array".to_string()),
+    };
+    Ok(NodeRecord {
+        id,
+        labels,
+        properties: jsonl_properties(value)?.into_iter().collect(),
+    })
+}
+
+fn jsonl_edge(value: &Value) -> std::result::Result<EdgeRecord, String> {
+    // APOC nests the endpoints: `"start": {"id": "1", ...}`.
+    let endpoint = |flat: &str, nested: &str| {
+        value
+            .get(flat)
+            .or_else(|| value.get(nested).and_then(|n| n.get("id")))
+            .and_then(parse_id)
+            .ok_or(format!("missing edge {flat}"))
+    };
+    Ok(EdgeRecord {
+        id: value.get("id").and_then(parse_id),
+        source: endpoint("source", "start")?,
+        target: endpoint("target", "end")?,
+        label: value
+            .get("label")
+            .and_then(Value::as_str)
+            .unwrap_or(DEFAULT_IMPORT_EDGE_LABEL)
+            .to_string(),
+        properties: jsonl_properties(value)?,
+    })
+}
+
+fn jsonl_properties(value: &Value) -> std::result::Result<HashMap<String, Value>, String> {
+    match value.get("properties") {
+        None | Some(Value::Null) => Ok(HashMap::new()),
+        Some(Value::Object(props)) => Ok(props.clone().into_iter().collect()),
+        Some(_) => Err("properties must be an object".to_string()),
+    }
+}
+
+/// Reads an id given as a number or a numeric string.
+fn parse_id(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => parse_id_str(s),
+        _ => None,
+    }
+}
+
+/// Parses `42` as well as the `n42` / `e42` ids common in GraphML files.
+pub(super) fn parse_id_str(s: &str) -> Option<u64> {
+    let s = s.trim();
+    s.parse().ok().or_else(|| {
+        s.strip_prefix(['n', 'e'])
+            .and_then(|rest| rest.parse().ok())
+    })
+}
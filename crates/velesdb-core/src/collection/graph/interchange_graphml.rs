@@ -0,0 +1,393 @@
+//! GraphML reader and writer for graph interchange.
+//!
+//! Node labels travel in a `labels` key as `:Person:Employee` (the APOC
+//! convention) and edge labels in a `label` key. Other properties get one
+//! `<key>` each, typed `boolean`, `long`, `double` or `string`; values that
+//! GraphML cannot type (arrays, objects, mixed types) are written as JSON
+//! text in a `string` key, so JSON Lines is the lossless format.
+//!
+//! The reader accepts GraphML from other tools: node ids may be `42` or
+//! `n42`, edge ids are optional, `labels` may also be a node attribute, and
+//! `edgedefault="undirected"` edges are imported in their written direction.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+use quick_xml::escape::{escape, resolve_predefined_entity};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+use serde_json::{Map, Number, Value};
+
+use super::interchange::{
+    parse_id_str, EdgeRecord, GraphDocument, NodeRecord, DEFAULT_IMPORT_EDGE_LABEL,
+};
+use crate::error::{Error, Result};
+
+const NODE_LABELS_KEY: &str = "labels";
+const EDGE_LABEL_KEY: &str = "label";
+
+/// GraphML `attr.type` of a property key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyType {
+    Boolean,
+    Long,
+    Double,
+    String,
+}
+
+impl KeyType {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Bool(_) => Self::Boolean,
+            Value::Number(n) if n.is_f64() => Self::Double,
+            Value::Number(_) => Self::Long,
+            _ => Self::String,
+        }
+    }
+
+    /// Narrowest type able to hold values of both `self` and `other`.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Self::Long, Self::Double) | (Self::Double, Self::Long) => Self::Double,
+            _ => Self::String,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Boolean => "boolean",
+            Self::Long => "long",
+            Self::Double => "double",
+            Self::String => "string",
+        }
+    }
+
+    fn parse(name: &str) -> Self {
+        match name {
+            "boolean" => Self::Boolean,
+            "int" | "long" => Self::Long,
+            "float" | "double" => Self::Double,
+            _ => Self::String,
+        }
+    }
+}
+
+// =========================================================================
+// Writer
+// =========================================================================
+
+pub(super) fn write_graphml(doc: &GraphDocument) -> String {
+    // (domain, property) -> type, in a stable order for the key ids.
+    let mut keys: BTreeMap<(&str, &str), KeyType> = BTreeMap::new();
+    let mut observe = |domain, name, value: &Value| {
+        if !value.is_null() {
+            let ty = KeyType::of(value);
+            keys.entry((domain, name))
+                .and_modify(|t| *t = t.merge(ty))
+                .or_insert(ty);
+        }
+    };
+    for node in &doc.nodes {
+        for (name, value) in &node.properties {
+            observe("node", name.as_str(), value);
+        }
+    }
+    for edge in &doc.edges {
+        for (name, value) in &edge.properties {
+            observe("edge", name.as_str(), value);
+        }
+    }
+    let key_ids: HashMap<(&str, &str), String> = keys
+        .keys()
+        .enumerate()
+        .map(|(i, key)| (*key, format!("d{i}")))
+        .collect();
+
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+    );
+    let _ = writeln!(
+        out,
+        "  <key id=\"{NODE_LABELS_KEY}\" for=\"node\" attr.name=\"{NODE_LABELS_KEY}\" attr.type=\"string\"/>"
+    );
+    let _ = writeln!(
+        out,
+        "  <key id=\"{EDGE_LABEL_KEY}\" for=\"edge\" attr.name=\"{EDGE_LABEL_KEY}\" attr.type=\"string\"/>"
+    );
+    for ((domain, name), ty) in &keys {
+        let _ = writeln!(
+            out,
+            "  <key id=\"{}\" for=\"{domain}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+            key_ids[&(*domain, *name)],
+            escape(*name),
+            ty.name()
+        );
+    }
+    out.push_str("  <graph id=\"G\" edgedefault=\"directed\">\n");
+
+    let write_data = |out: &mut String, domain, properties: Vec<(&String, &Value)>| {
+        let mut properties = properties;
+        properties.sort_unstable_by_key(|(name, _)| name.as_str());
+        for (name, value) in properties {
+            if value.is_null() {
+                continue;
+            }
+            let key = (domain, name.as_str());
+            let text = match (keys[&key], value) {
+                (KeyType::String, Value::String(s)) => s.clone(),
+                _ => value.to_string(),
+            };
+            let _ = writeln!(
+                out,
+                "      <data key=\"{}\">{}</data>",
+                key_ids[&key],
+                escape(text.as_str())
+            );
+        }
+    };
+    for node in &doc.nodes {
+        let _ = writeln!(out, "    <node id=\"{}\">", node.id);
+        if !node.labels.is_empty() {
+            let labels = format!(":{}", node.labels.join(":"));
+            let _ = writeln!(
+                out,
+                "      <data key=\"{NODE_LABELS_KEY}\">{}</data>",
+                escape(labels.as_str())
+            );
+        }
+        write_data(&mut out, "node", node.properties.iter().collect());
+        out.push_str("    </node>\n");
+    }
+    for edge in &doc.edges {
+        out.push_str("    <edge");
+        if let Some(id) = edge.id {
+            let _ = write!(out, " id=\"{id}\"");
+        }
+        let _ = writeln!(
+            out,
+            " source=\"{}\" target=\"{}\">\n      <data key=\"{EDGE_LABEL_KEY}\">{}</data>",
+            edge.source,
+            edge.target,
+            escape(edge.label.as_str())
+        );
+        write_data(&mut out, "edge", edge.properties.iter().collect());
+        out.push_str("    </edge>\n");
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+// =========================================================================
+// Reader
+// =========================================================================
+
+/// A `<key>` declaration.
+struct KeyDef {
+    name: String,
+    ty: KeyType,
+}
+
+/// The element whose `<data>` children are being read.
+enum Current {
+    None,
+    Node(NodeRecord),
+    Edge(EdgeRecord),
+}
+
+fn malformed(msg: impl std::fmt::Display) -> Error {
+    Error::Serialization(format!("GraphML: {msg}"))
+}
+
+pub(super) fn read_graphml(data: &str) -> Result<GraphDocument> {
+    let mut reader = Reader::from_str(data);
+    let mut doc = GraphDocument::default();
+    let mut keys: HashMap<String, KeyDef> = HashMap::new();
+    let mut current = Current::None;
+    // `Some((key id, text so far))` while inside a `<data>` element.
+    let mut data_text: Option<(String, String)> = None;
+
+    loop {
+        match reader.read_event().map_err(malformed)? {
+            Event::Start(e) | Event::Empty(e) if local_name(&e) == "key" => {
+                let attrs = attributes(&e)?;
+                let id = attrs
+                    .get("id")
+                    .cloned()
+                    .ok_or_else(|| malformed("key without id"))?;
+                let name = attrs
+                    .get("attr.name")
+                    .cloned()
+                    .unwrap_or_else(|| id.clone());
+                let ty = KeyType::parse(attrs.get("attr.type").map_or("string", String::as_str));
+                keys.insert(id, KeyDef { name, ty });
+            }
+            Event::Start(e) if local_name(&e) == "node" => {
+                current = Current::Node(node_start(&e)?);
+            }
+            Event::Empty(e) if local_name(&e) == "node" => doc.nodes.push(node_start(&e)?),
+            Event::Start(e) if local_name(&e) == "edge" => {
+                current = Current::Edge(edge_start(&e)?);
+            }
+            Event::Empty(e) if local_name(&e) == "edge" => doc.edges.push(edge_start(&e)?),
+            Event::Start(e) if local_name(&e) == "data" => {
+                let key = attributes(&e)?
+                    .remove("key")
+                    .ok_or_else(|| malformed("data without key"))?;
+                data_text = Some((key, String::new()));
+            }
+            Event::Text(t) => {
+                if let Some((_, text)) = data_text.as_mut() {
+                    text.push_str(&t.xml10_content());
+                }
+            }
+            Event::CData(t) => {
+                if let Some((_, text)) = data_text.as_mut() {
+                    text.push_str(&t.xml10_content());
+                }
+            }
+            Event::GeneralRef(r) => {
+                if let Some((_, text)) = data_text.as_mut() {
+                    match r.resolve_char_ref().map_err(malformed)? {
+                        Some(c) => text.push(c),
+                        None => text.push_str(
+                            resolve_predefined_entity(&r)
+                                .ok_or_else(|| malformed(format!("unknown entity &{};", &*r)))?,
+                        ),
+                    }
+                }
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                "data" => {
+                    if let Some((key, text)) = data_text.take() {
+                        apply_data(&mut current, &keys, &key, text)?;
+                    }
+                }
+                "node" | "edge" => match std::mem::replace(&mut current, Current::None) {
+                    Current::Node(node) => doc.nodes.push(node),
+                    Current::Edge(edge) => doc.edges.push(edge),
+                    Current::None => {}
+                },
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(doc)
+}
+
+fn local_name<'a>(e: &'a BytesStart<'_>) -> &'a str {
+    e.local_name().into_inner()
+}
+
+fn attributes(e: &BytesStart<'_>) -> Result<HashMap<String, String>> {
+    e.attributes()
+        .map(|attr| {
+            let attr = attr.map_err(malformed)?;
+            let value = attr
+                .normalized_value(XmlVersion::Implicit1_0)
+                .map_err(malformed)?;
+            Ok((attr.key.as_ref().to_string(), value.into_owned()))
+        })
+        .collect()
+}
+
+fn required_id(attrs: &HashMap<String, String>, name: &str, element: &str) -> Result<u64> {
+    let raw = attrs
+        .get(name)
+        .ok_or_else(|| malformed(format!("{element} without {name}")))?;
+    parse_id_str(raw).ok_or_else(|| malformed(format!("{element} {name} '{raw}' is not numeric")))
+}
+
+fn node_start(e: &BytesStart<'_>) -> Result<NodeRecord> {
+    let attrs = attributes(e)?;
+    Ok(NodeRecord {
+        id: required_id(&attrs, "id", "node")?,
+        labels: attrs
+            .get(NODE_LABELS_KEY)
+            .map(|l| split_labels(l))
+            .unwrap_or_default(),
+        properties: Map::new(),
+    })
+}
+
+fn edge_start(e: &BytesStart<'_>) -> Result<EdgeRecord> {
+    let attrs = attributes(e)?;
+    Ok(EdgeRecord {
+        id: attrs.get("id").and_then(|id| parse_id_str(id)),
+        source: required_id(&attrs, "source", "edge")?,
+        target: required_id(&attrs, "target", "edge")?,
+        label: attrs
+            .get(EDGE_LABEL_KEY)
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_IMPORT_EDGE_LABEL.to_string()),
+        properties: HashMap::new(),
+    })
+}
+
+/// `:Person:Employee` (or `Person`) -> `["Person", "Employee"]`.
+fn split_labels(labels: &str) -> Vec<String> {
+    labels
+        .split(':')
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Stores a `<data>` value on the element being read.
+fn apply_data(
+    current: &mut Current,
+    keys: &HashMap<String, KeyDef>,
+    key: &str,
+    text: String,
+) -> Result<()> {
+    let (name, ty) = keys
+        .get(key)
+        .map_or((key, KeyType::String), |def| (def.name.as_str(), def.ty));
+    match current {
+        Current::Node(node) if name == NODE_LABELS_KEY => node.labels = split_labels(&text),
+        Current::Node(node) => {
+            node.properties
+                .insert(name.to_string(), typed_value(name, ty, text)?);
+        }
+        Current::Edge(edge) if name == EDGE_LABEL_KEY => edge.label = text.trim().to_string(),
+        Current::Edge(edge) => {
+            edge.properties
+                .insert(name.to_string(), typed_value(name, ty, text)?);
+        }
+        // Graph-level data is not stored.
+        Current::None => {}
+    }
+    Ok(())
+}
+
+fn typed_value(name: &str, ty: KeyType, text: String) -> Result<Value> {
+    let invalid = || {
+        malformed(format!(
+            "'{text}' is not a valid {} for '{name}'",
+            ty.name()
+        ))
+    };
+    let trimmed = text.trim();
+    match ty {
+        KeyType::String => Ok(Value::String(text)),
+        KeyType::Boolean => match trimmed.to_ascii_lowercase().as_str() {
+            "true" | "1" => Ok(Value::Bool(true)),
+            "false" | "0" => Ok(Value::Bool(false)),
+            _ => Err(invalid()),
+        },
+        KeyType::Long => trimmed
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| invalid()),
+        KeyType::Double => trimmed
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(invalid),
+    }
+}
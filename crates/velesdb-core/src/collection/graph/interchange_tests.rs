@@ -0,0 +1,157 @@
+//! Tests for `interchange` / `interchange_graphml` - GraphML and JSONL formats.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use super::interchange::{read_graph, write_graph, EdgeRecord, GraphDocument, NodeRecord};
+use super::GraphFormat;
+use crate::error::Error;
+
+fn sample_document() -> GraphDocument {
+    let props = |value: Value| value.as_object().cloned().unwrap_or_default();
+    GraphDocument {
+        nodes: vec![
+            NodeRecord {
+                id: 1,
+                labels: vec!["Person".into(), "Employee".into()],
+                properties: props(json!({"name": "Alice & <Bob>", "age": 30, "active": true})),
+            },
+            NodeRecord {
+                id: 2,
+                labels: vec!["Company".into()],
+                properties: props(json!({"name": "Acme", "score": 4.5})),
+            },
+            NodeRecord {
+                id: 3,
+                labels: Vec::new(),
+                properties: props(json!({})),
+            },
+        ],
+        edges: vec![EdgeRecord {
+            id: Some(10),
+            source: 1,
+            target: 2,
+            label: "WORKS_AT".into(),
+            properties: HashMap::from([("since".to_string(), json!(2020))]),
+        }],
+    }
+}
+
+#[test]
+fn test_graph_format_parse_and_display() {
+    assert_eq!(
+        "GraphML".parse::<GraphFormat>().unwrap(),
+        GraphFormat::GraphMl
+    );
+    assert_eq!("ndjson".parse::<GraphFormat>().unwrap(), GraphFormat::Jsonl);
+    assert_eq!(GraphFormat::Jsonl.to_string(), "jsonl");
+    assert!(matches!(
+        "csv".parse::<GraphFormat>(),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn test_jsonl_round_trip() {
+    let doc = sample_document();
+    let text = write_graph(&doc, GraphFormat::Jsonl);
+
+    assert_eq!(text.lines().count(), 4);
+    assert_eq!(read_graph(&text, GraphFormat::Jsonl).unwrap(), doc);
+}
+
+#[test]
+fn test_graphml_round_trip_keeps_types_and_escaping() {
+    let doc = sample_document();
+    let text = write_graph(&doc, GraphFormat::GraphMl);
+
+    assert!(
+        text.contains("attr.name=\"age\" attr.type=\"long\""),
+        "{text}"
+    );
+    assert!(text.contains("<data key=\"labels\">:Person:Employee</data>"));
+    assert!(text.contains("Alice &amp; &lt;Bob&gt;"));
+    assert_eq!(read_graph(&text, GraphFormat::GraphMl).unwrap(), doc);
+}
+
+#[test]
+fn test_graphml_writes_nested_and_mixed_values_as_json_text() {
+    let mut doc = sample_document();
+    doc.nodes[0]
+        .properties
+        .insert("tags".into(), json!(["a", "b"]));
+    doc.nodes[1]
+        .properties
+        .insert("age".into(), json!("unknown"));
+
+    let parsed = read_graph(
+        &write_graph(&doc, GraphFormat::GraphMl),
+        GraphFormat::GraphMl,
+    )
+    .unwrap();
+    assert_eq!(parsed.nodes[0].properties["tags"], json!("[\"a\",\"b\"]"));
+    // `age` mixes numbers and strings, so both come back as strings.
+    assert_eq!(parsed.nodes[0].properties["age"], json!("30"));
+    assert_eq!(parsed.nodes[1].properties["age"], json!("unknown"));
+}
+
+#[test]
+fn test_graphml_reads_networkx_style_documents() {
+    let text = r#"<?xml version='1.0' encoding='utf-8'?>
+<!-- written by NetworkX -->
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="weight" attr.type="double"/>
+  <key id="d1" for="edge" attr.name="w" attr.type="int"/>
+  <graph edgedefault="undirected">
+    <node id="n0"><data key="d0">1.5</data></node>
+    <node id="n1" labels=":City"/>
+    <edge source="n0" target="n1"><data key="d1">7</data></edge>
+  </graph>
+</graphml>"#;
+    let doc = read_graph(text, GraphFormat::GraphMl).unwrap();
+
+    assert_eq!(doc.nodes.len(), 2);
+    assert_eq!(doc.nodes[0].properties["weight"], json!(1.5));
+    assert_eq!(doc.nodes[1].labels, vec!["City".to_string()]);
+    let edge = &doc.edges[0];
+    assert_eq!((edge.id, edge.source, edge.target), (None, 0, 1));
+    assert_eq!(edge.label, super::DEFAULT_IMPORT_EDGE_LABEL);
+    assert_eq!(edge.properties["w"], json!(7));
+}
+
+#[test]
+fn test_jsonl_reads_apoc_relationships() {
+    let text = r#"{"type":"node","id":"5","labels":["User"],"properties":{"name":"x"}}
+{"type":"relationship","id":"0","label":"FOLLOWS","start":{"id":"5"},"end":{"id":"6","labels":["User"]}}
+"#;
+    let doc = read_graph(text, GraphFormat::Jsonl).unwrap();
+
+    assert_eq!(doc.nodes[0].id, 5);
+    let edge = &doc.edges[0];
+    assert_eq!((edge.id, edge.source, edge.target), (Some(0), 5, 6));
+    assert_eq!(edge.label, "FOLLOWS");
+}
+
+#[test]
+fn test_malformed_documents_are_rejected() {
+    let bad = [
+        (GraphFormat::Jsonl, "{\"type\":\"node\"}"),
+        (GraphFormat::Jsonl, "{\"type\":\"hyperedge\",\"id\":1}"),
+        (GraphFormat::Jsonl, "not json"),
+        (
+            GraphFormat::GraphMl,
+            "<graphml><graph><node id=\"abc\"/></graph></graphml>",
+        ),
+        (
+            GraphFormat::GraphMl,
+            "<graphml><key id=\"d0\" for=\"node\" attr.name=\"n\" attr.type=\"int\"/>\
+             <graph><node id=\"1\"><data key=\"d0\">x</data></node></graph></graphml>",
+        ),
+        (GraphFormat::GraphMl, "<graphml><graph></node></graphml>"),
+    ];
+    for (format, text) in bad {
+        let err = read_graph(text, format).expect_err(text);
+        assert!(matches!(err, Error::Serialization(_)), "{text}: {err:?}");
+    }
+}
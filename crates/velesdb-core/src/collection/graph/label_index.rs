@@ -173,6 +173,15 @@ impl LabelIndex {
         self.labels.len()
     }
 
+    /// Returns `(label, node count)` for every indexed label.
+    ///
+    /// Nodes with ids above `u32::MAX` are not indexed and so not counted.
+    pub fn label_counts(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.labels
+            .iter()
+            .map(|(label, bitmap)| (label.as_str(), bitmap.len()))
+    }
+
     /// Returns `true` if the index contains no entries.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -43,6 +43,7 @@
 
 mod clustered_index;
 mod csr_snapshot;
+mod degree_stats;
 mod edge;
 mod edge_concurrent;
 mod edge_persistence;
@@ -50,6 +51,10 @@ mod edge_removal;
 #[cfg(feature = "persistence")]
 pub(crate) mod edge_wal;
 pub(crate) mod helpers;
+mod interchange;
+mod interchange_graphml;
+#[cfg(test)]
+mod interchange_tests;
 mod label_index;
 #[cfg(test)]
 mod label_index_tests;
@@ -66,7 +71,13 @@ mod streaming;
 mod streaming_tests;
 mod traversal;
 mod traversal_bidir;
+mod traversal_cost;
+#[cfg(test)]
+mod traversal_cost_tests;
 mod traversal_csr;
+mod traversal_shortest;
+#[cfg(test)]
+mod traversal_shortest_tests;
 #[cfg(test)]
 mod traversal_tests;
 
@@ -89,13 +100,17 @@ mod schema_tests;
 
 pub use clustered_index::ClusteredIndex;
 pub use csr_snapshot::{AdjacencySource, CsrSnapshot, EdgePredicate, LabelFilter, NoFilter};
-pub use edge::{EdgeStore, GraphEdge};
+pub use degree_stats::{DegreeBucket, GraphStats, HubNode, HUB_DEGREE_THRESHOLD, TOP_HUBS};
+pub(crate) use edge::TRAVERSAL_PROPERTIES;
+pub use edge::{EdgeStore, GraphEdge, VALID_FROM_PROPERTY, VALID_TO_PROPERTY, WEIGHT_PROPERTY};
 #[allow(unused_imports)] // Re-exported for test access via super::*
 pub(crate) use node::Element;
 // Re-exported for use by ConcurrentEdgeStore (Task 5) and other internal consumers.
 #[allow(unused_imports)]
 pub(crate) use csr_snapshot::SnapshotBuilder;
 pub use edge_concurrent::ConcurrentEdgeStore;
+pub(crate) use interchange::{read_graph, write_graph, EdgeRecord, GraphDocument, NodeRecord};
+pub use interchange::{GraphFormat, GraphImportStats, DEFAULT_IMPORT_EDGE_LABEL};
 pub use label_index::LabelIndex;
 pub use label_table::{LabelId, LabelTable};
 pub use metrics::{GraphMetrics, LatencyHistogram};
@@ -104,10 +119,14 @@ pub use property_index::PropertyIndex;
 pub use range_index::RangeIndex;
 pub use schema::{EdgeType, GraphSchema, NodeType, ValueType};
 pub use streaming::{
-    bfs_stream, concurrent_bfs_stream, BfsIterator, ConcurrentBfsIterator, StreamingConfig,
-    MAX_VISITED_SIZE,
+    bfs_stream, concurrent_bfs_stream, BfsCursor, BfsIterator, ConcurrentBfsIterator,
+    StreamingConfig, CURSOR_MAX_VISITED_SIZE, MAX_VISITED_SIZE,
 };
 pub(crate) use traversal::{deadline_reached, DEADLINE_CHECK_INTERVAL};
 pub use traversal::{TraversalConfig, TraversalPath, TraversalResult, DEFAULT_MAX_DEPTH};
 pub use traversal_bidir::bfs_traverse_both;
+pub use traversal_cost::dijkstra_traverse;
+pub(crate) use traversal_cost::CostBoundedSearch;
 pub use traversal_csr::{bfs_traverse_csr, bfs_traverse_csr_filtered};
+pub use traversal_shortest::bidirectional_shortest_path;
+pub(crate) use traversal_shortest::{bfs_shortest_paths, bidirectional_bfs};
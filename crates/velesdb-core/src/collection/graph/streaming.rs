@@ -5,8 +5,10 @@
 
 use super::edge_concurrent::ConcurrentEdgeStore;
 use super::traversal::{deadline_reached, reconstruct_path, BfsState, DEADLINE_CHECK_INTERVAL};
-use super::{EdgeStore, TraversalResult, DEFAULT_MAX_DEPTH};
+use super::traversal_cost::CostBoundedSearch;
+use super::{EdgeStore, GraphEdge, TraversalResult, DEFAULT_MAX_DEPTH};
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::borrow::Borrow;
 use std::collections::VecDeque;
 use std::time::Instant;
 
@@ -38,6 +40,14 @@ pub struct StreamingConfig {
     /// expanding and terminates (returns `None`), yielding the partial result
     /// accumulated so far. `None` (the default) disables the time bound.
     pub deadline: Option<Instant>,
+    /// Only follow edges valid at this Unix timestamp (see
+    /// [`GraphEdge::is_valid_at`]). `None` (the default) ignores edge
+    /// validity windows.
+    pub valid_at: Option<i64>,
+    /// Maximum summed [`GraphEdge::weight`] of a path. When set, results
+    /// stream cheapest first (Dijkstra) and stop at this budget; `None` (the
+    /// default) bounds paths by hops only.
+    pub max_cost: Option<f64>,
 }
 
 impl Default for StreamingConfig {
@@ -48,6 +58,8 @@ impl Default for StreamingConfig {
             max_visited_size: MAX_VISITED_SIZE, // ~800KB for FxHashSet<u64>
             rel_types: Vec::new(),
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         }
     }
 }
@@ -88,6 +100,20 @@ impl StreamingConfig {
         self.deadline = Some(deadline);
         self
     }
+
+    /// Only follows edges whose validity window contains the Unix timestamp `t`.
+    #[must_use]
+    pub fn with_valid_at(mut self, t: i64) -> Self {
+        self.valid_at = Some(t);
+        self
+    }
+
+    /// Bounds paths by their summed edge weight instead of hops alone.
+    #[must_use]
+    pub fn with_max_cost(mut self, max_cost: f64) -> Self {
+        self.max_cost = Some(max_cost);
+        self
+    }
 }
 
 /// Shared BFS bookkeeping for the streaming iterators.
@@ -109,6 +135,8 @@ struct BfsBookkeeping {
     yielded: usize,
     /// Pops since the last wall-clock deadline check (see `drive`).
     nodes_since_check: u32,
+    /// Replaces the FIFO frontier when `max_cost` is set (see `drive_cost`).
+    cost_search: Option<CostBoundedSearch>,
 }
 
 impl BfsBookkeeping {
@@ -122,6 +150,10 @@ impl BfsBookkeeping {
             node_id: start_id,
             depth: 0,
         });
+        let cost_search = config
+            .max_cost
+            .is_some()
+            .then(|| CostBoundedSearch::for_streaming(start_id, &config));
         Self {
             config,
             queue,
@@ -135,6 +167,7 @@ impl BfsBookkeeping {
             // Start at the threshold so an already-expired deadline aborts on
             // the first pop; otherwise the clock is read every N pops.
             nodes_since_check: DEADLINE_CHECK_INTERVAL,
+            cost_search,
         }
     }
 
@@ -163,6 +196,11 @@ impl BfsBookkeeping {
         true
     }
 
+    /// Returns `true` if `edge` passes the `valid_at` filter.
+    fn follows(&self, edge: &GraphEdge) -> bool {
+        self.config.valid_at.is_none_or(|t| edge.is_valid_at(t))
+    }
+
     /// Processes one candidate edge: applies the rel-type (when `label` is
     /// `Some`), depth, and visited filters, then on acceptance records the
     /// parent pointer, enqueues the target, and buffers a pending result.
@@ -230,6 +268,20 @@ impl BfsBookkeeping {
         }
         None
     }
+
+    /// Cost-bounded counterpart of `drive`: pulls the next-cheapest node
+    /// from the Dijkstra search. `edges` supplies a node's outgoing edges.
+    fn drive_cost<E: Borrow<GraphEdge>>(
+        &mut self,
+        edges: impl FnMut(u64) -> Vec<E>,
+    ) -> Option<TraversalResult> {
+        if self.config.limit.is_some_and(|limit| self.yielded >= limit) {
+            return None;
+        }
+        let result = self.cost_search.as_mut()?.next_with(edges)?;
+        self.yielded += 1;
+        Some(result)
+    }
 }
 
 /// Expands a node over the CSR zero-copy path (contiguous `&[u64]` neighbours).
@@ -248,6 +300,9 @@ fn expand_csr(edge_store: &EdgeStore, core: &mut BfsBookkeeping, state: &BfsStat
 /// Expands a node over the legacy `EdgeStore` path (owned `GraphEdge` values).
 fn expand_legacy(edge_store: &EdgeStore, core: &mut BfsBookkeeping, state: &BfsState) {
     for edge in edge_store.get_outgoing(state.node_id) {
+        if !core.follows(edge) {
+            continue;
+        }
         core.process_candidate(
             state.node_id,
             edge.target(),
@@ -264,7 +319,10 @@ fn expand_concurrent(
     core: &mut BfsBookkeeping,
     state: &BfsState,
 ) {
-    for edge in &edge_store.get_outgoing(state.node_id) {
+    for edge in &edge_store.get_outgoing_for_traversal(state.node_id) {
+        if !core.follows(edge) {
+            continue;
+        }
         core.process_candidate(
             state.node_id,
             edge.target(),
@@ -347,9 +405,13 @@ impl Iterator for BfsIterator<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let edge_store = self.edge_store;
+        if self.core.cost_search.is_some() {
+            return self.core.drive_cost(|node| edge_store.get_outgoing(node));
+        }
         // Dispatch: CSR zero-copy path when a snapshot exists, legacy otherwise.
+        // The snapshot carries no edge properties, so `valid_at` needs legacy.
         self.core.drive(|core, state| {
-            if edge_store.has_csr_snapshot() {
+            if edge_store.has_csr_snapshot() && core.config.valid_at.is_none() {
                 expand_csr(edge_store, core, state);
             } else {
                 expand_legacy(edge_store, core, state);
@@ -376,7 +438,7 @@ pub fn bfs_stream(
 ///
 /// Unlike [`BfsIterator`] (which borrows `&EdgeStore` and returns edge
 /// references), this iterator acquires per-shard read locks on each
-/// `get_outgoing()` call and works with owned `GraphEdge` values.
+/// `get_outgoing_for_traversal()` call and works with owned `GraphEdge` values.
 /// No shard lock is held across iterations, maximising concurrency.
 /// Uses parent-pointer map for zero-clone path reconstruction.
 pub struct ConcurrentBfsIterator<'a> {
@@ -404,6 +466,11 @@ impl Iterator for ConcurrentBfsIterator<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let edge_store = self.edge_store;
+        if self.core.cost_search.is_some() {
+            return self
+                .core
+                .drive_cost(|node| edge_store.get_outgoing_for_traversal(node));
+        }
         self.core
             .drive(|core, state| expand_concurrent(edge_store, core, state))
     }
@@ -420,4 +487,108 @@ pub fn concurrent_bfs_stream(
     ConcurrentBfsIterator::new(edge_store, start_id, config)
 }
 
+// ---------------------------------------------------------------------------
+// BfsCursor — resumable BFS with owned state (paged traversals)
+// ---------------------------------------------------------------------------
+
+/// Default visited-set bound for a [`BfsCursor`].
+///
+/// Paged traversals are meant to walk millions of nodes, so the bound is
+/// much higher than [`MAX_VISITED_SIZE`] (~32 MB for the visited set).
+pub const CURSOR_MAX_VISITED_SIZE: usize = 2_000_000;
+
+/// BFS over a [`ConcurrentEdgeStore`] that can be paused and resumed.
+///
+/// Unlike [`ConcurrentBfsIterator`], the cursor owns its frontier and borrows
+/// the edge store only for the duration of each [`next_page`](Self::next_page)
+/// call, so it can be stored between requests. Edges written between pages
+/// are seen wherever the traversal has not reached yet.
+pub struct BfsCursor {
+    core: BfsBookkeeping,
+    /// Results shallower than this are skipped.
+    min_depth: u32,
+    /// Result pulled by [`has_more`](Self::has_more) but not yet returned.
+    peeked: Option<TraversalResult>,
+}
+
+impl BfsCursor {
+    /// Creates a cursor positioned at `start_id`.
+    #[must_use]
+    pub fn new(start_id: u64, config: StreamingConfig) -> Self {
+        Self {
+            core: BfsBookkeeping::new(start_id, config),
+            min_depth: 0,
+            peeked: None,
+        }
+    }
+
+    /// Skips results shallower than `min_depth`.
+    #[must_use]
+    pub fn with_min_depth(mut self, min_depth: u32) -> Self {
+        self.min_depth = min_depth;
+        self
+    }
+
+    /// Returns up to `n` further results, resuming where the previous page
+    /// stopped.
+    pub fn next_page(
+        &mut self,
+        edge_store: &ConcurrentEdgeStore,
+        n: usize,
+    ) -> Vec<TraversalResult> {
+        let mut page = Vec::with_capacity(n.min(1024));
+        while page.len() < n {
+            let Some(result) = self.peeked.take().or_else(|| self.pull(edge_store)) else {
+                break;
+            };
+            page.push(result);
+        }
+        page
+    }
+
+    /// Whether the traversal has results left, expanding the frontier until
+    /// one is found or it is exhausted.
+    pub fn has_more(&mut self, edge_store: &ConcurrentEdgeStore) -> bool {
+        if self.peeked.is_none() {
+            self.peeked = self.pull(edge_store);
+        }
+        self.peeked.is_some()
+    }
+
+    /// Returns the number of results produced so far (including a result
+    /// held back by [`has_more`](Self::has_more)).
+    #[must_use]
+    pub fn yielded_count(&self) -> usize {
+        self.core.yielded
+    }
+
+    /// Returns true if the visited set has overflowed its limit, in which
+    /// case later pages may repeat nodes.
+    #[must_use]
+    pub fn is_visited_overflow(&self) -> bool {
+        self.core.visited_overflow
+    }
+
+    /// Returns the current size of the visited set.
+    #[must_use]
+    pub fn visited_size(&self) -> usize {
+        self.core.visited.len()
+    }
+
+    fn pull(&mut self, edge_store: &ConcurrentEdgeStore) -> Option<TraversalResult> {
+        loop {
+            let result = if self.core.cost_search.is_some() {
+                self.core
+                    .drive_cost(|node| edge_store.get_outgoing_for_traversal(node))
+            } else {
+                self.core
+                    .drive(|core, state| expand_concurrent(edge_store, core, state))
+            }?;
+            if result.depth >= self.min_depth {
+                return Some(result);
+            }
+        }
+    }
+}
+
 // Tests moved to streaming_tests.rs per project rules
@@ -1,7 +1,7 @@
 //! Tests for `streaming` module - Streaming BFS traversal.
 
 use super::streaming::*;
-use super::{EdgeStore, GraphEdge, DEFAULT_MAX_DEPTH};
+use super::{ConcurrentEdgeStore, EdgeStore, GraphEdge, DEFAULT_MAX_DEPTH};
 use std::time::{Duration, Instant};
 
 fn create_test_edge_store() -> EdgeStore {
@@ -372,3 +372,87 @@ fn test_streaming_parent_pointer_csr_path() {
         "CSR path: 1->2->3->4 via edges 100,101,102"
     );
 }
+
+// =========================================================================
+// BfsCursor — paged traversal
+// =========================================================================
+
+fn create_chain_concurrent_store(len: u64) -> ConcurrentEdgeStore {
+    let store = ConcurrentEdgeStore::new();
+    for i in 0..len {
+        store
+            .add_edge(GraphEdge::new(1000 + i, i, i + 1, "NEXT").unwrap())
+            .unwrap();
+    }
+    store
+}
+
+#[test]
+fn test_bfs_cursor_pages_match_single_pass() {
+    let store = create_chain_concurrent_store(25);
+    let config = StreamingConfig::default().with_max_depth(50);
+
+    let expected: Vec<u64> = concurrent_bfs_stream(&store, 0, config.clone())
+        .map(|r| r.target_id)
+        .collect();
+
+    let mut cursor = BfsCursor::new(0, config);
+    let mut paged = Vec::new();
+    loop {
+        let page = cursor.next_page(&store, 7);
+        paged.extend(page.iter().map(|r| r.target_id));
+        if !cursor.has_more(&store) {
+            break;
+        }
+        assert_eq!(page.len(), 7, "only the last page may be short");
+    }
+
+    assert_eq!(paged, expected);
+    assert_eq!(paged.len(), 25);
+    assert!(cursor.next_page(&store, 7).is_empty());
+}
+
+#[test]
+fn test_bfs_cursor_has_more_does_not_drop_results() {
+    let store = create_chain_concurrent_store(3);
+    let mut cursor = BfsCursor::new(0, StreamingConfig::default());
+
+    assert!(cursor.has_more(&store));
+    assert!(cursor.has_more(&store));
+    let page = cursor.next_page(&store, 10);
+    assert_eq!(
+        page.iter().map(|r| r.target_id).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert!(!cursor.has_more(&store));
+}
+
+#[test]
+fn test_bfs_cursor_min_depth_skips_shallow_results() {
+    let store = create_chain_concurrent_store(5);
+    let config = StreamingConfig::default().with_max_depth(10);
+    let mut cursor = BfsCursor::new(0, config).with_min_depth(3);
+
+    let page = cursor.next_page(&store, 10);
+    assert_eq!(
+        page.iter().map(|r| r.depth).collect::<Vec<_>>(),
+        vec![3, 4, 5]
+    );
+}
+
+#[test]
+fn test_bfs_cursor_sees_edges_added_between_pages() {
+    let store = create_chain_concurrent_store(2);
+    let mut cursor = BfsCursor::new(0, StreamingConfig::default());
+
+    assert_eq!(cursor.next_page(&store, 1)[0].target_id, 1);
+    store
+        .add_edge(GraphEdge::new(9000, 2, 3, "NEXT").unwrap())
+        .unwrap();
+    let rest: Vec<u64> = cursor
+        .next_page(&store, 10)
+        .iter()
+        .map(|r| r.target_id)
+        .collect();
+    assert_eq!(rest, vec![2, 3]);
+}
@@ -8,6 +8,7 @@
 //! For large graphs, the module provides streaming iterators that yield results
 //! lazily without loading all visited nodes into memory at once.
 
+use super::traversal_cost::CostBoundedSearch;
 use super::EdgeStore;
 use rustc_hash::{FxHashMap, FxHashSet};
 use smallvec::SmallVec;
@@ -134,6 +135,14 @@ pub struct TraversalConfig {
     /// convention as the `limit` / `MAX_VISITED_SIZE` guards). `None` (the
     /// default) disables the time bound entirely.
     pub deadline: Option<Instant>,
+    /// Only follow edges valid at this Unix timestamp (see
+    /// [`GraphEdge::is_valid_at`](super::GraphEdge::is_valid_at)). `None`
+    /// (the default) ignores edge validity windows.
+    pub valid_at: Option<i64>,
+    /// Maximum summed [`GraphEdge::weight`](super::GraphEdge::weight) of a
+    /// path. When set, traversal is cost-ordered (Dijkstra) and stops at this
+    /// budget; `None` (the default) bounds paths by hops only.
+    pub max_cost: Option<f64>,
 }
 
 impl Default for TraversalConfig {
@@ -144,6 +153,8 @@ impl Default for TraversalConfig {
             limit: 100,
             rel_types: Vec::new(),
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         }
     }
 }
@@ -202,6 +213,26 @@ impl TraversalConfig {
         self.deadline = Some(deadline);
         self
     }
+
+    /// Only follows edges whose validity window contains the Unix timestamp `t`.
+    #[must_use]
+    pub fn with_valid_at(mut self, t: i64) -> Self {
+        self.valid_at = Some(t);
+        self
+    }
+
+    /// Bounds paths by their summed edge weight instead of hops alone.
+    #[must_use]
+    pub fn with_max_cost(mut self, max_cost: f64) -> Self {
+        self.max_cost = Some(max_cost);
+        self
+    }
+
+    /// Returns `true` if `edge` passes the [`valid_at`](Self::valid_at) filter.
+    #[must_use]
+    pub(crate) fn follows(&self, edge: &super::GraphEdge) -> bool {
+        self.valid_at.is_none_or(|t| edge.is_valid_at(t))
+    }
 }
 
 /// BFS state for traversal (parent-pointer variant).
@@ -260,7 +291,8 @@ enum BfsDirection {
 ///
 /// For forward direction, uses CSR zero-copy path when snapshot exists,
 /// avoiding `GraphEdge` cloning. Reverse direction always uses legacy path
-/// since the CSR snapshot only covers outgoing edges.
+/// since the CSR snapshot only covers outgoing edges. With a `max_cost`
+/// budget the walk is cost-ordered instead (see [`CostBoundedSearch`]).
 #[must_use]
 fn bfs_traverse_directed(
     edge_store: &EdgeStore,
@@ -268,6 +300,17 @@ fn bfs_traverse_directed(
     config: &TraversalConfig,
     direction: BfsDirection,
 ) -> Vec<TraversalResult> {
+    if config.max_cost.is_some() {
+        let search = CostBoundedSearch::for_traversal(source_id, config);
+        return match direction {
+            BfsDirection::Forward => {
+                search.collect(|node| edge_store.get_outgoing(node), config.limit)
+            }
+            BfsDirection::Reverse => search
+                .reversed()
+                .collect(|node| edge_store.get_incoming(node), config.limit),
+        };
+    }
     let mut results = Vec::new();
     let mut visited = FxHashSet::default();
     let mut queue = VecDeque::new();
@@ -288,7 +331,10 @@ fn bfs_traverse_directed(
     });
 
     // Use CSR zero-copy path for forward traversal when snapshot exists.
-    let use_csr = direction == BfsDirection::Forward && edge_store.has_csr_snapshot();
+    // The snapshot carries no edge properties, so time filters need the edges.
+    let use_csr = direction == BfsDirection::Forward
+        && edge_store.has_csr_snapshot()
+        && config.valid_at.is_none();
 
     // Start at the threshold so an already-expired deadline aborts on the
     // first pop; otherwise the clock is only read every N pops.
@@ -412,6 +458,9 @@ fn process_bfs_neighbors(
         if !rel_filter.is_empty() && !rel_filter.contains(edge.label()) {
             continue;
         }
+        if !config.follows(edge) {
+            continue;
+        }
         let next_node = match direction {
             BfsDirection::Forward => edge.target(),
             BfsDirection::Reverse => edge.source(),
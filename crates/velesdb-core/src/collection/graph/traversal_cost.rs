@@ -0,0 +1,252 @@
+//! Cost-bounded traversal over weighted edges (Dijkstra).
+//!
+//! Hop limits treat every edge alike; on weighted graphs (road networks,
+//! latency or distance graphs) exploration is better bounded by the summed
+//! [`GraphEdge::weight`] of a path. [`CostBoundedSearch`] runs a uniform-cost
+//! search: nodes are reached in increasing cost order, each along its cheapest
+//! path, and edges that would exceed the budget are never followed.
+//!
+//! `max_depth` still applies. The search keeps one frontier state per
+//! `(node, hops)` pair that is not dominated by a cheaper, shorter one, so a
+//! node whose cheapest path is too long is still reached through a costlier
+//! path that fits the hop limit.
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::time::Instant;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::streaming::StreamingConfig;
+use super::traversal::{deadline_reached, DEADLINE_CHECK_INTERVAL};
+use super::{EdgeStore, GraphEdge, TraversalConfig, TraversalResult, MAX_VISITED_SIZE};
+
+/// A search state: a node reached by a specific path.
+struct CostState {
+    node: u64,
+    /// Index of the state this one was reached from (`None` for the source).
+    parent: Option<usize>,
+    edge_id: u64,
+    hops: u32,
+}
+
+/// Heap entry ordered so that `BinaryHeap` pops the cheapest state first
+/// (ties: earliest pushed).
+struct Frontier {
+    cost: f64,
+    state: usize,
+}
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.state.cmp(&self.state))
+    }
+}
+
+/// Incremental uniform-cost search from one source node.
+///
+/// Yields each reachable node once, cheapest first, until the cost budget,
+/// the hop limit, the state cap or the deadline stops it. Results never
+/// include the source itself.
+pub(crate) struct CostBoundedSearch {
+    max_cost: f64,
+    min_depth: u32,
+    max_depth: u32,
+    rel_types: FxHashSet<String>,
+    valid_at: Option<i64>,
+    deadline: Option<Instant>,
+    nodes_since_check: u32,
+    max_states: usize,
+    reverse: bool,
+    heap: BinaryHeap<Frontier>,
+    states: Vec<CostState>,
+    /// Fewest hops among the states of each node popped so far. States are
+    /// popped cheapest first, so a later state of the node is only useful
+    /// with strictly fewer hops.
+    settled_hops: FxHashMap<u64, u32>,
+    emitted: FxHashSet<u64>,
+}
+
+impl CostBoundedSearch {
+    /// Search bounded by `config` (`max_cost` unset means no cost budget).
+    pub(crate) fn for_traversal(source: u64, config: &TraversalConfig) -> Self {
+        let mut search = Self::new(source, config.max_cost, config.max_depth);
+        search.min_depth = config.min_depth;
+        search.rel_types = config.rel_types.iter().cloned().collect();
+        search.valid_at = config.valid_at;
+        search.deadline = config.deadline;
+        search
+    }
+
+    /// Search bounded by a streaming `config`.
+    pub(crate) fn for_streaming(source: u64, config: &StreamingConfig) -> Self {
+        let mut search = Self::new(source, config.max_cost, config.max_depth);
+        search.rel_types = config.rel_types.iter().cloned().collect();
+        search.valid_at = config.valid_at;
+        search.deadline = config.deadline;
+        search.max_states = config.max_visited_size;
+        search
+    }
+
+    fn new(source: u64, max_cost: Option<f64>, max_depth: u32) -> Self {
+        let mut heap = BinaryHeap::new();
+        heap.push(Frontier {
+            cost: 0.0,
+            state: 0,
+        });
+        let mut emitted = FxHashSet::default();
+        emitted.insert(source);
+        Self {
+            max_cost: max_cost.unwrap_or(f64::INFINITY),
+            min_depth: 1,
+            max_depth,
+            rel_types: FxHashSet::default(),
+            valid_at: None,
+            deadline: None,
+            // Start at the threshold so an already-expired deadline aborts on
+            // the first pop.
+            nodes_since_check: DEADLINE_CHECK_INTERVAL,
+            max_states: MAX_VISITED_SIZE,
+            reverse: false,
+            heap,
+            states: vec![CostState {
+                node: source,
+                parent: None,
+                edge_id: 0,
+                hops: 0,
+            }],
+            settled_hops: FxHashMap::default(),
+            emitted,
+        }
+    }
+
+    /// Follows edges against their direction (`edges` then yields incoming
+    /// edges and the search moves to their sources).
+    #[must_use]
+    pub(crate) fn reversed(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Runs the search to completion, returning at most `limit` results.
+    pub(crate) fn collect<E: Borrow<GraphEdge>>(
+        mut self,
+        mut edges: impl FnMut(u64) -> Vec<E>,
+        limit: usize,
+    ) -> Vec<TraversalResult> {
+        let mut results = Vec::new();
+        while results.len() < limit {
+            match self.next_with(&mut edges) {
+                Some(result) => results.push(result),
+                None => break,
+            }
+        }
+        results
+    }
+
+    /// Returns the next-cheapest node. `edges(node)` supplies the node's
+    /// outgoing edges (incoming when [`reversed`](Self::reversed)).
+    pub(crate) fn next_with<E: Borrow<GraphEdge>>(
+        &mut self,
+        mut edges: impl FnMut(u64) -> Vec<E>,
+    ) -> Option<TraversalResult> {
+        while let Some(Frontier { cost, state }) = self.heap.pop() {
+            if deadline_reached(self.deadline, &mut self.nodes_since_check) {
+                self.heap.clear();
+                return None;
+            }
+            let CostState { node, hops, .. } = self.states[state];
+            if self.settled_hops.get(&node).is_some_and(|&h| h <= hops) {
+                continue;
+            }
+            self.settled_hops.insert(node, hops);
+            if hops < self.max_depth {
+                for edge in edges(node) {
+                    self.relax(state, cost, hops, edge.borrow());
+                }
+            }
+            if hops >= self.min_depth && self.emitted.insert(node) {
+                return Some(TraversalResult::new(node, self.path_to(state), hops));
+            }
+        }
+        None
+    }
+
+    /// Queues the state reached from `state` through `edge`, if it passes
+    /// the filters and the budget.
+    fn relax(&mut self, state: usize, cost: f64, hops: u32, edge: &GraphEdge) {
+        if !self.rel_types.is_empty() && !self.rel_types.contains(edge.label()) {
+            return;
+        }
+        if self.valid_at.is_some_and(|t| !edge.is_valid_at(t)) {
+            return;
+        }
+        let next_cost = cost + edge.weight();
+        if next_cost > self.max_cost || self.states.len() >= self.max_states {
+            return;
+        }
+        let node = if self.reverse {
+            edge.source()
+        } else {
+            edge.target()
+        };
+        if self.settled_hops.get(&node).is_some_and(|&h| h <= hops + 1) {
+            return;
+        }
+        self.states.push(CostState {
+            node,
+            parent: Some(state),
+            edge_id: edge.id(),
+            hops: hops + 1,
+        });
+        self.heap.push(Frontier {
+            cost: next_cost,
+            state: self.states.len() - 1,
+        });
+    }
+
+    /// Edge ids from the source to `state`.
+    fn path_to(&self, mut state: usize) -> Vec<u64> {
+        let mut path = Vec::new();
+        while let Some(parent) = self.states[state].parent {
+            path.push(self.states[state].edge_id);
+            state = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Cost-ordered traversal from `source_id` (Dijkstra).
+///
+/// Returns the nodes whose cheapest path (within `max_depth` hops) costs at
+/// most `config.max_cost`, cheapest first, each with that path. Without
+/// `max_cost` every node within `max_depth` hops is returned, still in cost
+/// order.
+#[must_use]
+pub fn dijkstra_traverse(
+    edge_store: &EdgeStore,
+    source_id: u64,
+    config: &TraversalConfig,
+) -> Vec<TraversalResult> {
+    CostBoundedSearch::for_traversal(source_id, config)
+        .collect(|node| edge_store.get_outgoing(node), config.limit)
+}
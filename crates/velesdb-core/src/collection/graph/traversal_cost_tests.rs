@@ -0,0 +1,126 @@
+//! Tests for `traversal_cost` module - cost-bounded (Dijkstra) traversal.
+
+use super::edge_concurrent::ConcurrentEdgeStore;
+use super::streaming::{BfsIterator, ConcurrentBfsIterator, StreamingConfig};
+use super::traversal::{bfs_traverse, bfs_traverse_reverse};
+use super::traversal_cost::dijkstra_traverse;
+use super::{EdgeStore, GraphEdge, TraversalConfig, TraversalResult};
+use serde_json::json;
+
+fn weighted(id: u64, source: u64, target: u64, label: &str, weight: f64) -> GraphEdge {
+    GraphEdge::new(id, source, target, label)
+        .unwrap()
+        .with_properties([("weight".to_string(), json!(weight))].into())
+}
+
+/// Chain 1 -> 2 -> 3 -> 4 (weight 1 each), a costly shortcut 1 -> 4
+/// (weight 10), a cheap 1 -> 5 (weight 0.5) and an unweighted 2 -> 5.
+fn weighted_edges() -> Vec<GraphEdge> {
+    vec![
+        weighted(100, 1, 2, "ROAD", 1.0),
+        weighted(101, 2, 3, "ROAD", 1.0),
+        weighted(102, 3, 4, "ROAD", 1.0),
+        weighted(103, 1, 4, "ROAD", 10.0),
+        weighted(104, 1, 5, "ROAD", 0.5),
+        GraphEdge::new(105, 2, 5, "RAIL").unwrap(),
+    ]
+}
+
+fn create_weighted_edge_store() -> EdgeStore {
+    let mut store = EdgeStore::new();
+    for edge in weighted_edges() {
+        store.add_edge(edge).unwrap();
+    }
+    store
+}
+
+fn targets(results: &[TraversalResult]) -> Vec<u64> {
+    results.iter().map(|r| r.target_id).collect()
+}
+
+#[test]
+fn test_edge_weight_defaults_to_one() {
+    assert!((weighted(1, 1, 2, "R", 2.5).weight() - 2.5).abs() < f64::EPSILON);
+    assert!((weighted(1, 1, 2, "R", -1.0).weight() - 1.0).abs() < f64::EPSILON);
+    let unweighted = GraphEdge::new(1, 1, 2, "R").unwrap();
+    assert!((unweighted.weight() - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_dijkstra_returns_nodes_cheapest_first() {
+    let store = create_weighted_edge_store();
+    let results = dijkstra_traverse(&store, 1, &TraversalConfig::with_range(1, 5));
+
+    assert_eq!(targets(&results), vec![5, 2, 3, 4]);
+    // Node 4 is reached along the cheap three-hop chain, not the shortcut.
+    let node4 = &results[3];
+    assert_eq!(node4.path, vec![100, 101, 102]);
+    assert_eq!(node4.depth, 3);
+}
+
+#[test]
+fn test_max_cost_prunes_expensive_paths() {
+    let store = create_weighted_edge_store();
+    let config = TraversalConfig::with_range(1, 5).with_max_cost(2.0);
+
+    assert_eq!(targets(&bfs_traverse(&store, 1, &config)), vec![5, 2, 3]);
+    let config = TraversalConfig::with_range(1, 5).with_max_cost(0.1);
+    assert!(bfs_traverse(&store, 1, &config).is_empty());
+}
+
+#[test]
+fn test_max_cost_respects_max_depth() {
+    let store = create_weighted_edge_store();
+    // The cheapest path to 4 needs three hops; within two hops only the
+    // costly shortcut fits.
+    let config = TraversalConfig::with_range(1, 2).with_max_cost(20.0);
+    let results = bfs_traverse(&store, 1, &config);
+
+    assert_eq!(targets(&results), vec![5, 2, 3, 4]);
+    assert_eq!(results[3].path, vec![103]);
+    assert_eq!(results[3].depth, 1);
+}
+
+#[test]
+fn test_max_cost_with_min_depth_rel_types_and_limit() {
+    let store = create_weighted_edge_store();
+    let config = TraversalConfig::with_range(2, 5).with_max_cost(3.0);
+    assert_eq!(targets(&bfs_traverse(&store, 1, &config)), vec![3, 4]);
+
+    let config = TraversalConfig::with_range(1, 5)
+        .with_max_cost(3.0)
+        .with_rel_types(vec!["RAIL".to_string()]);
+    assert!(bfs_traverse(&store, 1, &config).is_empty());
+
+    let config = TraversalConfig::with_range(1, 5)
+        .with_max_cost(3.0)
+        .with_limit(2);
+    assert_eq!(targets(&bfs_traverse(&store, 1, &config)), vec![5, 2]);
+}
+
+#[test]
+fn test_max_cost_reverse_follows_incoming_edges() {
+    let store = create_weighted_edge_store();
+    let config = TraversalConfig::with_range(1, 5).with_max_cost(2.5);
+
+    let results = bfs_traverse_reverse(&store, 4, &config);
+    assert_eq!(targets(&results), vec![3, 2]);
+    assert_eq!(results[1].path, vec![102, 101]);
+}
+
+#[test]
+fn test_streaming_max_cost_matches_batch() {
+    let store = create_weighted_edge_store();
+    let config = StreamingConfig::default()
+        .with_max_depth(5)
+        .with_max_cost(2.0);
+    let streamed: Vec<_> = BfsIterator::new(&store, 1, config.clone()).collect();
+    assert_eq!(targets(&streamed), vec![5, 2, 3]);
+
+    let concurrent = ConcurrentEdgeStore::new();
+    for edge in weighted_edges() {
+        concurrent.add_edge(edge).unwrap();
+    }
+    let streamed: Vec<_> = ConcurrentBfsIterator::new(&concurrent, 1, config).collect();
+    assert_eq!(targets(&streamed), vec![5, 2, 3]);
+}
@@ -0,0 +1,214 @@
+//! Shortest paths by breadth-first search.
+//!
+//! Between two known nodes, [`bidirectional_shortest_path`] grows one BFS
+//! frontier from each end, always expanding the smaller one, and stops at
+//! the first level where they meet. Each side explores about half the path
+//! length, so on a graph with branching factor `b` a path of length `d`
+//! costs roughly `2·b^(d/2)` expansions instead of `b^d`.
+//!
+//! When only the source is known, [`bfs_shortest_paths`] reports each
+//! reachable node once, along a shortest path, in increasing distance order.
+//!
+//! Both searches move across an edge to its other endpoint, so the edge
+//! callbacks alone decide the direction: outgoing edges for a directed walk,
+//! incoming ones for a reverse walk, or both for an undirected one.
+
+use std::borrow::Borrow;
+use std::collections::hash_map::Entry;
+use std::ops::ControlFlow;
+
+use rustc_hash::FxHashMap;
+
+use super::traversal::{deadline_reached, DEADLINE_CHECK_INTERVAL};
+use super::{EdgeStore, GraphEdge, TraversalConfig, TraversalResult, MAX_VISITED_SIZE};
+
+/// One side of a level-synchronous BFS.
+struct BfsSide {
+    /// Reached node -> (parent, edge id, depth). The root maps to itself.
+    reached: FxHashMap<u64, (u64, u64, u32)>,
+    /// Nodes reached at `depth`, to be expanded next.
+    frontier: Vec<u64>,
+    depth: u32,
+    nodes_since_check: u32,
+}
+
+impl BfsSide {
+    fn new(root: u64) -> Self {
+        let mut reached = FxHashMap::default();
+        reached.insert(root, (root, 0, 0));
+        Self {
+            reached,
+            frontier: vec![root],
+            depth: 0,
+            // Start at the threshold so an already-expired deadline aborts on
+            // the first expansion.
+            nodes_since_check: DEADLINE_CHECK_INTERVAL,
+        }
+    }
+
+    /// Expands the whole frontier by one hop. Afterwards `frontier` holds
+    /// exactly the newly reached nodes; it is left empty when the deadline
+    /// or the [`MAX_VISITED_SIZE`] cap stops the search.
+    fn expand<E: Borrow<GraphEdge>>(
+        &mut self,
+        config: &TraversalConfig,
+        edges: &mut impl FnMut(u64) -> Vec<E>,
+    ) {
+        let depth = self.depth + 1;
+        let mut next = Vec::new();
+        for node in std::mem::take(&mut self.frontier) {
+            if deadline_reached(config.deadline, &mut self.nodes_since_check)
+                || self.reached.len() >= MAX_VISITED_SIZE
+            {
+                return;
+            }
+            for edge in edges(node) {
+                let edge = edge.borrow();
+                if !follows(config, edge) {
+                    continue;
+                }
+                let neighbor = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if let Entry::Vacant(slot) = self.reached.entry(neighbor) {
+                    slot.insert((node, edge.id(), depth));
+                    next.push(neighbor);
+                }
+            }
+        }
+        self.frontier = next;
+        self.depth = depth;
+    }
+
+    /// Edge ids from the root to a reached `node`, root first.
+    fn path_to(&self, mut node: u64) -> Vec<u64> {
+        let mut path = Vec::new();
+        while let Some(&(parent, edge_id, depth)) = self.reached.get(&node) {
+            if depth == 0 {
+                break;
+            }
+            path.push(edge_id);
+            node = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Returns `true` if `edge` passes the relationship-type and validity filters.
+fn follows(config: &TraversalConfig, edge: &GraphEdge) -> bool {
+    (config.rel_types.is_empty() || config.rel_types.iter().any(|t| t == edge.label()))
+        && config.follows(edge)
+}
+
+/// Shortest path from `source` to `target` by bidirectional BFS.
+///
+/// `forward(node)` yields the edges a path may leave `node` through and
+/// `backward(node)` the edges it may enter `node` through. Paths are at most
+/// `config.max_depth` hops; `config.rel_types`, `valid_at` and `deadline`
+/// apply, `limit` and `max_cost` do not. `config.min_depth` only matters when
+/// `source == target`: the empty path is returned if it is `0`, otherwise
+/// there is no result.
+///
+/// Returns `None` when no path fits, or when the deadline or the
+/// [`MAX_VISITED_SIZE`] cap stops the search first.
+#[must_use]
+pub(crate) fn bidirectional_bfs<E: Borrow<GraphEdge>>(
+    source: u64,
+    target: u64,
+    config: &TraversalConfig,
+    mut forward: impl FnMut(u64) -> Vec<E>,
+    mut backward: impl FnMut(u64) -> Vec<E>,
+) -> Option<TraversalResult> {
+    if source == target {
+        return (config.min_depth == 0).then(|| TraversalResult::new(target, Vec::new(), 0));
+    }
+    let mut from_source = BfsSide::new(source);
+    let mut from_target = BfsSide::new(target);
+    while from_source.depth + from_target.depth < config.max_depth
+        && !from_source.frontier.is_empty()
+        && !from_target.frontier.is_empty()
+    {
+        // No node was reached by both sides yet, so the shortest path is
+        // longer than both depths combined: any node the next level shares
+        // with the other side lies on a shortest path.
+        let meeting = if from_source.frontier.len() <= from_target.frontier.len() {
+            from_source.expand(config, &mut forward);
+            meeting_node(&from_source, &from_target)
+        } else {
+            from_target.expand(config, &mut backward);
+            meeting_node(&from_target, &from_source)
+        };
+        if let Some(node) = meeting {
+            let mut path = from_source.path_to(node);
+            let mut tail = from_target.path_to(node);
+            tail.reverse();
+            path.extend(tail);
+            let depth = u32::try_from(path.len()).unwrap_or(u32::MAX);
+            return Some(TraversalResult::new(target, path, depth));
+        }
+    }
+    None
+}
+
+/// First node of `side`'s new frontier already reached by `other`.
+fn meeting_node(side: &BfsSide, other: &BfsSide) -> Option<u64> {
+    side.frontier
+        .iter()
+        .copied()
+        .find(|node| other.reached.contains_key(node))
+}
+
+/// Single-source BFS reporting every reachable node once, along a shortest
+/// path, in increasing distance order.
+///
+/// `edges(node)` yields the edges a path may leave `node` through. Nodes
+/// within `config.min_depth..=config.max_depth` hops are passed to `visit`
+/// (the source itself when `min_depth` is `0`); returning
+/// [`ControlFlow::Break`] stops the search. `config.limit` does not apply.
+pub(crate) fn bfs_shortest_paths<E: Borrow<GraphEdge>>(
+    source: u64,
+    config: &TraversalConfig,
+    mut edges: impl FnMut(u64) -> Vec<E>,
+    mut visit: impl FnMut(TraversalResult) -> ControlFlow<()>,
+) {
+    if config.min_depth == 0 && visit(TraversalResult::new(source, Vec::new(), 0)).is_break() {
+        return;
+    }
+    let mut side = BfsSide::new(source);
+    while side.depth < config.max_depth && !side.frontier.is_empty() {
+        side.expand(config, &mut edges);
+        if side.depth < config.min_depth {
+            continue;
+        }
+        for &node in &side.frontier {
+            if visit(TraversalResult::new(node, side.path_to(node), side.depth)).is_break() {
+                return;
+            }
+        }
+    }
+}
+
+/// Shortest directed path from `source` to `target` (bidirectional BFS).
+///
+/// Paths are at most `config.max_depth` hops and follow only edges passing
+/// `config.rel_types` and `valid_at`; `limit` and `max_cost` do not apply.
+/// When `source == target` the empty path is returned if `config.min_depth`
+/// is `0`. Returns `None` when no path fits or the deadline expires first.
+#[must_use]
+pub fn bidirectional_shortest_path(
+    edge_store: &EdgeStore,
+    source: u64,
+    target: u64,
+    config: &TraversalConfig,
+) -> Option<TraversalResult> {
+    bidirectional_bfs(
+        source,
+        target,
+        config,
+        |node| edge_store.get_outgoing(node),
+        |node| edge_store.get_incoming(node),
+    )
+}
@@ -0,0 +1,211 @@
+//! Tests for `traversal_shortest` module - BFS shortest paths.
+
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+
+use super::traversal::bfs_traverse;
+use super::traversal_shortest::{
+    bfs_shortest_paths, bidirectional_bfs, bidirectional_shortest_path,
+};
+use super::{EdgeStore, GraphEdge, TraversalConfig, TraversalResult};
+
+fn store_with(edges: &[(u64, u64, u64, &str)]) -> EdgeStore {
+    let mut store = EdgeStore::new();
+    for &(id, source, target, label) in edges {
+        store
+            .add_edge(GraphEdge::new(id, source, target, label).unwrap())
+            .unwrap();
+    }
+    store
+}
+
+/// Chain 1 -> 2 -> 3 -> 4 -> 5, a shortcut 2 -> 4 and a detour
+/// 1 -> 6 -> 7 -> 5 over `RAIL` edges.
+fn create_shortcut_store() -> EdgeStore {
+    store_with(&[
+        (100, 1, 2, "ROAD"),
+        (101, 2, 3, "ROAD"),
+        (102, 3, 4, "ROAD"),
+        (103, 4, 5, "ROAD"),
+        (104, 2, 4, "ROAD"),
+        (105, 1, 6, "RAIL"),
+        (106, 6, 7, "RAIL"),
+        (107, 7, 5, "RAIL"),
+    ])
+}
+
+/// Binary tree of the given depth: node `n` links to `2n` and `2n + 1`.
+fn create_binary_tree(depth: u32) -> EdgeStore {
+    let mut store = EdgeStore::new();
+    for node in 1..(1u64 << depth) {
+        for child in [2 * node, 2 * node + 1] {
+            store
+                .add_edge(GraphEdge::new(child, node, child, "CHILD").unwrap())
+                .unwrap();
+        }
+    }
+    store
+}
+
+#[test]
+fn test_bidirectional_finds_shortest_path() {
+    let store = create_shortcut_store();
+
+    let result = bidirectional_shortest_path(&store, 1, 5, &TraversalConfig::with_range(1, 10))
+        .expect("path exists");
+
+    assert_eq!(result.target_id, 5);
+    assert_eq!(result.depth, 3);
+    assert_eq!(result.path.len(), 3);
+}
+
+#[test]
+fn test_bidirectional_path_is_contiguous() {
+    let store = create_shortcut_store();
+
+    let result = bidirectional_shortest_path(
+        &store,
+        1,
+        5,
+        &TraversalConfig::with_range(1, 10).with_rel_types(vec!["ROAD".to_string()]),
+    )
+    .expect("path exists");
+
+    assert_eq!(result.path, vec![100, 104, 103]);
+}
+
+#[test]
+fn test_bidirectional_honours_direction() {
+    let store = create_shortcut_store();
+
+    assert!(
+        bidirectional_shortest_path(&store, 5, 1, &TraversalConfig::with_range(1, 10)).is_none()
+    );
+}
+
+#[test]
+fn test_bidirectional_respects_max_depth() {
+    let store = create_shortcut_store();
+
+    assert!(
+        bidirectional_shortest_path(&store, 1, 5, &TraversalConfig::with_range(1, 2)).is_none()
+    );
+    let result = bidirectional_shortest_path(&store, 1, 5, &TraversalConfig::with_range(1, 3))
+        .expect("three hops fit");
+    assert_eq!(result.depth, 3);
+}
+
+#[test]
+fn test_bidirectional_rel_type_filter_changes_route() {
+    let store = create_shortcut_store();
+
+    let result = bidirectional_shortest_path(
+        &store,
+        1,
+        5,
+        &TraversalConfig::with_range(1, 10).with_rel_types(vec!["RAIL".to_string()]),
+    )
+    .expect("rail path exists");
+
+    assert_eq!(result.path, vec![105, 106, 107]);
+}
+
+#[test]
+fn test_bidirectional_same_endpoints() {
+    let store = create_shortcut_store();
+
+    assert!(
+        bidirectional_shortest_path(&store, 1, 1, &TraversalConfig::with_range(1, 3)).is_none()
+    );
+    let result = bidirectional_shortest_path(&store, 1, 1, &TraversalConfig::with_range(0, 3))
+        .expect("empty path");
+    assert!(result.path.is_empty());
+    assert_eq!(result.depth, 0);
+}
+
+#[test]
+fn test_bidirectional_undirected_walk() {
+    let store = create_shortcut_store();
+    let both = |node: u64| {
+        let mut edges = store.get_outgoing(node);
+        edges.extend(store.get_incoming(node));
+        edges
+    };
+
+    let config = TraversalConfig::with_range(1, 10).with_rel_types(vec!["ROAD".to_string()]);
+
+    let result = bidirectional_bfs(5, 1, &config, both, both).expect("undirected path exists");
+
+    assert_eq!(result.depth, 3);
+    assert_eq!(result.path, vec![103, 104, 100]);
+}
+
+#[test]
+fn test_bidirectional_matches_bfs_depth_on_tree() {
+    let store = create_binary_tree(8);
+    let config = TraversalConfig::with_range(1, 10).with_limit(usize::MAX);
+
+    for target in [2, 9, 77, 300, 511] {
+        let expected = bfs_traverse(&store, 1, &config)
+            .into_iter()
+            .find(|r| r.target_id == target)
+            .expect("reachable in the tree");
+        let result = bidirectional_shortest_path(&store, 1, target, &config).expect("path exists");
+        assert_eq!(result.depth, expected.depth, "target {target}");
+        assert_eq!(result.path, expected.path, "target {target}");
+    }
+}
+
+#[test]
+fn test_bidirectional_expired_deadline_returns_none() {
+    let store = create_shortcut_store();
+    let config = TraversalConfig::with_range(1, 10)
+        .with_deadline(Instant::now().checked_sub(Duration::from_secs(1)).unwrap());
+
+    assert!(bidirectional_shortest_path(&store, 1, 5, &config).is_none());
+}
+
+#[test]
+fn test_bfs_shortest_paths_reports_each_node_once_by_distance() {
+    let store = create_shortcut_store();
+    let mut reached: Vec<TraversalResult> = Vec::new();
+
+    bfs_shortest_paths(
+        1,
+        &TraversalConfig::with_range(1, 10),
+        |node| store.get_outgoing(node),
+        |result| {
+            reached.push(result);
+            ControlFlow::Continue(())
+        },
+    );
+
+    let depths: Vec<(u64, u32)> = reached.iter().map(|r| (r.target_id, r.depth)).collect();
+    assert_eq!(reached.len(), 6);
+    assert!(depths.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert!(depths.contains(&(4, 2)));
+    assert!(depths.contains(&(5, 3)));
+}
+
+#[test]
+fn test_bfs_shortest_paths_min_depth_and_break() {
+    let store = create_shortcut_store();
+    let mut reached = Vec::new();
+
+    bfs_shortest_paths(
+        1,
+        &TraversalConfig::with_range(0, 10),
+        |node| store.get_outgoing(node),
+        |result| {
+            reached.push(result.target_id);
+            if reached.len() == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    );
+
+    assert_eq!(reached[0], 1, "min_depth 0 reports the source first");
+    assert_eq!(reached.len(), 2);
+}
@@ -476,3 +476,27 @@ fn test_parent_pointer_cyclic_graph_shortest_path() {
         .expect("test: node 3");
     assert_eq!(node3.path, vec![100, 101], "1->2->3 via edges 100,101");
 }
+
+#[test]
+fn test_bfs_valid_at_bypasses_csr_snapshot() {
+    let mut store = EdgeStore::new();
+    store
+        .add_edge(
+            GraphEdge::new(100, 1, 2, "KNOWS")
+                .unwrap()
+                .with_validity(None, Some(50)),
+        )
+        .unwrap();
+    store
+        .add_edge(GraphEdge::new(101, 2, 3, "KNOWS").unwrap())
+        .unwrap();
+    store.build_read_snapshot();
+
+    let config = TraversalConfig::with_range(1, 3);
+    assert_eq!(bfs_traverse(&store, 1, &config).len(), 2);
+    assert_eq!(
+        bfs_traverse(&store, 1, &config.clone().with_valid_at(49)).len(),
+        2
+    );
+    assert!(bfs_traverse(&store, 1, &config.with_valid_at(50)).is_empty());
+}
@@ -10,7 +10,10 @@
 
 use std::path::PathBuf;
 
-use crate::collection::graph::{GraphEdge, GraphSchema, TraversalConfig, TraversalResult};
+use crate::collection::graph::{
+    BfsCursor, GraphEdge, GraphFormat, GraphImportStats, GraphSchema, GraphStats, TraversalConfig,
+    TraversalResult,
+};
 use crate::collection::types::Collection;
 use crate::distance::DistanceMetric;
 use crate::error::Result;
@@ -249,7 +252,9 @@ impl GraphCollection {
         batch_size: usize,
         filter: Option<&crate::filter::Filter>,
     ) -> Result<crate::collection::ScrollBatch> {
-        self.inner.scroll_batch(cursor, batch_size, filter)
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner
+            .scroll_batch(cursor, batch_size, filter.as_deref())
     }
 
     /// Returns the number of nodes (points) stored in this collection.
@@ -295,6 +300,41 @@ impl GraphCollection {
         self.inner.edge_exists(edge_id)
     }
 
+    /// Keeps edge properties in an on-disk log instead of RAM (or moves them
+    /// back with `false`), and persists the choice to `config.json`.
+    ///
+    /// Only the edge topology (id, endpoints, label) stays resident, so
+    /// property-heavy graphs need far less memory; returning edges costs one
+    /// positional read per edge with properties.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the properties cannot be moved or the config
+    /// cannot be persisted.
+    pub fn set_edge_properties_on_disk(&self, on_disk: bool) -> Result<()> {
+        self.inner.set_edge_properties_on_disk(on_disk)
+    }
+
+    /// Returns `true` when edge properties are kept on disk.
+    #[must_use]
+    pub fn edge_properties_on_disk(&self) -> bool {
+        self.inner.edge_properties_on_disk()
+    }
+
+    /// Rejects a traversal or query `limit` above the collection's
+    /// `max_query_limit`.
+    ///
+    /// The traversal methods cap `config.limit` silently; callers that want
+    /// an explicit error check it first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::QueryLimitExceeded`](crate::Error::QueryLimitExceeded)
+    /// when `limit` is too large.
+    pub fn check_query_limit(&self, limit: u64) -> Result<()> {
+        self.inner.check_query_limit(limit)
+    }
+
     /// Performs BFS traversal from a source node.
     ///
     /// # Examples
@@ -315,6 +355,43 @@ impl GraphCollection {
         self.inner.traverse_bfs_config(source_id, config)
     }
 
+    /// Starts a resumable BFS from a source node, paged with
+    /// [`Self::next_traversal_page`].
+    ///
+    /// `config.limit` is ignored; every page sets its own size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{GraphCollection, GraphSchema, DistanceMetric};
+    /// # use velesdb_core::collection::graph::TraversalConfig;
+    /// # let coll = GraphCollection::create("./data/kg".into(), "kg", None, DistanceMetric::Cosine, GraphSchema::schemaless())?;
+    /// let config = TraversalConfig { max_depth: 10, ..TraversalConfig::default() };
+    /// let mut cursor = coll.traversal_cursor(100, &config);
+    /// loop {
+    ///     let (page, has_more) = coll.next_traversal_page(&mut cursor, 1000);
+    ///     println!("{} nodes", page.len());
+    ///     if !has_more {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    #[must_use]
+    pub fn traversal_cursor(&self, source_id: u64, config: &TraversalConfig) -> BfsCursor {
+        self.inner.traversal_cursor(source_id, config)
+    }
+
+    /// Returns the next page of at most `limit` results from `cursor`, and
+    /// whether more results remain.
+    pub fn next_traversal_page(
+        &self,
+        cursor: &mut BfsCursor,
+        limit: usize,
+    ) -> (Vec<TraversalResult>, bool) {
+        self.inner.next_traversal_page(cursor, limit)
+    }
+
     /// Performs DFS traversal from a source node.
     #[must_use]
     pub fn traverse_dfs(&self, source_id: u64, config: &TraversalConfig) -> Vec<TraversalResult> {
@@ -349,6 +426,55 @@ impl GraphCollection {
         self.inner.traverse_bfs_parallel(start_nodes, config)
     }
 
+    /// Finds the shortest directed path between two nodes (bidirectional BFS).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{GraphCollection, GraphSchema, DistanceMetric};
+    /// # use velesdb_core::collection::graph::TraversalConfig;
+    /// # let coll = GraphCollection::create("./data/kg".into(), "kg", None, DistanceMetric::Cosine, GraphSchema::schemaless())?;
+    /// if let Some(path) = coll.shortest_path(100, 200, &TraversalConfig::with_range(1, 6)) {
+    ///     println!("{} hops via edges {:?}", path.depth, path.path);
+    /// }
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    #[must_use]
+    pub fn shortest_path(
+        &self,
+        source_id: u64,
+        target_id: u64,
+        config: &TraversalConfig,
+    ) -> Option<TraversalResult> {
+        self.inner.shortest_path(source_id, target_id, config)
+    }
+
+    /// Serializes the graph (node payloads and edges) as GraphML or JSON Lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a node payload cannot be read.
+    pub fn export_graph(&self, format: GraphFormat) -> Result<String> {
+        self.inner.export_graph(format)
+    }
+
+    /// Imports nodes and edges from a GraphML or JSON Lines document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is malformed, an edge endpoint is
+    /// missing, or storage fails.
+    pub fn import_graph(&self, format: GraphFormat, data: &str) -> Result<GraphImportStats> {
+        self.inner.import_graph(format, data)
+    }
+
+    /// Returns node/edge counts per label, the degree histogram and the
+    /// highest-degree nodes.
+    #[must_use]
+    pub fn graph_stats(&self) -> GraphStats {
+        self.inner.graph_stats()
+    }
+
     // -------------------------------------------------------------------------
     // Payload / node properties
     // -------------------------------------------------------------------------
@@ -412,6 +538,7 @@ impl GraphCollection {
     /// Returns `Error::VectorNotAllowed` if this collection has no embeddings,
     /// or `Error::DimensionMismatch` if the query dimension is wrong.
     pub fn search_by_embedding(&self, query: &[f32], k: usize) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(crate::collection::AdmissionKind::Search)?;
         self.inner.search_by_embedding(query, k)
     }
 
@@ -556,6 +683,7 @@ mod tests {
         let match_clause = crate::velesql::MatchClause {
             patterns: vec![crate::velesql::GraphPattern {
                 name: None,
+                shortest_path: false,
                 nodes: vec![
                     crate::velesql::NodePattern::new().with_alias("a"),
                     crate::velesql::NodePattern::new().with_alias("b"),
@@ -569,6 +697,7 @@ mod tests {
                 items: vec![],
                 order_by: None,
                 limit: Some(10),
+                limit_per: None,
             },
         };
 
@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 
 use crate::collection::search::query::match_exec::MatchResult;
+use crate::collection::AdmissionKind;
 use crate::error::Result;
 use crate::point::SearchResult;
 
@@ -61,6 +62,7 @@ impl GraphCollection {
         match_clause: &crate::velesql::MatchClause,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<Vec<MatchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Scan)?;
         self.inner.execute_match(match_clause, params)
     }
 
@@ -76,6 +78,7 @@ impl GraphCollection {
         similarity_threshold: f32,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<Vec<MatchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Scan)?;
         self.inner.execute_match_with_similarity(
             match_clause,
             query_vector,
@@ -100,6 +103,7 @@ impl GraphCollection {
         match_clause: &crate::velesql::MatchClause,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<Vec<MatchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Scan)?;
         self.inner.match_query_ordered(match_clause, params)
     }
 }
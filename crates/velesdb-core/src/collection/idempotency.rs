@@ -0,0 +1,236 @@
+//! Request idempotency keys.
+//!
+//! A client that retries a write after a network failure cannot tell whether
+//! the first attempt was applied. Tagging the write with an idempotency key
+//! lets the collection recognise the retry: the first request with a key
+//! claims it, and its response is recorded once it succeeds; a later request
+//! carrying the same key and the same body replays that response instead of
+//! writing again.
+//!
+//! Keys are remembered for [`IDEMPOTENCY_WINDOW`] and at most
+//! [`MAX_IDEMPOTENCY_KEYS`] per collection (oldest evicted first). The log is
+//! persisted in `idempotency.json` next to `config.json`, so a replay is
+//! still recognised after a restart.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::collection::expiry::now_unix_secs;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+
+/// How long a recorded response is replayed for its key.
+pub const IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Maximum number of keys remembered per collection.
+pub const MAX_IDEMPOTENCY_KEYS: usize = 10_000;
+
+/// Maximum length of an idempotency key, in bytes.
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// File holding the recorded responses, in the collection directory.
+const IDEMPOTENCY_FILE: &str = "idempotency.json";
+
+/// Response recorded for an idempotency key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdempotentResponse {
+    /// Status code of the original response (HTTP status for the server).
+    pub status: u16,
+    /// Body of the original response.
+    pub body: serde_json::Value,
+}
+
+/// Result of [`AnyCollection::claim_idempotency_key`](crate::AnyCollection::claim_idempotency_key).
+#[derive(Debug)]
+pub enum IdempotencyOutcome {
+    /// First use of the key: run the request, then record its response with
+    /// [`IdempotencyClaim::complete`].
+    Claimed(IdempotencyClaim),
+    /// The key already completed with the same request: return this response
+    /// without running the request again.
+    Replay(IdempotentResponse),
+    /// Another request with this key is still running.
+    InFlight,
+    /// The key was already used for a different request.
+    KeyReused,
+}
+
+/// Exclusive claim on an idempotency key.
+///
+/// Dropping the claim without calling [`complete`](Self::complete) — the
+/// request failed — releases the key so a retry runs the request again.
+#[derive(Debug)]
+pub struct IdempotencyClaim {
+    log: IdempotencyLog,
+    path: PathBuf,
+    key: String,
+    fingerprint: u64,
+    completed: bool,
+}
+
+impl IdempotencyClaim {
+    /// Records `response` for the key and persists the log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be written; the key is released.
+    pub fn complete(mut self, response: IdempotentResponse) -> Result<()> {
+        self.completed = true;
+        let entry = Entry {
+            fingerprint: self.fingerprint,
+            recorded_at: now_unix_secs(),
+            response,
+        };
+        self.log.complete(&self.path, &self.key, entry)
+    }
+}
+
+impl Drop for IdempotencyClaim {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.log.state.lock().in_flight.remove(&self.key);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    fingerprint: u64,
+    recorded_at: u64,
+    response: IdempotentResponse,
+}
+
+#[derive(Debug, Default)]
+struct LogState {
+    /// Whether `entries` has been read from disk yet.
+    loaded: bool,
+    entries: HashMap<String, Entry>,
+    in_flight: HashSet<String>,
+}
+
+impl LogState {
+    fn load(&mut self, path: &Path) {
+        if self.loaded {
+            return;
+        }
+        self.loaded = true;
+        let Ok(bytes) = std::fs::read(path) else {
+            return;
+        };
+        match serde_json::from_slice(&bytes) {
+            Ok(entries) => self.entries = entries,
+            // Losing the log only disables replay for the old keys.
+            Err(e) => tracing::warn!(
+                path = %path.display(),
+                error = %e,
+                "ignoring unreadable idempotency log"
+            ),
+        }
+    }
+
+    /// Drops expired entries, then the oldest ones beyond the key limit
+    /// (keeping room for one more).
+    fn prune(&mut self, now: u64) {
+        let window = IDEMPOTENCY_WINDOW.as_secs();
+        self.entries
+            .retain(|_, entry| now.saturating_sub(entry.recorded_at) < window);
+        let excess = (self.entries.len() + 1).saturating_sub(MAX_IDEMPOTENCY_KEYS);
+        if excess > 0 {
+            let mut by_age: Vec<(u64, String)> = self
+                .entries
+                .iter()
+                .map(|(key, entry)| (entry.recorded_at, key.clone()))
+                .collect();
+            by_age.sort_unstable();
+            for (_, key) in by_age.into_iter().take(excess) {
+                self.entries.remove(&key);
+            }
+        }
+    }
+}
+
+/// A collection's idempotency log, shared by its clones.
+///
+/// Loaded from disk on first use. The mutex is a leaf: it is held while the
+/// log is read or written, never while the request runs.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct IdempotencyLog {
+    state: Arc<Mutex<LogState>>,
+}
+
+impl IdempotencyLog {
+    fn claim(&self, path: &Path, key: &str, fingerprint: u64, now: u64) -> IdempotencyOutcome {
+        let mut state = self.state.lock();
+        state.load(path);
+        if state.in_flight.contains(key) {
+            return IdempotencyOutcome::InFlight;
+        }
+        if let Some(entry) = state.entries.get(key) {
+            let window = IDEMPOTENCY_WINDOW.as_secs();
+            if now.saturating_sub(entry.recorded_at) < window {
+                return if entry.fingerprint == fingerprint {
+                    IdempotencyOutcome::Replay(entry.response.clone())
+                } else {
+                    IdempotencyOutcome::KeyReused
+                };
+            }
+        }
+        state.in_flight.insert(key.to_string());
+        IdempotencyOutcome::Claimed(IdempotencyClaim {
+            log: self.clone(),
+            path: path.to_path_buf(),
+            key: key.to_string(),
+            fingerprint,
+            completed: false,
+        })
+    }
+
+    fn complete(&self, path: &Path, key: &str, entry: Entry) -> Result<()> {
+        let mut state = self.state.lock();
+        state.in_flight.remove(key);
+        state.prune(entry.recorded_at);
+        state.entries.insert(key.to_string(), entry);
+        let bytes =
+            serde_json::to_vec(&state.entries).map_err(|e| Error::Serialization(e.to_string()))?;
+        crate::storage::atomic_write::atomic_write(path, &bytes)?;
+        Ok(())
+    }
+}
+
+impl Collection {
+    /// Claims `key` for a write whose full request (method, target and
+    /// body) is `request`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `key` is empty, longer than
+    /// [`MAX_IDEMPOTENCY_KEY_LEN`] or not printable ASCII.
+    pub(crate) fn claim_idempotency_key(
+        &self,
+        key: &str,
+        request: &[u8],
+    ) -> Result<IdempotencyOutcome> {
+        validate_key(key)?;
+        let fingerprint = crate::wire::stable_hash::hash_id_bytes(request);
+        let path = self.storage.path.join(IDEMPOTENCY_FILE);
+        Ok(self
+            .runtime
+            .idempotency
+            .claim(&path, key, fingerprint, now_unix_secs()))
+    }
+}
+
+fn validate_key(key: &str) -> Result<()> {
+    let printable = key.bytes().all(|b| b.is_ascii_graphic());
+    if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LEN || !printable {
+        return Err(Error::Config(format!(
+            "idempotency key must be 1-{MAX_IDEMPOTENCY_KEY_LEN} printable ASCII characters"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Tests for request idempotency keys.
+
+use std::path::Path;
+
+use crate::collection::idempotency::{IDEMPOTENCY_WINDOW, MAX_IDEMPOTENCY_KEY_LEN};
+use crate::collection::{
+    AnyCollection, IdempotencyOutcome, IdempotentResponse, MetadataCollection, VectorCollection,
+};
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::quantization::StorageMode;
+
+fn create(path: &Path) -> AnyCollection {
+    AnyCollection::Vector(
+        VectorCollection::create(
+            path.to_path_buf(),
+            "c",
+            2,
+            DistanceMetric::Cosine,
+            StorageMode::Full,
+        )
+        .expect("create collection"),
+    )
+}
+
+fn response(count: u64) -> IdempotentResponse {
+    IdempotentResponse {
+        status: 200,
+        body: serde_json::json!({ "count": count }),
+    }
+}
+
+fn claim_and_complete(col: &AnyCollection, key: &str, request: &[u8], count: u64) {
+    match col.claim_idempotency_key(key, request).expect("claim") {
+        IdempotencyOutcome::Claimed(claim) => claim.complete(response(count)).expect("complete"),
+        other => panic!("expected a fresh claim, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_completed_key_replays_the_original_response() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = create(&dir.path().join("c"));
+    claim_and_complete(&col, "batch-1", b"POST /points {..}", 3);
+
+    let outcome = col
+        .claim_idempotency_key("batch-1", b"POST /points {..}")
+        .expect("claim");
+
+    match outcome {
+        IdempotencyOutcome::Replay(recorded) => assert_eq!(recorded, response(3)),
+        other => panic!("expected a replay, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_key_reused_for_another_request_is_reported() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = create(&dir.path().join("c"));
+    claim_and_complete(&col, "batch-1", b"first body", 1);
+
+    let outcome = col
+        .claim_idempotency_key("batch-1", b"second body")
+        .expect("claim");
+
+    assert!(
+        matches!(outcome, IdempotencyOutcome::KeyReused),
+        "{outcome:?}"
+    );
+}
+
+#[test]
+fn test_running_key_is_in_flight_and_dropped_claim_releases_it() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = create(&dir.path().join("c"));
+
+    let first = col.claim_idempotency_key("k", b"req").expect("claim");
+    assert!(matches!(first, IdempotencyOutcome::Claimed(_)));
+    let second = col.claim_idempotency_key("k", b"req").expect("claim");
+    assert!(matches!(second, IdempotencyOutcome::InFlight), "{second:?}");
+
+    drop(first);
+    let retry = col.claim_idempotency_key("k", b"req").expect("claim");
+    assert!(matches!(retry, IdempotencyOutcome::Claimed(_)), "{retry:?}");
+}
+
+#[test]
+fn test_log_survives_reopen_and_expires_after_the_window() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("c");
+    let col = create(&path);
+    claim_and_complete(&col, "old", b"req", 1);
+    claim_and_complete(&col, "new", b"req", 2);
+    col.flush().expect("flush");
+    drop(col);
+
+    // Age the "old" entry past the window.
+    let log_path = path.join("idempotency.json");
+    let mut log: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&log_path).expect("read log")).expect("parse log");
+    let recorded_at = log["old"]["recorded_at"].as_u64().expect("recorded_at");
+    log["old"]["recorded_at"] = (recorded_at - IDEMPOTENCY_WINDOW.as_secs()).into();
+    std::fs::write(&log_path, serde_json::to_vec(&log).expect("encode")).expect("write log");
+
+    let col = AnyCollection::Vector(VectorCollection::open(path).expect("reopen"));
+    let replay = col.claim_idempotency_key("new", b"req").expect("claim");
+    assert!(matches!(replay, IdempotencyOutcome::Replay(ref r) if *r == response(2)));
+    let expired = col.claim_idempotency_key("old", b"req").expect("claim");
+    assert!(
+        matches!(expired, IdempotencyOutcome::Claimed(_)),
+        "{expired:?}"
+    );
+}
+
+#[test]
+fn test_invalid_keys_are_rejected() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = AnyCollection::Metadata(
+        MetadataCollection::create(dir.path().join("m"), "m").expect("create"),
+    );
+    let too_long = "k".repeat(MAX_IDEMPOTENCY_KEY_LEN + 1);
+
+    for key in ["", "has space", too_long.as_str()] {
+        let err = col.claim_idempotency_key(key, b"req").expect_err(key);
+        assert!(matches!(err, Error::Config(_)), "{err:?}");
+    }
+}
@@ -0,0 +1,154 @@
+//! Index build progress events.
+//!
+//! A collection reports the phases of long index builds — storing a bulk
+//! batch, linking it into the HNSW layers, flushing to disk — to a callback
+//! registered with [`VectorCollection::on_progress`](crate::VectorCollection::on_progress).
+//! Bulk upserts, `flush` and online reindex all report through it.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use serde::Serialize;
+
+/// Number of progress events reported while linking a batch into HNSW.
+pub(crate) const LINK_PROGRESS_STEPS: usize = 10;
+
+/// Phase of an index build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum IndexBuildPhase {
+    /// Writing vectors and payloads to storage (WAL + mmap).
+    StoringVectors,
+    /// Inserting vectors into the HNSW graph layers.
+    LinkingLayers,
+    /// Persisting storage and the HNSW graph to disk.
+    Flushing,
+}
+
+impl IndexBuildPhase {
+    /// Snake-case name, as serialized.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::StoringVectors => "storing_vectors",
+            Self::LinkingLayers => "linking_layers",
+            Self::Flushing => "flushing",
+        }
+    }
+}
+
+/// One progress report: `processed` of `total` units done in `phase`.
+///
+/// Units are vectors for `StoringVectors` and `LinkingLayers`, and flush
+/// steps for `Flushing`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct IndexProgress {
+    /// Current phase.
+    pub phase: IndexBuildPhase,
+    /// Units completed in this phase.
+    pub processed: usize,
+    /// Units the phase will process in total.
+    pub total: usize,
+    /// Completion of this phase (0-100).
+    pub percent: u8,
+}
+
+impl IndexProgress {
+    /// Builds a report; an empty phase counts as complete.
+    #[must_use]
+    pub fn new(phase: IndexBuildPhase, processed: usize, total: usize) -> Self {
+        let percent = if total == 0 {
+            100
+        } else {
+            // processed <= total, so the quotient is at most 100.
+            u8::try_from(processed.min(total) * 100 / total).unwrap_or(100)
+        };
+        Self {
+            phase,
+            processed,
+            total,
+            percent,
+        }
+    }
+}
+
+type ProgressCallback = Arc<dyn Fn(IndexProgress) + Send + Sync>;
+
+/// Slot for the collection's progress callback, shared by its clones.
+///
+/// The lock is a leaf: it is only held to clone or replace the callback,
+/// never while the callback runs.
+#[derive(Clone, Default)]
+pub(crate) struct ProgressListener {
+    callback: Arc<RwLock<Option<ProgressCallback>>>,
+}
+
+impl ProgressListener {
+    /// Registers `callback`, replacing any previous one.
+    pub(crate) fn set(&self, callback: ProgressCallback) {
+        *self.callback.write() = Some(callback);
+    }
+
+    /// Removes the registered callback.
+    pub(crate) fn clear(&self) {
+        *self.callback.write() = None;
+    }
+
+    /// Whether a callback is registered.
+    pub(crate) fn is_set(&self) -> bool {
+        self.callback.read().is_some()
+    }
+
+    /// Reports `processed` of `total` units of `phase`; no-op without a
+    /// callback.
+    pub(crate) fn emit(&self, phase: IndexBuildPhase, processed: usize, total: usize) {
+        let callback = self.callback.read().clone();
+        if let Some(callback) = callback {
+            callback(IndexProgress::new(phase, processed, total));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+
+    #[test]
+    fn test_percent_is_clamped_and_empty_phase_complete() {
+        let phase = IndexBuildPhase::LinkingLayers;
+        assert_eq!(IndexProgress::new(phase, 0, 8).percent, 0);
+        assert_eq!(IndexProgress::new(phase, 3, 8).percent, 37);
+        assert_eq!(IndexProgress::new(phase, 9, 8).percent, 100);
+        assert_eq!(IndexProgress::new(phase, 0, 0).percent, 100);
+    }
+
+    #[test]
+    fn test_listener_emits_only_while_set() {
+        let listener = ProgressListener::default();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        listener.emit(IndexBuildPhase::Flushing, 0, 1);
+
+        let sink = Arc::clone(&seen);
+        listener.set(Arc::new(move |p| sink.lock().push(p)));
+        assert!(listener.is_set());
+        listener.clone().emit(IndexBuildPhase::Flushing, 1, 1);
+        listener.clear();
+        listener.emit(IndexBuildPhase::Flushing, 1, 1);
+
+        let seen = seen.lock();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].phase, IndexBuildPhase::Flushing);
+        assert_eq!(seen[0].percent, 100);
+    }
+
+    #[test]
+    fn test_phase_serializes_snake_case() {
+        let json = serde_json::to_value(IndexProgress::new(IndexBuildPhase::StoringVectors, 1, 2))
+            .expect("serialize");
+        assert_eq!(json["phase"], "storing_vectors");
+        assert_eq!(IndexBuildPhase::StoringVectors.as_str(), "storing_vectors");
+        assert_eq!(json["percent"], 50);
+    }
+}
@@ -163,7 +163,9 @@ impl MetadataCollection {
         batch_size: usize,
         filter: Option<&crate::filter::Filter>,
     ) -> Result<crate::collection::ScrollBatch> {
-        self.inner.scroll_batch(cursor, batch_size, filter)
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner
+            .scroll_batch(cursor, batch_size, filter.as_deref())
     }
 
     // -------------------------------------------------------------------------
@@ -189,6 +191,23 @@ impl MetadataCollection {
         self.inner.upsert_metadata(points)
     }
 
+    /// Registers a validator run on every point before an upsert is
+    /// accepted, replacing any previous one.
+    ///
+    /// Same contract as
+    /// [`VectorCollection::set_upsert_validator`](crate::VectorCollection::set_upsert_validator).
+    pub fn set_upsert_validator<F>(&self, validator: F)
+    where
+        F: Fn(&Point) -> Result<()> + Send + Sync + 'static,
+    {
+        self.inner.set_upsert_validator(validator);
+    }
+
+    /// Removes the validator registered with [`Self::set_upsert_validator`].
+    pub fn clear_upsert_validator(&self) {
+        self.inner.clear_upsert_validator();
+    }
+
     /// Retrieves items by IDs.
     #[must_use]
     pub fn get(&self, ids: &[u64]) -> Vec<Option<Point>> {
@@ -210,6 +229,9 @@ impl MetadataCollection {
 
     /// Performs BM25 full-text search over payloads.
     ///
+    /// Every string value of a payload is indexed on upsert. Results carry
+    /// the payload and an empty vector.
+    ///
     /// # Errors
     ///
     /// Returns an error if storage retrieval fails.
@@ -217,6 +239,21 @@ impl MetadataCollection {
         self.inner.text_search(query, k)
     }
 
+    /// Performs BM25 full-text search, keeping only payloads matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storage retrieval fails.
+    pub fn text_search_with_filter(
+        &self,
+        query: &str,
+        k: usize,
+        filter: &crate::filter::Filter,
+    ) -> Result<Vec<SearchResult>> {
+        self.inner
+            .text_search_with_filter(query, k, &self.inner.collated(filter))
+    }
+
     /// Performs vector similarity search.
     ///
     /// Note: metadata-only collections have no vectors, so this will
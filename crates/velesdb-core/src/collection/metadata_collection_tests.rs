@@ -92,30 +92,104 @@ fn test_delete_points() {
 }
 
 // =========================================================================
-// text_search returns empty for metadata-only (no vector_storage entries)
+// BM25 text search over metadata-only payloads
 // =========================================================================
 
-#[test]
-fn test_text_search_returns_empty_for_metadata_only() {
-    let dir = tempdir().unwrap();
-    let coll = MetadataCollection::create(dir.path().to_path_buf(), "docs").unwrap();
-
+fn catalog(dir: &std::path::Path) -> MetadataCollection {
+    let coll = MetadataCollection::create(dir.to_path_buf(), "docs").unwrap();
     coll.upsert(vec![
-        Point::metadata_only(1, json!({"title": "Rust programming language"})),
-        Point::metadata_only(2, json!({"title": "Python programming guide"})),
+        Point::metadata_only(
+            1,
+            json!({"title": "Rust programming language", "year": 2015}),
+        ),
+        Point::metadata_only(
+            2,
+            json!({"title": "Python programming guide", "year": 2008}),
+        ),
+        Point::metadata_only(3, json!({"title": "Gardening for beginners", "year": 2020})),
     ])
     .unwrap();
+    coll
+}
+
+fn ids(results: &[crate::SearchResult]) -> Vec<u64> {
+    let mut ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn test_text_search_returns_payload_hits() {
+    let dir = tempdir().unwrap();
+    let coll = catalog(dir.path());
 
-    // text_search delegates to BM25 + hydrate_point; hydrate_point requires
-    // vector_storage entries which metadata-only points lack, so the result
-    // set is empty.  The call itself must not error.
     let results = coll.text_search("programming", 10).unwrap();
-    assert!(
-        results.is_empty(),
-        "text_search returns empty for metadata-only points (no vector storage)"
+    assert_eq!(ids(&results), vec![1, 2]);
+    for result in &results {
+        assert!(result.point.vector.is_empty());
+        assert!(result.point.payload.is_some());
+        assert!(result.score > 0.0);
+    }
+}
+
+#[test]
+fn test_text_search_with_filter() {
+    let dir = tempdir().unwrap();
+    let coll = catalog(dir.path());
+
+    let filter = crate::filter::Filter::new(crate::filter::Condition::gt("year", json!(2010)));
+    let results = coll
+        .text_search_with_filter("programming", 10, &filter)
+        .unwrap();
+    assert_eq!(ids(&results), vec![1]);
+}
+
+#[test]
+fn test_text_search_skips_deleted_points() {
+    let dir = tempdir().unwrap();
+    let coll = catalog(dir.path());
+
+    coll.delete(&[1]).unwrap();
+    assert_eq!(ids(&coll.text_search("programming", 10).unwrap()), vec![2]);
+}
+
+#[test]
+fn test_text_search_survives_reopen() {
+    let dir = tempdir().unwrap();
+    {
+        let coll = catalog(dir.path());
+        coll.flush_full().unwrap();
+    }
+    let coll = MetadataCollection::open(dir.path().to_path_buf()).unwrap();
+    assert_eq!(
+        ids(&coll.text_search("programming", 10).unwrap()),
+        vec![1, 2]
     );
 }
 
+#[test]
+fn test_velesql_match_on_metadata_only() {
+    let dir = tempdir().unwrap();
+    let coll = catalog(dir.path());
+    let params = std::collections::HashMap::new();
+
+    let results = coll
+        .execute_query_str(
+            "SELECT * FROM docs WHERE title MATCH 'programming' LIMIT 10",
+            &params,
+        )
+        .unwrap();
+    assert_eq!(ids(&results), vec![1, 2]);
+
+    let results = coll
+        .execute_query_str(
+            "SELECT * FROM docs WHERE title MATCH 'programming' AND year < 2010 LIMIT 10",
+            &params,
+        )
+        .unwrap();
+    assert_eq!(ids(&results), vec![2]);
+}
+
 // =========================================================================
 // execute_query_str with simple VelesQL
 // =========================================================================
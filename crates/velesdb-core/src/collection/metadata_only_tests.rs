@@ -416,3 +416,117 @@ fn test_execute_query_on_metadata_collection() {
 
     assert_eq!(results.len(), 5, "execute_query should return all 5 items");
 }
+
+// =============================================================================
+// AC10: window functions rank the whole filtered set, not the LIMIT slice
+// =============================================================================
+
+/// 30 scores split across two categories; `score == id` so expected ranks
+/// are easy to derive.
+fn leaderboard_db() -> (tempfile::TempDir, Database) {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    db.create_metadata_collection("scores").unwrap();
+    let coll = db.get_metadata_collection("scores").unwrap();
+    let points: Vec<Point> = (1u64..=30)
+        .map(|id| {
+            let category = if id % 2 == 0 { "even" } else { "odd" };
+            Point::metadata_only(id, json!({"category": category, "score": id}))
+        })
+        .collect();
+    coll.upsert(points).unwrap();
+    (dir, db)
+}
+
+fn query_rows(db: &Database, sql: &str) -> Vec<crate::SearchResult> {
+    let parsed = crate::velesql::Parser::parse(sql).unwrap();
+    db.execute_query(&parsed, &std::collections::HashMap::new())
+        .unwrap()
+}
+
+fn payload_u64(result: &crate::SearchResult, field: &str) -> u64 {
+    result.point.payload.as_ref().unwrap()[field]
+        .as_u64()
+        .unwrap_or_else(|| panic!("missing {field} on point {}", result.point.id))
+}
+
+#[test]
+fn test_window_function_without_order_by_ranks_entire_collection() {
+    let (_dir, db) = leaderboard_db();
+
+    // No ORDER BY and the default LIMIT: the 10 returned rows must still
+    // carry their rank among all 30, not among the 10 fetched.
+    let rows = query_rows(
+        &db,
+        "SELECT score, ROW_NUMBER() OVER (ORDER BY score DESC) AS pos FROM scores",
+    );
+    assert_eq!(rows.len(), 10);
+    for row in &rows {
+        assert_eq!(payload_u64(row, "pos"), 31 - payload_u64(row, "score"));
+    }
+}
+
+#[test]
+fn test_window_function_partitioned_leaderboard() {
+    let (_dir, db) = leaderboard_db();
+
+    let rows = query_rows(
+        &db,
+        "SELECT category, score, RANK() OVER (PARTITION BY category ORDER BY score DESC) AS r \
+         FROM scores WHERE score > 4 ORDER BY r, category LIMIT 4",
+    );
+    let got: Vec<(u64, u64)> = rows
+        .iter()
+        .map(|r| (r.point.id, payload_u64(r, "r")))
+        .collect();
+    assert_eq!(got, vec![(30, 1), (29, 1), (28, 2), (27, 2)]);
+}
+
+#[test]
+fn test_gated_text_search_on_metadata_only_collection() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    db.create_collection_typed("books", &CollectionType::MetadataOnly)
+        .unwrap();
+    let coll = db.get_metadata_collection("books").unwrap();
+    coll.upsert(vec![
+        Point::metadata_only(1, json!({"title": "Rust in Action", "lang": "en"})),
+        Point::metadata_only(2, json!({"title": "Rust für Einsteiger", "lang": "de"})),
+        Point::metadata_only(3, json!({"title": "Learning Go", "lang": "en"})),
+    ])
+    .unwrap();
+
+    let read = |filter| crate::GatedRead::Text {
+        query: "rust",
+        k: 10,
+        filter,
+    };
+    let mut ids: Vec<u64> = db
+        .gated_search_with_context("books", None, read(None))
+        .unwrap()
+        .iter()
+        .map(|r| r.point.id)
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec![1, 2]);
+
+    let english = crate::filter::Filter::new(crate::filter::Condition::eq("lang", "en"));
+    let results = db
+        .gated_search_with_context("books", None, read(Some(&english)))
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].point.id, 1);
+
+    // Dense reads still need a vector collection.
+    let dense = crate::GatedRead::Dense {
+        query: &[1.0],
+        k: 1,
+        ef: None,
+        quality: None,
+        filter: None,
+    };
+    assert!(matches!(
+        db.gated_search_with_context("books", None, dense),
+        Err(Error::CollectionNotFound(_))
+    ));
+}
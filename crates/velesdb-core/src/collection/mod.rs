@@ -22,9 +22,29 @@
 pub mod query_cost;
 pub mod stats;
 
+#[cfg(feature = "persistence")]
+pub mod access_stats;
+#[cfg(all(test, feature = "persistence"))]
+mod access_stats_tests;
+#[cfg(feature = "persistence")]
+pub mod admission;
+#[cfg(all(test, feature = "persistence"))]
+mod admission_tests;
+#[cfg(feature = "persistence")]
+pub mod archive;
+#[cfg(all(test, feature = "persistence"))]
+mod archive_tests;
 #[cfg(feature = "persistence")]
 pub mod auto_reindex;
 #[cfg(feature = "persistence")]
+pub mod change_feed;
+#[cfg(all(test, feature = "persistence"))]
+mod change_feed_tests;
+#[cfg(feature = "chaos")]
+pub mod chaos;
+#[cfg(all(test, feature = "chaos"))]
+mod chaos_tests;
+#[cfg(feature = "persistence")]
 mod collection_config;
 #[cfg(feature = "persistence")]
 pub(crate) mod config_serde;
@@ -33,22 +53,44 @@ mod core;
 #[cfg(feature = "persistence")]
 pub mod diagnostics;
 #[cfg(feature = "persistence")]
+pub mod embedding_info;
+#[cfg(feature = "persistence")]
 pub(crate) mod expiry;
 #[cfg(feature = "persistence")]
+pub mod file_import;
+#[cfg(feature = "persistence")]
 pub mod graph;
 #[cfg(feature = "persistence")]
 mod graph_collection;
 #[cfg(feature = "persistence")]
 mod graph_collection_query;
 #[cfg(feature = "persistence")]
+pub mod idempotency;
+#[cfg(all(test, feature = "persistence"))]
+mod idempotency_tests;
+#[cfg(feature = "persistence")]
+pub mod index_progress;
+#[cfg(feature = "persistence")]
 mod metadata_collection;
 #[cfg(feature = "persistence")]
 pub(crate) mod order_by_advisor;
 #[cfg(feature = "persistence")]
+pub(crate) mod partial_index;
+#[cfg(feature = "persistence")]
 pub(crate) mod payload_mirror;
 #[cfg(feature = "persistence")]
 pub(crate) mod payload_size;
 #[cfg(feature = "persistence")]
+pub mod projection;
+#[cfg(feature = "persistence")]
+pub mod query_defaults;
+#[cfg(feature = "persistence")]
+pub(crate) mod read_snapshot;
+#[cfg(all(test, feature = "persistence"))]
+mod read_snapshot_tests;
+#[cfg(feature = "persistence")]
+pub mod scoring_profile;
+#[cfg(feature = "persistence")]
 pub mod search;
 #[cfg(feature = "persistence")]
 pub mod streaming;
@@ -57,6 +99,10 @@ pub(crate) mod text_utils;
 #[cfg(feature = "persistence")]
 mod types;
 #[cfg(feature = "persistence")]
+pub(crate) mod upsert_validator;
+#[cfg(all(test, feature = "persistence"))]
+mod upsert_validator_tests;
+#[cfg(feature = "persistence")]
 mod vector_collection;
 
 #[cfg(feature = "persistence")]
@@ -80,28 +126,63 @@ mod e2e_integration_tests;
 #[cfg(all(test, feature = "persistence"))]
 mod set_operations_execution_tests;
 
+#[cfg(feature = "persistence")]
+pub use access_stats::{AccessStats, PayloadCacheStats, PointAccessCount};
+#[cfg(feature = "persistence")]
+pub use admission::{AdmissionKind, AdmissionKindStats, AdmissionStats};
 #[cfg(feature = "persistence")]
 pub use any_collection::AnyCollection;
 #[cfg(feature = "persistence")]
+pub use archive::{ArchivePolicy, ArchiveReport};
+#[cfg(feature = "persistence")]
+pub use change_feed::{ChangeEvent, ChangeKind, ChangeSubscription};
+#[cfg(feature = "persistence")]
 pub use collection_config::{CollectionConfig, CURRENT_SCHEMA_VERSION};
 #[cfg(feature = "persistence")]
-pub use core::{IndexInfo, ScrollBatch, MAX_DIMENSION, MIN_DIMENSION};
+pub use core::{
+    IndexInfo, ProfileComparison, ProfileQuery, ProfileRunReport, ScrollBatch, UpsertOptions,
+    MAX_DIMENSION, MIN_DIMENSION,
+};
 #[cfg(feature = "persistence")]
 pub use diagnostics::{CollectionDiagnostics, IndexHealth};
 #[cfg(feature = "persistence")]
+pub use embedding_info::EmbeddingInfo;
+#[cfg(feature = "persistence")]
 pub use expiry::EXPIRES_AT_KEY;
 #[cfg(feature = "persistence")]
+pub use file_import::{
+    ImportMapping, ImportProgress, ImportReport, ImportRowError, OnBadRow, VectorEncoding,
+};
+#[cfg(feature = "persistence")]
 pub use graph::{
-    ConcurrentEdgeStore, EdgeStore, EdgeType, GraphEdge, GraphNode, GraphSchema, NodeType,
-    PropertyIndex, RangeIndex, TraversalConfig, TraversalPath, TraversalResult, ValueType,
+    ConcurrentEdgeStore, EdgeStore, EdgeType, GraphEdge, GraphFormat, GraphImportStats, GraphNode,
+    GraphSchema, GraphStats, NodeType, PropertyIndex, RangeIndex, TraversalConfig, TraversalPath,
+    TraversalResult, ValueType,
 };
 #[cfg(feature = "persistence")]
 pub use graph_collection::GraphCollection;
 #[cfg(feature = "persistence")]
+pub use idempotency::{IdempotencyClaim, IdempotencyOutcome, IdempotentResponse};
+#[cfg(feature = "persistence")]
 pub use metadata_collection::MetadataCollection;
 #[cfg(feature = "persistence")]
 pub use order_by_advisor::{OrderByIndexState, OrderByIndexSuggestion};
 #[cfg(feature = "persistence")]
+pub use partial_index::PartialIndexInfo;
+#[cfg(feature = "persistence")]
+pub use payload_size::payload_exceeds;
+#[cfg(feature = "persistence")]
+pub use query_defaults::QueryDefaults;
+#[cfg(feature = "persistence")]
+pub use scoring_profile::ScoringProfile;
+#[cfg(feature = "persistence")]
+pub use search::{
+    NearDuplicateQuery, NegativeStrategy, PointsView, RecommendStrategy, SearchGroup,
+    SearchOptions, SearchPage, SearchResultIter, SearchView, SimilarityMatrix,
+    SparseSimilarityMatrix, VectorReader, DEFAULT_NEGATIVE_WEIGHT, MAX_DENSE_PAIRWISE_IDS,
+    MAX_SEARCH_CURSORS, METRIC_OVERRIDE_FLAT_SCAN_MAX, SEARCH_CURSOR_TTL,
+};
+#[cfg(feature = "persistence")]
 pub(crate) use types::Collection;
 #[cfg(feature = "persistence")]
 pub use types::CollectionType;
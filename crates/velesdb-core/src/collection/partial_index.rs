@@ -0,0 +1,159 @@
+//! Partial vector indexes: an HNSW graph over the points matching a filter.
+//!
+//! [`Collection::create_partial_index`](crate::collection::Collection::create_partial_index)
+//! builds a second, smaller graph holding only the points whose payload
+//! matches the index filter, and every upsert and delete keeps it in step.
+//! A filtered search whose filter implies the index filter — it is the same
+//! condition, or ANDs it with further conditions — walks that graph instead
+//! of over-fetching from the full one and discarding the misses. Results
+//! still pass the full filter re-match, so the route never changes what a
+//! query returns, only how many candidates it visits.
+//!
+//! Implication is checked syntactically: the index filter's top-level AND
+//! conjuncts must all appear among the query filter's, with the same
+//! collation. `category = 'news'` routes `category = 'news' AND lang = 'en'`;
+//! `category IN ('news')` does not.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::filter::{Condition, Filter};
+use crate::index::{HnswIndex, VectorIndex};
+
+/// Longest accepted partial index name.
+const MAX_PARTIAL_INDEX_NAME_LEN: usize = 64;
+
+/// A partial index, as listed by `Collection::partial_indexes`.
+#[derive(Debug, Clone)]
+pub struct PartialIndexInfo {
+    /// Index name.
+    pub name: String,
+    /// Filter a point's payload must match to be indexed.
+    pub filter: Filter,
+    /// Points currently in the index.
+    pub points: usize,
+    /// Filtered searches routed to the index since the collection opened.
+    pub searches: u64,
+}
+
+/// How a search filter relates to the filter of the partial index serving it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Coverage {
+    /// Same conditions: every indexed point matches the search filter.
+    Exact,
+    /// The search filter adds conditions the index does not check.
+    Narrower,
+}
+
+/// In-memory state of one partial index; rebuilt from the config on open.
+pub(crate) struct PartialIndex {
+    filter: Filter,
+    /// Top-level AND conjuncts of `filter`, serialized for comparison.
+    conjuncts: Vec<Value>,
+    pub(crate) index: HnswIndex,
+    searches: AtomicU64,
+}
+
+impl PartialIndex {
+    /// An empty partial index over `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph cannot be created or the filter cannot
+    /// be serialized.
+    pub(crate) fn new(filter: Filter, dimension: usize, metric: DistanceMetric) -> Result<Self> {
+        let conjuncts = conjuncts(&filter.condition)
+            .into_iter()
+            .map(serde_json::to_value)
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|e| Error::Serialization(format!("partial index filter: {e}")))?;
+        Ok(Self {
+            filter,
+            conjuncts,
+            index: HnswIndex::new(dimension, metric)?,
+            searches: AtomicU64::new(0),
+        })
+    }
+
+    /// Whether `filter` implies this index's filter, and how closely.
+    pub(crate) fn coverage(&self, filter: &Filter) -> Option<Coverage> {
+        if filter.effective_collation() != self.filter.effective_collation() {
+            return None;
+        }
+        // A conjunct that fails to serialize is simply never matched.
+        let query: Vec<Value> = conjuncts(&filter.condition)
+            .into_iter()
+            .filter_map(|c| serde_json::to_value(c).ok())
+            .collect();
+        if !self.conjuncts.iter().all(|c| query.contains(c)) {
+            return None;
+        }
+        if query.iter().all(|c| self.conjuncts.contains(c)) {
+            Some(Coverage::Exact)
+        } else {
+            Some(Coverage::Narrower)
+        }
+    }
+
+    /// Whether a point with `payload` belongs in the index.
+    pub(crate) fn admits(&self, payload: Option<&Value>) -> bool {
+        self.filter.matches(payload.unwrap_or(&Value::Null))
+    }
+
+    /// Brings point `id` in line with its stored state: indexed when it has
+    /// a vector and its payload matches, removed otherwise.
+    pub(crate) fn sync_point(&self, id: u64, vector: Option<&[f32]>, payload: Option<&Value>) {
+        match vector {
+            Some(vector) if self.admits(payload) => self.index.insert(id, vector),
+            _ => {
+                self.index.remove(id);
+            }
+        }
+    }
+
+    /// Counts a search routed to the index.
+    pub(crate) fn record_search(&self) {
+        self.searches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn info(&self, name: &str) -> PartialIndexInfo {
+        PartialIndexInfo {
+            name: name.to_string(),
+            filter: self.filter.clone(),
+            points: self.index.len(),
+            searches: self.searches.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Partial indexes of a collection, by name.
+pub(crate) type PartialIndexes = std::collections::BTreeMap<String, Arc<PartialIndex>>;
+
+/// Top-level AND conjuncts of `condition`, flattening nested ANDs.
+fn conjuncts(condition: &Condition) -> Vec<&Condition> {
+    match condition {
+        Condition::And { conditions } => conditions.iter().flat_map(conjuncts).collect(),
+        other => vec![other],
+    }
+}
+
+/// Checks that `name` is 1-64 letters, digits, `_` or `-`.
+pub(crate) fn validate_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_PARTIAL_INDEX_NAME_LEN
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Config(format!(
+            "invalid partial index name '{name}': use 1-{MAX_PARTIAL_INDEX_NAME_LEN} letters, \
+             digits, '_' or '-'"
+        )))
+    }
+}
@@ -9,6 +9,7 @@
 use crate::collection::payload_mirror::MIRROR_MIN_ROWS;
 use crate::collection::types::Collection;
 use crate::distance::DistanceMetric;
+use crate::filter::Collation;
 use crate::point::Point;
 use std::collections::HashMap;
 use tempfile::TempDir;
@@ -182,12 +183,36 @@ fn test_unsupported_conditions_fall_back_with_correct_results() {
     let (_dir, col) = setup_collection();
     col.build_payload_mirror();
 
-    // LIKE is not columnar-eligible — must fall back and stay correct.
+    // IS NULL is not columnar-eligible — must fall back and stay correct.
     let ids = query_ids(
         &col,
-        "SELECT * FROM c WHERE category LIKE 'cat1%' AND price < 10 LIMIT 500",
+        "SELECT * FROM c WHERE category IS NULL AND price < 10 LIMIT 500",
     );
+    assert!(ids.is_empty());
+}
+
+#[test]
+fn test_like_and_string_ranges_are_answered_from_the_mirror() {
+    let (_dir, col) = setup_collection();
+    col.build_payload_mirror();
     let expected: Vec<u64> = (0..10u64).filter(|i| i % 3 == 1).collect();
+
+    let ids = query_ids(
+        &col,
+        "SELECT * FROM c WHERE category LIKE 'cat1%' AND price < 10 LIMIT 500",
+    );
+    assert_eq!(ids, expected);
+
+    let ids = query_ids(
+        &col,
+        "SELECT * FROM c WHERE category ILIKE 'CAT1' AND price < 10 LIMIT 500",
+    );
+    assert_eq!(ids, expected);
+
+    let ids = query_ids(
+        &col,
+        "SELECT * FROM c WHERE category > 'cat0' AND category < 'cat2' AND price < 10 LIMIT 500",
+    );
     assert_eq!(ids, expected);
 }
 
@@ -273,7 +298,9 @@ fn test_apply_upserts_batch_is_atomic_for_concurrent_readers() {
         let mut passes_after_done = 0;
         while passes_after_done < 3 {
             for gen in 1..=GENS {
-                if let MirrorAnswer::Ids(ids) = mirror.candidate_ids(&gen_eq(gen)) {
+                if let MirrorAnswer::Ids(ids) =
+                    mirror.candidate_ids(&gen_eq(gen), Collation::Binary)
+                {
                     assert!(
                         ids.is_empty() || ids.len() == BATCH,
                         "half-applied batch visible: gen {gen} matched {} of {BATCH} rows",
@@ -288,7 +315,11 @@ fn test_apply_upserts_batch_is_atomic_for_concurrent_readers() {
     });
 
     // Once the writer is done, the last generation must be fully visible.
-    match col.storage.payload_mirror.candidate_ids(&gen_eq(GENS)) {
+    match col
+        .storage
+        .payload_mirror
+        .candidate_ids(&gen_eq(GENS), Collation::Binary)
+    {
         MirrorAnswer::Ids(ids) => assert_eq!(ids.len(), BATCH),
         _ => panic!("mirror must still be built and answer the Eq probe"),
     }
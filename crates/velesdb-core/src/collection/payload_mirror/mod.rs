@@ -348,13 +348,18 @@ impl PayloadMirror {
         }
     }
 
-    /// Translates a filter condition to candidate point ids via columnar scans.
-    pub(crate) fn candidate_ids(&self, condition: &crate::filter::Condition) -> MirrorAnswer {
+    /// Translates a filter condition to candidate point ids via columnar
+    /// scans, comparing strings under `collation`.
+    pub(crate) fn candidate_ids(
+        &self,
+        condition: &crate::filter::Condition,
+        collation: crate::filter::Collation,
+    ) -> MirrorAnswer {
         let guard = self.state.read();
         let Some(state) = guard.as_ref() else {
             return MirrorAnswer::NotBuilt;
         };
-        match translate::condition_bitmap(state, condition) {
+        match translate::condition_bitmap(state, condition, collation) {
             Some(eval) => MirrorAnswer::Ids(
                 eval.bits
                     .iter()
@@ -372,11 +377,13 @@ impl crate::collection::types::Collection {
     ///
     /// Returns `None` when the condition is unsupported or the mirror is not
     /// (yet) worth building — the caller falls back to the JSON scan path.
-    pub(crate) fn mirror_candidate_ids(
-        &self,
-        condition: &crate::filter::Condition,
-    ) -> Option<Vec<u64>> {
-        match self.storage.payload_mirror.candidate_ids(condition) {
+    pub(crate) fn mirror_candidate_ids(&self, filter: &crate::filter::Filter) -> Option<Vec<u64>> {
+        let (condition, collation) = (&filter.condition, filter.effective_collation());
+        match self
+            .storage
+            .payload_mirror
+            .candidate_ids(condition, collation)
+        {
             MirrorAnswer::Ids(ids) => return Some(ids),
             MirrorAnswer::Unsupported => return None,
             MirrorAnswer::NotBuilt => {}
@@ -385,7 +392,11 @@ impl crate::collection::types::Collection {
             return None;
         }
         self.build_payload_mirror();
-        match self.storage.payload_mirror.candidate_ids(condition) {
+        match self
+            .storage
+            .payload_mirror
+            .candidate_ids(condition, collation)
+        {
             MirrorAnswer::Ids(ids) => Some(ids),
             MirrorAnswer::Unsupported | MirrorAnswer::NotBuilt => None,
         }
@@ -22,11 +22,16 @@
 //! were untranslatable; `exact` tracks this so `Not`/`Neq` complements are
 //! only ever taken over exact operands (a complemented superset would lose
 //! matches — a false negative).
+//!
+//! String equality, `IN` and `LIKE` leaves honour the filter's
+//! [`Collation`] by folding each distinct dictionary value, so they stay
+//! exact under every collation.
 
 use super::MirrorState;
 use crate::column_store::TypedColumn;
-use crate::filter::Condition;
+use crate::filter::{Collation, Condition};
 use roaring::RoaringBitmap;
+use std::ops::Bound;
 
 /// A translated condition: matching live rows plus exactness.
 pub(super) struct Eval {
@@ -60,22 +65,28 @@ enum FieldCol {
 ///
 /// Returns `None` when the condition (or a structurally required branch)
 /// cannot be answered from columnar data.
-pub(super) fn condition_bitmap(state: &MirrorState, cond: &Condition) -> Option<Eval> {
+pub(super) fn condition_bitmap(
+    state: &MirrorState,
+    cond: &Condition,
+    collation: Collation,
+) -> Option<Eval> {
     match cond {
-        Condition::Eq { field, value } => leaf_eq(state, field, value),
+        Condition::Eq { field, value } => leaf_eq(state, field, value, collation),
         Condition::Neq { field, value } => {
-            let eq = leaf_eq(state, field, value)?;
+            let eq = leaf_eq(state, field, value, collation)?;
             complement(state, &eq)
         }
         Condition::Gt { field, value } => leaf_ord(state, field, value, std::cmp::Ordering::is_gt),
         Condition::Gte { field, value } => leaf_ord(state, field, value, std::cmp::Ordering::is_ge),
         Condition::Lt { field, value } => leaf_ord(state, field, value, std::cmp::Ordering::is_lt),
         Condition::Lte { field, value } => leaf_ord(state, field, value, std::cmp::Ordering::is_le),
-        Condition::In { field, values } => leaf_in(state, field, values),
-        Condition::And { conditions } => and_bitmap(state, conditions),
-        Condition::Or { conditions } => or_bitmap(state, conditions),
+        Condition::In { field, values } => leaf_in(state, field, values, collation),
+        Condition::Like { field, pattern } => leaf_like(state, field, pattern, false, collation),
+        Condition::ILike { field, pattern } => leaf_like(state, field, pattern, true, collation),
+        Condition::And { conditions } => and_bitmap(state, conditions, collation),
+        Condition::Or { conditions } => or_bitmap(state, conditions, collation),
         Condition::Not { condition } => {
-            let inner = condition_bitmap(state, condition)?;
+            let inner = condition_bitmap(state, condition, collation)?;
             complement(state, &inner)
         }
         _ => None,
@@ -89,7 +100,7 @@ fn complement(state: &MirrorState, eval: &Eval) -> Option<Eval> {
 
 /// AND: intersect translatable branches; untranslatable branches widen the
 /// result to a superset (`exact = false`), which the post-filter narrows.
-fn and_bitmap(state: &MirrorState, conditions: &[Condition]) -> Option<Eval> {
+fn and_bitmap(state: &MirrorState, conditions: &[Condition], collation: Collation) -> Option<Eval> {
     if conditions.is_empty() {
         // `And { [] }` is the engine-handled identity: matches everything.
         return Some(Eval::exact(state.live.clone()));
@@ -97,7 +108,7 @@ fn and_bitmap(state: &MirrorState, conditions: &[Condition]) -> Option<Eval> {
     let mut acc: Option<RoaringBitmap> = None;
     let mut exact = true;
     for cond in conditions {
-        match condition_bitmap(state, cond) {
+        match condition_bitmap(state, cond, collation) {
             Some(eval) => {
                 exact &= eval.exact;
                 acc = Some(match acc {
@@ -112,11 +123,11 @@ fn and_bitmap(state: &MirrorState, conditions: &[Condition]) -> Option<Eval> {
 }
 
 /// OR: every branch must translate, otherwise matches could be lost.
-fn or_bitmap(state: &MirrorState, conditions: &[Condition]) -> Option<Eval> {
+fn or_bitmap(state: &MirrorState, conditions: &[Condition], collation: Collation) -> Option<Eval> {
     let mut acc = RoaringBitmap::new();
     let mut exact = true;
     for cond in conditions {
-        let eval = condition_bitmap(state, cond)?;
+        let eval = condition_bitmap(state, cond, collation)?;
         exact &= eval.exact;
         acc |= eval.bits;
     }
@@ -142,9 +153,14 @@ fn classify_field(state: &MirrorState, field: &str) -> Option<FieldCol> {
 }
 
 /// Equality leaf. Eligible only for type-matched scalar literals; replicates
-/// `values_equal` exactly (epsilon equality for numbers, interned equality
-/// for strings).
-fn leaf_eq(state: &MirrorState, field: &str, value: &serde_json::Value) -> Option<Eval> {
+/// `values_equal_collated` exactly (epsilon equality for numbers, interned
+/// or collated equality for strings).
+fn leaf_eq(
+    state: &MirrorState,
+    field: &str,
+    value: &serde_json::Value,
+    collation: Collation,
+) -> Option<Eval> {
     let col = classify_field(state, field)?;
     let bits = match (col, value) {
         (FieldCol::Absent, v) if is_scalar(v) => RoaringBitmap::new(),
@@ -154,9 +170,9 @@ fn leaf_eq(state: &MirrorState, field: &str, value: &serde_json::Value) -> Optio
                 .store
                 .filter_float_bitmap(field, move |v| (v - lit).abs() < f64::EPSILON)
         }
-        (FieldCol::Str, serde_json::Value::String(s)) => {
-            state.store.filter_eq_string_bitmap(field, s)
-        }
+        (FieldCol::Str, serde_json::Value::String(s)) => state
+            .store
+            .filter_eq_string_collated_bitmap(field, s, collation),
         (FieldCol::Bool, serde_json::Value::Bool(b)) => {
             state.store.filter_bool_eq_bitmap(field, *b)
         }
@@ -167,6 +183,7 @@ fn leaf_eq(state: &MirrorState, field: &str, value: &serde_json::Value) -> Optio
 
 /// Ordering leaf. JSON ordering (`compare_values`) only ever compares
 /// Number/Number or String/String; all other combinations match nothing.
+/// String ordering is bytewise, answered from the sorted dictionary.
 fn leaf_ord(
     state: &MirrorState,
     field: &str,
@@ -185,14 +202,63 @@ fn leaf_ord(
                 .filter_float_bitmap(field, move |v| v.partial_cmp(&lit).is_some_and(&holds));
             Some(Eval::exact(bits))
         }
-        // String ordering (lexicographic) and cross-type leaves fall back.
+        (FieldCol::Str, serde_json::Value::String(lit)) => {
+            let (low, high) = string_bounds(lit, holds)?;
+            Some(Eval::exact(
+                state.store.filter_range_string_bitmap(field, low, high),
+            ))
+        }
+        // Cross-type leaves fall back: off-type rows are nulls in the mirror.
+        _ => None,
+    }
+}
+
+/// Bounds selecting the strings `s` for which `holds(s.cmp(lit))`.
+fn string_bounds(
+    lit: &str,
+    holds: impl Fn(std::cmp::Ordering) -> bool,
+) -> Option<(Bound<&str>, Bound<&str>)> {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+    match (holds(Less), holds(Equal), holds(Greater)) {
+        (false, false, true) => Some((Bound::Excluded(lit), Bound::Unbounded)),
+        (false, true, true) => Some((Bound::Included(lit), Bound::Unbounded)),
+        (true, false, false) => Some((Bound::Unbounded, Bound::Excluded(lit))),
+        (true, true, false) => Some((Bound::Unbounded, Bound::Included(lit))),
+        // Not a single range; never produced by the comparison operators.
         _ => None,
     }
 }
 
+/// LIKE / ILIKE leaf. JSON LIKE only matches string values, which are
+/// exactly the non-null cells of a string column.
+fn leaf_like(
+    state: &MirrorState,
+    field: &str,
+    pattern: &str,
+    case_insensitive: bool,
+    collation: Collation,
+) -> Option<Eval> {
+    match classify_field(state, field)? {
+        FieldCol::Absent => Some(Eval::empty()),
+        FieldCol::Str => Some(Eval::exact(state.store.filter_like_string_collated_bitmap(
+            field,
+            pattern,
+            case_insensitive,
+            collation,
+        ))),
+        // Strings on a float/bool column are mirrored as nulls.
+        FieldCol::Float | FieldCol::Bool => None,
+    }
+}
+
 /// IN leaf. Eligible only when every list value matches the column type —
 /// a single off-type value could match rows the mirror stored as null.
-fn leaf_in(state: &MirrorState, field: &str, values: &[serde_json::Value]) -> Option<Eval> {
+fn leaf_in(
+    state: &MirrorState,
+    field: &str,
+    values: &[serde_json::Value],
+    collation: Collation,
+) -> Option<Eval> {
     let col = classify_field(state, field)?;
     if values.is_empty() {
         return Some(Eval::empty());
@@ -201,26 +267,43 @@ fn leaf_in(state: &MirrorState, field: &str, values: &[serde_json::Value]) -> Op
         // Missing fields never match IN, whatever the list contains.
         FieldCol::Absent => RoaringBitmap::new(),
         FieldCol::Float => {
-            let lits: Vec<f64> = values
+            let mut lits: Vec<f64> = values
                 .iter()
                 .map(serde_json::Value::as_f64)
                 .collect::<Option<Vec<f64>>>()?;
-            state.store.filter_float_bitmap(field, move |v| {
-                lits.iter().any(|lit| (v - lit).abs() < f64::EPSILON)
-            })
+            lits.sort_unstable_by(f64::total_cmp);
+            state
+                .store
+                .filter_float_bitmap(field, move |v| float_in_sorted(&lits, v))
         }
         FieldCol::Str => {
             let strs: Vec<&str> = values
                 .iter()
                 .map(serde_json::Value::as_str)
                 .collect::<Option<Vec<&str>>>()?;
-            state.store.filter_in_string_bitmap(field, &strs)
+            state
+                .store
+                .filter_in_string_collated_bitmap(field, &strs, collation)
         }
         FieldCol::Bool => bool_in_bitmap(state, field, values)?,
     };
     Some(Eval::exact(bits))
 }
 
+/// Epsilon membership test against sorted literals, O(log n) per row so
+/// large parameter lists (`IN $ids`) do not cost a list scan per row.
+///
+/// The literals nearest to `v` on either side bracket the lower bound;
+/// if any literal is epsilon-equal to `v`, one of those two is.
+fn float_in_sorted(lits: &[f64], v: f64) -> bool {
+    let pos = lits.partition_point(|lit| lit.total_cmp(&v).is_lt());
+    let near = |i: usize| {
+        lits.get(i)
+            .is_some_and(|lit| (v - lit).abs() < f64::EPSILON)
+    };
+    near(pos) || pos > 0 && near(pos - 1)
+}
+
 /// IN over a bool column: union of the (at most two) distinct values.
 fn bool_in_bitmap(
     state: &MirrorState,
@@ -6,7 +6,7 @@
 
 use super::translate::condition_bitmap;
 use super::MirrorState;
-use crate::filter::Condition;
+use crate::filter::{Collation, Condition};
 use serde_json::json;
 
 /// Builds a mirror with rows:
@@ -44,7 +44,7 @@ fn eq_string_matches_exact_rows() {
         field: "category".into(),
         value: json!("tech"),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![0, 2]);
     assert!(eval.exact);
 }
@@ -56,7 +56,7 @@ fn eq_number_uses_epsilon_semantics() {
         field: "price".into(),
         value: json!(20.5),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![1]);
     assert!(eval.exact);
 }
@@ -68,7 +68,7 @@ fn eq_bool_matches() {
         field: "active".into(),
         value: json!(true),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![0]);
     assert!(eval.exact);
 }
@@ -80,7 +80,7 @@ fn eq_absent_field_is_exact_empty() {
         field: "nonexistent".into(),
         value: json!("x"),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert!(eval.bits.is_empty());
     assert!(eval.exact);
 }
@@ -94,7 +94,7 @@ fn eq_on_array_only_field_is_exact_empty() {
         field: "tags".into(),
         value: json!("a"),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert!(eval.bits.is_empty());
     assert!(eval.exact);
 }
@@ -106,7 +106,7 @@ fn dotted_field_falls_back() {
         field: "meta.x".into(),
         value: json!(1),
     };
-    assert!(condition_bitmap(&state, &cond).is_none());
+    assert!(condition_bitmap(&state, &cond, Collation::Binary).is_none());
 }
 
 #[test]
@@ -118,7 +118,7 @@ fn type_mismatched_literal_falls_back() {
         field: "price".into(),
         value: json!("not-a-number"),
     };
-    assert!(condition_bitmap(&state, &cond).is_none());
+    assert!(condition_bitmap(&state, &cond, Collation::Binary).is_none());
 }
 
 #[test]
@@ -128,7 +128,7 @@ fn ordering_on_numbers_matches_compare_values() {
         field: "price".into(),
         value: json!(20),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     // Row 3 holds a string price (null cell) and must not match.
     assert_eq!(rows(&eval), vec![1, 2]);
     assert!(eval.exact);
@@ -142,19 +142,81 @@ fn ordering_with_bool_literal_is_exact_empty() {
         field: "price".into(),
         value: json!(true),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert!(eval.bits.is_empty());
     assert!(eval.exact);
 }
 
 #[test]
-fn string_ordering_falls_back() {
+fn string_ordering_uses_sorted_dictionary() {
     let state = sample_state();
-    let cond = Condition::Gt {
+    let gt = Condition::Gt {
+        field: "category".into(),
+        value: json!("bio"),
+    };
+    let eval = condition_bitmap(&state, &gt, Collation::Binary).expect("supported");
+    assert_eq!(rows(&eval), vec![0, 2]);
+    assert!(eval.exact);
+
+    let lte = Condition::Lte {
         field: "category".into(),
+        value: json!("bio"),
+    };
+    assert_eq!(
+        rows(&condition_bitmap(&state, &lte, Collation::Binary).expect("supported")),
+        vec![1]
+    );
+}
+
+#[test]
+fn string_ordering_on_float_column_falls_back() {
+    let state = sample_state();
+    // Row 3 holds a string price, which the mirror stores as null.
+    let cond = Condition::Gt {
+        field: "price".into(),
         value: json!("a"),
     };
-    assert!(condition_bitmap(&state, &cond).is_none());
+    assert!(condition_bitmap(&state, &cond, Collation::Binary).is_none());
+}
+
+#[test]
+fn like_and_ilike_on_string_column_are_exact() {
+    let state = sample_state();
+    let like = Condition::Like {
+        field: "category".into(),
+        pattern: "te%".into(),
+    };
+    let eval = condition_bitmap(&state, &like, Collation::Binary).expect("supported");
+    assert_eq!(rows(&eval), vec![0, 2]);
+    assert!(eval.exact);
+
+    let insensitive = Condition::ILike {
+        field: "category".into(),
+        pattern: "%IO".into(),
+    };
+    assert_eq!(
+        rows(&condition_bitmap(&state, &insensitive, Collation::Binary).expect("supported")),
+        vec![1]
+    );
+}
+
+#[test]
+fn like_on_absent_field_is_exact_empty_and_on_float_falls_back() {
+    let state = sample_state();
+    let absent = Condition::Like {
+        field: "nope".into(),
+        pattern: "%".into(),
+    };
+    assert!(condition_bitmap(&state, &absent, Collation::Binary)
+        .expect("supported")
+        .bits
+        .is_empty());
+
+    let on_float = Condition::Like {
+        field: "price".into(),
+        pattern: "not%".into(),
+    };
+    assert!(condition_bitmap(&state, &on_float, Collation::Binary).is_none());
 }
 
 #[test]
@@ -164,7 +226,7 @@ fn neq_complements_over_live_rows() {
         field: "category".into(),
         value: json!("tech"),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     // JSON Neq matches rows where the field differs OR is missing.
     assert_eq!(rows(&eval), vec![1, 3, 4, 5]);
     assert!(eval.exact);
@@ -178,10 +240,51 @@ fn neq_excludes_tombstoned_rows() {
         field: "category".into(),
         value: json!("tech"),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![1, 3, 4]);
 }
 
+#[test]
+fn string_leaves_are_exact_under_collation() {
+    let state = sample_state();
+    let ci = Collation::CaseInsensitive;
+    let eq = Condition::Eq {
+        field: "category".into(),
+        value: json!("TECH"),
+    };
+    assert!(rows(&condition_bitmap(&state, &eq, Collation::Binary).expect("supported")).is_empty());
+    let eval = condition_bitmap(&state, &eq, ci).expect("supported");
+    assert_eq!(rows(&eval), vec![0, 2]);
+    assert!(eval.exact);
+
+    let neq = Condition::Neq {
+        field: "category".into(),
+        value: json!("TECH"),
+    };
+    assert_eq!(
+        rows(&condition_bitmap(&state, &neq, ci).expect("supported")),
+        vec![1, 3, 4, 5]
+    );
+
+    let in_list = Condition::In {
+        field: "category".into(),
+        values: vec![json!("Bio")],
+    };
+    assert_eq!(
+        rows(&condition_bitmap(&state, &in_list, ci).expect("supported")),
+        vec![1]
+    );
+
+    let like = Condition::Like {
+        field: "category".into(),
+        pattern: "TE%".into(),
+    };
+    assert_eq!(
+        rows(&condition_bitmap(&state, &like, ci).expect("supported")),
+        vec![0, 2]
+    );
+}
+
 #[test]
 fn in_list_on_strings_and_numbers() {
     let state = sample_state();
@@ -189,18 +292,37 @@ fn in_list_on_strings_and_numbers() {
         field: "category".into(),
         values: vec![json!("bio"), json!("nope")],
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![1]);
 
     let cond = Condition::In {
         field: "price".into(),
         values: vec![json!(10), json!(30)],
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![0, 2]);
     assert!(eval.exact);
 }
 
+#[test]
+fn large_unsorted_in_list_on_numbers_keeps_epsilon_semantics() {
+    let state = sample_state();
+    // 10k literals, unsorted, with the matches at both ends of the list.
+    let mut values: Vec<_> = (0..10_000)
+        .rev()
+        .map(|i| json!(f64::from(i) + 100.5))
+        .collect();
+    values.insert(0, json!(30.0 + f64::EPSILON / 4.0));
+    values.push(json!(20.5));
+    let cond = Condition::In {
+        field: "price".into(),
+        values,
+    };
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
+    assert_eq!(rows(&eval), vec![1, 2]);
+    assert!(eval.exact);
+}
+
 #[test]
 fn in_list_with_mixed_types_falls_back() {
     let state = sample_state();
@@ -208,7 +330,7 @@ fn in_list_with_mixed_types_falls_back() {
         field: "price".into(),
         values: vec![json!(10), json!("not-a-number")],
     };
-    assert!(condition_bitmap(&state, &cond).is_none());
+    assert!(condition_bitmap(&state, &cond, Collation::Binary).is_none());
 }
 
 #[test]
@@ -218,7 +340,7 @@ fn empty_in_list_is_exact_empty() {
         field: "category".into(),
         values: vec![],
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert!(eval.bits.is_empty());
     assert!(eval.exact);
 }
@@ -232,13 +354,14 @@ fn and_with_unsupported_branch_is_inexact_superset() {
                 field: "category".into(),
                 value: json!("tech"),
             },
-            Condition::Like {
+            Condition::Contains {
                 field: "category".into(),
-                pattern: "%ech".into(),
+                value: "ech".into(),
             },
         ],
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported branch present");
+    let eval =
+        condition_bitmap(&state, &cond, Collation::Binary).expect("supported branch present");
     assert_eq!(rows(&eval), vec![0, 2]); // superset from the Eq branch
     assert!(!eval.exact);
 }
@@ -247,7 +370,7 @@ fn and_with_unsupported_branch_is_inexact_superset() {
 fn empty_and_is_identity_over_live_rows() {
     let state = sample_state();
     let cond = Condition::And { conditions: vec![] };
-    let eval = condition_bitmap(&state, &cond).expect("identity");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("identity");
     assert_eq!(rows(&eval), vec![0, 1, 2, 3, 4, 5]);
     assert!(eval.exact);
 }
@@ -261,13 +384,13 @@ fn or_with_unsupported_branch_falls_back() {
                 field: "category".into(),
                 value: json!("tech"),
             },
-            Condition::Like {
+            Condition::Contains {
                 field: "category".into(),
-                pattern: "%bio".into(),
+                value: "bio".into(),
             },
         ],
     };
-    assert!(condition_bitmap(&state, &cond).is_none());
+    assert!(condition_bitmap(&state, &cond, Collation::Binary).is_none());
 }
 
 #[test]
@@ -285,7 +408,7 @@ fn or_unions_branches() {
             },
         ],
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![1, 2]);
     assert!(eval.exact);
 }
@@ -300,14 +423,14 @@ fn not_over_inexact_falls_back() {
                     field: "category".into(),
                     value: json!("tech"),
                 },
-                Condition::Like {
+                Condition::Contains {
                     field: "category".into(),
-                    pattern: "%x".into(),
+                    value: "x".into(),
                 },
             ],
         }),
     };
-    assert!(condition_bitmap(&state, &cond).is_none());
+    assert!(condition_bitmap(&state, &cond, Collation::Binary).is_none());
 }
 
 #[test]
@@ -319,7 +442,7 @@ fn not_over_exact_complements() {
             values: vec![json!("tech")],
         }),
     };
-    let eval = condition_bitmap(&state, &cond).expect("supported");
+    let eval = condition_bitmap(&state, &cond, Collation::Binary).expect("supported");
     assert_eq!(rows(&eval), vec![1, 3, 4, 5]);
     assert!(eval.exact);
 }
@@ -335,14 +458,14 @@ fn upsert_same_id_is_last_writer_wins() {
         field: "category".into(),
         value: json!("old"),
     };
-    let eval = condition_bitmap(&state, &old).expect("supported");
+    let eval = condition_bitmap(&state, &old, Collation::Binary).expect("supported");
     assert!(eval.bits.is_empty());
 
     let new = Condition::Eq {
         field: "category".into(),
         value: json!("new"),
     };
-    let eval = condition_bitmap(&state, &new).expect("supported");
+    let eval = condition_bitmap(&state, &new, Collation::Binary).expect("supported");
     assert_eq!(eval.bits.len(), 1);
 }
 
@@ -367,5 +490,5 @@ fn column_cap_routes_overflow_fields_to_fallback() {
         field: capped_field,
         value: json!(1),
     };
-    assert!(condition_bitmap(&state, &cond).is_none());
+    assert!(condition_bitmap(&state, &cond, Collation::Binary).is_none());
 }
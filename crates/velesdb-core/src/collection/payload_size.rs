@@ -50,6 +50,17 @@ impl Write for BoundedCounter {
     }
 }
 
+/// Returns whether `payload` serializes to more than `cap` bytes of JSON.
+///
+/// Serialization stops as soon as the cap is passed. A payload that fails to
+/// serialize for any other reason counts as within the cap.
+#[must_use]
+pub fn payload_exceeds(payload: &serde_json::Value, cap: usize) -> bool {
+    let mut counter = BoundedCounter::new(cap);
+    // Passing the cap is the only way the counter itself fails.
+    serde_json::to_writer(&mut counter, payload).is_err() && counter.exceeded()
+}
+
 #[cfg(test)]
 mod tests {
     use super::BoundedCounter;
@@ -0,0 +1,151 @@
+//! 2-D projections of a collection for visualization frontends.
+//!
+//! [`project`] lays out a sample of vectors in the plane and labels each one
+//! with a k-means cluster computed in the original space, so a scatter plot
+//! can colour points by cluster. Two layouts are available:
+//!
+//! - [`ProjectionMethod::Pca`] — the two principal components, always built.
+//! - [`ProjectionMethod::UmapApprox`] — a compact UMAP-style neighbour-graph
+//!   embedding, behind the `umap-approx` feature. It trades UMAP's
+//!   approximate-kNN machinery for an exact kNN on the sample.
+
+mod pca;
+#[cfg(feature = "umap-approx")]
+mod umap;
+
+#[cfg(test)]
+mod tests;
+
+use serde::{Deserialize, Serialize};
+
+use crate::quantization::pq_kmeans::kmeans_train;
+use crate::simd_native::squared_l2_native;
+
+/// Largest sample `Collection::project_2d` will draw; the UMAP neighbour
+/// graph is quadratic in the sample size.
+pub const MAX_PROJECTION_SAMPLE: usize = 2_000;
+
+/// Cluster count used when the caller does not pick one.
+pub const DEFAULT_PROJECTION_CLUSTERS: usize = 8;
+
+/// Seed for the k-means and UMAP optimisers, so a given sample always
+/// produces the same layout.
+const PROJECTION_SEED: u64 = 0x005E_ED2D;
+
+/// Layout algorithm for [`project`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProjectionMethod {
+    /// Principal component analysis (linear, fast, preserves global shape).
+    Pca,
+    /// Approximate UMAP (non-linear, preserves local neighbourhoods).
+    #[cfg(feature = "umap-approx")]
+    UmapApprox,
+}
+
+impl ProjectionMethod {
+    /// Parses `"pca"` or `"umap_approx"` (case-insensitive). The latter only
+    /// resolves when the `umap-approx` feature is compiled in.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "pca" => Some(Self::Pca),
+            #[cfg(feature = "umap-approx")]
+            "umap_approx" | "umap" => Some(Self::UmapApprox),
+            _ => None,
+        }
+    }
+}
+
+/// One point of a 2-D layout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectedPoint {
+    /// Point id.
+    pub id: u64,
+    /// Horizontal coordinate.
+    pub x: f32,
+    /// Vertical coordinate.
+    pub y: f32,
+    /// k-means cluster label in `0..cluster_count`.
+    pub cluster: usize,
+}
+
+/// A 2-D layout of a sample of the collection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Projection2d {
+    /// Algorithm that produced the layout.
+    pub method: ProjectionMethod,
+    /// Projected points, in sample order.
+    pub points: Vec<ProjectedPoint>,
+    /// Number of distinct cluster labels.
+    pub cluster_count: usize,
+    /// Share of the total variance captured by each axis (PCA only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explained_variance_ratio: Option<[f32; 2]>,
+}
+
+/// Projects `vectors` (paired with `ids`) to 2-D and labels them with up to
+/// `clusters` k-means clusters.
+///
+/// Vectors are expected to be pre-normalized by the caller when the
+/// collection uses cosine similarity, so Euclidean geometry matches it.
+#[must_use]
+pub fn project(
+    ids: &[u64],
+    vectors: &[Vec<f32>],
+    method: ProjectionMethod,
+    clusters: usize,
+) -> Projection2d {
+    debug_assert_eq!(ids.len(), vectors.len());
+    let (coords, explained_variance_ratio) = match method {
+        ProjectionMethod::Pca => {
+            let layout = pca::project(vectors);
+            (layout.coords, Some(layout.explained_variance_ratio))
+        }
+        #[cfg(feature = "umap-approx")]
+        ProjectionMethod::UmapApprox => (umap::project(vectors, PROJECTION_SEED), None),
+    };
+    let (labels, cluster_count) = cluster_labels(vectors, clusters);
+
+    let points = ids
+        .iter()
+        .zip(coords)
+        .zip(labels)
+        .map(|((&id, [x, y]), cluster)| ProjectedPoint { id, x, y, cluster })
+        .collect();
+    Projection2d {
+        method,
+        points,
+        cluster_count,
+        explained_variance_ratio,
+    }
+}
+
+/// Assigns each vector to its nearest of `k` k-means centroids.
+fn cluster_labels(vectors: &[Vec<f32>], k: usize) -> (Vec<usize>, usize) {
+    let k = k.min(vectors.len());
+    if k == 0 {
+        return (vec![0; vectors.len()], 0);
+    }
+    let centroids = kmeans_train(
+        vectors,
+        k,
+        25,
+        PROJECTION_SEED,
+        #[cfg(feature = "gpu")]
+        None,
+    );
+    let labels = vectors
+        .iter()
+        .map(|v| {
+            centroids
+                .iter()
+                .map(|c| squared_l2_native(v, c))
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(0, |(i, _)| i)
+        })
+        .collect();
+    (labels, k)
+}
@@ -0,0 +1,133 @@
+//! Two-component PCA by power iteration.
+//!
+//! The covariance matrix is never materialised: each iteration computes
+//! `Xᵀ(X·v)` on the centred sample, which costs `O(n · d)` instead of the
+//! `O(d²)` memory a 1536-d covariance would need.
+
+/// Power-iteration cap per component.
+const MAX_ITERS: usize = 100;
+
+/// Stop once the direction moves less than this between iterations.
+const TOLERANCE: f64 = 1e-9;
+
+/// Output of [`project`].
+pub(super) struct PcaLayout {
+    /// `[pc1, pc2]` coordinates per input vector.
+    pub coords: Vec<[f32; 2]>,
+    /// Fraction of the total variance captured by each component.
+    pub explained_variance_ratio: [f32; 2],
+}
+
+/// Projects `vectors` onto their first two principal components.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+// Reason: coordinates are reported in f32; n is bounded by the sample cap.
+pub(super) fn project(vectors: &[Vec<f32>]) -> PcaLayout {
+    let n = vectors.len();
+    let dim = vectors.first().map_or(0, Vec::len);
+    if n == 0 || dim == 0 {
+        return PcaLayout {
+            coords: vec![[0.0, 0.0]; n],
+            explained_variance_ratio: [0.0, 0.0],
+        };
+    }
+
+    let mut mean = vec![0.0f64; dim];
+    for v in vectors {
+        for (m, &x) in mean.iter_mut().zip(v) {
+            *m += f64::from(x);
+        }
+    }
+    for m in &mut mean {
+        *m /= n as f64;
+    }
+    let centred: Vec<Vec<f64>> = vectors
+        .iter()
+        .map(|v| {
+            v.iter()
+                .zip(&mean)
+                .map(|(&x, m)| f64::from(x) - m)
+                .collect()
+        })
+        .collect();
+    let total_variance: f64 = centred.iter().flatten().map(|x| x * x).sum();
+
+    let pc1 = principal_direction(&centred, None);
+    let pc2 = principal_direction(&centred, Some(&pc1));
+
+    let mut variance = [0.0f64; 2];
+    let coords = centred
+        .iter()
+        .map(|row| {
+            let x = dot(row, &pc1);
+            let y = dot(row, &pc2);
+            variance[0] += x * x;
+            variance[1] += y * y;
+            [x as f32, y as f32]
+        })
+        .collect();
+    let ratio = |v: f64| {
+        if total_variance > 0.0 {
+            (v / total_variance) as f32
+        } else {
+            0.0
+        }
+    };
+
+    PcaLayout {
+        coords,
+        explained_variance_ratio: [ratio(variance[0]), ratio(variance[1])],
+    }
+}
+
+/// Leading eigenvector of `XᵀX`, orthogonal to `exclude` when given.
+fn principal_direction(centred: &[Vec<f64>], exclude: Option<&[f64]>) -> Vec<f64> {
+    let dim = centred[0].len();
+    // Deterministic start that is unlikely to be orthogonal to the answer.
+    #[allow(clippy::cast_precision_loss)] // Reason: dim ≤ MAX_DIMENSION.
+    let mut v: Vec<f64> = (0..dim).map(|i| 1.0 + (i as f64 * 0.618).fract()).collect();
+    orthonormalize(&mut v, exclude);
+
+    for _ in 0..MAX_ITERS {
+        let mut next = vec![0.0f64; dim];
+        for row in centred {
+            let proj = dot(row, &v);
+            for (acc, x) in next.iter_mut().zip(row) {
+                *acc += proj * x;
+            }
+        }
+        if !orthonormalize(&mut next, exclude) {
+            // No variance left in the remaining subspace.
+            return next;
+        }
+        let delta: f64 = next.iter().zip(&v).map(|(a, b)| (a - b).abs()).sum();
+        v = next;
+        if delta < TOLERANCE {
+            break;
+        }
+    }
+    v
+}
+
+/// Removes the `exclude` component and scales to unit length. Returns
+/// `false` (leaving a zero vector) when nothing remains.
+fn orthonormalize(v: &mut [f64], exclude: Option<&[f64]>) -> bool {
+    if let Some(e) = exclude {
+        let overlap = dot(v, e);
+        for (x, e) in v.iter_mut().zip(e) {
+            *x -= overlap * e;
+        }
+    }
+    let norm = dot(v, v).sqrt();
+    if norm <= f64::EPSILON {
+        v.fill(0.0);
+        return false;
+    }
+    for x in v.iter_mut() {
+        *x /= norm;
+    }
+    true
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
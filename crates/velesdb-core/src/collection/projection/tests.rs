@@ -0,0 +1,106 @@
+//! Tests for 2-D projections.
+
+use super::*;
+use rand::{RngExt, SeedableRng};
+
+/// Three tight, well-separated blobs in 6-D, `per_blob` points each.
+fn blobs(per_blob: usize) -> (Vec<u64>, Vec<Vec<f32>>) {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(9);
+    let centres = [[10.0f32, 0.0], [-10.0, 0.0], [0.0, 10.0]];
+    let mut vectors = Vec::new();
+    for centre in centres {
+        for _ in 0..per_blob {
+            let mut v: Vec<f32> = (0..6).map(|_| rng.random_range(-0.5f32..0.5)).collect();
+            v[0] += centre[0];
+            v[1] += centre[1];
+            vectors.push(v);
+        }
+    }
+    let ids = (0..vectors.len() as u64).collect();
+    (ids, vectors)
+}
+
+fn assert_blobs_clustered(projection: &Projection2d, per_blob: usize) {
+    for blob in projection.points.chunks(per_blob) {
+        assert!(
+            blob.iter().all(|p| p.cluster == blob[0].cluster),
+            "a blob was split across clusters"
+        );
+    }
+    let labels: std::collections::HashSet<usize> =
+        projection.points.iter().map(|p| p.cluster).collect();
+    assert_eq!(labels.len(), 3);
+}
+
+#[test]
+fn test_parse_projection_method() {
+    assert_eq!(ProjectionMethod::parse("PCA"), Some(ProjectionMethod::Pca));
+    assert_eq!(ProjectionMethod::parse("tsne"), None);
+    #[cfg(feature = "umap-approx")]
+    assert_eq!(
+        ProjectionMethod::parse("umap_approx"),
+        Some(ProjectionMethod::UmapApprox)
+    );
+    #[cfg(not(feature = "umap-approx"))]
+    assert_eq!(ProjectionMethod::parse("umap_approx"), None);
+}
+
+#[test]
+fn test_pca_recovers_dominant_axes() {
+    let (ids, vectors) = blobs(20);
+    let projection = project(&ids, &vectors, ProjectionMethod::Pca, 3);
+
+    assert_eq!(projection.points.len(), 60);
+    assert_eq!(projection.cluster_count, 3);
+    let [first, second] = projection.explained_variance_ratio.expect("pca ratio");
+    assert!(
+        first + second > 0.95,
+        "blobs live in a plane: {first} + {second}"
+    );
+    assert!(first >= second);
+    assert_blobs_clustered(&projection, 20);
+
+    // Blob 0 and blob 1 sit on opposite sides along the first component.
+    let x0 = projection.points[0].x;
+    let x1 = projection.points[20].x;
+    assert!(x0 * x1 < 0.0, "{x0} vs {x1}");
+}
+
+#[test]
+fn test_projection_of_empty_and_tiny_samples() {
+    let empty = project(&[], &[], ProjectionMethod::Pca, 8);
+    assert!(empty.points.is_empty());
+    assert_eq!(empty.cluster_count, 0);
+
+    let single = project(&[7], &[vec![1.0, 2.0]], ProjectionMethod::Pca, 8);
+    assert_eq!(single.points.len(), 1);
+    assert_eq!(single.points[0].id, 7);
+    assert_eq!(single.cluster_count, 1);
+}
+
+#[cfg(feature = "umap-approx")]
+#[test]
+#[allow(clippy::cast_precision_loss)] // Reason: blob sizes are tiny.
+fn test_umap_approx_keeps_blobs_apart() {
+    let (ids, vectors) = blobs(20);
+    let projection = project(&ids, &vectors, ProjectionMethod::UmapApprox, 3);
+    assert!(projection.explained_variance_ratio.is_none());
+    assert_blobs_clustered(&projection, 20);
+
+    let centroid = |blob: &[ProjectedPoint]| {
+        let n = blob.len() as f32;
+        let (sx, sy) = blob.iter().fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+        (sx / n, sy / n)
+    };
+    let spread = |blob: &[ProjectedPoint]| {
+        let (cx, cy) = centroid(blob);
+        blob.iter()
+            .map(|p| ((p.x - cx).powi(2) + (p.y - cy).powi(2)).sqrt())
+            .fold(0.0f32, f32::max)
+    };
+    let (a, b) = (&projection.points[..20], &projection.points[20..40]);
+    let (ax, ay) = centroid(a);
+    let (bx, by) = centroid(b);
+    let gap = ((ax - bx).powi(2) + (ay - by).powi(2)).sqrt();
+    assert!(gap > spread(a) + spread(b), "blobs overlap in the layout");
+}
@@ -0,0 +1,174 @@
+//! Approximate UMAP layout (feature `umap-approx`).
+//!
+//! Follows the reference algorithm (McInnes et al., 2018) at small scale:
+//! an exact k-nearest-neighbour graph on the sample, smooth-kNN fuzzy
+//! memberships, fuzzy-union symmetrisation, a PCA initial layout and SGD
+//! with negative sampling. The spectral initialisation and approximate
+//! NN-descent of the reference implementation are left out — the sample is
+//! small enough for exact kNN, and PCA is a good enough starting point.
+
+use std::collections::HashMap;
+
+use rand::{RngExt, SeedableRng};
+
+use crate::simd_native::squared_l2_native;
+
+/// Neighbours per point in the fuzzy graph.
+const N_NEIGHBORS: usize = 15;
+/// Optimisation epochs.
+const N_EPOCHS: usize = 200;
+/// Repulsive samples per attractive update.
+const NEGATIVE_SAMPLES: usize = 5;
+/// Curve parameters fitted for `min_dist = 0.1`, `spread = 1.0`.
+const CURVE_A: f32 = 1.577;
+const CURVE_B: f32 = 0.895;
+/// Gradient clip, as in the reference implementation.
+const GRAD_CLIP: f32 = 4.0;
+/// Initial layout is scaled into `[-INIT_SCALE, INIT_SCALE]`.
+const INIT_SCALE: f32 = 10.0;
+
+/// Returns a 2-D UMAP-style layout of `vectors`.
+pub(super) fn project(vectors: &[Vec<f32>], seed: u64) -> Vec<[f32; 2]> {
+    let n = vectors.len();
+    if n < 3 {
+        return super::pca::project(vectors).coords;
+    }
+    let k = N_NEIGHBORS.min(n - 1);
+    let edges = fuzzy_graph(vectors, k);
+    let mut layout = initial_layout(vectors);
+    optimize(&mut layout, &edges, seed);
+    layout
+}
+
+/// Symmetrised fuzzy simplicial set as `(i, j, weight)` edges.
+fn fuzzy_graph(vectors: &[Vec<f32>], k: usize) -> Vec<(usize, usize, f32)> {
+    let n = vectors.len();
+    let mut weights: HashMap<(usize, usize), f32> = HashMap::new();
+    for i in 0..n {
+        let mut neighbours: Vec<(usize, f32)> = (0..n)
+            .filter(|&j| j != i)
+            .map(|j| (j, squared_l2_native(&vectors[i], &vectors[j]).sqrt()))
+            .collect();
+        neighbours.select_nth_unstable_by(k - 1, |a, b| a.1.total_cmp(&b.1));
+        neighbours.truncate(k);
+
+        let rho = neighbours
+            .iter()
+            .map(|&(_, d)| d)
+            .filter(|&d| d > 0.0)
+            .fold(f32::INFINITY, f32::min);
+        let rho = if rho.is_finite() { rho } else { 0.0 };
+        let sigma = smooth_knn_sigma(&neighbours, rho, k);
+
+        for &(j, d) in &neighbours {
+            let w = (-((d - rho).max(0.0)) / sigma).exp();
+            // Fuzzy union: w_ij + w_ji - w_ij * w_ji.
+            let entry = weights.entry((i.min(j), i.max(j))).or_insert(0.0);
+            *entry = *entry + w - *entry * w;
+        }
+    }
+    weights
+        .into_iter()
+        .filter(|&(_, w)| w > 0.0)
+        .map(|((i, j), w)| (i, j, w))
+        .collect()
+}
+
+/// Binary-searches `sigma` so the memberships sum to `log2(k)`.
+fn smooth_knn_sigma(neighbours: &[(usize, f32)], rho: f32, k: usize) -> f32 {
+    #[allow(clippy::cast_precision_loss)] // Reason: k ≤ N_NEIGHBORS.
+    let target = (k as f32).log2();
+    let (mut lo, mut hi, mut sigma) = (0.0f32, f32::INFINITY, 1.0f32);
+    for _ in 0..64 {
+        let sum: f32 = neighbours
+            .iter()
+            .map(|&(_, d)| (-((d - rho).max(0.0)) / sigma).exp())
+            .sum();
+        if (sum - target).abs() < 1e-5 {
+            break;
+        }
+        if sum > target {
+            hi = sigma;
+            sigma = f32::midpoint(lo, hi);
+        } else {
+            lo = sigma;
+            sigma = if hi.is_finite() {
+                f32::midpoint(lo, hi)
+            } else {
+                sigma * 2.0
+            };
+        }
+    }
+    sigma.max(1e-3)
+}
+
+/// PCA layout rescaled into the range UMAP's optimiser expects.
+fn initial_layout(vectors: &[Vec<f32>]) -> Vec<[f32; 2]> {
+    let mut layout = super::pca::project(vectors).coords;
+    let extent = layout
+        .iter()
+        .flat_map(|p| p.iter().map(|c| c.abs()))
+        .fold(0.0f32, f32::max);
+    if extent > 0.0 {
+        let scale = INIT_SCALE / extent;
+        for c in layout.iter_mut().flatten() {
+            *c *= scale;
+        }
+    }
+    layout
+}
+
+/// SGD over the edges with negative sampling and a linearly decaying rate.
+fn optimize(layout: &mut [[f32; 2]], edges: &[(usize, usize, f32)], seed: u64) {
+    let n = layout.len();
+    let max_weight = edges.iter().map(|e| e.2).fold(0.0f32, f32::max);
+    if max_weight <= 0.0 {
+        return;
+    }
+    // Strong edges are sampled every epoch, weak ones proportionally less.
+    let epochs_per_sample: Vec<f32> = edges.iter().map(|e| max_weight / e.2).collect();
+    let mut next_epoch = epochs_per_sample.clone();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+
+    for epoch in 0..N_EPOCHS {
+        #[allow(clippy::cast_precision_loss)] // Reason: epoch ≤ N_EPOCHS.
+        let (now, alpha) = (epoch as f32, 1.0 - epoch as f32 / N_EPOCHS as f32);
+        for (e, &(i, j, _)) in edges.iter().enumerate() {
+            if next_epoch[e] > now + 1.0 {
+                continue;
+            }
+            next_epoch[e] += epochs_per_sample[e];
+
+            let d2 = dist2(layout[i], layout[j]);
+            if d2 > 0.0 {
+                let coeff = -2.0 * CURVE_A * CURVE_B * d2.powf(CURVE_B - 1.0)
+                    / (1.0 + CURVE_A * d2.powf(CURVE_B));
+                for c in 0..2 {
+                    let grad = (coeff * (layout[i][c] - layout[j][c])).clamp(-GRAD_CLIP, GRAD_CLIP);
+                    layout[i][c] += alpha * grad;
+                    layout[j][c] -= alpha * grad;
+                }
+            }
+            for _ in 0..NEGATIVE_SAMPLES {
+                let other = rng.random_range(0..n);
+                if other == i {
+                    continue;
+                }
+                let d2 = dist2(layout[i], layout[other]);
+                let coeff = 2.0 * CURVE_B / ((0.001 + d2) * (1.0 + CURVE_A * d2.powf(CURVE_B)));
+                for c in 0..2 {
+                    let grad = if d2 > 0.0 {
+                        (coeff * (layout[i][c] - layout[other][c])).clamp(-GRAD_CLIP, GRAD_CLIP)
+                    } else {
+                        GRAD_CLIP
+                    };
+                    layout[i][c] += alpha * grad;
+                }
+            }
+        }
+    }
+}
+
+fn dist2(a: [f32; 2], b: [f32; 2]) -> f32 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)
+}
@@ -0,0 +1,126 @@
+//! Collection-level defaults for query-time search options.
+//!
+//! `WITH (ef_search = 200, rerank = true)` and `USING FUSION(...)` tune a
+//! single query. Repeating them on every query is tedious, so a collection
+//! can record defaults in `config.json` (`ALTER COLLECTION ... SET`) that
+//! apply whenever a query omits the option. An option the query sets itself
+//! always wins.
+//!
+//! The same record holds the collection's string [`Collation`]: it applies to
+//! every filter (VelesQL `WHERE` or the native [`Filter`](crate::filter::Filter)
+//! API) that does not set a collation of its own.
+
+use serde::{Deserialize, Serialize};
+
+use crate::filter::Collation;
+use crate::velesql::{FusionClause, FusionStrategyType, SelectStatement, WithValue};
+
+/// Search-option defaults applied to every SELECT against a collection.
+///
+/// Stored in [`CollectionConfig::query_defaults`](crate::collection::CollectionConfig)
+/// via `Collection::set_query_defaults`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryDefaults {
+    /// Default for `WITH (ef_search = N)`. Not applied to a query that sets
+    /// `mode` (or `quality`) itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ef_search: Option<usize>,
+    /// Default for `WITH (rerank = true|false)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank: Option<bool>,
+    /// Default `USING FUSION(strategy = ...)` for hybrid queries, with the
+    /// strategy's default parameters.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "fusion_strategy_name"
+    )]
+    pub fusion: Option<FusionStrategyType>,
+    /// String collation for filters that do not set one
+    /// ([`Filter::collation`](crate::filter::Filter::collation)). `None`
+    /// compares strings byte-for-byte.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collation: Option<Collation>,
+}
+
+impl QueryDefaults {
+    /// Returns `true` when no default is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ef_search.is_none()
+            && self.rerank.is_none()
+            && self.fusion.is_none()
+            && self.collation.is_none()
+    }
+
+    /// Returns a copy of `stmt` with every default the statement does not set
+    /// itself filled in, or `None` when nothing needs filling.
+    #[must_use]
+    pub(crate) fn apply_to(&self, stmt: &SelectStatement) -> Option<SelectStatement> {
+        let with = stmt.with_clause.as_ref();
+        // `ef_search` overrides `mode`, so a query that picks a mode must not
+        // have it silently replaced by the default.
+        let ef_search = self.ef_search.filter(|_| {
+            with.is_none_or(|w| w.get("ef_search").is_none() && w.get_mode().is_none())
+        });
+        let rerank = self
+            .rerank
+            .filter(|_| with.and_then(|w| w.get("rerank")).is_none());
+        let fusion = self.fusion.filter(|_| stmt.fusion_clause.is_none());
+        if ef_search.is_none() && rerank.is_none() && fusion.is_none() {
+            return None;
+        }
+
+        let mut stmt = stmt.clone();
+        if ef_search.is_some() || rerank.is_some() {
+            let mut clause = stmt.with_clause.take().unwrap_or_default();
+            if let Some(ef) = ef_search {
+                clause = clause.with_option(
+                    "ef_search",
+                    WithValue::Integer(i64::try_from(ef).unwrap_or(i64::MAX)),
+                );
+            }
+            if let Some(rerank) = rerank {
+                clause = clause.with_option("rerank", WithValue::Boolean(rerank));
+            }
+            stmt.with_clause = Some(clause);
+        }
+        if let Some(strategy) = fusion {
+            stmt.fusion_clause = Some(FusionClause {
+                strategy,
+                ..FusionClause::default()
+            });
+        }
+        Some(stmt)
+    }
+}
+
+/// Serializes the fusion strategy by its VelesQL name (`"rrf"`, `"rsf"`, ...).
+mod fusion_strategy_name {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::velesql::FusionStrategyType;
+
+    #[allow(clippy::ref_option, clippy::trivially_copy_pass_by_ref)] // Reason: signature imposed by `#[serde(with)]`.
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<FusionStrategyType>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(strategy) => serializer.serialize_some(strategy.as_str()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<FusionStrategyType>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|name| {
+                FusionStrategyType::parse(&name).ok_or_else(|| {
+                    serde::de::Error::custom(format!("unknown fusion strategy '{name}'"))
+                })
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,116 @@
+//! Snapshot sequence numbers and lock-based consistent scans.
+//!
+//! An upsert writes payload storage and vector storage under two separate
+//! locks, one after the other. Without coordination a scan that takes both
+//! read locks between the two writes sees new payloads next to old vectors —
+//! a half-applied batch.
+//!
+//! Every multi-storage write runs inside a [`CommitGuard`] taken from the
+//! collection's [`CommitSequence`]; dropping the guard publishes the next
+//! snapshot sequence number. Scans and aggregations call
+//! [`Collection::read_snapshot`], which takes both storage read guards
+//! between two commits: the writes of one commit are either all visible or
+//! none are.
+//!
+//! This is read consistency by locking, not a versioned (MVCC) view: no
+//! data is copied or versioned, the storage read guards themselves keep
+//! later commits out. Every upsert, bulk import and delete on the
+//! collection blocks until the scan drops its [`ReadSnapshot`], so a long
+//! scan or aggregation stalls writers for its whole duration.
+//!
+//! The isolation level of each operation is documented in
+//! `docs/CONCURRENCY_MODEL.md` ("Read isolation").
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::collection::types::Collection;
+use crate::storage::{LogPayloadStorage, MmapStorage};
+
+/// Commit gate and snapshot sequence counter of one collection.
+///
+/// Lock order position: **1c** — after `config` (1) and `payload_mirror`
+/// (1b), before `vector_storage` (2).
+#[derive(Debug, Default)]
+pub(crate) struct CommitSequence {
+    gate: RwLock<()>,
+    committed: AtomicU64,
+}
+
+impl CommitSequence {
+    /// Starts a commit: scans opened from now on wait until the returned
+    /// guard is dropped.
+    ///
+    /// Must not be called while holding a [`ReadSnapshot`] or any storage
+    /// guard.
+    pub(crate) fn begin_commit(&self) -> CommitGuard<'_> {
+        CommitGuard {
+            _gate: self.gate.write(),
+            committed: &self.committed,
+        }
+    }
+
+    /// Sequence number of the last finished commit.
+    pub(crate) fn current(&self) -> u64 {
+        self.committed.load(Ordering::Acquire)
+    }
+}
+
+/// An in-flight commit; publishes the next sequence number when dropped.
+pub(crate) struct CommitGuard<'a> {
+    _gate: RwLockWriteGuard<'a, ()>,
+    committed: &'a AtomicU64,
+}
+
+impl Drop for CommitGuard<'_> {
+    fn drop(&mut self) {
+        // Published while the gate is still held: a scan never observes the
+        // new sequence without the writes it stands for.
+        self.committed.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+/// Vector and payload storage pinned at one committed sequence number.
+pub(crate) struct ReadSnapshot<'a> {
+    /// Commits visible through this snapshot.
+    pub(crate) sequence: u64,
+    /// Vector storage read guard.
+    pub(crate) vectors: RwLockReadGuard<'a, MmapStorage>,
+    /// Payload storage read guard.
+    pub(crate) payloads: RwLockReadGuard<'a, LogPayloadStorage>,
+}
+
+impl Collection {
+    /// Read-locks vector and payload storage between two commits for a scan.
+    ///
+    /// Waits for an in-flight commit to finish, so the view never contains
+    /// half of a batch, then blocks every later commit until the returned
+    /// guards are dropped: keep the scan short. Do not open a second
+    /// snapshot while holding one.
+    pub(crate) fn read_snapshot(&self) -> ReadSnapshot<'_> {
+        // LOCK ORDER: commit gate(1c) → vector_storage(2) → payload_storage(3).
+        // The gate is only held while the storage guards are acquired; the
+        // storage guards alone keep later commits (and their writers) out
+        // until the scan ends.
+        let gate = self.storage.commits.gate.read();
+        let vectors = self.storage.vector_storage.read();
+        let payloads = self.storage.payload_storage.read();
+        let sequence = self.storage.commits.current();
+        drop(gate);
+        ReadSnapshot {
+            sequence,
+            vectors,
+            payloads,
+        }
+    }
+
+    /// Sequence number of the last write committed to this collection.
+    ///
+    /// Starts at 0 when the collection is opened and increases by one per
+    /// upsert batch or delete.
+    #[must_use]
+    pub(crate) fn snapshot_sequence(&self) -> u64 {
+        self.storage.commits.current()
+    }
+}
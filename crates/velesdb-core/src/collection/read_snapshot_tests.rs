@@ -0,0 +1,109 @@
+//! Tests for snapshot sequence numbers and consistent scan views.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde_json::json;
+
+use crate::collection::VectorCollection;
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+use crate::quantization::StorageMode;
+use crate::storage::VectorStorage;
+
+fn create(path: &Path) -> VectorCollection {
+    VectorCollection::create(
+        path.to_path_buf(),
+        "c",
+        2,
+        DistanceMetric::Euclidean,
+        StorageMode::Full,
+    )
+    .expect("create collection")
+}
+
+/// Every point of round `round` carries the round in its vector and payload.
+fn round_points(round: u16) -> Vec<Point> {
+    (1..=50)
+        .map(|id| {
+            Point::new(
+                id,
+                vec![f32::from(round), 1.0],
+                Some(json!({ "round": round })),
+            )
+        })
+        .collect()
+}
+
+fn assert_untorn(point: &Point) {
+    let round = point
+        .payload
+        .as_ref()
+        .and_then(|p| p["round"].as_f64())
+        .expect("round payload");
+    #[allow(clippy::cast_possible_truncation)]
+    let payload_round = round as f32;
+    assert!(
+        (point.vector[0] - payload_round).abs() < f32::EPSILON,
+        "point {} pairs payload round {round} with vector {:?}",
+        point.id,
+        point.vector
+    );
+}
+
+#[test]
+fn test_snapshot_sequence_counts_commits() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"));
+    assert_eq!(coll.snapshot_sequence(), 0);
+
+    coll.upsert(round_points(1)).expect("upsert");
+    assert_eq!(coll.snapshot_sequence(), 1);
+    coll.upsert_bulk(&round_points(2)).expect("bulk upsert");
+    assert_eq!(coll.snapshot_sequence(), 2);
+    coll.delete(&[1, 2]).expect("delete");
+    assert_eq!(coll.snapshot_sequence(), 3);
+
+    let snapshot = coll.inner.read_snapshot();
+    assert_eq!(snapshot.sequence, 3);
+    assert_eq!(snapshot.vectors.ids().len(), 48);
+}
+
+#[test]
+fn test_scans_never_see_half_applied_upserts() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let coll = create(&dir.path().join("c"));
+    coll.upsert(round_points(0)).expect("seed");
+    let done = AtomicBool::new(false);
+    let params = HashMap::new();
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            for round in 1..=60 {
+                coll.upsert(round_points(round)).expect("upsert");
+            }
+            done.store(true, Ordering::Release);
+        });
+
+        let mut scans = 0;
+        while !done.load(Ordering::Acquire) || scans == 0 {
+            let rows = coll
+                .execute_query_str("SELECT * FROM c WHERE round >= 0 LIMIT 100", &params)
+                .expect("scan");
+            assert_eq!(rows.len(), 50);
+            for row in &rows {
+                assert_untorn(&row.point);
+            }
+            for point in coll.get(&[1, 25, 50]).into_iter().flatten() {
+                assert_untorn(&point);
+            }
+            scans += 1;
+        }
+    });
+
+    let rows = coll
+        .execute_query_str("SELECT * FROM c WHERE round = 60 LIMIT 100", &params)
+        .expect("final scan");
+    assert_eq!(rows.len(), 50);
+}
@@ -0,0 +1,152 @@
+//! Named scoring profiles stored with a collection.
+//!
+//! A relevance setup — fusion strategy, `field_boost`, time decay, rerank —
+//! spans several `WITH` options and a `USING FUSION` clause. A collection can
+//! record such a setup under a name in `config.json`, and a query opts into
+//! it with `WITH (profile = 'news_ranking')`, so every client ranks the same
+//! way without repeating the options.
+//!
+//! Profile options fill whatever the query leaves unset; an option the query
+//! sets itself always wins, and collection [`QueryDefaults`] only fill what
+//! neither sets.
+//!
+//! [`QueryDefaults`]: crate::collection::QueryDefaults
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::velesql::{FusionClause, SelectStatement, WithClause, WithValue};
+
+/// `WITH` key a query names its scoring profile with.
+pub(crate) const PROFILE_OPTION: &str = "profile";
+
+/// Longest accepted profile name, in bytes.
+const MAX_PROFILE_NAME_LEN: usize = 64;
+
+/// Search options a query picks up with `WITH (profile = '<name>')`.
+///
+/// Stored in [`CollectionConfig::scoring_profiles`](crate::collection::CollectionConfig)
+/// via `Collection::set_scoring_profile`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    /// `WITH` options of the profile, e.g. `rerank`, `ef_search`, `metric`,
+    /// `decay_field` / `half_life`. Values are strings, numbers or booleans.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub with: BTreeMap<String, serde_json::Value>,
+    /// `USING FUSION` clause for queries that set none, e.g. a
+    /// `field_boost` on a popularity counter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fusion: Option<FusionClause>,
+}
+
+impl ScoringProfile {
+    /// Returns `true` when the profile sets nothing.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.with.is_empty() && self.fusion.is_none()
+    }
+
+    /// Checks the profile before it is stored under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] for an invalid name, an empty profile, a
+    /// nested `profile` option, a non-scalar option value, a non-positive
+    /// `ef_search` or invalid time-decay options.
+    pub(crate) fn validate(&self, name: &str) -> Result<()> {
+        validate_profile_name(name)?;
+        if self.is_empty() {
+            return Err(Error::Config(format!(
+                "scoring profile '{name}' sets no option"
+            )));
+        }
+        let with = self.with_clause()?;
+        if with.get(PROFILE_OPTION).is_some() {
+            return Err(Error::Config(format!(
+                "scoring profile '{name}' cannot reference another profile"
+            )));
+        }
+        if with
+            .get("ef_search")
+            .is_some_and(|v| v.as_integer().is_none_or(|ef| ef <= 0))
+        {
+            return Err(Error::Config(format!(
+                "scoring profile '{name}': ef_search must be a positive integer"
+            )));
+        }
+        crate::collection::search::query::TimeDecay::from_with_clause(Some(&with))
+            .map_err(|e| Error::Config(format!("scoring profile '{name}': {e}")))?;
+        Ok(())
+    }
+
+    /// Returns a copy of `stmt` with every profile option the statement does
+    /// not set itself filled in.
+    #[must_use]
+    pub(crate) fn apply_to(&self, stmt: &SelectStatement) -> SelectStatement {
+        let mut stmt = stmt.clone();
+        let mut clause = stmt.with_clause.take().unwrap_or_default();
+        // `ef_search` overrides `mode`, so a query that picks a mode keeps it.
+        let query_sets_mode = clause.get_mode().is_some();
+        for (key, value) in &self.with {
+            if clause.get(key).is_some() || (query_sets_mode && key == "ef_search") {
+                continue;
+            }
+            if let Some(value) = with_value(value) {
+                clause = clause.with_option(key.clone(), value);
+            }
+        }
+        stmt.with_clause = Some(clause);
+        if stmt.fusion_clause.is_none() {
+            stmt.fusion_clause.clone_from(&self.fusion);
+        }
+        stmt
+    }
+
+    /// The profile options as a VelesQL WITH clause.
+    fn with_clause(&self) -> Result<WithClause> {
+        self.with
+            .iter()
+            .try_fold(WithClause::new(), |clause, (key, value)| {
+                let value = with_value(value).ok_or_else(|| {
+                    Error::Config(format!(
+                        "scoring profile option '{key}' must be a string, number or boolean, \
+                         got {value}"
+                    ))
+                })?;
+                Ok(clause.with_option(key.clone(), value))
+            })
+    }
+}
+
+/// Maps a scalar JSON value to a WITH value; `None` for null, arrays and
+/// objects.
+fn with_value(value: &serde_json::Value) -> Option<WithValue> {
+    match value {
+        serde_json::Value::String(s) => Some(WithValue::String(s.clone())),
+        serde_json::Value::Bool(b) => Some(WithValue::Boolean(*b)),
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .map(WithValue::Integer)
+            .or_else(|| n.as_f64().map(WithValue::Float)),
+        _ => None,
+    }
+}
+
+/// Profile names are 1-64 ASCII letters, digits, `_` or `-`.
+fn validate_profile_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_PROFILE_NAME_LEN
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Config(format!(
+            "invalid scoring profile name '{name}': use 1-{MAX_PROFILE_NAME_LEN} letters, \
+             digits, '_' or '-'"
+        )))
+    }
+}
@@ -1,11 +1,12 @@
 //! Batch and multi-query search methods for Collection.
 
 use super::resolve;
+use crate::collection::access_stats::PayloadReader;
 use crate::collection::types::Collection;
 use crate::error::{Error, Result};
 use crate::index::SearchQuality;
 use crate::point::{Point, SearchResult};
-use crate::storage::{PayloadStorage, VectorStorage};
+use crate::storage::VectorStorage;
 use crate::validation::validate_dimension_match;
 use rayon::prelude::*;
 
@@ -68,14 +69,18 @@ impl Collection {
 
         // Parallel filter + resolve across queries (P0 QPS optimization)
         let vs: &dyn VectorStorage = &*vector_storage;
-        let ps: &dyn PayloadStorage = &*payload_storage;
+        let payloads = self.payload_reader(&*payload_storage);
 
         let all_results: Vec<Vec<SearchResult>> = merged
             .par_iter()
             .zip(filters.par_iter())
             .map(|(query_results, filter_opt)| {
-                let mut filtered =
-                    Self::filter_and_resolve_batch(query_results, filter_opt.as_ref(), vs, ps);
+                let mut filtered = Self::filter_and_resolve_batch(
+                    query_results,
+                    filter_opt.as_ref(),
+                    vs,
+                    &payloads,
+                );
                 resolve::sort_results_by_metric(&mut filtered, higher_is_better);
                 filtered.truncate(k);
                 filtered
@@ -90,12 +95,12 @@ impl Collection {
         results: &[crate::scored_result::ScoredResult],
         filter: Option<&crate::filter::Filter>,
         vector_storage: &dyn VectorStorage,
-        payload_storage: &dyn PayloadStorage,
+        payloads: &PayloadReader<'_>,
     ) -> Vec<SearchResult> {
         results
             .iter()
             .filter_map(|sr| {
-                let payload = payload_storage.retrieve(sr.id).ok().flatten();
+                let payload = payloads.retrieve(sr.id);
                 if let Some(f) = filter {
                     let matches = match payload.as_ref() {
                         Some(p) => f.matches(p),
@@ -167,11 +172,11 @@ impl Collection {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
         let vs: &dyn VectorStorage = &*vector_storage;
-        let ps: &dyn PayloadStorage = &*payload_storage;
+        let payloads = self.payload_reader(&*payload_storage);
 
         let results: Vec<Vec<SearchResult>> = merged
             .par_iter()
-            .map(|query_results| resolve::resolve_scored_results(query_results, vs, ps))
+            .map(|query_results| resolve::resolve_scored_results(query_results, vs, &payloads))
             .collect();
 
         Ok(results)
@@ -300,13 +305,14 @@ impl Collection {
             return batch_results;
         };
         let payload_storage = self.storage.payload_storage.read();
+        let payloads = self.payload_reader(&*payload_storage);
         batch_results
             .into_iter()
             .map(|query_results| {
                 query_results
                     .into_iter()
                     .filter(|(id, _score)| {
-                        if let Ok(Some(payload)) = payload_storage.retrieve(*id) {
+                        if let Some(payload) = payloads.retrieve(*id) {
                             f.matches(&payload)
                         } else {
                             false
@@ -322,7 +328,8 @@ impl Collection {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
 
-        resolve::resolve_id_score_pairs(fused, top_k, &*vector_storage, &*payload_storage)
+        let payloads = self.payload_reader(&*payload_storage);
+        resolve::resolve_id_score_pairs(fused, top_k, &*vector_storage, &payloads)
     }
 
     /// Performs multi-query search returning only IDs and fused scores.
@@ -0,0 +1,56 @@
+//! Search with a packed bitset query on `Hamming` / `Jaccard` collections.
+//!
+//! The query uses the [`Point::from_bits`](crate::Point::from_bits) layout.
+//! Candidates come from the HNSW index; their scores are then recomputed on
+//! packed u64 words with the popcount kernels of
+//! [`DistanceMetric::calculate_bits`], so they are exact bit counts whatever
+//! the storage mode.
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::point::{pack_bits, unpack_bits, SearchResult};
+use crate::simd_native::pack_bits_u64;
+
+impl Collection {
+    /// Returns the `k` points nearest to the bitset `query`.
+    ///
+    /// `query` holds `dimension / 8` bytes, least significant bit first.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] if the collection metric is not `Hamming` or
+    ///   `Jaccard`.
+    /// - [`Error::DimensionMismatch`] if `query.len() * 8` is not the
+    ///   collection dimension.
+    /// - [`Error::SearchNotSupported`] on a metadata-only collection.
+    pub fn search_bits(&self, query: &[u8], k: usize) -> Result<Vec<SearchResult>> {
+        let metric = self.storage.config.read().metric;
+        if !metric.is_binary() {
+            return Err(Error::Config(format!(
+                "bitset search needs a hamming or jaccard collection, got {metric:?}"
+            )));
+        }
+        let mut results = self.search(&unpack_bits(query), k)?;
+        rescore_bits(metric, &pack_bits_u64(query), &mut results);
+        Ok(results)
+    }
+}
+
+/// Replaces each score with the packed-bitset score and re-sorts. Points
+/// whose vector is not 0/1 keep their index score.
+fn rescore_bits(metric: DistanceMetric, query: &[u64], results: &mut [SearchResult]) {
+    for result in results.iter_mut() {
+        let Some(bits) = pack_bits(&result.point.vector) else {
+            continue;
+        };
+        if let Some(score) = metric.calculate_bits(query, &pack_bits_u64(&bits)) {
+            result.score = score;
+        }
+    }
+    if metric.higher_is_better() {
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+    } else {
+        results.sort_by(|a, b| a.score.total_cmp(&b.score));
+    }
+}
@@ -0,0 +1,94 @@
+//! Tests for bitset search (`search_bits`) on Hamming / Jaccard collections.
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::path::PathBuf;
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+
+/// 64-bit fingerprints: each differs from `QUERY` in `id` bits.
+const QUERY: [u8; 8] = [0; 8];
+
+fn fingerprint(flipped_bits: u64) -> [u8; 8] {
+    let word = if flipped_bits == 0 {
+        0
+    } else {
+        u64::MAX >> (64 - flipped_bits)
+    };
+    word.to_le_bytes()
+}
+
+fn setup(metric: DistanceMetric) -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("test: tempdir");
+    let col = Collection::create(PathBuf::from(dir.path()), 64, metric).expect("test: create");
+    let points: Vec<_> = (0..20)
+        .map(|id| Point::from_bits(id, &fingerprint(id)))
+        .collect();
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+#[test]
+fn test_hamming_bits_search_ranks_by_differing_bits() {
+    let (_dir, col) = setup(DistanceMetric::Hamming);
+
+    let results = col.search_bits(&QUERY, 3).expect("search_bits");
+
+    let hits: Vec<(u64, f32)> = results.iter().map(|r| (r.point.id, r.score)).collect();
+    assert_eq!(hits, vec![(0, 0.0), (1, 1.0), (2, 2.0)]);
+}
+
+#[test]
+fn test_jaccard_bits_search_matches_f32_scores() {
+    let (_dir, col) = setup(DistanceMetric::Jaccard);
+    let query = fingerprint(10);
+
+    let results = col.search_bits(&query, 5).expect("search_bits");
+
+    assert_eq!(results[0].point.id, 10);
+    assert!((results[0].score - 1.0).abs() < 1e-6);
+    let query_vec = Point::from_bits(0, &query).vector;
+    for r in &results {
+        let expected = DistanceMetric::Jaccard.calculate(&r.point.vector, &query_vec);
+        assert!((r.score - expected).abs() < 1e-6, "id {}", r.point.id);
+    }
+}
+
+#[test]
+fn test_stored_points_round_trip_to_bits() {
+    let (_dir, col) = setup(DistanceMetric::Hamming);
+
+    let stored = col.get(&[7])[0].clone().expect("point 7");
+
+    assert_eq!(stored.to_bits(), Some(fingerprint(7).to_vec()));
+}
+
+#[test]
+fn test_non_binary_metric_is_rejected() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+
+    let err = col.search_bits(&QUERY, 3).expect_err("cosine collection");
+
+    assert!(matches!(err, Error::Config(_)), "{err:?}");
+}
+
+#[test]
+fn test_wrong_bitset_length_is_a_dimension_mismatch() {
+    let (_dir, col) = setup(DistanceMetric::Hamming);
+
+    let err = col.search_bits(&[0; 4], 3).expect_err("32-bit query");
+
+    assert!(
+        matches!(
+            err,
+            Error::DimensionMismatch {
+                expected: 64,
+                actual: 32
+            }
+        ),
+        "{err:?}"
+    );
+}
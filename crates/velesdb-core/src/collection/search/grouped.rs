@@ -0,0 +1,179 @@
+//! Grouped search: the best hits for each distinct value of a payload field.
+//!
+//! Typical use is chunked documents: "top 3 chunks for each of the 10 best
+//! documents". Candidates are over-fetched from the vector index and kept in
+//! one bounded heap per group.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use rustc_hash::FxHashMap;
+
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::point::SearchResult;
+
+/// One group returned by [`Collection::search_grouped`].
+#[derive(Debug, Clone)]
+pub struct SearchGroup {
+    /// Value of the group-by field shared by every hit.
+    pub key: serde_json::Value,
+    /// Best hits of the group, best first.
+    pub hits: Vec<SearchResult>,
+}
+
+/// Candidates fetched per requested hit on the first pass.
+const GROUPED_OVERFETCH: usize = 4;
+
+impl Collection {
+    /// Searches for the `per_group` best hits of each of the `groups` best
+    /// groups, grouping on the top-level payload field `group_by_field`.
+    ///
+    /// Groups are ranked by their best hit. Points without the field are
+    /// skipped. The candidate pool grows until enough groups are filled or
+    /// the collection's query limit is reached, so rare groups can come back
+    /// with fewer than `per_group` hits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query dimension doesn't match the collection
+    /// or if this is a metadata-only collection.
+    pub fn search_grouped(
+        &self,
+        query: &[f32],
+        group_by_field: &str,
+        groups: usize,
+        per_group: usize,
+    ) -> Result<Vec<SearchGroup>> {
+        if groups == 0 || per_group == 0 {
+            return Ok(Vec::new());
+        }
+        let higher_is_better = self.storage.config.read().metric.higher_is_better();
+        let max = self.max_query_limit();
+        let columns = [group_by_field.to_string()];
+        let mut fetch = groups
+            .saturating_mul(per_group)
+            .saturating_mul(GROUPED_OVERFETCH)
+            .min(max);
+        loop {
+            let candidates = self.search(query, fetch)?;
+            let exhausted = candidates.len() < fetch || fetch == max;
+            let grouped = group_top_hits(candidates, &columns, groups, per_group, higher_is_better);
+            let full = grouped.len() == groups && grouped.iter().all(|g| g.hits.len() == per_group);
+            if full || exhausted {
+                return Ok(grouped);
+            }
+            fetch = fetch.saturating_mul(2).min(max);
+        }
+    }
+}
+
+/// Heap entry ordered so the *worst* hit is on top of a `Reverse` heap.
+struct HeapHit {
+    result: SearchResult,
+    higher_is_better: bool,
+}
+
+impl HeapHit {
+    fn cmp_quality(&self, other: &Self) -> Ordering {
+        let by_score = if self.higher_is_better {
+            self.result.score.total_cmp(&other.result.score)
+        } else {
+            other.result.score.total_cmp(&self.result.score)
+        };
+        // Lower id wins ties, matching a stable sort of the input.
+        by_score.then(other.result.point.id.cmp(&self.result.point.id))
+    }
+}
+
+impl PartialEq for HeapHit {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp_quality(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapHit {}
+
+impl PartialOrd for HeapHit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapHit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_quality(other)
+    }
+}
+
+/// Groups `results` by `group_by_columns` and keeps the `per_group` best hits
+/// of the `groups` best groups (ranked by best hit), best first.
+///
+/// The input order doesn't matter: every group keeps a bounded min-heap.
+/// Results missing a group-by field are skipped.
+pub(crate) fn group_top_hits(
+    results: Vec<SearchResult>,
+    group_by_columns: &[String],
+    groups: usize,
+    per_group: usize,
+    higher_is_better: bool,
+) -> Vec<SearchGroup> {
+    let mut heaps: FxHashMap<String, (serde_json::Value, BinaryHeap<Reverse<HeapHit>>)> =
+        FxHashMap::default();
+    for result in results {
+        let Some(key) = group_key(result.point.payload.as_ref(), group_by_columns) else {
+            continue;
+        };
+        let (_, heap) = heaps
+            .entry(key.to_string())
+            .or_insert_with(|| (key, BinaryHeap::with_capacity(per_group + 1)));
+        heap.push(Reverse(HeapHit {
+            result,
+            higher_is_better,
+        }));
+        if heap.len() > per_group {
+            heap.pop();
+        }
+    }
+
+    let mut grouped: Vec<SearchGroup> = heaps
+        .into_values()
+        .map(|(key, heap)| SearchGroup {
+            key,
+            // `into_sorted_vec` on `Reverse` yields the best hit first.
+            hits: heap
+                .into_sorted_vec()
+                .into_iter()
+                .map(|Reverse(hit)| hit.result)
+                .collect(),
+        })
+        .collect();
+    grouped.sort_by(|a, b| {
+        let (a, b) = (&a.hits[0], &b.hits[0]);
+        let by_score = if higher_is_better {
+            b.score.total_cmp(&a.score)
+        } else {
+            a.score.total_cmp(&b.score)
+        };
+        by_score.then(a.point.id.cmp(&b.point.id))
+    });
+    grouped.truncate(groups);
+    grouped
+}
+
+/// Group key of a payload: the single field's value, or an array of values
+/// for multi-column `GROUP BY`.
+fn group_key(
+    payload: Option<&serde_json::Value>,
+    group_by_columns: &[String],
+) -> Option<serde_json::Value> {
+    let payload = payload?;
+    if let [column] = group_by_columns {
+        return payload.get(column).cloned();
+    }
+    group_by_columns
+        .iter()
+        .map(|column| payload.get(column).cloned())
+        .collect::<Option<Vec<_>>>()
+        .map(serde_json::Value::Array)
+}
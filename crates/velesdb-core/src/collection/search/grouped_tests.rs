@@ -0,0 +1,137 @@
+//! Tests for grouped search (`search_grouped`, `GROUP BY ... WITH (per_group = n)`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::collections::HashMap;
+
+use crate::collection::types::Collection;
+use crate::error::Error;
+use crate::point::SearchResult;
+use crate::test_fixtures::fixtures::{make_point_with_payload, setup_collection};
+
+const QUERY: [f32; 3] = [1.0, 0.0, 0.0];
+
+/// Four chunks of doc "a", two of "b", one of "c", and one chunk without a
+/// `doc` field that outranks everything.
+fn setup_chunks() -> (tempfile::TempDir, Collection) {
+    let chunks: [(u64, [f32; 3], Option<&str>); 8] = [
+        (1, [1.0, 0.0, 0.0], Some("a")),
+        (2, [0.99, 0.14, 0.0], Some("a")),
+        (3, [0.95, 0.31, 0.0], Some("a")),
+        (4, [0.9, 0.44, 0.0], Some("a")),
+        (5, [0.97, 0.0, 0.24], Some("b")),
+        (6, [0.8, 0.0, 0.6], Some("b")),
+        (7, [0.0, 1.0, 0.0], Some("c")),
+        (8, [1.0, 0.0, 0.01], None),
+    ];
+    let points: Vec<_> = chunks
+        .into_iter()
+        .map(|(id, vector, doc)| {
+            let payload = doc.map_or(serde_json::json!({}), |d| serde_json::json!({ "doc": d }));
+            make_point_with_payload(id, vector.to_vec(), payload)
+        })
+        .collect();
+    let (dir, col) = setup_collection(3);
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+fn ids(results: &[SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+#[test]
+fn test_search_grouped_returns_best_hits_of_best_groups() {
+    let (_dir, col) = setup_chunks();
+    let groups = col.search_grouped(&QUERY, "doc", 2, 2).expect("grouped");
+    let keys: Vec<_> = groups.iter().map(|g| g.key.clone()).collect();
+    assert_eq!(keys, vec![serde_json::json!("a"), serde_json::json!("b")]);
+    assert_eq!(ids(&groups[0].hits), vec![1, 2]);
+    assert_eq!(ids(&groups[1].hits), vec![5, 6]);
+}
+
+#[test]
+fn test_search_grouped_small_groups_and_zero_sizes() {
+    let (_dir, col) = setup_chunks();
+    let groups = col.search_grouped(&QUERY, "doc", 10, 3).expect("grouped");
+    let hits: Vec<Vec<u64>> = groups.iter().map(|g| ids(&g.hits)).collect();
+    assert_eq!(hits, vec![vec![1, 2, 3], vec![5, 6], vec![7]]);
+
+    assert!(col
+        .search_grouped(&QUERY, "doc", 0, 3)
+        .expect("no groups")
+        .is_empty());
+    assert!(col
+        .search_grouped(&QUERY, "doc", 3, 0)
+        .expect("no hits")
+        .is_empty());
+}
+
+#[test]
+fn test_search_grouped_widens_candidate_pool_for_rare_groups() {
+    // 30 near-duplicates of doc "a" fill the first over-fetch pass (2 * 1 * 4
+    // candidates); doc "b" is only reached once the pool grows.
+    let mut points: Vec<_> = (0..30_u64)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let y = i as f32 * 0.001;
+            make_point_with_payload(i, vec![1.0, y, 0.0], serde_json::json!({ "doc": "a" }))
+        })
+        .collect();
+    points.push(make_point_with_payload(
+        100,
+        vec![0.5, 0.5, 0.0],
+        serde_json::json!({ "doc": "b" }),
+    ));
+    let (_dir, col) = setup_collection(3);
+    col.upsert(points).expect("test: upsert");
+
+    let groups = col.search_grouped(&QUERY, "doc", 2, 1).expect("grouped");
+    let hits: Vec<Vec<u64>> = groups.iter().map(|g| ids(&g.hits)).collect();
+    assert_eq!(hits, vec![vec![0], vec![100]]);
+}
+
+#[test]
+fn test_velesql_per_group_flattens_groups_and_pages_by_group() {
+    let (_dir, col) = setup_chunks();
+    let params = HashMap::from([("v".to_string(), serde_json::json!(QUERY))]);
+
+    let rows = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v GROUP BY doc LIMIT 2 WITH (per_group = 2)",
+            &params,
+        )
+        .expect("per_group query");
+    assert_eq!(ids(&rows), vec![1, 2, 5, 6]);
+    // Hits keep their full payload rather than a collapsed group row.
+    assert_eq!(
+        rows[2].point.payload,
+        Some(serde_json::json!({ "doc": "b" }))
+    );
+
+    let rows = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v GROUP BY doc LIMIT 5 OFFSET 1 \
+             WITH (per_group = 2)",
+            &params,
+        )
+        .expect("per_group query with offset");
+    assert_eq!(ids(&rows), vec![5, 6, 7]);
+}
+
+#[test]
+fn test_velesql_per_group_rejects_invalid_shapes() {
+    let (_dir, col) = setup_chunks();
+    let params = HashMap::from([("v".to_string(), serde_json::json!(QUERY))]);
+    for query in [
+        "SELECT * FROM c WHERE vector NEAR $v LIMIT 2 WITH (per_group = 2)",
+        "SELECT * FROM c WHERE vector NEAR $v GROUP BY doc LIMIT 2 WITH (per_group = 0)",
+        "SELECT doc, MAX(score) FROM c WHERE vector NEAR $v GROUP BY doc LIMIT 2 \
+         WITH (per_group = 2)",
+    ] {
+        let err = col
+            .execute_query_str(query, &params)
+            .expect_err("invalid per_group query");
+        assert!(matches!(err, Error::Query(_)), "{query}: got {err:?}");
+    }
+}
@@ -0,0 +1,94 @@
+//! Vector search restricted to caller-supplied id sets.
+//!
+//! The candidate sets (e.g. ids a user is permitted to read, computed by an
+//! external ACL service) are enforced inside the HNSW traversal, so a narrow
+//! allowlist still returns `k` results instead of whatever survived a
+//! post-filter.
+
+use super::resolve;
+use super::vector::tag_vector_component_scores;
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::point::SearchResult;
+use roaring::RoaringBitmap;
+
+/// Id restrictions for [`Collection::search_with_options`].
+///
+/// Ids are point ids; points whose id exceeds `u32::MAX` cannot be listed
+/// and are never returned while `include_ids` is set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchOptions {
+    /// Only these ids may be returned. `None` allows every id.
+    pub include_ids: Option<RoaringBitmap>,
+    /// These ids are never returned, even if also in `include_ids`.
+    pub exclude_ids: Option<RoaringBitmap>,
+}
+
+impl SearchOptions {
+    /// Returns `true` when neither list is set.
+    #[must_use]
+    pub fn is_unrestricted(&self) -> bool {
+        self.include_ids.is_none() && self.exclude_ids.is_none()
+    }
+
+    /// Returns `true` if `id` passes both lists.
+    #[must_use]
+    pub fn admits(&self, id: u64) -> bool {
+        let Ok(id) = u32::try_from(id) else {
+            return self.include_ids.is_none();
+        };
+        self.include_ids.as_ref().is_none_or(|ids| ids.contains(id))
+            && !self
+                .exclude_ids
+                .as_ref()
+                .is_some_and(|ids| ids.contains(id))
+    }
+}
+
+impl Collection {
+    /// Searches for the k nearest neighbors of `query` among the ids allowed
+    /// by `options`.
+    ///
+    /// With no restriction this is a plain [`search`](Self::search).
+    /// Points still in the streaming delta buffer are not in the graph yet;
+    /// they are over-fetched and filtered after the merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query vector dimension doesn't match the
+    /// collection, or if this is a metadata-only collection.
+    pub fn search_with_options(
+        &self,
+        query: &[f32],
+        k: usize,
+        options: &SearchOptions,
+    ) -> Result<Vec<SearchResult>> {
+        let metric = self.validate_query_and_read_metric(query)?;
+        if options.is_unrestricted() {
+            return self.search(query, k);
+        }
+
+        let index_results = self.storage.index.search_with_id_filter(
+            query,
+            k,
+            crate::SearchQuality::Balanced,
+            options.include_ids.as_ref(),
+            options.exclude_ids.as_ref(),
+        )?;
+
+        let merge_k = k.saturating_mul(4).max(k + 32);
+        let mut index_results = self.merge_delta(index_results, query, merge_k, metric);
+        index_results.retain(|r| options.admits(r.id));
+        index_results.truncate(k);
+
+        let vector_storage = self.storage.vector_storage.read();
+        let payload_storage = self.storage.payload_storage.read();
+
+        let payloads = self.payload_reader(&*payload_storage);
+        let mut results =
+            resolve::resolve_scored_results(&index_results, &*vector_storage, &payloads);
+        tag_vector_component_scores(&mut results);
+        self.record_reads(results.iter().map(|r| r.point.id));
+        Ok(results)
+    }
+}
@@ -0,0 +1,162 @@
+//! Tests for id-restricted search (`search_with_options`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use roaring::RoaringBitmap;
+
+use crate::collection::types::Collection;
+use crate::collection::SearchOptions;
+use crate::point::{Point, SearchResult};
+use crate::test_fixtures::fixtures::setup_collection;
+
+const DIM: usize = 8;
+const POINTS: u32 = 600;
+
+fn vector(seed: u32) -> Vec<f32> {
+    let mut state = seed.wrapping_mul(2_654_435_761).wrapping_add(1);
+    (0..DIM)
+        .map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            #[allow(clippy::cast_precision_loss)]
+            let v = (state >> 8) as f32 / (1u32 << 24) as f32;
+            v - 0.5
+        })
+        .collect()
+}
+
+fn setup_points_collection() -> (tempfile::TempDir, Collection) {
+    let (dir, col) = setup_collection(DIM);
+    let points: Vec<Point> = (0..POINTS)
+        .map(|id| Point::without_payload(u64::from(id), vector(id)))
+        .collect();
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+fn ids(results: &[SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+/// Exact top-k among the ids `options` admits.
+fn exact_top_k(col: &Collection, query: &[f32], k: usize, options: &SearchOptions) -> Vec<u64> {
+    let all = col
+        .search_with_quality(query, POINTS as usize, crate::SearchQuality::Perfect)
+        .expect("exact search");
+    all.iter()
+        .map(|r| r.point.id)
+        .filter(|&id| options.admits(id))
+        .take(k)
+        .collect()
+}
+
+#[test]
+fn test_unrestricted_options_match_plain_search() {
+    let (_dir, col) = setup_points_collection();
+    let query = vector(10_000);
+    let plain = col.search(&query, 10).expect("search");
+    let with_options = col
+        .search_with_options(&query, 10, &SearchOptions::default())
+        .expect("search_with_options");
+    assert_eq!(ids(&with_options), ids(&plain));
+}
+
+#[test]
+fn test_allowlist_fills_k_from_allowed_ids_only() {
+    let (_dir, col) = setup_points_collection();
+    let query = vector(10_001);
+    // 60 of 600 ids (10%): too broad for the exhaustive path, so the filter
+    // runs inside the graph traversal.
+    let options = SearchOptions {
+        include_ids: Some((0..POINTS).step_by(10).collect()),
+        exclude_ids: None,
+    };
+
+    let results = col
+        .search_with_options(&query, 10, &options)
+        .expect("search_with_options");
+
+    assert_eq!(results.len(), 10);
+    assert!(results.iter().all(|r| r.point.id.is_multiple_of(10)));
+    let expected = exact_top_k(&col, &query, 10, &options);
+    let found = ids(&results);
+    let hits = expected.iter().filter(|id| found.contains(id)).count();
+    assert!(hits >= 9, "recall too low: {found:?} vs {expected:?}");
+}
+
+#[test]
+fn test_denylist_removes_ids_and_backfills() {
+    let (_dir, col) = setup_points_collection();
+    let query = vector(10_002);
+    let plain = ids(&col.search(&query, 5).expect("search"));
+    let options = SearchOptions {
+        include_ids: None,
+        exclude_ids: Some(plain.iter().map(|&id| u32::try_from(id).unwrap()).collect()),
+    };
+
+    let results = col
+        .search_with_options(&query, 5, &options)
+        .expect("search_with_options");
+
+    assert_eq!(results.len(), 5);
+    assert!(results.iter().all(|r| !plain.contains(&r.point.id)));
+}
+
+#[test]
+fn test_exclude_wins_over_include() {
+    let (_dir, col) = setup_points_collection();
+    let query = vector(10_003);
+    let options = SearchOptions {
+        include_ids: Some((0..POINTS).step_by(5).collect()),
+        exclude_ids: Some((0..POINTS).step_by(10).collect()),
+    };
+
+    let results = col
+        .search_with_options(&query, 10, &options)
+        .expect("search_with_options");
+
+    assert_eq!(results.len(), 10);
+    assert!(results
+        .iter()
+        .all(|r| r.point.id.is_multiple_of(5) && !r.point.id.is_multiple_of(10)));
+}
+
+#[test]
+fn test_tiny_allowlist_is_exact() {
+    let (_dir, col) = setup_points_collection();
+    let query = vector(10_004);
+    let options = SearchOptions {
+        include_ids: Some([3, 141, 277, 599].into_iter().collect()),
+        exclude_ids: None,
+    };
+
+    let results = col
+        .search_with_options(&query, 3, &options)
+        .expect("search_with_options");
+
+    assert_eq!(ids(&results), exact_top_k(&col, &query, 3, &options));
+}
+
+#[test]
+fn test_empty_allowlist_returns_nothing() {
+    let (_dir, col) = setup_points_collection();
+    let options = SearchOptions {
+        include_ids: Some(RoaringBitmap::new()),
+        exclude_ids: None,
+    };
+
+    let results = col
+        .search_with_options(&vector(10_005), 10, &options)
+        .expect("search_with_options");
+
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_options_reject_wrong_dimension() {
+    let (_dir, col) = setup_points_collection();
+    let options = SearchOptions {
+        include_ids: Some((0..10).collect()),
+        exclude_ids: None,
+    };
+    assert!(col.search_with_options(&[1.0, 0.0], 3, &options).is_err());
+}
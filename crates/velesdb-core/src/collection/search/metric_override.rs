@@ -0,0 +1,127 @@
+//! Per-query distance metric override.
+//!
+//! The HNSW graph is built for the collection's metric, so another metric
+//! can only rank vectors that something else selected: every stored vector
+//! (flat scan) on small collections, or an over-fetched HNSW candidate pool
+//! (re-rank) on larger ones. Only the float metrics (cosine, euclidean, dot)
+//! can stand in for one another; Hamming and Jaccard collections store
+//! binary or set vectors that the float metrics do not rank meaningfully.
+
+use super::vector::tag_vector_component_scores;
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::point::SearchResult;
+
+/// Largest collection [`Collection::search_with_metric`] ranks exactly by
+/// scoring every stored vector. Larger collections re-rank HNSW candidates.
+pub const METRIC_OVERRIDE_FLAT_SCAN_MAX: usize = 10_000;
+
+/// Candidates fetched per requested result on the re-rank path.
+const METRIC_OVERRIDE_OVERFETCH: usize = 10;
+
+/// Checks that `requested` can rank a collection indexed for `native`.
+///
+/// # Errors
+///
+/// Returns [`Error::Query`] when the metrics differ and either one is binary
+/// (Hamming or Jaccard).
+pub(crate) fn check_metric_override(
+    native: DistanceMetric,
+    requested: DistanceMetric,
+) -> Result<()> {
+    if native == requested || !(native.is_binary() || requested.is_binary()) {
+        return Ok(());
+    }
+    Err(Error::Query(format!(
+        "metric override '{}' is not supported on a {} collection: only cosine, \
+         euclidean and dot can replace one another",
+        requested.canonical_name(),
+        native.canonical_name()
+    )))
+}
+
+impl Collection {
+    /// Searches for the k nearest neighbors of `query`, ranked by `metric`
+    /// instead of the collection's own metric.
+    ///
+    /// Scores are `metric` scores. With the collection's own metric this is a
+    /// plain [`search`](Self::search). Collections of up to
+    /// [`METRIC_OVERRIDE_FLAT_SCAN_MAX`] points are scanned exactly; larger
+    /// ones re-rank `10 * k` HNSW candidates, so a point that the native
+    /// metric ranks far away can be missed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query dimension doesn't match the collection,
+    /// if this is a metadata-only collection, or if `metric` cannot replace
+    /// the collection's metric (see the module docs).
+    pub fn search_with_metric(
+        &self,
+        query: &[f32],
+        k: usize,
+        metric: DistanceMetric,
+    ) -> Result<Vec<SearchResult>> {
+        let native = self.validate_query_and_read_metric(query)?;
+        if metric == native {
+            return self.search(query, k);
+        }
+        check_metric_override(native, metric)?;
+
+        let candidates = if self.len() <= METRIC_OVERRIDE_FLAT_SCAN_MAX {
+            self.all_vector_points(METRIC_OVERRIDE_FLAT_SCAN_MAX)
+        } else {
+            let candidates_k = k
+                .saturating_mul(METRIC_OVERRIDE_OVERFETCH)
+                .min(self.max_query_limit())
+                .max(k);
+            self.search(query, candidates_k)?
+        };
+        Ok(rank_by_metric(candidates, query, k, metric))
+    }
+
+    /// Exact metric-override scan over the whole collection, for
+    /// `WITH (hint = 'force_scan', metric = ...)`.
+    pub(crate) fn scan_with_metric(
+        &self,
+        query: &[f32],
+        k: usize,
+        metric: DistanceMetric,
+    ) -> Result<Vec<SearchResult>> {
+        let native = self.validate_query_and_read_metric(query)?;
+        check_metric_override(native, metric)?;
+        Ok(rank_by_metric(
+            self.all_vector_points(usize::MAX),
+            query,
+            k,
+            metric,
+        ))
+    }
+
+    /// Up to `limit` stored points with their vectors.
+    fn all_vector_points(&self, limit: usize) -> Vec<SearchResult> {
+        let all = crate::filter::Filter::new(crate::filter::Condition::And { conditions: vec![] });
+        self.execute_scan_query(&all, limit, None)
+    }
+}
+
+/// Scores `candidates` against `query` with `metric` and keeps the best `k`.
+fn rank_by_metric(
+    mut candidates: Vec<SearchResult>,
+    query: &[f32],
+    k: usize,
+    metric: DistanceMetric,
+) -> Vec<SearchResult> {
+    candidates.retain(|r| r.point.vector.len() == query.len());
+    for result in &mut candidates {
+        result.score = metric.calculate(&result.point.vector, query);
+    }
+    if metric.higher_is_better() {
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    } else {
+        candidates.sort_by(|a, b| a.score.total_cmp(&b.score));
+    }
+    candidates.truncate(k);
+    tag_vector_component_scores(&mut candidates);
+    candidates
+}
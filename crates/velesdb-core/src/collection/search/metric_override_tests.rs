@@ -0,0 +1,107 @@
+//! Tests for per-query metric override (`search_with_metric`,
+//! `WITH (metric = ...)`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::point::Point;
+use crate::test_fixtures::fixtures::{make_point_with_payload, setup_collection};
+
+const QUERY: [f32; 2] = [1.0, 0.0];
+
+/// Point 2 is the longest vector: second under cosine, first under dot.
+fn setup_metric_collection() -> (tempfile::TempDir, Collection) {
+    let points = vec![
+        make_point_with_payload(1, vec![1.0, 0.0], serde_json::json!({ "n": 1 })),
+        make_point_with_payload(2, vec![3.0, 1.0], serde_json::json!({ "n": 2 })),
+        make_point_with_payload(3, vec![0.5, 0.5], serde_json::json!({ "n": 3 })),
+    ];
+    let (dir, col) = setup_collection(2);
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+fn ids(results: &[crate::point::SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+#[test]
+fn test_search_with_metric_reranks_by_dot_product() {
+    let (_dir, col) = setup_metric_collection();
+    assert_eq!(ids(&col.search(&QUERY, 3).expect("search")), vec![1, 2, 3]);
+
+    let results = col
+        .search_with_metric(&QUERY, 2, DistanceMetric::DotProduct)
+        .expect("dot override");
+    assert_eq!(ids(&results), vec![2, 1]);
+    assert!((results[0].score - 3.0).abs() < 1e-5);
+
+    // Euclidean ranks lowest distance first.
+    let results = col
+        .search_with_metric(&QUERY, 3, DistanceMetric::Euclidean)
+        .expect("euclidean override");
+    assert_eq!(ids(&results), vec![1, 3, 2]);
+}
+
+#[test]
+fn test_search_with_metric_rejects_binary_metrics() {
+    let (_dir, col) = setup_metric_collection();
+    let err = col
+        .search_with_metric(&QUERY, 2, DistanceMetric::Hamming)
+        .expect_err("hamming cannot rank a cosine collection");
+    assert!(matches!(err, Error::Query(_)), "got {err:?}");
+    assert!(err.to_string().contains("hamming"), "{err}");
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let bits = Collection::create(PathBuf::from(dir.path()), 2, DistanceMetric::Hamming)
+        .expect("hamming collection");
+    bits.upsert(vec![Point::without_payload(1, vec![1.0, 0.0])])
+        .expect("upsert");
+    assert!(bits
+        .search_with_metric(&QUERY, 1, DistanceMetric::DotProduct)
+        .is_err());
+}
+
+#[test]
+fn test_velesql_with_metric_override() {
+    let (_dir, col) = setup_metric_collection();
+    let params = HashMap::from([("v".to_string(), serde_json::json!(QUERY))]);
+    let rows = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v LIMIT 2 WITH (metric = 'dot')",
+            &params,
+        )
+        .expect("metric override executes");
+    assert_eq!(ids(&rows), vec![2, 1]);
+
+    let rows = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v LIMIT 2 \
+             WITH (metric = 'dot', hint = 'force_scan')",
+            &params,
+        )
+        .expect("metric override with force_scan executes");
+    assert_eq!(ids(&rows), vec![2, 1]);
+}
+
+#[test]
+fn test_velesql_metric_override_rejects_unsupported_shapes() {
+    let (_dir, col) = setup_metric_collection();
+    let params = HashMap::from([("v".to_string(), serde_json::json!(QUERY))]);
+    for sql in [
+        "SELECT * FROM c WHERE vector NEAR $v AND n > 1 LIMIT 2 WITH (metric = 'dot')",
+        "SELECT * FROM c WHERE n > 1 LIMIT 2 WITH (metric = 'dot')",
+        "SELECT * FROM c WHERE vector NEAR $v LIMIT 2 WITH (metric = 'manhattan')",
+        "SELECT * FROM c WHERE vector NEAR $v LIMIT 2 WITH (metric = 'jaccard')",
+    ] {
+        let err = col
+            .execute_query_str(sql, &params)
+            .expect_err("unsupported metric override");
+        assert!(matches!(err, Error::Query(_)), "{sql}: got {err:?}");
+    }
+}
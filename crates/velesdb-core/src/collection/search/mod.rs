@@ -5,16 +5,56 @@
 //! - Full-text search (BM25)
 //! - Hybrid search (vector + text with RRF fusion)
 //! - Batch and multi-query search
+//! - Bitset queries on Hamming/Jaccard collections
+//! - Grouped search (top hits per payload-field value)
+//! - Near-duplicate lookup on SimHash fingerprints
+//! - Search restricted to id allowlists / denylists
+//! - Negative-vector ("avoid") search
+//! - Per-query distance metric override
+//! - Pairwise similarity matrices over stored points
+//! - Threshold (range) search over a similarity bound
+//! - Recommendation from example point ids
+//! - Cursor-paginated and streaming search
 //! - VelesQL query execution
 
 mod batch;
 #[cfg(test)]
 mod batch_tests;
+mod bits;
+#[cfg(test)]
+mod bits_tests;
 #[cfg(test)]
 mod distance_semantics_tests;
+mod grouped;
+#[cfg(test)]
+mod grouped_tests;
+mod id_filter;
+#[cfg(test)]
+mod id_filter_tests;
+mod metric_override;
+#[cfg(test)]
+mod metric_override_tests;
+mod near_duplicate;
+#[cfg(test)]
+mod near_duplicate_tests;
+mod negatives;
+#[cfg(test)]
+mod negatives_tests;
+mod paginated;
+#[cfg(test)]
+mod paginated_tests;
+mod pairwise;
+#[cfg(test)]
+mod pairwise_tests;
+mod projected;
+#[cfg(test)]
+mod projected_tests;
 pub mod query;
 #[cfg(test)]
 mod query_validation_tests;
+mod recommend;
+#[cfg(test)]
+mod recommend_tests;
 pub(crate) mod resolve;
 #[cfg(test)]
 mod similarity_exec_tests;
@@ -25,6 +65,9 @@ mod text;
 mod text_fusion;
 #[cfg(test)]
 mod text_tests;
+mod threshold;
+#[cfg(test)]
+mod threshold_tests;
 mod vector;
 #[cfg(test)]
 mod vector_dispatch_parity_tests;
@@ -33,10 +76,24 @@ mod vector_filter;
 mod vector_filter_tests;
 #[cfg(test)]
 mod vector_tests;
+mod vector_view;
+#[cfg(test)]
+mod vector_view_tests;
 
 // Re-export all search methods via trait implementations
 // The actual impl blocks are in submodules
 
+pub use grouped::SearchGroup;
+pub use id_filter::SearchOptions;
+pub use metric_override::METRIC_OVERRIDE_FLAT_SCAN_MAX;
+pub use near_duplicate::NearDuplicateQuery;
+pub use negatives::{NegativeStrategy, DEFAULT_NEGATIVE_WEIGHT};
+pub(crate) use paginated::SearchCursors;
+pub use paginated::{SearchPage, SearchResultIter, MAX_SEARCH_CURSORS, SEARCH_CURSOR_TTL};
+pub use pairwise::{SimilarityMatrix, SparseSimilarityMatrix, MAX_DENSE_PAIRWISE_IDS};
+pub use recommend::RecommendStrategy;
+pub use vector_view::{PointsView, SearchView, VectorReader};
+
 /// Wrapper for f32 to implement Ord for `BinaryHeap` in hybrid search.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) struct OrderedFloat(pub f32);
@@ -0,0 +1,82 @@
+//! Near-duplicate lookup on a SimHash fingerprint collection.
+//!
+//! The collection is a 64-dimension `Hamming` companion whose points were
+//! stored with [`simhash_bits`](crate::fingerprint::simhash_bits) via
+//! [`Point::from_bits`](crate::Point::from_bits), typically under the same
+//! ids as the documents they fingerprint.
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::fingerprint::{simhash_bits, SIMHASH_BITS};
+use crate::point::{unpack_bits, SearchResult};
+use crate::velesql::CompareOp;
+
+/// What [`Collection::near_duplicate_text`] compares against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NearDuplicateQuery<'a> {
+    /// The fingerprint already stored under this id (excluded from results).
+    Id(u64),
+    /// A text fingerprinted on the fly.
+    Text(&'a str),
+}
+
+impl From<u64> for NearDuplicateQuery<'_> {
+    fn from(id: u64) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl<'a> From<&'a str> for NearDuplicateQuery<'a> {
+    fn from(text: &'a str) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl Collection {
+    /// Returns the fingerprints within `threshold` differing bits of the
+    /// query, nearest first (score = Hamming distance).
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] if the collection is not a 64-dimension `Hamming`
+    ///   collection.
+    /// - [`Error::PointNotFound`] if an id query names no stored point.
+    pub fn near_duplicate_text<'a>(
+        &self,
+        query: impl Into<NearDuplicateQuery<'a>>,
+        threshold: u32,
+    ) -> Result<Vec<SearchResult>> {
+        let (metric, dimension) = {
+            let config = self.storage.config.read();
+            (config.metric, config.dimension)
+        };
+        if metric != DistanceMetric::Hamming || dimension != SIMHASH_BITS {
+            return Err(Error::Config(format!(
+                "near-duplicate search needs a {SIMHASH_BITS}-dimension hamming collection, \
+                 got {metric:?} with dimension {dimension}"
+            )));
+        }
+
+        let (vector, exclude) = match query.into() {
+            NearDuplicateQuery::Id(id) => {
+                let point = self
+                    .get(&[id])
+                    .into_iter()
+                    .next()
+                    .flatten()
+                    .ok_or(Error::PointNotFound(id))?;
+                (point.vector, Some(id))
+            }
+            NearDuplicateQuery::Text(text) => (unpack_bits(&simhash_bits(text)), None),
+        };
+
+        #[allow(clippy::cast_precision_loss)] // Reason: threshold is at most 64 in practice.
+        let threshold = threshold as f32;
+        // On a distance metric `Gte` reads "at least this similar": distance <= threshold.
+        let mut results =
+            self.search_by_threshold(&vector, threshold, CompareOp::Gte, usize::MAX)?;
+        results.retain(|r| Some(r.point.id) != exclude);
+        Ok(results)
+    }
+}
@@ -0,0 +1,102 @@
+//! Tests for near-duplicate lookup (`near_duplicate_text`) on a SimHash
+//! fingerprint collection.
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::path::PathBuf;
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::fingerprint::{simhash, simhash_bits};
+use crate::point::Point;
+
+const DOCS: [&str; 4] = [
+    "Vector databases store embeddings and answer nearest neighbour queries \
+     over millions of points with approximate indexes such as HNSW.",
+    "Vector databases store embeddings and answer nearest neighbour queries \
+     over millions of points with approximate indexes like HNSW!",
+    "The recipe calls for two cups of flour, a pinch of salt and three eggs \
+     whisked until the batter turns pale and smooth.",
+    "Trains leave the central station every twenty minutes on weekdays and \
+     every hour on sundays and public holidays.",
+];
+
+fn setup() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("test: tempdir");
+    let col = Collection::create(PathBuf::from(dir.path()), 64, DistanceMetric::Hamming)
+        .expect("test: create");
+    let points: Vec<_> = (0_u64..)
+        .zip(DOCS)
+        .map(|(id, doc)| Point::from_bits(id, &simhash_bits(doc)))
+        .collect();
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+fn distance(a: &str, b: &str) -> u32 {
+    (simhash(a) ^ simhash(b)).count_ones()
+}
+
+#[test]
+fn test_text_query_finds_its_near_duplicate() {
+    let (_dir, col) = setup();
+    let threshold = distance(DOCS[0], DOCS[1]);
+    assert!(threshold < distance(DOCS[0], DOCS[2]));
+    assert!(threshold < distance(DOCS[0], DOCS[3]));
+
+    let results = col
+        .near_duplicate_text(DOCS[0], threshold)
+        .expect("near duplicates");
+
+    let ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
+    assert_eq!(ids, vec![0, 1]);
+    assert!(results[0].score < 0.5, "exact copy is at distance 0");
+}
+
+#[test]
+fn test_id_query_excludes_itself() {
+    let (_dir, col) = setup();
+    let threshold = distance(DOCS[0], DOCS[1]);
+
+    let results = col
+        .near_duplicate_text(1_u64, threshold)
+        .expect("near duplicates");
+
+    let ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
+    assert_eq!(ids, vec![0]);
+}
+
+#[test]
+fn test_zero_threshold_matches_exact_copies_only() {
+    let (_dir, col) = setup();
+
+    let results = col.near_duplicate_text(2_u64, 0).expect("near duplicates");
+    assert!(results.is_empty());
+
+    let results = col
+        .near_duplicate_text(DOCS[3], 0)
+        .expect("near duplicates");
+    let ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
+    assert_eq!(ids, vec![3]);
+}
+
+#[test]
+fn test_unknown_id_is_point_not_found() {
+    let (_dir, col) = setup();
+
+    let err = col.near_duplicate_text(99_u64, 3).expect_err("missing id");
+
+    assert!(matches!(err, Error::PointNotFound(99)), "{err:?}");
+}
+
+#[test]
+fn test_non_fingerprint_collection_is_rejected() {
+    let dir = tempfile::tempdir().expect("test: tempdir");
+    let col = Collection::create(PathBuf::from(dir.path()), 64, DistanceMetric::Cosine)
+        .expect("test: create");
+
+    let err = col.near_duplicate_text("text", 3).expect_err("cosine");
+
+    assert!(matches!(err, Error::Config(_)), "{err:?}");
+}
@@ -0,0 +1,189 @@
+//! Negative-vector search: "similar to A but unlike B".
+//!
+//! Two strategies are offered. Rocchio adjustment moves the query away from
+//! the negatives before a single ANN pass; the per-candidate penalty keeps the
+//! original query and demotes candidates that sit close to any negative.
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::point::SearchResult;
+use crate::validation::validate_dimension_match;
+
+/// Weight given to the negative vectors when none is specified
+/// (VelesQL `NEAR $v AVOID $neg`).
+pub const DEFAULT_NEGATIVE_WEIGHT: f32 = 0.5;
+
+/// How [`Collection::search_with_negatives`] steers results away from the
+/// negative vectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NegativeStrategy {
+    /// Rocchio query adjustment: searches `positive - weight * mean(negatives)`
+    /// in one ANN pass. Scores are against the adjusted query.
+    Rocchio {
+        /// Weight of the negative centroid (`>= 0`).
+        weight: f32,
+    },
+    /// Per-candidate penalty: over-fetches neighbours of `positive`, then
+    /// subtracts `weight` times each candidate's score against its closest
+    /// negative. Costs one extra distance per candidate and negative.
+    Penalty {
+        /// Penalty multiplier (`>= 0`).
+        weight: f32,
+    },
+}
+
+impl Default for NegativeStrategy {
+    fn default() -> Self {
+        Self::Rocchio {
+            weight: DEFAULT_NEGATIVE_WEIGHT,
+        }
+    }
+}
+
+impl NegativeStrategy {
+    const fn weight(self) -> f32 {
+        match self {
+            Self::Rocchio { weight } | Self::Penalty { weight } => weight,
+        }
+    }
+}
+
+/// Builds the Rocchio-adjusted query `positive - weight * mean(negatives)`.
+///
+/// Under cosine every input is unit-normalized first so `weight` is
+/// independent of the vectors' magnitudes.
+pub(crate) fn rocchio_query(
+    positive: &[f32],
+    negatives: &[&[f32]],
+    weight: f32,
+    metric: DistanceMetric,
+) -> Vec<f32> {
+    let unit = |v: &[f32]| -> Vec<f32> {
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if metric == DistanceMetric::Cosine && norm > 0.0 {
+            v.iter().map(|x| x / norm).collect()
+        } else {
+            v.to_vec()
+        }
+    };
+    let mut query = unit(positive);
+    if negatives.is_empty() {
+        return query;
+    }
+    // Reason: the negative count is a handful of vectors, exact in f32.
+    #[allow(clippy::cast_precision_loss)]
+    let scale = weight / negatives.len() as f32;
+    for negative in negatives {
+        for (q, n) in query.iter_mut().zip(unit(negative)) {
+            *q -= scale * n;
+        }
+    }
+    query
+}
+
+impl Collection {
+    /// Searches for points similar to `positive` but dissimilar to every
+    /// vector in `negatives`.
+    ///
+    /// With no negatives this is a plain [`search`](Self::search).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any vector's dimension doesn't match the
+    /// collection, if the strategy weight is negative or not finite, or if
+    /// this is a metadata-only collection.
+    pub fn search_with_negatives(
+        &self,
+        positive: &[f32],
+        negatives: &[&[f32]],
+        k: usize,
+        strategy: NegativeStrategy,
+    ) -> Result<Vec<SearchResult>> {
+        let weight = strategy.weight();
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(Error::Config(format!(
+                "negative-vector weight must be a finite value >= 0, got {weight}"
+            )));
+        }
+        let (dimension, metric) = {
+            let config = self.storage.config.read();
+            (config.dimension, config.metric)
+        };
+        for negative in negatives {
+            validate_dimension_match(dimension, negative.len())?;
+        }
+        if negatives.is_empty() {
+            return self.search(positive, k);
+        }
+
+        match strategy {
+            NegativeStrategy::Rocchio { weight } => {
+                validate_dimension_match(dimension, positive.len())?;
+                self.search(&rocchio_query(positive, negatives, weight, metric), k)
+            }
+            NegativeStrategy::Penalty { weight } => {
+                let candidates_k = k.saturating_mul(4).max(k + 10);
+                let mut results = self.search(positive, candidates_k)?;
+                penalize_near_negatives(&mut results, negatives, weight, metric);
+                results.truncate(k);
+                Ok(results)
+            }
+        }
+    }
+
+    /// Applies a VelesQL `NEAR ... AVOID` clause to the resolved query vector:
+    /// Rocchio adjustment with [`DEFAULT_NEGATIVE_WEIGHT`].
+    ///
+    /// Only the negatives are dimension-checked here; the adjusted query goes
+    /// through the regular NEAR validation downstream.
+    pub(crate) fn avoid_adjusted_query(
+        &self,
+        positive: &[f32],
+        negatives: &[Vec<f32>],
+    ) -> Result<Vec<f32>> {
+        let (dimension, metric) = {
+            let config = self.storage.config.read();
+            (config.dimension, config.metric)
+        };
+        for negative in negatives {
+            validate_dimension_match(dimension, negative.len())?;
+        }
+        let negatives: Vec<&[f32]> = negatives.iter().map(Vec::as_slice).collect();
+        Ok(rocchio_query(
+            positive,
+            &negatives,
+            DEFAULT_NEGATIVE_WEIGHT,
+            metric,
+        ))
+    }
+}
+
+/// Rescores `results` by their closest negative and re-sorts them.
+///
+/// Similarity metrics subtract `weight * max(sim)`; distance metrics subtract
+/// `weight * min(dist)`, rewarding candidates far from every negative.
+fn penalize_near_negatives(
+    results: &mut [SearchResult],
+    negatives: &[&[f32]],
+    weight: f32,
+    metric: DistanceMetric,
+) {
+    let higher_is_better = metric.higher_is_better();
+    for result in results.iter_mut() {
+        let scores = negatives
+            .iter()
+            .map(|negative| metric.calculate(&result.point.vector, negative));
+        let closest = if higher_is_better {
+            scores.fold(f32::NEG_INFINITY, f32::max)
+        } else {
+            scores.fold(f32::INFINITY, f32::min)
+        };
+        result.score -= weight * closest;
+    }
+    if higher_is_better {
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+    } else {
+        results.sort_by(|a, b| a.score.total_cmp(&b.score));
+    }
+}
@@ -0,0 +1,155 @@
+//! Tests for negative-vector search (`search_with_negatives`, `NEAR ... AVOID`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::collections::HashMap;
+
+use crate::collection::types::Collection;
+use crate::collection::NegativeStrategy;
+use crate::error::Error;
+use crate::test_fixtures::fixtures::{make_point_with_payload, setup_collection};
+use crate::velesql::{Condition, Parser, VectorExpr};
+
+const POSITIVE: [f32; 3] = [1.0, 0.0, 0.0];
+const NEGATIVE: [f32; 3] = [0.0, 1.0, 0.0];
+
+/// Point 2 beats point 3 on the positive alone but leans toward the negative.
+fn setup_negatives_collection() -> (tempfile::TempDir, Collection) {
+    let points = vec![
+        make_point_with_payload(1, vec![1.0, 0.0, 0.0], serde_json::json!({ "n": 1 })),
+        make_point_with_payload(2, vec![0.9, 0.44, 0.0], serde_json::json!({ "n": 2 })),
+        make_point_with_payload(3, vec![0.8, 0.0, 0.6], serde_json::json!({ "n": 3 })),
+        make_point_with_payload(4, vec![0.0, 1.0, 0.0], serde_json::json!({ "n": 4 })),
+    ];
+    let (dir, col) = setup_collection(3);
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+fn ids(results: &[crate::point::SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+#[test]
+fn test_plain_search_prefers_point_near_negative() {
+    let (_dir, col) = setup_negatives_collection();
+    let results = col.search(&POSITIVE, 3).expect("search");
+    assert_eq!(ids(&results), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_rocchio_pushes_results_away_from_negative() {
+    let (_dir, col) = setup_negatives_collection();
+    let results = col
+        .search_with_negatives(&POSITIVE, &[&NEGATIVE], 3, NegativeStrategy::default())
+        .expect("rocchio search");
+    assert_eq!(ids(&results), vec![1, 3, 2]);
+}
+
+#[test]
+fn test_penalty_rescores_candidates_by_closest_negative() {
+    let (_dir, col) = setup_negatives_collection();
+    let results = col
+        .search_with_negatives(
+            &POSITIVE,
+            &[&NEGATIVE],
+            4,
+            NegativeStrategy::Penalty { weight: 0.5 },
+        )
+        .expect("penalty search");
+    assert_eq!(ids(&results), vec![1, 3, 2, 4]);
+    // Point 3 is orthogonal to the negative, so it keeps its cosine of 0.8.
+    assert!(
+        (results[1].score - 0.8).abs() < 1e-4,
+        "{}",
+        results[1].score
+    );
+    // Point 4 *is* the negative: 0 - 0.5 * 1.
+    assert!(
+        (results[3].score + 0.5).abs() < 1e-4,
+        "{}",
+        results[3].score
+    );
+}
+
+#[test]
+fn test_no_negatives_is_plain_search() {
+    let (_dir, col) = setup_negatives_collection();
+    let plain = col.search(&POSITIVE, 3).expect("search");
+    let results = col
+        .search_with_negatives(&POSITIVE, &[], 3, NegativeStrategy::default())
+        .expect("no negatives");
+    assert_eq!(ids(&results), ids(&plain));
+}
+
+#[test]
+fn test_invalid_negatives_rejected() {
+    let (_dir, col) = setup_negatives_collection();
+    let err = col
+        .search_with_negatives(&POSITIVE, &[&[1.0, 0.0]], 3, NegativeStrategy::default())
+        .expect_err("dimension mismatch");
+    assert!(
+        matches!(err, Error::DimensionMismatch { .. }),
+        "got {err:?}"
+    );
+
+    let err = col
+        .search_with_negatives(
+            &POSITIVE,
+            &[&NEGATIVE],
+            3,
+            NegativeStrategy::Rocchio { weight: -1.0 },
+        )
+        .expect_err("negative weight");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+}
+
+#[test]
+fn test_velesql_avoid_parses_single_and_list() {
+    let query = Parser::parse("SELECT * FROM c WHERE vector NEAR $v AVOID [$n1, $n2] LIMIT 5")
+        .expect("AVOID list parses");
+    let Some(Condition::VectorSearch(vs)) = query.select.where_clause else {
+        panic!("expected a NEAR condition");
+    };
+    assert_eq!(
+        vs.avoid,
+        vec![
+            VectorExpr::Parameter("n1".to_string()),
+            VectorExpr::Parameter("n2".to_string())
+        ]
+    );
+
+    let query = Parser::parse("SELECT * FROM c WHERE vector NEAR [1.0, 0.0] AVOID [0.0, 1.0]")
+        .expect("AVOID literal parses");
+    let Some(Condition::VectorSearch(vs)) = query.select.where_clause else {
+        panic!("expected a NEAR condition");
+    };
+    assert_eq!(vs.avoid, vec![VectorExpr::Literal(vec![0.0, 1.0])]);
+}
+
+#[test]
+fn test_velesql_near_avoid_matches_rocchio_search() {
+    let (_dir, col) = setup_negatives_collection();
+    let params = HashMap::from([
+        ("v".to_string(), serde_json::json!(POSITIVE)),
+        ("n".to_string(), serde_json::json!(NEGATIVE)),
+    ]);
+    let rows = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v AVOID $n LIMIT 3",
+            &params,
+        )
+        .expect("NEAR ... AVOID executes");
+    assert_eq!(ids(&rows), vec![1, 3, 2]);
+
+    let err = col
+        .execute_query_str(
+            "SELECT * FROM c WHERE vector NEAR $v AVOID [0.0, 1.0] LIMIT 3",
+            &params,
+        )
+        .expect_err("AVOID literal has the wrong dimension");
+    assert!(
+        matches!(err, Error::DimensionMismatch { .. }),
+        "got {err:?}"
+    );
+}
@@ -0,0 +1,300 @@
+//! Cursor-paginated and streaming vector search.
+//!
+//! [`Collection::search_paginated`] returns one page of results plus a
+//! cursor token; passing the token back returns the next page. The ranking
+//! behind a cursor is computed once over a window of candidates and served
+//! from memory page after page, so a page costs only the hydration of its own
+//! points. When a cursor runs past its window, the window is re-ranked at
+//! twice the size (up to [`Collection::max_query_limit`]) and the points
+//! already served are skipped, so no point is returned twice.
+//!
+//! [`Collection::search_iter`] pulls the same pages lazily through an
+//! [`Iterator`].
+//!
+//! Cursors live in memory only, per collection. They expire
+//! [`SEARCH_CURSOR_TTL`] after being parked, and at most
+//! [`MAX_SEARCH_CURSORS`] are kept; parking one more evicts the oldest.
+//! Tokens are single-use: each page parks the cursor again under a fresh
+//! token.
+
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, VecDeque};
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use rustc_hash::FxHashSet;
+
+use super::resolve;
+use super::vector::tag_vector_component_scores;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::point::SearchResult;
+use crate::scored_result::ScoredResult;
+
+/// How long a parked search cursor stays resumable.
+pub const SEARCH_CURSOR_TTL: Duration = Duration::from_secs(300);
+
+/// Maximum number of parked search cursors per collection.
+pub const MAX_SEARCH_CURSORS: usize = 256;
+
+/// Pages ranked up front when a cursor is created.
+const PREFETCH_PAGES: usize = 4;
+
+/// One page of [`VectorCollection::search_paginated`](crate::VectorCollection::search_paginated).
+#[derive(Debug, Clone)]
+pub struct SearchPage {
+    /// Results of this page, best match first.
+    pub results: Vec<SearchResult>,
+    /// Token resuming after this page (`None` when no results are left).
+    pub next_cursor: Option<String>,
+}
+
+/// Ranked candidates of one query, consumed page by page.
+#[derive(Debug, Clone)]
+struct RankedWindow {
+    query: Vec<f32>,
+    /// Ranked candidates not served yet.
+    pending: VecDeque<ScoredResult>,
+    /// Ids already served, skipped when the window is re-ranked.
+    served: FxHashSet<u64>,
+    /// `k` of the last ranking.
+    ranked_k: usize,
+    /// No candidate exists beyond `pending`.
+    exhausted: bool,
+}
+
+/// Parked search cursors by token.
+#[derive(Default)]
+pub(crate) struct SearchCursors {
+    cursors: Mutex<HashMap<String, ParkedWindow>>,
+    /// Cursors parked so far; orders evictions and keeps tokens distinct.
+    issued: AtomicU64,
+}
+
+struct ParkedWindow {
+    window: RankedWindow,
+    parked_at: Instant,
+    seq: u64,
+}
+
+impl SearchCursors {
+    fn park(&self, window: RankedWindow) -> String {
+        let seq = self.issued.fetch_add(1, Ordering::Relaxed);
+        let token = new_token(seq);
+        let now = Instant::now();
+        let mut cursors = self.cursors.lock();
+        cursors.retain(|_, parked| now.duration_since(parked.parked_at) < SEARCH_CURSOR_TTL);
+        if cursors.len() >= MAX_SEARCH_CURSORS {
+            let oldest = cursors
+                .iter()
+                .min_by_key(|(_, parked)| parked.seq)
+                .map(|(token, _)| token.clone());
+            if let Some(oldest) = oldest {
+                cursors.remove(&oldest);
+            }
+        }
+        cursors.insert(
+            token.clone(),
+            ParkedWindow {
+                window,
+                parked_at: now,
+                seq,
+            },
+        );
+        token
+    }
+
+    /// Removes and returns the window parked under `token` for `query`; a
+    /// window parked for another query stays parked.
+    fn take(&self, token: &str, query: &[f32]) -> Option<RankedWindow> {
+        let mut cursors = self.cursors.lock();
+        let parked = cursors.get(token)?;
+        if parked.parked_at.elapsed() >= SEARCH_CURSOR_TTL {
+            cursors.remove(token);
+            return None;
+        }
+        if parked.window.query != query {
+            return None;
+        }
+        cursors.remove(token).map(|parked| parked.window)
+    }
+}
+
+/// Lazily pulls the results of a query, page by page. Created by
+/// [`VectorCollection::search_iter`](crate::VectorCollection::search_iter).
+pub struct SearchResultIter {
+    collection: Collection,
+    window: RankedWindow,
+    page_size: usize,
+    page: std::vec::IntoIter<SearchResult>,
+}
+
+impl Iterator for SearchResultIter {
+    type Item = SearchResult;
+
+    fn next(&mut self) -> Option<SearchResult> {
+        if let Some(result) = self.page.next() {
+            return Some(result);
+        }
+        self.page = self
+            .collection
+            .next_page(&mut self.window, self.page_size)
+            .into_iter();
+        self.page.next()
+    }
+}
+
+impl std::fmt::Debug for SearchResultIter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchResultIter")
+            .field("page_size", &self.page_size)
+            .field("served", &self.window.served.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Collection {
+    /// Returns one page of the kNN results for `query`.
+    ///
+    /// Pass `cursor: None` for the first page and the previous page's
+    /// `next_cursor` for the following ones, with the same `query`. Results
+    /// come best match first and no point is returned twice across the
+    /// pages of a cursor.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Config` if `page_size` is 0.
+    /// - `Error::QueryLimitExceeded` if `page_size` exceeds
+    ///   [`max_query_limit`](Self::max_query_limit).
+    /// - `Error::Query` if `cursor` is unknown, expired, already used, or
+    ///   was issued for another query.
+    /// - `Error::SearchNotSupported` / `Error::DimensionMismatch` as
+    ///   [`search`](Self::search).
+    pub fn search_paginated(
+        &self,
+        query: &[f32],
+        page_size: usize,
+        cursor: Option<&str>,
+    ) -> Result<SearchPage> {
+        let mut window = match cursor {
+            None => self.new_window(query, page_size)?,
+            Some(token) => {
+                self.check_page_size(page_size)?;
+                self.query
+                    .search_cursors
+                    .take(token, query)
+                    .ok_or_else(|| {
+                        Error::Query(
+                            "search cursor is unknown, expired or was issued for another query"
+                                .to_string(),
+                        )
+                    })?
+            }
+        };
+        let results = self.next_page(&mut window, page_size);
+        let next_cursor = (!window.pending.is_empty() || !window.exhausted)
+            .then(|| self.query.search_cursors.park(window));
+        Ok(SearchPage {
+            results,
+            next_cursor,
+        })
+    }
+
+    /// Returns an iterator over the kNN results for `query`, best match
+    /// first, ranking `page_size` results at a time as it is consumed.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`search_paginated`](Self::search_paginated) for a first
+    /// page.
+    pub fn search_iter(&self, query: &[f32], page_size: usize) -> Result<SearchResultIter> {
+        Ok(SearchResultIter {
+            collection: self.clone(),
+            window: self.new_window(query, page_size)?,
+            page_size,
+            page: Vec::new().into_iter(),
+        })
+    }
+
+    fn check_page_size(&self, page_size: usize) -> Result<()> {
+        if page_size == 0 {
+            return Err(Error::Config(
+                "page_size must be greater than 0".to_string(),
+            ));
+        }
+        self.check_query_limit(u64::try_from(page_size).unwrap_or(u64::MAX))
+    }
+
+    /// Validates `query` and ranks the first window of candidates.
+    fn new_window(&self, query: &[f32], page_size: usize) -> Result<RankedWindow> {
+        self.check_page_size(page_size)?;
+        self.validate_query_and_read_metric(query)?;
+        let mut window = RankedWindow {
+            query: query.to_vec(),
+            pending: VecDeque::new(),
+            served: FxHashSet::default(),
+            ranked_k: 0,
+            exhausted: false,
+        };
+        let k = page_size
+            .saturating_mul(PREFETCH_PAGES)
+            .min(self.max_query_limit());
+        self.rank(&mut window, k);
+        Ok(window)
+    }
+
+    /// Re-ranks `window` at `k` candidates, keeping those not served yet.
+    fn rank(&self, window: &mut RankedWindow, k: usize) {
+        let ranked = self.search_ids_with_adc_if_pq(&window.query, k);
+        window.exhausted = ranked.len() < k || k >= self.max_query_limit();
+        window.ranked_k = k;
+        window.pending = ranked
+            .into_iter()
+            .filter(|candidate| !window.served.contains(&candidate.id))
+            .collect();
+    }
+
+    /// Hydrates up to `page_size` further results of `window`, widening it
+    /// when it runs dry. Deleted and expired candidates are skipped.
+    fn next_page(&self, window: &mut RankedWindow, page_size: usize) -> Vec<SearchResult> {
+        let mut results = Vec::with_capacity(page_size);
+        while results.len() < page_size {
+            if window.pending.is_empty() {
+                if window.exhausted {
+                    break;
+                }
+                let k = window
+                    .ranked_k
+                    .saturating_mul(2)
+                    .min(self.max_query_limit());
+                self.rank(window, k);
+                continue;
+            }
+            let take = (page_size - results.len()).min(window.pending.len());
+            let batch: Vec<ScoredResult> = window.pending.drain(..take).collect();
+            window
+                .served
+                .extend(batch.iter().map(|candidate| candidate.id));
+
+            let vector_storage = self.storage.vector_storage.read();
+            let payload_storage = self.storage.payload_storage.read();
+            let payloads = self.payload_reader(&*payload_storage);
+            results.extend(resolve::resolve_scored_results(
+                &batch,
+                &*vector_storage,
+                &payloads,
+            ));
+        }
+        tag_vector_component_scores(&mut results);
+        self.record_reads(results.iter().map(|r| r.point.id));
+        results
+    }
+}
+
+fn new_token(seq: u64) -> String {
+    let hi = RandomState::new().hash_one(seq);
+    let lo = RandomState::new().hash_one(seq);
+    format!("{hi:016x}{lo:016x}")
+}
@@ -0,0 +1,152 @@
+//! Tests for cursor-paginated and streaming search (`search_paginated`,
+//! `search_iter`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::test_fixtures::fixtures::make_point_with_payload;
+
+const POINTS: u64 = 100;
+
+fn vector_of(id: u64) -> Vec<f32> {
+    #[allow(clippy::cast_precision_loss)] // Reason: ids stay below 2^24.
+    let theta = (id as f32 * 1.7).to_radians();
+    vec![theta.cos(), theta.sin(), 0.0, 0.0]
+}
+
+fn setup() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("test: tempdir");
+    let col = Collection::create(PathBuf::from(dir.path()), 4, DistanceMetric::Cosine)
+        .expect("test: create");
+    let points: Vec<_> = (0..POINTS)
+        .map(|id| make_point_with_payload(id, vector_of(id), serde_json::json!({ "id": id })))
+        .collect();
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+/// Follows the cursors from the first page to the last, returning the ids
+/// of each page.
+fn all_pages(col: &Collection, query: &[f32], page_size: usize) -> Vec<Vec<u64>> {
+    let mut pages = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = col
+            .search_paginated(query, page_size, cursor.as_deref())
+            .expect("test: page");
+        pages.push(page.results.iter().map(|r| r.point.id).collect());
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => return pages,
+        }
+    }
+}
+
+#[test]
+fn test_pages_cover_every_point_once_best_first() {
+    let (_dir, col) = setup();
+    let query = vector_of(0);
+
+    // Page size 7 exhausts the initial 28-candidate window several times.
+    let pages = all_pages(&col, &query, 7);
+    let ids: Vec<u64> = pages.iter().flatten().copied().collect();
+    assert_eq!(ids.len(), usize::try_from(POINTS).unwrap());
+    assert_eq!(ids.iter().copied().collect::<HashSet<_>>().len(), ids.len());
+    assert!(pages[..pages.len() - 1].iter().all(|page| page.len() == 7));
+
+    let first: Vec<u64> = col
+        .search(&query, 7)
+        .expect("test: search")
+        .iter()
+        .map(|r| r.point.id)
+        .collect();
+    assert_eq!(pages[0], first);
+    assert_eq!(ids[0], 0);
+}
+
+#[test]
+fn test_search_iter_streams_the_same_ranking() {
+    let (_dir, col) = setup();
+    let query = vector_of(10);
+
+    let paged: Vec<u64> = all_pages(&col, &query, 10).concat();
+    let streamed: Vec<u64> = col
+        .search_iter(&query, 10)
+        .expect("test: iter")
+        .map(|r| r.point.id)
+        .collect();
+    assert_eq!(streamed, paged);
+
+    let first_three: Vec<u64> = col
+        .search_iter(&query, 2)
+        .expect("test: iter")
+        .take(3)
+        .map(|r| r.point.id)
+        .collect();
+    assert_eq!(first_three, paged[..3]);
+}
+
+#[test]
+fn test_points_deleted_between_pages_are_skipped() {
+    let (_dir, col) = setup();
+    let query = vector_of(0);
+    let first = col
+        .search_paginated(&query, 5, None)
+        .expect("test: first page");
+    let cursor = first.next_cursor.expect("test: more pages");
+
+    let upcoming: Vec<u64> = col
+        .search(&query, 10)
+        .expect("test: search")
+        .iter()
+        .skip(5)
+        .map(|r| r.point.id)
+        .collect();
+    col.delete(&upcoming[..2]).expect("test: delete");
+
+    let second = col
+        .search_paginated(&query, 5, Some(&cursor))
+        .expect("test: second page");
+    let ids: Vec<u64> = second.results.iter().map(|r| r.point.id).collect();
+    assert_eq!(ids.len(), 5);
+    assert!(!ids.contains(&upcoming[0]) && !ids.contains(&upcoming[1]));
+    assert_eq!(ids[..3], upcoming[2..5]);
+}
+
+#[test]
+fn test_invalid_cursors_and_page_sizes_are_rejected() {
+    let (_dir, col) = setup();
+    let query = vector_of(0);
+    assert!(matches!(
+        col.search_paginated(&query, 0, None),
+        Err(Error::Config(_))
+    ));
+    assert!(matches!(
+        col.search_paginated(&query, 5, Some("nope")),
+        Err(Error::Query(_))
+    ));
+
+    let cursor = col
+        .search_paginated(&query, 5, None)
+        .expect("test: first page")
+        .next_cursor
+        .expect("test: more pages");
+    // A cursor only resumes the query it was issued for, and stays parked
+    // when presented with another one.
+    assert!(matches!(
+        col.search_paginated(&vector_of(50), 5, Some(&cursor)),
+        Err(Error::Query(_))
+    ));
+    col.search_paginated(&query, 5, Some(&cursor))
+        .expect("test: resume");
+    // Tokens are single-use.
+    assert!(matches!(
+        col.search_paginated(&query, 5, Some(&cursor)),
+        Err(Error::Query(_))
+    ));
+}
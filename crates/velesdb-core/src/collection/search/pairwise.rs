@@ -0,0 +1,216 @@
+//! Pairwise similarity between stored points.
+//!
+//! Returns either the full `n x n` matrix or each point's `k` nearest
+//! neighbours within the set. Useful for clustering, near-duplicate review
+//! and building a graph from embeddings. Rows are scored in parallel; each
+//! row is one batched SIMD kernel call over every vector of the set.
+
+use std::cmp::Ordering;
+
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::simd_native;
+use crate::storage::VectorStorage;
+
+/// Most ids accepted by [`Collection::pairwise_similarity`]: the dense matrix
+/// holds `n²` scores (64 MiB at this size).
+pub const MAX_DENSE_PAIRWISE_IDS: usize = 4_096;
+
+/// Full similarity matrix returned by [`Collection::pairwise_similarity`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarityMatrix {
+    /// Point ids, in request order; row and column `i` belong to `ids[i]`.
+    pub ids: Vec<u64>,
+    /// Row-major `ids.len() x ids.len()` scores in the collection metric.
+    pub scores: Vec<f32>,
+}
+
+impl SimilarityMatrix {
+    /// Number of rows (and columns).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` for the matrix of an empty id set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Scores of `ids[row]` against every point of the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= self.len()`.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[f32] {
+        let n = self.len();
+        &self.scores[row * n..(row + 1) * n]
+    }
+
+    /// Score between `ids[row]` and `ids[col]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    #[must_use]
+    pub fn get(&self, row: usize, col: usize) -> f32 {
+        self.row(row)[col]
+    }
+}
+
+/// Sparse similarity matrix returned by
+/// [`Collection::pairwise_similarity_top_k`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseSimilarityMatrix {
+    /// Point ids, in request order.
+    pub ids: Vec<u64>,
+    /// For each id, its best `(neighbour id, score)` pairs within the set,
+    /// best first. A point is never its own neighbour.
+    pub neighbors: Vec<Vec<(u64, f32)>>,
+}
+
+impl Collection {
+    /// Computes the similarity of every pair of `ids`, in the collection's
+    /// metric (cosine similarity, dot product, euclidean distance, ...).
+    ///
+    /// The diagonal holds each point's score against itself. For more than
+    /// [`MAX_DENSE_PAIRWISE_IDS`] points use
+    /// [`pairwise_similarity_top_k`](Self::pairwise_similarity_top_k).
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] for duplicate ids or more than
+    ///   [`MAX_DENSE_PAIRWISE_IDS`] ids.
+    /// - [`Error::PointNotFound`] if an id has no stored vector.
+    /// - [`Error::SearchNotSupported`] on a metadata-only collection.
+    pub fn pairwise_similarity(&self, ids: &[u64]) -> Result<SimilarityMatrix> {
+        if ids.len() > MAX_DENSE_PAIRWISE_IDS {
+            return Err(Error::Config(format!(
+                "pairwise_similarity accepts at most {MAX_DENSE_PAIRWISE_IDS} ids (got {}); \
+                 use pairwise_similarity_top_k for larger sets",
+                ids.len()
+            )));
+        }
+        let (metric, vectors) = self.pairwise_vectors(ids)?;
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+        let n = ids.len();
+
+        let mut scores = vec![0.0_f32; n * n];
+        if n > 0 {
+            scores
+                .par_chunks_mut(n)
+                .zip(refs.par_iter())
+                .for_each(|(row, query)| row.copy_from_slice(&score_row(metric, &refs, query)));
+        }
+        Ok(SimilarityMatrix {
+            ids: ids.to_vec(),
+            scores,
+        })
+    }
+
+    /// Keeps, for every point of `ids`, its `k` most similar other points of
+    /// the set. Memory stays `O(n·k)`, so this scales past the dense limit.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] for duplicate ids.
+    /// - [`Error::QueryLimitExceeded`] if `ids` is longer than the
+    ///   collection's [`max_query_limit`](Self::max_query_limit).
+    /// - [`Error::PointNotFound`] if an id has no stored vector.
+    /// - [`Error::SearchNotSupported`] on a metadata-only collection.
+    pub fn pairwise_similarity_top_k(
+        &self,
+        ids: &[u64],
+        k: usize,
+    ) -> Result<SparseSimilarityMatrix> {
+        self.check_query_limit(ids.len() as u64)?;
+        let (metric, vectors) = self.pairwise_vectors(ids)?;
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+        let k = k.min(ids.len().saturating_sub(1));
+
+        let neighbors = refs
+            .par_iter()
+            .enumerate()
+            .map(|(row, query)| {
+                let mut hits: Vec<(u64, f32)> = ids
+                    .iter()
+                    .copied()
+                    .zip(score_row(metric, &refs, query))
+                    .enumerate()
+                    .filter_map(|(col, hit)| (col != row).then_some(hit))
+                    .collect();
+                let cmp = |a: &(u64, f32), b: &(u64, f32)| best_first(metric, a, b);
+                if k < hits.len() {
+                    hits.select_nth_unstable_by(k, cmp);
+                    hits.truncate(k);
+                }
+                hits.sort_unstable_by(cmp);
+                hits
+            })
+            .collect();
+        Ok(SparseSimilarityMatrix {
+            ids: ids.to_vec(),
+            neighbors,
+        })
+    }
+
+    /// Loads the stored vectors of `ids`, unit-normalized under cosine so
+    /// rows can use the plain dot-product kernel.
+    fn pairwise_vectors(&self, ids: &[u64]) -> Result<(DistanceMetric, Vec<Vec<f32>>)> {
+        let (metric, name, metadata_only) = {
+            let config = self.storage.config.read();
+            (config.metric, config.name.clone(), config.metadata_only)
+        };
+        if metadata_only {
+            return Err(Error::SearchNotSupported(name));
+        }
+        let mut seen = FxHashSet::default();
+        if let Some(id) = ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(Error::Config(format!(
+                "duplicate id {id} in pairwise similarity request"
+            )));
+        }
+
+        let vector_storage = self.storage.vector_storage.read();
+        let mut vectors = Vec::with_capacity(ids.len());
+        for &id in ids {
+            let mut vector = vector_storage
+                .retrieve(id)?
+                .ok_or(Error::PointNotFound(id))?;
+            if metric == DistanceMetric::Cosine {
+                simd_native::normalize_inplace_native(&mut vector);
+            }
+            vectors.push(vector);
+        }
+        Ok((metric, vectors))
+    }
+}
+
+/// Scores `query` against every vector of the set with the metric's batch
+/// kernel. Cosine vectors are pre-normalized, so cosine is a dot product.
+fn score_row(metric: DistanceMetric, vectors: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    match metric {
+        DistanceMetric::Cosine | DistanceMetric::DotProduct => {
+            simd_native::batch_dot_product_native(vectors, query)
+        }
+        DistanceMetric::Euclidean => simd_native::batch_euclidean_native(vectors, query),
+        DistanceMetric::Hamming => simd_native::batch_hamming_native(vectors, query),
+        DistanceMetric::Jaccard => simd_native::batch_jaccard_native(vectors, query),
+    }
+}
+
+/// Orders neighbours best first for the metric, lower id first on ties.
+fn best_first(metric: DistanceMetric, a: &(u64, f32), b: &(u64, f32)) -> Ordering {
+    let by_score = if metric.higher_is_better() {
+        b.1.total_cmp(&a.1)
+    } else {
+        a.1.total_cmp(&b.1)
+    };
+    by_score.then(a.0.cmp(&b.0))
+}
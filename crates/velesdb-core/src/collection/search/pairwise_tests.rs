@@ -0,0 +1,119 @@
+//! Tests for pairwise similarity (`pairwise_similarity`, `pairwise_similarity_top_k`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::path::PathBuf;
+
+use crate::collection::types::Collection;
+use crate::collection::MAX_DENSE_PAIRWISE_IDS;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::test_fixtures::fixtures::{make_point, setup_collection_with_points};
+
+const VECTORS: [(u64, [f32; 3]); 5] = [
+    (1, [1.0, 0.0, 0.0]),
+    (2, [0.9, 0.1, 0.0]),
+    (3, [0.0, 1.0, 0.0]),
+    (4, [0.0, 0.0, 1.0]),
+    (5, [0.5, 0.5, 0.0]),
+];
+
+fn points() -> Vec<crate::point::Point> {
+    VECTORS
+        .iter()
+        .map(|(id, v)| make_point(*id, v.to_vec()))
+        .collect()
+}
+
+fn neighbor_ids(row: &[(u64, f32)]) -> Vec<u64> {
+    row.iter().map(|(id, _)| *id).collect()
+}
+
+#[test]
+fn test_dense_matrix_matches_metric_for_every_pair() {
+    let (_dir, col) = setup_collection_with_points(3, points());
+    let ids = [3, 1, 2];
+    let matrix = col.pairwise_similarity(&ids).expect("pairwise");
+
+    assert_eq!(matrix.ids, ids);
+    assert_eq!(matrix.len(), 3);
+    for (row, a) in ids.iter().enumerate() {
+        for (col_idx, b) in ids.iter().enumerate() {
+            let va = VECTORS.iter().find(|(id, _)| id == a).map(|(_, v)| v);
+            let vb = VECTORS.iter().find(|(id, _)| id == b).map(|(_, v)| v);
+            let expected = DistanceMetric::Cosine
+                .calculate(va.expect("test: vector a"), vb.expect("test: vector b"));
+            let got = matrix.get(row, col_idx);
+            assert!(
+                (got - expected).abs() < 1e-5,
+                "({a},{b}): {got} vs {expected}"
+            );
+        }
+        assert!((matrix.row(row)[row] - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_dense_matrix_uses_distance_metrics_as_is() {
+    let dir = tempfile::tempdir().expect("test: tempdir");
+    let col = Collection::create(PathBuf::from(dir.path()), 3, DistanceMetric::Euclidean)
+        .expect("test: collection");
+    col.upsert(points()).expect("test: upsert");
+
+    let matrix = col.pairwise_similarity(&[1, 3, 4]).expect("pairwise");
+    assert!(matrix.get(0, 0).abs() < 1e-6);
+    assert!((matrix.get(0, 1) - 2.0_f32.sqrt()).abs() < 1e-5);
+    assert!((matrix.get(2, 1) - matrix.get(1, 2)).abs() < 1e-6);
+
+    // Distances: the nearest neighbour has the *smallest* score.
+    let sparse = col.pairwise_similarity_top_k(&[1, 2, 3], 1).expect("top k");
+    assert_eq!(neighbor_ids(&sparse.neighbors[2]), vec![2]);
+}
+
+#[test]
+fn test_top_k_keeps_best_neighbors_excluding_self() {
+    let (_dir, col) = setup_collection_with_points(3, points());
+    let ids = [1, 2, 3, 4, 5];
+    let sparse = col.pairwise_similarity_top_k(&ids, 2).expect("top k");
+
+    assert_eq!(sparse.ids, ids);
+    let rows: Vec<Vec<u64>> = sparse.neighbors.iter().map(|r| neighbor_ids(r)).collect();
+    assert_eq!(rows[0], vec![2, 5]);
+    assert_eq!(rows[2], vec![5, 2]);
+    // Point 4 is orthogonal to all others: ties resolve to the lower ids.
+    assert_eq!(rows[3], vec![1, 2]);
+    assert!(sparse.neighbors[0][0].1 > sparse.neighbors[0][1].1);
+}
+
+#[test]
+fn test_top_k_clamps_k_and_handles_empty_sets() {
+    let (_dir, col) = setup_collection_with_points(3, points());
+    let sparse = col
+        .pairwise_similarity_top_k(&[1, 2, 3], 10)
+        .expect("top k");
+    assert!(sparse.neighbors.iter().all(|row| row.len() == 2));
+
+    let sparse = col.pairwise_similarity_top_k(&[1, 2], 0).expect("k = 0");
+    assert!(sparse.neighbors.iter().all(Vec::is_empty));
+
+    let matrix = col.pairwise_similarity(&[]).expect("empty");
+    assert!(matrix.is_empty());
+    assert!(matrix.scores.is_empty());
+}
+
+#[test]
+fn test_pairwise_rejects_bad_id_sets() {
+    let (_dir, col) = setup_collection_with_points(3, points());
+
+    let err = col.pairwise_similarity(&[1, 2, 1]).expect_err("duplicate");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+
+    let err = col
+        .pairwise_similarity_top_k(&[1, 99], 1)
+        .expect_err("unknown id");
+    assert!(matches!(err, Error::PointNotFound(99)), "got {err:?}");
+
+    let too_many: Vec<u64> = (0..=MAX_DENSE_PAIRWISE_IDS as u64).collect();
+    let err = col.pairwise_similarity(&too_many).expect_err("too many");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+}
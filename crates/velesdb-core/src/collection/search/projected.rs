@@ -0,0 +1,48 @@
+//! Vector search with a [`PointProjection`] applied to each hit.
+
+use super::resolve;
+use super::vector::tag_vector_component_scores;
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::point::{PointProjection, SearchResult};
+
+impl Collection {
+    /// Searches for the k nearest neighbors, keeping only the parts of each
+    /// hit selected by `projection`.
+    ///
+    /// Ranking and visibility are identical to [`search`](Self::search).
+    /// When the vector is excluded, hits are hydrated from the payload store
+    /// only, so vector bytes are never copied out of storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query vector dimension doesn't match the
+    /// collection, or if this is a metadata-only collection.
+    pub fn search_projected(
+        &self,
+        query: &[f32],
+        k: usize,
+        projection: PointProjection,
+    ) -> Result<Vec<SearchResult>> {
+        if projection.with_vector {
+            let mut results = self.search(query, k)?;
+            for result in &mut results {
+                projection.apply(&mut result.point);
+            }
+            return Ok(results);
+        }
+
+        let index_results = self.search_ids(query, k)?;
+        let vector_storage = self.storage.vector_storage.read();
+        let payload_storage = self.storage.payload_storage.read();
+        let payloads = self.payload_reader(&*payload_storage);
+        let mut results = resolve::resolve_scored_results_without_vectors(
+            &index_results,
+            projection,
+            &*vector_storage,
+            &payloads,
+        );
+        tag_vector_component_scores(&mut results);
+        Ok(results)
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::{
+    collection::Collection,
+    distance::DistanceMetric,
+    point::{Point, PointProjection},
+};
+use std::path::PathBuf;
+
+fn seeded_collection() -> (tempfile::TempDir, Collection) {
+    let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+    let collection = Collection::create(PathBuf::from(temp_dir.path()), 3, DistanceMetric::Cosine)
+        .expect("collection should be created");
+    collection
+        .upsert(vec![
+            Point::new(1, vec![1.0, 0.0, 0.0], Some(serde_json::json!({"t": "a"}))),
+            Point::new(2, vec![0.9, 0.1, 0.0], Some(serde_json::json!({"t": "b"}))),
+            Point::new(3, vec![0.0, 0.0, 1.0], Some(serde_json::json!({"t": "c"}))),
+        ])
+        .expect("upsert should succeed");
+    (temp_dir, collection)
+}
+
+#[test]
+fn test_search_projected_matches_search_ranking() {
+    let (_dir, collection) = seeded_collection();
+    let query = [1.0, 0.0, 0.0];
+    let full = collection.search(&query, 3).expect("search");
+
+    for projection in [
+        PointProjection::FULL,
+        PointProjection::new(true, false),
+        PointProjection::new(false, true),
+        PointProjection::ID_ONLY,
+    ] {
+        let projected = collection
+            .search_projected(&query, 3, projection)
+            .expect("projected search");
+        let ids: Vec<u64> = projected.iter().map(|r| r.point.id).collect();
+        let expected: Vec<u64> = full.iter().map(|r| r.point.id).collect();
+        assert_eq!(ids, expected, "ranking must not depend on {projection:?}");
+        for (p, f) in projected.iter().zip(&full) {
+            assert!((p.score - f.score).abs() < f32::EPSILON);
+            assert_eq!(p.point.vector.is_empty(), !projection.with_vector);
+            assert_eq!(p.point.payload.is_some(), projection.with_payload);
+            assert!(p.component_scores.is_some());
+        }
+    }
+}
+
+#[test]
+fn test_search_projected_rejects_dimension_mismatch() {
+    let (_dir, collection) = seeded_collection();
+    assert!(collection
+        .search_projected(&[1.0, 0.0], 3, PointProjection::ID_ONLY)
+        .is_err());
+}
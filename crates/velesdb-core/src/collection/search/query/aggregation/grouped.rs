@@ -17,8 +17,8 @@ use crate::collection::types::Collection;
 use crate::error::Result;
 use crate::storage::{PayloadStorage, VectorStorage};
 use crate::velesql::{
-    AggregateArg, AggregateFunction, AggregateResult, AggregateType, Aggregator, HavingClause,
-    Query,
+    AggregateArg, AggregateFunction, AggregateResult, AggregateType, Aggregator, GroupByClause,
+    HavingClause, Query,
 };
 use std::collections::HashMap;
 
@@ -28,20 +28,19 @@ impl Collection {
         &self,
         query: &Query,
         aggregations: &[AggregateFunction],
-        group_by_columns: &[String],
+        group_by: &GroupByClause,
         having: Option<&HavingClause>,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<serde_json::Value> {
         let stmt = &query.select;
         let max_groups = Self::extract_max_groups_limit(stmt.with_clause.as_ref());
 
-        let groups =
-            self.scan_and_group(stmt, aggregations, group_by_columns, max_groups, params)?;
+        let groups = self.scan_and_group(stmt, aggregations, group_by, max_groups, params)?;
 
         let results = Self::build_grouped_results(
             groups,
             aggregations,
-            group_by_columns,
+            &group_by.columns,
             having,
             stmt.order_by.as_deref(),
             stmt.limit,
@@ -56,29 +55,33 @@ impl Collection {
         &self,
         stmt: &crate::velesql::SelectStatement,
         aggregations: &[AggregateFunction],
-        group_by_columns: &[String],
+        group_by: &GroupByClause,
         max_groups: usize,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<HashMap<GroupKey, Aggregator>> {
         let where_clause = stmt.where_clause.as_ref();
         let use_runtime = Self::needs_runtime_where_eval(where_clause);
         let needs_vector_eval = where_clause.is_some_and(Self::condition_requires_vector_eval);
-        let filter = Self::build_static_filter(where_clause, use_runtime, params)?;
+        let filter = self.build_static_filter(where_clause, use_runtime, params)?;
         let (columns_vec, has_count_star) = Self::prepare_agg_columns(aggregations);
 
-        // LOCK ORDER: vector_storage(2) before payload_storage(3) — was
-        // reversed here. See .investigation/http-deadlock-2026-07-22/.
-        let vector_storage = self.storage.vector_storage.read();
-        let payload_storage = self.storage.payload_storage.read();
+        // One committed snapshot for every group (see `collection::read_snapshot`).
+        let snapshot = self.read_snapshot();
+        let (vector_storage, payload_storage) = (&*snapshot.vectors, &*snapshot.payloads);
         let ids = vector_storage.ids();
-        let mut graph_cache = GraphMatchEvalCache::default();
+        tracing::debug!(
+            snapshot = snapshot.sequence,
+            rows = ids.len(),
+            "grouped aggregation scan"
+        );
+        let mut graph_cache = GraphMatchEvalCache::new(self.default_collation());
         let mut groups: HashMap<GroupKey, Aggregator> = HashMap::new();
 
         for id in ids {
             let payload = payload_storage.retrieve(id).ok().flatten();
             let passes = if use_runtime {
                 let mut rt_ctx = super::RuntimeWhereCtx {
-                    vector_storage: &*vector_storage,
+                    vector_storage,
                     stmt,
                     params,
                     needs_vector_eval,
@@ -96,7 +99,8 @@ impl Collection {
             Self::insert_into_group(
                 &mut groups,
                 payload.as_ref(),
-                group_by_columns,
+                group_by,
+                aggregations,
                 &columns_vec,
                 has_count_star,
                 max_groups,
@@ -120,6 +124,7 @@ impl Collection {
     /// Returns an error when a WHERE parameter placeholder is missing from
     /// `params` (never silently converts it to `NULL`).
     pub(super) fn build_static_filter(
+        &self,
         where_clause: Option<&crate::velesql::Condition>,
         use_runtime: bool,
         params: &HashMap<String, serde_json::Value>,
@@ -130,9 +135,7 @@ impl Collection {
         where_clause
             .map(|cond| {
                 let resolved = Self::resolve_condition_params(cond, params)?;
-                Ok(crate::filter::Filter::new(crate::filter::Condition::from(
-                    resolved,
-                )))
+                Ok(self.where_filter(resolved))
             })
             .transpose()
     }
@@ -164,19 +167,22 @@ impl Collection {
     fn insert_into_group(
         groups: &mut HashMap<GroupKey, Aggregator>,
         payload: Option<&serde_json::Value>,
-        group_by_columns: &[String],
+        group_by: &GroupByClause,
+        aggregations: &[AggregateFunction],
         columns_to_aggregate: &[String],
         has_count_star: bool,
         max_groups: usize,
     ) -> Result<()> {
-        let group_key = Self::extract_group_key_fast(payload, group_by_columns);
+        let group_key = Self::extract_group_key_fast(payload, group_by);
         if !groups.contains_key(&group_key) && groups.len() >= max_groups {
             return Err(crate::error::Error::Config(format!(
                 "Too many groups (limit: {max_groups})"
             )));
         }
 
-        let aggregator = groups.entry(group_key).or_default();
+        let aggregator = groups
+            .entry(group_key)
+            .or_insert_with(|| Aggregator::for_aggregations(aggregations));
         Self::accumulate_record(aggregator, payload, columns_to_aggregate, has_count_star);
         Ok(())
     }
@@ -239,28 +245,8 @@ impl Collection {
         } else {
             match &agg.argument {
                 AggregateArg::Wildcard => "count".to_string(),
-                AggregateArg::Score => {
-                    let prefix = match agg.function_type {
-                        AggregateType::Count => "count",
-                        AggregateType::Sum => "sum",
-                        AggregateType::Avg => "avg",
-                        AggregateType::Min => "min",
-                        AggregateType::Max => "max",
-                        AggregateType::First => "first",
-                    };
-                    format!("{prefix}_score")
-                }
-                AggregateArg::Column(col) => {
-                    let prefix = match agg.function_type {
-                        AggregateType::Count => "count",
-                        AggregateType::Sum => "sum",
-                        AggregateType::Avg => "avg",
-                        AggregateType::Min => "min",
-                        AggregateType::Max => "max",
-                        AggregateType::First => "first",
-                    };
-                    format!("{prefix}_{col}")
-                }
+                AggregateArg::Score => format!("{}_score", agg.result_key_prefix()),
+                AggregateArg::Column(col) => format!("{}_{col}", agg.result_key_prefix()),
             }
         }
     }
@@ -303,6 +289,21 @@ impl Collection {
                 .maxs
                 .get(col)
                 .map_or(serde_json::Value::Null, |v| serde_json::json!(v)),
+            (AggregateType::StdDev, Some(col)) => agg_result
+                .stddevs
+                .get(col)
+                .map_or(serde_json::Value::Null, |v| serde_json::json!(v)),
+            (AggregateType::Variance, Some(col)) => agg_result
+                .variances
+                .get(col)
+                .map_or(serde_json::Value::Null, |v| serde_json::json!(v)),
+            (AggregateType::PercentileCont, Some(col)) => agg_result
+                .percentile(col, agg.fraction.unwrap_or(0.5))
+                .map_or(serde_json::Value::Null, |v| serde_json::json!(v)),
+            (AggregateType::CountDistinct, Some(col)) => {
+                let count = agg_result.distinct_counts.get(col).copied().unwrap_or(0);
+                serde_json::json!(count)
+            }
             _ => serde_json::Value::Null,
         }
     }
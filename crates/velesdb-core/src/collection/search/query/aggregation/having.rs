@@ -40,28 +40,31 @@ impl Collection {
     ) {
         use crate::velesql::OrderByExpr;
 
-        let sort_columns: Vec<(String, bool)> = order_by
+        let sort_columns: Vec<(String, bool, crate::filter::SortCollation)> = order_by
             .iter()
             .filter_map(|clause| {
                 let column = match &clause.expr {
                     OrderByExpr::Field(name) => name.clone(),
                     OrderByExpr::Aggregate(agg) => Self::aggregation_result_key(agg),
-                    // Similarity/Arithmetic ordering not applicable to grouped aggregate rows.
+                    // Similarity/Arithmetic/random ordering not applicable to grouped aggregate rows.
                     OrderByExpr::Similarity(_)
                     | OrderByExpr::SimilarityBare
-                    | OrderByExpr::Arithmetic(_) => return None,
+                    | OrderByExpr::Arithmetic(_)
+                    | OrderByExpr::Random => return None,
                 };
-                Some((column, clause.descending))
+                Some((column, clause.descending, clause.collation))
             })
             .collect();
 
         results.sort_unstable_by(|a, b| {
-            for (column, descending) in &sort_columns {
+            for (column, descending, collation) in &sort_columns {
                 let val_a = a.get(column);
                 let val_b = b.get(column);
 
                 let ordering =
-                    crate::collection::search::query::ordering::compare_json_values(val_a, val_b);
+                    crate::collection::search::query::ordering::compare_json_values_collated(
+                        val_a, val_b, *collation,
+                    );
 
                 let ordering = if *descending {
                     ordering.reverse()
@@ -79,16 +82,21 @@ impl Collection {
 
     /// Extract group key from payload with pre-computed hash (optimized).
     /// Avoids JSON serialization overhead by using direct value hashing.
+    /// Expression keys (`date_trunc(...)`, `floor(price / 10) * 10`) are
+    /// evaluated against the payload; a missing payload groups under NULL.
     pub(super) fn extract_group_key_fast(
         payload: Option<&serde_json::Value>,
-        group_by_columns: &[String],
+        group_by: &crate::velesql::GroupByClause,
     ) -> super::GroupKey {
-        let values: Vec<serde_json::Value> = group_by_columns
+        let values: Vec<serde_json::Value> = group_by
+            .columns
             .iter()
-            .map(|col| {
-                payload
-                    .and_then(|p| Self::get_nested_value(p, col).cloned())
-                    .unwrap_or(serde_json::Value::Null)
+            .map(|col| match (payload, group_by.expression(col)) {
+                (Some(p), Some(expr)) => crate::filter::evaluate_expression(expr, p),
+                (Some(p), None) => Self::get_nested_value(p, col)
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Null),
+                (None, _) => serde_json::Value::Null,
             })
             .collect();
         super::GroupKey::new(values)
@@ -151,6 +159,21 @@ impl Collection {
             (AggregateType::Max, AggregateArg::Column(col)) => {
                 result.maxs.get(col.as_str()).copied()
             }
+            (AggregateType::StdDev, AggregateArg::Column(col)) => {
+                result.stddevs.get(col.as_str()).copied()
+            }
+            (AggregateType::Variance, AggregateArg::Column(col)) => {
+                result.variances.get(col.as_str()).copied()
+            }
+            (AggregateType::PercentileCont, AggregateArg::Column(col)) => {
+                result.percentile(col, agg.fraction.unwrap_or(0.5))
+            }
+            (AggregateType::CountDistinct, AggregateArg::Column(col)) => Some(
+                result
+                    .distinct_counts
+                    .get(col.as_str())
+                    .map_or(0.0, |&c| c as f64),
+            ),
             _ => None,
         }
     }
@@ -259,7 +282,8 @@ impl Collection {
     /// # Errors
     ///
     /// Returns an error when a referenced parameter is missing from `params`
-    /// or has an unsupported type (array/object).
+    /// or has an unsupported type (an object, or an array outside an
+    /// `IN` / `CONTAINS ANY|ALL` list).
     pub(crate) fn resolve_condition_params(
         cond: &crate::velesql::Condition,
         params: &HashMap<String, serde_json::Value>,
@@ -335,14 +359,16 @@ impl Collection {
         })
     }
 
-    /// Resolves every value in a list via [`Self::resolve_where_param`].
+    /// Resolves every value in a list via [`Self::resolve_where_param_list`],
+    /// splicing array parameters (`IN $ids`) in place.
     fn resolve_value_list(
         values: &[Value],
         params: &HashMap<String, serde_json::Value>,
     ) -> crate::error::Result<Vec<Value>> {
-        values
-            .iter()
-            .map(|v| Self::resolve_where_param(v, params))
-            .collect()
+        let mut resolved = Vec::with_capacity(values.len());
+        for value in values {
+            resolved.extend(Self::resolve_where_param_list(value, params)?);
+        }
+        Ok(resolved)
     }
 }
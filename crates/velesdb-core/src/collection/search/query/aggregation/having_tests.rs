@@ -17,8 +17,10 @@ fn test_sort_aggregation_results_order_by_count_desc_sorts_rows() {
             function_type: AggregateType::Count,
             argument: AggregateArg::Wildcard,
             alias: None,
+            fraction: None,
         }),
         descending: true,
+        collation: crate::filter::SortCollation::Binary,
     }];
 
     // ACT
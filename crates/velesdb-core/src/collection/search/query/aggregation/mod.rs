@@ -112,6 +112,7 @@ struct SequentialAggCtx<'a> {
     stmt: &'a crate::velesql::SelectStatement,
     params: &'a HashMap<String, serde_json::Value>,
     filter: Option<&'a crate::filter::Filter>,
+    aggregations: &'a [AggregateFunction],
     columns_to_aggregate: &'a [String],
     has_count_star: bool,
     use_runtime_where_eval: bool,
@@ -127,7 +128,8 @@ const CHUNK_SIZE: usize = 1000;
 impl Collection {
     /// Execute an aggregation query and return results as JSON.
     ///
-    /// Supports COUNT(*), COUNT(column), SUM, AVG, MIN, MAX.
+    /// Supports COUNT(*), COUNT(column), COUNT(DISTINCT column), SUM, AVG,
+    /// MIN, MAX, STDDEV, VARIANCE and PERCENTILE_CONT.
     /// Uses streaming aggregation - O(1) memory, single pass over data.
     ///
     /// # Arguments
@@ -179,7 +181,7 @@ impl Collection {
             return self.execute_grouped_aggregate(
                 query,
                 aggregations,
-                &group_by.columns,
+                group_by,
                 having.as_ref(),
                 params,
             );
@@ -207,32 +209,50 @@ impl Collection {
             Self::condition_contains_graph_match(cond) || Self::condition_requires_vector_eval(cond)
         });
 
-        let filter = Self::build_static_filter(where_clause, use_runtime_where_eval, params)?;
+        let filter = self.build_static_filter(where_clause, use_runtime_where_eval, params)?;
+
+        // `COUNT(*)` alone needs no per-column state: route it to
+        // `Collection::count`, which can answer from secondary indexes.
+        if !use_runtime_where_eval && Self::is_count_star_only(aggregations) {
+            return Ok(crate::velesql::AggregateResult {
+                count: self.count(filter.as_ref()) as u64,
+                ..Default::default()
+            });
+        }
+
         let (columns_vec, has_count_star) = Self::prepare_agg_columns(aggregations);
 
-        // LOCK ORDER: vector_storage(2) before payload_storage(3) — was
-        // reversed here. See .investigation/http-deadlock-2026-07-22/. This
-        // site also feeds `run_parallel_path` (rayon `par_chunks`), so the
-        // wrong order compounded the ABBA risk with rayon-pool exhaustion.
-        let vector_storage = self.storage.vector_storage.read();
-        let payload_storage = self.storage.payload_storage.read();
+        // One committed snapshot for the whole aggregation (see
+        // `collection::read_snapshot`). This site also feeds
+        // `run_parallel_path` (rayon `par_chunks`); the snapshot takes
+        // vector_storage(2) before payload_storage(3), so the ABBA risk of
+        // .investigation/http-deadlock-2026-07-22/ stays closed.
+        let snapshot = self.read_snapshot();
+        let (vector_storage, payload_storage) = (&*snapshot.vectors, &*snapshot.payloads);
         let ids: Vec<u64> = vector_storage.ids();
+        tracing::debug!(
+            snapshot = snapshot.sequence,
+            rows = ids.len(),
+            "aggregation scan"
+        );
 
         if ids.len() >= PARALLEL_THRESHOLD && !use_runtime_where_eval {
             Ok(Self::run_parallel_path(
                 &ids,
-                &*payload_storage,
+                payload_storage,
                 filter.as_ref(),
+                aggregations,
                 &columns_vec,
                 has_count_star,
             ))
         } else {
             let ctx = SequentialAggCtx {
-                payload_storage: &*payload_storage,
-                vector_storage: &*vector_storage,
+                payload_storage,
+                vector_storage,
                 stmt,
                 params,
                 filter: filter.as_ref(),
+                aggregations,
                 columns_to_aggregate: &columns_vec,
                 has_count_star,
                 use_runtime_where_eval,
@@ -241,6 +261,15 @@ impl Collection {
         }
     }
 
+    /// Returns true when every aggregate is `COUNT(*)` (aliases aside).
+    fn is_count_star_only(aggregations: &[AggregateFunction]) -> bool {
+        !aggregations.is_empty()
+            && aggregations.iter().all(|agg| {
+                agg.function_type == crate::velesql::AggregateType::Count
+                    && agg.argument == crate::velesql::AggregateArg::Wildcard
+            })
+    }
+
     /// Runs parallel aggregation, streaming payloads per chunk.
     ///
     /// #901: previously this pre-collected **every** payload into one
@@ -252,10 +281,18 @@ impl Collection {
         ids: &[u64],
         payload_storage: &dyn PayloadStorage,
         filter: Option<&crate::filter::Filter>,
+        aggregations: &[AggregateFunction],
         columns_vec: &[String],
         has_count_star: bool,
     ) -> crate::velesql::AggregateResult {
-        Self::aggregate_parallel(ids, payload_storage, filter, columns_vec, has_count_star)
+        Self::aggregate_parallel(
+            ids,
+            payload_storage,
+            filter,
+            aggregations,
+            columns_vec,
+            has_count_star,
+        )
     }
 
     /// Returns true if the payload passes the static filter.
@@ -296,13 +333,14 @@ impl Collection {
         ids: &[u64],
         payload_storage: &dyn PayloadStorage,
         filter: Option<&crate::filter::Filter>,
+        aggregations: &[AggregateFunction],
         columns_to_aggregate: &[String],
         has_count_star: bool,
     ) -> crate::velesql::AggregateResult {
         let partial_aggregators: Vec<Aggregator> = ids
             .par_chunks(CHUNK_SIZE)
             .map(|chunk| {
-                let mut chunk_agg = Aggregator::new();
+                let mut chunk_agg = Aggregator::for_aggregations(aggregations);
                 for &id in chunk {
                     let payload = payload_storage.retrieve(id).ok().flatten();
                     if let Some(f) = filter {
@@ -390,8 +428,8 @@ impl Collection {
             .where_clause
             .as_ref()
             .is_some_and(Self::condition_requires_vector_eval);
-        let mut aggregator = Aggregator::new();
-        let mut graph_cache = GraphMatchEvalCache::default();
+        let mut aggregator = Aggregator::for_aggregations(ctx.aggregations);
+        let mut graph_cache = GraphMatchEvalCache::new(self.default_collation());
 
         for &id in ids {
             let payload = ctx.payload_storage.retrieve(id).ok().flatten();
@@ -438,18 +476,6 @@ impl Collection {
         payload: &'a serde_json::Value,
         path: &str,
     ) -> Option<&'a serde_json::Value> {
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = payload;
-
-        for part in parts {
-            match current {
-                serde_json::Value::Object(map) => {
-                    current = map.get(part)?;
-                }
-                _ => return None,
-            }
-        }
-
-        Some(current)
+        crate::filter::lookup_path(payload, path)
     }
 }
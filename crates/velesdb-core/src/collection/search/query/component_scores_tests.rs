@@ -375,6 +375,7 @@ fn test_order_by_arithmetic_uses_component_scores() {
             }),
         }),
         descending: true,
+        collation: crate::filter::SortCollation::Binary,
     }];
 
     let params = std::collections::HashMap::new();
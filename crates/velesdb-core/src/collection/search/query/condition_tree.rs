@@ -78,6 +78,7 @@ mod tests {
     fn vector_search_leaf() -> Condition {
         Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Literal(vec![0.1, 0.2, 0.3]),
+            avoid: Vec::new(),
         })
     }
 
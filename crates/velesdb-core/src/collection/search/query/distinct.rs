@@ -8,6 +8,8 @@ use crate::point::SearchResult;
 use crate::velesql::SelectColumns;
 use rustc_hash::FxHashSet;
 
+use super::scratch::Scratch;
+
 /// Apply DISTINCT deduplication to results based on selected columns (EPIC-052 US-001).
 ///
 /// Uses HashSet for O(n) complexity and preserves insertion order.
@@ -25,6 +27,8 @@ use rustc_hash::FxHashSet;
 ///     and must participate in the dedup key, otherwise
 ///     `SELECT DISTINCT ctx.*, title FROM docs` would collapse rows that
 ///     differ only by non-title wildcard fields.
+///   - `expressions` join the listed `columns` by output name: their values
+///     are injected into the payload before DISTINCT runs.
 ///   - `similarity_scores` presence appends the similarity score to the key
 ///     so rows differing only by score are not collapsed.
 ///   - `aggregations` are handled by a separate aggregation pipeline and
@@ -43,13 +47,21 @@ pub fn apply_distinct(results: Vec<SearchResult>, columns: &SelectColumns) -> Ve
             columns: cols,
             similarity_scores,
             qualified_wildcards,
+            expressions,
             ..
         } => {
             // Qualified wildcards expand to every payload field; fall back
             // to "dedup by full payload" (empty column list) so those
             // fields participate in the key.
             let cols_for_dedup = if qualified_wildcards.is_empty() {
-                cols.iter().map(|c| c.name.clone()).collect()
+                cols.iter()
+                    .map(|c| c.name.clone())
+                    .chain(
+                        expressions
+                            .iter()
+                            .map(crate::velesql::SelectExpression::output_name),
+                    )
+                    .collect()
             } else {
                 Vec::new()
             };
@@ -65,7 +77,7 @@ pub fn apply_distinct(results: Vec<SearchResult>, columns: &SelectColumns) -> Ve
         | SelectColumns::QualifiedWildcard(_) => (Vec::new(), false),
     };
 
-    let mut seen: FxHashSet<String> = FxHashSet::default();
+    let mut seen = Scratch::<FxHashSet<String>>::take();
     results
         .into_iter()
         .filter(|r| {
@@ -236,6 +248,7 @@ mod tests {
             }],
             qualified_wildcards: vec![],
             window_functions: vec![],
+            expressions: vec![],
         };
 
         let distinct = apply_distinct(results, &columns);
@@ -259,6 +272,7 @@ mod tests {
             similarity_scores: vec![],
             qualified_wildcards: vec![],
             window_functions: vec![],
+            expressions: vec![],
         };
 
         let distinct = apply_distinct(results, &columns);
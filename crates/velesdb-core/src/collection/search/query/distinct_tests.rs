@@ -137,6 +137,7 @@ mod tests {
             similarity_scores: Vec::new(),
             qualified_wildcards: vec!["ctx".to_string()],
             window_functions: Vec::new(),
+            expressions: vec![],
         };
 
         let distinct = apply_distinct(results, &columns);
@@ -173,6 +174,7 @@ mod tests {
             similarity_scores: Vec::new(),
             qualified_wildcards: Vec::new(),
             window_functions: Vec::new(),
+            expressions: vec![],
         };
 
         let distinct = apply_distinct(results, &columns);
@@ -2,7 +2,6 @@
 //!
 //! Extracted from `query/mod.rs` to reduce NLOC below the 500 threshold.
 
-use super::options::MAX_LIMIT;
 use crate::collection::types::Collection;
 use crate::error::Result;
 use crate::point::SearchResult;
@@ -77,7 +76,7 @@ impl Collection {
         // truncate before the sort (same defect as the main-dispatch path).
         // Fetch exhaustively so the sort precedes truncation.
         let limit = if Self::order_by_requires_exhaustive_fetch(stmt) {
-            MAX_LIMIT
+            self.max_query_limit()
         } else {
             limit
         };
@@ -91,7 +90,7 @@ impl Collection {
 
     /// Runs the union early-return path (EPIC-044 US-002: similarity() OR metadata).
     ///
-    /// The union path keeps the `MAX_LIMIT` window when graph predicates are
+    /// The union path keeps the `max_query_limit` window when graph predicates are
     /// present: its similarity/metadata legs rank independently and would each
     /// need anchor-aware fetching to drop it.
     fn run_union_early(
@@ -99,9 +98,9 @@ impl Collection {
         early: &EarlyReturnCtx<'_>,
         limit: usize,
     ) -> Result<Vec<SearchResult>> {
-        let mut graph_cache = super::where_eval::GraphMatchEvalCache::default();
+        let mut graph_cache = super::where_eval::GraphMatchEvalCache::new(self.default_collation());
         let execution_limit = if early.has_graph_predicates {
-            MAX_LIMIT
+            self.max_query_limit()
         } else {
             limit
         };
@@ -114,13 +113,13 @@ impl Collection {
 
     /// Runs the NOT-similarity early path with GraphFirst anchoring:
     /// AND-required MATCH predicates restrict the scan to their anchor set,
-    /// making the fetch exact at `limit` instead of a MAX_LIMIT window.
+    /// making the fetch exact at `limit` instead of a `max_query_limit` window.
     fn run_not_similarity_early(
         &self,
         early: &EarlyReturnCtx<'_>,
         limit: usize,
     ) -> Result<Vec<SearchResult>> {
-        let mut graph_cache = super::where_eval::GraphMatchEvalCache::default();
+        let mut graph_cache = super::where_eval::GraphMatchEvalCache::new(self.default_collation());
         let anchors = if early.has_graph_predicates {
             self.compute_required_anchor_ids(
                 early.cond,
@@ -132,7 +131,7 @@ impl Collection {
             None
         };
         let execution_limit = if early.has_graph_predicates && anchors.is_none() {
-            MAX_LIMIT
+            self.max_query_limit()
         } else {
             limit
         };
@@ -182,6 +181,7 @@ impl Collection {
         mut results: Vec<SearchResult>,
         early: &EarlyReturnCtx<'_>,
     ) -> Result<Vec<SearchResult>> {
+        super::select_dispatch::inject_select_expressions(&mut results, &early.stmt.columns);
         // Bug #475: Apply DISTINCT before ORDER BY (same as finalize_query_results path).
         if early.stmt.distinct == crate::velesql::DistinctMode::All {
             results = super::distinct::apply_distinct(results, &early.stmt.columns);
@@ -195,14 +195,15 @@ impl Collection {
             let skip = usize::try_from(offset).unwrap_or(usize::MAX);
             results = results.into_iter().skip(skip).collect();
         }
+        let max = self.max_query_limit();
         let final_limit = usize::try_from(
             early
                 .stmt
                 .limit
                 .unwrap_or(crate::velesql::DEFAULT_SELECT_LIMIT),
         )
-        .unwrap_or(MAX_LIMIT)
-        .min(MAX_LIMIT);
+        .unwrap_or(max)
+        .min(max);
         results.truncate(final_limit);
         self.check_guardrails_and_record(early.ctx, results.len())?;
         self.runtime.guard_rails.circuit_breaker.record_success();
@@ -1,4 +1,5 @@
-use super::{Collection, HashSet, QuerySearchOptions, Result, SearchResult, MAX_LIMIT};
+use super::scratch::Scratch;
+use super::{Collection, HashSet, QuerySearchOptions, Result, SearchResult};
 
 impl Collection {
     // Metadata index query strategy is in metadata_query.rs
@@ -10,6 +11,11 @@ impl Collection {
         from_aliases: &[String],
     ) -> Result<HashSet<u64>> {
         let anchor_alias = Self::resolve_anchor_alias(predicate, from_aliases)?;
+        // Patterns that walk reachability decides exactly share one backward
+        // sweep across all anchors instead of a traversal per anchor.
+        if let Some(ids) = self.batched_anchor_ids(&predicate.pattern, params)? {
+            return Ok(ids);
+        }
         let clause = Self::build_anchor_match_clause(predicate);
 
         // Anchor evaluation needs the full unordered match set (it collects bound
@@ -112,6 +118,7 @@ impl Collection {
                 order_by: None,
                 // Internal anchor evaluation must not silently cap MATCH results.
                 limit: Some(u64::MAX),
+                limit_per: None,
             },
         }
     }
@@ -122,6 +129,7 @@ impl Collection {
     /// Handles all combinations of NEAR, similarity(), and metadata-only queries.
     /// Applies optional metadata post-filter to an already similarity-filtered result set.
     fn apply_optional_metadata_filter(
+        &self,
         filtered: Vec<SearchResult>,
         filter_cond: Option<&crate::velesql::Condition>,
         skip_metadata_prefilter_for_graph_or: bool,
@@ -136,7 +144,7 @@ impl Collection {
         let Some(metadata_cond) = Self::extract_metadata_filter(cond) else {
             return filtered;
         };
-        let filter = crate::filter::Filter::new(crate::filter::Condition::from(metadata_cond));
+        let filter = self.where_filter(metadata_cond);
         filtered
             .into_iter()
             .filter(|r| match r.point.payload.as_ref() {
@@ -190,8 +198,7 @@ impl Collection {
             // are silently dropped.
             // Bug #6: route through hybrid_search_with_clause so the FUSION
             // strategy / graph_weight take effect instead of always running RRF.
-            let filter = Self::extract_metadata_filter(cond)
-                .map(|c| crate::filter::Filter::new(crate::filter::Condition::from(c)));
+            let filter = Self::extract_metadata_filter(cond).map(|c| self.where_filter(c));
             return self.hybrid_search_with_clause(
                 vector,
                 &text_query,
@@ -202,12 +209,12 @@ impl Collection {
         }
         let cbo_search_k = execution_limit
             .saturating_mul(cbo_over_fetch)
-            .min(MAX_LIMIT);
+            .min(self.max_query_limit());
         if skip_metadata_prefilter_for_graph_or {
             return self.search_with_opts(vector, execution_limit, search_opts);
         }
         if let Some(metadata_cond) = Self::extract_metadata_filter(cond) {
-            let filter = crate::filter::Filter::new(crate::filter::Condition::from(metadata_cond));
+            let filter = self.where_filter(metadata_cond);
             return self.dispatch_vector_with_strategy(
                 vector,
                 &filter,
@@ -221,7 +228,8 @@ impl Collection {
     }
 
     /// Dispatches a filtered vector query according to the CBO strategy
-    /// (GraphFirst, Parallel, or the default VectorFirst path).
+    /// (GraphFirst, Parallel, or the default VectorFirst path), or to the
+    /// partial index covering `filter` when one exists.
     fn dispatch_vector_with_strategy(
         &self,
         vector: &[f32],
@@ -231,6 +239,11 @@ impl Collection {
         execution_limit: usize,
         search_opts: &QuerySearchOptions,
     ) -> Result<Vec<SearchResult>> {
+        // A covering partial index holds just the filtered points, so its
+        // graph beats both the filtered scan and the full-graph over-fetch.
+        if self.partial_index_for(filter).is_some() {
+            return self.search_with_filter_and_opts(vector, cbo_search_k, filter, search_opts);
+        }
         match cbo_strategy {
             crate::velesql::ExecutionStrategy::GraphFirst => {
                 Ok(self.scan_and_score_by_vector(filter, vector, execution_limit))
@@ -274,6 +287,7 @@ impl Collection {
         cond: &crate::velesql::Condition,
         execution_limit: usize,
         skip_metadata_prefilter_for_graph_or: bool,
+        hints: super::PlannerHints,
     ) -> Result<Vec<SearchResult>> {
         if let crate::velesql::Condition::Match(ref m) = cond {
             return self.text_search(&m.query, execution_limit);
@@ -283,9 +297,26 @@ impl Collection {
         if skip_metadata_prefilter_for_graph_or {
             return Ok(self.execute_scan_query(&empty_filter(), execution_limit, None));
         }
+        // MATCH alongside metadata conditions (`title MATCH 'x' AND year > 2000`):
+        // BM25 drives the candidates and the rest of the WHERE filters them, as
+        // on the NEAR path (Bug #474), instead of scanning without the MATCH.
+        if let Some(text_query) = Self::extract_match_query(cond) {
+            return match Self::extract_metadata_filter(cond) {
+                Some(metadata_cond) => self.text_search_with_filter(
+                    &text_query,
+                    execution_limit,
+                    &self.where_filter(metadata_cond),
+                ),
+                None => self.text_search(&text_query, execution_limit),
+            };
+        }
         let Some(metadata_cond) = Self::extract_metadata_filter(cond) else {
             return Ok(self.execute_scan_query(&empty_filter(), execution_limit, None));
         };
+        if hints.force_scan {
+            let filter = self.where_filter(metadata_cond.clone());
+            return Ok(self.execute_scan_query(&filter, execution_limit, Some(&metadata_cond)));
+        }
         Ok(self.dispatch_metadata_filter(cond, &metadata_cond, execution_limit))
     }
 
@@ -301,8 +332,7 @@ impl Collection {
         // Fast path: use bitmap from secondary indexes (same mechanism as
         // search_with_filter). This handles AND conditions, Eq lookups, and
         // range queries via the bitmap infrastructure.
-        let filter =
-            crate::filter::Filter::new(crate::filter::Condition::from(metadata_cond.clone()));
+        let filter = self.where_filter(metadata_cond.clone());
         if let Some(bitmap_results) =
             self.try_bitmap_prefilter(&filter, metadata_cond, execution_limit)
         {
@@ -330,8 +360,7 @@ impl Collection {
             return like_results;
         }
 
-        let filter =
-            crate::filter::Filter::new(crate::filter::Condition::from(metadata_cond.clone()));
+        let filter = self.where_filter(metadata_cond.clone());
         self.execute_scan_query(&filter, execution_limit, Some(metadata_cond))
     }
 
@@ -444,7 +473,8 @@ impl Collection {
         if bitmap.is_empty() {
             return Some(Vec::new());
         }
-        let candidate_ids: Vec<u64> = bitmap.iter().map(u64::from).collect();
+        let mut candidate_ids = Scratch::<Vec<u64>>::take();
+        candidate_ids.extend(bitmap.iter().map(u64::from));
         if self.prefer_candidate_scan(candidate_ids.len(), execution_limit, Some(cond)) {
             return Some(self.scan_ids_with_filter(&candidate_ids, filter, execution_limit));
         }
@@ -468,7 +498,7 @@ impl Collection {
         execution_limit: usize,
     ) -> Option<Vec<SearchResult>> {
         const HYDRATION_CHUNK: usize = 1024;
-        let candidate_ids = self.mirror_candidate_ids(&filter.condition)?;
+        let candidate_ids = self.mirror_candidate_ids(filter)?;
         let mut results = Vec::new();
         for chunk in candidate_ids.chunks(HYDRATION_CHUNK) {
             let remaining = execution_limit.saturating_sub(results.len());
@@ -503,7 +533,7 @@ impl Collection {
         limit: usize,
     ) -> Option<Vec<SearchResult>> {
         let candidate_ids = self.bm25_candidates_for_like(cond, limit)?;
-        let filter = crate::filter::Filter::new(crate::filter::Condition::from(cond.clone()));
+        let filter = self.where_filter(cond.clone());
         let results = self.collect_matching_points(&candidate_ids, &filter, limit);
 
         // Only return BM25 results when we filled the limit — otherwise the
@@ -628,18 +658,21 @@ impl Collection {
             // Pure NEAR (no filter, no similarity threshold).
             // Strategy IGNORED (deliberate): with no metadata/graph predicate
             // there is no second leg to run first or in parallel — only the
-            // VectorFirst HNSW search exists, so the strategy is moot.
+            // VectorFirst HNSW search exists, so the strategy is moot. A
+            // `force_scan` hint swaps HNSW for an exact scan.
             (Some(vector), None, None) => {
                 self.dispatch_pure_near(vector, execution_limit, search_opts)
             }
             // Metadata-only (no vector query at all).
             // Strategy IGNORED (deliberate): `ExecutionStrategy` orders a vector
             // search relative to a filter; with no vector query there is nothing
-            // to order — the path is a pure index/bitmap/scan resolution.
+            // to order — the path is a pure index/bitmap/scan resolution
+            // (scan only under a `force_scan` hint).
             (None, None, Some(cond)) => self.dispatch_metadata_only(
                 cond,
                 execution_limit,
                 skip_metadata_prefilter_for_graph_or,
+                search_opts.hints,
             ),
             // SELECT * (no WHERE, no vector).
             // Strategy IGNORED (deliberate): no predicate and no vector query —
@@ -666,16 +699,39 @@ impl Collection {
     ) -> Result<Vec<SearchResult>> {
         let k = execution_limit
             .saturating_mul(10 * similarity_conditions.len().max(1))
-            .min(MAX_LIMIT);
-        let search_vec = search_vector.unwrap_or(&sim.1);
-        let candidates = self.search_with_opts(search_vec, k, search_opts)?;
-        let filtered = self.apply_similarity_cascade(
-            candidates,
-            sim,
-            similarity_conditions,
-            execution_limit.saturating_mul(2),
-        );
-        Ok(Self::apply_optional_metadata_filter(
+            .min(self.max_query_limit());
+        // Without a NEAR vector on the main field, the threshold alone defines
+        // the match set: fetch every point past it (range search) instead of
+        // a LIMIT-scaled top-k, and keep them all for the metadata filter.
+        let threshold_only = search_vector.is_none() && sim.0 == "vector";
+        let candidates = if threshold_only {
+            let max_results = if filter_cond.is_none() && similarity_conditions.len() <= 1 {
+                execution_limit
+            } else {
+                self.max_query_limit()
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            // Reason: similarity thresholds are f64 in the AST; f32 is the score type.
+            let threshold = sim.3 as f32;
+            self.search_by_threshold_with(
+                &sim.1,
+                threshold,
+                sim.2,
+                max_results,
+                k,
+                search_opts.ef_search,
+            )?
+        } else {
+            self.search_with_opts(search_vector.unwrap_or(&sim.1), k, search_opts)?
+        };
+        let filter_k = if threshold_only {
+            candidates.len()
+        } else {
+            execution_limit.saturating_mul(2)
+        };
+        let filtered =
+            self.apply_similarity_cascade(candidates, sim, similarity_conditions, filter_k);
+        Ok(self.apply_optional_metadata_filter(
             filtered,
             filter_cond,
             skip_metadata_prefilter_for_graph_or,
@@ -690,6 +746,14 @@ impl Collection {
         execution_limit: usize,
         search_opts: &QuerySearchOptions,
     ) -> Result<Vec<SearchResult>> {
+        if search_opts.hints.force_scan {
+            if let Some(metric) = search_opts.metric {
+                return self.scan_with_metric(vector, execution_limit, metric);
+            }
+            let all =
+                crate::filter::Filter::new(crate::filter::Condition::And { conditions: vec![] });
+            return Ok(self.scan_and_score_by_vector(&all, vector, execution_limit));
+        }
         self.search_with_opts(vector, execution_limit, search_opts)
     }
 }
@@ -58,7 +58,15 @@ impl Collection {
         match condition {
             Condition::VectorSearch(vs) => {
                 let vec = Self::resolve_vector(&vs.vector, params)?;
-                Ok(Some(vec))
+                if vs.avoid.is_empty() {
+                    return Ok(Some(vec));
+                }
+                let negatives = vs
+                    .avoid
+                    .iter()
+                    .map(|v| Self::resolve_vector(v, params))
+                    .collect::<Result<Vec<_>>>()?;
+                self.avoid_adjusted_query(&vec, &negatives).map(Some)
             }
             Condition::And(left, right) => {
                 if let Some(v) = self.extract_vector_search(left, params)? {
@@ -34,6 +34,7 @@ fn make_similarity(field: &str, threshold: f64) -> Condition {
 fn make_vector_search() -> Condition {
     Condition::VectorSearch(VectorSearch {
         vector: VectorExpr::Parameter("v".to_string()),
+        avoid: Vec::new(),
     })
 }
 
@@ -0,0 +1,72 @@
+//! `USING FUSION field_boost(field = 'views', alpha = 0.2)` execution.
+//!
+//! Fuses a numeric payload field (popularity, rating, ...) into the ranking
+//! of a single vector `NEAR`: each hit's similarity is multiplied by the
+//! [`LogFieldBoost`] `1 + alpha * ln(1 + field)`, so a popular point rises
+//! without the raw counter swamping the similarity. Like time decay, the
+//! vector search fetches [`RESCORE_OVERFETCH`](super::RESCORE_OVERFETCH)
+//! times the requested rows so boosted hits from beyond the `LIMIT` can be
+//! promoted.
+
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::point::SearchResult;
+use crate::velesql::FusionStrategyType;
+
+use super::score_fusion::{rescale_scores, BoostFunction, LogFieldBoost};
+use super::ExtractedComponents;
+
+/// Boost strength when the clause omits `alpha`.
+const DEFAULT_ALPHA: f64 = 1.0;
+
+impl Collection {
+    /// Reads the `field_boost` fusion of a SELECT; `None` for any other
+    /// (or no) fusion strategy. Field and alpha are validated upfront (V012).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the `NEAR` is part of an `OR` or
+    /// `NOT similarity()` query, which rank on paths of their own.
+    pub(super) fn field_boost_option(
+        stmt: &crate::velesql::SelectStatement,
+        extracted: &ExtractedComponents,
+    ) -> Result<Option<LogFieldBoost>> {
+        let Some(fc) = stmt
+            .fusion_clause
+            .as_ref()
+            .filter(|fc| fc.strategy == FusionStrategyType::FieldBoost)
+        else {
+            return Ok(None);
+        };
+        if !extracted.is_main_vector_search() {
+            return Err(Error::Query(
+                "USING FUSION field_boost requires a vector NEAR search (not OR or \
+                 NOT similarity())"
+                    .to_string(),
+            ));
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        // Reason: alpha is a user-facing boost weight; f64→f32 precision loss is acceptable.
+        let alpha = fc.boost_alpha.unwrap_or(DEFAULT_ALPHA) as f32;
+        Ok(Some(LogFieldBoost::new(
+            fc.boost_field.clone().unwrap_or_default(),
+            alpha,
+        )))
+    }
+
+    /// Applies `boost` to the vector hits of `stmt` and re-ranks them.
+    pub(super) fn apply_field_boost(
+        &self,
+        boost: &LogFieldBoost,
+        stmt: &crate::velesql::SelectStatement,
+        results: &mut [SearchResult],
+    ) {
+        let higher_is_better = self.ranking_metric(stmt).higher_is_better();
+        rescale_scores(results, higher_is_better, |r| {
+            r.point
+                .payload
+                .as_ref()
+                .map_or(1.0, |p| f64::from(boost.compute(p)))
+        });
+    }
+}
@@ -0,0 +1,104 @@
+//! Tests for `USING FUSION field_boost(...)` payload-weighted scoring.
+#![cfg(all(test, feature = "persistence"))]
+#![allow(clippy::float_cmp, clippy::cast_precision_loss)]
+
+use super::score_fusion::{BoostFunction, LogFieldBoost};
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::{Point, SearchResult};
+use crate::velesql::Parser;
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Ten unviewed points close to the query, then one popular point further
+/// off the query direction.
+fn setup_collection(metric: DistanceMetric) -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 2, metric).expect("create collection");
+    let mut points: Vec<Point> = (0..10u64)
+        .map(|i| {
+            let y = 0.01 * (i + 1) as f32;
+            Point::new(i, vec![1.0, y], Some(json!({ "views": 0 })))
+        })
+        .collect();
+    points.push(Point::new(
+        10,
+        vec![1.0, 0.3],
+        Some(json!({ "views": 10_000 })),
+    ));
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn run(col: &Collection, sql: &str) -> crate::error::Result<Vec<SearchResult>> {
+    let parsed = Parser::parse(sql).expect("parse");
+    let params = HashMap::from([("v".to_string(), json!([1.0, 0.0]))]);
+    col.execute_query(&parsed, &params)
+}
+
+#[test]
+fn test_log_field_boost_factor() {
+    let boost = LogFieldBoost::new("stats.views", 0.5);
+    let e_minus_one = std::f64::consts::E - 1.0;
+    let factor = boost.compute(&json!({ "stats": { "views": e_minus_one } }));
+    assert!((factor - 1.5).abs() < 1e-6);
+    assert_eq!(boost.compute(&json!({ "stats": { "views": 0 } })), 1.0);
+    assert_eq!(boost.compute(&json!({ "stats": { "views": -5 } })), 1.0);
+    assert_eq!(boost.compute(&json!({ "stats": { "views": "many" } })), 1.0);
+    assert_eq!(LogFieldBoost::new("views", -1.0).alpha, 0.0);
+}
+
+#[test]
+fn test_field_boost_promotes_popular_hit_from_beyond_limit() {
+    let (_dir, col) = setup_collection(DistanceMetric::Cosine);
+    let plain = run(&col, "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3").expect("plain");
+    assert!(plain.iter().all(|r| r.point.id != 10));
+
+    let boosted = run(
+        &col,
+        "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3 \
+         USING FUSION field_boost(field = 'views', alpha = 0.2)",
+    )
+    .expect("boosted");
+    assert_eq!(boosted.len(), 3);
+    assert_eq!(boosted[0].point.id, 10);
+    let similarity = 1.0 / 1.09_f32.sqrt();
+    let expected = similarity * (1.0 + 0.2 * 10_001_f32.ln());
+    assert!((boosted[0].score - expected).abs() < 1e-3);
+    // Unviewed points keep their similarity.
+    assert!(boosted[1].score <= 1.0);
+}
+
+#[test]
+fn test_field_boost_on_distance_metric() {
+    let (_dir, col) = setup_collection(DistanceMetric::Euclidean);
+    let boosted = run(
+        &col,
+        "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3 \
+         USING FUSION(strategy = 'field_boost', field = 'views', alpha = 10)",
+    )
+    .expect("boosted");
+    assert_eq!(boosted[0].point.id, 10);
+    assert!(boosted[0].score < boosted[1].score);
+}
+
+#[test]
+fn test_field_boost_rejects_misconfiguration() {
+    let (_dir, col) = setup_collection(DistanceMetric::Cosine);
+    for sql in [
+        // No NEAR to boost.
+        "SELECT * FROM docs WHERE views > 0 LIMIT 3 USING FUSION field_boost(field = 'views')",
+        // Missing field.
+        "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3 USING FUSION field_boost(alpha = 0.2)",
+        // Negative alpha.
+        "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3 \
+         USING FUSION field_boost(field = 'views', alpha = -1)",
+        // OR-combined NEAR ranks on the union path.
+        "SELECT * FROM docs WHERE vector NEAR $v OR views > 5 LIMIT 3 \
+         USING FUSION field_boost(field = 'views')",
+    ] {
+        let err = run(&col, sql).expect_err(sql);
+        assert!(err.to_string().contains("field_boost"), "{sql}: {err}");
+    }
+}
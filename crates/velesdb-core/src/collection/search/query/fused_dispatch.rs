@@ -39,7 +39,7 @@ impl Collection {
             .filter_condition
             .as_ref()
             .and_then(Self::extract_metadata_filter)
-            .map(|c| crate::filter::Filter::new(crate::filter::Condition::from(c)));
+            .map(|c| self.where_filter(c));
         let results = self.multi_query_search(&slices, limit, strategy, filter.as_ref())?;
         self.check_guardrails_and_record(ctx, results.len())?;
         self.finalize_sparse_results(stmt, params, results)
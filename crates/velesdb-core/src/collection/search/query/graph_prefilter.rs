@@ -10,7 +10,7 @@
 //!   graph matches instead of hoping they appear inside a bounded over-fetch
 //!   window.
 //! - **Unranked + MATCH**: the anchor set IS the candidate set — hydrate it
-//!   directly instead of scanning a `MAX_LIMIT` window.
+//!   directly instead of scanning a `max_query_limit` window.
 //! - **Sparse + MATCH**: the anchor set feeds the sparse index's per-id
 //!   filter, so the fetch is exact at `limit` (see `hybrid_sparse.rs`).
 //!
@@ -19,6 +19,7 @@
 //! post-filter always runs afterwards (with the warmed predicate cache, so
 //! anchor sets are never evaluated twice).
 
+use super::scratch::Scratch;
 use super::where_eval::GraphMatchEvalCache;
 use crate::collection::types::Collection;
 use crate::error::Result;
@@ -110,7 +111,7 @@ impl Collection {
         let metric = self.validate_query_and_read_metric(vector)?;
         let metadata_filter = filter_condition
             .and_then(Self::extract_metadata_filter)
-            .map(|c| crate::filter::Filter::new(crate::filter::Condition::from(c)));
+            .map(|c| self.where_filter(c));
 
         if anchor_ids.len() <= ANCHORED_EXACT_SCORE_MAX {
             return Ok(self.score_anchors_exact(
@@ -154,7 +155,7 @@ impl Collection {
             .map_or(limit, |f| {
                 super::super::vector_filter::compute_oversampled_k(limit, f)
             })
-            .min(super::MAX_LIMIT);
+            .min(self.max_query_limit());
         let index_results = self.storage.index.search_with_quality_and_bitmap(
             vector,
             candidates_k,
@@ -180,7 +181,8 @@ impl Collection {
         limit: usize,
         higher_is_better: bool,
     ) -> Vec<SearchResult> {
-        let mut ids: Vec<u64> = anchor_ids.iter().copied().collect();
+        let mut ids = Scratch::<Vec<u64>>::take();
+        ids.extend(anchor_ids.iter().copied());
         ids.sort_unstable();
 
         let mut scored = Vec::new();
@@ -205,7 +207,7 @@ impl Collection {
 
     /// Anchored unranked fetch: hydrates the anchor set in deterministic
     /// (ascending id) order, applies the full WHERE per candidate, and stops
-    /// at `limit` — replacing the `MAX_LIMIT` scan window with an exact,
+    /// at `limit` — replacing the `max_query_limit` scan window with an exact,
     /// exhaustive fetch (the anchors are the only possible matches).
     pub(super) fn fetch_anchor_candidates(
         &self,
@@ -216,7 +218,8 @@ impl Collection {
         cache: &mut GraphMatchEvalCache,
         limit: usize,
     ) -> Result<Vec<SearchResult>> {
-        let mut ids: Vec<u64> = anchor_ids.iter().copied().collect();
+        let mut ids = Scratch::<Vec<u64>>::take();
+        ids.extend(anchor_ids.iter().copied());
         ids.sort_unstable();
 
         let mut results = Vec::new();
@@ -204,7 +204,7 @@ impl Collection {
         // Build payload filter if there are non-vector metadata conditions.
         let metadata_filter = filter_condition
             .and_then(Self::extract_metadata_filter)
-            .map(|cond| crate::filter::Filter::new(crate::filter::Condition::from(cond)));
+            .map(|cond| self.where_filter(cond));
 
         let results = if metadata_filter.is_some() || anchors.is_some() {
             self.sparse_search_with_id_filter(
@@ -329,7 +329,7 @@ impl Collection {
         // Pre-build metadata filter for the sparse branch.
         let metadata_filter = filter_condition
             .and_then(Self::extract_metadata_filter)
-            .map(|cond| crate::filter::Filter::new(crate::filter::Condition::from(cond)));
+            .map(|cond| self.where_filter(cond));
 
         // Execute both branches.
         let (dense_results, sparse_results) = self.execute_both_branches(
@@ -478,6 +478,7 @@ impl Collection {
     ) -> Vec<SearchResult> {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
+        let payloads = self.payload_reader(&*payload_storage);
         let now_secs = now_unix_secs();
 
         let mut out = Vec::with_capacity(capacity_hint.min(limit));
@@ -487,7 +488,7 @@ impl Collection {
                 .ok()
                 .flatten()
                 .unwrap_or_default();
-            let payload = payload_storage.retrieve(id).ok().flatten();
+            let payload = payloads.retrieve(id);
             if is_payload_expired(payload.as_ref(), now_secs) {
                 continue;
             }
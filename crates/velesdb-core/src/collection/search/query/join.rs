@@ -33,7 +33,7 @@ impl JoinedResult {
 }
 
 /// Conservative server-side ceiling on the number of joined rows materialized
-/// when the query carries no explicit LIMIT (mirrors `query::options::MAX_LIMIT`).
+/// when the query carries no explicit LIMIT (mirrors the default `limits.max_query_limit`).
 pub const JOIN_ROW_CEILING: usize = 100_000;
 
 /// Adaptive batch size thresholds.
@@ -0,0 +1,121 @@
+//! Execution tests for `json_extract(payload, '$.path')` in WHERE and SELECT.
+//!
+//! The function lowers to the equivalent dotted path, so these cover the
+//! array-index segments (`items[0]` → `items.0`) that plain dot-paths could
+//! not reach before.
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::{Point, SearchResult};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+fn setup_orders() -> (TempDir, Collection) {
+    let dir = TempDir::new().unwrap();
+    let col = Collection::create(dir.path().join("orders"), 2, DistanceMetric::Cosine)
+        .expect("create collection");
+    let payloads = [
+        serde_json::json!({
+            "customer": {"address": {"city": "Paris"}},
+            "items": [{"sku": "A-1", "qty": 2}, {"sku": "B-7", "qty": 1}],
+        }),
+        serde_json::json!({
+            "customer": {"address": {"city": "Lyon"}},
+            "items": [{"sku": "B-7", "qty": 5}],
+        }),
+        serde_json::json!({"customer": {"name": "no address"}}),
+    ];
+    let points: Vec<Point> = payloads
+        .into_iter()
+        .zip(1u64..)
+        .map(|(payload, id)| Point {
+            id,
+            vector: vec![1.0, 0.0],
+            payload: Some(payload),
+            sparse_vectors: None,
+        })
+        .collect();
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn run(col: &Collection, sql: &str) -> Vec<SearchResult> {
+    col.execute_query_str(sql, &HashMap::new())
+        .unwrap_or_else(|e| panic!("{sql}: {e}"))
+}
+
+fn ids(results: &[SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+#[test]
+fn test_where_json_extract_nested_object() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE json_extract(payload, '$.customer.address.city') = 'Lyon'",
+    );
+    assert_eq!(ids(&results), vec![2]);
+}
+
+#[test]
+fn test_where_json_extract_array_index() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE json_extract(payload, '$.items[0].sku') = 'B-7'",
+    );
+    assert_eq!(ids(&results), vec![2]);
+
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE json_extract(payload, '$.items[1].qty') >= 1 \
+         OR json_extract(payload, '$.items[0].qty') > 4 ORDER BY id",
+    );
+    assert_eq!(ids(&results), vec![1, 2]);
+}
+
+#[test]
+fn test_where_json_extract_is_null_and_in() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE json_extract(payload, '$.customer.address') IS NULL",
+    );
+    assert_eq!(ids(&results), vec![3]);
+
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE json_extract(payload, '$.customer.address.city') \
+         IN ('Paris', 'Nice')",
+    );
+    assert_eq!(ids(&results), vec![1]);
+}
+
+#[test]
+fn test_select_json_extract_projects_nested_values() {
+    let (_dir, col) = setup_orders();
+    let sql = "SELECT id, json_extract(payload, '$.items[0].sku') AS first_sku, \
+               json_extract(payload, '$.customer.address.city') FROM orders ORDER BY id";
+    let query = crate::velesql::Parser::parse(sql).expect("parse");
+    let results = run(&col, sql);
+    let rows = super::projection::project_results(&results, &query.select.columns);
+    assert_eq!(
+        rows,
+        vec![
+            serde_json::json!({"id": 1, "first_sku": "A-1", "customer.address.city": "Paris"}),
+            serde_json::json!({"id": 2, "first_sku": "B-7", "customer.address.city": "Lyon"}),
+            serde_json::json!({"id": 3, "first_sku": null, "customer.address.city": null}),
+        ]
+    );
+}
+
+#[test]
+fn test_json_extract_in_scalar_expression() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE json_extract(payload, '$.items[0].qty') * 10 = 50",
+    );
+    assert_eq!(ids(&results), vec![2]);
+}
@@ -0,0 +1,144 @@
+//! Tests for batched WHERE MATCH anchor evaluation (`match_exec::anchor_batch`).
+//!
+//! The batched backward sweep must return exactly the anchors the per-anchor
+//! walker binds, and must decline patterns where walks and trails differ.
+
+use crate::collection::graph::GraphEdge;
+use crate::collection::types::Collection;
+use crate::point::Point;
+use crate::test_fixtures::fixtures::setup_collection;
+use crate::velesql::{GraphPattern, MatchClause, Parser};
+use std::collections::{HashMap, HashSet};
+use tempfile::TempDir;
+
+const NODES: u64 = 40;
+
+/// Docs (even ids) and Topics (multiples of 5) wired with `CITES` and
+/// `ABOUT` edges, including 2-cycles, a self-loop and a longer chain.
+fn setup_graph() -> (TempDir, Collection) {
+    let (dir, col) = setup_collection(4);
+    let points: Vec<Point> = (0..NODES)
+        .map(|id| {
+            let mut labels = Vec::new();
+            if id % 2 == 0 {
+                labels.push("Doc");
+            }
+            if id % 5 == 0 {
+                labels.push("Topic");
+            }
+            Point::new(
+                id,
+                vec![1.0, 0.0, 0.0, 0.0],
+                Some(serde_json::json!({"_labels": labels, "bucket": id % 3})),
+            )
+        })
+        .collect();
+    col.upsert(points).expect("test: upsert");
+
+    let mut edge_id = 1000;
+    let mut add = |source: u64, target: u64, label: &str| {
+        edge_id += 1;
+        col.add_edge(GraphEdge::new(edge_id, source, target, label).expect("test: edge"))
+            .expect("test: add edge");
+    };
+    for id in 0..NODES {
+        add(id, (id * 7 + 3) % NODES, "CITES");
+        if id % 3 == 0 {
+            add(id, (id + 11) % NODES, "ABOUT");
+        }
+    }
+    add(1, 2, "CITES");
+    add(2, 1, "CITES");
+    add(4, 4, "CITES");
+    (dir, col)
+}
+
+fn match_clause(sql: &str) -> MatchClause {
+    Parser::parse(sql)
+        .expect("test: parse")
+        .match_clause
+        .expect("test: MATCH clause")
+}
+
+fn pattern(sql: &str) -> GraphPattern {
+    match_clause(sql).patterns.remove(0)
+}
+
+/// Reference: anchors bound by the per-anchor pattern walker.
+fn walker_anchor_ids(col: &Collection, sql: &str) -> HashSet<u64> {
+    col.execute_match(&match_clause(sql), &HashMap::new())
+        .expect("test: execute MATCH")
+        .into_iter()
+        .filter_map(|m| m.bindings.get("a").copied())
+        .collect()
+}
+
+fn batched(col: &Collection, sql: &str) -> Option<HashSet<u64>> {
+    col.batched_anchor_ids(&pattern(sql), &HashMap::new())
+        .expect("test: batched anchors")
+}
+
+#[test]
+fn test_batched_anchors_match_walker() {
+    let (_dir, col) = setup_graph();
+
+    for sql in [
+        "MATCH (a)-[:CITES]->(b) RETURN a",
+        "MATCH (a:Doc)-[:CITES]->(b:Topic) RETURN a",
+        "MATCH (a:Doc)<-[:CITES]-(b) RETURN a",
+        "MATCH (a)-[:CITES]-(b:Topic) RETURN a",
+        "MATCH (a:Doc)-[:CITES*1..3]->(b:Topic) RETURN a",
+        "MATCH (a)-[:CITES*0..2]->(b {bucket: 2}) RETURN a",
+        "MATCH (a:Doc)-[*..4]->(b:Topic) RETURN a",
+        "MATCH (a:Topic)-[:CITES*..6]->(a2:Topic) RETURN a",
+        "MATCH (a)-[:ABOUT]->(m:Doc)-[:CITES*1..2]->(b:Topic) RETURN a",
+        "MATCH (a:Doc)-[:CITES]->(m {bucket: 0})-[:ABOUT]->(b) RETURN a",
+        "MATCH (a)-[:CITES]->(m:Topic)-[:ABOUT]->(b) RETURN a",
+    ] {
+        let expected = walker_anchor_ids(&col, sql);
+        let ids = batched(&col, sql).unwrap_or_else(|| panic!("batched: {sql}"));
+        assert_eq!(ids, expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_batched_anchors_cycle_back_to_anchor() {
+    let (_dir, col) = setup_graph();
+
+    // Node 4 only reaches itself through its self-loop; 1 and 2 through
+    // their 2-cycle.
+    let sql = "MATCH (a)-[:CITES*1..2]->(b {bucket: 1}) RETURN a";
+    assert_eq!(batched(&col, sql), Some(walker_anchor_ids(&col, sql)));
+}
+
+#[test]
+fn test_batched_anchors_declines_walk_only_patterns() {
+    let (_dir, col) = setup_graph();
+
+    for sql in [
+        // Undirected variable length: `a-b-a` is a walk, not a trail.
+        "MATCH (a)-[:CITES*1..2]-(b) RETURN a",
+        // Minimum length above one.
+        "MATCH (a)-[:CITES*2..3]->(b) RETURN a",
+        // Shared relationship type across relationships.
+        "MATCH (a)-[:CITES]->(m)-[:CITES]->(b) RETURN a",
+        // Untyped relationship in a chain.
+        "MATCH (a)-[:CITES]->(m)-[]->(b) RETURN a",
+        // Repeated node alias.
+        "MATCH (a)-[:CITES]->(m)-[:ABOUT]->(a) RETURN a",
+        // Cost budget.
+        "MATCH (a)-[:CITES*1..3 {max_cost: 2.0}]->(b) RETURN a",
+        // Unconstrained middle node before an unconstrained tail.
+        "MATCH (a)-[:ABOUT]->(m)-[:CITES]->(b) RETURN a",
+    ] {
+        assert_eq!(batched(&col, sql), None, "{sql}");
+    }
+}
+
+#[test]
+fn test_batched_anchors_empty_when_no_anchor_matches() {
+    let (_dir, col) = setup_graph();
+
+    let ids = batched(&col, "MATCH (a:Missing)-[:CITES]->(b) RETURN a");
+    assert_eq!(ids, Some(HashSet::new()));
+}
@@ -10,8 +10,6 @@ use crate::error::Result;
 use crate::point::SearchResult;
 use crate::velesql::{CompareOp, Condition};
 
-use super::MAX_LIMIT;
-
 /// Global MATCH query metrics collector (EPIC-050).
 ///
 /// Uses `LazyLock` for thread-safe one-time initialisation.
@@ -286,6 +284,8 @@ impl Collection {
         let mut sorted = match_results;
         self.apply_match_order_by(&mut sorted, match_clause, params)
             .inspect_err(|_| self.runtime.guard_rails.circuit_breaker.record_failure())?;
+        self.apply_match_limit_per(&mut sorted, match_clause)
+            .inspect_err(|_| self.runtime.guard_rails.circuit_breaker.record_failure())?;
 
         let mut results = self
             .match_results_to_search_results(sorted)
@@ -294,7 +294,7 @@ impl Collection {
         ctx.check_cardinality(results.len())
             .map_err(crate::error::Error::from)
             .inspect_err(|_| self.runtime.guard_rails.circuit_breaker.record_failure())?;
-        if let Some(limit) = match_return_limit(match_clause) {
+        if let Some(limit) = self.match_return_limit(match_clause) {
             results.truncate(limit);
         }
         // Reason: u128->u64 cast; query durations < u64::MAX µs (~585 millennia)
@@ -313,7 +313,7 @@ impl Collection {
     /// WITHOUT converting to `SearchResult`.
     ///
     /// Shares the exact ordering ([`apply_match_order_by`](Self::apply_match_order_by))
-    /// and LIMIT ([`match_return_limit`]) logic with the SQL `SearchResult`
+    /// and LIMIT ([`match_return_limit`](Self::match_return_limit)) logic with the SQL `SearchResult`
     /// finalize path, so the ordered `MatchResult` surface ranks identically.
     fn finalize_match_ordering(
         &self,
@@ -329,6 +329,8 @@ impl Collection {
         let mut sorted = match_results;
         self.apply_match_order_by(&mut sorted, match_clause, params)
             .inspect_err(|_| self.runtime.guard_rails.circuit_breaker.record_failure())?;
+        self.apply_match_limit_per(&mut sorted, match_clause)
+            .inspect_err(|_| self.runtime.guard_rails.circuit_breaker.record_failure())?;
 
         // Final cardinality check for MATCH path (EPIC-048 US-003), matching
         // `finalize_match_results` so the ordered surface rejects oversized
@@ -336,13 +338,32 @@ impl Collection {
         ctx.check_cardinality(sorted.len())
             .map_err(crate::error::Error::from)
             .inspect_err(|_| self.runtime.guard_rails.circuit_breaker.record_failure())?;
-        if let Some(limit) = match_return_limit(match_clause) {
+        if let Some(limit) = self.match_return_limit(match_clause) {
             sorted.truncate(limit);
         }
         self.runtime.guard_rails.circuit_breaker.record_success();
         Ok(sorted)
     }
 
+    /// Computes the effective RETURN `LIMIT` for a MATCH query, clamped to
+    /// [`max_query_limit`](Self::max_query_limit). `None` means no LIMIT was
+    /// specified, so the caller leaves the result set unbounded (subject only
+    /// to that ceiling upstream). `LIMIT n PER alias` also yields `None`: it
+    /// caps each group (`apply_match_limit_per`), not the result.
+    pub(in crate::collection::search::query) fn match_return_limit(
+        &self,
+        match_clause: &crate::velesql::MatchClause,
+    ) -> Option<usize> {
+        if match_clause.return_clause.limit_per.is_some() {
+            return None;
+        }
+        let max = self.max_query_limit();
+        match_clause
+            .return_clause
+            .limit
+            .map(|l| usize::try_from(l).unwrap_or(max).min(max))
+    }
+
     /// Applies RETURN `ORDER BY` (with the deterministic `(node_id, depth, path)`
     /// tie-break baseline) to raw MATCH results in place. Sorts only when an
     /// ORDER BY is present, so traversal-order output is otherwise preserved.
@@ -366,18 +387,6 @@ impl Collection {
     }
 }
 
-/// Computes the effective RETURN `LIMIT` for a MATCH query, clamped to the
-/// server-wide `MAX_LIMIT` ceiling. `None` means no LIMIT was specified, so the
-/// caller leaves the result set unbounded (subject only to `MAX_LIMIT` upstream).
-pub(in crate::collection::search::query) fn match_return_limit(
-    match_clause: &crate::velesql::MatchClause,
-) -> Option<usize> {
-    match_clause
-        .return_clause
-        .limit
-        .map(|l| usize::try_from(l).unwrap_or(MAX_LIMIT).min(MAX_LIMIT))
-}
-
 /// Deterministic ORDER BY tie-break baseline keyed by `(node_id, depth, path)` —
 /// a total order over connected matches: a single-node match has a unique
 /// `node_id` (empty path); a multi-node match is fixed by its edge-id `path`
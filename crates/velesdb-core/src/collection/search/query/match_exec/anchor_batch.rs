@@ -0,0 +1,185 @@
+//! Batched anchor evaluation for `SELECT ... WHERE MATCH (...)` predicates.
+//!
+//! A WHERE MATCH predicate only asks which first-node ids have at least one
+//! match. Walking the pattern once per anchor re-expands shared
+//! neighbourhoods for every anchor and enumerates every path where one would
+//! do. Instead, match sets are propagated backwards from the last node
+//! pattern: one multi-source reverse BFS per relationship, whose frontier and
+//! visited set are shared by all anchors.
+//!
+//! The sweep follows walks while MATCH follows trails (an edge at most once
+//! per path). The two agree when every walk implies a trail of an accepted
+//! length, which holds when:
+//! - every relationship has a minimum length of 0 or 1, so the shortest walk
+//!   into the target set is a simple path or cycle;
+//! - undirected relationships are single-hop, since `a-b-a` is a closed walk
+//!   but not a trail;
+//! - a multi-relationship pattern names pairwise disjoint relationship types,
+//!   so no edge can serve two relationships.
+//!
+//! Other patterns, and patterns reusing a node alias, keep the per-anchor
+//! walker.
+
+use std::collections::{HashMap, HashSet};
+
+use super::expand::reversed;
+use crate::collection::graph::ConcurrentEdgeStore;
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::velesql::{Direction, GraphPattern, NodePattern, RelationshipPattern};
+
+impl Collection {
+    /// Returns the first-node ids of `pattern` with at least one match, or
+    /// `None` when the pattern needs the per-anchor walker.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Query` if a relationship parameter is missing or
+    /// invalid.
+    pub(in crate::collection::search::query) fn batched_anchor_ids(
+        &self,
+        pattern: &GraphPattern,
+        params: &HashMap<String, serde_json::Value>,
+    ) -> Result<Option<HashSet<u64>>> {
+        let pattern = Self::resolve_relationship_params(pattern, params)?;
+        if !supports_batched_anchors(&pattern) {
+            return Ok(None);
+        }
+        let anchors: HashSet<u64> = self
+            .find_start_nodes(&pattern)?
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        if anchors.is_empty() {
+            return Ok(Some(anchors));
+        }
+        let payload_guard = self.storage.payload_storage.read();
+        let edge_store = &self.graph.edge_store;
+
+        // Nodes that can complete the pattern from the current position;
+        // `None` stands for every node (an unconstrained tail).
+        let last = pattern.relationships.len();
+        let mut matched = if is_constrained(&pattern.nodes[last]) {
+            Some(self.node_candidates(&pattern.nodes[last])?)
+        } else {
+            None
+        };
+        for idx in (0..last).rev() {
+            let rel = &pattern.relationships[idx];
+            let node = &pattern.nodes[idx];
+            matched = match matched {
+                Some(targets) => {
+                    let mut sources = Self::reverse_reach(edge_store, &targets, rel);
+                    if idx > 0 {
+                        sources.retain(|&id| {
+                            Self::node_matches_bound_pattern(id, node, &payload_guard)
+                        });
+                    }
+                    Some(sources)
+                }
+                None if rel.range.is_some_and(|(min_hops, _)| min_hops == 0) => None,
+                None => {
+                    // Any matching edge reaches the unconstrained tail, but
+                    // only a finite candidate set can be checked for one.
+                    let mut candidates = if idx == 0 {
+                        anchors.clone()
+                    } else if is_constrained(node) {
+                        self.node_candidates(node)?
+                    } else {
+                        return Ok(None);
+                    };
+                    candidates.retain(|&id| !Self::pattern_edges(edge_store, id, rel).is_empty());
+                    Some(candidates)
+                }
+            };
+        }
+        Ok(Some(match matched {
+            Some(matched) => anchors.intersection(&matched).copied().collect(),
+            None => anchors,
+        }))
+    }
+
+    /// Ids of the nodes matching a single node pattern.
+    fn node_candidates(&self, node: &NodePattern) -> Result<HashSet<u64>> {
+        let node_only = GraphPattern {
+            name: None,
+            shortest_path: false,
+            nodes: vec![node.clone()],
+            relationships: Vec::new(),
+        };
+        Ok(self
+            .find_start_nodes(&node_only)?
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect())
+    }
+
+    /// Nodes with a walk matching `rel` into `targets`: a multi-source BFS
+    /// over the reversed relationship, up to its maximum length.
+    fn reverse_reach(
+        edge_store: &ConcurrentEdgeStore,
+        targets: &HashSet<u64>,
+        rel: &RelationshipPattern,
+    ) -> HashSet<u64> {
+        let (min_hops, max_hops) = rel.range.unwrap_or((1, 1));
+        let reverse = reversed(rel);
+        let mut reached = if min_hops == 0 {
+            targets.clone()
+        } else {
+            HashSet::new()
+        };
+        let mut visited = targets.clone();
+        let mut frontier: Vec<u64> = targets.iter().copied().collect();
+        let mut hops = 0;
+        while hops < max_hops && !frontier.is_empty() {
+            hops += 1;
+            let mut next = Vec::new();
+            for node in frontier {
+                for edge in Self::pattern_edges(edge_store, node, &reverse) {
+                    let previous = Self::edge_next_node(&edge, node);
+                    // A target reached again still gains a non-empty walk.
+                    reached.insert(previous);
+                    if visited.insert(previous) {
+                        next.push(previous);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        reached
+    }
+}
+
+/// Whether a node pattern filters nodes during traversal.
+fn is_constrained(node: &NodePattern) -> bool {
+    node.collection.is_none() && !(node.labels.is_empty() && node.properties.is_empty())
+}
+
+/// Whether walk reachability decides `pattern` exactly (see module docs).
+fn supports_batched_anchors(pattern: &GraphPattern) -> bool {
+    let rels = &pattern.relationships;
+    if rels.is_empty() || pattern.nodes.len() != rels.len() + 1 {
+        return false;
+    }
+    let mut aliases = HashSet::new();
+    if !pattern
+        .nodes
+        .iter()
+        .filter_map(|node| node.alias.as_deref())
+        .all(|alias| aliases.insert(alias))
+    {
+        return false;
+    }
+    let walks_are_trails = rels.iter().all(|rel| {
+        let (min_hops, max_hops) = rel.range.unwrap_or((1, 1));
+        !rel.properties.contains_key("max_cost")
+            && min_hops <= 1
+            && (rel.direction != Direction::Both || max_hops <= 1)
+    });
+    let mut types = HashSet::new();
+    walks_are_trails
+        && (rels.len() == 1
+            || rels.iter().all(|rel| {
+                !rel.types.is_empty() && rel.types.iter().all(|t| types.insert(t.as_str()))
+            }))
+}
@@ -5,27 +5,36 @@
 //! direction, multi-type, multi-hop / variable-length, and binding acceptance.
 
 use super::{AliasBinding, MatchResult, TraversalCtx};
-use crate::collection::graph::GraphEdge;
+use crate::collection::graph::{GraphEdge, TRAVERSAL_PROPERTIES};
 use crate::collection::types::Collection;
 use crate::error::{Error, Result};
-use crate::velesql::{Direction, GraphPattern, RelationshipPattern};
+use crate::velesql::{Direction, GraphPattern, RelationshipPattern, Value};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
+/// Relationship-pattern property selecting edges valid at a Unix timestamp
+/// (`-[:REL {at: $t}]->`) instead of comparing an edge property.
+const AT_PROPERTY: &str = "at";
+
+/// Relationship-pattern property bounding the summed edge weight of the
+/// relationship's hops (`-[:ROAD*1..10 {max_cost: 5.0}]->`).
+const MAX_COST_PROPERTY: &str = "max_cost";
+
 /// Ambient state threaded through a single pattern walk.
 ///
 /// Bundling the invariant pattern/edge-store references with the mutable
 /// traversal context, bindings, and path keeps the recursive walker functions
 /// well under the argument-count limit (they previously took 12 parameters).
-struct Walk<'a, 't> {
-    pattern: &'a GraphPattern,
-    edge_store: &'a crate::collection::graph::ConcurrentEdgeStore,
-    ctx: &'a mut TraversalCtx<'t>,
-    bindings: &'a mut HashMap<String, u64>,
+pub(super) struct Walk<'a, 't> {
+    pub(super) pattern: &'a GraphPattern,
+    pub(super) edge_store: &'a crate::collection::graph::ConcurrentEdgeStore,
+    pub(super) ctx: &'a mut TraversalCtx<'t>,
+    pub(super) bindings: &'a mut HashMap<String, u64>,
     /// Bound relationship aliases (alias -> traversed edge id).
-    edge_bindings: &'a mut HashMap<String, u64>,
+    pub(super) edge_bindings: &'a mut HashMap<String, u64>,
     /// Variable-length relationship aliases (alias -> ordered edge-id list).
-    edge_paths: &'a mut HashMap<String, Vec<u64>>,
-    path: &'a mut Vec<u64>,
+    pub(super) edge_paths: &'a mut HashMap<String, Vec<u64>>,
+    pub(super) path: &'a mut Vec<u64>,
 }
 
 /// Backtracking record for an edge-alias binding made by `bind_edge_alias`.
@@ -40,12 +49,29 @@ enum EdgeAliasSave {
 
 impl Collection {
     /// Traverses a single graph pattern via BFS for each start node.
+    ///
+    /// Large start-node sets with an aliased first node are walked in
+    /// parallel (see `parallel_walk`).
     pub(super) fn traverse_pattern(
         &self,
         pattern: &GraphPattern,
         start_nodes: &[(u64, HashMap<String, u64>)],
         edge_store: &crate::collection::graph::ConcurrentEdgeStore,
         ctx: &mut TraversalCtx<'_>,
+    ) -> Result<()> {
+        if let Some(config) = Self::parallel_walk_config(pattern, start_nodes.len()) {
+            return self.traverse_pattern_parallel(pattern, start_nodes, edge_store, ctx, &config);
+        }
+        self.walk_start_nodes(pattern, start_nodes, edge_store, ctx)
+    }
+
+    /// Walks the pattern from each start node in order.
+    pub(super) fn walk_start_nodes(
+        &self,
+        pattern: &GraphPattern,
+        start_nodes: &[(u64, HashMap<String, u64>)],
+        edge_store: &crate::collection::graph::ConcurrentEdgeStore,
+        ctx: &mut TraversalCtx<'_>,
     ) -> Result<()> {
         for (start_id, start_bindings) in start_nodes {
             if ctx.all_results.len() >= ctx.limit {
@@ -87,7 +113,7 @@ impl Collection {
         if rel_idx >= walk.pattern.relationships.len() {
             return self.accept_pattern_match(walk, current_id);
         }
-        self.expand_relationship(walk, current_id, rel_idx, 0)
+        self.expand_relationship(walk, current_id, rel_idx, 0, 0.0)
     }
 
     fn expand_relationship(
@@ -96,6 +122,7 @@ impl Collection {
         current_id: u64,
         rel_idx: usize,
         hops: u32,
+        cost: f64,
     ) -> Result<()> {
         let (min_hops, max_hops) = walk.pattern.relationships[rel_idx].range.unwrap_or((1, 1));
         if hops >= min_hops {
@@ -110,7 +137,7 @@ impl Collection {
             &walk.pattern.relationships[rel_idx],
         );
         for edge in edges {
-            self.follow_edge(walk, current_id, &edge, rel_idx, hops)?;
+            self.follow_edge(walk, current_id, &edge, rel_idx, (hops, cost))?;
         }
         Ok(())
     }
@@ -121,7 +148,7 @@ impl Collection {
         current_id: u64,
         edge: &GraphEdge,
         rel_idx: usize,
-        hops: u32,
+        (hops, cost): (u32, f64),
     ) -> Result<()> {
         // Relationship isomorphism (Cypher semantics): an edge may be
         // traversed at most once per matched path. `walk.path` is exactly
@@ -135,13 +162,18 @@ impl Collection {
         {
             return Ok(());
         }
+        // `max_cost` bounds the summed weight of this relationship's hops.
+        let cost = cost + edge.weight();
+        if max_cost(&walk.pattern.relationships[rel_idx]).is_some_and(|max| cost > max) {
+            return Ok(());
+        }
         let next_id = Self::edge_next_node(edge, current_id);
         walk.path.push(edge.id());
         let saved_alias = Self::bind_edge_alias(walk, rel_idx, edge.id());
         *walk.ctx.iteration_count += 1;
         let depth = walk.path.len() as u32;
         self.check_depth_and_periodic_guardrails(depth, walk.ctx)?;
-        self.expand_relationship(walk, next_id, rel_idx, hops.saturating_add(1))?;
+        self.expand_relationship(walk, next_id, rel_idx, hops.saturating_add(1), cost)?;
         Self::restore_edge_alias(walk, saved_alias);
         walk.path.pop();
         Ok(())
@@ -213,7 +245,7 @@ impl Collection {
         Ok(())
     }
 
-    fn accept_pattern_match(&self, walk: &mut Walk<'_, '_>, node_id: u64) -> Result<()> {
+    pub(super) fn accept_pattern_match(&self, walk: &mut Walk<'_, '_>, node_id: u64) -> Result<()> {
         if let Some(where_clause) = walk.ctx.match_clause.where_clause.as_ref() {
             if !self.evaluate_where_condition(
                 node_id,
@@ -250,7 +282,7 @@ impl Collection {
         Ok(())
     }
 
-    fn check_depth_and_periodic_guardrails(
+    pub(super) fn check_depth_and_periodic_guardrails(
         &self,
         depth: u32,
         ctx: &mut TraversalCtx<'_>,
@@ -268,7 +300,7 @@ impl Collection {
         )
     }
 
-    fn matching_edges(
+    pub(super) fn matching_edges(
         edge_store: &crate::collection::graph::ConcurrentEdgeStore,
         node_id: u64,
         rel: &RelationshipPattern,
@@ -284,42 +316,115 @@ impl Collection {
         }
     }
 
+    /// Edges of `node_id` with one of `rel`'s types. Bound edges are kept
+    /// by id and re-read for WHERE and RETURN, so their on-disk properties
+    /// are only loaded when `rel` filters on one.
     fn typed_edges(
         edge_store: &crate::collection::graph::ConcurrentEdgeStore,
         node_id: u64,
         rel: &RelationshipPattern,
         outgoing: bool,
     ) -> Vec<GraphEdge> {
+        let hydrate = rel.properties.keys().any(|key| {
+            key != AT_PROPERTY
+                && key != MAX_COST_PROPERTY
+                && !TRAVERSAL_PROPERTIES.contains(&key.as_str())
+        });
         if rel.types.is_empty() {
-            return if outgoing {
-                edge_store.get_outgoing(node_id)
-            } else {
-                edge_store.get_incoming(node_id)
+            return match (outgoing, hydrate) {
+                (true, true) => edge_store.get_outgoing(node_id),
+                (true, false) => edge_store.get_outgoing_for_traversal(node_id),
+                (false, true) => edge_store.get_incoming(node_id),
+                (false, false) => edge_store.get_incoming_for_traversal(node_id),
             };
         }
         rel.types
             .iter()
-            .flat_map(|label| {
-                if outgoing {
-                    edge_store.get_outgoing_by_label(node_id, label)
-                } else {
-                    edge_store.get_incoming_by_label(node_id, label)
-                }
+            .flat_map(|label| match (outgoing, hydrate) {
+                (true, true) => edge_store.get_outgoing_by_label(node_id, label),
+                (true, false) => edge_store.get_outgoing_by_label_for_traversal(node_id, label),
+                (false, true) => edge_store.get_incoming_by_label(node_id, label),
+                (false, false) => edge_store.get_incoming_by_label_for_traversal(node_id, label),
             })
             .collect()
     }
 
-    fn edge_matches(edge: &GraphEdge, rel: &RelationshipPattern) -> bool {
+    /// Edges of `node` that a path matching `rel` may leave through.
+    pub(super) fn pattern_edges(
+        edge_store: &crate::collection::graph::ConcurrentEdgeStore,
+        node: u64,
+        rel: &RelationshipPattern,
+    ) -> Vec<GraphEdge> {
+        let mut edges = Self::matching_edges(edge_store, node, rel);
+        edges.retain(|edge| Self::edge_matches(edge, rel));
+        edges
+    }
+
+    pub(super) fn edge_matches(edge: &GraphEdge, rel: &RelationshipPattern) -> bool {
         if !rel.types.is_empty() && !rel.types.iter().any(|t| t == edge.label()) {
             return false;
         }
         rel.properties.iter().all(|(key, expected)| {
+            match key.as_str() {
+                AT_PROPERTY => return at_timestamp(expected).is_some_and(|t| edge.is_valid_at(t)),
+                // Checked on the path cost in `follow_edge`.
+                MAX_COST_PROPERTY => return true,
+                _ => {}
+            }
             edge.property(key)
                 .is_some_and(|v| Self::values_match(expected, v))
         })
     }
 
-    fn edge_next_node(edge: &GraphEdge, current_id: u64) -> u64 {
+    /// Substitutes `$param` values in relationship properties, borrowing the
+    /// pattern when it has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Query` if a parameter is missing, `at` is not an
+    /// integer timestamp or `max_cost` is not a non-negative number.
+    pub(super) fn resolve_relationship_params<'p>(
+        pattern: &'p GraphPattern,
+        params: &HashMap<String, serde_json::Value>,
+    ) -> Result<Cow<'p, GraphPattern>> {
+        let has_params = pattern
+            .relationships
+            .iter()
+            .flat_map(|rel| rel.properties.values())
+            .any(|value| matches!(value, Value::Parameter(_)));
+        let pattern = if has_params {
+            let mut owned = pattern.clone();
+            for value in owned
+                .relationships
+                .iter_mut()
+                .flat_map(|rel| rel.properties.values_mut())
+            {
+                *value = Self::resolve_where_param(value, params)?;
+            }
+            Cow::Owned(owned)
+        } else {
+            Cow::Borrowed(pattern)
+        };
+        for rel in &pattern.relationships {
+            if let Some(at) = rel.properties.get(AT_PROPERTY) {
+                if at_timestamp(at).is_none() {
+                    return Err(Error::Query(format!(
+                        "relationship property `at` must be an integer Unix timestamp, got {at:?}"
+                    )));
+                }
+            }
+            if let Some(value) = rel.properties.get(MAX_COST_PROPERTY) {
+                if max_cost(rel).is_none_or(|max| max.is_nan() || max < 0.0) {
+                    return Err(Error::Query(format!(
+                        "relationship property `max_cost` must be a non-negative number, got {value:?}"
+                    )));
+                }
+            }
+        }
+        Ok(pattern)
+    }
+
+    pub(super) fn edge_next_node(edge: &GraphEdge, current_id: u64) -> u64 {
         if edge.source() == current_id {
             edge.target()
         } else {
@@ -327,7 +432,7 @@ impl Collection {
         }
     }
 
-    fn bind_node_alias(
+    pub(super) fn bind_node_alias(
         bindings: &mut HashMap<String, u64>,
         node: &crate::velesql::NodePattern,
         node_id: u64,
@@ -376,3 +481,35 @@ impl Collection {
         signature
     }
 }
+
+/// Reads a resolved `at` value as a Unix timestamp.
+fn at_timestamp(value: &Value) -> Option<i64> {
+    match value {
+        Value::Integer(t) => Some(*t),
+        Value::UnsignedInteger(t) => i64::try_from(*t).ok(),
+        _ => None,
+    }
+}
+
+/// Reads a relationship's resolved `max_cost` budget.
+#[allow(clippy::cast_precision_loss)] // Reason: cost budgets are far below 2^52
+fn max_cost(rel: &RelationshipPattern) -> Option<f64> {
+    match rel.properties.get(MAX_COST_PROPERTY)? {
+        Value::Float(max) => Some(*max),
+        Value::Integer(max) => Some(*max as f64),
+        Value::UnsignedInteger(max) => Some(*max as f64),
+        _ => None,
+    }
+}
+
+/// The same relationship walked from its end node back to its start node.
+pub(super) fn reversed(rel: &RelationshipPattern) -> RelationshipPattern {
+    RelationshipPattern {
+        direction: match rel.direction {
+            Direction::Outgoing => Direction::Incoming,
+            Direction::Incoming => Direction::Outgoing,
+            Direction::Both => Direction::Both,
+        },
+        ..rel.clone()
+    }
+}
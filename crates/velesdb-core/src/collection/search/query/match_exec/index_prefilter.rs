@@ -399,6 +399,7 @@ mod tests {
     fn test_build_alias_label_map() {
         let pattern = GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 NodePattern::new().with_alias("n").with_label("Person"),
                 NodePattern::new().with_alias("m").with_label("Company"),
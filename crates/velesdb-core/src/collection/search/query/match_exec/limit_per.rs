@@ -0,0 +1,75 @@
+//! `LIMIT n PER alias` for MATCH results: top rows per binding.
+//!
+//! `MATCH (d:Doc)-[:CITES]->(c) RETURN d, c LIMIT 3 PER d` keeps at most
+//! three rows for each `d`. Traversal collects every row (the LIMIT no longer
+//! bounds the result), RETURN `ORDER BY` ranks them, then one pass with a
+//! counter per binding keeps the first `n` rows of each group.
+
+use super::MatchResult;
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::velesql::MatchClause;
+use std::collections::HashMap;
+
+impl Collection {
+    /// Applies the `PER <alias>` modifier of a RETURN LIMIT: keeps, in
+    /// result order, the first `n` rows of each distinct binding of `alias`.
+    /// A no-op without `PER`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when `alias` names neither a node nor a
+    /// single-hop relationship of the MATCH patterns.
+    pub(in crate::collection::search::query) fn apply_match_limit_per(
+        &self,
+        results: &mut Vec<MatchResult>,
+        match_clause: &MatchClause,
+    ) -> Result<()> {
+        let Some(alias) = match_clause.return_clause.limit_per.as_deref() else {
+            return Ok(());
+        };
+        check_limit_per_alias(match_clause, alias)?;
+
+        let max = self.max_query_limit();
+        let per = match_clause
+            .return_clause
+            .limit
+            .map_or(max, |l| usize::try_from(l).unwrap_or(max).min(max));
+        let mut counts: HashMap<u64, usize> = HashMap::new();
+        results.retain(|row| {
+            let Some(&key) = row
+                .bindings
+                .get(alias)
+                .or_else(|| row.edge_bindings.get(alias))
+            else {
+                return true;
+            };
+            let count = counts.entry(key).or_insert(0);
+            *count += 1;
+            *count <= per
+        });
+        Ok(())
+    }
+}
+
+/// Checks that `alias` is bound to one node or one edge in every row.
+///
+/// Variable-length relationship aliases bind a list of edges, which has no
+/// single identity to group by.
+fn check_limit_per_alias(match_clause: &MatchClause, alias: &str) -> Result<()> {
+    let patterns = &match_clause.patterns;
+    let is_node = patterns
+        .iter()
+        .flat_map(|p| &p.nodes)
+        .any(|n| n.alias.as_deref() == Some(alias));
+    let is_edge = patterns
+        .iter()
+        .flat_map(|p| &p.relationships)
+        .any(|r| r.alias.as_deref() == Some(alias) && r.range.is_none());
+    if is_node || is_edge {
+        return Ok(());
+    }
+    Err(Error::Query(format!(
+        "LIMIT ... PER '{alias}': expected a node or single-hop relationship alias of the MATCH pattern"
+    )))
+}
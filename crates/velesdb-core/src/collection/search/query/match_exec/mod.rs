@@ -11,9 +11,13 @@
 #![allow(clippy::cast_precision_loss)]
 #![allow(clippy::cast_possible_truncation)]
 
+mod anchor_batch;
 mod expand;
 mod index_prefilter;
+mod limit_per;
 mod order_by;
+mod parallel_walk;
+mod shortest_path;
 mod similarity;
 mod start_nodes;
 mod vector_first;
@@ -224,9 +228,13 @@ impl Collection {
         match_clause: &MatchClause,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<Vec<MatchResult>> {
+        if let Some(limit) = match_clause.return_clause.limit {
+            self.check_query_limit(limit)?;
+        }
         let mut results = self.execute_match_with_context(match_clause, params, None)?;
         self.apply_match_order_by(&mut results, match_clause, params)?;
-        if let Some(limit) = super::match_dispatch::match_return_limit(match_clause) {
+        self.apply_match_limit_per(&mut results, match_clause)?;
+        if let Some(limit) = self.match_return_limit(match_clause) {
             results.truncate(limit);
         }
         Ok(results)
@@ -259,8 +267,8 @@ impl Collection {
 
         // Documented contract (VELESQL_SPEC "Default LIMIT"): MATCH ... RETURN
         // has no implicit LIMIT 10 — results are bounded only by the
-        // server-wide MAX_LIMIT ceiling shared with compound queries.
-        let limit = traversal_limit(match_clause);
+        // collection's max_query_limit ceiling shared with compound queries.
+        let limit = traversal_limit(match_clause, self.max_query_limit());
         let mut all_results: Vec<MatchResult> = Vec::new();
         let mut iteration_count: u32 = 0;
         let mut reported_cardinality: usize = 0;
@@ -272,8 +280,9 @@ impl Collection {
             if all_results.len() >= limit {
                 break;
             }
+            let pattern = Self::resolve_relationship_params(pattern, params)?;
             self.execute_single_pattern(
-                pattern,
+                &pattern,
                 match_clause,
                 params,
                 ctx,
@@ -302,7 +311,7 @@ impl Collection {
     }
 
     /// Executes a single graph pattern: finds start nodes, then dispatches to
-    /// single-node collection or BFS traversal.
+    /// single-node collection, shortest-path search or BFS traversal.
     #[allow(clippy::too_many_arguments)]
     fn execute_single_pattern(
         &self,
@@ -357,6 +366,9 @@ impl Collection {
             reported_cardinality,
             seen_bindings: &mut HashSet::new(),
         };
+        if pattern.shortest_path {
+            return self.traverse_shortest_paths(pattern, &start_nodes, edge_store, &mut trav_ctx);
+        }
         self.traverse_pattern(pattern, &start_nodes, edge_store, &mut trav_ctx)
     }
 
@@ -439,17 +451,20 @@ impl Collection {
 /// early-break at `return_clause.limit` is correct and stops traversal as soon
 /// as enough rows are collected. WITH an `ORDER BY`, the post-sort LIMIT must
 /// select the GLOBAL top-K, so traversal must visit the full candidate set
-/// (bounded only by the shared `MAX_LIMIT` ceiling and the guard-rails) before
+/// (bounded only by the shared `max` ceiling and the guard-rails) before
 /// the sort — otherwise the LIMIT would be applied to the first-K-traversed
-/// rows instead of the globally ordered set (backlog #1b).
-fn traversal_limit(match_clause: &MatchClause) -> usize {
-    if match_clause.return_clause.order_by.is_some() {
-        return super::MAX_LIMIT;
+/// rows instead of the globally ordered set (backlog #1b). `LIMIT n PER alias`
+/// bounds each group, not the result, so it also needs every row.
+fn traversal_limit(match_clause: &MatchClause, max: usize) -> usize {
+    if match_clause.return_clause.order_by.is_some()
+        || match_clause.return_clause.limit_per.is_some()
+    {
+        return max;
     }
     match_clause
         .return_clause
         .limit
-        .map_or(super::MAX_LIMIT, |l| l as usize)
+        .map_or(max, |l| usize::try_from(l).unwrap_or(max).min(max))
 }
 
 // Tests moved to match_exec_tests.rs per project rules
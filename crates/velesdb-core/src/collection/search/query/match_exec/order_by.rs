@@ -16,9 +16,10 @@ impl Collection {
     /// Applies a structured ORDER BY expression to MATCH results.
     ///
     /// Supported: `similarity()` (search score), `depth`, a valid
-    /// `alias.property` path, explicit `similarity(field, $v)`, and arithmetic
-    /// over a property (e.g. `year - 2000`). Aggregates (no GROUP BY) and bare
-    /// aliases are rejected.
+    /// `alias.property` path, explicit `similarity(field, $v)`, arithmetic
+    /// over a property (e.g. `year - 2000`), and `random()` (shuffle; the
+    /// direction is irrelevant). Aggregates (no GROUP BY) and bare aliases are
+    /// rejected.
     ///
     /// # Errors
     ///
@@ -49,6 +50,10 @@ impl Collection {
                 self.sort_match_by_arithmetic(results, arith, descending);
                 Ok(())
             }
+            OrderByExpr::Random => {
+                rand::seq::SliceRandom::shuffle(results, &mut rand::rng());
+                Ok(())
+            }
             OrderByExpr::Aggregate(_) => Err(Error::GraphNotSupported(
                 "MATCH ORDER BY aggregate expression is not supported (use \
                  similarity(), depth, alias.property, or arithmetic over properties)"
@@ -0,0 +1,101 @@
+//! Parallel MATCH pattern walks across start nodes (EPIC-051).
+//!
+//! Each start node's walk is independent, so large start-node sets are split
+//! into contiguous chunks walked on the rayon pool, each with its own result
+//! buffer, de-duplication set and limit. Buffers are appended in start-node
+//! order. The first pattern node must be aliased: matches from different
+//! start nodes then never share a binding signature, so the merged rows are
+//! exactly the sequential walk's, LIMIT included.
+
+use std::collections::{HashMap, HashSet};
+
+use rayon::prelude::*;
+
+use super::TraversalCtx;
+use crate::collection::graph::ConcurrentEdgeStore;
+use crate::collection::search::query::parallel_traversal::ParallelConfig;
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::velesql::GraphPattern;
+
+/// Chunks per worker thread, so a chunk of hub nodes does not leave the
+/// other workers idle.
+const CHUNKS_PER_THREAD: usize = 4;
+
+impl Collection {
+    /// Returns the parallel walk config when `pattern` should be walked in
+    /// parallel from `start_count` start nodes.
+    pub(in crate::collection::search::query) fn parallel_walk_config(
+        pattern: &GraphPattern,
+        start_count: usize,
+    ) -> Option<ParallelConfig> {
+        let config = ParallelConfig::default();
+        let anchored = pattern
+            .nodes
+            .first()
+            .is_some_and(|node| node.alias.is_some());
+        (anchored && config.should_parallelize(start_count)).then_some(config)
+    }
+
+    /// Walks `pattern` from chunks of `start_nodes` in parallel and appends
+    /// the matches in start-node order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error, in start-node order, raised by a chunk.
+    pub(super) fn traverse_pattern_parallel(
+        &self,
+        pattern: &GraphPattern,
+        start_nodes: &[(u64, HashMap<String, u64>)],
+        edge_store: &ConcurrentEdgeStore,
+        ctx: &mut TraversalCtx<'_>,
+        config: &ParallelConfig,
+    ) -> Result<()> {
+        let remaining = ctx.limit.saturating_sub(ctx.all_results.len());
+        if remaining == 0 {
+            return Ok(());
+        }
+        let chunk_size = start_nodes
+            .len()
+            .div_ceil(config.effective_threads() * CHUNKS_PER_THREAD)
+            .max(1);
+        let (match_clause, params, payload_guard, guardrail) = (
+            ctx.match_clause,
+            ctx.params,
+            ctx.payload_guard,
+            ctx.guardrail,
+        );
+
+        let chunks: Vec<Result<_>> = start_nodes
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut results = Vec::new();
+                let mut iterations = 0u32;
+                let mut reported = 0usize;
+                let mut chunk_ctx = TraversalCtx {
+                    match_clause,
+                    params,
+                    payload_guard,
+                    guardrail,
+                    all_results: &mut results,
+                    limit: remaining,
+                    iteration_count: &mut iterations,
+                    reported_cardinality: &mut reported,
+                    seen_bindings: &mut HashSet::new(),
+                };
+                self.walk_start_nodes(pattern, chunk, edge_store, &mut chunk_ctx)?;
+                Ok((results, iterations))
+            })
+            .collect();
+
+        for chunk in chunks {
+            let (results, iterations) = chunk?;
+            *ctx.iteration_count = ctx.iteration_count.saturating_add(iterations);
+            let room = ctx.limit.saturating_sub(ctx.all_results.len());
+            ctx.all_results.extend(results.into_iter().take(room));
+        }
+        // Chunks reported their own rows to the cardinality guard-rail.
+        *ctx.reported_cardinality = ctx.all_results.len();
+        Ok(())
+    }
+}
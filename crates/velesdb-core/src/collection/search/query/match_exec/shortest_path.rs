@@ -0,0 +1,177 @@
+//! `shortestPath(...)` pattern execution for MATCH.
+//!
+//! A shortest-path pattern binds one shortest path between each pair of start
+//! and end nodes instead of enumerating every path. When the end node is bound
+//! by labels or properties and matches at most
+//! [`BIDIRECTIONAL_MAX_TARGETS`] nodes, every pair runs a bidirectional BFS.
+//! Otherwise one BFS per start node reports the first, and therefore
+//! shortest, reach of every node matching the end pattern.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use super::expand::{reversed, Walk};
+use super::{AliasBinding, TraversalCtx};
+use crate::collection::graph::{
+    bfs_shortest_paths, bidirectional_bfs, ConcurrentEdgeStore, TraversalConfig, TraversalResult,
+};
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::velesql::{GraphPattern, NodePattern, RelationshipPattern};
+
+/// Largest end-node candidate set searched pair by pair with bidirectional
+/// BFS. Beyond it, one BFS per start node is cheaper than one search per pair.
+const BIDIRECTIONAL_MAX_TARGETS: usize = 64;
+
+/// Hop cap for an unbounded range (upper bound `u32::MAX`), matching the
+/// traversal safety depth.
+const SHORTEST_PATH_MAX_HOPS: u32 = 100;
+
+type NodeBinding = (u64, HashMap<String, u64>);
+
+impl Collection {
+    /// Matches a `shortestPath(...)` pattern from each start node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Query` if the pattern does not have exactly one
+    /// relationship, its minimum length exceeds 1 or it sets `max_cost`, and
+    /// `Error::GuardRail` when a guard-rail trips.
+    pub(super) fn traverse_shortest_paths(
+        &self,
+        pattern: &GraphPattern,
+        start_nodes: &[NodeBinding],
+        edge_store: &ConcurrentEdgeStore,
+        ctx: &mut TraversalCtx<'_>,
+    ) -> Result<()> {
+        let ([rel], Some(end)) = (pattern.relationships.as_slice(), pattern.nodes.get(1)) else {
+            return Err(Error::Query(
+                "shortestPath requires exactly one relationship".to_string(),
+            ));
+        };
+        let config = shortest_path_config(rel)?;
+        let reverse = reversed(rel);
+        let forward = |node| Self::pattern_edges(edge_store, node, rel);
+        let backward = |node| Self::pattern_edges(edge_store, node, &reverse);
+        let targets = self.bound_end_nodes(end)?;
+
+        for start in start_nodes {
+            if ctx.all_results.len() >= ctx.limit {
+                break;
+            }
+            match &targets {
+                Some(targets) => {
+                    for (target, _) in targets {
+                        if ctx.all_results.len() >= ctx.limit {
+                            break;
+                        }
+                        if let Some(found) =
+                            bidirectional_bfs(start.0, *target, &config, forward, backward)
+                        {
+                            self.accept_shortest_path(pattern, edge_store, ctx, start, found)?;
+                        }
+                    }
+                }
+                None => {
+                    let mut outcome = Ok(());
+                    bfs_shortest_paths(start.0, &config, forward, |found| {
+                        if !Self::node_matches_bound_pattern(
+                            found.target_id,
+                            end,
+                            ctx.payload_guard,
+                        ) {
+                            return ControlFlow::Continue(());
+                        }
+                        outcome = self.accept_shortest_path(pattern, edge_store, ctx, start, found);
+                        if outcome.is_err() || ctx.all_results.len() >= ctx.limit {
+                            ControlFlow::Break(())
+                        } else {
+                            ControlFlow::Continue(())
+                        }
+                    });
+                    outcome?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves the end-node candidates when the end node is bound, or
+    /// `None` when it is unconstrained or matches too many nodes.
+    fn bound_end_nodes(&self, end: &NodePattern) -> Result<Option<Vec<NodeBinding>>> {
+        if end.collection.is_some() || (end.labels.is_empty() && end.properties.is_empty()) {
+            return Ok(None);
+        }
+        let end_only = GraphPattern {
+            name: None,
+            shortest_path: false,
+            nodes: vec![end.clone()],
+            relationships: Vec::new(),
+        };
+        let targets = self.find_start_nodes(&end_only)?;
+        Ok((targets.len() <= BIDIRECTIONAL_MAX_TARGETS).then_some(targets))
+    }
+
+    /// Binds the end node and relationship aliases of a found path, then
+    /// applies WHERE, deduplication and RETURN projection.
+    fn accept_shortest_path(
+        &self,
+        pattern: &GraphPattern,
+        edge_store: &ConcurrentEdgeStore,
+        ctx: &mut TraversalCtx<'_>,
+        (_, start_bindings): &NodeBinding,
+        found: TraversalResult,
+    ) -> Result<()> {
+        let mut bindings = start_bindings.clone();
+        if let AliasBinding::Conflict =
+            Self::bind_node_alias(&mut bindings, &pattern.nodes[1], found.target_id)
+        {
+            return Ok(());
+        }
+        let rel = &pattern.relationships[0];
+        let mut edge_bindings = HashMap::new();
+        let mut edge_paths = HashMap::new();
+        if let Some(alias) = rel.alias.clone() {
+            if rel.range.is_some() {
+                edge_paths.insert(alias, found.path.clone());
+            } else if let Some(&edge_id) = found.path.first() {
+                edge_bindings.insert(alias, edge_id);
+            }
+        }
+        *ctx.iteration_count += found.depth;
+        self.check_depth_and_periodic_guardrails(found.depth, ctx)?;
+
+        let mut path = found.path;
+        let mut walk = Walk {
+            pattern,
+            edge_store,
+            ctx,
+            bindings: &mut bindings,
+            edge_bindings: &mut edge_bindings,
+            edge_paths: &mut edge_paths,
+            path: &mut path,
+        };
+        self.accept_pattern_match(&mut walk, found.target_id)
+    }
+}
+
+/// Hop range of a shortest-path relationship as a traversal config.
+fn shortest_path_config(rel: &RelationshipPattern) -> Result<TraversalConfig> {
+    if rel.properties.contains_key("max_cost") {
+        return Err(Error::Query(
+            "shortestPath counts hops and does not support `max_cost`".to_string(),
+        ));
+    }
+    let (min_hops, max_hops) = rel.range.unwrap_or((1, 1));
+    if min_hops > 1 {
+        return Err(Error::Query(format!(
+            "shortestPath minimum length must be 0 or 1, got {min_hops}"
+        )));
+    }
+    let max_hops = if max_hops == u32::MAX {
+        SHORTEST_PATH_MAX_HOPS
+    } else {
+        max_hops
+    };
+    Ok(TraversalConfig::with_range(min_hops, max_hops))
+}
@@ -315,6 +315,9 @@ impl Collection {
         similarity_threshold: f32,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<Vec<MatchResult>> {
+        if let Some(limit) = match_clause.return_clause.limit {
+            self.check_query_limit(limit)?;
+        }
         // Raw traversal results (no ORDER BY/LIMIT yet): the similarity score is
         // computed below, then the shared finalize step applies ORDER BY + LIMIT.
         let results = self.execute_match_with_context(match_clause, params, None)?;
@@ -355,7 +358,8 @@ impl Collection {
         // then apply the shared post-sort LIMIT so this vector branch matches
         // the SQL `/query` pipeline instead of returning score-ordered results.
         self.apply_match_order_by(&mut scored_results, match_clause, params)?;
-        if let Some(limit) = super::super::match_dispatch::match_return_limit(match_clause) {
+        self.apply_match_limit_per(&mut scored_results, match_clause)?;
+        if let Some(limit) = self.match_return_limit(match_clause) {
             scored_results.truncate(limit);
         }
 
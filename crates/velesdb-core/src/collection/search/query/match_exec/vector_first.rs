@@ -62,11 +62,17 @@ impl Collection {
             .filter(|r| passes_threshold(r.score, threshold, higher_is_better))
             .collect();
 
-        let limit = match_clause
-            .return_clause
-            .limit
-            .and_then(|l| usize::try_from(l).ok())
-            .unwrap_or(100);
+        // `LIMIT n PER alias` caps groups, not the result: keep every
+        // candidate and let the finalize step apply the per-group LIMIT.
+        let limit = if match_clause.return_clause.limit_per.is_some() {
+            top_k
+        } else {
+            match_clause
+                .return_clause
+                .limit
+                .and_then(|l| usize::try_from(l).ok())
+                .unwrap_or(100)
+        };
 
         self.filter_candidates_by_graph(
             &above_threshold,
@@ -227,7 +227,8 @@ impl Collection {
             | Condition::ContainsText(_)
             | Condition::Contains(_)
             | Condition::GeoDistance(_)
-            | Condition::GeoBbox(_) => self.evaluate_metadata_condition_for_node(ctx, condition),
+            | Condition::GeoBbox(_)
+            | Condition::Expression(_) => self.evaluate_metadata_condition_for_node(ctx, condition),
             // VectorSearch, VectorFusedSearch, SparseVectorSearch, and GraphMatch
             // are handled separately in `execute_match_with_similarity`.
             Condition::VectorSearch(_)
@@ -457,40 +458,49 @@ impl Collection {
         value: &crate::velesql::Value,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<crate::velesql::Value> {
-        use crate::velesql::Value;
-
         match value {
-            Value::Parameter(name) => {
-                let param_value = params
-                    .get(name)
-                    .ok_or_else(|| Error::Query(format!("Missing parameter: ${name}")))?;
-
-                Ok(match param_value {
-                    serde_json::Value::Number(n) => {
-                        if let Some(i) = n.as_i64() {
-                            Value::Integer(i)
-                        } else if let Some(u) = n.as_u64() {
-                            Value::UnsignedInteger(u)
-                        } else if let Some(f) = n.as_f64() {
-                            Value::Float(f)
-                        } else {
-                            Value::Null
-                        }
-                    }
-                    serde_json::Value::String(s) => Value::String(s.clone()),
-                    serde_json::Value::Bool(b) => Value::Boolean(*b),
-                    serde_json::Value::Null => Value::Null,
-                    _ => {
-                        return Err(Error::Query(format!(
-                            "Unsupported parameter type for ${name}: {param_value:?}",
-                        )));
-                    }
-                })
+            crate::velesql::Value::Parameter(name) => {
+                scalar_param_value(name, Self::lookup_where_param(name, params)?)
             }
             other => Ok(other.clone()),
         }
     }
 
+    /// Resolves one entry of an `IN` / `CONTAINS ANY|ALL` list.
+    ///
+    /// A parameter bound to a JSON array expands into one value per element,
+    /// so `IN $ids` and `IN ($ids)` both accept a whole list. Scalars resolve
+    /// as in [`Self::resolve_where_param`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required parameter is missing, or if it (or an
+    /// array element) is an object or a nested array.
+    pub(crate) fn resolve_where_param_list(
+        value: &crate::velesql::Value,
+        params: &HashMap<String, serde_json::Value>,
+    ) -> Result<Vec<crate::velesql::Value>> {
+        let crate::velesql::Value::Parameter(name) = value else {
+            return Ok(vec![value.clone()]);
+        };
+        match Self::lookup_where_param(name, params)? {
+            serde_json::Value::Array(items) => items
+                .iter()
+                .map(|item| scalar_param_value(name, item))
+                .collect(),
+            scalar => Ok(vec![scalar_param_value(name, scalar)?]),
+        }
+    }
+
+    fn lookup_where_param<'a>(
+        name: &str,
+        params: &'a HashMap<String, serde_json::Value>,
+    ) -> Result<&'a serde_json::Value> {
+        params
+            .get(name)
+            .ok_or_else(|| Error::Query(format!("Missing parameter: ${name}")))
+    }
+
     /// Evaluates a comparison operation.
     #[allow(clippy::unnecessary_wraps)] // Consistent with other evaluation methods
     pub(crate) fn evaluate_comparison(
@@ -536,11 +546,7 @@ impl Collection {
             return Some(root);
         }
 
-        let mut current = root;
-        for part in path.split('.') {
-            current = current.get(part)?;
-        }
-        Some(current)
+        crate::filter::lookup_path(root, path)
     }
 }
 
@@ -635,7 +641,43 @@ fn rewrite_condition_aliases(
             gb.column = strip_alias_owned(&gb.column, is_alias);
             Condition::GeoBbox(gb)
         }
+        Condition::Expression(mut ec) => {
+            let mut strip = |column: &str| strip_alias_owned(column, is_alias);
+            ec.left.rewrite_variables(&mut strip);
+            ec.right.rewrite_variables(&mut strip);
+            Condition::Expression(ec)
+        }
         // Non-metadata conditions pass through unchanged.
         other => other,
     }
 }
+
+/// Converts a scalar JSON parameter into a VelesQL value.
+fn scalar_param_value(
+    name: &str,
+    param_value: &serde_json::Value,
+) -> Result<crate::velesql::Value> {
+    use crate::velesql::Value;
+
+    Ok(match param_value {
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::Integer(i)
+            } else if let Some(u) = n.as_u64() {
+                Value::UnsignedInteger(u)
+            } else if let Some(f) = n.as_f64() {
+                Value::Float(f)
+            } else {
+                Value::Null
+            }
+        }
+        serde_json::Value::String(s) => Value::String(s.clone()),
+        serde_json::Value::Bool(b) => Value::Boolean(*b),
+        serde_json::Value::Null => Value::Null,
+        _ => {
+            return Err(Error::Query(format!(
+                "Unsupported parameter type for ${name}: {param_value:?}",
+            )));
+        }
+    })
+}
@@ -0,0 +1,120 @@
+//! Tests for `LIMIT n PER alias` on MATCH results (`match_exec::limit_per`).
+
+use crate::collection::graph::GraphEdge;
+use crate::collection::types::Collection;
+use crate::error::Error;
+use crate::point::Point;
+use crate::test_fixtures::fixtures::setup_collection;
+use crate::velesql::{MatchClause, Parser};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+/// Docs 1..=3, each citing five papers (ids `d * 10 + i`, `year = 2000 + i`).
+fn setup_citations() -> (TempDir, Collection) {
+    let (dir, col) = setup_collection(4);
+    let mut points = Vec::new();
+    let mut edge_id = 1_000;
+    for doc in 1..=3u64 {
+        points.push(Point::new(
+            doc,
+            vec![1.0, 0.0, 0.0, 0.0],
+            Some(serde_json::json!({"_labels": ["Doc"]})),
+        ));
+        for i in 0..5u64 {
+            points.push(Point::new(
+                doc * 10 + i,
+                vec![0.0, 1.0, 0.0, 0.0],
+                Some(serde_json::json!({"_labels": ["Paper"], "year": 2000 + i})),
+            ));
+        }
+    }
+    col.upsert(points).expect("test: upsert");
+    for doc in 1..=3u64 {
+        for i in 0..5u64 {
+            edge_id += 1;
+            let edge = GraphEdge::new(edge_id, doc, doc * 10 + i, "CITES").expect("test: edge");
+            col.add_edge(edge).expect("test: add edge");
+        }
+    }
+    (dir, col)
+}
+
+fn match_clause(sql: &str) -> MatchClause {
+    Parser::parse(sql)
+        .expect("test: parse")
+        .match_clause
+        .expect("test: MATCH clause")
+}
+
+/// `(d, c)` bindings of every row, in result order.
+fn pairs(col: &Collection, sql: &str) -> Vec<(u64, u64)> {
+    col.execute_match(&match_clause(sql), &HashMap::new())
+        .expect("test: execute MATCH")
+        .into_iter()
+        .map(|m| (m.bindings["d"], m.bindings["c"]))
+        .collect()
+}
+
+fn rows_per_doc(pairs: &[(u64, u64)]) -> HashMap<u64, usize> {
+    let mut counts = HashMap::new();
+    for (d, _) in pairs {
+        *counts.entry(*d).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[test]
+fn test_limit_per_caps_rows_per_binding() {
+    let (_dir, col) = setup_citations();
+    let rows = pairs(
+        &col,
+        "MATCH (d:Doc)-[:CITES]->(c) RETURN d, c LIMIT 2 PER d",
+    );
+    assert_eq!(rows.len(), 6, "{rows:?}");
+    assert_eq!(rows_per_doc(&rows), HashMap::from([(1, 2), (2, 2), (3, 2)]));
+
+    // Without PER the same LIMIT bounds the whole result.
+    let rows = pairs(&col, "MATCH (d:Doc)-[:CITES]->(c) RETURN d, c LIMIT 2");
+    assert_eq!(rows.len(), 2);
+}
+
+#[test]
+fn test_limit_per_keeps_top_rows_by_order_by() {
+    let (_dir, col) = setup_citations();
+    let rows = pairs(
+        &col,
+        "MATCH (d:Doc)-[:CITES]->(c) RETURN d, c ORDER BY c.year DESC LIMIT 2 PER d",
+    );
+    let mut newest: Vec<(u64, u64)> = rows.clone();
+    newest.sort_unstable();
+    assert_eq!(
+        newest,
+        vec![(1, 13), (1, 14), (2, 23), (2, 24), (3, 33), (3, 34)]
+    );
+}
+
+#[test]
+fn test_limit_per_through_query_pipeline() {
+    let (_dir, col) = setup_citations();
+    let results = col
+        .execute_query_str(
+            "MATCH (d:Doc)-[:CITES]->(c) RETURN d, c LIMIT 1 PER d",
+            &HashMap::new(),
+        )
+        .expect("test: MATCH query");
+    assert_eq!(results.len(), 3);
+}
+
+#[test]
+fn test_limit_per_rejects_unknown_or_list_alias() {
+    let (_dir, col) = setup_citations();
+    for sql in [
+        "MATCH (d:Doc)-[:CITES]->(c) RETURN d, c LIMIT 2 PER x",
+        "MATCH (d:Doc)-[r:CITES*1..2]->(c) RETURN d, c LIMIT 2 PER r",
+    ] {
+        let err = col
+            .execute_match(&match_clause(sql), &HashMap::new())
+            .expect_err("invalid PER alias");
+        assert!(matches!(err, Error::Query(_)), "{sql}: got {err:?}");
+    }
+}
@@ -0,0 +1,95 @@
+//! Tests for parallel MATCH pattern walks (`match_exec::parallel_walk`).
+//!
+//! An aliased first node over enough start nodes walks in parallel; the same
+//! pattern with an anonymous first node walks sequentially. Both must yield
+//! the same rows in the same order.
+
+use crate::collection::graph::GraphEdge;
+use crate::collection::types::Collection;
+use crate::point::Point;
+use crate::test_fixtures::fixtures::setup_collection;
+use crate::velesql::{MatchClause, Parser};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+const DOCS: u64 = 300;
+
+/// 300 `Doc` nodes, each citing three others, forming a dense 3-hop graph.
+fn setup_graph() -> (TempDir, Collection) {
+    let (dir, col) = setup_collection(4);
+    let points: Vec<Point> = (0..DOCS)
+        .map(|id| {
+            Point::new(
+                id,
+                vec![1.0, 0.0, 0.0, 0.0],
+                Some(serde_json::json!({"_labels": ["Doc"], "idx": id})),
+            )
+        })
+        .collect();
+    col.upsert(points).expect("test: upsert");
+    let mut edge_id = 10_000;
+    for id in 0..DOCS {
+        for step in [1, 7, 31] {
+            edge_id += 1;
+            let edge =
+                GraphEdge::new(edge_id, id, (id * 3 + step) % DOCS, "CITES").expect("test: edge");
+            col.add_edge(edge).expect("test: add edge");
+        }
+    }
+    (dir, col)
+}
+
+fn match_clause(sql: &str) -> MatchClause {
+    Parser::parse(sql)
+        .expect("test: parse")
+        .match_clause
+        .expect("test: MATCH clause")
+}
+
+/// `(node_id, path)` of every row, in result order.
+fn rows(col: &Collection, sql: &str) -> Vec<(u64, Vec<u64>)> {
+    col.execute_match(&match_clause(sql), &HashMap::new())
+        .expect("test: execute MATCH")
+        .into_iter()
+        .map(|m| (m.node_id, m.path))
+        .collect()
+}
+
+#[test]
+fn test_parallel_walk_only_for_aliased_large_start_sets() {
+    let pattern = |sql: &str| match_clause(sql).patterns.remove(0);
+    let aliased = pattern("MATCH (a:Doc)-[:CITES]->(b) RETURN b");
+    let anonymous = pattern("MATCH (:Doc)-[:CITES]->(b) RETURN b");
+
+    assert!(Collection::parallel_walk_config(&aliased, 1000).is_some());
+    assert!(Collection::parallel_walk_config(&aliased, 3).is_none());
+    assert!(Collection::parallel_walk_config(&anonymous, 1000).is_none());
+}
+
+#[test]
+fn test_parallel_walk_matches_sequential_order() {
+    let (_dir, col) = setup_graph();
+
+    let parallel = rows(&col, "MATCH (a:Doc)-[r:CITES*1..3]->(b) RETURN b");
+    let sequential = rows(&col, "MATCH (:Doc)-[r:CITES*1..3]->(b) RETURN b");
+
+    assert_eq!(parallel.len(), sequential.len());
+    assert_eq!(parallel, sequential);
+}
+
+#[test]
+fn test_parallel_walk_limit_keeps_sequential_prefix() {
+    let (_dir, col) = setup_graph();
+
+    let parallel = rows(
+        &col,
+        "MATCH (a:Doc)-[r:CITES]->(m)-[s:CITES]->(b) RETURN b LIMIT 1234",
+    );
+    let sequential = rows(
+        &col,
+        "MATCH (:Doc)-[r:CITES]->(m)-[s:CITES]->(b) RETURN b LIMIT 1234",
+    );
+
+    assert_eq!(parallel.len(), 1234);
+    assert_eq!(parallel, sequential);
+}
@@ -12,8 +12,9 @@ impl Collection {
         cond: &crate::velesql::Condition,
         execution_limit: usize,
     ) -> Option<Vec<SearchResult>> {
+        let collation = self.default_collation();
         // Try simple Eq lookup first (fastest path).
-        if let Some((field_name, key)) = Self::extract_index_lookup_condition(cond) {
+        if let Some((field_name, key)) = Self::extract_index_lookup_condition(cond, collation) {
             let ids = self.secondary_index_lookup(&field_name, &key)?;
             tracing::debug!(
                 field = %field_name,
@@ -29,7 +30,7 @@ impl Collection {
                 tracing::debug!("indexed metadata query: too many hits, falling through to scan");
                 return None; // Fall through to scan
             }
-            let filter = crate::filter::Filter::new(crate::filter::Condition::from(cond.clone()));
+            let filter = self.where_filter(cond.clone());
             return Some(self.scan_ids_with_filter(&ids, &filter, execution_limit));
         }
 
@@ -40,11 +41,11 @@ impl Collection {
             let mut leaves = Vec::new();
             Self::flatten_and_conditions(cond, &mut leaves);
             for sub in &leaves {
-                if let Some((field_name, key)) = Self::extract_index_lookup_condition(sub) {
+                if let Some((field_name, key)) =
+                    Self::extract_index_lookup_condition(sub, collation)
+                {
                     if let Some(ids) = self.secondary_index_lookup(&field_name, &key) {
-                        let filter = crate::filter::Filter::new(crate::filter::Condition::from(
-                            cond.clone(),
-                        ));
+                        let filter = self.where_filter(cond.clone());
                         return Some(self.scan_ids_with_filter(&ids, &filter, execution_limit));
                     }
                 }
@@ -100,13 +101,18 @@ impl Collection {
     /// Extracts an `(field_name, value)` pair from an `Eq` comparison condition.
     ///
     /// Returns `None` for non-Eq operators or when the value cannot be converted
-    /// to a `JsonValue` suitable for index lookup.
+    /// to a `JsonValue` suitable for index lookup. String keys are rejected
+    /// under a non-binary `collation`, since the index stores raw strings.
     pub(super) fn extract_index_lookup_condition(
         cond: &crate::velesql::Condition,
+        collation: crate::filter::Collation,
     ) -> Option<(String, crate::index::JsonValue)> {
         if let crate::velesql::Condition::Comparison(cmp) = cond {
             if cmp.operator == crate::velesql::CompareOp::Eq {
                 return crate::index::JsonValue::from_ast_value(&cmp.value)
+                    .filter(|v| {
+                        collation.is_binary() || !matches!(v, crate::index::JsonValue::String(_))
+                    })
                     .map(|v| (cmp.column.clone(), v));
             }
         }
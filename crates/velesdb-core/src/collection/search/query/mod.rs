@@ -43,6 +43,9 @@ mod execution_paths;
 mod extraction;
 #[cfg(test)]
 mod extraction_tests;
+mod field_boost;
+#[cfg(test)]
+mod field_boost_tests;
 mod fused_dispatch;
 mod graph_prefilter;
 mod hybrid_sparse;
@@ -52,14 +55,22 @@ pub mod join;
 #[cfg(test)]
 mod join_tests;
 #[cfg(test)]
+mod json_extract_tests;
+#[cfg(test)]
 mod let_execution_tests;
+#[cfg(test)]
+mod match_anchor_batch_tests;
 mod match_dispatch;
 pub mod match_exec;
 #[cfg(test)]
 mod match_exec_tests;
+#[cfg(test)]
+mod match_limit_per_tests;
 pub mod match_metrics;
 #[cfg(test)]
 mod match_metrics_tests;
+#[cfg(test)]
+mod match_parallel_walk_tests;
 mod metadata_query;
 mod multi_vector;
 #[cfg(test)]
@@ -77,15 +88,26 @@ pub mod pushdown;
 #[cfg(test)]
 mod pushdown_tests;
 mod query_pipeline;
+mod random_sample;
+#[cfg(test)]
+mod random_sample_tests;
 #[cfg(test)]
 mod scan_cap_observability_tests;
 pub mod score_fusion;
 #[cfg(test)]
 mod score_fusion_tests;
+mod scratch;
+#[cfg(test)]
+mod scratch_tests;
 mod select_dispatch;
+#[cfg(test)]
+mod select_expression_tests;
 pub(crate) mod set_operations;
 mod similarity_filter;
 mod sparse_dispatch;
+mod time_decay;
+#[cfg(test)]
+mod time_decay_tests;
 mod union_query;
 mod validation;
 pub(crate) mod vector_group_by;
@@ -95,22 +117,27 @@ mod with_options_tests;
 
 // Re-export for potential external use
 #[allow(unused_imports)]
-pub use ordering::compare_json_values;
+pub use ordering::{compare_json_values, compare_json_values_collated};
 // Re-export join functions for future integration with execute_query
 #[allow(unused_imports)]
 pub use join::{execute_join, JoinedResult, JOIN_ROW_CEILING};
 
 // Re-export types from options.rs so sibling submodules can use `super::*`.
-pub(crate) use options::QuerySearchOptions;
 pub(in crate::collection::search::query) use options::{
-    ExtractedComponents, QueryFinalizationContext, MAX_LIMIT,
+    ExtractedComponents, QueryFinalizationContext,
 };
+pub(crate) use options::{PlannerHints, QuerySearchOptions};
+pub(crate) use time_decay::TimeDecay;
 
 use crate::collection::types::Collection;
 use crate::error::Result;
 use crate::point::SearchResult;
 use std::collections::HashSet;
 
+/// Candidate multiplier of vector searches whose hits are rescored after
+/// retrieval (time decay, `field_boost` fusion).
+const RESCORE_OVERFETCH: usize = 4;
+
 /// Bundles the non-query/params arguments for
 /// [`Collection::dispatch_and_finalize`] to stay within the parameter limit.
 struct DispatchFinalizeArgs<'a> {
@@ -118,6 +145,8 @@ struct DispatchFinalizeArgs<'a> {
     limit: usize,
     fetch_limit: usize,
     is_vgb: bool,
+    decay: Option<&'a time_decay::TimeDecay>,
+    boost: Option<&'a score_fusion::LogFieldBoost>,
     ctx: &'a crate::guardrails::QueryContext,
 }
 
@@ -140,14 +169,23 @@ impl Collection {
         // EPIC-040 US-006: For compound queries, execute each operand without the
         // outer LIMIT so the set operation sees the full result sets.  The final
         // LIMIT is applied once on the merged output (SQL-standard behaviour).
-        // Use MAX_LIMIT (not None) to avoid the default-10 cap in execute_query_with_client.
-        let compound_limit = Some(u64::try_from(MAX_LIMIT).unwrap_or(u64::MAX));
-        let left_results = if query.compound.is_some() {
+        // Use the query limit ceiling (not None) to avoid the default-10 cap in
+        // execute_query_with_client.
+        let max = self.max_query_limit();
+        let compound_limit = Some(u64::try_from(max).unwrap_or(u64::MAX));
+        let (merge, left_results) = if query.compound.is_some() {
+            self.check_query_limits(query)?;
+            // Leading-SELECT `set_score`: how UNION / INTERSECT merge scores.
+            let merge =
+                set_operations::ScoreMerge::from_with_clause(query.select.with_clause.as_ref())?;
             let mut left_query = query.clone();
             left_query.select.limit = compound_limit;
             left_query.select.offset = None; // OFFSET applies to combined result, not operands.
             left_query.compound = None;
-            self.execute_query_with_client(&left_query, params, "default")?
+            (
+                merge,
+                self.execute_query_with_client(&left_query, params, "default")?,
+            )
         } else {
             return self.execute_query_with_client(query, params, "default");
         };
@@ -156,6 +194,9 @@ impl Collection {
         if let Some(ref compound) = query.compound {
             let mut accumulated = left_results;
             for (operator, right_select) in &compound.operations {
+                if let Some(limit) = right_select.limit {
+                    self.check_query_limit(limit)?;
+                }
                 let mut right_query = crate::velesql::Query::new_select(right_select.clone());
                 right_query.select.limit = compound_limit;
                 let right_results =
@@ -166,7 +207,8 @@ impl Collection {
                     accumulated,
                     right_results,
                     *operator,
-                    MAX_LIMIT,
+                    merge,
+                    max,
                 );
             }
             // SQL-standard: OFFSET then LIMIT on the combined result.
@@ -197,8 +239,16 @@ impl Collection {
         params: &std::collections::HashMap<String, serde_json::Value>,
         client_id: &str,
     ) -> Result<Vec<SearchResult>> {
+        // A named scoring profile fills the options the query leaves unset;
+        // resolved first so validation and the timeout see its options too.
+        let profiled_query = self.apply_scoring_profile(query)?;
+        let query = profiled_query.as_ref().unwrap_or(query);
+
         // Phase 1: Pre-checks and context setup.
         let ctx = self.prepare_query_context(query, client_id)?;
+        // Held until the query returns; compound operands re-enter here one
+        // after another, so a query never holds two slots at once.
+        let _permit = self.admit(crate::collection::AdmissionKind::for_query(query))?;
 
         // MATCH queries take a completely separate path (no extraction needed).
         if let Some(results) = self.try_dispatch_match(query, params, &ctx)? {
@@ -211,6 +261,11 @@ impl Collection {
         let resolved_query = Self::resolve_query_where_params(query, params)?;
         let query = resolved_query.as_ref().unwrap_or(query);
 
+        // Collection-level WITH / USING FUSION defaults fill whatever the
+        // query leaves unset; options the query sets itself always win.
+        let defaulted_query = self.apply_query_defaults(query);
+        let query = defaulted_query.as_ref().unwrap_or(query);
+
         // Phase 2-3: SELECT extraction, early-return, dispatch, and finalization.
         self.execute_select_pipeline(query, params, &ctx)
     }
@@ -227,12 +282,15 @@ impl Collection {
         ctx: &crate::guardrails::QueryContext,
     ) -> Result<Vec<SearchResult>> {
         let stmt = &query.select;
-        let (limit, fetch_limit) = Self::compute_fetch_limit(stmt);
+        let (limit, fetch_limit) = self.compute_fetch_limit(stmt);
         let extracted = self.extract_query_components(stmt, params)?;
+        Self::check_metric_option(stmt, &extracted)?;
+        let decay = Self::time_decay_option(stmt, &extracted)?;
+        let boost = Self::field_boost_option(stmt, &extracted)?;
 
         // EPIC-081 phase 2: serve a plain `ORDER BY <indexed_field> LIMIT k` from
         // the field's ordered secondary index instead of the exhaustive
-        // MAX_LIMIT fetch + sort. Gated hard (single plain Field key, fully
+        // max_query_limit fetch + sort. Gated hard (single plain Field key, fully
         // covered index, no WHERE/JOIN/graph/vector/sparse/DISTINCT/aggregate);
         // OFFSET/LIMIT are applied inside the scan, so it returns the finished
         // page directly. Falls through unchanged otherwise.
@@ -240,10 +298,42 @@ impl Collection {
             return Ok(results);
         }
 
+        // `ORDER BY random() LIMIT n` over a plain or metadata-filtered scan is
+        // a uniform sample: draw it directly instead of fetching and shuffling.
+        if let Some(results) = self.try_random_sample(query, stmt, &extracted, ctx)? {
+            return Ok(results);
+        }
+
         // When vector GROUP BY is active, fetch more results from vector search
         // so grouping has enough chunks to work with.
         let is_vgb = vector_group_by::is_vector_group_by_query(stmt);
-        let effective_fetch_limit = if is_vgb { MAX_LIMIT } else { fetch_limit };
+        if is_vgb
+            && stmt
+                .group_by
+                .as_ref()
+                .is_some_and(|g| !g.expressions.is_empty())
+        {
+            return Err(crate::error::Error::Query(
+                "GROUP BY expressions are not supported on vector NEAR queries; group by a column"
+                    .to_string(),
+            ));
+        }
+        if !is_vgb && Self::per_group_option(stmt)?.is_some() {
+            return Err(crate::error::Error::Query(
+                "WITH (per_group = n) requires GROUP BY on a vector NEAR query".to_string(),
+            ));
+        }
+        // Decay and field boosts re-rank the hits, so candidates from beyond
+        // the LIMIT must be fetched too.
+        let effective_fetch_limit = if is_vgb {
+            self.max_query_limit()
+        } else if decay.is_some() || boost.is_some() {
+            fetch_limit
+                .saturating_mul(RESCORE_OVERFETCH)
+                .min(self.max_query_limit())
+        } else {
+            fetch_limit
+        };
 
         // Early-return paths or LET-binding guard for special query shapes.
         if let Some(results) = self.try_early_return_or_guard_let(
@@ -265,6 +355,8 @@ impl Collection {
                 limit,
                 fetch_limit: effective_fetch_limit,
                 is_vgb,
+                decay: decay.as_ref(),
+                boost: boost.as_ref(),
                 ctx,
             },
         )
@@ -284,19 +376,39 @@ impl Collection {
         let stmt = &query.select;
         let mut results =
             self.dispatch_main_select(stmt, params, args.extracted, args.fetch_limit, args.ctx)?;
+        if let Some(decay) = args.decay {
+            self.apply_time_decay(decay, stmt, &mut results);
+        }
+        if let Some(boost) = args.boost {
+            self.apply_field_boost(boost, stmt, &mut results);
+        }
 
         // Vector GROUP BY post-processing: group results by parent field
-        // before ORDER BY / LIMIT / OFFSET are applied.
+        // before ORDER BY / LIMIT / OFFSET are applied. With `per_group` the
+        // grouping step already applied LIMIT / OFFSET (they count groups), so
+        // finalization runs on the flattened rows without them.
+        let mut per_group_stmt = None;
+        let mut limit = args.limit;
         if args.is_vgb {
-            results = self.apply_vector_group_by(stmt, &results);
+            if let Some(per_group) = Self::per_group_option(stmt)? {
+                results = self.apply_vector_group_top_hits(stmt, results, limit, per_group)?;
+                limit = results.len();
+                per_group_stmt = Some(crate::velesql::SelectStatement {
+                    offset: None,
+                    ..stmt.clone()
+                });
+            } else {
+                results = self.apply_vector_group_by(stmt, &results);
+            }
         }
+        let stmt = per_group_stmt.as_ref().unwrap_or(stmt);
 
         self.finalize_query_results(
             &mut results,
             &QueryFinalizationContext {
                 stmt,
                 params,
-                limit: args.limit,
+                limit,
                 extracted: args.extracted,
                 ctx: args.ctx,
                 let_bindings: &query.let_bindings,
@@ -2,9 +2,6 @@
 //!
 //! Extracted from `query/mod.rs` to keep file NLOC under 500.
 
-/// Maximum allowed LIMIT value to prevent overflow in over-fetch calculations.
-pub(in crate::collection::search::query) const MAX_LIMIT: usize = 100_000;
-
 /// Query-time search options extracted from the WITH clause.
 ///
 /// Consolidates `mode`, `ef_search`, `rerank`, and `fusion_clause` into a single
@@ -16,10 +13,61 @@ pub(crate) struct QuerySearchOptions {
     pub quality: Option<crate::SearchQuality>,
     /// Explicit ef_search override from `WITH (ef_search=N)`.
     pub ef_search: Option<usize>,
+    /// HNSW entry-point count from `WITH (entry_probes=N)`.
+    pub entry_probes: Option<usize>,
     /// Force reranking on (`true`) or off (`false`) from `WITH (rerank=...)`.
     pub force_rerank: Option<bool>,
+    /// Ranking metric override from `WITH (metric='...')`.
+    pub metric: Option<crate::distance::DistanceMetric>,
     /// Fusion clause from `USING FUSION (...)`.
     pub fusion_clause: Option<crate::velesql::FusionClause>,
+    /// Planner hints from `WITH (hint='...')`.
+    pub hints: PlannerHints,
+}
+
+/// Planner hints from `WITH (hint='...')`, already validated (V013).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct PlannerHints {
+    /// `force_scan`: bypass HNSW and secondary indexes.
+    pub force_scan: bool,
+    /// `force_index`: keep HNSW first for filtered vector searches.
+    pub force_index: bool,
+    /// `no_overfetch`: fetch exactly `LIMIT` HNSW candidates.
+    pub no_overfetch: bool,
+}
+
+impl PlannerHints {
+    /// Collects the hints of an optional WITH clause; unknown names are
+    /// ignored here (validation rejects them first).
+    #[must_use]
+    pub(crate) fn from_with_clause(with: Option<&crate::velesql::WithClause>) -> Self {
+        let hints = with.and_then(|w| w.get_hints().ok()).unwrap_or_default();
+        let has = |hint| hints.contains(&hint);
+        Self {
+            force_scan: has(crate::velesql::QueryHint::ForceScan),
+            force_index: has(crate::velesql::QueryHint::ForceIndex),
+            no_overfetch: has(crate::velesql::QueryHint::NoOverfetch),
+        }
+    }
+
+    /// Overrides the cost-based strategy and over-fetch factor of a filtered
+    /// vector search.
+    #[must_use]
+    pub(crate) fn apply(
+        self,
+        strategy: crate::velesql::ExecutionStrategy,
+        over_fetch: usize,
+    ) -> (crate::velesql::ExecutionStrategy, usize) {
+        let strategy = if self.force_scan {
+            crate::velesql::ExecutionStrategy::GraphFirst
+        } else if self.force_index {
+            crate::velesql::ExecutionStrategy::VectorFirst
+        } else {
+            strategy
+        };
+        let over_fetch = if self.no_overfetch { 1 } else { over_fetch };
+        (strategy, over_fetch)
+    }
 }
 
 impl QuerySearchOptions {
@@ -30,6 +78,7 @@ impl QuerySearchOptions {
     /// are silently ignored (quality remains `None`).
     #[must_use]
     pub(crate) fn from_with_clause(with: Option<&crate::velesql::WithClause>) -> Self {
+        let hints = PlannerHints::from_with_clause(with);
         let Some(with) = with else {
             return Self::default();
         };
@@ -37,13 +86,20 @@ impl QuerySearchOptions {
         let quality = with.get_mode().and_then(parse_mode_to_quality);
 
         let ef_search = with.get_ef_search();
+        let entry_probes = with.get_entry_probes();
         let force_rerank = with.get_rerank();
+        let metric = with
+            .get_metric()
+            .and_then(crate::distance::DistanceMetric::parse_alias);
 
         Self {
             quality,
             ef_search,
+            entry_probes,
             force_rerank,
+            metric,
             fusion_clause: None,
+            hints,
         }
     }
 
@@ -57,14 +113,18 @@ impl QuerySearchOptions {
     /// Returns `true` when any quality-related override is set.
     #[must_use]
     pub(crate) fn has_quality_overrides(&self) -> bool {
-        self.quality.is_some() || self.ef_search.is_some() || self.force_rerank.is_some()
+        self.quality.is_some()
+            || self.ef_search.is_some()
+            || self.entry_probes.is_some()
+            || self.force_rerank.is_some()
     }
 }
 
 /// Maps a mode string from `WITH (mode='...')` to a [`SearchQuality`](crate::SearchQuality).
 ///
 /// Delegates to [`crate::api_types::mode_to_search_quality`] which also handles
-/// advanced modes (`custom:<ef>`, `adaptive:<min>:<max>`).
+/// advanced modes (`custom:<ef>`, `adaptive:<min>:<max>`,
+/// `multi_entry:<ef>:<probes>`).
 #[cfg(feature = "persistence")]
 fn parse_mode_to_quality(mode: &str) -> Option<crate::SearchQuality> {
     crate::api_types::mode_to_search_quality(mode)
@@ -88,6 +148,20 @@ pub(in crate::collection::search::query) struct ExtractedComponents {
     pub(in crate::collection::search::query) is_not_similarity_query: bool,
 }
 
+impl ExtractedComponents {
+    /// Whether the query runs through the main vector dispatch: a `NEAR`
+    /// outside `NEAR_FUSED`, `SPARSE_NEAR`, `OR` and `NOT similarity()`
+    /// queries, which rank on paths of their own. Options rescoring vector
+    /// hits after retrieval require it.
+    pub(in crate::collection::search::query) fn is_main_vector_search(&self) -> bool {
+        self.vector_search.is_some()
+            && self.sparse_vector_search.is_none()
+            && self.fused_search.is_none()
+            && !self.is_union_query
+            && !self.is_not_similarity_query
+    }
+}
+
 /// Bundles the parameters for [`Collection::finalize_query_results`] to stay
 /// within the 8-parameter limit.
 pub(in crate::collection::search::query) struct QueryFinalizationContext<'a> {
@@ -3,7 +3,7 @@
 //!
 //! Routes a scalar `ORDER BY <indexed_field> LIMIT k` — optionally carrying a
 //! pure-metadata `WHERE` — to the field's ordered secondary B-tree index instead
-//! of the B001 exhaustive `MAX_LIMIT` fetch + in-memory sort. Without a filter
+//! of the B001 exhaustive `max_query_limit` fetch + in-memory sort. Without a filter
 //! the walk is `O(log n + k)`. With one it walks the fully-covered ordered index
 //! applying the **same** metadata predicate the exhaustive path applies, stopping
 //! once the page is filled — equivalent to the exhaustive `filter → sort → limit`
@@ -17,7 +17,6 @@ use crate::error::Result;
 use crate::point::SearchResult;
 use crate::velesql::Condition;
 
-use super::options::MAX_LIMIT;
 use super::ExtractedComponents;
 
 /// Rows hydrated per batch while walking the ordered index under a WHERE filter.
@@ -95,7 +94,7 @@ impl Collection {
         stmt: &crate::velesql::SelectStatement,
         field: &str,
     ) -> Option<Vec<SearchResult>> {
-        let (limit, fetch_limit) = Self::compute_fetch_limit(stmt);
+        let (limit, fetch_limit) = self.compute_fetch_limit(stmt);
         let Some(ids) = self.ordered_ids_if_covered(field, order_descending(stmt), fetch_limit)
         else {
             self.query.order_by_advisor.write().observe(field);
@@ -120,7 +119,7 @@ impl Collection {
     /// stopping once the OFFSET+LIMIT page is filled.
     ///
     /// Declines (returns `None`) **without** observing the advisor when the
-    /// collection exceeds `MAX_LIMIT` (matching the capped exhaustive baseline)
+    /// collection exceeds `max_query_limit` (matching the capped exhaustive baseline)
     /// or the filter is too selective for the walk to beat the exhaustive bitmap
     /// prefilter — in those cases a covering index would not enable the route, so
     /// it is not an advisable gap. It *does* observe when the only thing missing
@@ -132,7 +131,7 @@ impl Collection {
         metadata_cond: &Condition,
     ) -> Option<Vec<SearchResult>> {
         let point_count = self.len();
-        if point_count > MAX_LIMIT {
+        if point_count > self.max_query_limit() {
             return None;
         }
         let selectivity = crate::velesql::CostEstimator::new(&self.get_stats())
@@ -148,9 +147,8 @@ impl Collection {
             self.query.order_by_advisor.write().observe(field);
             return None;
         };
-        let predicate =
-            crate::filter::Filter::new(crate::filter::Condition::from(metadata_cond.clone()));
-        let (limit, _) = Self::compute_fetch_limit(stmt);
+        let predicate = self.where_filter(metadata_cond.clone());
+        let (limit, _) = self.compute_fetch_limit(stmt);
         Some(self.collect_filtered_page(&ids, &predicate, order_offset(stmt), limit))
     }
 
@@ -162,7 +160,7 @@ impl Collection {
     /// the total order, so every row outside the leading buckets sorts strictly
     /// after them — the top page is wholly inside the prefix.
     ///
-    /// Declines (returns `None`) without observing the advisor above `MAX_LIMIT`;
+    /// Declines (returns `None`) without observing the advisor above `max_query_limit`;
     /// observes (a covering index is the missing piece) when the lead field has
     /// no covering index; and declines if any prefix row was dropped by `get`
     /// (deleted / TTL-expired — the page could need a trailing-bucket row, so the
@@ -173,10 +171,10 @@ impl Collection {
         lead_field: &str,
     ) -> Option<Vec<SearchResult>> {
         let point_count = self.len();
-        if point_count > MAX_LIMIT {
+        if point_count > self.max_query_limit() {
             return None;
         }
-        let (limit, fetch_limit) = Self::compute_fetch_limit(stmt);
+        let (limit, fetch_limit) = self.compute_fetch_limit(stmt);
         let Some(ids) =
             self.ordered_prefix_if_covered(lead_field, order_descending(stmt), fetch_limit)
         else {
@@ -249,7 +247,7 @@ fn order_descending(stmt: &crate::velesql::SelectStatement) -> bool {
 }
 
 /// OFFSET as a `usize` (SQL-standard: applied after ORDER BY, before LIMIT).
-fn order_offset(stmt: &crate::velesql::SelectStatement) -> usize {
+pub(super) fn order_offset(stmt: &crate::velesql::SelectStatement) -> usize {
     stmt.offset
         .map_or(0, |o| usize::try_from(o).unwrap_or(usize::MAX))
 }
@@ -281,6 +279,10 @@ fn ordered_index_scan_applies<'a>(
     extracted: &ExtractedComponents,
 ) -> Option<ScanPlan<'a>> {
     let keys = stmt.order_by.as_deref()?;
+    // Secondary indexes keep keys in binary order.
+    if keys.iter().any(|k| !k.collation.is_binary()) {
+        return None;
+    }
     let lead_field = match keys.first()?.expr {
         crate::velesql::OrderByExpr::Field(ref name) => name.as_str(),
         _ => return None,
@@ -323,7 +325,7 @@ fn multi_key_plan<'a>(
 }
 
 /// Outcome of classifying a SELECT's WHERE for the ordered-index route.
-enum MetadataRoute {
+pub(super) enum MetadataRoute {
     /// A non-metadata fetch (vector / similarity / sparse / graph / union) or a
     /// WHERE that yields no usable metadata filter → decline the route.
     Decline,
@@ -337,7 +339,7 @@ enum MetadataRoute {
 /// Whether the statement carries no clause — other than a metadata `WHERE`,
 /// handled separately — that changes the result shape (JOIN / DISTINCT /
 /// GROUP BY / HAVING / computed projection).
-fn plain_query_shape(stmt: &crate::velesql::SelectStatement) -> bool {
+pub(super) fn plain_query_shape(stmt: &crate::velesql::SelectStatement) -> bool {
     stmt.joins.is_empty()
         && stmt.distinct == crate::velesql::DistinctMode::None
         && stmt.group_by.is_none()
@@ -350,7 +352,7 @@ fn plain_query_shape(stmt: &crate::velesql::SelectStatement) -> bool {
 /// (`extract_metadata_filter`). Any ranked / graph / set-op fetch form — or a
 /// WHERE that yields no usable metadata filter — declines, so the route never
 /// silently drops a non-metadata predicate.
-fn route_metadata_filter(
+pub(super) fn route_metadata_filter(
     stmt: &crate::velesql::SelectStatement,
     extracted: &ExtractedComponents,
 ) -> MetadataRoute {
@@ -383,8 +385,8 @@ fn has_non_metadata_fetch(extracted: &ExtractedComponents) -> bool {
 /// list — so the fast path reproduces it exactly. The fast path returns its page
 /// directly (`mod.rs`), bypassing the post-processing stage that runs DISTINCT,
 /// **window functions** (`select_dispatch::evaluate`) and similarity scoring; any
-/// *computed* projection (aggregate, window function, `similarity()` score, or
-/// qualified wildcard) needs that bypassed stage and therefore disqualifies the
+/// *computed* projection (aggregate, window function, `similarity()` score,
+/// scalar expression, or qualified wildcard) needs that bypassed stage and therefore disqualifies the
 /// route. The `Mixed` arm names every field (no `..`) so a future computed field
 /// is a compile error here, not a silently-dropped projection.
 fn projection_is_plain(columns: &crate::velesql::SelectColumns) -> bool {
@@ -397,11 +399,13 @@ fn projection_is_plain(columns: &crate::velesql::SelectColumns) -> bool {
             similarity_scores,
             qualified_wildcards,
             window_functions,
+            expressions,
         } => {
             aggregations.is_empty()
                 && similarity_scores.is_empty()
                 && qualified_wildcards.is_empty()
                 && window_functions.is_empty()
+                && expressions.is_empty()
         }
         SelectColumns::Aggregations(_)
         | SelectColumns::SimilarityScore(_)
@@ -4,33 +4,31 @@
 //! - Metadata field sorting (ASC/DESC)
 //! - similarity() function sorting
 //! - Arithmetic expression sorting (EPIC-042)
+//! - `random()` shuffling (per-row random sort keys)
 //! - Mixed type JSON value comparison with total ordering
+//! - Per-key string collation (`ORDER BY name COLLATE ci_ai`)
 
+use super::scratch::Scratch;
 use crate::collection::types::Collection;
 use crate::error::Result;
+use crate::filter::SortCollation;
 use crate::point::SearchResult;
-use crate::velesql::{ArithmeticExpr, ArithmeticOp};
+use crate::velesql::{ArithmeticExpr, ArithmeticOp, ScalarFunction};
 use std::cmp::Ordering;
 
 /// Looks up a (possibly dotted) field path inside a JSON payload.
 ///
 /// A bare name (`"source"`) is a top-level lookup; a dotted path
-/// (`"meta.source"`) walks nested objects segment by segment. Returns `None`
-/// when any segment is missing or a non-object is traversed. Shared by ORDER BY
-/// field comparison and score-variable payload resolution so both honor the same
-/// nested-path semantics as projection/filter/aggregation.
+/// (`"meta.source"`) walks nested objects segment by segment, and numeric
+/// segments index arrays. Returns `None` when any segment is missing or a
+/// scalar is traversed. Shared by ORDER BY field comparison and score-variable
+/// payload resolution so both honor the same nested-path semantics as
+/// projection/filter/aggregation.
 fn get_nested_payload<'a>(
     payload: &'a serde_json::Value,
     path: &str,
 ) -> Option<&'a serde_json::Value> {
-    let mut current = payload;
-    for segment in path.split('.') {
-        match current {
-            serde_json::Value::Object(map) => current = map.get(segment)?,
-            _ => return None,
-        }
-    }
-    Some(current)
+    crate::filter::lookup_path(payload, path)
 }
 
 /// Compare two JSON values for sorting with total ordering.
@@ -41,6 +39,16 @@ fn get_nested_payload<'a>(
 pub fn compare_json_values(
     a: Option<&serde_json::Value>,
     b: Option<&serde_json::Value>,
+) -> Ordering {
+    compare_json_values_collated(a, b, SortCollation::Binary)
+}
+
+/// [`compare_json_values`] with strings ordered under `collation`.
+#[must_use]
+pub fn compare_json_values_collated(
+    a: Option<&serde_json::Value>,
+    b: Option<&serde_json::Value>,
+    collation: SortCollation,
 ) -> Ordering {
     match (a, b) {
         (None, None) => Ordering::Equal,
@@ -75,7 +83,9 @@ pub fn compare_json_values(
                     let fb = nb.as_f64().unwrap_or(0.0);
                     fa.total_cmp(&fb) // Use total_cmp for NaN safety
                 }
-                (serde_json::Value::String(sa), serde_json::Value::String(sb)) => sa.cmp(sb),
+                (serde_json::Value::String(sa), serde_json::Value::String(sb)) => {
+                    collation.compare(sa, sb)
+                }
                 (serde_json::Value::Bool(ba), serde_json::Value::Bool(bb)) => ba.cmp(bb),
                 // Null vs Null, Array vs Array, Object vs Object: treat as equal
                 // (comparing array/object contents would be complex and rarely needed)
@@ -125,7 +135,8 @@ impl Collection {
         let similarity_scores_map = self.precompute_similarity_scores(results, order_by, params)?;
         let higher_is_better = self.storage.config.read().metric.higher_is_better();
 
-        let mut indices: Vec<usize> = (0..results.len()).collect();
+        let mut indices = Scratch::<Vec<usize>>::with_capacity(results.len())?;
+        indices.extend(0..results.len());
         indices.sort_unstable_by(|&i, &j| {
             Self::compare_by_order_columns(
                 i,
@@ -164,7 +175,8 @@ impl Collection {
         Ok(())
     }
 
-    /// Pre-computes similarity scores for all ORDER BY similarity() columns.
+    /// Pre-computes similarity scores for all ORDER BY similarity() columns,
+    /// and one uniformly random key per row for each `random()` column.
     fn precompute_similarity_scores(
         &self,
         results: &[SearchResult],
@@ -186,6 +198,9 @@ impl Collection {
                     let scores: Vec<f32> = results.iter().map(|r| r.score).collect();
                     map.insert(idx, scores);
                 }
+                OrderByExpr::Random => {
+                    map.insert(idx, results.iter().map(|_| rand::random::<f32>()).collect());
+                }
                 OrderByExpr::Field(_) | OrderByExpr::Aggregate(_) | OrderByExpr::Arithmetic(_) => {}
             }
         }
@@ -245,12 +260,19 @@ impl Collection {
         use crate::velesql::OrderByExpr;
         for (idx, ob) in order_by.iter().enumerate() {
             let cmp = match &ob.expr {
-                OrderByExpr::Similarity(_) | OrderByExpr::SimilarityBare => similarity_scores
-                    .get(&idx)
-                    .map_or(Ordering::Equal, |scores| scores[i].total_cmp(&scores[j])),
-                OrderByExpr::Field(field_name) => {
-                    Self::compare_field_expr(field_name, i, j, results, per_result_let)
+                OrderByExpr::Similarity(_) | OrderByExpr::SimilarityBare | OrderByExpr::Random => {
+                    similarity_scores
+                        .get(&idx)
+                        .map_or(Ordering::Equal, |scores| scores[i].total_cmp(&scores[j]))
                 }
+                OrderByExpr::Field(field_name) => Self::compare_field_expr(
+                    field_name,
+                    ob.collation,
+                    i,
+                    j,
+                    results,
+                    per_result_let,
+                ),
                 OrderByExpr::Aggregate(_) => Ordering::Equal,
                 // Design: Arithmetic ORDER BY uses direct numeric ordering without
                 // distance-metric inversion. Users constructing custom formulas with
@@ -277,6 +299,7 @@ impl Collection {
     /// Compares a payload field value between two results.
     fn compare_payload_field(
         field_name: &str,
+        collation: SortCollation,
         i: usize,
         j: usize,
         results: &[SearchResult],
@@ -291,12 +314,13 @@ impl Collection {
             .payload
             .as_ref()
             .and_then(|p| get_nested_payload(p, field_name));
-        compare_json_values(val_i, val_j)
+        compare_json_values_collated(val_i, val_j, collation)
     }
 
     /// Compares a field name, checking LET bindings first, then payload.
     fn compare_field_or_let(
         field_name: &str,
+        collation: SortCollation,
         i: usize,
         j: usize,
         results: &[SearchResult],
@@ -311,7 +335,7 @@ impl Collection {
                 return vi.total_cmp(&vj);
             }
         }
-        Self::compare_payload_field(field_name, i, j, results)
+        Self::compare_payload_field(field_name, collation, i, j, results)
     }
 
     /// Compares a bare `ORDER BY` field. A built-in score variable
@@ -321,6 +345,7 @@ impl Collection {
     /// as an absent payload field. Anything else is a LET binding or payload.
     fn compare_field_expr(
         field_name: &str,
+        collation: SortCollation,
         i: usize,
         j: usize,
         results: &[SearchResult],
@@ -329,7 +354,7 @@ impl Collection {
         if is_builtin_score_variable(field_name) {
             Self::compare_score_variable(field_name, i, j, results, per_result_let)
         } else {
-            Self::compare_field_or_let(field_name, i, j, results, per_result_let)
+            Self::compare_field_or_let(field_name, collation, i, j, results, per_result_let)
         }
     }
 
@@ -635,7 +660,8 @@ const MAX_ARITHMETIC_DEPTH: u8 = 64;
 
 /// Evaluates an arithmetic expression against a score context (EPIC-042).
 ///
-/// Division by zero returns `0.0` (safe default for sorting).
+/// Division by zero returns `0.0` (safe default for sorting); string literals
+/// and `||`, which only scalar expressions produce, score `0.0` as well.
 /// Recursion depth is capped at [`MAX_ARITHMETIC_DEPTH`] to prevent stack overflow.
 pub(crate) fn evaluate_arithmetic(expr: &ArithmeticExpr, ctx: &ScoreContext<'_>) -> f32 {
     evaluate_arithmetic_inner(expr, ctx, 0)
@@ -658,6 +684,7 @@ fn evaluate_arithmetic_inner(expr: &ArithmeticExpr, ctx: &ScoreContext<'_>, dept
         // Only bare similarity() passes validation inside arithmetic expressions.
         // Parameterized similarity(field, $vec) is rejected at validation time (V008).
         ArithmeticExpr::Similarity(_) => ctx.search_score,
+        ArithmeticExpr::Text(_) => 0.0,
         ArithmeticExpr::BinaryOp { left, op, right } => {
             let l = evaluate_arithmetic_inner(left, ctx, depth + 1);
             let r = evaluate_arithmetic_inner(right, ctx, depth + 1);
@@ -672,6 +699,32 @@ fn evaluate_arithmetic_inner(expr: &ArithmeticExpr, ctx: &ScoreContext<'_>, dept
                         0.0
                     }
                 }
+                ArithmeticOp::Concat => 0.0,
+            }
+        }
+        ArithmeticExpr::Function { function, args } => {
+            let mut values = args
+                .iter()
+                .map(|arg| evaluate_arithmetic_inner(arg, ctx, depth + 1));
+            let x = values.next().unwrap_or(0.0);
+            match function {
+                ScalarFunction::Floor => x.floor(),
+                ScalarFunction::Ceil => x.ceil(),
+                ScalarFunction::Bucket => {
+                    let width = values.next().unwrap_or(0.0);
+                    if width > 0.0 {
+                        (x / width).floor() * width
+                    } else {
+                        0.0
+                    }
+                }
+                ScalarFunction::DateTrunc(unit) => {
+                    // Reason: score-context timestamps are f32 already; the
+                    // truncated value is returned at the same precision.
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+                    crate::filter::truncate_epoch_seconds(*unit, x.floor() as i64)
+                        .map_or(0.0, |secs| secs as f32)
+                }
             }
         }
     }
@@ -725,5 +725,48 @@ mod tests {
                 "highest weighted score (point 2) must rank first under DESC"
             );
         }
+
+        #[test]
+        fn test_order_by_collate_ci_ai_ignores_case_and_accents() {
+            let dir = tempfile::tempdir().expect("temp dir");
+            let col = Collection::create(PathBuf::from(dir.path()), 4, DistanceMetric::Cosine)
+                .expect("create collection");
+            let names = ["eric", "Zoé", "Émile", "adam", "Eve"];
+            let points: Vec<Point> = names
+                .iter()
+                .zip(1u64..)
+                .map(|(name, id)| Point {
+                    id,
+                    vector: vec![1.0, 0.0, 0.0, 0.0],
+                    payload: Some(serde_json::json!({ "name": name })),
+                    sparse_vectors: None,
+                })
+                .collect();
+            col.upsert(points).expect("upsert");
+            col.create_index("name").expect("create index");
+
+            let ordered = |sql: &str| -> Vec<String> {
+                let parsed = Parser::parse(sql).expect("parse");
+                col.execute_query(&parsed, &HashMap::new())
+                    .expect("execute")
+                    .iter()
+                    .map(|r| r.point.payload.as_ref().unwrap()["name"].to_string())
+                    .map(|name| name.trim_matches('"').to_string())
+                    .collect()
+            };
+
+            assert_eq!(
+                ordered("SELECT * FROM test ORDER BY name LIMIT 10"),
+                ["Eve", "Zoé", "adam", "eric", "Émile"]
+            );
+            assert_eq!(
+                ordered("SELECT * FROM test ORDER BY name COLLATE ci_ai LIMIT 10"),
+                ["adam", "Émile", "eric", "Eve", "Zoé"]
+            );
+            assert_eq!(
+                ordered("SELECT * FROM test ORDER BY name COLLATE ci DESC LIMIT 10"),
+                ["Émile", "Zoé", "Eve", "eric", "adam"]
+            );
+        }
     }
 }
@@ -38,12 +38,14 @@ fn project_single(result: &SearchResult, select_exprs: &SelectColumns) -> serde_
             similarity_scores,
             qualified_wildcards,
             window_functions,
+            expressions,
         } => project_mixed(
             result,
             columns,
             similarity_scores,
             qualified_wildcards,
             window_functions,
+            expressions,
         ),
     }
 }
@@ -91,11 +93,13 @@ fn project_similarity_only(result: &SearchResult, expr: &SimilarityScoreExpr) ->
     serde_json::Value::Object(map)
 }
 
-/// Mixed projection: columns + similarity scores + qualified wildcards + window functions.
+/// Mixed projection: columns + similarity scores + qualified wildcards + window
+/// functions + scalar expressions.
 ///
 /// Window function values were injected into the row's payload by
-/// [`crate::velesql::window_evaluator`]. The wildcard-expansion step below
-/// therefore must skip keys that correspond to window-function aliases —
+/// [`crate::velesql::window_evaluator`], and scalar expression values by
+/// `select_dispatch::inject_select_expressions`. The wildcard-expansion step
+/// below therefore must skip keys that correspond to those aliases —
 /// otherwise those values would be read from the payload twice (once by
 /// wildcard expansion, once by the explicit window-function loop). The final
 /// value would still be correct (the explicit loop wins), but the extra
@@ -106,12 +110,13 @@ fn project_mixed(
     similarity_scores: &[SimilarityScoreExpr],
     qualified_wildcards: &[String],
     window_functions: &[crate::velesql::WindowFunction],
+    expressions: &[crate::velesql::SelectExpression],
 ) -> serde_json::Value {
     let mut map = serde_json::Map::new();
 
-    // Pre-compute the set of window-function aliases so wildcard expansion
-    // can skip them in O(1) per payload key.
-    let window_aliases: rustc_hash::FxHashSet<&str> = window_functions
+    // Pre-compute the output names of every computed item (windows and
+    // expressions) so wildcard expansion can skip them in O(1) per payload key.
+    let window_aliases: Vec<&str> = window_functions
         .iter()
         .map(|wf| {
             wf.alias
@@ -119,28 +124,38 @@ fn project_mixed(
                 .unwrap_or(wf.function_type.default_alias())
         })
         .collect();
+    let expression_names: Vec<String> = expressions
+        .iter()
+        .map(crate::velesql::SelectExpression::output_name)
+        .collect();
+    let computed_keys: rustc_hash::FxHashSet<&str> = window_aliases
+        .iter()
+        .copied()
+        .chain(expression_names.iter().map(String::as_str))
+        .collect();
 
     if !qualified_wildcards.is_empty() {
-        insert_qualified_wildcards(&mut map, result, &window_aliases);
+        insert_qualified_wildcards(&mut map, result, &computed_keys);
     }
     insert_named_columns(&mut map, result, columns);
     insert_similarity_scores(&mut map, result, similarity_scores);
     insert_window_values(&mut map, result, window_functions);
+    insert_injected_values(&mut map, result, &expression_names);
 
     serde_json::Value::Object(map)
 }
 
 /// Expand a qualified wildcard (`c.*`) into id + every payload field, skipping
-/// any key shadowed by a window-function alias.
+/// any key shadowed by a window-function or expression alias.
 fn insert_qualified_wildcards(
     map: &mut serde_json::Map<String, serde_json::Value>,
     result: &SearchResult,
-    window_aliases: &rustc_hash::FxHashSet<&str>,
+    computed_keys: &rustc_hash::FxHashSet<&str>,
 ) {
     map.insert("id".to_string(), serde_json::Value::from(result.point.id));
     if let Some(serde_json::Value::Object(payload_map)) = result.point.payload.as_ref() {
         for (k, v) in payload_map {
-            if k != "id" && !window_aliases.contains(k.as_str()) {
+            if k != "id" && !computed_keys.contains(k.as_str()) {
                 map.insert(k.clone(), v.clone());
             }
         }
@@ -199,10 +214,30 @@ fn insert_window_values(
     }
 }
 
+/// Insert scalar expression values, injected into the payload under their
+/// output names by the SELECT post-processing stage.
+fn insert_injected_values(
+    map: &mut serde_json::Map<String, serde_json::Value>,
+    result: &SearchResult,
+    names: &[String],
+) {
+    for name in names {
+        let value = result
+            .point
+            .payload
+            .as_ref()
+            .and_then(|p| p.get(name))
+            .cloned()
+            .unwrap_or(serde_json::Value::Null);
+        map.insert(name.clone(), value);
+    }
+}
+
 /// Extracts a field value from a `SearchResult`, supporting nested paths.
 ///
 /// - `"title"` → `payload["title"]`
 /// - `"meta.source"` → `payload["meta"]["source"]`
+/// - `"items.0.sku"` → `payload["items"][0]["sku"]`
 /// - `"id"` → system point ID (takes precedence over payload)
 fn extract_field_value(result: &SearchResult, field_path: &str) -> serde_json::Value {
     if field_path == "id" {
@@ -213,22 +248,9 @@ fn extract_field_value(result: &SearchResult, field_path: &str) -> serde_json::V
         return serde_json::Value::Null;
     };
 
-    if field_path.contains('.') {
-        // Nested path traversal
-        let mut current = payload;
-        for segment in field_path.split('.') {
-            match current.get(segment) {
-                Some(next) => current = next,
-                None => return serde_json::Value::Null,
-            }
-        }
-        current.clone()
-    } else {
-        payload
-            .get(field_path)
-            .cloned()
-            .unwrap_or(serde_json::Value::Null)
-    }
+    crate::filter::lookup_path(payload, field_path)
+        .cloned()
+        .unwrap_or(serde_json::Value::Null)
 }
 
 #[cfg(test)]
@@ -344,6 +366,7 @@ mod tests {
             }],
             qualified_wildcards: vec![],
             window_functions: vec![],
+            expressions: vec![],
         };
         let projected = project_single(&result, &columns);
         let obj = projected.as_object().unwrap();
@@ -368,6 +391,7 @@ mod tests {
             }],
             qualified_wildcards: vec!["ctx".to_string()],
             window_functions: vec![],
+            expressions: vec![],
         };
         let projected = project_single(&result, &columns);
         let obj = projected.as_object().unwrap();
@@ -480,6 +504,7 @@ mod tests {
             similarity_scores: vec![],
             qualified_wildcards: vec!["docs".to_string()],
             window_functions: vec![],
+            expressions: vec![],
         };
         let projected = project_single(&result, &columns);
         let obj = projected.as_object().unwrap();
@@ -173,6 +173,15 @@ fn get_condition_source(
 
         Condition::GeoBbox(gb) => classify_column(&gb.column, graph_vars, join_tables),
 
+        Condition::Expression(ec) => ec
+            .left
+            .variables()
+            .into_iter()
+            .chain(ec.right.variables())
+            .map(|column| classify_column(column, graph_vars, join_tables))
+            .reduce(combine_sources)
+            .unwrap_or(Source::ColumnStore),
+
         // Graph pattern predicates and vector conditions are classified as Graph
         // because VelesDB stores embeddings in the collection/graph layer.
         Condition::GraphMatch(_)
@@ -4,7 +4,7 @@
 //! All methods here are `impl Collection` helpers used by the
 //! SELECT / MATCH execution pipeline.
 
-use super::options::{ExtractedComponents, QueryFinalizationContext, MAX_LIMIT};
+use super::options::{ExtractedComponents, QueryFinalizationContext};
 use super::vector_group_by;
 use crate::collection::types::Collection;
 use crate::error::Result;
@@ -71,19 +71,22 @@ impl Collection {
 
     /// Computes the effective `(limit, fetch_limit)` from a SELECT statement.
     ///
-    /// `limit` is the final row count requested by the user (capped at [`MAX_LIMIT`]);
+    /// `limit` is the final row count requested by the user, capped at
+    /// [`max_query_limit`](Self::max_query_limit);
     /// without an explicit LIMIT clause the engine default
     /// [`DEFAULT_SELECT_LIMIT`](crate::velesql::DEFAULT_SELECT_LIMIT) applies.
     /// `fetch_limit` adds the OFFSET so that post-processing can skip rows and still
     /// return `limit` results.
-    pub(super) fn compute_fetch_limit(stmt: &crate::velesql::SelectStatement) -> (usize, usize) {
+    pub(super) fn compute_fetch_limit(
+        &self,
+        stmt: &crate::velesql::SelectStatement,
+    ) -> (usize, usize) {
+        let max = self.max_query_limit();
         let limit = usize::try_from(stmt.limit.unwrap_or(crate::velesql::DEFAULT_SELECT_LIMIT))
-            .unwrap_or(MAX_LIMIT)
-            .min(MAX_LIMIT);
-        let offset_val = stmt
-            .offset
-            .map_or(0, |o| usize::try_from(o).unwrap_or(MAX_LIMIT));
-        let fetch_limit = limit.saturating_add(offset_val).min(MAX_LIMIT);
+            .unwrap_or(max)
+            .min(max);
+        let offset_val = stmt.offset.map_or(0, |o| usize::try_from(o).unwrap_or(max));
+        let fetch_limit = limit.saturating_add(offset_val).min(max);
         (limit, fetch_limit)
     }
 
@@ -168,6 +171,7 @@ impl Collection {
 
         crate::velesql::QueryValidator::validate(query)
             .map_err(|e| crate::error::Error::Query(e.to_string()))?;
+        self.check_query_limits(query)?;
 
         Ok(ctx)
     }
@@ -320,7 +324,6 @@ impl Collection {
     ///
     /// Extracts aggregation functions from the SELECT columns and delegates
     /// to [`vector_group_by::group_search_results`].
-    #[allow(clippy::unused_self)] // Instance method for consistency with other query pipeline stages.
     pub(super) fn apply_vector_group_by(
         &self,
         stmt: &crate::velesql::SelectStatement,
@@ -330,7 +333,9 @@ impl Collection {
             return results.to_vec();
         };
         let aggregations = Self::extract_aggregations(&stmt.columns);
-        let limit_hint = stmt.limit.map(|l| usize::try_from(l).unwrap_or(MAX_LIMIT));
+        let limit_hint = stmt
+            .limit
+            .map(|l| usize::try_from(l).unwrap_or_else(|_| self.max_query_limit()));
         let config = vector_group_by::VectorGroupByConfig {
             group_by_columns: &group_by.columns,
             aggregations: &aggregations,
@@ -339,6 +344,130 @@ impl Collection {
         vector_group_by::group_search_results(results, &config)
     }
 
+    /// Validates `WITH (metric = ...)`: a known metric name, on a query whose
+    /// only search predicate is one `NEAR`.
+    ///
+    /// Filtered, `similarity()`, fused, sparse and text searches score through
+    /// paths tied to the collection's metric, so the override is rejected
+    /// there rather than silently ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an unknown metric name or another query shape.
+    pub(super) fn check_metric_option(
+        stmt: &crate::velesql::SelectStatement,
+        extracted: &ExtractedComponents,
+    ) -> Result<()> {
+        let Some(value) = stmt.with_clause.as_ref().and_then(|w| w.get("metric")) else {
+            return Ok(());
+        };
+        if value
+            .as_str()
+            .and_then(crate::distance::DistanceMetric::parse_alias)
+            .is_none()
+        {
+            return Err(crate::error::Error::Query(format!(
+                "metric must be one of cosine, euclidean, dot, hamming, jaccard, got {value:?}"
+            )));
+        }
+        let residual = extracted.filter_condition.as_ref();
+        let single_near = extracted.vector_search.is_some()
+            && residual.and_then(Self::extract_metadata_filter).is_none()
+            && residual.and_then(Self::extract_match_query).is_none()
+            && !extracted.is_union_query
+            && extracted.similarity_conditions.is_empty()
+            && extracted.graph_match_predicates.is_empty()
+            && extracted.sparse_vector_search.is_none()
+            && extracted.fused_search.is_none()
+            && stmt.fusion_clause.is_none();
+        if single_near {
+            Ok(())
+        } else {
+            Err(crate::error::Error::Query(
+                "WITH (metric = ...) requires a WHERE clause made of a single vector NEAR \
+                 predicate (no other conditions, similarity(), NEAR_FUSED or fusion)"
+                    .to_string(),
+            ))
+        }
+    }
+
+    /// Metric the vector hits of `stmt` are scored with: the
+    /// `WITH (metric = ...)` override, else the collection's.
+    pub(super) fn ranking_metric(
+        &self,
+        stmt: &crate::velesql::SelectStatement,
+    ) -> crate::distance::DistanceMetric {
+        stmt.with_clause
+            .as_ref()
+            .and_then(crate::velesql::WithClause::get_metric)
+            .and_then(crate::distance::DistanceMetric::parse_alias)
+            .unwrap_or_else(|| self.storage.config.read().metric)
+    }
+
+    /// Reads the `WITH (per_group = n)` option of a vector `GROUP BY` query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the value isn't a positive integer.
+    pub(super) fn per_group_option(
+        stmt: &crate::velesql::SelectStatement,
+    ) -> Result<Option<usize>> {
+        let Some(value) = stmt.with_clause.as_ref().and_then(|w| w.get("per_group")) else {
+            return Ok(None);
+        };
+        match value.as_integer() {
+            Some(n) if n >= 1 => Ok(Some(usize::try_from(n).unwrap_or(usize::MAX))),
+            _ => Err(crate::error::Error::Query(format!(
+                "per_group must be a positive integer, got {value:?}"
+            ))),
+        }
+    }
+
+    /// Vector `GROUP BY ... WITH (per_group = n)`: keeps the `n` best hits of
+    /// each group instead of collapsing groups into one row.
+    ///
+    /// LIMIT and OFFSET count groups (ranked by their best hit) and are applied
+    /// here; the returned rows are flattened group by group, best first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the SELECT also asks for aggregates or ORDER BY,
+    /// which have no meaning on per-group hit lists.
+    pub(super) fn apply_vector_group_top_hits(
+        &self,
+        stmt: &crate::velesql::SelectStatement,
+        results: Vec<SearchResult>,
+        limit: usize,
+        per_group: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let Some(group_by) = stmt.group_by.as_ref() else {
+            return Ok(results);
+        };
+        if !Self::extract_aggregations(&stmt.columns).is_empty() || stmt.order_by.is_some() {
+            return Err(crate::error::Error::Query(
+                "WITH (per_group = n) returns the hits of each group; it cannot be combined \
+                 with aggregates or ORDER BY"
+                    .to_string(),
+            ));
+        }
+        let offset = stmt
+            .offset
+            .map_or(0, |o| usize::try_from(o).unwrap_or(usize::MAX));
+        let higher_is_better = self.storage.config.read().metric.higher_is_better();
+        let groups = super::super::grouped::group_top_hits(
+            results,
+            &group_by.columns,
+            offset.saturating_add(limit),
+            per_group,
+            higher_is_better,
+        );
+        Ok(groups
+            .into_iter()
+            .skip(offset)
+            .flat_map(|group| group.hits)
+            .collect())
+    }
+
     /// Extracts aggregate functions from `SelectColumns`.
     fn extract_aggregations(
         columns: &crate::velesql::SelectColumns,
@@ -0,0 +1,59 @@
+//! `ORDER BY random() [WHERE <metadata>] LIMIT n` fast path.
+//!
+//! A plain scan ordered by `random()` and cut at LIMIT is a uniform sample of
+//! the matching rows, so it is served by [`Collection::sample`] — which stops
+//! after `n` matches — instead of the exhaustive `max_query_limit` fetch followed by
+//! a shuffle. Every other `random()` shape (vector search, JOIN, GROUP BY, …)
+//! keeps the regular pipeline, where `random()` sorts by per-row random keys.
+
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::point::SearchResult;
+
+use super::ordered_index_scan::{
+    order_offset, plain_query_shape, route_metadata_filter, MetadataRoute,
+};
+use super::ExtractedComponents;
+
+impl Collection {
+    /// Attempts the sampling fast path. Returns `Ok(Some(results))` with the
+    /// finished OFFSET/LIMIT page when it fires, `Ok(None)` to fall through.
+    ///
+    /// Fires only when the sole ORDER BY key is `random()`, there are no LET
+    /// bindings, the statement has a plain shape (no JOIN / DISTINCT /
+    /// GROUP BY / computed projection) and the WHERE, if any, is pure
+    /// metadata. Results carry score 1.0 like the metadata-scan path.
+    pub(super) fn try_random_sample(
+        &self,
+        query: &crate::velesql::Query,
+        stmt: &crate::velesql::SelectStatement,
+        extracted: &ExtractedComponents,
+        ctx: &crate::guardrails::QueryContext,
+    ) -> Result<Option<Vec<SearchResult>>> {
+        let is_random_only = matches!(
+            stmt.order_by.as_deref(),
+            Some([key]) if key.expr == crate::velesql::OrderByExpr::Random
+        );
+        if !is_random_only || !query.let_bindings.is_empty() || !plain_query_shape(stmt) {
+            return Ok(None);
+        }
+        let filter = match route_metadata_filter(stmt, extracted) {
+            MetadataRoute::Decline => return Ok(None),
+            MetadataRoute::Plain => None,
+            MetadataRoute::Filtered(cond) => Some(self.where_filter(cond)),
+        };
+
+        let (limit, fetch_limit) = self.compute_fetch_limit(stmt);
+        let results: Vec<SearchResult> = self
+            .sample(fetch_limit, filter.as_ref(), None)
+            .into_iter()
+            .skip(order_offset(stmt))
+            .take(limit)
+            .map(|point| SearchResult::new(point, 1.0))
+            .collect();
+
+        self.check_guardrails_and_record(ctx, results.len())?;
+        self.runtime.guard_rails.circuit_breaker.record_success();
+        Ok(Some(results))
+    }
+}
@@ -0,0 +1,87 @@
+//! Tests for `ORDER BY random()` parsing and execution.
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::{Point, SearchResult};
+use crate::velesql::{OrderByExpr, Parser};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+fn setup_collection() -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 2, DistanceMetric::Cosine)
+        .expect("create collection");
+    let points: Vec<Point> = (0..50u64)
+        .map(|i| {
+            let kind = if i < 10 { "rare" } else { "common" };
+            #[allow(clippy::cast_precision_loss)]
+            let x = i as f32;
+            Point::new(i, vec![1.0, x], Some(json!({ "kind": kind })))
+        })
+        .collect();
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn run(col: &Collection, sql: &str) -> Vec<SearchResult> {
+    let parsed = Parser::parse(sql).expect("parse");
+    col.execute_query(&parsed, &HashMap::new())
+        .expect("execute")
+}
+
+#[test]
+fn test_parse_order_by_random() {
+    let parsed = Parser::parse("SELECT * FROM docs ORDER BY RANDOM() LIMIT 5").expect("parse");
+    let order_by = parsed.select.order_by.expect("order by");
+    assert_eq!(order_by.len(), 1);
+    assert_eq!(order_by[0].expr, OrderByExpr::Random);
+    assert!(!order_by[0].descending);
+}
+
+#[test]
+fn test_order_by_random_limit_returns_distinct_sample() {
+    let (_dir, col) = setup_collection();
+    let results = run(&col, "SELECT * FROM docs ORDER BY random() LIMIT 7");
+
+    assert_eq!(results.len(), 7);
+    let ids: HashSet<u64> = results.iter().map(|r| r.point.id).collect();
+    assert_eq!(ids.len(), 7);
+}
+
+#[test]
+fn test_order_by_random_with_metadata_filter() {
+    let (_dir, col) = setup_collection();
+    let results = run(
+        &col,
+        "SELECT * FROM docs WHERE kind = 'rare' ORDER BY random() LIMIT 20",
+    );
+
+    assert_eq!(results.len(), 10, "only ten rows match");
+    assert!(results.iter().all(|r| r.point.id < 10));
+}
+
+#[test]
+fn test_order_by_random_varies_between_runs() {
+    let (_dir, col) = setup_collection();
+    let sql = "SELECT * FROM docs ORDER BY random() LIMIT 10";
+    let first: Vec<u64> = run(&col, sql).iter().map(|r| r.point.id).collect();
+    let differs = (0..20).any(|_| {
+        let next: Vec<u64> = run(&col, sql).iter().map(|r| r.point.id).collect();
+        next != first
+    });
+    assert!(differs, "20 draws of 10 out of 50 should not all repeat");
+}
+
+#[test]
+fn test_order_by_random_after_vector_search_shuffles_candidates() {
+    let (_dir, col) = setup_collection();
+    let mut params = HashMap::new();
+    params.insert("v".to_string(), json!([1.0, 0.0]));
+    let parsed = Parser::parse("SELECT * FROM docs WHERE vector NEAR $v ORDER BY random() LIMIT 5")
+        .expect("parse");
+
+    let results = col.execute_query(&parsed, &params).expect("execute");
+    assert_eq!(results.len(), 5);
+}
@@ -9,6 +9,8 @@
 #![allow(clippy::cast_precision_loss)]
 #![allow(clippy::cast_possible_wrap)]
 
+use crate::point::SearchResult;
+
 /// Trait for boost functions that modify scores based on document metadata.
 pub trait BoostFunction: Send + Sync {
     /// Computes a boost multiplier for a document.
@@ -164,6 +166,72 @@ impl BoostFunction for FieldBoost {
     }
 }
 
+/// Logarithmic field boost: `1 + alpha * ln(1 + value)`.
+///
+/// Suited to unbounded popularity counters (views, likes, ratings count)
+/// whose raw values would swamp the similarity. Used by
+/// `USING FUSION field_boost(field = 'views', alpha = 0.2)`.
+#[derive(Debug, Clone)]
+pub struct LogFieldBoost {
+    /// Field containing a numeric value; dotted paths walk nested objects.
+    pub field: String,
+    /// Boost strength (0.0 = no boost).
+    pub alpha: f32,
+}
+
+impl LogFieldBoost {
+    /// Creates a new logarithmic field boost; a negative `alpha` reads as 0.
+    #[must_use]
+    pub fn new(field: impl Into<String>, alpha: f32) -> Self {
+        Self {
+            field: field.into(),
+            alpha: alpha.max(0.0),
+        }
+    }
+}
+
+impl BoostFunction for LogFieldBoost {
+    /// Missing, non-numeric and negative values give no boost.
+    fn compute(&self, document: &serde_json::Value) -> f32 {
+        let value = crate::filter::lookup_path(document, &self.field)
+            .and_then(serde_json::Value::as_f64)
+            .unwrap_or(0.0)
+            .max(0.0);
+        1.0 + self.alpha * value.ln_1p() as f32
+    }
+
+    fn name(&self) -> &'static str {
+        "log_field"
+    }
+}
+
+/// Multiplies each result's score by `factor(result)` and re-ranks.
+///
+/// For distance metrics (`higher_is_better == false`) the distance is
+/// divided by the factor instead, so a factor above 1 always ranks a result
+/// better and one below 1 worse. Distances are capped at `f32::MAX`.
+pub(crate) fn rescale_scores(
+    results: &mut [SearchResult],
+    higher_is_better: bool,
+    factor: impl Fn(&SearchResult) -> f64,
+) {
+    for result in results.iter_mut() {
+        let factor = factor(result);
+        let score = f64::from(result.score);
+        let rescaled = if higher_is_better {
+            score * factor
+        } else {
+            (score / factor).min(f64::from(f32::MAX))
+        };
+        result.score = rescaled as f32;
+    }
+    if higher_is_better {
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+    } else {
+        results.sort_by(|a, b| a.score.total_cmp(&b.score));
+    }
+}
+
 /// Strategy for combining multiple boost functions.
 #[derive(Debug, Clone, Copy, Default)]
 #[non_exhaustive]
@@ -16,12 +16,16 @@
 mod boost;
 mod explanation;
 mod path;
+mod search_result;
 
+pub(crate) use boost::rescale_scores;
 #[allow(unused_imports)] // Re-exported for test access
 pub(crate) use boost::BoostCombination;
-pub use boost::{BoostFunction, CompositeBoost, FieldBoost, RecencyBoost};
+pub use boost::{BoostFunction, CompositeBoost, FieldBoost, LogFieldBoost, RecencyBoost};
 pub use explanation::{ComponentExplanation, ScoreExplanation};
 pub use path::PathScorer;
+pub use search_result::ScoreSource;
+pub(crate) use search_result::{BM25_RAW_SCORE, VECTOR_RAW_SCORE};
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -72,6 +76,31 @@ pub struct ScoreBreakdown {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fusion_method: Option<String>,
 
+    /// Raw distance to the query vector under the collection metric
+    /// (`1 - similarity` for cosine/Jaccard, negated dot product for dot).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_distance: Option<f32>,
+
+    /// BM25 relevance score from the full-text branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bm25_score: Option<f32>,
+
+    /// Fusion weight applied to the vector branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector_weight: Option<f32>,
+
+    /// Fusion weight applied to the text branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_weight: Option<f32>,
+
+    /// Share of `final_score` contributed by the vector branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector_contribution: Option<f32>,
+
+    /// Share of `final_score` contributed by the text branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_contribution: Option<f32>,
+
     /// Final combined score after fusion.
     pub final_score: f32,
 }
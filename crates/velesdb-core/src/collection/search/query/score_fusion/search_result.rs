@@ -0,0 +1,98 @@
+//! Per-hit score breakdown for search responses (`explain_scores`).
+//!
+//! Search paths tag each [`SearchResult`] with the component scores that
+//! produced it; this module maps them onto a [`ScoreBreakdown`], reading the
+//! raw score according to the retrieval that ran.
+
+use super::{ScoreBreakdown, ScoreFusionMethod};
+use crate::distance::DistanceMetric;
+use crate::point::SearchResult;
+
+/// Component name of a hybrid hit's raw vector-branch score.
+pub(crate) const VECTOR_RAW_SCORE: &str = "vector_raw_score";
+/// Component name of a hybrid hit's raw BM25 score.
+pub(crate) const BM25_RAW_SCORE: &str = "bm25_raw_score";
+
+/// Retrieval that produced a [`SearchResult`], used to interpret its score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreSource {
+    /// Dense vector search scored with the given metric.
+    Dense(DistanceMetric),
+    /// BM25 full-text search.
+    Text,
+    /// Sparse vector search (inner product).
+    Sparse,
+    /// Vector + BM25 hybrid search fused with weighted RRF.
+    Hybrid {
+        /// Metric of the vector branch.
+        metric: DistanceMetric,
+        /// Vector weight passed to the search (text weight is `1 - w`).
+        vector_weight: f32,
+    },
+    /// Dense + sparse search fused by a rank/score fusion strategy; only the
+    /// fused score is known per hit.
+    Fused,
+}
+
+impl ScoreBreakdown {
+    /// Builds the score breakdown of a search hit.
+    ///
+    /// Uses the component scores the search path tagged onto `result`; an
+    /// untagged hit of a single-signal search falls back to `result.score`.
+    #[must_use]
+    pub fn from_search_result(result: &SearchResult, source: ScoreSource) -> Self {
+        let component = |name: &str| {
+            result
+                .component_scores
+                .as_deref()?
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|&(_, score)| score)
+        };
+        let mut breakdown = Self {
+            final_score: result.score,
+            ..Self::default()
+        };
+        match source {
+            ScoreSource::Dense(metric) => {
+                breakdown
+                    .set_vector_score(metric, component("vector_score").unwrap_or(result.score));
+            }
+            ScoreSource::Text => {
+                breakdown.bm25_score = Some(component("bm25_score").unwrap_or(result.score));
+            }
+            ScoreSource::Sparse => breakdown.sparse_score = Some(result.score),
+            ScoreSource::Hybrid {
+                metric,
+                vector_weight,
+            } => {
+                // Mirrors the clamp `hybrid_search` applies to the weight.
+                let vector_weight = vector_weight.clamp(0.0, 1.0);
+                breakdown.fusion_method = Some(ScoreFusionMethod::Rrf.as_str().to_string());
+                breakdown.vector_weight = Some(vector_weight);
+                breakdown.text_weight = Some(1.0 - vector_weight);
+                breakdown.vector_contribution = component("vector_score");
+                breakdown.text_contribution = component("bm25_score");
+                if let Some(raw) = component(VECTOR_RAW_SCORE) {
+                    breakdown.set_vector_score(metric, raw);
+                }
+                breakdown.bm25_score = component(BM25_RAW_SCORE);
+            }
+            ScoreSource::Fused => {}
+        }
+        breakdown
+    }
+
+    /// Records a raw metric score as both a distance and a similarity.
+    ///
+    /// Distance metrics map to similarity as `1 / (1 + d)`.
+    fn set_vector_score(&mut self, metric: DistanceMetric, score: f32) {
+        let (distance, similarity) = match metric {
+            DistanceMetric::Cosine | DistanceMetric::Jaccard => (1.0 - score, score),
+            DistanceMetric::DotProduct => (-score, score),
+            DistanceMetric::Euclidean | DistanceMetric::Hamming => (score, 1.0 / (1.0 + score)),
+        };
+        self.raw_distance = Some(distance);
+        self.vector_similarity = Some(similarity);
+    }
+}
@@ -529,3 +529,54 @@ fn test_explanation_json_serialization() {
     assert!(json.contains("components"));
     assert!(json.contains("human_readable"));
 }
+
+// =========================================================================
+// ScoreBreakdown::from_search_result (explain_scores)
+// =========================================================================
+
+fn hit(score: f32, components: &[(&'static str, f32)]) -> crate::point::SearchResult {
+    let point = crate::point::Point::without_payload(1, vec![0.0; 2]);
+    if components.is_empty() {
+        crate::point::SearchResult::new(point, score)
+    } else {
+        crate::point::SearchResult::with_component_scores(point, score, components.into())
+    }
+}
+
+#[test]
+fn test_from_search_result_dense_similarity_metric() {
+    let breakdown = ScoreBreakdown::from_search_result(
+        &hit(0.8, &[("vector_score", 0.8)]),
+        ScoreSource::Dense(crate::DistanceMetric::Cosine),
+    );
+    assert_eq!(breakdown.vector_similarity, Some(0.8));
+    assert!((breakdown.raw_distance.unwrap() - 0.2).abs() < 1e-6);
+    assert_eq!(breakdown.final_score, 0.8);
+    assert!(breakdown.fusion_method.is_none());
+}
+
+#[test]
+fn test_from_search_result_dense_distance_metric_normalizes() {
+    // Untagged hit: the raw score itself is the Euclidean distance.
+    let breakdown = ScoreBreakdown::from_search_result(
+        &hit(3.0, &[]),
+        ScoreSource::Dense(crate::DistanceMetric::Euclidean),
+    );
+    assert_eq!(breakdown.raw_distance, Some(3.0));
+    assert_eq!(breakdown.vector_similarity, Some(0.25));
+}
+
+#[test]
+fn test_from_search_result_text_and_sparse() {
+    let text =
+        ScoreBreakdown::from_search_result(&hit(4.2, &[("bm25_score", 4.2)]), ScoreSource::Text);
+    assert_eq!(text.bm25_score, Some(4.2));
+    assert!(text.vector_similarity.is_none());
+
+    let sparse = ScoreBreakdown::from_search_result(&hit(1.5, &[]), ScoreSource::Sparse);
+    assert_eq!(sparse.sparse_score, Some(1.5));
+
+    let fused = ScoreBreakdown::from_search_result(&hit(0.03, &[]), ScoreSource::Fused);
+    let json = serde_json::to_value(&fused).unwrap();
+    assert_eq!(json, serde_json::json!({ "final_score": 0.03_f32 }));
+}
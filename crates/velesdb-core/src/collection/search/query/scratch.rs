@@ -0,0 +1,179 @@
+//! Per-thread scratch buffers for VelesQL query execution.
+//!
+//! The HNSW layer already reuses its heaps and visited sets through
+//! thread-local pools (`search_pools.rs`). The layers above it — filtering,
+//! ORDER BY, DISTINCT — used to allocate fresh index, id and key buffers on
+//! every query. [`Scratch`] hands those buffers out from the same kind of
+//! per-thread pool and puts them back, cleared, when dropped, so a thread
+//! serving a steady stream of queries stops hitting the allocator for them.
+//!
+//! Retention is bounded twice: each pool keeps at most [`POOL_MAX`] buffers
+//! and a buffer whose capacity grew past [`MAX_RETAINED_BYTES`] (one huge
+//! query) is freed rather than pooled. Reservations go through
+//! [`check_alloc_bound`](crate::alloc_guard::check_alloc_bound) first, so an
+//! absurd size is an error instead of an abort.
+
+use std::cell::{Cell, RefCell};
+use std::ops::{Deref, DerefMut};
+use std::thread::LocalKey;
+
+use rustc_hash::FxHashSet;
+
+use crate::error::Result;
+
+/// Maximum number of pooled buffers retained per type per thread.
+///
+/// Query execution nests at most a few levels (compound operands,
+/// subqueries), so a handful per thread covers the steady state.
+pub(crate) const POOL_MAX: usize = 4;
+
+/// Buffers whose capacity exceeds this many bytes are freed on release
+/// instead of pooled: 4 MiB holds 512K ids.
+pub(crate) const MAX_RETAINED_BYTES: usize = 4 * 1024 * 1024;
+
+/// A buffer type that can live in a per-thread scratch pool.
+pub(crate) trait ScratchBuffer: Default + 'static {
+    /// The thread-local pool this type is recycled through.
+    fn pool() -> &'static LocalKey<RefCell<Vec<Self>>>;
+    /// Empties the buffer, keeping its capacity.
+    fn reset(&mut self);
+    /// Bytes currently held by the buffer's allocation.
+    fn retained_bytes(&self) -> usize;
+}
+
+thread_local! {
+    static INDEX_POOL: RefCell<Vec<Vec<usize>>> = const { RefCell::new(Vec::new()) };
+    static ID_POOL: RefCell<Vec<Vec<u64>>> = const { RefCell::new(Vec::new()) };
+    static SCORE_POOL: RefCell<Vec<Vec<f32>>> = const { RefCell::new(Vec::new()) };
+    static KEY_SET_POOL: RefCell<Vec<FxHashSet<String>>> = const { RefCell::new(Vec::new()) };
+    /// Buffers handed out from a pool (`.0`) vs. freshly created (`.1`).
+    static STATS: Cell<(u64, u64)> = const { Cell::new((0, 0)) };
+}
+
+impl ScratchBuffer for Vec<usize> {
+    fn pool() -> &'static LocalKey<RefCell<Vec<Self>>> {
+        &INDEX_POOL
+    }
+    fn reset(&mut self) {
+        self.clear();
+    }
+    fn retained_bytes(&self) -> usize {
+        self.capacity() * std::mem::size_of::<usize>()
+    }
+}
+
+impl ScratchBuffer for Vec<u64> {
+    fn pool() -> &'static LocalKey<RefCell<Vec<Self>>> {
+        &ID_POOL
+    }
+    fn reset(&mut self) {
+        self.clear();
+    }
+    fn retained_bytes(&self) -> usize {
+        self.capacity() * std::mem::size_of::<u64>()
+    }
+}
+
+impl ScratchBuffer for Vec<f32> {
+    fn pool() -> &'static LocalKey<RefCell<Vec<Self>>> {
+        &SCORE_POOL
+    }
+    fn reset(&mut self) {
+        self.clear();
+    }
+    fn retained_bytes(&self) -> usize {
+        self.capacity() * std::mem::size_of::<f32>()
+    }
+}
+
+impl ScratchBuffer for FxHashSet<String> {
+    fn pool() -> &'static LocalKey<RefCell<Vec<Self>>> {
+        &KEY_SET_POOL
+    }
+    fn reset(&mut self) {
+        // Drops the keys (owned strings) but keeps the table.
+        self.clear();
+    }
+    fn retained_bytes(&self) -> usize {
+        self.capacity() * std::mem::size_of::<String>()
+    }
+}
+
+/// A pooled buffer, returned to its thread's pool on drop.
+pub(crate) struct Scratch<T: ScratchBuffer> {
+    buf: T,
+}
+
+impl<T: ScratchBuffer> Scratch<T> {
+    /// Takes an empty buffer from the current thread's pool, or creates one.
+    pub(crate) fn take() -> Self {
+        let pooled = T::pool().with(|pool| pool.borrow_mut().pop());
+        STATS.with(|s| {
+            let (reused, created) = s.get();
+            s.set(if pooled.is_some() {
+                (reused + 1, created)
+            } else {
+                (reused, created + 1)
+            });
+        });
+        Self {
+            buf: pooled.unwrap_or_default(),
+        }
+    }
+}
+
+impl<T> Scratch<Vec<T>>
+where
+    Vec<T>: ScratchBuffer,
+{
+    /// Takes a buffer with room for at least `additional` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AllocationFailed`](crate::error::Error::AllocationFailed)
+    /// if the reservation exceeds the [`alloc_byte_limit`](crate::alloc_guard::alloc_byte_limit).
+    pub(crate) fn with_capacity(additional: usize) -> Result<Self> {
+        crate::alloc_guard::check_alloc_bound(additional.saturating_mul(std::mem::size_of::<T>()))?;
+        let mut scratch = Self::take();
+        scratch.buf.reserve(additional);
+        Ok(scratch)
+    }
+}
+
+impl<T: ScratchBuffer> Deref for Scratch<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.buf
+    }
+}
+
+impl<T: ScratchBuffer> DerefMut for Scratch<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.buf
+    }
+}
+
+impl<T: ScratchBuffer> Drop for Scratch<T> {
+    fn drop(&mut self) {
+        let mut buf = std::mem::take(&mut self.buf);
+        if buf.retained_bytes() > MAX_RETAINED_BYTES {
+            return;
+        }
+        buf.reset();
+        // `try_with`: the pool may already be gone during thread teardown.
+        let _ = T::pool().try_with(|pool| {
+            let mut pool = pool.borrow_mut();
+            if pool.len() < POOL_MAX {
+                pool.push(buf);
+            }
+        });
+    }
+}
+
+/// Returns `(reused, created)`: how many scratch buffers this thread took
+/// from a pool vs. allocated fresh since it started.
+#[cfg(test)]
+pub(crate) fn thread_stats() -> (u64, u64) {
+    STATS.with(Cell::get)
+}
@@ -0,0 +1,103 @@
+//! Tests for the per-thread query scratch pools.
+#![cfg(all(test, feature = "persistence"))]
+
+use super::scratch::{thread_stats, Scratch, MAX_RETAINED_BYTES, POOL_MAX};
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+use crate::velesql::Parser;
+use rustc_hash::FxHashSet;
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[test]
+fn test_released_buffer_is_reused_empty_with_capacity() {
+    let ptr = {
+        let mut ids = Scratch::<Vec<u64>>::take();
+        ids.extend(0..1000);
+        ids.as_ptr()
+    };
+    let ids = Scratch::<Vec<u64>>::take();
+    assert!(ids.is_empty());
+    assert!(ids.capacity() >= 1000);
+    assert_eq!(
+        ids.as_ptr(),
+        ptr,
+        "the pooled allocation is handed out again"
+    );
+}
+
+#[test]
+fn test_nested_takes_get_distinct_buffers() {
+    let mut outer = Scratch::<Vec<usize>>::take();
+    outer.push(1);
+    let inner = Scratch::<Vec<usize>>::take();
+    assert!(inner.is_empty());
+    assert_eq!(outer.len(), 1);
+}
+
+#[test]
+fn test_pool_retention_is_bounded() {
+    let held: Vec<_> = (0..POOL_MAX + 3)
+        .map(|_| {
+            let mut keys = Scratch::<FxHashSet<String>>::take();
+            keys.insert("k".to_string());
+            keys
+        })
+        .collect();
+    drop(held);
+    let (reused_before, _) = thread_stats();
+    let again: Vec<_> = (0..POOL_MAX + 3)
+        .map(|_| Scratch::<FxHashSet<String>>::take())
+        .collect();
+    let (reused_after, _) = thread_stats();
+    assert_eq!(reused_after - reused_before, POOL_MAX as u64);
+    assert!(again.iter().all(|set| set.is_empty()));
+}
+
+#[test]
+fn test_oversized_buffer_is_freed_not_pooled() {
+    let huge = MAX_RETAINED_BYTES / std::mem::size_of::<f32>() + 1;
+    drop(Scratch::<Vec<f32>>::with_capacity(huge).expect("within alloc bound"));
+    let scores = Scratch::<Vec<f32>>::take();
+    assert!(scores.capacity() < huge);
+}
+
+#[test]
+fn test_with_capacity_rejects_absurd_reservation() {
+    assert!(Scratch::<Vec<u64>>::with_capacity(usize::MAX / 2).is_err());
+}
+
+#[test]
+fn test_repeated_queries_reuse_scratch() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 2, DistanceMetric::Cosine)
+        .expect("create collection");
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<Point> = (0..20u64)
+        .map(|i| Point::new(i, vec![1.0, i as f32], Some(json!({"g": i % 3}))))
+        .collect();
+    col.upsert(points).expect("upsert");
+    let query = Parser::parse("SELECT DISTINCT g FROM c ORDER BY g DESC LIMIT 10").expect("parse");
+    let params = HashMap::new();
+
+    let first = col.execute_query(&query, &params).expect("first run");
+    let (reused_before, created_before) = thread_stats();
+    for _ in 0..5 {
+        let again = col.execute_query(&query, &params).expect("repeat run");
+        assert_eq!(
+            again.iter().map(|r| r.point.id).collect::<Vec<_>>(),
+            first.iter().map(|r| r.point.id).collect::<Vec<_>>()
+        );
+    }
+    let (reused_after, created_after) = thread_stats();
+    assert!(
+        reused_after > reused_before,
+        "ORDER BY / DISTINCT take scratch"
+    );
+    assert_eq!(
+        created_after, created_before,
+        "warm queries must not allocate new scratch buffers"
+    );
+}
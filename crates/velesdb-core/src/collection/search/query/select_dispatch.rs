@@ -10,7 +10,7 @@ use crate::collection::types::Collection;
 use crate::error::Result;
 use crate::point::SearchResult;
 
-use super::{distinct, pushdown, ExtractedComponents, MAX_LIMIT};
+use super::{distinct, pushdown, ExtractedComponents};
 
 impl Collection {
     /// Computes the CBO execution strategy and over-fetch factor for the query.
@@ -206,11 +206,13 @@ impl Collection {
                 .where_clause
                 .as_ref()
                 .is_some_and(Self::condition_contains_or);
-        let execution_limit = main_select_execution_limit(stmt, extracted, limit);
+        let execution_limit =
+            main_select_execution_limit(stmt, extracted, limit, self.max_query_limit());
         let search_opts = super::QuerySearchOptions::from_with_clause(stmt.with_clause.as_ref())
             .with_fusion(stmt.fusion_clause.clone());
         let (cbo_strategy, cbo_over_fetch) =
             self.compute_cbo_strategy(stmt, extracted.filter_condition.as_ref(), limit);
+        let (cbo_strategy, cbo_over_fetch) = search_opts.hints.apply(cbo_strategy, cbo_over_fetch);
 
         // GraphFirst by anchor ids: AND-required MATCH predicates are
         // evaluated FIRST so retrieval is exhaustive within the graph
@@ -221,8 +223,8 @@ impl Collection {
         // fetch_anchor_candidates must see EVERY anchor — the similarity
         // sort only runs downstream, so a bounded window would drop the
         // most-similar anchors by ascending-id order (see anchor_fetch_limit).
-        let mut graph_cache = super::where_eval::GraphMatchEvalCache::default();
-        let anchor_fetch_limit = anchor_fetch_limit(stmt, extracted, limit);
+        let mut graph_cache = super::where_eval::GraphMatchEvalCache::new(self.default_collation());
+        let anchor_fetch_limit = anchor_fetch_limit(stmt, extracted, limit, self.max_query_limit());
         let anchored = self.try_anchored_fetch(
             stmt,
             params,
@@ -450,12 +452,13 @@ impl Collection {
         analysis
     }
 
-    /// Applies DISTINCT, window functions, ORDER BY (with LET bindings), OFFSET, LIMIT, and
-    /// LET payload injection (Issue #473).
+    /// Applies computed SELECT expressions, DISTINCT, window functions, ORDER BY (with LET
+    /// bindings), OFFSET, LIMIT, and LET payload injection (Issue #473).
     ///
     /// # Pipeline order and its SQL-standard deviation
     ///
-    /// VelesQL runs `DISTINCT → window functions → ORDER BY → OFFSET/LIMIT`.
+    /// VelesQL runs `expressions → DISTINCT → window functions → ORDER BY →
+    /// OFFSET/LIMIT`.
     /// Standard SQL runs window functions **before** DISTINCT (logical order
     /// `SELECT → DISTINCT → ORDER BY`). This is an **intentional deviation**
     /// tailored to the vector-search use case:
@@ -480,6 +483,9 @@ impl Collection {
         limit: usize,
         let_bindings: &[crate::velesql::LetBinding],
     ) -> Result<Vec<SearchResult>> {
+        // Step 0: computed SELECT expressions, so DISTINCT, window functions
+        // and ORDER BY can all refer to them by output name.
+        inject_select_expressions(&mut results, &stmt.columns);
         // Step 1: DISTINCT — deduplication before any ranking (see pipeline
         // order contract in the doc comment above).
         if stmt.distinct == crate::velesql::DistinctMode::All {
@@ -575,7 +581,7 @@ impl Collection {
 ///
 /// Unranked metadata/scan fetches must NOT use this bound: they iterate in
 /// storage order, so a capped window would silently drop graph matches based
-/// on insertion order (those paths keep `MAX_LIMIT`, the pre-existing
+/// on insertion order (those paths keep `max_query_limit`, the pre-existing
 /// behavior).
 /// Whether the GraphFirst anchored fetch covers this query shape: graph
 /// predicates present, and no similarity() cascade or BM25 text-MATCH
@@ -595,7 +601,7 @@ fn anchored_fetch_applies(
 /// (vector NEAR or similarity() threshold): "rows ranked beyond the window"
 /// is a meaningful trade-off there. The metadata/scan paths fetch in storage
 /// order — capping them would silently drop graph matches depending on
-/// insertion order, so they keep the exhaustive MAX_LIMIT window (sparse
+/// insertion order, so they keep the exhaustive `max_query_limit` window (sparse
 /// queries never reach here; they are dispatched by `try_early_return_path`).
 /// This window only applies when the GraphFirst anchored fetch declined
 /// (`try_anchored_fetch`).
@@ -603,6 +609,7 @@ fn main_select_execution_limit(
     stmt: &crate::velesql::SelectStatement,
     extracted: &ExtractedComponents,
     limit: usize,
+    max: usize,
 ) -> usize {
     // A scalar (non-similarity) ORDER BY ranks rows AFTER the fetch, in
     // `apply_select_postprocessing`. Capping the fetch at `limit` would
@@ -611,14 +618,22 @@ fn main_select_execution_limit(
     // (KNOWN_LIMITATIONS #9: bounded results must equal the unbounded path
     // truncated to k). Fetch exhaustively so the sort precedes truncation.
     if Collection::order_by_requires_exhaustive_fetch(stmt) {
-        return MAX_LIMIT;
+        return max;
     }
     let has_graph_predicates = !extracted.graph_match_predicates.is_empty();
     let has_ranked_fetch =
         extracted.vector_search.is_some() || !extracted.similarity_conditions.is_empty();
+    // Window functions number the fetched rows. An unranked fetch returns an
+    // arbitrary storage-order slice, so ROW_NUMBER/RANK over a `limit`-sized
+    // window would be computed against the wrong population; rank the whole
+    // filtered set and truncate afterwards. A ranked fetch keeps `limit`:
+    // numbering the top-k neighbours is the intended vector-search shape.
+    if !has_ranked_fetch && Collection::extract_window_functions(&stmt.columns).is_some() {
+        return max;
+    }
     match (has_graph_predicates, has_ranked_fetch) {
         (true, true) => graph_overfetch_limit(limit),
-        (true, false) => MAX_LIMIT,
+        (true, false) => max,
         (false, _) => limit,
     }
 }
@@ -635,7 +650,7 @@ fn graph_overfetch_limit(limit: usize) -> usize {
 /// order and stops at the window, while the similarity sort only runs
 /// downstream in `apply_order_by` — any bounded window therefore drops the
 /// most-similar anchors whenever the anchor set is larger than the window.
-/// `MAX_LIMIT` is the same exhaustive window the unranked graph paths use.
+/// `max_query_limit` is the same exhaustive window the unranked graph paths use.
 /// Without ORDER BY similarity() the plain `limit` stays (nothing ranked to
 /// protect); with a NEAR vector the anchored search ranks inside the anchor
 /// set already.
@@ -643,21 +658,62 @@ fn anchor_fetch_limit(
     stmt: &crate::velesql::SelectStatement,
     extracted: &ExtractedComponents,
     limit: usize,
+    max: usize,
 ) -> usize {
     // A scalar ORDER BY ranks downstream, so the anchored fetch (which
     // hydrates anchors in ascending-id order) must be exhaustive too —
     // otherwise the ascending-id window drops rows the ORDER BY key would
     // have surfaced. Mirrors `main_select_execution_limit`.
     if Collection::order_by_requires_exhaustive_fetch(stmt) {
-        return MAX_LIMIT;
+        return max;
     }
-    if Collection::has_order_by_similarity(stmt) && extracted.vector_search.is_none() {
-        MAX_LIMIT
+    // Window functions over an anchored scan need every anchor for the same
+    // reason (see `main_select_execution_limit`).
+    if extracted.vector_search.is_none()
+        && (Collection::has_order_by_similarity(stmt)
+            || Collection::extract_window_functions(&stmt.columns).is_some())
+    {
+        max
     } else {
         limit
     }
 }
 
+/// Evaluates computed SELECT expressions (`price * 1.2 AS price_with_tax`)
+/// and injects them into each result's payload under their output names.
+///
+/// Every expression is evaluated against the stored payload before any value
+/// is injected, so an expression never sees another expression's output. The
+/// output name takes precedence over a payload field with the same name.
+pub(super) fn inject_select_expressions(
+    results: &mut [SearchResult],
+    columns: &crate::velesql::SelectColumns,
+) {
+    let crate::velesql::SelectColumns::Mixed { expressions, .. } = columns else {
+        return;
+    };
+    if expressions.is_empty() {
+        return;
+    }
+    let names: Vec<String> = expressions
+        .iter()
+        .map(crate::velesql::SelectExpression::output_name)
+        .collect();
+    for result in results.iter_mut() {
+        let payload = result
+            .point
+            .payload
+            .get_or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        let values: Vec<serde_json::Value> = expressions
+            .iter()
+            .map(|e| crate::filter::evaluate_expression(&e.expr, payload))
+            .collect();
+        if let serde_json::Value::Object(map) = payload {
+            map.extend(names.iter().cloned().zip(values));
+        }
+    }
+}
+
 /// Injects evaluated LET binding values into each result's payload.
 ///
 /// This makes LET bindings visible in SELECT projection and API responses.
@@ -0,0 +1,113 @@
+//! Execution tests for scalar expressions in WHERE and SELECT.
+//!
+//! Covers filtering on computed values, SELECT-list injection under the
+//! alias (or expression text), ORDER BY on an expression alias, and
+//! projection of the computed values.
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::{Point, SearchResult};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+fn setup_orders() -> (TempDir, Collection) {
+    let dir = TempDir::new().unwrap();
+    let col = Collection::create(dir.path().join("orders"), 2, DistanceMetric::Cosine)
+        .expect("create collection");
+    let rows = [
+        (1u64, 250, 2, "Ada", "Lovelace"),
+        (2, 120, 10, "Alan", "Turing"),
+        (3, 999, 1, "Grace", "Hopper"),
+        (4, 40, 35, "Edsger", "Dijkstra"),
+    ];
+    let points: Vec<Point> = rows
+        .iter()
+        .map(|&(id, price, quantity, first, last)| Point {
+            id,
+            vector: vec![1.0, 0.0],
+            payload: Some(serde_json::json!({
+                "price": price,
+                "quantity": quantity,
+                "first": first,
+                "last": last,
+            })),
+            sparse_vectors: None,
+        })
+        .collect();
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn run(col: &Collection, sql: &str) -> Vec<SearchResult> {
+    col.execute_query_str(sql, &HashMap::new())
+        .unwrap_or_else(|e| panic!("{sql}: {e}"))
+}
+
+fn ids(results: &[SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+#[test]
+fn test_where_filters_on_product_of_fields() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE price * quantity > 1000 ORDER BY id",
+    );
+    assert_eq!(ids(&results), vec![2, 4]);
+}
+
+#[test]
+fn test_where_expression_combines_with_plain_predicates() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE price * quantity >= 500 AND quantity < 5 ORDER BY id",
+    );
+    assert_eq!(ids(&results), vec![1, 3]);
+}
+
+#[test]
+fn test_where_string_concatenation() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT * FROM orders WHERE first || ' ' || last = 'Grace Hopper'",
+    );
+    assert_eq!(ids(&results), vec![3]);
+}
+
+#[test]
+fn test_select_expression_is_injected_under_alias() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT id, price * 1.5 AS price_with_tax FROM orders WHERE first = 'Alan'",
+    );
+    let payload = results[0].point.payload.as_ref().expect("payload");
+    assert_eq!(payload["price_with_tax"], serde_json::json!(180.0));
+}
+
+#[test]
+fn test_order_by_expression_alias() {
+    let (_dir, col) = setup_orders();
+    let results = run(
+        &col,
+        "SELECT id, price * quantity AS total FROM orders ORDER BY total DESC",
+    );
+    assert_eq!(ids(&results), vec![4, 2, 3, 1]);
+}
+
+#[test]
+fn test_projection_emits_expression_values() {
+    let (_dir, col) = setup_orders();
+    let sql =
+        "SELECT id, first || ' ' || last AS name, quantity - 1 FROM orders WHERE first = 'Ada'";
+    let query = crate::velesql::Parser::parse(sql).expect("parse");
+    let results = run(&col, sql);
+    let rows = super::projection::project_results(&results, &query.select.columns);
+    assert_eq!(
+        rows[0],
+        serde_json::json!({ "id": 1, "name": "Ada Lovelace", "quantity - 1": 1 })
+    );
+}
@@ -1,36 +1,100 @@
 //! Set operation execution for compound queries (UNION, INTERSECT, EXCEPT).
 //!
 //! Implements SQL-standard set semantics on `SearchResult` vectors, keyed by
-//! point ID. Each operator follows the scoring rules documented below.
+//! point ID. Each operator follows the scoring rules documented below; how
+//! UNION and INTERSECT combine the two scores of an id both operands return
+//! is chosen with `WITH (set_score = ...)` on the leading SELECT
+//! ([`ScoreMerge`]).
 
 use std::collections::HashMap;
 
+use crate::error::{Error, Result};
 use crate::point::SearchResult;
-use crate::velesql::SetOperator;
+use crate::velesql::{SetOperator, WithClause};
+
+/// `WITH` option of the leading SELECT selecting the [`ScoreMerge`] policy.
+pub(crate) const SET_SCORE_OPTION: &str = "set_score";
+
+/// How UNION and INTERSECT score an id returned by both operands.
+///
+/// Chained operators merge pairwise, left to right, so `avg` over three
+/// operands weighs the last one half.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ScoreMerge {
+    /// The higher score (default).
+    #[default]
+    Max,
+    /// The lower score.
+    Min,
+    /// Both scores added: rows found by both operands rank first.
+    Sum,
+    /// The mean of both scores.
+    Avg,
+    /// The left operand's score.
+    Left,
+}
+
+impl ScoreMerge {
+    /// Reads the policy from `WITH (set_score = ...)`; the default when unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] for a value other than `max`, `min`, `sum`,
+    /// `avg` or `left`.
+    pub(crate) fn from_with_clause(with: Option<&WithClause>) -> Result<Self> {
+        let Some(value) = with.and_then(|w| w.get(SET_SCORE_OPTION)) else {
+            return Ok(Self::default());
+        };
+        match value.as_str().map(str::to_ascii_lowercase).as_deref() {
+            Some("max") => Ok(Self::Max),
+            Some("min") => Ok(Self::Min),
+            Some("sum") => Ok(Self::Sum),
+            Some("avg") => Ok(Self::Avg),
+            Some("left") => Ok(Self::Left),
+            _ => Err(Error::Query(format!(
+                "set_score must be 'max', 'min', 'sum', 'avg' or 'left', got {value:?}"
+            ))),
+        }
+    }
+
+    /// Combined score of an id scored `left` and `right` by the operands.
+    fn merge(self, left: f32, right: f32) -> f32 {
+        match self {
+            Self::Max => left.max(right),
+            Self::Min => left.min(right),
+            Self::Sum => left + right,
+            Self::Avg => f32::midpoint(left, right),
+            Self::Left => left,
+        }
+    }
+}
 
 /// Applies a set operator to two result sets, bounding the output at `limit`.
 ///
 /// Scoring rules per operator:
-/// - **Union**: deduplicate by point ID, keep highest score.
+/// - **Union**: deduplicate by point ID; an ID on both sides gets the
+///   `merge` of its two scores.
 /// - **`UnionAll`**: concatenate without deduplication.
-/// - **Intersect**: keep only IDs present in both; take the higher score.
+/// - **Intersect**: keep only IDs present in both, scored by `merge`.
 /// - **Except**: keep left-side IDs that do not appear in the right side.
 ///
+/// An ID found on both sides keeps the row of the side that scored higher.
 /// Results are returned sorted by score descending and truncated to `limit`.
 /// Because the final result is score-ranked then capped, only the top `limit`
 /// rows are ever observable — so truncating here drops nothing within the
 /// requested window. Operands are expected to already be capped by the caller
-/// (`MAX_LIMIT`), which bounds buffering on the smaller-side scan for INTERSECT.
+/// (`max_query_limit`), which bounds buffering on the smaller-side scan for INTERSECT.
 pub(crate) fn apply_set_operation(
     left: Vec<SearchResult>,
     right: Vec<SearchResult>,
     operator: SetOperator,
+    merge: ScoreMerge,
     limit: usize,
 ) -> Vec<SearchResult> {
     let mut results = match operator {
-        SetOperator::Union => union_dedup(left, right),
+        SetOperator::Union => union_dedup(left, right, merge),
         SetOperator::UnionAll => union_all(left, right),
-        SetOperator::Intersect => intersect(left, &right),
+        SetOperator::Intersect => intersect(left, &right, merge),
         SetOperator::Except => except(left, &right),
     };
 
@@ -44,8 +108,25 @@ pub(crate) fn apply_set_operation(
     results
 }
 
-/// UNION: merge both sides, deduplicate by point ID (keep highest score).
-fn union_dedup(left: Vec<SearchResult>, right: Vec<SearchResult>) -> Vec<SearchResult> {
+/// The row of an ID both sides returned: the higher-scoring side's row,
+/// carrying the merged score.
+fn merge_rows(left: SearchResult, right: &SearchResult, merge: ScoreMerge) -> SearchResult {
+    let score = merge.merge(left.score, right.score);
+    let mut row = if right.score > left.score {
+        right.clone()
+    } else {
+        left
+    };
+    row.score = score;
+    row
+}
+
+/// UNION: merge both sides, deduplicate by point ID (scores merged).
+fn union_dedup(
+    left: Vec<SearchResult>,
+    right: Vec<SearchResult>,
+    merge: ScoreMerge,
+) -> Vec<SearchResult> {
     let mut map: HashMap<u64, SearchResult> = HashMap::with_capacity(left.len() + right.len());
 
     for result in left {
@@ -55,9 +136,8 @@ fn union_dedup(left: Vec<SearchResult>, right: Vec<SearchResult>) -> Vec<SearchR
     for result in right {
         match map.entry(result.point.id) {
             std::collections::hash_map::Entry::Occupied(mut existing) => {
-                if result.score > existing.get().score {
-                    existing.insert(result);
-                }
+                let existing = existing.get_mut();
+                *existing = merge_rows(existing.clone(), &result, merge);
             }
             std::collections::hash_map::Entry::Vacant(slot) => {
                 slot.insert(result);
@@ -74,16 +154,16 @@ fn union_all(mut left: Vec<SearchResult>, right: Vec<SearchResult>) -> Vec<Searc
     left
 }
 
-/// INTERSECT: keep only IDs present in both sides; take the higher score.
-fn intersect(left: Vec<SearchResult>, right: &[SearchResult]) -> Vec<SearchResult> {
+/// INTERSECT: keep only IDs present in both sides (scores merged).
+fn intersect(
+    left: Vec<SearchResult>,
+    right: &[SearchResult],
+    merge: ScoreMerge,
+) -> Vec<SearchResult> {
     let right_map: HashMap<u64, &SearchResult> = right.iter().map(|r| (r.point.id, r)).collect();
 
     left.into_iter()
-        .filter_map(|l| {
-            right_map
-                .get(&l.point.id)
-                .map(|r| if r.score > l.score { (*r).clone() } else { l })
-        })
+        .filter_map(|l| right_map.get(&l.point.id).map(|r| merge_rows(l, r, merge)))
         .collect()
 }
 
@@ -113,7 +193,8 @@ mod tests {
         let left = vec![make_result(1, 0.9), make_result(2, 0.5)];
         let right = vec![make_result(2, 0.8), make_result(3, 0.7)];
 
-        let results = apply_set_operation(left, right, SetOperator::Union, TEST_LIMIT);
+        let results =
+            apply_set_operation(left, right, SetOperator::Union, ScoreMerge::Max, TEST_LIMIT);
 
         assert_eq!(results.len(), 3);
         let ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
@@ -131,7 +212,13 @@ mod tests {
         let left = vec![make_result(1, 0.9), make_result(2, 0.5)];
         let right = vec![make_result(2, 0.8), make_result(3, 0.7)];
 
-        let results = apply_set_operation(left, right, SetOperator::UnionAll, TEST_LIMIT);
+        let results = apply_set_operation(
+            left,
+            right,
+            SetOperator::UnionAll,
+            ScoreMerge::Max,
+            TEST_LIMIT,
+        );
 
         assert_eq!(results.len(), 4);
     }
@@ -141,7 +228,13 @@ mod tests {
         let left = vec![make_result(1, 0.9), make_result(2, 0.5)];
         let right = vec![make_result(2, 0.8), make_result(3, 0.7)];
 
-        let results = apply_set_operation(left, right, SetOperator::Intersect, TEST_LIMIT);
+        let results = apply_set_operation(
+            left,
+            right,
+            SetOperator::Intersect,
+            ScoreMerge::Max,
+            TEST_LIMIT,
+        );
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].point.id, 2);
@@ -154,7 +247,13 @@ mod tests {
         let left = vec![make_result(1, 0.9), make_result(2, 0.5)];
         let right = vec![make_result(2, 0.8), make_result(3, 0.7)];
 
-        let results = apply_set_operation(left, right, SetOperator::Except, TEST_LIMIT);
+        let results = apply_set_operation(
+            left,
+            right,
+            SetOperator::Except,
+            ScoreMerge::Max,
+            TEST_LIMIT,
+        );
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].point.id, 1);
@@ -165,7 +264,13 @@ mod tests {
         let left = vec![make_result(1, 0.3), make_result(2, 0.9)];
         let right = vec![make_result(3, 0.6)];
 
-        let results = apply_set_operation(left, right, SetOperator::UnionAll, TEST_LIMIT);
+        let results = apply_set_operation(
+            left,
+            right,
+            SetOperator::UnionAll,
+            ScoreMerge::Max,
+            TEST_LIMIT,
+        );
 
         let scores: Vec<f32> = results.iter().map(|r| r.score).collect();
         for window in scores.windows(2) {
@@ -183,6 +288,7 @@ mod tests {
             Vec::new(),
             non_empty.clone(),
             SetOperator::Union,
+            ScoreMerge::Max,
             TEST_LIMIT,
         );
         assert_eq!(r.len(), 1);
@@ -192,15 +298,78 @@ mod tests {
             Vec::new(),
             non_empty.clone(),
             SetOperator::Intersect,
+            ScoreMerge::Max,
             TEST_LIMIT,
         );
         assert!(r.is_empty());
 
         // EXCEPT with empty right.
-        let r = apply_set_operation(non_empty, empty, SetOperator::Except, TEST_LIMIT);
+        let r = apply_set_operation(
+            non_empty,
+            empty,
+            SetOperator::Except,
+            ScoreMerge::Max,
+            TEST_LIMIT,
+        );
         assert_eq!(r.len(), 1);
     }
 
+    #[test]
+    fn test_score_merge_policies() {
+        let left = || vec![make_result(1, 0.9), make_result(2, 0.5)];
+        let right = || vec![make_result(2, 0.75), make_result(3, 0.7)];
+        let score_of_2 = |op, merge| {
+            apply_set_operation(left(), right(), op, merge, TEST_LIMIT)
+                .into_iter()
+                .find(|r| r.point.id == 2)
+                .expect("id 2 kept")
+                .score
+        };
+
+        for (merge, expected) in [
+            (ScoreMerge::Max, 0.75),
+            (ScoreMerge::Min, 0.5),
+            (ScoreMerge::Sum, 1.25),
+            (ScoreMerge::Avg, 0.625),
+            (ScoreMerge::Left, 0.5),
+        ] {
+            for op in [SetOperator::Union, SetOperator::Intersect] {
+                let score = score_of_2(op, merge);
+                assert!(
+                    (score - expected).abs() < f32::EPSILON,
+                    "{op:?} {merge:?}: {score}"
+                );
+            }
+        }
+
+        // Under `sum`, the id both operands found outranks the others.
+        let results = apply_set_operation(
+            left(),
+            right(),
+            SetOperator::Union,
+            ScoreMerge::Sum,
+            TEST_LIMIT,
+        );
+        assert_eq!(results[0].point.id, 2);
+    }
+
+    #[test]
+    fn test_score_merge_from_with_clause() {
+        let with = |value: &str| {
+            WithClause::new().with_option(
+                SET_SCORE_OPTION,
+                crate::velesql::WithValue::String(value.into()),
+            )
+        };
+        assert_eq!(ScoreMerge::from_with_clause(None).unwrap(), ScoreMerge::Max);
+        assert_eq!(
+            ScoreMerge::from_with_clause(Some(&with("SUM"))).unwrap(),
+            ScoreMerge::Sum
+        );
+        let err = ScoreMerge::from_with_clause(Some(&with("median"))).unwrap_err();
+        assert!(err.to_string().contains("set_score"), "{err}");
+    }
+
     /// Builds a result whose score equals its (small) id, avoiding lossy casts.
     fn scored(id: u16) -> SearchResult {
         make_result(u64::from(id), f32::from(id))
@@ -212,7 +381,7 @@ mod tests {
         let left: Vec<SearchResult> = (1..=100).map(scored).collect();
         let right: Vec<SearchResult> = (101..=200).map(scored).collect();
 
-        let results = apply_set_operation(left, right, SetOperator::Union, 5);
+        let results = apply_set_operation(left, right, SetOperator::Union, ScoreMerge::Max, 5);
 
         // Bounded to 5, and those 5 are the highest scores (196..=200).
         assert_eq!(results.len(), 5);
@@ -229,7 +398,7 @@ mod tests {
             .map(|i| make_result(u64::from(i), 0.0))
             .collect();
 
-        let results = apply_set_operation(left, right, SetOperator::Intersect, 3);
+        let results = apply_set_operation(left, right, SetOperator::Intersect, ScoreMerge::Max, 3);
 
         assert_eq!(results.len(), 3);
         let ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
@@ -242,7 +411,7 @@ mod tests {
         let left = vec![make_result(1, 0.9), make_result(2, 0.5)];
         let right = vec![make_result(3, 0.7)];
 
-        let results = apply_set_operation(left, right, SetOperator::Union, 100);
+        let results = apply_set_operation(left, right, SetOperator::Union, ScoreMerge::Max, 100);
         assert_eq!(results.len(), 3);
     }
 }
@@ -137,8 +137,7 @@ impl Collection {
         Self::warn_large_scan(total_count, limit);
 
         let metadata_filter = Self::extract_metadata_filter(condition);
-        let filter = metadata_filter
-            .map(|cond| crate::filter::Filter::new(crate::filter::Condition::from(cond)));
+        let filter = metadata_filter.map(|cond| self.where_filter(cond));
 
         let higher_is_better = self.storage.config.read().metric.higher_is_better();
 
@@ -358,11 +357,10 @@ impl Collection {
             // Fall through to sequential scan — cost model prefers full scan
         }
 
-        // Full sequential scan (slow fallback for non-indexed conditions).
-        // LOCK ORDER: vector_storage(2) before payload_storage(3) — was
-        // reversed here. See .investigation/http-deadlock-2026-07-22/.
-        let vector_storage = self.storage.vector_storage.read();
-        let payload_storage = self.storage.payload_storage.read();
+        // Full sequential scan (slow fallback for non-indexed conditions),
+        // over one committed snapshot of both storages.
+        let snapshot = self.read_snapshot();
+        let (vector_storage, payload_storage) = (&*snapshot.vectors, &*snapshot.payloads);
 
         let vector_ids = vector_storage.ids();
         let ids: Vec<u64> = if vector_ids.is_empty() {
@@ -371,6 +369,7 @@ impl Collection {
             vector_ids
         };
         let total_ids = ids.len();
+        tracing::debug!(snapshot = snapshot.sequence, rows = total_ids, "full scan");
 
         // Record rows actually visited as payload-mirror scan debt: once the
         // debt exceeds one full-scan-equivalent, the next metadata query
@@ -378,8 +377,8 @@ impl Collection {
         let mut scanned: u64 = 0;
         let counted_ids = ids.into_iter().inspect(|_| scanned += 1);
         let results = Self::collect_filtered_scan(
-            &*payload_storage,
-            &*vector_storage,
+            payload_storage,
+            vector_storage,
             counted_ids,
             filter,
             limit,
@@ -444,16 +443,19 @@ impl Collection {
     /// fields. Returns the smallest candidate set found, or `None` if no
     /// indexed condition exists.
     fn try_index_accelerated_ids(&self, filter: &crate::filter::Filter) -> Option<Vec<u64>> {
-        self.extract_bitmap_ids_from_filter(&filter.condition)
+        self.extract_bitmap_ids_from_filter(&filter.condition, filter.effective_collation())
     }
 
     /// Recursively extracts candidate IDs from a filter condition using indexes.
     fn extract_bitmap_ids_from_filter(
         &self,
         condition: &crate::filter::Condition,
+        collation: crate::filter::Collation,
     ) -> Option<Vec<u64>> {
         use crate::filter::Condition;
         match condition {
+            // Index keys are raw strings; collation-equal values would be missed.
+            Condition::Eq { value, .. } if value.is_string() && !collation.is_binary() => None,
             Condition::Eq { field, value } => {
                 if let Some(jv) = crate::index::JsonValue::from_json(value) {
                     return self.secondary_index_lookup(field, &jv);
@@ -464,7 +466,7 @@ impl Collection {
                 // Find the smallest indexed candidate set among AND children.
                 let mut best: Option<Vec<u64>> = None;
                 for sub in conditions {
-                    if let Some(ids) = self.extract_bitmap_ids_from_filter(sub) {
+                    if let Some(ids) = self.extract_bitmap_ids_from_filter(sub, collation) {
                         best = Some(match best {
                             Some(prev) if prev.len() <= ids.len() => prev,
                             _ => ids,
@@ -485,14 +487,11 @@ impl Collection {
         filter: &crate::filter::Filter,
         limit: usize,
     ) -> TrackedScan {
-        // LOCK ORDER: vector_storage(2) before payload_storage(3) — was
-        // reversed here. See .investigation/http-deadlock-2026-07-22/.
-        let vector_storage = self.storage.vector_storage.read();
-        let payload_storage = self.storage.payload_storage.read();
+        let snapshot = self.read_snapshot();
         let mut scanned: usize = 0;
         let results = Self::collect_filtered_scan(
-            &*payload_storage,
-            &*vector_storage,
+            &*snapshot.payloads,
+            &*snapshot.vectors,
             candidate_ids.iter().copied().inspect(|_| scanned += 1),
             filter,
             limit,
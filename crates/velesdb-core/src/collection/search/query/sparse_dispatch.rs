@@ -4,7 +4,7 @@
 //! Contains the sparse query dispatch, hybrid search execution, graph-predicate
 //! filtering, result finalization, and fusion strategy resolution.
 
-use super::{distinct, Collection, ExtractedComponents, Result, SearchResult, MAX_LIMIT};
+use super::{distinct, Collection, ExtractedComponents, Result, SearchResult};
 use tracing::warn;
 
 impl Collection {
@@ -23,9 +23,9 @@ impl Collection {
         // GraphFirst by anchor ids (sparse-only): AND-required MATCH
         // predicates restrict the sparse fetch via the index's per-id
         // filter, so retrieval is exact at `limit` within the graph matches
-        // instead of post-filtering a MAX_LIMIT window. Hybrid dense+sparse
+        // instead of post-filtering a `max_query_limit` window. Hybrid dense+sparse
         // keeps the window: its fusion legs rank independently.
-        let mut graph_cache = super::where_eval::GraphMatchEvalCache::default();
+        let mut graph_cache = super::where_eval::GraphMatchEvalCache::new(self.default_collation());
         let anchors = self.sparse_anchor_prefilter(stmt, params, extracted, &mut graph_cache)?;
 
         let mut results =
@@ -45,7 +45,7 @@ impl Collection {
     }
 
     /// Fetches sparse/hybrid results: anchored exact fetch when a GraphFirst
-    /// anchor set is available, MAX_LIMIT window fetch otherwise.
+    /// anchor set is available, `max_query_limit` window fetch otherwise.
     fn fetch_sparse_results(
         &self,
         stmt: &crate::velesql::SelectStatement,
@@ -59,7 +59,7 @@ impl Collection {
             let execution_limit = if extracted.graph_match_predicates.is_empty() {
                 limit
             } else {
-                MAX_LIMIT
+                self.max_query_limit()
             };
             return self.execute_sparse_or_hybrid(stmt, extracted, svs, params, execution_limit);
         };
@@ -180,11 +180,12 @@ impl Collection {
             results = results.into_iter().skip(skip).collect();
         }
         // Intentional saturating clamp: a missing or out-of-range limit collapses
-        // to `MAX_LIMIT` rather than erroring.
+        // to `max_query_limit` rather than erroring.
+        let max = self.max_query_limit();
         let final_limit =
             usize::try_from(stmt.limit.unwrap_or(crate::velesql::DEFAULT_SELECT_LIMIT))
-                .unwrap_or(MAX_LIMIT)
-                .min(MAX_LIMIT);
+                .unwrap_or(max)
+                .min(max);
         results.truncate(final_limit);
         self.runtime.guard_rails.circuit_breaker.record_success();
         Ok(results)
@@ -212,9 +213,13 @@ impl Collection {
                             crate::fusion::FusionStrategy::rrf_default()
                         })
                     }
-                    FusionStrategyType::Rrf => crate::fusion::FusionStrategy::RRF {
-                        k: fc.k.unwrap_or(60),
-                    },
+                    // field_boost never reaches a sparse query (validation
+                    // restricts it to a single NEAR).
+                    FusionStrategyType::Rrf | FusionStrategyType::FieldBoost => {
+                        crate::fusion::FusionStrategy::RRF {
+                            k: fc.k.unwrap_or(60),
+                        }
+                    }
                     FusionStrategyType::Average => crate::fusion::FusionStrategy::Average,
                     FusionStrategyType::Maximum => crate::fusion::FusionStrategy::Maximum,
                     FusionStrategyType::Weighted => {
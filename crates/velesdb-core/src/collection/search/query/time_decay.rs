@@ -0,0 +1,181 @@
+//! Time-decay scoring: `WITH (decay_field = 'created_at', half_life = '7d')`.
+//!
+//! Scales the score of every `NEAR` hit by a freshness factor
+//! `0.5^(age / half_life)`, where `age` is the time elapsed since the
+//! timestamp stored in `decay_field` (epoch seconds, or an RFC 3339 string).
+//! A hit one half-life old keeps half its similarity; for distance metrics
+//! (lower is better) the distance is divided by the factor instead, so older
+//! points rank further away in both cases. Points without a readable
+//! timestamp, or dated in the future, keep their score.
+//!
+//! Decay can promote a fresh hit from beyond the `LIMIT`, so the vector
+//! search fetches [`RESCORE_OVERFETCH`](super::RESCORE_OVERFETCH) times the
+//! requested rows and the decayed candidates are re-ranked before
+//! ORDER BY / OFFSET / LIMIT run.
+
+use crate::collection::types::Collection;
+use crate::error::{Error, Result};
+use crate::point::SearchResult;
+use crate::velesql::{WithClause, WithValue};
+
+use super::score_fusion::rescale_scores;
+use super::ExtractedComponents;
+
+/// Validated `decay_field` / `half_life` pair of a WITH clause.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TimeDecay {
+    /// Payload path of the timestamp (dotted paths walk nested objects).
+    field: String,
+    /// Half-life in seconds, positive and finite.
+    half_life_secs: f64,
+}
+
+impl TimeDecay {
+    /// Reads the decay options of an optional WITH clause; `None` when
+    /// neither is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when only one of the two options is set, when
+    /// `decay_field` isn't a string, or when `half_life` isn't a positive
+    /// duration.
+    pub(crate) fn from_with_clause(with: Option<&WithClause>) -> Result<Option<Self>> {
+        let field = with.and_then(|w| w.get("decay_field"));
+        let half_life = with.and_then(|w| w.get("half_life"));
+        let (field, half_life) = match (field, half_life) {
+            (None, None) => return Ok(None),
+            (Some(field), Some(half_life)) => (field, half_life),
+            _ => {
+                return Err(Error::Query(
+                    "decay_field and half_life must be set together".to_string(),
+                ))
+            }
+        };
+        let field = field.as_str().filter(|f| !f.is_empty()).ok_or_else(|| {
+            Error::Query(format!(
+                "decay_field must be a payload field name, got {field:?}"
+            ))
+        })?;
+        let half_life_secs = parse_half_life(half_life).ok_or_else(|| {
+            Error::Query(format!(
+                "half_life must be a positive duration such as '7d', '12h' or a number of \
+                 seconds, got {half_life:?}"
+            ))
+        })?;
+        Ok(Some(Self {
+            field: field.to_string(),
+            half_life_secs,
+        }))
+    }
+
+    /// Freshness factor in `(0, 1]` of a point at `now_secs`.
+    pub(crate) fn factor(&self, payload: Option<&serde_json::Value>, now_secs: f64) -> f64 {
+        let Some(timestamp) = payload
+            .and_then(|p| crate::filter::lookup_path(p, &self.field))
+            .and_then(timestamp_secs)
+        else {
+            return 1.0;
+        };
+        let age = (now_secs - timestamp).max(0.0);
+        0.5_f64.powf(age / self.half_life_secs)
+    }
+
+    /// Rescales the scores of `results` and re-ranks them, best first.
+    pub(crate) fn apply(
+        &self,
+        results: &mut [SearchResult],
+        higher_is_better: bool,
+        now_secs: f64,
+    ) {
+        rescale_scores(results, higher_is_better, |r| {
+            self.factor(r.point.payload.as_ref(), now_secs)
+        });
+    }
+}
+
+/// Parses `'7d'`, `'12 hours'`, `'90m'`, `'30s'`, `'2w'` or a plain number
+/// of seconds into seconds.
+fn parse_half_life(value: &WithValue) -> Option<f64> {
+    let secs = match value.as_str() {
+        Some(text) => {
+            let text = text.trim();
+            let split = text
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(text.len());
+            let magnitude: f64 = text[..split].parse().ok()?;
+            let unit = match text[split..].trim().to_ascii_lowercase().as_str() {
+                "" | "s" | "sec" | "second" | "seconds" => 1.0,
+                "m" | "min" | "minute" | "minutes" => 60.0,
+                "h" | "hour" | "hours" => 3_600.0,
+                "d" | "day" | "days" => 86_400.0,
+                "w" | "week" | "weeks" => 604_800.0,
+                _ => return None,
+            };
+            magnitude * unit
+        }
+        None => value.as_float()?,
+    };
+    (secs.is_finite() && secs > 0.0).then_some(secs)
+}
+
+/// Epoch seconds of a payload timestamp: a number of seconds, or an
+/// RFC 3339 string.
+fn timestamp_secs(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => {
+            #[allow(clippy::cast_precision_loss)]
+            // Reason: epoch milliseconds fit f64's 53-bit mantissa until year 287,396.
+            crate::column_store::timestamp::parse_rfc3339_millis(s).map(|ms| ms as f64 / 1000.0)
+        }
+        _ => None,
+    }
+}
+
+/// Current time in epoch seconds.
+fn now_epoch_secs() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64())
+}
+
+impl Collection {
+    /// Reads and validates the time-decay options of a SELECT.
+    ///
+    /// Decay rescales the scores of the main vector search, so it requires a
+    /// `NEAR` predicate and is rejected on the `NEAR_FUSED`, `SPARSE_NEAR`,
+    /// `OR` and `NOT similarity()` paths, which rank elsewhere.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for invalid options or another query shape.
+    pub(super) fn time_decay_option(
+        stmt: &crate::velesql::SelectStatement,
+        extracted: &ExtractedComponents,
+    ) -> Result<Option<TimeDecay>> {
+        let Some(decay) = TimeDecay::from_with_clause(stmt.with_clause.as_ref())? else {
+            return Ok(None);
+        };
+        if extracted.is_main_vector_search() {
+            Ok(Some(decay))
+        } else {
+            Err(Error::Query(
+                "WITH (decay_field = ..., half_life = ...) requires a vector NEAR search \
+                 (not NEAR_FUSED, SPARSE_NEAR, OR or NOT similarity())"
+                    .to_string(),
+            ))
+        }
+    }
+
+    /// Applies `decay` to the vector hits of `stmt`, ranking them by the
+    /// metric the query scores with.
+    pub(super) fn apply_time_decay(
+        &self,
+        decay: &TimeDecay,
+        stmt: &crate::velesql::SelectStatement,
+        results: &mut [SearchResult],
+    ) {
+        let higher_is_better = self.ranking_metric(stmt).higher_is_better();
+        decay.apply(results, higher_is_better, now_epoch_secs());
+    }
+}
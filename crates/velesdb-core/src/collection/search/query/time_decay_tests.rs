@@ -0,0 +1,148 @@
+//! Tests for `WITH (decay_field = ..., half_life = ...)` time-decay scoring.
+#![cfg(all(test, feature = "persistence"))]
+#![allow(clippy::float_cmp, clippy::cast_precision_loss)]
+
+use super::time_decay::TimeDecay;
+use crate::collection::Collection;
+use crate::distance::DistanceMetric;
+use crate::point::{Point, SearchResult};
+use crate::velesql::{Parser, WithClause, WithValue};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+const DAY: i64 = 86_400;
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock")
+        .as_secs()
+        .try_into()
+        .expect("epoch fits i64")
+}
+
+fn decay(half_life: WithValue) -> crate::error::Result<Option<TimeDecay>> {
+    let with = WithClause::new()
+        .with_option("decay_field", WithValue::String("created_at".to_string()))
+        .with_option("half_life", half_life);
+    TimeDecay::from_with_clause(Some(&with))
+}
+
+/// Ten old points matching the query exactly, then one fresh point slightly
+/// off the query direction.
+fn setup_collection(metric: DistanceMetric) -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = Collection::create(PathBuf::from(dir.path()), 2, metric).expect("create collection");
+    let old = now() - 70 * DAY;
+    let mut points: Vec<Point> = (0..10u64)
+        .map(|i| {
+            let y = 0.01 * (i + 1) as f32;
+            Point::new(i, vec![1.0, y], Some(json!({ "created_at": old })))
+        })
+        .collect();
+    points.push(Point::new(
+        10,
+        vec![1.0, 0.3],
+        Some(json!({ "created_at": now() })),
+    ));
+    col.upsert(points).expect("upsert");
+    (dir, col)
+}
+
+fn run(col: &Collection, sql: &str) -> crate::error::Result<Vec<SearchResult>> {
+    let parsed = Parser::parse(sql).expect("parse");
+    let params = HashMap::from([("v".to_string(), json!([1.0, 0.0]))]);
+    col.execute_query(&parsed, &params)
+}
+
+#[test]
+fn test_half_life_units() {
+    for (text, expected) in [
+        ("30s", 30.0),
+        ("90 m", 5_400.0),
+        ("12 hours", 43_200.0),
+        ("7d", 604_800.0),
+        ("2w", 1_209_600.0),
+        ("1.5d", 129_600.0),
+    ] {
+        let d = decay(WithValue::String(text.to_string()))
+            .expect("valid")
+            .expect("set");
+        let payload = json!({ "created_at": 0 });
+        assert!(
+            (d.factor(Some(&payload), expected) - 0.5).abs() < 1e-12,
+            "{text}"
+        );
+    }
+    let d = decay(WithValue::Integer(60)).expect("valid").expect("set");
+    assert!((d.factor(Some(&json!({ "created_at": 0 })), 120.0) - 0.25).abs() < 1e-12);
+}
+
+#[test]
+fn test_invalid_decay_options_are_rejected() {
+    assert!(decay(WithValue::String("7 fortnights".to_string())).is_err());
+    assert!(decay(WithValue::Integer(0)).is_err());
+    assert!(decay(WithValue::Boolean(true)).is_err());
+
+    let field_only =
+        WithClause::new().with_option("decay_field", WithValue::String("created_at".to_string()));
+    assert!(TimeDecay::from_with_clause(Some(&field_only)).is_err());
+    assert_eq!(TimeDecay::from_with_clause(None).expect("no options"), None);
+}
+
+#[test]
+fn test_factor_reads_rfc3339_and_ignores_missing_or_future() {
+    let d = decay(WithValue::String("1d".to_string()))
+        .expect("valid")
+        .expect("set");
+    let two_days_later = 1_704_067_200.0 + 2.0 * 86_400.0;
+    let rfc = json!({ "created_at": "2024-01-01T00:00:00Z" });
+    assert!((d.factor(Some(&rfc), two_days_later) - 0.25).abs() < 1e-12);
+    assert_eq!(d.factor(Some(&json!({ "other": 1 })), two_days_later), 1.0);
+    assert_eq!(d.factor(Some(&json!({ "created_at": "soon" })), 0.0), 1.0);
+    assert_eq!(d.factor(Some(&json!({ "created_at": 100 })), 0.0), 1.0);
+}
+
+#[test]
+fn test_decay_promotes_fresh_hit_from_beyond_limit() {
+    let (_dir, col) = setup_collection(DistanceMetric::Cosine);
+    let plain = run(&col, "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3").expect("plain");
+    assert!(plain.iter().all(|r| r.point.id != 10));
+
+    let decayed = run(
+        &col,
+        "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3 \
+         WITH (decay_field = 'created_at', half_life = '7d')",
+    )
+    .expect("decayed");
+    assert_eq!(decayed.len(), 3);
+    assert_eq!(decayed[0].point.id, 10);
+    // Ten half-lives old: about a thousandth of the similarity is left.
+    assert!(decayed[1].score < 0.001);
+}
+
+#[test]
+fn test_decay_on_distance_metric_pushes_old_points_away() {
+    let (_dir, col) = setup_collection(DistanceMetric::Euclidean);
+    let decayed = run(
+        &col,
+        "SELECT * FROM docs WHERE vector NEAR $v LIMIT 3 \
+         WITH (decay_field = 'created_at', half_life = '7d')",
+    )
+    .expect("decayed");
+    assert_eq!(decayed[0].point.id, 10);
+    assert!(decayed[0].score < decayed[1].score);
+}
+
+#[test]
+fn test_decay_requires_vector_near_query() {
+    let (_dir, col) = setup_collection(DistanceMetric::Cosine);
+    let err = run(
+        &col,
+        "SELECT * FROM docs WHERE created_at > 0 LIMIT 3 \
+         WITH (decay_field = 'created_at', half_life = '7d')",
+    )
+    .expect_err("metadata-only query");
+    assert!(err.to_string().contains("requires a vector NEAR search"));
+}
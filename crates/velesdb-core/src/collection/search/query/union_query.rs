@@ -7,9 +7,6 @@ use crate::collection::types::Collection;
 use crate::error::Result;
 use crate::point::SearchResult;
 
-/// Maximum allowed LIMIT value (re-imported from parent for local use).
-const MAX_LIMIT: usize = 100_000;
-
 impl Collection {
     /// EPIC-044 US-002: Execute union query for similarity() OR metadata patterns.
     ///
@@ -91,7 +88,9 @@ impl Collection {
         let similarity_conditions = self.extract_all_similarity_conditions(sim_cond, params)?;
         if let Some((field, vec, op, threshold)) = similarity_conditions.first() {
             let overfetch_factor = 10;
-            let candidates_k = limit.saturating_mul(overfetch_factor).min(MAX_LIMIT);
+            let candidates_k = limit
+                .saturating_mul(overfetch_factor)
+                .min(self.max_query_limit());
             let candidates = self.search(vec, candidates_k)?;
 
             let filter_k = limit.saturating_mul(2);
@@ -100,7 +99,7 @@ impl Collection {
 
             for result in filtered {
                 if let Some(outer) = outer_filter {
-                    if !Self::matches_metadata_filter(&result.point, outer) {
+                    if !self.matches_metadata_filter(&result.point, outer) {
                         continue;
                     }
                 }
@@ -125,8 +124,7 @@ impl Collection {
             }
             None => meta_cond,
         };
-        let filter =
-            crate::filter::Filter::new(crate::filter::Condition::from(combined_cond.clone()));
+        let filter = self.where_filter(combined_cond.clone());
         let metadata_results = self.execute_scan_query(&filter, limit, Some(&combined_cond));
 
         for result in metadata_results {
@@ -139,10 +137,11 @@ impl Collection {
     /// Check if a point matches a metadata filter condition.
     /// Used for applying outer AND filters to similarity results.
     pub(crate) fn matches_metadata_filter(
+        &self,
         point: &crate::Point,
         condition: &crate::velesql::Condition,
     ) -> bool {
-        let filter = crate::filter::Filter::new(crate::filter::Condition::from(condition.clone()));
+        let filter = self.where_filter(condition.clone());
         match point.payload.as_ref() {
             Some(payload) => filter.matches(payload),
             None => false, // No payload means filter doesn't match
@@ -237,15 +237,7 @@ fn aggregation_result_key(agg: &AggregateFunction) -> String {
         return format!("{prefix}_score");
     }
     if let AggregateArg::Column(col) = &agg.argument {
-        let prefix = match agg.function_type {
-            AggregateType::First => "first",
-            AggregateType::Count => "count",
-            AggregateType::Sum => "sum",
-            AggregateType::Avg => "avg",
-            AggregateType::Min => "min",
-            AggregateType::Max => "max",
-        };
-        return format!("{prefix}_{col}");
+        return format!("{}_{col}", agg.result_key_prefix());
     }
     format!("{:?}_{}", agg.function_type, arg_name(&agg.argument))
 }
@@ -332,6 +324,7 @@ mod tests {
             function_type: AggregateType::Max,
             argument: AggregateArg::Column("score".to_string()),
             alias: alias.map(String::from),
+            fraction: None,
         }
     }
 
@@ -340,6 +333,7 @@ mod tests {
             function_type: AggregateType::Avg,
             argument: AggregateArg::Column("score".to_string()),
             alias: alias.map(String::from),
+            fraction: None,
         }
     }
 
@@ -348,6 +342,7 @@ mod tests {
             function_type: AggregateType::First,
             argument: AggregateArg::Column(col.to_string()),
             alias: alias.map(String::from),
+            fraction: None,
         }
     }
 
@@ -447,10 +442,12 @@ mod tests {
         let stmt = SelectStatement {
             group_by: Some(crate::velesql::GroupByClause {
                 columns: vec!["parent".to_string()],
+                expressions: Vec::new(),
             }),
             where_clause: Some(crate::velesql::Condition::VectorSearch(
                 crate::velesql::VectorSearch {
                     vector: crate::velesql::VectorExpr::Literal(vec![1.0, 0.0, 0.0, 0.0]),
+                    avoid: Vec::new(),
                 },
             )),
             ..SelectStatement::empty()
@@ -463,6 +460,7 @@ mod tests {
         let stmt = SelectStatement {
             group_by: Some(crate::velesql::GroupByClause {
                 columns: vec!["parent".to_string()],
+                expressions: Vec::new(),
             }),
             where_clause: None,
             ..SelectStatement::empty()
@@ -477,6 +475,7 @@ mod tests {
             where_clause: Some(crate::velesql::Condition::VectorSearch(
                 crate::velesql::VectorSearch {
                     vector: crate::velesql::VectorExpr::Literal(vec![1.0, 0.0, 0.0, 0.0]),
+                    avoid: Vec::new(),
                 },
             )),
             ..SelectStatement::empty()
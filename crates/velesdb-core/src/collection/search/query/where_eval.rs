@@ -21,9 +21,19 @@ pub(crate) struct GraphMatchEvalCache {
     /// every row of a single evaluation, so pointer identity is a stable key
     /// and lets us build each leaf `Filter` exactly once instead of per row.
     filters: Vec<(usize, crate::filter::Filter)>,
+    /// Collation the cached `Filter`s are evaluated with.
+    collation: crate::filter::Collation,
 }
 
 impl GraphMatchEvalCache {
+    /// Creates an empty cache whose metadata filters use `collation`.
+    pub(crate) fn new(collation: crate::filter::Collation) -> Self {
+        Self {
+            collation,
+            ..Self::default()
+        }
+    }
+
     pub(super) fn get_or_compute(
         &mut self,
         collection: &Collection,
@@ -48,7 +58,8 @@ impl GraphMatchEvalCache {
         if let Some(idx) = self.filters.iter().position(|(k, _)| *k == key) {
             return &self.filters[idx].1;
         }
-        let filter = crate::filter::Filter::new(crate::filter::Condition::from(condition.clone()));
+        let filter = crate::filter::Filter::new(crate::filter::Condition::from(condition.clone()))
+            .with_collation(self.collation);
         self.filters.push((key, filter));
         let idx = self.filters.len() - 1;
         &self.filters[idx].1
@@ -123,7 +134,7 @@ impl Collection {
         params: &std::collections::HashMap<String, serde_json::Value>,
         from_aliases: &[String],
     ) -> Result<Vec<SearchResult>> {
-        let mut cache = GraphMatchEvalCache::default();
+        let mut cache = GraphMatchEvalCache::new(self.default_collation());
         self.apply_where_condition_to_results_with_cache(
             results,
             condition,
@@ -195,6 +195,35 @@ fn test_with_mode_used_in_near_with_filter_path() {
     assert!(!results.is_empty());
 }
 
+#[test]
+fn test_query_search_options_from_with_clause_entry_probes() {
+    let with = crate::velesql::WithClause::new()
+        .with_option("entry_probes", crate::velesql::WithValue::Integer(8));
+    let opts = QuerySearchOptions::from_with_clause(Some(&with));
+    assert_eq!(opts.entry_probes, Some(8));
+    assert!(opts.has_quality_overrides());
+
+    let with = crate::velesql::WithClause::new()
+        .with_option("entry_probes", crate::velesql::WithValue::Integer(0));
+    let opts = QuerySearchOptions::from_with_clause(Some(&with));
+    assert_eq!(opts.entry_probes, Some(1));
+}
+
+#[test]
+fn test_with_entry_probes_pure_near() {
+    let (_dir, col) = setup_with_options_collection();
+    let mut params = HashMap::new();
+    params.insert("v".to_string(), serde_json::json!([0.5, 0.5, 0.5, 0.3]));
+    let results = col
+        .execute_query_str(
+            "SELECT * FROM docs WHERE vector NEAR $v LIMIT 5 \
+             WITH (mode='accurate', entry_probes=8)",
+            &params,
+        )
+        .expect("query should succeed");
+    assert_eq!(results.len(), 5);
+}
+
 #[test]
 fn test_with_ef_search_pure_near() {
     let (_dir, col) = setup_with_options_collection();
@@ -528,8 +557,11 @@ fn test_search_with_filter_and_opts_applies_quality() {
     let opts = QuerySearchOptions {
         quality: Some(crate::SearchQuality::Accurate),
         ef_search: None,
+        entry_probes: None,
         force_rerank: None,
+        metric: None,
         fusion_clause: None,
+        hints: crate::collection::search::query::PlannerHints::default(),
     };
     // This method must exist and apply quality-aware search + filter.
     let results = col
@@ -725,3 +757,86 @@ fn test_with_mode_overrides_quality() {
         "mode should take precedence over quality"
     );
 }
+
+// ============================================================================
+// J. WITH (hint='...') planner hints
+// ============================================================================
+
+#[test]
+fn test_planner_hints_override_strategy_and_over_fetch() {
+    use crate::collection::search::query::PlannerHints;
+    use crate::velesql::ExecutionStrategy;
+
+    let with = crate::velesql::WithClause::new().with_option(
+        "hint",
+        crate::velesql::WithValue::String("force_index, no_overfetch".to_string()),
+    );
+    let hints = QuerySearchOptions::from_with_clause(Some(&with)).hints;
+    assert!(hints.force_index && hints.no_overfetch && !hints.force_scan);
+    assert_eq!(
+        hints.apply(ExecutionStrategy::GraphFirst, 8),
+        (ExecutionStrategy::VectorFirst, 1)
+    );
+
+    let scan = PlannerHints {
+        force_scan: true,
+        ..PlannerHints::default()
+    };
+    assert_eq!(
+        scan.apply(ExecutionStrategy::Parallel, 4),
+        (ExecutionStrategy::GraphFirst, 4)
+    );
+    assert_eq!(
+        PlannerHints::default().apply(ExecutionStrategy::Parallel, 4),
+        (ExecutionStrategy::Parallel, 4)
+    );
+}
+
+#[test]
+fn test_hint_force_scan_matches_index_results() {
+    let (_dir, col) = setup_with_options_collection();
+    col.create_index("idx").expect("create index");
+    let mut params = HashMap::new();
+    params.insert("v".to_string(), serde_json::json!([0.5, 0.5, 0.5, 0.3]));
+
+    let ids = |sql: &str| -> Vec<u64> {
+        let mut ids: Vec<u64> = col
+            .execute_query_str(sql, &params)
+            .expect("hinted query should succeed")
+            .iter()
+            .map(|r| r.point.id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    };
+
+    for base in [
+        "SELECT * FROM docs WHERE vector NEAR $v LIMIT 5",
+        "SELECT * FROM docs WHERE idx = 7 LIMIT 5",
+        "SELECT * FROM docs WHERE vector NEAR $v AND idx > 10 LIMIT 3",
+    ] {
+        let expected = ids(base);
+        assert!(!expected.is_empty(), "{base}");
+        for hint in ["force_scan", "force_index", "no_overfetch"] {
+            assert_eq!(
+                ids(&format!("{base} WITH (hint = '{hint}')")),
+                expected,
+                "{base} / {hint}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_invalid_hints_are_rejected() {
+    let (_dir, col) = setup_with_options_collection();
+    for sql in [
+        "SELECT * FROM docs LIMIT 5 WITH (hint = 'bogus')",
+        "SELECT * FROM docs LIMIT 5 WITH (hint = 'force_scan, force_index')",
+    ] {
+        let err = col
+            .execute_query_str(sql, &HashMap::new())
+            .expect_err("invalid hint must be rejected");
+        assert!(err.to_string().contains("V013"), "{sql}: {err}");
+    }
+}
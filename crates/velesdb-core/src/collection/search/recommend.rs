@@ -0,0 +1,203 @@
+//! Recommendation by example: search from stored points instead of a query
+//! vector.
+//!
+//! Clients name the points they like (and optionally the ones they don't);
+//! the collection looks their vectors up and builds the query itself.
+
+use std::collections::{HashMap, HashSet};
+
+use super::negatives::{rocchio_query, DEFAULT_NEGATIVE_WEIGHT};
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::filter::Filter;
+use crate::point::SearchResult;
+
+/// How [`Collection::recommend_with_strategy`] turns the example points into
+/// results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecommendStrategy {
+    /// Searches once with the positive centroid, moved away from the negative
+    /// centroid exactly like VelesQL `NEAR ... AVOID`.
+    #[default]
+    AverageVector,
+    /// Searches around every positive example, then ranks each candidate by
+    /// its best positive match. Candidates closer to a negative example than
+    /// to any positive one are ranked after all others.
+    ///
+    /// Slower (one ANN pass per positive) but keeps distinct interests apart
+    /// instead of averaging them into a single point.
+    BestScore,
+}
+
+impl Collection {
+    /// Recommends points similar to `positive_ids` and dissimilar to
+    /// `negative_ids`, using [`RecommendStrategy::AverageVector`].
+    ///
+    /// The example points themselves are never returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `positive_ids` is empty, if an example id does not
+    /// exist, or if this is a metadata-only collection.
+    pub fn recommend(
+        &self,
+        positive_ids: &[u64],
+        negative_ids: &[u64],
+        k: usize,
+        filter: Option<&Filter>,
+    ) -> Result<Vec<SearchResult>> {
+        self.recommend_with_strategy(
+            positive_ids,
+            negative_ids,
+            k,
+            filter,
+            RecommendStrategy::default(),
+        )
+    }
+
+    /// Recommends points similar to `positive_ids` and dissimilar to
+    /// `negative_ids`.
+    ///
+    /// With [`RecommendStrategy::BestScore`] scores lie in `(-1, 1)` and keep
+    /// the metric's ordering: for distance metrics lower is still better.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `positive_ids` is empty, if an example id does not
+    /// exist, or if this is a metadata-only collection.
+    pub fn recommend_with_strategy(
+        &self,
+        positive_ids: &[u64],
+        negative_ids: &[u64],
+        k: usize,
+        filter: Option<&Filter>,
+        strategy: RecommendStrategy,
+    ) -> Result<Vec<SearchResult>> {
+        if positive_ids.is_empty() {
+            return Err(Error::Config(
+                "recommend needs at least one positive example id".to_string(),
+            ));
+        }
+        let positives = self.example_vectors(positive_ids)?;
+        let negatives = self.example_vectors(negative_ids)?;
+        let examples: HashSet<u64> = positive_ids.iter().chain(negative_ids).copied().collect();
+        let metric = self.storage.config.read().metric;
+
+        let mut results = match strategy {
+            RecommendStrategy::AverageVector => {
+                let centroid = centroid(&positives);
+                let negatives: Vec<&[f32]> = negatives.iter().map(Vec::as_slice).collect();
+                let query = rocchio_query(&centroid, &negatives, DEFAULT_NEGATIVE_WEIGHT, metric);
+                self.search_filtered(&query, k + examples.len(), filter)?
+            }
+            RecommendStrategy::BestScore => {
+                let candidates_k = k.saturating_mul(4).max(k + 10) + examples.len();
+                let mut candidates: HashMap<u64, SearchResult> = HashMap::new();
+                for positive in &positives {
+                    for result in self.search_filtered(positive, candidates_k, filter)? {
+                        candidates.entry(result.point.id).or_insert(result);
+                    }
+                }
+                best_score_rank(
+                    candidates.into_values().collect(),
+                    &positives,
+                    &negatives,
+                    metric,
+                )
+            }
+        };
+        results.retain(|r| !examples.contains(&r.point.id));
+        results.truncate(k);
+        Ok(results)
+    }
+
+    /// Looks up the stored vectors of example points, failing on the first
+    /// missing (or expired) id.
+    fn example_vectors(&self, ids: &[u64]) -> Result<Vec<Vec<f32>>> {
+        self.get(ids)
+            .into_iter()
+            .zip(ids)
+            .map(|(point, &id)| point.map(|p| p.vector).ok_or(Error::PointNotFound(id)))
+            .collect()
+    }
+
+    fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        filter: Option<&Filter>,
+    ) -> Result<Vec<SearchResult>> {
+        match filter {
+            Some(filter) => self.search_with_filter(query, k, filter),
+            None => self.search(query, k),
+        }
+    }
+}
+
+/// Component-wise mean of the example vectors.
+fn centroid(vectors: &[Vec<f32>]) -> Vec<f32> {
+    let mut sum = vec![0.0_f32; vectors.first().map_or(0, Vec::len)];
+    for vector in vectors {
+        for (s, x) in sum.iter_mut().zip(vector) {
+            *s += x;
+        }
+    }
+    // Reason: the example count is a handful of ids, exact in f32.
+    #[allow(clippy::cast_precision_loss)]
+    let n = vectors.len().max(1) as f32;
+    for s in &mut sum {
+        *s /= n;
+    }
+    sum
+}
+
+/// Ranks candidates by their best positive match, pushing those that sit
+/// closer to a negative example below every other candidate.
+fn best_score_rank(
+    mut candidates: Vec<SearchResult>,
+    positives: &[Vec<f32>],
+    negatives: &[Vec<f32>],
+    metric: DistanceMetric,
+) -> Vec<SearchResult> {
+    let higher_is_better = metric.higher_is_better();
+    // Similarity in "higher is better" orientation for every metric.
+    let similarity = |a: &[f32], b: &[f32]| {
+        let raw = metric.calculate(a, b);
+        if higher_is_better {
+            raw
+        } else {
+            -raw
+        }
+    };
+    let best = |vector: &[f32], examples: &[Vec<f32>]| {
+        examples
+            .iter()
+            .map(|e| similarity(vector, e))
+            .fold(f32::NEG_INFINITY, f32::max)
+    };
+    // Monotonic squash into (0, 1) so the two buckets never overlap.
+    let squash = |x: f32| 0.5 * (x / (1.0 + x.abs()) + 1.0);
+
+    for candidate in &mut candidates {
+        let best_positive = best(&candidate.point.vector, positives);
+        let best_negative = best(&candidate.point.vector, negatives);
+        let score = if best_positive >= best_negative {
+            squash(best_positive)
+        } else {
+            -squash(best_negative)
+        };
+        candidate.score = if higher_is_better { score } else { -score };
+    }
+    // Candidates come out of a HashMap: break score ties by id so the
+    // ranking is deterministic.
+    candidates.sort_by(|a, b| {
+        let by_score = if higher_is_better {
+            b.score.total_cmp(&a.score)
+        } else {
+            a.score.total_cmp(&b.score)
+        };
+        by_score.then(a.point.id.cmp(&b.point.id))
+    });
+    candidates
+}
@@ -0,0 +1,97 @@
+//! Tests for recommend-by-example (`recommend`, `recommend_with_strategy`).
+
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::collection::types::Collection;
+use crate::collection::RecommendStrategy;
+use crate::error::Error;
+use crate::filter::{Condition, Filter};
+use crate::test_fixtures::fixtures::{make_point_with_payload, setup_collection};
+
+/// Two "interests" (y and z axes) around a shared x-axis cluster.
+fn setup_recommend_collection() -> (tempfile::TempDir, Collection) {
+    let points: Vec<_> = [
+        (1, [1.0, 0.0, 0.0], "a"),
+        (2, [0.95, 0.3, 0.0], "a"),
+        (3, [0.9, 0.0, 0.44], "b"),
+        (4, [0.0, 1.0, 0.0], "a"),
+        (5, [0.0, 0.0, 1.0], "b"),
+        (6, [0.7, 0.7, 0.0], "a"),
+        (7, [0.0, 0.7, 0.7], "b"),
+        (8, [0.3, 0.95, 0.0], "a"),
+    ]
+    .into_iter()
+    .map(|(id, vector, cat)| {
+        make_point_with_payload(id, vector.to_vec(), serde_json::json!({ "cat": cat }))
+    })
+    .collect();
+    let (dir, col) = setup_collection(3);
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+fn ids(results: &[crate::point::SearchResult]) -> Vec<u64> {
+    results.iter().map(|r| r.point.id).collect()
+}
+
+#[test]
+fn test_recommend_excludes_examples_and_ranks_by_similarity() {
+    let (_dir, col) = setup_recommend_collection();
+    let results = col.recommend(&[1], &[], 3, None).expect("recommend");
+    assert_eq!(ids(&results), vec![2, 3, 6]);
+}
+
+#[test]
+fn test_recommend_negative_example_reorders_results() {
+    let (_dir, col) = setup_recommend_collection();
+    let results = col.recommend(&[1], &[4], 2, None).expect("recommend");
+    assert_eq!(ids(&results), vec![3, 2]);
+}
+
+#[test]
+fn test_recommend_applies_filter() {
+    let (_dir, col) = setup_recommend_collection();
+    let filter = Filter::new(Condition::eq("cat", "b"));
+    let results = col
+        .recommend(&[1], &[], 1, Some(&filter))
+        .expect("recommend");
+    assert_eq!(ids(&results), vec![3]);
+}
+
+#[test]
+fn test_best_score_keeps_interests_apart() {
+    let (_dir, col) = setup_recommend_collection();
+    // The centroid of y and z lands exactly on point 7 ...
+    let average = col.recommend(&[4, 5], &[], 1, None).expect("average");
+    assert_eq!(ids(&average), vec![7]);
+    // ... while best-score prefers the point closest to either example.
+    let best = col
+        .recommend_with_strategy(&[4, 5], &[], 1, None, RecommendStrategy::BestScore)
+        .expect("best score");
+    assert_eq!(ids(&best), vec![8]);
+}
+
+#[test]
+fn test_best_score_ranks_negative_leaning_candidates_last() {
+    let (_dir, col) = setup_recommend_collection();
+    let results = col
+        .recommend_with_strategy(&[1], &[4], 10, None, RecommendStrategy::BestScore)
+        .expect("best score");
+    // Points 7 and 8 are closer to the negative than to the positive: they
+    // come last, with negative scores, 8 (nearest the negative) at the end.
+    assert_eq!(ids(&results), vec![2, 3, 6, 5, 7, 8]);
+    let (kept, demoted) = results.split_at(4);
+    assert!(kept.iter().all(|r| r.score > 0.0), "{results:?}");
+    assert!(demoted.iter().all(|r| r.score < 0.0), "{results:?}");
+    assert!(!ids(&results).contains(&4));
+}
+
+#[test]
+fn test_recommend_rejects_bad_examples() {
+    let (_dir, col) = setup_recommend_collection();
+    let err = col.recommend(&[], &[4], 3, None).expect_err("no positives");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+
+    let err = col.recommend(&[1], &[99], 3, None).expect_err("unknown id");
+    assert!(matches!(err, Error::PointNotFound(99)), "got {err:?}");
+}
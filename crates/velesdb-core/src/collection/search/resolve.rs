@@ -7,14 +7,15 @@
 //! These helpers are ready for adoption by search submodules.
 //! Currently tested directly; callers will migrate in a follow-up.
 
+use crate::collection::access_stats::PayloadReader;
 use crate::collection::expiry::{is_payload_expired, now_unix_secs};
 use crate::error::Error;
-use crate::point::{Point, SearchResult};
+use crate::point::{Point, PointProjection, SearchResult};
 use crate::scored_result::ScoredResult;
-use crate::storage::{PayloadStorage, VectorStorage};
+use crate::storage::VectorStorage;
 
 /// Hydrates a single `(id, score)` pair into a `SearchResult` by fetching
-/// vector and payload from storage.
+/// the vector from storage and the payload through `payloads`.
 ///
 /// Returns `None` if the vector cannot be retrieved (deleted point) or the
 /// payload is TTL-expired at `now_secs` (expired points are invisible on
@@ -25,10 +26,10 @@ pub(crate) fn hydrate_point(
     score: f32,
     now_secs: u64,
     vector_storage: &dyn VectorStorage,
-    payload_storage: &dyn PayloadStorage,
+    payloads: &PayloadReader<'_>,
 ) -> Option<SearchResult> {
     let vector = vector_storage.retrieve(id).ok().flatten()?;
-    let payload = payload_storage.retrieve(id).ok().flatten();
+    let payload = payloads.retrieve(id);
     if is_payload_expired(payload.as_ref(), now_secs) {
         return None;
     }
@@ -47,14 +48,34 @@ pub(crate) fn resolve_id_score_pairs(
     pairs: &[(u64, f32)],
     limit: usize,
     vector_storage: &dyn VectorStorage,
-    payload_storage: &dyn PayloadStorage,
+    payloads: &PayloadReader<'_>,
+) -> Vec<SearchResult> {
+    let now_secs = now_unix_secs();
+    pairs
+        .iter()
+        .take(limit)
+        .filter_map(|&(id, score)| hydrate_point(id, score, now_secs, vector_storage, payloads))
+        .collect()
+}
+
+/// [`resolve_id_score_pairs`] for a metadata-only collection, which has no
+/// vector storage: a point exists while its payload does, and is returned
+/// with an empty vector.
+pub(crate) fn resolve_payload_id_score_pairs(
+    pairs: &[(u64, f32)],
+    limit: usize,
+    payloads: &PayloadReader<'_>,
 ) -> Vec<SearchResult> {
     let now_secs = now_unix_secs();
     pairs
         .iter()
         .take(limit)
         .filter_map(|&(id, score)| {
-            hydrate_point(id, score, now_secs, vector_storage, payload_storage)
+            let payload = payloads.retrieve(id)?;
+            if is_payload_expired(Some(&payload), now_secs) {
+                return None;
+            }
+            Some(SearchResult::new(Point::metadata_only(id, payload), score))
         })
         .collect()
 }
@@ -63,12 +84,47 @@ pub(crate) fn resolve_id_score_pairs(
 pub(crate) fn resolve_scored_results(
     results: &[ScoredResult],
     vector_storage: &dyn VectorStorage,
-    payload_storage: &dyn PayloadStorage,
+    payloads: &PayloadReader<'_>,
+) -> Vec<SearchResult> {
+    let now_secs = now_unix_secs();
+    results
+        .iter()
+        .filter_map(|sr| hydrate_point(sr.id, sr.score, now_secs, vector_storage, payloads))
+        .collect()
+}
+
+/// Resolves `ScoredResult` values into `SearchResult` without copying vectors.
+///
+/// Visibility matches [`resolve_scored_results`] (deleted and TTL-expired
+/// points are dropped), but existence is checked via
+/// [`VectorStorage::contains`] and only the payload is read. The payload is
+/// kept when `projection.with_payload` is set; `projection.with_vector` is
+/// ignored (callers wanting vectors use [`resolve_scored_results`]).
+pub(crate) fn resolve_scored_results_without_vectors(
+    results: &[ScoredResult],
+    projection: PointProjection,
+    vector_storage: &dyn VectorStorage,
+    payloads: &PayloadReader<'_>,
 ) -> Vec<SearchResult> {
     let now_secs = now_unix_secs();
     results
         .iter()
-        .filter_map(|sr| hydrate_point(sr.id, sr.score, now_secs, vector_storage, payload_storage))
+        .filter_map(|sr| {
+            if !vector_storage.contains(sr.id) {
+                return None;
+            }
+            let payload = payloads.retrieve(sr.id);
+            if is_payload_expired(payload.as_ref(), now_secs) {
+                return None;
+            }
+            let point = Point {
+                id: sr.id,
+                vector: Vec::new(),
+                payload: payload.filter(|_| projection.with_payload),
+                sparse_vectors: None,
+            };
+            Some(SearchResult::new(point, sr.score))
+        })
         .collect()
 }
 
@@ -1,5 +1,6 @@
 //! Text and hybrid search methods for Collection.
 
+use super::query::score_fusion::{BM25_RAW_SCORE, VECTOR_RAW_SCORE};
 use super::resolve;
 use super::OrderedFloat;
 use crate::collection::expiry::{is_payload_expired, now_unix_secs};
@@ -15,6 +16,19 @@ use std::collections::HashSet;
 /// anchored hybrid paths).
 type AnchoredHybridStreams = (Vec<crate::scored_result::ScoredResult>, Vec<(u64, f32)>);
 
+/// A point's weighted-RRF contributions and the raw branch scores behind them.
+#[derive(Debug, Clone, Copy, Default)]
+struct HybridComponents {
+    vector_rrf: f32,
+    bm25_rrf: f32,
+    /// Metric score in the vector branch (`None` if the point was BM25-only).
+    vector_raw: Option<f32>,
+    /// BM25 score in the text branch (`None` if the point was vector-only).
+    bm25_raw: Option<f32>,
+}
+
+type HybridComponentMap = rustc_hash::FxHashMap<u64, HybridComponents>;
+
 /// Resolves `(weight, text_weight, rrf_constant)` from optional caller inputs.
 ///
 /// `vector_weight` defaults to 0.5; `rrf_k` defaults to 60.
@@ -56,10 +70,7 @@ fn compute_hybrid_scored(
     weight: f32,
     text_weight: f32,
     rrf_constant: f32,
-) -> (
-    rustc_hash::FxHashMap<u64, f32>,
-    rustc_hash::FxHashMap<u64, (f32, f32)>,
-) {
+) -> (rustc_hash::FxHashMap<u64, f32>, HybridComponentMap) {
     use crate::index::VectorIndex;
     let raw = collection.storage.index.search(vector_query, overfetch_k);
     let vec_res = collection.merge_delta(raw, vector_query, overfetch_k, metric);
@@ -77,15 +88,20 @@ fn compute_hybrid_scored(
 }
 
 /// Attaches RRF component scores to a `SearchResult` from the component map.
-fn attach_rrf_components(
-    result: &mut SearchResult,
-    component_map: &rustc_hash::FxHashMap<u64, (f32, f32)>,
-) {
-    if let Some(&(vec_score, bm25_score)) = component_map.get(&result.point.id) {
-        result.component_scores = Some(smallvec::smallvec![
-            ("vector_score", vec_score),
-            ("bm25_score", bm25_score),
-        ]);
+///
+/// `vector_score`/`bm25_score` carry the RRF contributions; the raw branch
+/// scores ride along for `ScoreBreakdown::from_search_result`.
+fn attach_rrf_components(result: &mut SearchResult, component_map: &HybridComponentMap) {
+    if let Some(c) = component_map.get(&result.point.id) {
+        let mut scores: crate::point::ComponentScores =
+            smallvec::smallvec![("vector_score", c.vector_rrf), ("bm25_score", c.bm25_rrf),];
+        if let Some(raw) = c.vector_raw {
+            scores.push((VECTOR_RAW_SCORE, raw));
+        }
+        if let Some(raw) = c.bm25_raw {
+            scores.push((BM25_RAW_SCORE, raw));
+        }
+        result.component_scores = Some(scores);
     }
 }
 
@@ -99,7 +115,8 @@ impl Collection {
     ///
     /// # Returns
     ///
-    /// Vector of search results sorted by BM25 score (descending).
+    /// Vector of search results sorted by BM25 score (descending). Results
+    /// of a metadata-only collection carry their payload and an empty vector.
     ///
     /// # Errors
     ///
@@ -107,16 +124,23 @@ impl Collection {
     #[allow(clippy::unnecessary_wraps)] // Reason: Public API contract — callers expect Result
     pub fn text_search(&self, query: &str, k: usize) -> Result<Vec<SearchResult>> {
         let bm25_results = self.storage.text_index.search(query, k);
-
-        let vector_storage = self.storage.vector_storage.read();
-        let payload_storage = self.storage.payload_storage.read();
-
-        let mut results = resolve::resolve_id_score_pairs(
-            &bm25_results,
-            bm25_results.len(),
-            &*vector_storage,
-            &*payload_storage,
-        );
+        let metadata_only = self.storage.config.read().metadata_only;
+
+        let mut results = if metadata_only {
+            let payload_storage = self.storage.payload_storage.read();
+            let payloads = self.payload_reader(&*payload_storage);
+            resolve::resolve_payload_id_score_pairs(&bm25_results, bm25_results.len(), &payloads)
+        } else {
+            let vector_storage = self.storage.vector_storage.read();
+            let payload_storage = self.storage.payload_storage.read();
+            let payloads = self.payload_reader(&*payload_storage);
+            resolve::resolve_id_score_pairs(
+                &bm25_results,
+                bm25_results.len(),
+                &*vector_storage,
+                &payloads,
+            )
+        };
         // Tag each result with its BM25 component score.
         for result in &mut results {
             result.component_scores = Some(smallvec::smallvec![("bm25_score", result.score),]);
@@ -134,7 +158,8 @@ impl Collection {
     ///
     /// # Returns
     ///
-    /// Vector of search results sorted by BM25 score (descending).
+    /// Vector of search results sorted by BM25 score (descending). Same result
+    /// shape as [`text_search`](Self::text_search).
     ///
     /// # Errors
     ///
@@ -149,6 +174,7 @@ impl Collection {
         // Retrieve more candidates for filtering
         let candidates_k = k.saturating_mul(4).max(k + 10);
         let bm25_results = self.storage.text_index.search(query, candidates_k);
+        let metadata_only = self.storage.config.read().metadata_only;
 
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
@@ -173,7 +199,13 @@ impl Collection {
                 // discarded for every non-match. Result-set membership is
                 // unchanged: inclusion still requires a present vector AND a
                 // non-expired payload passing the filter (a conjunction).
-                let vector = vector_storage.retrieve(id).ok().flatten()?;
+                // Metadata-only points have no vector and exist while their
+                // payload does.
+                let vector = if metadata_only {
+                    Vec::new()
+                } else {
+                    vector_storage.retrieve(id).ok().flatten()?
+                };
 
                 let point = Point {
                     id,
@@ -247,7 +279,7 @@ impl Collection {
     /// values amplify rank differences; higher values smooth them out.
     ///
     /// Returns `(fused_scores, component_map)` where `component_map` maps each
-    /// point ID to its individual `(vector_rrf, bm25_rrf)` contributions.
+    /// point ID to its individual RRF contributions and raw branch scores.
     // EPIC-040: FusionStrategy::WeightedRRF now provides the same weighted,
     // 0-based entry point (weight/(rank+k)). This method keeps its one-pass
     // implementation to build the fused-score map and per-component breakdown
@@ -260,25 +292,26 @@ impl Collection {
         vector_weight: f32,
         text_weight: f32,
         rrf_k: f32,
-    ) -> (
-        rustc_hash::FxHashMap<u64, f32>,
-        rustc_hash::FxHashMap<u64, (f32, f32)>,
-    ) {
+    ) -> (rustc_hash::FxHashMap<u64, f32>, HybridComponentMap) {
         let cap = vector_results.len() + text_results.len();
         let mut fused: rustc_hash::FxHashMap<u64, f32> =
             rustc_hash::FxHashMap::with_capacity_and_hasher(cap, rustc_hash::FxBuildHasher);
-        let mut components: rustc_hash::FxHashMap<u64, (f32, f32)> =
+        let mut components: HybridComponentMap =
             rustc_hash::FxHashMap::with_capacity_and_hasher(cap, rustc_hash::FxBuildHasher);
 
         for (rank, sr) in vector_results.iter().enumerate() {
             let contribution = vector_weight / (rank as f32 + rrf_k);
             *fused.entry(sr.id).or_insert(0.0) += contribution;
-            components.entry(sr.id).or_insert((0.0, 0.0)).0 += contribution;
+            let entry = components.entry(sr.id).or_default();
+            entry.vector_rrf += contribution;
+            entry.vector_raw.get_or_insert(sr.score);
         }
-        for (rank, (id, _)) in text_results.iter().enumerate() {
+        for (rank, &(id, score)) in text_results.iter().enumerate() {
             let contribution = text_weight / (rank as f32 + rrf_k);
-            *fused.entry(*id).or_insert(0.0) += contribution;
-            components.entry(*id).or_insert((0.0, 0.0)).1 += contribution;
+            *fused.entry(id).or_insert(0.0) += contribution;
+            let entry = components.entry(id).or_default();
+            entry.bm25_rrf += contribution;
+            entry.bm25_raw.get_or_insert(score);
         }
         (fused, components)
     }
@@ -310,22 +343,18 @@ impl Collection {
     fn resolve_scored_ids_with_components(
         &self,
         scored_ids: &[(u64, f32)],
-        component_map: &rustc_hash::FxHashMap<u64, (f32, f32)>,
+        component_map: &HybridComponentMap,
     ) -> Vec<SearchResult> {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
+        let payloads = self.payload_reader(&*payload_storage);
         let now_secs = now_unix_secs();
 
         scored_ids
             .iter()
             .filter_map(|&(id, score)| {
-                let mut result = resolve::hydrate_point(
-                    id,
-                    score,
-                    now_secs,
-                    &*vector_storage,
-                    &*payload_storage,
-                )?;
+                let mut result =
+                    resolve::hydrate_point(id, score, now_secs, &*vector_storage, &payloads)?;
                 attach_rrf_components(&mut result, component_map);
                 Some(result)
             })
@@ -467,7 +496,7 @@ impl Collection {
         scored_ids: &[(u64, f32)],
         filter: &crate::filter::Filter,
         k: usize,
-        component_map: &rustc_hash::FxHashMap<u64, (f32, f32)>,
+        component_map: &HybridComponentMap,
     ) -> Vec<SearchResult> {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
@@ -35,7 +35,9 @@ impl Collection {
             return self.hybrid_search_default(vector_query, text_query, k, None, filter);
         };
         match fc.strategy {
-            FusionStrategyType::Rrf => {
+            // field_boost never reaches a hybrid query (validation restricts
+            // it to a single NEAR).
+            FusionStrategyType::Rrf | FusionStrategyType::FieldBoost => {
                 let vw = fc.vector_weight.map(cast_weight);
                 self.hybrid_search_default(vector_query, text_query, k, vw, filter)
             }
@@ -80,7 +82,9 @@ impl Collection {
             );
         };
         match fc.strategy {
-            FusionStrategyType::Rrf => {
+            // field_boost never reaches a hybrid query (validation restricts
+            // it to a single NEAR).
+            FusionStrategyType::Rrf | FusionStrategyType::FieldBoost => {
                 let vw = fc.vector_weight.map(cast_weight);
                 self.hybrid_search_with_anchors(vector_query, text_query, k, vw, fc.k, anchor_ids)
             }
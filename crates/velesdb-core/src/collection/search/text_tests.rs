@@ -192,3 +192,38 @@ fn test_hybrid_search_dimension_mismatch_error() {
     let result = col.hybrid_search(&bad_vec, "rust", 5, None, None);
     assert!(result.is_err(), "wrong dimension should error");
 }
+
+#[test]
+fn test_hybrid_search_score_breakdown_explains_fused_score() {
+    use crate::collection::search::query::score_fusion::{ScoreBreakdown, ScoreSource};
+    use crate::distance::DistanceMetric;
+
+    let (_dir, col) = setup_text_collection();
+    let results = col
+        .hybrid_search(&[0.9, 0.1, 0.0, 0.0], "rust", 5, Some(0.7), None)
+        .expect("hybrid search");
+    let source = ScoreSource::Hybrid {
+        metric: DistanceMetric::Cosine,
+        vector_weight: 0.7,
+    };
+
+    let top = results.iter().find(|r| r.point.id == 1).expect("point 1");
+    let breakdown = ScoreBreakdown::from_search_result(top, source);
+    assert_eq!(breakdown.fusion_method.as_deref(), Some("rrf"));
+    assert_eq!(breakdown.vector_weight, Some(0.7));
+    assert!((breakdown.text_weight.expect("text weight") - 0.3).abs() < 1e-6);
+    let similarity = breakdown.vector_similarity.expect("vector branch hit");
+    assert!(similarity > 0.9, "similarity {similarity}");
+    assert!((breakdown.raw_distance.expect("distance") - (1.0 - similarity)).abs() < 1e-6);
+    assert!(breakdown.bm25_score.expect("text branch hit") > 0.0);
+    let contributions = breakdown.vector_contribution.expect("vector rrf")
+        + breakdown.text_contribution.expect("text rrf");
+    assert!((contributions - top.score).abs() < 1e-6);
+
+    // Point 3 has no "rust" in its title: it only came from the vector branch.
+    if let Some(vector_only) = results.iter().find(|r| r.point.id == 3) {
+        let breakdown = ScoreBreakdown::from_search_result(vector_only, source);
+        assert!(breakdown.bm25_score.is_none());
+        assert_eq!(breakdown.text_contribution, Some(0.0));
+    }
+}
@@ -0,0 +1,195 @@
+//! Threshold (range) search: every point whose similarity to a query passes
+//! `score <op> threshold`, rather than a fixed top `k`.
+//!
+//! `>` and `>=` ask for the points *more* similar than the threshold, which
+//! sit at the head of the HNSW ranking. They run on the index, re-querying
+//! with a doubled `k` (and `ef_search >= 2k`) until a margin of hits comes
+//! back on the failing side of the threshold — the boundary is then covered
+//! and no passing point hides past the end of the candidate list. `<`, `<=`,
+//! `=` and `!=` select points anywhere in the ranking, so they score every
+//! stored vector exactly.
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Result;
+use crate::point::SearchResult;
+use crate::storage::VectorStorage;
+use crate::velesql::CompareOp;
+
+/// Smallest candidate count of the first index round.
+const INITIAL_K: usize = 64;
+
+/// Default lower bound on `ef_search` for every index round (the Balanced
+/// mode's ef).
+const MIN_EF_SEARCH: usize = 128;
+
+/// Fewest failing hits that prove a round reached past the threshold.
+const MIN_BOUNDARY_MARGIN: usize = 8;
+
+impl Collection {
+    /// Returns the points whose similarity to `query` satisfies
+    /// `score <op> threshold`, best first, at most `max_results` of them.
+    ///
+    /// Comparisons are metric-aware like VelesQL `similarity()`: on distance
+    /// metrics (Euclidean, Hamming) `Gt` means "closer than `threshold`".
+    /// `Gt`/`Gte` use the HNSW index with an expanding candidate list; the
+    /// other operators scan all stored vectors. `max_results` is capped at
+    /// [`max_query_limit`](Self::max_query_limit).
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DimensionMismatch`](crate::Error::DimensionMismatch) if the
+    ///   query dimension does not match the collection.
+    /// - [`Error::SearchNotSupported`](crate::Error::SearchNotSupported) on a
+    ///   metadata-only collection.
+    pub fn search_by_threshold(
+        &self,
+        query: &[f32],
+        threshold: f32,
+        op: CompareOp,
+        max_results: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let first_k = max_results
+            .saturating_add(MIN_BOUNDARY_MARGIN)
+            .max(INITIAL_K);
+        self.search_by_threshold_with(query, threshold, op, max_results, first_k, None)
+    }
+
+    /// [`Self::search_by_threshold`] with caller-chosen expansion knobs: the
+    /// candidate count of the first index round and the `ef_search` floor
+    /// (`None` for the default).
+    ///
+    /// VelesQL starts from its usual LIMIT-scaled over-fetch but lets
+    /// `max_results` reach the query ceiling, so a selective metadata filter
+    /// applied afterwards never starves on a top-k cut.
+    pub(crate) fn search_by_threshold_with(
+        &self,
+        query: &[f32],
+        threshold: f32,
+        op: CompareOp,
+        max_results: usize,
+        first_k: usize,
+        min_ef: Option<usize>,
+    ) -> Result<Vec<SearchResult>> {
+        let metric = self.validate_query_and_read_metric(query)?;
+        let max_results = max_results.min(self.max_query_limit());
+        if max_results == 0 {
+            return Ok(Vec::new());
+        }
+        match op {
+            CompareOp::Gt | CompareOp::Gte => {
+                let plan = ExpansionPlan {
+                    first_k,
+                    min_ef: min_ef.unwrap_or(MIN_EF_SEARCH),
+                    max_results,
+                };
+                self.expanding_threshold_search(query, threshold, op, metric, &plan)
+            }
+            _ => Ok(self.exact_threshold_scan(query, threshold, op, max_results, metric)),
+        }
+    }
+
+    /// Index path for `Gt`/`Gte`: doubles `k` until the threshold boundary
+    /// is covered, the index is exhausted or `max_results` points pass.
+    fn expanding_threshold_search(
+        &self,
+        query: &[f32],
+        threshold: f32,
+        op: CompareOp,
+        metric: DistanceMetric,
+        plan: &ExpansionPlan,
+    ) -> Result<Vec<SearchResult>> {
+        let higher_is_better = metric.higher_is_better();
+        let max_results = plan.max_results;
+        let cap = self.max_query_limit();
+        let mut k = plan.first_k.clamp(1, cap);
+        loop {
+            let ef = k.saturating_mul(2).max(plan.min_ef);
+            let hits = self.search_with_ef(query, k, ef)?;
+            let exhausted = hits.len() < k || k >= cap;
+
+            let mut passing = Vec::new();
+            let mut failing = 0_usize;
+            for mut hit in hits {
+                // Rescore exactly: index scores may come from quantized codes.
+                hit.score = metric.calculate(&hit.point.vector, query);
+                if Self::compare_score(hit.score, threshold, op, higher_is_better) {
+                    passing.push(hit);
+                } else {
+                    failing += 1;
+                }
+            }
+
+            let margin = (k / 8).max(MIN_BOUNDARY_MARGIN);
+            if passing.len() >= max_results || failing >= margin || exhausted {
+                sort_best_first(&mut passing, higher_is_better);
+                passing.truncate(max_results);
+                return Ok(passing);
+            }
+            k = k.saturating_mul(2).min(cap);
+        }
+    }
+
+    /// Scan path: scores every stored vector, keeps the best `max_results`
+    /// passing ids and hydrates them (expired points are skipped).
+    fn exact_threshold_scan(
+        &self,
+        query: &[f32],
+        threshold: f32,
+        op: CompareOp,
+        max_results: usize,
+        metric: DistanceMetric,
+    ) -> Vec<SearchResult> {
+        let higher_is_better = metric.higher_is_better();
+        let mut scored: Vec<(u64, f32)> = {
+            let vector_storage = self.storage.vector_storage.read();
+            vector_storage
+                .ids()
+                .into_iter()
+                .filter_map(|id| {
+                    let vector = vector_storage.retrieve(id).ok().flatten()?;
+                    let score = metric.calculate(&vector, query);
+                    Self::compare_score(score, threshold, op, higher_is_better)
+                        .then_some((id, score))
+                })
+                .collect()
+        };
+        metric.sort_results(&mut scored);
+
+        // Hydrate in `max_results`-sized chunks so expired points are
+        // replaced by the next-best ids instead of shrinking the result.
+        let mut results = Vec::with_capacity(max_results.min(scored.len()));
+        for chunk in scored.chunks(max_results) {
+            let ids: Vec<u64> = chunk.iter().map(|(id, _)| *id).collect();
+            let points = self.get(&ids);
+            for (point, (_, score)) in points.into_iter().zip(chunk) {
+                if let Some(point) = point {
+                    results.push(SearchResult::new(point, *score));
+                }
+            }
+            if results.len() >= max_results {
+                break;
+            }
+        }
+        results.truncate(max_results);
+        results
+    }
+}
+
+/// Knobs of the expanding index search.
+struct ExpansionPlan {
+    /// Candidate count of the first round.
+    first_k: usize,
+    /// Lower bound on `ef_search` for every round.
+    min_ef: usize,
+    /// Most passing points to return.
+    max_results: usize,
+}
+
+fn sort_best_first(results: &mut [SearchResult], higher_is_better: bool) {
+    if higher_is_better {
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+    } else {
+        results.sort_by(|a, b| a.score.total_cmp(&b.score));
+    }
+}
@@ -0,0 +1,214 @@
+//! Tests for threshold (range) search (`search_by_threshold`) and the
+//! VelesQL `similarity()`-only path built on it.
+
+#![cfg(all(test, feature = "persistence"))]
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+use crate::collection::types::Collection;
+use crate::distance::DistanceMetric;
+use crate::error::Error;
+use crate::test_fixtures::fixtures::make_point_with_payload;
+use crate::velesql::{CompareOp, Parser};
+
+/// Number of points, spread evenly around the unit circle.
+const POINTS: u64 = 400;
+
+/// Unit vector at `id`'s angle, in degrees from the +x axis.
+fn angle_of(id: u64) -> f32 {
+    #[allow(clippy::cast_precision_loss)] // Reason: ids stay below 2^24.
+    let id = id as f32;
+    id * 360.0 / 400.0
+}
+
+fn vector_of(id: u64) -> Vec<f32> {
+    let theta = angle_of(id).to_radians();
+    vec![theta.cos(), theta.sin(), 0.0, 0.0]
+}
+
+/// Points whose angle lies in `[50°, 59°]` are tagged `rare`: they pass
+/// `similarity > 0.5` but rank well beyond the first few dozen neighbours.
+fn setup(metric: DistanceMetric) -> (tempfile::TempDir, Collection) {
+    let dir = tempfile::tempdir().expect("test: tempdir");
+    let col = Collection::create(PathBuf::from(dir.path()), 4, metric).expect("test: create");
+    let points: Vec<_> = (0..POINTS)
+        .map(|id| {
+            let angle = angle_of(id);
+            let category = if (50.0..=59.0).contains(&angle) {
+                "rare"
+            } else {
+                "common"
+            };
+            make_point_with_payload(
+                id,
+                vector_of(id),
+                serde_json::json!({ "category": category }),
+            )
+        })
+        .collect();
+    col.upsert(points).expect("test: upsert");
+    (dir, col)
+}
+
+/// Brute-force reference: ids passing `score <op> threshold`.
+fn expected_ids(
+    col: &Collection,
+    metric: DistanceMetric,
+    query: &[f32],
+    threshold: f32,
+    op: CompareOp,
+) -> BTreeSet<u64> {
+    (0..POINTS)
+        .filter(|id| {
+            let score = metric.calculate(&vector_of(*id), query);
+            Collection::compare_score(score, threshold, op, metric.higher_is_better())
+        })
+        .filter(|id| col.get(&[*id])[0].is_some())
+        .collect()
+}
+
+#[test]
+fn test_gt_returns_every_point_past_the_threshold() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+    let query = [1.0, 0.0, 0.0, 0.0];
+
+    let results = col
+        .search_by_threshold(&query, 0.5, CompareOp::Gt, usize::MAX)
+        .expect("threshold search");
+
+    let got: BTreeSet<u64> = results.iter().map(|r| r.point.id).collect();
+    let expected = expected_ids(&col, DistanceMetric::Cosine, &query, 0.5, CompareOp::Gt);
+    assert!(expected.len() > 100, "fixture should have a wide band");
+    assert_eq!(got, expected);
+    assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+}
+
+#[test]
+fn test_max_results_keeps_the_most_similar_points() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+    let query = [1.0, 0.0, 0.0, 0.0];
+
+    let results = col
+        .search_by_threshold(&query, 0.0, CompareOp::Gte, 5)
+        .expect("threshold search");
+
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[0].point.id, 0);
+    assert!(results.iter().all(|r| r.score > 0.99));
+}
+
+#[test]
+fn test_distance_metric_gt_means_closer_than_threshold() {
+    let (_dir, col) = setup(DistanceMetric::Euclidean);
+    let query = [1.0, 0.0, 0.0, 0.0];
+
+    let results = col
+        .search_by_threshold(&query, 0.5, CompareOp::Gt, usize::MAX)
+        .expect("threshold search");
+
+    let got: BTreeSet<u64> = results.iter().map(|r| r.point.id).collect();
+    let expected = expected_ids(&col, DistanceMetric::Euclidean, &query, 0.5, CompareOp::Gt);
+    assert!(!expected.is_empty());
+    assert_eq!(got, expected);
+    assert!(results.iter().all(|r| r.score < 0.5));
+    assert!(results.windows(2).all(|w| w[0].score <= w[1].score));
+}
+
+#[test]
+fn test_lt_scans_for_the_least_similar_points() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+    let query = [1.0, 0.0, 0.0, 0.0];
+
+    let results = col
+        .search_by_threshold(&query, -0.9, CompareOp::Lt, usize::MAX)
+        .expect("threshold search");
+
+    let got: BTreeSet<u64> = results.iter().map(|r| r.point.id).collect();
+    let expected = expected_ids(&col, DistanceMetric::Cosine, &query, -0.9, CompareOp::Lt);
+    assert!(!expected.is_empty());
+    assert_eq!(got, expected);
+    assert!(
+        got.contains(&200),
+        "the opposite point is the least similar"
+    );
+}
+
+#[test]
+fn test_deleted_points_are_not_returned() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+    col.delete(&[0, 1]).expect("delete");
+
+    for op in [CompareOp::Gt, CompareOp::Lt] {
+        let threshold = if op == CompareOp::Gt { 0.9 } else { 2.0 };
+        let results = col
+            .search_by_threshold(&[1.0, 0.0, 0.0, 0.0], threshold, op, usize::MAX)
+            .expect("threshold search");
+        assert!(results.iter().all(|r| r.point.id > 1), "{op:?}");
+        assert!(!results.is_empty(), "{op:?}");
+    }
+}
+
+#[test]
+fn test_max_results_is_capped_by_max_query_limit() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+    col.set_max_query_limit(Some(7)).expect("set limit");
+
+    let results = col
+        .search_by_threshold(&[1.0, 0.0, 0.0, 0.0], -2.0, CompareOp::Gt, usize::MAX)
+        .expect("threshold search");
+
+    assert_eq!(results.len(), 7);
+}
+
+#[test]
+fn test_dimension_mismatch_is_rejected() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+
+    let err = col
+        .search_by_threshold(&[1.0, 0.0], 0.5, CompareOp::Gt, 10)
+        .expect_err("wrong dimension");
+
+    assert!(matches!(err, Error::DimensionMismatch { .. }), "{err:?}");
+}
+
+#[test]
+fn test_velesql_similarity_with_filter_is_not_biased_by_limit() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+    let query = Parser::parse(
+        "SELECT * FROM c WHERE similarity(vector, $v) > 0.5 AND category = 'rare' LIMIT 3",
+    )
+    .expect("parse");
+    let params = HashMap::from([("v".to_string(), serde_json::json!([1.0, 0.0, 0.0, 0.0]))]);
+
+    let results = col.execute_query(&query, &params).expect("query");
+
+    // The rare points rank past the first 10 x LIMIT neighbours; a top-k
+    // candidate fetch would miss them all.
+    assert_eq!(results.len(), 3);
+    for r in &results {
+        let angle = angle_of(r.point.id);
+        assert!((50.0..=59.0).contains(&angle), "id {}", r.point.id);
+        assert!(r.score > 0.5);
+    }
+}
+
+#[test]
+fn test_velesql_similarity_without_limit_bias_returns_whole_band() {
+    let (_dir, col) = setup(DistanceMetric::Cosine);
+    let query = Parser::parse("SELECT * FROM c WHERE similarity(vector, $v) >= 0.8 LIMIT 1000")
+        .expect("parse");
+    let params = HashMap::from([("v".to_string(), serde_json::json!([1.0, 0.0, 0.0, 0.0]))]);
+
+    let results = col.execute_query(&query, &params).expect("query");
+
+    let got: BTreeSet<u64> = results.iter().map(|r| r.point.id).collect();
+    let expected = expected_ids(
+        &col,
+        DistanceMetric::Cosine,
+        &[1.0, 0.0, 0.0, 0.0],
+        0.8,
+        CompareOp::Gte,
+    );
+    assert_eq!(got, expected);
+}
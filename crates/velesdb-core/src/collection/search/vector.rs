@@ -42,7 +42,7 @@ pub(super) fn tag_vector_component_scores(results: &mut [SearchResult]) {
 }
 
 impl Collection {
-    fn search_ids_with_adc_if_pq(&self, query: &[f32], k: usize) -> Vec<ScoredResult> {
+    pub(super) fn search_ids_with_adc_if_pq(&self, query: &[f32], k: usize) -> Vec<ScoredResult> {
         let config = self.storage.config.read();
         let is_pq = matches!(config.storage_mode, StorageMode::ProductQuantization);
         let higher_is_better = config.metric.higher_is_better();
@@ -262,7 +262,7 @@ impl Collection {
     /// matching the behaviour of the inherent `search()` method.
     /// `#[inline]` preserves pre-refactor inlining (Phase 3.2 learning).
     #[inline]
-    pub(super) fn validate_query_and_read_metric(&self, query: &[f32]) -> Result<DistanceMetric> {
+    pub(crate) fn validate_query_and_read_metric(&self, query: &[f32]) -> Result<DistanceMetric> {
         let config = self.storage.config.read();
         if config.metadata_only {
             return Err(Error::SearchNotSupported(config.name.clone()));
@@ -291,9 +291,11 @@ impl Collection {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
 
+        let payloads = self.payload_reader(&*payload_storage);
         let mut results =
-            resolve::resolve_scored_results(&index_results, &*vector_storage, &*payload_storage);
+            resolve::resolve_scored_results(&index_results, &*vector_storage, &payloads);
         tag_vector_component_scores(&mut results);
+        self.record_reads(results.iter().map(|r| r.point.id));
         results
     }
 
@@ -322,9 +324,11 @@ impl Collection {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
 
+        let payloads = self.payload_reader(&*payload_storage);
         let mut results =
-            resolve::resolve_scored_results(&index_results, &*vector_storage, &*payload_storage);
+            resolve::resolve_scored_results(&index_results, &*vector_storage, &payloads);
         tag_vector_component_scores(&mut results);
+        self.record_reads(results.iter().map(|r| r.point.id));
         Ok(results)
     }
 
@@ -404,7 +408,9 @@ impl Collection {
 
     /// Routes vector search through `QuerySearchOptions` from a WITH clause.
     ///
-    /// Priority: `quality` (from `mode`) > `ef_search` > default `search()`.
+    /// Priority: `quality` (from `mode`) > `ef_search` > default `search()`;
+    /// `entry_probes` then turns the resolved profile into a
+    /// [`SearchQuality::MultiEntry`](crate::SearchQuality::MultiEntry) search.
     /// When `force_rerank` is `Some(true)`, applies explicit SIMD reranking
     /// regardless of quality mode. When `Some(false)`, suppresses automatic
     /// reranking even if the quality mode would enable it.
@@ -418,18 +424,27 @@ impl Collection {
         k: usize,
         opts: &crate::collection::search::query::QuerySearchOptions,
     ) -> Result<Vec<SearchResult>> {
+        // `WITH (metric = ...)` ranks by another metric: an exact scan or a
+        // re-rank of native candidates (see `search_with_metric`).
+        if let Some(metric) = opts.metric {
+            return self.search_with_metric(query, k, metric);
+        }
+
         // When no options are set, fall back to default search.
-        if opts.quality.is_none() && opts.ef_search.is_none() && opts.force_rerank.is_none() {
+        if !opts.has_quality_overrides() {
             return self.search(query, k);
         }
 
         // Resolve the search quality: explicit mode > exact ef_search > default.
-        let quality = opts.quality.unwrap_or_else(|| {
+        let mut quality = opts.quality.unwrap_or_else(|| {
             opts.ef_search.map_or(
                 crate::SearchQuality::Balanced,
                 super::vector_filter::ef_to_quality,
             )
         });
+        if let Some(probes) = opts.entry_probes {
+            quality = quality.with_entry_probes(probes, k);
+        }
 
         // Parity item E: gate Perfect-mode over-cap once here, covering the
         // forced-rerank / no-rerank branches that bypass `search_with_quality`.
@@ -513,7 +528,8 @@ impl Collection {
     /// Searches for the k nearest neighbors with metadata filtering.
     ///
     /// Performs post-filtering: retrieves more candidates from HNSW,
-    /// then filters by metadata conditions.
+    /// then filters by metadata conditions. A partial index covering
+    /// `filter` is searched instead of the full HNSW graph.
     ///
     /// # Arguments
     ///
@@ -533,6 +549,12 @@ impl Collection {
         let metric = self.validate_query_and_read_metric(query)?;
         let higher_is_better = metric.higher_is_better();
 
+        if let Some(index_results) =
+            self.search_partial_index(query, k, filter, crate::SearchQuality::Balanced)
+        {
+            return Ok(self.filter_and_hydrate(index_results?, filter, k, higher_is_better));
+        }
+
         let candidates_k = super::vector_filter::compute_oversampled_k(k, filter);
 
         // Attempt bitmap pre-filter from secondary indexes.
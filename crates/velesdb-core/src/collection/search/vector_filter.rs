@@ -10,12 +10,13 @@
 #![allow(clippy::cast_sign_loss)]
 
 use crate::collection::expiry::{is_payload_expired, now_unix_secs};
+use crate::collection::partial_index::Coverage;
 use crate::collection::search::resolve;
 use crate::collection::types::Collection;
 use crate::error::Result;
 use crate::point::SearchResult;
 use crate::scored_result::ScoredResult;
-use crate::storage::{PayloadStorage, VectorStorage};
+use crate::storage::VectorStorage;
 use crate::validation::validate_dimension_match;
 
 /// Selectivity threshold below which full-scan brute-force is used.
@@ -53,6 +54,10 @@ impl Collection {
         // unbounded brute-force scan, matching the unfiltered entry points.
         self.enforce_perfect_mode_limit(quality)?;
 
+        if let Some(index_results) = self.search_partial_index(query, k, filter, quality) {
+            return Ok(self.filter_and_hydrate(index_results?, filter, k, higher_is_better));
+        }
+
         let index_results = match self.build_prefilter_bitmap(filter) {
             Some(bitmap) if bitmap.is_empty() => return Ok(Vec::new()),
             Some(bitmap) => {
@@ -68,6 +73,32 @@ impl Collection {
         Ok(self.filter_and_hydrate(index_results, filter, k, higher_is_better))
     }
 
+    /// Searches the smallest partial index covering `filter`; `None` when
+    /// no partial index covers it.
+    ///
+    /// An exactly matching index holds only matching points, so `k`
+    /// candidates suffice; a narrower filter still over-fetches for the
+    /// conditions the index does not check.
+    pub(super) fn search_partial_index(
+        &self,
+        query: &[f32],
+        k: usize,
+        filter: &crate::filter::Filter,
+        quality: crate::SearchQuality,
+    ) -> Option<Result<Vec<ScoredResult>>> {
+        let (partial, coverage) = self.partial_index_for(filter)?;
+        partial.record_search();
+        let candidates_k = match coverage {
+            Coverage::Exact => k,
+            Coverage::Narrower => compute_oversampled_k(k, filter),
+        };
+        Some(
+            partial
+                .index
+                .search_with_quality(query, candidates_k, quality),
+        )
+    }
+
     /// Dispatches to full-scan, HNSW+bitmap, or post-filter based on selectivity.
     fn search_with_bitmap_strategy(
         &self,
@@ -127,12 +158,13 @@ impl Collection {
     ) -> Vec<SearchResult> {
         let vector_storage = self.storage.vector_storage.read();
         let payload_storage = self.storage.payload_storage.read();
+        let payloads = self.payload_reader(&*payload_storage);
         let now_secs = now_unix_secs();
 
         let mut results: Vec<SearchResult> = index_results
             .into_iter()
             .filter_map(|sr| {
-                let payload = payload_storage.retrieve(sr.id).ok().flatten();
+                let payload = payloads.retrieve(sr.id);
                 if is_payload_expired(payload.as_ref(), now_secs) {
                     return None;
                 }
@@ -161,6 +193,7 @@ impl Collection {
         resolve::sort_results_by_metric(&mut results, higher_is_better);
         results.truncate(k);
         super::vector::tag_vector_component_scores(&mut results);
+        self.record_reads(results.iter().map(|r| r.point.id));
         results
     }
 }
@@ -191,7 +224,7 @@ const OVERSAMPLE_CAP: f64 = 10_000.0;
 ///
 /// The result is bounded to `[min(k + 10, 10_000), 10_000]`: the candidate
 /// budget saturates at the cap for any `k >= 9_990`, so callers requesting
-/// huge `k` (e.g. LIMIT close to `MAX_LIMIT`) get at most 10_000 candidates
+/// huge `k` (e.g. LIMIT close to `max_query_limit`) get at most 10_000 candidates
 /// instead of panicking — `f64::clamp` asserts `min <= max`, and an
 /// unbounded lower bound of `k + 10` used to violate that for large `k`.
 pub(super) fn compute_oversampled_k(k: usize, filter: &crate::filter::Filter) -> usize {
@@ -229,7 +262,8 @@ fn estimate_condition_selectivity(cond: &crate::filter::Condition) -> f64 {
         | Condition::ArrayContainsAny { .. }
         | Condition::ArrayContainsAll { .. }
         | Condition::GeoDistance { .. }
-        | Condition::GeoBbox { .. } => 0.3,
+        | Condition::GeoBbox { .. }
+        | Condition::Expression { .. } => 0.3,
         Condition::In { values, .. } => {
             #[allow(clippy::cast_precision_loss)]
             let sel = values.len() as f64 * 0.05;
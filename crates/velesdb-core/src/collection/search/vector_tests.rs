@@ -307,8 +307,11 @@ fn test_search_with_filter_and_opts_bitmap_empty_returns_empty() {
     let opts = crate::collection::search::query::QuerySearchOptions {
         quality: Some(crate::SearchQuality::Balanced),
         ef_search: None,
+        entry_probes: None,
         force_rerank: None,
+        metric: None,
         fusion_clause: None,
+        hints: crate::collection::search::query::PlannerHints::default(),
     };
     let results = col
         .search_with_filter_and_opts(&[0.5, 0.5, 0.5, 0.5], 10, &filter, &opts)
@@ -333,8 +336,11 @@ fn test_search_with_filter_and_opts_no_bitmap_falls_back_to_post_filter() {
     let opts = crate::collection::search::query::QuerySearchOptions {
         quality: Some(crate::SearchQuality::Accurate),
         ef_search: None,
+        entry_probes: None,
         force_rerank: None,
+        metric: None,
         fusion_clause: None,
+        hints: crate::collection::search::query::PlannerHints::default(),
     };
     let results = col
         .search_with_filter_and_opts(&[0.5, 0.5, 0.5, 0.5], 5, &filter, &opts)
@@ -373,6 +379,8 @@ fn test_filtered_perfect_search_over_cap_is_rejected() {
         max_vectors_per_collection: 1_000,
         max_payload_size: 1_048_576,
         max_perfect_mode_vectors: 1,
+        max_query_limit: 100_000,
+        ..RuntimeLimits::default()
     });
 
     let filter = crate::filter::Filter::new(crate::filter::Condition::Eq {
@@ -382,8 +390,11 @@ fn test_filtered_perfect_search_over_cap_is_rejected() {
     let opts = crate::collection::search::query::QuerySearchOptions {
         quality: Some(crate::SearchQuality::Perfect),
         ef_search: None,
+        entry_probes: None,
         force_rerank: None,
+        metric: None,
         fusion_clause: None,
+        hints: crate::collection::search::query::PlannerHints::default(),
     };
 
     let err = col
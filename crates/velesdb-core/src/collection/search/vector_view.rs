@@ -0,0 +1,191 @@
+//! Zero-copy vector reads: borrowed views into the mmapped vector storage.
+//!
+//! [`Collection::get`](crate::VectorCollection::get) and
+//! [`search`](crate::VectorCollection::search) copy every vector into a
+//! fresh `Vec<f32>`. The views here resolve ids, scores and payloads the same
+//! way but hand out vectors as [`VectorSliceGuard`]s pointing straight into
+//! the mapping, saving one allocation and one copy per point.
+//!
+//! A view holds the collection's vector storage read lock until it is
+//! dropped: writes to the collection wait for it, so keep views short-lived
+//! and never write to the same collection while holding one.
+
+use parking_lot::RwLockReadGuard;
+
+use super::resolve;
+use super::vector::tag_vector_component_scores;
+use crate::collection::expiry::{is_payload_expired, now_unix_secs};
+use crate::collection::types::Collection;
+use crate::error::Result;
+use crate::point::{Point, PointProjection, SearchResult};
+use crate::storage::{MmapStorage, PayloadStorage, VectorSliceGuard, VectorStorage};
+
+/// Read-locked handle on a collection's vector storage.
+pub struct VectorReader<'a> {
+    storage: RwLockReadGuard<'a, MmapStorage>,
+}
+
+impl VectorReader<'_> {
+    /// Borrows the stored vector of `id`, or `None` if it has none.
+    ///
+    /// The mapping cannot be remapped while the reader is alive, so the
+    /// returned guard always dereferences successfully.
+    #[must_use]
+    pub fn vector(&self, id: u64) -> Option<VectorSliceGuard<'_>> {
+        self.storage.retrieve_ref(id).ok().flatten()
+    }
+
+    /// Number of stored vectors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns `true` if no vector is stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.storage.len() == 0
+    }
+}
+
+/// Points returned by [`Collection::get_view`], vectors borrowed.
+pub struct PointsView<'a> {
+    reader: VectorReader<'a>,
+    points: Vec<Option<Point>>,
+}
+
+impl PointsView<'_> {
+    /// The points in request order, `None` for missing or expired ids.
+    /// Their `vector` fields are empty: read vectors through [`Self::iter`]
+    /// or [`Self::reader`].
+    #[must_use]
+    pub fn points(&self) -> &[Option<Point>] {
+        &self.points
+    }
+
+    /// The found points with their borrowed vectors, in request order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, VectorSliceGuard<'_>)> {
+        self.points
+            .iter()
+            .flatten()
+            .filter_map(|p| Some((p, self.reader.vector(p.id)?)))
+    }
+
+    /// The underlying reader, for lookups by id.
+    #[must_use]
+    pub fn reader(&self) -> &VectorReader<'_> {
+        &self.reader
+    }
+}
+
+/// Results of [`Collection::search_view`], vectors borrowed.
+pub struct SearchView<'a> {
+    reader: VectorReader<'a>,
+    results: Vec<SearchResult>,
+}
+
+impl SearchView<'_> {
+    /// The hits, best first. Their `point.vector` fields are empty: read
+    /// vectors through [`Self::iter`] or [`Self::reader`].
+    #[must_use]
+    pub fn results(&self) -> &[SearchResult] {
+        &self.results
+    }
+
+    /// The hits with their borrowed vectors, best first.
+    pub fn iter(&self) -> impl Iterator<Item = (&SearchResult, VectorSliceGuard<'_>)> {
+        self.results
+            .iter()
+            .filter_map(|r| Some((r, self.reader.vector(r.point.id)?)))
+    }
+
+    /// Number of hits.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns `true` if nothing matched.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// The underlying reader, for lookups by id.
+    #[must_use]
+    pub fn reader(&self) -> &VectorReader<'_> {
+        &self.reader
+    }
+}
+
+impl Collection {
+    /// Read-locks the vector storage for zero-copy lookups by id.
+    ///
+    /// Unlike [`get`](Self::get), the reader does not apply the TTL filter:
+    /// it sees every stored vector.
+    pub fn vector_reader(&self) -> VectorReader<'_> {
+        VectorReader {
+            storage: self.storage.vector_storage.read(),
+        }
+    }
+
+    /// [`get`](Self::get) with vectors borrowed from storage instead of
+    /// copied. Visibility is identical; the payload is kept when
+    /// `with_payload` is set.
+    pub fn get_view(&self, ids: &[u64], with_payload: bool) -> PointsView<'_> {
+        let now_secs = now_unix_secs();
+        // LOCK ORDER: vector_storage(2) → payload_storage(3), as in `get_raw`.
+        let reader = self.vector_reader();
+        let payload_storage = self.storage.payload_storage.read();
+        let points = ids
+            .iter()
+            .map(|&id| {
+                if !reader.storage.contains(id) {
+                    return None;
+                }
+                let payload = payload_storage.retrieve(id).ok().flatten();
+                if is_payload_expired(payload.as_ref(), now_secs) {
+                    return None;
+                }
+                Some(Point {
+                    id,
+                    vector: Vec::new(),
+                    payload: payload.filter(|_| with_payload),
+                    sparse_vectors: None,
+                })
+            })
+            .collect();
+        drop(payload_storage);
+        PointsView { reader, points }
+    }
+
+    /// [`search`](Self::search) with vectors borrowed from storage instead
+    /// of copied. Ranking and visibility are identical; the payload is kept
+    /// when `with_payload` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query vector dimension doesn't match the
+    /// collection, or if this is a metadata-only collection.
+    pub fn search_view(
+        &self,
+        query: &[f32],
+        k: usize,
+        with_payload: bool,
+    ) -> Result<SearchView<'_>> {
+        let index_results = self.search_ids(query, k)?;
+        let reader = self.vector_reader();
+        let payload_storage = self.storage.payload_storage.read();
+        let payloads = self.payload_reader(&*payload_storage);
+        let mut results = resolve::resolve_scored_results_without_vectors(
+            &index_results,
+            PointProjection::new(false, with_payload),
+            &*reader.storage,
+            &payloads,
+        );
+        drop(payloads);
+        drop(payload_storage);
+        tag_vector_component_scores(&mut results);
+        Ok(SearchView { reader, results })
+    }
+}
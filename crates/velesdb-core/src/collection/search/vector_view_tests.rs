@@ -0,0 +1,112 @@
+#![cfg(all(test, feature = "persistence"))]
+
+use crate::{
+    collection::{Collection, EXPIRES_AT_KEY},
+    distance::DistanceMetric,
+    point::Point,
+    vector_ref::VectorRef,
+};
+use std::path::PathBuf;
+
+fn seeded_collection() -> (tempfile::TempDir, Collection) {
+    let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+    let collection = Collection::create(PathBuf::from(temp_dir.path()), 3, DistanceMetric::Cosine)
+        .expect("collection should be created");
+    collection
+        .upsert(vec![
+            Point::new(1, vec![1.0, 0.0, 0.0], Some(serde_json::json!({"t": "a"}))),
+            Point::new(2, vec![0.9, 0.1, 0.0], Some(serde_json::json!({"t": "b"}))),
+            Point::new(3, vec![0.0, 0.0, 1.0], Some(serde_json::json!({"t": "c"}))),
+            Point::new(
+                4,
+                vec![0.0, 1.0, 0.0],
+                Some(serde_json::json!({EXPIRES_AT_KEY: 1})),
+            ),
+        ])
+        .expect("upsert should succeed");
+    (temp_dir, collection)
+}
+
+#[test]
+fn test_search_view_matches_search() {
+    let (_dir, collection) = seeded_collection();
+    let query = [1.0, 0.0, 0.0];
+    let full = collection.search(&query, 4).expect("search");
+    let view = collection
+        .search_view(&query, 4, true)
+        .expect("search view");
+
+    assert_eq!(view.len(), full.len());
+    for ((hit, vector), expected) in view.iter().zip(&full) {
+        assert_eq!(hit.point.id, expected.point.id);
+        assert!((hit.score - expected.score).abs() < f32::EPSILON);
+        assert_eq!(hit.point.payload, expected.point.payload);
+        assert!(hit.point.vector.is_empty());
+        assert!(hit.component_scores.is_some());
+        assert_eq!(&*vector, expected.point.vector.as_slice());
+    }
+}
+
+#[test]
+fn test_search_view_without_payload_and_dimension_check() {
+    let (_dir, collection) = seeded_collection();
+    let view = collection
+        .search_view(&[1.0, 0.0, 0.0], 2, false)
+        .expect("search view");
+    assert!(view.results().iter().all(|r| r.point.payload.is_none()));
+    drop(view);
+
+    assert!(collection.search_view(&[1.0, 0.0], 2, false).is_err());
+}
+
+#[test]
+fn test_get_view_matches_get_visibility() {
+    let (_dir, collection) = seeded_collection();
+    let ids = [3, 99, 4, 1];
+    let copied = collection.get(&ids);
+    let view = collection.get_view(&ids, true);
+
+    let found: Vec<Option<u64>> = view
+        .points()
+        .iter()
+        .map(|p| p.as_ref().map(|p| p.id))
+        .collect();
+    assert_eq!(found, vec![Some(3), None, None, Some(1)]);
+    for ((point, vector), expected) in view.iter().zip(copied.iter().flatten()) {
+        assert_eq!(point.payload, expected.payload);
+        assert_eq!(VectorRef::as_slice(&vector), expected.vector.as_slice());
+    }
+}
+
+#[test]
+fn test_vector_reader_sees_unexpired_and_expired_vectors() {
+    let (_dir, collection) = seeded_collection();
+    let reader = collection.vector_reader();
+
+    assert_eq!(reader.len(), 4);
+    assert_eq!(
+        &*reader.vector(4).expect("expired vector still stored"),
+        &[0.0, 1.0, 0.0]
+    );
+    assert!(reader.vector(99).is_none());
+
+    // Several guards can be held at once under one reader.
+    let a = reader.vector(1).expect("vector 1");
+    let b = reader.vector(2).expect("vector 2");
+    assert_eq!(a.dimension(), b.dimension());
+}
+
+#[test]
+fn test_writes_proceed_after_view_dropped() {
+    let (_dir, collection) = seeded_collection();
+    {
+        let view = collection.get_view(&[1], false);
+        assert_eq!(view.iter().count(), 1);
+    }
+    collection
+        .upsert(vec![Point::without_payload(1, vec![0.0, 0.0, 1.0])])
+        .expect("upsert after view is dropped");
+    let view = collection.get_view(&[1], false);
+    let (_, vector) = view.iter().next().expect("point 1");
+    assert_eq!(&*vector, &[0.0, 0.0, 1.0]);
+}
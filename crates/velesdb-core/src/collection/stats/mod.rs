@@ -28,8 +28,13 @@ pub(crate) use histogram::next_after;
 pub(crate) use histogram::HistogramBuilder;
 pub use histogram::{Histogram, HistogramBucket};
 
+mod vector;
+pub use vector::{VectorStats, MAX_VECTOR_STATS_SAMPLE};
+
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod vector_tests;
 
 /// Statistics for a collection.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
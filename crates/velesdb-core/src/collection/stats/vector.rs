@@ -0,0 +1,147 @@
+//! Vector-space statistics computed over a sample of stored vectors.
+//!
+//! Unlike [`CollectionStats`](super::CollectionStats), which feeds the query
+//! planner from payload data, these figures describe the embedding space
+//! itself and are meant for humans tuning a collection:
+//!
+//! - **Norms** — tightly clustered around 1.0 means the vectors are already
+//!   normalized and Cosine and `DotProduct` rank identically.
+//! - **Mean pairwise cosine** — a high value means the embeddings occupy a
+//!   narrow cone (anisotropy), which hurts quantization and HNSW recall.
+//! - **Intrinsic dimension** — the number of degrees of freedom the data
+//!   actually uses, a guide for PQ subspaces and HNSW `m`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::simd_native::{cosine_similarity_native, norm_native, squared_l2_native};
+
+/// Largest sample `Collection::vector_stats` will draw; pairwise work is
+/// quadratic in the sample size.
+pub const MAX_VECTOR_STATS_SAMPLE: usize = 2_000;
+
+/// Summary of a collection's vector space, computed on a uniform sample.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VectorStats {
+    /// Number of vectors the statistics were computed on.
+    pub sample_size: usize,
+    /// Vector dimension.
+    pub dimension: usize,
+    /// Component-wise mean of the sampled vectors.
+    pub centroid: Vec<f32>,
+    /// Mean L2 norm.
+    pub norm_mean: f32,
+    /// Smallest L2 norm.
+    pub norm_min: f32,
+    /// Median L2 norm.
+    pub norm_p50: f32,
+    /// 90th percentile L2 norm.
+    pub norm_p90: f32,
+    /// 99th percentile L2 norm.
+    pub norm_p99: f32,
+    /// Largest L2 norm.
+    pub norm_max: f32,
+    /// Mean cosine similarity over all sampled pairs, whatever the collection
+    /// metric. `None` with fewer than two vectors.
+    pub mean_pairwise_cosine: Option<f32>,
+    /// `TwoNN` maximum-likelihood estimate of the intrinsic dimensionality
+    /// (Facco et al., 2017), from Euclidean nearest-neighbour distances.
+    /// `None` when fewer than three vectors have distinct neighbours.
+    pub intrinsic_dimension: Option<f64>,
+}
+
+impl VectorStats {
+    /// Computes the statistics for `vectors`, which must share `dimension`.
+    ///
+    /// Runs in `O(n² · dimension)`: every pair is visited once to accumulate
+    /// the cosine mean and each vector's two nearest neighbours.
+    #[must_use]
+    pub fn compute(vectors: &[Vec<f32>], dimension: usize) -> Self {
+        let n = vectors.len();
+        if n == 0 {
+            return Self {
+                dimension,
+                ..Self::default()
+            };
+        }
+
+        let mut sums = vec![0.0f64; dimension];
+        for v in vectors {
+            for (sum, &x) in sums.iter_mut().zip(v) {
+                *sum += f64::from(x);
+            }
+        }
+        let centroid = sums.iter().map(|s| (s / n as f64) as f32).collect();
+
+        let mut norms: Vec<f32> = vectors.iter().map(|v| norm_native(v)).collect();
+        norms.sort_unstable_by(f32::total_cmp);
+        let norm_mean = norms.iter().sum::<f32>() / n as f32;
+
+        let (mean_pairwise_cosine, intrinsic_dimension) = pairwise_stats(vectors);
+
+        Self {
+            sample_size: n,
+            dimension,
+            centroid,
+            norm_mean,
+            norm_min: norms[0],
+            norm_p50: percentile(&norms, 0.50),
+            norm_p90: percentile(&norms, 0.90),
+            norm_p99: percentile(&norms, 0.99),
+            norm_max: norms[n - 1],
+            mean_pairwise_cosine,
+            intrinsic_dimension,
+        }
+    }
+}
+
+/// Nearest-rank percentile of an ascending, non-empty slice.
+#[allow(clippy::cast_sign_loss)] // Reason: q is in [0, 1], so the rank is non-negative.
+fn percentile(sorted: &[f32], q: f64) -> f32 {
+    let rank = (q * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Single pass over all pairs: mean cosine similarity and the `TwoNN`
+/// intrinsic-dimension estimate.
+fn pairwise_stats(vectors: &[Vec<f32>]) -> (Option<f32>, Option<f64>) {
+    let n = vectors.len();
+    if n < 2 {
+        return (None, None);
+    }
+
+    // Squared distances to the first and second nearest neighbour.
+    let mut nearest = vec![(f32::INFINITY, f32::INFINITY); n];
+    let mut cosine_sum = 0.0f64;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            cosine_sum += f64::from(cosine_similarity_native(&vectors[i], &vectors[j]));
+            let d = squared_l2_native(&vectors[i], &vectors[j]);
+            push_neighbour(&mut nearest[i], d);
+            push_neighbour(&mut nearest[j], d);
+        }
+    }
+    let pairs = (n * (n - 1) / 2) as f64;
+    let mean_cosine = (cosine_sum / pairs) as f32;
+
+    // TwoNN: with mu = r2 / r1, d = N / sum(ln mu). Points with a duplicate
+    // neighbour (r1 = 0) carry no information and are skipped.
+    let log_ratios: Vec<f64> = nearest
+        .iter()
+        .filter(|(r1, r2)| *r1 > 0.0 && r2.is_finite())
+        .map(|&(r1, r2)| 0.5 * (f64::from(r2) / f64::from(r1)).ln())
+        .collect();
+    let log_sum: f64 = log_ratios.iter().sum();
+    let intrinsic =
+        (log_ratios.len() >= 3 && log_sum > 0.0).then(|| log_ratios.len() as f64 / log_sum);
+
+    (Some(mean_cosine), intrinsic)
+}
+
+/// Keeps the two smallest squared distances seen so far.
+fn push_neighbour(slot: &mut (f32, f32), d: f32) {
+    if d < slot.0 {
+        *slot = (d, slot.0);
+    } else if d < slot.1 {
+        slot.1 = d;
+    }
+}
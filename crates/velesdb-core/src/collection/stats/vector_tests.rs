@@ -0,0 +1,82 @@
+//! Tests for `VectorStats::compute`.
+
+use super::VectorStats;
+use rand::{RngExt, SeedableRng};
+
+fn uniform_cube(n: usize, dim: usize, seed: u64) -> Vec<Vec<f32>> {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    (0..n)
+        .map(|_| (0..dim).map(|_| rng.random_range(-1.0f32..1.0)).collect())
+        .collect()
+}
+
+#[test]
+fn test_vector_stats_empty_sample() {
+    let stats = VectorStats::compute(&[], 8);
+    assert_eq!(stats.sample_size, 0);
+    assert_eq!(stats.dimension, 8);
+    assert!(stats.centroid.is_empty());
+    assert_eq!(stats.mean_pairwise_cosine, None);
+    assert_eq!(stats.intrinsic_dimension, None);
+}
+
+#[test]
+fn test_vector_stats_norm_percentiles() {
+    #[allow(clippy::cast_precision_loss)] // Reason: i ≤ 100.
+    let vectors: Vec<Vec<f32>> = (1..=100).map(|i| vec![i as f32, 0.0]).collect();
+    let stats = VectorStats::compute(&vectors, 2);
+
+    assert_eq!(stats.sample_size, 100);
+    assert!((stats.norm_min - 1.0).abs() < f32::EPSILON);
+    assert!((stats.norm_p50 - 50.0).abs() < f32::EPSILON);
+    assert!((stats.norm_p90 - 90.0).abs() < f32::EPSILON);
+    assert!((stats.norm_p99 - 99.0).abs() < f32::EPSILON);
+    assert!((stats.norm_max - 100.0).abs() < f32::EPSILON);
+    assert!((stats.norm_mean - 50.5).abs() < 1e-4);
+    assert!((stats.centroid[0] - 50.5).abs() < 1e-4);
+    // Every vector points the same way.
+    assert!((stats.mean_pairwise_cosine.unwrap() - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_vector_stats_single_vector_has_no_pairwise_figures() {
+    let stats = VectorStats::compute(&[vec![3.0, 4.0]], 2);
+    assert!((stats.norm_mean - 5.0).abs() < f32::EPSILON);
+    assert_eq!(stats.mean_pairwise_cosine, None);
+    assert_eq!(stats.intrinsic_dimension, None);
+}
+
+#[test]
+fn test_intrinsic_dimension_tracks_the_data_not_the_embedding() {
+    // A 2-D square embedded in 16 dimensions.
+    let flat: Vec<Vec<f32>> = uniform_cube(800, 2, 1)
+        .into_iter()
+        .map(|v| {
+            let mut padded = vec![0.0; 16];
+            padded[3] = v[0];
+            padded[11] = v[1];
+            padded
+        })
+        .collect();
+    let flat_id = VectorStats::compute(&flat, 16).intrinsic_dimension.unwrap();
+    assert!(
+        (1.5..2.5).contains(&flat_id),
+        "2-D data estimated at {flat_id}"
+    );
+
+    let full_id = VectorStats::compute(&uniform_cube(800, 8, 2), 8)
+        .intrinsic_dimension
+        .unwrap();
+    assert!(
+        (5.5..10.5).contains(&full_id),
+        "8-D data estimated at {full_id}"
+    );
+}
+
+#[test]
+fn test_intrinsic_dimension_ignores_duplicates() {
+    let vectors = vec![vec![1.0, 1.0]; 10];
+    let stats = VectorStats::compute(&vectors, 2);
+    assert_eq!(stats.intrinsic_dimension, None);
+    assert!((stats.mean_pairwise_cosine.unwrap() - 1.0).abs() < 1e-4);
+}
@@ -141,13 +141,34 @@ impl AsyncIndexBuilder {
             // Another build is in progress — skip
             return Ok(0);
         }
+        Ok(self.build_claimed(hnsw_index))
+    }
+
+    /// Like [`Self::flush_sync`], but waits for an in-progress build
+    /// instead of skipping it.
+    ///
+    /// On return, every vector enqueued before the call is in the HNSW
+    /// index: either this call inserted it, or the build it waited for did.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HNSW insertion fails.
+    pub fn flush_and_wait(&self, hnsw_index: &HnswIndex) -> crate::error::Result<usize> {
+        while self.building.swap(true, Ordering::AcqRel) {
+            std::thread::yield_now();
+        }
+        Ok(self.build_claimed(hnsw_index))
+    }
 
+    /// Drains the buffer into `hnsw_index`; the caller must have claimed
+    /// the `building` flag, which is released on return.
+    fn build_claimed(&self, hnsw_index: &HnswIndex) -> usize {
         let vectors = self.drain_buffer();
         let count = vectors.len();
 
         if count == 0 {
             self.building.store(false, Ordering::Release);
-            return Ok(0);
+            return 0;
         }
 
         let pairs: Vec<(u64, &[f32])> = vectors.iter().map(|(id, v)| (*id, v.as_slice())).collect();
@@ -158,7 +179,7 @@ impl AsyncIndexBuilder {
 
         tracing::debug!("AsyncIndexBuilder::flush_sync: indexed {inserted}/{count} vectors");
 
-        Ok(inserted)
+        inserted
     }
 
     /// Returns `true` if a build is currently in progress.
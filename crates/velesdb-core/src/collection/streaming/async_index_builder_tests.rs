@@ -255,3 +255,34 @@ fn test_trigger_build_async_skips_when_already_building() {
     // Restore invariant so builder is not left in a permanently-locked state.
     builder.force_set_building(false);
 }
+
+/// `flush_and_wait` must not skip like `flush_sync` when another build holds
+/// the flag: it waits for that build, then drains what is left.
+#[test]
+fn test_flush_and_wait_waits_for_in_progress_build() {
+    let dim = 4;
+    let index = make_index(dim);
+    let builder = std::sync::Arc::new(AsyncIndexBuilder::new(default_config()));
+    builder.enqueue(vec![
+        (1, vec![1.0, 0.0, 0.0, 0.0]),
+        (2, vec![0.0, 1.0, 0.0, 0.0]),
+    ]);
+
+    builder.force_set_building(true);
+    assert_eq!(builder.flush_sync(&index).expect("flush_sync"), 0);
+
+    let releaser = {
+        let builder = std::sync::Arc::clone(&builder);
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            builder.force_set_building(false);
+        })
+    };
+    let inserted = builder.flush_and_wait(&index).expect("flush_and_wait");
+    releaser.join().expect("releaser thread");
+
+    assert_eq!(inserted, 2);
+    assert_eq!(builder.buffer_len(), 0);
+    assert_eq!(index.len(), 2);
+    assert!(!builder.is_building());
+}
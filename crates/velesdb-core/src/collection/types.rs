@@ -134,7 +134,7 @@ impl CollectionType {
 /// Runtime guard-rail limits threaded into a `Collection` from the live
 /// [`VelesConfig::limits`](crate::config::LimitsConfig).
 ///
-/// These three fields are the subset of `LimitsConfig` enforced at the
+/// These fields are the subset of `LimitsConfig` enforced at the
 /// `Collection` ingest/search boundary (the other two — `max_dimensions`
 /// and `max_collections` — are enforced at `Database` collection-creation
 /// time). They are **not** persisted to `config.json`: each `Database`
@@ -157,10 +157,25 @@ pub(crate) struct RuntimeLimits {
     pub(crate) max_payload_size: usize,
     /// Maximum collection size for which Perfect (brute-force) search is allowed.
     pub(crate) max_perfect_mode_vectors: usize,
+    /// Maximum rows a single query may request, unless the collection
+    /// overrides it ([`CollectionConfig::max_query_limit`]).
+    pub(crate) max_query_limit: usize,
+    /// Maximum searches running at once (`0` = unlimited).
+    pub(crate) max_concurrent_searches: usize,
+    /// Maximum scans running at once (`0` = unlimited).
+    pub(crate) max_concurrent_scans: usize,
+    /// Queries that may wait for a slot, per kind.
+    pub(crate) max_admission_queue: usize,
+    /// How long a queued query waits for a slot, in milliseconds.
+    pub(crate) admission_queue_timeout_ms: u64,
+    /// What upserts do with NaN or infinite vector components.
+    pub(crate) non_finite_vectors: crate::config::NonFiniteVectorPolicy,
+    /// Reject all-zero vectors on cosine collections.
+    pub(crate) reject_zero_norm_vectors: bool,
 }
 
 impl RuntimeLimits {
-    /// Extracts the three enforced fields from a [`LimitsConfig`](crate::config::LimitsConfig).
+    /// Extracts the enforced fields from a [`LimitsConfig`](crate::config::LimitsConfig).
     ///
     /// Single mapping point reused by both [`Default`] and the `Database`
     /// registration push, so the field correspondence never drifts.
@@ -170,6 +185,13 @@ impl RuntimeLimits {
             max_vectors_per_collection: limits.max_vectors_per_collection,
             max_payload_size: limits.max_payload_size,
             max_perfect_mode_vectors: limits.max_perfect_mode_vectors,
+            max_query_limit: limits.max_query_limit,
+            max_concurrent_searches: limits.max_concurrent_searches,
+            max_concurrent_scans: limits.max_concurrent_scans,
+            max_admission_queue: limits.max_admission_queue,
+            admission_queue_timeout_ms: limits.admission_queue_timeout_ms,
+            non_finite_vectors: limits.non_finite_vectors,
+            reject_zero_norm_vectors: limits.reject_zero_norm_vectors,
         }
     }
 }
@@ -187,6 +209,8 @@ impl Default for RuntimeLimits {
 // Canonical order (acquire lower numbers first):
 //   1. config
 //   1b. payload_mirror   (held while acquiring 2 and 3 during the lazy build)
+//   1c. commits          (commit gate: writers hold it across a multi-storage
+//                          write; scans hold it only while taking 2 and 3)
 //   2. vector_storage
 //   3. payload_storage
 //   3b. edge_wal_lock    (see below — sometimes nested inside 3, sometimes
@@ -194,6 +218,8 @@ impl Default for RuntimeLimits {
 //   4. sq8_cache / binary_cache / pq_cache  (any order among themselves)
 //   5. pq_quantizer → pq_training_buffer
 //   6. secondary_indexes
+//   6b. partial_indexes  (acquired alone: entries are cloned out and the
+//                          lock released before 2 and 3 are read)
 //   7. property_index / range_index         (any order among themselves)
 //   8. (reserved — edge_store now uses internal sharded locking)
 //   9. sparse_indexes
@@ -284,6 +310,12 @@ pub(crate) struct StorageState {
     /// (2) and `payload_storage` (3) during the lazy build; mutation hooks
     /// and queries acquire it with no other collection lock held.
     pub(crate) payload_mirror: Arc<crate::collection::payload_mirror::PayloadMirror>,
+
+    /// Commit gate and snapshot sequence number (see
+    /// [`read_snapshot`](crate::collection::read_snapshot)).
+    ///
+    /// Lock order position: **1c**.
+    pub(crate) commits: Arc<crate::collection::read_snapshot::CommitSequence>,
 }
 
 /// Graph node/edge indexes, advisors and the edge store.
@@ -381,6 +413,19 @@ pub(crate) struct QueryState {
     /// Lock order position: **6**.
     pub(super) secondary_indexes: Arc<RwLock<HashMap<String, SecondaryIndex>>>,
 
+    /// Points whose payload carries a TTL ([`EXPIRES_AT_KEY`]), kept by the
+    /// same hooks that maintain `secondary_indexes` and recounted on every
+    /// index backfill. Index-only counts are exact only while it is zero.
+    ///
+    /// [`EXPIRES_AT_KEY`]: crate::collection::EXPIRES_AT_KEY
+    pub(super) indexed_ttl_points: Arc<std::sync::atomic::AtomicUsize>,
+
+    /// Partial vector indexes, by name (see [`crate::collection::partial_index`]).
+    ///
+    /// Lock order position: **6b** (acquired alone; the graphs are updated
+    /// under the storage read locks after it is released).
+    pub(crate) partial_indexes: Arc<RwLock<crate::collection::partial_index::PartialIndexes>>,
+
     /// Scalar `ORDER BY <field>` index advisor (EPIC-081 phase 3a).
     ///
     /// Records eligible `ORDER BY` queries that fell back to the exhaustive
@@ -410,6 +455,12 @@ pub(crate) struct QueryState {
     /// at 11). Protects only disk I/O — no other lock is held while this one is
     /// held, so it cannot participate in a deadlock chain.
     pub(super) stats_io_mutex: Arc<Mutex<()>>,
+
+    /// Parked cursors of [`Collection::search_paginated`].
+    ///
+    /// Lock order position: **13** (acquired standalone, never while a
+    /// storage lock is held).
+    pub(super) search_cursors: Arc<crate::collection::search::SearchCursors>,
 }
 
 /// Monotonic generation counters that gate compiled-plan cache invalidation
@@ -509,6 +560,18 @@ pub(crate) struct StreamingState {
     /// `async_index_builder`).
     pub(crate) auto_reindex:
         Arc<RwLock<Option<Arc<crate::collection::auto_reindex::AutoReindexManager>>>>,
+
+    /// Runtime-only index build progress callback, registered via
+    /// [`VectorCollection::on_progress`](crate::VectorCollection::on_progress).
+    /// **Not persisted.**
+    ///
+    /// Its lock is a leaf: held only to read or swap the callback, never
+    /// while the callback runs.
+    pub(crate) progress: crate::collection::index_progress::ProgressListener,
+
+    /// Runtime-only change feed behind `subscribe`, shared by every clone.
+    /// **Not persisted.**
+    pub(crate) change_feed: crate::collection::change_feed::ChangeFeed,
 }
 
 /// Query-execution guard-rails and the runtime ingest/search limits.
@@ -539,6 +602,33 @@ pub(crate) struct RuntimeGuards {
     /// the setter can run after the registry has cloned the collection.
     /// **Not persisted** — re-pushed on every open.
     pub(crate) runtime_limits: Arc<RwLock<RuntimeLimits>>,
+
+    /// Per-kind concurrency limiters fed from `runtime_limits`; shared by
+    /// every clone so all handles of a collection compete for the same
+    /// slots. Its mutexes are leaves, never held across query execution.
+    pub(crate) admission: Arc<crate::collection::admission::AdmissionControl>,
+
+    /// Runtime-only user-defined upsert validator, registered via
+    /// [`VectorCollection::set_upsert_validator`](crate::VectorCollection::set_upsert_validator).
+    /// **Not persisted** — register again after reopening.
+    pub(crate) upsert_validator: crate::collection::upsert_validator::UpsertValidator,
+
+    /// Responses recorded for request idempotency keys, shared by every
+    /// clone. Persisted in `idempotency.json`, loaded on first use.
+    pub(crate) idempotency: crate::collection::idempotency::IdempotencyLog,
+
+    /// Access days and archived ids of the archive tier, shared by every
+    /// clone. Persisted in `access.bin` / `archive.bin`, loaded on first use.
+    pub(crate) archive: crate::collection::archive::ArchiveTier,
+
+    /// Per-point access counts and the payload LFU cache, shared by every
+    /// clone. Counts persisted in `access_counts.bin`, loaded on first use.
+    pub(crate) access: crate::collection::access_stats::AccessTracker,
+
+    /// Runtime-only fault policy of the `chaos` feature, shared by every
+    /// clone. **Not persisted.**
+    #[cfg(feature = "chaos")]
+    pub(crate) faults: crate::collection::chaos::FaultInjector,
 }
 
 /// A collection of vectors with associated metadata.
@@ -594,6 +684,7 @@ impl Collection {
     /// collection. The value is **not** persisted — each open re-pushes it.
     pub(crate) fn set_runtime_limits(&self, limits: RuntimeLimits) {
         *self.runtime.runtime_limits.write() = limits;
+        self.runtime.admission.configure(&limits);
     }
 
     /// Returns the current runtime limits snapshot (`Copy`, no lock retained).
@@ -665,24 +756,16 @@ impl Collection {
     ///
     /// The single shared payload-size gate, reused by every ingest path
     /// (`Point` upsert, raw bulk, and graph node writes). Measures the
-    /// serialized length with a bounded counting writer that stops as soon
-    /// as the running total passes `cap`, so it never materializes a throwaway
-    /// `Vec` and never serializes more than `cap + 1` bytes. Payloads that
-    /// fail to serialize (the JSON value is in-memory and infallible in
-    /// practice) are accepted.
+    /// serialized length with [`payload_exceeds`](crate::collection::payload_exceeds),
+    /// which never materializes a throwaway `Vec` and never serializes more
+    /// than `cap + 1` bytes. Payloads that fail to serialize (the JSON value
+    /// is in-memory and infallible in practice) are accepted.
     pub(crate) fn enforce_payload_value_size(
         id: u64,
         payload: &serde_json::Value,
         cap: usize,
     ) -> crate::error::Result<()> {
-        let mut counter = crate::collection::payload_size::BoundedCounter::new(cap);
-        if serde_json::to_writer(&mut counter, payload).is_err() && !counter.exceeded() {
-            // A real I/O error from the counter only ever signals "over cap"
-            // (see `BoundedCounter`); any other serde error is treated as
-            // unserializable and accepted, matching prior behavior.
-            return Ok(());
-        }
-        if counter.exceeded() {
+        if crate::collection::payload_size::payload_exceeds(payload, cap) {
             return Err(crate::error::Error::GuardRail(format!(
                 "point {id} payload exceeds max_payload_size cap of {cap} bytes; \
                  raise `limits.max_payload_size` in VelesConfig"
@@ -834,6 +917,33 @@ impl Collection {
         self.streaming.auto_reindex.write().take()
     }
 
+    /// Registers the index build progress callback, replacing any previous
+    /// one.
+    pub(crate) fn on_progress<F>(&self, callback: F)
+    where
+        F: Fn(crate::collection::index_progress::IndexProgress) + Send + Sync + 'static,
+    {
+        self.streaming.progress.set(Arc::new(callback));
+    }
+
+    /// Removes the index build progress callback, if any.
+    pub(crate) fn clear_progress(&self) {
+        self.streaming.progress.clear();
+    }
+
+    /// Registers the upsert validator, replacing any previous one.
+    pub(crate) fn set_upsert_validator<F>(&self, validator: F)
+    where
+        F: Fn(&crate::point::Point) -> crate::error::Result<()> + Send + Sync + 'static,
+    {
+        self.runtime.upsert_validator.set(Arc::new(validator));
+    }
+
+    /// Removes the upsert validator, if any.
+    pub(crate) fn clear_upsert_validator(&self) {
+        self.runtime.upsert_validator.clear();
+    }
+
     /// Returns a clone of the currently attached auto-reindex manager, if any.
     ///
     /// External consumers use this to inspect the manager state, register
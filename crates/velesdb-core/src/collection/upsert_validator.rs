@@ -0,0 +1,54 @@
+//! User-defined upsert validation.
+//!
+//! An application registers a validator with
+//! [`VectorCollection::set_upsert_validator`](crate::VectorCollection::set_upsert_validator)
+//! to enforce domain rules (vector norm bounds, required payload fields, id
+//! ranges) at the database boundary. Every upsert path — `upsert`,
+//! `upsert_bulk`, `upsert_bulk_from_raw` and `upsert_metadata` — runs it on
+//! each point before any storage lock or WAL write, so one rejected point
+//! rejects the whole batch with the validator's own error.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::error::Result;
+use crate::point::Point;
+
+type ValidatorFn = Arc<dyn Fn(&Point) -> Result<()> + Send + Sync>;
+
+/// Slot for the collection's upsert validator, shared by its clones.
+///
+/// The lock is a leaf: it is only held to clone or replace the validator,
+/// never while the validator runs.
+#[derive(Clone, Default)]
+pub(crate) struct UpsertValidator {
+    validator: Arc<RwLock<Option<ValidatorFn>>>,
+}
+
+impl UpsertValidator {
+    /// Registers `validator`, replacing any previous one.
+    pub(crate) fn set(&self, validator: ValidatorFn) {
+        *self.validator.write() = Some(validator);
+    }
+
+    /// Removes the registered validator.
+    pub(crate) fn clear(&self) {
+        *self.validator.write() = None;
+    }
+
+    /// Whether a validator is registered.
+    pub(crate) fn is_set(&self) -> bool {
+        self.validator.read().is_some()
+    }
+
+    /// Runs the validator on every point, stopping at the first rejection;
+    /// always `Ok` without a validator.
+    pub(crate) fn validate(&self, points: &[Point]) -> Result<()> {
+        let validator = self.validator.read().clone();
+        if let Some(validator) = validator {
+            points.iter().try_for_each(|point| validator(point))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+//! Tests for user-defined upsert validators.
+
+use crate::collection::{MetadataCollection, VectorCollection};
+use crate::distance::DistanceMetric;
+use crate::error::{Error, Result};
+use crate::point::Point;
+use crate::quantization::StorageMode;
+
+fn collection() -> (tempfile::TempDir, VectorCollection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = VectorCollection::create(
+        dir.path().join("c"),
+        "c",
+        2,
+        DistanceMetric::Cosine,
+        StorageMode::Full,
+    )
+    .expect("create collection");
+    (dir, col)
+}
+
+/// Rejects vectors whose L2 norm exceeds 2 and points without a `source`.
+fn domain_rules(point: &Point) -> Result<()> {
+    let norm = point.vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 2.0 {
+        return Err(Error::InvalidVector(format!(
+            "point {} has norm {norm} > 2",
+            point.id
+        )));
+    }
+    let has_source = point
+        .payload
+        .as_ref()
+        .is_some_and(|p| p.get("source").is_some());
+    if !has_source {
+        return Err(Error::SchemaValidation(format!(
+            "point {} has no 'source' field",
+            point.id
+        )));
+    }
+    Ok(())
+}
+
+fn valid(id: u64) -> Point {
+    Point::new(id, vec![1.0, 0.0], Some(serde_json::json!({"source": "a"})))
+}
+
+#[test]
+fn test_rejected_point_fails_the_whole_batch() {
+    let (_dir, col) = collection();
+    col.set_upsert_validator(domain_rules);
+
+    let err = col
+        .upsert(vec![valid(1), Point::new(2, vec![3.0, 0.0], None)])
+        .expect_err("norm violation");
+
+    assert!(matches!(err, Error::InvalidVector(_)), "{err:?}");
+    assert_eq!(col.len(), 0, "nothing of the batch is written");
+
+    col.upsert(vec![valid(1), valid(2)]).expect("valid batch");
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn test_validator_error_is_returned_unchanged() {
+    let (_dir, col) = collection();
+    col.set_upsert_validator(domain_rules);
+
+    let err = col
+        .upsert(vec![Point::new(7, vec![1.0, 0.0], None)])
+        .expect_err("missing source");
+
+    assert_eq!(err.code(), "VELES-017");
+    assert!(err.to_string().contains("point 7"), "{err}");
+}
+
+#[test]
+fn test_bulk_paths_are_validated() {
+    let (_dir, col) = collection();
+    col.set_upsert_validator(domain_rules);
+
+    let err = col
+        .upsert_bulk(&[valid(1), Point::new(2, vec![0.0, 5.0], None)])
+        .expect_err("bulk");
+    assert!(matches!(err, Error::InvalidVector(_)), "{err:?}");
+
+    let payloads = [Some(serde_json::json!({"source": "a"})), None];
+    let err = col
+        .upsert_bulk_from_raw(&[1.0, 0.0, 0.0, 1.0], &[1, 2], 2, Some(&payloads))
+        .expect_err("raw bulk");
+    assert!(matches!(err, Error::SchemaValidation(_)), "{err:?}");
+    assert_eq!(col.len(), 0);
+
+    let payloads = [
+        Some(serde_json::json!({"source": "a"})),
+        Some(serde_json::json!({"source": "b"})),
+    ];
+    let inserted = col
+        .upsert_bulk_from_raw(&[1.0, 0.0, 0.0, 1.0], &[1, 2], 2, Some(&payloads))
+        .expect("raw bulk");
+    assert_eq!(inserted, 2);
+}
+
+#[test]
+fn test_clear_and_replace_validator() {
+    let (_dir, col) = collection();
+    col.set_upsert_validator(|_| Err(Error::SchemaValidation("closed".into())));
+    assert!(col.upsert(vec![valid(1)]).is_err());
+
+    col.set_upsert_validator(domain_rules);
+    col.upsert(vec![valid(1)])
+        .expect("replaced validator accepts");
+
+    col.clear_upsert_validator();
+    col.upsert(vec![Point::new(2, vec![9.0, 9.0], None)])
+        .expect("no validator");
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn test_metadata_collection_upserts_are_validated() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let col = MetadataCollection::create(dir.path().join("m"), "m").expect("create");
+    col.set_upsert_validator(|point| {
+        if point.id < 100 {
+            Ok(())
+        } else {
+            Err(Error::SchemaValidation(format!(
+                "id {} out of range",
+                point.id
+            )))
+        }
+    });
+
+    col.upsert(vec![Point::metadata_only(1, serde_json::json!({"k": 1}))])
+        .expect("in range");
+    let err = col
+        .upsert(vec![Point::metadata_only(100, serde_json::json!({"k": 2}))])
+        .expect_err("out of range");
+
+    assert!(matches!(err, Error::SchemaValidation(_)), "{err:?}");
+    assert_eq!(col.len(), 1);
+}
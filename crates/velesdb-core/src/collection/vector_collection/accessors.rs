@@ -13,6 +13,13 @@ impl VectorCollection {
         self.inner.guard_rails()
     }
 
+    /// Returns the admission counters (running and queued queries,
+    /// rejections) for searches and scans on this collection.
+    #[must_use]
+    pub fn admission_stats(&self) -> crate::collection::AdmissionStats {
+        self.inner.admission_stats()
+    }
+
     /// Returns the collection name.
     #[must_use]
     pub fn name(&self) -> String {
@@ -80,7 +87,76 @@ impl VectorCollection {
         batch_size: usize,
         filter: Option<&crate::filter::Filter>,
     ) -> crate::error::Result<crate::collection::ScrollBatch> {
-        self.inner.scroll_batch(cursor, batch_size, filter)
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner
+            .scroll_batch(cursor, batch_size, filter.as_deref())
+    }
+
+    /// Counts live points matching `filter` (every live point when `None`).
+    ///
+    /// Uses secondary indexes to narrow the candidates when the filter allows
+    /// it, and a parallel payload scan otherwise. TTL-expired points are not
+    /// counted.
+    #[must_use]
+    pub fn count(&self, filter: Option<&crate::filter::Filter>) -> usize {
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner.count(filter.as_deref())
+    }
+
+    /// Returns up to `n` points sampled uniformly at random from the live
+    /// points matching `filter`.
+    ///
+    /// Passing the same `seed` over unchanged data returns the same sample;
+    /// `None` picks a fresh one.
+    #[must_use]
+    pub fn sample(
+        &self,
+        n: usize,
+        filter: Option<&crate::filter::Filter>,
+        seed: Option<u64>,
+    ) -> Vec<crate::point::Point> {
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner.sample(n, filter.as_deref(), seed)
+    }
+
+    /// Computes centroid, norm, pairwise-similarity and intrinsic-dimension
+    /// statistics on a uniform sample of up to `sample_size` vectors.
+    #[must_use]
+    pub fn vector_stats(&self, sample_size: usize) -> crate::collection::stats::VectorStats {
+        self.inner.vector_stats(sample_size)
+    }
+
+    /// Returns the sequence number of the last committed write.
+    ///
+    /// Starts at 0 when the collection is opened and increases by one per
+    /// upsert batch or delete.
+    #[must_use]
+    pub fn snapshot_sequence(&self) -> u64 {
+        self.inner.snapshot_sequence()
+    }
+
+    /// Returns approximate per-point read counts (the `top` most read live
+    /// points, at most [`TOP_TRACKED`](crate::collection::access_stats::TOP_TRACKED))
+    /// and the payload cache counters.
+    ///
+    /// Every point returned by `get` or a search counts as one read.
+    #[must_use]
+    pub fn access_stats(&self, top: usize) -> crate::collection::AccessStats {
+        self.inner.access_stats(top)
+    }
+
+    /// Lays out a uniform sample of up to `sample_size` vectors in 2-D with
+    /// `method`, labelling each point with one of up to `clusters` k-means
+    /// clusters. With a `filter`, only matching points are sampled.
+    #[must_use]
+    pub fn project_2d(
+        &self,
+        method: crate::collection::projection::ProjectionMethod,
+        sample_size: usize,
+        clusters: usize,
+        filter: Option<&crate::filter::Filter>,
+    ) -> crate::collection::projection::Projection2d {
+        self.inner.project_2d(method, sample_size, clusters, filter)
     }
 
     /// Returns the current collection config.
@@ -104,6 +180,16 @@ impl VectorCollection {
         self.inner.vacuum_hnsw_index()
     }
 
+    /// Checks the structure of the HNSW graph and returns connectivity
+    /// statistics; see [`HnswIndex::verify`](crate::HnswIndex::verify).
+    ///
+    /// Read-only. Vectors still buffered for deferred indexing show up as
+    /// unreachable until they are indexed.
+    #[must_use]
+    pub fn verify_index(&self) -> crate::index::HnswIntegrityReport {
+        self.inner.verify_index_graph()
+    }
+
     /// Compacts the underlying vector storage, rewriting active vectors
     /// into a contiguous layout and reclaiming disk space occupied by
     /// deleted entries.
@@ -121,6 +207,84 @@ impl VectorCollection {
         self.inner.compact_vector_storage()
     }
 
+    /// Applies `madvise`/`mlock` residency settings to the vector storage
+    /// mapping, overriding the `[storage]` config pushed by `Database`.
+    ///
+    /// Runtime only — not persisted. A failed `mlock` (typically
+    /// `RLIMIT_MEMLOCK`) is logged and leaves the mapping pageable; check
+    /// [`Self::is_memory_locked`].
+    pub fn set_mmap_residency(&self, residency: crate::config::MmapResidency) {
+        self.inner.set_mmap_residency(residency);
+    }
+
+    /// Returns the residency settings applied to the vector storage mapping.
+    #[must_use]
+    pub fn mmap_residency(&self) -> crate::config::MmapResidency {
+        self.inner.mmap_residency()
+    }
+
+    /// Returns `true` if the vector storage mapping is pinned in RAM.
+    #[must_use]
+    pub fn is_memory_locked(&self) -> bool {
+        self.inner.is_memory_locked()
+    }
+
+    /// Applies a growth policy to the vector data file (preallocation,
+    /// growth step, sparse allocation), overriding the `[storage]` config
+    /// pushed by `Database`. Runtime only — not persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy is invalid or resizing the data file
+    /// fails.
+    pub fn set_mmap_growth(&self, growth: crate::config::MmapGrowth) -> crate::error::Result<()> {
+        self.inner.set_mmap_growth(growth)
+    }
+
+    /// Returns the growth policy applied to the vector data file.
+    #[must_use]
+    pub fn mmap_growth(&self) -> crate::config::MmapGrowth {
+        self.inner.mmap_growth()
+    }
+
+    /// Sets the layout exact scans (`SearchQuality::Perfect`, collections of
+    /// up to 100 points) score vectors in, and persists it to `config.json`.
+    ///
+    /// [`VectorLayout::BlockedSoa`](crate::VectorLayout::BlockedSoa) pays off
+    /// on high-dimensional vectors; measure with the
+    /// `vector_layout_benchmark` bench before switching.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be persisted.
+    pub fn set_vector_layout(&self, layout: crate::VectorLayout) -> crate::error::Result<()> {
+        self.inner.set_vector_layout(layout)
+    }
+
+    /// Returns the layout exact scans score vectors in.
+    #[must_use]
+    pub fn vector_layout(&self) -> crate::VectorLayout {
+        self.inner.vector_layout()
+    }
+
+    /// Keeps graph edge properties in an on-disk log instead of RAM (or
+    /// moves them back with `false`), and persists the choice to
+    /// `config.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the properties cannot be moved or the config
+    /// cannot be persisted.
+    pub fn set_edge_properties_on_disk(&self, on_disk: bool) -> crate::error::Result<()> {
+        self.inner.set_edge_properties_on_disk(on_disk)
+    }
+
+    /// Returns `true` when graph edge properties are kept on disk.
+    #[must_use]
+    pub fn edge_properties_on_disk(&self) -> bool {
+        self.inner.edge_properties_on_disk()
+    }
+
     /// Applies post-creation overrides to the advanced configuration
     /// fields (`pq_rescore_oversampling`, `deferred_indexing`,
     /// `async_index_builder`) and persists the updated `config.json`.
@@ -151,6 +315,40 @@ impl VectorCollection {
         )
     }
 
+    /// Records (or clears, with `None`) the embedding model that produced
+    /// this collection's vectors, persisting it to `config.json`.
+    ///
+    /// Once set, [`check_embedding_model`](Self::check_embedding_model)
+    /// rejects requests that declare a different model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `info.dimension` differs from the collection
+    /// dimension, the model name is empty, or the config cannot be written.
+    pub fn set_embedding_info(
+        &self,
+        info: Option<crate::collection::EmbeddingInfo>,
+    ) -> crate::error::Result<()> {
+        self.inner.set_embedding_info(info)
+    }
+
+    /// Returns the recorded embedding model metadata, if any.
+    #[must_use]
+    pub fn embedding_info(&self) -> Option<crate::collection::EmbeddingInfo> {
+        self.inner.embedding_info()
+    }
+
+    /// Checks a client-declared embedding model (`"model"` or
+    /// `"model@version"`) against the recorded one. Always succeeds when no
+    /// model has been recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::Error::SchemaValidation`] on mismatch.
+    pub fn check_embedding_model(&self, declared: &str) -> crate::error::Result<()> {
+        self.inner.check_embedding_model(declared)
+    }
+
     /// Returns CBO statistics.
     #[must_use]
     pub fn get_stats(&self) -> crate::collection::stats::CollectionStats {
@@ -291,4 +489,174 @@ impl VectorCollection {
     ) -> Option<crate::collection::auto_reindex::DivergenceCheck> {
         self.inner.check_auto_reindex_divergence()
     }
+
+    /// Registers a callback for index build progress, replacing any
+    /// previous one.
+    ///
+    /// Bulk upserts report [`StoringVectors`](crate::collection::index_progress::IndexBuildPhase::StoringVectors)
+    /// then [`LinkingLayers`](crate::collection::index_progress::IndexBuildPhase::LinkingLayers)
+    /// (in chunks, unless HNSW insertion is deferred), `flush`/`flush_full`
+    /// report [`Flushing`](crate::collection::index_progress::IndexBuildPhase::Flushing),
+    /// and [`Self::reindex`] reports the rebuild's linking and final save.
+    /// The callback runs on the writing thread, so it should return quickly.
+    /// Not persisted — register again after reopening.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, StorageMode};
+    /// # let coll = VectorCollection::create("./data/docs".into(), "docs", 768, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// coll.on_progress(|p| println!("{:?}: {}%", p.phase, p.percent));
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    pub fn on_progress<F>(&self, callback: F)
+    where
+        F: Fn(crate::collection::index_progress::IndexProgress) + Send + Sync + 'static,
+    {
+        self.inner.on_progress(callback);
+    }
+
+    /// Removes the callback registered with [`Self::on_progress`].
+    pub fn clear_progress(&self) {
+        self.inner.clear_progress();
+    }
+
+    /// Subscribes to inserts, updates and deletes of the points matching
+    /// `filter` (every point when `None`).
+    ///
+    /// Events are reported as seen through the filter: a point updated out
+    /// of it arrives as a delete. Writes committed before the call are not
+    /// replayed. See [`change_feed`](crate::collection::change_feed).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, Filter, Condition};
+    /// # async fn run(coll: VectorCollection) {
+    /// let open = Filter::new(Condition::eq("status", "open"));
+    /// let mut changes = coll.subscribe(Some(open));
+    /// while let Some(change) = changes.recv().await {
+    ///     println!("{:?} {}", change.kind, change.id);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn subscribe(
+        &self,
+        filter: Option<crate::filter::Filter>,
+    ) -> crate::collection::ChangeSubscription {
+        self.inner.subscribe(filter)
+    }
+
+    /// Registers a validator run on every point before an upsert is
+    /// accepted, replacing any previous one.
+    ///
+    /// `upsert`, `upsert_bulk`, `upsert_bulk_from_raw` and `upsert_metadata`
+    /// call it after the dimension and runtime-limit checks and before any
+    /// write. The first `Err` rejects the whole batch and is returned to the
+    /// caller unchanged, so domain rules can surface as structured errors —
+    /// typically [`Error::SchemaValidation`](crate::Error::SchemaValidation)
+    /// or [`Error::InvalidVector`](crate::Error::InvalidVector). The
+    /// validator runs on the writing thread and is not persisted — register
+    /// it again after reopening.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, Error, StorageMode};
+    /// # let coll = VectorCollection::create("./data/docs".into(), "docs", 768, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// coll.set_upsert_validator(|point| {
+    ///     let has_source = point
+    ///         .payload
+    ///         .as_ref()
+    ///         .is_some_and(|p| p.get("source").is_some());
+    ///     if has_source {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(Error::SchemaValidation(format!("point {} has no 'source'", point.id)))
+    ///     }
+    /// });
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    pub fn set_upsert_validator<F>(&self, validator: F)
+    where
+        F: Fn(&crate::point::Point) -> crate::error::Result<()> + Send + Sync + 'static,
+    {
+        self.inner.set_upsert_validator(validator);
+    }
+
+    /// Removes the validator registered with [`Self::set_upsert_validator`].
+    pub fn clear_upsert_validator(&self) {
+        self.inner.clear_upsert_validator();
+    }
+
+    /// Installs a fault policy that delays or fails storage and index steps
+    /// of this collection (`chaos` feature), replacing any previous one.
+    ///
+    /// Applies to every handle of the collection until
+    /// [`Self::clear_fault_injection`]; not persisted.
+    #[cfg(feature = "chaos")]
+    #[must_use]
+    pub fn with_fault_injection(self, policy: crate::collection::chaos::FaultPolicy) -> Self {
+        self.inner.set_fault_policy(policy);
+        self
+    }
+
+    /// Removes the policy installed with [`Self::with_fault_injection`].
+    #[cfg(feature = "chaos")]
+    pub fn clear_fault_injection(&self) {
+        self.inner.runtime.faults.clear();
+    }
+
+    /// Times `point` was reached since the current fault policy was
+    /// installed; 0 without a policy.
+    #[cfg(feature = "chaos")]
+    #[must_use]
+    pub fn fault_hits(&self, point: crate::collection::chaos::FaultPoint) -> u64 {
+        self.inner.runtime.faults.hits(point)
+    }
+
+    /// Runs concurrent writers upserting overlapping ids against concurrent
+    /// readers, then checks the stored points (`chaos` feature).
+    ///
+    /// Overwrites ids `0..options.id_space`: run it on a scratch collection.
+    /// Batches rejected by an injected fault are counted, not returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimension is below 2 or an upsert fails for a
+    /// reason other than an injected fault.
+    #[cfg(feature = "chaos")]
+    pub fn run_upsert_stress(
+        &self,
+        options: &crate::collection::chaos::StressOptions,
+    ) -> crate::error::Result<crate::collection::chaos::StressReport> {
+        self.inner.run_upsert_stress(options)
+    }
+
+    /// Rebuilds the HNSW index with `params`, validating before the swap.
+    ///
+    /// Runs synchronously on the calling thread; `manager` receives the
+    /// lifecycle events (`Started`, `Progress`, `Validating`, then
+    /// `Completed` or `RolledBack`) through its
+    /// [`on_event`](crate::collection::auto_reindex::AutoReindexManager::on_event)
+    /// callback. The new graph replaces the current one only if its sampled
+    /// p99 latency and recall stay within the manager's regression
+    /// thresholds; the parameters are then persisted to `config.json`.
+    ///
+    /// Returns `true` when the new index was installed, `false` when it was
+    /// rolled back after validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `params` are invalid, a reindex is already
+    /// running on `manager`, the rebuild fails, or the result cannot be
+    /// persisted.
+    pub fn reindex(
+        &self,
+        params: crate::index::hnsw::HnswParams,
+        manager: &crate::collection::auto_reindex::AutoReindexManager,
+    ) -> crate::error::Result<bool> {
+        self.inner.reindex_with_params(params, manager)
+    }
 }
@@ -0,0 +1,83 @@
+//! Archive tier operations for `VectorCollection`.
+
+use crate::collection::expiry::now_unix_secs;
+use crate::collection::{ArchivePolicy, ArchiveReport};
+use crate::error::Result;
+use crate::point::SearchResult;
+
+use super::VectorCollection;
+
+impl VectorCollection {
+    /// Sets (or, with `None`, clears) the archive policy and persists it.
+    ///
+    /// While a policy is set, reads and writes record each point's last
+    /// access day; [`archive_cold_points`](Self::archive_cold_points) moves
+    /// the idle ones to the archive tier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `idle_days` is 0 or the config cannot be written.
+    pub fn set_archive_policy(&self, policy: Option<ArchivePolicy>) -> Result<()> {
+        self.inner.set_archive_policy(policy)
+    }
+
+    /// Returns the archive policy, if any.
+    #[must_use]
+    pub fn archive_policy(&self) -> Option<ArchivePolicy> {
+        self.inner.storage.config.read().archive_policy
+    }
+
+    /// Moves points not read or written for the policy's `idle_days` out of
+    /// the HNSW index and hot storage into the compressed archive segment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{ArchivePolicy, VectorCollection, DistanceMetric, StorageMode};
+    /// # let coll = VectorCollection::create("./data/v".into(), "v", 128, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// coll.set_archive_policy(Some(ArchivePolicy::idle_days(90)))?;
+    /// // Later, e.g. from a nightly job:
+    /// let report = coll.archive_cold_points()?;
+    /// println!("archived {} points", report.archived);
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no policy is set, the collection has sparse
+    /// vectors, or storage fails.
+    pub fn archive_cold_points(&self) -> Result<ArchiveReport> {
+        self.inner.archive_cold_points_at(now_unix_secs())
+    }
+
+    /// Number of points in the archive tier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive segment cannot be read.
+    pub fn archived_count(&self) -> Result<usize> {
+        self.inner.archived_count()
+    }
+
+    /// Exact k-NN search over the archive tier only.
+    ///
+    /// Scans the whole segment from disk, comparing at the archived (f16)
+    /// precision. Combine with [`search`](Self::search) to cover both tiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on a dimension mismatch or an unreadable segment.
+    pub fn search_archived(&self, query: &[f32], k: usize) -> Result<Vec<SearchResult>> {
+        self.inner.search_archived(query, k)
+    }
+
+    /// Moves archived points back to the hot tier and returns how many of
+    /// `ids` were archived.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storage fails.
+    pub fn restore_archived(&self, ids: &[u64]) -> Result<usize> {
+        self.inner.restore_archived(ids)
+    }
+}
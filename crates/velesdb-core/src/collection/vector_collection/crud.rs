@@ -1,7 +1,12 @@
 //! CRUD and index-mutation operations for `VectorCollection`.
 
+use std::path::Path;
+
+use crate::collection::{
+    ImportMapping, ImportProgress, ImportReport, PointsView, UpsertOptions, VectorReader,
+};
 use crate::error::Result;
-use crate::point::Point;
+use crate::point::{Point, PointProjection};
 
 use super::VectorCollection;
 
@@ -15,6 +20,21 @@ impl VectorCollection {
         self.inner.upsert_bulk(points)
     }
 
+    /// [`upsert_bulk`](Self::upsert_bulk) with per-call options; pass
+    /// [`UpsertOptions::wait_for_index`] to make the points searchable
+    /// before returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upsert or the index refresh fails.
+    pub fn upsert_bulk_with_options(
+        &self,
+        points: &[Point],
+        options: UpsertOptions,
+    ) -> Result<usize> {
+        self.inner.upsert_bulk_with_options(points, options)
+    }
+
     /// Bulk insert from contiguous flat slices (zero-copy from numpy / FFI).
     ///
     /// Accepts a flat `f32` slice of shape `(n, dimension)` in row-major order
@@ -36,6 +56,84 @@ impl VectorCollection {
             .upsert_bulk_from_raw(vectors, ids, dimension, payloads)
     }
 
+    /// Streams a JSON Lines file into the collection in batches.
+    ///
+    /// Columns are mapped with `mapping`; bad rows are skipped or abort the
+    /// import according to [`ImportMapping::on_bad_row`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the file cannot be read.
+    /// - Returns [`crate::error::Error::Serialization`] naming the first bad
+    ///   row under [`crate::OnBadRow::Abort`].
+    /// - Returns an error if a batch write fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, ImportMapping, StorageMode};
+    /// # let coll = VectorCollection::create("./data/v".into(), "v", 128, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// let report = coll.import_jsonl("vectors.jsonl", &ImportMapping::default())?;
+    /// println!("{} imported, {} skipped", report.imported, report.skipped);
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    pub fn import_jsonl(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+    ) -> Result<ImportReport> {
+        self.inner.import_jsonl(path, mapping)
+    }
+
+    /// [`import_jsonl`](Self::import_jsonl), calling `on_progress` after
+    /// each batch is written.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`import_jsonl`](Self::import_jsonl).
+    pub fn import_jsonl_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+        on_progress: impl FnMut(ImportProgress),
+    ) -> Result<ImportReport> {
+        self.inner
+            .import_jsonl_with_progress(path, mapping, on_progress)
+    }
+
+    /// Streams a CSV file with a header row into the collection in batches.
+    ///
+    /// Payload cells are stored as strings.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`crate::error::Error::Config`] if a mapped column is not in
+    ///   the header.
+    /// - Otherwise the same as [`import_jsonl`](Self::import_jsonl).
+    pub fn import_csv(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+    ) -> Result<ImportReport> {
+        self.inner.import_csv(path, mapping)
+    }
+
+    /// [`import_csv`](Self::import_csv), calling `on_progress` after each
+    /// batch is written.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`import_csv`](Self::import_csv).
+    pub fn import_csv_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        mapping: &ImportMapping,
+        on_progress: impl FnMut(ImportProgress),
+    ) -> Result<ImportReport> {
+        self.inner
+            .import_csv_with_progress(path, mapping, on_progress)
+    }
+
     /// Inserts or updates points in the collection.
     ///
     /// # Errors
@@ -59,6 +157,19 @@ impl VectorCollection {
         self.inner.upsert(points)
     }
 
+    /// [`upsert`](Self::upsert) with per-call options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upsert or the index refresh fails.
+    pub fn upsert_with_options(
+        &self,
+        points: impl IntoIterator<Item = Point>,
+        options: UpsertOptions,
+    ) -> Result<()> {
+        self.inner.upsert_with_options(points, options)
+    }
+
     /// Retrieves points by IDs, returning `None` for missing entries.
     ///
     /// # Examples
@@ -79,6 +190,59 @@ impl VectorCollection {
         self.inner.get(ids)
     }
 
+    /// Retrieves points by IDs, returning only the parts selected by
+    /// `projection` (missing entries are `None`, as with [`get`](Self::get)).
+    ///
+    /// Excluding the vector skips reading it from storage entirely.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, PointProjection, StorageMode};
+    /// # let coll = VectorCollection::create("./data/v".into(), "v", 128, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// // Payloads only — no vector bytes are copied.
+    /// let points = coll.get_projected(&[1, 2, 3], PointProjection::new(false, true));
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    #[must_use]
+    pub fn get_projected(&self, ids: &[u64], projection: PointProjection) -> Vec<Option<Point>> {
+        self.inner.get_projected(ids, projection)
+    }
+
+    /// Like [`get`](Self::get), but vectors are borrowed straight from the
+    /// memory-mapped storage instead of being copied.
+    ///
+    /// The returned view holds the vector storage read lock: writes to this
+    /// collection block until it is dropped, so keep it short-lived and do
+    /// not write to the collection from the same thread while holding it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, StorageMode};
+    /// # let coll = VectorCollection::create("./data/v".into(), "v", 128, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// let view = coll.get_view(&[1, 2, 3], false);
+    /// let norms: Vec<f32> = view
+    ///     .iter()
+    ///     .map(|(_, v)| v.iter().map(|x| x * x).sum::<f32>().sqrt())
+    ///     .collect();
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    #[must_use]
+    pub fn get_view(&self, ids: &[u64], with_payload: bool) -> PointsView<'_> {
+        self.inner.get_view(ids, with_payload)
+    }
+
+    /// Read-locks the vector storage for zero-copy vector lookups by id.
+    ///
+    /// Unlike [`get`](Self::get), the reader does not hide TTL-expired
+    /// points. The same locking caveats as [`get_view`](Self::get_view)
+    /// apply.
+    #[must_use]
+    pub fn vector_reader(&self) -> VectorReader<'_> {
+        self.inner.vector_reader()
+    }
+
     /// Deletes points by IDs.
     ///
     /// Missing IDs are silently ignored.
@@ -156,6 +320,15 @@ impl VectorCollection {
         self.inner.add_edge(edge)
     }
 
+    /// Links every node to its `k` nearest neighbours with `label` edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the label is empty or the edges cannot be stored.
+    pub fn build_knn_graph(&self, k: usize, threshold: Option<f32>, label: &str) -> Result<usize> {
+        self.inner.build_knn_graph(k, threshold, label)
+    }
+
     /// Removes a graph edge by ID. Returns `true` if the edge existed.
     #[must_use]
     pub fn remove_edge(&self, edge_id: u64) -> bool {
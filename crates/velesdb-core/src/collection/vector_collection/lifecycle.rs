@@ -146,6 +146,20 @@ impl VectorCollection {
         self.inner.flush()
     }
 
+    /// Makes every acknowledged write visible to search, without disk I/O.
+    ///
+    /// Only collections with deferred indexing or an async index builder
+    /// can lag behind their writes: their queued vectors are merged into
+    /// the HNSW graph, waiting for a build already in progress. A no-op
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if queued vectors cannot be indexed.
+    pub fn refresh(&self) -> Result<()> {
+        self.inner.refresh()
+    }
+
     /// Full durability flush including `vectors.idx` serialization.
     ///
     /// Issue #423: Use on graceful shutdown to avoid a full WAL replay
@@ -5,6 +5,7 @@
 //! any data synchronisation issues between separate storage layers.
 
 mod accessors;
+mod archive;
 mod crud;
 mod lifecycle;
 mod search;
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 
+use crate::collection::AdmissionKind;
 use crate::error::Result;
 use crate::point::SearchResult;
 
@@ -29,6 +30,7 @@ impl VectorCollection {
     /// # Ok::<(), velesdb_core::Error>(())
     /// ```
     pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.search(query, k)
     }
 
@@ -49,9 +51,62 @@ impl VectorCollection {
     /// # Ok::<(), velesdb_core::Error>(())
     /// ```
     pub fn text_search(&self, query: &str, k: usize) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.text_search(query, k)
     }
 
+    /// Returns one page of kNN results plus a cursor resuming after it.
+    ///
+    /// Pass `cursor: None` for the first page and the previous page's
+    /// `next_cursor` (with the same `query`) for the next ones. The ranking
+    /// is kept with the cursor, so later pages are not re-scored from
+    /// scratch. Cursors expire after [`crate::collection::SEARCH_CURSOR_TTL`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `page_size` is 0 or above the query limit.
+    /// - Returns an error if the cursor is unknown, expired or was issued
+    ///   for another query.
+    /// - Returns an error if the query dimension does not match the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, StorageMode};
+    /// # let coll = VectorCollection::create("./data/v".into(), "v", 128, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// let query = vec![0.1; 128];
+    /// let mut page = coll.search_paginated(&query, 20, None)?;
+    /// while let Some(cursor) = page.next_cursor.take() {
+    ///     page = coll.search_paginated(&query, 20, Some(&cursor))?;
+    /// }
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    pub fn search_paginated(
+        &self,
+        query: &[f32],
+        page_size: usize,
+        cursor: Option<&str>,
+    ) -> Result<crate::collection::SearchPage> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.search_paginated(query, page_size, cursor)
+    }
+
+    /// Returns an iterator over the kNN results for `query`, best match
+    /// first, ranking `page_size` results at a time as it is consumed.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `page_size` is 0 or above the query limit.
+    /// - Returns an error if the query dimension does not match the collection.
+    pub fn search_iter(
+        &self,
+        query: &[f32],
+        page_size: usize,
+    ) -> Result<crate::collection::SearchResultIter> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.search_iter(query, page_size)
+    }
+
     /// Performs kNN search with an explicit `ef_search` override.
     ///
     /// Higher `ef_search` values improve recall at the cost of latency.
@@ -65,9 +120,67 @@ impl VectorCollection {
         k: usize,
         ef_search: usize,
     ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.search_with_ef(query, k, ef_search)
     }
 
+    /// Performs kNN search with a packed bitset query on a `Hamming` or
+    /// `Jaccard` collection.
+    ///
+    /// `query` uses the [`Point::from_bits`](crate::Point::from_bits) layout;
+    /// scores are exact popcount-based values.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the metric is not `Hamming` or `Jaccard`.
+    /// - Returns an error if `query.len() * 8` does not match the dimension.
+    pub fn search_bits(&self, query: &[u8], k: usize) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.search_bits(query, k)
+    }
+
+    /// Returns the SimHash fingerprints within `threshold` differing bits of
+    /// a stored id (excluded from the results) or of a text, nearest first.
+    ///
+    /// The collection is a 64-dimension `Hamming` companion filled with
+    /// [`fingerprint::simhash_bits`](crate::fingerprint::simhash_bits).
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the collection is not 64-dimension `Hamming`.
+    /// - Returns an error if an id query names no stored point.
+    pub fn near_duplicate_text<'a>(
+        &self,
+        query: impl Into<crate::collection::NearDuplicateQuery<'a>>,
+        threshold: u32,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.near_duplicate_text(query, threshold)
+    }
+
+    /// Returns up to `max_results` points whose similarity to `query`
+    /// satisfies `score <op> threshold`, best first.
+    ///
+    /// Comparisons follow VelesQL `similarity()`: on distance metrics `Gt`
+    /// means "closer than `threshold`". `Gt`/`Gte` search the HNSW index,
+    /// widening the candidate list until the threshold boundary is covered;
+    /// the other operators scan every stored vector.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the query dimension does not match the collection.
+    pub fn search_by_threshold(
+        &self,
+        query: &[f32],
+        threshold: f32,
+        op: crate::velesql::CompareOp,
+        max_results: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner
+            .search_by_threshold(query, threshold, op, max_results)
+    }
+
     /// Performs kNN search with a specific [`crate::SearchQuality`] profile.
     ///
     /// Use this instead of [`Self::search_with_ef`] when you want named
@@ -83,6 +196,7 @@ impl VectorCollection {
         k: usize,
         quality: crate::SearchQuality,
     ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.search_with_quality(query, k, quality)
     }
 
@@ -98,7 +212,70 @@ impl VectorCollection {
         k: usize,
         filter: &crate::filter::Filter,
     ) -> Result<Vec<SearchResult>> {
-        self.inner.search_with_filter(query, k, filter)
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner
+            .search_with_filter(query, k, &self.inner.collated(filter))
+    }
+
+    /// Performs kNN search, keeping only the parts of each hit selected by
+    /// `projection`.
+    ///
+    /// Results rank exactly like [`search`](Self::search). Excluding the
+    /// vector skips copying it out of storage, which is the cheap path for
+    /// callers that only need ids, scores and payloads.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the query dimension does not match the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, PointProjection, StorageMode};
+    /// # let coll = VectorCollection::create("./data/v".into(), "v", 128, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// let hits = coll.search_projected(&vec![0.1; 128], 10, PointProjection::new(false, true))?;
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    pub fn search_projected(
+        &self,
+        query: &[f32],
+        k: usize,
+        projection: crate::point::PointProjection,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.search_projected(query, k, projection)
+    }
+
+    /// Like [`search`](Self::search), but hit vectors are borrowed straight
+    /// from the memory-mapped storage instead of being copied.
+    ///
+    /// The returned view holds the vector storage read lock: writes to this
+    /// collection block until it is dropped, so keep it short-lived and do
+    /// not write to the collection from the same thread while holding it.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the query dimension does not match the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{VectorCollection, DistanceMetric, StorageMode};
+    /// # let coll = VectorCollection::create("./data/v".into(), "v", 128, DistanceMetric::Cosine, StorageMode::Full)?;
+    /// let view = coll.search_view(&vec![0.1; 128], 10, true)?;
+    /// for (hit, vector) in view.iter() {
+    ///     println!("{} {} {}", hit.point.id, hit.score, vector[0]);
+    /// }
+    /// # Ok::<(), velesdb_core::Error>(())
+    /// ```
+    pub fn search_view(
+        &self,
+        query: &[f32],
+        k: usize,
+        with_payload: bool,
+    ) -> Result<crate::collection::SearchView<'_>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.search_view(query, k, with_payload)
     }
 
     /// Returns [`crate::ScoredResult`] pairs without payload hydration.
@@ -113,6 +290,7 @@ impl VectorCollection {
         query: &[f32],
         k: usize,
     ) -> Result<Vec<crate::scored_result::ScoredResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.search_ids(query, k)
     }
 
@@ -127,7 +305,9 @@ impl VectorCollection {
         k: usize,
         filter: &crate::filter::Filter,
     ) -> Result<Vec<SearchResult>> {
-        self.inner.text_search_with_filter(query, k, filter)
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner
+            .text_search_with_filter(query, k, &self.inner.collated(filter))
     }
 
     /// Performs hybrid search combining vector kNN and BM25 full-text via RRF fusion.
@@ -155,6 +335,7 @@ impl VectorCollection {
         k: usize,
         alpha: Option<f32>,
     ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.hybrid_search(vector, text, k, alpha, None)
     }
 
@@ -172,8 +353,10 @@ impl VectorCollection {
         alpha: Option<f32>,
         filter: &crate::filter::Filter,
     ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        let filter = self.inner.collated(filter);
         self.inner
-            .hybrid_search_with_filter(vector, text, k, alpha, filter, None)
+            .hybrid_search_with_filter(vector, text, k, alpha, &filter, None)
     }
 
     /// Performs batch kNN search with per-query metadata filters.
@@ -207,7 +390,12 @@ impl VectorCollection {
         k: usize,
         filters: &[Option<crate::filter::Filter>],
     ) -> Result<Vec<Vec<SearchResult>>> {
-        self.inner.search_batch_with_filters(queries, k, filters)
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        let filters: Vec<_> = filters
+            .iter()
+            .map(|f| f.as_ref().map(|f| self.inner.collated(f).into_owned()))
+            .collect();
+        self.inner.search_batch_with_filters(queries, k, &filters)
     }
 
     /// Performs batch kNN search without filters, optimized for throughput.
@@ -236,6 +424,7 @@ impl VectorCollection {
         queries: &[&[f32]],
         k: usize,
     ) -> Result<Vec<Vec<SearchResult>>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.search_batch_parallel(queries, k)
     }
 
@@ -252,7 +441,157 @@ impl VectorCollection {
         strategy: crate::fusion::FusionStrategy,
         filter: Option<&crate::filter::Filter>,
     ) -> Result<Vec<SearchResult>> {
-        self.inner.multi_query_search(queries, k, strategy, filter)
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner
+            .multi_query_search(queries, k, strategy, filter.as_deref())
+    }
+
+    /// Searches for points similar to `positive` but dissimilar to `negatives`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if any vector's dimension does not match the collection.
+    /// - Returns an error if the strategy weight is negative or not finite.
+    pub fn search_with_negatives(
+        &self,
+        positive: &[f32],
+        negatives: &[&[f32]],
+        k: usize,
+        strategy: crate::collection::NegativeStrategy,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner
+            .search_with_negatives(positive, negatives, k, strategy)
+    }
+
+    /// Searches for the k nearest neighbors of `query`, ranked by `metric`
+    /// instead of the collection's own metric.
+    ///
+    /// Collections of up to
+    /// [`METRIC_OVERRIDE_FLAT_SCAN_MAX`](crate::collection::METRIC_OVERRIDE_FLAT_SCAN_MAX)
+    /// points are ranked exactly; larger ones re-rank HNSW candidates.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the query dimension does not match the collection.
+    /// - Returns an error if `metric` and the collection's metric are not both
+    ///   cosine, euclidean or dot.
+    pub fn search_with_metric(
+        &self,
+        query: &[f32],
+        k: usize,
+        metric: crate::distance::DistanceMetric,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.search_with_metric(query, k, metric)
+    }
+
+    /// Performs kNN search restricted to the id sets in `options`.
+    ///
+    /// The allowlist / denylist is enforced during the HNSW traversal, so a
+    /// narrow allowlist still yields up to `k` results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query dimension does not match the collection.
+    pub fn search_with_options(
+        &self,
+        query: &[f32],
+        k: usize,
+        options: &crate::collection::SearchOptions,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.search_with_options(query, k, options)
+    }
+
+    /// Returns the `per_group` best hits for each of the `groups` best
+    /// distinct values of the payload field `group_by_field`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query dimension does not match the collection.
+    pub fn search_grouped(
+        &self,
+        query: &[f32],
+        group_by_field: &str,
+        groups: usize,
+        per_group: usize,
+    ) -> Result<Vec<crate::collection::SearchGroup>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner
+            .search_grouped(query, group_by_field, groups, per_group)
+    }
+
+    /// Recommends points similar to the `positive_ids` examples and
+    /// dissimilar to the `negative_ids` ones (average-vector strategy).
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `positive_ids` is empty.
+    /// - Returns an error if an example id does not exist.
+    pub fn recommend(
+        &self,
+        positive_ids: &[u64],
+        negative_ids: &[u64],
+        k: usize,
+        filter: Option<&crate::filter::Filter>,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner
+            .recommend(positive_ids, negative_ids, k, filter.as_deref())
+    }
+
+    /// Recommends points from example ids with an explicit strategy.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `positive_ids` is empty.
+    /// - Returns an error if an example id does not exist.
+    pub fn recommend_with_strategy(
+        &self,
+        positive_ids: &[u64],
+        negative_ids: &[u64],
+        k: usize,
+        filter: Option<&crate::filter::Filter>,
+        strategy: crate::collection::RecommendStrategy,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        let filter = filter.map(|f| self.inner.collated(f));
+        self.inner.recommend_with_strategy(
+            positive_ids,
+            negative_ids,
+            k,
+            filter.as_deref(),
+            strategy,
+        )
+    }
+
+    /// Computes the full similarity matrix of the given points.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error for duplicate ids or too many ids for a dense matrix.
+    /// - Returns an error if an id does not exist.
+    pub fn pairwise_similarity(&self, ids: &[u64]) -> Result<crate::collection::SimilarityMatrix> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.pairwise_similarity(ids)
+    }
+
+    /// Computes each point's `k` most similar other points within `ids`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error for duplicate ids or more ids than the query limit.
+    /// - Returns an error if an id does not exist.
+    pub fn pairwise_similarity_top_k(
+        &self,
+        ids: &[u64],
+        k: usize,
+    ) -> Result<crate::collection::SparseSimilarityMatrix> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
+        self.inner.pairwise_similarity_top_k(ids, k)
     }
 
     /// Performs multi-query search returning only IDs and fused scores.
@@ -267,6 +606,7 @@ impl VectorCollection {
         k: usize,
         strategy: crate::fusion::FusionStrategy,
     ) -> Result<Vec<(u64, f32)>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         self.inner.multi_query_search_ids(queries, k, strategy)
     }
 
@@ -281,6 +621,7 @@ impl VectorCollection {
         k: usize,
         index_name: &str,
     ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         let indexes = self.inner.query.sparse_indexes.read();
         let index = indexes.get(index_name).ok_or_else(|| {
             crate::error::Error::Config(format!(
@@ -311,6 +652,7 @@ impl VectorCollection {
         index_name: &str,
         strategy: &crate::fusion::FusionStrategy,
     ) -> Result<Vec<SearchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Search)?;
         let candidate_k = k.saturating_mul(2).max(k + 10);
 
         let (dense_results, sparse_results) = self.inner.execute_both_branches(
@@ -358,6 +700,7 @@ impl VectorCollection {
         match_clause: &crate::velesql::MatchClause,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> crate::error::Result<Vec<crate::collection::search::query::match_exec::MatchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Scan)?;
         self.inner.execute_match(match_clause, params)
     }
 
@@ -373,6 +716,7 @@ impl VectorCollection {
         threshold: f32,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> crate::error::Result<Vec<crate::collection::search::query::match_exec::MatchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Scan)?;
         self.inner
             .execute_match_with_similarity(match_clause, query_vector, threshold, params)
     }
@@ -391,6 +735,7 @@ impl VectorCollection {
         match_clause: &crate::velesql::MatchClause,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> crate::error::Result<Vec<crate::collection::search::query::match_exec::MatchResult>> {
+        let _permit = self.inner.admit(AdmissionKind::Scan)?;
         self.inner.match_query_ordered(match_clause, params)
     }
 
@@ -404,6 +749,7 @@ impl VectorCollection {
         query: &crate::velesql::Query,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<serde_json::Value> {
+        let _permit = self.inner.admit(AdmissionKind::Scan)?;
         self.inner.execute_aggregate(query, params)
     }
 
@@ -284,6 +284,9 @@ impl ColumnStore {
                 | (TypedColumn::Bool(_), ColumnValue::Bool(_))
                 | (TypedColumn::Array { .. }, ColumnValue::Array(_))
                 | (TypedColumn::GeoPoint(_), ColumnValue::GeoPoint(_, _))
+                | (TypedColumn::Timestamp(_), ColumnValue::Timestamp(_))
+                | (TypedColumn::Float32List(_), ColumnValue::Float32List(_))
+                | (TypedColumn::Json(_), ColumnValue::Json(_))
                 | (_, ColumnValue::Null)
         );
 
@@ -325,6 +328,15 @@ impl ColumnStore {
             (TypedColumn::GeoPoint(vec), ColumnValue::GeoPoint(lat, lng)) => {
                 Self::checked_set_geopoint(vec, row_idx, lat, lng)
             }
+            (TypedColumn::Timestamp(vec), ColumnValue::Timestamp(ms)) => {
+                Self::checked_set(vec, row_idx, Some(ms))
+            }
+            (TypedColumn::Float32List(vec), ColumnValue::Float32List(v)) => {
+                Self::checked_set(vec, row_idx, Some(v))
+            }
+            (TypedColumn::Json(vec), ColumnValue::Json(v)) => {
+                Self::checked_set(vec, row_idx, Some(v))
+            }
             (col, value) => Err(ColumnStoreError::TypeMismatch {
                 expected: Self::column_type_name(col),
                 actual: Self::value_type_name(&value),
@@ -362,7 +374,9 @@ impl ColumnStore {
     /// Sets a column cell to null at the given row index.
     fn set_column_null(col: &mut TypedColumn, row_idx: usize) -> Result<(), ColumnStoreError> {
         match col {
-            TypedColumn::Int(vec) => Self::checked_set(vec, row_idx, None),
+            TypedColumn::Int(vec) | TypedColumn::Timestamp(vec) => {
+                Self::checked_set(vec, row_idx, None)
+            }
             TypedColumn::Float(vec) => Self::checked_set(vec, row_idx, None),
             TypedColumn::String(vec) => Self::checked_set(vec, row_idx, None),
             TypedColumn::Bool(vec) => Self::checked_set(vec, row_idx, None),
@@ -374,6 +388,8 @@ impl ColumnStore {
                 Ok(())
             }
             TypedColumn::GeoPoint(vec) => Self::checked_set(vec, row_idx, None),
+            TypedColumn::Float32List(vec) => Self::checked_set(vec, row_idx, None),
+            TypedColumn::Json(vec) => Self::checked_set(vec, row_idx, None),
         }
     }
 
@@ -398,6 +414,9 @@ impl ColumnStore {
             TypedColumn::Bool(_) => "Bool".to_string(),
             TypedColumn::Array { .. } => "Array".to_string(),
             TypedColumn::GeoPoint(_) => "GeoPoint".to_string(),
+            TypedColumn::Timestamp(_) => "Timestamp".to_string(),
+            TypedColumn::Float32List(_) => "Float32List".to_string(),
+            TypedColumn::Json(_) => "Json".to_string(),
         }
     }
 
@@ -410,6 +429,9 @@ impl ColumnStore {
             ColumnValue::Null => "Null".to_string(),
             ColumnValue::Array(_) => "Array".to_string(),
             ColumnValue::GeoPoint(_, _) => "GeoPoint".to_string(),
+            ColumnValue::Timestamp(_) => "Timestamp".to_string(),
+            ColumnValue::Float32List(_) => "Float32List".to_string(),
+            ColumnValue::Json(_) => "Json".to_string(),
         }
     }
 
@@ -0,0 +1,243 @@
+//! Conditional batch updates (`SET stock = stock - 1 WHERE stock > 0`).
+//!
+//! Each row is handled on its own: conditions are tested and every
+//! assignment is evaluated against the row's current values, then the new
+//! values are validated as a whole. Only if all of them fit are they
+//! written, so a row is either fully updated or left untouched — a failing
+//! row never leaves half of its assignments applied, and never affects the
+//! other rows of the batch.
+
+use std::cmp::Ordering;
+
+use super::filter_geo::CompareOp;
+use super::types::{
+    BatchUpdateResult, ColumnArithOp, ColumnExpr, ColumnStoreError, ColumnValue, ConditionalUpdate,
+    TypedColumn, UpdateCondition,
+};
+use super::ColumnStore;
+
+impl ColumnStore {
+    /// Applies `update` to every row in `pks` whose conditions hold.
+    ///
+    /// `result.successful` counts updated rows and `result.skipped` rows
+    /// whose conditions did not hold. Rows that are missing, target the
+    /// primary key, or whose expressions fail to evaluate or type-check are
+    /// reported in `result.failed` and left unchanged. An `Int` result
+    /// assigned to a `Float` column is widened.
+    pub fn batch_update_where(
+        &mut self,
+        pks: &[i64],
+        update: &ConditionalUpdate,
+    ) -> BatchUpdateResult {
+        let mut result = BatchUpdateResult::default();
+        for &pk in pks {
+            match self.update_row_where(pk, update) {
+                Ok(true) => result.successful += 1,
+                Ok(false) => result.skipped += 1,
+                Err(e) => result.failed.push((pk, e)),
+            }
+        }
+        result
+    }
+
+    /// Updates one row; `Ok(false)` when a condition does not hold.
+    fn update_row_where(
+        &mut self,
+        pk: i64,
+        update: &ConditionalUpdate,
+    ) -> Result<bool, ColumnStoreError> {
+        let row_idx = self.resolve_live_row(pk)?;
+        for condition in &update.conditions {
+            if !self.condition_holds(condition, row_idx)? {
+                return Ok(false);
+            }
+        }
+
+        // Evaluate and type-check everything before the first write.
+        let mut values = Vec::with_capacity(update.assignments.len());
+        for (column, expr) in &update.assignments {
+            if self.primary_key_column.as_deref() == Some(column.as_str()) {
+                return Err(ColumnStoreError::PrimaryKeyUpdate);
+            }
+            let col = self
+                .columns
+                .get(column)
+                .ok_or_else(|| ColumnStoreError::ColumnNotFound(column.clone()))?;
+            let value = widen_for_column(col, self.eval_expr(expr, row_idx)?);
+            Self::validate_type_match(col, &value)?;
+            values.push((column.as_str(), value));
+        }
+
+        for (column, value) in values {
+            if let Some(col) = self.columns.get_mut(column) {
+                Self::set_column_value(col, row_idx, value)?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// Current value of a cell; `Null` for null cells.
+    fn cell_value(&self, column: &str, row_idx: usize) -> Result<ColumnValue, ColumnStoreError> {
+        let col = self
+            .columns
+            .get(column)
+            .ok_or_else(|| ColumnStoreError::ColumnNotFound(column.to_string()))?;
+        let value = match col {
+            TypedColumn::Int(v) => v.get(row_idx).copied().flatten().map(ColumnValue::Int),
+            TypedColumn::Float(v) => v.get(row_idx).copied().flatten().map(ColumnValue::Float),
+            TypedColumn::String(v) => v.get(row_idx).copied().flatten().map(ColumnValue::String),
+            TypedColumn::Bool(v) => v.get(row_idx).copied().flatten().map(ColumnValue::Bool),
+            TypedColumn::Timestamp(v) => v
+                .get(row_idx)
+                .copied()
+                .flatten()
+                .map(ColumnValue::Timestamp),
+            TypedColumn::GeoPoint(v) => v
+                .get(row_idx)
+                .copied()
+                .flatten()
+                .map(|(lat, lng)| ColumnValue::GeoPoint(lat, lng)),
+            TypedColumn::Array { data, .. } => data
+                .get(row_idx)
+                .and_then(Option::as_ref)
+                .map(|arr| ColumnValue::Array(arr.to_vec())),
+            TypedColumn::Float32List(v) => v
+                .get(row_idx)
+                .and_then(Option::as_ref)
+                .map(|list| ColumnValue::Float32List(list.clone())),
+            TypedColumn::Json(v) => v
+                .get(row_idx)
+                .and_then(Option::as_ref)
+                .map(|doc| ColumnValue::Json(doc.clone())),
+        };
+        Ok(value.unwrap_or(ColumnValue::Null))
+    }
+
+    /// Evaluates an expression against the row's current values.
+    fn eval_expr(
+        &self,
+        expr: &ColumnExpr,
+        row_idx: usize,
+    ) -> Result<ColumnValue, ColumnStoreError> {
+        match expr {
+            ColumnExpr::Value(value) => Ok(value.clone()),
+            ColumnExpr::Column(column) => self.cell_value(column, row_idx),
+            ColumnExpr::Binary { op, left, right } => {
+                let left = self.eval_expr(left, row_idx)?;
+                let right = self.eval_expr(right, row_idx)?;
+                apply_arith(*op, &left, &right)
+            }
+        }
+    }
+
+    /// Tests `column op value` on a row. Null cells never match.
+    fn condition_holds(
+        &self,
+        condition: &UpdateCondition,
+        row_idx: usize,
+    ) -> Result<bool, ColumnStoreError> {
+        let cell = self.cell_value(&condition.column, row_idx)?;
+        let ordering = match (&cell, &condition.value) {
+            (ColumnValue::Null, _) | (_, ColumnValue::Null) => return Ok(false),
+            (ColumnValue::String(a), ColumnValue::String(b)) => {
+                self.string_table.get(*a).cmp(&self.string_table.get(*b))
+            }
+            (a, b) => match compare_scalars(a, b) {
+                Some(ordering) => ordering,
+                // Incomparable types: only inequality can hold.
+                None => return Ok(condition.op == CompareOp::NotEq && a != b),
+            },
+        };
+        Ok(match condition.op {
+            CompareOp::Eq => ordering.is_eq(),
+            CompareOp::NotEq => ordering.is_ne(),
+            CompareOp::Gt => ordering.is_gt(),
+            CompareOp::Gte => ordering.is_ge(),
+            CompareOp::Lt => ordering.is_lt(),
+            CompareOp::Lte => ordering.is_le(),
+        })
+    }
+}
+
+/// Orders numeric, timestamp and bool values; `None` across other types.
+fn compare_scalars(a: &ColumnValue, b: &ColumnValue) -> Option<Ordering> {
+    match (a, b) {
+        (
+            ColumnValue::Int(x) | ColumnValue::Timestamp(x),
+            ColumnValue::Int(y) | ColumnValue::Timestamp(y),
+        ) => Some(x.cmp(y)),
+        (ColumnValue::Bool(x), ColumnValue::Bool(y)) => Some(x.cmp(y)),
+        _ => as_f64(a)?.partial_cmp(&as_f64(b)?),
+    }
+}
+
+fn as_f64(value: &ColumnValue) -> Option<f64> {
+    match value {
+        ColumnValue::Int(v) => Some(*v as f64),
+        ColumnValue::Float(v) => Some(*v),
+        _ => None,
+    }
+}
+
+/// `left op right` over numbers and timestamps.
+///
+/// `Int op Int` stays integral (checked; division truncates), any `Float`
+/// operand makes the result `Float`, and `Timestamp ± Int` shifts by
+/// milliseconds.
+pub(crate) fn apply_arith(
+    op: ColumnArithOp,
+    left: &ColumnValue,
+    right: &ColumnValue,
+) -> Result<ColumnValue, ColumnStoreError> {
+    let invalid = |reason: &str| ColumnStoreError::InvalidExpression(reason.to_string());
+    match (left, right) {
+        (ColumnValue::Null, _) | (_, ColumnValue::Null) => Ok(ColumnValue::Null),
+        (ColumnValue::Int(a), ColumnValue::Int(b)) => {
+            let result = match op {
+                ColumnArithOp::Add => a.checked_add(*b),
+                ColumnArithOp::Sub => a.checked_sub(*b),
+                ColumnArithOp::Mul => a.checked_mul(*b),
+                ColumnArithOp::Div if *b == 0 => return Err(invalid("division by zero")),
+                ColumnArithOp::Div => a.checked_div(*b),
+            };
+            result
+                .map(ColumnValue::Int)
+                .ok_or_else(|| invalid("integer overflow"))
+        }
+        (ColumnValue::Timestamp(t), ColumnValue::Int(ms)) => {
+            let shifted = match op {
+                ColumnArithOp::Add => t.checked_add(*ms),
+                ColumnArithOp::Sub => t.checked_sub(*ms),
+                _ => return Err(invalid("timestamps only support + and - milliseconds")),
+            };
+            shifted
+                .map(ColumnValue::Timestamp)
+                .ok_or_else(|| invalid("timestamp overflow"))
+        }
+        (a, b) => {
+            let (Some(a), Some(b)) = (as_f64(a), as_f64(b)) else {
+                return Err(ColumnStoreError::InvalidExpression(format!(
+                    "cannot apply {op:?} to {} and {}",
+                    ColumnStore::value_type_name(left),
+                    ColumnStore::value_type_name(right)
+                )));
+            };
+            let result = match op {
+                ColumnArithOp::Add => a + b,
+                ColumnArithOp::Sub => a - b,
+                ColumnArithOp::Mul => a * b,
+                ColumnArithOp::Div if b == 0.0 => return Err(invalid("division by zero")),
+                ColumnArithOp::Div => a / b,
+            };
+            Ok(ColumnValue::Float(result))
+        }
+    }
+}
+
+/// Widens an `Int` result assigned to a `Float` column.
+fn widen_for_column(col: &TypedColumn, value: ColumnValue) -> ColumnValue {
+    match (col, value) {
+        (TypedColumn::Float(_), ColumnValue::Int(v)) => ColumnValue::Float(v as f64),
+        (_, value) => value,
+    }
+}
@@ -0,0 +1,167 @@
+//! Tests for conditional batch updates (`batch_update_where`).
+
+use super::{
+    ColumnArithOp, ColumnExpr, ColumnStore, ColumnStoreError, ColumnType, ColumnValue, CompareOp,
+    ConditionalUpdate, UpdateCondition,
+};
+
+fn inventory() -> ColumnStore {
+    let mut store = ColumnStore::with_primary_key(
+        &[
+            ("id", ColumnType::Int),
+            ("stock", ColumnType::Int),
+            ("price", ColumnType::Float),
+            ("name", ColumnType::String),
+        ],
+        "id",
+    )
+    .expect("valid schema");
+    for (id, stock) in [(1, 5), (2, 0), (3, 1)] {
+        let name = store.string_table_mut().intern(&format!("item-{id}"));
+        store
+            .insert_row(&[
+                ("id", ColumnValue::Int(id)),
+                ("stock", ColumnValue::Int(stock)),
+                ("price", ColumnValue::Float(10.0)),
+                ("name", ColumnValue::String(name)),
+            ])
+            .expect("insert");
+    }
+    store
+}
+
+fn int_at(store: &ColumnStore, pk: i64, column: &str) -> Option<i64> {
+    let row = store.get_row_idx_by_pk(pk)?;
+    store.get_value_as_json(column, row)?.as_i64()
+}
+
+fn decrement_in_stock() -> ConditionalUpdate {
+    ConditionalUpdate {
+        assignments: vec![(
+            "stock".to_string(),
+            ColumnExpr::binary(
+                ColumnExpr::column("stock"),
+                ColumnArithOp::Sub,
+                ColumnExpr::Value(ColumnValue::Int(1)),
+            ),
+        )],
+        conditions: vec![UpdateCondition {
+            column: "stock".to_string(),
+            op: CompareOp::Gt,
+            value: ColumnValue::Int(0),
+        }],
+    }
+}
+
+#[test]
+fn test_conditional_decrement_skips_rows_failing_the_condition() {
+    let mut store = inventory();
+    let result = store.batch_update_where(&[1, 2, 3, 99], &decrement_in_stock());
+
+    assert_eq!(result.successful, 2);
+    assert_eq!(result.skipped, 1);
+    assert_eq!(result.failed, vec![(99, ColumnStoreError::RowNotFound(99))]);
+    assert_eq!(int_at(&store, 1, "stock"), Some(4));
+    assert_eq!(int_at(&store, 2, "stock"), Some(0));
+    assert_eq!(int_at(&store, 3, "stock"), Some(0));
+
+    // Row 3 is now out of stock too.
+    let result = store.batch_update_where(&[3], &decrement_in_stock());
+    assert_eq!((result.successful, result.skipped), (0, 1));
+}
+
+#[test]
+fn test_assignments_read_pre_update_values() {
+    let mut store = inventory();
+    let update = ConditionalUpdate {
+        assignments: vec![
+            (
+                "stock".to_string(),
+                ColumnExpr::binary(
+                    ColumnExpr::column("stock"),
+                    ColumnArithOp::Mul,
+                    ColumnExpr::Value(ColumnValue::Int(2)),
+                ),
+            ),
+            // Int result widened into the Float column, from the old stock.
+            ("price".to_string(), ColumnExpr::column("stock")),
+        ],
+        conditions: Vec::new(),
+    };
+    let result = store.batch_update_where(&[1], &update);
+    assert_eq!(result.successful, 1);
+    assert_eq!(int_at(&store, 1, "stock"), Some(10));
+    let row = store.get_row_idx_by_pk(1).expect("row");
+    assert_eq!(
+        store.get_value_as_json("price", row),
+        Some(serde_json::json!(5.0))
+    );
+}
+
+#[test]
+fn test_failing_assignment_leaves_row_untouched() {
+    let mut store = inventory();
+    let update = ConditionalUpdate {
+        assignments: vec![
+            (
+                "stock".to_string(),
+                ColumnExpr::Value(ColumnValue::Int(100)),
+            ),
+            (
+                "price".to_string(),
+                ColumnExpr::binary(
+                    ColumnExpr::column("price"),
+                    ColumnArithOp::Div,
+                    ColumnExpr::Value(ColumnValue::Int(0)),
+                ),
+            ),
+        ],
+        conditions: Vec::new(),
+    };
+    let result = store.batch_update_where(&[1], &update);
+    assert_eq!(result.successful, 0);
+    assert!(matches!(
+        result.failed.as_slice(),
+        [(1, ColumnStoreError::InvalidExpression(_))]
+    ));
+    assert_eq!(int_at(&store, 1, "stock"), Some(5), "no partial write");
+}
+
+#[test]
+fn test_type_mismatch_and_primary_key_are_rejected() {
+    let mut store = inventory();
+    let to_string = ConditionalUpdate {
+        assignments: vec![("stock".to_string(), ColumnExpr::column("name"))],
+        conditions: Vec::new(),
+    };
+    let result = store.batch_update_where(&[1], &to_string);
+    assert!(matches!(
+        result.failed.as_slice(),
+        [(1, ColumnStoreError::TypeMismatch { .. })]
+    ));
+
+    let pk = ConditionalUpdate {
+        assignments: vec![("id".to_string(), ColumnExpr::Value(ColumnValue::Int(7)))],
+        conditions: Vec::new(),
+    };
+    let result = store.batch_update_where(&[1], &pk);
+    assert_eq!(result.failed, vec![(1, ColumnStoreError::PrimaryKeyUpdate)]);
+}
+
+#[test]
+fn test_string_conditions_compare_text() {
+    let mut store = inventory();
+    let target = store.string_table_mut().intern("item-2");
+    let update = ConditionalUpdate {
+        assignments: vec![("stock".to_string(), ColumnExpr::Value(ColumnValue::Int(42)))],
+        conditions: vec![UpdateCondition {
+            column: "name".to_string(),
+            op: CompareOp::Gte,
+            value: ColumnValue::String(target),
+        }],
+    };
+    let result = store.batch_update_where(&[1, 2, 3], &update);
+    assert_eq!((result.successful, result.skipped), (2, 1));
+    assert_eq!(int_at(&store, 1, "stock"), Some(5));
+    assert_eq!(int_at(&store, 2, "stock"), Some(42));
+}
@@ -81,6 +81,18 @@ fn scan_float_column_bitmap(
     scan_cells_bitmap(col, &store.deletion_bitmap, predicate)
 }
 
+/// Scans a timestamp column, returning a `RoaringBitmap` of matching indices.
+fn scan_timestamp_column_bitmap(
+    store: &ColumnStore,
+    column: &str,
+    predicate: impl Fn(i64) -> bool,
+) -> RoaringBitmap {
+    let Some(TypedColumn::Timestamp(col)) = store.columns.get(column) else {
+        return RoaringBitmap::new();
+    };
+    scan_cells_bitmap(col, &store.deletion_bitmap, predicate)
+}
+
 /// Scans a bool column, returning a `RoaringBitmap` of rows equal to `value`.
 fn scan_bool_column_bitmap(store: &ColumnStore, column: &str, value: bool) -> RoaringBitmap {
     let Some(TypedColumn::Bool(col)) = store.columns.get(column) else {
@@ -255,6 +267,20 @@ impl ColumnStore {
         scan_int_column_bitmap(self, column, |v| v > low && v < high)
     }
 
+    /// Filters a timestamp column to `[start_ms, end_ms)`, returning a bitmap.
+    ///
+    /// Bounds are UTC epoch milliseconds. Excludes deleted rows; returns an
+    /// empty bitmap for missing or non-timestamp columns.
+    #[must_use]
+    pub fn filter_range_timestamp_bitmap(
+        &self,
+        column: &str,
+        start_ms: i64,
+        end_ms: i64,
+    ) -> RoaringBitmap {
+        scan_timestamp_column_bitmap(self, column, |v| v >= start_ms && v < end_ms)
+    }
+
     /// Filters a float column by an arbitrary predicate, returning a bitmap.
     ///
     /// Excludes deleted rows. Indices >= `u32::MAX` are safely skipped.
@@ -352,7 +378,7 @@ impl ColumnStore {
     /// Filters a string column by a predicate on the interned id, returning a bitmap.
     ///
     /// Excludes deleted rows. Indices >= `u32::MAX` are safely skipped.
-    fn filter_column_by_bitmap(
+    pub(super) fn filter_column_by_bitmap(
         &self,
         col: &[Option<StringId>],
         predicate: impl Fn(&StringId) -> bool,
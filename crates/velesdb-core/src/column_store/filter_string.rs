@@ -0,0 +1,208 @@
+//! LIKE and range filters over dictionary-encoded string columns.
+//!
+//! A string column stores interned [`StringId`]s, so a predicate on the
+//! string only has to be evaluated once per *distinct* value: the matching
+//! ids are collected into a mask over the dictionary, then the column is
+//! scanned comparing ids. Range predicates and LIKE patterns with a literal
+//! prefix binary-search the sorted dictionary instead of visiting every
+//! entry (see [`super::StringTable::sorted_ids`]).
+//!
+//! LIKE semantics are those of the JSON filter (`filter::like_match`),
+//! including its guardrails, so results agree with a payload scan.
+//!
+//! The `*_collated_*` variants compare under a [`Collation`]: each distinct
+//! value is folded once, so case-insensitive or NFC equality costs one pass
+//! over the dictionary rather than one per row.
+
+use std::ops::Bound;
+
+use roaring::RoaringBitmap;
+use rustc_hash::FxHashSet;
+
+use super::types::{StringId, TypedColumn};
+use super::ColumnStore;
+use crate::filter::{like_match, like_match_collated, Collation};
+
+impl ColumnStore {
+    /// Returns a bitmap of rows whose string value matches a SQL LIKE
+    /// pattern (`ILIKE` when `case_insensitive`).
+    ///
+    /// The pattern is evaluated once per distinct value. For case-sensitive
+    /// patterns that start with literal text, only the dictionary entries
+    /// sharing that prefix are evaluated.
+    ///
+    /// Excludes deleted rows. Returns an empty bitmap for missing or
+    /// non-string columns.
+    #[must_use]
+    pub fn filter_like_string_bitmap(
+        &self,
+        column: &str,
+        pattern: &str,
+        case_insensitive: bool,
+    ) -> RoaringBitmap {
+        let Some(TypedColumn::String(col)) = self.columns.get(column) else {
+            return RoaringBitmap::new();
+        };
+        let prefix = literal_prefix(pattern);
+        let candidates = if case_insensitive || prefix.is_empty() {
+            self.string_table.sorted_ids()
+        } else {
+            self.string_table.ids_with_prefix(&prefix)
+        };
+        let matching = candidates.iter().copied().filter(|&id| {
+            self.string_table
+                .get(id)
+                .is_some_and(|s| like_match(s, pattern, case_insensitive))
+        });
+        self.scan_string_mask(col, matching)
+    }
+
+    /// Like [`filter_like_string_bitmap`](Self::filter_like_string_bitmap),
+    /// with values and pattern compared under `collation`.
+    ///
+    /// A case-insensitive collation turns `LIKE` into `ILIKE`.
+    #[must_use]
+    pub fn filter_like_string_collated_bitmap(
+        &self,
+        column: &str,
+        pattern: &str,
+        case_insensitive: bool,
+        collation: Collation,
+    ) -> RoaringBitmap {
+        if collation.is_binary() {
+            return self.filter_like_string_bitmap(column, pattern, case_insensitive);
+        }
+        let Some(TypedColumn::String(col)) = self.columns.get(column) else {
+            return RoaringBitmap::new();
+        };
+        let matching = self
+            .string_table
+            .sorted_ids()
+            .iter()
+            .copied()
+            .filter(|&id| {
+                self.string_table
+                    .get(id)
+                    .is_some_and(|s| like_match_collated(s, pattern, case_insensitive, collation))
+            });
+        self.scan_string_mask(col, matching)
+    }
+
+    /// Returns a bitmap of rows whose string value equals `value` under
+    /// `collation`.
+    ///
+    /// Excludes deleted rows. Returns an empty bitmap for missing or
+    /// non-string columns.
+    #[must_use]
+    pub fn filter_eq_string_collated_bitmap(
+        &self,
+        column: &str,
+        value: &str,
+        collation: Collation,
+    ) -> RoaringBitmap {
+        if collation.is_binary() {
+            return self.filter_eq_string_bitmap(column, value);
+        }
+        self.filter_in_string_collated_bitmap(column, &[value], collation)
+    }
+
+    /// Returns a bitmap of rows whose string value equals any of `values`
+    /// under `collation`.
+    ///
+    /// The literals are folded once into a hash set; every distinct
+    /// dictionary value is folded and probed against it.
+    ///
+    /// Excludes deleted rows. Returns an empty bitmap for missing or
+    /// non-string columns.
+    #[must_use]
+    pub fn filter_in_string_collated_bitmap(
+        &self,
+        column: &str,
+        values: &[&str],
+        collation: Collation,
+    ) -> RoaringBitmap {
+        if collation.is_binary() {
+            return self.filter_in_string_bitmap(column, values);
+        }
+        let Some(TypedColumn::String(col)) = self.columns.get(column) else {
+            return RoaringBitmap::new();
+        };
+        let folded: FxHashSet<_> = values.iter().map(|v| collation.fold(v)).collect();
+        let matching = self
+            .string_table
+            .sorted_ids()
+            .iter()
+            .copied()
+            .filter(|&id| {
+                self.string_table
+                    .get(id)
+                    .is_some_and(|s| folded.contains(&collation.fold(s)))
+            });
+        self.scan_string_mask(col, matching)
+    }
+
+    /// Returns a bitmap of rows whose string value lies within
+    /// `(low, high)`, ordered bytewise like the JSON filter's string
+    /// comparisons.
+    ///
+    /// Excludes deleted rows. Returns an empty bitmap for missing or
+    /// non-string columns.
+    #[must_use]
+    pub fn filter_range_string_bitmap(
+        &self,
+        column: &str,
+        low: Bound<&str>,
+        high: Bound<&str>,
+    ) -> RoaringBitmap {
+        let Some(TypedColumn::String(col)) = self.columns.get(column) else {
+            return RoaringBitmap::new();
+        };
+        let ids = self.string_table.ids_in_range(low, high);
+        self.scan_string_mask(col, ids.iter().copied())
+    }
+
+    /// Scans a string column for rows whose id is among `ids`, using a mask
+    /// indexed by id.
+    fn scan_string_mask(
+        &self,
+        col: &[Option<StringId>],
+        ids: impl Iterator<Item = StringId>,
+    ) -> RoaringBitmap {
+        let mut mask = vec![false; self.string_table.len()];
+        let mut any = false;
+        for id in ids {
+            if let Some(slot) = mask.get_mut(id.0 as usize) {
+                *slot = true;
+                any = true;
+            }
+        }
+        if !any {
+            return RoaringBitmap::new();
+        }
+        self.filter_column_by_bitmap(col, |id| mask.get(id.0 as usize) == Some(&true))
+    }
+}
+
+/// Literal bytes a LIKE pattern starts with, up to its first wildcard.
+///
+/// Mirrors the pattern tokenizer: `\x` is the literal `x`, a trailing `\`
+/// is itself literal.
+fn literal_prefix(pattern: &str) -> Vec<u8> {
+    let bytes = pattern.as_bytes();
+    let mut prefix = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if i + 1 < bytes.len() => {
+                prefix.push(bytes[i + 1]);
+                i += 2;
+            }
+            b'%' | b'_' => break,
+            c => {
+                prefix.push(c);
+                i += 1;
+            }
+        }
+    }
+    prefix
+}
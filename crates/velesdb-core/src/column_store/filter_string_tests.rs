@@ -0,0 +1,169 @@
+//! Tests for LIKE / range scans over dictionary-encoded string columns.
+
+use std::ops::Bound;
+
+use crate::column_store::{ColumnStore, ColumnType, ColumnValue};
+use crate::filter::Collation;
+
+/// One row per label, in order, in a `name` string column.
+fn store_with_names(labels: &[&str]) -> ColumnStore {
+    let mut store = ColumnStore::with_schema(&[("name", ColumnType::String)]);
+    for label in labels {
+        let sid = store.string_table_mut().intern(label);
+        store.push_row(&[("name", ColumnValue::String(sid))]);
+    }
+    store
+}
+
+fn rows(bits: &roaring::RoaringBitmap) -> Vec<u32> {
+    bits.iter().collect()
+}
+
+const NAMES: &[&str] = &["apple", "apricot", "banana", "Apple", "apple", "cherry"];
+
+#[test]
+fn like_prefix_uses_exact_semantics() {
+    let store = store_with_names(NAMES);
+    assert_eq!(
+        rows(&store.filter_like_string_bitmap("name", "ap%", false)),
+        vec![0, 1, 4]
+    );
+    assert_eq!(
+        rows(&store.filter_like_string_bitmap("name", "ap_le", false)),
+        vec![0, 4]
+    );
+    assert!(store
+        .filter_like_string_bitmap("name", "zz%", false)
+        .is_empty());
+}
+
+#[test]
+fn like_without_prefix_and_ilike_scan_the_dictionary() {
+    let store = store_with_names(NAMES);
+    assert_eq!(
+        rows(&store.filter_like_string_bitmap("name", "%an%", false)),
+        vec![2]
+    );
+    assert_eq!(
+        rows(&store.filter_like_string_bitmap("name", "APP%", true)),
+        vec![0, 3, 4]
+    );
+}
+
+#[test]
+fn like_escaped_wildcard_is_part_of_the_prefix() {
+    let store = store_with_names(&["50%off", "50 off", "50%"]);
+    assert_eq!(
+        rows(&store.filter_like_string_bitmap("name", "50\\%%", false)),
+        vec![0, 2]
+    );
+}
+
+#[test]
+fn range_scan_orders_bytewise() {
+    let store = store_with_names(NAMES);
+    // Uppercase sorts before lowercase.
+    assert_eq!(
+        rows(&store.filter_range_string_bitmap(
+            "name",
+            Bound::Included("apple"),
+            Bound::Excluded("banana"),
+        )),
+        vec![0, 1, 4]
+    );
+    assert_eq!(
+        rows(&store.filter_range_string_bitmap("name", Bound::Unbounded, Bound::Excluded("a"))),
+        vec![3]
+    );
+    assert_eq!(
+        rows(&store.filter_range_string_bitmap(
+            "name",
+            Bound::Excluded("banana"),
+            Bound::Unbounded,
+        )),
+        vec![5]
+    );
+}
+
+#[test]
+fn sorted_dictionary_tracks_new_strings() {
+    let mut store = store_with_names(&["b", "d"]);
+    assert_eq!(
+        rows(&store.filter_range_string_bitmap("name", Bound::Unbounded, Bound::Unbounded)),
+        vec![0, 1]
+    );
+    let sid = store.string_table_mut().intern("c");
+    store.push_row(&[("name", ColumnValue::String(sid))]);
+    assert_eq!(
+        rows(&store.filter_range_string_bitmap("name", Bound::Included("c"), Bound::Included("c"))),
+        vec![2]
+    );
+}
+
+#[test]
+fn string_scans_skip_deleted_rows_and_missing_columns() {
+    let mut store = store_with_names(NAMES);
+    store.deletion_bitmap.insert(0);
+    assert_eq!(
+        rows(&store.filter_like_string_bitmap("name", "apple", false)),
+        vec![4]
+    );
+    assert!(store
+        .filter_like_string_bitmap("missing", "%", false)
+        .is_empty());
+    assert!(store
+        .filter_range_string_bitmap("missing", Bound::Unbounded, Bound::Unbounded)
+        .is_empty());
+}
+
+#[test]
+fn collated_eq_and_in_fold_each_distinct_value() {
+    let store = store_with_names(NAMES);
+    let ci = Collation::CaseInsensitive;
+    assert_eq!(
+        rows(&store.filter_eq_string_collated_bitmap("name", "APPLE", ci)),
+        vec![0, 3, 4]
+    );
+    assert_eq!(
+        rows(&store.filter_in_string_collated_bitmap("name", &["Cherry", "BANANA"], ci)),
+        vec![2, 5]
+    );
+    assert_eq!(
+        rows(&store.filter_eq_string_collated_bitmap("name", "APPLE", Collation::Binary)),
+        Vec::<u32>::new()
+    );
+    assert!(store
+        .filter_in_string_collated_bitmap("missing", &["apple"], ci)
+        .is_empty());
+}
+
+#[test]
+fn collated_eq_matches_nfc_equivalents() {
+    let store = store_with_names(&["caf\u{e9}", "cafe\u{301}", "cafe"]);
+    assert_eq!(
+        rows(&store.filter_eq_string_collated_bitmap("name", "caf\u{e9}", Collation::Nfc)),
+        vec![0, 1]
+    );
+    assert_eq!(
+        rows(&store.filter_eq_string_bitmap("name", "caf\u{e9}")),
+        vec![0]
+    );
+}
+
+#[test]
+fn collated_like_is_case_insensitive_under_ci_collation() {
+    let store = store_with_names(NAMES);
+    assert_eq!(
+        rows(&store.filter_like_string_collated_bitmap(
+            "name",
+            "APP%",
+            false,
+            Collation::CaseInsensitive
+        )),
+        vec![0, 3, 4]
+    );
+    assert_eq!(
+        rows(&store.filter_like_string_collated_bitmap("name", "app%", false, Collation::Binary)),
+        vec![0, 4]
+    );
+}
@@ -12,7 +12,7 @@
 //!   (`collection::payload_mirror`), built adaptively for scan-heavy
 //!   workloads; the `ColumnStore` also backs JOIN execution.
 //! - Cache-friendly sequential memory access
-//! - Support for common filter operations: Eq, Gt, Lt, In, Range
+//! - Support for common filter operations: Eq, Gt, Lt, In, Range, LIKE
 //!
 //! # Architecture
 //!
@@ -36,9 +36,15 @@
 mod batch;
 #[cfg(test)]
 mod batch_tests;
+mod conditional;
+#[cfg(test)]
+mod conditional_tests;
 mod filter;
 mod filter_array;
 mod filter_geo;
+mod filter_string;
+#[cfg(test)]
+mod filter_string_tests;
 #[cfg(test)]
 mod filter_tests;
 pub(crate) mod haversine;
@@ -46,6 +52,9 @@ pub(crate) mod haversine;
 mod haversine_tests;
 mod primary_key_ops;
 mod string_table;
+pub(crate) mod timestamp;
+#[cfg(test)]
+mod timestamp_tests;
 mod types;
 mod vacuum;
 #[cfg(test)]
@@ -55,12 +64,13 @@ use roaring::RoaringBitmap;
 use rustc_hash::FxHashMap;
 use std::collections::HashMap;
 
+pub(crate) use conditional::apply_arith;
 pub use filter_geo::{CompareOp, GeoBboxParams, GeoDistanceParams};
 pub use string_table::StringTable;
 pub use types::{
-    AutoVacuumConfig, BatchUpdate, BatchUpdateResult, BatchUpsertResult, ColumnStoreError,
-    ColumnType, ColumnValue, ExpireResult, StringId, TypedColumn, UpsertResult, VacuumConfig,
-    VacuumStats,
+    AutoVacuumConfig, BatchUpdate, BatchUpdateResult, BatchUpsertResult, ColumnArithOp, ColumnExpr,
+    ColumnStoreError, ColumnType, ColumnValue, ConditionalUpdate, ExpireResult, StringId,
+    TypedColumn, UpdateCondition, UpsertResult, VacuumConfig, VacuumStats,
 };
 
 /// Column store for high-performance filtering.
@@ -205,6 +215,9 @@ impl ColumnStore {
             ColumnType::Bool => TypedColumn::new_bool(0),
             ColumnType::Array(inner) => TypedColumn::new_array((**inner).clone(), 0),
             ColumnType::GeoPoint => TypedColumn::new_geopoint(0),
+            ColumnType::Timestamp => TypedColumn::new_timestamp(0),
+            ColumnType::Float32List => TypedColumn::new_float32_list(0),
+            ColumnType::Json => TypedColumn::new_json(0),
         };
         self.columns.insert(name.to_string(), column);
     }
@@ -355,6 +368,9 @@ impl ColumnStore {
             ColumnValue::GeoPoint(lat, lng) => {
                 serde_json::json!({"lat": lat, "lng": lng})
             }
+            ColumnValue::Timestamp(ms) => serde_json::json!(timestamp::format_rfc3339_millis(*ms)),
+            ColumnValue::Float32List(v) => serde_json::json!(v),
+            ColumnValue::Json(v) => v.clone(),
         }
     }
 }
@@ -5,6 +5,15 @@
 //! `StringId` uses u32 internally, limiting the table to ~4 billion strings.
 //! The `intern()` method debug-asserts this limit (physically unreachable:
 //! 4 billion interned strings would require terabytes of RAM).
+//!
+//! # Sorted dictionary
+//!
+//! Range and prefix scans need the dictionary in byte order. That view is
+//! built lazily on the first such scan and dropped whenever a new string is
+//! interned, so tables that only ever serve equality lookups never pay for it.
+
+use std::ops::Bound;
+use std::sync::OnceLock;
 
 use rustc_hash::FxHashMap;
 
@@ -17,6 +26,8 @@ pub struct StringTable {
     string_to_id: FxHashMap<String, StringId>,
     /// ID to string mapping (for retrieval)
     id_to_string: Vec<String>,
+    /// IDs ordered by their string's bytes, built on demand.
+    sorted: OnceLock<Vec<StringId>>,
 }
 
 impl StringTable {
@@ -45,6 +56,7 @@ impl StringTable {
         let id = StringId(len as u32);
         self.id_to_string.push(s.to_string());
         self.string_to_id.insert(s.to_string(), id);
+        self.sorted.take();
         id
     }
 
@@ -71,4 +83,50 @@ impl StringTable {
     pub fn is_empty(&self) -> bool {
         self.id_to_string.is_empty()
     }
+
+    /// Returns every ID, ordered by the bytes of its string.
+    ///
+    /// Built on first use and cached until the next new string is interned.
+    #[must_use]
+    pub fn sorted_ids(&self) -> &[StringId] {
+        self.sorted.get_or_init(|| {
+            let mut ids: Vec<StringId> = self.string_to_id.values().copied().collect();
+            ids.sort_unstable_by(|a, b| self.id_str(*a).cmp(self.id_str(*b)));
+            ids
+        })
+    }
+
+    /// Returns the IDs whose string lies within `(low, high)`, in byte order.
+    #[must_use]
+    pub fn ids_in_range(&self, low: Bound<&str>, high: Bound<&str>) -> &[StringId] {
+        let sorted = self.sorted_ids();
+        let start = match low {
+            Bound::Included(lo) => sorted.partition_point(|&id| self.id_str(id) < lo),
+            Bound::Excluded(lo) => sorted.partition_point(|&id| self.id_str(id) <= lo),
+            Bound::Unbounded => 0,
+        };
+        let end = match high {
+            Bound::Included(hi) => sorted.partition_point(|&id| self.id_str(id) <= hi),
+            Bound::Excluded(hi) => sorted.partition_point(|&id| self.id_str(id) < hi),
+            Bound::Unbounded => sorted.len(),
+        };
+        sorted.get(start..end).unwrap_or(&[])
+    }
+
+    /// Returns the IDs whose string starts with `prefix` (compared bytewise).
+    #[must_use]
+    pub fn ids_with_prefix(&self, prefix: &[u8]) -> &[StringId] {
+        let sorted = self.sorted_ids();
+        let start = sorted.partition_point(|&id| self.id_str(id).as_bytes() < prefix);
+        let len =
+            sorted[start..].partition_point(|&id| self.id_str(id).as_bytes().starts_with(prefix));
+        &sorted[start..start + len]
+    }
+
+    /// String for an ID known to be in the table.
+    fn id_str(&self, id: StringId) -> &str {
+        self.id_to_string
+            .get(id.0 as usize)
+            .map_or("", String::as_str)
+    }
 }
@@ -0,0 +1,45 @@
+//! Tests for RFC 3339 ↔ epoch-millisecond conversion.
+
+use super::timestamp::{format_rfc3339_millis, parse_rfc3339_millis};
+
+#[test]
+fn parses_utc_and_offset_forms() {
+    assert_eq!(parse_rfc3339_millis("1970-01-01T00:00:00Z"), Some(0));
+    assert_eq!(
+        parse_rfc3339_millis("2024-02-29T12:30:15Z"),
+        Some(1_709_209_815_000)
+    );
+    assert_eq!(
+        parse_rfc3339_millis("2024-02-29T14:30:15.250+02:00"),
+        Some(1_709_209_815_250)
+    );
+    assert_eq!(
+        parse_rfc3339_millis("1969-12-31T23:59:59.999999z"),
+        Some(-1)
+    );
+}
+
+#[test]
+fn rejects_partial_and_invalid_dates() {
+    for input in [
+        "2024-02-29",
+        "2024-02-29T12:30:15",
+        "2023-02-29T00:00:00Z",
+        "2024-13-01T00:00:00Z",
+        "2024-01-01T24:00:00Z",
+        "2024-01-01T00:00:00.Z",
+        "2024-01-01T00:00:00+0200",
+        "not a timestamp at all",
+    ] {
+        assert_eq!(parse_rfc3339_millis(input), None, "{input}");
+    }
+}
+
+#[test]
+fn formats_in_utc_and_round_trips() {
+    assert_eq!(format_rfc3339_millis(0), "1970-01-01T00:00:00Z");
+    assert_eq!(format_rfc3339_millis(-1), "1969-12-31T23:59:59.999Z");
+    for ms in [1_709_209_815_250, 951_782_400_000, -86_400_000] {
+        assert_eq!(parse_rfc3339_millis(&format_rfc3339_millis(ms)), Some(ms));
+    }
+}
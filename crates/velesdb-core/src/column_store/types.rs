@@ -3,6 +3,9 @@
 use smallvec::SmallVec;
 use thiserror::Error;
 
+use super::filter_geo::CompareOp;
+use super::timestamp::format_rfc3339_millis;
+
 /// Errors that can occur in ColumnStore operations.
 #[derive(Debug, Error, PartialEq, Eq)]
 #[non_exhaustive]
@@ -36,6 +39,9 @@ pub enum ColumnStoreError {
     /// Attempted to update primary key column.
     #[error("Cannot update primary key column - would corrupt index")]
     PrimaryKeyUpdate,
+    /// An update expression could not be evaluated.
+    #[error("Invalid update expression: {0}")]
+    InvalidExpression(String),
 }
 
 /// Interned string ID for fast equality comparisons.
@@ -58,6 +64,12 @@ pub enum ColumnType {
     Array(Box<ColumnType>),
     /// Geographic coordinate pair (latitude, longitude).
     GeoPoint,
+    /// Point in time as UTC epoch milliseconds.
+    Timestamp,
+    /// Dense `f32` vector (`List<f32>`), e.g. a secondary embedding.
+    Float32List,
+    /// Arbitrary JSON document, compared structurally.
+    Json,
 }
 
 /// A value that can be stored in a column.
@@ -78,12 +90,18 @@ pub enum ColumnValue {
     Array(Vec<ColumnValue>),
     /// Geographic coordinate pair (latitude, longitude).
     GeoPoint(f64, f64),
+    /// UTC epoch milliseconds.
+    Timestamp(i64),
+    /// Dense `f32` vector.
+    Float32List(Vec<f32>),
+    /// JSON document.
+    Json(serde_json::Value),
 }
 
 impl PartialEq for ColumnValue {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Int(a), Self::Int(b)) | (Self::Timestamp(a), Self::Timestamp(b)) => a == b,
             (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
             (Self::String(a), Self::String(b)) => a == b,
             (Self::Bool(a), Self::Bool(b)) => a == b,
@@ -92,6 +110,10 @@ impl PartialEq for ColumnValue {
             (Self::GeoPoint(lat1, lng1), Self::GeoPoint(lat2, lng2)) => {
                 lat1.to_bits() == lat2.to_bits() && lng1.to_bits() == lng2.to_bits()
             }
+            (Self::Float32List(a), Self::Float32List(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.to_bits() == y.to_bits())
+            }
+            (Self::Json(a), Self::Json(b)) => a == b,
             _ => false,
         }
     }
@@ -120,6 +142,12 @@ pub enum TypedColumn {
     },
     /// Geographic point column: each row is an optional `(lat, lng)` pair.
     GeoPoint(Vec<Option<(f64, f64)>>),
+    /// Timestamp column (UTC epoch milliseconds).
+    Timestamp(Vec<Option<i64>>),
+    /// `f32` vector column.
+    Float32List(Vec<Option<Vec<f32>>>),
+    /// JSON document column.
+    Json(Vec<Option<serde_json::Value>>),
 }
 
 impl TypedColumn {
@@ -162,16 +190,36 @@ impl TypedColumn {
         Self::GeoPoint(Vec::with_capacity(capacity))
     }
 
+    /// Creates a new timestamp column with the given capacity.
+    #[must_use]
+    pub fn new_timestamp(capacity: usize) -> Self {
+        Self::Timestamp(Vec::with_capacity(capacity))
+    }
+
+    /// Creates a new `f32` vector column with the given capacity.
+    #[must_use]
+    pub fn new_float32_list(capacity: usize) -> Self {
+        Self::Float32List(Vec::with_capacity(capacity))
+    }
+
+    /// Creates a new JSON document column with the given capacity.
+    #[must_use]
+    pub fn new_json(capacity: usize) -> Self {
+        Self::Json(Vec::with_capacity(capacity))
+    }
+
     /// Returns the number of values in the column.
     #[must_use]
     pub fn len(&self) -> usize {
         match self {
-            Self::Int(v) => v.len(),
+            Self::Int(v) | Self::Timestamp(v) => v.len(),
             Self::Float(v) => v.len(),
             Self::String(v) => v.len(),
             Self::Bool(v) => v.len(),
             Self::Array { data, .. } => data.len(),
             Self::GeoPoint(v) => v.len(),
+            Self::Float32List(v) => v.len(),
+            Self::Json(v) => v.len(),
         }
     }
 
@@ -184,12 +232,14 @@ impl TypedColumn {
     /// Pushes a null value to the column.
     pub fn push_null(&mut self) {
         match self {
-            Self::Int(v) => v.push(None),
+            Self::Int(v) | Self::Timestamp(v) => v.push(None),
             Self::Float(v) => v.push(None),
             Self::String(v) => v.push(None),
             Self::Bool(v) => v.push(None),
             Self::Array { data, .. } => data.push(None),
             Self::GeoPoint(v) => v.push(None),
+            Self::Float32List(v) => v.push(None),
+            Self::Json(v) => v.push(None),
         }
     }
 
@@ -211,6 +261,9 @@ impl TypedColumn {
                 // push_typed is unchecked and stores as-is.
                 col.push(Some((*lat, *lng)));
             }
+            (Self::Timestamp(col), ColumnValue::Timestamp(ms)) => col.push(Some(*ms)),
+            (Self::Float32List(col), ColumnValue::Float32List(v)) => col.push(Some(v.clone())),
+            (Self::Json(col), ColumnValue::Json(v)) => col.push(Some(v.clone())),
             (col, ColumnValue::Null | _) => col.push_null(),
         }
     }
@@ -235,6 +288,13 @@ impl TypedColumn {
             Self::GeoPoint(v) => v
                 .get(row_idx)
                 .and_then(|opt| opt.map(|(lat, lng)| serde_json::json!({"lat": lat, "lng": lng}))),
+            Self::Timestamp(v) => v
+                .get(row_idx)
+                .and_then(|opt| opt.map(|ms| serde_json::json!(format_rfc3339_millis(ms)))),
+            Self::Float32List(v) => v
+                .get(row_idx)
+                .and_then(|opt| opt.as_ref().map(|v| serde_json::json!(v))),
+            Self::Json(v) => v.get(row_idx).and_then(Clone::clone),
             Self::String(_) | Self::Array { .. } => None,
         }
     }
@@ -254,12 +314,93 @@ pub struct BatchUpdate {
 /// Result of a batch update operation.
 #[derive(Debug, Default)]
 pub struct BatchUpdateResult {
-    /// Number of successful updates.
+    /// Number of successful updates (cells for `batch_update`, rows for
+    /// `batch_update_where`).
     pub successful: usize,
+    /// Rows left untouched because a condition did not hold.
+    pub skipped: usize,
     /// List of failed updates with their errors.
     pub failed: Vec<(i64, ColumnStoreError)>,
 }
 
+/// Arithmetic operator in a [`ColumnExpr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColumnArithOp {
+    /// Addition (`+`).
+    Add,
+    /// Subtraction (`-`).
+    Sub,
+    /// Multiplication (`*`).
+    Mul,
+    /// Division (`/`); truncates between integers.
+    Div,
+}
+
+/// Expression over the current values of a row, e.g. `stock - 1`.
+///
+/// Evaluated by [`ColumnStore::batch_update_where`](super::ColumnStore::batch_update_where)
+/// against the row as it was *before* the update, so `SET a = b, b = a`
+/// swaps. A null operand yields null.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ColumnExpr {
+    /// Constant value.
+    Value(ColumnValue),
+    /// Current value of a column of the same row.
+    Column(String),
+    /// Binary arithmetic.
+    Binary {
+        /// Operator.
+        op: ColumnArithOp,
+        /// Left operand.
+        left: Box<ColumnExpr>,
+        /// Right operand.
+        right: Box<ColumnExpr>,
+    },
+}
+
+impl ColumnExpr {
+    /// Reference to a column of the row being updated.
+    #[must_use]
+    pub fn column(name: &str) -> Self {
+        Self::Column(name.to_string())
+    }
+
+    /// Builds `left op right`.
+    #[must_use]
+    pub fn binary(left: Self, op: ColumnArithOp, right: Self) -> Self {
+        Self::Binary {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+}
+
+/// Row predicate guarding a conditional update: `column op value`.
+///
+/// Null cells never satisfy a condition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateCondition {
+    /// Column to test.
+    pub column: String,
+    /// Comparison operator.
+    pub op: CompareOp,
+    /// Right-hand side value.
+    pub value: ColumnValue,
+}
+
+/// A conditional update applied to a set of rows:
+/// `SET assignments WHERE conditions`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConditionalUpdate {
+    /// `(column, expression)` pairs, all applied together or not at all.
+    pub assignments: Vec<(String, ColumnExpr)>,
+    /// Conditions that must all hold for a row to be updated.
+    pub conditions: Vec<UpdateCondition>,
+}
+
 /// Result of an expire operation.
 #[derive(Debug, Default)]
 pub struct ExpireResult {
@@ -183,6 +183,20 @@ impl ColumnStore {
                 let (new_data, bytes) = compact_vec(data, deleted, 16);
                 (TypedColumn::GeoPoint(new_data), bytes)
             }
+            TypedColumn::Timestamp(data) => {
+                let (new_data, bytes) = compact_vec(data, deleted, 8);
+                (TypedColumn::Timestamp(new_data), bytes)
+            }
+            TypedColumn::Float32List(data) => {
+                // Estimate a 1024-byte vector per cell (256 dimensions).
+                let (new_data, bytes) = compact_vec_clone(data, deleted, 1024);
+                (TypedColumn::Float32List(new_data), bytes)
+            }
+            TypedColumn::Json(data) => {
+                // Estimate ~64 bytes per document, as for array cells.
+                let (new_data, bytes) = compact_vec_clone(data, deleted, 64);
+                (TypedColumn::Json(new_data), bytes)
+            }
         }
     }
 
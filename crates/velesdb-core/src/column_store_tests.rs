@@ -2624,3 +2624,115 @@ mod array_column_tests {
         assert_eq!(with_many, bitmap_indices);
     }
 }
+
+#[cfg(test)]
+mod extended_type_tests {
+    use crate::column_store::*;
+
+    fn store() -> ColumnStore {
+        ColumnStore::with_primary_key(
+            &[
+                ("id", ColumnType::Int),
+                ("created_at", ColumnType::Timestamp),
+                ("embedding", ColumnType::Float32List),
+                ("meta", ColumnType::Json),
+            ],
+            "id",
+        )
+        .expect("valid schema")
+    }
+
+    #[test]
+    fn test_extended_types_round_trip_as_json() {
+        let mut store = store();
+        store
+            .insert_row(&[
+                ("id", ColumnValue::Int(1)),
+                ("created_at", ColumnValue::Timestamp(1_709_209_815_250)),
+                ("embedding", ColumnValue::Float32List(vec![0.5, -1.0])),
+                (
+                    "meta",
+                    ColumnValue::Json(serde_json::json!({"tags": ["a"]})),
+                ),
+            ])
+            .expect("insert");
+
+        assert_eq!(
+            store.get_value_as_json("created_at", 0),
+            Some(serde_json::json!("2024-02-29T12:30:15.250Z"))
+        );
+        assert_eq!(
+            store.get_value_as_json("embedding", 0),
+            Some(serde_json::json!([0.5, -1.0]))
+        );
+        assert_eq!(
+            store.get_value_as_json("meta", 0),
+            Some(serde_json::json!({"tags": ["a"]}))
+        );
+    }
+
+    #[test]
+    fn test_extended_types_reject_mismatched_values() {
+        let mut store = store();
+        store
+            .insert_row(&[("id", ColumnValue::Int(1))])
+            .expect("insert");
+
+        let err = store
+            .update_by_pk(1, "created_at", ColumnValue::Int(5))
+            .expect_err("Int into Timestamp");
+        assert!(matches!(err, ColumnStoreError::TypeMismatch { .. }));
+        store
+            .update_by_pk(1, "created_at", ColumnValue::Timestamp(5))
+            .expect("Timestamp into Timestamp");
+        store
+            .update_by_pk(1, "meta", ColumnValue::Json(serde_json::json!(null)))
+            .expect("any JSON document");
+    }
+
+    #[test]
+    fn test_timestamp_range_filter_is_half_open() {
+        let mut store = store();
+        for (id, ms) in [(1, 1_000), (2, 2_000), (3, 3_000)] {
+            store
+                .insert_row(&[
+                    ("id", ColumnValue::Int(id)),
+                    ("created_at", ColumnValue::Timestamp(ms)),
+                ])
+                .expect("insert");
+        }
+        store.delete_by_pk(1);
+
+        let rows: Vec<u32> = store
+            .filter_range_timestamp_bitmap("created_at", 1_000, 3_000)
+            .iter()
+            .collect();
+        assert_eq!(rows, vec![1]);
+        assert!(store
+            .filter_range_timestamp_bitmap("embedding", i64::MIN, i64::MAX)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_vacuum_compacts_extended_columns() {
+        let mut store = store();
+        for id in 0..4 {
+            store
+                .insert_row(&[
+                    ("id", ColumnValue::Int(id)),
+                    ("embedding", ColumnValue::Float32List(vec![0.0; 2])),
+                    ("meta", ColumnValue::Json(serde_json::json!(id))),
+                ])
+                .expect("insert");
+        }
+        store.delete_by_pk(0);
+        store.vacuum(VacuumConfig::default());
+
+        assert_eq!(store.row_count(), 3);
+        let row = store.get_row_idx_by_pk(3).expect("row 3 survives");
+        assert_eq!(
+            store.get_value_as_json("meta", row),
+            Some(serde_json::json!(3))
+        );
+    }
+}
@@ -19,7 +19,9 @@ use std::path::Path;
 use thiserror::Error;
 
 // Re-export quantization types so existing `crate::config::Quantization*` paths work.
+pub use crate::config_growth::MmapGrowth;
 pub use crate::config_quantization::{QuantizationConfig, QuantizationType};
+pub use crate::config_residency::{MmapAdvice, MmapResidency};
 
 /// Configuration errors.
 #[derive(Error, Debug)]
@@ -123,6 +125,10 @@ pub struct HnswConfig {
     pub ef_construction: Option<usize>,
     /// Maximum number of layers (0 = auto).
     pub max_layers: usize,
+    /// Runs [`HnswIndex::verify`](crate::HnswIndex::verify) on every
+    /// collection loaded at startup and logs a warning for each damaged
+    /// graph. Costs one pass over every edge per collection.
+    pub verify_on_open: bool,
 }
 
 /// Server-layer configuration types (HTTP transport, logging, storage paths).
@@ -131,8 +137,12 @@ pub struct HnswConfig {
 /// (`SearchConfig`, `HnswConfig`, `LimitsConfig`) to enforce layer boundaries.
 /// Import via `config::server::ServerConfig` or use the crate-root re-exports.
 pub mod server {
+    use std::collections::HashMap;
+
     use serde::{Deserialize, Serialize};
 
+    use super::{MmapGrowth, MmapResidency};
+
     /// Storage configuration section.
     #[derive(Debug, Clone, Serialize, Deserialize)]
     #[serde(default)]
@@ -145,6 +155,41 @@ pub mod server {
         pub mmap_cache_mb: usize,
         /// Vector alignment in bytes.
         pub vector_alignment: usize,
+        /// Default `madvise`/`mlock` residency of collection vector files.
+        pub residency: MmapResidency,
+        /// Per-collection residency overriding [`Self::residency`], keyed by
+        /// collection name.
+        pub collection_residency: HashMap<String, MmapResidency>,
+        /// Payloads kept in each collection's LFU cache for search result
+        /// hydration (0 = disabled).
+        pub payload_cache_entries: usize,
+        /// Default sizing policy of collection vector files.
+        pub growth: MmapGrowth,
+        /// Per-collection growth policy overriding [`Self::growth`], keyed
+        /// by collection name.
+        pub collection_growth: HashMap<String, MmapGrowth>,
+    }
+
+    impl StorageConfig {
+        /// Residency for the collection `name`: its override, else the
+        /// default.
+        #[must_use]
+        pub fn residency_for(&self, name: &str) -> MmapResidency {
+            self.collection_residency
+                .get(name)
+                .copied()
+                .unwrap_or(self.residency)
+        }
+
+        /// Growth policy for the collection `name`: its override, else the
+        /// default.
+        #[must_use]
+        pub fn growth_for(&self, name: &str) -> MmapGrowth {
+            self.collection_growth
+                .get(name)
+                .copied()
+                .unwrap_or(self.growth)
+        }
     }
 
     impl Default for StorageConfig {
@@ -154,6 +199,11 @@ pub mod server {
                 storage_mode: "mmap".to_string(),
                 mmap_cache_mb: 1024,
                 vector_alignment: 64,
+                residency: MmapResidency::default(),
+                collection_residency: HashMap::new(),
+                payload_cache_entries: 0,
+                growth: MmapGrowth::default(),
+                collection_growth: HashMap::new(),
             }
         }
     }
@@ -223,7 +273,8 @@ pub use server::{LoggingConfig, ServerConfig, StorageConfig};
 #[serde(default)]
 #[non_exhaustive]
 pub struct LimitsConfig {
-    /// Maximum vector dimensions.
+    /// Maximum vector dimensions. Default: 8192, which covers the largest
+    /// current embedding models.
     pub max_dimensions: usize,
     /// Maximum vectors per collection.
     pub max_vectors_per_collection: usize,
@@ -233,16 +284,79 @@ pub struct LimitsConfig {
     pub max_payload_size: usize,
     /// Maximum vectors for perfect mode (bruteforce).
     pub max_perfect_mode_vectors: usize,
+    /// Maximum rows a single query may request: SELECT and MATCH `LIMIT`,
+    /// traversal `limit` and scroll `batch_size`. Collections can override
+    /// it with `set_max_query_limit`.
+    pub max_query_limit: usize,
+    /// Maximum vector/text/hybrid searches running at once on one
+    /// collection. `0` (default) disables the limit.
+    pub max_concurrent_searches: usize,
+    /// Maximum scans (VelesQL queries without a vector search, MATCH,
+    /// aggregations, scroll, sampling) running at once on one collection.
+    /// `0` (default) disables the limit.
+    pub max_concurrent_scans: usize,
+    /// Queries that may wait for a slot per collection and kind once the
+    /// concurrency limit is reached; further queries are rejected at once.
+    pub max_admission_queue: usize,
+    /// How long a queued query waits for a slot before it is rejected,
+    /// in milliseconds.
+    pub admission_queue_timeout_ms: u64,
+    /// Largest collection a read query may fully scan (no index narrows its
+    /// filter, or `WITH (hint = 'force_scan')`). `0` (default) disables the
+    /// check.
+    pub max_full_scan_points: usize,
+    /// Maximum points a single server upsert request may carry. Larger
+    /// requests are rejected with `413 Payload Too Large`.
+    pub max_points_per_upsert: usize,
+    /// Maximum `top_k` a single server search request may ask for. Larger
+    /// values are rejected with `422 Unprocessable Entity`.
+    pub max_top_k: usize,
+    /// Maximum queries in one server batch search or multi-query search
+    /// request. Larger requests are rejected with `413 Payload Too Large`.
+    pub max_batch_queries: usize,
+    /// What upserts do with NaN or infinite vector components.
+    pub non_finite_vectors: NonFiniteVectorPolicy,
+    /// Reject all-zero vectors upserted into cosine collections, for which
+    /// cosine similarity is undefined. Off by default: agent memory stores
+    /// zero vectors for entries recorded without an embedding.
+    pub reject_zero_norm_vectors: bool,
+}
+
+/// Handling of NaN and ±infinity vector components on upsert
+/// (`limits.non_finite_vectors`).
+///
+/// Such components make every distance involving the vector NaN, which
+/// poisons search scores and HNSW neighbour selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum NonFiniteVectorPolicy {
+    /// Reject the whole batch with `InvalidVector` (VELES-005) (default).
+    #[default]
+    Reject,
+    /// Replace each non-finite component with `0.0` and store the point.
+    SanitizeToZero,
 }
 
 impl Default for LimitsConfig {
     fn default() -> Self {
         Self {
-            max_dimensions: 4096,
+            max_dimensions: 8192,
             max_vectors_per_collection: 100_000_000,
             max_collections: 1000,
             max_payload_size: 1_048_576, // 1 MB
             max_perfect_mode_vectors: 500_000,
+            max_query_limit: 100_000,
+            max_concurrent_searches: 0,
+            max_concurrent_scans: 0,
+            max_admission_queue: 64,
+            admission_queue_timeout_ms: 1_000,
+            max_full_scan_points: 0,
+            max_points_per_upsert: 100_000,
+            max_top_k: 10_000,
+            max_batch_queries: 1_000,
+            non_finite_vectors: NonFiniteVectorPolicy::Reject,
+            reject_zero_norm_vectors: false,
         }
     }
 }
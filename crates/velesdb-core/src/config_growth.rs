@@ -0,0 +1,96 @@
+//! Growth policy for mmapped vector storage.
+//!
+//! Controls how a collection's `vectors.dat` is sized: how much is reserved
+//! up front, how it grows when full, and whether the grown range is
+//! physically allocated. Every growth remaps the file, so fewer, larger steps
+//! mean fewer remaps. Set a default in `[storage.growth]` and per-collection
+//! overrides in `[storage.collection_growth.<name>]`:
+//!
+//! ```toml
+//! [storage.growth]
+//! preallocate_mb = 4
+//! growth_factor = 1.5
+//! min_growth_mb = 8
+//! max_growth_mb = 1024
+//!
+//! [storage.collection_growth.docs]
+//! preallocate_mb = 512
+//! sparse = false
+//! ```
+//!
+//! Re-exported by `config` and the crate root.
+
+use serde::{Deserialize, Serialize};
+
+/// Sizing policy applied to a collection's vector data file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MmapGrowth {
+    /// Size the data file is brought to when the policy is applied, in MiB.
+    ///
+    /// An empty file is resized to exactly this (so it can also shrink the
+    /// 16 MiB a new collection starts with); a file holding vectors only
+    /// grows to it. Default: 16.
+    pub preallocate_mb: u64,
+    /// Factor the file size is multiplied by when it runs out of room.
+    /// Default: 2.0 (doubling).
+    pub growth_factor: f64,
+    /// Smallest growth step, in MiB. Default: 64.
+    pub min_growth_mb: u64,
+    /// Largest growth step, in MiB, capping `growth_factor` on big files
+    /// (0 = unbounded). A write that needs more still gets it. Default: 0.
+    pub max_growth_mb: u64,
+    /// Leaves grown ranges sparse (the filesystem allocates blocks on first
+    /// write). With `false` the range is allocated when the file grows, so
+    /// a full disk fails the growth instead of a later write into the
+    /// mapping (`SIGBUS` on Unix). Default: `true`.
+    pub sparse: bool,
+}
+
+impl Default for MmapGrowth {
+    fn default() -> Self {
+        Self {
+            preallocate_mb: 16,
+            growth_factor: 2.0,
+            min_growth_mb: 64,
+            max_growth_mb: 0,
+            sparse: true,
+        }
+    }
+}
+
+impl MmapGrowth {
+    /// Whether this is the built-in policy.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// [`preallocate_mb`](Self::preallocate_mb) in bytes.
+    #[must_use]
+    pub fn preallocate_bytes(&self) -> u64 {
+        self.preallocate_mb.saturating_mul(MIB)
+    }
+
+    /// File length after growing a `current_len`-byte file so that it holds
+    /// at least `required_len` bytes.
+    #[must_use]
+    pub fn next_len(&self, current_len: u64, required_len: u64) -> u64 {
+        let min_step = self.min_growth_mb.saturating_mul(MIB);
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )] // Reason: file lengths stay far below 2^52; the float cast saturates.
+        let scaled = (current_len as f64 * self.growth_factor) as u64;
+        let mut step = scaled.saturating_sub(current_len).max(min_step);
+        if self.max_growth_mb > 0 {
+            step = step.min(self.max_growth_mb.saturating_mul(MIB).max(min_step));
+        }
+        current_len
+            .saturating_add(step)
+            .max(required_len.saturating_add(min_step))
+    }
+}
+
+const MIB: u64 = 1024 * 1024;
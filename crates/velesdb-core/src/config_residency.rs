@@ -0,0 +1,60 @@
+//! Memory residency configuration for mmapped vector storage.
+//!
+//! Controls how the OS pages a collection's `vectors.dat` mapping: an
+//! access-pattern hint (`madvise`) and whether to pin the mapping in RAM
+//! (`mlock`). Set a default in `[storage.residency]` and per-collection
+//! overrides in `[storage.collection_residency.<name>]`:
+//!
+//! ```toml
+//! [storage.residency]
+//! advice = "random"
+//!
+//! [storage.collection_residency.docs]
+//! advice = "will_need"
+//! lock = true
+//! ```
+//!
+//! Re-exported by `config` and the crate root.
+
+use serde::{Deserialize, Serialize};
+
+/// Access-pattern hint passed to `madvise` for a vector mapping.
+///
+/// Ignored on platforms without `madvise` (Windows).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MmapAdvice {
+    /// No hint: the kernel's default read-ahead.
+    #[default]
+    Normal,
+    /// Random access: disables read-ahead, so HNSW lookups fault in only
+    /// the pages they touch.
+    Random,
+    /// Sequential access: aggressive read-ahead, for full scans.
+    Sequential,
+    /// Expected to be needed soon: the kernel starts reading the whole
+    /// mapping in the background.
+    WillNeed,
+}
+
+/// Residency settings applied to a collection's vector mapping.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MmapResidency {
+    /// `madvise` hint for the mapping. Default: `normal`.
+    pub advice: MmapAdvice,
+    /// Pins the mapping in RAM with `mlock` so vectors are never paged out.
+    ///
+    /// Best effort: when the lock fails (typically `RLIMIT_MEMLOCK`), a
+    /// warning is logged and the mapping stays pageable. Default: `false`.
+    pub lock: bool,
+}
+
+impl MmapResidency {
+    /// Whether these settings leave the mapping untouched.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
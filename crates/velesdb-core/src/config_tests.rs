@@ -172,6 +172,93 @@ default_mode = "ultra_fast"
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_config_from_toml_storage_residency() {
+        // Arrange
+        let toml = r#"
+[storage.residency]
+advice = "random"
+
+[storage.collection_residency.docs]
+advice = "will_need"
+lock = true
+"#;
+
+        // Act
+        let config = VelesConfig::from_toml(toml).expect("parse");
+
+        // Assert
+        let default = MmapResidency {
+            advice: MmapAdvice::Random,
+            lock: false,
+        };
+        assert_eq!(config.storage.residency_for("other"), default);
+        assert_eq!(
+            config.storage.residency_for("docs"),
+            MmapResidency {
+                advice: MmapAdvice::WillNeed,
+                lock: true,
+            }
+        );
+        assert!(VelesConfig::default()
+            .storage
+            .residency_for("docs")
+            .is_default());
+    }
+
+    #[test]
+    fn test_config_from_toml_storage_growth() {
+        // Arrange
+        let toml = r"
+[storage.growth]
+preallocate_mb = 4
+growth_factor = 1.5
+
+[storage.collection_growth.docs]
+preallocate_mb = 512
+max_growth_mb = 128
+sparse = false
+";
+
+        // Act
+        let config = VelesConfig::from_toml(toml).expect("parse");
+
+        // Assert
+        let other = config.storage.growth_for("other");
+        assert_eq!(other.preallocate_mb, 4);
+        assert!((other.growth_factor - 1.5).abs() < f64::EPSILON);
+        assert_eq!(other.min_growth_mb, 64);
+        let docs = config.storage.growth_for("docs");
+        assert_eq!(docs.preallocate_bytes(), 512 * 1024 * 1024);
+        assert!(!docs.sparse);
+        assert!(VelesConfig::default()
+            .storage
+            .growth_for("docs")
+            .is_default());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_mmap_growth_next_len() {
+        const MIB: u64 = 1024 * 1024;
+        // Default: doubling with a 64 MiB floor and headroom past the write.
+        let growth = MmapGrowth::default();
+        assert_eq!(growth.next_len(16 * MIB, 17 * MIB), 81 * MIB);
+        assert_eq!(growth.next_len(256 * MIB, 257 * MIB), 512 * MIB);
+        assert_eq!(growth.next_len(16 * MIB, 500 * MIB), 564 * MIB);
+
+        // The cap bounds the step on big files but never a single write.
+        let capped = MmapGrowth {
+            growth_factor: 1.5,
+            min_growth_mb: 8,
+            max_growth_mb: 100,
+            ..MmapGrowth::default()
+        };
+        assert_eq!(capped.next_len(64 * MIB, 65 * MIB), 96 * MIB);
+        assert_eq!(capped.next_len(1024 * MIB, 1025 * MIB), 1124 * MIB);
+        assert_eq!(capped.next_len(1024 * MIB, 2048 * MIB), 2056 * MIB);
+    }
+
     // ========================================================================
     // Validation tests
     // ========================================================================
@@ -231,6 +318,31 @@ default_mode = "ultra_fast"
         assert!(err.to_string().contains("storage.storage_mode"));
     }
 
+    #[test]
+    fn test_config_validate_storage_growth_out_of_range() {
+        // Arrange
+        let mut config = VelesConfig::default();
+        config.storage.growth.growth_factor = 0.5;
+        let mut per_collection = VelesConfig::default();
+        per_collection.storage.collection_growth.insert(
+            "docs".to_string(),
+            MmapGrowth {
+                preallocate_mb: 0,
+                ..MmapGrowth::default()
+            },
+        );
+
+        // Act
+        let err = config.validate().unwrap_err();
+        let per_collection_err = per_collection.validate().unwrap_err();
+
+        // Assert
+        assert!(err.to_string().contains("storage.growth.growth_factor"));
+        assert!(per_collection_err
+            .to_string()
+            .contains("storage.collection_growth.docs.preallocate_mb"));
+    }
+
     #[test]
     fn test_config_validate_invalid_log_level() {
         // Arrange
@@ -292,6 +404,59 @@ max_collections = 0
         assert!(err.to_string().contains("limits.max_collections"));
     }
 
+    #[test]
+    fn test_from_toml_max_query_limit() {
+        let config = VelesConfig::from_toml("[limits]\nmax_query_limit = 5000\n").expect("parse");
+        assert_eq!(config.limits.max_query_limit, 5000);
+        assert_eq!(
+            VelesConfig::default().limits.max_query_limit,
+            100_000,
+            "default keeps the historical ceiling"
+        );
+
+        let err = VelesConfig::from_toml("[limits]\nmax_query_limit = 0\n").unwrap_err();
+        assert!(err.to_string().contains("limits.max_query_limit"));
+    }
+
+    #[test]
+    fn test_from_toml_request_size_limits() {
+        let defaults = VelesConfig::default().limits;
+        assert_eq!(defaults.max_points_per_upsert, 100_000);
+        assert_eq!(defaults.max_top_k, 10_000);
+        assert_eq!(defaults.max_batch_queries, 1_000);
+
+        let config = VelesConfig::from_toml(
+            "[limits]\nmax_points_per_upsert = 500\nmax_top_k = 50\nmax_batch_queries = 4\n",
+        )
+        .expect("parse");
+        assert_eq!(config.limits.max_points_per_upsert, 500);
+        assert_eq!(config.limits.max_top_k, 50);
+        assert_eq!(config.limits.max_batch_queries, 4);
+
+        let err = VelesConfig::from_toml("[limits]\nmax_top_k = 0\n").unwrap_err();
+        assert!(err.to_string().contains("limits.max_top_k"));
+    }
+
+    #[test]
+    fn test_from_toml_admission_limits() {
+        let defaults = VelesConfig::default().limits;
+        assert_eq!(defaults.max_concurrent_searches, 0, "unlimited by default");
+        assert_eq!(defaults.max_concurrent_scans, 0, "unlimited by default");
+
+        let config = VelesConfig::from_toml(
+            "[limits]\nmax_concurrent_searches = 8\nmax_concurrent_scans = 2\n\
+             max_admission_queue = 16\nadmission_queue_timeout_ms = 250\n",
+        )
+        .expect("parse");
+        assert_eq!(config.limits.max_concurrent_searches, 8);
+        assert_eq!(config.limits.max_concurrent_scans, 2);
+        assert_eq!(config.limits.max_admission_queue, 16);
+        assert_eq!(config.limits.admission_queue_timeout_ms, 250);
+
+        let err = VelesConfig::from_toml("[limits]\nmax_concurrent_scans = 100000\n").unwrap_err();
+        assert!(err.to_string().contains("limits.max_concurrent_scans"));
+    }
+
     #[test]
     fn test_from_toml_default_config_validates_via_loader() {
         // Regression (#907): the DEFAULT config must still pass validation
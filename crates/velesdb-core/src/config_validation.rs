@@ -29,6 +29,16 @@ const MAX_COLLECTIONS_CAP: usize = 1_000_000;
 const MAX_PAYLOAD_SIZE_CAP: usize = 1_073_741_824;
 /// Hard ceiling for `limits.max_perfect_mode_vectors` (100 million).
 const MAX_PERFECT_MODE_VECTORS_CAP: usize = 100_000_000;
+/// Hard ceiling for `limits.max_query_limit` (100 million rows).
+const MAX_QUERY_LIMIT_CAP: usize = 100_000_000;
+/// Hard ceiling for `limits.max_points_per_upsert`, `max_top_k` and
+/// `max_batch_queries` (10 million).
+const MAX_REQUEST_ITEMS_CAP: usize = 10_000_000;
+/// Hard ceiling for `limits.max_concurrent_searches` / `max_concurrent_scans`.
+/// `0` means "unlimited".
+const MAX_CONCURRENT_QUERIES_CAP: usize = 65_536;
+/// Hard ceiling for `limits.max_admission_queue`.
+const MAX_ADMISSION_QUEUE_CAP: usize = 1_000_000;
 /// Hard ceiling for `search.query_timeout_ms` (24 hours). `0` means
 /// "disabled". The previous 1-hour cap rejected legitimate long batch
 /// timeouts; 24h is generous enough for any real query while still rejecting
@@ -39,6 +49,10 @@ const MAX_LAYERS_CAP: usize = 64;
 /// Hard ceiling for `storage.mmap_cache_mb` (1 TiB). `0` is rejected: a
 /// zero-byte mmap cache is never a meaningful configuration.
 const MMAP_CACHE_MB_CAP: usize = 1_048_576;
+/// Hard ceiling for the `storage.growth` sizes, in MiB (1 TiB).
+const GROWTH_MB_CAP: u64 = 1_048_576;
+/// Hard ceiling for `storage.growth.growth_factor`.
+const GROWTH_FACTOR_CAP: f64 = 16.0;
 /// Hard ceiling for `server.workers`. `0` means "auto" (derive from CPU
 /// count), so it is allowed; any positive value is capped to a sane ceiling.
 const WORKERS_CAP: usize = 4_096;
@@ -71,6 +85,36 @@ fn range_check_upper<T: PartialOrd + Copy + std::fmt::Display>(
     Ok(())
 }
 
+/// Range-checks one `[storage.growth]`-shaped section under `prefix`.
+fn validate_growth(prefix: &str, growth: &crate::config::MmapGrowth) -> Result<(), ConfigError> {
+    for (field, value) in [
+        ("preallocate_mb", growth.preallocate_mb),
+        ("min_growth_mb", growth.min_growth_mb),
+    ] {
+        if value == 0 || value > GROWTH_MB_CAP {
+            return Err(ConfigError::InvalidValue {
+                key: format!("{prefix}.{field}"),
+                message: format!("value {value} is out of range [1, {GROWTH_MB_CAP}]"),
+            });
+        }
+    }
+    range_check_upper(
+        &format!("{prefix}.max_growth_mb"),
+        growth.max_growth_mb,
+        GROWTH_MB_CAP,
+    )?;
+    if !(1.0..=GROWTH_FACTOR_CAP).contains(&growth.growth_factor) {
+        return Err(ConfigError::InvalidValue {
+            key: format!("{prefix}.growth_factor"),
+            message: format!(
+                "value {} is out of range [1, {GROWTH_FACTOR_CAP}]",
+                growth.growth_factor
+            ),
+        });
+    }
+    Ok(())
+}
+
 impl VelesConfig {
     /// Validates the configuration.
     ///
@@ -161,6 +205,42 @@ impl VelesConfig {
             "limits.max_perfect_mode_vectors",
             limits.max_perfect_mode_vectors,
             MAX_PERFECT_MODE_VECTORS_CAP,
+        )?;
+        range_check_capacity(
+            "limits.max_query_limit",
+            limits.max_query_limit,
+            MAX_QUERY_LIMIT_CAP,
+        )?;
+        range_check_capacity(
+            "limits.max_points_per_upsert",
+            limits.max_points_per_upsert,
+            MAX_REQUEST_ITEMS_CAP,
+        )?;
+        range_check_capacity("limits.max_top_k", limits.max_top_k, MAX_REQUEST_ITEMS_CAP)?;
+        range_check_capacity(
+            "limits.max_batch_queries",
+            limits.max_batch_queries,
+            MAX_REQUEST_ITEMS_CAP,
+        )?;
+        range_check_upper(
+            "limits.max_concurrent_searches",
+            limits.max_concurrent_searches,
+            MAX_CONCURRENT_QUERIES_CAP,
+        )?;
+        range_check_upper(
+            "limits.max_concurrent_scans",
+            limits.max_concurrent_scans,
+            MAX_CONCURRENT_QUERIES_CAP,
+        )?;
+        range_check_upper(
+            "limits.max_admission_queue",
+            limits.max_admission_queue,
+            MAX_ADMISSION_QUEUE_CAP,
+        )?;
+        range_check_upper(
+            "limits.admission_queue_timeout_ms",
+            limits.admission_queue_timeout_ms,
+            QUERY_TIMEOUT_MS_CAP,
         )
     }
 
@@ -196,6 +276,10 @@ impl VelesConfig {
             self.storage.mmap_cache_mb,
             MMAP_CACHE_MB_CAP,
         )?;
+        validate_growth("storage.growth", &self.storage.growth)?;
+        for (name, growth) in &self.storage.collection_growth {
+            validate_growth(&format!("storage.collection_growth.{name}"), growth)?;
+        }
         Ok(())
     }
 
@@ -22,7 +22,7 @@ impl Database {
     pub(super) fn ensure_collection_name_available(&self, name: &str) -> Result<()> {
         crate::validation::validate_collection_name(name)?;
 
-        if self.collection_exists_in_registry(name) {
+        if self.collection_exists_in_registry(name) || self.views.read().contains_key(name) {
             return Err(Error::CollectionExists(name.to_string()));
         }
 
@@ -66,8 +66,31 @@ impl Database {
         ));
     }
 
+    /// Applies the live `[storage]` residency for `name` (its
+    /// `collection_residency` override, else `residency`) to the vector
+    /// mapping of a vector or graph collection, its growth policy
+    /// (`collection_growth` override, else `growth`) to the data file, and
+    /// sizes its payload cache (`payload_cache_entries`).
+    ///
+    /// Like the runtime limits, the settings are not persisted and are
+    /// re-pushed on every open. Default residency and growth are skipped so
+    /// collections without such config never touch their mapping.
+    pub(super) fn push_storage_residency(&self, name: &str, coll: &crate::collection::Collection) {
+        let residency = self.config.storage.residency_for(name);
+        if !residency.is_default() {
+            coll.set_mmap_residency(residency);
+        }
+        let growth = self.config.storage.growth_for(name);
+        if !growth.is_default() {
+            if let Err(e) = coll.set_mmap_growth(growth) {
+                tracing::warn!(collection = name, "failed to apply storage.growth: {e}");
+            }
+        }
+        coll.set_payload_cache_capacity(self.config.storage.payload_cache_entries);
+    }
+
     /// Checks whether a collection name exists in any of the typed registries.
-    fn collection_exists_in_registry(&self, name: &str) -> bool {
+    pub(super) fn collection_exists_in_registry(&self, name: &str) -> bool {
         self.vector_colls.read().contains_key(name)
             || self.graph_colls.read().contains_key(name)
             || self.metadata_colls.read().contains_key(name)
@@ -78,7 +101,7 @@ impl Database {
     ///
     /// Complements [`crate::validation::validate_dimension`] (the static
     /// `65_536` hard ceiling): the config-driven limit is typically tighter
-    /// — 4096 by default — and is consulted here so the guard-rail can
+    /// — 8192 by default — and is consulted here so the guard-rail can
     /// be relaxed per tenant via [`Database::open_with_config`] without
     /// touching the static constant.
     ///
@@ -264,10 +287,15 @@ impl Database {
 
     /// Deletes a collection by name.
     ///
+    /// The collection directory is renamed into the data directory's
+    /// `.trash/` folder and removed by a background thread, so the call
+    /// returns without waiting for the files to be deleted and the name can
+    /// be reused immediately.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the name is invalid or the collection does not
-    /// exist in any registry.
+    /// Returns an error if the name is invalid, the collection does not
+    /// exist in any registry, or its directory cannot be moved to the trash.
     pub fn delete_collection(&self, name: &str) -> Result<()> {
         crate::validation::validate_collection_name(name)?;
 
@@ -277,10 +305,13 @@ impl Database {
 
         let collection_path = self.data_dir.join(name);
         if collection_path.exists() {
-            std::fs::remove_dir_all(&collection_path)?;
+            self.trash.discard(&collection_path, name)?;
         }
 
         self.remove_from_all_registries(name);
+        self.drop_views_of(name);
+        self.drop_row_security_of(name);
+        self.drop_latency_slo_of(name);
 
         if let Some(ref obs) = self.observer {
             obs.on_collection_deleted(name);
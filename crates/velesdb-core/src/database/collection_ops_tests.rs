@@ -190,7 +190,52 @@ fn test_delete_collection_removes_disk_directory() {
     db.delete_collection("cleanup").unwrap();
     assert!(
         !coll_path.exists(),
-        "collection dir should be removed after delete"
+        "collection dir should be moved away by delete"
+    );
+
+    db.trash.wait_idle();
+    let trash_dir = dir.path().join(super::trash::TRASH_DIR);
+    assert_eq!(
+        std::fs::read_dir(&trash_dir).unwrap().count(),
+        0,
+        "trashed directory should be deleted in the background"
+    );
+}
+
+#[test]
+fn test_delete_then_recreate_same_name_starts_empty() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+
+    db.create_collection("reused", 2, DistanceMetric::Cosine)
+        .unwrap();
+    let coll = db.get_vector_collection("reused").unwrap();
+    coll.upsert(vec![Point::new(1, vec![1.0, 0.0], None)])
+        .unwrap();
+    drop(coll);
+
+    db.delete_collection("reused").unwrap();
+    db.create_collection("reused", 2, DistanceMetric::Cosine)
+        .unwrap();
+    db.trash.wait_idle();
+
+    assert!(db.get_vector_collection("reused").unwrap().is_empty());
+    assert_eq!(db.list_collections(), vec!["reused"]);
+}
+
+#[test]
+fn test_open_purges_leftover_trash() {
+    let dir = tempdir().unwrap();
+    let leftover = dir.path().join(super::trash::TRASH_DIR).join("old-1-0");
+    std::fs::create_dir_all(&leftover).unwrap();
+    std::fs::write(leftover.join("config.json"), b"{}").unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    assert!(db.list_collections().is_empty());
+    db.trash.wait_idle();
+    assert!(
+        !leftover.exists(),
+        "leftover trash should be purged on open"
     );
 }
 
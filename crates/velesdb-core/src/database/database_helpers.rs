@@ -1,5 +1,7 @@
 #[cfg(feature = "persistence")]
 use super::{ColumnStore, Database, Error, Result};
+#[cfg(feature = "persistence")]
+use crate::column_store::timestamp::parse_rfc3339_millis;
 
 impl Database {
     pub(super) fn resolve_dml_value(
@@ -129,7 +131,7 @@ impl Database {
 
         let combined = Self::combine_filter_conditions(filters);
         let stripped = Self::strip_table_prefix_from_condition(combined);
-        let filter = crate::Filter::new(crate::Condition::from(stripped));
+        let filter = collection.where_filter(stripped);
 
         let ids = Self::join_candidate_ids(collection, &filter);
         let points: Vec<_> = collection.get(&ids).into_iter().flatten().collect();
@@ -152,14 +154,48 @@ impl Database {
     /// fallback) for any predicate it cannot resolve completely: `!=` / `NOT IN`
     /// (whose matches include field-absent points the index does not store) and
     /// any field carrying an ID above `u32::MAX` (unrepresentable in the bitmap).
-    /// Falls back to `all_ids()` whenever no index can resolve the predicate.
+    ///
+    /// Without a usable index, the payload mirror's columnar translation is
+    /// tried next (string LIKE / range predicates included). It is skipped
+    /// when the filter tests `id`: that field is injected by
+    /// `point_matches_filter` and does not exist in the mirrored payloads.
+    /// Falls back to `all_ids()` when neither can resolve the predicate.
     fn join_candidate_ids(
         collection: &crate::collection::Collection,
         filter: &crate::Filter,
     ) -> Vec<u64> {
-        match collection.build_prefilter_bitmap(filter) {
-            Some(bitmap) => bitmap.iter().map(u64::from).collect(),
-            None => collection.all_ids(),
+        if let Some(bitmap) = collection.build_prefilter_bitmap(filter) {
+            return bitmap.iter().map(u64::from).collect();
+        }
+        if !Self::condition_tests_field(&filter.condition, "id") {
+            if let Some(ids) = collection.mirror_candidate_ids(filter) {
+                return ids;
+            }
+        }
+        collection.all_ids()
+    }
+
+    /// Whether any mirror-translatable leaf of `condition` tests `field`.
+    ///
+    /// Leaves the mirror never translates are ignored: they cannot turn into
+    /// a wrong bitmap, only into a fallback.
+    pub(super) fn condition_tests_field(condition: &crate::filter::Condition, field: &str) -> bool {
+        use crate::filter::Condition;
+        match condition {
+            Condition::And { conditions } | Condition::Or { conditions } => conditions
+                .iter()
+                .any(|c| Self::condition_tests_field(c, field)),
+            Condition::Not { condition } => Self::condition_tests_field(condition, field),
+            Condition::Eq { field: f, .. }
+            | Condition::Neq { field: f, .. }
+            | Condition::Gt { field: f, .. }
+            | Condition::Gte { field: f, .. }
+            | Condition::Lt { field: f, .. }
+            | Condition::Lte { field: f, .. }
+            | Condition::In { field: f, .. }
+            | Condition::Like { field: f, .. }
+            | Condition::ILike { field: f, .. } => f == field,
+            _ => false,
         }
     }
 
@@ -274,7 +310,7 @@ impl Database {
     }
 
     /// Builds a `ColumnStore` from a slice of point references.
-    fn build_column_store_from_points(points: &[&crate::Point]) -> Result<ColumnStore> {
+    pub(super) fn build_column_store_from_points(points: &[&crate::Point]) -> Result<ColumnStore> {
         let owned: Vec<crate::Point> = points.iter().copied().cloned().collect();
         let schema = Self::infer_column_schema(&owned);
         let schema_refs: Vec<(&str, crate::column_store::ColumnType)> = schema
@@ -316,7 +352,9 @@ impl Database {
 
     /// Infers a consistent column schema from point payloads.
     ///
-    /// Removes columns whose types are inconsistent across points.
+    /// Columns whose types are inconsistent across points are widened when
+    /// one type subsumes the other (timestamps are strings, float vectors
+    /// are JSON) and removed otherwise.
     fn infer_column_schema(
         points: &[crate::Point],
     ) -> Vec<(String, crate::column_store::ColumnType)> {
@@ -339,7 +377,14 @@ impl Database {
                 };
                 if let Some(existing) = inferred.get(key) {
                     if *existing != col_type {
-                        inferred.remove(key);
+                        match Self::widen_column_type(existing, &col_type) {
+                            Some(widened) => {
+                                inferred.insert(key.clone(), widened);
+                            }
+                            None => {
+                                inferred.remove(key);
+                            }
+                        }
                     }
                 } else {
                     inferred.insert(key.clone(), col_type);
@@ -371,10 +416,13 @@ impl Database {
             .and_then(serde_json::Value::as_object)
         {
             for (key, value) in obj {
-                if key == "id" || !schema_refs.iter().any(|(name, _)| *name == key.as_str()) {
+                if key == "id" {
                     continue;
                 }
-                if let Some(column_value) = Self::json_to_column_value(value, store) {
+                let Some((_, col_type)) = schema_refs.iter().find(|(name, _)| *name == key) else {
+                    continue;
+                };
+                if let Some(column_value) = Self::json_to_column_value(value, col_type, store) {
                     values.push((key.clone(), column_value));
                 }
             }
@@ -390,36 +438,76 @@ impl Database {
         Ok(())
     }
 
+    /// Column type a JSON payload value maps to.
+    ///
+    /// RFC 3339 date-time strings become `Timestamp`, non-empty all-number
+    /// arrays `Float32List`, and any other array or object `Json`.
     fn json_to_column_type(value: &serde_json::Value) -> Option<crate::column_store::ColumnType> {
         use crate::column_store::ColumnType;
         match value {
             serde_json::Value::Number(n) if n.is_i64() => Some(ColumnType::Int),
             serde_json::Value::Number(_) => Some(ColumnType::Float),
+            serde_json::Value::String(s) if parse_rfc3339_millis(s).is_some() => {
+                Some(ColumnType::Timestamp)
+            }
             serde_json::Value::String(_) => Some(ColumnType::String),
             serde_json::Value::Bool(_) => Some(ColumnType::Bool),
+            serde_json::Value::Array(items)
+                if !items.is_empty() && items.iter().all(serde_json::Value::is_number) =>
+            {
+                Some(ColumnType::Float32List)
+            }
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => Some(ColumnType::Json),
+            serde_json::Value::Null => None,
+        }
+    }
+
+    /// Common type for a column seen with both `a` and `b`, if any.
+    fn widen_column_type(
+        a: &crate::column_store::ColumnType,
+        b: &crate::column_store::ColumnType,
+    ) -> Option<crate::column_store::ColumnType> {
+        use crate::column_store::ColumnType;
+        match (a, b) {
+            (ColumnType::Timestamp, ColumnType::String)
+            | (ColumnType::String, ColumnType::Timestamp) => Some(ColumnType::String),
+            (ColumnType::Float32List, ColumnType::Json)
+            | (ColumnType::Json, ColumnType::Float32List) => Some(ColumnType::Json),
             _ => None,
         }
     }
 
+    /// Converts a payload value into a cell of the column's type.
+    ///
+    /// Returns `None` when the value does not fit the column.
     fn json_to_column_value(
         value: &serde_json::Value,
+        col_type: &crate::column_store::ColumnType,
         store: &mut ColumnStore,
     ) -> Option<crate::column_store::ColumnValue> {
-        use crate::column_store::ColumnValue;
-        match value {
-            serde_json::Value::Number(n) => {
-                if let Some(v) = n.as_i64() {
-                    Some(ColumnValue::Int(v))
-                } else {
-                    n.as_f64().map(ColumnValue::Float)
-                }
-            }
-            serde_json::Value::String(s) => {
+        use crate::column_store::{ColumnType, ColumnValue};
+        match (col_type, value) {
+            (_, serde_json::Value::Null) => Some(ColumnValue::Null),
+            (ColumnType::Int, serde_json::Value::Number(n)) => n.as_i64().map(ColumnValue::Int),
+            (ColumnType::Float, serde_json::Value::Number(n)) => n.as_f64().map(ColumnValue::Float),
+            (ColumnType::String, serde_json::Value::String(s)) => {
                 let sid = store.string_table_mut().intern(s);
                 Some(ColumnValue::String(sid))
             }
-            serde_json::Value::Bool(b) => Some(ColumnValue::Bool(*b)),
-            serde_json::Value::Null => Some(ColumnValue::Null),
+            (ColumnType::Timestamp, serde_json::Value::String(s)) => {
+                parse_rfc3339_millis(s).map(ColumnValue::Timestamp)
+            }
+            (ColumnType::Bool, serde_json::Value::Bool(b)) => Some(ColumnValue::Bool(*b)),
+            (ColumnType::Float32List, serde_json::Value::Array(items)) => items
+                .iter()
+                .map(|v| {
+                    #[allow(clippy::cast_possible_truncation)]
+                    // Reason: Float32List stores f32 by definition.
+                    v.as_f64().map(|f| f as f32)
+                })
+                .collect::<Option<Vec<f32>>>()
+                .map(ColumnValue::Float32List),
+            (ColumnType::Json, v) => Some(ColumnValue::Json(v.clone())),
             _ => None,
         }
     }
@@ -110,3 +110,97 @@ fn test_strip_prefix_on_match() {
         other => panic!("expected Match, got {other:?}"),
     }
 }
+
+/// The JOIN mirror shortcut must be skipped for filters on the injected
+/// `id` field, wherever it sits in the tree.
+#[test]
+fn test_condition_tests_field_finds_nested_id() {
+    use crate::filter::Condition as FilterCondition;
+
+    let on_id = FilterCondition::Not {
+        condition: Box::new(FilterCondition::Or {
+            conditions: vec![
+                FilterCondition::Like {
+                    field: "name".into(),
+                    pattern: "a%".into(),
+                },
+                FilterCondition::Gt {
+                    field: "id".into(),
+                    value: serde_json::json!(3),
+                },
+            ],
+        }),
+    };
+    assert!(Database::condition_tests_field(&on_id, "id"));
+
+    let on_name = FilterCondition::Like {
+        field: "name".into(),
+        pattern: "a%".into(),
+    };
+    assert!(!Database::condition_tests_field(&on_name, "id"));
+}
+
+/// JOIN column stores keep timestamps, float vectors and JSON documents
+/// typed instead of dropping them.
+#[test]
+fn test_join_column_store_ingests_extended_types() {
+    use crate::column_store::TypedColumn;
+    use serde_json::json;
+
+    let points = [
+        crate::Point::new(
+            1,
+            vec![0.0; 2],
+            Some(json!({
+                "created_at": "2024-02-29T14:30:15+02:00",
+                "embedding": [0.5, 1],
+                "meta": {"k": "v"},
+                "when": "2024-01-01T00:00:00Z",
+            })),
+        ),
+        crate::Point::new(
+            2,
+            vec![0.0; 2],
+            Some(json!({
+                "created_at": "2024-03-01T00:00:00Z",
+                "embedding": [0.25, 2],
+                "meta": ["x"],
+                "when": "later",
+            })),
+        ),
+    ];
+    let refs: Vec<&crate::Point> = points.iter().collect();
+    let store = Database::build_column_store_from_points(&refs).expect("build");
+
+    assert!(matches!(
+        store.get_column("created_at"),
+        Some(TypedColumn::Timestamp(_))
+    ));
+    assert!(matches!(
+        store.get_column("embedding"),
+        Some(TypedColumn::Float32List(_))
+    ));
+    assert!(matches!(
+        store.get_column("meta"),
+        Some(TypedColumn::Json(_))
+    ));
+    // A mix of timestamps and plain strings widens to a string column.
+    assert!(matches!(
+        store.get_column("when"),
+        Some(TypedColumn::String(_))
+    ));
+
+    let row = store.get_row_idx_by_pk(1).expect("row");
+    assert_eq!(
+        store.get_value_as_json("created_at", row),
+        Some(json!("2024-02-29T12:30:15Z"))
+    );
+    assert_eq!(
+        store.get_value_as_json("embedding", row),
+        Some(json!([0.5, 1.0]))
+    );
+    assert_eq!(
+        store.get_value_as_json("when", row),
+        Some(json!("2024-01-01T00:00:00Z"))
+    );
+}
@@ -385,6 +385,67 @@ fn test_database_execute_query_update_metadata_only_where_id() {
     assert_eq!(payload["price"], serde_json::json!(19.99));
 }
 
+#[test]
+fn test_database_execute_query_update_set_expression_where() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    db.create_collection_typed("products", &CollectionType::MetadataOnly)
+        .unwrap();
+    let products = db.get_metadata_collection("products").unwrap();
+    products
+        .upsert_metadata(vec![
+            Point::metadata_only(1, serde_json::json!({"stock": 3, "price": 10.0})),
+            Point::metadata_only(2, serde_json::json!({"stock": 0, "price": 4.0})),
+        ])
+        .unwrap();
+
+    let query =
+        Parser::parse("UPDATE products SET stock = stock - 1, price = price * 2 WHERE stock > 0")
+            .unwrap();
+    let results = db
+        .execute_query(&query, &std::collections::HashMap::new())
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let payload = |id| {
+        products
+            .get(&[id])
+            .into_iter()
+            .flatten()
+            .next()
+            .unwrap()
+            .payload
+            .unwrap()
+    };
+    assert_eq!(payload(1)["stock"], serde_json::json!(2));
+    assert_eq!(payload(1)["price"], serde_json::json!(20.0));
+    assert_eq!(payload(2)["stock"], serde_json::json!(0));
+}
+
+#[test]
+fn test_database_execute_query_update_set_expression_failure_changes_nothing() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    db.create_collection_typed("products", &CollectionType::MetadataOnly)
+        .unwrap();
+    let products = db.get_metadata_collection("products").unwrap();
+    products
+        .upsert_metadata(vec![
+            Point::metadata_only(1, serde_json::json!({"stock": 3})),
+            Point::metadata_only(2, serde_json::json!({"stock": "many"})),
+        ])
+        .unwrap();
+
+    let query = Parser::parse("UPDATE products SET stock = stock + 1").unwrap();
+    let err = db
+        .execute_query(&query, &std::collections::HashMap::new())
+        .unwrap_err();
+    assert!(err.to_string().contains("not numeric"), "{err}");
+
+    let point = products.get(&[1]).into_iter().flatten().next().unwrap();
+    assert_eq!(point.payload.unwrap()["stock"], serde_json::json!(3));
+}
+
 #[test]
 fn test_database_execute_query_insert_with_params() {
     let dir = tempdir().unwrap();
@@ -1221,6 +1282,8 @@ fn test_database_open_with_config_preserves_custom_fields() {
             max_collections: 500,
             max_payload_size: 524_288,
             max_perfect_mode_vectors: 250_000,
+            max_query_limit: 50_000,
+            ..LimitsConfig::default()
         },
         wal_batch: WalBatchConfig {
             enabled: true,
@@ -1241,6 +1304,44 @@ fn test_database_open_with_config_preserves_custom_fields() {
     assert_eq!(stored.wal_batch.max_batch_size, 256);
 }
 
+#[test]
+fn test_database_pushes_storage_residency_on_create_and_reopen() {
+    use crate::config::{MmapAdvice, MmapResidency, VelesConfig};
+
+    let dir = tempdir().unwrap();
+    let pinned = MmapResidency {
+        advice: MmapAdvice::WillNeed,
+        lock: false,
+    };
+    let mut config = VelesConfig::default();
+    config.storage.residency.advice = MmapAdvice::Random;
+    config
+        .storage
+        .collection_residency
+        .insert("hot".to_string(), pinned);
+
+    {
+        let db = Database::open_with_config(dir.path(), config.clone()).unwrap();
+        db.create_vector_collection("hot", 4, DistanceMetric::Cosine)
+            .unwrap();
+        db.create_vector_collection("cold", 4, DistanceMetric::Cosine)
+            .unwrap();
+        let hot = db.get_vector_collection("hot").unwrap();
+        assert_eq!(hot.mmap_residency(), pinned);
+        let cold = db.get_vector_collection("cold").unwrap();
+        assert_eq!(cold.mmap_residency().advice, MmapAdvice::Random);
+    }
+
+    {
+        let db = Database::open_with_config(dir.path(), config).unwrap();
+        let hot = db.get_vector_collection("hot").unwrap();
+        assert_eq!(hot.mmap_residency(), pinned);
+    }
+    let db = Database::open(dir.path()).unwrap();
+    let hot = db.get_vector_collection("hot").unwrap();
+    assert!(hot.mmap_residency().is_default());
+}
+
 #[test]
 fn test_database_config_arc_shares_same_instance() {
     let dir = tempdir().unwrap();
@@ -1368,6 +1469,42 @@ fn test_max_dimensions_limit_refuses_oversize_vector() {
     }
 }
 
+#[test]
+fn test_max_query_limit_reaches_collections_and_is_overridable() {
+    use crate::config::{LimitsConfig, VelesConfig};
+
+    let dir = tempdir().unwrap();
+    let config = VelesConfig {
+        limits: LimitsConfig {
+            max_query_limit: 50,
+            ..LimitsConfig::default()
+        },
+        ..VelesConfig::default()
+    };
+    let db = Database::open_with_config(dir.path(), config).unwrap();
+    db.create_collection("docs", 4, DistanceMetric::Cosine)
+        .unwrap();
+    let params = std::collections::HashMap::new();
+
+    let query = Parser::parse("SELECT * FROM docs LIMIT 51").unwrap();
+    let err = db.execute_query(&query, &params).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::QueryLimitExceeded {
+                requested: 51,
+                max: 50
+            }
+        ),
+        "got {err:?}"
+    );
+
+    let coll = db.get_any_collection("docs").unwrap();
+    coll.set_max_query_limit(Some(500)).unwrap();
+    assert_eq!(coll.max_query_limit(), 500);
+    db.execute_query(&query, &params).unwrap();
+}
+
 #[test]
 fn test_max_dimensions_limit_applies_to_graph_with_embeddings() {
     use crate::config::{LimitsConfig, VelesConfig};
@@ -1512,3 +1649,49 @@ fn test_graph_node_payload_enforces_payload_size_cap() {
     let small = serde_json::json!({ "k": 1 });
     graph.upsert_node_payload(2, &small).unwrap();
 }
+
+#[test]
+fn test_corrupt_collection_is_reported_as_load_failure() {
+    let dir = tempdir().unwrap();
+    {
+        let db = Database::open(dir.path()).unwrap();
+        db.create_collection("good", 4, DistanceMetric::Cosine)
+            .unwrap();
+        db.flush_all();
+    }
+    let broken = dir.path().join("broken");
+    std::fs::create_dir_all(&broken).unwrap();
+    std::fs::write(broken.join("config.json"), b"{ not json").unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    assert_eq!(db.list_collections(), vec!["good".to_string()]);
+
+    let failures = db.collection_load_failures();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].name, "broken");
+    assert!(
+        failures[0].error.contains("config.json"),
+        "unexpected error: {}",
+        failures[0].error
+    );
+}
+
+#[test]
+fn test_disk_space_and_diagnostics_backlog() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+
+    let space = db.disk_space().unwrap();
+    assert!(space.total_bytes > 0);
+    assert!(space.available_bytes <= space.total_bytes);
+
+    db.create_collection("docs", 4, DistanceMetric::Cosine)
+        .unwrap();
+    let coll = db.get_vector_collection("docs").unwrap();
+    coll.upsert(vec![Point::without_payload(1, vec![1.0, 0.0, 0.0, 0.0])])
+        .unwrap();
+
+    let diag = db.collection_diagnostics("docs").unwrap();
+    assert_eq!(diag.pending_index_ops, 0);
+    assert!(diag.wal_backlog_bytes > 0, "upsert should land in the WAL");
+}
@@ -8,10 +8,12 @@
 //! module.
 
 use crate::collection::graph::{EdgeType, GraphSchema, NodeType, ValueType};
-use crate::collection::Collection;
+use crate::collection::{Collection, QueryDefaults};
+use crate::filter::Collation;
 use crate::velesql::{
     AlterCollectionStatement, AnalyzeStatement, CreateCollectionKind, CreateIndexStatement,
-    DdlStatement, DropIndexStatement, GraphSchemaMode, SchemaDefinition, TruncateStatement,
+    DdlStatement, DropIndexStatement, FusionStrategyType, GraphSchemaMode, SchemaDefinition,
+    TruncateStatement,
 };
 use crate::{Error, Result, SearchResult};
 
@@ -227,12 +229,16 @@ impl Database {
 
     /// Executes an `ALTER COLLECTION <name> SET (<key> = <value>, ...)` statement.
     ///
-    /// Currently supports the `auto_reindex` (boolean) option: it attaches or
+    /// Supports the `auto_reindex` (boolean) option: it attaches or
     /// re-configures an
     /// [`AutoReindexManager`](crate::collection::auto_reindex::AutoReindexManager)
     /// on the collection and persists the policy via `flush()`, so the setting
     /// survives a restart (restored automatically on the next `Collection::open`).
     ///
+    /// `ef_search`, `rerank`, `fusion` and `collation` set the collection's
+    /// [`QueryDefaults`](crate::collection::QueryDefaults); the value
+    /// `default` clears one.
+    ///
     /// Error/apply order: the collection existence check runs first, then EVERY
     /// option is parsed and validated ([`parse_alter_option`]) before any is
     /// applied — so a malformed later option leaves the collection untouched
@@ -260,8 +266,13 @@ impl Database {
 
         // Step 3: apply the validated options to the live collection, then
         // persist so the change survives a restart.
+        let current_defaults = collection.query_defaults();
+        let mut defaults = current_defaults;
         for option in options {
-            option.apply(&collection);
+            option.apply(&collection, &mut defaults);
+        }
+        if defaults != current_defaults {
+            collection.set_query_defaults(defaults)?;
         }
         collection.flush()?;
         Ok(Vec::new())
@@ -272,13 +283,26 @@ impl Database {
 enum AlterOption {
     /// `auto_reindex = true|false`.
     AutoReindex(bool),
+    /// `ef_search = N|default`.
+    EfSearch(Option<usize>),
+    /// `rerank = true|false|default`.
+    Rerank(Option<bool>),
+    /// `fusion = '<strategy>'|default`.
+    Fusion(Option<FusionStrategyType>),
+    /// `collation = '<collation>'|default`.
+    Collation(Option<Collation>),
 }
 
 impl AlterOption {
-    /// Applies the option's side effect to the live collection.
-    fn apply(self, collection: &Collection) {
+    /// Applies the option's side effect to the live collection, or records a
+    /// query default in `defaults` for the caller to persist once.
+    fn apply(self, collection: &Collection, defaults: &mut QueryDefaults) {
         match self {
             Self::AutoReindex(enabled) => apply_auto_reindex(collection, enabled),
+            Self::EfSearch(ef_search) => defaults.ef_search = ef_search,
+            Self::Rerank(rerank) => defaults.rerank = rerank,
+            Self::Fusion(fusion) => defaults.fusion = fusion,
+            Self::Collation(collation) => defaults.collation = collation,
         }
     }
 }
@@ -383,7 +407,10 @@ fn build_typed_schema(definitions: &[SchemaDefinition]) -> GraphSchema {
 /// [`AlterOption`] WITHOUT applying any side effect (so the caller can validate
 /// every option before mutating the collection).
 ///
-/// Supported options: `auto_reindex` (boolean).
+/// Supported options: `auto_reindex` (boolean) and the query defaults
+/// `ef_search` (positive integer), `rerank` (boolean), `fusion` (strategy
+/// name) and `collation` (collation name). A query default set to `default`
+/// is cleared.
 ///
 /// # Errors
 ///
@@ -398,12 +425,56 @@ fn parse_alter_option(key: &str, value: &str) -> Result<AlterOption> {
             })?;
             Ok(AlterOption::AutoReindex(enabled))
         }
+        "ef_search" => parse_query_default(
+            value,
+            |v| v.parse::<usize>().ok().filter(|&ef| ef > 0),
+            "ef_search must be a positive integer or 'default'",
+        )
+        .map(AlterOption::EfSearch),
+        "rerank" => parse_query_default(
+            value,
+            |v| v.parse::<bool>().ok(),
+            "rerank must be 'true', 'false' or 'default'",
+        )
+        .map(AlterOption::Rerank),
+        "fusion" => parse_query_default(
+            value,
+            // field_boost needs a per-query field, so it can't be a default.
+            |v| FusionStrategyType::parse(v).filter(|s| *s != FusionStrategyType::FieldBoost),
+            "fusion must be one of rrf, weighted, maximum, rsf, average or 'default'",
+        )
+        .map(AlterOption::Fusion),
+        "collation" => parse_query_default(
+            value,
+            Collation::parse,
+            "collation must be one of binary, case_insensitive, nfc, nfc_case_insensitive or 'default'",
+        )
+        .map(AlterOption::Collation),
         _ => Err(Error::Query(format!(
-            "Unsupported ALTER option: '{key}'. Supported: auto_reindex"
+            "Unsupported ALTER option: '{key}'. Supported: auto_reindex, ef_search, rerank, fusion, collation"
         ))),
     }
 }
 
+/// Parses a query-default value: `None` for `default` (clears the default),
+/// otherwise the value `parse` accepts.
+///
+/// # Errors
+///
+/// Returns `Error::Query` carrying `expected` when `parse` rejects the value.
+fn parse_query_default<T>(
+    value: &str,
+    parse: impl FnOnce(&str) -> Option<T>,
+    expected: &str,
+) -> Result<Option<T>> {
+    if value.eq_ignore_ascii_case("default") {
+        return Ok(None);
+    }
+    parse(value)
+        .map(Some)
+        .ok_or_else(|| Error::Query(format!("{expected}, got '{value}'")))
+}
+
 /// Attaches an `AutoReindexManager` reflecting the requested enabled flag,
 /// preserving any thresholds already configured on the collection.
 ///
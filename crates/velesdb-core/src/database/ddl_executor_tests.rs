@@ -1005,6 +1005,139 @@ fn test_alter_collection_multi_option_is_atomic_on_error() {
     );
 }
 
+#[test]
+fn test_alter_collection_sets_and_clears_query_defaults() {
+    let dir = tempdir().expect("tempdir");
+    let db = Database::open(dir.path()).expect("open");
+    create_alter_collection(&db, "alter_defaults");
+    let params = std::collections::HashMap::new();
+
+    let set = crate::velesql::Parser::parse(
+        "ALTER COLLECTION alter_defaults SET (ef_search = 200, rerank = true, fusion = 'rsf')",
+    )
+    .expect("parse");
+    db.execute_query(&set, &params).expect("ALTER must succeed");
+    let vc = db.get_vector_collection("alter_defaults").expect("get");
+    assert_eq!(
+        vc.config().query_defaults,
+        crate::collection::QueryDefaults {
+            ef_search: Some(200),
+            rerank: Some(true),
+            fusion: Some(crate::velesql::FusionStrategyType::Rsf),
+            collation: None,
+        }
+    );
+
+    let clear =
+        crate::velesql::Parser::parse("ALTER COLLECTION alter_defaults SET (ef_search = default)")
+            .expect("parse");
+    db.execute_query(&clear, &params).expect("clear ef_search");
+    let defaults = vc.config().query_defaults;
+    assert_eq!(defaults.ef_search, None, "ef_search cleared");
+    assert_eq!(defaults.rerank, Some(true), "other defaults kept");
+}
+
+#[test]
+fn test_alter_collection_rejects_invalid_query_defaults() {
+    let dir = tempdir().expect("tempdir");
+    let db = Database::open(dir.path()).expect("open");
+    create_alter_collection(&db, "alter_bad_defaults");
+
+    for (key, value, expected) in [
+        ("ef_search", "0", "ef_search must be a positive integer"),
+        (
+            "rerank",
+            "maybe",
+            "rerank must be 'true', 'false' or 'default'",
+        ),
+        ("fusion", "median", "fusion must be one of"),
+        ("collation", "latin1", "collation must be one of"),
+    ] {
+        let alter = DdlStatement::AlterCollection(AlterCollectionStatement {
+            collection: "alter_bad_defaults".to_string(),
+            options: vec![
+                ("rerank".to_string(), "true".to_string()),
+                (key.to_string(), value.to_string()),
+            ],
+        });
+        let err = execute_ddl(&db, alter).expect_err("invalid default must error");
+        assert!(err.to_string().contains(expected), "{key}: {err}");
+    }
+    let vc = db.get_vector_collection("alter_bad_defaults").expect("get");
+    assert!(
+        vc.config().query_defaults.is_empty(),
+        "nothing applied when any option is invalid"
+    );
+}
+
+#[test]
+fn test_alter_collection_collation_applies_to_where_filters() {
+    let dir = tempdir().expect("tempdir");
+    let db = Database::open(dir.path()).expect("open");
+    create_alter_collection(&db, "alter_collation");
+    let vc = db.get_vector_collection("alter_collation").expect("get");
+    vc.upsert(vec![
+        crate::Point::new(
+            1,
+            vec![0.1; 32],
+            Some(serde_json::json!({"category": "Electronics"})),
+        ),
+        crate::Point::new(
+            2,
+            vec![0.2; 32],
+            Some(serde_json::json!({"category": "books"})),
+        ),
+        crate::Point::new(
+            3,
+            vec![0.3; 32],
+            Some(serde_json::json!({"category": "electronics"})),
+        ),
+    ])
+    .expect("upsert");
+    vc.create_index("category").expect("index");
+    let params = std::collections::HashMap::new();
+    let select = crate::velesql::Parser::parse(
+        "SELECT * FROM alter_collation WHERE category = 'ELECTRONICS' LIMIT 10",
+    )
+    .expect("parse");
+    let ids = |db: &Database| {
+        let mut ids: Vec<u64> = db
+            .execute_query(&select, &params)
+            .expect("select")
+            .iter()
+            .map(|r| r.point.id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    };
+    assert!(ids(&db).is_empty(), "binary collation by default");
+
+    let alter = crate::velesql::Parser::parse(
+        "ALTER COLLECTION alter_collation SET (collation = 'case_insensitive')",
+    )
+    .expect("parse");
+    db.execute_query(&alter, &params)
+        .expect("ALTER must succeed");
+    assert_eq!(
+        vc.config().query_defaults.collation,
+        Some(crate::filter::Collation::CaseInsensitive)
+    );
+    assert_eq!(
+        ids(&db),
+        vec![1, 3],
+        "secondary index bypassed under collation"
+    );
+
+    let filter = crate::Filter::new(crate::Condition::eq("category", "ELECTRONICS"));
+    assert_eq!(
+        vc.count(Some(&filter)),
+        2,
+        "API filters inherit the default"
+    );
+    let binary = filter.with_collation(crate::filter::Collation::Binary);
+    assert_eq!(vc.count(Some(&binary)), 0, "per-query collation wins");
+}
+
 // =========================================================================
 // SELECT EDGES AND — condition ordering optimization (Finding 3)
 // =========================================================================
@@ -33,7 +33,10 @@ impl Database {
     /// Executes a DELETE FROM statement.
     ///
     /// Extracts point IDs from the WHERE clause and deletes them
-    /// from the resolved collection.
+    /// from the resolved collection. An id predicate AND-ed with payload
+    /// predicates (`id IN (...) AND status = 'x'`, or a row-security policy
+    /// AND-ed in by [`apply_row_security`](Self::apply_row_security)) only
+    /// deletes the listed points whose payload matches.
     ///
     /// # Errors
     ///
@@ -42,14 +45,48 @@ impl Database {
     pub(super) fn execute_delete(
         &self,
         stmt: &crate::velesql::DeleteStatement,
+        params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<Vec<SearchResult>> {
-        self.check_dml_mutation("DELETE", &stmt.table)?;
-        let ids = extract_delete_ids(&stmt.where_clause)?;
-        let collection = self.resolve_writable_collection(&stmt.table)?;
+        let (collection, ids) = self.prepare_delete(stmt, params)?;
         collection.delete(&ids)?;
         Ok(Vec::new())
     }
 
+    /// Checks permission and resolves the collection and ids a DELETE
+    /// targets, without deleting anything.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`execute_delete`](Self::execute_delete).
+    pub(super) fn prepare_delete(
+        &self,
+        stmt: &crate::velesql::DeleteStatement,
+        params: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<(crate::collection::Collection, Vec<u64>)> {
+        self.check_dml_mutation("DELETE", &stmt.table)?;
+        let resolved =
+            crate::collection::Collection::resolve_condition_params(&stmt.where_clause, params)?;
+        let (id_condition, payload_conditions) = split_delete_condition(&resolved);
+        let mut ids = extract_delete_ids(id_condition)?;
+        let collection = self.resolve_writable_collection(&stmt.table)?;
+        if let Some(condition) = payload_conditions {
+            let filter = crate::Filter::new(crate::Condition::from(condition))
+                .with_collation(collection.default_collation());
+            let points = collection.get(&ids);
+            ids = ids
+                .into_iter()
+                .zip(points)
+                .filter(|(_, point)| {
+                    point.as_ref().is_none_or(|p| {
+                        filter.matches(p.payload.as_ref().unwrap_or(&serde_json::Value::Null))
+                    })
+                })
+                .map(|(id, _)| id)
+                .collect();
+        }
+        Ok((collection, ids))
+    }
+
     /// Executes a DELETE EDGE statement.
     ///
     /// Resolves the target graph collection and removes the edge by ID.
@@ -115,7 +152,7 @@ impl Database {
     ///
     /// Returns `Ok(())` if no observer is configured or if the observer
     /// allows the operation. Propagates the observer's error otherwise.
-    fn check_dml_mutation(&self, operation: &str, collection: &str) -> Result<()> {
+    pub(super) fn check_dml_mutation(&self, operation: &str, collection: &str) -> Result<()> {
         if let Some(ref observer) = self.observer {
             observer.on_dml_mutation_request(operation, collection)?;
         }
@@ -125,7 +162,10 @@ impl Database {
     /// Resolves a graph collection by name.
     ///
     /// Returns `CollectionNotFound` if no graph collection exists with the given name.
-    fn resolve_graph_collection(&self, name: &str) -> Result<crate::collection::GraphCollection> {
+    pub(super) fn resolve_graph_collection(
+        &self,
+        name: &str,
+    ) -> Result<crate::collection::GraphCollection> {
         self.get_graph_collection(name)
             .ok_or_else(|| Error::CollectionNotFound(name.to_string()))
     }
@@ -180,6 +220,27 @@ fn resolve_edge_properties(
         .collect()
 }
 
+/// Splits a DELETE WHERE clause into its leading id predicate and the
+/// payload predicates AND-ed after it, if any.
+fn split_delete_condition(
+    condition: &crate::velesql::Condition,
+) -> (
+    &crate::velesql::Condition,
+    Option<crate::velesql::Condition>,
+) {
+    match condition {
+        crate::velesql::Condition::And(left, right) => {
+            let (ids, rest) = split_delete_condition(left);
+            let rest = match rest {
+                Some(rest) => crate::velesql::Condition::And(Box::new(rest), right.clone()),
+                None => (**right).clone(),
+            };
+            (ids, Some(rest))
+        }
+        other => (other, None),
+    }
+}
+
 /// Extracts point IDs from a DELETE WHERE clause.
 ///
 /// Supports two patterns:
@@ -196,7 +257,7 @@ fn extract_delete_ids(condition: &crate::velesql::Condition) -> Result<Vec<u64>>
             extract_in_ids(in_cond)
         }
         _ => Err(Error::Query(
-            "DELETE WHERE must use 'id = N' or 'id IN (N1, N2, ...)'".to_string(),
+            "DELETE WHERE must use 'id = N', 'id IN (N1, N2, ...)' or 'id IN $ids'".to_string(),
         )),
     }
 }
@@ -0,0 +1,155 @@
+//! Dry-run execution of point DML (INSERT, UPSERT, UPDATE, DELETE).
+//!
+//! A dry run goes through the same validation, parameter binding and row
+//! resolution as the real statement, then stops before the write and reports
+//! which points would change.
+
+use std::collections::HashMap;
+
+use crate::collection::Collection;
+use crate::velesql::{DmlStatement, Query};
+use crate::{Error, Result};
+
+use super::Database;
+
+/// Maximum number of ids listed in [`DryRunReport::sample_ids`].
+pub const DRY_RUN_SAMPLE_IDS: usize = 10;
+
+/// What a DML statement would change, as computed by
+/// [`Database::execute_query_dry_run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DryRunReport {
+    /// Statement kind: `INSERT`, `UPSERT`, `UPDATE` or `DELETE`.
+    pub statement: &'static str,
+    /// Target collection.
+    pub collection: String,
+    /// Points that do not exist yet and would be created.
+    pub would_insert: usize,
+    /// Existing points that would be overwritten.
+    pub would_update: usize,
+    /// Existing points that would be deleted.
+    pub would_delete: usize,
+    /// Up to [`DRY_RUN_SAMPLE_IDS`] affected ids, in statement order.
+    pub sample_ids: Vec<u64>,
+}
+
+impl DryRunReport {
+    fn new(statement: &'static str, collection: &str) -> Self {
+        Self {
+            statement,
+            collection: collection.to_string(),
+            would_insert: 0,
+            would_update: 0,
+            would_delete: 0,
+            sample_ids: Vec::new(),
+        }
+    }
+
+    /// Total number of points the statement would touch.
+    #[must_use]
+    pub fn affected(&self) -> usize {
+        self.would_insert + self.would_update + self.would_delete
+    }
+}
+
+impl Database {
+    /// Validates a DML statement and reports which points it would insert,
+    /// update or delete, without changing anything.
+    ///
+    /// Parameters and scalar subqueries are resolved exactly as
+    /// [`execute_query`](Self::execute_query) would, so any error the real
+    /// statement raises before writing (unknown collection, bad parameter,
+    /// failing `SET` expression, denied DELETE) is raised here too. Ids
+    /// repeated within one statement are counted once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] if the query is not an INSERT, UPSERT,
+    /// UPDATE or DELETE, and otherwise the error the statement would fail
+    /// with.
+    pub fn execute_query_dry_run(
+        &self,
+        query: &Query,
+        params: &HashMap<String, serde_json::Value>,
+    ) -> Result<DryRunReport> {
+        if let Some(rewritten) = self.resolve_subqueries(query, params)? {
+            return self.execute_query_dry_run(&rewritten, params);
+        }
+        crate::velesql::QueryValidator::validate(query).map_err(|e| Error::Query(e.to_string()))?;
+
+        match query.dml.as_ref() {
+            Some(DmlStatement::Insert(stmt)) => {
+                let (collection, points) = self.prepare_insert(stmt, params)?;
+                let ids = points.iter().map(|p| p.id);
+                Ok(upsert_report("INSERT", &stmt.table, &collection, ids))
+            }
+            Some(DmlStatement::Upsert(stmt)) => {
+                let (collection, points) = self.prepare_insert(stmt, params)?;
+                let ids = points.iter().map(|p| p.id);
+                Ok(upsert_report("UPSERT", &stmt.table, &collection, ids))
+            }
+            Some(DmlStatement::Update(stmt)) => {
+                let (_, points) = self.prepare_update(stmt, params)?;
+                let mut report = DryRunReport::new("UPDATE", &stmt.table);
+                report.would_update = points.len();
+                report.sample_ids = points
+                    .iter()
+                    .map(|p| p.id)
+                    .take(DRY_RUN_SAMPLE_IDS)
+                    .collect();
+                Ok(report)
+            }
+            Some(DmlStatement::Delete(stmt)) => {
+                let (collection, ids) = self.prepare_delete(stmt, params)?;
+                let mut report = DryRunReport::new("DELETE", &stmt.table);
+                for (id, exists) in distinct_with_existence(&collection, ids) {
+                    if exists {
+                        report.would_delete += 1;
+                        push_sample(&mut report, id);
+                    }
+                }
+                Ok(report)
+            }
+            _ => Err(Error::Query(
+                "dry run supports only INSERT, UPSERT, UPDATE and DELETE statements".to_string(),
+            )),
+        }
+    }
+}
+
+/// Splits INSERT/UPSERT ids into new and existing points.
+fn upsert_report(
+    statement: &'static str,
+    table: &str,
+    collection: &Collection,
+    ids: impl Iterator<Item = u64>,
+) -> DryRunReport {
+    let mut report = DryRunReport::new(statement, table);
+    for (id, exists) in distinct_with_existence(collection, ids.collect()) {
+        if exists {
+            report.would_update += 1;
+        } else {
+            report.would_insert += 1;
+        }
+        push_sample(&mut report, id);
+    }
+    report
+}
+
+/// Deduplicates `ids` (keeping first occurrence order) and pairs each with
+/// whether the collection currently holds it.
+fn distinct_with_existence(collection: &Collection, mut ids: Vec<u64>) -> Vec<(u64, bool)> {
+    let mut seen = std::collections::HashSet::with_capacity(ids.len());
+    ids.retain(|id| seen.insert(*id));
+    let existing = collection.get(&ids);
+    ids.into_iter()
+        .zip(existing)
+        .map(|(id, point)| (id, point.is_some()))
+        .collect()
+}
+
+fn push_sample(report: &mut DryRunReport, id: u64) {
+    if report.sample_ids.len() < DRY_RUN_SAMPLE_IDS {
+        report.sample_ids.push(id);
+    }
+}
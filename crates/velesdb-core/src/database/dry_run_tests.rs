@@ -0,0 +1,115 @@
+//! Tests for `Database::execute_query_dry_run`.
+
+use std::collections::HashMap;
+
+use crate::database::{Database, DRY_RUN_SAMPLE_IDS};
+use crate::point::Point;
+use crate::velesql::Parser;
+use crate::DistanceMetric;
+use tempfile::TempDir;
+
+/// Opens a database with `items` holding ids 1..=3, `stock` = id.
+fn seeded_database() -> (TempDir, Database) {
+    let dir = TempDir::new().expect("tempdir");
+    let db = Database::open(dir.path()).expect("open database");
+    db.create_collection("items", 2, DistanceMetric::Cosine)
+        .expect("create collection");
+    let points: Vec<_> = (1..=3u64)
+        .map(|id| Point::new(id, vec![1.0, 0.0], Some(serde_json::json!({"stock": id}))))
+        .collect();
+    db.get_vector_collection("items")
+        .unwrap()
+        .upsert(points)
+        .unwrap();
+    (dir, db)
+}
+
+fn stock_of(db: &Database, id: u64) -> serde_json::Value {
+    let coll = db.get_vector_collection("items").unwrap();
+    coll.get(&[id])[0]
+        .as_ref()
+        .unwrap()
+        .payload
+        .as_ref()
+        .unwrap()["stock"]
+        .clone()
+}
+
+#[test]
+fn test_dry_run_insert_splits_new_and_existing_ids() {
+    let (_dir, db) = seeded_database();
+    let query = Parser::parse(
+        "INSERT INTO items (id, vector, stock) VALUES (3, $v, 0), (4, $v, 0), (4, $v, 1)",
+    )
+    .unwrap();
+    let params = HashMap::from([("v".to_string(), serde_json::json!([0.0, 1.0]))]);
+
+    let report = db.execute_query_dry_run(&query, &params).unwrap();
+
+    assert_eq!(report.statement, "INSERT");
+    assert_eq!(report.collection, "items");
+    assert_eq!((report.would_insert, report.would_update), (1, 1));
+    assert_eq!(report.sample_ids, vec![3, 4]);
+    assert_eq!(db.get_vector_collection("items").unwrap().len(), 3);
+    assert_eq!(stock_of(&db, 3), 3);
+}
+
+#[test]
+fn test_dry_run_update_reports_matching_rows_without_writing() {
+    let (_dir, db) = seeded_database();
+    let query = Parser::parse("UPDATE items SET stock = stock - 1 WHERE stock > 1").unwrap();
+
+    let report = db.execute_query_dry_run(&query, &HashMap::new()).unwrap();
+
+    assert_eq!(report.would_update, 2);
+    let mut ids = report.sample_ids.clone();
+    ids.sort_unstable();
+    assert_eq!(ids, vec![2, 3]);
+    assert_eq!(stock_of(&db, 2), 2);
+}
+
+#[test]
+fn test_dry_run_delete_counts_only_existing_ids() {
+    let (_dir, db) = seeded_database();
+    let query = Parser::parse("DELETE FROM items WHERE id IN (1, 2, 99)").unwrap();
+
+    let report = db.execute_query_dry_run(&query, &HashMap::new()).unwrap();
+
+    assert_eq!(report.would_delete, 2);
+    assert_eq!(report.affected(), 2);
+    assert_eq!(report.sample_ids, vec![1, 2]);
+    assert_eq!(db.get_vector_collection("items").unwrap().len(), 3);
+}
+
+#[test]
+fn test_dry_run_caps_sample_ids() {
+    let (_dir, db) = seeded_database();
+    let rows: Vec<String> = (100..120).map(|id| format!("({id}, 5)")).collect();
+    db.create_metadata_collection("meta").unwrap();
+    let query = Parser::parse(&format!(
+        "INSERT INTO meta (id, stock) VALUES {}",
+        rows.join(", ")
+    ))
+    .unwrap();
+
+    let report = db.execute_query_dry_run(&query, &HashMap::new()).unwrap();
+
+    assert_eq!(report.would_insert, 20);
+    assert_eq!(report.sample_ids.len(), DRY_RUN_SAMPLE_IDS);
+}
+
+#[test]
+fn test_dry_run_surfaces_statement_errors() {
+    let (_dir, db) = seeded_database();
+
+    let missing_param = Parser::parse("INSERT INTO items (id, vector) VALUES (9, $v)").unwrap();
+    assert!(db
+        .execute_query_dry_run(&missing_param, &HashMap::new())
+        .is_err());
+
+    let select = Parser::parse("SELECT * FROM items").unwrap();
+    let err = db
+        .execute_query_dry_run(&select, &HashMap::new())
+        .unwrap_err();
+    assert!(err.to_string().contains("dry run"), "{err}");
+}
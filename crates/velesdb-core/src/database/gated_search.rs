@@ -19,12 +19,13 @@ use crate::point::SearchResult;
 use crate::{Error, Result};
 
 use super::query_engine::RawGateOutcome;
-use super::Database;
+use super::{Database, RequestContext};
 
 /// A non-VelesQL read routed through the control-plane gate.
 ///
 /// Each variant maps to a [`VectorCollection`](crate::VectorCollection) search
-/// leaf and to a [`QueryOperationKind`] the observer sees. Observer-supplied
+/// leaf (`Text` also to the [`MetadataCollection`](crate::MetadataCollection)
+/// one) and to a [`QueryOperationKind`] the observer sees. Observer-supplied
 /// scope filters are AND-composed with any caller filter before execution, so
 /// narrowing can only shrink the result set, never widen it.
 #[derive(Debug, Clone, Copy)]
@@ -97,6 +98,8 @@ pub(crate) fn scope_to_core_filter(condition: crate::velesql::Condition) -> Filt
 
 /// AND-composes a caller filter with an observer scope filter. The result
 /// matches only rows satisfying both, so composing a scope can only narrow.
+/// A composed filter compares strings bytewise: a caller collation must not
+/// loosen the scope's own predicates.
 fn and_filters(caller: Option<&Filter>, scope: Option<Filter>) -> Option<Filter> {
     match (caller, scope) {
         (None, None) => None,
@@ -185,6 +188,10 @@ impl Database {
     /// With no observer registered this is a single `Option` check returning
     /// `Ok(None)` (zero-overhead contract).
     ///
+    /// When `collection` names a [view](Self::create_view), the gate is
+    /// consulted for its base collection and the view predicate is returned
+    /// as (part of) the filter.
+    ///
     /// # Errors
     ///
     /// Returns the observer's `Deny` error when access is refused, or the
@@ -196,15 +203,71 @@ impl Database {
         principal: Option<&str>,
         tenant_hint: Option<&str>,
     ) -> Result<Option<Filter>> {
-        match self.read_gate_raw(collection, operation, principal, tenant_hint)? {
-            RawGateOutcome::Allow => Ok(None),
-            RawGateOutcome::Deny(err) => Err(err),
-            RawGateOutcome::Scope(scope) => Ok(scope.filter.map(scope_to_core_filter)),
+        self.read_scope(collection, operation, principal, tenant_hint, None)
+            .map(|(_, filter)| filter)
+    }
+
+    /// [`authorize_read`](Self::authorize_read) for a read made on behalf of
+    /// `ctx`: the row-security policy of the collection read, bound to
+    /// `ctx`, is AND-composed into the returned filter. With `ctx` `None`
+    /// this is `authorize_read` with no principal or tenant.
+    ///
+    /// # Errors
+    ///
+    /// As [`authorize_read`](Self::authorize_read), plus [`Error::Query`]
+    /// when the policy references an attribute `ctx` does not set.
+    pub fn authorize_read_with_context(
+        &self,
+        collection: &str,
+        operation: QueryOperationKind,
+        ctx: Option<&RequestContext>,
+    ) -> Result<Option<Filter>> {
+        self.read_scope(collection, operation, None, None, ctx)
+            .map(|(_, filter)| filter)
+    }
+
+    /// Resolves the collection a read of `name` runs on and the filter that
+    /// narrows it: view predicate, observer scope and, with a `ctx`, the
+    /// row-security policy, AND-composed.
+    fn read_scope(
+        &self,
+        name: &str,
+        operation: QueryOperationKind,
+        principal: Option<&str>,
+        tenant_hint: Option<&str>,
+        ctx: Option<&RequestContext>,
+    ) -> Result<(String, Option<Filter>)> {
+        let (collection, view_filter) = self.view_read_target(name);
+        let scope_filter =
+            match self.read_gate_raw(&collection, operation, principal, tenant_hint)? {
+                RawGateOutcome::Allow => None,
+                RawGateOutcome::Deny(err) => return Err(err),
+                RawGateOutcome::Scope(scope) => scope.filter.map(scope_to_core_filter),
+            };
+        let mut filter = and_filters(view_filter.as_ref(), scope_filter);
+        if let Some(ctx) = ctx {
+            let row_filter = self.row_security_filter(&collection, Some(ctx))?;
+            filter = and_filters(filter.as_ref(), row_filter);
+        }
+        Ok((collection, filter))
+    }
+
+    /// The collection a read of `name` runs on, with the view predicate when
+    /// `name` is a view: the gate sees the base, and the predicate narrows
+    /// like a scope filter.
+    fn view_read_target(&self, name: &str) -> (String, Option<Filter>) {
+        match self.view_target(name) {
+            Some((base, condition)) => (base, Some(scope_to_core_filter(condition))),
+            None => (name.to_string(), None),
         }
     }
 
     /// Executes a search through the control-plane read gate.
     ///
+    /// `collection` may name a [view](Self::create_view): the search then
+    /// runs on its base collection with the view predicate AND-composed
+    /// into the filter.
+    ///
     /// Consults the registered observer via
     /// [`read_gate_raw`](Self::read_gate_raw) for the
     /// collection / operation / principal / tenant, then:
@@ -229,16 +292,57 @@ impl Database {
         tenant_hint: Option<&str>,
         read: GatedRead<'_>,
     ) -> Result<Vec<SearchResult>> {
-        let scope_filter =
-            match self.read_gate_raw(collection, read.operation_kind(), principal, tenant_hint)? {
-                RawGateOutcome::Allow => None,
-                RawGateOutcome::Deny(err) => return Err(err),
-                RawGateOutcome::Scope(scope) => scope.filter.map(scope_to_core_filter),
-            };
+        self.gated_search_scoped(collection, principal, tenant_hint, None, read)
+    }
+
+    /// [`gated_search`](Self::gated_search) for a read made on behalf of
+    /// `ctx`: the row-security policy of the collection searched, bound to
+    /// `ctx`, is AND-composed into the filter. With `ctx` `None` this is
+    /// `gated_search` with no principal or tenant.
+    ///
+    /// # Errors
+    ///
+    /// As [`gated_search`](Self::gated_search), plus [`Error::Query`] when
+    /// the policy references an attribute `ctx` does not set.
+    pub fn gated_search_with_context(
+        &self,
+        collection: &str,
+        ctx: Option<&RequestContext>,
+        read: GatedRead<'_>,
+    ) -> Result<Vec<SearchResult>> {
+        self.gated_search_scoped(collection, None, None, ctx, read)
+    }
+
+    fn gated_search_scoped(
+        &self,
+        collection: &str,
+        principal: Option<&str>,
+        tenant_hint: Option<&str>,
+        ctx: Option<&RequestContext>,
+        read: GatedRead<'_>,
+    ) -> Result<Vec<SearchResult>> {
+        let (collection, scope_filter) = self.read_scope(
+            collection,
+            read.operation_kind(),
+            principal,
+            tenant_hint,
+            ctx,
+        )?;
+
+        // Metadata-only collections have a BM25 index but no vectors: they
+        // serve text reads only.
+        if let GatedRead::Text { query, k, filter } = read {
+            if let Some(coll) = self.get_metadata_collection(&collection) {
+                return match and_filters(filter, scope_filter) {
+                    Some(f) => coll.text_search_with_filter(query, k, &f),
+                    None => coll.text_search(query, k),
+                };
+            }
+        }
 
         let coll = self
-            .get_vector_collection(collection)
-            .ok_or_else(|| Error::CollectionNotFound(collection.to_string()))?;
+            .get_vector_collection(&collection)
+            .ok_or(Error::CollectionNotFound(collection))?;
 
         dispatch_gated_read(&coll, read, scope_filter)
     }
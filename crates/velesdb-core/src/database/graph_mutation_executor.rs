@@ -0,0 +1,370 @@
+//! Graph mutation executor for `VelesQL` (`CREATE` edges, `[DETACH] DELETE`).
+//!
+//! The statement's MATCH patterns bind rows of node and relationship
+//! aliases, and the mutation is applied once per row. Everything is
+//! resolved before the first write: CREATE adds all edges as one batch that
+//! `GraphSchema` and endpoint validation accept or reject as a whole, and
+//! DELETE refuses to orphan relationships unless `DETACH` is given.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::collection::search::query::match_exec::MatchResult;
+use crate::collection::{GraphCollection, GraphEdge};
+use crate::velesql::{
+    CreateEdgePattern, GraphMutationAction, GraphMutationStatement, MatchClause, MutationNodeRef,
+    ReturnClause, Value,
+};
+use crate::{Error, Result, SearchResult};
+
+use super::Database;
+
+/// Node and relationship aliases bound by one MATCH row.
+#[derive(Debug, Clone, Default)]
+struct MutationRow {
+    nodes: HashMap<String, u64>,
+    /// Edge ids per relationship alias (several for variable-length ones).
+    edges: HashMap<String, Vec<u64>>,
+}
+
+impl MutationRow {
+    fn from_match(m: MatchResult) -> Self {
+        let mut edges: HashMap<String, Vec<u64>> = m.edge_paths;
+        for (alias, id) in m.edge_bindings {
+            edges.entry(alias).or_insert_with(|| vec![id]);
+        }
+        Self {
+            nodes: m.bindings,
+            edges,
+        }
+    }
+
+    /// Merges `other` into a copy of `self` when shared aliases agree.
+    fn join(&self, other: &Self) -> Option<Self> {
+        let nodes_agree = other
+            .nodes
+            .iter()
+            .all(|(alias, id)| self.nodes.get(alias).is_none_or(|bound| bound == id));
+        let edges_agree = other
+            .edges
+            .iter()
+            .all(|(alias, ids)| self.edges.get(alias).is_none_or(|bound| bound == ids));
+        if !(nodes_agree && edges_agree) {
+            return None;
+        }
+        let mut joined = self.clone();
+        joined.nodes.extend(other.nodes.clone());
+        joined.edges.extend(other.edges.clone());
+        Some(joined)
+    }
+}
+
+impl Database {
+    /// Executes a graph mutation statement.
+    ///
+    /// Returns a single summary row: `edges_created` for CREATE,
+    /// `nodes_deleted` and `edges_deleted` for DELETE.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no target collection is given, the collection is
+    /// not a graph collection, an alias is not bound by the MATCH, the MATCH
+    /// binds too many rows, a created edge fails schema or endpoint
+    /// validation, or a plain DELETE would leave relationships dangling.
+    pub(super) fn execute_graph_mutation(
+        &self,
+        stmt: &GraphMutationStatement,
+        params: &HashMap<String, serde_json::Value>,
+    ) -> Result<Vec<SearchResult>> {
+        let name = mutation_collection(stmt, params)?;
+        validate_mutation_aliases(stmt)?;
+        self.check_dml_mutation(mutation_operation(stmt), &name)?;
+        let graph = self.resolve_graph_collection(&name)?;
+        let rows = bind_mutation_rows(&graph, stmt, params)?;
+
+        let payload = match &stmt.action {
+            GraphMutationAction::CreateEdges(patterns) => {
+                let created = create_edges(&graph, patterns, &rows, params)?;
+                serde_json::json!({ "edges_created": created })
+            }
+            GraphMutationAction::Delete { aliases, detach } => {
+                let (nodes, edges) = delete_bound(&graph, aliases, *detach, &rows)?;
+                serde_json::json!({ "nodes_deleted": nodes, "edges_deleted": edges })
+            }
+        };
+        let result = SearchResult::new(crate::Point::metadata_only(0, payload), 0.0);
+        Ok(vec![result])
+    }
+}
+
+/// Target collection: the `IN` clause, else the `_collection` parameter.
+fn mutation_collection(
+    stmt: &GraphMutationStatement,
+    params: &HashMap<String, serde_json::Value>,
+) -> Result<String> {
+    if let Some(name) = &stmt.collection {
+        return Ok(name.clone());
+    }
+    match params.get("_collection") {
+        Some(serde_json::Value::String(name)) => Ok(name.clone()),
+        _ => Err(Error::Query(
+            "Graph mutation requires a target collection. Either append \
+             IN <collection>, or pass {\"_collection\": \"name\"} in params."
+                .to_string(),
+        )),
+    }
+}
+
+/// RBAC operation name, shared with the equivalent single-edge statements.
+fn mutation_operation(stmt: &GraphMutationStatement) -> &'static str {
+    match &stmt.action {
+        GraphMutationAction::CreateEdges(_) => "INSERT_EDGE",
+        GraphMutationAction::Delete { aliases, .. } => {
+            let deletes_nodes = stmt
+                .patterns
+                .iter()
+                .flat_map(|p| &p.nodes)
+                .any(|n| n.alias.as_ref().is_some_and(|a| aliases.contains(a)));
+            if deletes_nodes {
+                "DELETE"
+            } else {
+                "DELETE_EDGE"
+            }
+        }
+    }
+}
+
+/// Checks that every alias the mutation uses is bound by the MATCH:
+/// CREATE endpoints to node aliases, DELETE targets to any alias.
+fn validate_mutation_aliases(stmt: &GraphMutationStatement) -> Result<()> {
+    let node_aliases: HashSet<&str> = stmt
+        .patterns
+        .iter()
+        .flat_map(|p| &p.nodes)
+        .filter_map(|n| n.alias.as_deref())
+        .collect();
+    let rel_aliases: HashSet<&str> = stmt
+        .patterns
+        .iter()
+        .flat_map(|p| &p.relationships)
+        .filter_map(|r| r.alias.as_deref())
+        .collect();
+
+    match &stmt.action {
+        GraphMutationAction::CreateEdges(patterns) => {
+            let endpoints = patterns.iter().flat_map(|p| [&p.source, &p.target]);
+            for endpoint in endpoints {
+                if let MutationNodeRef::Alias(alias) = endpoint {
+                    if !node_aliases.contains(alias.as_str()) {
+                        return Err(Error::Query(format!(
+                            "CREATE endpoint '{alias}' is not a node bound by MATCH"
+                        )));
+                    }
+                }
+            }
+        }
+        GraphMutationAction::Delete { aliases, .. } => {
+            for alias in aliases {
+                let alias = alias.as_str();
+                if !node_aliases.contains(alias) && !rel_aliases.contains(alias) {
+                    return Err(Error::Query(format!(
+                        "DELETE target '{alias}' is not bound by MATCH"
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Runs each MATCH pattern and joins the rows on shared aliases. Without
+/// patterns there is a single empty row.
+///
+/// # Errors
+///
+/// Returns an error if MATCH fails, or if a pattern or the join reaches the
+/// collection's `max_query_limit` rows, since a truncated row set would
+/// apply the mutation partially.
+fn bind_mutation_rows(
+    graph: &GraphCollection,
+    stmt: &GraphMutationStatement,
+    params: &HashMap<String, serde_json::Value>,
+) -> Result<Vec<MutationRow>> {
+    let max = graph.inner.max_query_limit();
+    let check_rows = |count: usize| {
+        if count >= max {
+            return Err(Error::Query(format!(
+                "Graph mutation MATCH binds at least {max} rows (max_query_limit); \
+                 narrow the pattern"
+            )));
+        }
+        Ok(())
+    };
+
+    let mut rows = vec![MutationRow::default()];
+    for pattern in &stmt.patterns {
+        let clause = MatchClause {
+            patterns: vec![pattern.clone()],
+            where_clause: stmt.where_clause.clone(),
+            return_clause: ReturnClause {
+                items: Vec::new(),
+                order_by: None,
+                limit: None,
+                limit_per: None,
+            },
+        };
+        let matches = graph.execute_match(&clause, params)?;
+        check_rows(matches.len())?;
+        let bound: Vec<MutationRow> = matches.into_iter().map(MutationRow::from_match).collect();
+
+        let mut joined = Vec::new();
+        for left in &rows {
+            for right in &bound {
+                if let Some(row) = left.join(right) {
+                    joined.push(row);
+                    check_rows(joined.len())?;
+                }
+            }
+        }
+        rows = joined;
+    }
+    Ok(rows)
+}
+
+/// Creates one edge per pattern and row as a single validated batch.
+///
+/// Edge ids are hashed from `(source, target, label)` like `INSERT EDGE`,
+/// so an edge that already exists is left unchanged and not counted.
+fn create_edges(
+    graph: &GraphCollection,
+    patterns: &[CreateEdgePattern],
+    rows: &[MutationRow],
+    params: &HashMap<String, serde_json::Value>,
+) -> Result<usize> {
+    let properties = patterns
+        .iter()
+        .map(|p| resolve_properties(&p.properties, params))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    for row in rows {
+        for (pattern, props) in patterns.iter().zip(&properties) {
+            let source = resolve_node_ref(&pattern.source, row, params)?;
+            let target = resolve_node_ref(&pattern.target, row, params)?;
+            let id = crate::wire::hash_edge_id(source, target, &pattern.label);
+            if !seen.insert(id) {
+                continue;
+            }
+            let edge = GraphEdge::new(id, source, target, &pattern.label)?;
+            edges.push(if props.is_empty() {
+                edge
+            } else {
+                edge.with_properties(props.clone())
+            });
+        }
+    }
+    graph.add_edges_batch(edges)
+}
+
+/// Resolves a CREATE endpoint to a node id.
+fn resolve_node_ref(
+    node: &MutationNodeRef,
+    row: &MutationRow,
+    params: &HashMap<String, serde_json::Value>,
+) -> Result<u64> {
+    match node {
+        MutationNodeRef::Id(id) => Ok(*id),
+        MutationNodeRef::Parameter(name) => params
+            .get(name)
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| {
+                Error::Query(format!(
+                    "Parameter ${name} must be a node ID (non-negative integer)"
+                ))
+            }),
+        MutationNodeRef::Alias(alias) => row.nodes.get(alias).copied().ok_or_else(|| {
+            Error::Query(format!(
+                "CREATE endpoint '{alias}' is not a node bound by MATCH"
+            ))
+        }),
+    }
+}
+
+/// Resolves CREATE edge properties, substituting `$param` values.
+fn resolve_properties(
+    properties: &[(String, Value)],
+    params: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>> {
+    properties
+        .iter()
+        .map(|(key, value)| {
+            let json = match value {
+                Value::Parameter(name) => params
+                    .get(name)
+                    .cloned()
+                    .ok_or_else(|| Error::Query(format!("Missing parameter: ${name}")))?,
+                Value::Temporal(_) | Value::Subquery(_) => {
+                    return Err(Error::Query(
+                        "Edge properties must be literal values or parameters".to_string(),
+                    ));
+                }
+                other => other.to_json(),
+            };
+            Ok((key.clone(), json))
+        })
+        .collect()
+}
+
+/// Deletes the nodes and relationships bound to `aliases` and returns
+/// `(nodes_deleted, edges_deleted)`.
+///
+/// A node's other relationships are deleted with it under `DETACH`; without
+/// it, such a relationship rejects the statement before anything is
+/// deleted.
+fn delete_bound(
+    graph: &GraphCollection,
+    aliases: &[String],
+    detach: bool,
+    rows: &[MutationRow],
+) -> Result<(usize, usize)> {
+    let mut nodes = BTreeSet::new();
+    let mut edges = BTreeSet::new();
+    for row in rows {
+        for alias in aliases {
+            nodes.extend(row.nodes.get(alias));
+            edges.extend(row.edges.get(alias).into_iter().flatten());
+        }
+    }
+
+    let mut attached = BTreeSet::new();
+    for &node in &nodes {
+        let incident = graph.get_outgoing(node).into_iter();
+        for edge in incident.chain(graph.get_incoming(node)) {
+            if edges.contains(&edge.id()) {
+                continue;
+            }
+            if !detach {
+                return Err(Error::Query(format!(
+                    "Cannot DELETE node {node}: it still has relationships; \
+                     use DETACH DELETE or delete them in the same statement"
+                )));
+            }
+            attached.insert(edge.id());
+        }
+    }
+
+    let edges_deleted = edges
+        .iter()
+        .chain(&attached)
+        .filter(|&&id| graph.remove_edge(id))
+        .count();
+    let ids: Vec<u64> = nodes.into_iter().collect();
+    let mut nodes_deleted = 0;
+    for &id in &ids {
+        if graph.get_node_payload(id)?.is_some() {
+            nodes_deleted += 1;
+        }
+    }
+    graph.delete(&ids)?;
+    Ok((nodes_deleted, edges_deleted))
+}
@@ -80,6 +80,7 @@ impl Database {
     ) {
         // Parity item E: thread the live LimitsConfig caps into the collection.
         self.push_runtime_limits(&coll.inner);
+        self.push_storage_residency(name, &coll.inner);
 
         self.graph_colls
             .write()
@@ -121,6 +122,7 @@ impl Database {
         let coll = GraphCollection::open(self.data_dir.join(name)).ok()?;
         // Parity item E: re-push runtime limits on disk-open (not persisted).
         self.push_runtime_limits(&coll.inner);
+        self.push_storage_residency(name, &coll.inner);
         self.graph_colls
             .write()
             .insert(name.to_string(), coll.clone());
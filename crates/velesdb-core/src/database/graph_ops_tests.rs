@@ -172,3 +172,37 @@ fn test_schema_version_increments_on_graph_creation() {
     .unwrap();
     assert_eq!(db.schema_version(), v0 + 2);
 }
+
+// =========================================================================
+// Edges survive a database restart (snapshot + edge WAL)
+// =========================================================================
+
+#[test]
+fn test_graph_edges_survive_database_reopen() {
+    use crate::collection::graph::GraphEdge;
+
+    let dir = tempdir().unwrap();
+    {
+        let db = Database::open(dir.path()).unwrap();
+        db.create_graph_collection("kg", GraphSchema::schemaless())
+            .unwrap();
+        let gc = db.get_graph_collection("kg").unwrap();
+        for id in [10, 20, 30] {
+            gc.upsert_node_payload(id, &serde_json::json!({})).unwrap();
+        }
+        gc.add_edge(GraphEdge::new(1, 10, 20, "KNOWS").unwrap())
+            .unwrap();
+        gc.add_edge(GraphEdge::new(2, 20, 30, "KNOWS").unwrap())
+            .unwrap();
+        gc.flush().unwrap();
+        // Added after the last flush: only the edge WAL has it.
+        gc.add_edge(GraphEdge::new(3, 10, 30, "LIKES").unwrap())
+            .unwrap();
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let gc = db.get_graph_collection("kg").unwrap();
+    assert_eq!(gc.edge_count(), 3);
+    assert_eq!(gc.get_outgoing(10).len(), 2);
+    assert_eq!(gc.get_edges(Some("LIKES")).len(), 1);
+}
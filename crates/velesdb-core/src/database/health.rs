@@ -0,0 +1,53 @@
+//! Storage-level health information for readiness probes.
+//!
+//! Complements the per-collection [`CollectionDiagnostics`](crate::collection::CollectionDiagnostics)
+//! with the state only the database knows about: collection directories that
+//! could not be opened at startup and the space left on the data volume.
+
+use crate::Result;
+
+use super::Database;
+
+/// A collection directory that was found on disk but could not be opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionLoadFailure {
+    /// Collection name (the directory name).
+    pub name: String,
+    /// Why loading failed.
+    pub error: String,
+}
+
+/// Space on the filesystem holding the data directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskSpace {
+    /// Bytes available to this process.
+    pub available_bytes: u64,
+    /// Total size of the filesystem in bytes.
+    pub total_bytes: u64,
+}
+
+impl Database {
+    /// Returns the collections that failed to load, sorted by name.
+    ///
+    /// Failed collections are not registered, so they are invisible to
+    /// [`list_collections`](Self::list_collections); this is the only place
+    /// they show up besides the startup log.
+    #[must_use]
+    pub fn collection_load_failures(&self) -> Vec<CollectionLoadFailure> {
+        let mut failures: Vec<_> = self.load_failures.read().values().cloned().collect();
+        failures.sort_by(|a, b| a.name.cmp(&b.name));
+        failures
+    }
+
+    /// Returns the free and total space of the data directory's filesystem.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the filesystem cannot be queried.
+    pub fn disk_space(&self) -> Result<DiskSpace> {
+        Ok(DiskSpace {
+            available_bytes: fs2::available_space(&self.data_dir)?,
+            total_bytes: fs2::total_space(&self.data_dir)?,
+        })
+    }
+}
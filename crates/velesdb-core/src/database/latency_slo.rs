@@ -0,0 +1,196 @@
+//! Per-collection latency SLOs.
+//!
+//! Each collection may carry a [`LatencySlo`] such as "p99 search latency
+//! under 20 ms". Search latencies recorded for the collection — `VelesQL`
+//! SELECTs run through [`Database::execute_query`] and whatever the embedding
+//! layer reports through [`Database::record_search_latency`] — feed a
+//! sliding-window [`SloTracker`]. When the objective has been missed for its
+//! sustain period, and again when it recovers, an [`SloAlert`] is logged and
+//! passed to the hook registered with [`Database::set_slo_alert_hook`].
+//!
+//! Objectives are persisted in `latency_slos.json` in the data directory;
+//! the observed windows are not. Deleting the collection drops its objective.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::metrics::{LatencySlo, SloAlert, SloAlertKind, SloStatus, SloTracker};
+use crate::{Error, Result};
+
+use super::Database;
+
+/// Objective definitions file, in the data directory.
+const LATENCY_SLO_FILE: &str = "latency_slos.json";
+
+/// Callback invoked with every [`SloAlert`].
+pub type SloAlertHook = dyn Fn(&SloAlert) + Send + Sync;
+
+impl Database {
+    /// Sets the latency objective of `collection`, replacing any previous one
+    /// and starting from an empty window.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{Database, DistanceMetric, LatencySlo};
+    /// let db = Database::open("./data")?;
+    /// db.create_collection("docs", 384, DistanceMetric::Cosine)?;
+    /// db.set_latency_slo("docs", LatencySlo::new(99.0, 20.0))?;
+    /// db.set_slo_alert_hook(std::sync::Arc::new(|alert: &velesdb_core::SloAlert| {
+    ///     eprintln!("{} SLO {:?}", alert.collection, alert.kind);
+    /// }));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::CollectionNotFound`] if no collection named `collection`
+    ///   exists.
+    /// - [`Error::Config`] if the objective is invalid.
+    /// - An I/O error if `latency_slos.json` cannot be written.
+    pub fn set_latency_slo(&self, collection: &str, slo: LatencySlo) -> Result<()> {
+        if !self.collection_exists_in_registry(collection) {
+            return Err(Error::CollectionNotFound(collection.to_string()));
+        }
+        slo.validate()?;
+        let mut slos = self.latency_slos.write();
+        let previous = slos.insert(collection.to_string(), Arc::new(SloTracker::new(slo)));
+        if let Err(e) = self.save_latency_slos(&slos) {
+            match previous {
+                Some(tracker) => slos.insert(collection.to_string(), tracker),
+                None => slos.remove(collection),
+            };
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Removes the latency objective of `collection`. Returns `false` when it
+    /// had none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if `latency_slos.json` cannot be written.
+    pub fn remove_latency_slo(&self, collection: &str) -> Result<bool> {
+        let mut slos = self.latency_slos.write();
+        let Some(tracker) = slos.remove(collection) else {
+            return Ok(false);
+        };
+        if let Err(e) = self.save_latency_slos(&slos) {
+            slos.insert(collection.to_string(), tracker);
+            return Err(e);
+        }
+        Ok(true)
+    }
+
+    /// Evaluates the latency objective of `collection` now, or `None` when it
+    /// has none. A breach or recovery the evaluation detects is alerted.
+    #[must_use]
+    pub fn latency_slo_status(&self, collection: &str) -> Option<SloStatus> {
+        let tracker = self.latency_slos.read().get(collection).cloned()?;
+        let (status, transition) = tracker.poll();
+        if let Some(kind) = transition {
+            self.raise_slo_alert(collection, kind, status.clone());
+        }
+        Some(status)
+    }
+
+    /// Records the latency of one search on `collection` against its
+    /// objective. No-op when the collection has none.
+    ///
+    /// `VelesQL` SELECTs are recorded by [`Database::execute_query`]; callers
+    /// serving searches outside it (e.g. REST search endpoints) report them
+    /// here.
+    pub fn record_search_latency(&self, collection: &str, latency: Duration) {
+        let Some(tracker) = self.latency_slos.read().get(collection).cloned() else {
+            return;
+        };
+        if let Some((kind, status)) = tracker.record(latency) {
+            self.raise_slo_alert(collection, kind, status);
+        }
+    }
+
+    /// Registers the callback invoked with every SLO breach and recovery,
+    /// replacing any previous one. The callback runs on the thread that
+    /// detected the transition, so it should hand slow work off.
+    pub fn set_slo_alert_hook(&self, hook: Arc<SloAlertHook>) {
+        *self.slo_alert_hook.write() = Some(hook);
+    }
+
+    /// Removes the SLO alert callback; alerts are still logged.
+    pub fn clear_slo_alert_hook(&self) {
+        *self.slo_alert_hook.write() = None;
+    }
+
+    /// Returns `true` when `collection` has a latency objective.
+    pub(super) fn has_latency_slo(&self, collection: &str) -> bool {
+        self.latency_slos.read().contains_key(collection)
+    }
+
+    /// Drops the objective of the deleted collection `collection`.
+    pub(super) fn drop_latency_slo_of(&self, collection: &str) {
+        if let Err(e) = self.remove_latency_slo(collection) {
+            tracing::warn!(collection, error = %e, "failed to persist dropped latency SLO");
+        }
+    }
+
+    /// Loads `latency_slos.json`; invalid objectives are logged and skipped.
+    pub(super) fn load_latency_slos(&self) {
+        let path = self.data_dir.join(LATENCY_SLO_FILE);
+        let Ok(bytes) = std::fs::read(&path) else {
+            return;
+        };
+        let defs: BTreeMap<String, LatencySlo> = match serde_json::from_slice(&bytes) {
+            Ok(defs) => defs,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "ignoring unreadable latency SLO file");
+                return;
+            }
+        };
+        let mut slos = self.latency_slos.write();
+        for (collection, slo) in defs {
+            match slo.validate() {
+                Ok(()) => {
+                    slos.insert(collection, Arc::new(SloTracker::new(slo)));
+                }
+                Err(e) => {
+                    tracing::warn!(collection = %collection, error = %e, "skipping invalid latency SLO");
+                }
+            }
+        }
+    }
+
+    fn save_latency_slos(&self, slos: &HashMap<String, Arc<SloTracker>>) -> Result<()> {
+        let defs: BTreeMap<&str, &LatencySlo> = slos
+            .iter()
+            .map(|(collection, tracker)| (collection.as_str(), tracker.slo()))
+            .collect();
+        let bytes =
+            serde_json::to_vec_pretty(&defs).map_err(|e| Error::Serialization(e.to_string()))?;
+        crate::storage::atomic_write::atomic_write(&self.data_dir.join(LATENCY_SLO_FILE), &bytes)?;
+        Ok(())
+    }
+
+    fn raise_slo_alert(&self, collection: &str, kind: SloAlertKind, status: SloStatus) {
+        match kind {
+            SloAlertKind::Breached => tracing::warn!(
+                collection,
+                percentile = status.slo.percentile,
+                threshold_ms = status.slo.threshold_ms,
+                observed_ms = ?status.observed_ms,
+                "latency SLO breached"
+            ),
+            SloAlertKind::Recovered => tracing::info!(collection, "latency SLO recovered"),
+        }
+        // Clone the hook out so it runs without the lock held.
+        let Some(hook) = self.slo_alert_hook.read().clone() else {
+            return;
+        };
+        hook(&SloAlert {
+            collection: collection.to_string(),
+            kind,
+            status,
+        });
+    }
+}
@@ -0,0 +1,104 @@
+//! Tests for per-collection latency SLOs (`set_latency_slo` and alerting).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::*;
+use crate::metrics::{LatencySlo, SloAlert, SloAlertKind, SloState};
+use crate::velesql::Parser;
+use crate::DistanceMetric;
+use tempfile::tempdir;
+
+/// Breaches as soon as three samples exceed 10 ms.
+fn strict() -> LatencySlo {
+    LatencySlo {
+        percentile: 99.0,
+        threshold_ms: 10.0,
+        window_secs: 60,
+        sustain_secs: 0,
+        min_samples: 3,
+    }
+}
+
+#[test]
+fn test_sustained_violation_fires_hook() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+    db.set_latency_slo("docs", strict()).unwrap();
+    let alerts: Arc<parking_lot::Mutex<Vec<SloAlert>>> = Arc::default();
+    let sink = Arc::clone(&alerts);
+    db.set_slo_alert_hook(Arc::new(move |alert: &SloAlert| {
+        sink.lock().push(alert.clone());
+    }));
+
+    for _ in 0..3 {
+        db.record_search_latency("docs", Duration::from_millis(50));
+    }
+    let status = db.latency_slo_status("docs").unwrap();
+    assert_eq!(status.state, SloState::Breached);
+    assert_eq!(status.samples, 3);
+    assert_eq!(status.observed_ms, Some(50.0));
+
+    let alerts = alerts.lock();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].collection, "docs");
+    assert_eq!(alerts[0].kind, SloAlertKind::Breached);
+
+    // Collections without an objective are not tracked.
+    db.record_search_latency("other", Duration::from_millis(50));
+    assert!(db.latency_slo_status("other").is_none());
+}
+
+#[test]
+fn test_velesql_selects_are_recorded() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+    db.set_latency_slo("docs", LatencySlo::new(99.0, 1_000.0))
+        .unwrap();
+
+    let query = Parser::parse("SELECT * FROM docs LIMIT 10").unwrap();
+    db.execute_query(&query, &HashMap::new()).unwrap();
+    db.execute_query(&query, &HashMap::new()).unwrap();
+    let status = db.latency_slo_status("docs").unwrap();
+    assert_eq!(status.samples, 2);
+    assert_eq!(status.state, SloState::Ok);
+}
+
+#[test]
+fn test_slo_validation_persistence_and_drop() {
+    let dir = tempdir().unwrap();
+    {
+        let db = Database::open(dir.path()).unwrap();
+        assert!(matches!(
+            db.set_latency_slo("docs", strict()),
+            Err(Error::CollectionNotFound(_))
+        ));
+        db.create_collection("docs", 2, DistanceMetric::Cosine)
+            .unwrap();
+        assert!(matches!(
+            db.set_latency_slo("docs", LatencySlo::new(120.0, 10.0)),
+            Err(Error::Config(_))
+        ));
+        db.set_latency_slo("docs", strict()).unwrap();
+        db.record_search_latency("docs", Duration::from_millis(50));
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let status = db.latency_slo_status("docs").unwrap();
+    assert_eq!(status.slo, strict());
+    // Windows are not persisted.
+    assert_eq!(status.samples, 0);
+
+    assert!(db.remove_latency_slo("docs").unwrap());
+    assert!(!db.remove_latency_slo("docs").unwrap());
+    db.set_latency_slo("docs", strict()).unwrap();
+    db.delete_collection("docs").unwrap();
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+    assert!(db.latency_slo_status("docs").is_none());
+}
@@ -0,0 +1,517 @@
+//! Online migration of a vector collection to another storage mode, metric
+//! or dimension.
+//!
+//! [`Database::migrate_collection`] rebuilds the collection under
+//! `<data_dir>/.migrations/<name>` while the live collection keeps serving
+//! reads and writes, then swaps the rebuilt directory in under the same
+//! name. Writes that land during the copy are detected through the source's
+//! write generation and re-synced by catch-up passes. The migrations
+//! directory has no `config.json`, so it is never loaded as a collection;
+//! whatever a crash left in it is resolved the next time the database opens
+//! (see `Database::recover_migrations`).
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::collection::expiry::{is_payload_expired, now_unix_secs};
+use crate::collection::VectorCollection;
+use crate::index::hnsw::HnswParams;
+use crate::point::Point;
+use crate::{DistanceMetric, Error, Result, StorageMode};
+
+use super::Database;
+
+/// Name of the migrations directory inside the data directory.
+pub(super) const MIGRATIONS_DIR: &str = ".migrations";
+
+/// Suffix of the live directory parked during the swap. Collection names
+/// cannot contain a dot, so it never clashes with a staging directory.
+const PARKED_SUFFIX: &str = ".old";
+
+/// Points read from the source and written to the target per batch.
+const COPY_BATCH_SIZE: usize = 1000;
+
+/// Catch-up passes allowed when the source keeps changing during the copy.
+pub const MAX_CATCH_UP_PASSES: usize = 3;
+
+/// Vector mapping of a [`DimensionTransform::Custom`] migration.
+pub type VectorMapFn = Arc<dyn Fn(&[f32]) -> Vec<f32> + Send + Sync>;
+
+/// How vectors are reshaped when a migration changes the dimension.
+#[derive(Clone)]
+pub enum DimensionTransform {
+    /// Keeps the first `n` components (Matryoshka-style embeddings).
+    Truncate(usize),
+    /// Appends zeros up to `n` components.
+    ZeroPad(usize),
+    /// Maps every vector with `map`, which must return `dimension` components.
+    Custom {
+        /// Dimension of the migrated collection.
+        dimension: usize,
+        /// Vector mapping applied to every point.
+        map: VectorMapFn,
+    },
+}
+
+impl DimensionTransform {
+    /// Dimension of the migrated collection.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        match self {
+            Self::Truncate(n) | Self::ZeroPad(n) => *n,
+            Self::Custom { dimension, .. } => *dimension,
+        }
+    }
+
+    fn validate(&self, source_dimension: usize) -> Result<()> {
+        match *self {
+            Self::Truncate(n) if n == 0 || n > source_dimension => Err(Error::Config(format!(
+                "cannot truncate {source_dimension}-d vectors to {n} components"
+            ))),
+            Self::ZeroPad(n) if n < source_dimension => Err(Error::Config(format!(
+                "cannot zero-pad {source_dimension}-d vectors to {n} components"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn apply(&self, mut vector: Vec<f32>) -> Vec<f32> {
+        match self {
+            Self::Truncate(n) => {
+                vector.truncate(*n);
+                vector
+            }
+            Self::ZeroPad(n) => {
+                vector.resize(*n, 0.0);
+                vector
+            }
+            Self::Custom { map, .. } => map(&vector),
+        }
+    }
+}
+
+impl std::fmt::Debug for DimensionTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncate(n) => f.debug_tuple("Truncate").field(n).finish(),
+            Self::ZeroPad(n) => f.debug_tuple("ZeroPad").field(n).finish(),
+            Self::Custom { dimension, .. } => f
+                .debug_struct("Custom")
+                .field("dimension", dimension)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Target of [`Database::migrate_collection`]. `None` fields keep the
+/// collection's current value.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationSpec {
+    /// New storage mode (e.g. `Full` → `SQ8`).
+    pub storage_mode: Option<StorageMode>,
+    /// New distance metric (e.g. `Cosine` → `DotProduct`).
+    pub metric: Option<DistanceMetric>,
+    /// Reshapes vectors to a new dimension.
+    pub dimension_transform: Option<DimensionTransform>,
+}
+
+/// Stage of a running migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationPhase {
+    /// First copy of every point into the rebuilt collection.
+    Copying,
+    /// Re-sync of points written to the source during the previous pass.
+    CatchingUp,
+    /// The rebuilt collection is being swapped in.
+    Swapping,
+}
+
+/// Progress of a migration, reported after each batch and at the swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationProgress {
+    /// Current stage.
+    pub phase: MigrationPhase,
+    /// Catch-up pass number (0 during the first copy).
+    pub pass: usize,
+    /// Points copied in this pass so far.
+    pub copied: usize,
+    /// Points to copy in this pass.
+    pub total: usize,
+}
+
+/// Outcome of a completed migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Points in the migrated collection.
+    pub points: usize,
+    /// Catch-up passes run because the source changed during the copy.
+    pub catch_up_passes: usize,
+    /// Storage mode of the migrated collection.
+    pub storage_mode: StorageMode,
+    /// Metric of the migrated collection.
+    pub metric: DistanceMetric,
+    /// Dimension of the migrated collection.
+    pub dimension: usize,
+}
+
+impl Database {
+    /// Rebuilds a vector collection with another storage mode, metric or
+    /// dimension and swaps it in under the same name.
+    ///
+    /// See [`Self::migrate_collection_with_progress`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::migrate_collection_with_progress`].
+    pub fn migrate_collection(&self, name: &str, spec: &MigrationSpec) -> Result<MigrationReport> {
+        self.migrate_collection_with_progress(name, spec, |_| {})
+    }
+
+    /// Rebuilds a vector collection with another storage mode, metric or
+    /// dimension, calling `on_progress` after each copied batch.
+    ///
+    /// Runs on the calling thread — spawn it on a background thread to keep
+    /// serving. The live collection stays readable and writable until the
+    /// swap; writes made during the copy are re-synced by up to
+    /// [`MAX_CATCH_UP_PASSES`] catch-up passes, and writes racing the swap
+    /// by one final pass against the old handle. Secondary indexes, query
+    /// defaults and limits carry over; the HNSW parameters and embedding
+    /// model carry over unless the dimension changes.
+    ///
+    /// Searches started before the swap finish on the old collection;
+    /// later lookups get the migrated one.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::CollectionNotFound` if no collection has this name.
+    /// - `Error::Config` if it is not a vector collection, a migration of it
+    ///   is already running, the dimension transform does not fit, or the
+    ///   source kept changing through every catch-up pass. The live
+    ///   collection is left untouched.
+    /// - `Error::GuardRail` if the new dimension exceeds `max_dimensions`.
+    /// - Validation, storage and I/O errors from the rebuild or the swap.
+    pub fn migrate_collection_with_progress(
+        &self,
+        name: &str,
+        spec: &MigrationSpec,
+        mut on_progress: impl FnMut(MigrationProgress),
+    ) -> Result<MigrationReport> {
+        crate::validation::validate_collection_name(name)?;
+        let source = self.migration_source(name)?;
+        if source.archived_count()? > 0 {
+            return Err(Error::Config(format!(
+                "'{name}' has archived points; restore them before migrating"
+            )));
+        }
+        let config = source.config();
+        if let Some(transform) = &spec.dimension_transform {
+            transform.validate(config.dimension)?;
+        }
+        let dimension = spec
+            .dimension_transform
+            .as_ref()
+            .map_or(config.dimension, DimensionTransform::dimension);
+        self.enforce_vector_dimension_limit(dimension)?;
+        let storage_mode = spec.storage_mode.unwrap_or(config.storage_mode);
+        let metric = spec.metric.unwrap_or(config.metric);
+
+        let migrations_dir = self.data_dir.join(MIGRATIONS_DIR);
+        std::fs::create_dir_all(&migrations_dir)?;
+        let staging = migrations_dir.join(name);
+        match std::fs::create_dir(&staging) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                return Err(Error::Config(format!(
+                    "a migration of '{name}' is already running"
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let same_dimension = dimension == config.dimension;
+        let hnsw_params = config
+            .hnsw_params
+            .filter(|_| same_dimension)
+            .unwrap_or_else(|| HnswParams::auto(dimension));
+        let built = VectorCollection::create_with_params(
+            staging.clone(),
+            dimension,
+            metric,
+            storage_mode,
+            hnsw_params,
+            config.pq_rescore_oversampling,
+        )
+        .and_then(|target| {
+            let copy = MigrationCopy {
+                source: &source,
+                target: &target,
+                transform: spec.dimension_transform.as_ref(),
+            };
+            let synced = copy.until_stable(name, &mut on_progress)?;
+            {
+                let mut target_config = target.inner.config_write();
+                target_config
+                    .indexed_fields
+                    .clone_from(&config.indexed_fields);
+                target_config.query_defaults = config.query_defaults;
+                target_config
+                    .scoring_profiles
+                    .clone_from(&config.scoring_profiles);
+                target_config
+                    .partial_indexes
+                    .clone_from(&config.partial_indexes);
+                target_config.max_query_limit = config.max_query_limit;
+                target_config.archive_policy = config.archive_policy;
+                if same_dimension {
+                    target_config
+                        .embedding_info
+                        .clone_from(&config.embedding_info);
+                }
+            }
+            target.inner.save_config()?;
+            source.inner.copy_access_log(&staging)?;
+            source.inner.copy_access_counts(&staging)?;
+            target.flush_full()?;
+            Ok(synced)
+        });
+        let (catch_up_passes, synced_generation) = match built {
+            Ok(done) => done,
+            Err(e) => {
+                self.discard_migration_dir(&staging, name);
+                return Err(e);
+            }
+        };
+
+        on_progress(MigrationProgress {
+            phase: MigrationPhase::Swapping,
+            pass: catch_up_passes,
+            copied: 0,
+            total: 0,
+        });
+        let migrated = self.swap_in_migrated(name, &source, &staging)?;
+
+        // Writes that reached the old handle while the swap was running.
+        if source.inner.write_generation() != synced_generation {
+            let copy = MigrationCopy {
+                source: &source,
+                target: &migrated,
+                transform: spec.dimension_transform.as_ref(),
+            };
+            copy.pass(
+                MigrationPhase::CatchingUp,
+                catch_up_passes + 1,
+                &mut on_progress,
+            )?;
+        }
+        drop(source);
+        let parked = migrations_dir.join(format!("{name}{PARKED_SUFFIX}"));
+        self.discard_migration_dir(&parked, name);
+
+        tracing::info!(
+            collection = name,
+            points = migrated.len(),
+            catch_up_passes,
+            "Collection migrated"
+        );
+        Ok(MigrationReport {
+            points: migrated.len(),
+            catch_up_passes,
+            storage_mode,
+            metric,
+            dimension,
+        })
+    }
+
+    /// Returns the live vector collection to migrate.
+    fn migration_source(&self, name: &str) -> Result<VectorCollection> {
+        match self.get_vector_collection(name) {
+            Some(c) if !c.is_metadata_only() => Ok(c),
+            Some(_) => Err(Error::Config(format!(
+                "'{name}' is a metadata-only collection and has no vectors to migrate"
+            ))),
+            None if self.get_any_collection(name).is_some() => Err(Error::Config(format!(
+                "only vector collections can be migrated; '{name}' is not one"
+            ))),
+            None => Err(Error::CollectionNotFound(name.to_string())),
+        }
+    }
+
+    /// Moves the staged collection into place and registers it.
+    ///
+    /// The live directory is parked under the migrations directory first
+    /// and moved back if the staged one cannot be installed or opened.
+    fn swap_in_migrated(
+        &self,
+        name: &str,
+        source: &VectorCollection,
+        staging: &Path,
+    ) -> Result<VectorCollection> {
+        let live = self.data_dir.join(name);
+        let parked = staging.with_file_name(format!("{name}{PARKED_SUFFIX}"));
+        source.flush()?;
+        std::fs::rename(&live, &parked)?;
+        let migrated = std::fs::rename(staging, &live)
+            .map_err(Error::from)
+            .and_then(|()| VectorCollection::open(live.clone()));
+        let migrated = match migrated {
+            Ok(c) => c,
+            Err(e) => {
+                if live.exists() {
+                    let _ = std::fs::rename(&live, staging);
+                }
+                std::fs::rename(&parked, &live)?;
+                self.discard_migration_dir(staging, name);
+                return Err(e);
+            }
+        };
+
+        self.push_runtime_limits(&migrated.inner);
+        self.push_storage_residency(name, &migrated.inner);
+        self.vector_colls
+            .write()
+            .insert(name.to_string(), migrated.clone());
+        self.collection_stats.write().remove(name);
+        self.schema_version
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(migrated)
+    }
+
+    /// Moves a migration directory to the trash, logging a failure.
+    fn discard_migration_dir(&self, path: &Path, name: &str) {
+        if let Err(e) = self.trash.discard(path, name) {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to discard migration directory");
+        }
+    }
+
+    /// Resolves migrations interrupted by a crash.
+    ///
+    /// A parked live directory whose collection is missing is moved back
+    /// (the crash hit between the two renames of the swap); every other
+    /// entry — staged rebuilds and parked directories already replaced —
+    /// is discarded. Called by `open` before collections are loaded.
+    pub(super) fn recover_migrations(&self) {
+        let Ok(entries) = std::fs::read_dir(self.data_dir.join(MIGRATIONS_DIR)) else {
+            return;
+        };
+        let paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+        for path in paths {
+            let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let (name, parked) = match file_name.strip_suffix(PARKED_SUFFIX) {
+                Some(name) => (name.to_string(), true),
+                None => (file_name.to_string(), false),
+            };
+            let live = self.data_dir.join(&name);
+            if parked && !live.exists() {
+                match std::fs::rename(&path, &live) {
+                    Ok(()) => {
+                        tracing::warn!(collection = %name, "Restored collection parked by an interrupted migration");
+                    }
+                    Err(e) => {
+                        tracing::error!(collection = %name, error = %e, "Failed to restore collection parked by an interrupted migration");
+                    }
+                }
+                continue;
+            }
+            self.discard_migration_dir(&path, &name);
+        }
+    }
+}
+
+/// Copies points from the live collection into the rebuilt one.
+struct MigrationCopy<'a> {
+    source: &'a VectorCollection,
+    target: &'a VectorCollection,
+    transform: Option<&'a DimensionTransform>,
+}
+
+impl MigrationCopy<'_> {
+    /// Source write generation.
+    fn generation(&self) -> u64 {
+        self.source.inner.write_generation()
+    }
+
+    /// Copies until a pass sees no concurrent write; returns the number of
+    /// catch-up passes and the source generation the target is in sync with.
+    fn until_stable(
+        &self,
+        name: &str,
+        on_progress: &mut impl FnMut(MigrationProgress),
+    ) -> Result<(usize, u64)> {
+        let mut pass = 0;
+        loop {
+            let generation = self.generation();
+            let phase = if pass == 0 {
+                MigrationPhase::Copying
+            } else {
+                MigrationPhase::CatchingUp
+            };
+            self.pass(phase, pass, on_progress)?;
+            if self.generation() == generation {
+                return Ok((pass, generation));
+            }
+            if pass == MAX_CATCH_UP_PASSES {
+                return Err(Error::Config(format!(
+                    "collection '{name}' kept changing through {MAX_CATCH_UP_PASSES} catch-up \
+                     passes; retry the migration when writes are quieter"
+                )));
+            }
+            pass += 1;
+        }
+    }
+
+    /// Upserts every source point into the target and, on catch-up passes,
+    /// deletes target points the source no longer has.
+    fn pass(
+        &self,
+        phase: MigrationPhase,
+        pass: usize,
+        on_progress: &mut impl FnMut(MigrationProgress),
+    ) -> Result<()> {
+        let ids = self.source.all_point_ids();
+        let total = ids.len();
+        let mut copied = 0;
+        let now_secs = now_unix_secs();
+        for chunk in ids.chunks(COPY_BATCH_SIZE) {
+            // `get_raw` so copying does not count as an access for the
+            // archive policy.
+            let points: Vec<Point> = self
+                .source
+                .inner
+                .get_raw(chunk)
+                .into_iter()
+                .flatten()
+                .filter(|point| !is_payload_expired(point.payload.as_ref(), now_secs))
+                .map(|mut point| {
+                    if let Some(transform) = self.transform {
+                        point.vector = transform.apply(std::mem::take(&mut point.vector));
+                    }
+                    point
+                })
+                .collect();
+            self.target.upsert_bulk(&points)?;
+            copied += chunk.len();
+            on_progress(MigrationProgress {
+                phase,
+                pass,
+                copied,
+                total,
+            });
+        }
+        if phase != MigrationPhase::Copying {
+            let live: HashSet<u64> = ids.into_iter().collect();
+            let stale: Vec<u64> = self
+                .target
+                .all_point_ids()
+                .into_iter()
+                .filter(|id| !live.contains(id))
+                .collect();
+            if !stale.is_empty() {
+                self.target.delete(&stale)?;
+            }
+        }
+        Ok(())
+    }
+}
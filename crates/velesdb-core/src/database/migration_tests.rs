@@ -0,0 +1,229 @@
+//! Tests for online collection migration (`migrate_collection`).
+
+use super::migration::MIGRATIONS_DIR;
+use super::*;
+use crate::point::Point;
+use crate::{DistanceMetric, StorageMode};
+use serde_json::json;
+use tempfile::tempdir;
+
+fn seed(db: &Database, name: &str, count: u64) {
+    db.create_collection(name, 4, DistanceMetric::Cosine)
+        .unwrap();
+    let coll = db.get_vector_collection(name).unwrap();
+    let points: Vec<Point> = (1..=count)
+        .map(|id| {
+            #[allow(clippy::cast_precision_loss)]
+            let x = id as f32;
+            Point::new(id, vec![x, 1.0, 0.5, 0.25], Some(json!({ "n": id })))
+        })
+        .collect();
+    coll.upsert_bulk(&points).unwrap();
+}
+
+fn migrations_dir_is_empty(db: &Database) -> bool {
+    db.trash.wait_idle();
+    std::fs::read_dir(db.data_dir().join(MIGRATIONS_DIR))
+        .map_or(true, |mut entries| entries.next().is_none())
+}
+
+#[test]
+fn test_migrate_storage_mode_and_metric_keeps_points_and_settings() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db, "docs", 50);
+    db.get_vector_collection("docs")
+        .unwrap()
+        .create_index("n")
+        .unwrap();
+    let mut events = Vec::new();
+
+    let report = db
+        .migrate_collection_with_progress(
+            "docs",
+            &MigrationSpec {
+                storage_mode: Some(StorageMode::SQ8),
+                metric: Some(DistanceMetric::DotProduct),
+                ..MigrationSpec::default()
+            },
+            |p| events.push(p),
+        )
+        .unwrap();
+
+    assert_eq!(report.points, 50);
+    assert_eq!(report.catch_up_passes, 0);
+    let coll = db.get_vector_collection("docs").unwrap();
+    let config = coll.config();
+    assert_eq!(config.storage_mode, StorageMode::SQ8);
+    assert_eq!(config.metric, DistanceMetric::DotProduct);
+    assert_eq!(config.name, "docs");
+    assert_eq!(coll.len(), 50);
+    assert!(coll.has_secondary_index("n"));
+    let point = coll.get(&[7])[0].clone().unwrap();
+    assert_eq!(point.payload, Some(json!({ "n": 7 })));
+    assert_eq!(
+        coll.search(&[50.0, 1.0, 0.5, 0.25], 1).unwrap()[0].point.id,
+        50
+    );
+    assert_eq!(events.last().unwrap().phase, MigrationPhase::Swapping);
+    assert!(migrations_dir_is_empty(&db));
+
+    drop(coll);
+    drop(db);
+    let db = Database::open(dir.path()).unwrap();
+    let coll = db.get_vector_collection("docs").unwrap();
+    assert_eq!(coll.config().storage_mode, StorageMode::SQ8);
+    assert_eq!(coll.len(), 50);
+}
+
+#[test]
+fn test_migrate_truncates_dimension() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db, "docs", 5);
+
+    let report = db
+        .migrate_collection(
+            "docs",
+            &MigrationSpec {
+                dimension_transform: Some(DimensionTransform::Truncate(2)),
+                ..MigrationSpec::default()
+            },
+        )
+        .unwrap();
+
+    assert_eq!(report.dimension, 2);
+    let coll = db.get_vector_collection("docs").unwrap();
+    assert_eq!(coll.dimension(), 2);
+    assert_eq!(coll.get(&[3])[0].clone().unwrap().vector, vec![3.0, 1.0]);
+}
+
+#[test]
+fn test_migrate_catches_up_with_writes_during_the_copy() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db, "docs", 10);
+    let source = db.get_vector_collection("docs").unwrap();
+    let mut wrote = false;
+
+    let report = db
+        .migrate_collection_with_progress(
+            "docs",
+            &MigrationSpec {
+                storage_mode: Some(StorageMode::SQ8),
+                ..MigrationSpec::default()
+            },
+            |p| {
+                if p.phase == MigrationPhase::Copying && !wrote {
+                    wrote = true;
+                    source
+                        .upsert(vec![Point::new(99, vec![0.0, 0.0, 1.0, 0.0], None)])
+                        .unwrap();
+                    source.delete(&[1]).unwrap();
+                }
+            },
+        )
+        .unwrap();
+
+    assert_eq!(report.catch_up_passes, 1);
+    let coll = db.get_vector_collection("docs").unwrap();
+    assert_eq!(coll.len(), 10);
+    assert!(coll.get(&[99])[0].is_some());
+    assert!(coll.get(&[1])[0].is_none());
+}
+
+#[test]
+fn test_migrate_rejects_bad_targets_and_leaves_collection_untouched() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db, "docs", 3);
+    db.create_metadata_collection("meta").unwrap();
+
+    let err = db
+        .migrate_collection(
+            "docs",
+            &MigrationSpec {
+                dimension_transform: Some(DimensionTransform::Truncate(8)),
+                ..MigrationSpec::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, Error::Config(_)));
+
+    let custom = DimensionTransform::Custom {
+        dimension: 3,
+        map: std::sync::Arc::new(|v: &[f32]| v.to_vec()),
+    };
+    let err = db
+        .migrate_collection(
+            "docs",
+            &MigrationSpec {
+                dimension_transform: Some(custom),
+                ..MigrationSpec::default()
+            },
+        )
+        .unwrap_err();
+    assert!(!matches!(err, Error::Config(_)), "{err}");
+
+    assert!(matches!(
+        db.migrate_collection("missing", &MigrationSpec::default()),
+        Err(Error::CollectionNotFound(_))
+    ));
+    assert!(matches!(
+        db.migrate_collection("meta", &MigrationSpec::default()),
+        Err(Error::Config(_))
+    ));
+
+    let coll = db.get_vector_collection("docs").unwrap();
+    assert_eq!(coll.dimension(), 4);
+    assert_eq!(coll.len(), 3);
+    assert!(migrations_dir_is_empty(&db));
+}
+
+#[test]
+fn test_open_restores_collection_parked_by_interrupted_migration() {
+    let dir = tempdir().unwrap();
+    {
+        let db = Database::open(dir.path()).unwrap();
+        seed(&db, "docs", 4);
+        db.get_vector_collection("docs").unwrap().flush().unwrap();
+    }
+    // Crash between the two renames of the swap: the live directory is
+    // parked and a staged rebuild is left beside it.
+    let migrations = dir.path().join(MIGRATIONS_DIR);
+    std::fs::create_dir_all(migrations.join("docs")).unwrap();
+    std::fs::rename(dir.path().join("docs"), migrations.join("docs.old")).unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+
+    assert_eq!(db.get_vector_collection("docs").unwrap().len(), 4);
+    assert!(migrations_dir_is_empty(&db));
+}
+
+#[test]
+fn test_migrate_rejects_collection_with_archived_points() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db, "docs", 3);
+    let coll = db.get_vector_collection("docs").unwrap();
+    coll.set_archive_policy(Some(crate::ArchivePolicy::idle_days(1)))
+        .unwrap();
+    let later = crate::collection::expiry::now_unix_secs() + 2 * 24 * 60 * 60;
+    coll.inner.archive_cold_points_at(later).unwrap();
+
+    let err = db
+        .migrate_collection("docs", &MigrationSpec::default())
+        .unwrap_err();
+
+    assert!(err.to_string().contains("archived points"), "{err}");
+    coll.restore_archived(&[1, 2, 3]).unwrap();
+    let report = db
+        .migrate_collection("docs", &MigrationSpec::default())
+        .unwrap();
+    assert_eq!(report.points, 3);
+    let migrated = db.get_vector_collection("docs").unwrap();
+    assert_eq!(
+        migrated.archive_policy(),
+        Some(crate::ArchivePolicy::idle_days(1))
+    );
+}
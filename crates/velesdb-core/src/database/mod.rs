@@ -9,10 +9,18 @@
 //! - [`query_engine`] — `VelesQL` query execution, plan caching, DML dispatch
 //! - [`query_join`] — JOIN execution strategies (lookup, filtered, condition pushdown)
 //! - [`dml_executor`] — DML mutations (INSERT EDGE, DELETE, DELETE EDGE, SELECT EDGES, INSERT NODE)
+//! - [`dry_run`] — DML dry runs reporting the points a statement would change
 //! - [`persistence`] — Loading collections from disk at startup
 //! - [`training`] — `TRAIN QUANTIZER` statement execution
 //! - [`stats`] — Collection statistics (analyze, cache)
 //! - [`database_helpers`] — DML value conversion and JOIN column store helpers
+//! - [`verify`] — Collection directory checks and repairs (`fsck`)
+//! - [`migration`] — Online rebuild to another storage mode, metric or dimension
+//! - [`views`] — Named filtered views over a collection
+//! - [`row_security`] — Per-collection row-security policies bound to a request context
+//! - [`latency_slo`] — Per-collection latency SLOs with breach/recovery alerts
+//! - [`shipping`] — Warm-standby snapshot shipping and restore
+//! - [`packed`] — Databases packed into one file for shipping to edge devices
 
 use crate::collection::{GraphCollection, MetadataCollection, VectorCollection};
 use crate::observer::DatabaseObserver;
@@ -24,20 +32,32 @@ mod collection_ops;
 mod cross_collection;
 mod ddl_executor;
 mod dml_executor;
+mod dry_run;
 mod gated_search;
+mod graph_mutation_executor;
 mod graph_ops;
+mod health;
 mod introspection_executor;
 mod join_pushdown;
+mod latency_slo;
 mod metadata_ops;
+mod migration;
+mod packed;
 mod persistence;
 mod query_engine;
 mod query_engine_agg;
 mod query_engine_dml;
 mod query_join;
+mod rewrite_rules;
+mod row_security;
+mod shipping;
 mod stats;
 mod subquery_resolver;
 mod training;
+mod trash;
 mod vector_ops;
+mod verify;
+mod views;
 
 #[cfg(feature = "persistence")]
 mod database_helpers;
@@ -51,13 +71,46 @@ mod database_tests;
 #[cfg(all(test, feature = "persistence"))]
 mod ddl_executor_tests;
 #[cfg(all(test, feature = "persistence"))]
+mod dry_run_tests;
+#[cfg(all(test, feature = "persistence"))]
 mod graph_ops_tests;
 #[cfg(all(test, feature = "persistence"))]
+mod latency_slo_tests;
+#[cfg(all(test, feature = "persistence"))]
+mod migration_tests;
+#[cfg(all(test, feature = "persistence"))]
+mod packed_tests;
+#[cfg(all(test, feature = "persistence"))]
 mod query_engine_tests;
 #[cfg(all(test, feature = "persistence"))]
+mod rewrite_rules_tests;
+#[cfg(all(test, feature = "persistence"))]
+mod row_security_tests;
+#[cfg(all(test, feature = "persistence"))]
+mod shipping_tests;
+#[cfg(all(test, feature = "persistence"))]
 mod stats_tests;
+#[cfg(all(test, feature = "persistence"))]
+mod verify_tests;
+#[cfg(all(test, feature = "persistence"))]
+mod views_tests;
 
+pub use dry_run::{DryRunReport, DRY_RUN_SAMPLE_IDS};
 pub use gated_search::GatedRead;
+pub use health::{CollectionLoadFailure, DiskSpace};
+pub use latency_slo::SloAlertHook;
+pub use migration::{
+    DimensionTransform, MigrationPhase, MigrationProgress, MigrationReport, MigrationSpec,
+    VectorMapFn, MAX_CATCH_UP_PASSES,
+};
+pub use packed::CheckpointStats;
+pub use rewrite_rules::{ForbidFullScan, QueryRewriteRule, RewriteContext};
+pub use row_security::{RequestContext, RowSecurityPolicy};
+pub use shipping::{
+    RestoreReport, ShippedSnapshot, SnapshotKind, SnapshotShipper, FULL_SNAPSHOT_EVERY,
+};
+pub use verify::{CollectionReport, RecoveryReport, VerifyIssue};
+pub use views::CollectionView;
 
 /// Database instance managing collections and storage.
 ///
@@ -74,6 +127,11 @@ pub use gated_search::GatedRead;
 pub struct Database {
     /// Path to the data directory
     data_dir: std::path::PathBuf,
+    /// Background deletion of dropped collection directories.
+    ///
+    /// Declared before `_lock_file` so that dropping the database finishes
+    /// pending deletions while the lock is still held.
+    trash: trash::Trash,
     /// Exclusive file lock preventing multi-process corruption.
     ///
     /// The lock is held for the lifetime of the `Database` and released on `Drop`.
@@ -111,6 +169,32 @@ pub struct Database {
     /// Stores recently compiled `QueryPlan` instances keyed by `PlanKey`.
     /// Default sizing: L1 = 1K hot entries, L2 = 10K LRU entries.
     compiled_plan_cache: crate::cache::CompiledPlanCache,
+    /// Collection directories that failed to open, keyed by name.
+    ///
+    /// Filled by [`Database::load_collections`]; an entry is removed once
+    /// the collection loads. Surfaced through
+    /// [`Database::collection_load_failures`] for health checks.
+    load_failures: parking_lot::RwLock<std::collections::HashMap<String, CollectionLoadFailure>>,
+    /// Named filtered views, keyed by view name. Persisted in `views.json`.
+    views: parking_lot::RwLock<std::collections::HashMap<String, views::ViewEntry>>,
+    /// Row-security policies, keyed by collection name. Persisted in
+    /// `row_security.json`.
+    row_policies: parking_lot::RwLock<std::collections::HashMap<String, row_security::PolicyEntry>>,
+    /// Latency SLO trackers, keyed by collection name. Objectives are
+    /// persisted in `latency_slos.json`.
+    latency_slos: parking_lot::RwLock<
+        std::collections::HashMap<String, std::sync::Arc<crate::metrics::SloTracker>>,
+    >,
+    /// Callback invoked on SLO breach and recovery.
+    slo_alert_hook: parking_lot::RwLock<Option<std::sync::Arc<latency_slo::SloAlertHook>>>,
+    /// Query rewrite rules run before validation, in registration order.
+    rewrite_rules: parking_lot::RwLock<Vec<std::sync::Arc<dyn rewrite_rules::QueryRewriteRule>>>,
+    /// Pack file behind a database opened with [`Database::open_packed`];
+    /// `None` for a plain data directory.
+    ///
+    /// Declared last so that, after the closing checkpoint, the working
+    /// directory is removed only once every collection has been dropped.
+    packed: Option<packed::PackedStore>,
 }
 
 #[cfg(feature = "persistence")]
@@ -220,8 +304,12 @@ impl Database {
             "SIMD features detected - direct dispatch enabled"
         );
 
+        let trash = trash::Trash::new(&data_dir);
+        trash.purge_leftovers();
+
         let db = Self {
             data_dir,
+            trash,
             _lock_file: lock_file,
             config: std::sync::Arc::new(config),
             vector_colls: parking_lot::RwLock::new(std::collections::HashMap::new()),
@@ -231,10 +319,24 @@ impl Database {
             observer,
             schema_version: std::sync::atomic::AtomicU64::new(0),
             compiled_plan_cache: crate::cache::CompiledPlanCache::new(1_000, 10_000),
+            load_failures: parking_lot::RwLock::new(std::collections::HashMap::new()),
+            views: parking_lot::RwLock::new(std::collections::HashMap::new()),
+            row_policies: parking_lot::RwLock::new(std::collections::HashMap::new()),
+            latency_slos: parking_lot::RwLock::new(std::collections::HashMap::new()),
+            slo_alert_hook: parking_lot::RwLock::new(None),
+            rewrite_rules: parking_lot::RwLock::new(Vec::new()),
+            packed: None,
         };
+        db.register_configured_rewrite_rules();
+
+        // Settle migrations interrupted by a crash before anything is loaded.
+        db.recover_migrations();
 
         // Auto-load all existing collections from disk (replaces manual load_collections()).
         db.load_collections()?;
+        db.load_views();
+        db.load_row_policies();
+        db.load_latency_slos();
 
         Ok(db)
     }
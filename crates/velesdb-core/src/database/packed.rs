@@ -0,0 +1,276 @@
+//! Packed databases: a data directory shipped and stored as one file.
+//!
+//! [`Database::open_packed`] keeps the whole database — collections,
+//! vectors, indexes, payloads, views and policies — packed in one file
+//! (see `storage::pack`), which is easier to ship, back up and manage on
+//! IoT devices than a data directory.
+//!
+//! This is packaging, not a single-file storage engine: the engine never
+//! reads the pack file directly. Opening unpacks it into an ordinary data
+//! directory next to it (`<file>.work`), where the database runs, so the
+//! device needs free disk for the unpacked database as well as the pack.
+//! [`Database::checkpoint`] packs the working directory back into the file
+//! with sequential appends of the files that changed; dropping the database
+//! checkpoints one last time and removes the working directory.
+//!
+//! Writes are durable in the pack file once checkpointed. Between
+//! checkpoints they live in the working directory's WAL: after a crash, the
+//! next open resumes from the working directory when it matches the pack,
+//! and unpacks the pack otherwise.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::collection::Collection;
+use crate::storage::atomic_write::atomic_write;
+use crate::storage::pack::{Checkpoint, Container};
+use crate::{Error, Result};
+
+use super::shipping::{relative_path, walk_files, MAX_COPY_ATTEMPTS};
+use super::trash::TRASH_DIR;
+use super::Database;
+
+pub use crate::storage::pack::CheckpointStats;
+
+/// Marker in the working directory naming the pack generation it matches.
+const MARKER_FILE: &str = ".packed.json";
+
+/// Working directory entries that are never packed.
+const SKIPPED_FILES: [&str; 2] = ["velesdb.lock", MARKER_FILE];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Marker {
+    generation: u64,
+}
+
+/// The pack file behind a packed database and its working directory.
+pub(super) struct PackedStore {
+    work_dir: PathBuf,
+    /// Holds, while locked, the write generation each collection had at
+    /// the last checkpoint.
+    container: Mutex<(Container, HashMap<String, u64>)>,
+    /// Set once the closing checkpoint succeeded; the working directory is
+    /// kept for the next open otherwise.
+    closed_cleanly: AtomicBool,
+}
+
+impl Drop for PackedStore {
+    fn drop(&mut self) {
+        if self.closed_cleanly.load(Ordering::Acquire) {
+            if let Err(e) = std::fs::remove_dir_all(&self.work_dir) {
+                tracing::warn!(dir = %self.work_dir.display(), error = %e, "failed to remove packed database working directory");
+            }
+        }
+    }
+}
+
+impl Database {
+    /// Opens or creates a database packed in the file at `path`, with the
+    /// default [`VelesConfig`](crate::config::VelesConfig).
+    ///
+    /// The file is unpacked into a working directory next to it
+    /// (`<path>.work`, returned by [`Database::data_dir`]) and the database
+    /// runs there, so it needs disk space for both. Call
+    /// [`Database::checkpoint`] to pack writes back into the file. Dropping
+    /// the database checkpoints and removes the working directory.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DatabaseLocked`] if another handle has the file open.
+    /// - [`Error::Storage`] if `path` is not a pack file or a packed file
+    ///   fails its checksum.
+    /// - Any error [`Database::open`] returns for the working directory.
+    pub fn open_packed<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_packed_impl(path.as_ref(), None)
+    }
+
+    /// Like [`Database::open_packed`], with an explicit
+    /// [`VelesConfig`](crate::config::VelesConfig).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Database::open_packed`].
+    pub fn open_packed_with_config<P: AsRef<Path>>(
+        path: P,
+        config: crate::config::VelesConfig,
+    ) -> Result<Self> {
+        Self::open_packed_impl(path.as_ref(), Some(config))
+    }
+
+    fn open_packed_impl(path: &Path, config: Option<crate::config::VelesConfig>) -> Result<Self> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut container = Container::open(path)?;
+        let work_dir = work_dir_for(path);
+        if resumable(&work_dir, container.generation()) {
+            tracing::info!(dir = %work_dir.display(), "resuming packed database from its working directory");
+        } else {
+            if work_dir.exists() {
+                std::fs::remove_dir_all(&work_dir)?;
+            }
+            std::fs::create_dir_all(&work_dir)?;
+            container.unpack_into(&work_dir)?;
+            write_marker(&work_dir, container.generation())?;
+        }
+
+        let mut db = Self::open_impl(&work_dir, None, config)?;
+        db.packed = Some(PackedStore {
+            work_dir,
+            container: Mutex::new((container, HashMap::new())),
+            closed_cleanly: AtomicBool::new(false),
+        });
+        Ok(db)
+    }
+
+    /// Packs the working directory of a packed database back into its file.
+    ///
+    /// Every collection is flushed, then the files that changed since the
+    /// last checkpoint are appended to the pack file, followed by a new
+    /// directory. A crash mid-checkpoint leaves the previous checkpoint in
+    /// place. When superseded pages outnumber live ones the file is
+    /// compacted.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] if the database was not opened with
+    ///   [`Database::open_packed`].
+    /// - [`Error::Storage`] if a collection kept changing while it was
+    ///   copied.
+    /// - An I/O error if a file cannot be read or the pack file written.
+    pub fn checkpoint(&self) -> Result<CheckpointStats> {
+        let Some(store) = &self.packed else {
+            return Err(Error::Config(
+                "checkpoint() requires a database opened with Database::open_packed".to_string(),
+            ));
+        };
+        let mut guard = store.container.lock();
+        let (container, checkpointed) = &mut *guard;
+        let mut checkpoint = container.begin();
+
+        let collections = self.shippable_collections();
+        for path in walk_files(&self.data_dir)? {
+            let relative = relative_path(&self.data_dir, &path);
+            let top = relative.split('/').next().unwrap_or_default();
+            if is_skipped(&relative) || collections.iter().any(|(name, _)| name == top) {
+                continue;
+            }
+            stage_file(&mut checkpoint, &relative, &path)?;
+        }
+        let mut generations = HashMap::with_capacity(collections.len());
+        for (name, collection) in &collections {
+            let previous = checkpointed.get(name).copied();
+            let generation =
+                stage_collection(&mut checkpoint, &self.data_dir, name, collection, previous)?;
+            generations.insert(name.clone(), generation);
+        }
+
+        let stats = checkpoint.commit()?;
+        *checkpointed = generations;
+        write_marker(&store.work_dir, stats.generation)?;
+        Ok(stats)
+    }
+}
+
+impl Drop for Database {
+    fn drop(&mut self) {
+        let Some(store) = &self.packed else {
+            return;
+        };
+        match self.checkpoint() {
+            Ok(_) => store.closed_cleanly.store(true, Ordering::Release),
+            Err(e) => {
+                tracing::error!(dir = %store.work_dir.display(), error = %e, "closing checkpoint failed; keeping the working directory for the next open");
+            }
+        }
+    }
+}
+
+/// Stages the files of `collection`, retrying while writes land on it, and
+/// returns the write generation they hold.
+///
+/// The collection is flushed first unless no write reached it since the
+/// `previous` checkpoint: a flush rewrites the HNSW graph, which would
+/// otherwise be appended again by every checkpoint.
+fn stage_collection(
+    checkpoint: &mut Checkpoint<'_>,
+    data_dir: &Path,
+    name: &str,
+    collection: &Collection,
+    previous: Option<u64>,
+) -> Result<u64> {
+    let dir = data_dir.join(name);
+    let prefix = format!("{name}/");
+    for _ in 0..MAX_COPY_ATTEMPTS {
+        let generation = collection.write_generation();
+        if previous != Some(generation) {
+            collection.flush_full()?;
+        }
+        if !dir.exists() {
+            // Dropped while the checkpoint ran.
+            return Ok(generation);
+        }
+        for path in walk_files(&dir)? {
+            let relative = relative_path(data_dir, &path);
+            if !is_skipped(&relative) {
+                stage_file(checkpoint, &relative, &path)?;
+            }
+        }
+        if collection.write_generation() == generation {
+            return Ok(generation);
+        }
+        checkpoint.discard_prefix(&prefix);
+    }
+    Err(Error::Storage(format!(
+        "collection '{name}' kept changing during the checkpoint"
+    )))
+}
+
+/// Stages one file, skipping it if it was removed meanwhile.
+fn stage_file(checkpoint: &mut Checkpoint<'_>, relative: &str, path: &Path) -> Result<()> {
+    match checkpoint.add_file(relative, path) {
+        Err(Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
+}
+
+/// Lock, marker, trash and in-flight temp files stay out of the pack.
+fn is_skipped(relative: &str) -> bool {
+    let file_name = relative.rsplit('/').next().unwrap_or_default();
+    SKIPPED_FILES.contains(&relative)
+        || relative.starts_with(&format!("{TRASH_DIR}/"))
+        || file_name.contains(".tmp.")
+}
+
+/// `<path>.work`, next to the pack file.
+fn work_dir_for(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".work");
+    path.with_file_name(name)
+}
+
+/// Whether a working directory left by a crash can be reopened as is.
+///
+/// The pack only ever receives content from this working directory, so one
+/// whose marker names the pack generation — or the one before, if
+/// the crash hit between a commit and the marker update — holds everything
+/// the pack does and possibly later writes.
+fn resumable(work_dir: &Path, generation: u64) -> bool {
+    std::fs::read(work_dir.join(MARKER_FILE))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<Marker>(&bytes).ok())
+        .is_some_and(|marker| {
+            marker.generation == generation || marker.generation + 1 == generation
+        })
+}
+
+fn write_marker(work_dir: &Path, generation: u64) -> Result<()> {
+    let bytes = serde_json::to_vec(&Marker { generation })
+        .map_err(|e| Error::Serialization(e.to_string()))?;
+    atomic_write(&work_dir.join(MARKER_FILE), &bytes)?;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! Tests for packed databases (`open_packed`, `checkpoint`).
+
+use std::path::Path;
+
+use super::*;
+use crate::{DistanceMetric, Point};
+use tempfile::tempdir;
+
+fn point(id: u64) -> Point {
+    #[allow(clippy::cast_precision_loss)]
+    let x = id as f32;
+    Point::new(id, vec![x, 1.0], Some(serde_json::json!({ "id": id })))
+}
+
+fn has_point(db: &Database, collection: &str, id: u64) -> bool {
+    db.get_vector_collection(collection)
+        .unwrap()
+        .get(&[id])
+        .into_iter()
+        .flatten()
+        .next()
+        .is_some()
+}
+
+fn copy_tree(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            copy_tree(&entry.path(), &target);
+        } else {
+            std::fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_packed_survives_close_and_reopen() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("edge.veles");
+    {
+        let db = Database::open_packed(&file).unwrap();
+        assert_eq!(db.data_dir(), dir.path().join("edge.veles.work"));
+        db.create_collection("docs", 2, DistanceMetric::Cosine)
+            .unwrap();
+        db.get_vector_collection("docs")
+            .unwrap()
+            .upsert(vec![point(1), point(2)])
+            .unwrap();
+    }
+    // Closing checkpoints and leaves the pack file only.
+    assert!(file.is_file());
+    assert!(!dir.path().join("edge.veles.work").exists());
+
+    let db = Database::open_packed(&file).unwrap();
+    assert!(has_point(&db, "docs", 1));
+    assert!(has_point(&db, "docs", 2));
+
+    // A second checkpoint without writes appends no collection file.
+    let idle = db.checkpoint().unwrap();
+    let again = db.checkpoint().unwrap();
+    assert_eq!(again.generation, idle.generation + 1);
+    assert_eq!(again.files_written, 0);
+    assert!(again.files_unchanged > 0);
+}
+
+#[test]
+fn test_packed_resumes_working_directory_after_crash() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("edge.veles");
+    let crashed = dir.path().join("crashed");
+    {
+        let db = Database::open_packed(&file).unwrap();
+        db.create_collection("docs", 2, DistanceMetric::Cosine)
+            .unwrap();
+        let docs = db.get_vector_collection("docs").unwrap();
+        docs.upsert(vec![point(1)]).unwrap();
+        db.checkpoint().unwrap();
+        docs.upsert(vec![point(2)]).unwrap();
+        db.flush_all();
+
+        // Image of the disk as a crash would leave it: point 2 is only in
+        // the working directory.
+        std::fs::create_dir_all(&crashed).unwrap();
+        std::fs::copy(&file, crashed.join("edge.veles")).unwrap();
+        copy_tree(db.data_dir(), &crashed.join("edge.veles.work"));
+    }
+
+    let db = Database::open_packed(crashed.join("edge.veles")).unwrap();
+    assert!(has_point(&db, "docs", 1));
+    assert!(has_point(&db, "docs", 2));
+}
+
+#[test]
+fn test_packed_rejects_second_handle_and_plain_checkpoint() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("edge.veles");
+    let _db = Database::open_packed(&file).unwrap();
+    assert!(matches!(
+        Database::open_packed(&file),
+        Err(Error::DatabaseLocked(_))
+    ));
+
+    let plain = Database::open(dir.path().join("plain")).unwrap();
+    assert!(matches!(plain.checkpoint(), Err(Error::Config(_))));
+}
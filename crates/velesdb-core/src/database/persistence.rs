@@ -93,6 +93,7 @@ impl Database {
             Ok(d) => d,
             Err(e) => {
                 tracing::warn!(error = %e, name, "Cannot read config.json — skipping");
+                self.record_load_failure(name, format!("cannot read config.json: {e}"));
                 return false;
             }
         };
@@ -100,6 +101,7 @@ impl Database {
             Ok(c) => c,
             Err(e) => {
                 tracing::warn!(error = %e, name, "Cannot parse config.json — skipping");
+                self.record_load_failure(name, format!("cannot parse config.json: {e}"));
                 return false;
             }
         };
@@ -137,7 +139,8 @@ impl Database {
     /// Opens a collection from disk and registers it in the typed registry.
     ///
     /// The `open_fn` closure returns a `TypedColl` variant.
-    /// Returns `true` on success, `false` on failure (logged as warning).
+    /// Returns `true` on success, `false` on failure (logged as warning and
+    /// recorded in the load-failure registry).
     fn try_open_and_register(
         &self,
         path: &std::path::Path,
@@ -150,12 +153,19 @@ impl Database {
                 // Parity item E: thread the live LimitsConfig caps into the
                 // startup-loaded collection (not persisted — re-pushed here).
                 self.push_runtime_limits(typed.inner());
+                if !matches!(typed, TypedColl::Metadata(_)) {
+                    self.push_storage_residency(name, typed.inner());
+                    if self.config.hnsw.verify_on_open {
+                        warn_on_damaged_graph(name, typed.inner());
+                    }
+                }
                 typed.insert_into(
                     &self.vector_colls,
                     &self.graph_colls,
                     &self.metadata_colls,
                     name,
                 );
+                self.load_failures.write().remove(name);
                 true
             }
             Err(e) => {
@@ -164,11 +174,23 @@ impl Database {
                     name = %path.display(),
                     "Failed to load {kind} collection"
                 );
+                self.record_load_failure(name, format!("failed to open {kind} collection: {e}"));
                 false
             }
         }
     }
 
+    /// Remembers that `name` could not be loaded, replacing any earlier entry.
+    fn record_load_failure(&self, name: &str, error: String) {
+        self.load_failures.write().insert(
+            name.to_string(),
+            super::CollectionLoadFailure {
+                name: name.to_string(),
+                error,
+            },
+        );
+    }
+
     /// Flushes all collections including `vectors.idx` serialization.
     ///
     /// Issue #423: Uses `flush_full()` to ensure the vector index file is
@@ -193,6 +215,18 @@ impl Database {
     }
 }
 
+/// Logs the defects of `coll`'s HNSW graph (`[hnsw] verify_on_open`).
+fn warn_on_damaged_graph(name: &str, coll: &crate::collection::Collection) {
+    let report = coll.verify_index_graph();
+    if !report.is_healthy() {
+        tracing::warn!(
+            collection = %name,
+            problems = ?report.problems(),
+            "HNSW graph failed its integrity check at open"
+        );
+    }
+}
+
 /// Discriminated union for the three typed collection registries.
 ///
 /// Used by [`Database::try_open_and_register`] to route a freshly opened
@@ -256,6 +256,18 @@ impl Database {
     pub fn explain_query(
         &self,
         query: &crate::velesql::Query,
+    ) -> Result<crate::velesql::QueryPlan> {
+        if let Some(rewritten) = self.resolve_views(query)? {
+            return self.explain_query(&rewritten);
+        }
+        let query = self.apply_rewrite_rules(query)?;
+        self.explain_rewritten_query(&query)
+    }
+
+    /// [`Self::explain_query`] once views and rewrite rules are applied.
+    fn explain_rewritten_query(
+        &self,
+        query: &crate::velesql::Query,
     ) -> Result<crate::velesql::QueryPlan> {
         crate::velesql::QueryValidator::validate(query).map_err(|e| Error::Query(e.to_string()))?;
 
@@ -322,11 +334,13 @@ impl Database {
         query: &Query,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<ExplainOutput> {
-        crate::velesql::QueryValidator::validate(query).map_err(|e| Error::Query(e.to_string()))?;
-
-        let plan = self.explain_query(query)?;
+        if let Some(rewritten) = self.resolve_views(query)? {
+            return self.explain_analyze_query(&rewritten, params);
+        }
+        let query = self.apply_rewrite_rules(query)?;
+        let plan = self.explain_rewritten_query(&query)?;
         let start = std::time::Instant::now();
-        let (results, nodes, edges) = self.execute_query_counted(query, params)?;
+        let (results, nodes, edges) = self.execute_query_counted(&query, params)?;
         let stats = ActualStats::from_counted(results.len() as u64, start.elapsed(), nodes, edges);
         let node_stats = crate::velesql::build_leaf_node_stats(
             &plan.root,
@@ -350,11 +364,18 @@ impl Database {
         query: &crate::velesql::Query,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<Vec<SearchResult>> {
+        // Read views from their base collections, narrowed by the view
+        // predicate, before anything (gate, plan cache) sees the query.
+        if let Some(rewritten) = self.resolve_views(query)? {
+            return self.execute_query(&rewritten, params);
+        }
         // Resolve scalar subqueries (EPIC-039) into literals *before* validation
         // so the validator and every downstream path see a subquery-free AST.
         if let Some(rewritten) = self.resolve_subqueries(query, params)? {
             return self.execute_query(&rewritten, params);
         }
+        // Deployment rewrite rules see the view- and subquery-free AST.
+        let query = &*self.apply_rewrite_rules(query)?;
 
         crate::velesql::QueryValidator::validate(query).map_err(|e| Error::Query(e.to_string()))?;
 
@@ -383,8 +404,11 @@ impl Database {
     /// re-enter `execute_single_select` are folded into this one measurement
     /// and never fire their own telemetry.
     ///
-    /// When no observer is registered, this is a single `Option` presence check
-    /// with no timer and no notification beyond that check (Requirement 2.4).
+    /// The same measurement is recorded against the latency SLO of the source
+    /// collection, if it has one.
+    ///
+    /// When no observer is registered and the collection has no SLO, this is
+    /// a presence check with no timer and no notification (Requirement 2.4).
     /// The duration is reported in microseconds; `elapsed().as_micros()` is a
     /// `u128`, converted with a bounds-guarded `try_from` that saturates at
     /// `u64::MAX` rather than panicking (no `unwrap`/`expect`).
@@ -393,13 +417,21 @@ impl Database {
         query: &crate::velesql::Query,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<Vec<SearchResult>> {
-        let Some(observer) = self.observer.as_ref() else {
+        let collection = query.select.from.as_str();
+        let has_slo = self.has_latency_slo(collection);
+        if self.observer.is_none() && !has_slo {
             return self.execute_query_inner(query, params); // zero-overhead fast path
-        };
+        }
         let started = std::time::Instant::now();
         let results = self.execute_query_inner(query, params)?;
-        let duration_us = u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX);
-        observer.on_query(query.select.from.as_str(), duration_us);
+        let elapsed = started.elapsed();
+        if let Some(observer) = self.observer.as_ref() {
+            let duration_us = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+            observer.on_query(collection, duration_us);
+        }
+        if has_slo {
+            self.record_search_latency(collection, elapsed);
+        }
         Ok(results)
     }
 
@@ -656,31 +688,42 @@ impl Database {
         // EPIC-040 US-006: For compound queries, strip LIMIT from each operand so
         // the set operation sees the full result sets.  The final LIMIT is applied
         // once on the merged output (SQL-standard behaviour).
-        // Use MAX_LIMIT (not None) to avoid the default-10 cap downstream.
-        const COMPOUND_LIMIT: usize = 100_000;
-        let compound_limit = Some(COMPOUND_LIMIT as u64); // 100_000 fits u64 exactly.
-        let left_results = if query.compound.is_some() {
-            let mut left_query = query.clone();
-            left_query.select.limit = compound_limit;
-            self.execute_single_select(&left_query, params)?
-        } else {
+        // Each operand runs at its collection's query limit ceiling (not None)
+        // to avoid the default-10 cap downstream.
+        if query.compound.is_none() {
             return self.execute_single_select(query, params);
-        };
+        }
+        let base = self.resolve_collection(&query.select.from)?;
+        base.check_query_limits(query)?;
+        let max = base.max_query_limit();
+        // Leading-SELECT `set_score`: how UNION / INTERSECT merge scores.
+        let merge = crate::collection::search::query::set_operations::ScoreMerge::from_with_clause(
+            query.select.with_clause.as_ref(),
+        )?;
+        let mut left_query = query.clone();
+        left_query.select.limit = Some(u64::try_from(max).unwrap_or(u64::MAX));
+        let left_results = self.execute_single_select(&left_query, params)?;
 
         // compound is guaranteed Some here (non-compound returns above).
         if let Some(ref compound) = query.compound {
             let mut accumulated = left_results;
             for (operator, right_select) in &compound.operations {
                 let mut right_query = crate::velesql::Query::new_select(right_select.clone());
-                right_query.select.limit = compound_limit;
+                let right_coll = self.resolve_collection(&right_select.from)?;
+                if let Some(limit) = right_select.limit {
+                    right_coll.check_query_limit(limit)?;
+                }
+                let right_max = right_coll.max_query_limit();
+                right_query.select.limit = Some(u64::try_from(right_max).unwrap_or(u64::MAX));
                 let right_results = self.execute_single_select(&right_query, params)?;
                 accumulated = crate::collection::search::query::set_operations::apply_set_operation(
                     accumulated,
                     right_results,
                     *operator,
+                    merge,
                     // Intermediate ops keep the server-side ceiling: truncating to the
                     // user LIMIT here would drop rows a later chained set op still needs.
-                    COMPOUND_LIMIT,
+                    max,
                 );
             }
             // SQL-standard: LIMIT from the left (outer) SELECT applies to the final result.
@@ -877,7 +920,7 @@ impl Database {
         self.compiled_plan_cache.insert(post_exec_key, compiled);
     }
 
-    /// Dispatches a DML statement (INSERT, UPSERT, UPDATE, DELETE, or edge mutations).
+    /// Dispatches a DML statement (INSERT, UPSERT, UPDATE, DELETE, or graph mutations).
     pub(super) fn execute_dml(
         &self,
         dml: &crate::velesql::DmlStatement,
@@ -888,10 +931,13 @@ impl Database {
             | crate::velesql::DmlStatement::Upsert(stmt) => self.execute_insert(stmt, params),
             crate::velesql::DmlStatement::Update(stmt) => self.execute_update(stmt, params),
             crate::velesql::DmlStatement::InsertEdge(stmt) => self.execute_insert_edge(stmt),
-            crate::velesql::DmlStatement::Delete(stmt) => self.execute_delete(stmt),
+            crate::velesql::DmlStatement::Delete(stmt) => self.execute_delete(stmt, params),
             crate::velesql::DmlStatement::DeleteEdge(stmt) => self.execute_delete_edge(stmt),
             crate::velesql::DmlStatement::SelectEdges(stmt) => self.execute_select_edges(stmt),
             crate::velesql::DmlStatement::InsertNode(stmt) => self.execute_insert_node(stmt),
+            crate::velesql::DmlStatement::GraphMutation(stmt) => {
+                self.execute_graph_mutation(stmt, params)
+            }
         }
     }
 }
@@ -30,11 +30,15 @@ impl Database {
         query: &crate::velesql::Query,
         params: &HashMap<String, serde_json::Value>,
     ) -> Result<serde_json::Value> {
+        if let Some(rewritten) = self.resolve_views(query)? {
+            return self.execute_aggregate(&rewritten, params);
+        }
         // Resolve scalar subqueries (EPIC-039) in WHERE/HAVING before validation
         // so the aggregate engine sees a subquery-free AST.
         if let Some(rewritten) = self.resolve_subqueries(query, params)? {
             return self.execute_aggregate(&rewritten, params);
         }
+        let query = &*self.apply_rewrite_rules(query)?;
         crate::velesql::QueryValidator::validate(query).map_err(|e| Error::Query(e.to_string()))?;
         let name = aggregate_target_collection(query, params)?;
         let collection = self
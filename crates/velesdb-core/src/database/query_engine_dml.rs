@@ -3,10 +3,20 @@
 //! Extracted from `query_engine.rs` to keep that module focused on
 //! query dispatch, plan caching, and SELECT execution.
 
+use crate::column_store::{apply_arith, ColumnArithOp, ColumnValue};
+use crate::velesql::{ArithmeticExpr, ArithmeticOp};
 use crate::{Error, Result, SearchResult};
 
 use super::Database;
 
+/// Right-hand side of a resolved UPDATE assignment.
+enum AssignedValue {
+    /// Literal or bound parameter, identical for every row.
+    Fixed(serde_json::Value),
+    /// Arithmetic over the row's current payload (`stock - 1`).
+    Expr(ArithmeticExpr),
+}
+
 impl Database {
     /// Executes an INSERT or UPSERT statement (single or multi-row).
     pub(super) fn execute_insert(
@@ -14,6 +24,29 @@ impl Database {
         stmt: &crate::velesql::InsertStatement,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<Vec<SearchResult>> {
+        let (collection, points) = self.prepare_insert(stmt, params)?;
+
+        let results: Vec<SearchResult> = points
+            .iter()
+            .map(|p| SearchResult::new(p.clone(), 0.0))
+            .collect();
+        let point_count = points.len();
+        collection.upsert(points)?;
+        // Requirement 2.1/2.3/2.5: fire `on_upsert` exactly once at the Database
+        // DML use-case entry after the write completes, with the exact affected
+        // point count. Placed here (not inside `Collection::upsert`) so the
+        // programmatic collection API is not double-counted by this path.
+        self.fire_on_upsert(&stmt.table, point_count);
+        Ok(results)
+    }
+
+    /// Resolves the target collection and builds the points an INSERT or
+    /// UPSERT writes, without writing them.
+    pub(super) fn prepare_insert(
+        &self,
+        stmt: &crate::velesql::InsertStatement,
+        params: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<(crate::collection::Collection, Vec<crate::Point>)> {
         let collection = self.resolve_writable_collection(&stmt.table)?;
 
         let mut points = Vec::with_capacity(stmt.rows.len());
@@ -28,19 +61,7 @@ impl Database {
                 payload,
             )?);
         }
-
-        let results: Vec<SearchResult> = points
-            .iter()
-            .map(|p| SearchResult::new(p.clone(), 0.0))
-            .collect();
-        let point_count = points.len();
-        collection.upsert(points)?;
-        // Requirement 2.1/2.3/2.5: fire `on_upsert` exactly once at the Database
-        // DML use-case entry after the write completes, with the exact affected
-        // point count. Placed here (not inside `Collection::upsert`) so the
-        // programmatic collection API is not double-counted by this path.
-        self.fire_on_upsert(&stmt.table, point_count);
-        Ok(results)
+        Ok((collection, points))
     }
 
     /// Resolves column values from a single row into id, vector, and payload fields.
@@ -109,15 +130,7 @@ impl Database {
         stmt: &crate::velesql::UpdateStatement,
         params: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<Vec<SearchResult>> {
-        let collection = self.resolve_writable_collection(&stmt.table)?;
-
-        let assignments = Self::resolve_update_assignments(stmt, params)?;
-        let filter = Self::build_update_filter(stmt.where_clause.as_ref(), params)?;
-
-        let all_ids = collection.all_ids();
-        let rows = collection.get(&all_ids);
-        let updated_points =
-            Self::apply_update_assignments(&collection, rows, filter.as_ref(), &assignments)?;
+        let (collection, updated_points) = self.prepare_update(stmt, params)?;
 
         let point_count = updated_points.len();
         let results = Self::upsert_and_collect(&collection, updated_points)?;
@@ -131,15 +144,41 @@ impl Database {
         Ok(results)
     }
 
+    /// Resolves the target collection and computes the rows an UPDATE
+    /// rewrites, without writing them.
+    pub(super) fn prepare_update(
+        &self,
+        stmt: &crate::velesql::UpdateStatement,
+        params: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<(crate::collection::Collection, Vec<crate::Point>)> {
+        let collection = self.resolve_writable_collection(&stmt.table)?;
+
+        let assignments = Self::resolve_update_assignments(stmt, params)?;
+        let filter = Self::build_update_filter(stmt.where_clause.as_ref(), params)?
+            .map(|f| f.with_collation(collection.default_collation()));
+
+        let all_ids = collection.all_ids();
+        let rows = collection.get(&all_ids);
+        let updated_points =
+            Self::apply_update_assignments(&collection, rows, filter.as_ref(), &assignments)?;
+        Ok((collection, updated_points))
+    }
+
     /// Resolves and validates UPDATE assignment values.
     fn resolve_update_assignments(
         stmt: &crate::velesql::UpdateStatement,
         params: &std::collections::HashMap<String, serde_json::Value>,
-    ) -> Result<Vec<(String, serde_json::Value)>> {
+    ) -> Result<Vec<(String, AssignedValue)>> {
         let assignments = stmt
             .assignments
             .iter()
-            .map(|a| Ok((a.column.clone(), Self::resolve_dml_value(&a.value, params)?)))
+            .map(|a| {
+                let value = match &a.expr {
+                    Some(expr) => AssignedValue::Expr(expr.clone()),
+                    None => AssignedValue::Fixed(Self::resolve_dml_value(&a.value, params)?),
+                };
+                Ok((a.column.clone(), value))
+            })
             .collect::<Result<Vec<_>>>()?;
 
         if assignments.iter().any(|(name, _)| name == "id") {
@@ -167,11 +206,14 @@ impl Database {
     }
 
     /// Applies field assignments to matching points, producing updated points.
+    ///
+    /// Every row is computed before anything is written, so an expression
+    /// that fails on one row aborts the statement with no row changed.
     fn apply_update_assignments(
         collection: &crate::collection::Collection,
         rows: Vec<Option<crate::Point>>,
         filter: Option<&crate::Filter>,
-        assignments: &[(String, serde_json::Value)],
+        assignments: &[(String, AssignedValue)],
     ) -> Result<Vec<crate::Point>> {
         let mut updated_points = Vec::new();
         for point in rows.into_iter().flatten() {
@@ -188,20 +230,34 @@ impl Database {
     }
 
     /// Applies field assignments to a single point, producing the updated point.
+    ///
+    /// Expressions read the point's values from before the update, so
+    /// `SET a = b, b = a` swaps the two fields.
     fn apply_assignments_to_point(
         collection: &crate::collection::Collection,
         point: &crate::Point,
-        assignments: &[(String, serde_json::Value)],
+        assignments: &[(String, AssignedValue)],
     ) -> Result<crate::Point> {
-        let mut payload_map = point
+        let original = point
             .payload
             .as_ref()
             .and_then(serde_json::Value::as_object)
             .cloned()
             .unwrap_or_default();
+        let mut payload_map = original.clone();
         let mut updated_vector = point.vector.clone();
 
-        for (field, value) in assignments {
+        for (field, assigned) in assignments {
+            let computed;
+            let value = match assigned {
+                AssignedValue::Fixed(value) => value,
+                AssignedValue::Expr(expr) => {
+                    computed = Self::eval_update_expr(expr, point.id, &original)
+                        .and_then(|v| column_value_to_json(&v))
+                        .map_err(|e| Error::Query(format!("UPDATE SET {field}: {e}")))?;
+                    &computed
+                }
+            };
             if field == "vector" {
                 if collection.is_metadata_only() {
                     return Err(Error::Query(
@@ -224,4 +280,76 @@ impl Database {
             )
         })
     }
+
+    /// Evaluates an UPDATE arithmetic expression against a row's payload.
+    ///
+    /// Missing and `null` fields evaluate to null, which propagates through
+    /// the expression; `id` falls back to the point id when the payload has
+    /// no such field.
+    fn eval_update_expr(
+        expr: &ArithmeticExpr,
+        id: u64,
+        payload: &serde_json::Map<String, serde_json::Value>,
+    ) -> std::result::Result<ColumnValue, String> {
+        match expr {
+            ArithmeticExpr::Literal(v) => Ok(literal_value(*v)),
+            ArithmeticExpr::Variable(name) => match payload.get(name) {
+                None if name == "id" => i64::try_from(id)
+                    .map(ColumnValue::Int)
+                    .map_err(|_| format!("id {id} is out of range")),
+                None | Some(serde_json::Value::Null) => Ok(ColumnValue::Null),
+                Some(serde_json::Value::Number(n)) => Ok(n
+                    .as_i64()
+                    .map(ColumnValue::Int)
+                    .or_else(|| n.as_f64().map(ColumnValue::Float))
+                    .unwrap_or(ColumnValue::Null)),
+                Some(_) => Err(format!("field '{name}' is not numeric")),
+            },
+            ArithmeticExpr::Similarity(_) => {
+                Err("similarity() cannot be used in an UPDATE expression".to_string())
+            }
+            ArithmeticExpr::Text(_) => {
+                Err("string literals cannot be used in an UPDATE expression".to_string())
+            }
+            ArithmeticExpr::Function { function, .. } => Err(format!(
+                "{}() cannot be used in an UPDATE expression",
+                function.name()
+            )),
+            ArithmeticExpr::BinaryOp { left, op, right } => {
+                let left = Self::eval_update_expr(left, id, payload)?;
+                let right = Self::eval_update_expr(right, id, payload)?;
+                let op = match op {
+                    ArithmeticOp::Add => ColumnArithOp::Add,
+                    ArithmeticOp::Sub => ColumnArithOp::Sub,
+                    ArithmeticOp::Mul => ColumnArithOp::Mul,
+                    ArithmeticOp::Div => ColumnArithOp::Div,
+                    ArithmeticOp::Concat => {
+                        return Err("|| cannot be used in an UPDATE expression".to_string())
+                    }
+                };
+                apply_arith(op, &left, &right).map_err(|e| e.to_string())
+            }
+        }
+    }
+}
+
+/// Integral literals stay integers so `stock - 1` keeps `stock` an integer.
+fn literal_value(v: f64) -> ColumnValue {
+    // 2^53: beyond this, f64 no longer represents every integer exactly.
+    const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+    if v.fract() == 0.0 && v.abs() <= MAX_EXACT {
+        #[allow(clippy::cast_possible_truncation)] // Reason: integral and within ±2^53.
+        return ColumnValue::Int(v as i64);
+    }
+    ColumnValue::Float(v)
+}
+
+fn column_value_to_json(value: &ColumnValue) -> std::result::Result<serde_json::Value, String> {
+    match value {
+        ColumnValue::Int(v) => Ok((*v).into()),
+        ColumnValue::Float(v) => serde_json::Number::from_f64(*v)
+            .map(serde_json::Value::Number)
+            .ok_or_else(|| "result is not a finite number".to_string()),
+        _ => Ok(serde_json::Value::Null),
+    }
 }
@@ -182,6 +182,99 @@ fn test_execute_query_update_modifies_payload() {
     assert_eq!(payload["count"], serde_json::json!(0));
 }
 
+// =========================================================================
+// Array parameters in IN / CONTAINS ANY
+// =========================================================================
+
+fn seed_items(db: &Database, count: u64) -> crate::collection::MetadataCollection {
+    db.create_collection_typed("items", &crate::CollectionType::MetadataOnly)
+        .unwrap();
+    let coll = db.get_metadata_collection("items").unwrap();
+    let points: Vec<Point> = (0..count)
+        .map(|id| {
+            let status = if id % 2 == 0 { "even" } else { "odd" };
+            Point::metadata_only(id, serde_json::json!({ "status": status, "n": id }))
+        })
+        .collect();
+    coll.upsert_metadata(points).unwrap();
+    coll
+}
+
+fn sorted_ids(results: &[crate::SearchResult]) -> Vec<u64> {
+    let mut ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn test_execute_query_in_array_parameter_with_10k_values() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed_items(&db, 100);
+
+    // 10k candidates, only five of which exist in the collection.
+    let wanted: Vec<u64> = (0..10_000).map(|i| i * 20 + 3).collect();
+    let params = std::collections::HashMap::from([("ns".to_string(), serde_json::json!(wanted))]);
+    let query = Parser::parse("SELECT * FROM items WHERE n IN $ns LIMIT 100").unwrap();
+    let results = db.execute_query(&query, &params).unwrap();
+
+    assert_eq!(sorted_ids(&results), vec![3, 23, 43, 63, 83]);
+}
+
+#[test]
+fn test_execute_query_in_list_splices_array_parameter() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed_items(&db, 10);
+
+    let params = std::collections::HashMap::from([
+        ("ns".to_string(), serde_json::json!([1, 2])),
+        ("m".to_string(), serde_json::json!(7)),
+    ]);
+    let query = Parser::parse("SELECT * FROM items WHERE n NOT IN ($ns, $m, 9) LIMIT 100").unwrap();
+    let results = db.execute_query(&query, &params).unwrap();
+
+    assert_eq!(sorted_ids(&results), vec![0, 3, 4, 5, 6, 8]);
+}
+
+#[test]
+fn test_execute_query_in_parameter_rejects_nested_arrays() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed_items(&db, 4);
+
+    let params =
+        std::collections::HashMap::from([("ns".to_string(), serde_json::json!([1, [2, 3]]))]);
+    let query = Parser::parse("SELECT * FROM items WHERE n IN $ns").unwrap();
+    let err = db.execute_query(&query, &params).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("Unsupported parameter type for $ns"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_execute_query_delete_in_array_parameter() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    let coll = seed_items(&db, 5);
+
+    let params =
+        std::collections::HashMap::from([("ids".to_string(), serde_json::json!([1, 3, 42]))]);
+    let query = Parser::parse("DELETE FROM items WHERE id IN $ids").unwrap();
+    db.execute_query(&query, &params).unwrap();
+
+    let remaining: Vec<u64> = coll
+        .get(&[0, 1, 2, 3, 4])
+        .into_iter()
+        .flatten()
+        .map(|p| p.id)
+        .collect();
+    assert_eq!(remaining, vec![0, 2, 4]);
+}
+
 // =========================================================================
 // Schema version interaction with plan cache
 // =========================================================================
@@ -550,3 +643,51 @@ fn test_explain_analyze_match_is_gated() {
         "Deny observer must refuse EXPLAIN ANALYZE MATCH — no gate bypass"
     );
 }
+
+#[test]
+fn test_compound_query_set_score_merges_cross_collection_scores() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    for (name, points) in [
+        ("news", [(1, [1.0, 0.0]), (2, [0.8, 0.6])]),
+        ("blogs", [(2, [1.0, 0.0]), (3, [0.6, 0.8])]),
+    ] {
+        db.create_collection(name, 2, DistanceMetric::Cosine)
+            .unwrap();
+        db.get_vector_collection(name)
+            .unwrap()
+            .upsert(
+                points
+                    .iter()
+                    .map(|(id, v)| Point::new(*id, v.to_vec(), Some(serde_json::json!({}))))
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap();
+    }
+    let params =
+        std::collections::HashMap::from([("v".to_string(), serde_json::json!([1.0, 0.0]))]);
+    let run = |set_score: &str| {
+        let sql = format!(
+            "SELECT * FROM news WHERE vector NEAR $v LIMIT 3 WITH (set_score = '{set_score}') \
+             UNION SELECT * FROM blogs WHERE vector NEAR $v LIMIT 3"
+        );
+        db.execute_query(&Parser::parse(&sql).unwrap(), &params)
+    };
+
+    // `sum` ranks the id both collections return first.
+    let results = run("sum").unwrap();
+    let ids: Vec<u64> = results.iter().map(|r| r.point.id).collect();
+    assert_eq!(ids, vec![2, 1, 3]);
+    assert!(
+        (results[0].score - 1.8).abs() < 1e-5,
+        "{}",
+        results[0].score
+    );
+
+    let results = run("min").unwrap();
+    let two = results.iter().find(|r| r.point.id == 2).unwrap();
+    assert!((two.score - 0.8).abs() < 1e-5, "{}", two.score);
+
+    let err = run("median").unwrap_err();
+    assert!(err.to_string().contains("set_score"), "{err}");
+}
@@ -0,0 +1,171 @@
+//! Query rewrite rules: deployment-wide hooks that rewrite or reject
+//! `VelesQL` queries before they are validated and executed.
+//!
+//! Rules run in registration order on every query reaching
+//! [`Database::execute_query`], [`Database::execute_aggregate`] and the
+//! EXPLAIN entry points, after views and subqueries are resolved. The
+//! built-in [`ForbidFullScan`] is registered at open when
+//! `limits.max_full_scan_points` is set.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use super::Database;
+use crate::velesql::{CompareOp, Condition, Query, QueryHint, SelectStatement};
+use crate::{Error, Result};
+
+/// A deployment-wide query rewrite rule.
+///
+/// Returning `Ok(Some(query))` replaces the query for the following rules and
+/// for execution; `Ok(None)` leaves it unchanged; an error rejects it.
+pub trait QueryRewriteRule: Send + Sync {
+    /// Short name used in logs and error messages.
+    fn name(&self) -> &str;
+
+    /// Rewrites or rejects `query`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error the query is rejected with.
+    fn rewrite(&self, query: &Query, ctx: &RewriteContext<'_>) -> Result<Option<Query>>;
+}
+
+/// Read-only database facts available to rewrite rules.
+pub struct RewriteContext<'a> {
+    db: &'a Database,
+}
+
+impl RewriteContext<'_> {
+    /// Number of points in `collection`, `None` if it does not exist.
+    #[must_use]
+    pub fn point_count(&self, collection: &str) -> Option<usize> {
+        self.db
+            .get_any_collection(collection)
+            .map(|c| c.point_count())
+    }
+
+    /// Returns `true` when `select` has to visit every point of its `FROM`
+    /// collection: a `force_scan` hint, or a filter, sort or aggregation that
+    /// no vector, text, graph or secondary index narrows.
+    ///
+    /// A plain `SELECT ... LIMIT n` without WHERE, ORDER BY or aggregation
+    /// stops after `n` rows and is not a full scan.
+    #[must_use]
+    pub fn is_full_scan(&self, select: &SelectStatement) -> bool {
+        let force_scan = select
+            .with_clause
+            .as_ref()
+            .and_then(|with| with.get_hints().ok())
+            .is_some_and(|hints| hints.contains(&QueryHint::ForceScan));
+        if force_scan {
+            return true;
+        }
+        match &select.where_clause {
+            Some(condition) => {
+                let indexed = self.db.indexed_fields_for(&select.from);
+                !is_narrowed(condition, &indexed)
+            }
+            None => select.order_by.is_some() || select.is_aggregation_query(),
+        }
+    }
+}
+
+/// Whether some conjunct of `condition` is answered by an index.
+fn is_narrowed(condition: &Condition, indexed: &std::collections::HashSet<String>) -> bool {
+    match condition {
+        Condition::VectorSearch(_)
+        | Condition::VectorFusedSearch(_)
+        | Condition::SparseVectorSearch(_)
+        | Condition::Similarity(_)
+        | Condition::Match(_)
+        | Condition::GraphMatch(_) => true,
+        Condition::Comparison(c) => c.operator != CompareOp::NotEq && indexed.contains(&c.column),
+        Condition::In(c) => !c.negated && indexed.contains(&c.column),
+        Condition::Between(c) => indexed.contains(&c.column),
+        Condition::And(l, r) => is_narrowed(l, indexed) || is_narrowed(r, indexed),
+        Condition::Or(l, r) => is_narrowed(l, indexed) && is_narrowed(r, indexed),
+        Condition::Group(inner) => is_narrowed(inner, indexed),
+        _ => false,
+    }
+}
+
+/// Rejects read queries that would scan a collection of more than
+/// `max_points` points (`limits.max_full_scan_points`).
+#[derive(Debug, Clone, Copy)]
+pub struct ForbidFullScan {
+    /// Largest collection a query may fully scan.
+    pub max_points: usize,
+}
+
+impl QueryRewriteRule for ForbidFullScan {
+    fn name(&self) -> &'static str {
+        "forbid_full_scan"
+    }
+
+    fn rewrite(&self, query: &Query, ctx: &RewriteContext<'_>) -> Result<Option<Query>> {
+        if !query.is_select_query() {
+            return Ok(None);
+        }
+        let operands = query
+            .compound
+            .iter()
+            .flat_map(|compound| compound.operations.iter().map(|(_, select)| select));
+        for select in std::iter::once(&query.select).chain(operands) {
+            let points = ctx.point_count(&select.from).unwrap_or(0);
+            if points > self.max_points && ctx.is_full_scan(select) {
+                return Err(Error::Query(format!(
+                    "full scan of '{}' ({points} points) is forbidden above \
+                     limits.max_full_scan_points = {}: filter on an indexed field, \
+                     add a vector or text search, or raise the limit",
+                    select.from, self.max_points
+                )));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Database {
+    /// Registers a query rewrite rule, run after the rules already registered.
+    pub fn add_rewrite_rule(&self, rule: Arc<dyn QueryRewriteRule>) {
+        self.rewrite_rules.write().push(rule);
+    }
+
+    /// Removes every rewrite rule, including the built-in ones.
+    pub fn clear_rewrite_rules(&self) {
+        self.rewrite_rules.write().clear();
+    }
+
+    /// Names of the registered rewrite rules, in execution order.
+    #[must_use]
+    pub fn rewrite_rule_names(&self) -> Vec<String> {
+        self.rewrite_rules
+            .read()
+            .iter()
+            .map(|rule| rule.name().to_string())
+            .collect()
+    }
+
+    /// Runs the registered rules over `query`.
+    pub(super) fn apply_rewrite_rules<'q>(&self, query: &'q Query) -> Result<Cow<'q, Query>> {
+        // Clone the list so rules may register rules without deadlocking.
+        let rules = self.rewrite_rules.read().clone();
+        let ctx = RewriteContext { db: self };
+        let mut current = Cow::Borrowed(query);
+        for rule in &rules {
+            if let Some(rewritten) = rule.rewrite(&current, &ctx)? {
+                tracing::debug!(rule = rule.name(), "query rewritten");
+                current = Cow::Owned(rewritten);
+            }
+        }
+        Ok(current)
+    }
+
+    /// Registers the rules configured in `[limits]`.
+    pub(super) fn register_configured_rewrite_rules(&self) {
+        let max_points = self.config.limits.max_full_scan_points;
+        if max_points > 0 {
+            self.add_rewrite_rule(Arc::new(ForbidFullScan { max_points }));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Tests for query rewrite rules and the built-in `ForbidFullScan`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::*;
+use crate::config::{LimitsConfig, VelesConfig};
+use crate::point::Point;
+use crate::velesql::{Parser, Query};
+use crate::DistanceMetric;
+use serde_json::json;
+use tempfile::tempdir;
+
+fn seed(db: &Database) {
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+    let coll = db.get_vector_collection("docs").unwrap();
+    let points: Vec<Point> = (0..10)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let x = i as f32;
+            Point::new(i, vec![1.0, x], Some(json!({ "tag": i % 2, "n": i })))
+        })
+        .collect();
+    coll.upsert(points).unwrap();
+    coll.create_index("tag").unwrap();
+}
+
+fn run(db: &Database, sql: &str) -> Result<Vec<u64>> {
+    let mut params = HashMap::new();
+    params.insert("v".to_string(), json!([1.0, 0.0]));
+    let query = Parser::parse(sql).unwrap();
+    Ok(db
+        .execute_query(&query, &params)?
+        .into_iter()
+        .map(|r| r.point.id)
+        .collect())
+}
+
+#[test]
+fn test_forbid_full_scan_rejects_unindexed_scans_only() {
+    let dir = tempdir().unwrap();
+    let config = VelesConfig {
+        limits: LimitsConfig {
+            max_full_scan_points: 5,
+            ..LimitsConfig::default()
+        },
+        ..VelesConfig::default()
+    };
+    let db = Database::open_with_config(dir.path(), config).unwrap();
+    seed(&db);
+    assert_eq!(db.rewrite_rule_names(), ["forbid_full_scan"]);
+
+    for sql in [
+        "SELECT * FROM docs WHERE n = 3 LIMIT 5",
+        "SELECT * FROM docs WHERE tag = 1 OR n = 3 LIMIT 5",
+        "SELECT * FROM docs ORDER BY n LIMIT 5",
+        "SELECT * FROM docs WHERE tag = 1 LIMIT 5 WITH (hint = 'force_scan')",
+        "SELECT * FROM docs WHERE tag = 1 UNION SELECT * FROM docs WHERE n = 3",
+    ] {
+        let err = run(&db, sql).expect_err(sql);
+        assert!(
+            err.to_string().contains("max_full_scan_points"),
+            "{sql}: {err}"
+        );
+    }
+    for sql in [
+        "SELECT * FROM docs LIMIT 5",
+        "SELECT * FROM docs WHERE tag = 1 AND n > 2 LIMIT 5",
+        "SELECT * FROM docs WHERE vector NEAR $v AND n > 2 LIMIT 5",
+    ] {
+        assert!(run(&db, sql).is_ok(), "{sql}");
+    }
+
+    let explain = Parser::parse("SELECT * FROM docs WHERE n = 3 LIMIT 5").unwrap();
+    assert!(db.explain_query(&explain).is_err());
+    let count = Parser::parse("SELECT COUNT(*) FROM docs").unwrap();
+    assert!(db.execute_aggregate(&count, &HashMap::new()).is_err());
+
+    db.clear_rewrite_rules();
+    assert_eq!(
+        run(&db, "SELECT * FROM docs WHERE n = 3 LIMIT 5").unwrap(),
+        [3]
+    );
+}
+
+#[test]
+fn test_full_scans_are_allowed_by_default_and_on_small_collections() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    assert!(db.rewrite_rule_names().is_empty());
+    assert_eq!(
+        run(&db, "SELECT * FROM docs WHERE n = 3 LIMIT 5").unwrap(),
+        [3]
+    );
+
+    db.add_rewrite_rule(Arc::new(ForbidFullScan { max_points: 10 }));
+    assert_eq!(
+        run(&db, "SELECT * FROM docs WHERE n = 3 LIMIT 5").unwrap(),
+        [3]
+    );
+}
+
+/// Caps every SELECT at `LIMIT 2`.
+struct CapLimit;
+
+impl QueryRewriteRule for CapLimit {
+    fn name(&self) -> &'static str {
+        "cap_limit"
+    }
+
+    fn rewrite(&self, query: &Query, _ctx: &RewriteContext<'_>) -> Result<Option<Query>> {
+        if query.select.limit.is_some_and(|limit| limit <= 2) {
+            return Ok(None);
+        }
+        let mut query = query.clone();
+        query.select.limit = Some(2);
+        Ok(Some(query))
+    }
+}
+
+#[test]
+fn test_custom_rule_rewrites_queries_in_registration_order() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.add_rewrite_rule(Arc::new(CapLimit));
+    db.add_rewrite_rule(Arc::new(ForbidFullScan { max_points: 5 }));
+    assert_eq!(db.rewrite_rule_names(), ["cap_limit", "forbid_full_scan"]);
+
+    assert_eq!(
+        run(&db, "SELECT * FROM docs WHERE tag = 0 LIMIT 5")
+            .unwrap()
+            .len(),
+        2
+    );
+    assert!(run(&db, "SELECT * FROM docs WHERE n > 1 LIMIT 5").is_err());
+}
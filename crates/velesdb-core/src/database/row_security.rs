@@ -0,0 +1,448 @@
+//! Per-collection row-security policies.
+//!
+//! A policy is a `VelesQL` predicate over payload fields that may reference
+//! attributes of the caller as `$ctx.<name>`, e.g. `tenant_id = $ctx.tenant`.
+//! Reads made with a [`RequestContext`] are narrowed by the policy of every
+//! collection they read, with the attributes bound, the same way a view
+//! predicate narrows reads of a view. Reads without a context (embedded use,
+//! a server without API keys) are not narrowed.
+//!
+//! A policy narrows reads: searches, `VelesQL` SELECTs, aggregations,
+//! EXPLAIN ANALYZE, and the point reads and change subscriptions that AND
+//! [`Database::row_security_filter`] into their filter. It also narrows
+//! `VelesQL` UPDATE and DELETE (live and dry run), whose WHERE clause it is
+//! AND-ed into. INSERT and UPSERT are not checked. Statements the narrowing
+//! cannot be expressed for fail closed instead: JOINs onto a protected
+//! collection, MATCH queries over one, edge and node statements (`INSERT
+//! EDGE`, `DELETE EDGE`, `SELECT EDGES`, `INSERT NODE`, graph mutations) on
+//! one, and scalar subqueries while any policy exists.
+//!
+//! Policies are persisted in `row_security.json` in the data directory;
+//! deleting the collection drops its policy.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::collection::Collection;
+use crate::velesql::{Condition, Query, SelectStatement, Value};
+use crate::{Error, Result};
+
+use super::views::{narrow, parse_payload_predicate};
+use super::Database;
+
+/// Policy definitions file, in the data directory.
+const ROW_SECURITY_FILE: &str = "row_security.json";
+
+/// Parameter namespace of request-context attributes (`$ctx.<name>`).
+const CTX_PREFIX: &str = "ctx.";
+
+/// Attributes of the caller a read is made for, bound to `$ctx.<name>` in
+/// row-security policies.
+///
+/// Serializes as a plain JSON object of attributes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RequestContext {
+    attributes: HashMap<String, serde_json::Value>,
+}
+
+impl RequestContext {
+    /// Creates a context with no attributes.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the context with attribute `name` set to `value`.
+    #[must_use]
+    pub fn with_attribute(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Value of attribute `name`, if set.
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&serde_json::Value> {
+        self.attributes.get(name)
+    }
+
+    /// Returns `true` when no attribute is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+/// A collection's row-security policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowSecurityPolicy {
+    /// Collection the policy narrows.
+    pub collection: String,
+    /// `VelesQL` predicate rows must match, e.g. `tenant_id = $ctx.tenant`.
+    pub filter: String,
+}
+
+/// A policy with its predicate parsed.
+#[derive(Debug, Clone)]
+pub(super) struct PolicyEntry {
+    def: RowSecurityPolicy,
+    condition: Condition,
+}
+
+impl PolicyEntry {
+    fn new(def: RowSecurityPolicy) -> Result<Self> {
+        let condition = parse_payload_predicate(&def.filter, "row-security filter", |name| {
+            name.strip_prefix(CTX_PREFIX).is_some_and(|a| !a.is_empty())
+        })?;
+        Ok(Self { def, condition })
+    }
+
+    /// The predicate with `ctx`'s attributes bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when the predicate references an attribute
+    /// `ctx` does not set, or one bound to an object or nested array.
+    fn bind(&self, ctx: &RequestContext) -> Result<Condition> {
+        let params: HashMap<String, serde_json::Value> = ctx
+            .attributes
+            .iter()
+            .map(|(name, value)| (format!("{CTX_PREFIX}{name}"), value.clone()))
+            .collect();
+        Collection::resolve_condition_params(&self.condition, &params)
+            .map_err(|e| Error::Query(format!("row security on '{}': {e}", self.def.collection)))
+    }
+}
+
+impl Database {
+    /// Sets the row-security policy of `collection`, replacing any previous
+    /// one.
+    ///
+    /// `filter` is a `VelesQL` predicate over payload fields; `$ctx.<name>`
+    /// parameters are bound to the [`RequestContext`] of each read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{Database, DistanceMetric, RequestContext};
+    /// let db = Database::open("./data")?;
+    /// db.create_collection("docs", 384, DistanceMetric::Cosine)?;
+    /// db.set_row_security("docs", "tenant_id = $ctx.tenant")?;
+    /// let query = velesdb_core::velesql::Parser::parse("SELECT * FROM docs LIMIT 10")?;
+    /// let ctx = RequestContext::new().with_attribute("tenant", "acme");
+    /// let scoped = db.apply_row_security(&query, &ctx)?;
+    /// let results = db.execute_query(&scoped, &Default::default())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::CollectionNotFound`] if no collection named `collection`
+    ///   exists (views cannot carry a policy).
+    /// - [`Error::Query`] if `filter` is not a single payload predicate or
+    ///   uses parameters outside the `ctx` namespace.
+    /// - An I/O error if `row_security.json` cannot be written.
+    pub fn set_row_security(&self, collection: &str, filter: &str) -> Result<()> {
+        if !self.collection_exists_in_registry(collection) {
+            return Err(Error::CollectionNotFound(collection.to_string()));
+        }
+        let entry = PolicyEntry::new(RowSecurityPolicy {
+            collection: collection.to_string(),
+            filter: filter.to_string(),
+        })?;
+        let mut policies = self.row_policies.write();
+        let previous = policies.insert(collection.to_string(), entry);
+        if let Err(e) = self.save_row_policies(&policies) {
+            match previous {
+                Some(entry) => policies.insert(collection.to_string(), entry),
+                None => policies.remove(collection),
+            };
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Removes the row-security policy of `collection`. Returns `false` when
+    /// it had none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if `row_security.json` cannot be written.
+    pub fn drop_row_security(&self, collection: &str) -> Result<bool> {
+        let mut policies = self.row_policies.write();
+        let Some(entry) = policies.remove(collection) else {
+            return Ok(false);
+        };
+        if let Err(e) = self.save_row_policies(&policies) {
+            policies.insert(collection.to_string(), entry);
+            return Err(e);
+        }
+        Ok(true)
+    }
+
+    /// Returns the row-security policy of `collection`, if any.
+    #[must_use]
+    pub fn row_security(&self, collection: &str) -> Option<RowSecurityPolicy> {
+        self.row_policies
+            .read()
+            .get(collection)
+            .map(|entry| entry.def.clone())
+    }
+
+    /// Narrows `query` by the row-security policy of every collection it
+    /// reads, with `ctx`'s attributes bound. Views are resolved first, so a
+    /// view over a protected collection is narrowed too.
+    ///
+    /// Returns the query borrowed when no policy applies. The result is run
+    /// with [`execute_query`](Self::execute_query),
+    /// [`execute_aggregate`](Self::execute_aggregate) or
+    /// [`explain_analyze_query`](Self::explain_analyze_query) as usual.
+    ///
+    /// UPDATE and DELETE on a protected collection get the policy AND-ed into
+    /// their WHERE clause.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when the query JOINs onto or MATCHes over a
+    /// protected collection, is an edge or node statement on one, holds a
+    /// scalar subquery while any policy exists, or a policy references an
+    /// attribute `ctx` does not set.
+    pub fn apply_row_security<'q>(
+        &self,
+        query: &'q Query,
+        ctx: &RequestContext,
+    ) -> Result<Cow<'q, Query>> {
+        if self.row_policies.read().is_empty() {
+            return Ok(Cow::Borrowed(query));
+        }
+        let query = match self.resolve_views(query)? {
+            Some(rewritten) => Cow::Owned(rewritten),
+            None => Cow::Borrowed(query),
+        };
+        let policies = self.row_policies.read();
+        if query_has_subquery(&query) {
+            return Err(Error::Query(
+                "scalar subqueries are not supported under row security".to_string(),
+            ));
+        }
+
+        if query.dml.is_some() {
+            return scope_dml(query, &policies, ctx);
+        }
+
+        let mut selects = vec![&query.select];
+        if let Some(compound) = &query.compound {
+            selects.extend(compound.operations.iter().map(|(_, s)| s));
+        }
+        for select in &selects {
+            if let Some(join) = select
+                .joins
+                .iter()
+                .find(|j| policies.contains_key(&j.table))
+            {
+                return Err(Error::Query(format!(
+                    "collection '{}' has row security and cannot be a JOIN target",
+                    join.table
+                )));
+            }
+        }
+        if !selects.iter().any(|s| policies.contains_key(&s.from)) {
+            return Ok(query);
+        }
+        if query.match_clause.is_some() {
+            return Err(Error::Query(format!(
+                "collection '{}' has row security and cannot be the source of a MATCH query",
+                query.select.from
+            )));
+        }
+
+        let mut scoped = query.into_owned();
+        let apply = |select: &mut SelectStatement| -> Result<()> {
+            if let Some(entry) = policies.get(&select.from) {
+                narrow(select, &entry.bind(ctx)?);
+            }
+            Ok(())
+        };
+        apply(&mut scoped.select)?;
+        if let Some(compound) = &mut scoped.compound {
+            for (_, select) in &mut compound.operations {
+                apply(select)?;
+            }
+        }
+        Ok(Cow::Owned(scoped))
+    }
+
+    /// The filter a read of `collection` made for `ctx` must be AND-ed with:
+    /// its row-security policy bound to `ctx`, or `None` when the read has no
+    /// context or the collection no policy.
+    ///
+    /// For reads that do not go through a search or `VelesQL` (point
+    /// lookups, scrolls, counts, change subscriptions).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when the policy references an attribute `ctx`
+    /// does not set.
+    pub fn row_security_filter(
+        &self,
+        collection: &str,
+        ctx: Option<&RequestContext>,
+    ) -> Result<Option<crate::filter::Filter>> {
+        let Some(ctx) = ctx else {
+            return Ok(None);
+        };
+        Ok(self
+            .row_policy_condition(collection, ctx)?
+            .map(super::gated_search::scope_to_core_filter))
+    }
+
+    /// The row-security predicate of `collection` bound to `ctx`, or `None`
+    /// when it has no policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when the policy references an attribute `ctx`
+    /// does not set.
+    pub(super) fn row_policy_condition(
+        &self,
+        collection: &str,
+        ctx: &RequestContext,
+    ) -> Result<Option<Condition>> {
+        self.row_policies
+            .read()
+            .get(collection)
+            .map(|entry| entry.bind(ctx))
+            .transpose()
+    }
+
+    /// Drops the policy of the deleted collection `collection`.
+    pub(super) fn drop_row_security_of(&self, collection: &str) {
+        if let Err(e) = self.drop_row_security(collection) {
+            tracing::warn!(collection, error = %e, "failed to persist dropped row-security policy");
+        }
+    }
+
+    /// Loads `row_security.json`; unreadable policies are logged and skipped.
+    pub(super) fn load_row_policies(&self) {
+        let path = self.data_dir.join(ROW_SECURITY_FILE);
+        let Ok(bytes) = std::fs::read(&path) else {
+            return;
+        };
+        let defs: Vec<RowSecurityPolicy> = match serde_json::from_slice(&bytes) {
+            Ok(defs) => defs,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "ignoring unreadable row-security file");
+                return;
+            }
+        };
+        let mut policies = self.row_policies.write();
+        for def in defs {
+            let collection = def.collection.clone();
+            match PolicyEntry::new(def) {
+                Ok(entry) => {
+                    policies.insert(collection, entry);
+                }
+                Err(e) => {
+                    tracing::warn!(collection = %collection, error = %e, "skipping invalid row-security policy");
+                }
+            }
+        }
+    }
+
+    fn save_row_policies(&self, policies: &HashMap<String, PolicyEntry>) -> Result<()> {
+        let mut defs: Vec<&RowSecurityPolicy> = policies.values().map(|entry| &entry.def).collect();
+        defs.sort_by(|a, b| a.collection.cmp(&b.collection));
+        let bytes =
+            serde_json::to_vec_pretty(&defs).map_err(|e| Error::Serialization(e.to_string()))?;
+        crate::storage::atomic_write::atomic_write(&self.data_dir.join(ROW_SECURITY_FILE), &bytes)?;
+        Ok(())
+    }
+}
+
+/// Narrows a DML statement by the policy of the collection it writes.
+fn scope_dml<'q>(
+    query: Cow<'q, Query>,
+    policies: &HashMap<String, PolicyEntry>,
+    ctx: &RequestContext,
+) -> Result<Cow<'q, Query>> {
+    use crate::velesql::DmlStatement;
+    let Some(dml) = &query.dml else {
+        return Ok(query);
+    };
+    let (target, narrowable) = match dml {
+        DmlStatement::Insert(_) | DmlStatement::Upsert(_) => return Ok(query),
+        DmlStatement::Update(s) => (Some(&s.table), true),
+        DmlStatement::Delete(s) => (Some(&s.table), true),
+        DmlStatement::InsertEdge(s) => (Some(&s.collection), false),
+        DmlStatement::DeleteEdge(s) => (Some(&s.collection), false),
+        DmlStatement::SelectEdges(s) => (Some(&s.collection), false),
+        DmlStatement::InsertNode(s) => (Some(&s.collection), false),
+        DmlStatement::GraphMutation(s) => (s.collection.as_ref(), false),
+    };
+    let Some(target) = target else {
+        return Err(Error::Query(
+            "graph mutations under row security must name their collection with IN <collection>"
+                .to_string(),
+        ));
+    };
+    let Some(entry) = policies.get(target) else {
+        return Ok(query);
+    };
+    if !narrowable {
+        return Err(Error::Query(format!(
+            "collection '{target}' has row security and cannot be the target of edge or node statements"
+        )));
+    }
+    let policy = entry.bind(ctx)?;
+    let mut scoped = query.into_owned();
+    match &mut scoped.dml {
+        Some(DmlStatement::Update(s)) => {
+            s.where_clause = Some(match s.where_clause.take() {
+                Some(existing) => Condition::And(Box::new(existing), Box::new(policy)),
+                None => policy,
+            });
+        }
+        Some(DmlStatement::Delete(s)) => {
+            let existing = std::mem::replace(&mut s.where_clause, policy.clone());
+            s.where_clause = Condition::And(Box::new(existing), Box::new(policy));
+        }
+        _ => {}
+    }
+    Ok(Cow::Owned(scoped))
+}
+
+/// Returns `true` if any SELECT of `query` (or its DML) holds a scalar
+/// subquery.
+fn query_has_subquery(query: &Query) -> bool {
+    let mut selects = vec![&query.select];
+    if let Some(compound) = &query.compound {
+        selects.extend(compound.operations.iter().map(|(_, s)| s));
+    }
+    selects
+        .iter()
+        .any(|s| s.where_clause.as_ref().is_some_and(Condition::has_subquery))
+        || query.has_having_subquery()
+        || query.dml.as_ref().is_some_and(dml_has_subquery)
+}
+
+/// Returns `true` if a DML statement carries a scalar subquery.
+fn dml_has_subquery(dml: &crate::velesql::DmlStatement) -> bool {
+    use crate::velesql::DmlStatement;
+    match dml {
+        DmlStatement::Insert(s) | DmlStatement::Upsert(s) => {
+            s.rows.iter().any(|row| row.iter().any(Value::is_subquery))
+        }
+        DmlStatement::Update(s) => {
+            s.assignments.iter().any(|a| a.value.is_subquery())
+                || s.where_clause.as_ref().is_some_and(Condition::has_subquery)
+        }
+        DmlStatement::Delete(s) => s.where_clause.has_subquery(),
+        _ => false,
+    }
+}
@@ -0,0 +1,295 @@
+//! Tests for row-security policies (`set_row_security` and their enforcement).
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::observer::QueryOperationKind;
+use crate::point::Point;
+use crate::velesql::Parser;
+use crate::{DistanceMetric, GatedRead};
+use serde_json::json;
+use tempfile::tempdir;
+
+fn seed(db: &Database) {
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+    let coll = db.get_vector_collection("docs").unwrap();
+    coll.upsert(vec![
+        Point::new(
+            1,
+            vec![1.0, 0.0],
+            Some(json!({ "tenant_id": "acme", "lang": "en" })),
+        ),
+        Point::new(
+            2,
+            vec![0.9, 0.1],
+            Some(json!({ "tenant_id": "globex", "lang": "en" })),
+        ),
+        Point::new(
+            3,
+            vec![0.1, 0.9],
+            Some(json!({ "tenant_id": "acme", "lang": "fr" })),
+        ),
+    ])
+    .unwrap();
+}
+
+fn acme() -> RequestContext {
+    RequestContext::new().with_attribute("tenant", "acme")
+}
+
+fn scoped_ids(db: &Database, sql: &str, ctx: &RequestContext) -> Vec<u64> {
+    let query = Parser::parse(sql).unwrap();
+    let scoped = db.apply_row_security(&query, ctx).unwrap();
+    let mut ids: Vec<u64> = db
+        .execute_query(&scoped, &HashMap::new())
+        .unwrap()
+        .into_iter()
+        .map(|r| r.point.id)
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+fn dense(filter: Option<&crate::filter::Filter>) -> GatedRead<'_> {
+    GatedRead::Dense {
+        query: &[1.0, 0.0],
+        k: 10,
+        ef: None,
+        quality: None,
+        filter,
+    }
+}
+
+#[test]
+fn test_policy_narrows_velesql_reads_to_context() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.set_row_security("docs", "tenant_id = $ctx.tenant")
+        .unwrap();
+
+    assert_eq!(
+        scoped_ids(&db, "SELECT * FROM docs LIMIT 10", &acme()),
+        [1, 3]
+    );
+    assert_eq!(
+        scoped_ids(
+            &db,
+            "SELECT * FROM docs WHERE lang = 'en' LIMIT 10",
+            &acme()
+        ),
+        [1]
+    );
+    let globex = RequestContext::new().with_attribute("tenant", "globex");
+    assert_eq!(scoped_ids(&db, "SELECT * FROM docs LIMIT 10", &globex), [2]);
+
+    let count = Parser::parse("SELECT COUNT(*) FROM docs").unwrap();
+    let scoped = db.apply_row_security(&count, &acme()).unwrap();
+    let counts = db.execute_aggregate(&scoped, &HashMap::new()).unwrap();
+    assert_eq!(counts["count"], json!(2));
+}
+
+#[test]
+fn test_policy_applies_through_views() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.create_view("english", "docs", "lang = 'en'").unwrap();
+    db.set_row_security("docs", "tenant_id = $ctx.tenant")
+        .unwrap();
+
+    assert_eq!(
+        scoped_ids(&db, "SELECT * FROM english LIMIT 10", &acme()),
+        [1]
+    );
+}
+
+#[test]
+fn test_policy_narrows_gated_search_and_authorize_read() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.set_row_security("docs", "tenant_id = $ctx.tenant")
+        .unwrap();
+
+    let hits = db
+        .gated_search_with_context("docs", Some(&acme()), dense(None))
+        .unwrap();
+    let mut ids: Vec<u64> = hits.iter().map(|r| r.point.id).collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [1, 3]);
+
+    let french = crate::filter::Filter::new(crate::filter::Condition::eq("lang", "fr"));
+    let hits = db
+        .gated_search_with_context("docs", Some(&acme()), dense(Some(&french)))
+        .unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].point.id, 3);
+
+    // No context: the read is not narrowed.
+    assert_eq!(
+        db.gated_search_with_context("docs", None, dense(None))
+            .unwrap()
+            .len(),
+        3
+    );
+    assert!(db
+        .authorize_read_with_context("docs", QueryOperationKind::VectorSearch, None)
+        .unwrap()
+        .is_none());
+    assert!(db
+        .authorize_read_with_context("docs", QueryOperationKind::VectorSearch, Some(&acme()))
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn test_missing_context_attribute_fails_closed() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.set_row_security("docs", "tenant_id = $ctx.tenant")
+        .unwrap();
+    let anonymous = RequestContext::new();
+
+    let query = Parser::parse("SELECT * FROM docs LIMIT 10").unwrap();
+    assert!(matches!(
+        db.apply_row_security(&query, &anonymous),
+        Err(Error::Query(_))
+    ));
+    assert!(db
+        .gated_search_with_context("docs", Some(&anonymous), dense(None))
+        .is_err());
+}
+
+#[test]
+fn test_unscopable_reads_are_refused() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.create_collection("tenants", 2, DistanceMetric::Cosine)
+        .unwrap();
+    db.set_row_security("docs", "tenant_id = $ctx.tenant")
+        .unwrap();
+
+    for sql in [
+        "SELECT * FROM tenants JOIN docs ON docs.id = tenants.id LIMIT 10",
+        "SELECT * FROM tenants WHERE id > (SELECT COUNT(*) FROM docs) LIMIT 10",
+    ] {
+        let query = Parser::parse(sql).unwrap();
+        assert!(
+            matches!(db.apply_row_security(&query, &acme()), Err(Error::Query(_))),
+            "{sql}"
+        );
+    }
+
+    // A collection without a policy is read unmodified.
+    let query = Parser::parse("SELECT * FROM tenants LIMIT 10").unwrap();
+    assert!(matches!(
+        db.apply_row_security(&query, &acme()).unwrap(),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn test_policy_narrows_update_and_delete() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.set_row_security("docs", "tenant_id = $ctx.tenant")
+        .unwrap();
+    let params = HashMap::new();
+
+    let delete = Parser::parse("DELETE FROM docs WHERE id IN (1, 2)").unwrap();
+    let scoped = db.apply_row_security(&delete, &acme()).unwrap();
+    let report = db.execute_query_dry_run(&scoped, &params).unwrap();
+    assert_eq!(report.would_delete, 1);
+    db.execute_query(&scoped, &params).unwrap();
+    let coll = db.get_vector_collection("docs").unwrap();
+    let remaining: Vec<u64> = coll
+        .get(&[1, 2, 3])
+        .into_iter()
+        .flatten()
+        .map(|p| p.id)
+        .collect();
+    assert_eq!(remaining, [2, 3], "only the caller's row is deleted");
+
+    let update = Parser::parse("UPDATE docs SET lang = 'de'").unwrap();
+    let scoped = db.apply_row_security(&update, &acme()).unwrap();
+    db.execute_query(&scoped, &params).unwrap();
+    let langs: Vec<_> = coll
+        .get(&[2, 3])
+        .into_iter()
+        .flatten()
+        .map(|p| p.payload.unwrap()["lang"].clone())
+        .collect();
+    assert_eq!(langs, [json!("en"), json!("de")]);
+
+    for sql in [
+        "SELECT EDGES FROM docs",
+        "DELETE EDGE 1 FROM docs",
+        "INSERT NODE INTO docs (id = 9, payload = '{}')",
+    ] {
+        let query = Parser::parse(sql).unwrap();
+        assert!(
+            matches!(db.apply_row_security(&query, &acme()), Err(Error::Query(_))),
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn test_policy_validation() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+
+    assert!(matches!(
+        db.set_row_security("missing", "tenant_id = $ctx.tenant"),
+        Err(Error::CollectionNotFound(_))
+    ));
+    for filter in [
+        "tenant_id = $tenant",
+        "tenant_id = $other.tenant",
+        "tenant_id = $ctx.tenant LIMIT 5",
+        "vector NEAR $v",
+    ] {
+        assert!(
+            matches!(db.set_row_security("docs", filter), Err(Error::Query(_))),
+            "{filter}"
+        );
+    }
+    assert!(db.row_security("docs").is_none());
+}
+
+#[test]
+fn test_policy_persists_and_is_dropped_with_collection() {
+    let dir = tempdir().unwrap();
+    {
+        let db = Database::open(dir.path()).unwrap();
+        seed(&db);
+        db.set_row_security("docs", "tenant_id = $ctx.tenant")
+            .unwrap();
+    }
+    let db = Database::open(dir.path()).unwrap();
+    assert_eq!(
+        db.row_security("docs"),
+        Some(RowSecurityPolicy {
+            collection: "docs".to_string(),
+            filter: "tenant_id = $ctx.tenant".to_string(),
+        })
+    );
+    assert_eq!(
+        scoped_ids(&db, "SELECT * FROM docs LIMIT 10", &acme()),
+        [1, 3]
+    );
+
+    assert!(db.drop_row_security("docs").unwrap());
+    assert!(!db.drop_row_security("docs").unwrap());
+    db.set_row_security("docs", "tenant_id = $ctx.tenant")
+        .unwrap();
+    db.delete_collection("docs").unwrap();
+    seed(&db);
+    assert!(db.row_security("docs").is_none());
+}
@@ -0,0 +1,777 @@
+//! Warm-standby snapshot shipping.
+//!
+//! [`Database::ship_snapshots`] periodically copies the data directory to a
+//! destination directory as a chain of numbered snapshots: a full snapshot,
+//! then deltas holding only the files that changed since the previous
+//! snapshot and the ones that disappeared. Every [`FULL_SNAPSHOT_EVERY`]
+//! deltas a new full snapshot starts a new chain and chains older than the
+//! previous one are pruned.
+//!
+//! The destination is a filesystem path: a local disk or a mounted network
+//! share. There is no URL or object-store sink; ship to remote storage by
+//! mounting it.
+//!
+//! A standby process calls [`Database::restore_shipped`] on a timer to bring
+//! its own (closed) data directory up to the latest snapshot; on failover it
+//! opens that directory with [`Database::open`].
+//!
+//! Each collection is flushed before its files are copied, and its copy is
+//! retried when a write lands meanwhile. A collection that keeps changing is
+//! left out of a delta (and shipped by a later one); a full snapshot that
+//! cannot capture every collection is not committed. Writes acknowledged
+//! after the last shipped snapshot are lost on failover.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use parking_lot::{Condvar, Mutex};
+use serde::{Deserialize, Serialize};
+
+use crate::collection::Collection;
+use crate::{Error, Result};
+
+use super::Database;
+
+/// Deltas shipped between two full snapshots.
+pub const FULL_SNAPSHOT_EVERY: u64 = 24;
+
+/// Copies of a collection attempted while writes keep landing on it.
+pub(super) const MAX_COPY_ATTEMPTS: usize = 3;
+
+/// Manifest file inside every snapshot directory.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Directory holding the copied files inside a snapshot directory.
+const FILES_DIR: &str = "files";
+
+/// Sequence of the last snapshot applied, in a standby data directory.
+const STANDBY_STATE_FILE: &str = ".standby.json";
+
+/// Prefix of the directory a snapshot is assembled in before it is renamed.
+const STAGING_PREFIX: &str = ".staging-";
+
+/// Snapshot manifest format version.
+const MANIFEST_VERSION: u32 = 1;
+
+/// Data directory entries that are never shipped.
+const SKIPPED_FILES: [&str; 2] = ["velesdb.lock", STANDBY_STATE_FILE];
+
+/// Whether a snapshot stands alone or applies on top of the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotKind {
+    /// Every file of the data directory.
+    Full,
+    /// Files changed or removed since the previous snapshot.
+    Delta,
+}
+
+impl SnapshotKind {
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Delta => "delta",
+        }
+    }
+}
+
+/// Summary of one shipped snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShippedSnapshot {
+    /// Position in the destination's snapshot sequence.
+    pub sequence: u64,
+    /// Full or delta.
+    pub kind: SnapshotKind,
+    /// Files copied.
+    pub files: usize,
+    /// Bytes copied.
+    pub bytes: u64,
+    /// Files the snapshot removes.
+    pub removed: usize,
+    /// Collections left out because writes kept landing on them.
+    pub deferred: Vec<String>,
+}
+
+/// Outcome of [`Database::restore_shipped`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RestoreReport {
+    /// Sequences applied by this call, in order.
+    pub applied: Vec<u64>,
+    /// Sequence the standby directory is at, if any snapshot was ever applied.
+    pub sequence: Option<u64>,
+}
+
+/// Size and modification time of a shipped file; a file is re-shipped when
+/// either changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct FileStamp {
+    len: u64,
+    modified_ns: u128,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    version: u32,
+    sequence: u64,
+    kind: SnapshotKind,
+    /// Sequence a delta applies on top of.
+    base: Option<u64>,
+    created_at_ms: u64,
+    /// Files in this snapshot, relative to the data directory.
+    files: Vec<String>,
+    /// Files a delta deletes.
+    removed: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StandbyState {
+    sequence: u64,
+}
+
+/// What the shipper knows about the destination.
+#[derive(Debug, Default)]
+struct ShipState {
+    /// Last committed sequence, `None` before the first snapshot.
+    sequence: Option<u64>,
+    /// Sequence of the current chain's full snapshot.
+    chain_start: Option<u64>,
+    /// Stamps of every file as of the last committed snapshot.
+    shipped: BTreeMap<String, FileStamp>,
+    /// Write generation of each collection as of the last committed snapshot.
+    generations: HashMap<String, u64>,
+    last: Option<ShippedSnapshot>,
+}
+
+/// Files of one collection captured for a snapshot.
+struct CollectionCopy {
+    stamps: BTreeMap<String, FileStamp>,
+    copied: Vec<String>,
+    generation: u64,
+}
+
+/// Files captured for a snapshot.
+#[derive(Default)]
+struct Assembled {
+    /// Stamps of every file as of this snapshot.
+    stamps: BTreeMap<String, FileStamp>,
+    copied: Vec<String>,
+    generations: HashMap<String, u64>,
+    deferred: Vec<String>,
+}
+
+struct ShipperInner {
+    db: Arc<Database>,
+    dest: PathBuf,
+    state: Mutex<ShipState>,
+    stopped: Mutex<bool>,
+    wake: Condvar,
+}
+
+/// Handle of a running snapshot shipper; dropping it stops shipping.
+pub struct SnapshotShipper {
+    inner: Arc<ShipperInner>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for SnapshotShipper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnapshotShipper")
+            .field("dest", &self.inner.dest)
+            .field("last", &self.inner.state.lock().last)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SnapshotShipper {
+    /// Ships a snapshot now, outside the schedule: a full one when the chain
+    /// is due for it, a delta otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the destination cannot be written, or
+    /// [`Error::Io`] when a full snapshot cannot capture every collection.
+    pub fn ship_now(&self) -> Result<ShippedSnapshot> {
+        self.inner.ship()
+    }
+
+    /// The last snapshot shipped, if any.
+    #[must_use]
+    pub fn last_shipped(&self) -> Option<ShippedSnapshot> {
+        self.inner.state.lock().last.clone()
+    }
+
+    /// Stops shipping, waiting for a snapshot in progress to finish.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        *self.inner.stopped.lock() = true;
+        self.inner.wake.notify_all();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for SnapshotShipper {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl Database {
+    /// Ships snapshots of the data directory to `dest` every `interval`, for
+    /// a warm standby restoring them with [`Database::restore_shipped`].
+    ///
+    /// A full snapshot is shipped before this returns; later snapshots are
+    /// shipped from a background thread until the returned handle is
+    /// stopped or dropped. Shipping to a destination used before continues
+    /// its sequence with a new full snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use std::time::Duration;
+    /// # use velesdb_core::Database;
+    /// let db = Arc::new(Database::open("./data")?);
+    /// let shipper = db.ship_snapshots("/mnt/standby/velesdb", Duration::from_secs(60))?;
+    /// // ... serve traffic ...
+    /// shipper.stop();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `interval` is zero, `dest` cannot be created or
+    /// read, or the first full snapshot fails.
+    pub fn ship_snapshots(
+        self: &Arc<Self>,
+        dest: impl AsRef<Path>,
+        interval: Duration,
+    ) -> Result<SnapshotShipper> {
+        if interval.is_zero() {
+            return Err(Error::Config(
+                "snapshot shipping interval must be positive".to_string(),
+            ));
+        }
+        let dest = dest.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dest)?;
+        discard_staging(&dest)?;
+        let sequence = list_snapshots(&dest)?.last().map(|(seq, _, _)| *seq);
+        let inner = Arc::new(ShipperInner {
+            db: Arc::clone(self),
+            dest,
+            state: Mutex::new(ShipState {
+                sequence,
+                ..ShipState::default()
+            }),
+            stopped: Mutex::new(false),
+            wake: Condvar::new(),
+        });
+        inner.ship()?;
+
+        let worker = Arc::clone(&inner);
+        let thread = std::thread::Builder::new()
+            .name("velesdb-snapshot-shipper".to_string())
+            .spawn(move || worker.run(interval))?;
+        Ok(SnapshotShipper {
+            inner,
+            thread: Some(thread),
+        })
+    }
+
+    /// Brings the standby data directory `target` up to the latest snapshot
+    /// shipped to `source`, and returns what was applied. Idempotent: call
+    /// it on a timer to keep the standby warm.
+    ///
+    /// When `target` is behind the latest full snapshot (or has never been
+    /// restored) it is rebuilt from that full snapshot; the deltas after it
+    /// are then applied in order.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DatabaseLocked`] if a database is open on `target`.
+    /// - [`Error::Io`] if the snapshot chain in `source` is broken.
+    /// - An I/O error if a snapshot cannot be read or applied.
+    pub fn restore_shipped(
+        source: impl AsRef<Path>,
+        target: impl AsRef<Path>,
+    ) -> Result<RestoreReport> {
+        let source = source.as_ref();
+        let target = target.as_ref();
+        std::fs::create_dir_all(target)?;
+        let lock_file = std::fs::File::create(target.join("velesdb.lock"))?;
+        fs2::FileExt::try_lock_exclusive(&lock_file)
+            .map_err(|_| Error::DatabaseLocked(target.display().to_string()))?;
+
+        let mut current = read_standby_state(target)?;
+        let snapshots = list_snapshots(source)?;
+        let mut report = RestoreReport::default();
+
+        if let Some((full, _, dir)) = snapshots
+            .iter()
+            .rev()
+            .find(|(_, kind, _)| *kind == SnapshotKind::Full)
+        {
+            if current.is_none_or(|cur| cur < *full) {
+                let manifest = read_manifest(dir)?;
+                clear_data_dir(target)?;
+                apply_snapshot(dir, &manifest, target)?;
+                write_standby_state(target, *full)?;
+                current = Some(*full);
+                report.applied.push(*full);
+            }
+        }
+        for (sequence, kind, dir) in &snapshots {
+            if *kind != SnapshotKind::Delta || current.is_none_or(|cur| *sequence <= cur) {
+                continue;
+            }
+            let manifest = read_manifest(dir)?;
+            if manifest.base != current {
+                return Err(Error::Io(std::io::Error::other(format!(
+                    "snapshot {sequence} applies on top of {:?}, standby is at {current:?}",
+                    manifest.base
+                ))));
+            }
+            apply_snapshot(dir, &manifest, target)?;
+            write_standby_state(target, *sequence)?;
+            current = Some(*sequence);
+            report.applied.push(*sequence);
+        }
+        report.sequence = current;
+        Ok(report)
+    }
+
+    /// Every open collection with its name.
+    pub(super) fn shippable_collections(&self) -> Vec<(String, Collection)> {
+        let mut collections: Vec<(String, Collection)> = Vec::new();
+        collections.extend(
+            self.vector_colls
+                .read()
+                .iter()
+                .map(|(name, c)| (name.clone(), c.inner.clone())),
+        );
+        collections.extend(
+            self.graph_colls
+                .read()
+                .iter()
+                .map(|(name, c)| (name.clone(), c.inner.clone())),
+        );
+        collections.extend(
+            self.metadata_colls
+                .read()
+                .iter()
+                .map(|(name, c)| (name.clone(), c.inner.clone())),
+        );
+        collections
+    }
+}
+
+impl ShipperInner {
+    fn run(&self, interval: Duration) {
+        loop {
+            {
+                let mut stopped = self.stopped.lock();
+                if !*stopped {
+                    let _ = self.wake.wait_for(&mut stopped, interval);
+                }
+                if *stopped {
+                    return;
+                }
+            }
+            match self.ship() {
+                Ok(shipped) => tracing::debug!(
+                    sequence = shipped.sequence,
+                    kind = ?shipped.kind,
+                    files = shipped.files,
+                    "snapshot shipped"
+                ),
+                Err(e) => tracing::warn!(error = %e, "snapshot shipping failed"),
+            }
+        }
+    }
+
+    fn ship(&self) -> Result<ShippedSnapshot> {
+        let mut state = self.state.lock();
+        let sequence = state.sequence.map_or(1, |seq| seq + 1);
+        let kind = match state.chain_start {
+            Some(start) if sequence - start <= FULL_SNAPSHOT_EVERY => SnapshotKind::Delta,
+            _ => SnapshotKind::Full,
+        };
+        let staging = self.dest.join(format!("{STAGING_PREFIX}{sequence:020}"));
+        if staging.exists() {
+            std::fs::remove_dir_all(&staging)?;
+        }
+        let files_dir = staging.join(FILES_DIR);
+        std::fs::create_dir_all(&files_dir)?;
+
+        let assembled = match self.assemble(&state, kind, &files_dir) {
+            Ok(assembled) => assembled,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&staging);
+                return Err(e);
+            }
+        };
+        let Assembled {
+            stamps,
+            copied,
+            generations,
+            deferred,
+        } = assembled;
+
+        let removed: Vec<String> = match kind {
+            SnapshotKind::Full => Vec::new(),
+            SnapshotKind::Delta => state
+                .shipped
+                .keys()
+                .filter(|path| !stamps.contains_key(*path))
+                .cloned()
+                .collect(),
+        };
+        let bytes = copied.iter().map(|path| stamps[path].len).sum();
+        let manifest = Manifest {
+            version: MANIFEST_VERSION,
+            sequence,
+            kind,
+            base: match kind {
+                SnapshotKind::Full => None,
+                SnapshotKind::Delta => state.sequence,
+            },
+            created_at_ms: now_ms(),
+            files: copied,
+            removed,
+        };
+        let json = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        crate::storage::atomic_write::atomic_write(&staging.join(MANIFEST_FILE), &json)?;
+        let final_dir = self.dest.join(format!("{sequence:020}-{}", kind.suffix()));
+        std::fs::rename(&staging, &final_dir)?;
+
+        let shipped = ShippedSnapshot {
+            sequence,
+            kind,
+            files: manifest.files.len(),
+            bytes,
+            removed: manifest.removed.len(),
+            deferred,
+        };
+        state.sequence = Some(sequence);
+        state.shipped = stamps;
+        state.generations = generations;
+        if kind == SnapshotKind::Full {
+            if let Some(previous_start) = state.chain_start.replace(sequence) {
+                prune_before(&self.dest, previous_start);
+            }
+        }
+        state.last = Some(shipped.clone());
+        Ok(shipped)
+    }
+
+    /// Copies the files the snapshot needs into `files_dir`.
+    fn assemble(
+        &self,
+        state: &ShipState,
+        kind: SnapshotKind,
+        files_dir: &Path,
+    ) -> Result<Assembled> {
+        let data_dir = &self.db.data_dir;
+        let previous = match kind {
+            SnapshotKind::Full => None,
+            SnapshotKind::Delta => Some(state),
+        };
+        let mut assembled = Assembled::default();
+
+        // Database-level files (views, policies, SLOs, ...).
+        for entry in std::fs::read_dir(data_dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !entry.file_type()?.is_file() || SKIPPED_FILES.contains(&name.as_str()) {
+                continue;
+            }
+            let stamp = stamp_of(&entry.path())?;
+            if previous.and_then(|p| p.shipped.get(&name)) != Some(&stamp) {
+                copy_into(&entry.path(), &files_dir.join(&name))?;
+                assembled.copied.push(name.clone());
+            }
+            assembled.stamps.insert(name, stamp);
+        }
+
+        for (name, collection) in self.db.shippable_collections() {
+            match copy_collection(data_dir, &name, &collection, previous, files_dir)? {
+                Some(copy) => {
+                    assembled.stamps.extend(copy.stamps);
+                    assembled.copied.extend(copy.copied);
+                    assembled.generations.insert(name, copy.generation);
+                }
+                None if kind == SnapshotKind::Full => {
+                    return Err(Error::Io(std::io::Error::other(format!(
+                        "collection '{name}' kept changing during the full snapshot"
+                    ))));
+                }
+                None => {
+                    tracing::warn!(collection = %name, "collection kept changing; deferred to the next snapshot");
+                    // Keep what the standby already has.
+                    let prefix = format!("{name}/");
+                    assembled.stamps.extend(
+                        state
+                            .shipped
+                            .iter()
+                            .filter(|(path, _)| path.starts_with(&prefix))
+                            .map(|(path, stamp)| (path.clone(), *stamp)),
+                    );
+                    if let Some(generation) = state.generations.get(&name) {
+                        assembled.generations.insert(name.clone(), *generation);
+                    }
+                    assembled.deferred.push(name);
+                }
+            }
+        }
+        Ok(assembled)
+    }
+}
+
+/// Copies the files of `collection` that changed since `previous`, retrying
+/// while writes land on it. Returns `None` when it never held still.
+///
+/// The collection is flushed first unless no write reached it since
+/// `previous`: a flush rewrites the HNSW graph, which would otherwise be
+/// shipped again by every delta.
+fn copy_collection(
+    data_dir: &Path,
+    name: &str,
+    collection: &Collection,
+    previous: Option<&ShipState>,
+    files_dir: &Path,
+) -> Result<Option<CollectionCopy>> {
+    let shipped_generation = previous.and_then(|p| p.generations.get(name).copied());
+    for _ in 0..MAX_COPY_ATTEMPTS {
+        let generation = collection.write_generation();
+        if shipped_generation != Some(generation) {
+            collection.flush_full()?;
+        }
+        let mut copy = CollectionCopy {
+            stamps: BTreeMap::new(),
+            copied: Vec::new(),
+            generation,
+        };
+        for path in walk_files(&data_dir.join(name))? {
+            let relative = relative_path(data_dir, &path);
+            let stamp = stamp_of(&path)?;
+            if previous.and_then(|p| p.shipped.get(&relative)) != Some(&stamp) {
+                copy_into(&path, &files_dir.join(&relative))?;
+                copy.copied.push(relative.clone());
+            }
+            copy.stamps.insert(relative, stamp);
+        }
+        if collection.write_generation() == generation {
+            return Ok(Some(copy));
+        }
+        for relative in &copy.copied {
+            let _ = std::fs::remove_file(files_dir.join(relative));
+        }
+    }
+    Ok(None)
+}
+
+/// Copies the files of the snapshot in `dir` into `target` and deletes the
+/// files it removes.
+fn apply_snapshot(dir: &Path, manifest: &Manifest, target: &Path) -> Result<()> {
+    for relative in &manifest.files {
+        let dest = target.join(relative);
+        let tmp = dest.with_extension("standby-tmp");
+        copy_into(&dir.join(FILES_DIR).join(relative), &tmp)?;
+        std::fs::rename(&tmp, &dest)?;
+    }
+    for relative in &manifest.removed {
+        let path = target.join(relative);
+        match std::fs::remove_file(&path) {
+            Ok(()) => remove_empty_parents(&path, target),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
+/// Snapshots in `dest`, sorted by sequence.
+fn list_snapshots(dest: &Path) -> Result<Vec<(u64, SnapshotKind, PathBuf)>> {
+    let mut snapshots = Vec::new();
+    for entry in std::fs::read_dir(dest)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some((sequence, kind)) = name.split_once('-') else {
+            continue;
+        };
+        let kind = match kind {
+            "full" => SnapshotKind::Full,
+            "delta" => SnapshotKind::Delta,
+            _ => continue,
+        };
+        if let Ok(sequence) = sequence.parse::<u64>() {
+            snapshots.push((sequence, kind, entry.path()));
+        }
+    }
+    snapshots.sort_by_key(|(sequence, _, _)| *sequence);
+    Ok(snapshots)
+}
+
+/// Removes the snapshots older than `sequence`.
+fn prune_before(dest: &Path, sequence: u64) {
+    let Ok(snapshots) = list_snapshots(dest) else {
+        return;
+    };
+    for (seq, _, dir) in snapshots.into_iter().filter(|(seq, _, _)| *seq < sequence) {
+        if let Err(e) = std::fs::remove_dir_all(&dir) {
+            tracing::warn!(sequence = seq, error = %e, "failed to prune shipped snapshot");
+        }
+    }
+}
+
+/// Removes snapshots a crash left half-assembled.
+fn discard_staging(dest: &Path) -> Result<()> {
+    for entry in std::fs::read_dir(dest)? {
+        let entry = entry?;
+        if entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(STAGING_PREFIX)
+        {
+            std::fs::remove_dir_all(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+fn read_manifest(dir: &Path) -> Result<Manifest> {
+    let bytes = std::fs::read(dir.join(MANIFEST_FILE))?;
+    let manifest: Manifest =
+        serde_json::from_slice(&bytes).map_err(|e| Error::Serialization(e.to_string()))?;
+    if manifest.version != MANIFEST_VERSION {
+        return Err(Error::Io(std::io::Error::other(format!(
+            "unsupported snapshot manifest version {}",
+            manifest.version
+        ))));
+    }
+    if let Some(bad) = manifest
+        .files
+        .iter()
+        .chain(&manifest.removed)
+        .find(|relative| !is_contained_path(relative))
+    {
+        return Err(Error::Io(std::io::Error::other(format!(
+            "snapshot manifest entry '{bad}' escapes the data directory"
+        ))));
+    }
+    Ok(manifest)
+}
+
+/// Whether `relative` names a file strictly inside the directory it is joined
+/// onto: not empty, not absolute, and without `..` or drive prefixes.
+fn is_contained_path(relative: &str) -> bool {
+    let mut components = Path::new(relative).components().peekable();
+    components.peek().is_some() && components.all(|c| matches!(c, Component::Normal(_)))
+}
+
+fn read_standby_state(target: &Path) -> Result<Option<u64>> {
+    match std::fs::read(target.join(STANDBY_STATE_FILE)) {
+        Ok(bytes) => {
+            let state: StandbyState =
+                serde_json::from_slice(&bytes).map_err(|e| Error::Serialization(e.to_string()))?;
+            Ok(Some(state.sequence))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn write_standby_state(target: &Path, sequence: u64) -> Result<()> {
+    let bytes = serde_json::to_vec(&StandbyState { sequence })
+        .map_err(|e| Error::Serialization(e.to_string()))?;
+    crate::storage::atomic_write::atomic_write(&target.join(STANDBY_STATE_FILE), &bytes)?;
+    Ok(())
+}
+
+/// Empties a standby data directory before a full restore, keeping its lock.
+fn clear_data_dir(target: &Path) -> Result<()> {
+    for entry in std::fs::read_dir(target)? {
+        let entry = entry?;
+        if SKIPPED_FILES.contains(&entry.file_name().to_string_lossy().as_ref()) {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            std::fs::remove_dir_all(entry.path())?;
+        } else {
+            std::fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+/// Every regular file under `dir`, recursively.
+pub(super) fn walk_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// `path` relative to `base`, with `/` separators.
+pub(super) fn relative_path(base: &Path, path: &Path) -> String {
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn stamp_of(path: &Path) -> Result<FileStamp> {
+    let metadata = std::fs::metadata(path)?;
+    let modified_ns = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    Ok(FileStamp {
+        len: metadata.len(),
+        modified_ns,
+    })
+}
+
+fn copy_into(from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(from, to)?;
+    Ok(())
+}
+
+/// Removes the now-empty directories between `path` and `root`.
+fn remove_empty_parents(path: &Path, root: &Path) {
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == root || std::fs::remove_dir(current).is_err() {
+            break;
+        }
+        dir = current.parent();
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
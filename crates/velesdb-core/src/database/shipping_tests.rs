@@ -0,0 +1,177 @@
+//! Tests for warm-standby snapshot shipping (`ship_snapshots`, `restore_shipped`).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::*;
+use crate::{DistanceMetric, Point};
+use tempfile::tempdir;
+
+/// Long enough that only explicit `ship_now` calls ship during a test.
+const NEVER: Duration = Duration::from_secs(3600);
+
+fn point(id: u64) -> Point {
+    #[allow(clippy::cast_precision_loss)]
+    let x = id as f32;
+    Point::new(id, vec![x, 1.0], Some(serde_json::json!({ "id": id })))
+}
+
+#[test]
+fn test_standby_follows_full_then_deltas() {
+    let primary_dir = tempdir().unwrap();
+    let ship_dir = tempdir().unwrap();
+    let standby_dir = tempdir().unwrap();
+    let db = Arc::new(Database::open(primary_dir.path()).unwrap());
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+    db.get_vector_collection("docs")
+        .unwrap()
+        .upsert(vec![point(1), point(2)])
+        .unwrap();
+
+    let shipper = db.ship_snapshots(ship_dir.path(), NEVER).unwrap();
+    let first = shipper.last_shipped().unwrap();
+    assert_eq!((first.sequence, first.kind), (1, SnapshotKind::Full));
+    let report = Database::restore_shipped(ship_dir.path(), standby_dir.path()).unwrap();
+    assert_eq!(report.applied, vec![1]);
+
+    // Nothing written: the delta carries no collection file.
+    let idle = shipper.ship_now().unwrap();
+    assert_eq!(idle.kind, SnapshotKind::Delta);
+    assert_eq!(idle.files, 0);
+
+    db.get_vector_collection("docs")
+        .unwrap()
+        .upsert(vec![point(3)])
+        .unwrap();
+    db.create_collection("extra", 2, DistanceMetric::Cosine)
+        .unwrap();
+    let delta = shipper.ship_now().unwrap();
+    assert_eq!((delta.sequence, delta.kind), (3, SnapshotKind::Delta));
+    assert!(delta.files > 0);
+    db.delete_collection("extra").unwrap();
+    let removal = shipper.ship_now().unwrap();
+    assert!(removal.removed > 0);
+    shipper.stop();
+
+    let report = Database::restore_shipped(ship_dir.path(), standby_dir.path()).unwrap();
+    assert_eq!(report.applied, vec![2, 3, 4]);
+    assert_eq!(report.sequence, Some(4));
+    // Idempotent once caught up.
+    let again = Database::restore_shipped(ship_dir.path(), standby_dir.path()).unwrap();
+    assert!(again.applied.is_empty());
+
+    drop(db);
+    let standby = Database::open(standby_dir.path()).unwrap();
+    let docs = standby.get_vector_collection("docs").unwrap();
+    assert_eq!(docs.len(), 3);
+    assert!(docs.get(&[3])[0].is_some());
+    assert!(standby.get_vector_collection("extra").is_none());
+}
+
+#[test]
+fn test_restore_refuses_open_standby() {
+    let primary_dir = tempdir().unwrap();
+    let ship_dir = tempdir().unwrap();
+    let standby_dir = tempdir().unwrap();
+    let db = Arc::new(Database::open(primary_dir.path()).unwrap());
+    db.ship_snapshots(ship_dir.path(), NEVER).unwrap().stop();
+
+    let _standby = Database::open(standby_dir.path()).unwrap();
+    let err = Database::restore_shipped(ship_dir.path(), standby_dir.path()).unwrap_err();
+    assert!(matches!(err, Error::DatabaseLocked(_)), "{err}");
+}
+
+#[test]
+fn test_new_chain_prunes_old_snapshots_and_rebuilds_standby() {
+    let primary_dir = tempdir().unwrap();
+    let ship_dir = tempdir().unwrap();
+    let standby_dir = tempdir().unwrap();
+    let db = Arc::new(Database::open(primary_dir.path()).unwrap());
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+
+    let shipper = db.ship_snapshots(ship_dir.path(), NEVER).unwrap();
+    Database::restore_shipped(ship_dir.path(), standby_dir.path()).unwrap();
+    // Chains of one full and FULL_SNAPSHOT_EVERY deltas: the third full
+    // snapshot prunes the first chain.
+    let chain = shipping::FULL_SNAPSHOT_EVERY + 1;
+    for _ in 0..=2 * chain {
+        shipper.ship_now().unwrap();
+    }
+    let last = shipper.last_shipped().unwrap();
+    assert_eq!(last.sequence, 2 * chain + 2);
+    assert_eq!(last.kind, SnapshotKind::Delta);
+    shipper.stop();
+
+    // The first chain is gone; the standby rebuilds from the latest full.
+    let fulls: Vec<String> = std::fs::read_dir(ship_dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name.ends_with("-full"))
+        .collect();
+    assert_eq!(fulls.len(), 2, "{fulls:?}");
+    assert!(!ship_dir.path().join(format!("{:020}-full", 1)).exists());
+
+    let report = Database::restore_shipped(ship_dir.path(), standby_dir.path()).unwrap();
+    assert_eq!(report.applied, vec![2 * chain + 1, 2 * chain + 2]);
+    assert_eq!(report.sequence, Some(last.sequence));
+}
+
+#[test]
+fn test_zero_interval_is_rejected() {
+    let primary_dir = tempdir().unwrap();
+    let ship_dir = tempdir().unwrap();
+    let db = Arc::new(Database::open(primary_dir.path()).unwrap());
+    let err = db
+        .ship_snapshots(ship_dir.path(), Duration::ZERO)
+        .unwrap_err();
+    assert!(matches!(err, Error::Config(_)), "{err}");
+}
+
+#[test]
+fn test_restore_rejects_manifest_paths_outside_the_data_dir() {
+    let primary_dir = tempdir().unwrap();
+    let ship_dir = tempdir().unwrap();
+    let standby_dir = tempdir().unwrap();
+    let db = Arc::new(Database::open(primary_dir.path()).unwrap());
+    db.create_collection("docs", 2, DistanceMetric::Cosine)
+        .unwrap();
+    let shipper = db.ship_snapshots(ship_dir.path(), NEVER).unwrap();
+    shipper.stop();
+    let snapshot = std::fs::read_dir(ship_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.to_string_lossy().ends_with("-full"))
+        .unwrap();
+    let manifest_path = snapshot.join("manifest.json");
+    let original: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&manifest_path).unwrap()).unwrap();
+    let victim = standby_dir.path().parent().unwrap().join("victim");
+
+    for (list, entry) in [
+        ("files", "../escaped".to_string()),
+        ("files", victim.display().to_string()),
+        ("removed", "docs/../../victim".to_string()),
+        ("removed", String::new()),
+    ] {
+        let mut manifest = original.clone();
+        manifest[list]
+            .as_array_mut()
+            .unwrap()
+            .push(entry.clone().into());
+        std::fs::write(&manifest_path, serde_json::to_vec(&manifest).unwrap()).unwrap();
+
+        let err = Database::restore_shipped(ship_dir.path(), standby_dir.path()).unwrap_err();
+        assert!(
+            err.to_string().contains("escapes the data directory"),
+            "{entry}: {err}"
+        );
+    }
+    assert!(!standby_dir
+        .path()
+        .parent()
+        .unwrap()
+        .join("escaped")
+        .exists());
+}
@@ -354,11 +354,7 @@ fn nested_payload_value<'a>(
     payload: &'a serde_json::Value,
     path: &str,
 ) -> Option<&'a serde_json::Value> {
-    let mut current = payload;
-    for part in path.split('.') {
-        current = current.as_object()?.get(part)?;
-    }
-    Some(current)
+    crate::filter::lookup_path(payload, path)
 }
 
 /// Converts a resolved JSON scalar into a `VelesQL` [`Value`] literal.
@@ -0,0 +1,109 @@
+//! Background deletion of dropped collection directories.
+//!
+//! Removing a large collection directory can take seconds. Instead of doing
+//! it inline, [`Database::delete_collection`](super::Database::delete_collection)
+//! renames the directory into `<data_dir>/.trash/` — a cheap, same-volume
+//! rename that frees the collection name at once — and a background thread
+//! deletes it from there. The trash directory has no `config.json`, so it is
+//! never mistaken for a collection, and whatever a crash left in it is purged
+//! the next time the database opens.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread::JoinHandle;
+
+use parking_lot::Mutex;
+
+use crate::Result;
+
+/// Name of the trash directory inside the data directory.
+pub(super) const TRASH_DIR: &str = ".trash";
+
+/// Tracks the background threads deleting trashed directories.
+#[derive(Debug)]
+pub(super) struct Trash {
+    dir: PathBuf,
+    /// Disambiguates entries trashed under the same name.
+    sequence: AtomicU64,
+    pending: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl Trash {
+    pub(super) fn new(data_dir: &Path) -> Self {
+        Self {
+            dir: data_dir.join(TRASH_DIR),
+            sequence: AtomicU64::new(0),
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Moves `path` into the trash and deletes it in the background.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trash directory cannot be created or `path`
+    /// cannot be renamed into it; `path` is left untouched in that case.
+    pub(super) fn discard(&self, path: &Path, name: &str) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        let seq = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let target = self.dir.join(format!("{name}-{nanos}-{seq}"));
+        std::fs::rename(path, &target)?;
+        self.spawn(vec![target]);
+        Ok(())
+    }
+
+    /// Deletes, in the background, entries left over from a previous run.
+    pub(super) fn purge_leftovers(&self) {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        let paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+        if !paths.is_empty() {
+            self.spawn(paths);
+        }
+    }
+
+    fn spawn(&self, paths: Vec<PathBuf>) {
+        let handle = std::thread::spawn(move || {
+            for path in paths {
+                let removed = if path.is_dir() {
+                    std::fs::remove_dir_all(&path)
+                } else {
+                    std::fs::remove_file(&path)
+                };
+                match removed {
+                    Ok(()) => tracing::debug!(path = %path.display(), "Deleted trashed collection"),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                    // Left in place; retried by `purge_leftovers` on next open.
+                    Err(e) => tracing::warn!(
+                        path = %path.display(),
+                        error = %e,
+                        "Failed to delete trashed collection directory"
+                    ),
+                }
+            }
+        });
+        let mut pending = self.pending.lock();
+        pending.retain(|h| !h.is_finished());
+        pending.push(handle);
+    }
+
+    /// Blocks until every background deletion started so far has finished.
+    pub(super) fn wait_idle(&self) {
+        let handles = std::mem::take(&mut *self.pending.lock());
+        for handle in handles {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for Trash {
+    /// Finishes pending deletions so the directory is not still being
+    /// modified after the database lock is released.
+    fn drop(&mut self) {
+        self.wait_idle();
+    }
+}
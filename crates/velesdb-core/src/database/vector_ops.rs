@@ -136,6 +136,7 @@ impl Database {
         // before it is shared, so direct Collection::upsert / search paths
         // (used by every SDK/REST handler) enforce the configured limits.
         self.push_runtime_limits(&coll.inner);
+        self.push_storage_residency(name, &coll.inner);
 
         self.vector_colls
             .write()
@@ -180,6 +181,7 @@ impl Database {
         let coll = VectorCollection::open(self.data_dir.join(name)).ok()?;
         // Parity item E: re-push runtime limits on disk-open (not persisted).
         self.push_runtime_limits(&coll.inner);
+        self.push_storage_residency(name, &coll.inner);
         self.vector_colls
             .write()
             .insert(name.to_string(), coll.clone());
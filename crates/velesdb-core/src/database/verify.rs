@@ -0,0 +1,326 @@
+//! Offline-style consistency check of every collection directory (`fsck`).
+//!
+//! [`Database::verify`] inspects each collection directory under the data
+//! directory — including the ones that failed to load at startup — and
+//! reports what is wrong with it without changing anything.
+//! [`Database::repair`] then fixes what can be fixed from the data that is
+//! still intact and verifies again.
+
+use std::fmt;
+use std::path::Path;
+
+use crate::collection::Collection;
+use crate::storage::{snapshot, LogPayloadStorage, MmapStorage};
+use crate::Result;
+
+use super::Database;
+
+/// Files every collection directory must contain.
+const REQUIRED_FILES: [&str; 3] = ["config.json", "vectors.dat", "payloads.log"];
+
+/// Files that must accompany `native_meta.bin` once the HNSW index has been saved.
+const INDEX_FILES: [&str; 2] = ["native_mappings.bin", "native_hnsw.graph"];
+
+/// One problem found by [`Database::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerifyIssue {
+    /// A file the collection needs is absent.
+    MissingFile {
+        /// File name inside the collection directory.
+        file: String,
+    },
+    /// Records (or a whole file) failed their CRC check.
+    ChecksumFailure {
+        /// File name inside the collection directory.
+        file: String,
+        /// Number of corrupt records; 1 for whole-file checksums.
+        corrupt_records: usize,
+    },
+    /// A file exists but could not be read.
+    Unreadable {
+        /// File name inside the collection directory.
+        file: String,
+        /// The I/O error.
+        error: String,
+    },
+    /// The HNSW index does not cover exactly the vectors in storage.
+    CountMismatch {
+        /// Vectors in vector storage.
+        stored: usize,
+        /// Vectors in the HNSW index, including those still buffered.
+        indexed: usize,
+    },
+    /// The HNSW graph failed its structural check
+    /// ([`HnswIndex::verify`](crate::HnswIndex::verify)).
+    GraphIntegrity {
+        /// The defects found, one per entry.
+        problems: Vec<String>,
+    },
+    /// The collection could not be opened at startup.
+    LoadFailed {
+        /// Why loading failed.
+        error: String,
+    },
+}
+
+impl VerifyIssue {
+    fn is_index_issue(&self) -> bool {
+        match self {
+            Self::CountMismatch { .. } => true,
+            Self::MissingFile { file } => INDEX_FILES.contains(&file.as_str()),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for VerifyIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingFile { file } => write!(f, "missing file {file}"),
+            Self::ChecksumFailure {
+                file,
+                corrupt_records,
+            } => write!(f, "{file}: {corrupt_records} record(s) failed checksum"),
+            Self::Unreadable { file, error } => write!(f, "{file}: unreadable ({error})"),
+            Self::CountMismatch { stored, indexed } => write!(
+                f,
+                "index/vector count mismatch: {stored} stored, {indexed} indexed"
+            ),
+            Self::GraphIntegrity { problems } => {
+                write!(f, "HNSW graph damaged: {}", problems.join("; "))
+            }
+            Self::LoadFailed { error } => write!(f, "failed to load: {error}"),
+        }
+    }
+}
+
+/// Verification result for one collection directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionReport {
+    /// Collection name (the directory name).
+    pub name: String,
+    /// Whether the collection is open in this database.
+    pub loaded: bool,
+    /// Problems found, empty when the collection is healthy.
+    pub issues: Vec<VerifyIssue>,
+    /// Repairs applied by [`Database::repair`]; always empty from `verify`.
+    pub repairs: Vec<String>,
+}
+
+/// Result of [`Database::verify`] or [`Database::repair`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecoveryReport {
+    /// One entry per collection directory, sorted by name.
+    pub collections: Vec<CollectionReport>,
+}
+
+impl RecoveryReport {
+    /// Whether no collection has any issue.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.collections.iter().all(|c| c.issues.is_empty())
+    }
+
+    /// Total number of issues across all collections.
+    #[must_use]
+    pub fn issue_count(&self) -> usize {
+        self.collections.iter().map(|c| c.issues.len()).sum()
+    }
+}
+
+impl Database {
+    /// Checks every collection directory and reports what is wrong with it.
+    ///
+    /// Read-only. Covers missing files, CRC failures in the vector WAL,
+    /// payload log and payload snapshot, HNSW index/vector count mismatches
+    /// and HNSW graph defects (loaded collections only), and collections
+    /// that failed to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data directory cannot be listed.
+    pub fn verify(&self) -> Result<RecoveryReport> {
+        let mut collections = Vec::new();
+        for entry in std::fs::read_dir(&self.data_dir)? {
+            let path = entry?.path();
+            if !is_collection_dir(&path) {
+                continue;
+            }
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if crate::validation::validate_collection_name(name).is_err() {
+                continue;
+            }
+            collections.push(self.verify_collection(name, &path));
+        }
+        collections.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(RecoveryReport { collections })
+    }
+
+    /// Verifies, repairs what it can on loaded collections, and verifies again.
+    ///
+    /// Repairs: an index/vector count mismatch or missing index files
+    /// reconcile the HNSW index with storage and re-save it; corrupt vector
+    /// WAL records are dropped by checkpointing vector storage; a corrupt
+    /// payload snapshot is rewritten. Collections that failed to load, HNSW
+    /// graph defects, and damage to `vectors.dat` or `payloads.log`
+    /// themselves, are only reported. The returned report lists the remaining issues and, per
+    /// collection, the repairs applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data directory cannot be listed or a repair
+    /// fails.
+    pub fn repair(&self) -> Result<RecoveryReport> {
+        let before = self.verify()?;
+        let mut applied = Vec::new();
+        for report in &before.collections {
+            if report.issues.is_empty() {
+                continue;
+            }
+            let Some(coll) = self.loaded_collection(&report.name) else {
+                continue;
+            };
+            let repairs = repair_collection(&coll, &report.issues)?;
+            if !repairs.is_empty() {
+                tracing::info!(collection = %report.name, ?repairs, "Repaired collection");
+                applied.push((report.name.clone(), repairs));
+            }
+        }
+
+        let mut after = self.verify()?;
+        for (name, repairs) in applied {
+            if let Some(report) = after.collections.iter_mut().find(|c| c.name == name) {
+                report.repairs = repairs;
+            }
+        }
+        Ok(after)
+    }
+
+    fn verify_collection(&self, name: &str, path: &Path) -> CollectionReport {
+        let mut issues = Vec::new();
+        for file in REQUIRED_FILES {
+            if !path.join(file).exists() {
+                issues.push(VerifyIssue::MissingFile { file: file.into() });
+            }
+        }
+        if path.join("native_meta.bin").exists() {
+            for file in INDEX_FILES {
+                if !path.join(file).exists() {
+                    issues.push(VerifyIssue::MissingFile { file: file.into() });
+                }
+            }
+        }
+        check_checksums(path, &mut issues);
+
+        let coll = self.loaded_collection(name);
+        if let Some(coll) = &coll {
+            let (stored, indexed, pending) = coll.index_counts();
+            // Buffered vectors may re-index ids already in the graph, so
+            // only flag counts no buffering can explain.
+            if indexed > stored || indexed + pending < stored {
+                issues.push(VerifyIssue::CountMismatch {
+                    stored,
+                    indexed: indexed + pending,
+                });
+            }
+            // Buffered vectors are unreachable until indexed; skip the walk.
+            if pending == 0 {
+                let graph = coll.verify_index_graph();
+                if !graph.is_healthy() {
+                    issues.push(VerifyIssue::GraphIntegrity {
+                        problems: graph.problems(),
+                    });
+                }
+            }
+        } else if let Some(failure) = self.load_failures.read().get(name) {
+            issues.push(VerifyIssue::LoadFailed {
+                error: failure.error.clone(),
+            });
+        }
+
+        CollectionReport {
+            name: name.to_string(),
+            loaded: coll.is_some(),
+            issues,
+            repairs: Vec::new(),
+        }
+    }
+
+    /// The registered collection of any type, without opening it from disk.
+    fn loaded_collection(&self, name: &str) -> Option<Collection> {
+        if let Some(c) = self.vector_colls.read().get(name) {
+            return Some(c.inner.clone());
+        }
+        if let Some(c) = self.graph_colls.read().get(name) {
+            return Some(c.inner.clone());
+        }
+        self.metadata_colls
+            .read()
+            .get(name)
+            .map(|c| c.inner.clone())
+    }
+}
+
+/// A directory counts as a collection once it holds a config or a data file,
+/// so directories that lost `config.json` are still reported.
+fn is_collection_dir(path: &Path) -> bool {
+    path.is_dir() && REQUIRED_FILES.iter().any(|file| path.join(file).exists())
+}
+
+fn check_checksums(path: &Path, issues: &mut Vec<VerifyIssue>) {
+    let mut record = |file: &str, scanned: std::io::Result<usize>| match scanned {
+        Ok(0) => {}
+        Ok(corrupt_records) => issues.push(VerifyIssue::ChecksumFailure {
+            file: file.into(),
+            corrupt_records,
+        }),
+        Err(e) => issues.push(VerifyIssue::Unreadable {
+            file: file.into(),
+            error: e.to_string(),
+        }),
+    };
+    if path.join("vectors.wal").exists() {
+        record("vectors.wal", MmapStorage::count_corrupt_wal_records(path));
+    }
+    if path.join("payloads.log").exists() {
+        record(
+            "payloads.log",
+            LogPayloadStorage::count_corrupt_log_records(path),
+        );
+    }
+    let snapshot =
+        snapshot::payload_snapshot_is_valid(path).map(|valid| usize::from(valid == Some(false)));
+    record("payloads.snapshot", snapshot);
+}
+
+fn repair_collection(coll: &Collection, issues: &[VerifyIssue]) -> Result<Vec<String>> {
+    let mut repairs = Vec::new();
+    let corrupt_file = |name: &str| {
+        issues
+            .iter()
+            .any(|i| matches!(i, VerifyIssue::ChecksumFailure { file, .. } if file == name))
+    };
+
+    if corrupt_file("vectors.wal") {
+        // Replay already skipped the corrupt records; persisting what it
+        // applied makes the WAL redundant.
+        coll.checkpoint_vector_wal()?;
+        repairs.push("checkpointed vector storage, dropping corrupt WAL records".to_string());
+    }
+    if issues.iter().any(VerifyIssue::is_index_issue) {
+        let changed = coll.repair_index()?;
+        repairs.push(if changed {
+            "reconciled HNSW index with vector storage and saved it".to_string()
+        } else {
+            "saved HNSW index".to_string()
+        });
+    }
+    if corrupt_file("payloads.snapshot") {
+        coll.rewrite_payload_snapshot()?;
+        repairs.push("rewrote payload snapshot".to_string());
+    }
+    Ok(repairs)
+}
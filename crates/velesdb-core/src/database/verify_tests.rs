@@ -0,0 +1,156 @@
+//! Tests for `Database::verify` and `Database::repair`.
+
+use crate::database::{Database, VerifyIssue};
+use crate::distance::DistanceMetric;
+use crate::point::Point;
+use tempfile::TempDir;
+
+/// Opens a database holding one flushed 4-dim collection named `docs`.
+fn seeded_database() -> (TempDir, Database) {
+    let dir = TempDir::new().expect("tempdir");
+    let db = Database::open(dir.path()).expect("open database");
+    db.create_collection("docs", 4, DistanceMetric::Cosine)
+        .expect("create collection");
+    let points: Vec<_> = (0..5u8)
+        .map(|i| {
+            let f = f32::from(i);
+            Point::without_payload(u64::from(i), vec![f + 1.0, f, 1.0, 0.5])
+        })
+        .collect();
+    db.get_vector_collection("docs")
+        .expect("collection")
+        .upsert(points)
+        .expect("upsert");
+    db.flush_all();
+    (dir, db)
+}
+
+#[test]
+fn test_verify_healthy_database_is_clean() {
+    let (_dir, db) = seeded_database();
+    let report = db.verify().unwrap();
+
+    assert!(report.is_clean(), "{report:?}");
+    assert_eq!(report.collections.len(), 1);
+    assert_eq!(report.collections[0].name, "docs");
+    assert!(report.collections[0].loaded);
+}
+
+#[test]
+fn test_verify_reports_collection_that_failed_to_load() {
+    let (dir, db) = seeded_database();
+    drop(db);
+    let broken = dir.path().join("broken");
+    std::fs::create_dir_all(&broken).unwrap();
+    std::fs::write(broken.join("config.json"), b"{ not json").unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let report = db.repair().unwrap();
+
+    assert_eq!(report.issue_count(), 3);
+    let broken = &report.collections[0];
+    assert_eq!(broken.name, "broken");
+    assert!(!broken.loaded);
+    assert!(broken.repairs.is_empty());
+    assert!(broken.issues.contains(&VerifyIssue::MissingFile {
+        file: "vectors.dat".into()
+    }));
+    assert!(broken
+        .issues
+        .iter()
+        .any(|i| matches!(i, VerifyIssue::LoadFailed { .. })));
+}
+
+#[test]
+fn test_repair_drops_corrupt_wal_record() {
+    let (dir, db) = seeded_database();
+    // Store records are 33 bytes for 4 dims (op, id, len, data, crc); flip
+    // a vector byte of the first one.
+    let wal = dir.path().join("docs").join("vectors.wal");
+    let mut bytes = std::fs::read(&wal).unwrap();
+    bytes[20] ^= 0xFF;
+    std::fs::write(&wal, bytes).unwrap();
+
+    let report = db.verify().unwrap();
+    assert_eq!(
+        report.collections[0].issues,
+        vec![VerifyIssue::ChecksumFailure {
+            file: "vectors.wal".into(),
+            corrupt_records: 1,
+        }]
+    );
+
+    let repaired = db.repair().unwrap();
+    assert!(repaired.is_clean(), "{repaired:?}");
+    assert!(!repaired.collections[0].repairs.is_empty());
+    assert_eq!(db.get_vector_collection("docs").unwrap().len(), 5);
+}
+
+#[test]
+fn test_repair_rewrites_missing_index_file() {
+    let (dir, db) = seeded_database();
+    let graph = dir.path().join("docs").join("native_hnsw.graph");
+    std::fs::remove_file(&graph).unwrap();
+
+    let report = db.verify().unwrap();
+    assert_eq!(
+        report.collections[0].issues,
+        vec![VerifyIssue::MissingFile {
+            file: "native_hnsw.graph".into()
+        }]
+    );
+
+    let repaired = db.repair().unwrap();
+    assert!(repaired.is_clean(), "{repaired:?}");
+    assert!(graph.exists());
+}
+
+#[test]
+fn test_verify_reports_corrupt_payload_log() {
+    let (dir, db) = seeded_database();
+    let coll = db.get_vector_collection("docs").unwrap();
+    let points: Vec<_> = (10..13u64)
+        .map(|i| {
+            Point::new(
+                i,
+                vec![1.0, 0.0, 0.0, 0.0],
+                Some(serde_json::json!({"n": i})),
+            )
+        })
+        .collect();
+    coll.upsert(points).unwrap();
+    db.flush_all();
+
+    let log = dir.path().join("docs").join("payloads.log");
+    let mut bytes = std::fs::read(&log).unwrap();
+    // Three equal-sized records (marker, id, len, JSON, crc); flip a JSON
+    // byte of the middle one.
+    let record = bytes.len() / 3;
+    bytes[record + 15] ^= 0xFF;
+    std::fs::write(&log, bytes).unwrap();
+
+    let report = db.verify().unwrap();
+    assert!(
+        report.collections[0].issues.iter().any(|i| matches!(
+            i,
+            VerifyIssue::ChecksumFailure { file, .. } if file == "payloads.log"
+        )),
+        "{report:?}"
+    );
+}
+
+#[test]
+fn test_verify_on_open_loads_healthy_collection() {
+    let (dir, db) = seeded_database();
+    drop(db);
+
+    let mut config = crate::config::VelesConfig::default();
+    config.hnsw.verify_on_open = true;
+    let db = Database::open_with_config(dir.path(), config).unwrap();
+
+    let coll = db.get_vector_collection("docs").expect("collection");
+    let graph = coll.verify_index();
+    assert!(graph.is_healthy(), "{:?}", graph.problems());
+    assert_eq!(graph.live_nodes, 5);
+    assert!(db.verify().unwrap().is_clean());
+}
@@ -0,0 +1,336 @@
+//! Named filtered views over a collection (virtual collections).
+//!
+//! A view is a name, a base collection and a `VelesQL` predicate. It holds no
+//! data: a `VelesQL` query `FROM` a view runs against the base collection
+//! with the view predicate AND-composed into its WHERE clause at plan time
+//! (the same narrowing as an observer [`AccessScope`](crate::observer::AccessScope)),
+//! and [`Database::gated_search`] composes it with the caller filter the same
+//! way. A view over a view is flattened into one predicate over the
+//! underlying collection when it is created.
+//!
+//! Views are read-only and cannot be JOIN targets or MATCH sources.
+//! Definitions are persisted in `views.json` in the data directory; deleting
+//! the base collection drops its views.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::velesql::{Condition, Parser, Query, SelectStatement, Value};
+use crate::{Error, Result};
+
+use super::Database;
+
+/// View definitions file, in the data directory.
+const VIEWS_FILE: &str = "views.json";
+
+/// Placeholder collection name used to parse a bare view predicate.
+const PARSE_TABLE: &str = "view_base";
+
+/// A named filtered view over a collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionView {
+    /// View name, usable wherever a collection name is read from.
+    pub name: String,
+    /// Collection the view reads from.
+    pub base: String,
+    /// `VelesQL` predicate rows must match, e.g. `status = 'published'`.
+    pub filter: String,
+}
+
+/// A view with its predicate parsed.
+#[derive(Debug, Clone)]
+pub(super) struct ViewEntry {
+    pub(super) def: CollectionView,
+    pub(super) condition: Condition,
+}
+
+impl ViewEntry {
+    fn new(def: CollectionView) -> Result<Self> {
+        let condition = parse_view_filter(&def.filter)?;
+        Ok(Self { def, condition })
+    }
+}
+
+/// Parses a view predicate: a single WHERE condition over payload fields.
+fn parse_view_filter(filter: &str) -> Result<Condition> {
+    parse_payload_predicate(filter, "view filter", |_| false)
+}
+
+/// Parses a single WHERE condition over payload fields, rejecting anything
+/// else. Parameters are allowed only when `allow_param` accepts their name;
+/// `what` names the predicate in error messages.
+pub(super) fn parse_payload_predicate(
+    filter: &str,
+    what: &str,
+    allow_param: impl Fn(&str) -> bool + Copy,
+) -> Result<Condition> {
+    let invalid = |reason: &str| Error::Query(format!("invalid {what} `{filter}`: {reason}"));
+    let parsed = Parser::parse(&format!("SELECT * FROM {PARSE_TABLE} WHERE {filter}"))
+        .map_err(|e| invalid(&e.to_string()))?;
+    let Some(condition) = parsed.select.where_clause.clone() else {
+        return Err(invalid("expected a predicate"));
+    };
+    // Anything but the predicate (LIMIT, ORDER BY, a compound...) would have
+    // been smuggled in after it.
+    let mut bare = Parser::parse(&format!("SELECT * FROM {PARSE_TABLE}"))
+        .map_err(|e| Error::Internal(e.to_string()))?;
+    bare.select.where_clause = Some(condition.clone());
+    if parsed != bare {
+        return Err(invalid("only a WHERE predicate is allowed"));
+    }
+    check_payload_predicate(&condition, allow_param).map_err(invalid)?;
+    Ok(condition)
+}
+
+/// Rejects predicates that are not plain payload filters.
+fn check_payload_predicate(
+    condition: &Condition,
+    allow_param: impl Fn(&str) -> bool + Copy,
+) -> std::result::Result<(), &'static str> {
+    let bound = |v: &Value| match v {
+        Value::Parameter(name) if !allow_param(name) => Err("parameters are not allowed"),
+        Value::Subquery(_) => Err("subqueries are not allowed"),
+        _ => Ok(()),
+    };
+    match condition {
+        Condition::And(l, r) | Condition::Or(l, r) => {
+            check_payload_predicate(l, allow_param)?;
+            check_payload_predicate(r, allow_param)
+        }
+        Condition::Not(inner) | Condition::Group(inner) => {
+            check_payload_predicate(inner, allow_param)
+        }
+        Condition::Comparison(c) => bound(&c.value),
+        Condition::Between(c) => bound(&c.low).and_then(|()| bound(&c.high)),
+        Condition::In(c) => c.values.iter().try_for_each(bound),
+        Condition::Contains(c) => c.values.iter().try_for_each(bound),
+        Condition::Expression(_)
+        | Condition::Like(_)
+        | Condition::IsNull(_)
+        | Condition::ContainsText(_)
+        | Condition::GeoDistance(_)
+        | Condition::GeoBbox(_) => Ok(()),
+        _ => Err("only payload predicates are allowed"),
+    }
+}
+
+/// AND-composes `filter` into a SELECT's WHERE clause.
+pub(super) fn narrow(select: &mut SelectStatement, filter: &Condition) {
+    let filter = filter.clone();
+    select.where_clause = Some(match select.where_clause.take() {
+        Some(existing) => Condition::And(Box::new(existing), Box::new(filter)),
+        None => filter,
+    });
+}
+
+impl Database {
+    /// Creates a view named `name` over `base_collection`, filtered by the
+    /// `VelesQL` predicate `filter` (e.g. `tenant = 'acme' AND published = true`).
+    ///
+    /// The view can then be used as a collection name in `VelesQL` `FROM`
+    /// clauses and in [`gated_search`](Self::gated_search); its predicate is
+    /// AND-composed with the query's own. When `base_collection` is itself a
+    /// view, the new view reads from its base collection with both
+    /// predicates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use velesdb_core::{Database, DistanceMetric};
+    /// let db = Database::open("./data")?;
+    /// db.create_collection("articles", 384, DistanceMetric::Cosine)?;
+    /// db.create_view("published", "articles", "status = 'published'")?;
+    /// let query = velesdb_core::velesql::Parser::parse(
+    ///     "SELECT * FROM published WHERE lang = 'en' LIMIT 10",
+    /// )?;
+    /// let results = db.execute_query(&query, &Default::default())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::CollectionExists`] if a collection or view named `name`
+    ///   exists.
+    /// - [`Error::CollectionNotFound`] if `base_collection` does not exist.
+    /// - [`Error::Query`] if `filter` is not a single payload predicate
+    ///   (vector search, parameters and subqueries are rejected).
+    /// - An I/O error if `views.json` cannot be written.
+    pub fn create_view(&self, name: &str, base_collection: &str, filter: &str) -> Result<()> {
+        crate::validation::validate_collection_name(name)?;
+        let mut views = self.views.write();
+        if views.contains_key(name) || self.collection_exists_in_registry(name) {
+            return Err(Error::CollectionExists(name.to_string()));
+        }
+        let def = if let Some(parent) = views.get(base_collection) {
+            parse_view_filter(filter)?;
+            CollectionView {
+                name: name.to_string(),
+                base: parent.def.base.clone(),
+                filter: format!("({}) AND ({filter})", parent.def.filter),
+            }
+        } else if self.collection_exists_in_registry(base_collection) {
+            CollectionView {
+                name: name.to_string(),
+                base: base_collection.to_string(),
+                filter: filter.to_string(),
+            }
+        } else {
+            return Err(Error::CollectionNotFound(base_collection.to_string()));
+        };
+        views.insert(name.to_string(), ViewEntry::new(def)?);
+        if let Err(e) = self.save_views(&views) {
+            views.remove(name);
+            return Err(e);
+        }
+        drop(views);
+        self.schema_version
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Drops the view `name`. The base collection is untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CollectionNotFound`] if no view named `name` exists,
+    /// or an I/O error if `views.json` cannot be written.
+    pub fn drop_view(&self, name: &str) -> Result<()> {
+        let mut views = self.views.write();
+        let Some(entry) = views.remove(name) else {
+            return Err(Error::CollectionNotFound(name.to_string()));
+        };
+        if let Err(e) = self.save_views(&views) {
+            views.insert(name.to_string(), entry);
+            return Err(e);
+        }
+        drop(views);
+        self.schema_version
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Returns the definition of the view `name`, if any.
+    #[must_use]
+    pub fn get_view(&self, name: &str) -> Option<CollectionView> {
+        self.views.read().get(name).map(|entry| entry.def.clone())
+    }
+
+    /// Lists every view, sorted by name.
+    #[must_use]
+    pub fn list_views(&self) -> Vec<CollectionView> {
+        let mut views: Vec<CollectionView> = self
+            .views
+            .read()
+            .values()
+            .map(|entry| entry.def.clone())
+            .collect();
+        views.sort_by(|a, b| a.name.cmp(&b.name));
+        views
+    }
+
+    /// Base collection and predicate of `name` when it is a view.
+    pub(super) fn view_target(&self, name: &str) -> Option<(String, Condition)> {
+        let views = self.views.read();
+        let entry = views.get(name)?;
+        Some((entry.def.base.clone(), entry.condition.clone()))
+    }
+
+    /// Rewrites the views a query reads from into their base collections,
+    /// AND-composing each view predicate into the matching WHERE clause.
+    ///
+    /// Returns `None` when the query reads no view.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Query`] when a view is used as a JOIN target or as
+    /// the source of a MATCH query.
+    pub(super) fn resolve_views(&self, query: &Query) -> Result<Option<Query>> {
+        let views = self.views.read();
+        if views.is_empty() {
+            return Ok(None);
+        }
+        let mut selects = vec![&query.select];
+        if let Some(compound) = &query.compound {
+            selects.extend(compound.operations.iter().map(|(_, s)| s));
+        }
+        for select in &selects {
+            if let Some(join) = select.joins.iter().find(|j| views.contains_key(&j.table)) {
+                return Err(Error::Query(format!(
+                    "view '{}' cannot be a JOIN target",
+                    join.table
+                )));
+            }
+        }
+        if !selects.iter().any(|s| views.contains_key(&s.from)) {
+            return Ok(None);
+        }
+        if query.match_clause.is_some() {
+            return Err(Error::Query(format!(
+                "view '{}' cannot be the source of a MATCH query",
+                query.select.from
+            )));
+        }
+
+        let mut rewritten = query.clone();
+        let resolve = |select: &mut SelectStatement| {
+            if let Some(entry) = views.get(&select.from) {
+                select.from.clone_from(&entry.def.base);
+                narrow(select, &entry.condition);
+            }
+        };
+        resolve(&mut rewritten.select);
+        if let Some(compound) = &mut rewritten.compound {
+            for (_, select) in &mut compound.operations {
+                resolve(select);
+            }
+        }
+        Ok(Some(rewritten))
+    }
+
+    /// Drops the views reading from the deleted collection `base`.
+    pub(super) fn drop_views_of(&self, base: &str) {
+        let mut views = self.views.write();
+        let before = views.len();
+        views.retain(|_, entry| entry.def.base != base);
+        if views.len() != before {
+            if let Err(e) = self.save_views(&views) {
+                tracing::warn!(collection = base, error = %e, "failed to persist dropped views");
+            }
+        }
+    }
+
+    /// Loads `views.json`; unreadable definitions are logged and skipped.
+    pub(super) fn load_views(&self) {
+        let path = self.data_dir.join(VIEWS_FILE);
+        let Ok(bytes) = std::fs::read(&path) else {
+            return;
+        };
+        let defs: Vec<CollectionView> = match serde_json::from_slice(&bytes) {
+            Ok(defs) => defs,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "ignoring unreadable views file");
+                return;
+            }
+        };
+        let mut views = self.views.write();
+        for def in defs {
+            let name = def.name.clone();
+            match ViewEntry::new(def) {
+                Ok(entry) => {
+                    views.insert(name, entry);
+                }
+                Err(e) => tracing::warn!(view = %name, error = %e, "skipping invalid view"),
+            }
+        }
+    }
+
+    fn save_views(&self, views: &HashMap<String, ViewEntry>) -> Result<()> {
+        let mut defs: Vec<&CollectionView> = views.values().map(|entry| &entry.def).collect();
+        defs.sort_by(|a, b| a.name.cmp(&b.name));
+        let bytes =
+            serde_json::to_vec_pretty(&defs).map_err(|e| Error::Serialization(e.to_string()))?;
+        crate::storage::atomic_write::atomic_write(&self.data_dir.join(VIEWS_FILE), &bytes)?;
+        Ok(())
+    }
+}
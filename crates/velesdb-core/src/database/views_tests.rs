@@ -0,0 +1,222 @@
+//! Tests for filtered views (`create_view` and view resolution).
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::point::Point;
+use crate::velesql::Parser;
+use crate::{DistanceMetric, GatedRead};
+use serde_json::json;
+use tempfile::tempdir;
+
+fn seed(db: &Database) {
+    db.create_collection("articles", 2, DistanceMetric::Cosine)
+        .unwrap();
+    let coll = db.get_vector_collection("articles").unwrap();
+    coll.upsert(vec![
+        Point::new(
+            1,
+            vec![1.0, 0.0],
+            Some(json!({ "status": "published", "lang": "en" })),
+        ),
+        Point::new(
+            2,
+            vec![0.9, 0.1],
+            Some(json!({ "status": "draft", "lang": "en" })),
+        ),
+        Point::new(
+            3,
+            vec![0.1, 0.9],
+            Some(json!({ "status": "published", "lang": "fr" })),
+        ),
+    ])
+    .unwrap();
+}
+
+fn query_ids(db: &Database, sql: &str) -> Vec<u64> {
+    let query = Parser::parse(sql).unwrap();
+    let mut ids: Vec<u64> = db
+        .execute_query(&query, &HashMap::new())
+        .unwrap()
+        .into_iter()
+        .map(|r| r.point.id)
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn test_view_narrows_velesql_reads() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+
+    db.create_view("published", "articles", "status = 'published'")
+        .unwrap();
+
+    assert_eq!(query_ids(&db, "SELECT * FROM published LIMIT 10"), [1, 3]);
+    assert_eq!(
+        query_ids(&db, "SELECT * FROM published WHERE lang = 'en' LIMIT 10"),
+        [1]
+    );
+    let plan = db
+        .explain_query(&Parser::parse("SELECT * FROM published LIMIT 10").unwrap())
+        .unwrap();
+    assert!(format!("{plan:?}").contains("articles"));
+    let counts = db
+        .execute_aggregate(
+            &Parser::parse("SELECT COUNT(*) FROM published").unwrap(),
+            &HashMap::new(),
+        )
+        .unwrap();
+    assert_eq!(counts["count"], json!(2));
+}
+
+#[test]
+fn test_view_narrows_gated_search_and_composes_caller_filter() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.create_view("published", "articles", "status = 'published'")
+        .unwrap();
+
+    let hits = db
+        .gated_search(
+            "published",
+            None,
+            None,
+            GatedRead::Dense {
+                query: &[1.0, 0.0],
+                k: 10,
+                ef: None,
+                quality: None,
+                filter: None,
+            },
+        )
+        .unwrap();
+    let mut ids: Vec<u64> = hits.iter().map(|r| r.point.id).collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [1, 3]);
+
+    let english = crate::filter::Filter::new(crate::filter::Condition::eq("lang", "en"));
+    let hits = db
+        .gated_search(
+            "published",
+            None,
+            None,
+            GatedRead::Dense {
+                query: &[1.0, 0.0],
+                k: 10,
+                ef: None,
+                quality: None,
+                filter: Some(&english),
+            },
+        )
+        .unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].point.id, 1);
+    assert!(db
+        .authorize_read(
+            "published",
+            crate::observer::QueryOperationKind::VectorSearch,
+            None,
+            None
+        )
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn test_view_over_view_flattens_and_views_survive_reopen() {
+    let dir = tempdir().unwrap();
+    {
+        let db = Database::open(dir.path()).unwrap();
+        seed(&db);
+        db.create_view("published", "articles", "status = 'published'")
+            .unwrap();
+        db.create_view("published_fr", "published", "lang = 'fr'")
+            .unwrap();
+        let view = db.get_view("published_fr").unwrap();
+        assert_eq!(view.base, "articles");
+        assert_eq!(view.filter, "(status = 'published') AND (lang = 'fr')");
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let names: Vec<String> = db.list_views().into_iter().map(|v| v.name).collect();
+    assert_eq!(names, ["published", "published_fr"]);
+    assert_eq!(query_ids(&db, "SELECT * FROM published_fr LIMIT 10"), [3]);
+
+    db.drop_view("published").unwrap();
+    assert!(db.get_view("published").is_none());
+    assert_eq!(query_ids(&db, "SELECT * FROM published_fr LIMIT 10"), [3]);
+}
+
+#[test]
+fn test_create_view_rejects_invalid_definitions() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.create_view("published", "articles", "status = 'published'")
+        .unwrap();
+
+    assert!(matches!(
+        db.create_view("articles", "articles", "lang = 'en'"),
+        Err(Error::CollectionExists(_))
+    ));
+    assert!(matches!(
+        db.create_view("published", "articles", "lang = 'en'"),
+        Err(Error::CollectionExists(_))
+    ));
+    assert!(matches!(
+        db.create_view("v", "missing", "lang = 'en'"),
+        Err(Error::CollectionNotFound(_))
+    ));
+    for filter in [
+        "lang = 'en' LIMIT 5",
+        "lang = $lang",
+        "vector NEAR $v",
+        "status = ",
+    ] {
+        assert!(
+            matches!(
+                db.create_view("v", "articles", filter),
+                Err(Error::Query(_))
+            ),
+            "{filter}"
+        );
+    }
+    assert!(matches!(
+        db.create_collection("published", 2, DistanceMetric::Cosine),
+        Err(Error::CollectionExists(_))
+    ));
+    assert!(matches!(
+        db.drop_view("v"),
+        Err(Error::CollectionNotFound(_))
+    ));
+}
+
+#[test]
+fn test_view_cannot_be_join_target_and_is_dropped_with_its_base() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    seed(&db);
+    db.create_view("published", "articles", "status = 'published'")
+        .unwrap();
+
+    let join = Parser::parse(
+        "SELECT * FROM articles JOIN published ON published.id = articles.id LIMIT 10",
+    )
+    .unwrap();
+    assert!(matches!(
+        db.execute_query(&join, &HashMap::new()),
+        Err(Error::Query(_))
+    ));
+
+    db.delete_collection("articles").unwrap();
+    assert!(db.list_views().is_empty());
+    let query = Parser::parse("SELECT * FROM published LIMIT 10").unwrap();
+    assert!(matches!(
+        db.execute_query(&query, &HashMap::new()),
+        Err(Error::CollectionNotFound(_))
+    ));
+}
@@ -55,6 +55,7 @@ pub const CONDITION_TYPE_NAMES: &[&str] = &[
     "array_contains_all",
     "geo_distance",
     "geo_bbox",
+    "expression",
 ];
 
 /// Distance metric for vector similarity calculations.
@@ -150,6 +151,37 @@ impl DistanceMetric {
         }
     }
 
+    /// Calculates the metric on packed bitsets (see
+    /// [`pack_bits_u64`](simd_native::pack_bits_u64)) with 64-bit popcount
+    /// kernels.
+    ///
+    /// Returns `None` for metrics without a bitset form (everything but
+    /// `Hamming` and `Jaccard`). On 0/1 vectors the result equals
+    /// [`calculate`](Self::calculate) on their unpacked f32 form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bitsets have different word counts.
+    #[must_use]
+    #[inline]
+    pub fn calculate_bits(&self, a: &[u64], b: &[u64]) -> Option<f32> {
+        match self {
+            #[allow(clippy::cast_precision_loss)]
+            // Reason: bit counts stay far below 2^24 for any practical dimension.
+            Self::Hamming => Some(simd_native::hamming_binary_native(a, b) as f32),
+            Self::Jaccard => Some(simd_native::jaccard_binary_native(a, b)),
+            Self::Cosine | Self::Euclidean | Self::DotProduct => None,
+        }
+    }
+
+    /// Returns `true` for the metrics defined on binary vectors (`Hamming`,
+    /// `Jaccard`), which accept [`Point::from_bits`](crate::Point::from_bits)
+    /// points.
+    #[must_use]
+    pub const fn is_binary(&self) -> bool {
+        matches!(self, Self::Hamming | Self::Jaccard)
+    }
+
     /// Returns whether higher values indicate more similarity.
     #[must_use]
     pub const fn higher_is_better(&self) -> bool {
@@ -136,7 +136,8 @@ pub enum Error {
     /// Epoch mismatch (VELES-026).
     ///
     /// Indicates a stale mmap guard detected after a remap operation.
-    /// This is not recoverable — the guard must be re-acquired.
+    /// This is not recoverable — the guard must be re-acquired, which
+    /// retrying the operation does (see [`Error::is_retryable`]).
     #[error("[VELES-026] Epoch mismatch: {0}")]
     EpochMismatch(String),
 
@@ -221,6 +222,19 @@ pub enum Error {
         /// The maximum schema version this binary supports.
         supported: u32,
     },
+
+    /// Query limit exceeded (VELES-037).
+    ///
+    /// A query asked for more rows than `limits.max_query_limit` (or the
+    /// collection's override) allows: SELECT or MATCH `LIMIT`, traversal
+    /// `limit`, or scroll `batch_size`.
+    #[error("[VELES-037] Requested limit {requested} exceeds the maximum of {max} rows per query")]
+    QueryLimitExceeded {
+        /// The limit the query asked for.
+        requested: u64,
+        /// The effective maximum for the collection.
+        max: usize,
+    },
 }
 
 impl Error {
@@ -264,6 +278,7 @@ impl Error {
             Self::InvalidCollectionName { .. } => "VELES-034",
             Self::SnapshotBuildFailed(_) => "VELES-035",
             Self::IncompatibleSchemaVersion { .. } => "VELES-036",
+            Self::QueryLimitExceeded { .. } => "VELES-037",
         }
     }
 
@@ -281,8 +296,43 @@ impl Error {
                 | Self::IncompatibleSchemaVersion { .. }
         )
     }
+
+    /// Returns true if the same operation may succeed when retried later.
+    ///
+    /// Retryable errors are transient: the database directory is locked by
+    /// another process, a storage mmap was remapped (grown) under a reader,
+    /// an I/O call was interrupted or timed out, or a guard-rail shed load
+    /// (rate limit, open circuit breaker, full admission queue). Everything
+    /// else — invalid input, missing resources, exceeded hard limits,
+    /// corruption — fails the same way again, so retrying only adds load.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::DatabaseLocked(_) | Self::EpochMismatch(_) => true,
+            Self::Io(e) => matches!(
+                e.kind(),
+                std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::WouldBlock
+                    | std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::ResourceBusy
+            ),
+            Self::GuardRail(message) => LOAD_SHEDDING_GUARD_RAILS
+                .iter()
+                .any(|prefix| message.starts_with(prefix)),
+            _ => false,
+        }
+    }
 }
 
+/// Message prefixes of the guard-rail violations that shed load
+/// (`GuardRailViolation::sheds_load`): rate limit, open circuit breaker and
+/// full admission queue. Must match the violation's `Display`.
+const LOAD_SHEDDING_GUARD_RAILS: [&str; 3] = [
+    "Rate limit exceeded",
+    "Circuit breaker open",
+    "Collection overloaded",
+];
+
 /// Conversion from `VelesQL` `ParseError`.
 impl From<crate::velesql::ParseError> for Error {
     fn from(err: crate::velesql::ParseError) -> Self {
@@ -132,6 +132,65 @@ fn test_non_recoverable_errors() {
     assert!(!Error::Internal("unexpected state".into()).is_recoverable());
 }
 
+// -------------------------------------------------------------------------
+// Retryable tests
+// -------------------------------------------------------------------------
+
+#[test]
+fn test_retryable_errors() {
+    // Transient: the same call can succeed later
+    assert!(Error::DatabaseLocked("/data".into()).is_retryable());
+    assert!(Error::EpochMismatch("mmap was remapped".into()).is_retryable());
+    assert!(Error::Io(std::io::ErrorKind::Interrupted.into()).is_retryable());
+    assert!(Error::Io(std::io::ErrorKind::TimedOut.into()).is_retryable());
+}
+
+#[test]
+fn test_permanent_errors_are_not_retryable() {
+    assert!(!Error::CollectionNotFound("x".into()).is_retryable());
+    assert!(!Error::DimensionMismatch {
+        expected: 768,
+        actual: 512
+    }
+    .is_retryable());
+    assert!(!Error::IndexCorrupted("checksum mismatch".into()).is_retryable());
+    assert!(!Error::Io(std::io::ErrorKind::PermissionDenied.into()).is_retryable());
+    assert!(!Error::GuardRail("max_collections limit reached (8 / 8)".into()).is_retryable());
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_guard_rail_retryable_only_when_shedding_load() {
+    use crate::guardrails::GuardRailViolation;
+
+    let shed = [
+        GuardRailViolation::RateLimitExceeded { limit_qps: 10 },
+        GuardRailViolation::CircuitOpen {
+            recovery_in_seconds: 5,
+        },
+        GuardRailViolation::Overloaded {
+            kind: "search",
+            max_concurrent: 4,
+            waited_ms: 100,
+        },
+    ];
+    for violation in shed {
+        assert!(violation.sheds_load());
+        assert!(Error::from(violation).is_retryable());
+    }
+    let rejected = [
+        GuardRailViolation::DepthExceeded { max: 5, actual: 6 },
+        GuardRailViolation::Timeout {
+            max_ms: 10,
+            elapsed_ms: 11,
+        },
+    ];
+    for violation in rejected {
+        assert!(!violation.sheds_load());
+        assert!(!Error::from(violation).is_retryable());
+    }
+}
+
 // -------------------------------------------------------------------------
 // Professional API tests (for Python/Node exposure)
 // -------------------------------------------------------------------------
@@ -0,0 +1,198 @@
+//! String collations for filter comparisons and `ORDER BY`.
+//!
+//! A collation decides when two strings are "equal" for `Eq`, `Neq`, `In`,
+//! `Contains`, `Like` and `ILike`: both the payload value and the literal are
+//! folded with [`Collation::fold`] before comparing. Ordering comparisons
+//! (`Gt`, `Lt`, ...) stay bytewise under every collation.
+//!
+//! [`SortCollation`] is the per-key ordering of `ORDER BY ... COLLATE`.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+/// How strings are compared by equality, `IN` and `LIKE` filters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Collation {
+    /// Byte-for-byte comparison (the default).
+    #[default]
+    Binary,
+    /// Compares lower-cased strings: `'Electronics' = 'electronics'`.
+    CaseInsensitive,
+    /// Compares Unicode NFC forms, so a precomposed `é` equals `e` followed
+    /// by a combining acute accent.
+    Nfc,
+    /// NFC normalization plus case-insensitivity.
+    NfcCaseInsensitive,
+}
+
+impl Collation {
+    /// Every collation, in declaration order.
+    pub const ALL: [Self; 4] = [
+        Self::Binary,
+        Self::CaseInsensitive,
+        Self::Nfc,
+        Self::NfcCaseInsensitive,
+    ];
+
+    /// Returns the collation's name (`"binary"`, `"case_insensitive"`,
+    /// `"nfc"`, `"nfc_case_insensitive"`).
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Binary => "binary",
+            Self::CaseInsensitive => "case_insensitive",
+            Self::Nfc => "nfc",
+            Self::NfcCaseInsensitive => "nfc_case_insensitive",
+        }
+    }
+
+    /// Parses a collation name as returned by [`Self::as_str`]
+    /// (ASCII case-insensitive).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns `true` for [`Collation::Binary`].
+    #[must_use]
+    pub fn is_binary(self) -> bool {
+        self == Self::Binary
+    }
+
+    /// Returns `true` when the collation ignores case.
+    #[must_use]
+    pub fn is_case_insensitive(self) -> bool {
+        matches!(self, Self::CaseInsensitive | Self::NfcCaseInsensitive)
+    }
+
+    /// Folds `s` to the form compared under this collation.
+    ///
+    /// Borrows `s` when it is already in that form.
+    #[must_use]
+    pub fn fold(self, s: &str) -> Cow<'_, str> {
+        match self {
+            Self::Binary => Cow::Borrowed(s),
+            Self::CaseInsensitive => lowercase(s),
+            Self::Nfc => nfc(s),
+            Self::NfcCaseInsensitive => match lowercase(s) {
+                Cow::Borrowed(s) => nfc(s),
+                Cow::Owned(lower) => Cow::Owned(nfc(&lower).into_owned()),
+            },
+        }
+    }
+
+    /// Whether `a` and `b` are equal under this collation.
+    #[must_use]
+    pub fn equals(self, a: &str, b: &str) -> bool {
+        a == b || (!self.is_binary() && self.fold(a) == self.fold(b))
+    }
+}
+
+impl std::fmt::Display for Collation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn lowercase(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() && !s.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(s.to_lowercase())
+    }
+}
+
+fn nfc(s: &str) -> Cow<'_, str> {
+    if is_nfc_quick(s.chars()) == IsNormalized::Yes {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(s.nfc().collect())
+    }
+}
+
+/// String ordering of one `ORDER BY` key (`ORDER BY name COLLATE ci_ai`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SortCollation {
+    /// Code point order (the default): `'Zoe' < 'abel'`.
+    #[default]
+    Binary,
+    /// Case-insensitive order of the NFC forms: `'abel' < 'Zoe'`.
+    Ci,
+    /// Case- and accent-insensitive order: `'Émile'` sorts with `'emile'`,
+    /// before `'Eric'`.
+    CiAi,
+}
+
+impl SortCollation {
+    /// Every sort collation, in declaration order.
+    pub const ALL: [Self; 3] = [Self::Binary, Self::Ci, Self::CiAi];
+
+    /// Returns the collation's name (`"binary"`, `"ci"`, `"ci_ai"`).
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Binary => "binary",
+            Self::Ci => "ci",
+            Self::CiAi => "ci_ai",
+        }
+    }
+
+    /// Parses a collation name as returned by [`Self::as_str`]
+    /// (ASCII case-insensitive).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns `true` for [`SortCollation::Binary`].
+    #[must_use]
+    pub fn is_binary(&self) -> bool {
+        *self == Self::Binary
+    }
+
+    /// Orders two strings. Strings that only differ in case (or accents,
+    /// under `ci_ai`) fall back to code point order, so the order is total.
+    #[must_use]
+    pub fn compare(self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::Binary => a.cmp(b),
+            Self::Ci => Collation::NfcCaseInsensitive
+                .fold(a)
+                .cmp(&Collation::NfcCaseInsensitive.fold(b))
+                .then_with(|| a.cmp(b)),
+            Self::CiAi => strip_accents_lowercase(a)
+                .cmp(&strip_accents_lowercase(b))
+                .then_with(|| a.cmp(b)),
+        }
+    }
+}
+
+impl std::fmt::Display for SortCollation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Lower-cases `s` and removes combining marks from its NFD form.
+fn strip_accents_lowercase(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return lowercase(s);
+    }
+    Cow::Owned(
+        s.nfd()
+            .filter(|c| !unicode_normalization::char::is_combining_mark(*c))
+            .flat_map(char::to_lowercase)
+            .collect(),
+    )
+}
@@ -0,0 +1,136 @@
+//! Tests for `collation` - collated string comparisons in filters.
+
+use serde_json::json;
+
+use super::{Collation, Condition, Filter, SortCollation};
+
+/// "é" as `e` followed by U+0301 COMBINING ACUTE ACCENT.
+const DECOMPOSED_E_ACUTE: &str = "e\u{301}";
+
+#[test]
+fn parse_round_trips_names_case_insensitively() {
+    for collation in Collation::ALL {
+        assert_eq!(Collation::parse(collation.as_str()), Some(collation));
+    }
+    assert_eq!(
+        Collation::parse("Case_Insensitive"),
+        Some(Collation::CaseInsensitive)
+    );
+    assert_eq!(Collation::parse("latin1"), None);
+}
+
+#[test]
+fn fold_lowercases_and_normalizes() {
+    assert_eq!(Collation::Binary.fold("Électronique"), "Électronique");
+    assert_eq!(
+        Collation::CaseInsensitive.fold("ÉLECTRONICS"),
+        "électronics"
+    );
+    assert_eq!(Collation::Nfc.fold(DECOMPOSED_E_ACUTE), "é");
+    assert_eq!(
+        Collation::NfcCaseInsensitive.fold(&format!("CAF{DECOMPOSED_E_ACUTE}")),
+        "café"
+    );
+}
+
+#[test]
+fn eq_respects_collation() {
+    let payload = json!({"category": "Electronics", "name": "caf\u{e9}"});
+    let by_case = Condition::eq("category", "electronics");
+    let by_form = Condition::eq("name", format!("caf{DECOMPOSED_E_ACUTE}"));
+
+    assert!(!Filter::new(by_case.clone()).matches(&payload));
+    assert!(Filter::new(by_case.clone())
+        .with_collation(Collation::CaseInsensitive)
+        .matches(&payload));
+    assert!(!Filter::new(by_case.clone())
+        .with_collation(Collation::Nfc)
+        .matches(&payload));
+
+    assert!(!Filter::new(by_form.clone()).matches(&payload));
+    assert!(Filter::new(by_form)
+        .with_collation(Collation::Nfc)
+        .matches(&payload));
+    assert!(Filter::new(by_case)
+        .with_collation(Collation::NfcCaseInsensitive)
+        .matches(&payload));
+}
+
+#[test]
+fn neq_and_in_respect_collation() {
+    let payload = json!({"category": "Electronics"});
+    let ci = Collation::CaseInsensitive;
+
+    assert!(Filter::new(Condition::neq("category", "electronics")).matches(&payload));
+    assert!(!Filter::new(Condition::neq("category", "electronics"))
+        .with_collation(ci)
+        .matches(&payload));
+
+    let in_list = Condition::In {
+        field: "category".into(),
+        values: vec![json!("books"), json!("ELECTRONICS")],
+    };
+    assert!(!Filter::new(in_list.clone()).matches(&payload));
+    assert!(Filter::new(in_list).with_collation(ci).matches(&payload));
+}
+
+#[test]
+fn like_becomes_case_insensitive_under_collation() {
+    let payload = json!({"category": "Electronics"});
+    let like = Condition::Like {
+        field: "category".into(),
+        pattern: "elec%".into(),
+    };
+    assert!(!Filter::new(like.clone()).matches(&payload));
+    assert!(Filter::new(like)
+        .with_collation(Collation::CaseInsensitive)
+        .matches(&payload));
+}
+
+#[test]
+fn ordering_and_non_strings_are_unaffected() {
+    let payload = json!({"category": "b", "price": 10});
+    let ci = Collation::CaseInsensitive;
+    assert!(Filter::new(Condition::gt("category", "B"))
+        .with_collation(ci)
+        .matches(&payload));
+    assert!(Filter::new(Condition::eq("price", 10))
+        .with_collation(ci)
+        .matches(&payload));
+    assert!(!Filter::new(Condition::eq("price", "10"))
+        .with_collation(ci)
+        .matches(&payload));
+}
+
+#[test]
+fn collation_survives_serde_and_defaults_to_absent() {
+    let filter = Filter::new(Condition::eq("a", "x")).with_collation(Collation::Nfc);
+    let json = serde_json::to_value(&filter).unwrap();
+    assert_eq!(json["collation"], "nfc");
+    let back: Filter = serde_json::from_value(json).unwrap();
+    assert_eq!(back.collation, Some(Collation::Nfc));
+
+    let plain = serde_json::to_value(Filter::new(Condition::eq("a", "x"))).unwrap();
+    assert!(plain.get("collation").is_none());
+}
+
+#[test]
+fn sort_collation_parses_and_orders_strings() {
+    for collation in SortCollation::ALL {
+        assert_eq!(SortCollation::parse(collation.as_str()), Some(collation));
+    }
+    assert_eq!(SortCollation::parse("CI_AI"), Some(SortCollation::CiAi));
+    assert_eq!(SortCollation::parse("fr_FR"), None);
+
+    let mut names = vec!["eric", "Zoé", "Émile", "adam", "Eve", "émile"];
+    names.sort_by(|a, b| SortCollation::CiAi.compare(a, b));
+    assert_eq!(names, ["adam", "Émile", "émile", "eric", "Eve", "Zoé"]);
+    names.sort_by(|a, b| SortCollation::Ci.compare(a, b));
+    assert_eq!(names, ["adam", "eric", "Eve", "Zoé", "Émile", "émile"]);
+    names.sort_by(|a, b| SortCollation::Binary.compare(a, b));
+    assert_eq!(names, ["Eve", "Zoé", "adam", "eric", "Émile", "émile"]);
+    assert_eq!(
+        SortCollation::CiAi.compare(DECOMPOSED_E_ACUTE, "E"),
+        std::cmp::Ordering::Greater
+    );
+}
@@ -196,6 +196,11 @@ impl From<crate::velesql::Condition> for Condition {
                 lat_max: gb.lat_max,
                 lng_max: gb.lng_max,
             },
+            crate::velesql::Condition::Expression(ec) => Self::Expression {
+                left: ec.left,
+                operator: ec.operator,
+                right: ec.right,
+            },
         }
     }
 }
@@ -0,0 +1,230 @@
+//! Scalar expression evaluation over JSON payloads.
+//!
+//! Backs `WHERE price * quantity > 1000` and computed SELECT items such as
+//! `price * 1.2 AS price_with_tax`.
+//!
+//! # Coercion rules
+//!
+//! - A missing or `null` field evaluates to `null`, which propagates through
+//!   every operator.
+//! - `+ - * /` need numbers. `Int op Int` stays an integer (division
+//!   truncates, like `UPDATE ... SET`), any float operand yields a float.
+//!   Non-numeric operands, division by zero, integer overflow and non-finite
+//!   results yield `null`.
+//! - `||` renders strings, numbers and booleans as text; arrays and objects
+//!   yield `null`.
+//! - Strings are never parsed as numbers.
+//! - `floor`, `ceil` and `bucket` need numbers; integral results are
+//!   integers, so `bucket(9.5, 10)` and `bucket(3, 10)` both give `0`.
+//!   `bucket` needs a positive width.
+//! - `date_trunc` takes epoch seconds (fractions are dropped) and returns
+//!   the UTC start of the unit as epoch seconds.
+
+use serde_json::{Number, Value};
+
+use crate::velesql::{ArithmeticExpr, ArithmeticOp, DateTruncUnit, ScalarFunction};
+
+/// Maximum expression nesting evaluated; deeper subtrees yield `null`.
+/// Matches `DEFAULT_MAX_AST_DEPTH` (64) from validation.
+const MAX_EXPRESSION_DEPTH: u8 = 64;
+
+/// Evaluates a scalar expression against a payload, following the module-level
+/// coercion rules.
+#[must_use]
+pub fn evaluate_expression(expr: &ArithmeticExpr, payload: &Value) -> Value {
+    evaluate_inner(expr, payload, 0)
+}
+
+fn evaluate_inner(expr: &ArithmeticExpr, payload: &Value, depth: u8) -> Value {
+    if depth >= MAX_EXPRESSION_DEPTH {
+        return Value::Null;
+    }
+    match expr {
+        ArithmeticExpr::Literal(v) => literal_number(*v),
+        ArithmeticExpr::Text(s) => Value::String(s.clone()),
+        ArithmeticExpr::Variable(path) => lookup(payload, path).cloned().unwrap_or(Value::Null),
+        ArithmeticExpr::BinaryOp { left, op, right } => {
+            let left = evaluate_inner(left, payload, depth + 1);
+            let right = evaluate_inner(right, payload, depth + 1);
+            if *op == ArithmeticOp::Concat {
+                concat(&left, &right)
+            } else {
+                arith(*op, &left, &right)
+            }
+        }
+        ArithmeticExpr::Function { function, args } => {
+            let args: Vec<Value> = args
+                .iter()
+                .map(|arg| evaluate_inner(arg, payload, depth + 1))
+                .collect();
+            call(*function, &args)
+        }
+        // similarity() has no meaning over a bare payload.
+        _ => Value::Null,
+    }
+}
+
+fn lookup<'a>(payload: &'a Value, path: &str) -> Option<&'a Value> {
+    super::lookup_path(payload, path)
+}
+
+/// Integral literals stay integers so `quantity * 2` keeps `quantity` an integer.
+fn literal_number(v: f64) -> Value {
+    // 2^53: beyond this, f64 no longer represents every integer exactly.
+    const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+    if v.fract() == 0.0 && v.abs() <= MAX_EXACT {
+        #[allow(clippy::cast_possible_truncation)] // Reason: integral and within ±2^53.
+        return Value::from(v as i64);
+    }
+    float_value(v)
+}
+
+fn float_value(v: f64) -> Value {
+    Number::from_f64(v).map_or(Value::Null, Value::Number)
+}
+
+fn arith(op: ArithmeticOp, left: &Value, right: &Value) -> Value {
+    let (Value::Number(a), Value::Number(b)) = (left, right) else {
+        return Value::Null;
+    };
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        let result = match op {
+            ArithmeticOp::Add => a.checked_add(b),
+            ArithmeticOp::Sub => a.checked_sub(b),
+            ArithmeticOp::Mul => a.checked_mul(b),
+            ArithmeticOp::Div => a.checked_div(b),
+            _ => None,
+        };
+        return result.map_or(Value::Null, Value::from);
+    }
+    let (Some(a), Some(b)) = (a.as_f64(), b.as_f64()) else {
+        return Value::Null;
+    };
+    let result = match op {
+        ArithmeticOp::Add => a + b,
+        ArithmeticOp::Sub => a - b,
+        ArithmeticOp::Mul => a * b,
+        ArithmeticOp::Div if b == 0.0 => return Value::Null,
+        ArithmeticOp::Div => a / b,
+        _ => return Value::Null,
+    };
+    float_value(result)
+}
+
+fn concat(left: &Value, right: &Value) -> Value {
+    match (as_text(left), as_text(right)) {
+        (Some(l), Some(r)) => Value::String(l + &r),
+        _ => Value::Null,
+    }
+}
+
+fn as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn call(function: ScalarFunction, args: &[Value]) -> Value {
+    match (function, args) {
+        (ScalarFunction::Floor, [Value::Number(x)]) => round_with(x, f64::floor),
+        (ScalarFunction::Ceil, [Value::Number(x)]) => round_with(x, f64::ceil),
+        (ScalarFunction::Bucket, [Value::Number(x), Value::Number(width)]) => bucket(x, width),
+        (ScalarFunction::DateTrunc(unit), [Value::Number(ts)]) => {
+            let secs = ts.as_i64().or_else(|| ts.as_f64().and_then(float_to_i64));
+            secs.and_then(|secs| truncate_epoch_seconds(unit, secs))
+                .map_or(Value::Null, Value::from)
+        }
+        _ => Value::Null,
+    }
+}
+
+fn round_with(x: &Number, round: fn(f64) -> f64) -> Value {
+    if x.is_i64() || x.is_u64() {
+        return Value::Number(x.clone());
+    }
+    x.as_f64().map_or(Value::Null, |v| literal_number(round(v)))
+}
+
+fn bucket(x: &Number, width: &Number) -> Value {
+    if let (Some(x), Some(width)) = (x.as_i64(), width.as_i64()) {
+        if width <= 0 {
+            return Value::Null;
+        }
+        return x
+            .div_euclid(width)
+            .checked_mul(width)
+            .map_or(Value::Null, Value::from);
+    }
+    let (Some(x), Some(width)) = (x.as_f64(), width.as_f64()) else {
+        return Value::Null;
+    };
+    if width <= 0.0 || !width.is_finite() {
+        return Value::Null;
+    }
+    literal_number((x / width).floor() * width)
+}
+
+fn float_to_i64(v: f64) -> Option<i64> {
+    // ±2^62 keeps the conversion exact-range and the calendar math overflow-free.
+    const LIMIT: f64 = 4_611_686_018_427_387_904.0;
+    #[allow(clippy::cast_possible_truncation)] // Reason: finite and within ±2^62.
+    (v.is_finite() && v.abs() < LIMIT).then(|| v.floor() as i64)
+}
+
+/// Truncates epoch seconds to the UTC start of `unit` (weeks start on
+/// Monday). `None` for timestamps too far out for the calendar math.
+pub(crate) fn truncate_epoch_seconds(unit: DateTruncUnit, secs: i64) -> Option<i64> {
+    const DAY: i64 = 86_400;
+    // ~292 million years either way keeps every intermediate in range.
+    if secs.unsigned_abs() > 1 << 53 {
+        return None;
+    }
+    let days = secs.div_euclid(DAY);
+    let start = match unit {
+        DateTruncUnit::Second => return Some(secs),
+        DateTruncUnit::Minute => return Some(secs - secs.rem_euclid(60)),
+        DateTruncUnit::Hour => return Some(secs - secs.rem_euclid(3600)),
+        DateTruncUnit::Day => days,
+        // 1970-01-01 was a Thursday (Monday-based weekday 3).
+        DateTruncUnit::Week => days - (days + 3).rem_euclid(7),
+        DateTruncUnit::Month => {
+            let (year, month, _) = civil_from_days(days);
+            days_from_civil(year, month, 1)
+        }
+        DateTruncUnit::Quarter => {
+            let (year, month, _) = civil_from_days(days);
+            days_from_civil(year, (month - 1) / 3 * 3 + 1, 1)
+        }
+        DateTruncUnit::Year => days_from_civil(civil_from_days(days).0, 1, 1),
+    };
+    Some(start * DAY)
+}
+
+/// Proleptic Gregorian (year, month, day) of a day count since 1970-01-01
+/// (Howard Hinnant's `civil_from_days`).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let day_of_year = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Day count since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + day_of_year;
+    era * 146_097 + doe - 719_468
+}
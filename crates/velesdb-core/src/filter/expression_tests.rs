@@ -0,0 +1,222 @@
+//! Tests for `expression` module - scalar expression evaluation.
+
+use serde_json::{json, Value};
+
+use super::{evaluate_expression, Condition};
+use crate::velesql::{ArithmeticExpr, ArithmeticOp, CompareOp, DateTruncUnit, ScalarFunction};
+
+fn lit(v: f64) -> ArithmeticExpr {
+    ArithmeticExpr::Literal(v)
+}
+
+fn var(name: &str) -> ArithmeticExpr {
+    ArithmeticExpr::Variable(name.to_string())
+}
+
+fn text(s: &str) -> ArithmeticExpr {
+    ArithmeticExpr::Text(s.to_string())
+}
+
+fn bin(left: ArithmeticExpr, op: ArithmeticOp, right: ArithmeticExpr) -> ArithmeticExpr {
+    ArithmeticExpr::BinaryOp {
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+    }
+}
+
+#[test]
+fn test_integer_arithmetic_stays_integer() {
+    let payload = json!({"price": 7, "qty": 3});
+    let product = bin(var("price"), ArithmeticOp::Mul, var("qty"));
+    assert_eq!(evaluate_expression(&product, &payload), json!(21));
+    let quotient = bin(var("price"), ArithmeticOp::Div, lit(2.0));
+    assert_eq!(evaluate_expression(&quotient, &payload), json!(3));
+}
+
+#[test]
+fn test_float_operand_promotes_to_float() {
+    let payload = json!({"price": 10});
+    let taxed = bin(var("price"), ArithmeticOp::Mul, lit(1.5));
+    assert_eq!(evaluate_expression(&taxed, &payload), json!(15.0));
+}
+
+#[test]
+fn test_null_propagation() {
+    let payload = json!({"price": 10, "missing_is_null": null, "name": "x"});
+    for operand in ["absent", "missing_is_null", "name"] {
+        let expr = bin(var("price"), ArithmeticOp::Add, var(operand));
+        assert_eq!(
+            evaluate_expression(&expr, &payload),
+            Value::Null,
+            "{operand}"
+        );
+    }
+}
+
+#[test]
+fn test_division_by_zero_and_overflow_yield_null() {
+    let payload = json!({"n": i64::MAX, "f": 1.5});
+    assert_eq!(
+        evaluate_expression(&bin(var("n"), ArithmeticOp::Div, lit(0.0)), &payload),
+        Value::Null
+    );
+    assert_eq!(
+        evaluate_expression(&bin(var("f"), ArithmeticOp::Div, lit(0.0)), &payload),
+        Value::Null
+    );
+    assert_eq!(
+        evaluate_expression(&bin(var("n"), ArithmeticOp::Add, lit(1.0)), &payload),
+        Value::Null
+    );
+}
+
+#[test]
+fn test_concat_renders_scalars_as_text() {
+    let payload = json!({"first": "Ada", "n": 2, "ok": true, "tags": ["a"]});
+    let greeting = bin(
+        bin(var("first"), ArithmeticOp::Concat, text(" #")),
+        ArithmeticOp::Concat,
+        var("n"),
+    );
+    assert_eq!(evaluate_expression(&greeting, &payload), json!("Ada #2"));
+    let flag = bin(text("ok="), ArithmeticOp::Concat, var("ok"));
+    assert_eq!(evaluate_expression(&flag, &payload), json!("ok=true"));
+    let array = bin(var("first"), ArithmeticOp::Concat, var("tags"));
+    assert_eq!(evaluate_expression(&array, &payload), Value::Null);
+}
+
+#[test]
+fn test_strings_are_not_coerced_to_numbers() {
+    let payload = json!({"price": "10"});
+    let expr = bin(var("price"), ArithmeticOp::Mul, lit(2.0));
+    assert_eq!(evaluate_expression(&expr, &payload), Value::Null);
+}
+
+#[test]
+fn test_nested_path_lookup() {
+    let payload = json!({"meta": {"price": 4}});
+    let expr = bin(var("meta.price"), ArithmeticOp::Sub, lit(1.0));
+    assert_eq!(evaluate_expression(&expr, &payload), json!(3));
+}
+
+#[test]
+fn test_expression_condition_matches() {
+    let cond = Condition::Expression {
+        left: bin(var("price"), ArithmeticOp::Mul, var("qty")),
+        operator: CompareOp::Gt,
+        right: lit(1000.0),
+    };
+    assert!(cond.matches(&json!({"price": 300, "qty": 4})));
+    assert!(!cond.matches(&json!({"price": 300, "qty": 3})));
+    assert!(!cond.matches(&json!({"price": 300})));
+}
+
+#[test]
+fn test_null_never_matches_not_equal() {
+    let cond = Condition::Expression {
+        left: bin(var("a"), ArithmeticOp::Add, var("b")),
+        operator: CompareOp::NotEq,
+        right: lit(1.0),
+    };
+    assert!(!cond.matches(&json!({"a": 1})));
+    assert!(cond.matches(&json!({"a": 1, "b": 1})));
+}
+
+#[test]
+fn test_expression_condition_serde_round_trip() {
+    let cond = Condition::Expression {
+        left: bin(var("first"), ArithmeticOp::Concat, var("last")),
+        operator: CompareOp::Eq,
+        right: text("AdaLovelace"),
+    };
+    let json = serde_json::to_value(&cond).expect("serialize");
+    assert_eq!(json["type"], "expression");
+    let back: Condition = serde_json::from_value(json).expect("deserialize");
+    assert!(back.matches(&json!({"first": "Ada", "last": "Lovelace"})));
+}
+
+fn call(function: ScalarFunction, args: Vec<ArithmeticExpr>) -> ArithmeticExpr {
+    ArithmeticExpr::Function { function, args }
+}
+
+#[test]
+fn test_floor_ceil_and_bucket() {
+    let payload = json!({"price": 12.5, "qty": -7, "label": "x"});
+    let floor = call(ScalarFunction::Floor, vec![var("price")]);
+    assert_eq!(evaluate_expression(&floor, &payload), json!(12));
+    let ceil = call(ScalarFunction::Ceil, vec![var("price")]);
+    assert_eq!(evaluate_expression(&ceil, &payload), json!(13));
+
+    let bucket =
+        |value: &str, width: f64| call(ScalarFunction::Bucket, vec![var(value), lit(width)]);
+    assert_eq!(
+        evaluate_expression(&bucket("price", 10.0), &payload),
+        json!(10)
+    );
+    assert_eq!(
+        evaluate_expression(&bucket("price", 0.5), &payload),
+        json!(12.5)
+    );
+    // Buckets floor towards negative infinity.
+    assert_eq!(
+        evaluate_expression(&bucket("qty", 5.0), &payload),
+        json!(-10)
+    );
+    assert_eq!(
+        evaluate_expression(&bucket("qty", 0.0), &payload),
+        Value::Null
+    );
+    assert_eq!(
+        evaluate_expression(&bucket("label", 5.0), &payload),
+        Value::Null
+    );
+}
+
+#[test]
+fn test_date_trunc_units() {
+    // 2024-05-15T13:45:30Z, a Wednesday.
+    let payload = json!({"ts": 1_715_780_730, "fractional": 1_715_780_730.75, "text": "2024"});
+    let trunc = |unit: DateTruncUnit, field: &str| {
+        evaluate_expression(
+            &call(ScalarFunction::DateTrunc(unit), vec![var(field)]),
+            &payload,
+        )
+    };
+    let cases = [
+        (DateTruncUnit::Second, 1_715_780_730),
+        (DateTruncUnit::Minute, 1_715_780_700),
+        (DateTruncUnit::Hour, 1_715_778_000),
+        (DateTruncUnit::Day, 1_715_731_200),
+        // Monday 2024-05-13.
+        (DateTruncUnit::Week, 1_715_558_400),
+        (DateTruncUnit::Month, 1_714_521_600),
+        (DateTruncUnit::Quarter, 1_711_929_600),
+        (DateTruncUnit::Year, 1_704_067_200),
+    ];
+    for (unit, expected) in cases {
+        assert_eq!(trunc(unit, "ts"), json!(expected), "{}", unit.as_str());
+    }
+    assert_eq!(
+        trunc(DateTruncUnit::Second, "fractional"),
+        json!(1_715_780_730)
+    );
+    assert_eq!(trunc(DateTruncUnit::Day, "text"), Value::Null);
+}
+
+#[test]
+fn test_date_trunc_before_epoch() {
+    // 1969-12-31T23:00:00Z truncates back into 1969, not forward to 1970.
+    let payload = json!({"ts": -3_600});
+    let month = call(
+        ScalarFunction::DateTrunc(DateTruncUnit::Month),
+        vec![var("ts")],
+    );
+    assert_eq!(evaluate_expression(&month, &payload), json!(-2_678_400));
+    let week = call(
+        ScalarFunction::DateTrunc(DateTruncUnit::Week),
+        vec![var("ts")],
+    );
+    // Monday 1969-12-29.
+    assert_eq!(evaluate_expression(&week, &payload), json!(-259_200));
+}
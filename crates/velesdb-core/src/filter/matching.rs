@@ -1,6 +1,6 @@
 //! Condition matching logic and helper functions.
 
-use super::Condition;
+use super::{Collation, Condition};
 use crate::metrics::global_guardrails_metrics;
 use serde_json::Value;
 
@@ -59,7 +59,15 @@ pub(super) fn json_value_cmp(a: &Value, b: &Value) -> std::cmp::Ordering {
 ///   `json_value_cmp` to narrow position, then `values_equal` for the final
 ///   check — preserving epsilon semantics on both paths and requiring
 ///   `values` to be pre-sorted (`conversion.rs::convert_in` guarantees this).
-fn in_list_matches(field_val: &Value, values: &[Value]) -> bool {
+///
+/// Under a non-binary collation a string `field_val` is compared against
+/// every value, since the list is sorted bytewise rather than by collation.
+fn in_list_matches(field_val: &Value, values: &[Value], collation: Collation) -> bool {
+    if !collation.is_binary() && field_val.is_string() {
+        return values
+            .iter()
+            .any(|val| values_equal_collated(field_val, val, collation));
+    }
     if values.len() > IN_BINARY_SEARCH_THRESHOLD {
         debug_assert!(
             values
@@ -145,16 +153,22 @@ fn extract_geo_point(v: &Value) -> Option<(f64, f64)> {
 }
 
 impl Condition {
-    /// Evaluates the condition against a payload.
+    /// Evaluates the condition against a payload, comparing strings
+    /// byte-for-byte.
     #[must_use]
     pub fn matches(&self, payload: &Value) -> bool {
+        self.matches_collated(payload, Collation::Binary)
+    }
+
+    /// Evaluates the condition against a payload, comparing strings in
+    /// `Eq`, `Neq`, `In`, `Contains`, `Like` and `ILike` under `collation`.
+    #[must_use]
+    pub fn matches_collated(&self, payload: &Value, collation: Collation) -> bool {
         match self {
-            Self::Eq { field, value } => {
-                get_field(payload, field).is_some_and(|v| values_equal(v, value))
-            }
-            Self::Neq { field, value } => {
-                get_field(payload, field).is_none_or(|v| !values_equal(v, value))
-            }
+            Self::Eq { field, value } => get_field(payload, field)
+                .is_some_and(|v| values_equal_collated(v, value, collation)),
+            Self::Neq { field, value } => get_field(payload, field)
+                .is_none_or(|v| !values_equal_collated(v, value, collation)),
             Self::Gt { field, value } => get_field(payload, field)
                 .is_some_and(|v| compare_values(v, value).is_some_and(std::cmp::Ordering::is_gt)),
             Self::Gte { field, value } => get_field(payload, field)
@@ -164,19 +178,29 @@ impl Condition {
             Self::Lte { field, value } => get_field(payload, field)
                 .is_some_and(|v| compare_values(v, value).is_some_and(std::cmp::Ordering::is_le)),
             Self::In { field, values } => {
-                get_field(payload, field).is_some_and(|v| in_list_matches(v, values))
+                get_field(payload, field).is_some_and(|v| in_list_matches(v, values, collation))
             }
-            Self::Contains { field, value } => get_field(payload, field)
-                .is_some_and(|v| v.as_str().is_some_and(|s| s.contains(value.as_str()))),
+            Self::Contains { field, value } => get_field(payload, field).is_some_and(|v| {
+                v.as_str()
+                    .is_some_and(|s| collation.fold(s).contains(&*collation.fold(value)))
+            }),
             Self::IsNull { field } => get_field(payload, field).is_none_or(Value::is_null),
             Self::IsNotNull { field } => get_field(payload, field).is_some_and(|v| !v.is_null()),
-            Self::And { conditions } => conditions.iter().all(|c| c.matches(payload)),
-            Self::Or { conditions } => conditions.iter().any(|c| c.matches(payload)),
-            Self::Not { condition } => !condition.matches(payload),
-            Self::Like { field, pattern } => get_field(payload, field)
-                .is_some_and(|v| v.as_str().is_some_and(|s| like_match(s, pattern, false))),
-            Self::ILike { field, pattern } => get_field(payload, field)
-                .is_some_and(|v| v.as_str().is_some_and(|s| like_match(s, pattern, true))),
+            Self::And { conditions } => conditions
+                .iter()
+                .all(|c| c.matches_collated(payload, collation)),
+            Self::Or { conditions } => conditions
+                .iter()
+                .any(|c| c.matches_collated(payload, collation)),
+            Self::Not { condition } => !condition.matches_collated(payload, collation),
+            Self::Like { field, pattern } => get_field(payload, field).is_some_and(|v| {
+                v.as_str()
+                    .is_some_and(|s| like_match_collated(s, pattern, false, collation))
+            }),
+            Self::ILike { field, pattern } => get_field(payload, field).is_some_and(|v| {
+                v.as_str()
+                    .is_some_and(|s| like_match_collated(s, pattern, true, collation))
+            }),
             Self::ArrayContains { field, value } => match_array_condition(
                 payload,
                 field,
@@ -203,17 +227,31 @@ impl Condition {
                 lat_max,
                 lng_max,
             } => match_geo_bbox(payload, field, *lat_min, *lng_min, *lat_max, *lng_max),
+            Self::Expression {
+                left,
+                operator,
+                right,
+            } => match_expression(payload, left, *operator, right),
         }
     }
 }
 
 /// Gets a field from a JSON payload, supporting dot notation for nested fields.
 fn get_field<'a>(payload: &'a Value, field: &str) -> Option<&'a Value> {
-    let mut current = payload;
-    for part in field.split('.') {
-        current = current.get(part)?;
-    }
-    Some(current)
+    lookup_path(payload, field)
+}
+
+/// Resolves a dotted payload path (`meta.source`).
+///
+/// A numeric segment indexes into an array (`items.0.sku`), which is how
+/// `json_extract(payload, '$.items[0].sku')` is lowered. Returns `None` when
+/// a segment is missing or traverses a scalar.
+pub(crate) fn lookup_path<'a>(payload: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(payload, |current, part| match current {
+            Value::Array(items) => items.get(part.parse::<usize>().ok()?),
+            _ => current.get(part),
+        })
 }
 
 /// Compares two JSON values for equality.
@@ -234,6 +272,32 @@ fn values_equal(a: &Value, b: &Value) -> bool {
     }
 }
 
+/// [`values_equal`], with string pairs compared under `collation`.
+fn values_equal_collated(a: &Value, b: &Value, collation: Collation) -> bool {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => collation.equals(a, b),
+        _ => values_equal(a, b),
+    }
+}
+
+/// [`like_match`] with the value and pattern folded under `collation`; a
+/// case-insensitive collation turns `LIKE` into `ILIKE`.
+pub(crate) fn like_match_collated(
+    value: &str,
+    pattern: &str,
+    case_insensitive: bool,
+    collation: Collation,
+) -> bool {
+    if collation.is_binary() {
+        return like_match(value, pattern, case_insensitive);
+    }
+    like_match(
+        &collation.fold(value),
+        &collation.fold(pattern),
+        case_insensitive || collation.is_case_insensitive(),
+    )
+}
+
 /// Compares two JSON values.
 ///
 /// Returns `None` if the types are incompatible (e.g. Number vs String,
@@ -276,7 +340,7 @@ fn compare_values(a: &Value, b: &Value) -> Option<std::cmp::Ordering> {
 /// * `text` - The string to match against
 /// * `pattern` - The SQL LIKE pattern
 /// * `case_insensitive` - If true, performs case-insensitive matching (ILIKE)
-fn like_match(text: &str, pattern: &str, case_insensitive: bool) -> bool {
+pub(crate) fn like_match(text: &str, pattern: &str, case_insensitive: bool) -> bool {
     if pattern.len() > LIKE_MAX_PATTERN_BYTES {
         global_guardrails_metrics().record_like_guardrail_rejected();
         return false;
@@ -456,6 +520,31 @@ fn haversine_distance_m(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
     EARTH_RADIUS_M * 2.0 * a.sqrt().atan2((1.0 - a).sqrt())
 }
 
+/// Compares two scalar expressions; a `null` on either side never matches,
+/// not even for `!=`.
+fn match_expression(
+    payload: &Value,
+    left: &crate::velesql::ArithmeticExpr,
+    op: crate::velesql::CompareOp,
+    right: &crate::velesql::ArithmeticExpr,
+) -> bool {
+    use crate::velesql::CompareOp;
+    let left = super::evaluate_expression(left, payload);
+    let right = super::evaluate_expression(right, payload);
+    if left.is_null() || right.is_null() {
+        return false;
+    }
+    let ordering = || compare_values(&left, &right);
+    match op {
+        CompareOp::Eq => values_equal(&left, &right),
+        CompareOp::NotEq => !values_equal(&left, &right),
+        CompareOp::Gt => ordering().is_some_and(std::cmp::Ordering::is_gt),
+        CompareOp::Gte => ordering().is_some_and(std::cmp::Ordering::is_ge),
+        CompareOp::Lt => ordering().is_some_and(std::cmp::Ordering::is_lt),
+        CompareOp::Lte => ordering().is_some_and(std::cmp::Ordering::is_le),
+    }
+}
+
 /// Applies a comparison operator to a geo-distance value and threshold.
 fn compare_geo_distance(dist: f64, threshold: f64, op: crate::velesql::CompareOp) -> bool {
     use crate::velesql::CompareOp;
@@ -19,11 +19,27 @@
 //! ```
 
 mod builders;
+mod collation;
+#[cfg(test)]
+mod collation_tests;
 mod conversion;
 #[cfg(test)]
 mod conversion_tests;
+mod expression;
+#[cfg(test)]
+mod expression_tests;
 mod matching;
 
+pub use collation::{Collation, SortCollation};
+pub use expression::evaluate_expression;
+pub(crate) use matching::lookup_path;
+// Shared with (persistence-only) ORDER BY arithmetic.
+#[cfg(feature = "persistence")]
+pub(crate) use expression::truncate_epoch_seconds;
+// LIKE helpers are shared with the (persistence-only) column store.
+#[cfg(feature = "persistence")]
+pub(crate) use matching::{like_match, like_match_collated};
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -32,13 +48,35 @@ use serde_json::Value;
 pub struct Filter {
     /// The root condition of the filter.
     pub condition: Condition,
+    /// String collation for this filter.
+    ///
+    /// `None` uses the collection's default (`QueryDefaults::collation`),
+    /// which is [`Collation::Binary`] unless configured otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collation: Option<Collation>,
 }
 
 impl Filter {
     /// Creates a new filter with the given condition.
     #[must_use]
     pub fn new(condition: Condition) -> Self {
-        Self { condition }
+        Self {
+            condition,
+            collation: None,
+        }
+    }
+
+    /// Sets the string collation, overriding the collection's default.
+    #[must_use]
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = Some(collation);
+        self
+    }
+
+    /// Returns the collation the filter is evaluated with.
+    #[must_use]
+    pub fn effective_collation(&self) -> Collation {
+        self.collation.unwrap_or_default()
     }
 
     /// Deserializes a `Filter` from a JSON value.
@@ -56,7 +94,8 @@ impl Filter {
     /// Returns `true` if the payload matches the filter conditions.
     #[must_use]
     pub fn matches(&self, payload: &Value) -> bool {
-        self.condition.matches(payload)
+        self.condition
+            .matches_collated(payload, self.effective_collation())
     }
 }
 
@@ -216,6 +255,15 @@ pub enum Condition {
         /// Maximum longitude
         lng_max: f64,
     },
+    /// Comparison between two scalar expressions (`price * quantity > 1000`).
+    Expression {
+        /// Left-hand expression
+        left: crate::velesql::ArithmeticExpr,
+        /// Comparison operator
+        operator: crate::velesql::CompareOp,
+        /// Right-hand expression
+        right: crate::velesql::ArithmeticExpr,
+    },
 }
 
 #[cfg(test)]
@@ -249,6 +297,7 @@ mod condition_type_names_tests {
             Condition::ArrayContainsAll { .. } => "array_contains_all",
             Condition::GeoDistance { .. } => "geo_distance",
             Condition::GeoBbox { .. } => "geo_bbox",
+            Condition::Expression { .. } => "expression",
         }
     }
 
@@ -277,7 +326,7 @@ mod condition_type_names_tests {
         ]
     }
 
-    /// Logical, pattern, array and geo variants, in declaration order.
+    /// Logical, pattern, array, geo and expression variants, in declaration order.
     fn logical_and_geo_variants() -> Vec<Condition> {
         use serde_json::Value;
         let f = || "f".to_string();
@@ -321,6 +370,11 @@ mod condition_type_names_tests {
                 lat_max: 0.0,
                 lng_max: 0.0,
             },
+            Condition::Expression {
+                left: crate::velesql::ArithmeticExpr::Variable(f()),
+                operator: crate::velesql::CompareOp::Eq,
+                right: crate::velesql::ArithmeticExpr::Literal(0.0),
+            },
         ]
     }
 
@@ -0,0 +1,134 @@
+//! SimHash / MinHash text fingerprints for near-duplicate detection.
+//!
+//! Both work on word shingles: the text is lower-cased, split on
+//! non-alphanumeric characters, and every run of [`SHINGLE_WORDS`]
+//! consecutive words becomes one feature (a shorter text is one feature).
+//! Features are hashed with the stable FNV-1a of [`hash_id_bytes`], so a
+//! fingerprint is identical across processes and platforms and can be
+//! persisted.
+//!
+//! Fingerprints are meant to be stored as binary vectors with
+//! [`Point::from_bits`](crate::Point::from_bits) in a `Hamming` collection:
+//!
+//! - [`simhash_bits`] gives a 64-bit SimHash, where the Hamming distance
+//!   between two texts grows with how much they differ (near-duplicates
+//!   sit within a few bits). This is the layout
+//!   `VectorCollection::near_duplicate_text` expects.
+//! - [`minhash_bits`] gives a 1-bit MinHash: each bit matches with
+//!   probability `(1 + J) / 2` for shingle-set Jaccard similarity `J`.
+//!   [`minhash`] / [`minhash_similarity`] keep full 64-bit minima for a
+//!   sharper in-memory estimate.
+
+use crate::wire::stable_hash::hash_id_bytes;
+
+/// Words per shingle.
+pub const SHINGLE_WORDS: usize = 3;
+
+/// Bits in a SimHash fingerprint.
+pub const SIMHASH_BITS: usize = 64;
+
+/// Returns the stable hashes of the word shingles of `text` (empty when the
+/// text has no alphanumeric word).
+#[must_use]
+pub fn shingle_hashes(text: &str) -> Vec<u64> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+    if words.len() <= SHINGLE_WORDS {
+        return vec![hash_id_bytes(words.join(" ").as_bytes())];
+    }
+    words
+        .windows(SHINGLE_WORDS)
+        .map(|shingle| hash_id_bytes(shingle.join(" ").as_bytes()))
+        .collect()
+}
+
+/// 64-bit SimHash of `text`: bit `i` is set when most shingle hashes have
+/// bit `i` set. Returns `0` for a text without words.
+#[must_use]
+pub fn simhash(text: &str) -> u64 {
+    let mut votes = [0_i64; SIMHASH_BITS];
+    for hash in shingle_hashes(text) {
+        // FNV-1a's high bits mix poorly on short inputs; finalize first.
+        let hash = mix64(hash);
+        for (bit, vote) in votes.iter_mut().enumerate() {
+            *vote += if (hash >> bit) & 1 == 1 { 1 } else { -1 };
+        }
+    }
+    votes
+        .iter()
+        .enumerate()
+        .filter(|(_, vote)| **vote > 0)
+        .fold(0, |acc, (bit, _)| acc | (1 << bit))
+}
+
+/// [`simhash`] as a bitset for [`Point::from_bits`](crate::Point::from_bits)
+/// (least significant bit first, 64 dimensions).
+#[must_use]
+pub fn simhash_bits(text: &str) -> [u8; 8] {
+    simhash(text).to_le_bytes()
+}
+
+/// MinHash signature of `text`: for each of `num_hashes` seeded hash
+/// functions, the minimum hash over the text's shingles. A text without
+/// words yields all `u64::MAX`.
+#[must_use]
+pub fn minhash(text: &str, num_hashes: usize) -> Vec<u64> {
+    let shingles = shingle_hashes(text);
+    (0..num_hashes)
+        .map(|seed| {
+            let seed = mix64(seed as u64);
+            shingles
+                .iter()
+                .map(|hash| mix64(hash ^ seed))
+                .min()
+                .unwrap_or(u64::MAX)
+        })
+        .collect()
+}
+
+/// Estimated Jaccard similarity of two [`minhash`] signatures: the share of
+/// positions holding the same minimum. Signatures of different lengths are
+/// compared on their common prefix; two empty signatures give `0.0`.
+#[must_use]
+pub fn minhash_similarity(a: &[u64], b: &[u64]) -> f32 {
+    let len = a.len().min(b.len());
+    if len == 0 {
+        return 0.0;
+    }
+    let equal = a.iter().zip(b).filter(|(x, y)| x == y).count();
+    #[allow(clippy::cast_precision_loss)]
+    // Reason: signature lengths stay far below 2^24.
+    let similarity = equal as f32 / len as f32;
+    similarity
+}
+
+/// 1-bit MinHash of `text` as a bitset for
+/// [`Point::from_bits`](crate::Point::from_bits): bit `i` is the lowest bit
+/// of the `i`-th [`minhash`] value. `num_bits` is rounded up to a multiple
+/// of 8.
+#[must_use]
+pub fn minhash_bits(text: &str, num_bits: usize) -> Vec<u8> {
+    let signature = minhash(text, num_bits.div_ceil(8) * 8);
+    signature
+        .chunks(8)
+        .map(|chunk| {
+            chunk.iter().enumerate().fold(0_u8, |byte, (bit, value)| {
+                byte | (((value & 1) as u8) << bit)
+            })
+        })
+        .collect()
+}
+
+/// `splitmix64` finalizer: spreads every input bit over the whole word.
+const fn mix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,63 @@
+//! Tests for `fingerprint` module
+
+use super::fingerprint::*;
+
+const DOC: &str = "Vector databases store embeddings and answer nearest neighbour \
+                   queries over millions of points with approximate indexes such as HNSW.";
+const NEAR_DOC: &str = "Vector databases store embeddings and answer nearest neighbour \
+                        queries over millions of points with approximate indexes like HNSW!";
+const OTHER_DOC: &str = "The recipe calls for two cups of flour, a pinch of salt and \
+                         three eggs whisked until the batter turns pale and smooth.";
+
+#[test]
+fn test_shingles_ignore_case_and_punctuation() {
+    assert_eq!(
+        shingle_hashes("Hello, World foo bar"),
+        shingle_hashes("hello world   FOO bar!")
+    );
+    assert_eq!(shingle_hashes("one two three four five").len(), 3);
+    assert_eq!(shingle_hashes("two words").len(), 1);
+    assert!(shingle_hashes(" ,.; ").is_empty());
+}
+
+#[test]
+fn test_simhash_is_deterministic() {
+    assert_eq!(simhash(DOC), simhash(DOC));
+    assert_eq!(simhash_bits(DOC), simhash(DOC).to_le_bytes());
+    assert_eq!(simhash(""), 0);
+}
+
+#[test]
+fn test_simhash_near_duplicates_differ_in_few_bits() {
+    let near = (simhash(DOC) ^ simhash(NEAR_DOC)).count_ones();
+    let far = (simhash(DOC) ^ simhash(OTHER_DOC)).count_ones();
+
+    assert!(near < far, "near {near} vs far {far}");
+    assert!(near <= 16, "near {near}");
+    assert!(far >= 16, "far {far}");
+}
+
+#[test]
+fn test_minhash_similarity_tracks_shingle_overlap() {
+    let a = minhash(DOC, 128);
+    assert_eq!(a.len(), 128);
+
+    assert!(minhash_similarity(&a, &a) > 0.999);
+    assert!(minhash_similarity(&a, &minhash(NEAR_DOC, 128)) > 0.6);
+    assert!(minhash_similarity(&a, &minhash(OTHER_DOC, 128)) < 0.1);
+    assert!(minhash_similarity(&[], &[]) < f32::EPSILON);
+}
+
+#[test]
+fn test_minhash_bits_rounds_up_to_whole_bytes() {
+    assert_eq!(minhash_bits(DOC, 64).len(), 8);
+    assert_eq!(minhash_bits(DOC, 60).len(), 8);
+    assert_eq!(minhash_bits(DOC, 64), minhash_bits(DOC, 64));
+
+    let differing =
+        |a: &[u8], b: &[u8]| -> u32 { a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum() };
+    let base = minhash_bits(DOC, 256);
+    let near = differing(&base, &minhash_bits(NEAR_DOC, 256));
+    let far = differing(&base, &minhash_bits(OTHER_DOC, 256));
+    assert!(near < far, "near {near} vs far {far}");
+}
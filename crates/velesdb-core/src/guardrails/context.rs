@@ -98,7 +98,8 @@ impl QueryContext {
     /// This method is called on the final result set (post-filter, post-ORDER BY,
     /// pre-LIMIT). It does **not** track intermediate over-fetched candidate sets
     /// (e.g., `candidates_k = execution_limit * 10 * N` during similarity search).
-    /// Those are bounded by `MAX_LIMIT` internally and therefore do not escape.
+    /// Those are bounded by the collection's `max_query_limit` internally and
+    /// therefore do not escape.
     /// Future work: thread `QueryContext` into ANN search to track intermediates.
     pub fn check_cardinality(&self, count: usize) -> Result<(), GuardRailViolation> {
         let current = self.current_cardinality.fetch_add(count, Ordering::Relaxed) + count;
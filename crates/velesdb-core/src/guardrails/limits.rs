@@ -24,7 +24,7 @@ pub const DEFAULT_CIRCUIT_FAILURE_THRESHOLD: u32 = 5;
 pub const DEFAULT_CIRCUIT_RECOVERY_SECONDS: u64 = 30;
 
 /// Query limits configuration (EPIC-048).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct QueryLimits {
     /// Maximum graph traversal depth (US-002).
@@ -139,6 +139,29 @@ pub enum GuardRailViolation {
         /// Time until recovery in seconds.
         recovery_in_seconds: u64,
     },
+    /// Every concurrency slot of the collection was busy and the query
+    /// could not be queued or waited too long for one.
+    Overloaded {
+        /// Kind of query that was rejected (`"search"` or `"scan"`).
+        kind: &'static str,
+        /// Configured concurrency limit for that kind.
+        max_concurrent: usize,
+        /// Time spent in the admission queue, in milliseconds.
+        waited_ms: u64,
+    },
+}
+
+impl GuardRailViolation {
+    /// Returns true if the violation sheds load (rate limit, open circuit
+    /// breaker, full admission queue) rather than rejecting the query
+    /// itself, so the same query can succeed once load drops.
+    #[must_use]
+    pub const fn sheds_load(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimitExceeded { .. } | Self::CircuitOpen { .. } | Self::Overloaded { .. }
+        )
+    }
 }
 
 impl std::fmt::Display for GuardRailViolation {
@@ -175,6 +198,17 @@ impl std::fmt::Display for GuardRailViolation {
                     "Circuit breaker open, recovery in {recovery_in_seconds}s"
                 )
             }
+            Self::Overloaded {
+                kind,
+                max_concurrent,
+                waited_ms,
+            } => {
+                write!(
+                    f,
+                    "Collection overloaded: all {max_concurrent} concurrent {kind} slots busy, \
+                     rejected after {waited_ms}ms in queue"
+                )
+            }
         }
     }
 }
@@ -171,6 +171,17 @@ impl VectorData {
         }
     }
 
+    /// Returns the little-endian bytes of the components in their native
+    /// precision (4 bytes per dimension for F32, 2 for F16/BF16).
+    #[must_use]
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::F32(v) => v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            Self::F16(v) => v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            Self::BF16(v) => v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+        }
+    }
+
     /// Converts to another precision format.
     #[must_use]
     pub fn convert(&self, target: VectorPrecision) -> Self {
@@ -394,10 +394,7 @@ fn test_batch_search_adaptive_matches_individual() {
     }
     index.set_searching_mode();
 
-    let adaptive = SearchQuality::Adaptive {
-        min_ef: 32,
-        max_ef: 256,
-    };
+    let adaptive = SearchQuality::adaptive(32, 256);
 
     let queries: Vec<Vec<f32>> = (0..8).map(|_| lcg_vector(&mut seed, dim)).collect();
     let query_refs: Vec<&[f32]> = queries.iter().map(Vec::as_slice).collect();
@@ -5,6 +5,7 @@ use crate::index::hnsw::params::SearchQuality;
 use crate::index::hnsw::upsert::{self, UpsertResult};
 use crate::scored_result::ScoredResult;
 use crate::validation::validate_dimension_match;
+use crate::vector_layout::VectorLayout;
 use rayon::prelude::*;
 
 /// Prepared batch of vectors ready for HNSW graph insertion.
@@ -85,7 +86,8 @@ impl HnswIndex {
     /// insert -> mapping reconciliation. On graph failure, rollback undoes
     /// mappings in reverse order. Because `parallel_insert` uses rayon, the
     /// HNSW graph construction order is non-deterministic across runs (see
-    /// v1.7.2 CHANGELOG note).
+    /// v1.7.2 CHANGELOG note) unless the index was built with
+    /// [`HnswParams::deterministic_build`](crate::HnswParams::deterministic_build).
     ///
     /// # Arguments
     ///
@@ -120,6 +122,24 @@ impl HnswIndex {
     /// println!("Inserted {} vectors", inserted);
     /// ```
     pub fn insert_batch_parallel<'a, I>(&self, vectors: I) -> usize
+    where
+        I: IntoIterator<Item = (u64, &'a [f32])>,
+    {
+        self.insert_batch_parallel_with_progress(vectors, 1, &mut |_, _| {})
+    }
+
+    /// [`insert_batch_parallel`](Self::insert_batch_parallel) that links the
+    /// batch into the graph in `steps` chunks, calling
+    /// `on_progress(linked, total)` after each one.
+    ///
+    /// If a chunk fails, the chunks already linked are kept and the rest of
+    /// the batch is rolled back; the return value counts the linked vectors.
+    pub(crate) fn insert_batch_parallel_with_progress<'a, I>(
+        &self,
+        vectors: I,
+        steps: usize,
+        on_progress: &mut dyn FnMut(usize, usize),
+    ) -> usize
     where
         I: IntoIterator<Item = (u64, &'a [f32])>,
     {
@@ -142,22 +162,26 @@ impl HnswIndex {
             .map(|(idx, vec)| (*vec, *idx))
             .collect();
 
-        let assigned_ids = match self.inner.read().parallel_insert(&refs_for_hnsw) {
-            Ok(ids) => ids,
-            Err(e) => {
-                tracing::error!("insert_batch_parallel: parallel_insert failed: {e}");
-                // RF-DEDUP #448 Group D — reverse-order rollback shared with
-                // NativeHnswIndex::insert_batch.
-                upsert::rollback_batch(&self.mappings, &batch.rollback_info);
-                return 0;
+        let chunk = count.div_ceil(steps.max(1));
+        let mut assigned_ids = Vec::with_capacity(count);
+        for part in refs_for_hnsw.chunks(chunk) {
+            match self.inner.read().parallel_insert(part) {
+                Ok(ids) => assigned_ids.extend(ids),
+                Err(e) => {
+                    tracing::error!("insert_batch_parallel: parallel_insert failed: {e}");
+                    break;
+                }
             }
-        };
+            on_progress(assigned_ids.len(), count);
+        }
 
-        // RF-DEDUP #448 Group D — mapping reconciliation shared with
-        // NativeHnswIndex::insert_batch.
-        upsert::reconcile_batch_mappings(&self.mappings, &batch.rollback_info, &assigned_ids);
+        // RF-DEDUP #448 Group D — mapping reconciliation and reverse-order
+        // rollback shared with NativeHnswIndex::insert_batch.
+        let (linked, failed) = batch.rollback_info.split_at(assigned_ids.len());
+        upsert::reconcile_batch_mappings(&self.mappings, linked, &assigned_ids);
+        upsert::rollback_batch(&self.mappings, failed);
 
-        count
+        assigned_ids.len()
     }
 
     /// Performs batch search for multiple queries in parallel.
@@ -195,11 +219,12 @@ impl HnswIndex {
     ) -> crate::error::Result<Vec<Vec<ScoredResult>>> {
         self.validate_batch_dimensions(queries)?;
 
-        // Perfect, Adaptive, AutoTune, or very small collections: delegate to
+        // Perfect, Adaptive, AutoTune, MultiEntry, or very small collections: delegate to
         // search_with_quality per-query to match single-query behavior.
         // - Perfect: uses brute-force for 100% recall
         // - Adaptive: uses spread-based two-phase escalation (not batch-compatible)
         // - AutoTune: computes auto-ef range per dataset/dim/k (issue #699 follow-up)
+        // - MultiEntry: explicit entry-probe count the batch path cannot pass down
         // - Small (<=100): uses brute-force for fully-connected graph safety
         //
         // Without AutoTune in this list, batch + AutoTune would fall through to the
@@ -208,7 +233,10 @@ impl HnswIndex {
         // single-query path applies via try_search_special_quality.
         if matches!(
             quality,
-            SearchQuality::Perfect | SearchQuality::Adaptive { .. } | SearchQuality::AutoTune
+            SearchQuality::Perfect
+                | SearchQuality::Adaptive { .. }
+                | SearchQuality::AutoTune
+                | SearchQuality::MultiEntry { .. }
         ) || (self.len() <= 100 && self.enable_vector_storage && self.graph_vector_count() > 0)
         {
             let results: crate::error::Result<Vec<Vec<ScoredResult>>> = queries
@@ -364,6 +392,12 @@ impl HnswIndex {
             return Vec::new();
         }
 
+        if self.vector_layout() == VectorLayout::BlockedSoa {
+            if let Some(results) = self.brute_force_search_soa(query, k) {
+                return results;
+            }
+        }
+
         let (flat, dimension) = {
             let inner = self.inner.read();
             inner.with_contiguous_vectors(|vectors| {
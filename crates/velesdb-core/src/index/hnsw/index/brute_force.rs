@@ -4,12 +4,65 @@
 //! - `search_brute_force`: SIMD-optimized exact search for small indices
 //! - `search_brute_force_gpu`: GPU-accelerated search via wgpu
 //! - `search_brute_force_buffered`: Buffer-reuse variant
+//! - `brute_force_search_soa`: blocked struct-of-arrays scan
+//!   ([`VectorLayout::BlockedSoa`])
 
 use super::HnswIndex;
 use crate::index::hnsw::params::SearchQuality;
 use crate::scored_result::ScoredResult;
+use crate::vector_layout::{BlockedSoaVectors, VectorLayout};
 
 impl HnswIndex {
+    /// Sets the layout brute-force scans score vectors in.
+    ///
+    /// With [`VectorLayout::BlockedSoa`] each scan regroups the stored
+    /// vectors into dimension-major blocks in place of the flat snapshot the
+    /// AoS scan copies. Runtime only: collections persist the layout in their
+    /// config and set it on open.
+    pub fn set_vector_layout(&self, layout: VectorLayout) {
+        *self.vector_layout.write() = layout;
+    }
+
+    /// Layout used by brute-force scans.
+    #[must_use]
+    pub fn vector_layout(&self) -> VectorLayout {
+        *self.vector_layout.read()
+    }
+
+    /// Brute-force scan over a blocked struct-of-arrays snapshot.
+    ///
+    /// Returns `None` when the metric has no blocked kernel, so the caller
+    /// falls back to the AoS scan.
+    pub(super) fn brute_force_search_soa(
+        &self,
+        query: &[f32],
+        k: usize,
+    ) -> Option<Vec<ScoredResult>> {
+        if !crate::vector_layout::supports(self.metric) {
+            return None;
+        }
+        let blocked = {
+            let inner = self.inner.read();
+            inner.with_contiguous_vectors(|vectors| {
+                (vectors.dimension() > 0).then(|| {
+                    BlockedSoaVectors::from_flat(vectors.as_flat_slice(), vectors.dimension())
+                })
+            })?
+        };
+        let scores = blocked.par_scores(query, self.metric)?;
+        let mut results: Vec<ScoredResult> = scores
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, score)| {
+                let id = self.mappings.get_id(idx)?;
+                Some(ScoredResult::new(id, score))
+            })
+            .collect();
+        self.metric.sort_scored_results(&mut results);
+        results.truncate(k);
+        Some(results)
+    }
+
     /// Brute-force scan restricted to vectors in the bitmap.
     ///
     /// Iterates over bitmap IDs, resolves each to an internal index via
@@ -57,7 +110,7 @@ impl HnswIndex {
     }
 
     /// Scores vectors whose IDs are present in the bitmap (u32 range).
-    fn score_bitmap_ids(
+    pub(super) fn score_bitmap_ids(
         &self,
         query: &[f32],
         allowed_ids: &roaring::RoaringBitmap,
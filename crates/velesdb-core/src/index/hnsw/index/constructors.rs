@@ -146,7 +146,7 @@ impl HnswIndex {
         params: HnswParams,
         enable_vector_storage: bool,
     ) -> Result<Self> {
-        let inner = HnswInner::new_with_options(
+        let mut inner = HnswInner::new_with_options(
             metric,
             params.max_connections,
             params.max_elements,
@@ -155,6 +155,8 @@ impl HnswIndex {
             params.storage_mode,
             params.alpha,
         )?;
+        inner.set_neighbor_selection(params.neighbor_selection);
+        inner.set_deterministic_build(params.deterministic_seed());
 
         let mappings = ShardedMappings::with_capacity(params.max_elements);
 
@@ -166,6 +168,7 @@ impl HnswIndex {
             enable_vector_storage,
             rerank_latency_target_us: AtomicU64::new(0),
             rerank_latency_ema_us: AtomicU64::new(0),
+            vector_layout: RwLock::new(crate::vector_layout::VectorLayout::default()),
             io_holder: None,
         })
     }
@@ -288,6 +291,7 @@ impl HnswIndex {
             enable_vector_storage: meta.enable_vector_storage,
             rerank_latency_target_us: AtomicU64::new(0),
             rerank_latency_ema_us: AtomicU64::new(0),
+            vector_layout: RwLock::new(crate::vector_layout::VectorLayout::default()),
             io_holder: None,
         };
 
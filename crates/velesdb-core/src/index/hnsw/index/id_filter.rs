@@ -0,0 +1,108 @@
+//! Search restricted to an id allowlist and/or denylist.
+//!
+//! The lists are translated to graph node ids once per query and checked
+//! inside the layer-0 traversal ([`NodeFilter`]), so rejected points never
+//! take a slot in the `ef` pool. Very selective allowlists skip the graph
+//! and are scanned exhaustively instead.
+
+use super::HnswIndex;
+use crate::index::hnsw::native::NodeFilter;
+use crate::index::hnsw::params::SearchQuality;
+use crate::scored_result::ScoredResult;
+use roaring::RoaringBitmap;
+
+/// Share of the indexed vectors at or below which an allowlist is scanned
+/// exhaustively: a graph walk would visit more nodes than the list holds.
+const ALLOWLIST_SCAN_SELECTIVITY: f64 = 0.01;
+
+impl HnswIndex {
+    /// Quality-aware search returning only ids in `include` (every id when
+    /// `None`) and not in `exclude`.
+    ///
+    /// Unlike [`search_with_quality_and_bitmap`](Self::search_with_quality_and_bitmap),
+    /// which over-fetches and drops non-matching ids afterwards, the filter
+    /// is applied during the graph traversal, so a restrictive list does not
+    /// starve the result set. Ids above `u32::MAX` cannot be listed: they are
+    /// never returned when `include` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::Error::DimensionMismatch`] if query dimension is wrong.
+    pub fn search_with_id_filter(
+        &self,
+        query: &[f32],
+        k: usize,
+        quality: SearchQuality,
+        include: Option<&RoaringBitmap>,
+        exclude: Option<&RoaringBitmap>,
+    ) -> crate::error::Result<Vec<ScoredResult>> {
+        self.validate_dimension(query)?;
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut ef_search = quality.ef_search_for_scale(k, self.len()).max(k);
+        let filter = if let Some(include) = include {
+            let mut allowed = include.clone();
+            if let Some(exclude) = exclude {
+                allowed -= exclude;
+            }
+            #[allow(clippy::cast_precision_loss)] // Reason: ratio, precision loss acceptable
+            let selectivity = allowed.len() as f64 / self.len().max(1) as f64;
+            if selectivity <= ALLOWLIST_SCAN_SELECTIVITY {
+                return Ok(self.scan_allowed_ids(query, k, &allowed));
+            }
+            let nodes = self.node_set(&allowed);
+            // The pool can only fill with admitted nodes; a larger `ef` would
+            // walk the whole graph looking for more.
+            ef_search = ef_search.min(usize::try_from(nodes.len()).unwrap_or(usize::MAX));
+            if ef_search == 0 {
+                return Ok(Vec::new());
+            }
+            NodeFilter::new(Some(nodes), RoaringBitmap::new())
+        } else {
+            let denied = exclude.map_or_else(RoaringBitmap::new, |ids| self.node_set(ids));
+            NodeFilter::new(None, denied)
+        };
+
+        let inner = self.inner.read();
+        let neighbours = inner.search_filtered(query, k, ef_search, filter);
+        let mut results: Vec<ScoredResult> = neighbours
+            .into_iter()
+            .filter_map(|(node_id, raw_dist)| {
+                let id = self.mappings.get_id(node_id)?;
+                Some(ScoredResult::new(id, inner.transform_score(raw_dist)))
+            })
+            .collect();
+        drop(inner);
+
+        self.metric.sort_scored_results(&mut results);
+        results.truncate(k);
+        Ok(results)
+    }
+
+    /// Exact scan over the (few) allowed ids.
+    fn scan_allowed_ids(
+        &self,
+        query: &[f32],
+        k: usize,
+        allowed: &RoaringBitmap,
+    ) -> Vec<ScoredResult> {
+        let inner = self.inner.read();
+        inner.with_contiguous_vectors(|vectors| {
+            let mut scored = Vec::with_capacity(usize::try_from(allowed.len()).unwrap_or(k));
+            self.score_bitmap_ids(query, allowed, vectors, &mut scored);
+            self.metric.sort_scored_results(&mut scored);
+            scored.truncate(k);
+            scored
+        })
+    }
+
+    /// Graph node ids of the indexed points among `ids`.
+    fn node_set(&self, ids: &RoaringBitmap) -> RoaringBitmap {
+        ids.iter()
+            .filter_map(|id| self.mappings.get_idx(u64::from(id)))
+            .filter_map(|node| u32::try_from(node).ok())
+            .collect()
+    }
+}
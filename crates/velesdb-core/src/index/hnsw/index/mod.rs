@@ -23,6 +23,8 @@
 mod batch;
 mod brute_force;
 mod constructors;
+mod id_filter;
+mod rebuild;
 mod rerank;
 mod search;
 mod trait_impl;
@@ -103,6 +105,8 @@ pub struct HnswIndex {
     pub(crate) rerank_latency_target_us: AtomicU64,
     /// Exponential moving average of two-stage rerank latency (microseconds).
     pub(crate) rerank_latency_ema_us: AtomicU64,
+    /// Layout used by brute-force scans (see [`HnswIndex::set_vector_layout`]).
+    pub(crate) vector_layout: RwLock<crate::vector_layout::VectorLayout>,
     /// Reserved for future backends that may borrow from disk-mapped data.
     ///
     /// Always `None` with the native implementation. Declared AFTER `inner`
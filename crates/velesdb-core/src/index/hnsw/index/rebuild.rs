@@ -0,0 +1,247 @@
+//! Rebuilding the HNSW graph with new construction parameters.
+//!
+//! Unlike [`HnswIndex::vacuum`], which rebuilds and swaps in one call, a
+//! reindex is split in three steps so the caller can validate before
+//! committing: [`HnswIndex::build_rebuild_candidate`] builds a replacement
+//! graph beside the live one, [`HnswIndex::benchmark_current`] and
+//! [`RebuildCandidate::benchmark`] measure both on the same sample, and
+//! [`HnswIndex::install_rebuild_candidate`] swaps it in.
+
+use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+use std::time::Instant;
+
+use super::vacuum::VacuumError;
+use super::{HnswIndex, HnswInner};
+use crate::index::hnsw::params::{HnswParams, SearchQuality};
+
+/// Neighbours requested per benchmark query.
+const BENCH_K: usize = 10;
+/// Maximum number of stored vectors replayed as benchmark queries.
+const BENCH_MAX_QUERIES: usize = 64;
+/// Number of progress reports emitted while inserting into the new graph.
+const PROGRESS_STEPS: usize = 10;
+
+/// Latency and self-recall of a graph over the benchmark sample.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct GraphBenchmark {
+    /// P99 search latency in microseconds.
+    pub(crate) latency_p99_us: u64,
+    /// Share of queries that found their own vector in the top `BENCH_K`.
+    pub(crate) recall_estimate: f64,
+    /// Number of queries run.
+    pub(crate) query_count: usize,
+}
+
+/// A replacement graph built by [`HnswIndex::build_rebuild_candidate`].
+pub(crate) struct RebuildCandidate {
+    inner: HnswInner,
+    /// `(external id, internal index in the live graph)`, in new node order.
+    snapshot: Vec<(u64, usize)>,
+    /// Benchmark queries: `(vector, live index, new index)`.
+    queries: Vec<(Vec<f32>, usize, usize)>,
+    ef_search: usize,
+}
+
+impl RebuildCandidate {
+    /// Benchmarks the new graph on the sample queries.
+    pub(crate) fn benchmark(&self) -> GraphBenchmark {
+        run_benchmark(&self.inner, &self.queries, self.ef_search, |q| q.2)
+    }
+}
+
+impl HnswIndex {
+    /// Builds a graph with `params` from a snapshot of the live vectors.
+    ///
+    /// The live index keeps serving reads and writes meanwhile; writes made
+    /// after the snapshot are carried over by
+    /// [`install_rebuild_candidate`](Self::install_rebuild_candidate).
+    /// `on_progress` receives `(inserted, total)` after each insertion chunk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VacuumError::VectorStorageDisabled` for fast-insert indices
+    /// and `VacuumError::RebuildFailed` if the new graph cannot be built.
+    pub(crate) fn build_rebuild_candidate(
+        &self,
+        params: &HnswParams,
+        on_progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<RebuildCandidate, VacuumError> {
+        if !self.enable_vector_storage {
+            return Err(VacuumError::VectorStorageDisabled);
+        }
+        let active: Vec<(u64, usize, Vec<f32>)> = {
+            let inner = self.inner.read();
+            inner.with_contiguous_vectors(|vectors| {
+                self.mappings
+                    .iter()
+                    .filter_map(|(id, idx)| vectors.get(idx).map(|v| (id, idx, v.to_vec())))
+                    .collect()
+            })
+        };
+        let target_mode = self.inner.read().storage_mode();
+
+        // Built through a Standard backend and promoted afterwards, for the
+        // same reasons as `build_vacuum_replacement`.
+        let mut inner = HnswInner::new_with_options(
+            self.metric,
+            params.max_connections,
+            params.max_elements.max(active.len()),
+            params.ef_construction,
+            self.dimension,
+            crate::StorageMode::Full,
+            params.alpha,
+        )
+        .map_err(|e| VacuumError::RebuildFailed(e.to_string()))?;
+        inner.set_neighbor_selection(params.neighbor_selection);
+        inner.set_deterministic_build(params.deterministic_seed());
+
+        let refs: Vec<(&[f32], usize)> = active
+            .iter()
+            .enumerate()
+            .map(|(pos, (_, _, v))| (v.as_slice(), pos))
+            .collect();
+        let chunk = refs.len().div_ceil(PROGRESS_STEPS).max(1);
+        let mut done = 0usize;
+        for part in refs.chunks(chunk) {
+            inner
+                .parallel_insert(part)
+                .map_err(|e| VacuumError::RebuildFailed(e.to_string()))?;
+            done += part.len();
+            on_progress(done, refs.len());
+        }
+
+        let inner = if target_mode == crate::StorageMode::RaBitQ {
+            let promoted = inner.promote_to_rabitq(self.dimension);
+            #[cfg(feature = "persistence")]
+            if let Some(rabitq) = self.inner.read().rabitq_quantizer() {
+                promoted
+                    .install_trained_rabitq(rabitq)
+                    .map_err(|e| VacuumError::RebuildFailed(e.to_string()))?;
+            }
+            promoted
+        } else {
+            inner
+        };
+
+        let step = (active.len() / BENCH_MAX_QUERIES).max(1);
+        let queries = active
+            .iter()
+            .enumerate()
+            .step_by(step)
+            .take(BENCH_MAX_QUERIES)
+            .map(|(pos, (_, idx, v))| (v.clone(), *idx, pos))
+            .collect();
+
+        Ok(RebuildCandidate {
+            inner,
+            snapshot: active.into_iter().map(|(id, idx, _)| (id, idx)).collect(),
+            queries,
+            ef_search: SearchQuality::Balanced.ef_search(BENCH_K),
+        })
+    }
+
+    /// Benchmarks the live graph on the candidate's sample queries.
+    pub(crate) fn benchmark_current(&self, candidate: &RebuildCandidate) -> GraphBenchmark {
+        let inner = self.inner.read();
+        run_benchmark(&inner, &candidate.queries, candidate.ef_search, |q| q.1)
+    }
+
+    /// Swaps the candidate graph in, returning the number of indexed vectors.
+    ///
+    /// Vectors written or replaced after the snapshot are copied into the
+    /// new graph under the write lock, so nothing committed to the live
+    /// graph is lost. As with [`vacuum`](Self::vacuum), a write that is
+    /// between its mapping update and its graph insert at swap time may be
+    /// misplaced; avoid heavy concurrent ingest while reindexing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VacuumError::RebuildFailed` if carried-over writes cannot be
+    /// inserted; the live graph is then left untouched.
+    pub(crate) fn install_rebuild_candidate(
+        &self,
+        candidate: RebuildCandidate,
+    ) -> Result<usize, VacuumError> {
+        let RebuildCandidate {
+            inner: new_inner,
+            snapshot,
+            ..
+        } = candidate;
+        let snapshot_pos: HashMap<u64, (usize, usize)> = snapshot
+            .iter()
+            .enumerate()
+            .map(|(pos, &(id, idx))| (id, (idx, pos)))
+            .collect();
+
+        let mut inner_guard = self.inner.write();
+        let (mut pairs, changed) = inner_guard.with_contiguous_vectors(|vectors| {
+            let mut pairs: Vec<(u64, usize)> = Vec::with_capacity(snapshot.len());
+            let mut changed: Vec<(u64, Vec<f32>)> = Vec::new();
+            for (id, idx) in self.mappings.iter() {
+                match snapshot_pos.get(&id) {
+                    Some(&(old_idx, pos)) if old_idx == idx => pairs.push((id, pos)),
+                    _ => {
+                        if let Some(v) = vectors.get(idx) {
+                            changed.push((id, v.to_vec()));
+                        }
+                    }
+                }
+            }
+            (pairs, changed)
+        });
+
+        let base = snapshot.len();
+        let refs: Vec<(&[f32], usize)> = changed
+            .iter()
+            .enumerate()
+            .map(|(i, (_, v))| (v.as_slice(), base + i))
+            .collect();
+        let assigned = new_inner
+            .parallel_insert(&refs)
+            .map_err(|e| VacuumError::RebuildFailed(e.to_string()))?;
+        pairs.extend(changed.iter().map(|(id, _)| *id).zip(assigned));
+
+        // SAFETY: same invariants as the swap in `vacuum` — exclusive write
+        // lock held, dropped exactly once, immediately replaced.
+        unsafe {
+            ManuallyDrop::drop(&mut *inner_guard);
+        }
+        *inner_guard = ManuallyDrop::new(new_inner);
+        self.mappings.replace_all(&pairs, base + changed.len());
+        Ok(pairs.len())
+    }
+}
+
+/// Runs the sample queries against `inner` and measures p99 latency and
+/// how often each query finds its own vector.
+fn run_benchmark(
+    inner: &HnswInner,
+    queries: &[(Vec<f32>, usize, usize)],
+    ef_search: usize,
+    expected: impl Fn(&(Vec<f32>, usize, usize)) -> usize,
+) -> GraphBenchmark {
+    if queries.is_empty() {
+        return GraphBenchmark::default();
+    }
+    let mut latencies = Vec::with_capacity(queries.len());
+    let mut hits = 0usize;
+    for q in queries {
+        let start = Instant::now();
+        let results = inner.search(&q.0, BENCH_K, ef_search);
+        latencies.push(u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX));
+        let target = expected(q);
+        if results.iter().any(|&(idx, _)| idx == target) {
+            hits += 1;
+        }
+    }
+    latencies.sort_unstable();
+    let p99 = latencies[(latencies.len() * 99).div_ceil(100).saturating_sub(1)];
+    #[allow(clippy::cast_precision_loss)] // query counts are tiny
+    let recall_estimate = hits as f64 / queries.len() as f64;
+    GraphBenchmark {
+        latency_p99_us: p99,
+        recall_estimate,
+        query_count: queries.len(),
+    }
+}
@@ -235,11 +235,14 @@ impl HnswIndex {
         k: usize,
         ef_search: usize,
     ) -> Option<usize> {
-        // Skip reranking for Adaptive or AutoTune quality (these handle
-        // their own exploration strategy) or if vector storage is disabled.
+        // Skip reranking for Adaptive, AutoTune or MultiEntry quality (these
+        // handle their own exploration strategy) or if vector storage is
+        // disabled.
         if matches!(
             quality,
-            SearchQuality::Adaptive { .. } | SearchQuality::AutoTune
+            SearchQuality::Adaptive { .. }
+                | SearchQuality::AutoTune
+                | SearchQuality::MultiEntry { .. }
         ) || !self.enable_vector_storage
         {
             return None;
@@ -253,7 +256,10 @@ impl HnswIndex {
             SearchQuality::Fast => k,
             SearchQuality::Balanced => k * 3,
             SearchQuality::Accurate | SearchQuality::Custom(_) => k * 4,
-            SearchQuality::Perfect | SearchQuality::Adaptive { .. } | SearchQuality::AutoTune => {
+            SearchQuality::Perfect
+            | SearchQuality::Adaptive { .. }
+            | SearchQuality::AutoTune
+            | SearchQuality::MultiEntry { .. } => {
                 return None;
             }
         };
@@ -346,8 +352,8 @@ impl HnswIndex {
         Ok(self.search_hnsw_only(query, k, ef_search))
     }
 
-    /// Handles Perfect, small-collection brute-force, Adaptive, and AutoTune
-    /// quality modes. Returns `Ok(Some(results))` when handled, `Ok(None)` to
+    /// Handles Perfect, small-collection brute-force, Adaptive, MultiEntry
+    /// and AutoTune quality modes. Returns `Ok(Some(results))` when handled, `Ok(None)` to
     /// fall through to the standard HNSW path.
     fn try_search_special_quality(
         &self,
@@ -363,71 +369,95 @@ impl HnswIndex {
             return self.search_brute_force(query, k).map(Some);
         }
 
-        if let SearchQuality::Adaptive { min_ef, max_ef } = quality {
+        if let SearchQuality::Adaptive {
+            min_ef,
+            max_ef,
+            patience,
+        } = quality
+        {
             return Ok(Some(self.search_adaptive(
                 query,
                 k,
                 (*min_ef).max(k),
-                *max_ef,
+                (*max_ef).max(k),
+                *patience,
             )));
         }
 
+        if let SearchQuality::MultiEntry { probes, .. } = quality {
+            let ef_search = quality.ef_search_for_scale(k, self.len());
+            return Ok(Some(self.search_multi_entry(query, k, ef_search, *probes)));
+        }
+
         if matches!(quality, SearchQuality::AutoTune) {
             let (min_ef, max_ef) =
                 crate::index::hnsw::auto_ef::auto_ef_range(self.len(), self.dimension, k);
-            return Ok(Some(self.search_adaptive(query, k, min_ef, max_ef)));
+            return Ok(Some(self.search_adaptive(
+                query,
+                k,
+                min_ef,
+                max_ef,
+                SearchQuality::DEFAULT_ADAPTIVE_PATIENCE,
+            )));
         }
 
         Ok(None)
     }
 
-    /// Two-phase adaptive search that starts with a low ef and escalates if needed.
+    /// Adaptive search: a pool of up to `max_ef` candidates that stops
+    /// expanding once the top-k has held for `patience` expansions (after at
+    /// least `min_ef` candidates were collected).
     ///
-    /// Phase 1: search with `min_ef`. If the result spread (max_dist / min_dist)
-    /// indicates a hard query (scattered results), re-search with doubled ef.
-    /// This saves 2-4x latency on easy queries while maintaining recall on hard ones.
+    /// Easy queries settle within a few expansions and return long before
+    /// the `max_ef` budget is spent; hard queries keep changing their top-k
+    /// and get the full budget. Backends without stability tracking
+    /// (`RaBitQ`) run a fixed `max_ef` search.
     fn search_adaptive(
         &self,
         query: &[f32],
         k: usize,
         min_ef: usize,
         max_ef: usize,
+        patience: usize,
     ) -> Vec<ScoredResult> {
-        // Phase 1: fast search with min_ef
-        let results = self.search_hnsw_only(query, k, min_ef);
-        if results.len() < 2 {
-            return results;
-        }
-
-        // Check result spread to determine if this is a hard query.
-        // Use first/last scores -- ordering differs by metric (similarity=desc,
-        // distance=asc) so we take the absolute spread.
-        let score_a = results.first().map_or(0.0, |r| r.score);
-        let score_b = results.last().map_or(0.0, |r| r.score);
-        let diff = (score_a - score_b).abs();
-
-        // Relative spread: normalize by the smaller absolute score to handle
-        // both similarity metrics (high scores) and distance metrics (low scores).
-        let baseline = score_a.abs().min(score_b.abs());
-        let spread = if baseline > f32::EPSILON {
-            diff / baseline
-        } else {
-            diff
+        let inner = self.inner.read();
+        let Some(neighbours) = inner.search_until_stable(query, k, min_ef, max_ef, patience) else {
+            drop(inner);
+            return self.search_hnsw_only(query, k, max_ef);
         };
 
-        // Threshold 2.0: empirically tuned. Easy queries have spread < 1.0,
-        // hard queries typically > 3.0.
-        if spread < 2.0 {
-            return results;
+        let mut results: Vec<ScoredResult> = Vec::with_capacity(neighbours.len());
+        for &(node_id, raw_dist) in &neighbours {
+            if let Some(id) = self.mappings.get_id(node_id) {
+                results.push(ScoredResult::new(id, inner.transform_score(raw_dist)));
+            }
         }
+        results
+    }
 
-        // Phase 2: re-search with doubled ef (capped at max_ef)
-        let escalated_ef = (min_ef * 2).min(max_ef);
-        if escalated_ef <= min_ef {
-            return results;
-        }
+    /// Multi-entry search (`SearchQuality::MultiEntry`): the layer-0 beam
+    /// starts from `num_probes` entry points instead of the one picked by
+    /// the graph size. The `RaBitQ` backend runs a plain search.
+    fn search_multi_entry(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        num_probes: usize,
+    ) -> Vec<ScoredResult> {
+        let inner = self.inner.read();
+        let Some(neighbours) = inner.search_multi_entry(query, k, ef_search, num_probes) else {
+            drop(inner);
+            return self.search_hnsw_only(query, k, ef_search);
+        };
 
-        self.search_hnsw_only(query, k, escalated_ef)
+        let mut results: Vec<ScoredResult> = Vec::with_capacity(neighbours.len());
+        for &(node_id, raw_dist) in &neighbours {
+            if let Some(id) = self.mappings.get_id(node_id) {
+                results.push(ScoredResult::new(id, inner.transform_score(raw_dist)));
+            }
+        }
+        results
     }
 
     /// Sets the index to searching mode after bulk insertions.
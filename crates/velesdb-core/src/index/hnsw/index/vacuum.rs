@@ -182,7 +182,7 @@ impl HnswIndex {
         // threshold (then re-encode everything a second time on install) —
         // and would silently SELF-train an untrained collection from
         // compaction order. The graph is promoted afterwards.
-        let new_inner = HnswInner::new_with_storage_mode(
+        let mut new_inner = HnswInner::new_with_storage_mode(
             self.metric,
             params.max_connections,
             active_vectors.len().max(1000), // max_elements with reasonable minimum
@@ -191,6 +191,11 @@ impl HnswIndex {
             crate::StorageMode::Full,
         )
         .map_err(|e| VacuumError::RebuildFailed(e.to_string()))?;
+        {
+            let current = self.inner.read();
+            new_inner.set_neighbor_selection(current.neighbor_selection());
+            new_inner.set_deterministic_build(current.deterministic_seed());
+        }
 
         // Insertion references: idx = sequential, matches graph allocation.
         let refs_for_hnsw: Vec<(&[f32], usize)> = active_vectors
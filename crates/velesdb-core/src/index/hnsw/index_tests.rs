@@ -2255,19 +2255,11 @@ fn test_adaptive_search_spread_positive_for_distance_metrics() {
     // last results high distances (~50+), creating a large spread.
     let query: Vec<f32> = vec![0.0; dim];
 
-    // Adaptive search with a narrow min_ef and generous max_ef.
-    // If spread calculation is correct and positive, the adaptive path will
-    // either escalate or return valid results — either way, the search must
-    // complete successfully and return sensible results.
+    // Adaptive search with a narrow min_ef and generous max_ef: whether it
+    // stops early or runs to max_ef, the search must complete successfully
+    // and return sensible results.
     let results = index
-        .search_with_quality(
-            &query,
-            10,
-            SearchQuality::Adaptive {
-                min_ef: 32,
-                max_ef: 256,
-            },
-        )
+        .search_with_quality(&query, 10, SearchQuality::adaptive(32, 256))
         .unwrap();
 
     assert!(
@@ -2314,14 +2306,7 @@ fn test_adaptive_search_spread_works_for_similarity_metrics() {
     let query: Vec<f32> = (0..dim).map(|j| (j as f32 * 0.013).sin()).collect();
 
     let results = index
-        .search_with_quality(
-            &query,
-            10,
-            SearchQuality::Adaptive {
-                min_ef: 32,
-                max_ef: 256,
-            },
-        )
+        .search_with_quality(&query, 10, SearchQuality::adaptive(32, 256))
         .unwrap();
 
     assert!(
@@ -3390,3 +3375,70 @@ fn test_with_params_propagates_alpha_to_native_graph() {
         "alpha 1.5 must propagate to native graph, got {actual}"
     );
 }
+
+#[test]
+fn test_with_params_deterministic_build_reproduces_search_results() {
+    let vectors: Vec<Vec<f32>> = (0..400)
+        .map(|i| (0..8).map(|j| ((i * 8 + j) as f32 * 0.13).cos()).collect())
+        .collect();
+    let build = || {
+        let params = HnswParams::auto(8).with_deterministic_build(99);
+        let index = HnswIndex::with_params(8, DistanceMetric::Euclidean, params).expect("build");
+        assert_eq!(index.inner.read().deterministic_seed(), Some(99));
+        index.insert_batch_parallel(
+            vectors
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i as u64, v.as_slice())),
+        );
+        index
+    };
+    let (a, b) = (build(), build());
+
+    assert_eq!(a.verify(), b.verify());
+    for query in vectors.iter().step_by(37) {
+        let ids = |index: &HnswIndex| -> Vec<u64> {
+            VectorIndex::search(index, query, 10)
+                .into_iter()
+                .map(|r| r.id)
+                .collect()
+        };
+        assert_eq!(ids(&a), ids(&b));
+    }
+}
+
+/// `SearchQuality::MultiEntry` on clustered data: results stay sorted and
+/// match the brute-force top-k.
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_multi_entry_search_on_clustered_data() {
+    let dim = 16;
+    let index = HnswIndex::new(dim, DistanceMetric::Euclidean).unwrap();
+
+    // 8 well-separated clusters of 50 points each.
+    for i in 0u64..400 {
+        let cluster = (i % 8) as f32;
+        let v: Vec<f32> = (0..dim)
+            .map(|j| cluster * 10.0 + ((i * 31 + j as u64) as f32 * 0.37).sin() * 0.5)
+            .collect();
+        index.insert(i, &v);
+    }
+
+    let query: Vec<f32> = vec![50.0; dim];
+    let quality = SearchQuality::MultiEntry {
+        ef_search: 64,
+        probes: 8,
+    };
+    let results = index.search_with_quality(&query, 10, quality).unwrap();
+    let exact = index
+        .search_with_quality(&query, 10, SearchQuality::Perfect)
+        .unwrap();
+
+    assert_eq!(results.len(), 10);
+    for pair in results.windows(2) {
+        assert!(pair[0].score <= pair[1].score + f32::EPSILON);
+    }
+    let exact_ids: std::collections::HashSet<u64> = exact.iter().map(|r| r.id).collect();
+    let hits = results.iter().filter(|r| exact_ids.contains(&r.id)).count();
+    assert!(hits >= 9, "multi-entry recall@10 too low: {hits}/10");
+}
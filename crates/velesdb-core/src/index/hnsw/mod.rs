@@ -15,6 +15,7 @@
 //! - `params`: Index parameters and search quality profiles
 //! - `native`: Core HNSW graph with SIMD distance calculations
 //! - `index`: Main `HnswIndex` API
+//! - `verify`: Graph integrity check (`HnswIndex::verify`)
 
 // ============================================================================
 // Core modules
@@ -32,6 +33,7 @@ mod params;
 pub(crate) mod persistence;
 mod sharded_mappings;
 pub(crate) mod upsert;
+mod verify;
 // ============================================================================
 // Tests
 // ============================================================================
@@ -55,14 +57,17 @@ mod sharded_mappings_tests;
 mod sidecar_removal_tests;
 #[cfg(test)]
 mod upsert_tests;
+#[cfg(test)]
+mod verify_tests;
 
 // ============================================================================
 // Public API
 // ============================================================================
-pub use params::{HnswParams, SearchQuality};
+pub use params::{HnswParams, NeighborSelection, SearchQuality};
 
 /// Main HNSW index for vector search operations.
 pub use index::HnswIndex;
+pub use verify::{HnswIntegrityReport, LayerIntegrity};
 
 /// Native HNSW index with direct access to underlying graph.
 pub use native_index::NativeHnswIndex;
@@ -129,10 +129,12 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
     /// # Note
     ///
     /// Graph structure may differ from sequential insertion due to concurrent
-    /// neighbor selection. This does not affect search correctness.
+    /// neighbor selection. This does not affect search correctness. A
+    /// deterministic build ([`NativeHnsw::set_deterministic_build`]) always
+    /// inserts sequentially.
     pub fn parallel_insert(&self, data: &[(&[f32], usize)]) -> crate::error::Result<Vec<usize>> {
         // For small batches, sequential is faster due to parallelization overhead
-        if data.len() < 100 {
+        if data.len() < 100 || self.build_seed.is_some() {
             let mut assigned_ids = Vec::with_capacity(data.len());
             for (vec, _idx) in data {
                 assigned_ids.push(self.insert(vec)?);
@@ -246,17 +246,18 @@ fn load_corrupt(dir: &tempfile::TempDir, basename: &str) -> std::io::Result<()>
 
 // Graph header byte layout (little-endian): version u32 @0, num_layers u32 @4,
 // max_connections u32 @8, max_connections_0 u32 @12, ef_construction u32 @16,
-// entry_point u64 @20, max_layer u32 @28, count u64 @32. Layer 0 starts @40
-// with num_nodes u64, then per node: num_neighbors u32 followed by neighbor u32s.
+// entry_point u64 @20, max_layer u32 @28, count u64 @32, alpha f32 @40 (v2+),
+// neighbor selection u8 @44 (v3). Layer 0 starts @45 with num_nodes u64, then
+// per node: num_neighbors u32 followed by neighbor u32s.
 
 #[test]
 fn test_file_load_rejects_neighbor_id_beyond_count() {
     let dir = tempdir().unwrap();
     dump_small_index(&dir, "corrupt_nbr");
     let graph = dir.path().join("corrupt_nbr.graph");
-    // First node's first neighbor id sits at offset 40 (num_nodes u64)
-    // + 4 (num_neighbors u32) = 44. Set it to a huge value >= count.
-    patch_file(&graph, 44, &u32::MAX.to_le_bytes());
+    // First node's first neighbor id sits at offset 45 + 8 (num_nodes u64)
+    // + 4 (num_neighbors u32) = 57. Set it to a huge value >= count.
+    patch_file(&graph, 57, &u32::MAX.to_le_bytes());
     assert!(
         load_corrupt(&dir, "corrupt_nbr").is_err(),
         "load must reject an out-of-range neighbor id"
@@ -281,8 +282,8 @@ fn test_file_load_rejects_absurd_num_nodes() {
     let dir = tempdir().unwrap();
     dump_small_index(&dir, "corrupt_nodes");
     let graph = dir.path().join("corrupt_nodes.graph");
-    // Layer 0 num_nodes u64 @40 — far larger than the 20 vectors.
-    patch_file(&graph, 40, &1_000_000_000u64.to_le_bytes());
+    // Layer 0 num_nodes u64 @45 — far larger than the 20 vectors.
+    patch_file(&graph, 45, &1_000_000_000u64.to_le_bytes());
     assert!(
         load_corrupt(&dir, "corrupt_nodes").is_err(),
         "load must reject num_nodes exceeding the vector count"
@@ -294,8 +295,8 @@ fn test_file_load_rejects_absurd_num_neighbors() {
     let dir = tempdir().unwrap();
     dump_small_index(&dir, "corrupt_nnbr");
     let graph = dir.path().join("corrupt_nnbr.graph");
-    // First node's num_neighbors u32 @40 + 8 = 48; absurd value > cap.
-    patch_file(&graph, 48, &u32::MAX.to_le_bytes());
+    // First node's num_neighbors u32 @45 + 8 = 53; absurd value > cap.
+    patch_file(&graph, 53, &u32::MAX.to_le_bytes());
     assert!(
         load_corrupt(&dir, "corrupt_nnbr").is_err(),
         "load must reject num_neighbors exceeding the safety cap"
@@ -328,6 +329,37 @@ fn test_file_load_rejects_count_mismatch() {
     );
 }
 
+#[test]
+fn test_file_load_rejects_unknown_neighbor_selection() {
+    let dir = tempdir().unwrap();
+    dump_small_index(&dir, "corrupt_sel");
+    let graph = dir.path().join("corrupt_sel.graph");
+    // neighbor selection u8 @44: codes 0..=2 are defined.
+    patch_file(&graph, 44, &[9]);
+    assert!(
+        load_corrupt(&dir, "corrupt_sel").is_err(),
+        "load must reject an unknown neighbor selection code"
+    );
+}
+
+#[test]
+fn test_file_dump_load_preserves_neighbor_selection() {
+    use crate::index::hnsw::NeighborSelection;
+
+    let dir = tempdir().unwrap();
+    let engine = CachedSimdDistance::new(DistanceMetric::Euclidean, 8);
+    let mut hnsw = NativeHnsw::new(engine, 16, 100, 100);
+    hnsw.set_neighbor_selection(NeighborSelection::Simple);
+    for i in 0..10 {
+        hnsw.insert(&[i as f32; 8]).expect("insert");
+    }
+    hnsw.file_dump(dir.path(), "sel").expect("dump");
+
+    let engine2 = CachedSimdDistance::new(DistanceMetric::Euclidean, 8);
+    let loaded = NativeHnsw::file_load(dir.path(), "sel", engine2).expect("load");
+    assert_eq!(loaded.neighbor_selection(), NeighborSelection::Simple);
+}
+
 #[test]
 fn test_file_load_rejects_truncated_vectors_header() {
     let dir = tempdir().unwrap();
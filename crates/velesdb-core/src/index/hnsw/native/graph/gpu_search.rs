@@ -256,16 +256,7 @@ impl<D: DistanceEngine> NativeHnsw<D> {
     /// for large indices with high ef_search (where recall matters most).
     #[inline]
     fn gpu_adaptive_probes(count: usize, ef_search: usize, k: usize) -> usize {
-        if count < 10_000 || ef_search <= (k * 4).max(64) {
-            return 1;
-        }
-        if ef_search >= 1024 {
-            4
-        } else if ef_search >= 512 {
-            3
-        } else {
-            2
-        }
+        super::search::default_num_probes(count, ef_search, k)
     }
 
     /// Selects diversified entry points for multi-probe GPU search.
@@ -8,6 +8,7 @@
 //! - `insert`: Vector insertion and layer growth
 //! - `search`: k-NN search, multi-entry search, and layer-level search
 //! - `neighbors`: Neighbor selection (VAMANA diversification) and bidirectional connections
+//! - `verify`: Read-only structural scan behind `HnswIndex::verify`
 
 mod insert;
 pub(crate) mod locking;
@@ -20,6 +21,7 @@ mod search_pools;
 mod search_state;
 #[cfg(test)]
 mod search_tests;
+mod verify;
 
 #[cfg(feature = "gpu")]
 mod gpu_search;
@@ -27,9 +29,11 @@ mod gpu_search;
 use super::columnar_vectors::ColumnarVectors;
 use super::distance::DistanceEngine;
 use super::layer::Layer;
+use crate::index::hnsw::NeighborSelection;
 use crate::perf_optimizations::ContiguousVectors;
 use locking::{record_lock_acquire, record_lock_release, LockRank};
 use parking_lot::RwLock;
+pub(crate) use search_state::NodeFilter;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 /// Sentinel value for `NativeHnsw::entry_point` (private field): indicates no
@@ -98,6 +102,13 @@ pub struct NativeHnsw<D: DistanceEngine> {
     /// producing a more navigable graph with better recall across all search
     /// modes. The value 1.2 follows the VAMANA paper recommendation.
     pub(in crate::index::hnsw::native) alpha: f32,
+    /// Neighbor-selection strategy applied at insert time (persisted in the
+    /// `.graph` header since format v3).
+    pub(in crate::index::hnsw::native) neighbor_selection: NeighborSelection,
+    /// Seed of a deterministic build; `Some` makes batch inserts link nodes
+    /// sequentially in input order. Not persisted: reapplied from the
+    /// collection's `HnswParams` on open.
+    pub(in crate::index::hnsw::native) build_seed: Option<u64>,
     /// Maximum consecutive candidates without improving top-k before early termination.
     /// Default: `ef_construction / 2`. Set to `0` to disable.
     pub(crate) stagnation_limit: usize,
@@ -263,6 +274,8 @@ impl<D: DistanceEngine> NativeHnsw<D> {
             ef_construction,
             level_mult,
             alpha,
+            neighbor_selection: NeighborSelection::default(),
+            build_seed: None,
             // ef/2 gives beam search more exploration budget at scale.
             // The prior ef/4 caused premature termination at 100K+ vectors,
             // contributing to recall degradation (97% at 10K → 64% at 100K).
@@ -288,6 +301,37 @@ impl<D: DistanceEngine> NativeHnsw<D> {
         self.alpha
     }
 
+    /// Returns the neighbor-selection strategy used by inserts.
+    #[must_use]
+    pub fn neighbor_selection(&self) -> NeighborSelection {
+        self.neighbor_selection
+    }
+
+    /// Sets the neighbor-selection strategy for subsequent inserts.
+    ///
+    /// Call before the first insert: mixing strategies within one graph is
+    /// sound but makes its recall harder to reason about.
+    pub fn set_neighbor_selection(&mut self, selection: NeighborSelection) {
+        self.neighbor_selection = selection;
+    }
+
+    /// Returns the seed of a deterministic build, `None` for parallel builds.
+    #[must_use]
+    pub fn deterministic_seed(&self) -> Option<u64> {
+        self.build_seed
+    }
+
+    /// Switches deterministic builds on (`Some(seed)`) or off (`None`).
+    ///
+    /// When on, batch inserts run sequentially in input order and the
+    /// layer PRNG restarts from `seed`.
+    pub fn set_deterministic_build(&mut self, seed: Option<u64>) {
+        self.build_seed = seed;
+        if let Some(seed) = seed {
+            *self.rng_state.get_mut() = seed;
+        }
+    }
+
     /// Returns the number of elements in the index.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -3,11 +3,15 @@
 use super::super::distance::DistanceEngine;
 use super::super::layer::NodeId;
 use super::NativeHnsw;
+use crate::index::hnsw::NeighborSelection;
 use crate::perf_optimizations::ContiguousVectors;
 use rustc_hash::FxHashSet;
 
 impl<D: DistanceEngine> NativeHnsw<D> {
-    /// VAMANA-style neighbor selection with alpha diversification.
+    /// Picks up to `max_neighbors` of `candidates` (sorted by distance)
+    /// following the graph's [`NeighborSelection`]: the closest ones, or
+    /// VAMANA-style alpha diversification, optionally backfilled with the
+    /// pruned candidates.
     #[inline]
     pub(crate) fn select_neighbors(
         &self,
@@ -18,8 +22,13 @@ impl<D: DistanceEngine> NativeHnsw<D> {
             return Vec::new();
         }
 
-        if candidates.len() <= max_neighbors {
-            return candidates.iter().map(|(id, _)| *id).collect();
+        if candidates.len() <= max_neighbors || self.neighbor_selection == NeighborSelection::Simple
+        {
+            return candidates
+                .iter()
+                .take(max_neighbors)
+                .map(|(id, _)| *id)
+                .collect();
         }
 
         let mut selected: Vec<NodeId> = Vec::with_capacity(max_neighbors);
@@ -53,7 +62,9 @@ impl<D: DistanceEngine> NativeHnsw<D> {
             }
         });
 
-        Self::backfill_neighbors(candidates, &mut selected, &mut selected_set, max_neighbors);
+        if self.neighbor_selection == NeighborSelection::HeuristicKeepPruned {
+            Self::backfill_neighbors(candidates, &mut selected, &mut selected_set, max_neighbors);
+        }
 
         selected
     }
@@ -255,7 +266,9 @@ impl<D: DistanceEngine> NativeHnsw<D> {
         // evict the neighbor most redundant with new_node (closest to it).
         // Otherwise fall back to standard farthest-eviction.
         if new_dist < worst_dist {
-            let evict_idx = if self.alpha * new_dist <= closest_to_new_dist {
+            let evict_idx = if self.neighbor_selection != NeighborSelection::Simple
+                && self.alpha * new_dist <= closest_to_new_dist
+            {
                 closest_to_new_idx // Diverse: evict the most redundant
             } else {
                 worst_idx // Not diverse enough: evict the farthest
@@ -4,8 +4,11 @@ use super::super::distance::{batch_distance_with_prefetch, DistanceEngine};
 use super::super::layer::{Layer, NodeId};
 use super::super::ordered_float::OrderedFloat;
 use super::search_pools::should_prefetch;
-use super::search_state::{gather_unvisited_neighbors, process_batch_results, SearchState};
+use super::search_state::{
+    gather_unvisited_neighbors, process_batch_results, NodeFilter, SearchState,
+};
 use super::{NativeHnsw, NO_ENTRY_POINT};
+use crate::index::hnsw::params::SearchQuality;
 use crate::perf_optimizations::ContiguousVectors;
 use smallvec::SmallVec;
 use std::borrow::Cow;
@@ -38,6 +41,26 @@ thread_local! {
     static PROBE_RNG: Cell<u64> = const { Cell::new(0) };
 }
 
+/// Entry-probe count picked for a graph of `count` nodes when the caller
+/// does not set one (see `NativeHnsw::adaptive_num_probes`). Shared with the
+/// GPU path so both traversals probe alike.
+#[inline]
+pub(super) fn default_num_probes(count: usize, ef_search: usize, k: usize) -> usize {
+    if count <= 10_000 || ef_search <= (k * 4).max(64) {
+        return 1;
+    }
+
+    let by_ef = if ef_search >= 1024 {
+        4
+    } else if ef_search >= 512 {
+        3
+    } else {
+        2
+    };
+    let by_size = if count >= 1_000_000 { 3 } else { 2 };
+    by_ef.max(by_size)
+}
+
 impl<D: DistanceEngine> NativeHnsw<D> {
     /// Searches for k nearest neighbors.
     ///
@@ -92,21 +115,107 @@ impl<D: DistanceEngine> NativeHnsw<D> {
         }
     }
 
+    /// Searches for k nearest neighbors with a pool of up to `max_ef`
+    /// candidates, stopping early once the top-k has not changed for
+    /// `patience` consecutive expansions and at least `min_ef` candidates
+    /// were collected (`SearchQuality::Adaptive`).
+    ///
+    /// Returns raw engine distances, like [`search`](Self::search).
+    #[must_use]
+    pub fn search_until_stable(
+        &self,
+        query: &[f32],
+        k: usize,
+        min_ef: usize,
+        max_ef: usize,
+        patience: usize,
+    ) -> Vec<(NodeId, f32)> {
+        let prepared_query = self.prepare_query(query);
+        let results =
+            self.search_until_stable_prepared(&prepared_query, k, min_ef, max_ef, patience);
+        Self::recycle_cow(prepared_query);
+        results
+    }
+
+    fn search_until_stable_prepared(
+        &self,
+        query: &[f32],
+        k: usize,
+        min_ef: usize,
+        max_ef: usize,
+        patience: usize,
+    ) -> Vec<(NodeId, f32)> {
+        let ep = self.entry_point.load(Ordering::Acquire);
+        if ep == NO_ENTRY_POINT {
+            return Vec::new();
+        }
+
+        let max_layer = self.max_layer.load(Ordering::Relaxed);
+        let mut current_ep = ep;
+        for layer_idx in (1..=max_layer).rev() {
+            current_ep = self.search_layer_single(query, current_ep, layer_idx);
+        }
+
+        let capacity_hint = self.count.load(Ordering::Relaxed);
+        let state = SearchState::new(capacity_hint).with_topk_stability(k, min_ef, patience);
+        self.search_layer_with_state(
+            query,
+            &[current_ep],
+            max_ef,
+            0,
+            self.stagnation_limit,
+            Some(k),
+            state,
+        )
+    }
+
+    /// Searches for the k nearest neighbors among the nodes `filter` admits.
+    ///
+    /// The filter is applied inside the layer-0 traversal: rejected nodes
+    /// are still expanded, so the walk can cross them, but only admitted
+    /// nodes fill the `ef` result pool. Upper layers are descended as usual.
+    /// Stagnation-based early exit is disabled since expansions through
+    /// rejected nodes never improve the pool; callers should cap `ef` at the
+    /// number of admitted nodes so the pool can fill.
+    ///
+    /// Returns raw engine distances, like [`search`](Self::search).
+    #[must_use]
+    pub(crate) fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        filter: NodeFilter,
+    ) -> Vec<(NodeId, f32)> {
+        self.with_prepared_query(query, |query| {
+            let ep = self.entry_point.load(Ordering::Acquire);
+            if ep == NO_ENTRY_POINT {
+                return Vec::new();
+            }
+
+            let max_layer = self.max_layer.load(Ordering::Relaxed);
+            let mut current_ep = ep;
+            for layer_idx in (1..=max_layer).rev() {
+                current_ep = self.search_layer_single(query, current_ep, layer_idx);
+            }
+
+            let capacity_hint = self.count.load(Ordering::Relaxed);
+            let state = SearchState::new(capacity_hint).with_filter(filter);
+            self.search_layer_with_state(query, &[current_ep], ef_search, 0, 0, Some(k), state)
+        })
+    }
+
     /// Adaptive number of entry-point probes for high-recall searches.
+    ///
+    /// Small graphs (≤ 10K nodes) and narrow beams stay single-entry. Beyond
+    /// that the probe count follows `ef_search` (2, 3 at ef ≥ 512, 4 at
+    /// ef ≥ 1024) and the graph size: from 1M nodes, greedy descent lands in
+    /// the wrong cluster often enough on clustered data that at least 3
+    /// probes pay for themselves.
     #[inline]
     #[allow(clippy::unused_self)] // Reason: method receiver accesses graph config in future adaptive tuning
     fn adaptive_num_probes(&self, count: usize, ef_search: usize, k: usize) -> usize {
-        if count <= 10_000 || ef_search <= (k * 4).max(64) {
-            return 1;
-        }
-
-        if ef_search >= 1024 {
-            4
-        } else if ef_search >= 512 {
-            3
-        } else {
-            2
-        }
+        default_num_probes(count, ef_search, k)
     }
 
     /// Multi-entry point search for improved recall on hard queries.
@@ -185,7 +294,7 @@ impl<D: DistanceEngine> NativeHnsw<D> {
     ) -> Vec<NodeId> {
         let mut entry_points = vec![primary_ep];
         if num_probes > 1 && count > 10 {
-            for _ in 1..num_probes.min(4) {
+            for _ in 1..num_probes.min(SearchQuality::MAX_ENTRY_PROBES) {
                 let random_id = (Self::next_probe_rng() as usize) % count;
                 if !entry_points.contains(&random_id) {
                     entry_points.push(random_id);
@@ -388,8 +497,32 @@ impl<D: DistanceEngine> NativeHnsw<D> {
         result_limit: Option<usize>,
     ) -> Vec<(NodeId, f32)> {
         let capacity_hint = self.count.load(Ordering::Relaxed);
-        let mut state = SearchState::new(capacity_hint);
+        let state = SearchState::new(capacity_hint);
+        self.search_layer_with_state(
+            query,
+            entry_points,
+            ef,
+            layer,
+            stagnation_limit,
+            result_limit,
+            state,
+        )
+    }
 
+    /// [`search_layer`](Self::search_layer) on a caller-prepared
+    /// [`SearchState`] (e.g. one with top-k stability enabled).
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    fn search_layer_with_state(
+        &self,
+        query: &[f32],
+        entry_points: &[NodeId],
+        ef: usize,
+        layer: usize,
+        stagnation_limit: usize,
+        result_limit: Option<usize>,
+        mut state: SearchState,
+    ) -> Vec<(NodeId, f32)> {
         self.with_vectors_and_layers_read(|vectors, layers| {
             let use_prefetch = should_prefetch(vectors.dimension());
 
@@ -656,6 +789,31 @@ mod probe_tests {
         assert_eq!(hnsw.adaptive_num_probes(50_000, 1024, 10), 4);
     }
 
+    #[test]
+    fn three_probes_from_one_million_nodes() {
+        let hnsw = empty_hnsw();
+        assert_eq!(hnsw.adaptive_num_probes(999_999, 160, 10), 2);
+        assert_eq!(hnsw.adaptive_num_probes(1_000_000, 160, 10), 3);
+        assert_eq!(hnsw.adaptive_num_probes(1_000_000, 1024, 10), 4);
+        // A narrow beam stays single-entry at any size.
+        assert_eq!(hnsw.adaptive_num_probes(5_000_000, 40, 10), 1);
+    }
+
+    #[test]
+    fn explicit_probes_are_capped() {
+        let eps = NativeHnsw::<CpuDistance>::gather_multi_entry_points(0, 1_000_000, 64);
+        assert!(eps.len() <= SearchQuality::MAX_ENTRY_PROBES);
+        assert!(
+            eps.len() > 4,
+            "more than the former hard cap of 4: {}",
+            eps.len()
+        );
+        assert_eq!(
+            NativeHnsw::<CpuDistance>::gather_multi_entry_points(7, 1_000_000, 1),
+            vec![7]
+        );
+    }
+
     // =========================================================================
     // Thread-local probe RNG (issue #967)
     // =========================================================================
@@ -1,8 +1,8 @@
 //! Search state and helper functions for HNSW layer traversal.
 //!
 //! [`SearchState`] bundles the candidate/result heaps, visited set,
-//! stagnation counter, and cached furthest distance into a single
-//! struct. Helper functions [`gather_unvisited_neighbors`] and
+//! stagnation counter, optional top-k stability tracker, optional result
+//! filter, and cached furthest distance into a single struct. Helper functions [`gather_unvisited_neighbors`] and
 //! [`process_batch_results`] operate on the search state to keep
 //! each function under Codacy complexity limits.
 
@@ -13,6 +13,7 @@ use super::search_pools::{
     release_result_heap, release_visited_set, BitVecVisited, CandidateHeap, ResultHeap,
 };
 use crate::perf_optimizations::ContiguousVectors;
+use roaring::RoaringBitmap;
 use smallvec::SmallVec;
 use std::cmp::Reverse;
 
@@ -40,6 +41,90 @@ pub(super) struct SearchState {
     /// Kept in sync with `results.peek().map(|r| r.0.0)`.
     /// Initialized to `f32::MAX` when the result set is empty.
     pub(super) cached_furthest: f32,
+    /// Top-k early termination (`SearchQuality::Adaptive`); `None` otherwise.
+    pub(super) stability: Option<TopKStability>,
+    /// Restricts which nodes may enter `results`; `None` admits every node.
+    pub(super) filter: Option<NodeFilter>,
+}
+
+/// Node-id allow/deny sets for a filtered search.
+///
+/// Rejected nodes are still expanded as candidates, so the traversal can
+/// route through them, but they never enter the result set or count toward
+/// `ef`. Node ids above `u32::MAX` cannot appear in either set: they are
+/// rejected when an allowlist is given and admitted otherwise.
+pub(crate) struct NodeFilter {
+    include: Option<RoaringBitmap>,
+    exclude: RoaringBitmap,
+}
+
+impl NodeFilter {
+    /// Admits the nodes in `include` (every node when `None`) that are not
+    /// in `exclude`.
+    pub(crate) fn new(include: Option<RoaringBitmap>, exclude: RoaringBitmap) -> Self {
+        Self { include, exclude }
+    }
+
+    /// Whether `node` may enter the result set.
+    #[inline]
+    pub(crate) fn admits(&self, node: NodeId) -> bool {
+        match u32::try_from(node) {
+            Ok(node) => {
+                self.include.as_ref().is_none_or(|set| set.contains(node))
+                    && !self.exclude.contains(node)
+            }
+            Err(_) => self.include.is_none(),
+        }
+    }
+}
+
+/// Tracks whether the best `k` results are still changing.
+///
+/// Every node admitted to the result set is offered here; an expansion that
+/// admits nothing into the top-k counts toward `patience`. Once the top-k
+/// has held for `patience` consecutive expansions (and at least
+/// `min_results` candidates were collected), [`SearchState::should_terminate`]
+/// stops the search even though the `ef` pool may not be exhausted.
+pub(super) struct TopKStability {
+    k: usize,
+    min_results: usize,
+    patience: usize,
+    /// Max-heap of the `k` best results seen so far.
+    top: ResultHeap,
+    changed: bool,
+    pub(super) stable_expansions: usize,
+}
+
+impl TopKStability {
+    #[inline]
+    fn offer(&mut self, node: NodeId, dist: f32) {
+        if self.top.len() < self.k {
+            self.top.push((OrderedFloat(dist), node));
+            self.changed = true;
+        } else if self.top.peek().is_some_and(|worst| dist < worst.0 .0) {
+            self.top.pop();
+            self.top.push((OrderedFloat(dist), node));
+            self.changed = true;
+        }
+    }
+
+    #[inline]
+    fn end_expansion(&mut self) {
+        if self.changed {
+            self.stable_expansions = 0;
+            self.changed = false;
+        } else {
+            self.stable_expansions += 1;
+        }
+    }
+
+    #[inline]
+    fn settled(&self, result_count: usize) -> bool {
+        self.patience > 0
+            && self.stable_expansions >= self.patience
+            && self.top.len() >= self.k
+            && result_count >= self.min_results
+    }
 }
 
 impl SearchState {
@@ -56,6 +141,49 @@ impl SearchState {
             visited: acquire_visited_set(capacity_hint),
             stagnation_count: 0,
             cached_furthest: f32::MAX,
+            stability: None,
+            filter: None,
+        }
+    }
+
+    /// Restricts the result set to the nodes `filter` admits.
+    pub(super) fn with_filter(mut self, filter: NodeFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Whether `node` may enter the result set.
+    #[inline]
+    pub(super) fn admits(&self, node: NodeId) -> bool {
+        self.filter.as_ref().is_none_or(|f| f.admits(node))
+    }
+
+    /// Enables top-k early termination: stop once the best `k` results have
+    /// not changed for `patience` consecutive expansions, provided at least
+    /// `min_results` candidates have been collected. `patience = 0` leaves
+    /// termination unchanged.
+    pub(super) fn with_topk_stability(
+        mut self,
+        k: usize,
+        min_results: usize,
+        patience: usize,
+    ) -> Self {
+        self.stability = Some(TopKStability {
+            k: k.max(1),
+            min_results,
+            patience,
+            top: acquire_result_heap(),
+            changed: false,
+            stable_expansions: 0,
+        });
+        self
+    }
+
+    /// Offers an admitted result to the top-k tracker, if enabled.
+    #[inline]
+    pub(super) fn observe_result(&mut self, node: NodeId, dist: f32) {
+        if let Some(stability) = &mut self.stability {
+            stability.offer(node, dist);
         }
     }
 
@@ -64,12 +192,17 @@ impl SearchState {
     /// Refreshes `cached_furthest` from the heap root because the newly
     /// pushed node may become the furthest result. Called only during
     /// entry-point seeding (1-4 calls), so the `peek()` cost is negligible.
+    /// A node the filter rejects only enters the candidate heap.
     #[inline]
     pub(super) fn push_candidate(&mut self, node: NodeId, dist: f32) {
         self.candidates.push(Reverse((OrderedFloat(dist), node)));
+        self.visited.insert(node);
+        if !self.admits(node) {
+            return;
+        }
         self.results.push((OrderedFloat(dist), node));
         self.cached_furthest = self.results.peek().map_or(f32::MAX, |r| r.0 .0);
-        self.visited.insert(node);
+        self.observe_result(node, dist);
     }
 
     /// Returns `true` if the search should terminate.
@@ -78,6 +211,7 @@ impl SearchState {
     /// 1. The current candidate distance exceeds `cached_furthest` and
     ///    the result set has reached `ef` capacity.
     /// 2. Stagnation limit is enabled and the counter has reached it.
+    /// 3. Top-k stability is enabled and the top-k has settled.
     ///
     /// Uses `cached_furthest` instead of `results.peek()` to avoid a
     /// heap pointer chase on every candidate evaluation (Issue #422).
@@ -86,10 +220,18 @@ impl SearchState {
         if c_dist > self.cached_furthest && self.results.len() >= ef {
             return true;
         }
-        stagnation_limit > 0 && self.stagnation_count >= stagnation_limit
+        if stagnation_limit > 0 && self.stagnation_count >= stagnation_limit {
+            return true;
+        }
+        self.stability
+            .as_ref()
+            .is_some_and(|s| s.settled(self.results.len()))
     }
 
     /// Updates the stagnation counter: resets on improvement, increments otherwise.
+    ///
+    /// Called once per expansion, so it also closes the expansion for the
+    /// top-k stability tracker.
     #[inline]
     pub(super) fn update_stagnation(&mut self, improved: bool) {
         if improved {
@@ -97,6 +239,9 @@ impl SearchState {
         } else {
             self.stagnation_count += 1;
         }
+        if let Some(stability) = &mut self.stability {
+            stability.end_expansion();
+        }
     }
 
     /// Consumes the state and returns results sorted by distance ascending.
@@ -141,6 +286,10 @@ impl Drop for SearchState {
         if results.capacity() > 0 {
             release_result_heap(results);
         }
+
+        if let Some(stability) = self.stability.take() {
+            release_result_heap(stability.top);
+        }
     }
 }
 
@@ -204,6 +353,9 @@ pub(super) fn gather_unvisited_neighbors<'a>(
 /// Uses `state.cached_furthest` for the admission test instead of
 /// `results.peek()`, and refreshes it after each eviction (Issue #422).
 ///
+/// Neighbors the state's filter rejects are queued as candidates only, so
+/// they steer the traversal without taking a result slot.
+///
 /// Returns `true` if any neighbor improved the result set.
 #[inline]
 pub(super) fn process_batch_results(
@@ -218,6 +370,9 @@ pub(super) fn process_batch_results(
             state
                 .candidates
                 .push(Reverse((OrderedFloat(dist), node_id)));
+            if !state.admits(node_id) {
+                continue;
+            }
             state.results.push((OrderedFloat(dist), node_id));
             if state.results.len() > ef {
                 state.results.pop();
@@ -227,6 +382,7 @@ pub(super) fn process_batch_results(
             } else if dist > state.cached_furthest {
                 state.cached_furthest = dist;
             }
+            state.observe_result(node_id, dist);
             improved = true;
         }
     }
@@ -7,7 +7,9 @@ use super::super::distance::CachedSimdDistance;
 use super::super::layer::NodeId;
 use super::super::ordered_float::OrderedFloat;
 use super::search_pools::{BitVecVisited, CANDIDATE_HEAP_POOL, POOL_MAX, RESULT_HEAP_POOL};
-use super::search_state::{gather_unvisited_neighbors, process_batch_results, SearchState};
+use super::search_state::{
+    gather_unvisited_neighbors, process_batch_results, NodeFilter, SearchState,
+};
 use super::{NativeHnsw, NO_ENTRY_POINT};
 use crate::distance::DistanceMetric;
 use rustc_hash::FxHashSet;
@@ -1081,3 +1083,174 @@ fn test_prefetch_recall_regression() {
         avg_recall * 100.0,
     );
 }
+
+// =========================================================================
+// 16. Top-k stability early termination (SearchQuality::Adaptive)
+// =========================================================================
+
+#[test]
+fn test_search_state_topk_stability_terminates_once_settled() {
+    let ef = 100;
+    let mut state = SearchState::new(0).with_topk_stability(2, 3, 2);
+    state.push_candidate(1, 0.1);
+    state.push_candidate(2, 0.3);
+    state.update_stagnation(true);
+
+    // Top-2 is full but only 2 of the 3 required results are collected.
+    state.update_stagnation(false);
+    state.update_stagnation(false);
+    assert!(
+        !state.should_terminate(0.0, ef, 0),
+        "min_results not reached"
+    );
+
+    // A worse result fills the floor without touching the top-2.
+    state.push_candidate(3, 0.9);
+    state.update_stagnation(false);
+    assert!(
+        state.should_terminate(0.0, ef, 0),
+        "top-2 unchanged for `patience` expansions"
+    );
+
+    // A better result reopens the search.
+    state.observe_result(4, 0.05);
+    state.update_stagnation(true);
+    assert!(!state.should_terminate(0.0, ef, 0));
+}
+
+#[test]
+fn test_search_state_topk_stability_zero_patience_is_disabled() {
+    let mut state = SearchState::new(0).with_topk_stability(1, 0, 0);
+    state.push_candidate(1, 0.1);
+    for _ in 0..10 {
+        state.update_stagnation(false);
+    }
+    assert!(!state.should_terminate(0.0, 100, 0));
+}
+
+#[test]
+fn test_search_until_stable_recall() {
+    let dim = 32;
+    let n = 2000;
+    let k = 10;
+    let n_queries = 20;
+
+    let engine = CachedSimdDistance::new(DistanceMetric::Euclidean, dim);
+    let hnsw = NativeHnsw::new(engine, 16, 100, n);
+    let vectors: Vec<Vec<f32>> = (0..n)
+        .map(|i| {
+            (0..dim)
+                .map(|j| ((i * 31 + j * 17) as f32 * 0.37).sin())
+                .collect()
+        })
+        .collect();
+    for v in &vectors {
+        hnsw.insert(v).expect("insert should succeed in test");
+    }
+
+    let mut adaptive_hits = 0usize;
+    for q_idx in 0..n_queries {
+        let query = &vectors[q_idx * (n / n_queries)];
+        let adaptive = hnsw.search_until_stable(query, k, 32, 512, 64);
+        assert_eq!(adaptive.len(), k);
+        assert!(adaptive.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        let mut brute: Vec<(NodeId, f32)> = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let dist: f32 = v.iter().zip(query).map(|(a, b)| (a - b) * (a - b)).sum();
+                (i, dist)
+            })
+            .collect();
+        brute.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let gt: Vec<NodeId> = brute.iter().take(k).map(|(id, _)| *id).collect();
+        adaptive_hits += adaptive.iter().filter(|(id, _)| gt.contains(id)).count();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let recall = adaptive_hits as f64 / (n_queries * k) as f64;
+    assert!(
+        recall >= 0.95,
+        "adaptive recall@{k} must be >= 95% (got {:.1}%)",
+        recall * 100.0
+    );
+
+    assert!(hnsw.search_until_stable(&vectors[0], k, 0, 0, 64).len() <= k);
+}
+
+// =========================================================================
+// 17. Filtered search: NodeFilter applied inside the traversal
+// =========================================================================
+
+#[test]
+fn test_filtered_push_marks_visited_but_skips_results() {
+    let include: roaring::RoaringBitmap = [20].into_iter().collect();
+    let mut state = SearchState::new(0).with_filter(NodeFilter::new(
+        Some(include),
+        roaring::RoaringBitmap::new(),
+    ));
+
+    state.push_candidate(10, 0.1);
+    state.push_candidate(20, 0.5);
+
+    assert!(state.visited.contains(10), "rejected node is still visited");
+    assert_eq!(state.candidates.len(), 2, "rejected node is still expanded");
+    assert_eq!(state.results.len(), 1);
+    assert_eq!(state.results.peek().map(|&(_, id)| id), Some(20));
+}
+
+#[test]
+fn test_search_filtered_returns_only_admitted_nodes() {
+    let dim = 16;
+    let n = 300;
+    let k = 10;
+
+    let engine = CachedSimdDistance::new(DistanceMetric::Euclidean, dim);
+    let hnsw = NativeHnsw::new(engine, 16, 100, n);
+    #[allow(clippy::cast_precision_loss)]
+    let vectors: Vec<Vec<f32>> = (0..n)
+        .map(|i| {
+            (0..dim)
+                .map(|j| ((i * 31 + j * 17) as f32 * 0.37).sin())
+                .collect()
+        })
+        .collect();
+    for v in &vectors {
+        hnsw.insert(v).expect("insert should succeed in test");
+    }
+
+    // Every 7th node allowed, and a few of those denied on top.
+    let include: roaring::RoaringBitmap = (0..n as u32).step_by(7).collect();
+    let exclude: roaring::RoaringBitmap = [0, 7, 14].into_iter().collect();
+    let admitted = |id: NodeId| id.is_multiple_of(7) && ![0, 7, 14].contains(&id);
+
+    let query = &vectors[5];
+    let filtered = hnsw.search_filtered(
+        query,
+        k,
+        64,
+        NodeFilter::new(Some(include), exclude.clone()),
+    );
+    assert_eq!(filtered.len(), k);
+    assert!(filtered.iter().all(|&(id, _)| admitted(id)));
+
+    let mut brute: Vec<(NodeId, f32)> = vectors
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| admitted(i))
+        .map(|(i, v)| {
+            let dist: f32 = v.iter().zip(query).map(|(a, b)| (a - b) * (a - b)).sum();
+            (i, dist)
+        })
+        .collect();
+    brute.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let gt: Vec<NodeId> = brute.iter().take(k).map(|(id, _)| *id).collect();
+    let hits = filtered.iter().filter(|(id, _)| gt.contains(id)).count();
+    assert!(hits >= 9, "filtered recall too low: {hits}/{k}");
+
+    // Denylist only: the excluded nodes never come back.
+    let denied = hnsw.search_filtered(&vectors[0], k, 64, NodeFilter::new(None, exclude));
+    assert_eq!(denied.len(), k);
+    assert!(denied.iter().all(|&(id, _)| ![0, 7, 14].contains(&id)));
+}
@@ -0,0 +1,147 @@
+//! Read-only structural scan of the HNSW graph, used by `HnswIndex::verify`.
+
+use super::super::distance::DistanceEngine;
+use super::super::layer::{Layer, NodeId};
+use super::{NativeHnsw, NO_ENTRY_POINT};
+use crate::index::hnsw::verify::{HnswIntegrityReport, LayerIntegrity};
+use rustc_hash::FxHashSet;
+use std::collections::VecDeque;
+use std::sync::atomic::Ordering;
+
+impl<D: DistanceEngine> NativeHnsw<D> {
+    /// Scans every layer under the vectors + layers read locks.
+    ///
+    /// Returns the graph part of the report (mapping fields left at zero)
+    /// and, per node, whether a layer-0 walk from the entry point reaches it.
+    pub(in crate::index::hnsw) fn check_integrity(&self) -> (HnswIntegrityReport, Vec<bool>) {
+        self.with_vectors_and_layers_read(|vectors, layers| {
+            let node_count = vectors.len();
+            let entry = self.entry_point.load(Ordering::Acquire);
+            let max_layer = self.max_layer.load(Ordering::Acquire);
+
+            let mut report = HnswIntegrityReport {
+                node_count,
+                entry_point: (entry != NO_ENTRY_POINT).then_some(entry),
+                max_layer,
+                invalid_entry_point: if entry == NO_ENTRY_POINT {
+                    !self.is_empty()
+                } else {
+                    entry >= node_count || max_layer >= layers.len()
+                },
+                ..HnswIntegrityReport::default()
+            };
+
+            let mut below: Option<Vec<bool>> = None;
+            let mut reachable = vec![false; node_count];
+            for (level, layer) in layers.iter().enumerate() {
+                let adjacency = snapshot_adjacency(layer);
+                let bound = if level == 0 {
+                    self.max_connections_0
+                } else {
+                    self.max_connections
+                };
+                report
+                    .layers
+                    .push(scan_layer(level, bound, node_count, &adjacency));
+
+                let linked: Vec<bool> = adjacency.iter().map(|n| !n.is_empty()).collect();
+                if let Some(below) = &below {
+                    report.layer_violations += linked
+                        .iter()
+                        .enumerate()
+                        .filter(|&(node, &up)| up && !below.get(node).copied().unwrap_or(false))
+                        .count();
+                } else if entry < node_count {
+                    reachable = walk_from(entry, node_count, &adjacency);
+                    report.unreachable_nodes = linked
+                        .iter()
+                        .zip(&reachable)
+                        .filter(|&(&l, &r)| l && !r)
+                        .count();
+                    report.isolated_nodes = (0..node_count)
+                        .filter(|&node| {
+                            node != entry && !linked.get(node).copied().unwrap_or(false)
+                        })
+                        .count();
+                }
+                below = Some(linked);
+            }
+            (report, reachable)
+        })
+    }
+}
+
+/// Copies a layer's adjacency lists so no two neighbor locks are held at once.
+fn snapshot_adjacency(layer: &Layer) -> Vec<Vec<NodeId>> {
+    (0..layer.neighbors.len())
+        .map(|node| {
+            layer
+                .with_neighbors(node, <[NodeId]>::to_vec)
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn scan_layer(
+    level: usize,
+    degree_bound: usize,
+    node_count: usize,
+    adjacency: &[Vec<NodeId>],
+) -> LayerIntegrity {
+    let mut stats = LayerIntegrity {
+        level,
+        degree_bound,
+        ..LayerIntegrity::default()
+    };
+    let mut seen = FxHashSet::default();
+    for (node, neighbors) in adjacency.iter().enumerate() {
+        if neighbors.is_empty() {
+            continue;
+        }
+        stats.nodes += 1;
+        stats.edges += neighbors.len();
+        stats.max_degree = stats.max_degree.max(neighbors.len());
+        if neighbors.len() > degree_bound {
+            stats.over_degree += 1;
+        }
+        if node >= node_count {
+            // A slot past the vector store holds edges of a node that was
+            // never stored: every one of them dangles.
+            stats.out_of_bounds += neighbors.len();
+            continue;
+        }
+        seen.clear();
+        for &neighbor in neighbors {
+            if !seen.insert(neighbor) {
+                stats.duplicate_edges += 1;
+            }
+            if neighbor == node {
+                stats.self_loops += 1;
+            } else if neighbor >= node_count {
+                stats.out_of_bounds += 1;
+            } else if !adjacency
+                .get(neighbor)
+                .is_some_and(|back| back.contains(&node))
+            {
+                stats.asymmetric_edges += 1;
+            }
+        }
+    }
+    stats
+}
+
+/// Breadth-first walk over in-bounds edges starting at `entry`.
+fn walk_from(entry: NodeId, node_count: usize, adjacency: &[Vec<NodeId>]) -> Vec<bool> {
+    let mut reached = vec![false; node_count];
+    reached[entry] = true;
+    let mut queue = VecDeque::from([entry]);
+    while let Some(node) = queue.pop_front() {
+        for &neighbor in adjacency.get(node).map_or(&[][..], Vec::as_slice) {
+            if neighbor < node_count && !reached[neighbor] {
+                reached[neighbor] = true;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    reached
+}
@@ -8,6 +8,7 @@
 use super::distance::DistanceEngine;
 use super::graph::{NativeHnsw, DEFAULT_ALPHA, NO_ENTRY_POINT};
 use super::layer::Layer;
+use crate::index::hnsw::NeighborSelection;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::Path;
@@ -28,7 +29,9 @@ const MAX_NEIGHBORS_PER_NODE: usize = 1 << 20;
 /// - v2: header carries the VAMANA `alpha` (f32 LE) after `count_check`, so
 ///   a custom alpha survives the save/load round-trip instead of silently
 ///   resetting to the default.
-const GRAPH_FORMAT_VERSION: u32 = 2;
+/// - v3: a trailing `u8` [`NeighborSelection`] code follows `alpha`; v1/v2
+///   files load with the default strategy.
+const GRAPH_FORMAT_VERSION: u32 = 3;
 
 /// Builds an `InvalidData` I/O error with the given message.
 fn corrupt(msg: impl Into<String>) -> std::io::Error {
@@ -46,6 +49,8 @@ pub(super) struct LoadedGraph {
     pub(super) max_layer: usize,
     /// VAMANA alpha (v2 header); [`DEFAULT_ALPHA`] for v1 files.
     pub(super) alpha: f32,
+    /// Neighbor-selection strategy (v3 header); the default for older files.
+    pub(super) neighbor_selection: NeighborSelection,
 }
 
 /// Temporary struct for graph file header fields during dump.
@@ -57,6 +62,7 @@ struct GraphFileHeader {
     entry_point: u64,
     max_layer: u32,
     alpha: f32,
+    neighbor_selection: NeighborSelection,
 }
 
 /// Reads a little-endian `u32` from the reader and returns it as `usize`.
@@ -178,6 +184,7 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
             },
             max_layer: self.max_layer.load(std::sync::atomic::Ordering::Relaxed) as u32,
             alpha: self.alpha,
+            neighbor_selection: self.neighbor_selection,
         };
 
         Self::write_graph_header(&mut writer, &header, count)?;
@@ -185,13 +192,14 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
         writer.flush()
     }
 
-    /// Writes the graph file header fields to the writer (v2: alpha last).
+    /// Writes the graph file header fields to the writer (v3: alpha, then
+    /// the neighbor-selection code last).
     fn write_graph_header(
         writer: &mut BufWriter<File>,
         header: &GraphFileHeader,
         count: u64,
     ) -> std::io::Result<()> {
-        let fields: [&[u8]; 9] = [
+        let fields: [&[u8]; 10] = [
             &GRAPH_FORMAT_VERSION.to_le_bytes(),
             &header.num_layers.to_le_bytes(),
             &header.max_connections.to_le_bytes(),
@@ -201,6 +209,7 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
             &header.max_layer.to_le_bytes(),
             &count.to_le_bytes(),
             &header.alpha.to_le_bytes(),
+            &[header.neighbor_selection.to_code()],
         ];
         for field in &fields {
             writer.write_all(field)?;
@@ -271,6 +280,8 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
             ef_construction: graph.ef_construction,
             level_mult,
             alpha: graph.alpha,
+            neighbor_selection: graph.neighbor_selection,
+            build_seed: None,
             stagnation_limit: graph.ef_construction / 2,
             pre_allocated_capacity: std::sync::atomic::AtomicUsize::new(0),
             columnar: parking_lot::RwLock::new(None),
@@ -415,6 +426,7 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
             entry_point: graph_header.entry_point,
             max_layer: graph_header.max_layer,
             alpha: graph_header.alpha,
+            neighbor_selection: graph_header.neighbor_selection,
         })
     }
 
@@ -489,8 +501,8 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
 
     /// Validates the graph file version is supported, returning it.
     ///
-    /// v1 (pre-alpha persistence) and v2 are both accepted; the caller uses
-    /// the version to decide whether an alpha field follows the header.
+    /// v1 (pre-alpha persistence), v2 and v3 are accepted; the caller uses
+    /// the version to decide which trailing fields follow the header.
     fn validate_graph_version(reader: &mut BufReader<File>) -> std::io::Result<u32> {
         let mut buf4 = [0u8; 4];
         reader.read_exact(&mut buf4)?;
@@ -510,7 +522,7 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
     /// header's own `count_check` field must match it, otherwise the two
     /// files are inconsistent (corruption / mismatched pair). v2 headers
     /// carry the VAMANA alpha after `count_check`; v1 files load with
-    /// [`DEFAULT_ALPHA`].
+    /// [`DEFAULT_ALPHA`]. v3 headers add the neighbor-selection code.
     fn read_graph_header_fields(
         reader: &mut BufReader<File>,
         count: usize,
@@ -524,6 +536,7 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
             )));
         }
         header.alpha = Self::read_graph_header_alpha(reader, version)?;
+        header.neighbor_selection = Self::read_graph_header_selection(reader, version)?;
         Ok(header)
     }
 
@@ -546,6 +559,7 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
             entry_point,
             max_layer,
             alpha: DEFAULT_ALPHA, // overwritten by caller for v2 headers
+            neighbor_selection: NeighborSelection::default(), // v3 headers
         })
     }
 
@@ -559,6 +573,25 @@ impl<D: DistanceEngine + Send + Sync> NativeHnsw<D> {
         }
     }
 
+    /// Reads the trailing neighbor-selection code for v3 headers; older
+    /// files load with the default strategy. Unknown codes are rejected.
+    fn read_graph_header_selection(
+        reader: &mut BufReader<File>,
+        version: u32,
+    ) -> std::io::Result<NeighborSelection> {
+        if version < 3 {
+            return Ok(NeighborSelection::default());
+        }
+        let mut buf = [0u8; 1];
+        reader.read_exact(&mut buf)?;
+        NeighborSelection::from_code(buf[0]).ok_or_else(|| {
+            corrupt(format!(
+                "unknown neighbor selection code {} (corrupt header)",
+                buf[0]
+            ))
+        })
+    }
+
     /// Reads `num_layers` layers from the graph file, validating every node
     /// and neighbor ID against the trusted vector `count`.
     ///
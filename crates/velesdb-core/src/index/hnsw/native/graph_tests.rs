@@ -4,6 +4,7 @@ use super::graph::NativeHnsw;
 use super::layer::NodeId;
 use crate::distance::DistanceMetric;
 use crate::index::hnsw::native::distance::{CachedSimdDistance, CpuDistance};
+use crate::index::hnsw::NeighborSelection;
 
 #[allow(clippy::cast_precision_loss)]
 #[test]
@@ -163,6 +164,43 @@ fn test_heuristic_selection_prefers_diverse_neighbors() {
     );
 }
 
+#[allow(clippy::cast_precision_loss)]
+fn collinear_hnsw(selection: NeighborSelection) -> NativeHnsw<CpuDistance> {
+    let mut hnsw = NativeHnsw::new(CpuDistance::new(DistanceMetric::Euclidean), 16, 100, 100);
+    hnsw.set_neighbor_selection(selection);
+    for i in 0..10 {
+        hnsw.insert(&[i as f32; 32]).expect("test");
+    }
+    hnsw
+}
+
+#[allow(clippy::cast_precision_loss)]
+#[test]
+fn test_simple_selection_keeps_closest_candidates() {
+    let hnsw = collinear_hnsw(NeighborSelection::Simple);
+    let candidates: Vec<(NodeId, f32)> = (0..10).map(|i| (i, i as f32)).collect();
+
+    assert_eq!(
+        hnsw.select_neighbors(&candidates, 4),
+        vec![0, 1, 2, 3],
+        "simple selection takes the candidates in distance order"
+    );
+}
+
+#[allow(clippy::cast_precision_loss)]
+#[test]
+fn test_heuristic_without_keep_pruned_leaves_slots_free() {
+    let hnsw = collinear_hnsw(NeighborSelection::Heuristic);
+    let candidates: Vec<(NodeId, f32)> = (0..10).map(|i| (i, i as f32)).collect();
+
+    let selected = hnsw.select_neighbors(&candidates, 8);
+    assert!(
+        selected.len() < 8,
+        "pruned candidates must not be backfilled, got {selected:?}"
+    );
+    assert_eq!(selected[0], 0, "the closest candidate is always kept");
+}
+
 #[allow(clippy::cast_precision_loss)]
 #[test]
 fn test_heuristic_fills_quota_with_closest_if_needed() {
@@ -618,3 +656,133 @@ fn test_snapshot_version_detects_staleness_without_explicit_clear() {
         "invalidate_gpu_caches must also clear the snapshot mutex (belt-and-suspenders)"
     );
 }
+
+// =========================================================================
+// Integrity scan (`check_integrity`)
+// =========================================================================
+
+#[allow(clippy::cast_precision_loss)]
+fn small_graph(n: usize) -> NativeHnsw<CpuDistance> {
+    let hnsw = NativeHnsw::new(CpuDistance::new(DistanceMetric::Euclidean), 8, 50, n);
+    for i in 0..n {
+        let v: Vec<f32> = (0..8).map(|j| ((i * 7 + j * 3) % 17) as f32).collect();
+        hnsw.insert(&v).expect("test");
+    }
+    hnsw
+}
+
+#[test]
+fn test_check_integrity_clean_graph() {
+    let hnsw = small_graph(200);
+    let (report, reachable) = hnsw.check_integrity();
+
+    assert!(report.is_healthy(), "{:?}", report.problems());
+    assert_eq!(report.node_count, 200);
+    assert_eq!(report.layers[0].degree_bound, 16);
+    assert!(report.layers[0].edges > 0);
+    assert!(reachable.iter().all(|&r| r));
+}
+
+#[test]
+fn test_check_integrity_flags_bad_edges() {
+    let hnsw = small_graph(50);
+    {
+        let layers = hnsw.layers.read();
+        layers[0].set_neighbors(3, vec![3, 0]);
+        layers[0].set_neighbors(4, vec![10_000, 0]);
+        layers[0].set_neighbors(5, vec![0, 0]);
+    }
+    let (report, _) = hnsw.check_integrity();
+
+    let bottom = &report.layers[0];
+    assert_eq!(bottom.self_loops, 1);
+    assert_eq!(bottom.out_of_bounds, 1);
+    assert_eq!(bottom.duplicate_edges, 1);
+    assert_eq!(bottom.over_degree, 0);
+    assert!(!report.is_healthy());
+}
+
+#[test]
+fn test_check_integrity_flags_unreachable_node_and_layer_gap() {
+    let hnsw = small_graph(50);
+    let victim: NodeId = (0..50)
+        .find(|&n| n != hnsw.entry_point.load(std::sync::atomic::Ordering::Acquire))
+        .expect("test");
+    {
+        let layers = hnsw.layers.read();
+        // Cut every edge into `victim`, keep its own out-edges.
+        for node in 0..50 {
+            layers[0].with_neighbors_mut(node, |n| n.retain(|&x| x != victim));
+        }
+        for layer in layers.iter().skip(1) {
+            layer.set_neighbors(victim, Vec::new());
+        }
+    }
+    let (report, reachable) = hnsw.check_integrity();
+    assert_eq!(report.unreachable_nodes, 1);
+    assert!(!reachable[victim]);
+    assert_eq!(report.layer_violations, 0);
+
+    // Linking the node on layer 1 while it has no layer-0 edges breaks
+    // layer monotonicity.
+    {
+        let mut layers = hnsw.layers.write();
+        if layers.len() < 2 {
+            layers.push(super::layer::Layer::new(50));
+        }
+        layers[0].set_neighbors(victim, Vec::new());
+        layers[1].set_neighbors(victim, vec![0]);
+    }
+    let (report, _) = hnsw.check_integrity();
+    assert_eq!(report.layer_violations, 1);
+    assert!(!report.is_healthy());
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn seeded_build(seed: u64) -> Vec<Vec<Vec<NodeId>>> {
+    let engine = CachedSimdDistance::new(DistanceMetric::Euclidean, 16);
+    let mut hnsw = NativeHnsw::new(engine, 8, 50, 400);
+    hnsw.set_deterministic_build(Some(seed));
+    let vectors: Vec<Vec<f32>> = (0..300)
+        .map(|i| {
+            (0..16)
+                .map(|j| ((i * 16 + j) as f32 * 0.37).sin())
+                .collect()
+        })
+        .collect();
+    let data: Vec<(&[f32], usize)> = vectors
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.as_slice(), i))
+        .collect();
+    hnsw.parallel_insert(&data).expect("test");
+
+    let layers = hnsw.layers.read();
+    layers
+        .iter()
+        .map(|layer| {
+            (0..300)
+                .map(|n| {
+                    layer
+                        .with_neighbors(n, <[NodeId]>::to_vec)
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_deterministic_build_is_reproducible() {
+    let first = seeded_build(7);
+    assert_eq!(
+        first,
+        seeded_build(7),
+        "same seed must yield the same graph"
+    );
+    assert_ne!(
+        first,
+        seeded_build(8),
+        "the seed must drive layer assignment"
+    );
+}
@@ -63,7 +63,7 @@ impl NativeHnswIndex {
         metric: DistanceMetric,
         params: HnswParams,
     ) -> crate::error::Result<Self> {
-        let inner = NativeHnswInner::new_with_options(
+        let mut inner = NativeHnswInner::new_with_options(
             metric,
             params.max_connections,
             params.max_elements,
@@ -72,6 +72,8 @@ impl NativeHnswIndex {
             params.storage_mode,
             params.alpha,
         )?;
+        inner.set_neighbor_selection(params.neighbor_selection);
+        inner.set_deterministic_build(params.deterministic_seed());
 
         Ok(Self {
             dimension,
@@ -10,7 +10,7 @@
 #![allow(clippy::cast_precision_loss)]
 
 use super::native::rabitq_precision::RaBitQPrecisionHnsw;
-use super::native::{CachedSimdDistance, NativeHnsw, NativeNeighbour, DEFAULT_ALPHA};
+use super::native::{CachedSimdDistance, NativeHnsw, NativeNeighbour, NodeFilter, DEFAULT_ALPHA};
 use crate::distance::DistanceMetric;
 use std::path::Path;
 
@@ -146,6 +146,40 @@ impl NativeHnswInner {
         }
     }
 
+    /// Returns the neighbor-selection strategy used by this backend's graph.
+    #[must_use]
+    pub(crate) fn neighbor_selection(&self) -> super::NeighborSelection {
+        match &self.backend {
+            HnswBackend::Standard(g) => g.neighbor_selection(),
+            HnswBackend::RaBitQ(p) => p.inner.neighbor_selection(),
+        }
+    }
+
+    /// Sets the neighbor-selection strategy for subsequent inserts.
+    pub(crate) fn set_neighbor_selection(&mut self, selection: super::NeighborSelection) {
+        match &mut self.backend {
+            HnswBackend::Standard(g) => g.set_neighbor_selection(selection),
+            HnswBackend::RaBitQ(p) => p.inner.set_neighbor_selection(selection),
+        }
+    }
+
+    /// Returns the seed of a deterministic build, `None` for parallel builds.
+    #[must_use]
+    pub(crate) fn deterministic_seed(&self) -> Option<u64> {
+        match &self.backend {
+            HnswBackend::Standard(g) => g.deterministic_seed(),
+            HnswBackend::RaBitQ(p) => p.inner.deterministic_seed(),
+        }
+    }
+
+    /// Switches deterministic builds on (`Some(seed)`) or off (`None`).
+    pub(crate) fn set_deterministic_build(&mut self, seed: Option<u64>) {
+        match &mut self.backend {
+            HnswBackend::Standard(g) => g.set_deterministic_build(seed),
+            HnswBackend::RaBitQ(p) => p.inner.set_deterministic_build(seed),
+        }
+    }
+
     /// Installs a pre-trained `RaBitQ` quantizer into the `RaBitQ` backend,
     /// re-encoding every stored vector in `NodeId` order.
     ///
@@ -242,6 +276,76 @@ impl NativeHnswInner {
         }
     }
 
+    /// Early-terminating adaptive search (`SearchQuality::Adaptive`).
+    ///
+    /// Returns `None` for the `RaBitQ` backend, whose binary traversal has
+    /// no top-k stability tracking; callers fall back to a fixed-ef search.
+    /// Distances are raw, as with [`search`](Self::search).
+    #[must_use]
+    pub fn search_until_stable(
+        &self,
+        query: &[f32],
+        k: usize,
+        min_ef: usize,
+        max_ef: usize,
+        patience: usize,
+    ) -> Option<Vec<(usize, f32)>> {
+        match &self.backend {
+            HnswBackend::Standard(hnsw) => {
+                Some(hnsw.search_until_stable(query, k, min_ef, max_ef, patience))
+            }
+            HnswBackend::RaBitQ(_) => None,
+        }
+    }
+
+    /// Search whose layer-0 beam starts from `num_probes` entry points
+    /// (`SearchQuality::MultiEntry`).
+    ///
+    /// Returns `None` for the `RaBitQ` backend, whose binary traversal has a
+    /// single entry point; callers fall back to a plain search. Distances
+    /// are raw, as with [`search`](Self::search).
+    #[must_use]
+    pub fn search_multi_entry(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        num_probes: usize,
+    ) -> Option<Vec<(usize, f32)>> {
+        match &self.backend {
+            HnswBackend::Standard(hnsw) => {
+                Some(hnsw.search_multi_entry(query, k, ef_search, num_probes))
+            }
+            HnswBackend::RaBitQ(_) => None,
+        }
+    }
+
+    /// Filtered search: only nodes `filter` admits enter the result pool,
+    /// while the traversal may still cross rejected nodes.
+    ///
+    /// The `RaBitQ` binary traversal has no result filter: it over-fetches
+    /// `4 * ef_search` candidates and keeps the admitted ones. Distances
+    /// follow [`search`](Self::search).
+    #[must_use]
+    pub(crate) fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        filter: NodeFilter,
+    ) -> Vec<(usize, f32)> {
+        match &self.backend {
+            HnswBackend::Standard(hnsw) => hnsw.search_filtered(query, k, ef_search, filter),
+            HnswBackend::RaBitQ(rabitq) => {
+                let pool = ef_search.saturating_mul(4).max(k);
+                let mut hits = rabitq.search(query, pool, pool);
+                hits.retain(|&(node, _)| filter.admits(node));
+                hits.truncate(k);
+                hits
+            }
+        }
+    }
+
     /// Searches the HNSW graph, automatically choosing GPU or CPU path.
     ///
     /// When the GPU feature is enabled and the index exceeds the traversal
@@ -384,6 +488,14 @@ impl NativeHnswInner {
             HnswBackend::RaBitQ(rabitq) => rabitq.inner.reorder_for_locality(),
         }
     }
+
+    /// Scans the graph structure; see [`HnswIndex::verify`](super::HnswIndex::verify).
+    pub(crate) fn check_integrity(&self) -> (super::verify::HnswIntegrityReport, Vec<bool>) {
+        match &self.backend {
+            HnswBackend::Standard(hnsw) => hnsw.check_integrity(),
+            HnswBackend::RaBitQ(rabitq) => rabitq.inner.check_integrity(),
+        }
+    }
 }
 
 // ============================================================================
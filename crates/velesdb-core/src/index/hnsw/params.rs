@@ -13,6 +13,12 @@ const fn default_alpha() -> f32 {
     1.2
 }
 
+/// Serde default for the `SearchQuality::Adaptive` `patience` field, so
+/// configs that predate the field keep deserializing.
+const fn default_adaptive_patience() -> usize {
+    SearchQuality::DEFAULT_ADAPTIVE_PATIENCE
+}
+
 /// HNSW index parameters for tuning performance and recall.
 ///
 /// Use [`HnswParams::auto`] for automatic tuning based on vector dimension,
@@ -53,6 +59,27 @@ pub struct HnswParams {
     /// strict nearest-neighbor selection.
     #[serde(default = "default_alpha")]
     pub alpha: f32,
+    /// How a node's neighbors are chosen among the construction candidates
+    /// (default: [`NeighborSelection::HeuristicKeepPruned`]).
+    ///
+    /// Applies to graph construction only; searches are unaffected except
+    /// through the shape of the graph.
+    #[serde(default)]
+    pub neighbor_selection: NeighborSelection,
+    /// Builds the graph reproducibly (default: `false`).
+    ///
+    /// Batch inserts link nodes one at a time in input order instead of in
+    /// parallel, and layer assignment draws from [`Self::build_seed`], so the
+    /// same sequence of inserts yields the same graph on every run. Meant for
+    /// benchmarks and tests: parallel batch builds are several times faster.
+    /// Inserts issued concurrently by several callers still interleave
+    /// nondeterministically.
+    #[serde(default)]
+    pub deterministic_build: bool,
+    /// Seed of the layer-assignment PRNG, used when `deterministic_build`
+    /// is set.
+    #[serde(default)]
+    pub build_seed: u64,
 }
 
 // Eq is sound because `alpha` is finite in every supported path: the preset
@@ -83,6 +110,9 @@ impl HnswParams {
                 max_elements: 100_000,
                 storage_mode: StorageMode::Full,
                 alpha: default_alpha(),
+                neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+                deterministic_build: false,
+                build_seed: 0,
             },
             // 257+ dimensions: aggressive params targeting high recall
             _ => Self {
@@ -91,6 +121,9 @@ impl HnswParams {
                 max_elements: 100_000,
                 storage_mode: StorageMode::Full,
                 alpha: default_alpha(),
+                neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+                deterministic_build: false,
+                build_seed: 0,
             },
         }
     }
@@ -126,6 +159,9 @@ impl HnswParams {
             max_elements: max_elems,
             storage_mode: StorageMode::Full,
             alpha: default_alpha(),
+            neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+            deterministic_build: false,
+            build_seed: 0,
         }
     }
 
@@ -156,6 +192,9 @@ impl HnswParams {
             max_elements: 100_000,
             storage_mode: StorageMode::Full,
             alpha: default_alpha(),
+            neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+            deterministic_build: false,
+            build_seed: 0,
         }
     }
 
@@ -183,6 +222,9 @@ impl HnswParams {
             max_elements: 100_000,
             storage_mode: StorageMode::Full,
             alpha: default_alpha(),
+            neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+            deterministic_build: false,
+            build_seed: 0,
         }
     }
 
@@ -207,6 +249,9 @@ impl HnswParams {
                 max_elements: 100_000,
                 storage_mode: StorageMode::Full,
                 alpha: default_alpha(),
+                neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+                deterministic_build: false,
+                build_seed: 0,
             },
             257..=768 => Self {
                 max_connections: 48,
@@ -214,6 +259,9 @@ impl HnswParams {
                 max_elements: 100_000,
                 storage_mode: StorageMode::Full,
                 alpha: default_alpha(),
+                neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+                deterministic_build: false,
+                build_seed: 0,
             },
             _ => Self {
                 max_connections: 64,
@@ -221,6 +269,9 @@ impl HnswParams {
                 max_elements: 100_000,
                 storage_mode: StorageMode::Full,
                 alpha: default_alpha(),
+                neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+                deterministic_build: false,
+                build_seed: 0,
             },
         }
     }
@@ -249,6 +300,9 @@ impl HnswParams {
             max_elements,
             storage_mode: StorageMode::Full,
             alpha: 1.2,
+            neighbor_selection: NeighborSelection::HeuristicKeepPruned,
+            deterministic_build: false,
+            build_seed: 0,
         }
     }
 
@@ -296,6 +350,55 @@ impl HnswParams {
         Self { alpha, ..self }
     }
 
+    /// Returns a copy of these parameters with another neighbor-selection
+    /// strategy; see [`NeighborSelection`] for the trade-offs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velesdb_core::{HnswParams, NeighborSelection};
+    ///
+    /// let params = HnswParams::auto(768).with_neighbor_selection(NeighborSelection::Simple);
+    /// assert_eq!(params.neighbor_selection, NeighborSelection::Simple);
+    /// ```
+    #[must_use]
+    pub const fn with_neighbor_selection(self, neighbor_selection: NeighborSelection) -> Self {
+        Self {
+            neighbor_selection,
+            ..self
+        }
+    }
+
+    /// Returns a copy of these parameters that builds the graph
+    /// deterministically from `seed` (see [`Self::deterministic_build`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velesdb_core::HnswParams;
+    ///
+    /// let params = HnswParams::auto(768).with_deterministic_build(42);
+    /// assert_eq!(params.deterministic_seed(), Some(42));
+    /// ```
+    #[must_use]
+    pub const fn with_deterministic_build(self, seed: u64) -> Self {
+        Self {
+            deterministic_build: true,
+            build_seed: seed,
+            ..self
+        }
+    }
+
+    /// Returns the build seed when `deterministic_build` is set.
+    #[must_use]
+    pub const fn deterministic_seed(&self) -> Option<u64> {
+        if self.deterministic_build {
+            Some(self.build_seed)
+        } else {
+            None
+        }
+    }
+
     /// Validates parameters that can originate from untrusted public input
     /// (REST, Python, Tauri) before they reach the engine.
     ///
@@ -319,6 +422,89 @@ impl HnswParams {
     }
 }
 
+/// Neighbor-selection strategy used while building the HNSW graph.
+///
+/// | Strategy | Recall | Build time | Typical use |
+/// |----------|--------|------------|-------------|
+/// | `Simple` | lowest, drops on clustered data | fastest | uniform data, throwaway indexes |
+/// | `Heuristic` | high | `O(M²)` distances per node | memory-bound graphs (fewer edges) |
+/// | `HeuristicKeepPruned` | highest | as `Heuristic` | default |
+///
+/// The heuristic accepts a candidate only when it is farther, by the
+/// collection's `alpha`, from every neighbor already kept than from the
+/// node itself (VAMANA / HNSW paper, Algorithm 4), so edges point in
+/// different directions and greedy search escapes local clusters. Higher
+/// `alpha` keeps more distant neighbors: better recall on hard queries,
+/// longer edges and slightly slower inserts. `alpha` has no effect under
+/// `Simple`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum NeighborSelection {
+    /// Keeps the `M` closest candidates; full neighbor lists evict their
+    /// farthest entry.
+    Simple,
+    /// Keeps only the candidates that pass the diversity test, so nodes may
+    /// end up with fewer than `M` neighbors.
+    Heuristic,
+    /// Runs the diversity test, then fills the remaining slots with the
+    /// closest pruned candidates (`keepPrunedConnections`).
+    #[default]
+    HeuristicKeepPruned,
+}
+
+impl NeighborSelection {
+    /// Canonical lowercase name, as accepted by [`std::str::FromStr`].
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Simple => "simple",
+            Self::Heuristic => "heuristic",
+            Self::HeuristicKeepPruned => "heuristic_keep_pruned",
+        }
+    }
+
+    /// Byte stored in the `.graph` file header (format v3).
+    pub(crate) const fn to_code(self) -> u8 {
+        match self {
+            Self::Simple => 0,
+            Self::Heuristic => 1,
+            Self::HeuristicKeepPruned => 2,
+        }
+    }
+
+    /// Inverse of [`Self::to_code`]; `None` for an unknown byte.
+    pub(crate) const fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(Self::Simple),
+            1 => Some(Self::Heuristic),
+            2 => Some(Self::HeuristicKeepPruned),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for NeighborSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for NeighborSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "simple" => Ok(Self::Simple),
+            "heuristic" => Ok(Self::Heuristic),
+            "heuristic_keep_pruned" | "keep_pruned" => Ok(Self::HeuristicKeepPruned),
+            _ => Err(format!(
+                "Unknown neighbor selection '{s}'. Valid options: simple, heuristic, heuristic_keep_pruned"
+            )),
+        }
+    }
+}
+
 /// Search quality profile controlling the recall/latency tradeoff.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -344,26 +530,33 @@ pub enum SearchQuality {
     Perfect,
     /// Custom `ef_search` value.
     Custom(usize),
-    /// Adaptive `ef_search` that starts low and doubles if the query is "hard".
+    /// Adaptive `ef_search` with early termination on a stable top-k.
     ///
-    /// Uses a two-phase approach:
-    /// 1. Search with `min_ef`
-    /// 2. If result spread (`max_dist / min_dist`) exceeds a threshold, re-search
-    ///    with doubled ef (up to `max_ef`)
+    /// Searches with a candidate pool of up to `max_ef`, but stops expanding
+    /// candidates once the current top-k has not changed for `patience`
+    /// consecutive expansions and at least `min_ef` candidates have been
+    /// collected. Easy queries (dense cluster hits) settle quickly and finish
+    /// well before `max_ef`; hard queries keep improving and run to the
+    /// full `max_ef` budget without losing recall.
     ///
-    /// For easy queries (dense cluster hits), this is 2-4x faster than fixed ef.
-    /// For hard queries, it gracefully degrades to `max_ef` with no recall loss.
+    /// `patience = 0` disables early termination (a fixed `max_ef` search).
     Adaptive {
-        /// Minimum `ef_search` (starting point). Default: 32.
+        /// Minimum `ef_search`: candidates collected before early termination
+        /// may kick in. Default: 32.
         min_ef: usize,
         /// Maximum `ef_search` (cap). Default: 512.
         max_ef: usize,
+        /// Consecutive expansions without a top-k change before stopping.
+        /// Default: [`SearchQuality::DEFAULT_ADAPTIVE_PATIENCE`].
+        #[serde(default = "default_adaptive_patience")]
+        patience: usize,
     },
     /// Auto-tuned adaptive search based on collection statistics.
     ///
     /// Computes optimal `min_ef` / `max_ef` from the collection's current size
-    /// and vector dimension, then delegates to the same two-phase adaptive
-    /// algorithm used by [`SearchQuality::Adaptive`].
+    /// and vector dimension, then delegates to the same early-terminating
+    /// adaptive search used by [`SearchQuality::Adaptive`], with the default
+    /// patience.
     ///
     /// This is the recommended quality setting for applications that want
     /// good recall without manual ef tuning:
@@ -380,9 +573,41 @@ pub enum SearchQuality {
     /// let results = index.search_with_quality(&query, 10, SearchQuality::AutoTune);
     /// ```
     AutoTune,
+    /// Fixed `ef_search` with `probes` layer-0 entry points.
+    ///
+    /// Besides the greedy-descent entry point, the layer-0 beam starts from
+    /// `probes - 1` random nodes, so a query whose nearest neighbours sit in
+    /// a cluster the descent does not reach can still find them. Helps
+    /// recall on clustered datasets at the cost of a wider beam; the other
+    /// profiles already pick a probe count from the collection size and
+    /// `ef_search`. `probes` is capped at
+    /// [`SearchQuality::MAX_ENTRY_PROBES`]; `1` disables multi-entry search.
+    MultiEntry {
+        /// `ef_search` of the layer-0 beam.
+        ef_search: usize,
+        /// Number of layer-0 entry points.
+        probes: usize,
+    },
 }
 
 impl SearchQuality {
+    /// Largest number of layer-0 entry points a search starts from.
+    pub const MAX_ENTRY_PROBES: usize = 16;
+
+    /// Default [`Adaptive`](Self::Adaptive) patience: consecutive candidate
+    /// expansions without a top-k change before the search stops early.
+    pub const DEFAULT_ADAPTIVE_PATIENCE: usize = 64;
+
+    /// Adaptive profile with the default patience.
+    #[must_use]
+    pub const fn adaptive(min_ef: usize, max_ef: usize) -> Self {
+        Self::Adaptive {
+            min_ef,
+            max_ef,
+            patience: Self::DEFAULT_ADAPTIVE_PATIENCE,
+        }
+    }
+
     /// Returns the `ef_search` value for this quality profile.
     ///
     /// # Large-scale optimization (v0.9+)
@@ -390,7 +615,8 @@ impl SearchQuality {
     /// - **Accurate**: 512 base (was 256), scales with k×16 for ≥95% recall at 100K+
     /// - **Perfect**: 4096 base (was 2048), scales with k×100 for ~100% recall
     ///   (exactly 1.0 on the ≤100K contract tests; ~0.9994 on 1M SIFT1M)
-    /// - **Adaptive**: returns `min_ef` (first phase); caller handles second phase
+    /// - **Adaptive**: returns `min_ef` (the early-termination floor); the
+    ///   adaptive search itself runs up to `max_ef`
     #[must_use]
     pub fn ef_search(&self, k: usize) -> usize {
         match self {
@@ -403,8 +629,9 @@ impl SearchQuality {
             // Increased from 2048 to 4096 for ~100% recall (1.0 ≤100K; ~0.9994 at 1M)
             Self::Perfect => 4096.max(k * 100),
             Self::Custom(ef) => (*ef).max(k),
-            // Adaptive: start with min_ef (first phase)
+            // Adaptive: min_ef is the floor before early termination
             Self::Adaptive { min_ef, .. } => (*min_ef).max(k),
+            Self::MultiEntry { ef_search, .. } => (*ef_search).max(k),
         }
     }
 
@@ -437,12 +664,40 @@ impl SearchQuality {
         scaled.min(base * 2)
     }
 
-    /// Returns `true` if this quality profile uses two-phase adaptive search.
+    /// Returns `true` if this quality profile uses adaptive (early-terminating) search.
     #[must_use]
     pub const fn is_adaptive(&self) -> bool {
         matches!(self, Self::Adaptive { .. } | Self::AutoTune)
     }
 
+    /// Returns the explicit entry-probe count of
+    /// [`MultiEntry`](Self::MultiEntry), or `None` when the index picks it.
+    #[must_use]
+    pub const fn entry_probes(&self) -> Option<usize> {
+        match self {
+            Self::MultiEntry { probes, .. } => Some(*probes),
+            _ => None,
+        }
+    }
+
+    /// Turns this profile into a [`MultiEntry`](Self::MultiEntry) search with
+    /// the same `ef_search` for `k` results and `probes` entry points.
+    ///
+    /// `Perfect` (brute force) and the adaptive profiles pick their own
+    /// traversal and are returned unchanged; `AutoTune` falls back to the
+    /// `Balanced` budget, as [`ef_search`](Self::ef_search) does.
+    #[must_use]
+    pub fn with_entry_probes(self, probes: usize, k: usize) -> Self {
+        match self {
+            Self::Perfect | Self::Adaptive { .. } => self,
+            Self::MultiEntry { ef_search, .. } => Self::MultiEntry { ef_search, probes },
+            _ => Self::MultiEntry {
+                ef_search: self.ef_search(k),
+                probes,
+            },
+        }
+    }
+
     /// Returns the maximum ef for adaptive search, or `None` for fixed profiles.
     #[must_use]
     pub const fn adaptive_max_ef(&self) -> Option<usize> {
@@ -263,6 +263,47 @@ fn test_search_quality_autotune_default_is_not_autotune() {
     assert_ne!(SearchQuality::default(), SearchQuality::AutoTune);
 }
 
+#[test]
+fn test_search_quality_multi_entry() {
+    let quality = SearchQuality::MultiEntry {
+        ef_search: 64,
+        probes: 8,
+    };
+    assert_eq!(quality.ef_search(10), 64);
+    assert_eq!(quality.ef_search(100), 100);
+    assert_eq!(quality.entry_probes(), Some(8));
+    assert_eq!(SearchQuality::Balanced.entry_probes(), None);
+
+    let json = serde_json::to_string(&quality).unwrap();
+    let deserialized: SearchQuality = serde_json::from_str(&json).unwrap();
+    assert_eq!(quality, deserialized);
+}
+
+#[test]
+fn test_search_quality_with_entry_probes_keeps_budget() {
+    assert_eq!(
+        SearchQuality::Accurate.with_entry_probes(4, 10),
+        SearchQuality::MultiEntry {
+            ef_search: 512,
+            probes: 4
+        }
+    );
+    assert_eq!(
+        SearchQuality::Custom(200).with_entry_probes(3, 10),
+        SearchQuality::MultiEntry {
+            ef_search: 200,
+            probes: 3
+        }
+    );
+    // Brute force and adaptive searches pick their own traversal.
+    assert_eq!(
+        SearchQuality::Perfect.with_entry_probes(4, 10),
+        SearchQuality::Perfect
+    );
+    let adaptive = SearchQuality::adaptive(32, 512);
+    assert_eq!(adaptive.with_entry_probes(4, 10), adaptive);
+}
+
 // =============================================================================
 // Phase 1: Large-scale optimization tests
 // =============================================================================
@@ -470,3 +511,66 @@ fn test_validate_rejects_non_finite_alpha() {
         );
     }
 }
+
+#[test]
+fn test_neighbor_selection_defaults_to_keep_pruned() {
+    // Configs persisted before the field existed keep today's behavior.
+    let json = r#"{"max_connections":32,"ef_construction":400,"max_elements":100000}"#;
+    let params: HnswParams = serde_json::from_str(json).expect("test: deserialize");
+    assert_eq!(
+        params.neighbor_selection,
+        NeighborSelection::HeuristicKeepPruned
+    );
+    assert_eq!(
+        HnswParams::auto(128).neighbor_selection,
+        NeighborSelection::HeuristicKeepPruned
+    );
+}
+
+#[test]
+fn test_neighbor_selection_serde_and_parse_round_trip() {
+    let params = HnswParams::auto(128).with_neighbor_selection(NeighborSelection::Heuristic);
+    let json = serde_json::to_string(&params).expect("test: serialize");
+    assert!(
+        json.contains(r#""neighbor_selection":"heuristic""#),
+        "{json}"
+    );
+    let back: HnswParams = serde_json::from_str(&json).expect("test: deserialize");
+    assert_eq!(back, params);
+
+    for selection in [
+        NeighborSelection::Simple,
+        NeighborSelection::Heuristic,
+        NeighborSelection::HeuristicKeepPruned,
+    ] {
+        assert_eq!(
+            selection.as_str().parse::<NeighborSelection>(),
+            Ok(selection)
+        );
+        assert_eq!(
+            NeighborSelection::from_code(selection.to_code()),
+            Some(selection)
+        );
+    }
+    assert_eq!(
+        "Keep_Pruned".parse::<NeighborSelection>(),
+        Ok(NeighborSelection::HeuristicKeepPruned)
+    );
+    assert!("nearest".parse::<NeighborSelection>().is_err());
+    assert_eq!(NeighborSelection::from_code(3), None);
+}
+
+#[test]
+fn test_deterministic_build_defaults_off_and_round_trips() {
+    let json = r#"{"max_connections":32,"ef_construction":400,"max_elements":100000}"#;
+    let params: HnswParams = serde_json::from_str(json).expect("test: deserialize");
+    assert!(!params.deterministic_build);
+    assert_eq!(params.deterministic_seed(), None);
+
+    let seeded = HnswParams::auto(128).with_deterministic_build(7);
+    assert_eq!(seeded.deterministic_seed(), Some(7));
+    let back: HnswParams =
+        serde_json::from_str(&serde_json::to_string(&seeded).expect("test: serialize"))
+            .expect("test: deserialize");
+    assert_eq!(back, seeded);
+}
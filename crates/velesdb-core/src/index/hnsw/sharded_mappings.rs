@@ -356,6 +356,20 @@ impl ShardedMappings {
         self.tombstone_slots.store(0, Ordering::Relaxed);
     }
 
+    /// Replaces every mapping with `pairs` and sets the next index.
+    ///
+    /// Used when a rebuilt graph is installed whose node numbering has gaps
+    /// (entries that changed while it was being built), so sequential
+    /// [`Self::register`] cannot reproduce it.
+    pub(crate) fn replace_all(&self, pairs: &[(u64, usize)], next_idx: usize) {
+        self.clear();
+        for &(id, idx) in pairs {
+            self.id_to_idx.insert(id, idx);
+            self.idx_to_id.insert(idx, id);
+        }
+        self.next_idx.store(next_idx, Ordering::Relaxed);
+    }
+
     /// Creates mappings from existing data (for deserialization).
     ///
     /// # Arguments
@@ -0,0 +1,173 @@
+//! Structural integrity check of an HNSW index ([`HnswIndex::verify`]).
+//!
+//! The check is read-only and takes the graph's read locks for its whole
+//! duration, so concurrent inserts wait for it. It costs one pass over every
+//! edge plus a copy of one layer's adjacency lists at a time.
+
+use super::index::HnswIndex;
+
+/// Edge and degree statistics of one HNSW layer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayerIntegrity {
+    /// Layer number, 0 being the dense bottom layer.
+    pub level: usize,
+    /// Maximum neighbors a node may keep on this layer (`M0` on layer 0, `M` above).
+    pub degree_bound: usize,
+    /// Nodes with at least one neighbor on this layer.
+    pub nodes: usize,
+    /// Directed edges on this layer.
+    pub edges: usize,
+    /// Largest neighbor list on this layer.
+    pub max_degree: usize,
+    /// Edges `a → b` without the reverse edge `b → a`.
+    ///
+    /// Expected: neighbor pruning drops back-edges by design, so this is a
+    /// statistic, not a defect.
+    pub asymmetric_edges: usize,
+    /// Edges pointing past the vector store, or owned by a node past it.
+    pub out_of_bounds: usize,
+    /// Edges from a node to itself.
+    pub self_loops: usize,
+    /// Repeated entries in a neighbor list.
+    pub duplicate_edges: usize,
+    /// Nodes with more neighbors than `degree_bound`.
+    pub over_degree: usize,
+}
+
+impl LayerIntegrity {
+    /// Average neighbors per linked node; 0.0 for an empty layer.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Reason: statistic, precision loss acceptable
+    pub fn mean_degree(&self) -> f64 {
+        if self.nodes == 0 {
+            return 0.0;
+        }
+        self.edges as f64 / self.nodes as f64
+    }
+
+    /// Share of edges that have their reverse edge; 1.0 for an empty layer.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Reason: statistic, precision loss acceptable
+    pub fn symmetry_ratio(&self) -> f64 {
+        if self.edges == 0 {
+            return 1.0;
+        }
+        1.0 - self.asymmetric_edges as f64 / self.edges as f64
+    }
+}
+
+/// Result of [`HnswIndex::verify`].
+///
+/// Defects are reported by [`Self::problems`]; the remaining fields are
+/// connectivity statistics.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HnswIntegrityReport {
+    /// Vector slots in the graph (live nodes and tombstones).
+    pub node_count: usize,
+    /// Ids mapped to a graph node.
+    pub live_nodes: usize,
+    /// Entry point of searches, `None` for an empty graph.
+    pub entry_point: Option<usize>,
+    /// Top layer of the entry point.
+    pub max_layer: usize,
+    /// The entry point is missing while nodes were inserted, lies past the
+    /// vector store, or names a layer that does not exist.
+    pub invalid_entry_point: bool,
+    /// One entry per layer, bottom first.
+    pub layers: Vec<LayerIntegrity>,
+    /// Nodes linked on a layer without being linked on the layer below.
+    pub layer_violations: usize,
+    /// Nodes with layer-0 edges that a walk from the entry point never reaches.
+    pub unreachable_nodes: usize,
+    /// Nodes without any layer-0 edge, other than the entry point. Includes
+    /// vectors written ahead of deferred indexing.
+    pub isolated_nodes: usize,
+    /// Ids mapped to a node past the vector store.
+    pub dangling_mappings: usize,
+    /// Ids whose node a walk from the entry point never reaches, so no search
+    /// can return them. Includes vectors still waiting for deferred indexing.
+    pub unreachable_live_nodes: usize,
+}
+
+impl HnswIntegrityReport {
+    /// Whether [`Self::problems`] is empty.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.problems().is_empty()
+    }
+
+    /// Human-readable description of every defect found.
+    #[must_use]
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.invalid_entry_point {
+            problems.push(format!(
+                "invalid entry point {:?} at layer {} ({} nodes, {} layers)",
+                self.entry_point,
+                self.max_layer,
+                self.node_count,
+                self.layers.len()
+            ));
+        }
+        for layer in &self.layers {
+            let level = layer.level;
+            let counts = [
+                (layer.out_of_bounds, "out-of-bounds edge(s)"),
+                (layer.self_loops, "self-loop(s)"),
+                (layer.duplicate_edges, "duplicate edge(s)"),
+                (layer.over_degree, "node(s) above the degree bound"),
+            ];
+            for (count, what) in counts {
+                if count > 0 {
+                    problems.push(format!("layer {level}: {count} {what}"));
+                }
+            }
+        }
+        let counts = [
+            (
+                self.layer_violations,
+                "node(s) linked on a layer but not below it",
+            ),
+            (
+                self.unreachable_nodes,
+                "linked node(s) unreachable from the entry point",
+            ),
+            (self.dangling_mappings, "id(s) mapped past the vector store"),
+            (
+                self.unreachable_live_nodes,
+                "live id(s) unreachable from the entry point",
+            ),
+        ];
+        for (count, what) in counts {
+            if count > 0 {
+                problems.push(format!("{count} {what}"));
+            }
+        }
+        problems
+    }
+}
+
+impl HnswIndex {
+    /// Checks the graph structure and returns connectivity statistics.
+    ///
+    /// Checks neighbor-list bounds, duplicates, self-loops and degree bounds
+    /// per layer, that nodes linked on a layer are linked on the layer below,
+    /// that the entry point is valid, and that every linked node and every
+    /// live id is reachable from the entry point on layer 0. Back-edge
+    /// symmetry is reported as a statistic.
+    ///
+    /// Read-only; holds the graph read locks for the whole scan.
+    #[must_use]
+    pub fn verify(&self) -> HnswIntegrityReport {
+        let (mut report, reachable) = self.inner.read().check_integrity();
+        for (_, idx) in self.mappings.iter() {
+            report.live_nodes += 1;
+            match reachable.get(idx) {
+                None => report.dangling_mappings += 1,
+                Some(false) => report.unreachable_live_nodes += 1,
+                Some(true) => {}
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,64 @@
+//! Tests for `HnswIndex::verify`.
+#![allow(clippy::cast_precision_loss)]
+
+use super::index::HnswIndex;
+use crate::distance::DistanceMetric;
+use crate::index::VectorIndex;
+
+fn vector(i: u64) -> Vec<f32> {
+    (0..16)
+        .map(|j| ((i * 31 + j * 7) % 23) as f32 + 0.5)
+        .collect()
+}
+
+#[test]
+fn test_verify_empty_index_is_healthy() {
+    let index = HnswIndex::new(16, DistanceMetric::Cosine).expect("test");
+    let report = index.verify();
+
+    assert!(report.is_healthy(), "{:?}", report.problems());
+    assert_eq!(report.entry_point, None);
+    assert_eq!(report.live_nodes, 0);
+}
+
+#[test]
+fn test_verify_after_inserts_upserts_and_removes() {
+    let index = HnswIndex::new(16, DistanceMetric::Euclidean).expect("test");
+    for id in 0..300 {
+        index.insert(id, &vector(id));
+    }
+    let batch: Vec<(u64, Vec<f32>)> = (300..600).map(|id| (id, vector(id))).collect();
+    index.insert_batch_parallel(batch.iter().map(|(id, v)| (*id, v.as_slice())));
+    // Re-inserting an id leaves its old node behind as a tombstone.
+    for id in 0..50 {
+        index.insert(id, &vector(id + 1000));
+    }
+    for id in 100..150 {
+        index.remove(id);
+    }
+
+    let report = index.verify();
+    assert!(report.is_healthy(), "{:?}", report.problems());
+    assert_eq!(report.live_nodes, 550);
+    assert_eq!(report.node_count, 650);
+    let bottom = &report.layers[0];
+    assert!(bottom.mean_degree() > 1.0);
+    assert!(bottom.max_degree <= bottom.degree_bound);
+    assert!((0.0..=1.0).contains(&bottom.symmetry_ratio()));
+}
+
+#[test]
+fn test_verify_flags_mapping_past_vector_store() {
+    let index = HnswIndex::new(16, DistanceMetric::Euclidean).expect("test");
+    for id in 0..10 {
+        index.insert(id, &vector(id));
+    }
+    index.mappings.restore(99, 5_000);
+
+    let report = index.verify();
+    assert_eq!(report.dangling_mappings, 1);
+    assert_eq!(
+        report.problems(),
+        vec!["1 id(s) mapped past the vector store"]
+    );
+}
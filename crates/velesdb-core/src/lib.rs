@@ -81,7 +81,9 @@ pub mod column_store;
 mod column_store_tests;
 pub mod compression;
 pub mod config;
+pub mod config_growth;
 pub mod config_quantization;
+pub mod config_residency;
 #[cfg(test)]
 mod config_tests;
 mod config_validation;
@@ -102,6 +104,9 @@ pub mod filter;
 mod filter_like_tests;
 #[cfg(test)]
 mod filter_tests;
+pub mod fingerprint;
+#[cfg(test)]
+mod fingerprint_tests;
 pub mod fusion;
 pub mod gpu;
 #[cfg(test)]
@@ -162,6 +167,9 @@ mod test_fixtures;
 #[cfg(all(not(target_arch = "wasm32"), feature = "update-check"))]
 pub mod update_check;
 pub mod validation;
+pub mod vector_layout;
+#[cfg(test)]
+mod vector_layout_tests;
 pub mod vector_ref;
 #[cfg(test)]
 mod vector_ref_tests;
@@ -175,48 +183,116 @@ pub use update_check::{check_for_updates, spawn_update_check};
 pub use update_check::{compute_instance_hash, UpdateCheckConfig};
 
 #[cfg(feature = "persistence")]
-pub use index::{HnswIndex, HnswParams, SearchQuality, VectorIndex};
+pub use index::{
+    HnswIndex, HnswIntegrityReport, HnswParams, LayerIntegrity, NeighborSelection, SearchQuality,
+    VectorIndex,
+};
 
 #[cfg(feature = "persistence")]
 pub use collection::streaming::{BackpressureError, StreamIngester, StreamingConfig};
 #[cfg(feature = "persistence")]
 pub use collection::{
+    // Per-point access counts and payload cache counters (`access_stats`)
+    AccessStats,
+    // Per-collection query admission counters (`admission_stats`)
+    AdmissionKind,
+    AdmissionKindStats,
+    AdmissionStats,
     // Type-erased collection handle (v2.0.0)
     AnyCollection,
+    // Archive tier for cold points (`archive_cold_points`)
+    ArchivePolicy,
+    ArchiveReport,
+    // Filtered change subscriptions (`subscribe`)
+    ChangeEvent,
+    ChangeKind,
+    ChangeSubscription,
     // Diagnostics (US-006: embedded SDK health checks)
     CollectionDiagnostics,
     // Public user-facing types — 3 typed collections replace Collection as primary API
     CollectionType,
     // Graph API types (user-visible)
     EdgeType,
+    // Embedding model metadata recorded in config.json
+    EmbeddingInfo,
     GraphCollection,
     GraphEdge,
+    // Graph interchange (`export_graph` / `import_graph`)
+    GraphFormat,
+    GraphImportStats,
     GraphNode,
     GraphSchema,
+    // Graph shape statistics (`graph_stats`)
+    GraphStats,
+    // Request idempotency keys (`claim_idempotency_key`)
+    IdempotencyClaim,
+    IdempotencyOutcome,
+    IdempotentResponse,
+    // File import (`import_jsonl` / `import_csv`)
+    ImportMapping,
+    ImportProgress,
+    ImportReport,
+    ImportRowError,
     // Diagnostics (US-006: embedded SDK health checks)
     IndexHealth,
     IndexInfo,
     MetadataCollection,
+    // Near-duplicate query (`near_duplicate_text`)
+    NearDuplicateQuery,
+    // Negative-vector search strategy (`search_with_negatives`, `NEAR ... AVOID`)
+    NegativeStrategy,
     NodeType,
+    OnBadRow,
     // Ordered-index ORDER BY advisor (EPIC-081 phase 3a)
     OrderByIndexState,
     OrderByIndexSuggestion,
+    // Filtered-subset HNSW indexes (`create_partial_index`)
+    PartialIndexInfo,
+    PayloadCacheStats,
+    PointAccessCount,
+    // Zero-copy read views (`get_view`, `search_view`, `vector_reader`)
+    PointsView,
+    // Scoring-profile A/B comparison (`compare_profiles`)
+    ProfileComparison,
+    ProfileQuery,
+    ProfileRunReport,
+    // Collection-level search-option defaults recorded in config.json
+    QueryDefaults,
+    // Recommend-by-example strategy (`recommend_with_strategy`)
+    RecommendStrategy,
+    // Named scoring profiles (`set_scoring_profile`, `WITH (profile = ...)`)
+    ScoringProfile,
     // Scroll cursor (Issue #429)
     ScrollBatch,
+    // Grouped search result (`search_grouped`)
+    SearchGroup,
+    // Id allowlist / denylist for `search_with_options`
+    SearchOptions,
+    // Cursor-paginated / streaming search (`search_paginated`, `search_iter`)
+    SearchPage,
+    SearchResultIter,
+    SearchView,
+    // Pairwise similarity (`pairwise_similarity`, `pairwise_similarity_top_k`)
+    SimilarityMatrix,
+    SparseSimilarityMatrix,
     TraversalConfig,
     TraversalPath,
     TraversalResult,
+    // Per-call upsert options (read-your-writes)
+    UpsertOptions,
     ValueType,
     VectorCollection,
+    VectorEncoding,
+    VectorReader,
     // Durable TTL payload key (shared across all collection types and external crates)
     EXPIRES_AT_KEY,
 };
 pub use contiguous_ops::pad_to_simd_width;
 pub use distance::{DistanceMetric, CONDITION_TYPE_NAMES, DISTANCE_METRIC_NAMES};
 pub use error::{Error, Result};
-pub use filter::{Condition, Filter};
+pub use filter::{Collation, Condition, Filter, SortCollation};
 pub use lock_rank::{assert_lock_order, LockRank};
-pub use point::{ComponentScores, Point, SearchResult};
+pub use point::{ComponentScores, Point, PointProjection, SearchResult};
 pub use quantization::{
     cosine_similarity_quantized, cosine_similarity_quantized_simd, dot_product_quantized,
     dot_product_quantized_simd, euclidean_squared_quantized, euclidean_squared_quantized_simd,
@@ -224,9 +300,10 @@ pub use quantization::{
 };
 pub use scored_result::ScoredResult;
 pub use validation::{
-    validate_collection_name, validate_dimension, validate_dimension_match,
-    MAX_COLLECTION_NAME_LENGTH, MAX_DIMENSION, MIN_DIMENSION,
+    validate_collection_name, validate_dimension, validate_dimension_match, validate_vector_finite,
+    validate_vector_norm, MAX_COLLECTION_NAME_LENGTH, MAX_DIMENSION, MIN_DIMENSION,
 };
+pub use vector_layout::VectorLayout;
 // Canonical cross-engine stable hashing (FNV-1a). Lives in `wire::stable_hash`
 // so persistence-free targets (WASM) can delegate to it. Consumers deriving a
 // numeric ID from a string for persisted or interoperable use MUST call
@@ -239,8 +316,9 @@ pub use wire::stable_hash::{hash_edge_id, hash_id, hash_id_bytes};
 
 #[cfg(feature = "persistence")]
 pub use column_store::{
-    BatchUpdate, BatchUpdateResult, BatchUpsertResult, ColumnStore, ColumnStoreError, ColumnType,
-    ColumnValue, ExpireResult, StringId, StringTable, TypedColumn, UpsertResult,
+    BatchUpdate, BatchUpdateResult, BatchUpsertResult, ColumnArithOp, ColumnExpr, ColumnStore,
+    ColumnStoreError, ColumnType, ColumnValue, ConditionalUpdate, ExpireResult, StringId,
+    StringTable, TypedColumn, UpdateCondition, UpsertResult,
 };
 // Observability and guardrail surfaces (audit-2026q2 H2): these were previously
 // reachable only via deep paths (`velesdb_core::metrics::*`,
@@ -249,8 +327,9 @@ pub use column_store::{
 // applies the Facade pattern so the public API can evolve independently
 // of the internal organisation.
 pub use config::{
-    ConfigError, HnswConfig, LimitsConfig, QuantizationConfig, QuantizationType, SearchConfig,
-    SearchMode, VelesConfig,
+    ConfigError, HnswConfig, LimitsConfig, MmapAdvice, MmapGrowth, MmapResidency,
+    NonFiniteVectorPolicy, QuantizationConfig, QuantizationType, SearchConfig, SearchMode,
+    VelesConfig,
 };
 #[cfg(feature = "persistence")]
 pub use config::{LoggingConfig, ServerConfig, StorageConfig};
@@ -267,6 +346,7 @@ pub use metrics::{
 pub use metrics::{
     DurationHistogram, GuardRailsMetrics, OperationalMetrics, QueryStats, TraversalMetrics,
 };
+pub use metrics::{LatencySlo, SloAlert, SloAlertKind, SloState, SloStatus};
 
 #[cfg(feature = "persistence")]
 mod database;
@@ -274,7 +354,14 @@ mod database;
 pub mod observer;
 
 #[cfg(feature = "persistence")]
-pub use database::{Database, GatedRead};
+pub use database::{
+    CheckpointStats, CollectionLoadFailure, CollectionReport, CollectionView, Database,
+    DimensionTransform, DiskSpace, DryRunReport, ForbidFullScan, GatedRead, MigrationPhase,
+    MigrationProgress, MigrationReport, MigrationSpec, QueryRewriteRule, RecoveryReport,
+    RequestContext, RestoreReport, RewriteContext, RowSecurityPolicy, ShippedSnapshot,
+    SloAlertHook, SnapshotKind, SnapshotShipper, VectorMapFn, VerifyIssue, DRY_RUN_SAMPLE_IDS,
+    FULL_SNAPSHOT_EVERY, MAX_CATCH_UP_PASSES,
+};
 #[cfg(feature = "persistence")]
 pub use observer::DatabaseObserver;
 #[cfg(feature = "persistence")]
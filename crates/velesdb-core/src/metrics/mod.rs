@@ -5,6 +5,7 @@
 //! - **Latency statistics**: Percentile computation (p50, p95, p99)
 //! - **Operational metrics**: Prometheus-exportable counters/gauges
 //! - **Query diagnostics**: Slow query logging, tracing spans, histograms
+//! - **Latency SLOs**: Sliding-window objectives with breach/recovery alerts
 //!
 //! # Example
 //!
@@ -24,6 +25,7 @@ mod latency;
 mod operational;
 mod query;
 mod retrieval;
+mod slo;
 
 // Re-export retrieval quality metrics
 pub use retrieval::{
@@ -41,3 +43,6 @@ pub use guardrails::{global_guardrails_metrics, GuardRailsMetrics, TraversalMetr
 
 // Re-export query diagnostics
 pub use query::{DurationHistogram, QueryStats, SlowQueryLogger, SpanBuilder};
+
+// Re-export latency SLO tracking
+pub use slo::{LatencySlo, SloAlert, SloAlertKind, SloState, SloStatus, SloTracker};
@@ -0,0 +1,380 @@
+//! Latency SLO tracking over sliding windows.
+//!
+//! A [`LatencySlo`] states an objective such as "p99 search latency under
+//! 20 ms". An [`SloTracker`] keeps the latencies observed in the last
+//! `window_secs` and raises an [`SloAlert`] once the objective has been
+//! missed continuously for `sustain_secs`, then again when it recovers.
+//! Short spikes shorter than `sustain_secs` never alert.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// Most samples a tracker keeps; older samples are dropped first, so under
+/// heavy load the window shrinks to the most recent `MAX_SAMPLES` requests.
+const MAX_SAMPLES: usize = 10_000;
+
+/// How often [`SloTracker::record`] re-evaluates the window.
+const EVAL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A latency objective for one collection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatencySlo {
+    /// Percentile the objective applies to, in `(0, 100]` (e.g. `99.0`).
+    pub percentile: f64,
+    /// Latency the percentile must stay under, in milliseconds.
+    pub threshold_ms: f64,
+    /// Length of the sliding window the percentile is computed over.
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+    /// How long the objective must be missed before an alert fires.
+    #[serde(default = "default_sustain_secs")]
+    pub sustain_secs: u64,
+    /// Fewer samples than this in the window never count as a violation.
+    #[serde(default = "default_min_samples")]
+    pub min_samples: usize,
+}
+
+fn default_window_secs() -> u64 {
+    60
+}
+
+fn default_sustain_secs() -> u64 {
+    60
+}
+
+fn default_min_samples() -> usize {
+    20
+}
+
+impl LatencySlo {
+    /// An objective on `percentile` under `threshold_ms`, with a 60 s window,
+    /// a 60 s sustain period and at least 20 samples.
+    #[must_use]
+    pub fn new(percentile: f64, threshold_ms: f64) -> Self {
+        Self {
+            percentile,
+            threshold_ms,
+            window_secs: default_window_secs(),
+            sustain_secs: default_sustain_secs(),
+            min_samples: default_min_samples(),
+        }
+    }
+
+    /// Checks that the objective can be evaluated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] when the percentile is outside
+    /// `(0, 100]`, the threshold is not positive, or the window is empty.
+    pub fn validate(&self) -> Result<()> {
+        if !(self.percentile > 0.0 && self.percentile <= 100.0) {
+            return Err(Error::Config(format!(
+                "SLO percentile must be in (0, 100], got {}",
+                self.percentile
+            )));
+        }
+        if !(self.threshold_ms.is_finite() && self.threshold_ms > 0.0) {
+            return Err(Error::Config(format!(
+                "SLO threshold_ms must be positive, got {}",
+                self.threshold_ms
+            )));
+        }
+        if self.window_secs == 0 {
+            return Err(Error::Config(
+                "SLO window_secs must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Where an objective stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SloState {
+    /// Met, or too few samples to tell.
+    Ok,
+    /// Missed, for less than `sustain_secs` so far.
+    Violating,
+    /// Missed for at least `sustain_secs`; an alert has fired.
+    Breached,
+}
+
+/// A snapshot of an objective and the window it is evaluated on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloStatus {
+    /// The objective.
+    pub slo: LatencySlo,
+    /// Current state.
+    pub state: SloState,
+    /// Observed latency at the objective's percentile, in milliseconds;
+    /// `None` when the window holds no samples.
+    pub observed_ms: Option<f64>,
+    /// Samples in the window.
+    pub samples: usize,
+    /// Seconds the objective has been missed without interruption.
+    pub violating_for_secs: Option<u64>,
+}
+
+/// What an [`SloAlert`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SloAlertKind {
+    /// The objective has been missed for `sustain_secs`.
+    Breached,
+    /// A breached objective is met again.
+    Recovered,
+}
+
+/// Raised when an objective becomes breached or recovers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloAlert {
+    /// Collection the objective belongs to.
+    pub collection: String,
+    /// Breach or recovery.
+    pub kind: SloAlertKind,
+    /// The objective's status when the alert was raised.
+    pub status: SloStatus,
+}
+
+#[derive(Debug, Default)]
+struct Window {
+    samples: VecDeque<(Instant, Duration)>,
+    violating_since: Option<Instant>,
+    breached: bool,
+    last_eval: Option<Instant>,
+}
+
+/// Sliding-window tracker for one [`LatencySlo`].
+#[derive(Debug)]
+pub struct SloTracker {
+    slo: LatencySlo,
+    window: Mutex<Window>,
+}
+
+impl SloTracker {
+    /// Creates a tracker with an empty window.
+    #[must_use]
+    pub fn new(slo: LatencySlo) -> Self {
+        Self {
+            slo,
+            window: Mutex::new(Window::default()),
+        }
+    }
+
+    /// The tracked objective.
+    #[must_use]
+    pub fn slo(&self) -> &LatencySlo {
+        &self.slo
+    }
+
+    /// Records one request latency. Returns the transition it caused, if
+    /// any, with the status it was detected at (the window is re-evaluated at
+    /// most once per second).
+    pub fn record(&self, latency: Duration) -> Option<(SloAlertKind, SloStatus)> {
+        self.record_at(Instant::now(), latency)
+    }
+
+    /// Evaluates the window now. Returns the current status and the
+    /// transition the evaluation caused, if any: with no new traffic, an
+    /// objective is only seen to recover when polled. Polling does not delay
+    /// the evaluation of the next [`record`](Self::record).
+    pub fn poll(&self) -> (SloStatus, Option<SloAlertKind>) {
+        self.poll_at(Instant::now())
+    }
+
+    pub(crate) fn record_at(
+        &self,
+        now: Instant,
+        latency: Duration,
+    ) -> Option<(SloAlertKind, SloStatus)> {
+        let mut window = self.window.lock();
+        if window.samples.len() == MAX_SAMPLES {
+            window.samples.pop_front();
+        }
+        window.samples.push_back((now, latency));
+        if window
+            .last_eval
+            .is_some_and(|last| now.saturating_duration_since(last) < EVAL_INTERVAL)
+        {
+            return None;
+        }
+        window.last_eval = Some(now);
+        let (status, transition) = self.evaluate(&mut window, now);
+        transition.map(|kind| (kind, status))
+    }
+
+    pub(crate) fn poll_at(&self, now: Instant) -> (SloStatus, Option<SloAlertKind>) {
+        let mut window = self.window.lock();
+        self.evaluate(&mut window, now)
+    }
+
+    fn evaluate(&self, window: &mut Window, now: Instant) -> (SloStatus, Option<SloAlertKind>) {
+        let horizon = Duration::from_secs(self.slo.window_secs);
+        while window
+            .samples
+            .front()
+            .is_some_and(|(at, _)| now.saturating_duration_since(*at) > horizon)
+        {
+            window.samples.pop_front();
+        }
+
+        let observed = percentile_ms(&window.samples, self.slo.percentile);
+        let missed = window.samples.len() >= self.slo.min_samples.max(1)
+            && observed.is_some_and(|ms| ms > self.slo.threshold_ms);
+
+        let mut transition = None;
+        if missed {
+            let since = *window.violating_since.get_or_insert(now);
+            let sustained =
+                now.saturating_duration_since(since) >= Duration::from_secs(self.slo.sustain_secs);
+            if sustained && !window.breached {
+                window.breached = true;
+                transition = Some(SloAlertKind::Breached);
+            }
+        } else {
+            window.violating_since = None;
+            if window.breached {
+                window.breached = false;
+                transition = Some(SloAlertKind::Recovered);
+            }
+        }
+
+        let state = match (window.breached, window.violating_since) {
+            (true, _) => SloState::Breached,
+            (false, Some(_)) => SloState::Violating,
+            (false, None) => SloState::Ok,
+        };
+        let status = SloStatus {
+            slo: self.slo.clone(),
+            state,
+            observed_ms: observed,
+            samples: window.samples.len(),
+            violating_for_secs: window
+                .violating_since
+                .map(|since| now.saturating_duration_since(since).as_secs()),
+        };
+        (status, transition)
+    }
+}
+
+/// Nearest-rank percentile of the window, in milliseconds.
+fn percentile_ms(samples: &VecDeque<(Instant, Duration)>, percentile: f64) -> Option<f64> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut latencies: Vec<Duration> = samples.iter().map(|(_, latency)| *latency).collect();
+    latencies.sort_unstable();
+    // Reason: percentile is validated to (0, 100], so the rank is in [1, n].
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let rank = ((percentile / 100.0) * latencies.len() as f64).ceil() as usize;
+    let latency = latencies[rank.clamp(1, latencies.len()) - 1];
+    Some(latency.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slo() -> LatencySlo {
+        LatencySlo {
+            percentile: 99.0,
+            threshold_ms: 20.0,
+            window_secs: 10,
+            sustain_secs: 5,
+            min_samples: 3,
+        }
+    }
+
+    fn feed(tracker: &SloTracker, start: Instant, secs: u64, latency_ms: u64) -> Vec<SloAlertKind> {
+        (0..=secs)
+            .filter_map(|s| {
+                tracker
+                    .record_at(
+                        start + Duration::from_secs(s),
+                        Duration::from_millis(latency_ms),
+                    )
+                    .map(|(kind, _)| kind)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_objectives() {
+        assert!(slo().validate().is_ok());
+        for bad in [
+            LatencySlo::new(0.0, 20.0),
+            LatencySlo::new(100.5, 20.0),
+            LatencySlo::new(99.0, 0.0),
+            LatencySlo::new(99.0, f64::NAN),
+            LatencySlo {
+                window_secs: 0,
+                ..slo()
+            },
+        ] {
+            assert!(bad.validate().is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn test_sustained_violation_breaches_once_then_recovers() {
+        let tracker = SloTracker::new(slo());
+        let start = Instant::now();
+
+        assert!(feed(&tracker, start, 4, 50).is_empty());
+        let (status, _) = tracker.poll_at(start + Duration::from_secs(4));
+        assert_eq!(status.state, SloState::Violating);
+        assert_eq!(status.violating_for_secs, Some(2));
+
+        assert_eq!(
+            feed(&tracker, start + Duration::from_secs(5), 5, 50),
+            [SloAlertKind::Breached]
+        );
+        assert_eq!(
+            tracker.poll_at(start + Duration::from_secs(10)).0.state,
+            SloState::Breached
+        );
+
+        // Once the slow samples leave the window the objective recovers.
+        let later = start + Duration::from_secs(30);
+        assert_eq!(feed(&tracker, later, 3, 1), [SloAlertKind::Recovered]);
+        let (status, _) = tracker.poll_at(later + Duration::from_secs(3));
+        assert_eq!(status.state, SloState::Ok);
+        assert_eq!(status.samples, 4);
+        assert!(status.observed_ms.is_some_and(|ms| ms < 20.0));
+    }
+
+    #[test]
+    fn test_short_spike_and_small_windows_never_breach() {
+        let tracker = SloTracker::new(LatencySlo {
+            sustain_secs: 20,
+            ..slo()
+        });
+        let start = Instant::now();
+        assert!(feed(&tracker, start, 3, 50).is_empty());
+        assert!(feed(&tracker, start + Duration::from_secs(4), 20, 1).is_empty());
+        assert_eq!(
+            tracker.poll_at(start + Duration::from_secs(24)).0.state,
+            SloState::Ok
+        );
+
+        // Fewer than `min_samples` samples: never a violation.
+        let sparse = SloTracker::new(slo());
+        let (status, transition) = sparse.poll_at(start);
+        assert_eq!(
+            (status.state, transition, status.observed_ms),
+            (SloState::Ok, None, None)
+        );
+        sparse.record_at(start, Duration::from_millis(500));
+        assert_eq!(sparse.poll_at(start).0.state, SloState::Ok);
+    }
+}
@@ -102,6 +102,26 @@ impl Point {
         Self::new(id, vector, None)
     }
 
+    /// Creates a point from a packed bitset, for `Hamming` / `Jaccard`
+    /// collections (e.g. chemical fingerprints).
+    ///
+    /// Bit `i` is bit `i % 8` of `bits[i / 8]` (least significant first),
+    /// so the dimension is `bits.len() * 8`. Each bit becomes a `0.0` /
+    /// `1.0` component; [`Self::to_bits`] packs it back.
+    #[must_use]
+    pub fn from_bits(id: u64, bits: &[u8]) -> Self {
+        Self::without_payload(id, unpack_bits(bits))
+    }
+
+    /// Packs the vector into a bitset in the [`Self::from_bits`] layout.
+    ///
+    /// Returns `None` unless every component is exactly `0.0` or `1.0`. A
+    /// dimension that is not a multiple of 8 is zero-padded.
+    #[must_use]
+    pub fn to_bits(&self) -> Option<Vec<u8>> {
+        pack_bits(&self.vector)
+    }
+
     /// Creates a metadata-only point (no vector, only payload).
     ///
     /// Used for metadata-only collections that don't store vectors.
@@ -175,6 +195,19 @@ impl Point {
         self.vector.len()
     }
 
+    /// Replaces every NaN or infinite vector component with `0.0`.
+    ///
+    /// Returns the number of components replaced. Used by upserts when
+    /// `limits.non_finite_vectors = "sanitize_to_zero"`.
+    pub fn sanitize_non_finite(&mut self) -> usize {
+        let mut replaced = 0;
+        for value in self.vector.iter_mut().filter(|v| !v.is_finite()) {
+            *value = 0.0;
+            replaced += 1;
+        }
+        replaced
+    }
+
     /// Returns `true` if this point carries **no dense vector**.
     ///
     /// This is a *per-point* property — it means `self.vector` is empty, i.e.
@@ -197,6 +230,87 @@ impl Point {
     }
 }
 
+/// Expands a [`Point::from_bits`] bitset into `0.0` / `1.0` components.
+pub(crate) fn unpack_bits(bits: &[u8]) -> Vec<f32> {
+    bits.iter()
+        .flat_map(|byte| (0..8).map(move |bit| f32::from((byte >> bit) & 1)))
+        .collect()
+}
+
+/// Packs `0.0` / `1.0` components into a [`Point::from_bits`] bitset, or
+/// `None` if any component is something else.
+#[allow(clippy::float_cmp)] // Reason: only exact 0.0 / 1.0 components are bits.
+pub(crate) fn pack_bits(vector: &[f32]) -> Option<Vec<u8>> {
+    let mut bits = vec![0_u8; vector.len().div_ceil(8)];
+    for (i, &value) in vector.iter().enumerate() {
+        if value == 1.0 {
+            bits[i / 8] |= 1 << (i % 8);
+        } else if value != 0.0 {
+            return None;
+        }
+    }
+    Some(bits)
+}
+
+/// Which parts of a [`Point`] a read should return.
+///
+/// Reads default to [`PointProjection::FULL`]. Clients that only need ids
+/// (and scores, for searches) can drop the vector and/or payload to shrink
+/// responses; the id is always kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointProjection {
+    /// Keep the dense vector (and sparse vectors).
+    pub with_vector: bool,
+    /// Keep the JSON payload.
+    pub with_payload: bool,
+}
+
+impl Default for PointProjection {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+impl PointProjection {
+    /// Vector and payload are both returned.
+    pub const FULL: Self = Self {
+        with_vector: true,
+        with_payload: true,
+    };
+
+    /// Only the id is returned.
+    pub const ID_ONLY: Self = Self {
+        with_vector: false,
+        with_payload: false,
+    };
+
+    /// Creates a projection from the two inclusion flags.
+    #[must_use]
+    pub const fn new(with_vector: bool, with_payload: bool) -> Self {
+        Self {
+            with_vector,
+            with_payload,
+        }
+    }
+
+    /// Returns `true` when nothing is stripped.
+    #[must_use]
+    pub const fn is_full(self) -> bool {
+        self.with_vector && self.with_payload
+    }
+
+    /// Strips the excluded parts from `point` in place.
+    pub fn apply(self, point: &mut Point) {
+        if !self.with_vector {
+            point.vector = Vec::new();
+            point.sparse_vectors = None;
+        }
+        if !self.with_payload {
+            point.payload = None;
+        }
+    }
+}
+
 /// Per-component score breakdown for hybrid search results.
 ///
 /// Stores individual scores from each search pipeline component (vector,
@@ -150,3 +150,65 @@ fn test_point_named_sparse_vectors_multiple() {
     assert_eq!(sv_map.get("title").unwrap().nnz(), 2);
     assert_eq!(sv_map.get("body").unwrap().nnz(), 2);
 }
+
+#[test]
+fn test_point_projection_default_is_full() {
+    assert_eq!(PointProjection::default(), PointProjection::FULL);
+    assert!(PointProjection::FULL.is_full());
+    assert!(!PointProjection::new(false, true).is_full());
+}
+
+#[test]
+fn test_point_projection_apply_strips_excluded_parts() {
+    let sparse = BTreeMap::from([(String::new(), SparseVector::new(vec![(1, 1.0)]))]);
+    let original = Point::with_sparse(7, vec![0.1, 0.2], Some(json!({"k": 1})), Some(sparse));
+
+    let mut point = original.clone();
+    PointProjection::new(false, true).apply(&mut point);
+    assert!(point.vector.is_empty());
+    assert!(point.sparse_vectors.is_none());
+    assert_eq!(point.payload, Some(json!({"k": 1})));
+
+    let mut point = original.clone();
+    PointProjection::new(true, false).apply(&mut point);
+    assert_eq!(point.vector, vec![0.1, 0.2]);
+    assert!(point.payload.is_none());
+
+    let mut point = original;
+    PointProjection::ID_ONLY.apply(&mut point);
+    assert_eq!(point.id, 7);
+    assert!(point.vector.is_empty() && point.payload.is_none());
+}
+
+#[test]
+fn test_point_from_bits_unpacks_least_significant_bit_first() {
+    let point = Point::from_bits(3, &[0b0000_0101, 0b1000_0000]);
+
+    assert_eq!(point.id, 3);
+    assert_eq!(point.dimension(), 16);
+    let set: Vec<usize> = point
+        .vector
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| (*v > 0.5).then_some(i))
+        .collect();
+    assert_eq!(set, vec![0, 2, 15]);
+    assert_eq!(point.to_bits(), Some(vec![0b0000_0101, 0b1000_0000]));
+}
+
+#[test]
+fn test_point_to_bits_pads_and_rejects_non_binary_vectors() {
+    let padded = Point::without_payload(1, vec![1.0, 0.0, 1.0]);
+    assert_eq!(padded.to_bits(), Some(vec![0b101]));
+
+    let dense = Point::without_payload(2, vec![1.0, 0.5]);
+    assert_eq!(dense.to_bits(), None);
+}
+
+#[test]
+fn test_point_sanitize_non_finite_zeroes_nan_and_infinity() {
+    let mut point = Point::without_payload(4, vec![1.0, f32::NAN, f32::INFINITY, -f32::INFINITY]);
+    assert_eq!(point.sanitize_non_finite(), 3);
+    assert_eq!(point.vector, vec![1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(point.sanitize_non_finite(), 0);
+}
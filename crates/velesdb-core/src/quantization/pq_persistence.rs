@@ -14,9 +14,11 @@ use super::pq::ProductQuantizer;
 /// A valid codebook is `num_subspaces * num_centroids * subspace_dim` f32s
 /// plus small metadata; with the trained bounds (`num_subspaces <= 64`,
 /// `num_centroids <= u16::MAX`, `subspace_dim` modest) this stays well under
-/// 256 MiB. The cap rejects absurd/hostile files before they are decoded into
-/// a multi-gigabyte allocation. (Addresses the alloc-cap concern of #897.)
-const MAX_PQ_ARTIFACT_BYTES: u64 = 256 * 1024 * 1024;
+/// the cap. The largest artifact is the OPQ rotation, `dimension^2` f32s:
+/// 256 MiB at 8192 dimensions, so the cap leaves room above that. It rejects
+/// absurd/hostile files before they are decoded into a multi-gigabyte
+/// allocation. (Addresses the alloc-cap concern of #897.)
+const MAX_PQ_ARTIFACT_BYTES: u64 = 512 * 1024 * 1024;
 
 /// RF-2: Serializes `value` with postcard and atomically writes to `dir/filename`.
 ///
@@ -440,12 +440,12 @@ impl RaBitQIndex {
 /// Maximum accepted size of a persisted `RaBitQ` index file.
 ///
 /// The index stores a `dimension^2` rotation matrix plus a `dimension`
-/// centroid. For the documented stability ceiling (`dimension <= 2048`) the
-/// rotation is `2048^2` f32 ≈ 16 MiB; the 256 MiB cap rejects hostile files
-/// before they trigger a huge allocation. (Addresses the alloc-cap concern of
-/// #897.)
+/// centroid: `2048^2` f32 ≈ 16 MiB at the documented stability ceiling, and
+/// 256 MiB at 8192 dimensions, which the 512 MiB cap still admits. The cap
+/// rejects hostile files before they trigger a huge allocation. (Addresses
+/// the alloc-cap concern of #897.)
 #[cfg(feature = "persistence")]
-const MAX_RABITQ_INDEX_BYTES: u64 = 256 * 1024 * 1024;
+const MAX_RABITQ_INDEX_BYTES: u64 = 512 * 1024 * 1024;
 
 /// Generate a random orthogonal matrix using modified Gram-Schmidt.
 ///
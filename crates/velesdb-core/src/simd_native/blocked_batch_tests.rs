@@ -0,0 +1,76 @@
+#![allow(clippy::cast_precision_loss)]
+//! Tests for the cache-blocked batch kernels used from 2048 dimensions up.
+
+use super::{
+    batch_cosine_native, batch_dot_product_native, batch_euclidean_native, batch_squared_l2_native,
+    cosine_similarity_native, dot_product_native, euclidean_native, squared_l2_native,
+};
+
+fn vector(seed: usize, dim: usize) -> Vec<f32> {
+    (0..dim)
+        .map(|i| (((i * 31 + seed * 17) % 97) as f32 - 48.0) / 48.0)
+        .collect()
+}
+
+fn assert_close(batch: &[f32], single: &[f32], relative: f32) {
+    assert_eq!(batch.len(), single.len());
+    for (b, s) in batch.iter().zip(single) {
+        assert!(
+            (b - s).abs() <= relative * s.abs().max(1.0),
+            "blocked {b} vs single {s}"
+        );
+    }
+}
+
+#[test]
+fn test_blocked_batches_match_single_pair_kernels() {
+    // 3000 leaves a partial last chunk; 11 candidates a partial last group.
+    for dim in [2048, 3000, 8192] {
+        let query = vector(0, dim);
+        let owned: Vec<Vec<f32>> = (1..=11).map(|seed| vector(seed, dim)).collect();
+        let candidates: Vec<&[f32]> = owned.iter().map(Vec::as_slice).collect();
+        let single = |f: fn(&[f32], &[f32]) -> f32| -> Vec<f32> {
+            candidates.iter().map(|c| f(c, &query)).collect()
+        };
+
+        assert_close(
+            &batch_dot_product_native(&candidates, &query),
+            &single(dot_product_native),
+            1e-4,
+        );
+        assert_close(
+            &batch_squared_l2_native(&candidates, &query),
+            &single(squared_l2_native),
+            1e-4,
+        );
+        assert_close(
+            &batch_euclidean_native(&candidates, &query),
+            &single(euclidean_native),
+            1e-4,
+        );
+        assert_close(
+            &batch_cosine_native(&candidates, &query),
+            &single(cosine_similarity_native),
+            1e-4,
+        );
+    }
+}
+
+#[test]
+fn test_blocked_cosine_handles_zero_vectors() {
+    let dim = 4096;
+    let zero = vec![0.0; dim];
+    let query = vector(3, dim);
+    let candidates: Vec<&[f32]> = vec![&zero, &query];
+    let scores = batch_cosine_native(&candidates, &query);
+    assert!(scores[0].abs() < f32::EPSILON);
+    assert!((scores[1] - 1.0).abs() < 1e-4);
+}
+
+#[test]
+#[should_panic(expected = "Vector dimensions must match")]
+fn test_blocked_batch_rejects_mismatched_candidate() {
+    let query = vector(0, 2048);
+    let short = vector(1, 2047);
+    let _ = batch_dot_product_native(&[&short], &query);
+}
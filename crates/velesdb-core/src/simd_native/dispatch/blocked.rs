@@ -0,0 +1,95 @@
+//! Cache-blocked batch kernels for high-dimensional vectors.
+//!
+//! From [`BLOCKED_MIN_DIM`] dimensions up, a query and a candidate no longer
+//! share L1 (8 KiB each at 2048 dims, 32 KiB at 8192), so scoring candidates
+//! one after the other re-streams the whole query from L2 for each of them.
+//! These kernels split the dimension into [`BLOCK_DIM`]-float chunks and
+//! score a group of [`BLOCK_CANDIDATES`] candidates chunk by chunk, so each
+//! query chunk is loaded once per group and stays in L1 while the candidate
+//! chunks stream past. Chunks go through the regular SIMD kernels.
+
+use super::dot::dot_product_native;
+use super::euclidean::squared_l2_native;
+
+/// Dimension from which the batch kernels switch to cache blocking.
+pub(super) const BLOCKED_MIN_DIM: usize = 2048;
+
+/// Query floats kept hot per pass (4 KiB).
+const BLOCK_DIM: usize = 1024;
+
+/// Candidates scored per query chunk.
+const BLOCK_CANDIDATES: usize = 8;
+
+/// Runs `score(candidate_index, candidate_chunk, query_chunk)` over every
+/// chunk of every candidate, group by group.
+fn for_each_chunk(
+    candidates: &[&[f32]],
+    query: &[f32],
+    mut score: impl FnMut(usize, &[f32], &[f32]),
+) {
+    let dim = query.len();
+    assert!(
+        candidates.iter().all(|c| c.len() == dim),
+        "Vector dimensions must match"
+    );
+    for (group_index, group) in candidates.chunks(BLOCK_CANDIDATES).enumerate() {
+        let first = group_index * BLOCK_CANDIDATES;
+        for start in (0..dim).step_by(BLOCK_DIM) {
+            let end = (start + BLOCK_DIM).min(dim);
+            let query_chunk = &query[start..end];
+            for (offset, candidate) in group.iter().enumerate() {
+                score(first + offset, &candidate[start..end], query_chunk);
+            }
+        }
+    }
+}
+
+/// Sums `kernel` over the chunks of each candidate.
+fn batch_sum(candidates: &[&[f32]], query: &[f32], kernel: fn(&[f32], &[f32]) -> f32) -> Vec<f32> {
+    let mut sums = vec![0.0; candidates.len()];
+    for_each_chunk(candidates, query, |i, candidate, query| {
+        sums[i] += kernel(candidate, query);
+    });
+    sums
+}
+
+/// Blocked [`batch_dot_product_native`](super::batch_dot_product_native).
+pub(super) fn batch_dot(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    batch_sum(candidates, query, dot_product_native)
+}
+
+/// Blocked [`batch_squared_l2_native`](super::batch_squared_l2_native).
+pub(super) fn batch_squared_l2(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    batch_sum(candidates, query, squared_l2_native)
+}
+
+/// Blocked [`batch_euclidean_native`](super::batch_euclidean_native).
+pub(super) fn batch_euclidean(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    let mut distances = batch_squared_l2(candidates, query);
+    for distance in &mut distances {
+        *distance = distance.sqrt();
+    }
+    distances
+}
+
+/// Blocked [`batch_cosine_native`](super::batch_cosine_native).
+pub(super) fn batch_cosine(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    let mut dots = vec![0.0; candidates.len()];
+    let mut norms_sq = vec![0.0; candidates.len()];
+    for_each_chunk(candidates, query, |i, candidate, query| {
+        dots[i] += dot_product_native(candidate, query);
+        norms_sq[i] += dot_product_native(candidate, candidate);
+    });
+    let query_norm = dot_product_native(query, query).sqrt();
+    dots.iter()
+        .zip(&norms_sq)
+        .map(|(dot, norm_sq)| {
+            let norm = norm_sq.sqrt();
+            if query_norm == 0.0 || norm == 0.0 {
+                0.0
+            } else {
+                (dot / (query_norm * norm)).clamp(-1.0, 1.0)
+            }
+        })
+        .collect()
+}
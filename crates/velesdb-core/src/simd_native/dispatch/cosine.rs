@@ -68,9 +68,14 @@ pub fn cosine_similarity_native(a: &[f32], b: &[f32]) -> f32 {
 }
 
 /// Batch cosine similarity with cross-platform multi-level prefetch hints.
+///
+/// From 2048 dimensions up, candidates are scored in cache-blocked chunks.
 #[inline]
 #[must_use]
 pub fn batch_cosine_native(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    if query.len() >= super::blocked::BLOCKED_MIN_DIM {
+        return super::blocked::batch_cosine(candidates, query);
+    }
     super::batch_with_prefetch(candidates, query, cosine_similarity_native)
 }
 
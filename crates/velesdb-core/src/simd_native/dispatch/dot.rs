@@ -64,9 +64,13 @@ pub fn dot_product_native(a: &[f32], b: &[f32]) -> f32 {
 ///
 /// Prefetches multiple cache lines per vector for better coverage on
 /// high-dimensional vectors (e.g., 768d = 3072 bytes = 48 cache lines).
+/// From 2048 dimensions up, candidates are scored in cache-blocked chunks.
 #[inline]
 #[must_use]
 pub fn batch_dot_product_native(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    if query.len() >= super::blocked::BLOCKED_MIN_DIM {
+        return super::blocked::batch_dot(candidates, query);
+    }
     super::batch_with_prefetch(candidates, query, dot_product_native)
 }
 
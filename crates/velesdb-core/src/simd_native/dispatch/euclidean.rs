@@ -155,16 +155,26 @@ unsafe fn scale_inplace_avx2(v: &mut [f32], factor: f32) {
 // and AVX-512 CPUs hit it via the AVX2 dispatch arm above.
 
 /// Batch squared L2 distance with cross-platform multi-level prefetch hints.
+///
+/// From 2048 dimensions up, candidates are scored in cache-blocked chunks.
 #[inline]
 #[must_use]
 pub fn batch_squared_l2_native(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    if query.len() >= super::blocked::BLOCKED_MIN_DIM {
+        return super::blocked::batch_squared_l2(candidates, query);
+    }
     super::batch_with_prefetch(candidates, query, squared_l2_native)
 }
 
 /// Batch euclidean distance with cross-platform multi-level prefetch hints.
+///
+/// From 2048 dimensions up, candidates are scored in cache-blocked chunks.
 #[inline]
 #[must_use]
 pub fn batch_euclidean_native(candidates: &[&[f32]], query: &[f32]) -> Vec<f32> {
+    if query.len() >= super::blocked::BLOCKED_MIN_DIM {
+        return super::blocked::batch_euclidean(candidates, query);
+    }
     super::batch_with_prefetch(candidates, query, euclidean_native)
 }
 
@@ -243,6 +243,42 @@ pub fn hamming_binary_native(a: &[u64], b: &[u64]) -> u32 {
     }
 }
 
+/// Binary Jaccard similarity on packed u64 vectors.
+///
+/// `popcount(a AND b) / popcount(a OR b)`, one 64-bit popcount per word per
+/// operand. Agrees with [`jaccard_similarity_native`] on 0/1 vectors.
+///
+/// # Panics
+///
+/// Panics if `a.len() != b.len()`.
+#[inline]
+#[must_use]
+pub fn jaccard_binary_native(a: &[u64], b: &[u64]) -> f32 {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "Binary vector length mismatch: {} vs {}",
+        a.len(),
+        b.len()
+    );
+    crate::simd_native::scalar::jaccard_binary_scalar(a, b)
+}
+
+/// Packs a little-endian bitset (bit `i` is bit `i % 8` of byte `i / 8`)
+/// into u64 words for [`hamming_binary_native`] / [`jaccard_binary_native`].
+///
+/// The last word is zero-padded.
+#[must_use]
+pub fn pack_bits_u64(bits: &[u8]) -> Vec<u64> {
+    bits.chunks(8)
+        .map(|chunk| {
+            let mut word = [0_u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        })
+        .collect()
+}
+
 // =============================================================================
 // Batch operations with prefetch (Phase 4)
 // =============================================================================
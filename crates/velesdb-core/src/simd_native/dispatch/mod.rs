@@ -1,5 +1,6 @@
 //! Runtime SIMD level detection and dispatch wiring.
 
+mod blocked;
 mod cosine;
 mod dot;
 mod euclidean;
@@ -13,7 +14,7 @@ pub use euclidean::{
 };
 pub use hamming::{
     batch_hamming_native, batch_jaccard_native, hamming_binary_native, hamming_distance_native,
-    jaccard_similarity_native,
+    jaccard_binary_native, jaccard_similarity_native, pack_bits_u64,
 };
 
 /// SIMD capability level detected at runtime.
@@ -442,3 +442,46 @@ fn test_binary_hamming_native_all_differ() {
         );
     }
 }
+
+// =============================================================================
+// Binary Jaccard (packed u64) and bitset packing
+// =============================================================================
+
+use super::dispatch::{jaccard_binary_native, pack_bits_u64};
+
+#[test]
+fn test_binary_jaccard_matches_f32_kernel_on_unpacked_bits() {
+    for num_words in [1, 3, 8] {
+        let a: Vec<u64> = (0..num_words)
+            .map(|i| (i as u64 + 1).wrapping_mul(0x517c_c1b7_2722_0a95))
+            .collect();
+        let b: Vec<u64> = (0..num_words)
+            .map(|i| (i as u64 + 1).wrapping_mul(0x6c62_272e_07bb_0142))
+            .collect();
+        let unpack = |words: &[u64]| -> Vec<f32> {
+            words
+                .iter()
+                .flat_map(|w| (0..64).map(move |bit| ((w >> bit) & 1) as f32))
+                .collect()
+        };
+
+        let packed = jaccard_binary_native(&a, &b);
+        let reference = jaccard_similarity_native(&unpack(&a), &unpack(&b));
+
+        assert!(
+            (packed - reference).abs() < JACCARD_EPS,
+            "num_words={num_words}: packed={packed}, f32={reference}"
+        );
+    }
+}
+
+#[test]
+fn test_binary_jaccard_empty_sets_are_identical() {
+    assert_eq!(jaccard_binary_native(&[0, 0], &[0, 0]), 1.0);
+}
+
+#[test]
+fn test_pack_bits_u64_is_little_endian_and_zero_padded() {
+    let words = pack_bits_u64(&[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x03]);
+    assert_eq!(words, vec![0x8000_0000_0000_0001, 0x03]);
+}
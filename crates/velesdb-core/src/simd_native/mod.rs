@@ -121,8 +121,9 @@ pub use dispatch::{
     batch_cosine_native, batch_dot_product_native, batch_euclidean_native, batch_hamming_native,
     batch_jaccard_native, batch_squared_l2_native, cosine_normalized_native,
     cosine_similarity_native, dot_product_native, euclidean_native, hamming_binary_native,
-    hamming_distance_native, jaccard_similarity_native, norm_native, normalize_inplace_native,
-    simd_level, squared_l2_native, warmup_simd_cache, DistanceEngine, SimdLevel,
+    hamming_distance_native, jaccard_binary_native, jaccard_similarity_native, norm_native,
+    normalize_inplace_native, pack_bits_u64, simd_level, squared_l2_native, warmup_simd_cache,
+    DistanceEngine, SimdLevel,
 };
 
 // =============================================================================
@@ -132,6 +133,9 @@ pub use dispatch::{
 #[cfg(test)]
 mod simd_native_dispatch_tests;
 
+#[cfg(test)]
+mod blocked_batch_tests;
+
 #[cfg(test)]
 mod cosine_fused_tests;
 
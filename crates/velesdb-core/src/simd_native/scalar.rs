@@ -169,3 +169,24 @@ pub(crate) fn hamming_binary_scalar(a: &[u64], b: &[u64]) -> u32 {
         .map(|(x, y)| (x ^ y).count_ones())
         .sum()
 }
+
+/// Scalar Jaccard similarity for binary-packed u64 vectors.
+///
+/// `popcount(a AND b) / popcount(a OR b)`; two empty sets are identical
+/// (`1.0`), matching the f32 kernel.
+#[inline]
+#[allow(clippy::cast_precision_loss)]
+// Reason: bit counts stay far below 2^24 for any practical dimension.
+pub(crate) fn jaccard_binary_scalar(a: &[u64], b: &[u64]) -> f32 {
+    let (intersection, union) = a
+        .iter()
+        .zip(b.iter())
+        .fold((0_u32, 0_u32), |(inter, uni), (x, y)| {
+            (inter + (x & y).count_ones(), uni + (x | y).count_ones())
+        });
+    if union == 0 {
+        1.0
+    } else {
+        intersection as f32 / union as f32
+    }
+}
@@ -627,6 +627,14 @@ impl CompactionContext<'_> {
         self.reclaim_wal_if_unheld(&wal)
     }
 
+    /// Truncates the WAL after the caller has made the data and index files
+    /// durable; see [`MmapStorage::checkpoint_wal`](super::MmapStorage::checkpoint_wal).
+    pub fn checkpoint_wal(&self) -> io::Result<()> {
+        let mut wal = self.wal.write();
+        wal.flush()?;
+        self.reclaim_wal_if_unheld(&wal)
+    }
+
     /// Truncates the WAL to empty unless a registered consumer's low-watermark
     /// still needs a durable position (Requirement 6.3): a registered
     /// replication consumer holds the WAL until its low-watermark passes the
@@ -172,3 +172,12 @@ impl std::ops::Deref for VectorSliceGuard<'_> {
         }
     }
 }
+
+impl crate::vector_ref::VectorRef for VectorSliceGuard<'_> {
+    /// Same epoch-mismatch behavior as [`AsRef`]: an empty slice is returned
+    /// (and logged) if the mapping was remapped under the guard.
+    #[inline]
+    fn as_slice(&self) -> &[f32] {
+        self.as_ref()
+    }
+}
@@ -228,6 +228,44 @@ impl LogPayloadStorage {
         Ok(index)
     }
 
+    /// Counts CRC-failing records in the `payloads.log` under `dir`.
+    ///
+    /// Read-only. A bad final record is a torn tail, not corruption; an
+    /// unknown marker mid-stream counts once and ends the scan, as in replay.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be read.
+    pub(crate) fn count_corrupt_log_records(dir: &Path) -> io::Result<usize> {
+        let log_path = dir.join("payloads.log");
+        if !log_path.exists() {
+            return Ok(0);
+        }
+        let file = File::open(&log_path)?;
+        let end = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+        let mut pos = 0u64;
+        let mut corrupt = 0usize;
+        while pos < end {
+            let Some(entry) = WalEntry::read(&mut reader, pos) else {
+                // Marker + id fit, so this was an unknown marker rather
+                // than a record cut short.
+                if end - pos >= 9 {
+                    corrupt += 1;
+                }
+                break;
+            };
+            let Some((next, crc_ok)) = entry.verify(&mut reader, end)? else {
+                break;
+            };
+            if !crc_ok && next < end {
+                corrupt += 1;
+            }
+            pos = next;
+        }
+        Ok(corrupt)
+    }
+
     /// Creates a snapshot of the current index state.
     ///
     /// The snapshot captures:
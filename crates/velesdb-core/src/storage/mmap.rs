@@ -17,16 +17,24 @@
 //! - Initial size: 16MB (vs 64KB before) - handles most small-medium datasets
 //! - Growth factor: 2x minimum with 64MB floor - fewer resize operations
 //! - Explicit `reserve_capacity()` for bulk imports
+//!
+//! Sizes and growth are configurable per collection (see
+//! [`MmapStorage::set_growth`]).
 
+mod growth;
+mod residency;
 mod vector_io;
 mod wal_replay;
 
+pub(super) use growth::remap_resized;
+
 use super::compaction;
 use super::guard::VectorSliceGuard;
 use super::log_payload::DurabilityMode;
 use super::metrics::StorageMetrics;
 use super::sharded_index::ShardedIndex;
 use super::traits::VectorStorage;
+use crate::config::{MmapGrowth, MmapResidency};
 use crate::metrics::global_guardrails_metrics;
 
 use memmap2::MmapMut;
@@ -35,7 +43,7 @@ use rustc_hash::FxHashMap;
 use std::fs::{File, OpenOptions};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use tracing::error;
 
@@ -95,6 +103,13 @@ pub struct MmapStorage {
     /// so with no registered consumer truncation is unchanged from before this
     /// seam existed.
     watermarks: super::wal_cursor::WalWatermarkRegistry,
+    /// `madvise`/`mlock` settings, re-applied on every remap (see
+    /// [`MmapStorage::set_residency`]). Default: untouched mapping.
+    residency: MmapResidency,
+    /// Whether the current mapping is pinned with `mlock`.
+    memory_locked: AtomicBool,
+    /// Sizing of the data file (see [`MmapStorage::set_growth`]).
+    growth: MmapGrowth,
 }
 
 impl MmapStorage {
@@ -104,8 +119,16 @@ impl MmapStorage {
     /// P2: Increased from 1MB to 64MB minimum growth.
     pub(super) const MIN_GROWTH: u64 = 64 * 1024 * 1024;
 
-    /// P2: Growth factor for exponential pre-allocation.
-    pub(super) const GROWTH_FACTOR: u64 = 2;
+    /// Counts CRC-failing records in the `vectors.wal` under `dir`.
+    ///
+    /// Read-only; safe to call while the storage is open.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the WAL cannot be read.
+    pub(crate) fn count_corrupt_wal_records(dir: &Path) -> io::Result<usize> {
+        wal_replay::count_corrupt_records(&dir.join("vectors.wal"))
+    }
 
     /// Creates a new `MmapStorage` or opens an existing one.
     ///
@@ -185,6 +208,9 @@ impl MmapStorage {
             durability,
             wal_replayed_ids,
             watermarks: super::wal_cursor::WalWatermarkRegistry::new(),
+            residency: MmapResidency::default(),
+            memory_locked: AtomicBool::new(false),
+            growth: MmapGrowth::default(),
         })
     }
 
@@ -0,0 +1,125 @@
+//! `MmapStorage` growth policy and file resizing.
+//!
+//! Every resize of `vectors.dat` goes through [`remap_resized`], which also
+//! covers the Windows file-lock semantics: a file cannot change length while
+//! a view of it is mapped (`ERROR_USER_MAPPED_FILE`), so the old view is
+//! released before `set_len`, and sharing violations raised by a concurrent
+//! reader (backup tool, indexer, antivirus) are retried with a short backoff.
+
+use super::MmapStorage;
+use crate::config::MmapGrowth;
+
+use memmap2::MmapMut;
+use std::fs::File;
+use std::io;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+/// Attempts made at a resize failing with a transient lock error.
+const RESIZE_ATTEMPTS: u32 = 5;
+
+/// Backoff before the first retry; doubled after each attempt.
+const RESIZE_BACKOFF: Duration = Duration::from_millis(10);
+
+impl MmapStorage {
+    /// Applies `growth` to later growths and brings the data file to its
+    /// preallocated size.
+    ///
+    /// A file holding no vector yet is resized to exactly
+    /// [`MmapGrowth::preallocate_bytes`]; a file holding vectors only grows.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidInput` for a zero preallocation or growth step or a
+    /// growth factor below 1, and any I/O error of the resize.
+    pub fn set_growth(&mut self, growth: MmapGrowth) -> io::Result<()> {
+        if growth.preallocate_mb == 0 || growth.min_growth_mb == 0 || growth.growth_factor < 1.0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "mmap growth needs preallocate_mb > 0, min_growth_mb > 0 and growth_factor >= 1",
+            ));
+        }
+        self.growth = growth;
+
+        let target = growth.preallocate_bytes();
+        let mut mmap = self.mmap.write();
+        let current = mmap.len() as u64;
+        let empty = self.next_offset.load(Ordering::Acquire) == 0;
+        if current == target || (!empty && current > target) {
+            return Ok(());
+        }
+        let result = remap_resized(&mut mmap, &self.data_file, target, growth.sparse);
+        self.remap_epoch.fetch_add(1, Ordering::Release);
+        self.reapply_residency(&mmap);
+        result
+    }
+
+    /// Growth policy applied to the data file.
+    #[must_use]
+    pub fn growth(&self) -> MmapGrowth {
+        self.growth
+    }
+}
+
+/// Resizes `file` to `new_len` bytes and replaces `mmap` with a mapping of
+/// the resized file.
+///
+/// With `sparse == false` the new range is allocated on disk. `mmap` is
+/// remapped even when the resize fails, so it always maps the file as it
+/// stands; callers bump the remap epoch either way.
+pub(crate) fn remap_resized(
+    mmap: &mut MmapMut,
+    file: &File,
+    new_len: u64,
+    sparse: bool,
+) -> io::Result<()> {
+    mmap.flush()?;
+    // Windows refuses to change the length of a file with a live view of
+    // it, so drop ours first.
+    #[cfg(windows)]
+    {
+        *mmap = MmapMut::map_anon(1)?;
+    }
+    let resized = retry_transient(|| file.set_len(new_len)).and_then(|()| {
+        if sparse {
+            Ok(())
+        } else {
+            retry_transient(|| fs2::FileExt::allocate(file, new_len))
+        }
+    });
+    // SAFETY: `file` is the read+write handle of the data file and the new
+    // mapping covers its current length, whatever the resize did.
+    // - Condition 1: the mapping length is taken from the file itself.
+    // - Condition 2: the previous mapping is dropped on assign.
+    // SAFETY: Memory mapping requires unsafe; the length is read from the file.
+    *mmap = unsafe { MmapMut::map_mut(file)? };
+    resized
+}
+
+/// Runs `op`, retrying transient lock errors with exponential backoff.
+fn retry_transient(mut op: impl FnMut() -> io::Result<()>) -> io::Result<()> {
+    let mut backoff = RESIZE_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Err(e) if attempt < RESIZE_ATTEMPTS && is_transient_lock_error(&e) => {
+                tracing::debug!(attempt, "data file resize blocked, retrying: {e}");
+                std::thread::sleep(backoff);
+                backoff = backoff.saturating_mul(2);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Sharing violation, lock violation, or a view mapped by another handle.
+#[cfg(windows)]
+fn is_transient_lock_error(e: &io::Error) -> bool {
+    matches!(e.raw_os_error(), Some(32 | 33 | 1224))
+}
+
+#[cfg(not(windows))]
+fn is_transient_lock_error(_e: &io::Error) -> bool {
+    false
+}
@@ -0,0 +1,89 @@
+//! `MmapStorage` memory residency (`madvise` / `mlock`).
+//!
+//! The settings are kept on the storage and re-applied every time the data
+//! file is remapped (growth, compaction): a fresh mapping carries no advice
+//! and no lock.
+
+use super::MmapStorage;
+use crate::config::MmapResidency;
+
+use memmap2::MmapMut;
+use std::sync::atomic::Ordering;
+
+impl MmapStorage {
+    /// Applies `residency` to the vector mapping and keeps it for later
+    /// remaps.
+    ///
+    /// Failures are logged, not returned: the mapping stays readable with
+    /// whatever part of the settings the OS accepted.
+    pub fn set_residency(&mut self, residency: MmapResidency) {
+        self.residency = residency;
+        let mmap = self.mmap.read();
+        self.apply_residency(&mmap);
+    }
+
+    /// Residency settings currently applied to the mapping.
+    #[must_use]
+    pub fn residency(&self) -> MmapResidency {
+        self.residency
+    }
+
+    /// Whether the mapping is currently pinned in RAM.
+    #[must_use]
+    pub fn is_memory_locked(&self) -> bool {
+        self.memory_locked.load(Ordering::Acquire)
+    }
+
+    /// Re-applies the residency settings after `mmap` replaced the previous
+    /// mapping.
+    pub(crate) fn reapply_residency(&self, mmap: &MmapMut) {
+        // The old mapping's lock went away with it.
+        self.memory_locked.store(false, Ordering::Release);
+        if !self.residency.is_default() {
+            self.apply_residency(mmap);
+        }
+    }
+
+    #[cfg(unix)]
+    fn apply_residency(&self, mmap: &MmapMut) {
+        use crate::config::MmapAdvice;
+        use memmap2::Advice;
+
+        let advice = match self.residency.advice {
+            MmapAdvice::Random => Advice::Random,
+            MmapAdvice::Sequential => Advice::Sequential,
+            MmapAdvice::WillNeed => Advice::WillNeed,
+            _ => Advice::Normal,
+        };
+        if let Err(e) = mmap.advise(advice) {
+            tracing::warn!(path = %self.path.display(), "madvise({advice:?}) failed: {e}");
+        }
+
+        let locked = self.memory_locked.load(Ordering::Acquire);
+        if self.residency.lock && !locked {
+            match mmap.lock() {
+                Ok(()) => self.memory_locked.store(true, Ordering::Release),
+                Err(e) => tracing::warn!(
+                    path = %self.path.display(),
+                    bytes = mmap.len(),
+                    "mlock failed, vectors stay pageable (check RLIMIT_MEMLOCK): {e}"
+                ),
+            }
+        } else if !self.residency.lock && locked {
+            if let Err(e) = mmap.unlock() {
+                tracing::warn!(path = %self.path.display(), "munlock failed: {e}");
+            }
+            self.memory_locked.store(false, Ordering::Release);
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn apply_residency(&self, _mmap: &MmapMut) {
+        if !self.residency.is_default() {
+            tracing::debug!(
+                path = %self.path.display(),
+                "mmap residency settings are not supported on this platform; ignored"
+            );
+        }
+    }
+}
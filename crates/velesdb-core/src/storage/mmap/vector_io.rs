@@ -273,6 +273,10 @@ impl VectorStorage for MmapStorage {
         Ok(Some(bytes_to_vector(bytes, self.dimension)))
     }
 
+    fn contains(&self, id: u64) -> bool {
+        self.index.contains_key(id)
+    }
+
     fn delete(&mut self, id: u64) -> io::Result<()> {
         // 1. Write to WAL with CRC32 framing (Issue #317).
         // Issue #423 Component 4: Skip WAL when DurabilityMode::None.
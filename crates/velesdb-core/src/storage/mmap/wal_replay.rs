@@ -84,18 +84,11 @@ impl ReplayTarget<'_> {
         if self.mmap.len() >= required_len {
             return Ok(());
         }
-        self.mmap.flush()?;
         let required_u64 = u64::try_from(required_len)
             .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "replay length overflow"))?;
-        // Match the live growth floor (64 MB) to amortize remaps during replay.
+        // Match the default live growth floor (64 MB) to amortize remaps during replay.
         let new_len = required_u64.saturating_add(super::MmapStorage::MIN_GROWTH);
-        self.data_file.set_len(new_len)?;
-        // SAFETY: `set_len(new_len)` resized the backing file to fully cover the
-        // mapping range before remapping; the old mapping is dropped on assign.
-        // - Condition 1: file resized to `new_len` immediately above.
-        // - Condition 2: `data_file` is the read+write handle owning the file.
-        // SAFETY: remapping requires unsafe; resize guarantees mapping <= file size.
-        *self.mmap = unsafe { MmapMut::map_mut(self.data_file)? };
+        super::remap_resized(self.mmap, self.data_file, new_len, true)?;
         Ok(())
     }
 }
@@ -152,6 +145,46 @@ pub(crate) fn truncate_wal(wal_path: &Path) -> io::Result<()> {
     file.sync_all()
 }
 
+/// Counts fully-framed WAL records whose CRC does not match, without
+/// replaying anything.
+///
+/// Follows the replay policy: a torn tail is not corruption, and an unknown
+/// opcode with bytes after it counts once and ends the scan. Legacy (non-CRC)
+/// WAL files report zero.
+pub(crate) fn count_corrupt_records(wal_path: &Path) -> io::Result<usize> {
+    let Some((mut reader, file_len)) = open_crc_wal(wal_path)? else {
+        return Ok(0);
+    };
+    let mut corrupt = 0usize;
+    while reader.stream_position()? < file_len {
+        let mut op = [0u8; 1];
+        if reader.read_exact(&mut op).is_err() {
+            break;
+        }
+        let crc_ok = match op[0] {
+            1 => match read_store_entry(&mut reader, file_len)? {
+                Some((_, _, ok)) => ok,
+                None => break,
+            },
+            2 => match read_delete_entry(&mut reader, file_len)? {
+                Some((_, ok)) => ok,
+                None => break,
+            },
+            4 => true,
+            _ => {
+                if reader.stream_position()? < file_len {
+                    corrupt += 1;
+                }
+                break;
+            }
+        };
+        if !crc_ok && !is_at_tail(&mut reader, file_len)? {
+            corrupt += 1;
+        }
+    }
+    Ok(corrupt)
+}
+
 /// Opens the WAL file and validates it uses CRC32-framed format.
 ///
 /// Returns `None` if the file is missing, empty, or uses the legacy format.
@@ -3,9 +3,8 @@
 //! Extracted from `mmap.rs` to reduce NLOC below the 500 threshold.
 
 use super::compaction::CompactionContext;
-use super::mmap::MmapStorage;
+use super::mmap::{remap_resized, MmapStorage};
 
-use memmap2::MmapMut;
 use std::fs::OpenOptions;
 use std::io;
 use std::time::Instant;
@@ -15,9 +14,9 @@ impl MmapStorage {
     ///
     /// # P2 Optimization
     ///
-    /// Uses aggressive pre-allocation to minimize blocking:
-    /// - Exponential growth (2x) for amortized O(1)
-    /// - 64MB minimum growth to reduce resize frequency
+    /// Uses aggressive pre-allocation to minimize blocking; the step follows
+    /// the storage's [`MmapGrowth`](crate::config::MmapGrowth) policy
+    /// (by default 2x growth with a 64MB floor, amortized O(1)).
     pub(crate) fn ensure_capacity(&mut self, required_len: usize) -> io::Result<()> {
         let start = Instant::now();
         let mut did_resize = false;
@@ -25,18 +24,9 @@ impl MmapStorage {
 
         let mut mmap = self.mmap().write();
         if mmap.len() < required_len {
-            mmap.flush()?;
-
             let current_len = mmap.len() as u64;
-            let required_u64 = required_len as u64;
-
-            let doubled = current_len.saturating_mul(Self::GROWTH_FACTOR);
-            let with_headroom = required_u64.saturating_add(Self::MIN_GROWTH);
-            let min_growth = current_len.saturating_add(Self::MIN_GROWTH);
-
-            let new_len = doubled.max(with_headroom).max(min_growth).max(required_u64);
-
-            self.data_file.set_len(new_len)?;
+            let growth = self.growth();
+            let new_len = growth.next_len(current_len, required_len as u64);
 
             // No `data_file.sync_all()` here (unlike the replay growth path in
             // `replay_wal`): live growth is protected by the WAL. A store writes
@@ -47,16 +37,11 @@ impl MmapStorage {
             // the index is persisted against grown offsets AND the WAL is then
             // cleared, removing the recovery source — happens only in the replay
             // and compaction paths, which fsync the data file explicitly.
-
-            // SAFETY: data_file has been resized with set_len(new_len) above,
-            // ensuring the new mapping range is fully allocated.
-            // - Condition 1: File was resized to new_len before remapping.
-            // - Condition 2: Old mmap is dropped when we assign the new one.
-            // - Condition 3: File remains open with read+write permissions.
-            // SAFETY: Memory mapping requires unsafe; resizing ensures mapping doesn't exceed file bounds.
-            *mmap = unsafe { MmapMut::map_mut(&self.data_file)? };
+            let resized = remap_resized(&mut mmap, &self.data_file, new_len, growth.sparse);
             self.remap_epoch()
                 .fetch_add(1, std::sync::atomic::Ordering::Release);
+            self.reapply_residency(&mmap);
+            resized?;
 
             did_resize = true;
             bytes_resized = new_len.saturating_sub(current_len);
@@ -94,7 +79,7 @@ impl MmapStorage {
             mmap: self.mmap(),
             next_offset: self.next_offset(),
             wal: self.wal(),
-            initial_size: Self::INITIAL_SIZE,
+            initial_size: self.growth().preallocate_bytes(),
             watermarks: self.watermarks(),
         }
     }
@@ -121,11 +106,27 @@ impl MmapStorage {
             // concurrent mutation) and used to reopen the exact crash window
             // the staged commit closed — a torn index next to an
             // already-empty WAL is unrecoverable (audit 2026-06, finding 3).
+            self.reapply_residency(&self.mmap().read());
         }
 
         Ok(bytes_reclaimed)
     }
 
+    /// Makes `vectors.dat` and `vectors.idx` durable, then truncates the WAL
+    /// they supersede.
+    ///
+    /// Unlike [`compact`](Self::compact) this always reaches the WAL, so it
+    /// also discards records that failed their CRC during replay. A WAL still
+    /// held by a replication consumer is left intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if file operations fail.
+    pub fn checkpoint_wal(&mut self) -> io::Result<()> {
+        self.flush_full()?;
+        self.compaction_ctx().checkpoint_wal()
+    }
+
     /// Returns the fragmentation ratio (0.0 = no fragmentation, 1.0 = 100% fragmented).
     #[must_use]
     pub fn fragmentation_ratio(&self) -> f64 {
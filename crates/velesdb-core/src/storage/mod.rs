@@ -11,6 +11,7 @@
 //! - [`VectorSliceGuard`]: Zero-copy vector slice guard
 //! - [`metrics`]: Storage operation metrics (P0 audit - latency monitoring)
 //! - [`async_ops`]: Async wrappers for blocking I/O (EPIC-034/US-001)
+//! - `pack`: Page-allocated pack file behind packed databases
 //! - [`wal_cursor`]: Shippable WAL cursor — additive, read-only API over the
 //!   existing WAL framing for replication consumers (no on-disk format change)
 #![allow(clippy::doc_markdown)] // Storage docs include API and platform identifiers.
@@ -25,6 +26,9 @@ mod log_payload_io;
 pub mod metrics;
 mod mmap;
 mod mmap_capacity;
+pub(crate) mod pack;
+#[cfg(test)]
+mod pack_tests;
 mod sharded_index;
 #[cfg(test)]
 mod sharded_index_tests;
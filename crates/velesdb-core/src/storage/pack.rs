@@ -0,0 +1,476 @@
+//! Pack file behind [`Database::open_packed`](crate::Database::open_packed).
+//!
+//! The container stores a set of named files (the files of a data
+//! directory) in one file, as page-aligned extents. It is log-structured so
+//! that every write is a sequential append, which suits flash storage:
+//!
+//! ```text
+//! [extent][extent]...[directory][trailer] [extent]...[directory][trailer] ...
+//! ```
+//!
+//! A checkpoint appends the files whose content changed since the last one,
+//! then a directory (postcard: file name → extent) and a one-page trailer
+//! pointing at it. Unchanged files keep their extent. On open, the last page
+//! holding a valid trailer wins and anything after it — a checkpoint torn by
+//! a crash — is truncated away.
+//!
+//! Pages are allocated at the end of the file only; extents superseded by a
+//! later checkpoint become dead pages. Once dead pages outnumber live ones
+//! (and the file is past [`COMPACT_MIN_PAGES`]), a checkpoint compacts: the
+//! live extents are rewritten sequentially to a fresh file that replaces the
+//! old one with an atomic rename.
+//!
+//! ## Trailer page
+//!
+//! ```text
+//! [Magic: "VELESSF1" 8 bytes]
+//! [Version: u32] [Page size: u32]
+//! [Generation: u64] [Directory first page: u64] [Directory length: u64]
+//! [Directory CRC32: u32] [Trailer CRC32 (bytes 0..44): u32]
+//! [zero padding to the page size]
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::storage::snapshot::{crc32_hash, crc32_update};
+
+/// Allocation unit of the container, in bytes.
+pub(crate) const PAGE_SIZE: u64 = 4096;
+
+/// Containers smaller than this many pages are never compacted.
+pub(crate) const COMPACT_MIN_PAGES: u64 = 256;
+
+const MAGIC: &[u8; 8] = b"VELESSF1";
+
+const FORMAT_VERSION: u32 = 1;
+
+/// Bytes of the trailer covered by its own CRC.
+const TRAILER_BODY: usize = 44;
+
+/// Chunk size for streaming files in and out.
+const COPY_CHUNK: usize = 64 * 1024;
+
+/// Where one stored file lives in the container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Extent {
+    first_page: u64,
+    len: u64,
+    crc: u32,
+}
+
+impl Extent {
+    fn pages(&self) -> u64 {
+        pages_for(self.len)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Directory {
+    files: BTreeMap<String, Extent>,
+}
+
+/// Outcome of one [`Checkpoint::commit`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CheckpointStats {
+    /// Generation of the committed checkpoint.
+    pub generation: u64,
+    /// Files appended because their content changed.
+    pub files_written: usize,
+    /// Files whose previous extent was kept.
+    pub files_unchanged: usize,
+    /// Bytes appended, including the directory and trailer.
+    pub bytes_written: u64,
+    /// Whether the container was rewritten to reclaim dead pages.
+    pub compacted: bool,
+    /// Size of the container file after the checkpoint, in bytes.
+    pub file_size: u64,
+}
+
+/// An open pack file.
+#[derive(Debug)]
+pub(crate) struct Container {
+    path: PathBuf,
+    file: File,
+    /// Pages in the file; the next extent is allocated here.
+    end_page: u64,
+    generation: u64,
+    directory: Directory,
+}
+
+impl Container {
+    /// Opens the container at `path`, creating an empty one if the file does
+    /// not exist, and drops a checkpoint a crash left torn.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DatabaseLocked`] if another handle holds the container.
+    /// - [`Error::Storage`] if the file is not empty but holds no valid
+    ///   trailer.
+    /// - An I/O error if the file cannot be opened or read.
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        // Held for the container's lifetime: nobody else may truncate a
+        // torn tail while we append.
+        fs2::FileExt::try_lock_exclusive(&file)
+            .map_err(|_| Error::DatabaseLocked(path.display().to_string()))?;
+        let len = file.metadata()?.len();
+        let mut container = Self {
+            path: path.to_path_buf(),
+            file,
+            end_page: 0,
+            generation: 0,
+            directory: Directory::default(),
+        };
+        if len == 0 {
+            return Ok(container);
+        }
+        let Some((trailer_page, generation, directory)) = container.find_last_trailer(len)? else {
+            return Err(Error::Storage(format!(
+                "{} is not a VelesDB pack file",
+                path.display()
+            )));
+        };
+        container.end_page = trailer_page + 1;
+        container.generation = generation;
+        container.directory = directory;
+        if len != container.end_page * PAGE_SIZE {
+            container.file.set_len(container.end_page * PAGE_SIZE)?;
+            container.file.sync_all()?;
+        }
+        Ok(container)
+    }
+
+    /// Generation of the last committed checkpoint (0 for a new container).
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Pages referenced by the last checkpoint, directory and trailer
+    /// included.
+    pub(crate) fn live_pages(&self) -> u64 {
+        let data: u64 = self.directory.files.values().map(Extent::pages).sum();
+        let directory = postcard::to_allocvec(&self.directory).map_or(0, |d| d.len() as u64);
+        data + pages_for(directory) + 1
+    }
+
+    /// Pages in the file.
+    pub(crate) fn total_pages(&self) -> u64 {
+        self.end_page
+    }
+
+    /// Writes every stored file below `dir`, verifying its checksum.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Storage`] if a stored file fails its checksum.
+    /// - An I/O error if a file cannot be read or written.
+    pub(crate) fn unpack_into(&mut self, dir: &Path) -> Result<()> {
+        for (name, extent) in &self.directory.files {
+            let target = dir.join(name);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut out = File::create(&target)?;
+            let crc = copy_range(
+                &mut self.file,
+                extent.first_page * PAGE_SIZE,
+                extent.len,
+                &mut out,
+            )?;
+            if crc != extent.crc {
+                return Err(Error::Storage(format!(
+                    "{}: checksum mismatch for '{name}'",
+                    self.path.display()
+                )));
+            }
+            out.sync_all()?;
+        }
+        Ok(())
+    }
+
+    /// Starts staging a checkpoint. Nothing is visible until
+    /// [`Checkpoint::commit`]; a checkpoint dropped uncommitted only leaves
+    /// dead pages behind.
+    pub(crate) fn begin(&mut self) -> Checkpoint<'_> {
+        Checkpoint {
+            container: self,
+            files: BTreeMap::new(),
+            bytes_written: 0,
+        }
+    }
+
+    /// Scans back from the end of the file for the last valid trailer.
+    fn find_last_trailer(&mut self, len: u64) -> Result<Option<(u64, u64, Directory)>> {
+        let mut header = [0u8; TRAILER_BODY + 4];
+        for page in (0..len / PAGE_SIZE).rev() {
+            self.file.seek(SeekFrom::Start(page * PAGE_SIZE))?;
+            self.file.read_exact(&mut header)?;
+            if let Some(found) = self.parse_trailer(page, &header)? {
+                return Ok(Some(found));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Decodes the trailer at `page`, or `None` if the page is not one.
+    fn parse_trailer(
+        &mut self,
+        page: u64,
+        header: &[u8; TRAILER_BODY + 4],
+    ) -> Result<Option<(u64, u64, Directory)>> {
+        if &header[..8] != MAGIC
+            || read_u32(header, TRAILER_BODY) != crc32_hash(&header[..TRAILER_BODY])
+        {
+            return Ok(None);
+        }
+        let version = read_u32(header, 8);
+        let page_size = read_u32(header, 12);
+        if version != FORMAT_VERSION || u64::from(page_size) != PAGE_SIZE {
+            return Err(Error::Storage(format!(
+                "{}: unsupported pack file format (version {version}, page size {page_size})",
+                self.path.display()
+            )));
+        }
+        let generation = read_u64(header, 16);
+        let directory_page = read_u64(header, 24);
+        let directory_len = read_u64(header, 32);
+        let directory_crc = read_u32(header, 40);
+        if directory_page + pages_for(directory_len) > page {
+            return Ok(None);
+        }
+        let mut bytes = vec![0u8; usize::try_from(directory_len).unwrap_or(usize::MAX)];
+        self.file
+            .seek(SeekFrom::Start(directory_page * PAGE_SIZE))?;
+        self.file.read_exact(&mut bytes)?;
+        if crc32_hash(&bytes) != directory_crc {
+            return Ok(None);
+        }
+        let directory: Directory =
+            postcard::from_bytes(&bytes).map_err(|e| Error::Serialization(e.to_string()))?;
+        Ok(Some((page, generation, directory)))
+    }
+
+    /// Appends `len` bytes from `reader` at the end of the file, padded to a
+    /// page boundary.
+    fn append(&mut self, reader: &mut impl Read, len: u64) -> Result<Extent> {
+        let first_page = self.end_page;
+        self.file.seek(SeekFrom::Start(first_page * PAGE_SIZE))?;
+        let crc = copy_stream(reader, len, &mut self.file)?;
+        pad_to_page(&mut self.file, len)?;
+        self.end_page += pages_for(len);
+        Ok(Extent {
+            first_page,
+            len,
+            crc,
+        })
+    }
+
+    /// Appends the directory and the trailer pointing at it.
+    fn append_directory(&mut self, directory: &Directory, generation: u64) -> Result<u64> {
+        let bytes =
+            postcard::to_allocvec(directory).map_err(|e| Error::Serialization(e.to_string()))?;
+        let len = bytes.len() as u64;
+        let extent = self.append(&mut bytes.as_slice(), len)?;
+
+        #[allow(clippy::cast_possible_truncation)] // 4096 fits in usize
+        let mut trailer = vec![0u8; PAGE_SIZE as usize];
+        trailer[..8].copy_from_slice(MAGIC);
+        trailer[8..12].copy_from_slice(&FORMAT_VERSION.to_le_bytes());
+        #[allow(clippy::cast_possible_truncation)] // 4096 fits in u32
+        trailer[12..16].copy_from_slice(&(PAGE_SIZE as u32).to_le_bytes());
+        trailer[16..24].copy_from_slice(&generation.to_le_bytes());
+        trailer[24..32].copy_from_slice(&extent.first_page.to_le_bytes());
+        trailer[32..40].copy_from_slice(&len.to_le_bytes());
+        trailer[40..44].copy_from_slice(&extent.crc.to_le_bytes());
+        let trailer_crc = crc32_hash(&trailer[..TRAILER_BODY]);
+        trailer[TRAILER_BODY..TRAILER_BODY + 4].copy_from_slice(&trailer_crc.to_le_bytes());
+        self.file.write_all(&trailer)?;
+        self.end_page += 1;
+        Ok(pages_for(len) * PAGE_SIZE + PAGE_SIZE)
+    }
+
+    /// Rewrites the live extents to a fresh file and swaps it in.
+    fn compact(&mut self) -> Result<()> {
+        let file_name = self.path.file_name().unwrap_or_default().to_string_lossy();
+        let tmp_path = self.path.with_file_name(format!("{file_name}.compact"));
+        let result = self.compact_into(&tmp_path);
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+        result
+    }
+
+    fn compact_into(&mut self, tmp_path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(tmp_path)?;
+        fs2::FileExt::try_lock_exclusive(&file)
+            .map_err(|_| Error::DatabaseLocked(tmp_path.display().to_string()))?;
+        let mut fresh = Self {
+            path: self.path.clone(),
+            file,
+            end_page: 0,
+            generation: self.generation,
+            directory: Directory::default(),
+        };
+        for (name, extent) in &self.directory.files {
+            self.file
+                .seek(SeekFrom::Start(extent.first_page * PAGE_SIZE))?;
+            let mut source = (&self.file).take(extent.len);
+            let moved = fresh.append(&mut source, extent.len)?;
+            fresh.directory.files.insert(name.clone(), moved);
+        }
+        let directory = std::mem::take(&mut fresh.directory);
+        fresh.append_directory(&directory, self.generation)?;
+        fresh.directory = directory;
+        fresh.file.sync_all()?;
+        std::fs::rename(tmp_path, &self.path)?;
+        *self = fresh;
+        Ok(())
+    }
+}
+
+/// A checkpoint being staged by [`Container::begin`].
+pub(crate) struct Checkpoint<'a> {
+    container: &'a mut Container,
+    files: BTreeMap<String, Extent>,
+    bytes_written: u64,
+}
+
+impl Checkpoint<'_> {
+    /// Stages the file at `path` under `name`, appending it unless the last
+    /// checkpoint already holds the same content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the file cannot be read or appended.
+    pub(crate) fn add_file(&mut self, name: &str, path: &Path) -> Result<()> {
+        let mut source = File::open(path)?;
+        let len = source.metadata()?.len();
+        if let Some(previous) = self.container.directory.files.get(name) {
+            if previous.len == len && checksum(&mut source, len)? == previous.crc {
+                self.files.insert(name.to_string(), *previous);
+                return Ok(());
+            }
+            source.seek(SeekFrom::Start(0))?;
+        }
+        let extent = self.container.append(&mut source, len)?;
+        self.files.insert(name.to_string(), extent);
+        self.bytes_written += extent.pages() * PAGE_SIZE;
+        Ok(())
+    }
+
+    /// Forgets what was staged under `prefix`, e.g. a collection copied
+    /// while writes kept landing on it.
+    pub(crate) fn discard_prefix(&mut self, prefix: &str) {
+        self.files.retain(|name, _| !name.starts_with(prefix));
+    }
+
+    /// Makes the staged files the container's content: appends the
+    /// directory and trailer and syncs the file, then compacts if dead
+    /// pages outnumber live ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the directory cannot be written or synced,
+    /// or the compaction fails (the checkpoint itself is committed then).
+    pub(crate) fn commit(self) -> Result<CheckpointStats> {
+        let Self {
+            container,
+            files,
+            mut bytes_written,
+        } = self;
+        let files_written = files
+            .iter()
+            .filter(|(name, extent)| container.directory.files.get(*name) != Some(*extent))
+            .count();
+        let generation = container.generation + 1;
+        let directory = Directory { files };
+        bytes_written += container.append_directory(&directory, generation)?;
+        container.file.sync_all()?;
+        container.generation = generation;
+        container.directory = directory;
+
+        let total = container.total_pages();
+        let live = container.live_pages();
+        let compacted = total >= COMPACT_MIN_PAGES && total - live > live;
+        if compacted {
+            container.compact()?;
+        }
+        Ok(CheckpointStats {
+            generation,
+            files_written,
+            files_unchanged: container.directory.files.len() - files_written,
+            bytes_written,
+            compacted,
+            file_size: container.end_page * PAGE_SIZE,
+        })
+    }
+}
+
+/// Pages needed for `len` bytes.
+fn pages_for(len: u64) -> u64 {
+    len.div_ceil(PAGE_SIZE)
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(&bytes[at..at + 4]);
+    u32::from_le_bytes(buf)
+}
+
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[at..at + 8]);
+    u64::from_le_bytes(buf)
+}
+
+/// CRC32 of the next `len` bytes of `reader`.
+fn checksum(reader: &mut impl Read, len: u64) -> Result<u32> {
+    copy_stream(reader, len, &mut std::io::sink())
+}
+
+/// Copies `len` bytes from `offset` in `file` to `out`, returning their CRC32.
+fn copy_range(file: &mut File, offset: u64, len: u64, out: &mut impl Write) -> Result<u32> {
+    file.seek(SeekFrom::Start(offset))?;
+    copy_stream(file, len, out)
+}
+
+/// Copies exactly `len` bytes from `reader` to `out`, returning their CRC32.
+fn copy_stream(reader: &mut impl Read, len: u64, out: &mut impl Write) -> Result<u32> {
+    let mut buf = vec![0u8; COPY_CHUNK];
+    let mut remaining = len;
+    let mut crc = 0xFFFF_FFFF;
+    while remaining > 0 {
+        let chunk = usize::try_from(remaining.min(COPY_CHUNK as u64)).unwrap_or(COPY_CHUNK);
+        reader.read_exact(&mut buf[..chunk])?;
+        crc = crc32_update(crc, &buf[..chunk]);
+        out.write_all(&buf[..chunk])?;
+        remaining -= chunk as u64;
+    }
+    Ok(!crc)
+}
+
+/// Writes the zeros completing the last page of a `len`-byte extent.
+fn pad_to_page(out: &mut impl Write, len: u64) -> Result<()> {
+    let tail = len % PAGE_SIZE;
+    if tail != 0 {
+        let padding = usize::try_from(PAGE_SIZE - tail).unwrap_or(0);
+        out.write_all(&vec![0u8; padding])?;
+    }
+    Ok(())
+}
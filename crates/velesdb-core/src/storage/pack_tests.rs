@@ -0,0 +1,144 @@
+//! Tests for the `pack` container.
+
+use super::pack::{Container, COMPACT_MIN_PAGES, PAGE_SIZE};
+use crate::error::Error;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn write(dir: &Path, name: &str, bytes: &[u8]) -> std::path::PathBuf {
+    let path = dir.join(name);
+    std::fs::write(&path, bytes).expect("test: write source file");
+    path
+}
+
+#[test]
+fn test_checkpoint_roundtrip_and_unchanged_files_are_not_rewritten() {
+    let dir = TempDir::new().expect("test: temp dir");
+    let file = dir.path().join("db.veles");
+    let a = write(dir.path(), "a.bin", &vec![7u8; 5000]);
+    let b = write(dir.path(), "b.bin", b"hello");
+
+    let mut container = Container::open(&file).expect("test: create");
+    assert_eq!(container.generation(), 0);
+    let mut checkpoint = container.begin();
+    checkpoint.add_file("coll/a.bin", &a).expect("test: add a");
+    checkpoint.add_file("views.json", &b).expect("test: add b");
+    let first = checkpoint.commit().expect("test: commit");
+    assert_eq!(first.generation, 1);
+    assert_eq!(first.files_written, 2);
+    assert_eq!(first.file_size % PAGE_SIZE, 0);
+
+    std::fs::write(&b, b"hello, world").expect("test: modify b");
+    let mut checkpoint = container.begin();
+    checkpoint.add_file("coll/a.bin", &a).expect("test: add a");
+    checkpoint.add_file("views.json", &b).expect("test: add b");
+    let second = checkpoint.commit().expect("test: commit");
+    assert_eq!(second.files_written, 1);
+    assert_eq!(second.files_unchanged, 1);
+    drop(container);
+
+    let mut reopened = Container::open(&file).expect("test: reopen");
+    assert_eq!(reopened.generation(), 2);
+    let out = TempDir::new().expect("test: temp dir");
+    reopened.unpack_into(out.path()).expect("test: unpack");
+    assert_eq!(
+        std::fs::read(out.path().join("coll/a.bin")).expect("test: read a"),
+        vec![7u8; 5000]
+    );
+    assert_eq!(
+        std::fs::read(out.path().join("views.json")).expect("test: read b"),
+        b"hello, world"
+    );
+}
+
+#[test]
+fn test_torn_checkpoint_is_dropped_on_open() {
+    let dir = TempDir::new().expect("test: temp dir");
+    let file = dir.path().join("db.veles");
+    let a = write(dir.path(), "a.bin", b"committed");
+
+    let mut container = Container::open(&file).expect("test: create");
+    let mut checkpoint = container.begin();
+    checkpoint.add_file("a.bin", &a).expect("test: add");
+    let stats = checkpoint.commit().expect("test: commit");
+    drop(container);
+
+    // A crash halfway through the next checkpoint leaves garbage behind.
+    let mut raw = OpenOptions::new()
+        .append(true)
+        .open(&file)
+        .expect("test: open raw");
+    raw.write_all(&vec![0xAB; 10_000])
+        .expect("test: write garbage");
+    drop(raw);
+
+    let mut reopened = Container::open(&file).expect("test: reopen");
+    assert_eq!(reopened.generation(), 1);
+    assert_eq!(
+        std::fs::metadata(&file).expect("test: metadata").len(),
+        stats.file_size
+    );
+    let out = TempDir::new().expect("test: temp dir");
+    reopened.unpack_into(out.path()).expect("test: unpack");
+    assert_eq!(
+        std::fs::read(out.path().join("a.bin")).expect("test: read"),
+        b"committed"
+    );
+}
+
+#[test]
+fn test_dead_pages_are_compacted() {
+    let dir = TempDir::new().expect("test: temp dir");
+    let file = dir.path().join("db.veles");
+    let mut container = Container::open(&file).expect("test: create");
+
+    // Each rewrite supersedes 64 pages; compaction kicks in past the
+    // minimum size once dead pages dominate.
+    let mut round = 0u8;
+    loop {
+        round += 1;
+        assert!(
+            u64::from(round) < COMPACT_MIN_PAGES / 64 + 2,
+            "never compacted"
+        );
+        let source = write(dir.path(), "big.bin", &vec![round; 64 * 4096]);
+        let mut checkpoint = container.begin();
+        checkpoint.add_file("big.bin", &source).expect("test: add");
+        if checkpoint.commit().expect("test: commit").compacted {
+            break;
+        }
+    }
+    assert!(container.total_pages() < COMPACT_MIN_PAGES);
+    assert_eq!(container.total_pages(), container.live_pages());
+    drop(container);
+
+    let mut reopened = Container::open(&file).expect("test: reopen");
+    let out = TempDir::new().expect("test: temp dir");
+    reopened.unpack_into(out.path()).expect("test: unpack");
+    assert_eq!(
+        std::fs::metadata(out.path().join("big.bin"))
+            .expect("test: metadata")
+            .len(),
+        64 * 4096
+    );
+    assert_eq!(
+        std::fs::read(out.path().join("big.bin")).expect("test: read")[0],
+        round
+    );
+}
+
+#[test]
+fn test_open_rejects_foreign_files_and_second_handle() {
+    let dir = TempDir::new().expect("test: temp dir");
+    let foreign = write(dir.path(), "notes.txt", &vec![b'x'; 8192]);
+    assert!(matches!(Container::open(&foreign), Err(Error::Storage(_))));
+
+    let file = dir.path().join("db.veles");
+    let _held = Container::open(&file).expect("test: create");
+    assert!(matches!(
+        Container::open(&file),
+        Err(Error::DatabaseLocked(_))
+    ));
+}
@@ -28,38 +28,45 @@ pub(crate) const SNAPSHOT_VERSION: u8 = 1;
 /// Default threshold for automatic snapshot creation (10 MB of WAL since last snapshot).
 pub(crate) const DEFAULT_SNAPSHOT_THRESHOLD: u64 = 10 * 1024 * 1024;
 
+/// CRC32 lookup table (IEEE 802.3 polynomial).
+#[allow(clippy::cast_possible_truncation)] // Table index always 0-255
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            if crc & 1 != 0 {
+                crc = (crc >> 1) ^ 0xEDB8_8320;
+            } else {
+                crc >>= 1;
+            }
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
 /// Simple CRC32 implementation (IEEE 802.3 polynomial).
 ///
 /// Used for snapshot integrity validation.
 #[inline]
-#[allow(clippy::cast_possible_truncation)] // Table index always 0-255
 pub(crate) fn crc32_hash(data: &[u8]) -> u32 {
-    const CRC32_TABLE: [u32; 256] = {
-        let mut table = [0u32; 256];
-        let mut i = 0;
-        while i < 256 {
-            let mut crc = i as u32;
-            let mut j = 0;
-            while j < 8 {
-                if crc & 1 != 0 {
-                    crc = (crc >> 1) ^ 0xEDB8_8320;
-                } else {
-                    crc >>= 1;
-                }
-                j += 1;
-            }
-            table[i] = crc;
-            i += 1;
-        }
-        table
-    };
+    !crc32_update(0xFFFF_FFFF, data)
+}
 
-    let mut crc = 0xFFFF_FFFF_u32;
+/// Feeds `data` into a running CRC32 state, for checksumming a stream in
+/// chunks: start from `0xFFFF_FFFF` and invert the final state.
+#[inline]
+pub(crate) fn crc32_update(mut crc: u32, data: &[u8]) -> u32 {
     for &byte in data {
         let idx = ((crc ^ u32::from(byte)) & 0xFF) as usize;
         crc = (crc >> 8) ^ CRC32_TABLE[idx];
     }
-    !crc
+    crc
 }
 
 /// Loads index from a snapshot file.
@@ -89,6 +96,23 @@ fn validate_snapshot_format(data: &[u8]) -> io::Result<()> {
     Ok(())
 }
 
+/// Checks the payload snapshot under `dir` without loading it.
+///
+/// Returns `Ok(None)` when there is no snapshot, otherwise whether its
+/// header, size and CRC are valid.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read.
+pub(crate) fn payload_snapshot_is_valid(dir: &Path) -> io::Result<Option<bool>> {
+    let snapshot_path = dir.join("payloads.snapshot");
+    if !snapshot_path.exists() {
+        return Ok(None);
+    }
+    let data = std::fs::read(snapshot_path)?;
+    Ok(Some(validate_snapshot_header(&data).is_ok()))
+}
+
 /// Validates snapshot CRC and entry count, returns (wal_pos, entry_count).
 fn validate_snapshot_header(data: &[u8]) -> io::Result<(u64, usize)> {
     validate_snapshot_format(data)?;
@@ -37,6 +37,14 @@ pub trait VectorStorage: Send + Sync {
     /// Returns an error if the read operation fails.
     fn retrieve(&self, id: u64) -> io::Result<Option<Vec<f32>>>;
 
+    /// Returns `true` if a vector is stored under `id`.
+    ///
+    /// The default reads the vector; implementations with an id index
+    /// should override it to skip the copy.
+    fn contains(&self, id: u64) -> bool {
+        self.retrieve(id).ok().flatten().is_some()
+    }
+
     /// Deletes a vector by ID.
     ///
     /// # Errors
@@ -129,6 +129,55 @@ impl WalEntry {
         }
     }
 
+    /// Steps over this entry without applying it, checking its CRC.
+    ///
+    /// Returns the next position and whether the checksum matched (legacy
+    /// records carry none and always pass), or `Ok(None)` for a torn tail.
+    pub(super) fn verify(
+        self,
+        reader: &mut BufReader<File>,
+        wal_end: u64,
+    ) -> io::Result<Option<(u64, bool)>> {
+        let crc_len = u64::from(self.has_crc) * 4;
+        let (next, payload) = match self.op {
+            WalOp::Delete { .. } => (self.pos_after_header + crc_len, None),
+            WalOp::Store { .. } => {
+                let mut len_bytes = [0u8; 4];
+                if reader.read_exact(&mut len_bytes).is_err() {
+                    return Ok(None);
+                }
+                let payload_len = u64::from(u32::from_le_bytes(len_bytes));
+                let payload_start = self.pos_after_header + 4;
+                // Same OOM guard as `apply_store`.
+                if payload_len.saturating_add(crc_len) > wal_end.saturating_sub(payload_start) {
+                    return Ok(None);
+                }
+                (payload_start + payload_len + crc_len, Some(payload_len))
+            }
+        };
+        if next > wal_end {
+            return Ok(None);
+        }
+        if !self.has_crc {
+            reader.seek(SeekFrom::Start(next))?;
+            return Ok(Some((next, true)));
+        }
+
+        let computed = match (self.op, payload) {
+            (WalOp::Store { id }, Some(len)) => {
+                let len = usize::try_from(len)
+                    .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Payload too large"))?;
+                let mut payload_buf = vec![0u8; len];
+                reader.read_exact(&mut payload_buf)?;
+                compute_store_crc(id, &payload_buf)
+            }
+            (WalOp::Store { id } | WalOp::Delete { id }, _) => compute_delete_crc(id),
+        };
+        let mut crc_bytes = [0u8; 4];
+        reader.read_exact(&mut crc_bytes)?;
+        Ok(Some((next, u32::from_le_bytes(crc_bytes) == computed)))
+    }
+
     fn apply_store(
         &self,
         id: u64,
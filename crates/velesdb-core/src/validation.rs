@@ -1,7 +1,8 @@
 //! Unified validation helpers.
 //!
-//! Centralizes dimension range checks, collection name validation, and
-//! mismatch validation used across collection creation, CRUD, and search paths.
+//! Centralizes dimension range checks, collection name validation, vector
+//! value checks, and mismatch validation used across collection creation,
+//! CRUD, and search paths.
 
 use crate::error::{Error, Result};
 
@@ -43,6 +44,43 @@ pub fn validate_dimension_match(expected: usize, actual: usize) -> Result<()> {
     Ok(())
 }
 
+/// Validates that every component of a dense vector is finite.
+///
+/// NaN and ±infinity turn every distance involving the vector into NaN,
+/// which poisons search scores and HNSW neighbour selection.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidVector`] naming the point id and the index of the
+/// first non-finite component.
+pub fn validate_vector_finite(id: u64, vector: &[f32]) -> Result<()> {
+    if let Some(index) = vector.iter().position(|v| !v.is_finite()) {
+        return Err(Error::InvalidVector(format!(
+            "point {id}: component {index} is {} (set limits.non_finite_vectors = \
+             \"sanitize_to_zero\" to replace non-finite values with 0.0)",
+            vector[index]
+        )));
+    }
+    Ok(())
+}
+
+/// Validates that a dense vector has a non-zero norm, as cosine similarity
+/// requires. Empty vectors (metadata-only points) are accepted.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidVector`] naming the point id when every component
+/// is zero.
+pub fn validate_vector_norm(id: u64, vector: &[f32]) -> Result<()> {
+    if !vector.is_empty() && vector.iter().all(|v| *v == 0.0) {
+        return Err(Error::InvalidVector(format!(
+            "point {id}: zero-norm vector on a cosine collection \
+             (cosine similarity is undefined for it)"
+        )));
+    }
+    Ok(())
+}
+
 /// Validates that a collection name is safe for use as a filesystem directory.
 ///
 /// # Rules
@@ -252,4 +290,28 @@ mod tests {
         let err = validate_collection_name("").unwrap_err();
         assert!(err.is_recoverable());
     }
+
+    #[test]
+    fn rejects_non_finite_vector_components() {
+        let err = validate_vector_finite(9, &[0.5, f32::NAN]).unwrap_err();
+        assert_eq!(err.code(), "VELES-005");
+        let msg = err.to_string();
+        assert!(
+            msg.contains("point 9") && msg.contains("component 1"),
+            "{msg}"
+        );
+        assert!(msg.contains("non_finite_vectors"), "{msg}");
+
+        assert!(validate_vector_finite(1, &[f32::NEG_INFINITY]).is_err());
+        validate_vector_finite(1, &[0.0, -2.5, f32::MAX]).unwrap();
+    }
+
+    #[test]
+    fn rejects_zero_norm_vectors() {
+        let err = validate_vector_norm(3, &[0.0, -0.0]).unwrap_err();
+        assert!(err.to_string().contains("zero-norm"), "{err}");
+
+        validate_vector_norm(3, &[0.0, 1e-3]).unwrap();
+        validate_vector_norm(3, &[]).unwrap();
+    }
 }
@@ -0,0 +1,230 @@
+//! In-memory vector layouts for exact scans.
+//!
+//! Vectors are stored array-of-structs (AoS): each vector's components are
+//! contiguous, which suits graph traversal where one vector is scored at a
+//! time. Exact scans (`SearchQuality::Perfect`, small collections) score every
+//! vector against one query; for those, [`VectorLayout::BlockedSoa`] regroups
+//! the vectors into blocks of [`SOA_BLOCK`] stored dimension-major, so each
+//! query component is loaded once per block and multiplied across
+//! [`SOA_BLOCK`] vectors in straight-line SIMD-friendly code.
+//!
+//! The layout is a per-collection setting (`VectorCollection::set_vector_layout`);
+//! compare the two on your hardware with `cargo bench --bench vector_layout_benchmark`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::distance::DistanceMetric;
+
+/// Number of vectors interleaved per block of a [`BlockedSoaVectors`].
+pub const SOA_BLOCK: usize = 16;
+
+/// Memory layout used by a collection's exact scans.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorLayout {
+    /// One vector after the other (the storage layout).
+    #[default]
+    Aos,
+    /// Blocks of [`SOA_BLOCK`] vectors, stored component by component.
+    ///
+    /// Used for cosine, euclidean and dot-product scans; Hamming and
+    /// Jaccard scans stay on the AoS kernels.
+    BlockedSoa,
+}
+
+impl VectorLayout {
+    /// Whether this is the default layout.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Vectors regrouped into dimension-major blocks of [`SOA_BLOCK`].
+///
+/// Block `b` holds vectors `b * SOA_BLOCK ..`; component `d` of its lane `l`
+/// sits at `data[(b * dimension + d) * SOA_BLOCK + l]`. The last block is
+/// zero-padded.
+#[derive(Debug, Clone)]
+pub struct BlockedSoaVectors {
+    dimension: usize,
+    len: usize,
+    data: Vec<f32>,
+    /// Squared L2 norm of each vector, for cosine scans.
+    norms_sq: Vec<f32>,
+}
+
+impl BlockedSoaVectors {
+    /// Regroups `flat`, a run of `dimension`-sized vectors, into blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is 0 or `flat.len()` is not a multiple of it.
+    #[must_use]
+    pub fn from_flat(flat: &[f32], dimension: usize) -> Self {
+        assert!(dimension > 0, "dimension must be greater than 0");
+        assert_eq!(
+            flat.len() % dimension,
+            0,
+            "flat buffer must hold whole vectors"
+        );
+        let len = flat.len() / dimension;
+        let blocks = len.div_ceil(SOA_BLOCK);
+        let mut data = vec![0.0; blocks * dimension * SOA_BLOCK];
+        let mut norms_sq = Vec::with_capacity(len);
+        for (index, vector) in flat.chunks_exact(dimension).enumerate() {
+            let block = &mut data[(index / SOA_BLOCK) * dimension * SOA_BLOCK..];
+            let lane = index % SOA_BLOCK;
+            for (d, &value) in vector.iter().enumerate() {
+                block[d * SOA_BLOCK + lane] = value;
+            }
+            norms_sq.push(vector.iter().map(|v| v * v).sum());
+        }
+        Self {
+            dimension,
+            len,
+            data,
+            norms_sq,
+        }
+    }
+
+    /// Vector dimension.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Number of vectors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no vector is stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Copies vector `index` out of its block.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Vec<f32>> {
+        if index >= self.len {
+            return None;
+        }
+        let block = self.block(index / SOA_BLOCK);
+        let lane = index % SOA_BLOCK;
+        Some(
+            (0..self.dimension)
+                .map(|d| block[d * SOA_BLOCK + lane])
+                .collect(),
+        )
+    }
+
+    /// Scores every vector against `query`, in storage order, with the same
+    /// semantics as [`DistanceMetric::calculate`].
+    ///
+    /// Returns `None` for metrics without a blocked kernel (Hamming,
+    /// Jaccard).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `query.len()` differs from the dimension.
+    #[must_use]
+    pub fn scores(&self, query: &[f32], metric: DistanceMetric) -> Option<Vec<f32>> {
+        if !supports(metric) {
+            return None;
+        }
+        let mut scores = vec![0.0; self.len];
+        for (block, out) in scores.chunks_mut(SOA_BLOCK).enumerate() {
+            self.score_block(block, query, metric, out)?;
+        }
+        Some(scores)
+    }
+
+    /// [`scores`](Self::scores), with blocks spread over the rayon pool.
+    #[cfg(feature = "persistence")]
+    #[must_use]
+    pub fn par_scores(&self, query: &[f32], metric: DistanceMetric) -> Option<Vec<f32>> {
+        use rayon::prelude::*;
+
+        if !supports(metric) {
+            return None;
+        }
+        let mut scores = vec![0.0; self.len];
+        scores
+            .par_chunks_mut(SOA_BLOCK)
+            .enumerate()
+            .for_each(|(block, out)| {
+                self.score_block(block, query, metric, out);
+            });
+        Some(scores)
+    }
+
+    fn block(&self, block: usize) -> &[f32] {
+        let stride = self.dimension * SOA_BLOCK;
+        &self.data[block * stride..(block + 1) * stride]
+    }
+
+    /// Scores the vectors of `block` into `out` (one slot per live lane).
+    fn score_block(
+        &self,
+        block: usize,
+        query: &[f32],
+        metric: DistanceMetric,
+        out: &mut [f32],
+    ) -> Option<()> {
+        assert_eq!(query.len(), self.dimension, "Vector dimensions must match");
+        let rows = self.block(block).chunks_exact(SOA_BLOCK);
+        let mut acc = [0.0f32; SOA_BLOCK];
+        match metric {
+            DistanceMetric::DotProduct | DistanceMetric::Cosine => {
+                for (&q, row) in query.iter().zip(rows) {
+                    for (a, &v) in acc.iter_mut().zip(row) {
+                        *a += q * v;
+                    }
+                }
+            }
+            DistanceMetric::Euclidean => {
+                for (&q, row) in query.iter().zip(rows) {
+                    for (a, &v) in acc.iter_mut().zip(row) {
+                        let diff = q - v;
+                        *a += diff * diff;
+                    }
+                }
+            }
+            DistanceMetric::Hamming | DistanceMetric::Jaccard => return None,
+        }
+
+        match metric {
+            DistanceMetric::Cosine => {
+                let query_norm = query.iter().map(|q| q * q).sum::<f32>().sqrt();
+                let norms = &self.norms_sq[block * SOA_BLOCK..];
+                for ((slot, dot), norm_sq) in out.iter_mut().zip(acc).zip(norms) {
+                    let norm = norm_sq.sqrt();
+                    *slot = if query_norm == 0.0 || norm == 0.0 {
+                        0.0
+                    } else {
+                        (dot / (query_norm * norm)).clamp(-1.0, 1.0)
+                    };
+                }
+            }
+            DistanceMetric::Euclidean => {
+                for (slot, sum) in out.iter_mut().zip(acc) {
+                    *slot = sum.sqrt();
+                }
+            }
+            _ => out.copy_from_slice(&acc[..out.len()]),
+        }
+        Some(())
+    }
+}
+
+/// Whether `metric` has a blocked SoA kernel.
+#[must_use]
+pub fn supports(metric: DistanceMetric) -> bool {
+    matches!(
+        metric,
+        DistanceMetric::Cosine | DistanceMetric::Euclidean | DistanceMetric::DotProduct
+    )
+}
@@ -0,0 +1,65 @@
+#![allow(clippy::cast_precision_loss)]
+//! Tests for the blocked struct-of-arrays layout (`vector_layout`).
+
+use crate::distance::DistanceMetric;
+use crate::vector_layout::{BlockedSoaVectors, VectorLayout, SOA_BLOCK};
+
+fn flat(count: usize, dim: usize) -> Vec<f32> {
+    (0..count * dim)
+        .map(|i| (((i * 13) % 29) as f32 - 14.0) / 7.0)
+        .collect()
+}
+
+#[test]
+fn test_blocked_scores_match_metric() {
+    // Counts and dimensions off the block size exercise the padded lanes.
+    for (count, dim) in [(1, 3), (SOA_BLOCK + 5, 17), (40, 8192)] {
+        let data = flat(count, dim);
+        let query: Vec<f32> = (0..dim).map(|i| (i % 5) as f32 - 2.0).collect();
+        let blocked = BlockedSoaVectors::from_flat(&data, dim);
+        assert_eq!(blocked.len(), count);
+
+        for metric in [
+            DistanceMetric::Cosine,
+            DistanceMetric::Euclidean,
+            DistanceMetric::DotProduct,
+        ] {
+            let scores = blocked.scores(&query, metric).expect("supported metric");
+            assert_eq!(scores.len(), count);
+            for (vector, score) in data.chunks_exact(dim).zip(&scores) {
+                let expected = metric.calculate(&query, vector);
+                assert!(
+                    (score - expected).abs() <= 1e-3 * expected.abs().max(1.0),
+                    "{metric:?} dim {dim}: {score} vs {expected}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_get_returns_original_vectors() {
+    let dim = 5;
+    let data = flat(SOA_BLOCK + 3, dim);
+    let blocked = BlockedSoaVectors::from_flat(&data, dim);
+    for (index, vector) in data.chunks_exact(dim).enumerate() {
+        assert_eq!(blocked.get(index).as_deref(), Some(vector));
+    }
+    assert!(blocked.get(SOA_BLOCK + 3).is_none());
+}
+
+#[test]
+fn test_bitwise_metrics_have_no_blocked_kernel() {
+    let blocked = BlockedSoaVectors::from_flat(&flat(4, 8), 8);
+    assert!(blocked.scores(&[0.0; 8], DistanceMetric::Hamming).is_none());
+    assert!(blocked.scores(&[0.0; 8], DistanceMetric::Jaccard).is_none());
+}
+
+#[test]
+fn test_layout_serializes_snake_case() {
+    assert_eq!(
+        serde_json::to_string(&VectorLayout::BlockedSoa).unwrap(),
+        "\"blocked_soa\""
+    );
+    assert!(VectorLayout::default().is_default());
+}
@@ -0,0 +1,318 @@
+//! Mergeable sketches behind `PERCENTILE_CONT` and `COUNT(DISTINCT ...)`.
+//!
+//! Both sketches are bounded in memory and merge associatively, so partial
+//! aggregators built by parallel chunks or per group combine exactly like
+//! the plain SUM/MIN/MAX state:
+//!
+//! - [`TDigest`] keeps clustered centroids (merging t-digest, compression
+//!   [`TDIGEST_COMPRESSION`]). Small inputs stay as singleton centroids, so
+//!   percentiles of up to a few hundred values are exact.
+//! - [`DistinctCounter`] keeps an exact set of value hashes up to
+//!   [`EXACT_DISTINCT_LIMIT`] values, then switches to HyperLogLog
+//!   (2^[`HLL_PRECISION`] registers, ~0.8% standard error).
+
+// Reason: Numeric casts in sketches are intentional:
+// - u64->f64 for weights and cardinality estimates: precision loss acceptable
+// - f64->u64 when rounding the final HyperLogLog estimate (always >= 0)
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+use rustc_hash::{FxHashSet, FxHasher};
+use std::hash::{Hash, Hasher};
+
+/// Compression (delta) of [`TDigest`]: more centroids, better accuracy.
+pub(crate) const TDIGEST_COMPRESSION: f64 = 100.0;
+
+/// Unmerged values buffered before a [`TDigest`] compresses.
+const TDIGEST_BUFFER: usize = 500;
+
+/// Distinct values counted exactly before [`DistinctCounter`] switches to
+/// HyperLogLog.
+pub(crate) const EXACT_DISTINCT_LIMIT: usize = 4096;
+
+/// HyperLogLog precision: `2^HLL_PRECISION` one-byte registers.
+pub(crate) const HLL_PRECISION: u32 = 14;
+
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// Weighted cluster of values in a [`TDigest`].
+#[derive(Debug, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Merging t-digest for approximate quantiles.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TDigest {
+    /// Compressed centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values (and merged-in centroids) not yet compressed.
+    buffer: Vec<Centroid>,
+    total: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Adds one value. NaN is ignored.
+    pub(crate) fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.observe(value, value, 1.0);
+        self.buffer.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        if self.buffer.len() >= TDIGEST_BUFFER {
+            self.compress();
+        }
+    }
+
+    /// Folds `other` into this digest.
+    pub(crate) fn merge(&mut self, other: &Self) {
+        if other.total == 0.0 {
+            return;
+        }
+        self.observe(other.min, other.max, other.total);
+        self.buffer.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        if self.buffer.len() >= TDIGEST_BUFFER {
+            self.compress();
+        }
+    }
+
+    /// Continuous percentile at `fraction` (clamped to `[0, 1]`), linearly
+    /// interpolated between neighbouring ranks like SQL `PERCENTILE_CONT`.
+    /// `None` when no value was added.
+    pub(crate) fn quantile(&self, fraction: f64) -> Option<f64> {
+        if self.total == 0.0 {
+            return None;
+        }
+        let mut digest = self.clone();
+        digest.compress();
+        let centroids = &digest.centroids;
+        let rank = fraction.clamp(0.0, 1.0) * (digest.total - 1.0);
+
+        // Mean rank of the values in each centroid.
+        let mut cumulative = 0.0;
+        let mut prev: Option<(f64, f64)> = None;
+        for c in centroids {
+            let center = cumulative + (c.weight - 1.0) / 2.0;
+            cumulative += c.weight;
+            if rank <= center {
+                let (lo_rank, lo_value) = prev.unwrap_or((0.0, digest.min));
+                return Some(interpolate(lo_rank, lo_value, center, c.mean, rank));
+            }
+            prev = Some((center, c.mean));
+        }
+        let (lo_rank, lo_value) = prev.unwrap_or((0.0, digest.min));
+        Some(interpolate(
+            lo_rank,
+            lo_value,
+            digest.total - 1.0,
+            digest.max,
+            rank,
+        ))
+    }
+
+    fn observe(&mut self, min: f64, max: f64, weight: f64) {
+        if self.total == 0.0 {
+            self.min = min;
+            self.max = max;
+        } else {
+            self.min = self.min.min(min);
+            self.max = self.max.max(max);
+        }
+        self.total += weight;
+    }
+
+    /// Merges the buffer into the centroids, keeping each centroid under
+    /// the t-digest size bound `4 * total * q * (1 - q) / compression`.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut items = std::mem::take(&mut self.centroids);
+        items.append(&mut self.buffer);
+        items.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let mut merged = Vec::with_capacity(items.len().min(TDIGEST_BUFFER));
+        let mut items = items.into_iter();
+        let Some(mut current) = items.next() else {
+            return;
+        };
+        let mut before = 0.0;
+        for item in items {
+            let weight = current.weight + item.weight;
+            let q = (before + weight / 2.0) / self.total;
+            let bound = 4.0 * self.total * q * (1.0 - q) / TDIGEST_COMPRESSION;
+            if weight <= bound {
+                current.mean += (item.mean - current.mean) * item.weight / weight;
+                current.weight = weight;
+            } else {
+                before += current.weight;
+                merged.push(current);
+                current = item;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+}
+
+fn interpolate(lo_rank: f64, lo_value: f64, hi_rank: f64, hi_value: f64, rank: f64) -> f64 {
+    if hi_rank <= lo_rank {
+        return hi_value;
+    }
+    lo_value + (hi_value - lo_value) * (rank - lo_rank) / (hi_rank - lo_rank)
+}
+
+/// Distinct-value counter: exact while small, HyperLogLog beyond
+/// [`EXACT_DISTINCT_LIMIT`] values.
+#[derive(Debug, Clone)]
+pub(crate) enum DistinctCounter {
+    /// Hashes of every distinct value seen so far.
+    Exact(FxHashSet<u64>),
+    /// HyperLogLog registers (max leading-zero rank per bucket).
+    Approximate(Box<[u8]>),
+}
+
+impl Default for DistinctCounter {
+    fn default() -> Self {
+        Self::Exact(FxHashSet::default())
+    }
+}
+
+impl DistinctCounter {
+    /// Counts `value`. `NULL` is ignored, as in SQL. Numbers compare by
+    /// value (`1` and `1.0` are the same value).
+    pub(crate) fn insert(&mut self, value: &serde_json::Value) {
+        if let Some(hash) = value_hash(value) {
+            self.insert_hash(hash);
+        }
+    }
+
+    /// Folds `other` into this counter.
+    pub(crate) fn merge(&mut self, other: &Self) {
+        match other {
+            Self::Exact(hashes) => {
+                for &hash in hashes {
+                    self.insert_hash(hash);
+                }
+            }
+            Self::Approximate(theirs) => {
+                let ours = self.promote();
+                for (mine, &their) in ours.iter_mut().zip(theirs.iter()) {
+                    *mine = (*mine).max(their);
+                }
+            }
+        }
+    }
+
+    /// Number of distinct values (estimated once approximate).
+    pub(crate) fn count(&self) -> u64 {
+        match self {
+            Self::Exact(hashes) => hashes.len() as u64,
+            Self::Approximate(registers) => hll_estimate(registers),
+        }
+    }
+
+    fn insert_hash(&mut self, hash: u64) {
+        match self {
+            Self::Exact(hashes) => {
+                hashes.insert(hash);
+                if hashes.len() > EXACT_DISTINCT_LIMIT {
+                    self.promote();
+                }
+            }
+            Self::Approximate(registers) => hll_insert(registers, hash),
+        }
+    }
+
+    /// Switches to HyperLogLog (if still exact) and returns the registers.
+    fn promote(&mut self) -> &mut [u8] {
+        if let Self::Exact(hashes) = self {
+            let mut registers = vec![0u8; HLL_REGISTERS].into_boxed_slice();
+            for &hash in hashes.iter() {
+                hll_insert(&mut registers, hash);
+            }
+            *self = Self::Approximate(registers);
+        }
+        match self {
+            Self::Approximate(registers) => registers,
+            Self::Exact(_) => unreachable!("converted above"),
+        }
+    }
+}
+
+fn hll_insert(registers: &mut [u8], hash: u64) {
+    let bucket = (hash >> (64 - HLL_PRECISION)) as usize;
+    let rest = hash << HLL_PRECISION;
+    let rank = (rest.leading_zeros() + 1).min(64 - HLL_PRECISION + 1) as u8;
+    if rank > registers[bucket] {
+        registers[bucket] = rank;
+    }
+}
+
+/// HyperLogLog estimate with linear counting for small cardinalities.
+fn hll_estimate(registers: &[u8]) -> u64 {
+    let m = registers.len() as f64;
+    let alpha = 0.7213 / (1.0 + 1.079 / m);
+    let (mut sum, mut zeros) = (0.0, 0usize);
+    for &r in registers {
+        sum += 2f64.powi(-i32::from(r));
+        zeros += usize::from(r == 0);
+    }
+    let raw = alpha * m * m / sum;
+    let estimate = if raw <= 2.5 * m && zeros > 0 {
+        m * (m / zeros as f64).ln()
+    } else {
+        raw
+    };
+    estimate.round() as u64
+}
+
+/// Stable 64-bit hash of a JSON value, `None` for `NULL`.
+///
+/// The same on every thread and chunk, so partial counters merge.
+fn value_hash(value: &serde_json::Value) -> Option<u64> {
+    let mut hasher = FxHasher::default();
+    match value {
+        serde_json::Value::Null => return None,
+        serde_json::Value::Bool(b) => {
+            1u8.hash(&mut hasher);
+            b.hash(&mut hasher);
+        }
+        serde_json::Value::Number(n) => {
+            2u8.hash(&mut hasher);
+            match n.as_f64() {
+                // -0.0 and 0.0 are the same value.
+                Some(0.0) => 0u64.hash(&mut hasher),
+                Some(f) => f.to_bits().hash(&mut hasher),
+                None => n.to_string().hash(&mut hasher),
+            }
+        }
+        serde_json::Value::String(s) => {
+            3u8.hash(&mut hasher);
+            s.hash(&mut hasher);
+        }
+        other => {
+            4u8.hash(&mut hasher);
+            other.to_string().hash(&mut hasher);
+        }
+    }
+    Some(mix(hasher.finish()))
+}
+
+/// SplitMix64 finalizer: spreads FxHash output over all 64 bits, which
+/// HyperLogLog bucket selection relies on.
+fn mix(mut x: u64) -> u64 {
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
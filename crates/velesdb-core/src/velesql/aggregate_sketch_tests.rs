@@ -0,0 +1,113 @@
+//! Tests for `aggregate_sketch` module - t-digest and distinct counter.
+
+#![allow(clippy::cast_precision_loss)]
+
+use super::aggregate_sketch::*;
+use serde_json::json;
+
+#[test]
+fn test_tdigest_small_input_is_exact() {
+    let mut digest = TDigest::default();
+    for v in [4.0, 1.0, 3.0, 2.0] {
+        digest.add(v);
+    }
+    assert_eq!(digest.quantile(0.0), Some(1.0));
+    assert_eq!(digest.quantile(0.25), Some(1.75));
+    assert_eq!(digest.quantile(0.5), Some(2.5));
+    assert_eq!(digest.quantile(1.0), Some(4.0));
+}
+
+#[test]
+fn test_tdigest_empty_has_no_quantile() {
+    assert_eq!(TDigest::default().quantile(0.5), None);
+}
+
+#[test]
+fn test_tdigest_large_input_is_close() {
+    let mut digest = TDigest::default();
+    for i in 0..100_000 {
+        digest.add(f64::from(i));
+    }
+    for (fraction, expected) in [(0.5, 49_999.5), (0.95, 94_999.05), (0.99, 98_999.01)] {
+        let got = digest.quantile(fraction).unwrap();
+        assert!(
+            (got - expected).abs() < 200.0,
+            "p{fraction}: got {got}, expected ~{expected}"
+        );
+    }
+    assert_eq!(digest.quantile(0.0), Some(0.0));
+    assert_eq!(digest.quantile(1.0), Some(99_999.0));
+}
+
+#[test]
+fn test_tdigest_merge_matches_single_pass() {
+    let mut whole = TDigest::default();
+    let mut parts = vec![TDigest::default(); 4];
+    for i in 0..40_000u32 {
+        let v = f64::from((i * 7919) % 40_000);
+        whole.add(v);
+        parts[(i % 4) as usize].add(v);
+    }
+    let mut merged = TDigest::default();
+    for part in &parts {
+        merged.merge(part);
+    }
+    let (a, b) = (whole.quantile(0.9).unwrap(), merged.quantile(0.9).unwrap());
+    assert!((a - b).abs() < 100.0, "single pass {a} vs merged {b}");
+}
+
+#[test]
+fn test_distinct_counter_exact_for_small_sets() {
+    let mut counter = DistinctCounter::default();
+    for v in [
+        json!(1),
+        json!(1.0),
+        json!("1"),
+        json!(null),
+        json!(true),
+        json!(2),
+    ] {
+        counter.insert(&v);
+    }
+    // 1 and 1.0 are one value; NULL is not counted.
+    assert_eq!(counter.count(), 4);
+}
+
+#[test]
+fn test_distinct_counter_switches_to_hyperloglog() {
+    let mut counter = DistinctCounter::default();
+    for i in 0..100_000 {
+        counter.insert(&json!(format!("user-{i}")));
+        counter.insert(&json!(format!("user-{i}")));
+    }
+    assert!(matches!(counter, DistinctCounter::Approximate(_)));
+    let estimate = counter.count() as f64;
+    assert!(
+        (estimate - 100_000.0).abs() / 100_000.0 < 0.03,
+        "estimate {estimate}"
+    );
+}
+
+#[test]
+fn test_distinct_counter_merge_overlapping() {
+    let mut left = DistinctCounter::default();
+    let mut right = DistinctCounter::default();
+    for i in 0..3_000 {
+        left.insert(&json!(i));
+    }
+    for i in 2_000..5_000 {
+        right.insert(&json!(i));
+    }
+    left.merge(&right);
+    // 5 000 distinct values: past the exact limit, so estimated.
+    let estimate = left.count() as f64;
+    assert!((estimate - 5_000.0).abs() < 150.0, "estimate {estimate}");
+
+    let mut small = DistinctCounter::default();
+    let mut other = DistinctCounter::default();
+    small.insert(&json!("a"));
+    other.insert(&json!("a"));
+    other.insert(&json!("b"));
+    small.merge(&other);
+    assert_eq!(small.count(), 2);
+}
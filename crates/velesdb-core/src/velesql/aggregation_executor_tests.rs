@@ -222,3 +222,86 @@ fn test_executor_empty_collection() {
     let sum_price = result.get("sum_price");
     assert!(sum_price.is_none_or(serde_json::Value::is_null));
 }
+
+#[test]
+fn test_executor_statistical_aggregates_parallel() {
+    let (collection, _tmp) = create_test_collection();
+
+    // 20k rows: above the parallel threshold, so chunk results are merged.
+    let points: Vec<Point> = (0..20_000u64)
+        .map(|i| Point {
+            id: i,
+            vector: vec![0.1; 4],
+            payload: Some(serde_json::json!({"latency": i % 1000, "tag": format!("t{}", i % 50)})),
+            sparse_vectors: None,
+        })
+        .collect();
+    collection.upsert(points).unwrap();
+
+    let query = Parser::parse(
+        "SELECT STDDEV(latency), VARIANCE(latency), \
+         PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY latency), \
+         PERCENTILE_CONT(latency, 0.99), COUNT(DISTINCT tag) FROM logs",
+    )
+    .unwrap();
+    let result = collection
+        .execute_aggregate(&query, &HashMap::new())
+        .unwrap();
+
+    // Sample variance of 20 copies of 0..1000.
+    let n = 20_000.0;
+    let expected_variance = (1000.0_f64 * 1000.0 - 1.0) / 12.0 * n / (n - 1.0);
+    let variance = result["variance_latency"].as_f64().unwrap();
+    assert!((variance - expected_variance).abs() < 1e-6 * expected_variance);
+    let stddev = result["stddev_latency"].as_f64().unwrap();
+    assert!((stddev - expected_variance.sqrt()).abs() < 1e-6);
+
+    let p50 = result["p50_latency"].as_f64().unwrap();
+    assert!((p50 - 499.5).abs() < 5.0, "p50 = {p50}");
+    let p99 = result["p99_latency"].as_f64().unwrap();
+    assert!((p99 - 989.0).abs() < 5.0, "p99 = {p99}");
+    assert_eq!(result["count_distinct_tag"].as_u64(), Some(50));
+}
+
+#[test]
+fn test_executor_statistical_aggregates_grouped() {
+    let (collection, _tmp) = create_test_collection();
+
+    let rows = [
+        ("a", 1, "x"),
+        ("a", 2, "y"),
+        ("a", 3, "x"),
+        ("a", 4, "z"),
+        ("b", 10, "x"),
+    ];
+    let points: Vec<Point> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, (category, value, tag))| Point {
+            id: i as u64,
+            vector: vec![0.1; 4],
+            payload: Some(serde_json::json!({"category": category, "v": value, "tag": tag})),
+            sparse_vectors: None,
+        })
+        .collect();
+    collection.upsert(points).unwrap();
+
+    let query = Parser::parse(
+        "SELECT category, VARIANCE(v) AS var, PERCENTILE_CONT(v, 0.25) AS q1, \
+         COUNT(DISTINCT tag) AS tags FROM t GROUP BY category \
+         HAVING COUNT(DISTINCT tag) > 1 ORDER BY category",
+    )
+    .unwrap();
+    let result = collection
+        .execute_aggregate(&query, &HashMap::new())
+        .unwrap();
+    let groups = result.as_array().unwrap();
+
+    // Group "b" has a single tag and is filtered out by HAVING.
+    assert_eq!(groups.len(), 1);
+    let a = &groups[0];
+    assert_eq!(a["category"], "a");
+    assert!((a["var"].as_f64().unwrap() - 5.0 / 3.0).abs() < 1e-12);
+    assert_eq!(a["q1"].as_f64(), Some(1.75));
+    assert_eq!(a["tags"].as_u64(), Some(3));
+}
@@ -137,3 +137,83 @@ fn test_parser_aggregation_with_limit() {
 
     assert_eq!(query.select.limit, Some(1));
 }
+
+#[test]
+fn test_parser_stddev_variance() {
+    let query = Parser::parse("SELECT STDDEV(price), variance(price) AS v FROM items").unwrap();
+
+    match &query.select.columns {
+        SelectColumns::Aggregations(aggs) => {
+            assert_eq!(aggs[0].function_type, AggregateType::StdDev);
+            assert_eq!(aggs[0].argument, AggregateArg::Column("price".to_string()));
+            assert_eq!(aggs[1].function_type, AggregateType::Variance);
+            assert_eq!(aggs[1].alias.as_deref(), Some("v"));
+        }
+        _ => panic!("Expected Aggregations"),
+    }
+}
+
+#[test]
+fn test_parser_percentile_cont_both_forms() {
+    for sql in [
+        "SELECT PERCENTILE_CONT(0.95) WITHIN GROUP (ORDER BY latency) AS p95 FROM t",
+        "SELECT percentile_cont(latency, 0.95) AS p95 FROM t",
+    ] {
+        let query = Parser::parse(sql).unwrap();
+        match &query.select.columns {
+            SelectColumns::Aggregations(aggs) => {
+                assert_eq!(
+                    aggs[0].function_type,
+                    AggregateType::PercentileCont,
+                    "{sql}"
+                );
+                assert_eq!(
+                    aggs[0].argument,
+                    AggregateArg::Column("latency".to_string())
+                );
+                assert_eq!(aggs[0].fraction, Some(0.95));
+                assert_eq!(aggs[0].alias.as_deref(), Some("p95"));
+            }
+            _ => panic!("Expected Aggregations for {sql}"),
+        }
+    }
+}
+
+#[test]
+fn test_parser_percentile_cont_rejects_bad_fraction() {
+    assert!(Parser::parse("SELECT PERCENTILE_CONT(latency, 1.5) FROM t").is_err());
+    assert!(Parser::parse("SELECT PERCENTILE_CONT(*, 0.5) FROM t").is_err());
+}
+
+#[test]
+fn test_parser_count_distinct() {
+    let query =
+        Parser::parse("SELECT category, COUNT(DISTINCT tag) FROM t GROUP BY category").unwrap();
+
+    match &query.select.columns {
+        SelectColumns::Mixed { aggregations, .. } => {
+            assert_eq!(aggregations[0].function_type, AggregateType::CountDistinct);
+            assert_eq!(
+                aggregations[0].argument,
+                AggregateArg::Column("tag".to_string())
+            );
+        }
+        other => panic!("Expected Mixed, got {other:?}"),
+    }
+    assert!(Parser::parse("SELECT COUNT(DISTINCT *) FROM t").is_err());
+}
+
+#[test]
+fn test_parser_new_aggregates_in_having_and_order_by() {
+    let query = Parser::parse(
+        "SELECT category, STDDEV(price) FROM t GROUP BY category \
+         HAVING COUNT(DISTINCT tag) > 2 ORDER BY PERCENTILE_CONT(price, 0.5) DESC",
+    )
+    .unwrap();
+    let having = query.select.having.as_ref().unwrap();
+    assert_eq!(
+        having.conditions[0].aggregate.function_type,
+        AggregateType::CountDistinct
+    );
+    assert!(query.select.order_by.is_some());
+}
@@ -8,6 +8,8 @@
 // - Count values are bounded by result set size
 #![allow(clippy::cast_precision_loss)]
 
+use super::aggregate_sketch::{DistinctCounter, TDigest};
+use super::ast::{AggregateArg, AggregateFunction, AggregateType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -26,6 +28,18 @@ pub struct AggregateResult {
     pub mins: HashMap<String, f64>,
     /// MAX results by column name.
     pub maxs: HashMap<String, f64>,
+    /// Sample VARIANCE results by column name (columns with two or more values).
+    #[serde(default)]
+    pub variances: HashMap<String, f64>,
+    /// Sample STDDEV results by column name (columns with two or more values).
+    #[serde(default)]
+    pub stddevs: HashMap<String, f64>,
+    /// COUNT(DISTINCT column) results by column name.
+    #[serde(default)]
+    pub distinct_counts: HashMap<String, u64>,
+    /// Percentile digests by column name, see [`AggregateResult::percentile`].
+    #[serde(skip)]
+    pub(crate) digests: HashMap<String, TDigest>,
 }
 
 impl AggregateResult {
@@ -54,12 +68,40 @@ impl AggregateResult {
             map.insert(format!("max_{col}"), serde_json::json!(max));
         }
 
+        for (col, variance) in &self.variances {
+            map.insert(format!("variance_{col}"), serde_json::json!(variance));
+        }
+
+        for (col, stddev) in &self.stddevs {
+            map.insert(format!("stddev_{col}"), serde_json::json!(stddev));
+        }
+
+        for (col, distinct) in &self.distinct_counts {
+            map.insert(format!("count_distinct_{col}"), serde_json::json!(distinct));
+        }
+
         serde_json::Value::Object(map)
     }
+
+    /// PERCENTILE_CONT(`fraction`) of `column`: linear interpolation between
+    /// the neighbouring values, approximate (t-digest) beyond a few hundred
+    /// values.
+    ///
+    /// `None` when the column had no numeric value or was not tracked (see
+    /// [`Aggregator::for_aggregations`]).
+    #[must_use]
+    pub fn percentile(&self, column: &str, fraction: f64) -> Option<f64> {
+        self.digests.get(column)?.quantile(fraction)
+    }
 }
 
 /// Running aggregate state for a single column.
 ///
+/// `mean` and `m2` (sum of squared deviations from the mean) follow
+/// Welford's online algorithm and merge with Chan et al.'s pairwise
+/// formula, so VARIANCE and STDDEV stay numerically stable across parallel
+/// chunks.
+///
 /// Collocates sum, count, min, and max together so that the `Aggregator`
 /// can maintain them with a single `HashMap` lookup per value instead of four
 /// separate lookups across four maps. This also eliminates the cross-map
@@ -70,6 +112,8 @@ struct ColumnAgg {
     count: u64,
     min: f64,
     max: f64,
+    mean: f64,
+    m2: f64,
 }
 
 impl ColumnAgg {
@@ -79,15 +123,27 @@ impl ColumnAgg {
             count: 1,
             min: value,
             max: value,
+            mean: value,
+            m2: 0.0,
         }
     }
 
-    fn new_batch(sum: f64, count: u64, min: f64, max: f64) -> Self {
+    /// State of a batch of values; `values` must not be empty.
+    fn from_batch(values: &[f64]) -> Self {
+        // SIMD-friendly: compiler auto-vectorizes these loops
+        let sum: f64 = values.iter().sum();
+        let count = values.len() as u64;
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mean = sum / count as f64;
+        let m2 = values.iter().map(|v| (v - mean) * (v - mean)).sum();
         Self {
             sum,
             count,
             min,
             max,
+            mean,
+            m2,
         }
     }
 
@@ -101,24 +157,22 @@ impl ColumnAgg {
         if value > self.max {
             self.max = value;
         }
-    }
-
-    #[inline]
-    fn update_batch(&mut self, batch_sum: f64, batch_count: u64, batch_min: f64, batch_max: f64) {
-        self.sum += batch_sum;
-        self.count += batch_count;
-        if batch_min < self.min {
-            self.min = batch_min;
-        }
-        if batch_max > self.max {
-            self.max = batch_max;
-        }
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
     }
 
     #[inline]
     fn merge_from(&mut self, other: &Self) {
+        let total = self.count + other.count;
+        if total > 0 {
+            let delta = other.mean - self.mean;
+            let weight = other.count as f64 / total as f64;
+            self.mean += delta * weight;
+            self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        }
         self.sum += other.sum;
-        self.count += other.count;
+        self.count = total;
         if other.min < self.min {
             self.min = other.min;
         }
@@ -126,18 +180,29 @@ impl ColumnAgg {
             self.max = other.max;
         }
     }
+
+    /// Sample variance (`n - 1` denominator); `None` below two values.
+    fn sample_variance(&self) -> Option<f64> {
+        (self.count >= 2).then(|| (self.m2 / (self.count - 1) as f64).max(0.0))
+    }
 }
 
 /// Streaming aggregator - O(1) memory, single-pass.
 ///
 /// Based on online algorithms for computing aggregates without
-/// storing all values in memory.
+/// storing all values in memory. PERCENTILE_CONT and COUNT(DISTINCT) need
+/// bounded sketches per column, kept only for the columns registered by
+/// [`Aggregator::for_aggregations`].
 #[derive(Debug, Default)]
 pub struct Aggregator {
     /// Running count for COUNT(*).
     count: u64,
     /// Per-column running aggregates (sum, count, min, max in one entry).
     columns: HashMap<String, ColumnAgg>,
+    /// Percentile digests for PERCENTILE_CONT columns.
+    digests: HashMap<String, TDigest>,
+    /// Distinct-value counters for COUNT(DISTINCT) columns.
+    distinct: HashMap<String, DistinctCounter>,
 }
 
 impl Aggregator {
@@ -147,6 +212,34 @@ impl Aggregator {
         Self::default()
     }
 
+    /// Create an aggregator that also keeps the sketches `aggregations`
+    /// need: a t-digest per PERCENTILE_CONT column and a distinct counter
+    /// per COUNT(DISTINCT) column.
+    ///
+    /// `score` arguments are tracked as the `score` column, like every
+    /// other aggregate outside vector GROUP BY.
+    #[must_use]
+    pub fn for_aggregations(aggregations: &[AggregateFunction]) -> Self {
+        let mut aggregator = Self::new();
+        for agg in aggregations {
+            let column = match &agg.argument {
+                AggregateArg::Column(col) => col.as_str(),
+                AggregateArg::Score => "score",
+                AggregateArg::Wildcard => continue,
+            };
+            match agg.function_type {
+                AggregateType::PercentileCont => {
+                    aggregator.digests.entry(column.to_string()).or_default();
+                }
+                AggregateType::CountDistinct => {
+                    aggregator.distinct.entry(column.to_string()).or_default();
+                }
+                _ => {}
+            }
+        }
+        aggregator
+    }
+
     /// Increment the row count (for COUNT(*)).
     pub fn process_count(&mut self) {
         self.count += 1;
@@ -156,8 +249,14 @@ impl Aggregator {
     ///
     /// Updates SUM, MIN, MAX, and count for AVG calculation in a single
     /// HashMap lookup (fast path) or one allocation (slow path on first
-    /// occurrence of the column).
+    /// occurrence of the column). Tracked sketch columns also feed their
+    /// digest (numbers) or distinct counter (any non-null value).
     pub fn process_value(&mut self, column: &str, value: &serde_json::Value) {
+        if !self.distinct.is_empty() {
+            if let Some(counter) = self.distinct.get_mut(column) {
+                counter.insert(value);
+            }
+        }
         if let Some(num) = Self::extract_number(value) {
             match self.columns.get_mut(column) {
                 Some(agg) => agg.update(num),
@@ -165,6 +264,11 @@ impl Aggregator {
                     self.columns.insert(column.to_string(), ColumnAgg::new(num));
                 }
             }
+            if !self.digests.is_empty() {
+                if let Some(digest) = self.digests.get_mut(column) {
+                    digest.add(num);
+                }
+            }
         }
     }
 
@@ -189,27 +293,30 @@ impl Aggregator {
             return;
         }
 
-        // SIMD-friendly: compiler auto-vectorizes these loops
-        let batch_sum: f64 = values.iter().sum();
-        let batch_count = values.len() as u64;
-        let batch_min = values.iter().copied().fold(f64::INFINITY, f64::min);
-        let batch_max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-
+        let batch = ColumnAgg::from_batch(values);
         match self.columns.get_mut(column) {
-            Some(agg) => agg.update_batch(batch_sum, batch_count, batch_min, batch_max),
+            Some(agg) => agg.merge_from(&batch),
             None => {
-                self.columns.insert(
-                    column.to_string(),
-                    ColumnAgg::new_batch(batch_sum, batch_count, batch_min, batch_max),
-                );
+                self.columns.insert(column.to_string(), batch);
+            }
+        }
+        if let Some(digest) = self.digests.get_mut(column) {
+            for &value in values {
+                digest.add(value);
+            }
+        }
+        if let Some(counter) = self.distinct.get_mut(column) {
+            for &value in values {
+                counter.insert(&serde_json::json!(value));
             }
         }
     }
 
     /// Merge another aggregator into this one (for parallel aggregation).
     ///
-    /// Combines counts, sums, mins, maxs from the other aggregator.
-    /// Used in map-reduce pattern for parallel processing.
+    /// Combines counts, sums, mins, maxs, variance state and sketches from
+    /// the other aggregator. Used in map-reduce pattern for parallel
+    /// processing.
     pub fn merge(&mut self, other: Self) {
         self.count += other.count;
         for (col, other_agg) in other.columns {
@@ -220,6 +327,22 @@ impl Aggregator {
                 }
             }
         }
+        for (col, other_digest) in other.digests {
+            match self.digests.get_mut(&col) {
+                Some(digest) => digest.merge(&other_digest),
+                None => {
+                    self.digests.insert(col, other_digest);
+                }
+            }
+        }
+        for (col, other_counter) in other.distinct {
+            match self.distinct.get_mut(&col) {
+                Some(counter) => counter.merge(&other_counter),
+                None => {
+                    self.distinct.insert(col, other_counter);
+                }
+            }
+        }
     }
 
     /// Finalize aggregation and return results.
@@ -231,11 +354,17 @@ impl Aggregator {
         let mut avgs = HashMap::with_capacity(cap);
         let mut mins = HashMap::with_capacity(cap);
         let mut maxs = HashMap::with_capacity(cap);
+        let mut variances = HashMap::with_capacity(cap);
+        let mut stddevs = HashMap::with_capacity(cap);
 
         for (col, agg) in self.columns {
             if agg.count > 0 {
                 avgs.insert(col.clone(), agg.sum / agg.count as f64);
             }
+            if let Some(variance) = agg.sample_variance() {
+                variances.insert(col.clone(), variance);
+                stddevs.insert(col.clone(), variance.sqrt());
+            }
             sums.insert(col.clone(), agg.sum);
             counts.insert(col.clone(), agg.count);
             mins.insert(col.clone(), agg.min);
@@ -249,6 +378,14 @@ impl Aggregator {
             avgs,
             mins,
             maxs,
+            variances,
+            stddevs,
+            distinct_counts: self
+                .distinct
+                .into_iter()
+                .map(|(col, counter)| (col, counter.count()))
+                .collect(),
+            digests: self.digests,
         }
     }
 }
@@ -149,3 +149,93 @@ fn test_process_batch_equivalence_with_process_value() {
         value_result.maxs.get("price")
     );
 }
+
+#[test]
+fn test_aggregator_variance_stddev() {
+    let mut agg = Aggregator::new();
+    for v in [2, 4, 4, 4, 5, 5, 7, 9] {
+        agg.process_value("x", &serde_json::json!(v));
+    }
+    let result = agg.finalize();
+    // Sample variance of the classic example: 32 / 7.
+    let variance = result.variances["x"];
+    assert!((variance - 32.0 / 7.0).abs() < 1e-12);
+    assert!((result.stddevs["x"] - variance.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_aggregator_variance_needs_two_values() {
+    let mut agg = Aggregator::new();
+    agg.process_value("x", &serde_json::json!(3));
+    let result = agg.finalize();
+    assert!(!result.variances.contains_key("x"));
+    assert!(!result.stddevs.contains_key("x"));
+}
+
+#[test]
+fn test_aggregator_variance_merge_matches_single_pass() {
+    let values: Vec<f64> = (0..1000).map(|i| f64::from(i % 37) * 1.5 + 1e6).collect();
+    let mut whole = Aggregator::new();
+    let mut left = Aggregator::new();
+    let mut right = Aggregator::new();
+    for (i, v) in values.iter().enumerate() {
+        whole.process_value("x", &serde_json::json!(v));
+        if i < 300 {
+            left.process_value("x", &serde_json::json!(v));
+        } else {
+            right.process_value("x", &serde_json::json!(v));
+        }
+    }
+    left.merge(right);
+    let (a, b) = (whole.finalize(), left.finalize());
+    assert!((a.variances["x"] - b.variances["x"]).abs() < 1e-6);
+
+    let mut batch = Aggregator::new();
+    batch.process_batch("x", &values[..500]);
+    batch.process_batch("x", &values[500..]);
+    assert!((batch.finalize().variances["x"] - a.variances["x"]).abs() < 1e-6);
+}
+
+#[test]
+fn test_aggregator_sketches_only_for_tracked_columns() {
+    let query = crate::velesql::Parser::parse(
+        "SELECT PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY latency), COUNT(DISTINCT tag) FROM t",
+    )
+    .unwrap();
+    let crate::velesql::SelectColumns::Aggregations(aggs) = &query.select.columns else {
+        panic!("expected aggregations");
+    };
+
+    let mut agg = Aggregator::for_aggregations(aggs);
+    for (latency, tag) in [(10, "a"), (20, "b"), (30, "a"), (40, "c")] {
+        agg.process_value("latency", &serde_json::json!(latency));
+        agg.process_value("tag", &serde_json::json!(tag));
+        agg.process_value("other", &serde_json::json!(latency));
+    }
+    let result = agg.finalize();
+    assert_eq!(result.percentile("latency", 0.5), Some(25.0));
+    assert_eq!(result.percentile("other", 0.5), None);
+    assert_eq!(result.distinct_counts.get("tag"), Some(&3));
+    assert!(!result.distinct_counts.contains_key("other"));
+}
+
+#[test]
+fn test_aggregator_sketches_survive_merge() {
+    let aggs = [crate::velesql::AggregateFunction {
+        function_type: crate::velesql::AggregateType::CountDistinct,
+        argument: crate::velesql::AggregateArg::Column("tag".to_string()),
+        alias: None,
+        fraction: None,
+    }];
+    let mut left = Aggregator::for_aggregations(&aggs);
+    let mut right = Aggregator::for_aggregations(&aggs);
+    left.process_value("tag", &serde_json::json!("a"));
+    right.process_value("tag", &serde_json::json!("a"));
+    right.process_value("tag", &serde_json::json!("b"));
+
+    // The final aggregator of the parallel path starts untracked.
+    let mut total = Aggregator::new();
+    total.merge(left);
+    total.merge(right);
+    assert_eq!(total.finalize().distinct_counts.get("tag"), Some(&2));
+}
@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::condition::CompareOp;
+use super::select::ArithmeticExpr;
 use super::values::Value;
 
 /// Aggregate function type.
@@ -24,6 +25,15 @@ pub enum AggregateType {
     Max,
     /// FIRST(column) — returns the value from the highest-scoring row in a group.
     First,
+    /// STDDEV(column) — sample standard deviation.
+    StdDev,
+    /// VARIANCE(column) — sample variance.
+    Variance,
+    /// PERCENTILE_CONT(fraction) — interpolated percentile, see
+    /// [`AggregateFunction::fraction`].
+    PercentileCont,
+    /// COUNT(DISTINCT column) — number of distinct non-null values.
+    CountDistinct,
 }
 
 /// Argument to an aggregate function.
@@ -47,13 +57,69 @@ pub struct AggregateFunction {
     pub argument: AggregateArg,
     /// Optional alias (AS clause).
     pub alias: Option<String>,
+    /// Percentile in `[0, 1]` for [`AggregateType::PercentileCont`]; `None`
+    /// for every other function.
+    #[serde(default)]
+    pub fraction: Option<f64>,
+}
+
+impl AggregateFunction {
+    /// Prefix of the default result key `<prefix>_<column>` used when no
+    /// alias is given: `count`, `sum`, `avg`, `min`, `max`, `first`,
+    /// `stddev`, `variance`, `count_distinct`, and `p<percent>` for
+    /// PERCENTILE_CONT (`p95` for 0.95, `p99.9` for 0.999).
+    #[must_use]
+    pub fn result_key_prefix(&self) -> String {
+        match self.function_type {
+            AggregateType::Count => "count".to_string(),
+            AggregateType::Sum => "sum".to_string(),
+            AggregateType::Avg => "avg".to_string(),
+            AggregateType::Min => "min".to_string(),
+            AggregateType::Max => "max".to_string(),
+            AggregateType::First => "first".to_string(),
+            AggregateType::StdDev => "stddev".to_string(),
+            AggregateType::Variance => "variance".to_string(),
+            AggregateType::CountDistinct => "count_distinct".to_string(),
+            AggregateType::PercentileCont => {
+                let percent = (self.fraction.unwrap_or(0.5) * 100.0 * 1e6).round() / 1e6;
+                format!("p{percent}")
+            }
+        }
+    }
 }
 
 /// GROUP BY clause for aggregation queries.
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct GroupByClause {
-    /// Columns to group by.
+    /// Columns to group by, one result key per GROUP BY item. A grouping
+    /// expression contributes its key from [`GroupByClause::expressions`].
     pub columns: Vec<String>,
+    /// Grouping expressions such as `date_trunc('day', created_at)` or
+    /// `floor(price / 10) * 10`, evaluated per row.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expressions: Vec<GroupByExpression>,
+}
+
+impl GroupByClause {
+    /// The grouping expression producing result key `column`, if it is not
+    /// a plain payload column.
+    #[must_use]
+    pub fn expression(&self, column: &str) -> Option<&ArithmeticExpr> {
+        self.expressions
+            .iter()
+            .find(|e| e.key == column)
+            .map(|e| &e.expr)
+    }
+}
+
+/// A computed GROUP BY item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupByExpression {
+    /// Result key of the group value: the alias of the matching SELECT
+    /// expression, or the expression text.
+    pub key: String,
+    /// Expression evaluated per row.
+    pub expr: ArithmeticExpr,
 }
 
 /// Logical operator for combining HAVING conditions.
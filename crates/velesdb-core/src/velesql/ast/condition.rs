@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::fusion::FusionConfig;
+use super::select::ArithmeticExpr;
 use super::values::{Value, VectorExpr};
 use crate::sparse_index::SparseVector;
 use crate::velesql::GraphPattern;
@@ -24,6 +25,8 @@ pub enum Condition {
     Similarity(SimilarityCondition),
     /// Comparison: column op value
     Comparison(Comparison),
+    /// Comparison between scalar expressions: `price * quantity > 1000`
+    Expression(ExpressionCondition),
     /// IN operator: column IN (values)
     In(InCondition),
     /// BETWEEN operator: column BETWEEN a AND b
@@ -66,6 +69,9 @@ pub struct GraphMatchPredicate {
 pub struct VectorSearch {
     /// Vector expression (literal or parameter).
     pub vector: VectorExpr,
+    /// Negative vectors from `AVOID` (empty when the clause is absent).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub avoid: Vec<VectorExpr>,
 }
 
 /// Multi-vector fused search condition.
@@ -109,6 +115,17 @@ pub struct SimilarityCondition {
     pub threshold: f64,
 }
 
+/// Comparison between two scalar expressions over payload fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpressionCondition {
+    /// Left-hand expression.
+    pub left: ArithmeticExpr,
+    /// Comparison operator.
+    pub operator: CompareOp,
+    /// Right-hand expression.
+    pub right: ArithmeticExpr,
+}
+
 /// Comparison condition.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Comparison {
@@ -270,6 +287,7 @@ impl Condition {
             Self::Contains(_)
             | Self::ContainsText(_)
             | Self::Comparison(_)
+            | Self::Expression(_)
             | Self::In(_)
             | Self::Between(_)
             | Self::Like(_)
@@ -349,6 +367,7 @@ impl Condition {
             | Self::VectorFusedSearch(_)
             | Self::SparseVectorSearch(_)
             | Self::Similarity(_)
+            | Self::Expression(_)
             | Self::Like(_)
             | Self::IsNull(_)
             | Self::Match(_)
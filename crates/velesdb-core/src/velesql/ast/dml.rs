@@ -4,7 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{Condition, Value};
+use super::{ArithmeticExpr, Condition, Value};
+use crate::velesql::GraphPattern;
 
 /// INSERT or UPSERT statement (supports multi-row).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -29,6 +30,10 @@ pub struct UpdateAssignment {
     pub column: String,
     /// Assigned value expression.
     pub value: Value,
+    /// Arithmetic over the row's current values (`stock - 1`). When set,
+    /// `value` is `Null` and the expression is evaluated per row instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expr: Option<ArithmeticExpr>,
 }
 
 /// UPDATE statement.
@@ -104,6 +109,65 @@ pub struct InsertNodeStatement {
     pub payload: serde_json::Value,
 }
 
+/// Node endpoint of an edge created by a graph mutation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MutationNodeRef {
+    /// Literal node ID: `(42)`.
+    Id(u64),
+    /// Node ID bound at execution time: `($src)`.
+    Parameter(String),
+    /// Node alias bound by the statement's MATCH: `(a)`.
+    Alias(String),
+}
+
+/// Edge created by a graph mutation: `(a)-[:TYPE {key: value}]->(b)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateEdgePattern {
+    /// Source node.
+    pub source: MutationNodeRef,
+    /// Target node.
+    pub target: MutationNodeRef,
+    /// Edge label/type.
+    pub label: String,
+    /// Edge properties.
+    pub properties: Vec<(String, Value)>,
+}
+
+/// Mutation applied by a graph mutation statement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GraphMutationAction {
+    /// `CREATE (a)-[:TYPE]->(b), ...`: creates one edge per pattern and row.
+    CreateEdges(Vec<CreateEdgePattern>),
+    /// `[DETACH] DELETE x, ...`: deletes the bound nodes and relationships.
+    Delete {
+        /// Node or relationship aliases bound by the MATCH.
+        aliases: Vec<String>,
+        /// `DETACH DELETE` also removes the edges of deleted nodes.
+        detach: bool,
+    },
+}
+
+/// Cypher-style graph mutation statement.
+///
+/// ```text
+/// [MATCH pattern, ... [WHERE condition]] CREATE (a)-[:TYPE {k: v}]->(b) [IN collection]
+/// MATCH pattern, ... [WHERE condition] [DETACH] DELETE alias, ... [IN collection]
+/// ```
+///
+/// The mutation is applied once per row bound by the MATCH. Without `IN`,
+/// the collection comes from the `_collection` parameter, as for MATCH.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphMutationStatement {
+    /// Target graph collection name, when given with `IN`.
+    pub collection: Option<String>,
+    /// MATCH patterns binding the aliases the mutation refers to.
+    pub patterns: Vec<GraphPattern>,
+    /// Optional WHERE clause of the MATCH (single pattern only).
+    pub where_clause: Option<Condition>,
+    /// The mutation to apply.
+    pub action: GraphMutationAction,
+}
+
 /// DML statement.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -124,4 +188,6 @@ pub enum DmlStatement {
     SelectEdges(SelectEdgesStatement),
     /// INSERT NODE statement (VelesQL v3.5 Phase 5).
     InsertNode(InsertNodeStatement),
+    /// Graph mutation: `CREATE` edges, `[DETACH] DELETE`.
+    GraphMutation(GraphMutationStatement),
 }
@@ -20,12 +20,45 @@ pub enum FusionStrategyType {
     Rsf,
     /// Average score across all queries where the document appears.
     Average,
+    /// Single-branch vector search boosted by a numeric payload field:
+    /// `score * (1 + alpha * ln(1 + field))`.
+    FieldBoost,
+}
+
+impl FusionStrategyType {
+    /// Parses a strategy name (case-insensitive). `relative_score` is accepted
+    /// as an alias of `rsf`.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "rrf" => Some(Self::Rrf),
+            "weighted" => Some(Self::Weighted),
+            "maximum" => Some(Self::Maximum),
+            "rsf" | "relative_score" => Some(Self::Rsf),
+            "average" => Some(Self::Average),
+            "field_boost" => Some(Self::FieldBoost),
+            _ => None,
+        }
+    }
+
+    /// Returns the strategy name as written in `USING FUSION(strategy = ...)`.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rrf => "rrf",
+            Self::Weighted => "weighted",
+            Self::Maximum => "maximum",
+            Self::Rsf => "rsf",
+            Self::Average => "average",
+            Self::FieldBoost => "field_boost",
+        }
+    }
 }
 
 /// USING FUSION clause for hybrid search (EPIC-040 US-005).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FusionClause {
-    /// Fusion strategy (rrf, weighted, maximum, rsf, average).
+    /// Fusion strategy (rrf, weighted, maximum, rsf, average, field_boost).
     pub strategy: FusionStrategyType,
     /// RRF k parameter (default 60).
     pub k: Option<u32>,
@@ -39,6 +72,12 @@ pub struct FusionClause {
     /// Sparse vector weight for RSF fusion (0.0-1.0).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sparse_weight: Option<f32>,
+    /// Numeric payload field of `field_boost` fusion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boost_field: Option<String>,
+    /// Boost strength of `field_boost` fusion (default 1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boost_alpha: Option<f64>,
 }
 
 impl Default for FusionClause {
@@ -50,6 +89,8 @@ impl Default for FusionClause {
             graph_weight: None,
             dense_weight: None,
             sparse_weight: None,
+            boost_field: None,
+            boost_alpha: None,
         }
     }
 }
@@ -21,14 +21,14 @@ use serde::{Deserialize, Serialize};
 // Re-export all types for backward compatibility
 pub use admin::{AdminStatement, FlushStatement};
 pub use aggregation::{
-    AggregateArg, AggregateFunction, AggregateType, GroupByClause, HavingClause, HavingCondition,
-    LogicalOp,
+    AggregateArg, AggregateFunction, AggregateType, GroupByClause, GroupByExpression, HavingClause,
+    HavingCondition, LogicalOp,
 };
 pub use condition::{
     BetweenCondition, CompareOp, Comparison, Condition, ContainsCondition, ContainsMode,
-    ContainsTextCondition, GeoBboxCondition, GeoDistanceCondition, GraphMatchPredicate,
-    InCondition, IsNullCondition, LikeCondition, MatchCondition, SimilarityCondition,
-    SparseVectorExpr, SparseVectorSearch, VectorFusedSearch, VectorSearch,
+    ContainsTextCondition, ExpressionCondition, GeoBboxCondition, GeoDistanceCondition,
+    GraphMatchPredicate, InCondition, IsNullCondition, LikeCondition, MatchCondition,
+    SimilarityCondition, SparseVectorExpr, SparseVectorSearch, VectorFusedSearch, VectorSearch,
 };
 pub use ddl::{
     AlterCollectionStatement, AnalyzeStatement, CreateCollectionKind, CreateCollectionStatement,
@@ -37,22 +37,24 @@ pub use ddl::{
     VectorCollectionParams,
 };
 pub use dml::{
-    DeleteEdgeStatement, DeleteStatement, DmlStatement, InsertEdgeStatement, InsertNodeStatement,
-    InsertStatement, SelectEdgesStatement, UpdateAssignment, UpdateStatement,
+    CreateEdgePattern, DeleteEdgeStatement, DeleteStatement, DmlStatement, GraphMutationAction,
+    GraphMutationStatement, InsertEdgeStatement, InsertNodeStatement, InsertStatement,
+    MutationNodeRef, SelectEdgesStatement, UpdateAssignment, UpdateStatement,
 };
 pub use fusion::{FusionClause, FusionConfig, FusionStrategyType};
 pub use introspection::{DescribeCollectionStatement, IntrospectionStatement};
 pub use join::{ColumnRef, JoinClause, JoinCondition, JoinType};
 pub use select::{
-    ArithmeticExpr, ArithmeticOp, Column, DistinctMode, LetBinding, OrderByExpr, SelectColumns,
-    SelectOrderBy, SelectStatement, SimilarityOrderBy, SimilarityScoreExpr, DEFAULT_SELECT_LIMIT,
+    ArithmeticExpr, ArithmeticOp, Column, DateTruncUnit, DistinctMode, LetBinding, OrderByExpr,
+    ScalarFunction, SelectColumns, SelectExpression, SelectOrderBy, SelectStatement,
+    SimilarityOrderBy, SimilarityScoreExpr, DEFAULT_SELECT_LIMIT,
 };
 pub use train::TrainStatement;
 pub use values::{
     CorrelatedColumn, IntervalUnit, IntervalValue, Subquery, TemporalExpr, Value, VectorExpr,
 };
 pub use window::{OverClause, WindowFunction, WindowFunctionType, WindowOrderBy};
-pub use with_clause::{QuantizationMode, WithClause, WithOption, WithValue};
+pub use with_clause::{QuantizationMode, QueryHint, WithClause, WithOption, WithValue};
 
 /// A complete VelesQL query.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -202,6 +204,7 @@ impl Query {
             DmlStatement::DeleteEdge(s) => &s.collection,
             DmlStatement::SelectEdges(s) => &s.collection,
             DmlStatement::InsertNode(s) => &s.collection,
+            DmlStatement::GraphMutation(s) => s.collection.as_ref()?,
         };
         if name.is_empty() {
             None
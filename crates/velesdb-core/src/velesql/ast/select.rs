@@ -11,6 +11,7 @@ use super::fusion::FusionClause;
 use super::join::JoinClause;
 use super::values::VectorExpr;
 use super::with_clause::WithClause;
+use crate::filter::SortCollation;
 
 /// Default `LIMIT` applied to every SELECT statement without an explicit
 /// `LIMIT` clause.
@@ -85,7 +86,8 @@ pub enum SelectColumns {
     Columns(Vec<Column>),
     /// Select aggregate functions.
     Aggregations(Vec<AggregateFunction>),
-    /// Mixed: columns + aggregations + similarity scores + qualified wildcards + window functions.
+    /// Mixed: columns + aggregations + similarity scores + qualified wildcards
+    /// + window functions + scalar expressions.
     Mixed {
         /// Regular columns.
         columns: Vec<Column>,
@@ -100,6 +102,9 @@ pub enum SelectColumns {
         /// Window function expressions (Issue #386).
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         window_functions: Vec<super::window::WindowFunction>,
+        /// Computed scalar expressions (e.g., `price * 1.2 AS price_with_tax`).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        expressions: Vec<SelectExpression>,
     },
     /// Select similarity() score only (zero-arg form).
     SimilarityScore(SimilarityScoreExpr),
@@ -142,10 +147,11 @@ impl SelectColumns {
                 similarity_scores,
                 qualified_wildcards,
                 window_functions,
+                expressions,
             } => {
                 // Order mirrors the SELECT-list grammar: columns, aggregates,
                 // similarity(), qualified wildcards (`alias.*`), window
-                // functions. Python/WASM bindings consume this list to expose
+                // functions, scalar expressions. Python/WASM bindings consume this list to expose
                 // the column metadata contract, so every SELECT-list variant
                 // must contribute a display name.
                 let mut result: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
@@ -165,6 +171,7 @@ impl SelectColumns {
                         .clone()
                         .unwrap_or_else(|| wf.function_type.default_alias().to_string())
                 }));
+                result.extend(expressions.iter().map(SelectExpression::output_name));
                 result
             }
             Self::SimilarityScore(expr) => {
@@ -185,6 +192,35 @@ pub struct SimilarityScoreExpr {
     pub alias: Option<String>,
 }
 
+/// A computed scalar expression in the SELECT list, with optional alias.
+///
+/// Example: `SELECT price * 1.2 AS price_with_tax`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectExpression {
+    /// Expression evaluated against each row's payload.
+    pub expr: ArithmeticExpr,
+    /// Optional alias (e.g., `AS price_with_tax`).
+    pub alias: Option<String>,
+}
+
+impl SelectExpression {
+    /// Returns the output key: the alias, or the expression text without
+    /// its outermost parentheses (`price * 1.2`).
+    #[must_use]
+    pub fn output_name(&self) -> String {
+        if let Some(alias) = &self.alias {
+            return alias.clone();
+        }
+        let text = self.expr.to_string();
+        match text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+            Some(inner) if matches!(self.expr, ArithmeticExpr::BinaryOp { .. }) => {
+                inner.to_string()
+            }
+            _ => text,
+        }
+    }
+}
+
 /// A column reference.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Column {
@@ -221,6 +257,9 @@ pub struct SelectOrderBy {
     pub expr: OrderByExpr,
     /// Sort direction (true = DESC).
     pub descending: bool,
+    /// String ordering from `COLLATE <name>` (binary when absent).
+    #[serde(default, skip_serializing_if = "SortCollation::is_binary")]
+    pub collation: SortCollation,
 }
 
 impl SelectOrderBy {
@@ -228,12 +267,16 @@ impl SelectOrderBy {
     #[must_use]
     pub fn to_display_pair(&self) -> (String, String) {
         let dir = if self.descending { "DESC" } else { "ASC" };
-        let col = match &self.expr {
+        let mut col = match &self.expr {
             OrderByExpr::Field(f) => f.clone(),
             OrderByExpr::Similarity(_) | OrderByExpr::SimilarityBare => "similarity()".to_string(),
             OrderByExpr::Aggregate(agg) => format!("{:?}", agg.function_type),
             OrderByExpr::Arithmetic(expr) => format!("{expr}"),
+            OrderByExpr::Random => "random()".to_string(),
         };
+        if !self.collation.is_binary() {
+            col = format!("{col} COLLATE {}", self.collation);
+        }
         (col, dir.to_string())
     }
 }
@@ -254,6 +297,9 @@ pub enum OrderByExpr {
     ///
     /// Example: `0.7 * vector_score + 0.3 * graph_score`
     Arithmetic(ArithmeticExpr),
+    /// `random()`: each row gets a uniformly random sort key, so
+    /// `ORDER BY random() LIMIT n` returns a random sample of `n` rows.
+    Random,
 }
 
 /// A named score binding defined by a `LET` clause (VelesQL v1.10 Phase 3).
@@ -278,6 +324,8 @@ pub struct LetBinding {
 ///
 /// Supports binary operations (+, -, *, /) with numeric literals,
 /// variables (field references), and similarity() function calls.
+/// Scalar expressions in WHERE and SELECT reuse this tree and add string
+/// literals and `||` concatenation.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ArithmeticExpr {
@@ -287,6 +335,8 @@ pub enum ArithmeticExpr {
     Variable(String),
     /// Similarity function call (zero-arg or with field+vector).
     Similarity(Box<OrderByExpr>),
+    /// String literal (e.g., `' '`), only produced by scalar expressions.
+    Text(String),
     /// Binary operation with operator precedence.
     BinaryOp {
         /// Left operand.
@@ -296,6 +346,111 @@ pub enum ArithmeticExpr {
         /// Right operand.
         right: Box<ArithmeticExpr>,
     },
+    /// Built-in scalar function call (e.g., `floor(price / 10)`), only
+    /// produced by scalar expressions.
+    Function {
+        /// Function being called.
+        function: ScalarFunction,
+        /// Arguments in call order.
+        args: Vec<ArithmeticExpr>,
+    },
+}
+
+/// Built-in scalar functions of scalar expressions.
+///
+/// Numeric functions yield `null` for non-numeric input. Timestamps are epoch
+/// seconds, like `NOW()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ScalarFunction {
+    /// `floor(x)`: largest integer not above `x`.
+    Floor,
+    /// `ceil(x)`: smallest integer not below `x`.
+    Ceil,
+    /// `bucket(x, width)`: `floor(x / width) * width`, the start of the
+    /// width-sized bucket holding `x`.
+    Bucket,
+    /// `date_trunc('unit', ts)`: epoch seconds truncated to the start of
+    /// the UTC `unit` (weeks start on Monday).
+    DateTrunc(DateTruncUnit),
+}
+
+impl ScalarFunction {
+    /// Function name as written in queries.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Floor => "floor",
+            Self::Ceil => "ceil",
+            Self::Bucket => "bucket",
+            Self::DateTrunc(_) => "date_trunc",
+        }
+    }
+
+    /// Number of arguments the function takes in the expression tree
+    /// (`date_trunc`'s unit is part of the function).
+    #[must_use]
+    pub fn arity(self) -> usize {
+        match self {
+            Self::Floor | Self::Ceil | Self::DateTrunc(_) => 1,
+            Self::Bucket => 2,
+        }
+    }
+}
+
+/// Truncation unit of `date_trunc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum DateTruncUnit {
+    /// `'second'`
+    Second,
+    /// `'minute'`
+    Minute,
+    /// `'hour'`
+    Hour,
+    /// `'day'`
+    Day,
+    /// `'week'` (ISO weeks, starting on Monday)
+    Week,
+    /// `'month'`
+    Month,
+    /// `'quarter'`
+    Quarter,
+    /// `'year'`
+    Year,
+}
+
+impl DateTruncUnit {
+    /// Parses a unit name, case-insensitively (`'day'`, `'MONTH'`, ...).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "second" => Self::Second,
+            "minute" => Self::Minute,
+            "hour" => Self::Hour,
+            "day" => Self::Day,
+            "week" => Self::Week,
+            "month" => Self::Month,
+            "quarter" => Self::Quarter,
+            "year" => Self::Year,
+            _ => return None,
+        })
+    }
+
+    /// Lower-case unit name as written in queries.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Quarter => "quarter",
+            Self::Year => "year",
+        }
+    }
 }
 
 /// Arithmetic operators for ORDER BY expressions (EPIC-042).
@@ -310,6 +465,52 @@ pub enum ArithmeticOp {
     Mul,
     /// Division (`/`).
     Div,
+    /// String concatenation (`||`), only produced by scalar expressions.
+    Concat,
+}
+
+impl ArithmeticExpr {
+    /// Returns every field reference in the expression, left to right.
+    #[must_use]
+    pub fn variables(&self) -> Vec<&str> {
+        let mut out = Vec::new();
+        self.collect_variables(&mut out);
+        out
+    }
+
+    fn collect_variables<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Self::Variable(name) => out.push(name),
+            Self::BinaryOp { left, right, .. } => {
+                left.collect_variables(out);
+                right.collect_variables(out);
+            }
+            Self::Function { args, .. } => {
+                for arg in args {
+                    arg.collect_variables(out);
+                }
+            }
+            Self::Literal(_) | Self::Text(_) | Self::Similarity(_) => {}
+        }
+    }
+
+    /// Rewrites every field reference in place (e.g. to strip a MATCH alias).
+    #[cfg_attr(not(feature = "persistence"), allow(dead_code))]
+    pub(crate) fn rewrite_variables(&mut self, f: &mut impl FnMut(&str) -> String) {
+        match self {
+            Self::Variable(name) => *name = f(name),
+            Self::BinaryOp { left, right, .. } => {
+                left.rewrite_variables(f);
+                right.rewrite_variables(f);
+            }
+            Self::Function { args, .. } => {
+                for arg in args {
+                    arg.rewrite_variables(f);
+                }
+            }
+            Self::Literal(_) | Self::Text(_) | Self::Similarity(_) => {}
+        }
+    }
 }
 
 impl fmt::Display for ArithmeticOp {
@@ -319,6 +520,7 @@ impl fmt::Display for ArithmeticOp {
             Self::Sub => write!(f, "-"),
             Self::Mul => write!(f, "*"),
             Self::Div => write!(f, "/"),
+            Self::Concat => write!(f, "||"),
         }
     }
 }
@@ -328,6 +530,7 @@ impl fmt::Display for ArithmeticExpr {
         match self {
             Self::Literal(v) => write!(f, "{v}"),
             Self::Variable(name) => write!(f, "{name}"),
+            Self::Text(s) => write!(f, "'{}'", s.replace('\'', "''")),
             Self::Similarity(inner) => match inner.as_ref() {
                 OrderByExpr::Similarity(sim) => {
                     let vec_str = match &sim.vector {
@@ -339,6 +542,19 @@ impl fmt::Display for ArithmeticExpr {
                 _ => write!(f, "similarity()"),
             },
             Self::BinaryOp { left, op, right } => write!(f, "({left} {op} {right})"),
+            Self::Function { function, args } => {
+                write!(f, "{}(", function.name())?;
+                let mut sep = "";
+                if let ScalarFunction::DateTrunc(unit) = function {
+                    write!(f, "'{}'", unit.as_str())?;
+                    sep = ", ";
+                }
+                for arg in args {
+                    write!(f, "{sep}{arg}")?;
+                    sep = ", ";
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -47,6 +47,47 @@ impl QuantizationMode {
     }
 }
 
+/// Planner hint from `WITH (hint = '...')`.
+///
+/// Hints override the cost-based choice of the execution path; they never
+/// change which rows match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum QueryHint {
+    /// Skip the indexes: exact scan of the points matching the filter
+    /// (scored by vector distance when the query has a `NEAR`).
+    ForceScan,
+    /// Keep the HNSW index as the first step of a filtered vector search,
+    /// whatever the filter selectivity.
+    ForceIndex,
+    /// Fetch exactly `LIMIT` candidates from HNSW for a filtered vector
+    /// search instead of a selectivity-based multiple.
+    NoOverfetch,
+}
+
+impl QueryHint {
+    /// Parses a hint name (case-insensitive).
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "force_scan" => Some(Self::ForceScan),
+            "force_index" => Some(Self::ForceIndex),
+            "no_overfetch" => Some(Self::NoOverfetch),
+            _ => None,
+        }
+    }
+
+    /// Returns the string representation.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ForceScan => "force_scan",
+            Self::ForceIndex => "force_index",
+            Self::NoOverfetch => "no_overfetch",
+        }
+    }
+}
+
 /// WITH clause for query-time configuration overrides.
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct WithClause {
@@ -100,6 +141,16 @@ impl WithClause {
             .map(|v| v as usize)
     }
 
+    /// Gets the number of HNSW entry points (`WITH (entry_probes = 4)`) if
+    /// specified; values below 1 read as 1.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub fn get_entry_probes(&self) -> Option<usize> {
+        self.get("entry_probes")
+            .and_then(WithValue::as_integer)
+            .map(|v| v.max(1) as usize)
+    }
+
     /// Gets timeout in milliseconds if specified.
     #[must_use]
     #[allow(clippy::cast_sign_loss)]
@@ -115,6 +166,13 @@ impl WithClause {
         self.get("rerank").and_then(WithValue::as_bool)
     }
 
+    /// Gets the distance metric override (`WITH (metric = 'dot')`) if
+    /// specified, unparsed.
+    #[must_use]
+    pub fn get_metric(&self) -> Option<&str> {
+        self.get("metric").and_then(WithValue::as_str)
+    }
+
     /// Gets quantization mode if specified (EPIC-055 US-005).
     ///
     /// Supported values: 'f32', 'int8', 'dual', 'auto'.
@@ -135,6 +193,23 @@ impl WithClause {
             .and_then(WithValue::as_integer)
             .map(|v| v.max(1) as usize)
     }
+
+    /// Gets the planner hints of `WITH (hint = '...')`, several separated by
+    /// commas (`hint = 'force_index, no_overfetch'`).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first name that is not a known [`QueryHint`].
+    pub fn get_hints(&self) -> Result<Vec<QueryHint>, String> {
+        let Some(value) = self.get("hint").and_then(WithValue::as_str) else {
+            return Ok(Vec::new());
+        };
+        value
+            .split(',')
+            .filter(|name| !name.trim().is_empty())
+            .map(|name| QueryHint::parse(name).ok_or_else(|| name.trim().to_string()))
+            .collect()
+    }
 }
 
 /// A single option in a WITH clause.
@@ -259,6 +259,7 @@ fn test_display_names_mixed_includes_all_variants() {
             },
             alias: Some("rn".to_string()),
         }],
+        expressions: vec![],
     };
 
     // Expected order (mirrors the SELECT-list grammar):
@@ -286,6 +287,7 @@ fn test_display_names_mixed_without_columns_still_exposes_score_and_wildcard() {
         similarity_scores: vec![SimilarityScoreExpr { alias: None }],
         qualified_wildcards: vec!["docs".to_string()],
         window_functions: Vec::new(),
+        expressions: vec![],
     };
 
     assert_eq!(
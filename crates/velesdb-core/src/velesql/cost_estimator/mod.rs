@@ -361,7 +361,7 @@ impl<'a> CostEstimator<'a> {
             Condition::Match(_) | Condition::Contains(_) | Condition::GeoDistance(_) => 0.1,
             Condition::ContainsText(_) => 0.05,
             Condition::GeoBbox(_) => 0.2,
-            Condition::GraphMatch(_) => 0.5,
+            Condition::GraphMatch(_) | Condition::Expression(_) => 0.5,
             Condition::And(left, right) => {
                 self.estimate_condition_selectivity(left)
                     * self.estimate_condition_selectivity(right)
@@ -37,7 +37,8 @@ impl CostEstimator<'_> {
             | Condition::GeoDistance(_)
             | Condition::ContainsText(_)
             | Condition::GeoBbox(_)
-            | Condition::GraphMatch(_) => (
+            | Condition::GraphMatch(_)
+            | Condition::Expression(_) => (
                 self.estimate_condition_selectivity(condition),
                 SelectivityMethod::Heuristic,
             ),
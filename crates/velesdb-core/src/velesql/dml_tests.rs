@@ -3,7 +3,9 @@
 //! Also covers collection name resolution across all DML variants
 //! (regression tests from Devin review).
 
-use crate::velesql::{CompareOp, Condition, DmlStatement, Parser, Value};
+use crate::velesql::{
+    ArithmeticExpr, ArithmeticOp, CompareOp, Condition, DmlStatement, Parser, Value,
+};
 
 #[test]
 fn test_parse_insert_statement() {
@@ -52,6 +54,50 @@ fn test_parse_update_statement_with_where() {
     }
 }
 
+#[test]
+fn test_parse_update_set_arithmetic_expression() {
+    let query = Parser::parse("UPDATE products SET stock = stock - 1, name = 'x' WHERE stock > 0")
+        .expect("UPDATE with expression should parse");
+    let Some(DmlStatement::Update(update)) = query.dml else {
+        panic!("Expected UPDATE statement");
+    };
+
+    let stock = &update.assignments[0];
+    assert_eq!(stock.value, Value::Null);
+    assert_eq!(
+        stock.expr,
+        Some(ArithmeticExpr::BinaryOp {
+            left: Box::new(ArithmeticExpr::Variable("stock".to_string())),
+            op: ArithmeticOp::Sub,
+            right: Box::new(ArithmeticExpr::Literal(1.0)),
+        })
+    );
+    // Plain values keep parsing as values.
+    assert_eq!(update.assignments[1].value, Value::String("x".to_string()));
+    assert!(update.assignments[1].expr.is_none());
+    assert!(update.where_clause.is_some());
+}
+
+#[test]
+fn test_parse_update_set_field_reference_and_precedence() {
+    let query = Parser::parse("UPDATE t SET total = price * (qty + 1), copy = other")
+        .expect("UPDATE with expressions should parse");
+    let Some(DmlStatement::Update(update)) = query.dml else {
+        panic!("Expected UPDATE statement");
+    };
+    assert!(matches!(
+        update.assignments[0].expr,
+        Some(ArithmeticExpr::BinaryOp {
+            op: ArithmeticOp::Mul,
+            ..
+        })
+    ));
+    assert_eq!(
+        update.assignments[1].expr,
+        Some(ArithmeticExpr::Variable("other".to_string()))
+    );
+}
+
 // ============================================================================
 // Regression — DML collection name resolution (Devin review)
 // ============================================================================
@@ -215,6 +215,8 @@ impl QueryPlan {
     const DEFAULT_EF_SEARCH: u32 = 100;
 
     /// Builds the primary scan node based on search type.
+    ///
+    /// A `WITH (hint = 'force_scan')` always yields a table scan.
     fn build_scan_node(
         stmt: &SelectStatement,
         has_vector_search: bool,
@@ -222,8 +224,14 @@ impl QueryPlan {
     ) -> (Vec<PlanNode>, Option<IndexType>) {
         let mut nodes = Vec::new();
         let index_used;
+        let force_scan = stmt
+            .with_clause
+            .as_ref()
+            .and_then(|with| with.get_hints().ok())
+            .is_some_and(|hints| hints.contains(&crate::velesql::ast::QueryHint::ForceScan));
+        let index_lookup = index_lookup.filter(|_| !force_scan);
 
-        if has_vector_search {
+        if has_vector_search && !force_scan {
             index_used = Some(IndexType::Hnsw);
             let candidates =
                 u32::try_from(stmt.limit.unwrap_or(DEFAULT_SELECT_LIMIT)).unwrap_or(u32::MAX);
@@ -279,6 +287,7 @@ impl QueryPlan {
             crate::velesql::ast::FusionStrategyType::Maximum => "Maximum",
             crate::velesql::ast::FusionStrategyType::Rsf => "RSF",
             crate::velesql::ast::FusionStrategyType::Average => "Average",
+            crate::velesql::ast::FusionStrategyType::FieldBoost => "FieldBoost",
         };
         let weights = Self::format_fusion_weights(fc);
         Some(FusionInfo {
@@ -303,6 +312,12 @@ impl QueryPlan {
         if let Some(sw) = fc.sparse_weight {
             parts.push(format!("sparse={sw}"));
         }
+        if let Some(field) = &fc.boost_field {
+            parts.push(format!("field={field}"));
+        }
+        if let Some(alpha) = fc.boost_alpha {
+            parts.push(format!("alpha={alpha}"));
+        }
         if parts.is_empty() {
             None
         } else {
@@ -510,6 +525,9 @@ impl QueryPlan {
                 gd.operator.as_str()
             ),
             Condition::GeoBbox(gb) => format!("GEO_BBOX({}, ...)", gb.column),
+            Condition::Expression(ec) => {
+                format!("{} {} {}", ec.left, ec.operator.as_str(), ec.right)
+            }
             _ => return None,
         };
         Some(desc)
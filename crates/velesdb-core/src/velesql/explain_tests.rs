@@ -47,6 +47,7 @@ fn test_plan_from_vector_search() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("query".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(5),
@@ -77,6 +78,7 @@ fn test_plan_with_filter() {
         where_clause: Some(Condition::And(
             Box::new(Condition::VectorSearch(VsCondition {
                 vector: VectorExpr::Parameter("v".to_string()),
+                avoid: Vec::new(),
             })),
             Box::new(Condition::Comparison(Comparison {
                 column: "category".to_string(),
@@ -112,6 +114,7 @@ fn test_plan_to_tree_format() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("q".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(10),
@@ -201,6 +204,7 @@ fn test_filter_strategy_post_filter_default() {
         where_clause: Some(Condition::And(
             Box::new(Condition::VectorSearch(VsCondition {
                 vector: VectorExpr::Parameter("v".to_string()),
+                avoid: Vec::new(),
             })),
             Box::new(Condition::Comparison(Comparison {
                 column: "status".to_string(),
@@ -553,6 +557,7 @@ fn test_ef_search_reads_with_clause() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("q".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(10),
@@ -578,6 +583,7 @@ fn test_ef_search_defaults_to_100_without_with() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("q".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(10),
@@ -609,6 +615,7 @@ fn test_with_options_displayed_in_tree() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(10),
@@ -674,6 +681,7 @@ fn test_let_bindings_displayed_via_from_query() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(10),
@@ -761,6 +769,7 @@ fn test_fusion_rrf_displayed_in_tree() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(10),
@@ -791,6 +800,7 @@ fn test_fusion_weighted_with_weights() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(10),
@@ -805,6 +815,8 @@ fn test_fusion_weighted_with_weights() {
             graph_weight: Some(0.3),
             dense_weight: None,
             sparse_weight: None,
+            boost_field: None,
+            boost_alpha: None,
         }),
     };
 
@@ -862,6 +874,7 @@ fn test_with_options_serialized_in_json() {
         joins: vec![],
         where_clause: Some(Condition::VectorSearch(VsCondition {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         })),
         order_by: None,
         limit: Some(5),
@@ -1451,3 +1464,22 @@ fn test_compound_query_plan_has_no_implicit_limit() {
         "compound queries without LIMIT have no implicit limit: {tree}"
     );
 }
+
+#[test]
+fn test_explain_force_scan_hint_replaces_index_lookup() {
+    let mut indexed_fields = HashSet::new();
+    indexed_fields.insert("category".to_string());
+    let indexed =
+        crate::velesql::Parser::parse("SELECT * FROM docs WHERE category = 'tech' LIMIT 10")
+            .expect("parse indexed query");
+    let hinted = crate::velesql::Parser::parse(
+        "SELECT * FROM docs WHERE category = 'tech' LIMIT 10 WITH (hint = 'force_scan')",
+    )
+    .expect("parse hinted query");
+
+    let plan = QueryPlan::from_select_with_indexed_fields(&indexed.select, &indexed_fields);
+    assert_eq!(plan.index_used, Some(IndexType::Property));
+    let plan = QueryPlan::from_select_with_indexed_fields(&hinted.select, &indexed_fields);
+    assert_eq!(plan.index_used, None);
+    assert!(plan.to_tree().contains("TableScan"), "{}", plan.to_tree());
+}
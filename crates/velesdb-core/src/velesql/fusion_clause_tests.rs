@@ -144,3 +144,38 @@ fn test_fusion_with_where_clause() {
         other => panic!("Expected comparison WHERE clause, got {other:?}"),
     }
 }
+
+#[test]
+fn test_using_fusion_field_boost_call_form() {
+    let sql = "SELECT * FROM docs WHERE vector NEAR $v LIMIT 5 \
+               USING FUSION field_boost(field = 'views', alpha = 0.2)";
+    let query = Parser::parse(sql).expect("field_boost call form should parse");
+    let fusion = query
+        .select
+        .fusion_clause
+        .as_ref()
+        .expect("FUSION clause should be present");
+    assert_eq!(
+        fusion.strategy,
+        crate::velesql::FusionStrategyType::FieldBoost
+    );
+    assert_eq!(fusion.boost_field.as_deref(), Some("views"));
+    assert_eq!(fusion.boost_alpha, Some(0.2));
+}
+
+#[test]
+fn test_using_fusion_field_boost_option_form_and_unknown_call() {
+    let sql = "SELECT * FROM docs WHERE vector NEAR $v LIMIT 5 \
+               USING FUSION(strategy = 'field_boost', field = 'stats.rating')";
+    let query = Parser::parse(sql).expect("field_boost option form should parse");
+    let fusion = query.select.fusion_clause.expect("FUSION clause");
+    assert_eq!(
+        fusion.strategy,
+        crate::velesql::FusionStrategyType::FieldBoost
+    );
+    assert_eq!(fusion.boost_field.as_deref(), Some("stats.rating"));
+    assert_eq!(fusion.boost_alpha, None);
+
+    let sql = "SELECT * FROM docs USING FUSION popularity(field = 'views')";
+    assert!(Parser::parse(sql).is_err());
+}
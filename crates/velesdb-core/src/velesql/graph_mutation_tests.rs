@@ -0,0 +1,124 @@
+//! Unit tests for graph mutation parsing (`CREATE` edges, `[DETACH] DELETE`).
+
+use crate::velesql::{
+    DmlStatement, GraphMutationAction, GraphMutationStatement, MutationNodeRef, Parser, Value,
+};
+
+fn parse_mutation(sql: &str) -> GraphMutationStatement {
+    let query = Parser::parse(sql).unwrap_or_else(|e| panic!("should parse {sql}: {e}"));
+    assert!(query.is_dml_query());
+    assert!(!query.is_match_query());
+    match query.dml {
+        Some(DmlStatement::GraphMutation(stmt)) => stmt,
+        other => panic!("Expected GraphMutation, got {other:?}"),
+    }
+}
+
+// ============================================================================
+// A. CREATE
+// ============================================================================
+
+#[test]
+fn test_parse_create_edge_by_ids() {
+    let stmt = parse_mutation("CREATE (1)-[:KNOWS {since: 2020}]->(2) IN social");
+
+    assert_eq!(stmt.collection.as_deref(), Some("social"));
+    assert!(stmt.patterns.is_empty());
+    let GraphMutationAction::CreateEdges(edges) = stmt.action else {
+        panic!("Expected CreateEdges");
+    };
+    assert_eq!(edges.len(), 1);
+    assert_eq!(edges[0].source, MutationNodeRef::Id(1));
+    assert_eq!(edges[0].target, MutationNodeRef::Id(2));
+    assert_eq!(edges[0].label, "KNOWS");
+    assert_eq!(
+        edges[0].properties,
+        vec![("since".to_string(), Value::Integer(2020))]
+    );
+}
+
+#[test]
+fn test_parse_create_edge_from_match() {
+    let stmt = parse_mutation(
+        "MATCH (a:Person {name: 'Alice'}), (b:Person {name: 'Bob'}) \
+         CREATE (a)-[:KNOWS]->(b), (b)<-[:FOLLOWS]-($fan)",
+    );
+
+    assert!(stmt.collection.is_none());
+    assert_eq!(stmt.patterns.len(), 2);
+    let GraphMutationAction::CreateEdges(edges) = stmt.action else {
+        panic!("Expected CreateEdges");
+    };
+    assert_eq!(edges[0].source, MutationNodeRef::Alias("a".to_string()));
+    assert_eq!(edges[0].target, MutationNodeRef::Alias("b".to_string()));
+    // `<-` swaps the endpoints.
+    assert_eq!(
+        edges[1].source,
+        MutationNodeRef::Parameter("fan".to_string())
+    );
+    assert_eq!(edges[1].target, MutationNodeRef::Alias("b".to_string()));
+    assert_eq!(edges[1].label, "FOLLOWS");
+}
+
+#[test]
+fn test_parse_create_edge_rejects_ambiguous_relationships() {
+    for sql in [
+        "CREATE (1)-[:KNOWS]-(2)",
+        "CREATE (1)-[]->(2)",
+        "CREATE (1)-[:KNOWS|LIKES]->(2)",
+        "CREATE (1)-[:KNOWS*1..2]->(2)",
+    ] {
+        assert!(Parser::parse(sql).is_err(), "{sql} should be rejected");
+    }
+}
+
+// ============================================================================
+// B. DELETE / DETACH DELETE
+// ============================================================================
+
+#[test]
+fn test_parse_delete_relationship() {
+    let stmt = parse_mutation("MATCH (a)-[r:KNOWS]->(b) WHERE r.since < 2000 DELETE r IN social");
+
+    assert_eq!(stmt.collection.as_deref(), Some("social"));
+    assert!(stmt.where_clause.is_some());
+    assert_eq!(
+        stmt.action,
+        GraphMutationAction::Delete {
+            aliases: vec!["r".to_string()],
+            detach: false,
+        }
+    );
+}
+
+#[test]
+fn test_parse_detach_delete_nodes() {
+    let stmt = parse_mutation("MATCH (n:Person {name: 'Bob'}) DETACH DELETE n");
+
+    assert_eq!(
+        stmt.action,
+        GraphMutationAction::Delete {
+            aliases: vec!["n".to_string()],
+            detach: true,
+        }
+    );
+}
+
+#[test]
+fn test_parse_graph_mutation_rejects_invalid_forms() {
+    for sql in [
+        // DELETE needs a MATCH to bind its aliases.
+        "DETACH DELETE n",
+        // WHERE is only supported with a single pattern.
+        "MATCH (a), (b) WHERE a.x = 1 CREATE (a)-[:R]->(b)",
+    ] {
+        assert!(Parser::parse(sql).is_err(), "{sql} should be rejected");
+    }
+}
+
+#[test]
+fn test_match_without_mutation_still_requires_return() {
+    assert!(Parser::parse("MATCH (a)-[r]->(b)").is_err());
+    let query = Parser::parse("MATCH (a)-[r]->(b) RETURN a").expect("should parse");
+    assert!(query.is_match_query());
+}
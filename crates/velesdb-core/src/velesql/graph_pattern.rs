@@ -23,6 +23,10 @@ pub struct MatchClause {
 pub struct GraphPattern {
     /// Optional path name (for `p = (a)-[*]->(b)`).
     pub name: Option<String>,
+    /// `shortestPath(...)`: match only a shortest path between each pair of
+    /// end nodes.
+    #[serde(default)]
+    pub shortest_path: bool,
     /// Nodes in the pattern.
     pub nodes: Vec<NodePattern>,
     /// Relationships between nodes.
@@ -139,6 +143,11 @@ pub struct ReturnClause {
     pub order_by: Option<Vec<OrderByItem>>,
     /// Optional LIMIT.
     pub limit: Option<u64>,
+    /// Alias of `LIMIT n PER <alias>`: LIMIT then caps the rows kept for
+    /// each distinct binding of this node or relationship alias instead of
+    /// the whole result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit_per: Option<String>,
 }
 
 /// A single item in the RETURN clause.
@@ -575,3 +575,127 @@ fn test_executor_groupby_nested_null_handling() {
         Some(2)
     );
 }
+
+// ========== Expression GROUP BY ==========
+
+/// Six orders over two days (epoch seconds), priced across three buckets.
+fn create_orders_collection() -> (Collection, tempfile::TempDir) {
+    let (collection, tmp) = create_test_collection();
+    // 2024-03-01T00:00:00Z
+    let day = 1_709_251_200_i64;
+    let orders = [
+        (1, day + 60, 5.0),
+        (2, day + 3_600, 12.5),
+        (3, day + 86_399, 18.0),
+        (4, day + 86_400, 21.0),
+        (5, day + 90_000, 9.99),
+        (6, day + 100_000, 10.0),
+    ];
+    let points: Vec<_> = orders
+        .into_iter()
+        .map(|(id, created_at, price)| Point {
+            id,
+            vector: vec![0.1; 4],
+            payload: Some(serde_json::json!({"created_at": created_at, "price": price})),
+            sparse_vectors: None,
+        })
+        .collect();
+    collection.upsert(points).unwrap();
+    (collection, tmp)
+}
+
+fn group_counts(result: &serde_json::Value, key: &str) -> Vec<(serde_json::Value, u64)> {
+    let mut counts: Vec<_> = result
+        .as_array()
+        .expect("Result should be array")
+        .iter()
+        .map(|g| (g[key].clone(), g["cnt"].as_u64().unwrap()))
+        .collect();
+    counts.sort_by_key(|(k, _)| k.to_string());
+    counts
+}
+
+#[test]
+fn test_parser_groupby_expression() {
+    let query = Parser::parse(
+        "SELECT COUNT(*) FROM orders GROUP BY date_trunc('day', created_at), floor(price / 10) * 10",
+    )
+    .unwrap();
+    let group_by = query.select.group_by.unwrap();
+    assert_eq!(group_by.columns.len(), 2);
+    assert_eq!(group_by.expressions.len(), 2);
+    assert_eq!(group_by.columns[0], "date_trunc('day', created_at)");
+    assert!(group_by.expression(&group_by.columns[1]).is_some());
+}
+
+#[test]
+fn test_parser_groupby_expression_takes_select_alias() {
+    let query = Parser::parse(
+        "SELECT date_trunc('day', created_at) AS day, COUNT(*) FROM orders GROUP BY day",
+    )
+    .unwrap();
+    let group_by = query.select.group_by.unwrap();
+    assert_eq!(group_by.columns, vec!["day".to_string()]);
+    assert!(group_by.expression("day").is_some());
+
+    let query = Parser::parse(
+        "SELECT bucket(price, 10) AS band, COUNT(*) FROM orders GROUP BY bucket(price, 10)",
+    )
+    .unwrap();
+    let group_by = query.select.group_by.unwrap();
+    assert_eq!(group_by.columns, vec!["band".to_string()]);
+}
+
+#[test]
+fn test_parser_groupby_expression_rejects_bad_calls() {
+    for sql in [
+        "SELECT COUNT(*) FROM orders GROUP BY date_trunc('fortnight', created_at)",
+        "SELECT COUNT(*) FROM orders GROUP BY date_trunc(created_at, 'day')",
+        "SELECT COUNT(*) FROM orders GROUP BY bucket(price)",
+    ] {
+        assert!(Parser::parse(sql).is_err(), "{sql} should not parse");
+    }
+}
+
+#[test]
+fn test_executor_groupby_date_trunc() {
+    let (collection, _tmp) = create_orders_collection();
+    let query = Parser::parse(
+        "SELECT date_trunc('day', created_at) AS day, COUNT(*) AS cnt FROM orders GROUP BY day",
+    )
+    .unwrap();
+    let result = collection
+        .execute_aggregate(&query, &HashMap::new())
+        .unwrap();
+    assert_eq!(
+        group_counts(&result, "day"),
+        vec![
+            (serde_json::json!(1_709_251_200_i64), 3),
+            (serde_json::json!(1_709_337_600_i64), 3),
+        ]
+    );
+}
+
+#[test]
+fn test_executor_groupby_numeric_bucket() {
+    let (collection, _tmp) = create_orders_collection();
+    for sql in [
+        "SELECT floor(price / 10) * 10 AS band, COUNT(*) AS cnt FROM orders \
+         GROUP BY floor(price / 10) * 10",
+        "SELECT bucket(price, 10) AS band, COUNT(*) AS cnt FROM orders GROUP BY band",
+    ] {
+        let query = Parser::parse(sql).unwrap();
+        let result = collection
+            .execute_aggregate(&query, &HashMap::new())
+            .unwrap();
+        assert_eq!(
+            group_counts(&result, "band"),
+            vec![
+                (serde_json::json!(0), 2),
+                (serde_json::json!(10), 3),
+                (serde_json::json!(20), 1),
+            ],
+            "{sql}"
+        );
+    }
+}
@@ -298,6 +298,9 @@ impl MatchQueryPlanner {
     }
 
     /// Estimate top-k based on limit and selectivity.
+    ///
+    /// `LIMIT n PER alias` bounds groups, not the result, so it is estimated
+    /// like a missing LIMIT.
     fn estimate_top_k(
         match_clause: &MatchClause,
         stats: &CollectionStats,
@@ -306,6 +309,7 @@ impl MatchQueryPlanner {
         let limit = match_clause
             .return_clause
             .limit
+            .filter(|_| match_clause.return_clause.limit_per.is_none())
             .and_then(|l| usize::try_from(l).ok())
             .unwrap_or(100);
         let selectivity = Self::estimate_selectivity(threshold);
@@ -400,7 +404,8 @@ fn column_targets_alias(column: &str, aliases: &[&str]) -> bool {
 /// can only honor an `ORDER BY` that *is* the start-node similarity (its natural
 /// output). An `ORDER BY` on a payload field, arithmetic, or aggregate requires
 /// every WHERE-matching node to be ranked, which only `GraphFirst`'s exact
-/// enumeration guarantees (backlog #1b). `Similarity`/`SimilarityBare` orderings
+/// enumeration guarantees (backlog #1b); so does `random()`, which must draw
+/// from every candidate. `Similarity`/`SimilarityBare` orderings
 /// stay on `VectorFirst` (no perf regression for the canonical top-K).
 fn order_by_needs_full_candidates(return_clause: &crate::velesql::ReturnClause) -> bool {
     use crate::velesql::OrderByExpr;
@@ -408,7 +413,10 @@ fn order_by_needs_full_candidates(return_clause: &crate::velesql::ReturnClause)
         items.iter().any(|item| {
             matches!(
                 item.expr,
-                OrderByExpr::Field(_) | OrderByExpr::Aggregate(_) | OrderByExpr::Arithmetic(_)
+                OrderByExpr::Field(_)
+                    | OrderByExpr::Aggregate(_)
+                    | OrderByExpr::Arithmetic(_)
+                    | OrderByExpr::Random
             )
         })
     })
@@ -421,16 +429,18 @@ fn order_by_expr_targets_alias(expr: &crate::velesql::OrderByExpr, aliases: &[&s
     match expr {
         OrderByExpr::Field(f) => column_targets_alias(f, aliases),
         OrderByExpr::Similarity(s) => column_targets_alias(&s.field, aliases),
-        OrderByExpr::SimilarityBare | OrderByExpr::Aggregate(_) | OrderByExpr::Arithmetic(_) => {
-            false
-        }
+        OrderByExpr::SimilarityBare
+        | OrderByExpr::Aggregate(_)
+        | OrderByExpr::Arithmetic(_)
+        | OrderByExpr::Random => false,
     }
 }
 
 /// Extracts the column name from a metadata condition variant.
 ///
 /// Returns `Some(&str)` for condition types that carry a `column` field.
-/// Non-metadata variants return `None`. Persistence-free helper shared by the
+/// Non-metadata variants return `None`; a scalar expression reports its first
+/// field reference, so it evaluates against that field's node. Persistence-free helper shared by the
 /// MATCH planner and the collection WHERE-evaluator (relocated here in P1.4).
 pub(crate) fn column_of_metadata_condition(condition: &Condition) -> Option<&str> {
     match condition {
@@ -443,6 +453,12 @@ pub(crate) fn column_of_metadata_condition(condition: &Condition) -> Option<&str
         Condition::Contains(c) => Some(&c.column),
         Condition::GeoDistance(gd) => Some(&gd.column),
         Condition::GeoBbox(gb) => Some(&gb.column),
+        Condition::Expression(ec) => ec
+            .left
+            .variables()
+            .into_iter()
+            .chain(ec.right.variables())
+            .next(),
         _ => None,
     }
 }
@@ -20,6 +20,7 @@ fn make_match_clause(has_similarity: bool, limit: Option<u64>) -> MatchClause {
     MatchClause {
         patterns: vec![GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![NodePattern {
                 alias: Some("a".to_string()),
                 labels: vec!["Person".to_string()],
@@ -48,6 +49,7 @@ fn make_match_clause(has_similarity: bool, limit: Option<u64>) -> MatchClause {
             items: vec![],
             order_by: None,
             limit,
+            limit_per: None,
         },
     }
 }
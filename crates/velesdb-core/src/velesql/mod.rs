@@ -20,6 +20,9 @@
 //! ```
 #![allow(clippy::doc_markdown, clippy::uninlined_format_args)]
 
+mod aggregate_sketch;
+#[cfg(test)]
+mod aggregate_sketch_tests;
 #[cfg(test)]
 mod aggregation_executor_tests;
 #[cfg(test)]
@@ -55,6 +58,8 @@ mod explain;
 // itself is now persistence-free (P1.4).
 #[cfg(all(test, feature = "persistence"))]
 mod explain_tests;
+#[cfg(test)]
+mod graph_mutation_tests;
 mod graph_pattern;
 #[cfg(test)]
 mod graph_pattern_tests;
@@ -185,7 +190,10 @@ pub use ast::{
     CorrelatedColumn,
     CreateCollectionKind,
     CreateCollectionStatement,
+    // Graph mutations (CREATE / [DETACH] DELETE)
+    CreateEdgePattern,
     CreateIndexStatement,
+    DateTruncUnit,
     DdlStatement,
     // DML (used by database execute_dml)
     DeleteEdgeStatement,
@@ -196,6 +204,7 @@ pub use ast::{
     DmlStatement,
     DropCollectionStatement,
     DropIndexStatement,
+    ExpressionCondition,
     // Fusion
     FlushStatement,
     FusionClause,
@@ -206,8 +215,11 @@ pub use ast::{
     GeoDistanceCondition,
     GraphCollectionParams,
     GraphMatchPredicate,
+    GraphMutationAction,
+    GraphMutationStatement,
     GraphSchemaMode,
     GroupByClause,
+    GroupByExpression,
     HavingClause,
     HavingCondition,
     InCondition,
@@ -227,15 +239,19 @@ pub use ast::{
     LikeCondition,
     LogicalOp,
     MatchCondition,
+    MutationNodeRef,
     OrderByExpr,
     // Window functions (Issue #386)
     OverClause,
     // WITH clause
     QuantizationMode,
     Query,
+    QueryHint,
+    ScalarFunction,
     SchemaDefinition,
     SelectColumns,
     SelectEdgesStatement,
+    SelectExpression,
     SelectOrderBy,
     SelectStatement,
     SetOperator,
@@ -361,3 +361,25 @@ fn test_parse_order_by_subtraction() {
         other => panic!("Expected Arithmetic(BinaryOp(Sub)), got {other:?}"),
     }
 }
+
+#[test]
+fn test_orderby_collate_per_key() {
+    let sql =
+        "SELECT * FROM people ORDER BY last_name COLLATE ci_ai DESC, first_name COLLATE CI, age";
+    let query = Parser::parse(sql).expect("COLLATE should parse");
+    let order_by = query.select.order_by.expect("ORDER BY should be present");
+
+    assert_eq!(order_by[0].collation, crate::SortCollation::CiAi);
+    assert!(order_by[0].descending);
+    assert_eq!(order_by[1].collation, crate::SortCollation::Ci);
+    assert!(!order_by[1].descending);
+    assert_eq!(order_by[2].collation, crate::SortCollation::Binary);
+    assert_eq!(
+        order_by[0].to_display_pair(),
+        ("last_name COLLATE ci_ai".to_string(), "DESC".to_string())
+    );
+
+    let err = Parser::parse("SELECT * FROM people ORDER BY name COLLATE fr_FR")
+        .expect_err("unknown collation must be rejected");
+    assert!(err.to_string().contains("collation"), "{err}");
+}
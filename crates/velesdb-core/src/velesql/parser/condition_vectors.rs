@@ -12,17 +12,41 @@ impl Parser {
         pair: pest::iterators::Pair<Rule>,
     ) -> Result<Condition, ParseError> {
         let mut vector = None;
+        let mut avoid = Vec::new();
 
         for inner in pair.into_inner() {
-            if inner.as_rule() == Rule::vector_value {
-                vector = Some(Self::parse_vector_value(inner)?);
+            match inner.as_rule() {
+                Rule::vector_value => vector = Some(Self::parse_vector_value(inner)?),
+                Rule::avoid_clause => avoid = Self::parse_avoid_clause(inner)?,
+                _ => {}
             }
         }
 
         let vector =
             vector.ok_or_else(|| ParseError::syntax(0, "", "Expected vector expression"))?;
 
-        Ok(Condition::VectorSearch(VectorSearch { vector }))
+        Ok(Condition::VectorSearch(VectorSearch { vector, avoid }))
+    }
+
+    /// Parses `AVOID $neg` or `AVOID [$n1, $n2]` into its negative vectors.
+    fn parse_avoid_clause(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<Vec<VectorExpr>, ParseError> {
+        let mut avoid = Vec::new();
+        for inner in pair.into_inner() {
+            match inner.as_rule() {
+                Rule::vector_value => avoid.push(Self::parse_vector_value(inner)?),
+                Rule::vector_array => {
+                    for vec_value in inner.into_inner() {
+                        if vec_value.as_rule() == Rule::vector_value {
+                            avoid.push(Self::parse_vector_value(vec_value)?);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(avoid)
     }
 
     /// Parses a sparse vector search: `vector SPARSE_NEAR sparse_value [USING 'index-name']`
@@ -14,9 +14,19 @@ impl Parser {
     const MAX_CONDITION_DEPTH: usize = 256;
 
     pub(super) fn extract_column_name(pair: &pest::iterators::Pair<'_, Rule>) -> String {
+        if pair.as_rule() == Rule::json_extract {
+            return Self::json_extract_path(pair);
+        }
         if pair.as_rule() != Rule::column_name && pair.as_rule() != Rule::where_column {
             return extract_identifier(pair);
         }
+        if let Some(json) = pair
+            .clone()
+            .into_inner()
+            .find(|p| p.as_rule() == Rule::json_extract)
+        {
+            return Self::json_extract_path(&json);
+        }
 
         let parts: Vec<String> = pair
             .clone()
@@ -32,6 +42,17 @@ impl Parser {
         }
     }
 
+    /// Lowers `json_extract(payload, '$.a.items[0]')` to the dotted path
+    /// `a.items.0` resolved by the payload lookups.
+    pub(super) fn json_extract_path(pair: &pest::iterators::Pair<'_, Rule>) -> String {
+        pair.clone()
+            .into_inner()
+            .flat_map(pest::iterators::Pair::into_inner)
+            .map(|step| step.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
     pub(crate) fn parse_where_clause(
         pair: pest::iterators::Pair<Rule>,
     ) -> Result<Condition, ParseError> {
@@ -153,6 +174,7 @@ impl Parser {
             Rule::geo_distance_expr => Self::parse_geo_distance_expr(inner),
             Rule::geo_bbox_expr => Self::parse_geo_bbox_expr(inner),
             Rule::compare_expr => Self::parse_compare_expr(inner),
+            Rule::expr_compare => Self::parse_expr_compare(inner),
             _ => Err(ParseError::syntax(
                 0,
                 inner.as_str(),
@@ -239,19 +261,11 @@ impl Parser {
             inner.next(); // consume the not_kw token
         }
 
-        let value_list = inner
-            .find(|p| p.as_rule() == Rule::value_list)
-            .ok_or_else(|| ParseError::syntax(0, "", "Expected value list"))?;
-
-        let values: Result<Vec<_>, _> = value_list
-            .into_inner()
-            .filter(|p| p.as_rule() == Rule::value)
-            .map(Self::parse_value)
-            .collect();
+        let values = Self::collect_value_list(&mut inner, "")?;
 
         Ok(Condition::In(InCondition {
             column,
-            values: values?,
+            values,
             negated,
         }))
     }
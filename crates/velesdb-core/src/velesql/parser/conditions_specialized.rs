@@ -3,7 +3,7 @@
 //! These leaf-level condition parsers are separated from the core condition
 //! dispatch tree (`conditions.rs`) to keep file NLOC under 500.
 
-use super::helpers::compare_op_from_str;
+use super::helpers::{compare_op_from_str, parse_scalar_from_rule};
 use super::Rule;
 use crate::velesql::ast::{
     Condition, ContainsCondition, ContainsMode, GeoBboxCondition, GeoDistanceCondition,
@@ -65,8 +65,8 @@ impl Parser {
     /// Parses a CONTAINS expression into a `Condition::Contains`.
     ///
     /// Handles three forms:
-    /// - `column CONTAINS ALL (v1, v2, ...)`
-    /// - `column CONTAINS ANY (v1, v2, ...)`
+    /// - `column CONTAINS ALL (v1, v2, ...)` or `column CONTAINS ALL $list`
+    /// - `column CONTAINS ANY (v1, v2, ...)` or `column CONTAINS ANY $list`
     /// - `column CONTAINS value`
     pub(crate) fn parse_contains_expr(
         pair: pest::iterators::Pair<Rule>,
@@ -102,17 +102,23 @@ impl Parser {
         }
     }
 
-    /// Collects values from a `value_list` rule in the parse tree.
-    fn collect_value_list(
+    /// Collects values from an `in_list`: a parenthesized `value_list`, or a
+    /// bare `$name` kept as a single [`Value::Parameter`] that the executor
+    /// expands from a JSON array.
+    ///
+    /// [`Value::Parameter`]: crate::velesql::ast::Value::Parameter
+    pub(crate) fn collect_value_list(
         inner: &mut pest::iterators::Pairs<Rule>,
         raw: &str,
     ) -> Result<Vec<crate::velesql::ast::Value>, ParseError> {
-        let value_list = inner
-            .find(|p| p.as_rule() == Rule::value_list)
+        let list = inner
+            .find(|p| matches!(p.as_rule(), Rule::value_list | Rule::parameter))
             .ok_or_else(|| ParseError::syntax(0, raw, "Expected value list"))?;
 
-        value_list
-            .into_inner()
+        if list.as_rule() == Rule::parameter {
+            return Ok(vec![parse_scalar_from_rule(&list)?]);
+        }
+        list.into_inner()
             .filter(|p| p.as_rule() == Rule::value)
             .map(Self::parse_value)
             .collect()
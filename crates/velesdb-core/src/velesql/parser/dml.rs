@@ -137,8 +137,23 @@ impl Parser {
         let value_pair = inner
             .next()
             .ok_or_else(|| ParseError::syntax(0, "", "UPDATE assignment missing value"))?;
+        if value_pair.as_rule() == Rule::update_arithmetic {
+            let additive = value_pair
+                .into_inner()
+                .next()
+                .ok_or_else(|| ParseError::syntax(0, "", "UPDATE assignment missing value"))?;
+            return Ok(UpdateAssignment {
+                column,
+                value: Value::Null,
+                expr: Some(Self::parse_arithmetic_additive(additive)?),
+            });
+        }
         let value = Self::parse_value(value_pair)?;
-        Ok(UpdateAssignment { column, value })
+        Ok(UpdateAssignment {
+            column,
+            value,
+            expr: None,
+        })
     }
 
     /// Parses an `INSERT EDGE` statement.
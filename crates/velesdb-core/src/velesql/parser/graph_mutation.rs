@@ -0,0 +1,172 @@
+//! Graph mutation parsing (`[MATCH ...] CREATE (a)-[:TYPE]->(b)`,
+//! `MATCH ... [DETACH] DELETE alias`).
+
+use super::{extract_identifier, Rule};
+use crate::velesql::ast::{
+    CreateEdgePattern, DmlStatement, GraphMutationAction, GraphMutationStatement, MutationNodeRef,
+    Query,
+};
+use crate::velesql::error::ParseError;
+use crate::velesql::{Direction, Parser};
+
+impl Parser {
+    /// Parses a graph mutation statement.
+    ///
+    /// Grammar:
+    /// ```text
+    /// [MATCH pattern, ... [WHERE cond]] CREATE (a)-[:TYPE {k: v}]->(b), ... [IN collection]
+    /// MATCH pattern, ... [WHERE cond] [DETACH] DELETE alias, ... [IN collection]
+    /// ```
+    pub(crate) fn parse_graph_mutation_stmt(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<Query, ParseError> {
+        let mut collection = None;
+        let mut patterns = Vec::new();
+        let mut where_clause = None;
+        let mut action = None;
+
+        for inner in pair.into_inner() {
+            match inner.as_rule() {
+                Rule::mutation_match => {
+                    for part in inner.into_inner() {
+                        match part.as_rule() {
+                            Rule::graph_pattern => patterns.push(Self::parse_graph_pattern(part)?),
+                            Rule::where_clause => {
+                                where_clause = Some(Self::parse_where_clause(part)?);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Rule::create_edges_clause => {
+                    let edges = inner
+                        .into_inner()
+                        .filter(|p| p.as_rule() == Rule::create_edge)
+                        .map(Self::parse_create_edge)
+                        .collect::<Result<_, _>>()?;
+                    action = Some(GraphMutationAction::CreateEdges(edges));
+                }
+                Rule::delete_aliases_clause => action = Some(parse_delete_aliases(inner)),
+                Rule::mutation_target => {
+                    collection = inner
+                        .into_inner()
+                        .find(|p| p.as_rule() == Rule::identifier)
+                        .map(|p| extract_identifier(&p));
+                }
+                _ => {}
+            }
+        }
+
+        let action = action
+            .ok_or_else(|| ParseError::syntax(0, "", "Graph mutation requires CREATE or DELETE"))?;
+        if where_clause.is_some() && patterns.len() > 1 {
+            return Err(ParseError::syntax(
+                0,
+                "",
+                "WHERE in a graph mutation requires a single MATCH pattern",
+            ));
+        }
+
+        Ok(Query::new_dml(DmlStatement::GraphMutation(
+            GraphMutationStatement {
+                collection,
+                patterns,
+                where_clause,
+                action,
+            },
+        )))
+    }
+
+    /// Parses one `(a)-[:TYPE {k: v}]->(b)` edge of a CREATE clause.
+    ///
+    /// The relationship must be directed, name exactly one type and have a
+    /// fixed length; `<-` swaps source and target.
+    fn parse_create_edge(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<CreateEdgePattern, ParseError> {
+        let text = pair.as_str().to_string();
+        let mut nodes = Vec::new();
+        let mut rel = None;
+        for inner in pair.into_inner() {
+            match inner.as_rule() {
+                Rule::mutation_node => nodes.push(parse_mutation_node(inner)?),
+                Rule::relationship_pattern => rel = Some(Self::parse_relationship_pattern(inner)?),
+                _ => {}
+            }
+        }
+
+        let (Some(rel), Ok([left, right])) = (rel, <[MutationNodeRef; 2]>::try_from(nodes)) else {
+            return Err(ParseError::syntax(
+                0,
+                &text,
+                "CREATE expects (a)-[:TYPE]->(b)",
+            ));
+        };
+        let (source, target) = match rel.direction {
+            Direction::Outgoing => (left, right),
+            Direction::Incoming => (right, left),
+            Direction::Both => {
+                return Err(ParseError::syntax(
+                    0,
+                    &text,
+                    "CREATE requires a directed relationship (-> or <-)",
+                ));
+            }
+        };
+        let [label] = <[String; 1]>::try_from(rel.types).map_err(|_| {
+            ParseError::syntax(0, &text, "CREATE requires exactly one relationship type")
+        })?;
+        if rel.range.is_some() {
+            return Err(ParseError::syntax(
+                0,
+                &text,
+                "CREATE does not accept a variable-length relationship",
+            ));
+        }
+
+        let mut properties: Vec<_> = rel.properties.into_iter().collect();
+        properties.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(CreateEdgePattern {
+            source,
+            target,
+            label,
+            properties,
+        })
+    }
+}
+
+/// Parses a CREATE endpoint: `(42)`, `($id)` or `(alias)`.
+fn parse_mutation_node(pair: pest::iterators::Pair<Rule>) -> Result<MutationNodeRef, ParseError> {
+    let Some(inner) = pair.into_inner().next() else {
+        return Err(ParseError::syntax(
+            0,
+            "",
+            "CREATE endpoint must not be empty",
+        ));
+    };
+    let raw = inner.as_str();
+    match inner.as_rule() {
+        Rule::integer => raw
+            .parse()
+            .map(MutationNodeRef::Id)
+            .map_err(|_| ParseError::syntax(0, raw, "Node ID must be a non-negative integer")),
+        Rule::parameter => Ok(MutationNodeRef::Parameter(
+            raw.trim_start_matches('$').to_string(),
+        )),
+        _ => Ok(MutationNodeRef::Alias(raw.to_string())),
+    }
+}
+
+/// Parses `[DETACH] DELETE alias, ...`.
+fn parse_delete_aliases(pair: pest::iterators::Pair<Rule>) -> GraphMutationAction {
+    let mut aliases = Vec::new();
+    let mut detach = false;
+    for inner in pair.into_inner() {
+        match inner.as_rule() {
+            Rule::detach_kw => detach = true,
+            Rule::identifier => aliases.push(extract_identifier(&inner)),
+            _ => {}
+        }
+    }
+    GraphMutationAction::Delete { aliases, detach }
+}
@@ -19,6 +19,7 @@ const KEYWORDS: &[&str] = &[
     "UPSERT",
     "UPDATE",
     "DELETE",
+    "DETACH",
     "INTO",
     "VALUES",
     "CREATE",
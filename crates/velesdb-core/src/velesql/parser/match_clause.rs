@@ -166,10 +166,18 @@ fn find_operator(input: &str, op: &str) -> Option<usize> {
 }
 
 fn parse_return_clause(input: &str) -> ReturnClause {
-    let (is, limit) = if let Some(lp) = find_keyword(input, "LIMIT") {
-        (&input[..lp], input[lp + 5..].trim().parse().ok())
+    let (is, limit, limit_per) = if let Some(lp) = find_keyword(input, "LIMIT") {
+        let limit_str = &input[lp + 5..];
+        let (count, per) = match find_keyword(limit_str, "PER") {
+            Some(pp) => (
+                &limit_str[..pp],
+                Some(limit_str[pp + 3..].trim().to_string()),
+            ),
+            None => (limit_str, None),
+        };
+        (&input[..lp], count.trim().parse().ok(), per)
     } else {
-        (input, None)
+        (input, None, None)
     };
     let items = is
         .split(',')
@@ -192,6 +200,7 @@ fn parse_return_clause(input: &str) -> ReturnClause {
         items,
         order_by: None,
         limit,
+        limit_per,
     }
 }
 
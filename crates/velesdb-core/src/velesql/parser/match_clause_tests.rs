@@ -95,6 +95,15 @@ fn test_parse_match_simple() {
     assert_eq!(mc.patterns[0].relationships.len(), 1);
 }
 
+#[test]
+fn test_parse_match_limit_per() {
+    let mc = parse_match_clause("MATCH (d:Doc)-[:CITES]->(c) RETURN d, c LIMIT 3 PER d")
+        .expect("LIMIT ... PER parses");
+    assert_eq!(mc.return_clause.limit, Some(3));
+    assert_eq!(mc.return_clause.limit_per.as_deref(), Some("d"));
+    assert_eq!(mc.return_clause.items.len(), 2);
+}
+
 #[test]
 fn test_parse_match_with_where() {
     let result = parse_match_clause("MATCH (p:Person)-[:WROTE]->(a) WHERE p.age > 18 RETURN a");
@@ -109,6 +118,16 @@ fn test_parse_match_named_path() {
     assert_eq!(result.unwrap().patterns[0].name, Some("path".to_string()));
 }
 
+#[test]
+fn test_parse_match_shortest_path() {
+    let mc = parse_match_clause("MATCH p = shortestPath((a)-[:KNOWS*..4]->(b)) RETURN p").unwrap();
+    let pattern = &mc.patterns[0];
+    assert!(pattern.shortest_path);
+    assert_eq!(pattern.name, Some("p".to_string()));
+    assert_eq!(pattern.nodes.len(), 2);
+    assert_eq!(pattern.relationships[0].range, Some((1, 4)));
+}
+
 #[test]
 fn test_error_missing_return() {
     assert!(parse_match_clause("MATCH (n:Person)").is_err());
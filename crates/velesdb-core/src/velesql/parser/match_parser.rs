@@ -22,8 +22,10 @@ impl Parser {
             items: Vec::new(),
             order_by: None,
             limit: None,
+            limit_per: None,
         };
         let mut limit = None;
+        let mut limit_per = None;
 
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
@@ -34,11 +36,18 @@ impl Parser {
                     return_clause.order_by = Some(Self::convert_order_by_to_match(inner_pair)?);
                 }
                 Rule::limit_clause => limit = Self::extract_limit_integer(inner_pair),
+                Rule::limit_per_clause => {
+                    limit_per = inner_pair
+                        .into_inner()
+                        .find(|p| p.as_rule() == Rule::identifier)
+                        .map(|p| extract_identifier(&p));
+                }
                 _ => {}
             }
         }
 
         return_clause.limit = limit;
+        return_clause.limit_per = limit_per;
 
         Ok(Query::new_match(MatchClause {
             patterns,
@@ -79,6 +88,11 @@ impl Parser {
 
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
+                Rule::shortest_path_pattern => {
+                    let mut pattern = Self::parse_graph_pattern(inner_pair)?;
+                    pattern.shortest_path = true;
+                    return Ok(pattern);
+                }
                 Rule::node_pattern => {
                     nodes.push(Self::parse_node_pattern(inner_pair)?);
                 }
@@ -91,6 +105,7 @@ impl Parser {
 
         Ok(GraphPattern {
             name: None,
+            shortest_path: false,
             nodes,
             relationships,
         })
@@ -219,7 +234,7 @@ impl Parser {
     }
 
     /// Parse a relationship pattern (EPIC-045 US-001).
-    fn parse_relationship_pattern(
+    pub(super) fn parse_relationship_pattern(
         pair: pest::iterators::Pair<Rule>,
     ) -> Result<RelationshipPattern, ParseError> {
         let mut direction = Direction::Outgoing;
@@ -341,6 +356,7 @@ impl Parser {
             items,
             order_by: None,
             limit: None,
+            limit_per: None,
         })
     }
 
@@ -235,11 +235,30 @@ pub(super) fn parse_pattern_list(input: &str) -> Result<Vec<GraphPattern>, Parse
     } else {
         (None, input)
     };
-    let mut pattern = parse_path_pattern(ps)?;
+    let mut pattern = match strip_shortest_path(ps) {
+        Some(inner) => GraphPattern {
+            shortest_path: true,
+            ..parse_path_pattern(inner)?
+        },
+        None => parse_path_pattern(ps)?,
+    };
     pattern.name = name;
     Ok(vec![pattern])
 }
 
+/// Returns the inner path of a `shortestPath(...)` pattern.
+fn strip_shortest_path(input: &str) -> Option<&str> {
+    const KEYWORD: &str = "shortestPath";
+    let head = input.get(..KEYWORD.len())?;
+    if !head.eq_ignore_ascii_case(KEYWORD) {
+        return None;
+    }
+    input[KEYWORD.len()..]
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
 fn parse_path_pattern(input: &str) -> Result<GraphPattern, ParseError> {
     let mut nodes = Vec::new();
     let mut rels = Vec::new();
@@ -257,6 +276,7 @@ fn parse_path_pattern(input: &str) -> Result<GraphPattern, ParseError> {
     }
     Ok(GraphPattern {
         name: None,
+        shortest_path: false,
         nodes,
         relationships: rels,
     })
@@ -58,6 +58,23 @@ fn test_parse_match_with_limit() {
     assert_eq!(mc.return_clause.limit, Some(10));
 }
 
+#[test]
+fn test_parse_match_with_limit_per() {
+    let query = Parser::parse("MATCH (d:Doc)-[:CITES]->(c) RETURN d, c LIMIT 3 PER d").unwrap();
+    let mc = query.match_clause.unwrap();
+    assert_eq!(mc.return_clause.limit, Some(3));
+    assert_eq!(mc.return_clause.limit_per.as_deref(), Some("d"));
+
+    let mc = Parser::parse("MATCH (n) RETURN n LIMIT 3")
+        .unwrap()
+        .match_clause
+        .unwrap();
+    assert_eq!(mc.return_clause.limit_per, None);
+    // PER needs a LIMIT and an alias.
+    assert!(Parser::parse("MATCH (d)-[:CITES]->(c) RETURN d PER d").is_err());
+    assert!(Parser::parse("MATCH (d)-[:CITES]->(c) RETURN d LIMIT 3 PER").is_err());
+}
+
 #[test]
 fn test_parse_match_with_properties() {
     let query = Parser::parse("MATCH (n:Person {name: 'Alice', age: 30}) RETURN n").unwrap();
@@ -140,3 +157,25 @@ fn test_parse_match_chain() {
     assert_eq!(mc.patterns[0].nodes.len(), 3);
     assert_eq!(mc.patterns[0].relationships.len(), 2);
 }
+
+#[test]
+fn test_parse_match_shortest_path() {
+    let query =
+        Parser::parse("MATCH shortestPath((a {name: 'x'})-[r:KNOWS*..6]-(b:Person)) RETURN r")
+            .unwrap();
+    let mc = query.match_clause.unwrap();
+    let pattern = &mc.patterns[0];
+    assert!(pattern.shortest_path);
+    assert_eq!(pattern.nodes.len(), 2);
+    assert_eq!(pattern.relationships[0].range, Some((1, 6)));
+    assert_eq!(pattern.relationships[0].direction, Direction::Both);
+
+    let plain = Parser::parse("MATCH (a)-[:KNOWS*..6]-(b) RETURN b").unwrap();
+    assert!(!plain.match_clause.unwrap().patterns[0].shortest_path);
+}
+
+#[test]
+fn test_parse_match_shortest_path_requires_single_relationship() {
+    assert!(Parser::parse("MATCH shortestPath((a)-[:R]->(b)-[:R]->(c)) RETURN c").is_err());
+    assert!(Parser::parse("MATCH shortestPath((a)) RETURN a").is_err());
+}
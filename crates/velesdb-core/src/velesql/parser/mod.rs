@@ -8,11 +8,13 @@ mod ddl;
 mod ddl_helpers;
 mod dml;
 mod dml_helpers;
+mod graph_mutation;
 pub(crate) mod helpers;
 mod hints;
 mod introspection;
 mod match_parser;
 mod prescan;
+mod scalar_expr;
 mod select;
 mod train;
 mod values;
@@ -27,6 +29,8 @@ mod match_query_tests;
 #[cfg(test)]
 mod robustness_tests;
 #[cfg(test)]
+mod scalar_expr_tests;
+#[cfg(test)]
 mod sparse_search_tests;
 #[cfg(test)]
 mod subquery_tests;
@@ -0,0 +1,150 @@
+//! Scalar expression parsing for WHERE comparisons and SELECT items.
+//!
+//! Scalar expressions reuse [`ArithmeticExpr`] and extend the ORDER BY
+//! arithmetic grammar with string literals, dotted payload paths, the `||`
+//! concatenation operator and built-in [`ScalarFunction`] calls.
+
+use super::helpers::{compare_op_from_str, unescape_string_literal};
+use super::Rule;
+use crate::velesql::ast::{
+    ArithmeticExpr, Condition, DateTruncUnit, ExpressionCondition, ScalarFunction, SelectExpression,
+};
+use crate::velesql::error::ParseError;
+use crate::velesql::Parser;
+
+impl Parser {
+    /// Parses `scalar_expr`: `||` chains of additive expressions.
+    pub(super) fn parse_scalar_expr(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<ArithmeticExpr, ParseError> {
+        Self::parse_arithmetic_binary_chain(pair, "concatenation", Self::parse_scalar_additive)
+    }
+
+    fn parse_scalar_additive(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<ArithmeticExpr, ParseError> {
+        Self::parse_arithmetic_binary_chain(pair, "additive", Self::parse_scalar_multiplicative)
+    }
+
+    fn parse_scalar_multiplicative(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<ArithmeticExpr, ParseError> {
+        Self::parse_arithmetic_binary_chain(pair, "multiplicative", Self::parse_scalar_atom)
+    }
+
+    /// Parses a `scalar_atom`: number, string, payload path, or parenthesized.
+    fn parse_scalar_atom(pair: pest::iterators::Pair<Rule>) -> Result<ArithmeticExpr, ParseError> {
+        let inner = pair
+            .into_inner()
+            .next()
+            .ok_or_else(|| ParseError::syntax(0, "", "Empty scalar expression"))?;
+        match inner.as_rule() {
+            Rule::float | Rule::integer => {
+                let val: f64 = inner.as_str().parse().map_err(|_| {
+                    ParseError::syntax(0, inner.as_str(), "Invalid numeric literal")
+                })?;
+                Ok(ArithmeticExpr::Literal(val))
+            }
+            Rule::string => Ok(ArithmeticExpr::Text(unescape_string_literal(
+                inner.as_str(),
+            ))),
+            Rule::scalar_expr => Self::parse_scalar_expr(inner),
+            Rule::scalar_function => Self::parse_scalar_function(inner),
+            Rule::where_column => Ok(ArithmeticExpr::Variable(Self::extract_column_name(&inner))),
+            _ => Err(ParseError::syntax(
+                0,
+                inner.as_str(),
+                "Invalid scalar expression operand",
+            )),
+        }
+    }
+
+    /// Parses a `scalar_function` call, checking its arity. `date_trunc`
+    /// takes its unit as a leading string literal.
+    fn parse_scalar_function(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<ArithmeticExpr, ParseError> {
+        let start = pair.as_span().start();
+        let mut inner = pair.into_inner();
+        let name = inner
+            .next()
+            .ok_or_else(|| ParseError::syntax(start, "", "Expected function name"))?
+            .as_str()
+            .to_ascii_lowercase();
+        let mut args = inner
+            .map(Self::parse_scalar_expr)
+            .collect::<Result<Vec<_>, _>>()?;
+        let function = match name.as_str() {
+            "floor" => ScalarFunction::Floor,
+            "ceil" => ScalarFunction::Ceil,
+            "bucket" => ScalarFunction::Bucket,
+            _ => {
+                let unit = match args.first() {
+                    Some(ArithmeticExpr::Text(unit)) => DateTruncUnit::parse(unit),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    ParseError::syntax(
+                        start,
+                        &name,
+                        "date_trunc expects a unit literal: 'second', 'minute', 'hour', 'day', \
+                         'week', 'month', 'quarter' or 'year'",
+                    )
+                })?;
+                args.remove(0);
+                ScalarFunction::DateTrunc(unit)
+            }
+        };
+        if args.len() != function.arity() {
+            // Count date_trunc's unit as written in the query.
+            let unit = usize::from(matches!(function, ScalarFunction::DateTrunc(_)));
+            return Err(ParseError::syntax(
+                start,
+                &name,
+                format!(
+                    "{name}() takes {} argument(s), got {}",
+                    function.arity() + unit,
+                    args.len() + unit
+                ),
+            ));
+        }
+        Ok(ArithmeticExpr::Function { function, args })
+    }
+
+    /// Parses `expr_compare`: `scalar_expr compare_op scalar_expr`.
+    pub(super) fn parse_expr_compare(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<Condition, ParseError> {
+        let mut inner = pair.into_inner();
+        let mut next = |what: &str| {
+            inner
+                .next()
+                .ok_or_else(|| ParseError::syntax(0, "", format!("Expected {what}")))
+        };
+        let left = Self::parse_scalar_expr(next("left expression")?)?;
+        let operator = compare_op_from_str(next("operator")?.as_str())?;
+        let right = Self::parse_scalar_expr(next("right expression")?)?;
+        Ok(Condition::Expression(ExpressionCondition {
+            left,
+            operator,
+            right,
+        }))
+    }
+
+    /// Parses an `expression_item` in the SELECT list.
+    pub(super) fn parse_expression_item(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<SelectExpression, ParseError> {
+        let mut expr = None;
+        let mut alias = None;
+        for inner in pair.into_inner() {
+            match inner.as_rule() {
+                Rule::scalar_expr => expr = Some(Self::parse_scalar_expr(inner)?),
+                Rule::identifier => alias = Some(super::extract_identifier(&inner)),
+                _ => {}
+            }
+        }
+        let expr = expr.ok_or_else(|| ParseError::syntax(0, "", "Expected expression"))?;
+        Ok(SelectExpression { expr, alias })
+    }
+}
@@ -0,0 +1,136 @@
+//! Tests for scalar expression parsing in WHERE and SELECT.
+
+use crate::velesql::ast::{
+    ArithmeticExpr, ArithmeticOp, CompareOp, Condition, SelectColumns, SelectExpression,
+};
+use crate::velesql::Parser;
+
+fn where_clause(query: &str) -> Condition {
+    Parser::parse(query)
+        .unwrap_or_else(|e| panic!("{query} should parse: {e}"))
+        .select
+        .where_clause
+        .expect("WHERE clause")
+}
+
+fn expressions(query: &str) -> Vec<SelectExpression> {
+    let parsed = Parser::parse(query).unwrap_or_else(|e| panic!("{query} should parse: {e}"));
+    match parsed.select.columns {
+        SelectColumns::Mixed { expressions, .. } => expressions,
+        other => panic!("expected Mixed columns, got {other:?}"),
+    }
+}
+
+fn var(name: &str) -> ArithmeticExpr {
+    ArithmeticExpr::Variable(name.to_string())
+}
+
+#[test]
+fn test_where_product_compared_to_literal() {
+    let Condition::Expression(ec) =
+        where_clause("SELECT * FROM orders WHERE price * quantity > 1000")
+    else {
+        panic!("expected an expression condition");
+    };
+    assert_eq!(
+        ec.left,
+        ArithmeticExpr::BinaryOp {
+            left: Box::new(var("price")),
+            op: ArithmeticOp::Mul,
+            right: Box::new(var("quantity")),
+        }
+    );
+    assert_eq!(ec.operator, CompareOp::Gt);
+    assert_eq!(ec.right, ArithmeticExpr::Literal(1000.0));
+}
+
+#[test]
+fn test_where_expression_on_right_hand_side() {
+    let cond = where_clause("SELECT * FROM orders WHERE total = price * 2");
+    let Condition::Expression(ec) = cond else {
+        panic!("expected an expression condition, got {cond:?}");
+    };
+    assert_eq!(ec.left, ArithmeticExpr::Variable("total".to_string()));
+    assert_eq!(ec.right.to_string(), "(price * 2)");
+}
+
+#[test]
+fn test_plain_comparison_still_parses_as_comparison() {
+    let cond = where_clause("SELECT * FROM orders WHERE price > 10 AND qty = -3");
+    let Condition::And(left, right) = cond else {
+        panic!("expected AND");
+    };
+    assert!(matches!(*left, Condition::Comparison(_)));
+    assert!(matches!(*right, Condition::Comparison(_)));
+}
+
+#[test]
+fn test_precedence_and_parentheses() {
+    let cond = where_clause("SELECT * FROM t WHERE (a + b) * c - d / 2 >= 0");
+    let Condition::Expression(ec) = cond else {
+        panic!("expected an expression condition");
+    };
+    assert_eq!(ec.left.to_string(), "(((a + b) * c) - (d / 2))");
+}
+
+#[test]
+fn test_concat_binds_looser_than_arithmetic() {
+    let cond = where_clause("SELECT * FROM t WHERE first || ' ' || n + 1 = 'Ada 2'");
+    let Condition::Expression(ec) = cond else {
+        panic!("expected an expression condition");
+    };
+    assert_eq!(ec.left.to_string(), "((first || ' ') || (n + 1))");
+    assert_eq!(ec.right, ArithmeticExpr::Text("Ada 2".to_string()));
+}
+
+#[test]
+fn test_nested_path_operand() {
+    let cond = where_clause("SELECT * FROM t WHERE meta.price * 2 < 10");
+    let Condition::Expression(ec) = cond else {
+        panic!("expected an expression condition");
+    };
+    assert_eq!(ec.left.variables(), vec!["meta.price"]);
+}
+
+#[test]
+fn test_select_expression_with_alias() {
+    let exprs = expressions("SELECT id, price * 1.2 AS price_with_tax FROM products");
+    assert_eq!(exprs.len(), 1);
+    assert_eq!(exprs[0].alias.as_deref(), Some("price_with_tax"));
+    assert_eq!(exprs[0].output_name(), "price_with_tax");
+    assert_eq!(
+        exprs[0].expr,
+        ArithmeticExpr::BinaryOp {
+            left: Box::new(var("price")),
+            op: ArithmeticOp::Mul,
+            right: Box::new(ArithmeticExpr::Literal(1.2)),
+        }
+    );
+}
+
+#[test]
+fn test_select_expression_without_alias_uses_expression_text() {
+    let exprs = expressions("SELECT first || ' ' || last FROM people");
+    assert_eq!(exprs[0].output_name(), "(first || ' ') || last");
+}
+
+#[test]
+fn test_select_plain_columns_unaffected() {
+    let parsed = Parser::parse("SELECT price, qty AS q FROM products").expect("parse");
+    assert!(matches!(parsed.select.columns, SelectColumns::Columns(_)));
+}
+
+#[test]
+fn test_display_names_include_expressions() {
+    let parsed =
+        Parser::parse("SELECT name, price * qty AS total, price - 1 FROM t").expect("parse");
+    assert_eq!(
+        parsed.select.columns.to_display_names(),
+        vec!["name", "total", "price - 1"]
+    );
+}
+
+#[test]
+fn test_parameter_inside_expression_is_rejected() {
+    assert!(Parser::parse("SELECT * FROM t WHERE price * $factor > 10").is_err());
+}
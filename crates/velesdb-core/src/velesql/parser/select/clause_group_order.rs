@@ -2,9 +2,11 @@
 
 use super::super::{extract_identifier, Rule};
 use super::validation;
+use crate::filter::SortCollation;
 use crate::velesql::ast::{
-    AggregateFunction, ArithmeticExpr, ArithmeticOp, CompareOp, GroupByClause, HavingClause,
-    HavingCondition, OrderByExpr, SelectOrderBy, SimilarityOrderBy, Value,
+    AggregateFunction, ArithmeticExpr, ArithmeticOp, CompareOp, GroupByClause, GroupByExpression,
+    HavingClause, HavingCondition, OrderByExpr, SelectColumns, SelectOrderBy, SimilarityOrderBy,
+    Value,
 };
 use crate::velesql::error::ParseError;
 use crate::velesql::Parser;
@@ -18,23 +20,75 @@ struct HavingParts {
 }
 
 impl Parser {
-    pub(crate) fn parse_group_by_clause(pair: pest::iterators::Pair<Rule>) -> GroupByClause {
-        let mut columns = Vec::new();
-        for inner_pair in pair.into_inner() {
-            if inner_pair.as_rule() == Rule::group_by_list {
-                for col_pair in inner_pair.into_inner() {
-                    if col_pair.as_rule() == Rule::group_by_column {
-                        let parts: Vec<String> = col_pair
-                            .into_inner()
-                            .filter(|p| p.as_rule() == Rule::identifier)
-                            .map(|p| extract_identifier(&p))
-                            .collect();
-                        columns.push(parts.join("."));
-                    }
+    /// Parses GROUP BY items: payload columns and grouping expressions. An
+    /// expression's result key is its text until
+    /// [`Self::resolve_group_by_aliases`] matches it to a SELECT alias.
+    pub(crate) fn parse_group_by_clause(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<GroupByClause, ParseError> {
+        let mut clause = GroupByClause::default();
+        let items = pair
+            .into_inner()
+            .filter(|p| p.as_rule() == Rule::group_by_list)
+            .flat_map(pest::iterators::Pair::into_inner);
+        for item in items {
+            let Some(item) = item.into_inner().next() else {
+                continue;
+            };
+            match item.as_rule() {
+                Rule::group_by_column => {
+                    let parts: Vec<String> = item
+                        .into_inner()
+                        .filter(|p| p.as_rule() == Rule::identifier)
+                        .map(|p| extract_identifier(&p))
+                        .collect();
+                    clause.columns.push(parts.join("."));
                 }
+                Rule::group_by_expr => {
+                    let expr_pair = item
+                        .into_inner()
+                        .next()
+                        .ok_or_else(|| ParseError::syntax(0, "", "Expected GROUP BY expression"))?;
+                    let expr = Self::parse_scalar_expr(expr_pair)?;
+                    let key = expr.to_string();
+                    clause.columns.push(key.clone());
+                    clause.expressions.push(GroupByExpression { key, expr });
+                }
+                _ => {}
+            }
+        }
+        Ok(clause)
+    }
+
+    /// Ties GROUP BY items to computed SELECT items so rows carry the SELECT
+    /// alias: `SELECT date_trunc('day', ts) AS day ... GROUP BY day` and
+    /// `... GROUP BY date_trunc('day', ts)` both group by the expression and
+    /// report it as `day`.
+    pub(crate) fn resolve_group_by_aliases(group_by: &mut GroupByClause, columns: &SelectColumns) {
+        let SelectColumns::Mixed { expressions, .. } = columns else {
+            return;
+        };
+        for select_expr in expressions {
+            let Some(alias) = select_expr.alias.as_deref() else {
+                continue;
+            };
+            let text = select_expr.expr.to_string();
+            if let Some(grouped) = group_by
+                .expressions
+                .iter_mut()
+                .find(|e| e.expr == select_expr.expr)
+            {
+                if let Some(column) = group_by.columns.iter_mut().find(|c| **c == text) {
+                    *column = alias.to_string();
+                }
+                grouped.key = alias.to_string();
+            } else if group_by.columns.iter().any(|c| c == alias) {
+                group_by.expressions.push(GroupByExpression {
+                    key: alias.to_string(),
+                    expr: select_expr.expr.clone(),
+                });
             }
         }
-        GroupByClause { columns }
     }
 
     pub(crate) fn parse_having_clause(
@@ -117,12 +171,7 @@ impl Parser {
     pub(crate) fn parse_aggregate_function_only(
         pair: pest::iterators::Pair<Rule>,
     ) -> Result<AggregateFunction, ParseError> {
-        let (function_type, argument) = Self::parse_aggregate_function(pair)?;
-        Ok(AggregateFunction {
-            function_type,
-            argument,
-            alias: None,
-        })
+        Self::parse_aggregate_function(pair)
     }
 
     pub(crate) fn parse_order_by_clause(
@@ -143,6 +192,7 @@ impl Parser {
         let mut expr = None;
         let mut descending = None;
         let mut is_similarity = false;
+        let mut collation = SortCollation::Binary;
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
                 Rule::order_by_expr => {
@@ -150,6 +200,7 @@ impl Parser {
                     expr = Some(parsed_expr);
                     is_similarity = sim;
                 }
+                Rule::collate_clause => collation = Self::parse_collate_clause(inner_pair)?,
                 Rule::sort_direction => {
                     descending = Some(inner_pair.as_str().to_uppercase() == "DESC");
                 }
@@ -163,6 +214,25 @@ impl Parser {
         Ok(SelectOrderBy {
             expr,
             descending: descending.unwrap_or(is_similarity),
+            collation,
+        })
+    }
+
+    fn parse_collate_clause(
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<SortCollation, ParseError> {
+        let position = pair.as_span().start();
+        let name = pair
+            .into_inner()
+            .find(|p| p.as_rule() == Rule::identifier)
+            .map(|p| extract_identifier(&p))
+            .unwrap_or_default();
+        SortCollation::parse(&name).ok_or_else(|| {
+            ParseError::syntax(
+                position,
+                &name,
+                "Unknown collation: expected binary, ci or ci_ai",
+            )
         })
     }
 
@@ -174,6 +244,7 @@ impl Parser {
                 Rule::order_by_arithmetic => {
                     return Self::parse_order_by_arithmetic(inner_pair);
                 }
+                Rule::order_by_random => return Ok((OrderByExpr::Random, false)),
                 Rule::aggregate_function => {
                     return Ok((
                         OrderByExpr::Aggregate(Self::parse_aggregate_function_only(inner_pair)?),
@@ -218,7 +289,7 @@ impl Parser {
     }
 
     /// Parses `arithmetic_additive`: handles `+` and `-` (left-associative).
-    fn parse_arithmetic_additive(
+    pub(crate) fn parse_arithmetic_additive(
         pair: pest::iterators::Pair<Rule>,
     ) -> Result<ArithmeticExpr, ParseError> {
         Self::parse_arithmetic_binary_chain(pair, "additive", Self::parse_arithmetic_multiplicative)
@@ -236,7 +307,7 @@ impl Parser {
     /// Both additive (`+`, `-`) and multiplicative (`*`, `/`) levels follow the
     /// same pattern: parse operands separated by operator tokens.  The `parse_operand`
     /// closure dispatches to the next-lower precedence level.
-    fn parse_arithmetic_binary_chain(
+    pub(in crate::velesql::parser) fn parse_arithmetic_binary_chain(
         pair: pest::iterators::Pair<Rule>,
         level_name: &str,
         parse_operand: fn(pest::iterators::Pair<Rule>) -> Result<ArithmeticExpr, ParseError>,
@@ -310,13 +381,15 @@ impl Parser {
         Err(ParseError::syntax(0, "", "Invalid arithmetic atom"))
     }
 
-    /// Converts an operator rule (`add_op`, `sub_op`, `mul_op`, `div_op`) to `ArithmeticOp`.
+    /// Converts an operator rule (`add_op`, `sub_op`, `mul_op`, `div_op`,
+    /// `concat_op`) to `ArithmeticOp`.
     fn parse_arithmetic_op(pair: &pest::iterators::Pair<Rule>) -> Result<ArithmeticOp, ParseError> {
         match pair.as_rule() {
             Rule::add_op => Ok(ArithmeticOp::Add),
             Rule::sub_op => Ok(ArithmeticOp::Sub),
             Rule::mul_op => Ok(ArithmeticOp::Mul),
             Rule::div_op => Ok(ArithmeticOp::Div),
+            Rule::concat_op => Ok(ArithmeticOp::Concat),
             _ => Err(ParseError::syntax(
                 0,
                 pair.as_str(),
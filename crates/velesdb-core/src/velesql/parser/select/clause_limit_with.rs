@@ -15,11 +15,30 @@ impl Parser {
             graph_weight: None,
             dense_weight: None,
             sparse_weight: None,
+            boost_field: None,
+            boost_alpha: None,
         };
 
         for inner_pair in pair.into_inner() {
-            if inner_pair.as_rule() == Rule::fusion_options {
-                Self::parse_fusion_options(inner_pair, &mut clause)?;
+            match inner_pair.as_rule() {
+                Rule::fusion_options => Self::parse_fusion_options(inner_pair, &mut clause)?,
+                // `strategy(options)`: the name sets the strategy, an explicit
+                // `strategy = ...` option inside still wins.
+                Rule::fusion_call => {
+                    for part in inner_pair.into_inner() {
+                        match part.as_rule() {
+                            Rule::identifier => {
+                                clause.strategy =
+                                    Self::parse_fusion_strategy_type(&extract_identifier(&part))?;
+                            }
+                            Rule::fusion_options => {
+                                Self::parse_fusion_options(part, &mut clause)?;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
             }
         }
 
@@ -85,6 +104,8 @@ impl Parser {
             "graph_weight" => clause.graph_weight = value_str.parse().ok(),
             "dense_w" | "dense_weight" => clause.dense_weight = value_str.parse().ok(),
             "sparse_w" | "sparse_weight" => clause.sparse_weight = value_str.parse().ok(),
+            "field" => clause.boost_field = Some(value_str),
+            "alpha" => clause.boost_alpha = value_str.parse().ok(),
             other => {
                 return Err(ParseError::new(
                     ParseErrorKind::SyntaxError,
@@ -92,7 +113,7 @@ impl Parser {
                     other.to_string(),
                     format!(
                         "Unknown USING FUSION option '{other}'. Valid keys: strategy, k, \
-                         vector_weight, graph_weight, dense_weight, sparse_weight"
+                         vector_weight, graph_weight, dense_weight, sparse_weight, field, alpha"
                     ),
                 ));
             }
@@ -107,21 +128,17 @@ impl Parser {
     fn parse_fusion_strategy_type(
         name: &str,
     ) -> Result<crate::velesql::FusionStrategyType, ParseError> {
-        match name.to_lowercase().as_str() {
-            "rrf" => Ok(crate::velesql::FusionStrategyType::Rrf),
-            "weighted" => Ok(crate::velesql::FusionStrategyType::Weighted),
-            "maximum" => Ok(crate::velesql::FusionStrategyType::Maximum),
-            "rsf" | "relative_score" => Ok(crate::velesql::FusionStrategyType::Rsf),
-            "average" => Ok(crate::velesql::FusionStrategyType::Average),
-            other => Err(ParseError::new(
+        crate::velesql::FusionStrategyType::parse(name).ok_or_else(|| {
+            let other = name.to_lowercase();
+            ParseError::new(
                 ParseErrorKind::SyntaxError,
                 0,
-                other.to_string(),
+                other.clone(),
                 format!(
                     "Unknown USING FUSION strategy '{other}'. Valid strategies: rrf, weighted, \
-                     maximum, rsf (relative_score), average"
+                     maximum, rsf (relative_score), average, field_boost"
                 ),
-            )),
-        }
+            )
+        })
     }
 }
@@ -5,8 +5,8 @@ use super::super::{extract_identifier, Rule};
 use super::validation;
 use crate::velesql::ast::OverClause;
 use crate::velesql::ast::{
-    AggregateArg, AggregateFunction, AggregateType, Column, SelectColumns, SimilarityScoreExpr,
-    WindowFunction, WindowFunctionType, WindowOrderBy,
+    AggregateArg, AggregateFunction, AggregateType, Column, SelectColumns, SelectExpression,
+    SimilarityScoreExpr, WindowFunction, WindowFunctionType, WindowOrderBy,
 };
 use crate::velesql::error::ParseError;
 use crate::velesql::Parser;
@@ -27,6 +27,7 @@ struct SelectItemAccumulator {
     similarity_scores: Vec<SimilarityScoreExpr>,
     qualified_wildcards: Vec<String>,
     window_functions: Vec<WindowFunction>,
+    expressions: Vec<SelectExpression>,
 }
 
 impl SelectItemAccumulator {
@@ -37,6 +38,7 @@ impl SelectItemAccumulator {
             similarity_scores: Vec::new(),
             qualified_wildcards: Vec::new(),
             window_functions: Vec::new(),
+            expressions: Vec::new(),
         }
     }
 
@@ -55,6 +57,7 @@ impl SelectItemAccumulator {
             similarity_scores: self.similarity_scores,
             qualified_wildcards: self.qualified_wildcards,
             window_functions: self.window_functions,
+            expressions: self.expressions,
         }
     }
 
@@ -66,6 +69,7 @@ impl SelectItemAccumulator {
             !self.similarity_scores.is_empty(),
             !self.qualified_wildcards.is_empty(),
             !self.window_functions.is_empty(),
+            !self.expressions.is_empty(),
         ]
         .iter()
         .filter(|&&b| b)
@@ -91,13 +95,15 @@ impl SelectItemAccumulator {
                 return SelectColumns::QualifiedWildcard(wildcard);
             }
         }
-        // Multiple similarity scores, wildcards, or window functions without other types -> Mixed
+        // Multiple similarity scores, wildcards, window functions, or
+        // expressions without other types -> Mixed
         SelectColumns::Mixed {
             columns: self.columns,
             aggregations: self.aggregations,
             similarity_scores: self.similarity_scores,
             qualified_wildcards: self.qualified_wildcards,
             window_functions: self.window_functions,
+            expressions: self.expressions,
         }
     }
 }
@@ -135,6 +141,9 @@ impl Parser {
                             acc.qualified_wildcards
                                 .push(Self::parse_qualified_wildcard(item));
                         }
+                        Rule::expression_item => {
+                            acc.expressions.push(Self::parse_expression_item(item)?);
+                        }
                         Rule::column => acc.columns.push(Self::parse_column(item)?),
                         _ => {}
                     }
@@ -184,55 +193,74 @@ impl Parser {
     pub(crate) fn parse_aggregation_item(
         pair: pest::iterators::Pair<Rule>,
     ) -> Result<AggregateFunction, ParseError> {
-        let mut function_type = None;
-        let mut argument = None;
+        let mut function = None;
         let mut alias = None;
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
                 Rule::aggregate_function => {
-                    let (ft, arg) = Self::parse_aggregate_function(inner_pair)?;
-                    function_type = Some(ft);
-                    argument = Some(arg);
+                    function = Some(Self::parse_aggregate_function(inner_pair)?);
                 }
                 Rule::identifier => alias = Some(extract_identifier(&inner_pair)),
                 _ => {}
             }
         }
-        Ok(AggregateFunction {
-            function_type: function_type
-                .ok_or_else(|| ParseError::syntax(0, "", "Expected aggregate function"))?,
-            argument: argument
-                .ok_or_else(|| ParseError::syntax(0, "", "Expected aggregate argument"))?,
-            alias,
-        })
+        let function =
+            function.ok_or_else(|| ParseError::syntax(0, "", "Expected aggregate function"))?;
+        Ok(AggregateFunction { alias, ..function })
     }
 
+    /// Parses an `aggregate_function` node into an unaliased [`AggregateFunction`].
     pub(crate) fn parse_aggregate_function(
         pair: pest::iterators::Pair<Rule>,
-    ) -> Result<(AggregateType, AggregateArg), ParseError> {
-        let (agg_type, arg) = Self::extract_aggregate_parts(pair)?;
+    ) -> Result<AggregateFunction, ParseError> {
+        let (agg_type, arg, fraction) = Self::extract_aggregate_parts(pair)?;
         validation::validate_aggregate_wildcard(agg_type, &arg)?;
-        Ok((agg_type, arg))
+        Ok(AggregateFunction {
+            function_type: agg_type,
+            argument: arg,
+            alias: None,
+            fraction,
+        })
     }
 
-    /// Extracts the aggregate type and argument from an `aggregate_function` node.
+    /// Extracts the aggregate type, argument and PERCENTILE_CONT fraction
+    /// from an `aggregate_function` node.
     fn extract_aggregate_parts(
         pair: pest::iterators::Pair<Rule>,
-    ) -> Result<(AggregateType, AggregateArg), ParseError> {
+    ) -> Result<(AggregateType, AggregateArg, Option<f64>), ParseError> {
         let mut agg_type = None;
         let mut arg = None;
-        for inner_pair in pair.into_inner() {
+        let mut fraction = None;
+        let mut inner = pair.into_inner().peekable();
+        if let Some(special) = inner.next_if(|p| {
+            matches!(
+                p.as_rule(),
+                Rule::percentile_function | Rule::count_distinct_function
+            )
+        }) {
+            agg_type = Some(if special.as_rule() == Rule::percentile_function {
+                AggregateType::PercentileCont
+            } else {
+                AggregateType::CountDistinct
+            });
+            inner = special.into_inner().peekable();
+        }
+        for inner_pair in inner {
             match inner_pair.as_rule() {
                 Rule::aggregate_type => {
                     agg_type = Some(validation::parse_aggregate_type(&inner_pair)?);
                 }
                 Rule::aggregate_arg => arg = Some(Self::parse_aggregate_arg(&inner_pair)),
+                Rule::percentile_fraction => {
+                    fraction = Some(validation::parse_percentile_fraction(&inner_pair)?);
+                }
                 _ => {}
             }
         }
         Ok((
             agg_type.ok_or_else(|| ParseError::syntax(0, "", "Expected aggregate type"))?,
             arg.ok_or_else(|| ParseError::syntax(0, "", "Expected aggregate argument"))?,
+            fraction,
         ))
     }
 
@@ -271,6 +299,9 @@ impl Parser {
     }
 
     pub(crate) fn parse_column_name(pair: &pest::iterators::Pair<Rule>) -> String {
+        if pair.as_rule() == Rule::json_extract {
+            return Self::json_extract_path(pair);
+        }
         let raw = pair.as_str();
         Self::strip_quotes_from_column_name(raw)
     }
@@ -45,6 +45,7 @@ impl Parser {
             Rule::alter_collection_stmt => Self::parse_alter_collection_stmt(p),
             Rule::flush_stmt => Self::parse_flush_stmt(p),
             Rule::match_query => Self::parse_match_query(p),
+            Rule::graph_mutation_stmt => Self::parse_graph_mutation_stmt(p),
             Rule::compound_query => Self::parse_compound_query(p),
             Rule::train_stmt => Self::parse_train_stmt(p),
             Rule::create_index_stmt => Self::parse_create_index_stmt(p),
@@ -108,8 +109,8 @@ impl Parser {
                 ))
             }
             crate::velesql::OrderByExpr::Arithmetic(arith) => arith,
-            crate::velesql::OrderByExpr::Aggregate(_) => {
-                // Aggregates in LET are nonsensical; store as literal 0.
+            crate::velesql::OrderByExpr::Aggregate(_) | crate::velesql::OrderByExpr::Random => {
+                // Aggregates and random() in LET are nonsensical; store as literal 0.
                 crate::velesql::ArithmeticExpr::Literal(0.0)
             }
         }
@@ -123,6 +124,9 @@ impl Parser {
         for inner_pair in pair.into_inner() {
             Self::dispatch_select_clause(inner_pair, &mut stmt)?;
         }
+        if let Some(group_by) = stmt.group_by.as_mut() {
+            Self::resolve_group_by_aliases(group_by, &stmt.columns);
+        }
 
         Ok(stmt.build())
     }
@@ -168,7 +172,7 @@ impl Parser {
     ) -> Result<(), ParseError> {
         match pair.as_rule() {
             Rule::where_clause => stmt.where_clause = Some(Self::parse_where_clause(pair)?),
-            Rule::group_by_clause => stmt.group_by = Some(Self::parse_group_by_clause(pair)),
+            Rule::group_by_clause => stmt.group_by = Some(Self::parse_group_by_clause(pair)?),
             Rule::having_clause => stmt.having = Some(Self::parse_having_clause(pair)?),
             Rule::order_by_clause => stmt.order_by = Some(Self::parse_order_by_clause(pair)?),
             _ => Self::dispatch_trailing_clause(pair, stmt)?,
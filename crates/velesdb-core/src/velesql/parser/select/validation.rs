@@ -5,7 +5,7 @@ use super::super::Rule;
 use crate::velesql::ast::{AggregateArg, AggregateType, CompareOp};
 use crate::velesql::error::ParseError;
 
-/// Parse aggregate type keyword (COUNT, SUM, AVG, MIN, MAX).
+/// Parse aggregate type keyword (COUNT, SUM, AVG, MIN, MAX, FIRST, STDDEV, VARIANCE).
 pub(crate) fn parse_aggregate_type(
     pair: &pest::iterators::Pair<Rule>,
 ) -> Result<AggregateType, ParseError> {
@@ -17,6 +17,8 @@ pub(crate) fn parse_aggregate_type(
         "MIN" => Ok(AggregateType::Min),
         "MAX" => Ok(AggregateType::Max),
         "FIRST" => Ok(AggregateType::First),
+        "STDDEV" => Ok(AggregateType::StdDev),
+        "VARIANCE" => Ok(AggregateType::Variance),
         other => Err(ParseError::syntax(0, other, "Unknown aggregate function")),
     }
 }
@@ -29,6 +31,10 @@ pub(crate) fn validate_aggregate_wildcard(
     if matches!(arg, AggregateArg::Wildcard) && !matches!(agg_type, AggregateType::Count) {
         let msg = if matches!(agg_type, AggregateType::First) {
             "FIRST does not accept wildcard argument (*)".to_string()
+        } else if matches!(agg_type, AggregateType::CountDistinct) {
+            "COUNT(DISTINCT *) is invalid - use COUNT(DISTINCT column_name)".to_string()
+        } else if matches!(agg_type, AggregateType::PercentileCont) {
+            "PERCENTILE_CONT requires a column to order by, not *".to_string()
         } else {
             format!(
                 "{agg_type:?}(*) is invalid - only COUNT(*) accepts *. \
@@ -40,6 +46,21 @@ pub(crate) fn validate_aggregate_wildcard(
     Ok(())
 }
 
+/// Parse a PERCENTILE_CONT fraction, which must lie in `[0, 1]`.
+pub(crate) fn parse_percentile_fraction(
+    pair: &pest::iterators::Pair<Rule>,
+) -> Result<f64, ParseError> {
+    let raw = pair.as_str();
+    match raw.parse::<f64>() {
+        Ok(fraction) if (0.0..=1.0).contains(&fraction) => Ok(fraction),
+        _ => Err(ParseError::syntax(
+            pair.as_span().start(),
+            raw,
+            "PERCENTILE_CONT fraction must be between 0 and 1",
+        )),
+    }
+}
+
 /// Parse comparison operator token into `CompareOp`.
 ///
 /// Delegates to the shared [`compare_op_from_str`] helper.
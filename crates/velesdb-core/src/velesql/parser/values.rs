@@ -262,7 +262,7 @@ impl Parser {
     fn parse_subquery_select(
         pair: pest::iterators::Pair<Rule>,
     ) -> Result<crate::velesql::ast::SelectStatement, ParseError> {
-        use crate::velesql::ast::{GroupByClause, SelectColumns, SelectStatement};
+        use crate::velesql::ast::{SelectColumns, SelectStatement};
 
         let mut columns = SelectColumns::All;
         let mut from = String::new();
@@ -277,13 +277,7 @@ impl Parser {
                 Rule::identifier => from = super::extract_identifier(&sub_pair),
                 Rule::where_clause => where_clause = Some(Self::parse_where_clause(sub_pair)?),
                 Rule::group_by_clause => {
-                    let cols: Vec<String> = sub_pair
-                        .into_inner()
-                        .filter(|p| p.as_rule() == Rule::group_by_list)
-                        .flat_map(pest::iterators::Pair::into_inner)
-                        .map(|p| super::extract_identifier(&p))
-                        .collect();
-                    group_by = Some(GroupByClause { columns: cols });
+                    group_by = Some(Self::parse_group_by_clause(sub_pair)?);
                 }
                 Rule::having_clause => having = Some(Self::parse_having_clause(sub_pair)?),
                 Rule::limit_clause => limit = Some(Self::parse_limit_clause(sub_pair)?),
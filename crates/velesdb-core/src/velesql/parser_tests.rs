@@ -276,6 +276,31 @@ fn test_parse_in_with_not_in_string_value() {
     }
 }
 
+#[test]
+fn test_parse_in_bare_parameter_binds_whole_list() {
+    let query = Parser::parse("SELECT * FROM docs WHERE id NOT IN $ids").unwrap();
+    match query.select.where_clause {
+        Some(Condition::In(c)) => {
+            assert_eq!(c.column, "id");
+            assert_eq!(c.values, vec![Value::Parameter("ids".to_string())]);
+            assert!(c.negated);
+        }
+        other => panic!("expected an IN condition, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_contains_any_bare_parameter() {
+    let query = Parser::parse("SELECT * FROM docs WHERE tags CONTAINS ANY $tags").unwrap();
+    match query.select.where_clause {
+        Some(Condition::Contains(c)) => {
+            assert_eq!(c.mode, ContainsMode::Any);
+            assert_eq!(c.values, vec![Value::Parameter("tags".to_string())]);
+        }
+        other => panic!("expected a CONTAINS condition, got {other:?}"),
+    }
+}
+
 #[test]
 fn test_parse_string_with_escaped_quote() {
     let query = Parser::parse("SELECT * FROM docs WHERE name = 'O''Brien'").unwrap();
@@ -254,3 +254,33 @@ fn test_regression_order_by_similarity_two_arg() {
     let order_by = query.select.order_by.as_ref().unwrap();
     assert!(matches!(&order_by[0].expr, OrderByExpr::Similarity(_)));
 }
+
+// ========== json_extract() ==========
+
+#[test]
+fn test_parse_json_extract_lowers_to_dotted_path() {
+    let query = Parser::parse(
+        "SELECT json_extract(payload, '$.meta.items[0].sku') AS sku FROM t \
+         WHERE JSON_EXTRACT(payload, '$.meta.tags[2]') = 'x'",
+    )
+    .unwrap();
+    match &query.select.columns {
+        SelectColumns::Columns(cols) => {
+            assert_eq!(cols[0].name, "meta.items.0.sku");
+            assert_eq!(cols[0].alias.as_deref(), Some("sku"));
+        }
+        other => panic!("Expected Columns, got {other:?}"),
+    }
+    match query.select.where_clause {
+        Some(Condition::Comparison(cmp)) => assert_eq!(cmp.column, "meta.tags.2"),
+        other => panic!("Expected Comparison, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_json_extract_rejects_malformed_paths() {
+    for path in ["meta.source", "$", "$.a..b", "$.a[x]", "$.a[0"] {
+        let sql = format!("SELECT * FROM t WHERE json_extract(payload, '{path}') = 1");
+        assert!(Parser::parse(&sql).is_err(), "{path} must not parse");
+    }
+}
@@ -74,6 +74,7 @@ impl QueryValidator {
         Self::validate_qualified_wildcards(stmt)?;
         Self::validate_vector_group_by(stmt)?;
         super::validation_fusion::validate_fusion(stmt)?;
+        Self::validate_hints(stmt)?;
         stmt.where_clause.as_ref().map_or(Ok(()), |condition| {
             // V011 anchor rule (explicit and implicit binding, guards
             // G1/G2/G3) lives in `validation_anchor.rs`.
@@ -81,6 +82,33 @@ impl QueryValidator {
         })
     }
 
+    /// Validates `WITH (hint = ...)`: known names, no `force_scan` together
+    /// with `force_index`.
+    fn validate_hints(stmt: &super::ast::SelectStatement) -> Result<(), ValidationError> {
+        let Some(ref with) = stmt.with_clause else {
+            return Ok(());
+        };
+        let hints = with.get_hints().map_err(|name| {
+            ValidationError::new(
+                ValidationErrorKind::InvalidQueryHint,
+                None,
+                name,
+                "Use one of: force_scan, force_index, no_overfetch",
+            )
+        })?;
+        if hints.contains(&super::ast::QueryHint::ForceScan)
+            && hints.contains(&super::ast::QueryHint::ForceIndex)
+        {
+            return Err(ValidationError::new(
+                ValidationErrorKind::InvalidQueryHint,
+                None,
+                "force_scan, force_index",
+                "Pick either force_scan or force_index",
+            ));
+        }
+        Ok(())
+    }
+
     /// Validates that `similarity()` in SELECT or ORDER BY has a score context.
     fn validate_similarity_context(
         stmt: &super::ast::SelectStatement,
@@ -152,7 +180,12 @@ impl QueryValidator {
                 Self::validate_arithmetic_similarity(left, has_score_context)?;
                 Self::validate_arithmetic_similarity(right, has_score_context)
             }
-            ArithmeticExpr::Literal(_) | ArithmeticExpr::Variable(_) => Ok(()),
+            ArithmeticExpr::Function { args, .. } => args
+                .iter()
+                .try_for_each(|arg| Self::validate_arithmetic_similarity(arg, has_score_context)),
+            ArithmeticExpr::Literal(_) | ArithmeticExpr::Variable(_) | ArithmeticExpr::Text(_) => {
+                Ok(())
+            }
         }
     }
 
@@ -10,6 +10,8 @@
 //!   non-negative — so the execution-time RRF fallback is unreachable.
 //! - **#15** `NEAR_FUSED` rejects `weighted`/`rsf` (ill-defined over N
 //!   homogeneous query vectors).
+//! - `field_boost` rescores a single vector `NEAR` branch, so it requires
+//!   exactly that branch, a `field` and a non-negative `alpha`.
 
 use super::ast::{Condition, FusionStrategyType, SelectStatement};
 use super::validation_types::{ValidationError, ValidationErrorKind};
@@ -100,10 +102,42 @@ pub(super) fn validate_fusion(stmt: &SelectStatement) -> Result<(), ValidationEr
         return Ok(());
     };
 
+    if fc.strategy == FusionStrategyType::FieldBoost {
+        return validate_field_boost(fc, &counts);
+    }
     validate_fusion_applicability(&counts)?;
     validate_fusion_weights(fc)
 }
 
+/// `field_boost` needs one vector `NEAR` and nothing else to fuse, a boost
+/// field and a finite, non-negative `alpha`.
+fn validate_field_boost(
+    fc: &super::ast::FusionClause,
+    counts: &BranchCounts,
+) -> Result<(), ValidationError> {
+    const LABEL: &str = "USING FUSION(strategy='field_boost')";
+    if counts.near != 1 || counts.fusable_total() != 1 {
+        return Err(fusion_error(
+            LABEL,
+            "USING FUSION field_boost rescores a single vector NEAR search; it cannot be \
+             combined with MATCH, SPARSE_NEAR, NEAR_FUSED or another NEAR",
+        ));
+    }
+    if fc.boost_field.as_deref().is_none_or(str::is_empty) {
+        return Err(fusion_error(
+            LABEL,
+            "USING FUSION field_boost requires field = '<numeric payload field>'",
+        ));
+    }
+    if fc.boost_alpha.is_some_and(|a| !a.is_finite() || a < 0.0) {
+        return Err(fusion_error(
+            LABEL,
+            "USING FUSION field_boost alpha must be a non-negative number",
+        ));
+    }
+    Ok(())
+}
+
 /// #16: USING FUSION requires at least two fusable branches, or a NEAR_FUSED.
 fn validate_fusion_applicability(counts: &BranchCounts) -> Result<(), ValidationError> {
     // A single NEAR_FUSED is self-fusing and a valid FUSION target.
@@ -51,6 +51,7 @@ mod tests {
     fn vector_search_condition() -> Condition {
         Condition::VectorSearch(VectorSearch {
             vector: VectorExpr::Parameter("v".to_string()),
+            avoid: Vec::new(),
         })
     }
 
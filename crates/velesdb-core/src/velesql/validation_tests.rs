@@ -464,9 +464,11 @@ fn test_validate_vector_search_near_with_or_detected() {
 
     let near1 = Condition::VectorSearch(VectorSearch {
         vector: VectorExpr::Parameter("v1".to_string()),
+        avoid: Vec::new(),
     });
     let near2 = Condition::VectorSearch(VectorSearch {
         vector: VectorExpr::Parameter("v2".to_string()),
+        avoid: Vec::new(),
     });
 
     let query = Query {
@@ -509,6 +511,7 @@ fn test_validate_vector_search_or_now_passes() {
 
     let near = Condition::VectorSearch(VectorSearch {
         vector: VectorExpr::Parameter("v".to_string()),
+        avoid: Vec::new(),
     });
     let meta = Condition::Comparison(Comparison {
         column: "category".to_string(),
@@ -674,6 +677,7 @@ fn make_similarity() -> Condition {
 fn make_vector_search() -> Condition {
     Condition::VectorSearch(VectorSearch {
         vector: VectorExpr::Parameter("v".to_string()),
+        avoid: Vec::new(),
     })
 }
 
@@ -1042,6 +1046,7 @@ fn make_query_with_arithmetic_order_by(
             order_by: Some(vec![SelectOrderBy {
                 expr: OrderByExpr::Arithmetic(arithmetic_expr),
                 descending: true,
+                collation: crate::filter::SortCollation::Binary,
             }]),
             limit: Some(10),
             offset: None,
@@ -1110,6 +1115,7 @@ fn test_validate_bare_similarity_in_arithmetic_with_near_passes() {
     };
     let near = Condition::VectorSearch(VectorSearch {
         vector: VectorExpr::Parameter("v".to_string()),
+        avoid: Vec::new(),
     });
     let query = make_query_with_arithmetic_order_by(Some(near), expr);
     let result = QueryValidator::validate(&query);
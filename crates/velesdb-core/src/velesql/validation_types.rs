@@ -131,6 +131,9 @@ pub enum ValidationErrorKind {
     /// strategy not allowed for the query shape (e.g. weighted/rsf on
     /// NEAR_FUSED).
     FusionMisconfigured,
+    /// `WITH (hint = ...)` names an unknown planner hint, or combines the
+    /// contradictory `force_scan` and `force_index`.
+    InvalidQueryHint,
 }
 
 impl ValidationErrorKind {
@@ -150,6 +153,7 @@ impl ValidationErrorKind {
             Self::SubqueryNotExecutable => "V010",
             Self::GraphMatchAnchorMismatch => "V011",
             Self::FusionMisconfigured => "V012",
+            Self::InvalidQueryHint => "V013",
         }
     }
 
@@ -176,6 +180,7 @@ impl ValidationErrorKind {
                 "MATCH predicate anchor must be an alias declared in FROM/JOIN"
             }
             Self::FusionMisconfigured => "USING FUSION clause is misconfigured",
+            Self::InvalidQueryHint => "Invalid planner hint in WITH clause",
         }
     }
 }
@@ -330,15 +330,9 @@ fn extract_nested_value(result: &SearchResult, column: &str) -> serde_json::Valu
 
     if column.contains('.') {
         // Navigate nested path: "metadata.source" → payload["metadata"]["source"]
-        let parts: Vec<&str> = column.split('.').collect();
-        let mut current = payload;
-        for part in &parts {
-            match current.get(*part) {
-                Some(v) => current = v,
-                None => return serde_json::Value::Null,
-            }
-        }
-        current.clone()
+        crate::filter::lookup_path(payload, column)
+            .cloned()
+            .unwrap_or(serde_json::Value::Null)
     } else {
         payload
             .get(column)
@@ -0,0 +1,66 @@
+//! Base64-encoded half-precision vectors.
+//!
+//! The response-side counterpart of [`f32_base64`](super::f32_base64): each
+//! component is rounded to IEEE-754 binary16 with
+//! [`half_precision`](crate::half_precision) and the little-endian bytes are
+//! carried as standard (padded) base64 — about 2.7 characters per component.
+//! Used for `vector_precision: "f16"` search responses, where a preview of
+//! the vector matters more than its exact value.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use half::f16;
+
+use crate::half_precision::{VectorData, VectorPrecision};
+
+/// Rounds `vector` to f16 and encodes the little-endian bytes as base64.
+#[must_use]
+pub fn encode(vector: &[f32]) -> String {
+    STANDARD.encode(VectorData::from_f32_slice(vector, VectorPrecision::F16).to_le_bytes())
+}
+
+/// Decodes a vector produced by [`encode`] back to `f32` components.
+/// Surrounding whitespace is ignored.
+///
+/// # Errors
+///
+/// Returns a description of the problem if `text` is not valid base64 or
+/// does not decode to a whole number of f16 values.
+pub fn decode(text: &str) -> Result<Vec<f32>, String> {
+    let bytes = STANDARD
+        .decode(text.trim())
+        .map_err(|e| format!("invalid base64 vector: {e}"))?;
+    if bytes.len() % 2 != 0 {
+        return Err(format!(
+            "base64 vector decodes to {} bytes, not a whole number of f16 values",
+            bytes.len()
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|chunk| f16::from_le_bytes([chunk[0], chunk[1]]).to_f32())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_rounds_to_f16() {
+        let vector = [0.1_f32, -2.5, 1.0, 0.0];
+        let text = encode(&vector);
+        assert_eq!(text.len(), 12);
+        let decoded = decode(&text).expect("decode");
+        assert_eq!(decoded[1..], [-2.5, 1.0, 0.0]);
+        assert!((decoded[0] - 0.1).abs() < 1e-3);
+        assert_eq!(decoded[0].to_bits(), f16::from_f32(0.1).to_f32().to_bits());
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_input() {
+        assert!(decode("not base64!").is_err());
+        // Three bytes: not a whole f16.
+        assert!(decode("AAAA").unwrap_err().contains("3 bytes"));
+    }
+}
@@ -3,6 +3,8 @@
 //! These are pure byte (de)serialisers with no storage or persistence
 //! dependency, so they compile on every target (including `wasm32`).
 
+pub mod f16_base64;
+pub mod f32_base64;
 pub mod stable_hash;
 pub mod vrb1;
 
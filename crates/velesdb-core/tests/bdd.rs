@@ -57,6 +57,8 @@ mod fusion_weighted_bug;
 mod geo_distance;
 #[path = "bdd/graph_anchor_prefilter.rs"]
 mod graph_anchor_prefilter;
+#[path = "bdd/graph_mutations.rs"]
+mod graph_mutations;
 #[path = "bdd/graph_queries.rs"]
 mod graph_queries;
 #[path = "bdd/graph_vector_hybrid.rs"]
@@ -81,10 +83,16 @@ mod match_order_by_core;
 mod match_order_by_exact;
 #[path = "bdd/match_relationship_semantics.rs"]
 mod match_relationship_semantics;
+#[path = "bdd/match_shortest_path.rs"]
+mod match_shortest_path;
+#[path = "bdd/match_temporal_edges.rs"]
+mod match_temporal_edges;
 #[path = "bdd/match_traversal_exact.rs"]
 mod match_traversal_exact;
 #[path = "bdd/match_vector_first.rs"]
 mod match_vector_first;
+#[path = "bdd/match_weighted_paths.rs"]
+mod match_weighted_paths;
 #[path = "bdd/metrics_ranking_conformance.rs"]
 mod metrics_ranking_conformance;
 #[path = "bdd/near_exact_ranking.rs"]
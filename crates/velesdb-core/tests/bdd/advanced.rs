@@ -656,6 +656,7 @@ fn test_graph_match_basic_traversal_via_collection() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new().with_alias("a"),
                 velesdb_core::velesql::NodePattern::new().with_alias("b"),
@@ -672,6 +673,7 @@ fn test_graph_match_basic_traversal_via_collection() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -718,6 +720,7 @@ fn test_graph_match_with_label_filter() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new().with_alias("p"),
                 velesdb_core::velesql::NodePattern::new().with_alias("q"),
@@ -738,6 +741,7 @@ fn test_graph_match_with_label_filter() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
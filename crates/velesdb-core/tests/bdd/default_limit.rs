@@ -128,7 +128,7 @@ fn test_match_return_without_limit_returns_all_matches() {
 /// GIVEN 150 nodes labeled `Bulk` (above the historical silent cap of 100)
 /// WHEN running `MATCH (n:Bulk) RETURN n` without LIMIT
 /// THEN all 150 matches are returned — pins the contract against any
-/// implicit per-query cap below the server-wide `MAX_LIMIT` ceiling.
+/// implicit per-query cap below the `limits.max_query_limit` ceiling.
 #[test]
 fn test_match_return_without_limit_exceeds_one_hundred_matches() {
     let (_dir, db) = create_test_db();
@@ -156,7 +156,7 @@ fn test_match_return_without_limit_exceeds_one_hundred_matches() {
     assert_eq!(
         results.len(),
         150,
-        "MATCH ... RETURN must not silently cap below MAX_LIMIT"
+        "MATCH ... RETURN must not silently cap below max_query_limit"
     );
 }
 
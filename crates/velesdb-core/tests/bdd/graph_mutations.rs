@@ -0,0 +1,295 @@
+//! BDD tests for graph mutations: `CREATE` edges and `[DETACH] DELETE`.
+//!
+//! Endpoints come from literal ids, parameters or MATCH bindings; the
+//! mutation applies once per MATCH row, is validated against the graph
+//! schema, and leaves the graph unchanged when rejected.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use velesdb_core::{Database, GraphEdge, SearchResult};
+
+use super::helpers::{create_test_db, execute_sql, execute_sql_with_params};
+
+// =========================================================================
+// Module-specific setup
+// =========================================================================
+
+/// GIVEN base: people 1..=4 (Alice, Bob, Carol, Dan) in schemaless `social`,
+/// with `KNOWS` edges 1 -> 2 (since 1995) and 2 -> 3 (since 2021).
+fn setup_social_graph(db: &Database) {
+    execute_sql(
+        db,
+        "CREATE GRAPH COLLECTION social (dimension = 4, metric = 'cosine') SCHEMALESS",
+    )
+    .expect("test: create graph");
+    let gc = db
+        .get_graph_collection("social")
+        .expect("test: get social graph");
+    for (id, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol"), (4, "Dan")] {
+        gc.upsert_node_payload(id, &json!({"_labels": ["Person"], "name": name}))
+            .expect("test: add person");
+    }
+    for (id, source, target, since) in [(100, 1, 2, 1995), (101, 2, 3, 2021)] {
+        let edge = GraphEdge::new(id, source, target, "KNOWS")
+            .expect("test: edge")
+            .with_properties(HashMap::from([("since".to_string(), json!(since))]));
+        gc.add_edge(edge).expect("test: add edge");
+    }
+}
+
+fn summary(results: &[SearchResult], key: &str) -> u64 {
+    assert_eq!(results.len(), 1, "one summary row: {results:?}");
+    results[0]
+        .point
+        .payload
+        .as_ref()
+        .and_then(|p| p.get(key))
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or_else(|| panic!("summary key {key}: {results:?}"))
+}
+
+/// `(source, target, label)` of every edge in `social`, sorted.
+fn edges(db: &Database) -> Vec<(u64, u64, String)> {
+    let gc = db
+        .get_graph_collection("social")
+        .expect("test: get social graph");
+    let mut edges: Vec<_> = gc
+        .get_edges(None)
+        .into_iter()
+        .map(|e| (e.source(), e.target(), e.label().to_string()))
+        .collect();
+    edges.sort();
+    edges
+}
+
+fn knows(source: u64, target: u64) -> (u64, u64, String) {
+    (source, target, "KNOWS".to_string())
+}
+
+// =========================================================================
+// A. CREATE
+// =========================================================================
+
+/// GIVEN the social graph
+/// WHEN creating an edge between literal node ids
+/// THEN the edge carries its properties, and creating it again is a no-op.
+#[test]
+fn test_create_edge_by_ids() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let sql = "CREATE (3)-[:KNOWS {since: 2024}]->(4) IN social";
+    let created = execute_sql(&db, sql).expect("test: create edge");
+    assert_eq!(summary(&created, "edges_created"), 1);
+
+    let gc = db.get_graph_collection("social").expect("test: graph");
+    let edge = gc
+        .get_outgoing(3)
+        .into_iter()
+        .find(|e| e.target() == 4)
+        .expect("test: edge 3 -> 4");
+    assert_eq!(edge.label(), "KNOWS");
+    assert_eq!(edge.properties().get("since"), Some(&json!(2024)));
+
+    let again = execute_sql(&db, sql).expect("test: create again");
+    assert_eq!(summary(&again, "edges_created"), 0);
+    assert_eq!(edges(&db).len(), 3);
+}
+
+/// GIVEN the social graph
+/// WHEN CREATE endpoints are bound by a MATCH
+/// THEN one edge is created per matched row.
+#[test]
+fn test_create_edges_from_match_rows() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let created = execute_sql(
+        &db,
+        "MATCH (a:Person)-[:KNOWS]->(b), (d {name: 'Dan'}) CREATE (d)-[:FOLLOWS]->(a) IN social",
+    )
+    .expect("test: create from match");
+
+    assert_eq!(summary(&created, "edges_created"), 2);
+    assert_eq!(
+        edges(&db),
+        vec![
+            knows(1, 2),
+            knows(2, 3),
+            (4, 1, "FOLLOWS".to_string()),
+            (4, 2, "FOLLOWS".to_string()),
+        ]
+    );
+}
+
+/// GIVEN the social graph
+/// WHEN endpoints and properties are parameters and the collection comes
+/// from `_collection`
+/// THEN they are substituted.
+#[test]
+fn test_create_edge_with_parameters() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let params = HashMap::from([
+        ("_collection".to_string(), json!("social")),
+        ("src".to_string(), json!(4)),
+        ("year".to_string(), json!(2030)),
+    ]);
+    let created = execute_sql_with_params(
+        &db,
+        "MATCH (c {name: 'Carol'}) CREATE ($src)-[:KNOWS {since: $year}]->(c)",
+        &params,
+    )
+    .expect("test: create with params");
+
+    assert_eq!(summary(&created, "edges_created"), 1);
+    assert!(edges(&db).contains(&knows(4, 3)));
+}
+
+/// GIVEN the social graph
+/// WHEN one of the created edges points at a missing node
+/// THEN the whole statement fails and no edge is created.
+#[test]
+fn test_create_edges_is_atomic() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let err = execute_sql(
+        &db,
+        "CREATE (3)-[:KNOWS]->(4), (4)-[:KNOWS]->(99) IN social",
+    )
+    .expect_err("test: missing endpoint");
+
+    assert!(
+        matches!(err, velesdb_core::Error::NodeNotFound(99)),
+        "{err:?}"
+    );
+    assert_eq!(edges(&db), vec![knows(1, 2), knows(2, 3)]);
+}
+
+/// GIVEN a graph collection with a typed schema
+/// WHEN creating an edge type the schema does not declare
+/// THEN the statement is rejected, while a declared type is accepted.
+#[test]
+fn test_create_edge_validates_graph_schema() {
+    let (_dir, db) = create_test_db();
+    execute_sql(
+        &db,
+        "CREATE GRAPH COLLECTION typed (dimension = 4, metric = 'cosine') \
+         WITH SCHEMA (NODE Person (name: STRING), EDGE KNOWS FROM Person TO Person)",
+    )
+    .expect("test: create typed graph");
+    let gc = db.get_graph_collection("typed").expect("test: graph");
+    for id in [1, 2] {
+        gc.upsert_node_payload(id, &json!({"_labels": ["Person"], "name": "p"}))
+            .expect("test: add person");
+    }
+
+    let err = execute_sql(&db, "CREATE (1)-[:LIKES]->(2) IN typed")
+        .expect_err("test: undeclared edge type");
+    assert!(
+        matches!(err, velesdb_core::Error::SchemaValidation(_)),
+        "{err:?}"
+    );
+
+    execute_sql(&db, "CREATE (1)-[:KNOWS]->(2) IN typed").expect("test: declared edge type");
+    assert_eq!(gc.edge_count(), 1);
+}
+
+// =========================================================================
+// B. DELETE / DETACH DELETE
+// =========================================================================
+
+/// GIVEN the social graph
+/// WHEN deleting the relationships a filtered MATCH binds
+/// THEN only those edges are removed.
+#[test]
+fn test_delete_matched_relationships() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let deleted = execute_sql(
+        &db,
+        "MATCH (a)-[r:KNOWS]->(b) WHERE r.since < 2000 DELETE r IN social",
+    )
+    .expect("test: delete relationship");
+
+    assert_eq!(summary(&deleted, "edges_deleted"), 1);
+    assert_eq!(summary(&deleted, "nodes_deleted"), 0);
+    assert_eq!(edges(&db), vec![knows(2, 3)]);
+}
+
+/// GIVEN the social graph
+/// WHEN deleting a node that still has relationships
+/// THEN a plain DELETE is rejected and DETACH DELETE removes both.
+#[test]
+fn test_delete_node_requires_detach() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let err = execute_sql(&db, "MATCH (n {name: 'Bob'}) DELETE n IN social")
+        .expect_err("test: node has relationships");
+    assert!(matches!(err, velesdb_core::Error::Query(_)), "{err:?}");
+    assert_eq!(edges(&db).len(), 2);
+
+    let deleted = execute_sql(&db, "MATCH (n {name: 'Bob'}) DETACH DELETE n IN social")
+        .expect("test: detach delete");
+    assert_eq!(summary(&deleted, "nodes_deleted"), 1);
+    assert_eq!(summary(&deleted, "edges_deleted"), 2);
+    assert!(edges(&db).is_empty());
+    let gc = db.get_graph_collection("social").expect("test: graph");
+    assert!(gc
+        .get_node_payload(2)
+        .expect("test: read payload")
+        .is_none());
+}
+
+/// GIVEN the social graph
+/// WHEN a node is deleted together with its only relationship
+/// THEN no DETACH is needed.
+#[test]
+fn test_delete_node_with_its_relationships() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let deleted = execute_sql(
+        &db,
+        "MATCH (a {name: 'Alice'})-[r:KNOWS]->(b) DELETE a, r IN social",
+    )
+    .expect("test: delete node and relationship");
+
+    assert_eq!(summary(&deleted, "nodes_deleted"), 1);
+    assert_eq!(summary(&deleted, "edges_deleted"), 1);
+    assert_eq!(edges(&db), vec![knows(2, 3)]);
+}
+
+// =========================================================================
+// C. Rejections
+// =========================================================================
+
+/// GIVEN the social graph
+/// WHEN the statement names no collection, an unbound alias or a
+/// vector collection
+/// THEN it is rejected.
+#[test]
+fn test_graph_mutation_rejections() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+    execute_sql(
+        &db,
+        "CREATE COLLECTION docs (dimension = 4, metric = 'cosine')",
+    )
+    .expect("test: create vector collection");
+
+    for sql in [
+        "CREATE (1)-[:KNOWS]->(4)",
+        "MATCH (a {name: 'Alice'}) CREATE (a)-[:KNOWS]->(x) IN social",
+        "MATCH (a {name: 'Alice'}) DELETE x IN social",
+        "CREATE (1)-[:KNOWS]->(2) IN docs",
+    ] {
+        assert!(execute_sql(&db, sql).is_err(), "{sql} should be rejected");
+    }
+    assert_eq!(edges(&db), vec![knows(1, 2), knows(2, 3)]);
+}
@@ -260,6 +260,7 @@ fn test_label_index_consistency_after_upsert() {
     let match_clause = velesql::MatchClause {
         patterns: vec![velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesql::NodePattern::new()
                     .with_alias("p")
@@ -278,6 +279,7 @@ fn test_label_index_consistency_after_upsert() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -343,6 +345,7 @@ fn test_label_index_consistency_after_delete() {
     let match_clause = velesql::MatchClause {
         patterns: vec![velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesql::NodePattern::new()
                     .with_alias("p")
@@ -361,6 +364,7 @@ fn test_label_index_consistency_after_delete() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -0,0 +1,213 @@
+//! BDD tests for `shortestPath(...)` MATCH patterns.
+//!
+//! A shortest-path pattern returns one shortest path per (start, end) pair
+//! instead of every path. Bound end nodes are searched with bidirectional
+//! BFS, unbound ones with a single BFS per start node; both must agree with
+//! the collection-level `shortest_path` API.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use velesdb_core::{Database, GraphEdge, SearchResult, TraversalConfig};
+
+use super::helpers::{create_test_db, execute_sql, result_ids};
+
+// =========================================================================
+// Module-specific setup
+// =========================================================================
+
+/// Parses `sql` and executes it against `social`.
+fn run_match(db: &Database, sql: &str) -> velesdb_core::Result<Vec<SearchResult>> {
+    let mut params = HashMap::new();
+    params.insert("_collection".to_string(), json!("social"));
+    let query = velesdb_core::velesql::Parser::parse(sql).expect("test: parse MATCH query");
+    db.execute_query(&query, &params)
+}
+
+/// GIVEN base: people 1..=6, Alice (1) and Zoe (6) labelled, with a long
+/// `KNOWS` chain 1 -> 2 -> 3 -> 4 -> 6 and a short one 1 -> 5 -> 6.
+fn setup_social_graph(db: &Database) {
+    execute_sql(
+        db,
+        "CREATE GRAPH COLLECTION social (dimension = 4, metric = 'cosine') SCHEMALESS",
+    )
+    .expect("test: create graph");
+    let gc = db
+        .get_graph_collection("social")
+        .expect("test: get social graph");
+    for (id, name) in [
+        (1, "Alice"),
+        (2, "Bob"),
+        (3, "Carol"),
+        (4, "Dan"),
+        (5, "Eve"),
+        (6, "Zoe"),
+    ] {
+        gc.upsert_node_payload(id, &json!({"_labels": ["Person"], "name": name}))
+            .expect("test: add person");
+    }
+    for (id, source, target) in [
+        (100, 1, 2),
+        (101, 2, 3),
+        (102, 3, 4),
+        (103, 4, 6),
+        (104, 1, 5),
+        (105, 5, 6),
+    ] {
+        gc.add_edge(GraphEdge::new(id, source, target, "KNOWS").expect("test: edge"))
+            .expect("test: add edge");
+    }
+}
+
+fn projected(result: &SearchResult, key: &str) -> Option<serde_json::Value> {
+    result
+        .point
+        .payload
+        .as_ref()
+        .and_then(|p| p.get(key))
+        .cloned()
+}
+
+// =========================================================================
+// A. Both endpoints bound (bidirectional BFS)
+// =========================================================================
+
+/// GIVEN two routes from Alice to Zoe
+/// WHEN matching `shortestPath` between them
+/// THEN only the two-hop route is returned, while a plain MATCH sees both.
+#[test]
+fn test_shortest_path_between_bound_nodes() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let shortest = run_match(
+        &db,
+        "MATCH shortestPath((a {name: 'Alice'})-[r:KNOWS*..6]->(b {name: 'Zoe'})) RETURN r",
+    )
+    .expect("test: shortest path");
+    assert_eq!(shortest.len(), 1);
+    assert_eq!(shortest[0].point.id, 6);
+    assert_eq!(projected(&shortest[0], "r"), Some(json!([104, 105])));
+
+    let all = run_match(
+        &db,
+        "MATCH (a {name: 'Alice'})-[r:KNOWS*..6]->(b {name: 'Zoe'}) RETURN r",
+    )
+    .expect("test: all paths");
+    assert_eq!(all.len(), 2);
+}
+
+/// GIVEN the social graph
+/// WHEN the pattern direction or hop limit rules the path out
+/// THEN no row is returned.
+#[test]
+fn test_shortest_path_honours_direction_and_hops() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let reversed = run_match(
+        &db,
+        "MATCH shortestPath((a {name: 'Alice'})<-[:KNOWS*..10]-(b {name: 'Zoe'})) RETURN b",
+    )
+    .expect("test: reversed");
+    assert!(reversed.is_empty(), "{reversed:?}");
+
+    let too_short = run_match(
+        &db,
+        "MATCH shortestPath((a {name: 'Alice'})-[:KNOWS*..1]->(b {name: 'Zoe'})) RETURN b",
+    )
+    .expect("test: one hop");
+    assert!(too_short.is_empty(), "{too_short:?}");
+
+    let undirected = run_match(
+        &db,
+        "MATCH shortestPath((a {name: 'Zoe'})-[r:KNOWS*..10]-(b {name: 'Alice'})) RETURN r",
+    )
+    .expect("test: undirected");
+    assert_eq!(undirected.len(), 1);
+    assert_eq!(projected(&undirected[0], "r"), Some(json!([105, 104])));
+}
+
+// =========================================================================
+// B. Unbound end node (single-source BFS)
+// =========================================================================
+
+/// GIVEN the social graph
+/// WHEN the end node is unconstrained
+/// THEN every reachable person is returned once, with its hop count.
+#[test]
+fn test_shortest_path_to_every_reachable_node() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let results = run_match(
+        &db,
+        "MATCH shortestPath((a {name: 'Alice'})-[r:KNOWS*..10]->(b)) RETURN b, r",
+    )
+    .expect("test: single source");
+
+    assert_eq!(result_ids(&results), [2, 3, 4, 5, 6].into());
+    let zoe = results
+        .iter()
+        .find(|r| r.point.id == 6)
+        .expect("test: Zoe reached");
+    assert_eq!(projected(zoe, "r"), Some(json!([104, 105])));
+}
+
+/// GIVEN the social graph
+/// WHEN the WHERE clause filters the end node
+/// THEN only shortest paths to matching nodes remain.
+#[test]
+fn test_shortest_path_with_where_filter() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let results = run_match(
+        &db,
+        "MATCH shortestPath((a {name: 'Alice'})-[:KNOWS*..10]->(b)) WHERE b.name = 'Dan' RETURN b",
+    )
+    .expect("test: filtered");
+
+    assert_eq!(result_ids(&results), [4].into());
+}
+
+/// GIVEN the social graph
+/// WHEN the minimum length exceeds one hop
+/// THEN the query is rejected.
+#[test]
+fn test_shortest_path_rejects_min_length_above_one() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+
+    let err = run_match(
+        &db,
+        "MATCH shortestPath((a {name: 'Alice'})-[:KNOWS*2..5]->(b)) RETURN b",
+    )
+    .expect_err("test: min length 2");
+    assert!(matches!(err, velesdb_core::Error::Query(_)), "{err:?}");
+}
+
+// =========================================================================
+// C. Collection API
+// =========================================================================
+
+/// GIVEN the social graph
+/// WHEN asking the graph collection for a shortest path
+/// THEN it matches the MATCH result.
+#[test]
+fn test_graph_collection_shortest_path() {
+    let (_dir, db) = create_test_db();
+    setup_social_graph(&db);
+    let gc = db
+        .get_graph_collection("social")
+        .expect("test: get social graph");
+
+    let found = gc
+        .shortest_path(1, 6, &TraversalConfig::with_range(1, 6))
+        .expect("test: path exists");
+    assert_eq!(found.path, vec![104, 105]);
+    assert_eq!(found.depth, 2);
+    assert!(gc
+        .shortest_path(6, 1, &TraversalConfig::with_range(1, 6))
+        .is_none());
+}
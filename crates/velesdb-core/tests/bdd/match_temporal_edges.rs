@@ -0,0 +1,184 @@
+//! BDD tests for temporal edges: `valid_from` / `valid_to` windows.
+//!
+//! Covers the MATCH relationship filter `-[:REL {at: $t}]->`, the
+//! `TraversalConfig::valid_at` option, and validity windows surviving a
+//! flush + reopen of the edge snapshot.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use velesdb_core::{Database, GraphEdge, Point, SearchResult, TraversalConfig};
+
+use super::helpers::{create_test_db, result_ids};
+
+// =========================================================================
+// Module-specific setup
+// =========================================================================
+
+/// Builds a params map with the `_collection` routing key and `$t`.
+fn temporal_params(t: serde_json::Value) -> HashMap<String, serde_json::Value> {
+    let mut params = HashMap::new();
+    params.insert("_collection".to_string(), json!("history"));
+    params.insert("t".to_string(), t);
+    params
+}
+
+/// Parses `sql` and executes it against `history` with `$t` bound.
+fn run_at(
+    db: &Database,
+    sql: &str,
+    t: serde_json::Value,
+) -> velesdb_core::Result<Vec<SearchResult>> {
+    let query = velesdb_core::velesql::Parser::parse(sql).expect("test: parse MATCH query");
+    db.execute_query(&query, &temporal_params(t))
+}
+
+/// GIVEN base: Alice (1) worked at Acme (2) from 2000 until 2010, then at
+/// Globex (3) from 2010 on; she has known Bob (4) forever.
+fn setup_history_collection(db: &Database) {
+    db.create_vector_collection("history", 4, velesdb_core::DistanceMetric::Cosine)
+        .expect("test: create history collection");
+    let vc = db
+        .get_vector_collection("history")
+        .expect("test: get history collection");
+
+    vc.upsert(vec![
+        Point::new(
+            1,
+            vec![1.0, 0.0, 0.0, 0.0],
+            Some(json!({"_labels": ["Person"], "name": "Alice"})),
+        ),
+        Point::new(2, vec![0.0, 1.0, 0.0, 0.0], Some(json!({"name": "Acme"}))),
+        Point::new(3, vec![0.0, 0.0, 1.0, 0.0], Some(json!({"name": "Globex"}))),
+        Point::new(4, vec![0.0, 0.0, 0.0, 1.0], Some(json!({"name": "Bob"}))),
+    ])
+    .expect("test: upsert nodes");
+
+    let edges = [
+        GraphEdge::new(10, 1, 2, "WORKS_AT")
+            .expect("test: create edge 1->2")
+            .with_validity(Some(2000), Some(2010)),
+        GraphEdge::new(11, 1, 3, "WORKS_AT")
+            .expect("test: create edge 1->3")
+            .with_validity(Some(2010), None),
+        GraphEdge::new(12, 1, 4, "KNOWS").expect("test: create edge 1->4"),
+    ];
+    for edge in edges {
+        vc.add_edge(edge).expect("test: add edge");
+    }
+}
+
+const WORKS_AT_SQL: &str = "MATCH (a:Person)-[:WORKS_AT {at: $t}]->(b) RETURN a, b LIMIT 10";
+
+// =========================================================================
+// A. MATCH {at: $t}
+// =========================================================================
+
+/// GIVEN Alice's employment history
+/// WHEN matching `-[:WORKS_AT {at: $t}]->` at different times
+/// THEN only the employer valid at `$t` is returned, and no edge before 2000.
+#[test]
+fn test_match_at_param_selects_edges_valid_at_time() {
+    let (_dir, db) = create_test_db();
+    setup_history_collection(&db);
+
+    let at_2005 = run_at(&db, WORKS_AT_SQL, json!(2005)).expect("test: match 2005");
+    assert_eq!(result_ids(&at_2005), [2].into());
+
+    let at_2020 = run_at(&db, WORKS_AT_SQL, json!(2020)).expect("test: match 2020");
+    assert_eq!(result_ids(&at_2020), [3].into());
+
+    let at_1990 = run_at(&db, WORKS_AT_SQL, json!(1990)).expect("test: match 1990");
+    assert!(at_1990.is_empty(), "no employer before 2000: {at_1990:?}");
+}
+
+/// GIVEN Alice's employment history
+/// WHEN matching with a literal `at` on the 2010 boundary, and on an
+///      unbounded `KNOWS` edge
+/// THEN `valid_to` is exclusive and edges without bounds always match.
+#[test]
+fn test_match_at_literal_is_half_open_and_keeps_unbounded_edges() {
+    let (_dir, db) = create_test_db();
+    setup_history_collection(&db);
+
+    let boundary = run_at(
+        &db,
+        "MATCH (a:Person)-[:WORKS_AT {at: 2010}]->(b) RETURN a, b LIMIT 10",
+        json!(null),
+    )
+    .expect("test: match boundary");
+    assert_eq!(result_ids(&boundary), [3].into());
+
+    let knows = run_at(
+        &db,
+        "MATCH (a:Person)-[:KNOWS {at: $t}]->(b) RETURN a, b LIMIT 10",
+        json!(1),
+    )
+    .expect("test: match KNOWS");
+    assert_eq!(result_ids(&knows), [4].into());
+}
+
+/// GIVEN Alice's employment history
+/// WHEN `$t` is not an integer timestamp
+/// THEN the query is rejected instead of silently matching nothing.
+#[test]
+fn test_match_at_rejects_non_integer_time() {
+    let (_dir, db) = create_test_db();
+    setup_history_collection(&db);
+
+    let err = run_at(&db, WORKS_AT_SQL, json!("2005")).expect_err("test: string time");
+    assert!(matches!(err, velesdb_core::Error::Query(_)), "got {err:?}");
+}
+
+// =========================================================================
+// B. Traversal and persistence
+// =========================================================================
+
+/// GIVEN Alice's employment history
+/// WHEN traversing with `valid_at`
+/// THEN expired and not-yet-valid edges are not followed.
+#[test]
+fn test_traversal_valid_at_skips_edges_outside_window() {
+    let (_dir, db) = create_test_db();
+    setup_history_collection(&db);
+    let vc = db
+        .get_vector_collection("history")
+        .expect("test: get history collection");
+
+    let reached = |config: TraversalConfig| -> Vec<u64> {
+        let mut ids: Vec<u64> = vc
+            .traverse_bfs(1, &config)
+            .iter()
+            .map(|r| r.target_id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    };
+    assert_eq!(reached(TraversalConfig::default()), vec![2, 3, 4]);
+    assert_eq!(
+        reached(TraversalConfig::default().with_valid_at(2005)),
+        vec![2, 4]
+    );
+    assert_eq!(
+        reached(TraversalConfig::default().with_valid_at(2010)),
+        vec![3, 4]
+    );
+}
+
+/// GIVEN Alice's employment history, flushed to disk
+/// WHEN the database is reopened
+/// THEN the validity windows (edge properties) are still applied.
+#[test]
+fn test_validity_windows_survive_reopen() {
+    let (dir, db) = create_test_db();
+    setup_history_collection(&db);
+    db.get_vector_collection("history")
+        .expect("test: get history collection")
+        .flush()
+        .expect("test: flush");
+    drop(db);
+
+    let db = Database::open(dir.path()).expect("test: reopen database");
+    let at_2005 = run_at(&db, WORKS_AT_SQL, json!(2005)).expect("test: match after reopen");
+    assert_eq!(result_ids(&at_2005), [2].into());
+}
@@ -0,0 +1,157 @@
+//! BDD tests for weighted, cost-bounded paths.
+//!
+//! Covers the MATCH relationship budget `-[:REL*1..n {max_cost: c}]->`,
+//! which sums the `weight` property of the edges on a variable-length path,
+//! and `TraversalConfig::max_cost` on the collection traversal API.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use velesdb_core::{Database, GraphEdge, Point, SearchResult, TraversalConfig};
+
+use super::helpers::{create_test_db, result_ids};
+
+// =========================================================================
+// Module-specific setup
+// =========================================================================
+
+/// Parses `sql` and executes it against `roads` with `$budget` bound.
+fn run_with_budget(
+    db: &Database,
+    sql: &str,
+    budget: serde_json::Value,
+) -> velesdb_core::Result<Vec<SearchResult>> {
+    let mut params = HashMap::new();
+    params.insert("_collection".to_string(), json!("roads"));
+    params.insert("budget".to_string(), budget);
+    let query = velesdb_core::velesql::Parser::parse(sql).expect("test: parse MATCH query");
+    db.execute_query(&query, &params)
+}
+
+/// GIVEN base: a road chain A (1) -> B (2) -> C (3) -> D (4), 2 km per
+/// leg, plus a 10 km bypass A -> D.
+fn setup_roads_collection(db: &Database) {
+    db.create_vector_collection("roads", 4, velesdb_core::DistanceMetric::Cosine)
+        .expect("test: create roads collection");
+    let vc = db
+        .get_vector_collection("roads")
+        .expect("test: get roads collection");
+
+    vc.upsert(vec![
+        Point::new(
+            1,
+            vec![1.0, 0.0, 0.0, 0.0],
+            Some(json!({"_labels": ["City"], "name": "A"})),
+        ),
+        Point::new(2, vec![0.0, 1.0, 0.0, 0.0], Some(json!({"name": "B"}))),
+        Point::new(3, vec![0.0, 0.0, 1.0, 0.0], Some(json!({"name": "C"}))),
+        Point::new(4, vec![0.0, 0.0, 0.0, 1.0], Some(json!({"name": "D"}))),
+    ])
+    .expect("test: upsert cities");
+
+    for (id, source, target, km) in [
+        (10, 1, 2, 2.0),
+        (11, 2, 3, 2.0),
+        (12, 3, 4, 2.0),
+        (13, 1, 4, 10.0),
+    ] {
+        let edge = GraphEdge::new(id, source, target, "ROAD")
+            .expect("test: create road")
+            .with_properties([("weight".to_string(), json!(km))].into());
+        vc.add_edge(edge).expect("test: add road");
+    }
+}
+
+const BUDGET_SQL: &str =
+    "MATCH (a:City)-[:ROAD*1..5 {max_cost: $budget}]->(b) RETURN a, b LIMIT 10";
+
+// =========================================================================
+// A. MATCH {max_cost: ...}
+// =========================================================================
+
+/// GIVEN the road network
+/// WHEN matching variable-length roads under different budgets
+/// THEN only cities reachable within the summed distance are returned.
+#[test]
+fn test_match_max_cost_bounds_summed_weight() {
+    let (_dir, db) = create_test_db();
+    setup_roads_collection(&db);
+
+    let within_5 = run_with_budget(&db, BUDGET_SQL, json!(5.0)).expect("test: budget 5");
+    assert_eq!(result_ids(&within_5), [2, 3].into());
+
+    let within_6 = run_with_budget(&db, BUDGET_SQL, json!(6)).expect("test: budget 6");
+    assert_eq!(result_ids(&within_6), [2, 3, 4].into());
+
+    let within_1 = run_with_budget(&db, BUDGET_SQL, json!(1.5)).expect("test: budget 1.5");
+    assert!(within_1.is_empty(), "no road is that short: {within_1:?}");
+}
+
+/// GIVEN the road network
+/// WHEN the budget is a literal and the hop range allows a single hop
+/// THEN the costly bypass is kept only when it fits the budget.
+#[test]
+fn test_match_max_cost_literal_with_single_hop() {
+    let (_dir, db) = create_test_db();
+    setup_roads_collection(&db);
+
+    let tight = run_with_budget(
+        &db,
+        "MATCH (a:City)-[:ROAD*1..1 {max_cost: 9.5}]->(b) RETURN a, b LIMIT 10",
+        json!(null),
+    )
+    .expect("test: tight budget");
+    assert_eq!(result_ids(&tight), [2].into());
+
+    let loose = run_with_budget(
+        &db,
+        "MATCH (a:City)-[:ROAD*1..1 {max_cost: 10}]->(b) RETURN a, b LIMIT 10",
+        json!(null),
+    )
+    .expect("test: loose budget");
+    assert_eq!(result_ids(&loose), [2, 4].into());
+}
+
+/// GIVEN the road network
+/// WHEN the budget is negative or not a number
+/// THEN the query is rejected instead of silently matching nothing.
+#[test]
+fn test_match_max_cost_rejects_invalid_budget() {
+    let (_dir, db) = create_test_db();
+    setup_roads_collection(&db);
+
+    for budget in [json!(-1.0), json!("5")] {
+        let err = run_with_budget(&db, BUDGET_SQL, budget.clone()).expect_err("test: bad budget");
+        assert!(
+            matches!(err, velesdb_core::Error::Query(_)),
+            "{budget}: got {err:?}"
+        );
+    }
+}
+
+// =========================================================================
+// B. Traversal API
+// =========================================================================
+
+/// GIVEN the road network
+/// WHEN traversing from A with `max_cost`
+/// THEN cities come back cheapest first, each along its cheapest path.
+#[test]
+fn test_traversal_max_cost_orders_by_distance() {
+    let (_dir, db) = create_test_db();
+    setup_roads_collection(&db);
+    let vc = db
+        .get_vector_collection("roads")
+        .expect("test: get roads collection");
+
+    let config = TraversalConfig::with_range(1, 5).with_max_cost(6.0);
+    let results = vc.traverse_bfs(1, &config);
+    let reached: Vec<(u64, Vec<u64>)> = results
+        .iter()
+        .map(|r| (r.target_id, r.path.clone()))
+        .collect();
+    assert_eq!(
+        reached,
+        vec![(2, vec![10]), (3, vec![10, 11]), (4, vec![10, 11, 12])]
+    );
+}
@@ -431,6 +431,7 @@ fn test_regression_match_return_wildcard_populates_projected() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new().with_alias("a"),
                 velesdb_core::velesql::NodePattern::new().with_alias("b"),
@@ -447,6 +448,7 @@ fn test_regression_match_return_wildcard_populates_projected() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -485,6 +487,7 @@ fn test_regression_match_return_property_path_populates_projected() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new().with_alias("a"),
                 velesdb_core::velesql::NodePattern::new().with_alias("b"),
@@ -501,6 +504,7 @@ fn test_regression_match_return_property_path_populates_projected() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -532,6 +536,7 @@ fn test_regression_match_return_bare_alias_populates_projected() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new().with_alias("a"),
                 velesdb_core::velesql::NodePattern::new().with_alias("b"),
@@ -548,6 +553,7 @@ fn test_regression_match_return_bare_alias_populates_projected() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -585,6 +591,7 @@ fn test_regression_match_single_node_return_bare_alias() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![velesdb_core::velesql::NodePattern::new().with_alias("n")],
             relationships: vec![], // No relationships = single-node path
         }],
@@ -596,6 +603,7 @@ fn test_regression_match_single_node_return_bare_alias() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
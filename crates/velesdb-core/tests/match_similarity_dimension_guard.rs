@@ -45,6 +45,7 @@ fn setup_collection_with_graph() -> (TempDir, VectorCollection) {
 fn build_match_clause() -> MatchClause {
     let pattern = GraphPattern {
         name: None,
+        shortest_path: false,
         nodes: vec![
             NodePattern::new().with_alias("a"),
             NodePattern::new().with_alias("b"),
@@ -68,6 +69,7 @@ fn build_match_clause() -> MatchClause {
             }],
             order_by: None,
             limit: Some(10),
+            limit_per: None,
         },
     }
 }
@@ -203,6 +203,7 @@ fn test_composite_property_filter_via_match() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new()
                     .with_alias("n")
@@ -236,6 +237,7 @@ fn test_composite_property_filter_via_match() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -438,6 +440,7 @@ fn test_match_where_range_filter_gt() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new()
                     .with_alias("n")
@@ -462,6 +465,7 @@ fn test_match_where_range_filter_gt() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -528,6 +532,7 @@ fn test_label_index_auto_populated_on_insert() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new()
                     .with_alias("p")
@@ -546,6 +551,7 @@ fn test_label_index_auto_populated_on_insert() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -679,6 +685,7 @@ fn test_match_nonexistent_label_returns_empty() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new()
                     .with_alias("n")
@@ -694,6 +701,7 @@ fn test_match_nonexistent_label_returns_empty() {
             items: vec![],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -830,6 +838,7 @@ fn test_match_label_plus_where_combined() {
     let match_clause = velesdb_core::velesql::MatchClause {
         patterns: vec![velesdb_core::velesql::GraphPattern {
             name: None,
+            shortest_path: false,
             nodes: vec![
                 velesdb_core::velesql::NodePattern::new()
                     .with_alias("n")
@@ -854,6 +863,7 @@ fn test_match_label_plus_where_combined() {
             }],
             order_by: None,
             limit: Some(100),
+            limit_per: None,
         },
     };
 
@@ -0,0 +1,110 @@
+#![cfg(feature = "persistence")]
+//! Allocation budget of the zero-copy read views.
+//!
+//! `search` and `get` copy every vector into its own `Vec<f32>`;
+//! `search_view` and `get_view` borrow them from the mapping instead. A
+//! counting global allocator (per thread, so parallel tests don't interfere)
+//! checks that the views save at least one allocation per returned point.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use velesdb_core::{DistanceMetric, Point, StorageMode, VectorCollection};
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: Delegates every call to `System`; the counter is a const-initialised
+// thread-local that never allocates itself.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        // SAFETY: Same contract as the caller's.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: Same contract as the caller's.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        // SAFETY: Same contract as the caller's.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations_during<R>(f: impl FnOnce() -> R) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    drop(f());
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+const DIM: usize = 64;
+const K: usize = 10;
+
+fn seeded() -> (tempfile::TempDir, VectorCollection) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let coll = VectorCollection::create(
+        dir.path().join("v"),
+        "v",
+        DIM,
+        DistanceMetric::Cosine,
+        StorageMode::Full,
+    )
+    .expect("create");
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<Point> = (0..200usize)
+        .map(|i| {
+            let vector = (0..DIM).map(|d| ((i * 7 + d) % 13) as f32 + 1.0).collect();
+            Point::without_payload(i as u64, vector)
+        })
+        .collect();
+    coll.upsert(points).expect("upsert");
+    (dir, coll)
+}
+
+#[test]
+fn test_search_view_allocates_less_than_search() {
+    let (_dir, coll) = seeded();
+    let query = vec![1.0; DIM];
+    // Warm up lazily initialised state so both measurements see the same path.
+    let _ = coll.search(&query, K).expect("search");
+
+    let copied = allocations_during(|| coll.search(&query, K).expect("search"));
+    let borrowed = allocations_during(|| {
+        let view = coll.search_view(&query, K, false).expect("search view");
+        assert_eq!(view.len(), K);
+        view.iter().map(|(_, v)| v[0]).sum::<f32>()
+    });
+
+    assert!(
+        copied >= borrowed + K,
+        "search_view should save one allocation per hit: search={copied}, search_view={borrowed}"
+    );
+}
+
+#[test]
+fn test_get_view_allocates_less_than_get() {
+    let (_dir, coll) = seeded();
+    let ids: Vec<u64> = (0..50).collect();
+    let _ = coll.get(&ids);
+
+    let copied = allocations_during(|| coll.get(&ids));
+    let borrowed = allocations_during(|| {
+        let view = coll.get_view(&ids, false);
+        view.iter().map(|(_, v)| v[0]).sum::<f32>()
+    });
+
+    assert!(
+        copied >= borrowed + ids.len(),
+        "get_view should save one allocation per point: get={copied}, get_view={borrowed}"
+    );
+}
@@ -92,7 +92,8 @@ fn test_search_quality_adaptive_conversion() {
         core,
         CoreSearchQuality::Adaptive {
             min_ef: 32,
-            max_ef: 512
+            max_ef: 512,
+            ..
         }
     ));
 }
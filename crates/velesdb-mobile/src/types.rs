@@ -157,9 +157,9 @@ pub enum SearchQuality {
         /// The `ef_search` expansion factor.
         ef: u32,
     },
-    /// Adaptive two-phase search that starts low and doubles if needed.
+    /// Adaptive search that stops early once the top-k settles.
     Adaptive {
-        /// Minimum `ef_search` (starting point).
+        /// Minimum `ef_search` before early termination may kick in.
         min_ef: u32,
         /// Maximum `ef_search` (cap).
         max_ef: u32,
@@ -178,10 +178,10 @@ impl From<SearchQuality> for velesdb_core::SearchQuality {
             SearchQuality::Custom { ef } => {
                 velesdb_core::SearchQuality::Custom(usize::try_from(ef).unwrap_or(usize::MAX))
             }
-            SearchQuality::Adaptive { min_ef, max_ef } => velesdb_core::SearchQuality::Adaptive {
-                min_ef: usize::try_from(min_ef).unwrap_or(usize::MAX),
-                max_ef: usize::try_from(max_ef).unwrap_or(usize::MAX),
-            },
+            SearchQuality::Adaptive { min_ef, max_ef } => velesdb_core::SearchQuality::adaptive(
+                usize::try_from(min_ef).unwrap_or(usize::MAX),
+                usize::try_from(max_ef).unwrap_or(usize::MAX),
+            ),
             SearchQuality::AutoTune => velesdb_core::SearchQuality::AutoTune,
         }
     }
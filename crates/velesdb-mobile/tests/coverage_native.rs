@@ -187,6 +187,8 @@ fn diagnostics_maps_needs_rebuild_branch() {
         index_health: velesdb_core::collection::IndexHealth::NeedsRebuild(
             "schema changed".to_string(),
         ),
+        pending_index_ops: 0,
+        wal_backlog_bytes: 0,
     };
 
     let mobile: MobileCollectionDiagnostics = core.into();
@@ -2761,6 +2761,7 @@ class LimitsOptions:
     max_vectors_per_collection: Optional[int]
     max_payload_size: Optional[int]
     max_perfect_mode_vectors: Optional[int]
+    max_query_limit: Optional[int]
 
     def __init__(
         self,
@@ -2769,6 +2770,7 @@ class LimitsOptions:
         max_vectors_per_collection: Optional[int] = None,
         max_payload_size: Optional[int] = None,
         max_perfect_mode_vectors: Optional[int] = None,
+        max_query_limit: Optional[int] = None,
     ) -> None: ...
 
 
@@ -736,7 +736,7 @@ fn parse_adaptive_params(params: &str) -> PyResult<velesdb_core::SearchQuality>
             "Adaptive min_ef ({min_ef}) must be <= max_ef ({max_ef})"
         )));
     }
-    Ok(velesdb_core::SearchQuality::Adaptive { min_ef, max_ef })
+    Ok(velesdb_core::SearchQuality::adaptive(min_ef, max_ef))
 }
 
 #[cfg(test)]
@@ -835,7 +835,8 @@ mod tests {
             q,
             velesdb_core::SearchQuality::Adaptive {
                 min_ef: 32,
-                max_ef: 512
+                max_ef: 512,
+                ..
             }
         ));
     }
@@ -848,7 +849,8 @@ mod tests {
             q,
             velesdb_core::SearchQuality::Adaptive {
                 min_ef: 100,
-                max_ef: 100
+                max_ef: 100,
+                ..
             }
         ));
     }
@@ -861,7 +863,8 @@ mod tests {
             q,
             velesdb_core::SearchQuality::Adaptive {
                 min_ef: 16,
-                max_ef: 256
+                max_ef: 256,
+                ..
             }
         ));
     }
@@ -62,6 +62,7 @@ use velesdb_core::{Filter, Point, SearchResult};
 /// | VELES-034  | `InvalidCollectionName`   | `ValueError`                  |
 /// | VELES-035  | `SnapshotBuildFailed`     | `VelesDBError`                |
 /// | VELES-036  | `IncompatibleSchemaVersion` | `VelesDBError`              |
+/// | VELES-037  | `QueryLimitExceeded`      | `ValueError`                  |
 ///
 /// The wildcard arm at the bottom handles future variants added under
 /// the `#[non_exhaustive]` attribute on `velesdb_core::Error`. New
@@ -102,7 +103,8 @@ pub fn core_err(e: velesdb_core::Error) -> PyErr {
         | E::InvalidEdgeLabel(_)
         | E::InvalidQuantizerConfig(_)
         | E::InvalidDimension { .. }
-        | E::InvalidCollectionName { .. } => PyValueError::new_err(e.to_string()),
+        | E::InvalidCollectionName { .. }
+        | E::QueryLimitExceeded { .. } => PyValueError::new_err(e.to_string()),
 
         // Numeric overflow / allocation failure — specific Python builtins
         E::Overflow(_) => PyOverflowError::new_err(e.to_string()),
@@ -515,6 +515,10 @@ mod tests {
                     found: 2,
                     supported: 1,
                 },
+                CoreError::QueryLimitExceeded {
+                    requested: 2,
+                    max: 1,
+                },
             ];
             // VELES-011 (Io) requires a std::io::Error which we construct
             // explicitly rather than inline into the vec literal.
@@ -664,6 +664,8 @@ fn build_traversal_config(
         limit: limit.unwrap_or(100),
         rel_types: rel_types.unwrap_or_default(),
         deadline: None,
+        valid_at: None,
+        max_cost: None,
     }
 }
 
@@ -239,6 +239,8 @@ impl GraphStore {
             rel_types,
             limit: Some(config.max_visited),
             deadline: None,
+            valid_at: None,
+            max_cost: None,
         };
 
         // Release GIL during traversal (no Py<PyAny> involved)
@@ -218,7 +218,7 @@ impl HnswOptions {
             ef_construction: self.ef_construction.unwrap_or(base.ef_construction),
             max_elements: self.max_elements.unwrap_or(base.max_elements),
             alpha: self.alpha.unwrap_or(base.alpha),
-            storage_mode: base.storage_mode,
+            ..base
         };
         params
             .validate()
@@ -248,7 +248,7 @@ impl HnswOptions {
 /// [`velesdb_core::config::LimitsConfig`].
 ///
 /// All fields are optional — unspecified fields fall back to the
-/// engine defaults (max_collections=1000, max_dimensions=4096, etc.).
+/// engine defaults (max_collections=1000, max_dimensions=8192, etc.).
 ///
 /// Enforcement status:
 /// - `max_collections` — enforced at collection creation (Commit 7)
@@ -256,6 +256,7 @@ impl HnswOptions {
 /// - `max_vectors_per_collection` — enforced at the runtime ingest/search boundary (GuardRail VELES-027)
 /// - `max_payload_size` — enforced at the runtime ingest/search boundary (GuardRail VELES-027)
 /// - `max_perfect_mode_vectors` — enforced at the runtime ingest/search boundary (GuardRail VELES-027)
+/// - `max_query_limit` — enforced per query (QueryLimitExceeded VELES-037)
 ///
 /// See `docs/CORE_WIRING_DEBT.md` for the enforcement roadmap.
 #[pyclass(module = "velesdb", from_py_object)]
@@ -277,6 +278,9 @@ pub struct LimitsOptions {
     /// enforced).
     #[pyo3(get, set)]
     pub max_perfect_mode_vectors: Option<usize>,
+    /// Maximum rows a single query may request. Default: 100000.
+    #[pyo3(get, set)]
+    pub max_query_limit: Option<usize>,
 }
 
 #[pymethods]
@@ -288,6 +292,7 @@ impl LimitsOptions {
         max_vectors_per_collection = None,
         max_payload_size = None,
         max_perfect_mode_vectors = None,
+        max_query_limit = None,
     ))]
     fn new(
         max_collections: Option<usize>,
@@ -295,6 +300,7 @@ impl LimitsOptions {
         max_vectors_per_collection: Option<usize>,
         max_payload_size: Option<usize>,
         max_perfect_mode_vectors: Option<usize>,
+        max_query_limit: Option<usize>,
     ) -> Self {
         Self {
             max_collections,
@@ -302,17 +308,19 @@ impl LimitsOptions {
             max_vectors_per_collection,
             max_payload_size,
             max_perfect_mode_vectors,
+            max_query_limit,
         }
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "LimitsOptions(max_collections={:?}, max_dimensions={:?}, max_vectors_per_collection={:?}, max_payload_size={:?}, max_perfect_mode_vectors={:?})",
+            "LimitsOptions(max_collections={:?}, max_dimensions={:?}, max_vectors_per_collection={:?}, max_payload_size={:?}, max_perfect_mode_vectors={:?}, max_query_limit={:?})",
             self.max_collections,
             self.max_dimensions,
             self.max_vectors_per_collection,
             self.max_payload_size,
             self.max_perfect_mode_vectors,
+            self.max_query_limit,
         )
     }
 }
@@ -331,6 +339,7 @@ impl LimitsOptions {
         cfg.max_perfect_mode_vectors = self
             .max_perfect_mode_vectors
             .unwrap_or(cfg.max_perfect_mode_vectors);
+        cfg.max_query_limit = self.max_query_limit.unwrap_or(cfg.max_query_limit);
         cfg
     }
 
@@ -344,6 +353,7 @@ impl LimitsOptions {
             max_vectors_per_collection: Some(core.max_vectors_per_collection),
             max_payload_size: Some(core.max_payload_size),
             max_perfect_mode_vectors: Some(core.max_perfect_mode_vectors),
+            max_query_limit: Some(core.max_query_limit),
         }
     }
 }
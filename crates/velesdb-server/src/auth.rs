@@ -3,6 +3,11 @@
 //! When `api_keys` is non-empty, all requests except those to public paths
 //! (e.g. `GET /health`) must include a valid `Authorization: Bearer <key>` header.
 //! When `api_keys` is empty, authentication is disabled (local dev mode).
+//!
+//! Every authenticated request carries the key's [`RequestContext`] as a
+//! request extension (empty when the key has no `[auth.key_contexts]`
+//! entry); read handlers hand it to the database so row-security policies
+//! are applied.
 
 use axum::{
     body::Body,
@@ -12,7 +17,10 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use parking_lot::RwLock;
+use std::collections::HashMap;
 use std::sync::Arc;
+use velesdb_core::RequestContext;
 
 /// Constant-time byte comparison to prevent timing side-channel attacks.
 ///
@@ -38,15 +46,21 @@ fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
 }
 
 /// Checks whether `token` matches any configured API key in constant time.
+#[cfg(test)]
+fn any_key_matches(keys: &[String], token: &str) -> bool {
+    matching_key(keys, token).is_some()
+}
+
+/// Returns the index of the configured API key equal to `token`.
 ///
 /// Iterates over **all** keys regardless of early matches to avoid leaking
 /// which key (if any) was correct through timing differences.
-fn any_key_matches(keys: &[String], token: &str) -> bool {
+fn matching_key(keys: &[String], token: &str) -> Option<usize> {
     let token_bytes = token.as_bytes();
-    let mut matched = false;
-    for key in keys {
+    let mut matched = None;
+    for (index, key) in keys.iter().enumerate() {
         if constant_time_eq(key.as_bytes(), token_bytes) {
-            matched = true;
+            matched = Some(index);
         }
         // Do NOT early-return — iterate all keys unconditionally.
     }
@@ -54,31 +68,71 @@ fn any_key_matches(keys: &[String], token: &str) -> bool {
 }
 
 /// Shared authentication state injected into the middleware.
+///
+/// Clones share one key list, so [`set_api_keys`](Self::set_api_keys) on
+/// any clone applies to every request that starts afterwards; requests
+/// already past the middleware are unaffected.
 #[derive(Debug, Clone)]
 pub struct AuthState {
     /// Allowed API keys. Empty means auth is disabled.
-    pub api_keys: Arc<Vec<String>>,
+    api_keys: Arc<RwLock<Arc<Vec<String>>>>,
+    /// Request context attached to requests made with a given key.
+    key_contexts: Arc<RwLock<Arc<HashMap<String, RequestContext>>>>,
 }
 
 impl AuthState {
     /// Create a new `AuthState` from a list of API keys.
     pub fn new(api_keys: Vec<String>) -> Self {
         Self {
-            api_keys: Arc::new(api_keys),
+            api_keys: Arc::new(RwLock::new(Arc::new(api_keys))),
+            key_contexts: Arc::default(),
         }
     }
 
+    /// Sets the request context attached to each key's requests.
+    #[must_use]
+    pub fn with_key_contexts(self, key_contexts: HashMap<String, RequestContext>) -> Self {
+        self.set_key_contexts(key_contexts);
+        self
+    }
+
+    /// Snapshot of the allowed API keys.
+    pub fn api_keys(&self) -> Arc<Vec<String>> {
+        Arc::clone(&self.api_keys.read())
+    }
+
+    /// Atomically replaces the allowed API keys (config reload).
+    pub fn set_api_keys(&self, api_keys: Vec<String>) {
+        *self.api_keys.write() = Arc::new(api_keys);
+    }
+
+    /// Atomically replaces the per-key request contexts (config reload).
+    pub fn set_key_contexts(&self, key_contexts: HashMap<String, RequestContext>) {
+        *self.key_contexts.write() = Arc::new(key_contexts);
+    }
+
+    /// Request context for `key`; empty when the key has none configured.
+    pub fn key_context(&self, key: &str) -> RequestContext {
+        self.key_contexts
+            .read()
+            .get(key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// Returns `true` when authentication is enabled.
     pub fn auth_enabled(&self) -> bool {
-        !self.api_keys.is_empty()
+        !self.api_keys.read().is_empty()
     }
 }
 
 /// Paths that bypass authentication (both legacy and `/v1/` versioned).
 ///
 /// `/health` and `/ready` are the only genuinely public endpoints — they
-/// expose no internal state beyond liveness booleans and are needed by
-/// container orchestrators that cannot carry authentication headers.
+/// expose only aggregate component status (counts, disk bytes, no
+/// collection names or errors) and are needed by container orchestrators
+/// that cannot carry authentication headers. `/health/details` names the
+/// collections and therefore stays behind the API key gate.
 ///
 /// `/metrics` is **not** in this list. The Prometheus metrics endpoint
 /// returns detailed operational data (collection counts, cache hit
@@ -115,11 +169,12 @@ fn extract_bearer_token(header_value: &str) -> Option<&str> {
 /// Use with `axum::middleware::from_fn_with_state`.
 pub async fn auth_middleware(
     axum::extract::State(state): axum::extract::State<AuthState>,
-    request: Request<Body>,
+    mut request: Request<Body>,
     next: Next,
 ) -> Response {
     // Skip auth if disabled (no keys configured)
-    if !state.auth_enabled() {
+    let api_keys = state.api_keys();
+    if api_keys.is_empty() {
         return next.run(request).await;
     }
 
@@ -136,8 +191,14 @@ pub async fn auth_middleware(
 
     match auth_header {
         Some(value) => match extract_bearer_token(value) {
-            Some(token) if any_key_matches(&state.api_keys, token) => next.run(request).await,
-            Some(_) => unauthorized_response("invalid API key"),
+            Some(token) => match matching_key(&api_keys, token) {
+                Some(index) => {
+                    let context = state.key_context(&api_keys[index]);
+                    request.extensions_mut().insert(context);
+                    next.run(request).await
+                }
+                None => unauthorized_response("invalid API key"),
+            },
             None => {
                 unauthorized_response("invalid Authorization header format, expected: Bearer <key>")
             }
@@ -178,6 +239,41 @@ mod tests {
         assert!(state.auth_enabled());
     }
 
+    #[test]
+    fn test_set_api_keys_applies_to_all_clones() {
+        let state = AuthState::new(vec!["old".to_string()]);
+        let held = state.api_keys();
+        let clone = state.clone();
+
+        clone.set_api_keys(vec!["new".to_string()]);
+        assert_eq!(*state.api_keys(), vec!["new"]);
+        // A snapshot taken before the swap is unchanged.
+        assert_eq!(*held, vec!["old"]);
+
+        state.set_api_keys(Vec::new());
+        assert!(!clone.auth_enabled());
+    }
+
+    #[test]
+    fn test_key_context_defaults_to_empty() {
+        let scoped = RequestContext::new().with_attribute("tenant", "acme");
+        let state = AuthState::new(vec!["admin".to_string(), "acme".to_string()])
+            .with_key_contexts(HashMap::from([("acme".to_string(), scoped.clone())]));
+
+        assert_eq!(state.key_context("acme"), scoped);
+        assert!(state.key_context("admin").is_empty());
+
+        state.set_key_contexts(HashMap::new());
+        assert!(state.key_context("acme").is_empty());
+    }
+
+    #[test]
+    fn test_matching_key_returns_index() {
+        let keys = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(matching_key(&keys, "b"), Some(1));
+        assert_eq!(matching_key(&keys, "c"), None);
+    }
+
     #[test]
     fn test_is_public_path_health() {
         assert!(is_public_path("/health"));
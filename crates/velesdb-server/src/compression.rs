@@ -0,0 +1,42 @@
+//! gzip/zstd HTTP compression.
+//!
+//! [`build_compression_layer`] compresses responses (large search results,
+//! exports) for clients that send `Accept-Encoding: gzip` or `zstd`;
+//! [`build_decompression_layer`] transparently inflates request bodies sent
+//! with `Content-Encoding: gzip` or `zstd`, including the NDJSON stream of
+//! `POST /collections/{name}/points/stream`, which is decoded as it arrives.
+//! Body size limits apply to the decompressed bytes.
+
+use tower_http::compression::predicate::{And, NotForContentType, Predicate, SizeAbove};
+use tower_http::compression::{CompressionLayer, CompressionLevel};
+use tower_http::decompression::RequestDecompressionLayer;
+
+use crate::config::CompressionConfig;
+
+/// When a response is compressed: large enough, and not an event stream
+/// (SSE must reach the client unbuffered) or an already-compressed image.
+pub type ResponsePredicate = And<And<SizeAbove, NotForContentType>, NotForContentType>;
+
+/// Builds the response compression layer from the `[compression]` settings.
+///
+/// With `enabled = false` the layer never compresses.
+pub fn build_compression_layer(config: &CompressionConfig) -> CompressionLayer<ResponsePredicate> {
+    let level = config
+        .level
+        .map_or(CompressionLevel::Default, CompressionLevel::Precise);
+    let predicate = SizeAbove::new(config.min_size_bytes)
+        .and(NotForContentType::SSE)
+        .and(NotForContentType::IMAGES);
+    CompressionLayer::new()
+        .gzip(config.enabled)
+        .zstd(config.enabled)
+        .quality(level)
+        .compress_when(predicate)
+}
+
+/// Builds the layer that decompresses gzip/zstd request bodies.
+///
+/// Other encodings are rejected with `415 Unsupported Media Type`.
+pub fn build_decompression_layer() -> RequestDecompressionLayer {
+    RequestDecompressionLayer::new().gzip(true).zstd(true)
+}
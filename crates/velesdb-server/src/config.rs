@@ -4,7 +4,10 @@
 //! CLI flags > environment variables > velesdb.toml > defaults.
 
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use velesdb_core::guardrails::QueryLimits;
+use velesdb_core::RequestContext;
 
 // ============================================================================
 // Core engine configuration (issue #1549)
@@ -88,20 +91,28 @@ struct FileConfig {
     auth: Option<AuthSection>,
     tls: Option<TlsSection>,
     cors: Option<CorsSection>,
+    compression: Option<CompressionSection>,
+    guardrails: Option<QueryLimits>,
+    slo: Option<SloSection>,
+    embedder: Option<EmbedderSection>,
 }
 
 #[derive(Debug, Deserialize, Default)]
 struct ServerSection {
     host: Option<String>,
     port: Option<u16>,
+    uds_path: Option<String>,
     data_dir: Option<String>,
     shutdown_timeout_secs: Option<u64>,
     rate_limit: Option<u32>,
+    worker_threads: Option<usize>,
+    max_blocking_threads: Option<usize>,
 }
 
 #[derive(Debug, Deserialize, Default)]
 struct AuthSection {
     api_keys: Option<Vec<String>>,
+    key_contexts: Option<HashMap<String, RequestContext>>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -119,6 +130,26 @@ struct CorsSection {
     max_age_secs: Option<u64>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+struct SloSection {
+    webhook_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct EmbedderSection {
+    url: Option<String>,
+    model: Option<String>,
+    api_key: Option<String>,
+    timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct CompressionSection {
+    enabled: Option<bool>,
+    level: Option<i32>,
+    min_size_bytes: Option<u64>,
+}
+
 // ============================================================================
 // Resolved configuration
 // ============================================================================
@@ -142,13 +173,35 @@ impl TlsConfig {
     }
 }
 
+/// OpenAI-compatible embeddings service behind `POST /embeddings` and
+/// `POST /retrieval` (`[embedder]`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbedderConfig {
+    /// Embeddings endpoint, e.g. `http://localhost:8000/v1/embeddings`.
+    pub url: String,
+    /// Model name sent with every request.
+    pub model: String,
+    /// Sent as `Authorization: Bearer <key>` when set.
+    pub api_key: Option<String>,
+    /// Per-request timeout.
+    pub timeout_secs: u64,
+}
+
+/// Default `[embedder] timeout_secs`.
+const DEFAULT_EMBEDDER_TIMEOUT_SECS: u64 = 30;
+
 /// Final resolved server configuration.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    /// Unix domain socket to listen on instead of `host:port` (Unix only).
+    pub uds_path: Option<String>,
     pub data_dir: String,
     pub api_keys: Vec<String>,
+    /// Request context per API key (`[auth.key_contexts."<key>"]`), bound to
+    /// `$ctx.*` in row-security policies.
+    pub key_contexts: HashMap<String, RequestContext>,
     /// TLS certificate and key configuration (both or neither).
     pub tls: TlsConfig,
     pub shutdown_timeout_secs: u64,
@@ -156,6 +209,19 @@ pub struct ServerConfig {
     pub rate_limit: u32,
     /// CORS configuration for cross-origin requests.
     pub cors: CorsConfig,
+    /// gzip/zstd response compression.
+    pub compression: CompressionConfig,
+    /// Tokio worker threads (`None` = one per CPU core).
+    pub worker_threads: Option<usize>,
+    /// Upper bound on Tokio's blocking pool, which runs searches and other
+    /// CPU-bound handlers (`None` = Tokio default of 512).
+    pub max_blocking_threads: Option<usize>,
+    /// Initial query guard-rails; `PUT /guardrails` can change them at runtime.
+    pub guardrails: QueryLimits,
+    /// URL latency SLO breaches and recoveries are POSTed to (`[slo]`).
+    pub slo_webhook_url: Option<String>,
+    /// Embeddings service for the retrieval endpoints (`None` = disabled).
+    pub embedder: Option<EmbedderConfig>,
 }
 
 /// CORS configuration for the server.
@@ -180,6 +246,36 @@ pub struct CorsConfig {
     pub max_age_secs: u64,
 }
 
+/// Response compression settings (`[compression]`).
+///
+/// Responses are compressed with gzip or zstd when the client sends a
+/// matching `Accept-Encoding` and the body is at least `min_size_bytes`.
+/// Compressed request bodies (`Content-Encoding: gzip`/`zstd`) are always
+/// accepted, whatever these settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressionConfig {
+    /// Whether responses are compressed.
+    pub enabled: bool,
+    /// Algorithm-specific level (gzip 0-9, zstd 1-22, clamped to the
+    /// algorithm's range); `None` uses each algorithm's default.
+    pub level: Option<i32>,
+    /// Smallest response body, in bytes, worth compressing.
+    pub min_size_bytes: u64,
+}
+
+/// Default minimum response size for compression, in bytes.
+const DEFAULT_COMPRESSION_MIN_SIZE_BYTES: u64 = 1024;
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            level: None,
+            min_size_bytes: DEFAULT_COMPRESSION_MIN_SIZE_BYTES,
+        }
+    }
+}
+
 /// Default burst budget for rate limiting (requests per second per IP).
 const DEFAULT_RATE_LIMIT: u32 = 100;
 
@@ -217,16 +313,33 @@ impl Default for ServerConfig {
         Self {
             host: "127.0.0.1".to_string(),
             port: 8080,
+            uds_path: None,
             data_dir: "./velesdb_data".to_string(),
             api_keys: Vec::new(),
+            key_contexts: HashMap::new(),
             tls: TlsConfig::default(),
             shutdown_timeout_secs: 30,
             rate_limit: DEFAULT_RATE_LIMIT,
             cors: CorsConfig::default(),
+            compression: CompressionConfig::default(),
+            worker_threads: None,
+            max_blocking_threads: None,
+            guardrails: QueryLimits::default(),
+            slo_webhook_url: None,
+            embedder: None,
         }
     }
 }
 
+/// Configuration the server was started with, reported by `GET /config`.
+#[derive(Debug, Clone, Default)]
+pub struct EffectiveConfig {
+    /// Server transport settings.
+    pub server: ServerConfig,
+    /// Core engine configuration passed to the database.
+    pub engine: velesdb_core::config::VelesConfig,
+}
+
 // ============================================================================
 // Loading logic
 // ============================================================================
@@ -248,25 +361,61 @@ impl ServerConfig {
         let auth = file.auth.unwrap_or_default();
         let tls = file.tls.unwrap_or_default();
         let cors_section = file.cors.unwrap_or_default();
+        let compression_section = file.compression.unwrap_or_default();
+        let guardrails = file.guardrails.unwrap_or(defaults.guardrails);
+        let slo_webhook_url = file
+            .slo
+            .and_then(|slo| slo.webhook_url)
+            .or(defaults.slo_webhook_url);
+        // `url` switches the embedder on; a missing model is caught by validate.
+        let embedder = file
+            .embedder
+            .and_then(|section| {
+                Some(EmbedderConfig {
+                    url: section.url?,
+                    model: section.model.unwrap_or_default(),
+                    api_key: section.api_key,
+                    timeout_secs: section
+                        .timeout_secs
+                        .unwrap_or(DEFAULT_EMBEDDER_TIMEOUT_SECS),
+                })
+            })
+            .or(defaults.embedder);
 
         // Layer: TOML over defaults
         let host = server.host.unwrap_or(defaults.host);
         let port = server.port.unwrap_or(defaults.port);
+        let uds_path = server.uds_path.or(defaults.uds_path);
         let data_dir = server.data_dir.unwrap_or(defaults.data_dir);
         let shutdown_timeout_secs = server
             .shutdown_timeout_secs
             .unwrap_or(defaults.shutdown_timeout_secs);
         let rate_limit = server.rate_limit.unwrap_or(defaults.rate_limit);
+        let worker_threads = server.worker_threads.or(defaults.worker_threads);
+        let max_blocking_threads = server
+            .max_blocking_threads
+            .or(defaults.max_blocking_threads);
         let api_keys = auth.api_keys.unwrap_or(defaults.api_keys);
+        let key_contexts = auth.key_contexts.unwrap_or(defaults.key_contexts);
         let tls = TlsConfig {
             cert: tls.cert.or(defaults.tls.cert),
             key: tls.key.or(defaults.tls.key),
         };
-        let cors = resolve_cors(defaults.cors, cors_section);
+        let mut cors = resolve_cors(defaults.cors, cors_section);
+        let compression = CompressionConfig {
+            enabled: compression_section
+                .enabled
+                .unwrap_or(defaults.compression.enabled),
+            level: compression_section.level.or(defaults.compression.level),
+            min_size_bytes: compression_section
+                .min_size_bytes
+                .unwrap_or(defaults.compression.min_size_bytes),
+        };
 
         // Layer: CLI/env over TOML (only override when explicitly set)
         let host = cli.host.unwrap_or(host);
         let port = cli.port.unwrap_or(port);
+        let uds_path = cli.uds_path.or(uds_path);
         let data_dir = cli.data_dir.unwrap_or(data_dir);
         let api_keys = cli.api_keys.unwrap_or(api_keys);
         let tls = TlsConfig {
@@ -274,16 +423,30 @@ impl ServerConfig {
             key: cli.tls_key.or(tls.key),
         };
         let rate_limit = cli.rate_limit.unwrap_or(rate_limit);
+        let shutdown_timeout_secs = cli.shutdown_timeout_secs.unwrap_or(shutdown_timeout_secs);
+        let worker_threads = cli.worker_threads.or(worker_threads);
+        let max_blocking_threads = cli.max_blocking_threads.or(max_blocking_threads);
+        if let Some(origins) = cli.cors_allowed_origins {
+            cors.allowed_origins = origins;
+        }
 
         Self {
             host,
             port,
+            uds_path,
             data_dir,
             api_keys,
+            key_contexts,
             tls,
             shutdown_timeout_secs,
             rate_limit,
             cors,
+            compression,
+            worker_threads,
+            max_blocking_threads,
+            guardrails,
+            slo_webhook_url,
+            embedder,
         }
     }
 
@@ -295,6 +458,37 @@ impl ServerConfig {
         if self.data_dir.is_empty() {
             anyhow::bail!("data_dir must not be empty");
         }
+        if self.worker_threads == Some(0) {
+            anyhow::bail!("worker_threads must be at least 1");
+        }
+        if self.max_blocking_threads == Some(0) {
+            anyhow::bail!("max_blocking_threads must be at least 1");
+        }
+        // Never echo the key itself: the count is enough to find the typo.
+        let unknown = self
+            .key_contexts
+            .keys()
+            .filter(|key| !self.api_keys.contains(key))
+            .count();
+        if unknown > 0 {
+            anyhow::bail!("auth.key_contexts has {unknown} entry(ies) for keys not in api_keys");
+        }
+        if let Some(url) = &self.slo_webhook_url {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                anyhow::bail!("slo.webhook_url must be an http:// or https:// URL");
+            }
+        }
+        if let Some(embedder) = &self.embedder {
+            if !(embedder.url.starts_with("http://") || embedder.url.starts_with("https://")) {
+                anyhow::bail!("embedder.url must be an http:// or https:// URL");
+            }
+            if embedder.model.trim().is_empty() {
+                anyhow::bail!("embedder.model must not be empty");
+            }
+            if embedder.timeout_secs == 0 {
+                anyhow::bail!("embedder.timeout_secs must be at least 1");
+            }
+        }
 
         // TLS: both cert and key must be provided together
         match (&self.tls.cert, &self.tls.key) {
@@ -315,6 +509,20 @@ impl ServerConfig {
             (None, None) => {}
         }
 
+        if let Some(path) = &self.uds_path {
+            if !cfg!(unix) {
+                anyhow::bail!("uds_path is only supported on Unix");
+            }
+            if path.is_empty() {
+                anyhow::bail!("uds_path must not be empty");
+            }
+            if self.tls_enabled() {
+                anyhow::bail!(
+                    "TLS applies to the TCP listener; it cannot be combined with uds_path"
+                );
+            }
+        }
+
         Ok(())
     }
 
@@ -335,6 +543,8 @@ impl ServerConfig {
 
     /// Returns `true` when the bind host is reachable beyond the local machine.
     ///
+    /// Always `false` when listening on a Unix domain socket.
+    ///
     /// A loopback host is treated as private: `localhost`, any `127.0.0.0/8`
     /// address (`127.0.0.1`, `127.0.0.5`, …), IPv6 loopback `::1`, and the
     /// IPv4-mapped form `::ffff:127.0.0.1`. Matching is case-insensitive and
@@ -343,6 +553,9 @@ impl ServerConfig {
     /// hostname — is considered publicly reachable. Errs toward *over*-warning
     /// (an unrecognised host is treated as public), never under-warning.
     pub fn binds_publicly(&self) -> bool {
+        if self.uds_path.is_some() {
+            return false;
+        }
         // Case-insensitive, whitespace- and bracket-tolerant (`[::1]`).
         let host = self
             .host
@@ -369,16 +582,21 @@ impl ServerConfig {
 
 /// Values explicitly provided via CLI flags or environment variables.
 /// `None` means "not provided — fall through to TOML or default".
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct CliOverrides {
     pub config_path: Option<PathBuf>,
     pub host: Option<String>,
     pub port: Option<u16>,
+    pub uds_path: Option<String>,
     pub data_dir: Option<String>,
     pub api_keys: Option<Vec<String>>,
     pub tls_cert: Option<String>,
     pub tls_key: Option<String>,
     pub rate_limit: Option<u32>,
+    pub shutdown_timeout_secs: Option<u64>,
+    pub worker_threads: Option<usize>,
+    pub max_blocking_threads: Option<usize>,
+    pub cors_allowed_origins: Option<Vec<String>>,
 }
 
 // ============================================================================
@@ -465,6 +683,46 @@ pub fn build_cors_layer(cors: &CorsConfig) -> tower_http::cors::CorsLayer {
     }
 }
 
+/// CORS policy that can be replaced at runtime.
+///
+/// Clones share the current layer, so [`set`](Self::set) on any clone
+/// applies to every request that starts afterwards.
+#[derive(Clone)]
+pub struct CorsState {
+    layer: std::sync::Arc<parking_lot::RwLock<tower_http::cors::CorsLayer>>,
+}
+
+impl CorsState {
+    /// Creates the state from a resolved CORS config.
+    pub fn new(cors: &CorsConfig) -> Self {
+        Self {
+            layer: std::sync::Arc::new(parking_lot::RwLock::new(build_cors_layer(cors))),
+        }
+    }
+
+    /// Replaces the CORS policy (config reload).
+    pub fn set(&self, cors: &CorsConfig) {
+        *self.layer.write() = build_cors_layer(cors);
+    }
+}
+
+/// Axum middleware applying the current [`CorsState`] policy.
+///
+/// Use with `axum::middleware::from_fn_with_state`.
+pub async fn cors_middleware(
+    axum::extract::State(state): axum::extract::State<CorsState>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use tower::{Layer, ServiceExt};
+
+    let layer = state.layer.read().clone();
+    match layer.layer(next).oneshot(request).await {
+        Ok(response) => response,
+        Err(never) => match never {},
+    }
+}
+
 /// Applies the headers policy to a `CorsLayer`, honouring the CORS spec rule that
 /// `allow_credentials=true` is incompatible with wildcard headers (browsers reject
 /// the preflight). Logs a warning and falls back to default headers in that case.
@@ -502,21 +760,30 @@ fn apply_cors_headers_policy(
 }
 
 // ============================================================================
-// Helper: parse comma-separated API keys from env var
+// Helper: parse comma-separated lists from env vars
 // ============================================================================
 
 /// Parse `VELESDB_API_KEYS` env var (comma-separated) into a `Vec<String>`.
 pub fn parse_api_keys_env() -> Option<Vec<String>> {
-    let val = std::env::var("VELESDB_API_KEYS").ok()?;
-    let keys: Vec<String> = val
+    split_csv(&std::env::var("VELESDB_API_KEYS").ok()?)
+}
+
+/// Parse `VELESDB_CORS_ORIGINS` env var (comma-separated) into a `Vec<String>`.
+pub fn parse_cors_origins_env() -> Option<Vec<String>> {
+    split_csv(&std::env::var("VELESDB_CORS_ORIGINS").ok()?)
+}
+
+/// Trimmed, non-empty items of a comma-separated list; `None` if there are none.
+fn split_csv(val: &str) -> Option<Vec<String>> {
+    let items: Vec<String> = val
         .split(',')
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty())
         .collect();
-    if keys.is_empty() {
+    if items.is_empty() {
         None
     } else {
-        Some(keys)
+        Some(items)
     }
 }
 
@@ -684,6 +951,123 @@ key = "/etc/ssl/key.pem"
         assert!(cfg.tls_enabled());
     }
 
+    #[test]
+    fn test_slo_webhook_from_toml() {
+        let toml_content = r#"
+[slo]
+webhook_url = "https://alerts.example.com/velesdb"
+"#;
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, CliOverrides::default());
+        assert_eq!(
+            cfg.slo_webhook_url.as_deref(),
+            Some("https://alerts.example.com/velesdb")
+        );
+        cfg.validate().expect("https webhook is valid");
+
+        let bad = ServerConfig {
+            slo_webhook_url: Some("alerts.example.com".to_string()),
+            ..cfg
+        };
+        let err = bad.validate().expect_err("scheme is required");
+        assert!(err.to_string().contains("webhook_url"));
+    }
+
+    #[test]
+    fn test_embedder_from_toml() {
+        let toml_content = r#"
+[embedder]
+url = "http://localhost:8000/v1/embeddings"
+model = "bge-small-en"
+api_key = "sk-local"
+"#;
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, CliOverrides::default());
+        let embedder = cfg.embedder.clone().expect("embedder configured");
+        assert_eq!(embedder.url, "http://localhost:8000/v1/embeddings");
+        assert_eq!(embedder.model, "bge-small-en");
+        assert_eq!(embedder.api_key.as_deref(), Some("sk-local"));
+        assert_eq!(embedder.timeout_secs, DEFAULT_EMBEDDER_TIMEOUT_SECS);
+        cfg.validate().expect("embedder config is valid");
+
+        let bad = ServerConfig {
+            embedder: Some(EmbedderConfig {
+                model: String::new(),
+                ..embedder
+            }),
+            ..cfg
+        };
+        let err = bad.validate().expect_err("model is required");
+        assert!(err.to_string().contains("embedder.model"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_uds_path_from_toml_and_cli() {
+        let toml_content = r#"
+[server]
+uds_path = "/run/velesdb/file.sock"
+"#;
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, CliOverrides::default());
+        assert_eq!(cfg.uds_path.as_deref(), Some("/run/velesdb/file.sock"));
+        assert!(!cfg.binds_publicly());
+        cfg.validate().expect("socket path is valid");
+
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cli = CliOverrides {
+            uds_path: Some("/tmp/cli.sock".to_string()),
+            ..Default::default()
+        };
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, cli);
+        assert_eq!(cfg.uds_path.as_deref(), Some("/tmp/cli.sock"));
+
+        // Any existing file stands in for the PEM files.
+        let pem = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string();
+        let with_tls = ServerConfig {
+            tls: TlsConfig {
+                cert: Some(pem.clone()),
+                key: Some(pem),
+            },
+            ..cfg
+        };
+        let err = with_tls.validate().expect_err("TLS is TCP-only");
+        assert!(err.to_string().contains("uds_path"));
+    }
+
+    #[test]
+    fn test_key_contexts_from_toml() {
+        let toml_content = r#"
+[auth]
+api_keys = ["admin-key", "acme-key"]
+
+[auth.key_contexts."acme-key"]
+tenant = "acme"
+tier = 2
+"#;
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, CliOverrides::default());
+
+        let acme = &cfg.key_contexts["acme-key"];
+        assert_eq!(acme.attribute("tenant"), Some(&serde_json::json!("acme")));
+        assert_eq!(acme.attribute("tier"), Some(&serde_json::json!(2)));
+        assert!(!cfg.key_contexts.contains_key("admin-key"));
+        cfg.validate()
+            .expect("contexts for configured keys are valid");
+
+        let orphan = ServerConfig {
+            api_keys: vec!["admin-key".to_string()],
+            ..cfg
+        };
+        let err = orphan.validate().expect_err("context for an unknown key");
+        assert!(!err.to_string().contains("acme-key"));
+    }
+
     #[test]
     fn test_cli_overrides_toml() {
         let toml_content = r#"
@@ -818,13 +1202,80 @@ port = 4000
     #[test]
     fn test_parse_api_keys_env() {
         // Simulate by directly testing the parsing logic
-        let input = "key1, key2 , key3";
-        let keys: Vec<String> = input
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
+        let keys = split_csv("key1, key2 , key3").expect("test: non-empty list");
         assert_eq!(keys, vec!["key1", "key2", "key3"]);
+        assert_eq!(split_csv(" , "), None);
+    }
+
+    #[test]
+    fn test_guardrails_and_threads_from_toml() {
+        let toml_content = r#"
+[server]
+worker_threads = 8
+max_blocking_threads = 64
+
+[guardrails]
+timeout_ms = 5000
+max_depth = 3
+"#;
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, CliOverrides::default());
+
+        assert_eq!(cfg.worker_threads, Some(8));
+        assert_eq!(cfg.max_blocking_threads, Some(64));
+        assert_eq!(cfg.guardrails.timeout_ms, 5000);
+        assert_eq!(cfg.guardrails.max_depth, 3);
+        // Keys absent from [guardrails] keep their defaults.
+        assert_eq!(
+            cfg.guardrails.max_cardinality,
+            QueryLimits::default().max_cardinality
+        );
+    }
+
+    #[test]
+    fn test_env_overrides_threads_shutdown_and_cors() {
+        let toml_content = r#"
+[server]
+shutdown_timeout_secs = 60
+worker_threads = 8
+
+[cors]
+allowed_origins = ["https://toml.example.com"]
+allow_credentials = true
+"#;
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cli = CliOverrides {
+            shutdown_timeout_secs: Some(5),
+            worker_threads: Some(2),
+            cors_allowed_origins: Some(vec!["https://env.example.com".to_string()]),
+            ..Default::default()
+        };
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, cli);
+
+        assert_eq!(cfg.shutdown_timeout_secs, 5);
+        assert_eq!(cfg.worker_threads, Some(2));
+        assert_eq!(cfg.cors.allowed_origins, vec!["https://env.example.com"]);
+        // Only the origins are overridden; the rest of [cors] still applies.
+        assert!(cfg.cors.allow_credentials);
+    }
+
+    #[test]
+    fn test_validate_zero_threads_rejected() {
+        let cfg = ServerConfig {
+            worker_threads: Some(0),
+            ..ServerConfig::default()
+        };
+        let err = cfg.validate().unwrap_err();
+        assert!(err.to_string().contains("worker_threads"));
+
+        let cfg = ServerConfig {
+            max_blocking_threads: Some(0),
+            ..ServerConfig::default()
+        };
+        let err = cfg.validate().unwrap_err();
+        assert!(err.to_string().contains("max_blocking_threads"));
     }
 
     #[test]
@@ -1003,6 +1454,32 @@ allowed_origins = ["https://myapp.com"]
         assert_eq!(cfg.cors.allowed_methods.len(), 6); // default methods
     }
 
+    #[test]
+    fn test_compression_from_toml() {
+        let toml_content = r#"
+[compression]
+level = 9
+min_size_bytes = 4096
+"#;
+        let file_cfg: FileConfig =
+            toml::from_str(toml_content).expect("test: valid FileConfig TOML");
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, CliOverrides::default());
+
+        assert!(cfg.compression.enabled);
+        assert_eq!(cfg.compression.level, Some(9));
+        assert_eq!(cfg.compression.min_size_bytes, 4096);
+
+        let file_cfg: FileConfig =
+            toml::from_str("[compression]\nenabled = false\n").expect("test: valid TOML");
+        let cfg = ServerConfig::merge(ServerConfig::default(), file_cfg, CliOverrides::default());
+        assert!(!cfg.compression.enabled);
+        assert_eq!(cfg.compression.level, None);
+        assert_eq!(
+            cfg.compression.min_size_bytes,
+            DEFAULT_COMPRESSION_MIN_SIZE_BYTES
+        );
+    }
+
     #[test]
     fn test_cors_absent_from_toml_uses_permissive_default() {
         let toml_content = r#"
@@ -0,0 +1,260 @@
+//! The REST API as a library: mount VelesDB inside another Axum application.
+//!
+//! [`build_router`] wraps an open [`Database`] in the same routes and
+//! middleware the `velesdb-server` binary serves (`/v1/...`, auth, CORS,
+//! compression, rate limiting, `Idempotency-Key`), as a plain
+//! [`axum::Router`] the host application can `merge` or `nest` next to its
+//! own routes and wrap in its own layers:
+//!
+//! ```rust,no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! use velesdb_server::embedded::{build_router, RouterOptions};
+//!
+//! let db = velesdb_core::Database::open("./data")?;
+//! let app = axum::Router::new()
+//!     .route("/", axum::routing::get(|| async { "my app" }))
+//!     .nest("/db", build_router(db, RouterOptions::default())?);
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await?;
+//! axum::serve(listener, app).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Settings that belong to the process rather than the router (listen
+//! address, TLS, runtime threads, signal handling, config reloads) stay with
+//! the host application.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use axum::{http::HeaderValue, middleware::Next, Router};
+use tower_http::trace::TraceLayer;
+use velesdb_core::{
+    Database, DurationHistogram, OperationalMetrics, QueryLimits, RequestContext, TraversalMetrics,
+};
+
+use crate::auth::{auth_middleware, AuthState};
+use crate::compression::{build_compression_layer, build_decompression_layer};
+use crate::config::{
+    cors_middleware, CompressionConfig, CorsConfig, CorsState, EffectiveConfig, EmbedderConfig,
+    ServerConfig,
+};
+use crate::embedder::Embedder;
+use crate::idempotency::idempotency_middleware;
+use crate::index_events::IndexEventHub;
+use crate::rate_limit::{rate_limit_middleware, RateLimitState};
+use crate::reindex::ReindexJobs;
+use crate::routes::api_routes;
+use crate::traversal_cursors::TraversalCursors;
+use crate::{slo_webhook, AppState, OnboardingMetrics};
+
+/// Options for [`build_router`].
+///
+/// The defaults suit an embedded API: no authentication, no rate limiting
+/// and no CORS layer (the host application usually brings its own), and
+/// legacy unversioned routes disabled so only `/v1/...` is served.
+#[derive(Debug, Clone, Default)]
+pub struct RouterOptions {
+    /// Accepted API keys; empty disables authentication.
+    pub api_keys: Vec<String>,
+    /// Request context per API key, bound to `$ctx.*` in row-security
+    /// policies.
+    pub key_contexts: HashMap<String, RequestContext>,
+    /// Maximum requests per second per client IP (0 = disabled).
+    ///
+    /// The limiter keys on `X-Forwarded-For`/`X-Real-IP`/`Forwarded` or
+    /// the peer address, so serve the host application with
+    /// `into_make_service_with_connect_info::<SocketAddr>()` when enabling
+    /// it without a reverse proxy.
+    pub rate_limit: u32,
+    /// CORS policy; `None` adds no CORS layer.
+    pub cors: Option<CorsConfig>,
+    /// gzip/zstd response compression.
+    pub compression: CompressionConfig,
+    /// Initial query guard-rails; `PUT /guardrails` can change them.
+    pub guardrails: QueryLimits,
+    /// URL latency SLO breaches and recoveries are POSTed to.
+    pub slo_webhook_url: Option<String>,
+    /// Embeddings service behind `/embeddings` and `/retrieval`; `None`
+    /// disables both.
+    pub embedder: Option<EmbedderConfig>,
+    /// Also serve every route without the `/v1` prefix, with deprecation
+    /// headers, as the binary does.
+    pub legacy_routes: bool,
+}
+
+impl RouterOptions {
+    /// Options matching what the binary serves for `config`: its auth keys,
+    /// rate limit, CORS policy, compression, guard-rails, SLO webhook and
+    /// embedder, with legacy routes enabled.
+    #[must_use]
+    pub fn from_server_config(config: &ServerConfig) -> Self {
+        Self {
+            api_keys: config.api_keys.clone(),
+            key_contexts: config.key_contexts.clone(),
+            rate_limit: config.rate_limit,
+            cors: Some(config.cors.clone()),
+            compression: config.compression.clone(),
+            guardrails: config.guardrails.clone(),
+            slo_webhook_url: config.slo_webhook_url.clone(),
+            embedder: config.embedder.clone(),
+            legacy_routes: true,
+        }
+    }
+}
+
+/// Middleware states wrapped around the API by [`router_for_state`].
+///
+/// They are shared handles: the binary keeps clones so a config reload can
+/// swap keys, limits and CORS policy without rebuilding the router.
+#[derive(Clone)]
+pub struct RouterLayers {
+    /// API keys and their request contexts.
+    pub auth: AuthState,
+    /// Per-IP rate limit.
+    pub rate_limit: RateLimitState,
+    /// CORS policy; `None` adds no CORS layer.
+    pub cors: Option<CorsState>,
+}
+
+/// Builds the VelesDB REST API around `db`, ready to be merged or nested
+/// into another Axum application.
+///
+/// Must be called from within a Tokio runtime when
+/// [`RouterOptions::slo_webhook_url`] is set.
+///
+/// # Errors
+///
+/// Returns an error if the rate limiter cannot be configured.
+pub fn build_router(db: Database, options: RouterOptions) -> anyhow::Result<Router> {
+    build_router_with_state(db, options).map(|(router, _)| router)
+}
+
+/// Like [`build_router`], also returning the state the handlers share, so
+/// the host application can reach the database (e.g. `state.db.flush_all()`
+/// on shutdown) and the server metrics.
+///
+/// # Errors
+///
+/// Returns an error if the rate limiter cannot be configured.
+pub fn build_router_with_state(
+    db: Database,
+    options: RouterOptions,
+) -> anyhow::Result<(Router, Arc<AppState>)> {
+    let layers = RouterLayers {
+        auth: AuthState::new(options.api_keys.clone())
+            .with_key_contexts(options.key_contexts.clone()),
+        rate_limit: RateLimitState::new(options.rate_limit)?,
+        cors: options.cors.as_ref().map(CorsState::new),
+    };
+    db.update_guardrails(&options.guardrails);
+    slo_webhook::install(&db, options.slo_webhook_url.clone());
+    let server = ServerConfig {
+        api_keys: options.api_keys,
+        key_contexts: options.key_contexts,
+        rate_limit: options.rate_limit,
+        cors: options.cors.unwrap_or_default(),
+        compression: options.compression.clone(),
+        guardrails: options.guardrails.clone(),
+        slo_webhook_url: options.slo_webhook_url,
+        embedder: options.embedder,
+        ..ServerConfig::default()
+    };
+    let embedder = server
+        .embedder
+        .as_ref()
+        .map(|config| Arc::new(Embedder::new(config)));
+    let engine = db.config().clone();
+    let state = Arc::new(AppState {
+        db,
+        onboarding_metrics: OnboardingMetrics::default(),
+        query_limits: parking_lot::RwLock::new(options.guardrails),
+        effective_config: parking_lot::RwLock::new(EffectiveConfig { server, engine }),
+        config_reloader: None,
+        reindex_jobs: ReindexJobs::default(),
+        index_events: IndexEventHub::default(),
+        ready: AtomicBool::new(true),
+        operational_metrics: OperationalMetrics::new_arc(),
+        traversal_metrics: Arc::new(TraversalMetrics::new()),
+        traversal_cursors: TraversalCursors::default(),
+        query_duration_histogram: Arc::new(DurationHistogram::new()),
+        embedder: parking_lot::RwLock::new(embedder),
+    });
+    let router = router_for_state(
+        Arc::clone(&state),
+        layers,
+        &options.compression,
+        options.legacy_routes,
+    );
+    Ok((router, state))
+}
+
+/// Wraps the API routes for an existing [`AppState`] in the server's
+/// middleware stack. [`build_router`] and the `velesdb-server` binary both
+/// go through it.
+#[allow(clippy::similar_names)] // Reason: `routes` (handler tree) and `router` (final router) are distinct concepts.
+pub fn router_for_state(
+    state: Arc<AppState>,
+    layers: RouterLayers,
+    compression: &CompressionConfig,
+    legacy_routes: bool,
+) -> Router {
+    let routes = api_routes();
+
+    // Canonical versioned API under /v1/
+    let mut api_router = Router::new().nest("/v1", routes.clone());
+    if legacy_routes {
+        // Legacy unversioned routes with deprecation headers for backward compat
+        api_router = api_router.merge(routes.layer(axum::middleware::from_fn(deprecation_header)));
+    }
+    let api_router =
+        api_router
+            .with_state(Arc::clone(&state))
+            .layer(axum::middleware::from_fn_with_state(
+                state,
+                idempotency_middleware,
+            ));
+
+    #[cfg(feature = "swagger-ui")]
+    let api_router = {
+        use utoipa::OpenApi;
+        let swagger_ui = utoipa_swagger_ui::SwaggerUi::new("/swagger-ui")
+            .url("/api-docs/openapi.json", crate::ApiDoc::openapi());
+        api_router.merge(Router::<()>::new().merge(swagger_ui))
+    };
+
+    // Auth keys, CORS policy and rate limit live in shared states that a
+    // config reload swaps in place.
+    let mut router = api_router.layer(axum::middleware::from_fn_with_state(
+        layers.auth,
+        auth_middleware,
+    ));
+    if let Some(cors) = layers.cors {
+        router = router.layer(axum::middleware::from_fn_with_state(cors, cors_middleware));
+    }
+    router
+        .layer(build_compression_layer(compression))
+        .layer(build_decompression_layer())
+        .layer(TraceLayer::new_for_http())
+        .layer(axum::middleware::from_fn_with_state(
+            layers.rate_limit,
+            rate_limit_middleware,
+        ))
+}
+
+/// Middleware that adds deprecation headers to responses served on
+/// unversioned (legacy) routes. Clients should migrate to `/v1/` prefix.
+async fn deprecation_header(
+    request: axum::extract::Request,
+    next: Next,
+) -> axum::response::Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    headers.insert("deprecation", HeaderValue::from_static("true"));
+    headers.insert(
+        "x-api-deprecated",
+        HeaderValue::from_static("Use /v1/ prefix"),
+    );
+    response
+}
@@ -0,0 +1,105 @@
+//! Client for the OpenAI-compatible embeddings service configured in
+//! `[embedder]` (vLLM, TEI, Ollama, OpenAI, ...).
+//!
+//! Backs `POST /embeddings` and `POST /retrieval`, which let LangChain and
+//! LlamaIndex integrations send text to the server instead of vectors.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::config::EmbedderConfig;
+
+/// Embeddings client for one configured service.
+pub struct Embedder {
+    client: reqwest::Client,
+    url: String,
+    model: String,
+    api_key: Option<String>,
+}
+
+/// Failure to obtain embeddings from the upstream service.
+#[derive(Debug)]
+pub struct EmbedderError(String);
+
+impl std::fmt::Display for EmbedderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for EmbedderError {}
+
+#[derive(Deserialize)]
+struct EmbeddingsBody {
+    data: Vec<EmbeddingItem>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingItem {
+    #[serde(default)]
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+impl Embedder {
+    /// Builds a client for `config`.
+    #[must_use]
+    pub fn new(config: &EmbedderConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            url: config.url.clone(),
+            model: config.model.clone(),
+            api_key: config.api_key.clone(),
+        }
+    }
+
+    /// Model name sent with every request.
+    #[must_use]
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Embeds `inputs`, returning one vector per input in input order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbedderError`] when the service is unreachable, answers
+    /// with a non-2xx status, or returns a body that is not an embeddings
+    /// list with one entry per input.
+    pub async fn embed(&self, inputs: &[String]) -> Result<Vec<Vec<f32>>, EmbedderError> {
+        let mut request = self.client.post(&self.url).json(&serde_json::json!({
+            "model": self.model,
+            "input": inputs,
+        }));
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+        // The URL may embed a token: keep it out of error messages.
+        let body: EmbeddingsBody = async {
+            request
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<EmbeddingsBody>()
+                .await
+        }
+        .await
+        .map_err(|e| EmbedderError(format!("embedder request failed: {}", e.without_url())))?;
+
+        let mut data = body.data;
+        if data.len() != inputs.len() {
+            return Err(EmbedderError(format!(
+                "embedder returned {} embeddings for {} inputs",
+                data.len(),
+                inputs.len()
+            )));
+        }
+        data.sort_by_key(|item| item.index);
+        Ok(data.into_iter().map(|item| item.embedding).collect())
+    }
+}
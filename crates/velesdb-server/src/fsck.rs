@@ -0,0 +1,82 @@
+//! `velesdb-server --fsck`: check the data directory offline and print a
+//! recovery report instead of serving.
+
+use std::fmt::Write as _;
+
+use velesdb_core::config::VelesConfig;
+use velesdb_core::{Database, RecoveryReport};
+
+/// Opens the database at `data_dir`, verifies every collection and, when
+/// `repair` is set, applies the repairs [`Database::repair`] supports.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened (including when a
+/// running server holds its lock) or a repair fails.
+pub fn run(data_dir: &str, config: VelesConfig, repair: bool) -> anyhow::Result<RecoveryReport> {
+    let db = Database::open_with_config(data_dir, config)?;
+    let report = if repair { db.repair()? } else { db.verify()? };
+    db.flush_all();
+    Ok(report)
+}
+
+/// Renders a report as the human-readable text printed by `--fsck`.
+#[must_use]
+pub fn render(report: &RecoveryReport) -> String {
+    let mut out = String::new();
+    for coll in &report.collections {
+        let state = if coll.issues.is_empty() {
+            "ok"
+        } else if coll.loaded {
+            "DAMAGED"
+        } else {
+            "NOT LOADED"
+        };
+        let _ = writeln!(out, "{}: {state}", coll.name);
+        for repair in &coll.repairs {
+            let _ = writeln!(out, "  repaired: {repair}");
+        }
+        for issue in &coll.issues {
+            let _ = writeln!(out, "  - {issue}");
+        }
+    }
+    let _ = write!(
+        out,
+        "{} collection(s) checked, {} issue(s) remaining",
+        report.collections.len(),
+        report.issue_count()
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_lists_issues_and_repairs() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let db = Database::open(dir.path()).unwrap();
+            db.create_collection("docs", 4, velesdb_core::DistanceMetric::Cosine)
+                .unwrap();
+            db.flush_all();
+        }
+        std::fs::remove_file(dir.path().join("docs").join("native_hnsw.graph")).ok();
+        let broken = dir.path().join("broken");
+        std::fs::create_dir_all(&broken).unwrap();
+        std::fs::write(broken.join("config.json"), b"{").unwrap();
+
+        let path = dir.path().to_str().unwrap();
+        let report = run(path, VelesConfig::default(), true).unwrap();
+        let text = render(&report);
+
+        assert!(text.starts_with("broken: NOT LOADED\n"), "{text}");
+        assert!(text.contains("  - missing file vectors.dat"), "{text}");
+        assert!(text.contains("docs: ok"), "{text}");
+        assert!(
+            text.ends_with("2 collection(s) checked, 3 issue(s) remaining"),
+            "{text}"
+        );
+    }
+}
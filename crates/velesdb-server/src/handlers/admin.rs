@@ -1,17 +1,23 @@
 //! Admin and diagnostic handlers: stats, config, guardrails, analyze.
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
+use serde::Deserialize;
 use std::sync::Arc;
+use utoipa::IntoParams;
+use velesdb_core::RequestContext;
 
 use crate::types::{
-    CollectionConfigResponse, CollectionDiagnosticsResponse, CollectionStatsResponse,
-    ColumnStatsResponse, ErrorResponse, GuardRailsConfigRequest, GuardRailsConfigResponse,
-    IndexStatsResponse,
+    AccessStatsResponse, AuthSettingsResponse, CollectionConfigResponse,
+    CollectionDiagnosticsResponse, CollectionStatsResponse, ColumnStatsResponse,
+    CompressionSettingsResponse, ConfigReloadResponse, CorsSettingsResponse,
+    EffectiveConfigResponse, ErrorResponse, GuardRailsConfigRequest, GuardRailsConfigResponse,
+    IndexStatsResponse, PayloadCacheStatsResponse, PointAccessCountResponse,
+    ServerSettingsResponse, TlsSettingsResponse, VectorStatsResponse,
 };
 use crate::AppState;
 
@@ -80,6 +86,11 @@ pub async fn get_collection_config(
         hnsw_params,
         deferred_indexing,
         async_index_builder,
+        embedding_info: config
+            .embedding_info
+            .as_ref()
+            .and_then(|e| serde_json::to_value(e).ok()),
+        max_query_limit: config.max_query_limit,
     })
     .into_response()
 }
@@ -298,7 +309,7 @@ pub async fn analyze_collection(
         tokio::task::spawn_blocking(move || state_clone.db.analyze_collection(&coll_name)).await;
     match result {
         Ok(Ok(stats)) => {
-            let response = map_stats_to_response(&stats);
+            let response = map_stats_to_response(&state.db, &name, &stats);
             (StatusCode::OK, Json(response)).into_response()
         }
         Ok(Err(e)) => auto_core_error_response(&e),
@@ -329,7 +340,7 @@ pub async fn get_collection_stats(
 ) -> impl IntoResponse {
     match state.db.get_collection_stats(&name) {
         Ok(Some(stats)) => {
-            let response = map_stats_to_response(&stats);
+            let response = map_stats_to_response(&state.db, &name, &stats);
             (StatusCode::OK, Json(response)).into_response()
         }
         Ok(None) => error_response(
@@ -340,6 +351,148 @@ pub async fn get_collection_stats(
     }
 }
 
+/// Query parameters for vector statistics.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct VectorStatsParams {
+    /// Number of vectors to sample (default 1000, capped at 2000).
+    #[param(example = 1000)]
+    pub sample_size: Option<usize>,
+}
+
+/// Default sample size for `GET /collections/{name}/stats/vectors`.
+const DEFAULT_VECTOR_STATS_SAMPLE: usize = 1_000;
+
+/// Compute vector-space statistics (centroid, norms, pairwise similarity,
+/// intrinsic dimension) on a random sample of the collection's vectors.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/stats/vectors",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name"),
+        VectorStatsParams
+    ),
+    responses(
+        (status = 200, description = "Vector statistics", body = VectorStatsResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 500, description = "Computation failed", body = ErrorResponse)
+    )
+)]
+pub async fn get_vector_stats(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Query(params): Query<VectorStatsParams>,
+) -> impl IntoResponse {
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    let sample_size = params.sample_size.unwrap_or(DEFAULT_VECTOR_STATS_SAMPLE);
+
+    match tokio::task::spawn_blocking(move || collection.vector_stats(sample_size)).await {
+        Ok(stats) => (StatusCode::OK, Json(vector_stats_to_response(stats))).into_response(),
+        Err(join_err) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("vector_stats task panicked: {join_err}"),
+        ),
+    }
+}
+
+/// Maps core `VectorStats` to the REST response DTO.
+fn vector_stats_to_response(
+    stats: velesdb_core::collection::stats::VectorStats,
+) -> VectorStatsResponse {
+    VectorStatsResponse {
+        sample_size: stats.sample_size,
+        dimension: stats.dimension,
+        centroid: stats.centroid,
+        norm_mean: stats.norm_mean,
+        norm_min: stats.norm_min,
+        norm_p50: stats.norm_p50,
+        norm_p90: stats.norm_p90,
+        norm_p99: stats.norm_p99,
+        norm_max: stats.norm_max,
+        mean_pairwise_cosine: stats.mean_pairwise_cosine,
+        intrinsic_dimension: stats.intrinsic_dimension,
+    }
+}
+
+/// Query parameters for access statistics.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AccessStatsParams {
+    /// Number of most read points to return (default 10, capped at 100).
+    #[param(example = 10)]
+    pub top: Option<usize>,
+}
+
+/// Default number of points returned by `GET /collections/{name}/stats/access`.
+const DEFAULT_ACCESS_STATS_TOP: usize = 10;
+
+/// Get approximate per-point read counts (most read points first) and the
+/// payload cache counters of a collection.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/stats/access",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name"),
+        AccessStatsParams
+    ),
+    responses(
+        (status = 200, description = "Access statistics", body = AccessStatsResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 500, description = "Computation failed", body = ErrorResponse)
+    )
+)]
+pub async fn get_access_stats(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+    Query(params): Query<AccessStatsParams>,
+) -> impl IntoResponse {
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    // Per-point read counts expose ids the policy may hide.
+    if let Err(resp) = super::row_security::refuse_unfiltered_read(&state, &name, ctx.as_deref()) {
+        return resp.into_response();
+    }
+    let top = params.top.unwrap_or(DEFAULT_ACCESS_STATS_TOP);
+
+    match tokio::task::spawn_blocking(move || collection.access_stats(top)).await {
+        Ok(stats) => (StatusCode::OK, Json(access_stats_to_response(stats))).into_response(),
+        Err(join_err) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("access_stats task panicked: {join_err}"),
+        ),
+    }
+}
+
+/// Maps core `AccessStats` to the REST response DTO.
+fn access_stats_to_response(stats: velesdb_core::AccessStats) -> AccessStatsResponse {
+    let cache = stats.payload_cache;
+    AccessStatsResponse {
+        total_accesses: stats.total_accesses,
+        top: stats
+            .top
+            .into_iter()
+            .map(|p| PointAccessCountResponse {
+                id: p.id,
+                count: p.count,
+            })
+            .collect(),
+        payload_cache: PayloadCacheStatsResponse {
+            capacity: cache.capacity,
+            entries: cache.entries,
+            hits: cache.hits,
+            misses: cache.misses,
+            evictions: cache.evictions,
+        },
+    }
+}
+
 /// Get health diagnostics for a collection (index readiness, point count).
 #[utoipa::path(
     get,
@@ -422,6 +575,124 @@ pub async fn update_guardrails(
     Json(limits_to_response(&limits))
 }
 
+/// Get the effective server configuration.
+///
+/// Reports the settings the server was started with after merging defaults,
+/// `velesdb.toml`, environment variables and CLI flags. API keys are never
+/// returned, only their count. Guard-rails reflect the values currently in
+/// force, including updates made through `PUT /guardrails`.
+#[utoipa::path(
+    get,
+    path = "/config",
+    tag = "config",
+    responses(
+        (status = 200, description = "Effective server configuration", body = EffectiveConfigResponse)
+    )
+)]
+pub async fn get_server_config(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let guardrails = limits_to_response(&state.query_limits.read());
+    Json(effective_config_to_response(
+        &state.effective_config.read(),
+        guardrails,
+    ))
+}
+
+/// Reload the server configuration without restarting.
+///
+/// Re-reads `velesdb.toml` (CLI flags and environment variables still take
+/// precedence) and swaps API keys, CORS policy, rate limit and guard-rails
+/// in place; in-flight requests are unaffected. Same as sending `SIGHUP`.
+#[utoipa::path(
+    post,
+    path = "/config/reload",
+    tag = "config",
+    responses(
+        (status = 200, description = "Configuration reloaded", body = ConfigReloadResponse),
+        (status = 400, description = "Config file failed to load or validate; nothing changed", body = ErrorResponse),
+        (status = 501, description = "Reload not enabled on this server", body = ErrorResponse)
+    )
+)]
+pub async fn reload_config(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let Some(reloader) = state.config_reloader.as_ref() else {
+        return error_response(
+            StatusCode::NOT_IMPLEMENTED,
+            "config reload is not enabled on this server".to_string(),
+        );
+    };
+    match reloader.reload(&state) {
+        Ok(outcome) => {
+            tracing::info!(
+                "Config reloaded: applied {:?}, restart required for {:?}",
+                outcome.applied,
+                outcome.restart_required
+            );
+            Json(ConfigReloadResponse {
+                applied: outcome.applied.iter().map(ToString::to_string).collect(),
+                restart_required: outcome
+                    .restart_required
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            })
+            .into_response()
+        }
+        Err(e) => error_response(
+            StatusCode::BAD_REQUEST,
+            format!("config reload failed: {e}"),
+        ),
+    }
+}
+
+/// Convert the startup configuration to the redacted REST response type.
+fn effective_config_to_response(
+    config: &crate::config::EffectiveConfig,
+    guardrails: GuardRailsConfigResponse,
+) -> EffectiveConfigResponse {
+    let server = &config.server;
+    // The core config also carries `[server]`/`[logging]` tables that this
+    // crate never reads (see `load_core_config`); leave them out.
+    let mut engine = serde_json::to_value(&config.engine).unwrap_or_default();
+    if let Some(sections) = engine.as_object_mut() {
+        sections.remove("server");
+        sections.remove("logging");
+    }
+    EffectiveConfigResponse {
+        server: ServerSettingsResponse {
+            host: server.host.clone(),
+            port: server.port,
+            uds_path: server.uds_path.clone(),
+            data_dir: server.data_dir.clone(),
+            shutdown_timeout_secs: server.shutdown_timeout_secs,
+            rate_limit: server.rate_limit,
+            worker_threads: server.worker_threads,
+            max_blocking_threads: server.max_blocking_threads,
+        },
+        auth: AuthSettingsResponse {
+            enabled: server.auth_enabled(),
+            api_key_count: server.api_keys.len(),
+        },
+        tls: TlsSettingsResponse {
+            enabled: server.tls_enabled(),
+            cert: server.tls.cert.clone(),
+            key: server.tls.key.clone(),
+        },
+        cors: CorsSettingsResponse {
+            allowed_origins: server.cors.allowed_origins.clone(),
+            allowed_methods: server.cors.allowed_methods.clone(),
+            allowed_headers: server.cors.allowed_headers.clone(),
+            allow_credentials: server.cors.allow_credentials,
+            max_age_secs: server.cors.max_age_secs,
+        },
+        compression: CompressionSettingsResponse {
+            enabled: server.compression.enabled,
+            level: server.compression.level,
+            min_size_bytes: server.compression.min_size_bytes,
+        },
+        guardrails,
+        engine,
+    }
+}
+
 /// Convert `QueryLimits` to the REST response type.
 fn limits_to_response(limits: &velesdb_core::guardrails::QueryLimits) -> GuardRailsConfigResponse {
     GuardRailsConfigResponse {
@@ -437,6 +708,8 @@ fn limits_to_response(limits: &velesdb_core::guardrails::QueryLimits) -> GuardRa
 
 /// Convert core `CollectionStats` to the REST response type.
 fn map_stats_to_response(
+    db: &velesdb_core::Database,
+    name: &str,
     stats: &velesdb_core::collection::stats::CollectionStats,
 ) -> CollectionStatsResponse {
     let column_stats = stats
@@ -486,6 +759,9 @@ fn map_stats_to_response(
         last_analyzed_epoch_ms: stats.last_analyzed_epoch_ms,
         column_stats,
         index_stats,
+        slo: db
+            .latency_slo_status(name)
+            .map(|status| super::slo::slo_status_to_response(name.to_string(), status)),
     }
 }
 
@@ -10,7 +10,7 @@ use std::sync::Arc;
 
 use crate::types::{CollectionResponse, CreateCollectionRequest, ErrorResponse};
 use crate::AppState;
-use velesdb_core::index::HnswParams;
+use velesdb_core::index::{HnswParams, NeighborSelection};
 use velesdb_core::{DistanceMetric, StorageMode};
 
 use super::helpers::{auto_core_error_response, error_response, get_collection_or_404};
@@ -96,11 +96,13 @@ fn build_hnsw_params_override(
     req: &CreateCollectionRequest,
     dimension: usize,
     storage_mode: StorageMode,
+    neighbor_selection: Option<NeighborSelection>,
 ) -> Option<HnswParams> {
     if req.hnsw_m.is_none()
         && req.hnsw_ef_construction.is_none()
         && req.hnsw_alpha.is_none()
         && req.hnsw_max_elements.is_none()
+        && neighbor_selection.is_none()
     {
         return None;
     }
@@ -111,14 +113,16 @@ fn build_hnsw_params_override(
         max_elements: req.hnsw_max_elements.unwrap_or(base.max_elements),
         storage_mode,
         alpha: req.hnsw_alpha.unwrap_or(base.alpha),
+        neighbor_selection: neighbor_selection.unwrap_or(base.neighbor_selection),
+        ..base
     })
 }
 
 /// Create a vector collection, requiring a dimension in the request.
 ///
 /// Applies advanced configuration overrides (pq_rescore_oversampling,
-/// deferred_indexing, async_index_builder) in a second pass via
-/// `VectorCollection::apply_advanced_config` once the base collection
+/// deferred_indexing, async_index_builder, embedding_model) in a second
+/// pass via `VectorCollection::apply_advanced_config` once the base collection
 /// has been registered. This two-step approach keeps the core
 /// `Database::create_vector_collection_*` API stable while still
 /// honouring the full PROP-CONFIG-ADVANCED field set on the REST
@@ -142,17 +146,25 @@ fn create_vector_collection(
     // disk. This avoids the half-initialised state where the base
     // collection exists but the advanced fields are missing.
     let advanced = parse_advanced_config(req)?;
+    let neighbor_selection = req
+        .hnsw_neighbor_selection
+        .as_deref()
+        .map(|raw| {
+            raw.parse::<NeighborSelection>()
+                .map_err(|e| error_response(StatusCode::BAD_REQUEST, e))
+        })
+        .transpose()?;
 
     // Phase 1: create the base collection with HNSW params.
     //
-    // Any of `hnsw_m`, `hnsw_ef_construction`, `hnsw_alpha`, or
-    // `hnsw_max_elements` being present triggers the "with_params"
+    // Any of `hnsw_m`, `hnsw_ef_construction`, `hnsw_alpha`,
+    // `hnsw_neighbor_selection` or `hnsw_max_elements` being present triggers the "with_params"
     // path so the caller-supplied values flow into a full `HnswParams`
     // starting from the engine's dimension-aware auto defaults. The
     // legacy `with_hnsw` helper cannot carry alpha/max_elements and
     // would silently drop them, re-introducing the PROP-HNSW-ALPHA gap.
     let base_result = if let Some(hnsw_params) =
-        build_hnsw_params_override(req, dimension, storage_mode)
+        build_hnsw_params_override(req, dimension, storage_mode, neighbor_selection)
     {
         // Reject out-of-range tunables (e.g. hnsw_alpha < 1.0 or non-finite)
         // before any collection is created on disk.
@@ -198,11 +210,17 @@ fn apply_advanced_with_rollback(
             name.to_string(),
         ));
     };
-    if let Err(phase_two_err) = coll.apply_advanced_config(
-        advanced.pq_rescore_oversampling,
-        advanced.deferred_indexing,
-        advanced.async_index_builder,
-    ) {
+    let phase_two = coll
+        .apply_advanced_config(
+            advanced.pq_rescore_oversampling,
+            advanced.deferred_indexing,
+            advanced.async_index_builder,
+        )
+        .and_then(|()| match advanced.embedding_info {
+            Some(info) => coll.set_embedding_info(Some(info)),
+            None => Ok(()),
+        });
+    if let Err(phase_two_err) = phase_two {
         drop(coll);
         let rollback_outcome = state.db.delete_collection(name);
         if let Err(ref rollback_err) = rollback_outcome {
@@ -253,6 +271,7 @@ struct AdvancedCreateOverrides {
     deferred_indexing: Option<Option<velesdb_core::collection::streaming::DeferredIndexerConfig>>,
     async_index_builder:
         Option<Option<velesdb_core::collection::streaming::AsyncIndexBuilderConfig>>,
+    embedding_info: Option<velesdb_core::EmbeddingInfo>,
 }
 
 impl AdvancedCreateOverrides {
@@ -260,6 +279,7 @@ impl AdvancedCreateOverrides {
         self.pq_rescore_oversampling.is_some()
             || self.deferred_indexing.is_some()
             || self.async_index_builder.is_some()
+            || self.embedding_info.is_some()
     }
 }
 
@@ -297,6 +317,12 @@ fn parse_advanced_config(
         overrides.async_index_builder = Some(Some(parsed));
     }
 
+    if let Some(ref model) = req.embedding_model {
+        let mut info = velesdb_core::EmbeddingInfo::new(model, req.dimension.unwrap_or(0));
+        info.version.clone_from(&req.embedding_model_version);
+        overrides.embedding_info = Some(info);
+    }
+
     Ok(overrides)
 }
 
@@ -396,6 +422,9 @@ pub async fn get_collection(
         metric: format!("{:?}", config.metric).to_lowercase(),
         point_count: config.point_count,
         storage_mode: format!("{:?}", config.storage_mode).to_lowercase(),
+        query_defaults: (!config.query_defaults.is_empty())
+            .then(|| serde_json::to_value(config.query_defaults).ok())
+            .flatten(),
     })
     .into_response()
 }
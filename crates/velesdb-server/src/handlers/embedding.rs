@@ -0,0 +1,66 @@
+//! Embedding model metadata handler.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+use crate::types::{ErrorResponse, SetEmbeddingInfoRequest};
+use crate::AppState;
+use velesdb_core::EmbeddingInfo;
+
+use super::helpers::{auto_core_error_response, error_response, get_vector_collection_or_404};
+
+/// Record (or clear) the embedding model that produced a collection's vectors.
+///
+/// Once recorded, upsert and search requests carrying an `X-Embedding-Model`
+/// header are rejected with `VELES-017` when the declared model differs.
+/// Sending `"model": null` clears the metadata and disables the check.
+#[utoipa::path(
+    put,
+    path = "/collections/{name}/embedding",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    request_body = SetEmbeddingInfoRequest,
+    responses(
+        (status = 200, description = "Embedding metadata updated", body = Object),
+        (status = 400, description = "Invalid metadata for this collection", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse)
+    )
+)]
+pub async fn set_embedding_info(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Json(req): Json<SetEmbeddingInfoRequest>,
+) -> impl IntoResponse {
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+
+    let info = req.model.map(|model| {
+        let mut info = EmbeddingInfo::new(model, collection.config().dimension);
+        info.version = req.version;
+        info
+    });
+    let response_info = info.clone();
+
+    let result = tokio::task::spawn_blocking(move || collection.set_embedding_info(info)).await;
+    match result {
+        Ok(Ok(())) => Json(serde_json::json!({
+            "collection": name,
+            "embedding_info": response_info
+        }))
+        .into_response(),
+        Ok(Err(e)) => auto_core_error_response(&e),
+        Err(join_err) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("embedding metadata task panicked: {join_err}"),
+        ),
+    }
+}
@@ -0,0 +1,137 @@
+//! Server-sent event streams of a collection: index build progress and
+//! filtered point changes.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    Extension,
+};
+use futures::{stream, StreamExt};
+use serde::Deserialize;
+use std::convert::Infallible;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use velesdb_core::RequestContext;
+
+use crate::types::{ErrorResponse, IndexProgressEvent, PointChangeEvent};
+use crate::AppState;
+
+use super::helpers::{error_response, get_collection_or_404, get_vector_collection_or_404};
+use super::row_security::{and_row_filter, row_filter};
+
+/// Stream a collection's index build progress.
+///
+/// Each SSE record is named `progress` and reports the phase
+/// (`storing_vectors`, `linking_layers`, `flushing`) and its percentage.
+/// Bulk upserts, flushes and reindex jobs report progress; events are not
+/// replayed, so the stream covers builds from the moment it is opened.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/events",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    responses(
+        (status = 200, description = "SSE stream of index build progress", body = IndexProgressEvent),
+        (status = 404, description = "Collection not found", body = ErrorResponse)
+    )
+)]
+pub async fn collection_events(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    let events = state
+        .index_events
+        .subscribe(&name, &collection)
+        .map(|event| {
+            Ok::<_, Infallible>(
+                Event::default()
+                    .event("progress")
+                    .json_data(&event)
+                    .unwrap_or_else(|_| Event::default().event("error")),
+            )
+        });
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Query parameters of `GET /collections/{name}/subscribe`.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SubscribeParams {
+    /// Filter as JSON, in the search `filter` format (URL-encoded). Every
+    /// point is followed when absent.
+    #[param(example = r#"{"condition":{"type":"eq","field":"status","value":"open"}}"#)]
+    pub filter: Option<String>,
+}
+
+/// Stream changes to the points matching a filter.
+///
+/// Each SSE record is named after the change kind (`insert`, `update` or
+/// `delete`) and carries the point id and payload. Changes are reported as
+/// seen through the filter: a point updated into it arrives as `insert`, one
+/// updated out of it as `delete` with its last matching payload. Writes
+/// committed before the stream opened are not replayed; a client that falls
+/// far behind skips the writes it missed. The key's row-security policy is
+/// AND-ed into the filter, so changes to rows it hides are never sent.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/subscribe",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name"),
+        SubscribeParams
+    ),
+    responses(
+        (status = 200, description = "SSE stream of point changes", body = PointChangeEvent),
+        (status = 400, description = "Invalid filter", body = ErrorResponse),
+        (status = 403, description = "Row-security policy cannot be bound to the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse)
+    )
+)]
+pub async fn subscribe_collection(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+    Query(params): Query<SubscribeParams>,
+) -> impl IntoResponse {
+    let collection = match get_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    let filter = match params.filter.as_deref().map(serde_json::from_str) {
+        None => None,
+        Some(Ok(filter)) => Some(filter),
+        Some(Err(e)) => {
+            return error_response(StatusCode::BAD_REQUEST, format!("Invalid filter: {e}"))
+        }
+    };
+    let filter = match row_filter(&state, &name, ctx.as_deref()) {
+        Ok(row) => and_row_filter(filter, row),
+        Err(resp) => return resp,
+    };
+    let changes = stream::unfold(collection.subscribe(filter), |mut sub| async move {
+        let change = sub.recv().await?;
+        Some((PointChangeEvent::from(change), sub))
+    })
+    .map(|change| {
+        Ok::<_, Infallible>(
+            Event::default()
+                .event(change.kind.clone())
+                .json_data(&change)
+                .unwrap_or_else(|_| Event::default().event("error")),
+        )
+    });
+    Sse::new(changes)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
@@ -11,11 +11,13 @@ use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
-use velesdb_core::collection::graph::{GraphEdge, TraversalConfig};
+use velesdb_core::collection::graph::{BfsCursor, GraphEdge, TraversalConfig, TraversalResult};
+use velesdb_core::RequestContext;
 
 use crate::handlers::helpers::auto_core_error_response;
+use crate::handlers::row_security::refuse_unfiltered_read;
 use crate::types::ErrorResponse;
 use crate::AppState;
 
@@ -38,6 +40,39 @@ pub(super) fn graph_preamble(
     get_graph_collection_or_404(state, name)
 }
 
+/// [`graph_preamble`] for graph reads: graph endpoints have no filter
+/// channel, so a key narrowed by the collection's row-security policy is
+/// refused (fail closed).
+#[allow(clippy::result_large_err)]
+pub(super) fn graph_read_preamble(
+    state: &AppState,
+    name: &str,
+    ctx: Option<&RequestContext>,
+) -> Result<velesdb_core::GraphCollection, (StatusCode, Json<ErrorResponse>)> {
+    let coll = graph_preamble(state, name)?;
+    refuse_unfiltered_read(state, name, ctx)?;
+    Ok(coll)
+}
+
+/// Rejects a traversal `limit` above the collection's `max_query_limit` with
+/// 400 + `VELES-037`; the core traversal would otherwise truncate silently.
+pub(super) fn check_traversal_limit(
+    coll: &velesdb_core::GraphCollection,
+    limit: usize,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    coll.check_query_limit(u64::try_from(limit).unwrap_or(u64::MAX))
+        .map_err(|err| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: err.to_string(),
+                    code: Some(err.code().to_string()),
+                    retryable: Some(err.is_retryable()),
+                }),
+            )
+        })
+}
+
 /// Resolves a `GraphCollection` by name.
 ///
 /// # Returns
@@ -74,6 +109,7 @@ pub(super) fn get_graph_collection_or_404(
                      Use /collections/{name}/graph only on graph-typed collections.",
                 ),
                 code: None,
+                retryable: None,
             }),
         ));
     }
@@ -91,6 +127,7 @@ pub(super) fn get_graph_collection_or_404(
                  POST /collections and collection_type = \"graph\".",
             ),
             code: Some(err.code().to_string()),
+            retryable: Some(err.is_retryable()),
         }),
     ))
 }
@@ -103,7 +140,9 @@ pub(super) fn get_graph_collection_or_404(
     responses(
         (status = 200, description = "Edges retrieved successfully", body = EdgesResponse),
         (status = 400, description = "Missing required 'label' query parameter", body = ErrorResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -112,6 +151,7 @@ pub async fn get_edges(
     Path(name): Path<String>,
     Query(params): Query<EdgeQueryParams>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
 ) -> Result<Json<EdgesResponse>, (StatusCode, Json<ErrorResponse>)> {
     let label = params.label.ok_or_else(|| {
         (
@@ -119,11 +159,12 @@ pub async fn get_edges(
             Json(ErrorResponse {
                 error: "Query parameter 'label' is required. Listing all edges requires pagination (not yet implemented).".to_string(),
                 code: None,
+                retryable: None,
             }),
         )
     })?;
 
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
 
     let edges: Vec<EdgeResponse> = coll
         .get_edges(Some(&label))
@@ -150,6 +191,7 @@ pub async fn get_edges(
         (status = 201, description = "Edge added successfully"),
         (status = 400, description = "Invalid request", body = ErrorResponse),
         (status = 404, description = "Collection not found, or source/target node has no stored payload (VELES-022 NodeNotFound)", body = ErrorResponse),
+        (status = 409, description = "Edge ID already exists (VELES-019), or the collection is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -192,6 +234,7 @@ fn build_edge(request: AddEdgeRequest) -> Result<GraphEdge, (StatusCode, Json<Er
                 Json(ErrorResponse {
                     error: "Properties must be an object or null".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             ));
         }
@@ -204,6 +247,7 @@ fn build_edge(request: AddEdgeRequest) -> Result<GraphEdge, (StatusCode, Json<Er
                 Json(ErrorResponse {
                     error: format!("Invalid edge: {e}"),
                     code: None,
+                    retryable: None,
                 }),
             )
         })?
@@ -220,6 +264,7 @@ fn build_edge(request: AddEdgeRequest) -> Result<GraphEdge, (StatusCode, Json<Er
         (status = 201, description = "Edges added successfully", body = AddEdgesBatchResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
         (status = 404, description = "Collection not found, or a source/target node has no stored payload (VELES-022 NodeNotFound) — the whole batch is rejected", body = ErrorResponse),
+        (status = 409, description = "Edge ID already exists (VELES-019), or the collection is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -253,14 +298,20 @@ pub async fn add_edges_batch(
 }
 
 /// Traverse the graph using BFS or DFS from a source node.
+///
+/// A BFS page that leaves results behind returns `next_cursor`; sending it
+/// back as `cursor` resumes the same traversal server-side (see
+/// [`crate::traversal_cursors`]).
 #[utoipa::path(
     post,
     path = "/collections/{name}/graph/traverse",
     request_body = TraverseRequest,
     responses(
         (status = 200, description = "Traversal completed successfully", body = TraverseResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 400, description = "Invalid request, or unknown or expired cursor", body = ErrorResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -268,31 +319,88 @@ pub async fn add_edges_batch(
 pub async fn traverse_graph(
     Path(name): Path<String>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     Json(request): Json<TraverseRequest>,
 ) -> Result<Json<TraverseResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
+    check_traversal_limit(&coll, request.limit)?;
 
-    let config = TraversalConfig::with_range(1, request.max_depth)
+    let strategy = request.strategy.to_lowercase();
+    if let Some(token) = request.cursor.as_deref() {
+        if strategy != "bfs" {
+            return Err(bad_request(
+                "Traversal cursors are only supported with the 'bfs' strategy".to_string(),
+            ));
+        }
+        let cursor = state
+            .traversal_cursors
+            .take(&name, request.source, token)
+            .ok_or_else(|| {
+                bad_request(format!(
+                    "Unknown or expired traversal cursor '{token}' for source {}",
+                    request.source
+                ))
+            })?;
+        return Ok(Json(bfs_page(
+            &state,
+            &name,
+            &coll,
+            request.source,
+            cursor,
+            request.limit,
+        )));
+    }
+
+    let mut config = TraversalConfig::with_range(1, request.max_depth)
         .with_limit(request.limit)
         .with_rel_types(request.rel_types);
+    config.valid_at = request.valid_at;
+    config.max_cost = request.max_cost;
 
-    let raw_results = match request.strategy.to_lowercase().as_str() {
-        "bfs" => coll.traverse_bfs(request.source, &config),
-        "dfs" => coll.traverse_dfs(request.source, &config),
-        _ => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: format!(
-                        "Invalid strategy '{}'. Use 'bfs' or 'dfs'.",
-                        request.strategy
-                    ),
-                    code: None,
-                }),
-            ));
+    match strategy.as_str() {
+        "bfs" => {
+            let cursor = coll.traversal_cursor(request.source, &config);
+            Ok(Json(bfs_page(
+                &state,
+                &name,
+                &coll,
+                request.source,
+                cursor,
+                request.limit,
+            )))
         }
-    };
+        "dfs" => {
+            let raw_results = coll.traverse_dfs(request.source, &config);
+            let has_more = raw_results.len() >= request.limit;
+            Ok(Json(traverse_response(raw_results, has_more, None)))
+        }
+        _ => Err(bad_request(format!(
+            "Invalid strategy '{}'. Use 'bfs' or 'dfs'.",
+            request.strategy
+        ))),
+    }
+}
 
+/// Runs one BFS page from `cursor` and parks the cursor again when results
+/// remain.
+fn bfs_page(
+    state: &AppState,
+    name: &str,
+    coll: &velesdb_core::GraphCollection,
+    source: u64,
+    mut cursor: BfsCursor,
+    limit: usize,
+) -> TraverseResponse {
+    let (raw_results, has_more) = coll.next_traversal_page(&mut cursor, limit);
+    let next_cursor = has_more.then(|| state.traversal_cursors.park(name, source, cursor));
+    traverse_response(raw_results, has_more, next_cursor)
+}
+
+fn traverse_response(
+    raw_results: Vec<TraversalResult>,
+    has_more: bool,
+    next_cursor: Option<String>,
+) -> TraverseResponse {
     let results: Vec<super::types::TraversalResultItem> = raw_results
         .into_iter()
         .map(|r| super::types::TraversalResultItem {
@@ -304,16 +412,27 @@ pub async fn traverse_graph(
 
     let depth_reached = results.iter().map(|r| r.depth).max().unwrap_or(0);
     let visited = results.len();
-    let has_more = visited >= request.limit;
 
-    Ok(Json(TraverseResponse {
+    TraverseResponse {
         results,
         has_more,
+        next_cursor,
         stats: TraversalStats {
             visited,
             depth_reached,
         },
-    }))
+    }
+}
+
+fn bad_request(error: String) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse {
+            error,
+            code: None,
+            retryable: None,
+        }),
+    )
 }
 
 /// Get the degree (in and out) of a specific node.
@@ -326,7 +445,9 @@ pub async fn traverse_graph(
     ),
     responses(
         (status = 200, description = "Degree retrieved successfully", body = DegreeResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -334,8 +455,9 @@ pub async fn traverse_graph(
 pub async fn get_node_degree(
     Path((name, node_id)): Path<(String, u64)>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
 ) -> Result<Json<DegreeResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
     let (in_degree, out_degree) = coll.node_degree(node_id);
     Ok(Json(DegreeResponse {
         in_degree,
@@ -8,18 +8,20 @@ use std::sync::Arc;
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
-    Json,
+    Extension, Json,
 };
 use velesdb_core::collection::graph::TraversalConfig;
+use velesdb_core::RequestContext;
 
 use crate::types::ErrorResponse;
 use crate::AppState;
 
-use super::handlers::graph_preamble;
+use super::handlers::{check_traversal_limit, graph_preamble, graph_read_preamble};
 use super::types::{
     EdgeCountResponse, EdgeResponse, EdgesResponse, GraphSearchRequest, GraphSearchResponse,
-    GraphSearchResultItem, NodeEdgeQueryParams, NodeListResponse, NodePayloadResponse,
-    ParallelTraverseRequest, TraversalStats, TraverseResponse, UpsertNodePayloadRequest,
+    GraphSearchResultItem, GraphStatsResponse, NodeEdgeQueryParams, NodeListResponse,
+    NodePayloadResponse, ParallelTraverseRequest, TraversalStats, TraverseResponse,
+    UpsertNodePayloadRequest,
 };
 
 /// Remove an edge by ID.
@@ -33,6 +35,7 @@ use super::types::{
     responses(
         (status = 204, description = "Edge removed successfully"),
         (status = 404, description = "Edge or collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -56,6 +59,7 @@ pub async fn remove_edge(
             Json(ErrorResponse {
                 error: format!("{err} in collection '{name}'"),
                 code: Some(err.code().to_string()),
+                retryable: Some(err.is_retryable()),
             }),
         ))
     }
@@ -70,20 +74,47 @@ pub async fn remove_edge(
     ),
     responses(
         (status = 200, description = "Edge count retrieved", body = EdgeCountResponse),
-        (status = 404, description = "Collection not found", body = ErrorResponse)
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse)
     ),
     tag = "graph"
 )]
 pub async fn get_edge_count(
     Path(name): Path<String>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
 ) -> Result<Json<EdgeCountResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
     Ok(Json(EdgeCountResponse {
         count: coll.edge_count(),
     }))
 }
 
+/// Get node/edge counts per label, the degree histogram and the top hubs.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/graph/stats",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    responses(
+        (status = 200, description = "Graph statistics retrieved", body = GraphStatsResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse)
+    ),
+    tag = "graph"
+)]
+pub async fn get_graph_stats(
+    Path(name): Path<String>,
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+) -> Result<Json<GraphStatsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
+    Ok(Json(coll.graph_stats().into()))
+}
+
 /// List all node IDs in the graph.
 #[utoipa::path(
     get,
@@ -93,15 +124,18 @@ pub async fn get_edge_count(
     ),
     responses(
         (status = 200, description = "Node list retrieved", body = NodeListResponse),
-        (status = 404, description = "Collection not found", body = ErrorResponse)
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse)
     ),
     tag = "graph"
 )]
 pub async fn list_nodes(
     Path(name): Path<String>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
 ) -> Result<Json<NodeListResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
     let node_ids = coll.all_node_ids();
     let count = node_ids.len();
     Ok(Json(NodeListResponse { node_ids, count }))
@@ -118,7 +152,9 @@ pub async fn list_nodes(
     ),
     responses(
         (status = 200, description = "Node edges retrieved", body = EdgesResponse),
-        (status = 404, description = "Collection not found", body = ErrorResponse)
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse)
     ),
     tag = "graph"
 )]
@@ -126,8 +162,9 @@ pub async fn get_node_edges(
     Path((name, node_id)): Path<(String, u64)>,
     Query(params): Query<NodeEdgeQueryParams>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
 ) -> Result<Json<EdgesResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
 
     let raw_edges = match params.direction.to_lowercase().as_str() {
         "in" => coll.get_incoming(node_id),
@@ -172,6 +209,7 @@ pub async fn get_node_edges(
     responses(
         (status = 204, description = "Payload stored successfully"),
         (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -189,6 +227,7 @@ pub async fn upsert_node_payload(
                 Json(ErrorResponse {
                     error: format!("Failed to store payload: {e}"),
                     code: None,
+                    retryable: None,
                 }),
             )
         })?;
@@ -205,7 +244,9 @@ pub async fn upsert_node_payload(
     ),
     responses(
         (status = 200, description = "Payload retrieved", body = NodePayloadResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -213,14 +254,16 @@ pub async fn upsert_node_payload(
 pub async fn get_node_payload(
     Path((name, node_id)): Path<(String, u64)>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
 ) -> Result<Json<NodePayloadResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
     let payload = coll.get_node_payload(node_id).map_err(|e| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
                 error: format!("Failed to get payload: {e}"),
                 code: None,
+                retryable: None,
             }),
         )
     })?;
@@ -235,13 +278,16 @@ pub async fn get_node_payload(
     responses(
         (status = 200, description = "Parallel traversal completed", body = TraverseResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Collection not found", body = ErrorResponse)
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse)
     ),
     tag = "graph"
 )]
 pub async fn traverse_parallel(
     Path(name): Path<String>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     Json(request): Json<ParallelTraverseRequest>,
 ) -> Result<Json<TraverseResponse>, (StatusCode, Json<ErrorResponse>)> {
     if request.sources.is_empty() {
@@ -250,15 +296,19 @@ pub async fn traverse_parallel(
             Json(ErrorResponse {
                 error: "At least one source node ID is required".to_string(),
                 code: None,
+                retryable: None,
             }),
         ));
     }
 
-    let coll = graph_preamble(&state, &name)?;
+    let coll = graph_read_preamble(&state, &name, ctx.as_deref())?;
+    check_traversal_limit(&coll, request.limit)?;
 
-    let config = TraversalConfig::with_range(1, request.max_depth)
+    let mut config = TraversalConfig::with_range(1, request.max_depth)
         .with_limit(request.limit)
         .with_rel_types(request.rel_types);
+    config.valid_at = request.valid_at;
+    config.max_cost = request.max_cost;
 
     let raw_results = coll.traverse_bfs_parallel(&request.sources, &config);
 
@@ -278,6 +328,7 @@ pub async fn traverse_parallel(
     Ok(Json(TraverseResponse {
         results,
         has_more,
+        next_cursor: None,
         stats: TraversalStats {
             visited,
             depth_reached,
@@ -293,7 +344,9 @@ pub async fn traverse_parallel(
     responses(
         (status = 200, description = "Graph search results", body = GraphSearchResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Read denied by governance policy", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "graph"
@@ -301,6 +354,7 @@ pub async fn traverse_parallel(
 pub async fn graph_search(
     Path(name): Path<String>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     Json(request): Json<GraphSearchRequest>,
 ) -> Result<Json<GraphSearchResponse>, (StatusCode, Json<ErrorResponse>)> {
     let coll = graph_preamble(&state, &name)?;
@@ -314,17 +368,17 @@ pub async fn graph_search(
                      Create it with create_graph_collection_with_embeddings() to enable search."
                 ),
                 code: None,
+                retryable: None,
             }),
         ));
     }
 
     // Gate the read (CORE-2). Graph embedding search has no metadata-filter
     // channel, so a denied or scope-narrowed decision refuses it (fail closed).
-    match state.db.authorize_read(
+    match state.db.authorize_read_with_context(
         &name,
         velesdb_core::observer::QueryOperationKind::VectorSearch,
-        None,
-        None,
+        ctx.as_deref(),
     ) {
         Ok(None) => {}
         Ok(Some(_)) | Err(_) => {
@@ -333,6 +387,7 @@ pub async fn graph_search(
                 Json(ErrorResponse {
                     error: "Read denied by governance policy".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             ));
         }
@@ -346,6 +401,7 @@ pub async fn graph_search(
                 Json(ErrorResponse {
                     error: format!("Graph search failed: {e}"),
                     code: None,
+                    retryable: None,
                 }),
             )
         })?;
@@ -0,0 +1,119 @@
+//! Graph export/import handlers (GraphML, JSON Lines).
+//!
+//! Lets users round-trip a graph collection with NetworkX, Neo4j and other
+//! graph tooling.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use velesdb_core::{GraphFormat, RequestContext};
+
+use crate::handlers::helpers::{auto_core_error_response, core_error_response, error_response};
+use crate::types::ErrorResponse;
+use crate::AppState;
+
+use super::handlers::{graph_preamble, graph_read_preamble};
+use super::types::{GraphFormatParams, GraphImportResponse};
+
+/// Parses the `format` query parameter, rejecting unknown formats with 400.
+#[allow(clippy::result_large_err)]
+fn parse_format(params: &GraphFormatParams) -> Result<GraphFormat, axum::response::Response> {
+    params
+        .format
+        .parse()
+        .map_err(|err| auto_core_error_response(&err))
+}
+
+/// Export the graph (node payloads and edges) as GraphML or JSON Lines.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/graph/export",
+    params(("name" = String, Path, description = "Collection name"), GraphFormatParams),
+    responses(
+        (status = 200, description = "Graph document", body = String, content_type = "application/graphml+xml"),
+        (status = 400, description = "Unknown format", body = ErrorResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "graph"
+)]
+pub async fn export_graph(
+    Path(name): Path<String>,
+    Query(params): Query<GraphFormatParams>,
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+) -> axum::response::Response {
+    let format = match parse_format(&params) {
+        Ok(f) => f,
+        Err(resp) => return resp,
+    };
+    let coll = match graph_read_preamble(&state, &name, ctx.as_deref()) {
+        Ok(c) => c,
+        Err(resp) => return resp.into_response(),
+    };
+
+    // Export walks every payload — keep it off the async runtime.
+    match tokio::task::spawn_blocking(move || coll.export_graph(format)).await {
+        Ok(Ok(body)) => ([(header::CONTENT_TYPE, format.content_type())], body).into_response(),
+        Ok(Err(e)) => auto_core_error_response(&e),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Graph export task failed: {e}"),
+        ),
+    }
+}
+
+/// Import nodes and edges from a GraphML or JSON Lines document.
+#[utoipa::path(
+    post,
+    path = "/collections/{name}/graph/import",
+    params(("name" = String, Path, description = "Collection name"), GraphFormatParams),
+    request_body(content = String, content_type = "application/graphml+xml", description = "GraphML or JSON Lines document"),
+    responses(
+        (status = 200, description = "Graph imported", body = GraphImportResponse),
+        (status = 400, description = "Unknown format, malformed document or invalid edge", body = ErrorResponse),
+        (status = 404, description = "Collection not found, or an edge endpoint is missing (VELES-022 NodeNotFound)", body = ErrorResponse),
+        (status = 409, description = "Collection exists but is not a graph collection", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "graph"
+)]
+pub async fn import_graph(
+    Path(name): Path<String>,
+    Query(params): Query<GraphFormatParams>,
+    State(state): State<Arc<AppState>>,
+    body: String,
+) -> axum::response::Response {
+    let format = match parse_format(&params) {
+        Ok(f) => f,
+        Err(resp) => return resp,
+    };
+    let coll = match graph_preamble(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp.into_response(),
+    };
+
+    match tokio::task::spawn_blocking(move || coll.import_graph(format, &body)).await {
+        Ok(Ok(stats)) => Json(GraphImportResponse {
+            nodes: stats.nodes,
+            edges: stats.edges,
+        })
+        .into_response(),
+        // A malformed document is a client error, not a storage failure.
+        Ok(Err(e @ velesdb_core::Error::Serialization(_))) => {
+            core_error_response(StatusCode::BAD_REQUEST, &e)
+        }
+        Ok(Err(e)) => auto_core_error_response(&e),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Graph import task failed: {e}"),
+        ),
+    }
+}
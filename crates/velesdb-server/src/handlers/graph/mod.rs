@@ -6,6 +6,7 @@
 
 pub mod handlers;
 pub mod handlers_extended;
+pub mod interchange;
 pub mod stream;
 pub mod types;
 
@@ -13,18 +14,20 @@ pub mod types;
 pub use handlers::{add_edge, add_edges_batch, get_edges, get_node_degree, traverse_graph};
 // Re-export public API — extended handlers (parity)
 pub use handlers_extended::{
-    get_edge_count, get_node_edges, get_node_payload, graph_search, list_nodes, remove_edge,
-    traverse_parallel, upsert_node_payload,
+    get_edge_count, get_graph_stats, get_node_edges, get_node_payload, graph_search, list_nodes,
+    remove_edge, traverse_parallel, upsert_node_payload,
 };
+pub use interchange::{export_graph, import_graph};
 pub use stream::stream_traverse;
 #[allow(unused_imports)]
 pub use types::{
-    AddEdgeRequest, AddEdgesBatchRequest, AddEdgesBatchResponse, DegreeResponse, EdgeCountResponse,
-    EdgeQueryParams, EdgeResponse, EdgesResponse, GraphSearchRequest, GraphSearchResponse,
-    GraphSearchResultItem, NodeEdgeQueryParams, NodeListResponse, NodePayloadResponse,
-    ParallelTraverseRequest, StreamDoneEvent, StreamErrorEvent, StreamNodeEvent, StreamStatsEvent,
-    StreamTraverseParams, TraversalResultItem, TraversalStats, TraverseRequest, TraverseResponse,
-    UpsertNodePayloadRequest,
+    AddEdgeRequest, AddEdgesBatchRequest, AddEdgesBatchResponse, DegreeBucketResponse,
+    DegreeResponse, EdgeCountResponse, EdgeQueryParams, EdgeResponse, EdgesResponse,
+    GraphFormatParams, GraphImportResponse, GraphSearchRequest, GraphSearchResponse,
+    GraphSearchResultItem, GraphStatsResponse, HubNodeResponse, NodeEdgeQueryParams,
+    NodeListResponse, NodePayloadResponse, ParallelTraverseRequest, StreamDoneEvent,
+    StreamErrorEvent, StreamNodeEvent, StreamStatsEvent, StreamTraverseParams, TraversalResultItem,
+    TraversalStats, TraverseRequest, TraverseResponse, UpsertNodePayloadRequest,
 };
 
 #[cfg(test)]
@@ -174,6 +177,7 @@ mod tests {
                 path: vec![100],
             }],
             has_more: false,
+            next_cursor: None,
             stats: TraversalStats {
                 visited: 1,
                 depth_reached: 1,
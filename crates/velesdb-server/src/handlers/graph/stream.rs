@@ -26,6 +26,7 @@
 use axum::{
     extract::{Path, Query, State},
     response::sse::{Event, KeepAlive, Sse},
+    Extension,
 };
 use futures::stream::{self, Stream};
 use std::convert::Infallible;
@@ -33,7 +34,9 @@ use std::time::Instant;
 use tracing::warn;
 
 use std::sync::Arc;
+use velesdb_core::RequestContext;
 
+use crate::handlers::row_security::refuse_unfiltered_read;
 use crate::AppState;
 
 use super::types::{
@@ -50,7 +53,8 @@ const STATS_INTERVAL: usize = 100;
 /// - `node`: Each node reached during traversal
 /// - `stats`: Periodic statistics (every [`STATS_INTERVAL`] nodes)
 /// - `done`: Traversal completed
-/// - `error`: If an error occurs
+/// - `error`: If an error occurs, or when the API key is narrowed by the
+///   collection's row-security policy, which a traversal cannot apply
 #[utoipa::path(
     get,
     path = "/collections/{name}/graph/traverse/stream",
@@ -60,17 +64,23 @@ const STATS_INTERVAL: usize = 100;
         StreamTraverseParams
     ),
     responses(
-        (status = 200, description = "SSE stream of traversal events (node, stats, done, error)")
+        (status = 200, description = "SSE stream of traversal events: `node` (StreamNodeEvent), `stats` (StreamStatsEvent), `done` (StreamDoneEvent) and `error` (StreamErrorEvent)", content_type = "text/event-stream", body = StreamNodeEvent)
     )
 )]
 pub async fn stream_traverse(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     Path(collection): Path<String>,
     Query(params): Query<StreamTraverseParams>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
     let start_time = Instant::now();
     state.onboarding_metrics.record_graph_request();
 
+    if let Err((_, body)) = refuse_unfiltered_read(&state, &collection, ctx.as_deref()) {
+        let events = build_error_events(body.0.error);
+        return Sse::new(stream::iter(events)).keep_alive(KeepAlive::default());
+    }
+
     // Snapshot the parameters and state needed by the blocking worker.
     let coll_handle = state.db.get_graph_collection(&collection);
 
@@ -114,6 +124,9 @@ fn run_traversal_blocking(
         ));
     };
 
+    coll.check_query_limit(u64::try_from(params.limit).unwrap_or(u64::MAX))
+        .map_err(|e| e.to_string())?;
+
     let rel_types: Vec<String> = params
         .relationship_types
         .as_ref()
@@ -38,16 +38,32 @@ pub struct TraverseRequest {
     pub source: u64,
     /// Traversal strategy: "bfs" or "dfs".
     #[serde(default = "default_strategy")]
+    #[schema(example = "bfs")]
     pub strategy: String,
     /// Maximum traversal depth.
     #[serde(default = "default_max_depth")]
+    #[schema(example = 3)]
     pub max_depth: u32,
     /// Maximum number of results to return.
     #[serde(default = "default_limit")]
     pub limit: usize,
     /// Filter by relationship types (empty = all types).
     #[serde(default)]
+    #[schema(example = json!(["KNOWS"]))]
     pub rel_types: Vec<String>,
+    /// Only follow edges valid at this Unix timestamp, per their
+    /// `valid_from` / `valid_to` properties.
+    #[serde(default)]
+    pub valid_at: Option<i64>,
+    /// Maximum summed edge `weight` of a path; results then come cheapest
+    /// first.
+    #[serde(default)]
+    pub max_cost: Option<f64>,
+    /// `next_cursor` of the previous page: resumes that BFS instead of
+    /// starting a new one. `source` must match the first request; the other
+    /// traversal options are taken from it and ignored here.
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 fn default_strategy() -> String {
@@ -69,6 +85,9 @@ pub struct TraverseResponse {
     pub results: Vec<TraversalResultItem>,
     /// Whether more results are available.
     pub has_more: bool,
+    /// Token resuming this BFS on the next request (`cursor`); `null` when
+    /// the traversal is exhausted or for DFS and parallel traversals.
+    pub next_cursor: Option<String>,
     /// Traversal statistics.
     pub stats: TraversalStats,
 }
@@ -137,9 +156,11 @@ pub struct AddEdgeRequest {
     #[cfg_attr(feature = "openapi", schema(schema_with = serde_id::id_input_schema))]
     pub target: u64,
     /// Edge label (relationship type).
+    #[schema(example = "KNOWS")]
     pub label: String,
     /// Edge properties.
     #[serde(default)]
+    #[schema(example = json!({"since": 2020, "weight": 0.8}))]
     pub properties: serde_json::Value,
 }
 
@@ -225,22 +246,34 @@ pub struct ParallelTraverseRequest {
     pub sources: Vec<u64>,
     /// Maximum traversal depth.
     #[serde(default = "default_max_depth")]
+    #[schema(example = 3)]
     pub max_depth: u32,
     /// Maximum number of results per source.
     #[serde(default = "default_limit")]
     pub limit: usize,
     /// Filter by relationship types (empty = all types).
     #[serde(default)]
+    #[schema(example = json!(["KNOWS"]))]
     pub rel_types: Vec<String>,
+    /// Only follow edges valid at this Unix timestamp, per their
+    /// `valid_from` / `valid_to` properties.
+    #[serde(default)]
+    pub valid_at: Option<i64>,
+    /// Maximum summed edge `weight` of a path; results then come cheapest
+    /// first.
+    #[serde(default)]
+    pub max_cost: Option<f64>,
 }
 
 /// Request for graph embedding search.
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct GraphSearchRequest {
     /// Query vector for similarity search.
+    #[schema(example = json!([0.1, 0.2, 0.3]))]
     pub vector: Vec<f32>,
     /// Number of results to return.
     #[serde(default = "default_graph_search_k")]
+    #[schema(example = 10)]
     pub top_k: usize,
 }
 
@@ -351,6 +384,118 @@ pub struct StreamErrorEvent {
     pub error: String,
 }
 
+// ============================================================================
+// Graph export / import
+// ============================================================================
+
+/// Query parameters selecting a graph interchange format.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GraphFormatParams {
+    /// Interchange format: "graphml" or "jsonl".
+    #[serde(default = "default_graph_format")]
+    #[param(example = "graphml")]
+    pub format: String,
+}
+
+fn default_graph_format() -> String {
+    "jsonl".to_string()
+}
+
+/// Response for a graph import.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GraphImportResponse {
+    /// Node payloads written.
+    pub nodes: usize,
+    /// Edges added (edges whose id already exists are skipped).
+    pub edges: usize,
+}
+
+// ============================================================================
+// Graph Statistics
+// ============================================================================
+
+/// One bucket of the node degree histogram (bounds inclusive).
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DegreeBucketResponse {
+    /// Smallest total degree (out + in) in the bucket.
+    pub min_degree: usize,
+    /// Largest total degree in the bucket.
+    pub max_degree: usize,
+    /// Number of nodes in the bucket.
+    pub nodes: usize,
+}
+
+/// A high-degree node.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HubNodeResponse {
+    /// Node ID.
+    #[serde(serialize_with = "serde_id::serialize_id_as_string")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub id: u64,
+    /// Number of outgoing edges.
+    pub out_degree: usize,
+    /// Number of incoming edges.
+    pub in_degree: usize,
+}
+
+/// Response for graph statistics.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GraphStatsResponse {
+    /// Number of nodes.
+    pub node_count: usize,
+    /// Number of edges.
+    pub edge_count: usize,
+    /// Nodes per label.
+    pub nodes_by_label: std::collections::BTreeMap<String, u64>,
+    /// Edges per label.
+    pub edges_by_label: std::collections::BTreeMap<String, usize>,
+    /// Non-empty power-of-two buckets of the total degree, ascending.
+    pub degree_histogram: Vec<DegreeBucketResponse>,
+    /// Largest total degree.
+    pub max_degree: usize,
+    /// Mean total degree.
+    pub mean_degree: f64,
+    /// Nodes whose total degree is at least `hub_threshold`.
+    pub hub_count: usize,
+    /// Total degree from which a node counts as a hub.
+    pub hub_threshold: usize,
+    /// Highest-degree nodes, highest first.
+    pub top_hubs: Vec<HubNodeResponse>,
+}
+
+impl From<velesdb_core::GraphStats> for GraphStatsResponse {
+    fn from(stats: velesdb_core::GraphStats) -> Self {
+        Self {
+            node_count: stats.node_count,
+            edge_count: stats.edge_count,
+            nodes_by_label: stats.nodes_by_label,
+            edges_by_label: stats.edges_by_label,
+            degree_histogram: stats
+                .degree_histogram
+                .into_iter()
+                .map(|b| DegreeBucketResponse {
+                    min_degree: b.min_degree,
+                    max_degree: b.max_degree,
+                    nodes: b.nodes,
+                })
+                .collect(),
+            max_degree: stats.max_degree,
+            mean_degree: stats.mean_degree,
+            hub_count: stats.hub_count,
+            hub_threshold: velesdb_core::collection::graph::HUB_DEGREE_THRESHOLD,
+            top_hubs: stats
+                .top_hubs
+                .into_iter()
+                .map(|h| HubNodeResponse {
+                    id: h.id,
+                    out_degree: h.out_degree,
+                    in_degree: h.in_degree,
+                })
+                .collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,22 +4,146 @@ use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use crate::types::{
+    BackgroundJobsHealth, CollectionHealthEntry, CollectionsHealth, DiskHealth, HealthComponents,
+    HealthResponse, HealthStatus, WalHealth,
+};
 use crate::AppState;
 
-/// Liveness probe — always returns 200 OK.
+/// Free space below this share of the filesystem (percent) fails `/health`.
+const DISK_UNHEALTHY_PERCENT: u64 = 1;
+/// Free space below this share of the filesystem (percent) degrades `/health`.
+const DISK_DEGRADED_PERCENT: u64 = 10;
+
+/// Component health — 200 while serving, 503 when a critical component fails.
+///
+/// Critical components are collection loading and disk space. The body only
+/// carries aggregate counts because this endpoint is public; per-collection
+/// state is on the authenticated `GET /health/details`.
 #[utoipa::path(
     get,
     path = "/health",
     tag = "health",
     responses(
-        (status = 200, description = "Server is alive", body = Object)
+        (status = 200, description = "Server is healthy or degraded", body = HealthResponse),
+        (status = 503, description = "A critical component failed", body = HealthResponse)
     )
 )]
-pub async fn health_check() -> impl IntoResponse {
-    Json(serde_json::json!({
-        "status": "ok",
-        "version": env!("CARGO_PKG_VERSION")
-    }))
+pub async fn health_check(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    health_response(build_health_report(&state, false))
+}
+
+/// Component health with per-collection state (authenticated).
+#[utoipa::path(
+    get,
+    path = "/health/details",
+    tag = "health",
+    responses(
+        (status = 200, description = "Server is healthy or degraded", body = HealthResponse),
+        (status = 503, description = "A critical component failed", body = HealthResponse)
+    )
+)]
+pub async fn health_details(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    health_response(build_health_report(&state, true))
+}
+
+fn health_response(report: HealthResponse) -> (StatusCode, Json<HealthResponse>) {
+    let code = if report.status == HealthStatus::Unhealthy {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+    (code, Json(report))
+}
+
+/// Collects component statuses; `with_collections` adds the per-collection list.
+fn build_health_report(state: &AppState, with_collections: bool) -> HealthResponse {
+    let failures = state.db.collection_load_failures();
+    let mut entries = Vec::new();
+    let mut wal_backlog = 0u64;
+    let mut queue_depth = 0usize;
+
+    for name in state.db.list_collections() {
+        // A collection dropped between listing and lookup is simply skipped.
+        let Ok(diag) = state.db.collection_diagnostics(&name) else {
+            continue;
+        };
+        wal_backlog += diag.wal_backlog_bytes;
+        queue_depth += diag.pending_index_ops;
+        entries.push(CollectionHealthEntry {
+            name,
+            status: HealthStatus::Ok,
+            point_count: Some(diag.point_count),
+            pending_index_ops: Some(diag.pending_index_ops),
+            wal_backlog_bytes: Some(diag.wal_backlog_bytes),
+            error: None,
+        });
+    }
+    let loaded = entries.len();
+    entries.extend(failures.iter().map(|f| CollectionHealthEntry {
+        name: f.name.clone(),
+        status: HealthStatus::Unhealthy,
+        point_count: None,
+        pending_index_ops: None,
+        wal_backlog_bytes: None,
+        error: Some(f.error.clone()),
+    }));
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let collections = CollectionsHealth {
+        status: if failures.is_empty() {
+            HealthStatus::Ok
+        } else {
+            HealthStatus::Unhealthy
+        },
+        loaded,
+        failed: failures.len(),
+    };
+    let disk = disk_health(&state.db);
+    let status = collections.status.max(disk.status);
+
+    HealthResponse {
+        status,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        components: HealthComponents {
+            collections,
+            disk,
+            wal: WalHealth {
+                backlog_bytes: wal_backlog,
+            },
+            background_jobs: BackgroundJobsHealth { queue_depth },
+        },
+        collections: with_collections.then_some(entries),
+    }
+}
+
+fn disk_health(db: &velesdb_core::Database) -> DiskHealth {
+    match db.disk_space() {
+        Ok(space) => {
+            // u128 keeps the percentage comparison overflow-free.
+            let available = u128::from(space.available_bytes) * 100;
+            let total = u128::from(space.total_bytes);
+            let status = if available < total * u128::from(DISK_UNHEALTHY_PERCENT) {
+                HealthStatus::Unhealthy
+            } else if available < total * u128::from(DISK_DEGRADED_PERCENT) {
+                HealthStatus::Degraded
+            } else {
+                HealthStatus::Ok
+            };
+            DiskHealth {
+                status,
+                available_bytes: Some(space.available_bytes),
+                total_bytes: Some(space.total_bytes),
+                error: None,
+            }
+        }
+        Err(e) => DiskHealth {
+            status: HealthStatus::Unhealthy,
+            available_bytes: None,
+            total_bytes: None,
+            error: Some(e.to_string()),
+        },
+    }
 }
 
 /// Readiness probe — returns 200 when the database is fully loaded, 503 otherwise.
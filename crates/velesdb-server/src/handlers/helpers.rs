@@ -15,6 +15,7 @@ pub(crate) fn error_response(status: StatusCode, message: String) -> axum::respo
         Json(ErrorResponse {
             error: message,
             code: None,
+            retryable: None,
         }),
     )
         .into_response()
@@ -34,6 +35,7 @@ pub(crate) fn core_error_response(
         Json(ErrorResponse {
             error: error.to_string(),
             code: Some(error.code().to_string()),
+            retryable: Some(error.is_retryable()),
         }),
     )
         .into_response()
@@ -69,7 +71,8 @@ pub(crate) fn http_status_for_error(e: &velesdb_core::Error) -> StatusCode {
         | Error::VectorRequired(_)
         | Error::SearchNotSupported(_)
         | Error::GraphNotSupported(_)
-        | Error::Overflow(_) => StatusCode::BAD_REQUEST,
+        | Error::Overflow(_)
+        | Error::QueryLimitExceeded { .. } => StatusCode::BAD_REQUEST,
 
         // 503 Service Unavailable
         Error::DatabaseLocked(_) | Error::GuardRail(_) => StatusCode::SERVICE_UNAVAILABLE,
@@ -85,6 +88,35 @@ pub(crate) fn auto_core_error_response(error: &velesdb_core::Error) -> axum::res
     core_error_response(http_status_for_error(error), error)
 }
 
+/// Attempts [`retry_transient`] makes before returning the error.
+const TRANSIENT_RETRY_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry in [`retry_transient`]; doubles each time.
+const TRANSIENT_RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Runs a blocking core operation, retrying with exponential backoff while
+/// it fails with a retryable error ([`velesdb_core::Error::is_retryable`]),
+/// e.g. a stale mmap epoch after storage grew under the operation.
+///
+/// Only for idempotent operations such as upserts; call it from a blocking
+/// thread (`spawn_blocking`), since it sleeps between attempts.
+pub(crate) fn retry_transient<T>(
+    mut operation: impl FnMut() -> velesdb_core::Result<T>,
+) -> velesdb_core::Result<T> {
+    let mut backoff = TRANSIENT_RETRY_BACKOFF;
+    for _ in 1..TRANSIENT_RETRY_ATTEMPTS {
+        match operation() {
+            Err(e) if e.is_retryable() => {
+                tracing::debug!(error = %e, ?backoff, "retrying transient error");
+                std::thread::sleep(backoff);
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+    operation()
+}
+
 /// Look up a type-erased collection by name, returning a 404 response on miss.
 ///
 /// Emits `VELES-002 CollectionNotFound` via [`core_error_response`] so that
@@ -138,6 +170,32 @@ pub(crate) fn extract_client_id(headers: &axum::http::HeaderMap) -> String {
         .to_string()
 }
 
+/// Request header through which a client declares the embedding model that
+/// produced the vectors it sends (`model` or `model@version`).
+pub(crate) const EMBEDDING_MODEL_HEADER: &str = "x-embedding-model";
+
+/// Validates the optional [`EMBEDDING_MODEL_HEADER`] against the embedding
+/// model recorded on the collection.
+///
+/// Absent header or no recorded model ⇒ `Ok`. A mismatch yields a 400 with
+/// the core `VELES-017` code so clients can tell a model switch apart from a
+/// plain dimension error.
+#[allow(clippy::result_large_err)]
+pub(crate) fn check_declared_embedding_model(
+    collection: &velesdb_core::collection::VectorCollection,
+    headers: &axum::http::HeaderMap,
+) -> Result<(), axum::response::Response> {
+    let Some(declared) = headers
+        .get(EMBEDDING_MODEL_HEADER)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return Ok(());
+    };
+    collection
+        .check_embedding_model(declared)
+        .map_err(|e| auto_core_error_response(&e))
+}
+
 /// Record query timing via a `tracing` event and notify the `DatabaseObserver`.
 ///
 /// Emits a structured log at `DEBUG` level and forwards the query duration
@@ -173,6 +231,7 @@ pub(crate) fn apply_pre_check(
     client_id: &str,
 ) -> Result<(), axum::response::Response> {
     if let Err(violation) = guard_rails.pre_check(client_id) {
+        let retryable = violation.sheds_load();
         let (status, msg) = match violation {
             velesdb_core::guardrails::GuardRailViolation::RateLimitExceeded { .. } => (
                 StatusCode::TOO_MANY_REQUESTS,
@@ -195,6 +254,7 @@ pub(crate) fn apply_pre_check(
                 // `Error::GuardRail` code) so SDK clients can discriminate
                 // rate-limit / circuit-breaker rejections by code.
                 code: Some("VELES-027".to_string()),
+                retryable: Some(retryable),
             }),
         )
             .into_response());
@@ -268,5 +328,54 @@ mod tests {
             .expect("read rate-limit body");
         let json: serde_json::Value = serde_json::from_slice(&body).expect("valid JSON");
         assert_eq!(json["code"], "VELES-027");
+        assert_eq!(json["retryable"], true);
+    }
+
+    #[tokio::test]
+    async fn test_core_error_response_flags_retryable() {
+        for (err, retryable) in [
+            (velesdb_core::Error::DatabaseLocked("/data".into()), true),
+            (
+                velesdb_core::Error::CollectionNotFound("docs".into()),
+                false,
+            ),
+        ] {
+            let response = auto_core_error_response(&err);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("read error body");
+            let json: serde_json::Value = serde_json::from_slice(&body).expect("valid JSON");
+            assert_eq!(json["retryable"], retryable, "{json}");
+        }
+    }
+
+    #[test]
+    fn test_retry_transient_retries_only_retryable_errors() {
+        let mut calls = 0;
+        let result = retry_transient(|| {
+            calls += 1;
+            if calls < 3 {
+                Err(velesdb_core::Error::EpochMismatch("remapped".into()))
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result.expect("third attempt succeeds"), 3);
+
+        let mut calls = 0;
+        let result: velesdb_core::Result<()> = retry_transient(|| {
+            calls += 1;
+            Err(velesdb_core::Error::CollectionNotFound("docs".into()))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+
+        let mut calls = 0;
+        let result: velesdb_core::Result<()> = retry_transient(|| {
+            calls += 1;
+            Err(velesdb_core::Error::EpochMismatch("remapped".into()))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, TRANSIENT_RETRY_ATTEMPTS);
     }
 }
@@ -7,14 +7,14 @@
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use utoipa::ToSchema;
 use velesdb_core::api_types::serde_id;
-use velesdb_core::Error;
+use velesdb_core::{Error, RequestContext};
 
 use crate::handlers::helpers::auto_core_error_response;
 use crate::types::{ErrorResponse, VELESQL_CONTRACT_VERSION};
@@ -24,9 +24,13 @@ use crate::AppState;
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct MatchQueryRequest {
     /// VelesQL MATCH query string.
+    #[schema(
+        example = "MATCH (a:Person)-[:KNOWS]->(b) WHERE a.name = $name RETURN a.name, b.name LIMIT 10"
+    )]
     pub query: String,
     /// Query parameters (e.g., vectors, values).
     #[serde(default)]
+    #[schema(example = json!({"name": "Alice"}))]
     pub params: HashMap<String, serde_json::Value>,
     /// Query vector for similarity scoring (EPIC-058 US-007).
     #[serde(default)]
@@ -115,9 +119,13 @@ pub struct MatchQueryMeta {
 pub async fn match_query(
     Path(collection_name): Path<String>,
     State(state): State<Arc<AppState>>,
-    Json(request): Json<MatchQueryRequest>,
+    ctx: Option<Extension<RequestContext>>,
+    Json(mut request): Json<MatchQueryRequest>,
 ) -> axum::response::Response {
-    match run_match(&state, &collection_name, &request) {
+    if let Err(message) = velesdb_core::api_types::decode_bytes_params(&mut request.params) {
+        return auto_core_error_response(&Error::InvalidVector(message));
+    }
+    match run_match(&state, &collection_name, &request, ctx.as_deref()) {
         Ok(response) => Json(response).into_response(),
         Err(e) => auto_core_error_response(&e),
     }
@@ -130,6 +138,7 @@ fn run_match(
     state: &AppState,
     collection_name: &str,
     request: &MatchQueryRequest,
+    ctx: Option<&RequestContext>,
 ) -> Result<MatchQueryResponse, Error> {
     let start = std::time::Instant::now();
 
@@ -144,11 +153,10 @@ fn run_match(
     // closed.
     if state
         .db
-        .authorize_read(
+        .authorize_read_with_context(
             collection_name,
             velesdb_core::observer::QueryOperationKind::GraphTraversal,
-            None,
-            None,
+            ctx,
         )?
         .is_some()
     {
@@ -7,6 +7,10 @@
 //! Metrics exposed:
 //! - `velesdb_info`: Server version info
 //! - `velesdb_up`: Server availability gauge
+//! - `velesdb_graph_*`: Per-graph-collection node/edge counts, degree
+//!   histogram and hub count (`GraphCollection::graph_stats`)
+//! - `velesdb_admission_*`: Per-collection running/queued queries and
+//!   admission rejections, by kind (`AnyCollection::admission_stats`)
 
 #![allow(dead_code)] // Functions exposed via feature flag, used when prometheus feature enabled
 
@@ -37,7 +41,7 @@ fn formatting_error_response() -> Response {
     path = "/metrics",
     responses(
         (status = 200, description = "Prometheus metrics", content_type = "text/plain"),
-        (status = 500, description = "Internal server error")
+        (status = 500, description = "Internal server error", content_type = "text/plain", body = String)
     ),
     tag = "metrics"
 )]
@@ -88,6 +92,220 @@ fn write_metrics(output: &mut String, state: &AppState) -> std::fmt::Result {
         "Query execution duration in seconds",
     ));
 
+    write_graph_stats(output, state)?;
+    write_admission_stats(output, state)
+}
+
+/// Escapes a Prometheus label value (`\`, `"` and newlines).
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Writes shape gauges and the node degree histogram of every graph
+/// collection, for capacity planning.
+///
+/// Each scrape walks the adjacency lists of every graph (O(nodes)).
+fn write_graph_stats(output: &mut String, state: &AppState) -> std::fmt::Result {
+    let graphs: Vec<(String, velesdb_core::GraphStats)> = state
+        .db
+        .list_collections()
+        .into_iter()
+        .filter_map(|name| {
+            let stats = state.db.get_graph_collection(&name)?.graph_stats();
+            Some((escape_label_value(&name), stats))
+        })
+        .collect();
+    if graphs.is_empty() {
+        return Ok(());
+    }
+
+    write_graph_counts(output, &graphs)?;
+    write_graph_label_counts(output, &graphs)?;
+    write_graph_degree_histogram(output, &graphs)
+}
+
+/// Reads one gauge value out of [`velesdb_core::GraphStats`].
+type GraphStatsField = fn(&velesdb_core::GraphStats) -> usize;
+
+fn write_graph_counts(
+    output: &mut String,
+    graphs: &[(String, velesdb_core::GraphStats)],
+) -> std::fmt::Result {
+    let gauges: [(&str, &str, GraphStatsField); 4] = [
+        ("velesdb_graph_nodes", "Graph nodes", |s| s.node_count),
+        ("velesdb_graph_edges", "Graph edges", |s| s.edge_count),
+        (
+            "velesdb_graph_max_degree",
+            "Largest node degree (out + in)",
+            |s| s.max_degree,
+        ),
+        (
+            "velesdb_graph_hub_nodes",
+            "Nodes at or above the hub degree threshold",
+            |s| s.hub_count,
+        ),
+    ];
+    for (name, help, value) in gauges {
+        writeln!(output, "# HELP {name} {help}")?;
+        writeln!(output, "# TYPE {name} gauge")?;
+        for (collection, stats) in graphs {
+            writeln!(
+                output,
+                "{name}{{collection=\"{collection}\"}} {}",
+                value(stats)
+            )?;
+        }
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+fn write_graph_label_counts(
+    output: &mut String,
+    graphs: &[(String, velesdb_core::GraphStats)],
+) -> std::fmt::Result {
+    writeln!(
+        output,
+        "# HELP velesdb_graph_label_nodes Graph nodes per label"
+    )?;
+    writeln!(output, "# TYPE velesdb_graph_label_nodes gauge")?;
+    for (collection, stats) in graphs {
+        for (label, count) in &stats.nodes_by_label {
+            writeln!(
+                output,
+                "velesdb_graph_label_nodes{{collection=\"{collection}\",label=\"{}\"}} {count}",
+                escape_label_value(label)
+            )?;
+        }
+    }
+    writeln!(output)?;
+    writeln!(
+        output,
+        "# HELP velesdb_graph_label_edges Graph edges per label"
+    )?;
+    writeln!(output, "# TYPE velesdb_graph_label_edges gauge")?;
+    for (collection, stats) in graphs {
+        for (label, count) in &stats.edges_by_label {
+            writeln!(
+                output,
+                "velesdb_graph_label_edges{{collection=\"{collection}\",label=\"{}\"}} {count}",
+                escape_label_value(label)
+            )?;
+        }
+    }
+    writeln!(output)
+}
+
+/// Cumulative histogram over the power-of-two degree buckets (`le` is the
+/// inclusive upper bound of each non-empty bucket).
+fn write_graph_degree_histogram(
+    output: &mut String,
+    graphs: &[(String, velesdb_core::GraphStats)],
+) -> std::fmt::Result {
+    writeln!(
+        output,
+        "# HELP velesdb_graph_node_degree Node degree (out + in) distribution"
+    )?;
+    writeln!(output, "# TYPE velesdb_graph_node_degree histogram")?;
+    for (collection, stats) in graphs {
+        let mut cumulative = 0;
+        for bucket in &stats.degree_histogram {
+            cumulative += bucket.nodes;
+            writeln!(
+                output,
+                "velesdb_graph_node_degree_bucket{{collection=\"{collection}\",le=\"{}\"}} {cumulative}",
+                bucket.max_degree
+            )?;
+        }
+        writeln!(
+            output,
+            "velesdb_graph_node_degree_bucket{{collection=\"{collection}\",le=\"+Inf\"}} {}",
+            stats.node_count
+        )?;
+        writeln!(
+            output,
+            "velesdb_graph_node_degree_sum{{collection=\"{collection}\"}} {}",
+            // Every edge adds one to the out-degree of its source and one
+            // to the in-degree of its target.
+            stats.edge_count * 2
+        )?;
+        writeln!(
+            output,
+            "velesdb_graph_node_degree_count{{collection=\"{collection}\"}} {}",
+            stats.node_count
+        )?;
+    }
+    writeln!(output)
+}
+
+/// Reads one value out of [`velesdb_core::AdmissionKindStats`].
+type AdmissionStatsField = fn(&velesdb_core::AdmissionKindStats) -> u64;
+
+/// Writes the admission gauges and counters of every collection, labelled by
+/// collection and query kind (`search` / `scan`).
+fn write_admission_stats(output: &mut String, state: &AppState) -> std::fmt::Result {
+    let collections: Vec<(String, velesdb_core::AdmissionStats)> = state
+        .db
+        .list_collections()
+        .into_iter()
+        .filter_map(|name| {
+            let stats = state.db.get_any_collection(&name)?.admission_stats();
+            Some((escape_label_value(&name), stats))
+        })
+        .collect();
+    if collections.is_empty() {
+        return Ok(());
+    }
+
+    let series: [(&str, &str, &str, AdmissionStatsField); 5] = [
+        (
+            "velesdb_admission_in_flight",
+            "Queries currently running",
+            "gauge",
+            |s| s.in_flight as u64,
+        ),
+        (
+            "velesdb_admission_queue_depth",
+            "Queries waiting for a concurrency slot",
+            "gauge",
+            |s| s.queue_depth as u64,
+        ),
+        (
+            "velesdb_admission_admitted_total",
+            "Queries admitted",
+            "counter",
+            |s| s.admitted_total,
+        ),
+        (
+            "velesdb_admission_rejected_total",
+            "Queries rejected because the queue was full or the wait timed out",
+            "counter",
+            |s| s.rejected_total,
+        ),
+        (
+            "velesdb_admission_timed_out_total",
+            "Queries rejected after timing out in the queue",
+            "counter",
+            |s| s.timed_out_total,
+        ),
+    ];
+    for (name, help, kind, value) in series {
+        writeln!(output, "# HELP {name} {help}")?;
+        writeln!(output, "# TYPE {name} {kind}")?;
+        for (collection, stats) in &collections {
+            for (label, kind_stats) in [("search", &stats.searches), ("scan", &stats.scans)] {
+                writeln!(
+                    output,
+                    "{name}{{collection=\"{collection}\",kind=\"{label}\"}} {}",
+                    value(kind_stats)
+                )?;
+            }
+        }
+        writeln!(output)?;
+    }
     Ok(())
 }
 
@@ -216,17 +434,27 @@ mod tests {
 
     fn test_app_state() -> Arc<AppState> {
         let dir = tempfile::tempdir().unwrap();
-        let db = velesdb_core::Database::open(dir.path()).unwrap();
+        test_app_state_in(dir.path())
+    }
+
+    fn test_app_state_in(path: &std::path::Path) -> Arc<AppState> {
+        let db = velesdb_core::Database::open(path).unwrap();
         Arc::new(AppState {
             db,
             onboarding_metrics: OnboardingMetrics::default(),
             query_limits: parking_lot::RwLock::new(velesdb_core::guardrails::QueryLimits::default()),
+            effective_config: parking_lot::RwLock::default(),
+            config_reloader: None,
+            reindex_jobs: crate::reindex::ReindexJobs::default(),
+            index_events: crate::index_events::IndexEventHub::default(),
             ready: std::sync::atomic::AtomicBool::new(true),
             operational_metrics: velesdb_core::metrics::OperationalMetrics::new_arc(),
             traversal_metrics: std::sync::Arc::new(velesdb_core::metrics::TraversalMetrics::new()),
+            traversal_cursors: crate::traversal_cursors::TraversalCursors::default(),
             query_duration_histogram: std::sync::Arc::new(
                 velesdb_core::metrics::DurationHistogram::new(),
             ),
+            embedder: parking_lot::RwLock::default(),
         })
     }
 
@@ -287,4 +515,75 @@ mod tests {
             "should contain plan cache hit rate"
         );
     }
+
+    #[tokio::test]
+    async fn test_graph_stats_in_prometheus_output() {
+        use velesdb_core::collection::graph::{GraphEdge, GraphSchema};
+
+        let dir = tempfile::tempdir().unwrap();
+        let state = test_app_state_in(dir.path());
+        state
+            .db
+            .create_graph_collection("social", GraphSchema::schemaless())
+            .unwrap();
+        let graph = state.db.get_graph_collection("social").unwrap();
+        for id in [1, 2, 3] {
+            graph
+                .upsert_node_payload(id, &serde_json::json!({"_labels": ["Person"]}))
+                .unwrap();
+        }
+        graph
+            .add_edge(GraphEdge::new(10, 1, 2, "KNOWS").unwrap())
+            .unwrap();
+
+        let response = prometheus_metrics(State(state)).await.into_response();
+        let body = axum::body::to_bytes(response.into_body(), 1_000_000)
+            .await
+            .unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+
+        for line in [
+            "velesdb_graph_nodes{collection=\"social\"} 3",
+            "velesdb_graph_edges{collection=\"social\"} 1",
+            "velesdb_graph_label_nodes{collection=\"social\",label=\"Person\"} 3",
+            "velesdb_graph_label_edges{collection=\"social\",label=\"KNOWS\"} 1",
+            "velesdb_graph_node_degree_bucket{collection=\"social\",le=\"0\"} 1",
+            "velesdb_graph_node_degree_bucket{collection=\"social\",le=\"1\"} 3",
+            "velesdb_graph_node_degree_sum{collection=\"social\"} 2",
+        ] {
+            assert!(text.contains(line), "missing `{line}` in:\n{text}");
+        }
+    }
+
+    #[test]
+    fn test_escape_label_value() {
+        assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[tokio::test]
+    async fn test_admission_stats_in_prometheus_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = test_app_state_in(dir.path());
+        state
+            .db
+            .create_collection("docs", 2, velesdb_core::DistanceMetric::Cosine)
+            .unwrap();
+        let docs = state.db.get_vector_collection("docs").unwrap();
+        docs.search(&[1.0, 0.0], 1).unwrap();
+
+        let response = prometheus_metrics(State(state)).await.into_response();
+        let body = axum::body::to_bytes(response.into_body(), 1_000_000)
+            .await
+            .unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+
+        for line in [
+            "velesdb_admission_admitted_total{collection=\"docs\",kind=\"search\"} 1",
+            "velesdb_admission_in_flight{collection=\"docs\",kind=\"search\"} 0",
+            "velesdb_admission_queue_depth{collection=\"docs\",kind=\"scan\"} 0",
+            "velesdb_admission_rejected_total{collection=\"docs\",kind=\"scan\"} 0",
+        ] {
+            assert!(text.contains(line), "missing `{line}` in:\n{text}");
+        }
+    }
 }
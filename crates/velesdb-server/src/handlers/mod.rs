@@ -4,50 +4,74 @@
 //! - `health`: Health check endpoints
 //! - `collections`: Collection CRUD operations
 //! - `admin`: Stats, config, guardrails, and analyze endpoints
+//! - `embedding`: Embedding model metadata
+//! - `events`: Index build progress stream
 //! - `points`: Vector point operations
-//! - `search`: Vector similarity search
+//! - `projection`: 2-D layout export for visualization
+//! - `reindex`: Online HNSW reindex and its progress stream
+//! - `search`: Vector similarity search, plus the OpenAI-compatible
+//!   `/embeddings` and text-in `/retrieval` endpoints
 //! - `query`: VelesQL query execution
 //! - `indexes`: Property index management (EPIC-009)
 //! - `graph`: Graph operations (EPIC-016/US-031)
+//! - `row_security`: Per-collection row-security policies
+//! - `slo`: Per-collection latency SLOs
+//! - `views`: Filtered views over collections
 //! - `metrics`: Prometheus metrics (requires `prometheus` feature)
 
 pub mod admin;
 pub mod collections;
+pub mod embedding;
+pub mod events;
 pub mod graph;
 pub mod health;
 pub mod helpers;
 pub mod indexes;
 pub mod match_query;
 pub mod points;
+pub mod projection;
 pub mod query;
+pub mod reindex;
+pub mod request_limits;
+pub mod row_security;
 pub mod search;
+pub mod slo;
+pub mod views;
 
 #[cfg(feature = "prometheus")]
 pub mod metrics;
 
 pub use admin::{
-    analyze_collection, collection_diagnostics, compact_collection, get_collection_config,
-    get_collection_stats, get_guardrails, rebuild_index, reorder_for_locality, update_guardrails,
+    analyze_collection, collection_diagnostics, compact_collection, get_access_stats,
+    get_collection_config, get_collection_stats, get_guardrails, get_server_config,
+    get_vector_stats, rebuild_index, reload_config, reorder_for_locality, update_guardrails,
     vacuum_collection,
 };
 pub use collections::{
     collection_sanity, create_collection, delete_collection, flush_collection, get_collection,
     is_empty, list_collections,
 };
-pub use health::{health_check, readiness_check};
+pub use embedding::set_embedding_info;
+pub use events::{collection_events, subscribe_collection};
+pub use health::{health_check, health_details, readiness_check};
 pub use indexes::{create_index, delete_index, list_indexes};
 pub use points::{
-    bulk_delete_points, delete_point, enable_streaming, get_point, get_point_relations,
-    relate_points, scroll_points, set_point_ttl, stream_insert, stream_upsert_points,
-    unrelate_points, upsert_points, upsert_points_raw,
+    bulk_delete_points, count_points, delete_point, enable_streaming, get_point,
+    get_point_relations, get_points, relate_points, scroll_points, set_point_ttl, stream_insert,
+    stream_upsert_points, unrelate_points, upsert_points, upsert_points_raw,
 };
+pub use projection::project_collection;
 // EPIC-058 US-007: match_query handler for /collections/{name}/match
 pub use match_query::match_query;
 pub use query::{aggregate, explain, query};
+pub use reindex::{reindex_status, start_reindex};
+pub use row_security::{delete_row_security, get_row_security, set_row_security};
 pub use search::{
-    batch_search, hybrid_search, multi_query_search, multi_query_search_ids, search, search_ids,
-    text_search,
+    batch_search, create_embeddings, hybrid_search, multi_query_search, multi_query_search_ids,
+    recommend, retrieval, search, search_ids, text_search,
 };
+pub use slo::{delete_latency_slo, get_latency_slo, set_latency_slo};
+pub use views::{create_view, delete_view, get_view, list_views};
 
 // Graph handlers (EPIC-016) - exported via lib.rs
 #[allow(unused_imports)]
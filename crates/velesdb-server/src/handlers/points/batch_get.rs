@@ -0,0 +1,97 @@
+//! Batch point retrieval by ID.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use std::sync::Arc;
+
+use crate::types::{ErrorResponse, GetPointsRequest, GetPointsResponse, RetrievedPoint};
+use crate::AppState;
+use velesdb_core::{PointProjection, RequestContext};
+
+use crate::handlers::helpers::{error_response, get_vector_collection_or_404};
+use crate::handlers::row_security::row_filter;
+
+/// Maximum number of IDs in a single batch get request.
+const MAX_GET_POINTS_SIZE: usize = 10_000;
+
+/// Fetch several points by ID in one call.
+///
+/// Unknown (or TTL-expired) IDs, and points the key's row-security policy
+/// hides, are omitted from the response; found points keep request order. Setting `with_vector: false` skips reading vectors
+/// from storage, which is the cheap path for UIs that only show payloads.
+#[utoipa::path(
+    post,
+    path = "/collections/{name}/points/get",
+    tag = "points",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    request_body = GetPointsRequest,
+    responses(
+        (status = 200, description = "Found points", body = GetPointsResponse),
+        (status = 400, description = "Batch too large", body = ErrorResponse),
+        (status = 403, description = "Row-security policy cannot be bound to the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_points(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+    Json(req): Json<GetPointsRequest>,
+) -> impl IntoResponse {
+    if req.ids.len() > MAX_GET_POINTS_SIZE {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Batch too large: {} IDs (max {MAX_GET_POINTS_SIZE})",
+                req.ids.len()
+            ),
+        );
+    }
+
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+
+    let row_filter = match row_filter(&state, &name, ctx.as_deref()) {
+        Ok(f) => f,
+        Err(resp) => return resp,
+    };
+
+    let projection = PointProjection::new(req.with_vector, req.with_payload);
+    // The policy is checked against payloads, so they are loaded whenever one
+    // applies and dropped afterwards if the caller did not ask for them.
+    let load = PointProjection::new(req.with_vector, req.with_payload || row_filter.is_some());
+    let ids = req.ids;
+    let result = tokio::task::spawn_blocking(move || collection.get_projected(&ids, load)).await;
+
+    match result {
+        Ok(found) => {
+            let points = found
+                .into_iter()
+                .flatten()
+                .filter(|p| {
+                    row_filter.as_ref().is_none_or(|f| {
+                        f.matches(p.payload.as_ref().unwrap_or(&serde_json::Value::Null))
+                    })
+                })
+                .map(|p| RetrievedPoint {
+                    id: p.id,
+                    vector: projection.with_vector.then_some(p.vector),
+                    payload: p.payload.filter(|_| projection.with_payload),
+                })
+                .collect();
+            Json(GetPointsResponse { points }).into_response()
+        }
+        Err(join_err) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("get_points task panicked: {join_err}"),
+        ),
+    }
+}
@@ -0,0 +1,68 @@
+//! Filtered point count.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use std::sync::Arc;
+
+use crate::types::{CountPointsRequest, CountPointsResponse, ErrorResponse};
+use crate::AppState;
+use velesdb_core::RequestContext;
+
+use super::parse_scroll_filter;
+use crate::handlers::helpers::{error_response, get_vector_collection_or_404};
+use crate::handlers::row_security::{and_row_filter, row_filter};
+
+/// Count the points matching an optional filter.
+///
+/// Answers from secondary indexes when the filter allows it and falls back
+/// to a parallel scan otherwise. TTL-expired points are not counted, nor are
+/// points the key's row-security policy hides.
+#[utoipa::path(
+    post,
+    path = "/collections/{name}/points/count",
+    tag = "points",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    request_body = CountPointsRequest,
+    responses(
+        (status = 200, description = "Matching point count", body = CountPointsResponse),
+        (status = 400, description = "Invalid filter", body = ErrorResponse),
+        (status = 403, description = "Row-security policy cannot be bound to the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse)
+    )
+)]
+pub async fn count_points(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+    Json(req): Json<CountPointsRequest>,
+) -> impl IntoResponse {
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+
+    let filter = match parse_scroll_filter(&req.filter) {
+        Ok(f) => f,
+        Err(resp) => return resp,
+    };
+    let filter = match row_filter(&state, &name, ctx.as_deref()) {
+        Ok(row) => and_row_filter(filter, row),
+        Err(resp) => return resp,
+    };
+
+    let result = tokio::task::spawn_blocking(move || collection.count(filter.as_ref())).await;
+
+    match result {
+        Ok(count) => Json(CountPointsResponse { count }).into_response(),
+        Err(join_err) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("count_points task panicked: {join_err}"),
+        ),
+    }
+}
@@ -1,9 +1,13 @@
 //! Point operations handlers.
 
+pub mod batch_get;
+pub mod count;
 pub mod raw;
 pub mod relations;
 pub mod streaming;
 
+pub use batch_get::get_points;
+pub use count::count_points;
 pub use raw::upsert_points_raw;
 pub use relations::{get_point_relations, relate_points, set_point_ttl, unrelate_points};
 pub use streaming::{
@@ -15,7 +19,7 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use std::sync::Arc;
 
@@ -25,11 +29,14 @@ use crate::types::{
 };
 use crate::AppState;
 use velesdb_core::api_types::serde_id;
-use velesdb_core::Point;
+use velesdb_core::{Point, RequestContext, UpsertOptions};
 
 use crate::handlers::helpers::{
-    auto_core_error_response, error_response, get_vector_collection_or_404,
+    auto_core_error_response, check_declared_embedding_model, error_response,
+    get_vector_collection_or_404, retry_transient,
 };
+use crate::handlers::request_limits::{check_payload_sizes, check_upsert_size};
+use crate::handlers::row_security::{and_row_filter, row_filter};
 
 use velesdb_core::index::sparse::SparseVector;
 
@@ -88,59 +95,61 @@ fn merge_named_sparse_vectors(
     Ok(())
 }
 
-/// Maximum number of points in a single JSON upsert request.
-///
-/// Consistent with `MAX_BULK_DELETE_SIZE` and `MAX_SCROLL_BATCH_SIZE`.
-/// The 100 MB body limit on the route already bounds bytes; this constant
-/// bounds the point *count* to prevent memory amplification from metadata-only
-/// or tiny-vector collections where 100 MB of JSON can represent millions of points.
-const MAX_UPSERT_BATCH_SIZE: usize = 100_000;
-
 /// Upsert points to a collection.
 #[utoipa::path(
     post,
     path = "/collections/{name}/points",
     tag = "points",
     params(
-        ("name" = String, Path, description = "Collection name")
+        ("name" = String, Path, description = "Collection name"),
+        ("x-embedding-model" = Option<String>, Header, description = "Embedding model that produced the vectors (`model` or `model@version`); rejected with VELES-017 when it differs from the collection's recorded model")
     ),
     request_body = UpsertPointsRequest,
     responses(
         (status = 200, description = "Points upserted", body = Object),
         (status = 404, description = "Collection not found", body = ErrorResponse),
-        (status = 400, description = "Invalid request or batch too large", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 413, description = "Too many points (`limits.max_points_per_upsert`) or a payload over `limits.max_payload_size` (VELES-027)", body = ErrorResponse)
     )
 )]
 pub async fn upsert_points(
     State(state): State<Arc<AppState>>,
+    headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<UpsertPointsRequest>,
 ) -> impl IntoResponse {
-    if req.points.len() > MAX_UPSERT_BATCH_SIZE {
-        return error_response(
-            StatusCode::BAD_REQUEST,
-            format!(
-                "Batch too large: {} points (max {MAX_UPSERT_BATCH_SIZE})",
-                req.points.len()
-            ),
-        );
+    if let Err(resp) = check_upsert_size(&state, req.point_count()) {
+        return resp;
     }
 
     let collection = match get_vector_collection_or_404(&state, &name) {
         Ok(c) => c,
         Err(resp) => return resp,
     };
+    if let Err(resp) = check_declared_embedding_model(&collection, &headers) {
+        return resp;
+    }
 
-    let points = match build_points_from_request(req) {
+    let options = UpsertOptions {
+        wait_for_index: req.wait_for_index,
+    };
+    let points = match build_points_from_request(req, collection.dimension()) {
         Ok(p) => p,
         Err(e) => {
             return error_response(StatusCode::BAD_REQUEST, e);
         }
     };
+    if let Err(resp) = check_payload_sizes(&state, &points) {
+        return resp;
+    }
 
     // CRITICAL: upsert_bulk is blocking (HNSW insertion + I/O).
     // Must use spawn_blocking to avoid blocking the async runtime.
-    let result = tokio::task::spawn_blocking(move || collection.upsert_bulk(&points)).await;
+    // Upserts are idempotent, so transient failures are retried in place.
+    let result = tokio::task::spawn_blocking(move || {
+        retry_transient(|| collection.upsert_bulk_with_options(&points, options))
+    })
+    .await;
 
     upsert_result_to_response(&state, &name, result)
 }
@@ -176,18 +185,62 @@ pub(super) fn upsert_result_to_response(
     }
 }
 
-/// Convert an `UpsertPointsRequest` into a `Vec<Point>`, merging sparse inputs.
-fn build_points_from_request(req: UpsertPointsRequest) -> Result<Vec<Point>, String> {
+/// Convert an `UpsertPointsRequest` into a `Vec<Point>`, decoding `$bytes`
+/// vectors (checked against `dimension`) and merging sparse inputs.
+fn build_points_from_request(
+    req: UpsertPointsRequest,
+    dimension: usize,
+) -> Result<Vec<Point>, String> {
+    if req.is_columnar() {
+        return build_points_from_columns(req, dimension);
+    }
     let mut points: Vec<Point> = Vec::with_capacity(req.points.len());
     for p in req.points {
+        let vector = p
+            .vector
+            .into_vector(dimension)
+            .map_err(|e| format!("Point {}: {e}", p.id))?;
         let sparse = convert_sparse_inputs(p.sparse_vector, p.sparse_vectors)?;
-        let mut point = Point::new(p.id, p.vector, p.payload);
+        let mut point = Point::new(p.id, vector, p.payload);
         point.sparse_vectors = sparse;
         points.push(point);
     }
     Ok(points)
 }
 
+/// Convert a columnar `UpsertPointsRequest` (`ids`/`vectors`/`payloads`)
+/// into a `Vec<Point>`, checking the columns line up.
+fn build_points_from_columns(
+    req: UpsertPointsRequest,
+    dimension: usize,
+) -> Result<Vec<Point>, String> {
+    if !req.points.is_empty() {
+        return Err("Use either `points` or `ids`/`vectors`/`payloads`, not both".to_string());
+    }
+    let count = req.ids.len();
+    let vectors = req
+        .vectors
+        .ok_or("Columnar upsert requires `vectors` next to `ids`")?
+        .into_rows(count, dimension)?;
+    let payloads = match req.payloads {
+        Some(payloads) if payloads.len() != count => {
+            return Err(format!(
+                "payloads has {} entries, expected one per id ({count})",
+                payloads.len()
+            ));
+        }
+        Some(payloads) => payloads,
+        None => vec![None; count],
+    };
+    Ok(req
+        .ids
+        .into_iter()
+        .zip(vectors)
+        .zip(payloads)
+        .map(|((id, vector), payload)| Point::new(id, vector, payload))
+        .collect())
+}
+
 /// Get a point by ID.
 #[utoipa::path(
     get,
@@ -199,21 +252,35 @@ fn build_points_from_request(req: UpsertPointsRequest) -> Result<Vec<Point>, Str
     ),
     responses(
         (status = 200, description = "Point found", body = Object),
-        (status = 404, description = "Point or collection not found", body = ErrorResponse)
+        (status = 403, description = "Row-security policy cannot be bound to the API key", body = ErrorResponse),
+        (status = 404, description = "Point or collection not found, or hidden by row security", body = ErrorResponse)
     )
 )]
 pub async fn get_point(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     Path((name, id)): Path<(String, u64)>,
 ) -> impl IntoResponse {
     let collection = match get_vector_collection_or_404(&state, &name) {
         Ok(c) => c,
         Err(resp) => return resp,
     };
+    let row_filter = match row_filter(&state, &name, ctx.as_deref()) {
+        Ok(f) => f,
+        Err(resp) => return resp,
+    };
 
     let points = collection.get(&[id]);
 
-    match points.into_iter().next().flatten() {
+    // A point the key's row policy hides is reported exactly like a missing
+    // one, so its existence does not leak.
+    let visible = points.into_iter().next().flatten().filter(|point| {
+        row_filter
+            .as_ref()
+            .is_none_or(|f| f.matches(point.payload.as_ref().unwrap_or(&serde_json::Value::Null)))
+    });
+
+    match visible {
         // ID as a string for JS precision-safety above 2^53-1, consistent with
         // every other read surface (search/scroll/relations, see `serde_id`).
         Some(point) => Json(serde_json::json!({
@@ -277,11 +344,13 @@ const MAX_SCROLL_BATCH_SIZE: u32 = 10_000;
     responses(
         (status = 200, description = "Scroll batch", body = ScrollResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Row-security policy cannot be bound to the API key", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse)
     )
 )]
 pub async fn scroll_points(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     Path(name): Path<String>,
     Json(req): Json<ScrollRequest>,
 ) -> impl IntoResponse {
@@ -301,6 +370,10 @@ pub async fn scroll_points(
         Ok(f) => f,
         Err(resp) => return resp,
     };
+    let filter = match row_filter(&state, &name, ctx.as_deref()) {
+        Ok(row) => and_row_filter(filter, row),
+        Err(resp) => return resp,
+    };
 
     let batch_size = req.batch_size as usize;
     let cursor = req.cursor;
@@ -452,11 +525,6 @@ pub async fn bulk_delete_points(
 mod tests {
     use super::*;
 
-    #[test]
-    fn upsert_batch_constant_matches_expected_value() {
-        assert_eq!(MAX_UPSERT_BATCH_SIZE, 100_000);
-    }
-
     #[test]
     fn scroll_batch_constant_matches_expected_value() {
         assert_eq!(MAX_SCROLL_BATCH_SIZE, 10_000);
@@ -470,6 +538,7 @@ mod tests {
     #[test]
     fn upsert_batch_limit_is_larger_than_delete_limit() {
         // Upsert is intentionally higher: ingestion workloads need larger batches.
-        assert!(MAX_UPSERT_BATCH_SIZE > MAX_BULK_DELETE_SIZE);
+        let max_upsert = velesdb_core::config::LimitsConfig::default().max_points_per_upsert;
+        assert!(max_upsert > MAX_BULK_DELETE_SIZE);
     }
 }
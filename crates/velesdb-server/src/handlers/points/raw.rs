@@ -13,8 +13,11 @@ use axum::{body::Bytes, extract::Path, extract::State, http::StatusCode, respons
 use std::sync::Arc;
 use velesdb_core::wire::vrb1;
 
-use super::{upsert_result_to_response, MAX_UPSERT_BATCH_SIZE};
-use crate::handlers::helpers::{error_response, get_vector_collection_or_404};
+use super::upsert_result_to_response;
+use crate::handlers::helpers::{
+    check_declared_embedding_model, error_response, get_vector_collection_or_404, retry_transient,
+};
+use crate::handlers::request_limits::check_upsert_size;
 use crate::types::ErrorResponse;
 use crate::AppState;
 
@@ -30,17 +33,20 @@ use crate::AppState;
     path = "/collections/{name}/points/raw",
     tag = "points",
     params(
-        ("name" = String, Path, description = "Collection name")
+        ("name" = String, Path, description = "Collection name"),
+        ("x-embedding-model" = Option<String>, Header, description = "Embedding model that produced the vectors (`model` or `model@version`)")
     ),
     request_body(content = String, content_type = "application/octet-stream", description = "VRB1 binary bulk format: header + packed u64 ids + packed f32 vectors"),
     responses(
         (status = 200, description = "Points upserted", body = Object),
         (status = 400, description = "Malformed body or dimension mismatch", body = ErrorResponse),
-        (status = 404, description = "Collection not found", body = ErrorResponse)
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 413, description = "Too many points (`limits.max_points_per_upsert`, VELES-027)", body = ErrorResponse)
     )
 )]
 pub async fn upsert_points_raw(
     State(state): State<Arc<AppState>>,
+    headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     body: Bytes,
 ) -> impl IntoResponse {
@@ -48,20 +54,17 @@ pub async fn upsert_points_raw(
         Ok(c) => c,
         Err(resp) => return resp,
     };
+    if let Err(resp) = check_declared_embedding_model(&collection, &headers) {
+        return resp;
+    }
 
     let batch = match vrb1::decode(&body) {
         Ok(b) => b,
         Err(err) => return error_response(StatusCode::BAD_REQUEST, err.to_string()),
     };
 
-    if batch.ids.len() > MAX_UPSERT_BATCH_SIZE {
-        return error_response(
-            StatusCode::BAD_REQUEST,
-            format!(
-                "Batch too large: {} points (max {MAX_UPSERT_BATCH_SIZE})",
-                batch.ids.len()
-            ),
-        );
+    if let Err(resp) = check_upsert_size(&state, batch.ids.len()) {
+        return resp;
     }
 
     let dimension = batch.dimension;
@@ -69,9 +72,9 @@ pub async fn upsert_points_raw(
     let vectors = batch.vectors;
 
     // upsert_bulk_from_raw is blocking (HNSW insertion + I/O) — spawn_blocking
-    // keeps the async runtime free.
+    // keeps the async runtime free. Transient failures are retried in place.
     let result = tokio::task::spawn_blocking(move || {
-        collection.upsert_bulk_from_raw(&vectors, &ids, dimension, None)
+        retry_transient(|| collection.upsert_bulk_from_raw(&vectors, &ids, dimension, None))
     })
     .await;
 
@@ -10,14 +10,16 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use velesdb_core::api_types::serde_id;
 use velesdb_core::collection::graph::GraphEdge;
 use velesdb_core::point::Point;
+use velesdb_core::RequestContext;
 
+use crate::handlers::row_security::refuse_unfiltered_read;
 use crate::types::ErrorResponse;
 use crate::AppState;
 
@@ -219,6 +221,7 @@ pub async fn unrelate_points(
             Json(ErrorResponse {
                 error: format!("{err} in collection '{name}'"),
                 code: Some(err.code().to_string()),
+                retryable: Some(err.is_retryable()),
             }),
         )
             .into_response()
@@ -235,6 +238,7 @@ pub async fn unrelate_points(
     ),
     responses(
         (status = 200, description = "Outgoing relations", body = RelationsResponse),
+        (status = 403, description = "Row-security policy narrows the API key", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
@@ -243,11 +247,15 @@ pub async fn unrelate_points(
 pub async fn get_point_relations(
     Path((name, id)): Path<(String, u64)>,
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
 ) -> axum::response::Response {
     let coll = match get_collection_or_404(&state, &name) {
         Ok(c) => c,
         Err(r) => return r,
     };
+    if let Err(resp) = refuse_unfiltered_read(&state, &name, ctx.as_deref()) {
+        return resp.into_response();
+    }
 
     let raw_edges = coll.get_outgoing_edges(id);
     let edges: Vec<RelationEdge> = raw_edges
@@ -306,6 +314,7 @@ pub async fn set_point_ttl(
                 Json(ErrorResponse {
                     error: format!("{err} in collection '{name}'"),
                     code: Some(err.code().to_string()),
+                    retryable: Some(err.is_retryable()),
                 }),
             )
                 .into_response();
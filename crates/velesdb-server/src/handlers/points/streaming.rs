@@ -307,6 +307,7 @@ fn stream_insert_result_to_response(
                 Json(ErrorResponse {
                     error: "Stream buffer full, retry after 1s".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response()
@@ -0,0 +1,123 @@
+//! 2-D projection export for visualization frontends.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use velesdb_core::collection::projection::{
+    Projection2d, ProjectionMethod, DEFAULT_PROJECTION_CLUSTERS,
+};
+use velesdb_core::RequestContext;
+
+use crate::types::{ErrorResponse, ProjectedPointResponse, Projection2dResponse};
+use crate::AppState;
+
+use super::helpers::{error_response, get_vector_collection_or_404};
+use super::row_security::row_filter;
+
+/// Default sample size for `GET /collections/{name}/projection`.
+const DEFAULT_PROJECTION_SAMPLE: usize = 1_000;
+
+/// Query parameters for the 2-D projection.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ProjectionParams {
+    /// Layout algorithm: `pca` (default) or `umap_approx` (requires the
+    /// server's `umap-approx` feature).
+    #[param(example = "pca")]
+    pub method: Option<String>,
+    /// Number of vectors to sample (default 1000, capped at 2000).
+    #[param(example = 1000)]
+    pub sample_size: Option<usize>,
+    /// Number of k-means clusters to label points with (default 8).
+    #[param(example = 8)]
+    pub clusters: Option<usize>,
+}
+
+/// Export a 2-D layout of a random sample of the collection, with k-means
+/// cluster labels, for scatter-plot visualization. Only points the key's
+/// row-security policy lets it read are sampled.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/projection",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name"),
+        ProjectionParams
+    ),
+    responses(
+        (status = 200, description = "2-D layout", body = Projection2dResponse),
+        (status = 400, description = "Unknown or unavailable projection method", body = ErrorResponse),
+        (status = 403, description = "Row-security policy cannot be bound to the API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 500, description = "Projection failed", body = ErrorResponse)
+    )
+)]
+pub async fn project_collection(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+    Query(params): Query<ProjectionParams>,
+) -> impl IntoResponse {
+    let method_name = params.method.as_deref().unwrap_or("pca");
+    let Some(method) = ProjectionMethod::parse(method_name) else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Unknown projection method '{method_name}': expected 'pca' or 'umap_approx' \
+                 (the latter requires the umap-approx feature)"
+            ),
+        );
+    };
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    let filter = match row_filter(&state, &name, ctx.as_deref()) {
+        Ok(f) => f,
+        Err(resp) => return resp,
+    };
+    let sample_size = params.sample_size.unwrap_or(DEFAULT_PROJECTION_SAMPLE);
+    let clusters = params.clusters.unwrap_or(DEFAULT_PROJECTION_CLUSTERS);
+
+    match tokio::task::spawn_blocking(move || {
+        collection.project_2d(method, sample_size, clusters, filter.as_ref())
+    })
+    .await
+    {
+        Ok(projection) => {
+            (StatusCode::OK, Json(projection_to_response(projection))).into_response()
+        }
+        Err(join_err) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("projection task panicked: {join_err}"),
+        ),
+    }
+}
+
+/// Maps the core layout to the REST response DTO.
+fn projection_to_response(projection: Projection2d) -> Projection2dResponse {
+    let method = serde_json::to_value(projection.method)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_owned))
+        .unwrap_or_default();
+    Projection2dResponse {
+        method,
+        points: projection
+            .points
+            .into_iter()
+            .map(|p| ProjectedPointResponse {
+                id: p.id,
+                x: p.x,
+                y: p.y,
+                cluster: p.cluster,
+            })
+            .collect(),
+        cluster_count: projection.cluster_count,
+        explained_variance_ratio: projection.explained_variance_ratio,
+    }
+}
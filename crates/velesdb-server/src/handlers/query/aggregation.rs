@@ -3,17 +3,22 @@
 //! Handles detection and execution of GROUP BY / aggregate function queries,
 //! routing them to `execute_aggregate` on the appropriate collection.
 
-use axum::{http::StatusCode, response::IntoResponse, Json};
+use axum::{http::StatusCode, response::IntoResponse, Extension, Json};
 use std::sync::Arc;
 use velesdb_core::velesql::Query;
+use velesdb_core::RequestContext;
 
 use crate::handlers::helpers::notify_query_timing;
 use crate::types::{
     AggregationResponse, QueryRequest, QueryResponseMeta, VELESQL_CONTRACT_VERSION,
 };
+use crate::wire::WireFormat;
 use crate::AppState;
 
-use super::velesql_helpers::{parse_and_validate, velesql_collection_not_found, velesql_error};
+use super::velesql_helpers::{
+    apply_row_security, decode_params, parse_and_validate, velesql_collection_not_found,
+    velesql_error,
+};
 
 fn aggregation_result_count(result: &serde_json::Value) -> usize {
     match result {
@@ -29,15 +34,25 @@ pub(crate) fn execute_aggregation_query(
     parsed: &Query,
     params: &std::collections::HashMap<String, serde_json::Value>,
     start: std::time::Instant,
+    format: WireFormat,
 ) -> axum::response::Response {
-    // Prefer typed vector collection for aggregation.
-    let result = if let Some(vc) = state.db.get_vector_collection(collection_name) {
-        vc.execute_aggregate(parsed, params)
-    } else if let Some(any) = state.db.get_any_collection(collection_name) {
-        any.execute_aggregate(parsed, params)
-    } else {
+    if state.db.get_view(collection_name).is_none()
+        && state.db.get_any_collection(collection_name).is_none()
+    {
         state.operational_metrics.inc_errors();
         return velesql_collection_not_found(collection_name);
+    }
+    // Run through the database so views and query rewrite rules apply. A
+    // query without FROM names its collection in the request body.
+    let result = if parsed.select.from.is_empty() {
+        let mut params = params.clone();
+        params.insert(
+            "_collection".to_string(),
+            serde_json::Value::String(collection_name.to_string()),
+        );
+        state.db.execute_aggregate(parsed, &params)
+    } else {
+        state.db.execute_aggregate(parsed, params)
     };
 
     let result = match result {
@@ -62,7 +77,7 @@ pub(crate) fn execute_aggregation_query(
         .observe(elapsed.as_secs_f64());
     let count = aggregation_result_count(&result);
 
-    Json(AggregationResponse {
+    format.respond(&AggregationResponse {
         result,
         timing_ms,
         meta: QueryResponseMeta {
@@ -70,7 +85,6 @@ pub(crate) fn execute_aggregation_query(
             count,
         },
     })
-    .into_response()
 }
 
 /// Resolve the collection name for an aggregation query.
@@ -118,11 +132,18 @@ pub(crate) fn resolve_aggregate_collection(
 #[allow(clippy::unused_async)]
 pub async fn aggregate(
     axum::extract::State(state): axum::extract::State<Arc<AppState>>,
-    Json(req): Json<QueryRequest>,
+    ctx: Option<Extension<RequestContext>>,
+    headers: axum::http::HeaderMap,
+    Json(mut req): Json<QueryRequest>,
 ) -> impl IntoResponse {
     let start = std::time::Instant::now();
     state.operational_metrics.inc_queries();
 
+    if let Err(resp) = decode_params(&mut req.params) {
+        state.operational_metrics.inc_errors();
+        return resp;
+    }
+
     let parsed = match parse_and_validate(&req.query) {
         Ok(q) => q,
         Err(resp) => {
@@ -150,6 +171,21 @@ pub async fn aggregate(
             return resp;
         }
     };
+    let parsed = match apply_row_security(&state.db, parsed, &collection_name, ctx.as_deref()) {
+        Ok(scoped) => scoped,
+        Err(resp) => {
+            state.operational_metrics.inc_errors();
+            return resp;
+        }
+    };
 
-    execute_aggregation_query(&state, &collection_name, &parsed, &req.params, start)
+    let format = WireFormat::from_headers(&headers);
+    execute_aggregation_query(
+        &state,
+        &collection_name,
+        &parsed,
+        &req.params,
+        start,
+        format,
+    )
 }
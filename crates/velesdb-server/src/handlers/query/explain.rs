@@ -1,8 +1,9 @@
 //! EXPLAIN query handler and plan building logic.
 
-use axum::{extract::State, response::IntoResponse, Json};
+use axum::{extract::State, response::IntoResponse, Extension, Json};
 use std::sync::Arc;
 use velesdb_core::velesql::{Condition, QueryPlan, SelectColumns};
+use velesdb_core::RequestContext;
 
 use crate::types::{
     ActualStatsResponse, ExplainCost, ExplainFeatures, ExplainRequest, ExplainResponse,
@@ -10,7 +11,10 @@ use crate::types::{
 };
 use crate::AppState;
 
-use super::velesql_helpers::{parse_and_validate, velesql_collection_not_found, velesql_error};
+use super::velesql_helpers::{
+    apply_row_security, decode_params, parse_and_validate, velesql_collection_not_found,
+    velesql_error,
+};
 use axum::http::StatusCode;
 use velesdb_core::Error as CoreError;
 
@@ -33,8 +37,12 @@ use velesdb_core::Error as CoreError;
 #[allow(clippy::unused_async)]
 pub async fn explain(
     State(state): State<Arc<AppState>>,
-    Json(req): Json<ExplainRequest>,
+    ctx: Option<Extension<RequestContext>>,
+    Json(mut req): Json<ExplainRequest>,
 ) -> impl IntoResponse {
+    if let Err(resp) = decode_params(&mut req.params) {
+        return resp;
+    }
     let parsed = match parse_and_validate(&req.query) {
         Ok(q) => q,
         Err(resp) => return resp,
@@ -42,13 +50,19 @@ pub async fn explain(
 
     let select = &parsed.select;
 
-    let collection_exists = state.db.get_any_collection(&select.from).is_some();
+    let collection_exists = state.db.get_any_collection(&select.from).is_some()
+        || state.db.get_view(&select.from).is_some();
     if !collection_exists && !select.from.is_empty() {
         return velesql_collection_not_found(&select.from);
     }
 
     if req.analyze {
-        return explain_with_analyze(&state, &req, &parsed);
+        // ANALYZE executes the query, so it reads only what the key may see.
+        let from = select.from.clone();
+        return match apply_row_security(&state.db, parsed, &from, ctx.as_deref()) {
+            Ok(scoped) => explain_with_analyze(&state, &req, &scoped),
+            Err(resp) => resp,
+        };
     }
 
     explain_plan_only(&state, &req, &parsed)
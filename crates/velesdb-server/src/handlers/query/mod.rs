@@ -8,21 +8,27 @@ pub use aggregation::__path_aggregate;
 pub use aggregation::aggregate;
 pub use explain::{__path_explain, explain};
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Extension, Json};
 use std::sync::Arc;
 use velesdb_core::collection::search::query::projection;
 #[cfg(test)]
 use velesdb_core::velesql;
 use velesdb_core::velesql::{DmlStatement, Query, SelectColumns};
+use velesdb_core::RequestContext;
 
 use crate::types::{
-    QueryRequest, QueryResponse, QueryResponseMeta, QueryType, VELESQL_CONTRACT_VERSION,
+    QueryDryRunResponse, QueryRequest, QueryResponse, QueryResponseMeta, QueryType,
+    VELESQL_CONTRACT_VERSION,
 };
+use crate::wire::WireFormat;
 use crate::AppState;
 
 use aggregation::execute_aggregation_query;
 use explain::condition_has_vector_search;
-use velesql_helpers::{parse_and_validate, velesql_collection_not_found, velesql_error};
+use velesql_helpers::{
+    apply_row_security, decode_params, parse_and_validate, velesql_collection_not_found,
+    velesql_error, velesql_execution_error,
+};
 
 /// Returns `true` when the query should bypass collection resolution and go
 /// directly through `Database::execute_query` — DDL, introspection, admin,
@@ -37,7 +43,8 @@ fn requires_mutation_dispatch(parsed: &Query) -> bool {
 
 /// Returns `true` for DML statements that resolve their collection name from
 /// the AST rather than from the request body's `FROM` clause:
-/// `INSERT EDGE`, `DELETE`, `DELETE EDGE`, `SELECT EDGES`, `INSERT NODE`.
+/// `INSERT EDGE`, `DELETE`, `DELETE EDGE`, `SELECT EDGES`, `INSERT NODE`,
+/// and graph mutations (`CREATE (a)-[:T]->(b)`, `[DETACH] DELETE`).
 ///
 /// `INSERT INTO`, `UPSERT`, and `UPDATE` return result rows and must flow
 /// through the standard query path (they use `stmt.table` which maps to
@@ -51,6 +58,7 @@ fn is_ast_routed_dml(parsed: &Query) -> bool {
                 | DmlStatement::DeleteEdge(_)
                 | DmlStatement::SelectEdges(_)
                 | DmlStatement::InsertNode(_)
+                | DmlStatement::GraphMutation(_)
         )
     )
 }
@@ -62,14 +70,19 @@ fn is_ast_routed_dml(parsed: &Query) -> bool {
 ///
 /// DDL statements (CREATE/DROP COLLECTION) are intercepted before collection
 /// resolution and dispatched directly through `Database::execute_query`.
+///
+/// With `dry_run: true`, an INSERT, UPSERT, UPDATE or DELETE is validated
+/// and resolved but not executed; the response is a `QueryDryRunResponse`
+/// listing the points it would change.
 #[utoipa::path(
     post,
     path = "/query",
     tag = "query",
     request_body = QueryRequest,
     responses(
-        (status = 200, description = "Query results", body = QueryResponse),
+        (status = 200, description = "Query rows, aggregation groups, or a dry-run preview when `dry_run` is set", body = crate::types::QueryResult),
         (status = 400, description = "Query syntax error", body = crate::types::QueryErrorResponse),
+        (status = 403, description = "Row security cannot scope the query for this API key", body = crate::types::VelesqlErrorResponse),
         (status = 422, description = "Query validation/execution error", body = crate::types::VelesqlErrorResponse),
         (status = 404, description = "Collection not found", body = crate::types::VelesqlErrorResponse)
     )
@@ -77,11 +90,19 @@ fn is_ast_routed_dml(parsed: &Query) -> bool {
 #[allow(clippy::unused_async)]
 pub async fn query(
     State(state): State<Arc<AppState>>,
-    Json(req): Json<QueryRequest>,
+    ctx: Option<Extension<RequestContext>>,
+    headers: axum::http::HeaderMap,
+    Json(mut req): Json<QueryRequest>,
 ) -> impl IntoResponse {
     let start = std::time::Instant::now();
+    let format = WireFormat::from_headers(&headers);
     state.operational_metrics.inc_queries();
 
+    if let Err(resp) = decode_params(&mut req.params) {
+        state.operational_metrics.inc_errors();
+        return resp;
+    }
+
     let parsed = match parse_and_validate(&req.query) {
         Ok(q) => q,
         Err(resp) => {
@@ -90,11 +111,27 @@ pub async fn query(
         }
     };
 
-    // DDL/Introspection/Admin/graph-mutation bypass: these extract collection from
-    // the SQL AST, not from the request body.  INSERT INTO, UPSERT, and UPDATE flow
-    // through the standard path because they return meaningful result rows.
-    if requires_mutation_dispatch(&parsed) {
-        return execute_mutation_query(&state, &parsed, &req.params, start);
+    // Dry runs and AST-routed statements name their collection in the AST;
+    // narrow them before they run so a DELETE, UPDATE or its dry run only
+    // sees the caller's rows.
+    if req.dry_run || requires_mutation_dispatch(&parsed) {
+        let target = parsed
+            .dml_collection_name()
+            .map_or_else(|| parsed.select.from.clone(), str::to_string);
+        let parsed = match apply_row_security(&state.db, parsed, &target, ctx.as_deref()) {
+            Ok(scoped) => scoped,
+            Err(resp) => {
+                state.operational_metrics.inc_errors();
+                return resp;
+            }
+        };
+        if req.dry_run {
+            return execute_dry_run(&state, &parsed, &req.params, start);
+        }
+        // DDL/Introspection/Admin/graph-mutation bypass: these extract collection from
+        // the SQL AST, not from the request body.  INSERT INTO, UPSERT, and UPDATE flow
+        // through the standard path because they return meaningful result rows.
+        return execute_mutation_query(&state, &parsed, &req.params, start, format);
     }
 
     let collection_name = match resolve_collection_name(&parsed, &req) {
@@ -104,10 +141,24 @@ pub async fn query(
             return resp;
         }
     };
+    let parsed = match apply_row_security(&state.db, parsed, &collection_name, ctx.as_deref()) {
+        Ok(scoped) => scoped,
+        Err(resp) => {
+            state.operational_metrics.inc_errors();
+            return resp;
+        }
+    };
 
     // BUG-1 FIX: Detect aggregation queries and route to execute_aggregate
     if parsed.select.is_aggregation_query() {
-        return execute_aggregation_query(&state, &collection_name, &parsed, &req.params, start);
+        return execute_aggregation_query(
+            &state,
+            &collection_name,
+            &parsed,
+            &req.params,
+            start,
+            format,
+        );
     }
 
     let results = match execute_standard_query(&state, &parsed, &collection_name, &req) {
@@ -118,7 +169,7 @@ pub async fn query(
         }
     };
 
-    build_query_response(&state, start, results, &parsed.select.columns)
+    build_query_response(&state, start, format, results, &parsed.select.columns)
 }
 
 /// Execute a DDL, graph/delete DML, introspection, admin, or TRAIN query.
@@ -137,9 +188,10 @@ fn execute_mutation_query(
     parsed: &Query,
     params: &std::collections::HashMap<String, serde_json::Value>,
     start: std::time::Instant,
+    format: WireFormat,
 ) -> axum::response::Response {
     match state.db.execute_query(parsed, params) {
-        Ok(results) => build_query_response(state, start, results, &parsed.select.columns),
+        Ok(results) => build_query_response(state, start, format, results, &parsed.select.columns),
         Err(e) => {
             state.operational_metrics.inc_errors();
             velesql_error(
@@ -153,6 +205,34 @@ fn execute_mutation_query(
     }
 }
 
+/// Report what a DML statement would change without executing it.
+fn execute_dry_run(
+    state: &Arc<AppState>,
+    parsed: &Query,
+    params: &std::collections::HashMap<String, serde_json::Value>,
+    start: std::time::Instant,
+) -> axum::response::Response {
+    match state.db.execute_query_dry_run(parsed, params) {
+        Ok(report) => {
+            let timing_ms = start.elapsed().as_secs_f64() * 1000.0;
+            Json(QueryDryRunResponse::new(report, timing_ms)).into_response()
+        }
+        Err(e) => {
+            state.operational_metrics.inc_errors();
+            match e {
+                velesdb_core::Error::CollectionNotFound(name) => velesql_collection_not_found(&name),
+                other => velesql_error(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "VELESQL_DRY_RUN_ERROR",
+                    &other.to_string(),
+                    "Dry runs accept INSERT, UPSERT, UPDATE and DELETE; check the statement and its parameters",
+                    None,
+                ),
+            }
+        }
+    }
+}
+
 /// Determine the target collection from the parsed query and request body.
 #[allow(clippy::result_large_err)]
 fn resolve_collection_name(
@@ -202,13 +282,14 @@ fn execute_standard_query(
 
     execute_result.map_err(|e| match e {
         velesdb_core::Error::CollectionNotFound(name) => velesql_collection_not_found(&name),
-        other => velesql_error(
-            StatusCode::UNPROCESSABLE_ENTITY,
-            "VELESQL_EXECUTION_ERROR",
-            &other.to_string(),
-            "Validate query semantics and parameter types against the target collection",
-            None,
+        velesdb_core::Error::QueryLimitExceeded { requested, max } => velesql_error(
+            StatusCode::BAD_REQUEST,
+            "VELESQL_LIMIT_EXCEEDED",
+            &e.to_string(),
+            "Lower LIMIT, or raise limits.max_query_limit (or the collection override)",
+            Some(serde_json::json!({ "requested": requested, "max": max })),
         ),
+        other => velesql_execution_error(&other),
     })
 }
 
@@ -225,6 +306,7 @@ fn execute_standard_query(
 fn build_query_response(
     state: &Arc<AppState>,
     start: std::time::Instant,
+    format: WireFormat,
     results: Vec<velesdb_core::SearchResult>,
     select_columns: &SelectColumns,
 ) -> axum::response::Response {
@@ -239,7 +321,7 @@ fn build_query_response(
     let projected = projection::project_results(&results, select_columns);
     let rows_returned = projected.len();
 
-    Json(QueryResponse {
+    format.respond(&QueryResponse {
         results: projected,
         timing_ms,
         took_ms,
@@ -249,7 +331,6 @@ fn build_query_response(
             count: rows_returned,
         },
     })
-    .into_response()
 }
 
 /// Detect query type from parsed AST (EPIC-052 US-006).
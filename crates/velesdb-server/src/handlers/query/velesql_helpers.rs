@@ -1,7 +1,9 @@
 //! Shared VelesQL helpers for parse, validate, and error formatting.
 
 use axum::{http::StatusCode, response::IntoResponse, Json};
-use velesdb_core::velesql;
+use std::borrow::Cow;
+use velesdb_core::velesql::{self, Query};
+use velesdb_core::RequestContext;
 
 use crate::types::{
     QueryErrorDetail, QueryErrorResponse, VelesqlErrorDetail, VelesqlErrorResponse,
@@ -27,6 +29,93 @@ pub(crate) fn parse_and_validate(
     Ok(parsed)
 }
 
+/// Narrow a query by the row-security policies bound to the API key's
+/// request context, returning a 403 `VELESQL_ROW_SECURITY` response when the
+/// query cannot be scoped.
+///
+/// `collection` is the resolved target: a query without FROM reads it, and
+/// a MATCH query over a protected collection is refused. UPDATE and DELETE
+/// are narrowed too; edge and node statements on a protected collection are
+/// refused.
+#[allow(clippy::result_large_err)]
+pub(crate) fn apply_row_security(
+    db: &velesdb_core::Database,
+    mut parsed: Query,
+    collection: &str,
+    ctx: Option<&RequestContext>,
+) -> Result<Query, axum::response::Response> {
+    let Some(ctx) = ctx else {
+        return Ok(parsed);
+    };
+    let denied = |e: &velesdb_core::Error| {
+        velesql_error(
+            StatusCode::FORBIDDEN,
+            "VELESQL_ROW_SECURITY",
+            &e.to_string(),
+            "Use a protected collection without JOINs, subqueries, MATCH or edge and node statements, with an API key whose context sets every attribute its policy references",
+            Some(serde_json::json!({ "collection": collection })),
+        )
+    };
+    if parsed.is_match_query() {
+        return match db.authorize_read_with_context(
+            collection,
+            velesdb_core::observer::QueryOperationKind::GraphTraversal,
+            Some(ctx),
+        ) {
+            Ok(None) => Ok(parsed),
+            Ok(Some(_)) => Err(denied(&velesdb_core::Error::Query(format!(
+                "collection '{collection}' has row security and cannot be the source of a MATCH query"
+            )))),
+            Err(e) => Err(denied(&e)),
+        };
+    }
+    if parsed.select.from.is_empty() {
+        parsed.select.from = collection.to_string();
+    }
+    let scoped = match db.apply_row_security(&parsed, ctx) {
+        Ok(Cow::Owned(scoped)) => Some(scoped),
+        Ok(Cow::Borrowed(_)) => None,
+        Err(e) => return Err(denied(&e)),
+    };
+    Ok(scoped.unwrap_or(parsed))
+}
+
+/// Decode `{"$bytes": "<base64>"}` params into float arrays in place,
+/// returning a 400 `VELESQL_INVALID_PARAM` response on bad base64.
+#[allow(clippy::result_large_err)]
+pub(crate) fn decode_params(
+    params: &mut std::collections::HashMap<String, serde_json::Value>,
+) -> Result<(), axum::response::Response> {
+    velesdb_core::api_types::decode_bytes_params(params).map_err(|message| {
+        velesql_error(
+            StatusCode::BAD_REQUEST,
+            "VELESQL_INVALID_PARAM",
+            &message,
+            "Encode $bytes vectors as standard base64 of little-endian f32 values",
+            None,
+        )
+    })
+}
+
+/// Build the 422 `VELESQL_EXECUTION_ERROR` response for an engine error,
+/// flagging whether the query can be retried.
+pub(crate) fn velesql_execution_error(error: &velesdb_core::Error) -> axum::response::Response {
+    (
+        StatusCode::UNPROCESSABLE_ENTITY,
+        Json(VelesqlErrorResponse {
+            error: VelesqlErrorDetail {
+                code: "VELESQL_EXECUTION_ERROR".to_string(),
+                message: error.to_string(),
+                hint: "Validate query semantics and parameter types against the target collection"
+                    .to_string(),
+                details: None,
+                retryable: Some(error.is_retryable()),
+            },
+        }),
+    )
+        .into_response()
+}
+
 /// Build a VelesQL parse error response from a `ParseError`.
 pub(crate) fn velesql_parse_error(e: &velesql::ParseError) -> axum::response::Response {
     (
@@ -59,6 +148,7 @@ pub(crate) fn velesql_error(
                 message: message.to_string(),
                 hint: hint.to_string(),
                 details,
+                retryable: None,
             },
         }),
     )
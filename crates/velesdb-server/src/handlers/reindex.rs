@@ -0,0 +1,167 @@
+//! Online HNSW reindex: start a background job and stream its progress.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    Json,
+};
+use futures::StreamExt;
+use std::convert::Infallible;
+use std::sync::Arc;
+use velesdb_core::collection::auto_reindex::AutoReindexManager;
+use velesdb_core::{HnswParams, NeighborSelection};
+
+use crate::types::{ErrorResponse, ReindexAcceptedResponse, ReindexRequest, ReindexStatusEvent};
+use crate::AppState;
+
+use super::helpers::{auto_core_error_response, error_response, get_vector_collection_or_404};
+
+/// Rebuild a collection's HNSW graph in the background.
+///
+/// The new graph is built beside the live one, which keeps serving reads
+/// and writes. Both are benchmarked on a sample of the collection; the new
+/// graph is swapped in and its parameters persisted only if its p99 latency
+/// and recall stay within the regression tolerances, otherwise it is
+/// discarded. Unset HNSW parameters default to the values recommended for
+/// the collection's current size.
+///
+/// Follow the job with `GET /collections/{name}/reindex/status`.
+#[utoipa::path(
+    post,
+    path = "/collections/{name}/reindex",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    request_body(content = Option<ReindexRequest>, description = "Optional HNSW parameters and tolerances"),
+    responses(
+        (status = 202, description = "Reindex started", body = ReindexAcceptedResponse),
+        (status = 400, description = "Invalid parameters", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "A reindex is already running", body = ErrorResponse)
+    )
+)]
+pub async fn start_reindex(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    body: Option<Json<ReindexRequest>>,
+) -> impl IntoResponse {
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    let req = body.map(|Json(req)| req).unwrap_or_default();
+
+    let neighbor_selection = match req.hnsw_neighbor_selection.as_deref() {
+        Some(raw) => match raw.parse::<NeighborSelection>() {
+            Ok(selection) => selection,
+            Err(e) => return error_response(StatusCode::BAD_REQUEST, e),
+        },
+        None => {
+            collection
+                .config()
+                .hnsw_params
+                .unwrap_or_default()
+                .neighbor_selection
+        }
+    };
+    let base = HnswParams::for_dataset_size(collection.dimension(), collection.len());
+    let params = HnswParams {
+        max_connections: req.hnsw_m.unwrap_or(base.max_connections),
+        ef_construction: req.hnsw_ef_construction.unwrap_or(base.ef_construction),
+        alpha: req.hnsw_alpha.unwrap_or(base.alpha),
+        neighbor_selection,
+        ..base
+    };
+    if let Err(e) = params.validate() {
+        return auto_core_error_response(&e);
+    }
+
+    let mut config = collection.config().auto_reindex_config.unwrap_or_default();
+    if let Some(pct) = req.max_latency_regression_percent {
+        config.max_latency_regression_percent = pct;
+    }
+    if let Some(pct) = req.max_recall_regression_percent {
+        config.max_recall_regression_percent = pct;
+    }
+
+    let Some(job) = state.reindex_jobs.start(&name) else {
+        return error_response(
+            StatusCode::CONFLICT,
+            format!("a reindex of '{name}' is already running"),
+        );
+    };
+    let manager = AutoReindexManager::new(config);
+    let sink = Arc::clone(&job);
+    manager.on_event(move |event| sink.push_core(&event));
+
+    let task_job = Arc::clone(&job);
+    let task = tokio::task::spawn_blocking(move || collection.reindex(params, &manager));
+    tokio::spawn(async move {
+        // `Completed` / `RolledBack` come from the manager; only errors
+        // raised before the manager started need reporting here.
+        let error = match task.await {
+            Ok(Ok(_)) => return,
+            Ok(Err(e)) => e.to_string(),
+            Err(join_err) => format!("reindex task panicked: {join_err}"),
+        };
+        task_job.push(ReindexStatusEvent::Failed { error });
+    });
+
+    (
+        StatusCode::ACCEPTED,
+        Json(ReindexAcceptedResponse {
+            status_url: format!("/collections/{name}/reindex/status"),
+            collection: name,
+            hnsw_m: params.max_connections,
+            hnsw_ef_construction: params.ef_construction,
+        }),
+    )
+        .into_response()
+}
+
+/// Stream the progress of a collection's current or last reindex via SSE.
+///
+/// Past events are replayed first, so a client connecting late still sees
+/// the whole run. Each SSE record is named after its `event` field
+/// (`started`, `progress`, `validating`, `completed`, `rolled_back`,
+/// `failed`); the stream closes after `completed`, `rolled_back` or
+/// `failed`.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/reindex/status",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    responses(
+        (status = 200, description = "SSE stream of reindex events", body = ReindexStatusEvent),
+        (status = 404, description = "No reindex has been started for this collection", body = ErrorResponse)
+    )
+)]
+pub async fn reindex_status(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    let Some(job) = state.reindex_jobs.get(&name) else {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            format!("no reindex has been started for '{name}'"),
+        );
+    };
+    let events = job.follow().map(|event| {
+        Ok::<_, Infallible>(
+            Event::default()
+                .event(event.name())
+                .json_data(&event)
+                .unwrap_or_else(|_| Event::default().event("error")),
+        )
+    });
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
@@ -0,0 +1,106 @@
+//! Per-request size guardrails from the engine `[limits]` section.
+//!
+//! Points per upsert, payload bytes per point, search `top_k` and queries per
+//! batch or multi-query search are checked before any work starts, so a
+//! runaway client request is refused up front instead of tying up the engine.
+//! Oversized requests get `413 Payload Too Large`, an oversized `top_k` gets
+//! `422 Unprocessable Entity`. Both carry a `VELES-027` body naming the value,
+//! the cap and the `limits.<field>` to raise.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::types::ErrorResponse;
+use crate::AppState;
+
+/// Builds the non-retryable guard-rail error returned for an oversized request.
+fn limit_exceeded(status: StatusCode, message: String) -> Response {
+    (
+        status,
+        Json(ErrorResponse {
+            error: message,
+            code: Some("VELES-027".to_string()),
+            retryable: Some(false),
+        }),
+    )
+        .into_response()
+}
+
+/// Rejects an upsert of more than `limits.max_points_per_upsert` points.
+#[allow(clippy::result_large_err)]
+pub(crate) fn check_upsert_size(state: &AppState, points: usize) -> Result<(), Response> {
+    let cap = state.db.config().limits.max_points_per_upsert;
+    if points > cap {
+        return Err(limit_exceeded(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Batch too large: {points} points exceeds the cap of {cap}; \
+                 split the batch or raise `limits.max_points_per_upsert`"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects points whose payload serializes to more than
+/// `limits.max_payload_size` bytes.
+#[allow(clippy::result_large_err)]
+pub(crate) fn check_payload_sizes(
+    state: &AppState,
+    points: &[velesdb_core::Point],
+) -> Result<(), Response> {
+    let cap = state.db.config().limits.max_payload_size;
+    let oversized = points.iter().find(|point| {
+        point
+            .payload
+            .as_ref()
+            .is_some_and(|payload| velesdb_core::collection::payload_exceeds(payload, cap))
+    });
+    match oversized {
+        Some(point) => Err(limit_exceeded(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Point {} payload exceeds the cap of {cap} bytes; \
+                 shrink the payload or raise `limits.max_payload_size`",
+                point.id
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Rejects a search asking for more than `limits.max_top_k` results.
+#[allow(clippy::result_large_err)]
+pub(crate) fn check_top_k(state: &AppState, top_k: usize) -> Result<(), Response> {
+    let cap = state.db.config().limits.max_top_k;
+    if top_k > cap {
+        return Err(limit_exceeded(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!(
+                "top_k {top_k} exceeds the cap of {cap}; \
+                 page with a smaller top_k or raise `limits.max_top_k`"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects a batch or multi-query search of more than
+/// `limits.max_batch_queries` queries.
+#[allow(clippy::result_large_err)]
+pub(crate) fn check_batch_queries(state: &AppState, queries: usize) -> Result<(), Response> {
+    let cap = state.db.config().limits.max_batch_queries;
+    if queries > cap {
+        return Err(limit_exceeded(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Batch too large: {queries} queries exceeds the cap of {cap}; \
+                 split the batch or raise `limits.max_batch_queries`"
+            ),
+        ));
+    }
+    Ok(())
+}
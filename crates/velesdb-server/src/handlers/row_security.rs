@@ -0,0 +1,198 @@
+//! Row-security policy handlers.
+//!
+//! A policy narrows every read made with an authenticated API key (searches,
+//! queries, point reads, counts and change subscriptions) to the rows
+//! matching its predicate; reads with no filter channel (graph endpoints,
+//! relations, access statistics) are refused instead. Keys scoped by a
+//! request context are the ones being restricted, so they cannot read or
+//! change policies.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use std::sync::Arc;
+use velesdb_core::{Condition, Filter, RequestContext, RowSecurityPolicy};
+
+use crate::types::{ErrorResponse, RowSecurityResponse, SetRowSecurityRequest};
+use crate::AppState;
+
+use super::helpers::{auto_core_error_response, error_response};
+
+/// Get a collection's row-security policy.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/row-security",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    responses(
+        (status = 200, description = "Row-security policy", body = RowSecurityResponse),
+        (status = 403, description = "API key is scoped by a request context", body = ErrorResponse),
+        (status = 404, description = "Collection has no policy", body = ErrorResponse)
+    )
+)]
+pub async fn get_row_security(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    if let Err(resp) = reject_scoped_key(ctx.as_deref()) {
+        return resp;
+    }
+    match state.db.row_security(&name) {
+        Some(policy) => Json(policy_to_response(policy)).into_response(),
+        None => policy_not_found(&name),
+    }
+}
+
+/// Set (or replace) a collection's row-security policy.
+///
+/// `$ctx.<attribute>` parameters in the filter are bound from the
+/// `[auth.key_contexts]` entry of the API key making each request.
+#[utoipa::path(
+    put,
+    path = "/collections/{name}/row-security",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    request_body = SetRowSecurityRequest,
+    responses(
+        (status = 200, description = "Policy set", body = RowSecurityResponse),
+        (status = 400, description = "Invalid filter", body = ErrorResponse),
+        (status = 403, description = "API key is scoped by a request context", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse)
+    )
+)]
+pub async fn set_row_security(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+    Json(req): Json<SetRowSecurityRequest>,
+) -> impl IntoResponse {
+    if let Err(resp) = reject_scoped_key(ctx.as_deref()) {
+        return resp;
+    }
+    if let Err(e) = state.db.set_row_security(&name, &req.filter) {
+        return auto_core_error_response(&e);
+    }
+    Json(RowSecurityResponse {
+        collection: name,
+        filter: req.filter,
+    })
+    .into_response()
+}
+
+/// Remove a collection's row-security policy.
+#[utoipa::path(
+    delete,
+    path = "/collections/{name}/row-security",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    responses(
+        (status = 200, description = "Policy removed", body = Object),
+        (status = 403, description = "API key is scoped by a request context", body = ErrorResponse),
+        (status = 404, description = "Collection has no policy", body = ErrorResponse)
+    )
+)]
+pub async fn delete_row_security(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    if let Err(resp) = reject_scoped_key(ctx.as_deref()) {
+        return resp;
+    }
+    match state.db.drop_row_security(&name) {
+        Ok(true) => Json(serde_json::json!({
+            "message": "Row-security policy removed",
+            "collection": name
+        }))
+        .into_response(),
+        Ok(false) => policy_not_found(&name),
+        Err(e) => auto_core_error_response(&e),
+    }
+}
+
+/// The row-security filter a read of `collection` made with `ctx` must be
+/// AND-ed with, or a 403 when the key's context lacks an attribute the
+/// policy references.
+#[allow(clippy::result_large_err)]
+pub(crate) fn row_filter(
+    state: &AppState,
+    collection: &str,
+    ctx: Option<&RequestContext>,
+) -> Result<Option<Filter>, axum::response::Response> {
+    state
+        .db
+        .row_security_filter(collection, ctx)
+        .map_err(|e| error_response(StatusCode::FORBIDDEN, e.to_string()))
+}
+
+/// AND-composes a caller filter with a row-security filter.
+pub(crate) fn and_row_filter(filter: Option<Filter>, row: Option<Filter>) -> Option<Filter> {
+    match (filter, row) {
+        (filter, None) => filter,
+        (None, row) => row,
+        (Some(filter), Some(row)) => Some(Filter::new(Condition::And {
+            conditions: vec![filter.condition, row.condition],
+        })),
+    }
+}
+
+/// Refuses (403) a read of `collection` through an endpoint that has no
+/// filter channel — graph reads, relations, access statistics — when the
+/// key's row-security policy would narrow it (fail closed).
+#[allow(clippy::result_large_err)]
+pub(crate) fn refuse_unfiltered_read(
+    state: &AppState,
+    collection: &str,
+    ctx: Option<&RequestContext>,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let error = match state.db.row_security_filter(collection, ctx) {
+        Ok(None) => return Ok(()),
+        Ok(Some(_)) => format!(
+            "Collection '{collection}' has a row-security policy this endpoint cannot apply"
+        ),
+        Err(e) => e.to_string(),
+    };
+    Err((
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse {
+            error,
+            code: None,
+            retryable: None,
+        }),
+    ))
+}
+
+#[allow(clippy::result_large_err)]
+fn reject_scoped_key(ctx: Option<&RequestContext>) -> Result<(), axum::response::Response> {
+    match ctx {
+        Some(ctx) if !ctx.is_empty() => Err(error_response(
+            StatusCode::FORBIDDEN,
+            "API keys with a request context cannot manage row-security policies".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn policy_not_found(name: &str) -> axum::response::Response {
+    error_response(
+        StatusCode::NOT_FOUND,
+        format!("Collection '{name}' has no row-security policy"),
+    )
+}
+
+fn policy_to_response(policy: RowSecurityPolicy) -> RowSecurityResponse {
+    RowSecurityResponse {
+        collection: policy.collection,
+        filter: policy.filter,
+    }
+}
@@ -4,20 +4,25 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use std::sync::Arc;
+use velesdb_core::RequestContext;
 
 use crate::types::{BatchSearchRequest, BatchSearchResponse, ErrorResponse, SearchResponse};
+use crate::wire::WireFormat;
 use crate::AppState;
+use velesdb_core::collection::search::query::score_fusion::ScoreSource;
 
 use super::pipeline::{
     actionable_search_error, build_search_response, record_circuit_breaker,
-    validate_query_dimension,
+    validate_query_dimension, HitRendering,
 };
 use crate::handlers::helpers::{
-    apply_pre_check, extract_client_id, get_vector_collection_or_404, notify_query_timing,
+    apply_pre_check, check_declared_embedding_model, extract_client_id,
+    get_vector_collection_or_404, notify_query_timing,
 };
+use crate::handlers::request_limits::{check_batch_queries, check_top_k};
 
 /// Batch search for multiple vectors.
 #[utoipa::path(
@@ -25,21 +30,36 @@ use crate::handlers::helpers::{
     path = "/collections/{name}/search/batch",
     tag = "search",
     params(
-        ("name" = String, Path, description = "Collection name")
+        ("name" = String, Path, description = "Collection name"),
+        ("x-embedding-model" = Option<String>, Header, description = "Embedding model that produced the query vector (`model` or `model@version`)")
     ),
     request_body = BatchSearchRequest,
     responses(
         (status = 200, description = "Batch search results", body = BatchSearchResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Read denied by governance policy", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse)
+        (status = 413, description = "More queries than `limits.max_batch_queries` (VELES-027)", body = ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = ErrorResponse)
     )
 )]
 pub async fn batch_search(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<BatchSearchRequest>,
 ) -> impl IntoResponse {
+    let within_limits = check_batch_queries(&state, req.searches.len()).and_then(|()| {
+        req.searches
+            .iter()
+            .try_for_each(|search| check_top_k(&state, search.top_k))
+    });
+    if let Err(resp) = within_limits {
+        return resp;
+    }
     let start = std::time::Instant::now();
     state.onboarding_metrics.record_search_request();
 
@@ -47,6 +67,9 @@ pub async fn batch_search(
         Ok(c) => c,
         Err(resp) => return resp,
     };
+    if let Err(resp) = check_declared_embedding_model(&collection, &headers) {
+        return resp;
+    }
 
     // Record query type only after confirming the collection exists, so
     // 404s do not inflate queries_total or vector_queries.
@@ -74,11 +97,10 @@ pub async fn batch_search(
     // Gate the read (CORE-2) before dispatching the off-thread batch. A denied
     // decision — or a scope narrowing, which batch has no per-query channel to
     // apply — refuses the batch (fail closed).
-    match state.db.authorize_read(
+    match state.db.authorize_read_with_context(
         &name,
         velesdb_core::observer::QueryOperationKind::VectorSearch,
-        None,
-        None,
+        ctx.as_deref(),
     ) {
         Ok(None) => {}
         Ok(Some(_)) | Err(_) => {
@@ -88,6 +110,7 @@ pub async fn batch_search(
                 Json(ErrorResponse {
                     error: "Read denied by governance policy".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response();
@@ -118,6 +141,7 @@ pub async fn batch_search(
                 Json(ErrorResponse {
                     error: "Batch search worker failed".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response();
@@ -126,14 +150,20 @@ pub async fn batch_search(
     record_circuit_breaker(&collection, &batch_result);
 
     let all_results = match batch_result {
-        Ok(batch_results) => build_batch_responses(&state, batch_results, &req),
+        Ok(batch_results) => build_batch_responses(
+            &state,
+            batch_results,
+            &req,
+            ScoreSource::Dense(collection.config().metric),
+        ),
         Err(e) => {
             state.operational_metrics.inc_errors();
             return (StatusCode::BAD_REQUEST, Json(actionable_search_error(&e))).into_response();
         }
     };
 
-    finish_batch_search(&state, &name, start, all_results)
+    let format = WireFormat::from_headers(&headers);
+    finish_batch_search(&state, &name, start, format, all_results)
 }
 
 /// Dispatch a batch search to the throughput-optimized parallel kernel when no
@@ -158,6 +188,7 @@ fn finish_batch_search(
     state: &AppState,
     name: &str,
     start: std::time::Instant,
+    format: WireFormat,
     results: Vec<SearchResponse>,
 ) -> axum::response::Response {
     let elapsed = start.elapsed();
@@ -167,7 +198,7 @@ fn finish_batch_search(
         .query_duration_histogram
         .observe(elapsed.as_secs_f64());
 
-    Json(BatchSearchResponse { results, timing_ms }).into_response()
+    format.respond(&BatchSearchResponse { results, timing_ms })
 }
 
 /// Validate that every query vector in a batch request matches the collection dimension.
@@ -188,6 +219,7 @@ fn validate_batch_dimensions(
                 Json(ErrorResponse {
                     error: format!("Invalid query at index {idx}: {}", error.error),
                     code: error.code.clone(),
+                    retryable: None,
                 }),
             )
                 .into_response());
@@ -216,6 +248,7 @@ fn parse_batch_filters(
                                 "Invalid filter at index {idx}: {e}. Hint: validate filter syntax and start with a broader query before reintroducing strict filters."
                             ),
                             code: None,
+                            retryable: None,
                         }),
                     )
                         .into_response());
@@ -229,10 +262,14 @@ fn parse_batch_filters(
 }
 
 /// Convert batch search results into response objects, recording metrics for empty results.
+///
+/// `source` is the dense retrieval every query ran, used by searches that set
+/// `explain_scores`.
 fn build_batch_responses(
     state: &AppState,
     batch_results: Vec<Vec<velesdb_core::SearchResult>>,
     req: &BatchSearchRequest,
+    source: ScoreSource,
 ) -> Vec<SearchResponse> {
     let empty_count = batch_results
         .iter()
@@ -251,7 +288,13 @@ fn build_batch_responses(
         .zip(req.searches.iter())
         .map(|(results, search)| {
             let truncated: Vec<_> = results.into_iter().take(search.top_k).collect();
-            build_search_response(truncated)
+            build_search_response(
+                truncated,
+                HitRendering::new(search.with_vector, search.with_payload)
+                    .with_vector_precision(search.vector_precision)
+                    .with_explain(search.explain_scores.then_some(source))
+                    .with_rerank_preview(search.rerank_preview.clone()),
+            )
         })
         .collect()
 }
@@ -3,27 +3,37 @@
 pub(crate) mod batch;
 pub(crate) mod multi;
 mod pipeline;
+pub(crate) mod recommend;
+pub(crate) mod retrieval;
 mod workers;
 
 use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use std::sync::Arc;
+use velesdb_core::collection::search::query::score_fusion::ScoreSource;
 use velesdb_core::collection::VectorCollection;
+use velesdb_core::RequestContext;
 
 use crate::types::{
     HybridSearchRequest, SearchIdsResponse, SearchRequest, SearchResponse, TextSearchRequest,
 };
+use crate::wire::WireFormat;
 use crate::AppState;
 
-use super::helpers::{apply_pre_check, extract_client_id, get_vector_collection_or_404};
+use super::helpers::{
+    apply_pre_check, check_declared_embedding_model, extract_client_id,
+    get_vector_collection_or_404,
+};
+use super::request_limits::check_top_k;
 use pipeline::{
     execute_dense_search_ids, execute_search_request, finish_search_ids_with_cb,
     finish_search_with_cb, finish_search_with_status, ids_fast_path_eligible,
-    parse_optional_filter, timeout_response, validate_query_dimension,
+    parse_optional_filter, search_score_source, timeout_response, validate_query_dimension,
+    HitRendering,
 };
 use workers::{run_blocking_search, run_search_with_optional_timeout};
 
@@ -35,6 +45,12 @@ pub use multi::__path_multi_query_search;
 #[allow(unused_imports)]
 pub use multi::__path_multi_query_search_ids;
 pub use multi::{multi_query_search, multi_query_search_ids};
+#[allow(unused_imports)]
+pub use recommend::__path_recommend;
+pub use recommend::recommend;
+#[allow(unused_imports)]
+pub use retrieval::{__path_create_embeddings, __path_retrieval};
+pub use retrieval::{create_embeddings, retrieval};
 
 /// Shared search preamble: record onboarding metric and resolve collection.
 ///
@@ -53,7 +69,46 @@ fn search_preamble(
     name: &str,
 ) -> Result<VectorCollection, axum::response::Response> {
     state.onboarding_metrics.record_search_request();
-    get_vector_collection_or_404(state, name)
+    // A view searches its base collection; `name` still reaches the gated
+    // read, which applies the view predicate.
+    match state.db.get_view(name) {
+        Some(view) => get_vector_collection_or_404(state, &view.base),
+        None => get_vector_collection_or_404(state, name),
+    }
+}
+
+/// [`search_preamble`] for endpoints that take a query vector: additionally
+/// rejects a declared embedding model (`X-Embedding-Model`) that differs from
+/// the one recorded on the collection.
+#[allow(clippy::result_large_err)]
+fn vector_search_preamble(
+    state: &AppState,
+    name: &str,
+    headers: &axum::http::HeaderMap,
+) -> Result<VectorCollection, axum::response::Response> {
+    let collection = search_preamble(state, name)?;
+    check_declared_embedding_model(&collection, headers)?;
+    Ok(collection)
+}
+
+/// [`search_preamble`] for BM25 text search, which metadata-only collections
+/// serve too. Those are wrapped in their structural vector view so guard
+/// rails and hit rendering go through the shared path; the search itself is
+/// dispatched by the gated read.
+#[allow(clippy::result_large_err)]
+fn text_search_preamble(
+    state: &AppState,
+    name: &str,
+) -> Result<VectorCollection, axum::response::Response> {
+    let base = state.db.get_view(name).map(|view| view.base);
+    let target = base.as_deref().unwrap_or(name);
+    match state.db.get_metadata_collection(target) {
+        Some(metadata) => {
+            state.onboarding_metrics.record_search_request();
+            Ok(metadata.into_vector_view())
+        }
+        None => search_preamble(state, name),
+    }
 }
 
 /// Executes the full search pipeline and records circuit-breaker on failure.
@@ -65,8 +120,9 @@ fn execute_with_cb(
     name: &str,
     collection: &VectorCollection,
     req: &mut SearchRequest,
+    ctx: Option<&RequestContext>,
 ) -> Result<velesdb_core::Result<Vec<velesdb_core::SearchResult>>, axum::response::Response> {
-    execute_search_request(state, name, collection, req).inspect_err(|_| {
+    execute_search_request(state, name, collection, req, ctx).inspect_err(|_| {
         collection.guard_rails().circuit_breaker.record_failure();
     })
 }
@@ -82,25 +138,34 @@ fn execute_with_cb(
     path = "/collections/{name}/search",
     tag = "search",
     params(
-        ("name" = String, Path, description = "Collection name")
+        ("name" = String, Path, description = "Collection name"),
+        ("x-embedding-model" = Option<String>, Header, description = "Embedding model that produced the query vector (`model` or `model@version`)")
     ),
     request_body = SearchRequest,
     responses(
         (status = 200, description = "Search results", body = SearchResponse),
+        (status = 400, description = "Invalid request", body = crate::types::ErrorResponse),
         (status = 404, description = "Collection not found", body = crate::types::ErrorResponse),
-        (status = 400, description = "Invalid request", body = crate::types::ErrorResponse)
+        (status = 408, description = "Search exceeded `timeout_ms` (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = crate::types::ErrorResponse)
     )
 )]
 #[allow(clippy::result_large_err)]
 pub async fn search(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<SearchRequest>,
 ) -> impl IntoResponse {
+    if let Err(resp) = check_top_k(&state, req.top_k) {
+        return resp;
+    }
     let start = std::time::Instant::now();
 
-    let collection = match search_preamble(&state, &name) {
+    let collection = match vector_search_preamble(&state, &name, &headers) {
         Ok(c) => c,
         Err(resp) => return resp,
     };
@@ -117,6 +182,14 @@ pub async fn search(
     // responsive and the timer can actually fire. See
     // `run_search_with_optional_timeout` for the cancellation contract.
     let timeout_ms = req.timeout_ms;
+    let rendering = HitRendering::new(req.with_vector, req.with_payload)
+        .with_vector_precision(req.vector_precision)
+        .with_explain(
+            req.explain_scores
+                .then(|| search_score_source(&req, collection.config().metric)),
+        )
+        .with_rerank_preview(req.rerank_preview.clone())
+        .with_format(WireFormat::from_headers(&headers));
     let state_for_work = Arc::clone(&state);
     let name_for_work = name.clone();
     let collection_for_work = collection.clone();
@@ -128,6 +201,7 @@ pub async fn search(
             &name_for_work,
             &collection_for_work,
             &mut owned_req,
+            ctx.as_deref(),
         )
     })
     .await;
@@ -148,7 +222,7 @@ pub async fn search(
         }
     };
 
-    finish_search_with_cb(&state, &name, start, &collection, search_result)
+    finish_search_with_cb(&state, &name, start, &collection, rendering, search_result)
 }
 
 /// Owned-request wrapper around [`execute_with_cb`] used by the
@@ -161,8 +235,9 @@ fn execute_search_with_cb_owned(
     name: &str,
     collection: &VectorCollection,
     req: &mut SearchRequest,
+    ctx: Option<&RequestContext>,
 ) -> Result<velesdb_core::Result<Vec<velesdb_core::SearchResult>>, axum::response::Response> {
-    execute_with_cb(state, name, collection, req)
+    execute_with_cb(state, name, collection, req, ctx)
 }
 
 /// Owned-request variant for `/search/ids`: takes the `search_ids` fast path
@@ -175,15 +250,20 @@ fn execute_search_ids_with_cb_owned(
     name: &str,
     collection: &VectorCollection,
     req: &mut SearchRequest,
+    ctx: Option<&RequestContext>,
 ) -> Result<velesdb_core::Result<Vec<velesdb_core::SearchResult>>, axum::response::Response> {
-    if ids_fast_path_eligible(req) {
-        return execute_dense_search_ids(state, name, collection, req)
+    // A view's predicate or a row-security policy needs the filtered pipeline.
+    if ids_fast_path_eligible(req)
+        && state.db.get_view(name).is_none()
+        && state.db.row_security(name).is_none()
+    {
+        return execute_dense_search_ids(state, name, collection, req, ctx)
             .inspect_err(|_| collection.guard_rails().circuit_breaker.record_failure());
     }
-    execute_with_cb(state, name, collection, req)
+    execute_with_cb(state, name, collection, req, ctx)
 }
 
-/// Search using BM25 full-text search.
+/// Search using BM25 full-text search, on vector and metadata-only collections.
 #[utoipa::path(
     post,
     path = "/collections/{name}/search/text",
@@ -194,17 +274,25 @@ fn execute_search_ids_with_cb_owned(
     request_body = TextSearchRequest,
     responses(
         (status = 200, description = "Text search results", body = SearchResponse),
-        (status = 404, description = "Collection not found", body = crate::types::ErrorResponse)
+        (status = 400, description = "Invalid request", body = crate::types::ErrorResponse),
+        (status = 404, description = "Collection not found", body = crate::types::ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = crate::types::ErrorResponse)
     )
 )]
 #[allow(clippy::result_large_err)]
 pub async fn text_search(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<TextSearchRequest>,
 ) -> impl IntoResponse {
-    let collection = match search_preamble(&state, &name) {
+    if let Err(resp) = check_top_k(&state, req.top_k) {
+        return resp;
+    }
+    let collection = match text_search_preamble(&state, &name) {
         Ok(c) => c,
         Err(resp) => return resp,
     };
@@ -225,6 +313,10 @@ pub async fn text_search(
     let filter_json = req.filter.clone();
     let query = req.query.clone();
     let top_k = req.top_k;
+    let rendering = HitRendering::new(req.with_vector, req.with_payload)
+        .with_vector_precision(req.vector_precision)
+        .with_explain(req.explain_scores.then_some(ScoreSource::Text))
+        .with_format(WireFormat::from_headers(&headers));
     let name_for_work = name.clone();
     let state_for_work = Arc::clone(&state);
 
@@ -241,7 +333,7 @@ pub async fn text_search(
         };
         Ok(state_for_work
             .db
-            .gated_search(&name_for_work, None, None, read))
+            .gated_search_with_context(&name_for_work, ctx.as_deref(), read))
     })
     .await;
 
@@ -259,6 +351,7 @@ pub async fn text_search(
         start,
         &collection,
         StatusCode::INTERNAL_SERVER_ERROR,
+        rendering,
         search_result,
     )
 }
@@ -269,23 +362,31 @@ pub async fn text_search(
     path = "/collections/{name}/search/hybrid",
     tag = "search",
     params(
-        ("name" = String, Path, description = "Collection name")
+        ("name" = String, Path, description = "Collection name"),
+        ("x-embedding-model" = Option<String>, Header, description = "Embedding model that produced the query vector (`model` or `model@version`)")
     ),
     request_body = HybridSearchRequest,
     responses(
         (status = 200, description = "Hybrid search results", body = SearchResponse),
+        (status = 400, description = "Invalid request", body = crate::types::ErrorResponse),
         (status = 404, description = "Collection not found", body = crate::types::ErrorResponse),
-        (status = 400, description = "Invalid request", body = crate::types::ErrorResponse)
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = crate::types::ErrorResponse)
     )
 )]
 #[allow(clippy::result_large_err)]
 pub async fn hybrid_search(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<HybridSearchRequest>,
 ) -> impl IntoResponse {
-    let collection = match search_preamble(&state, &name) {
+    if let Err(resp) = check_top_k(&state, req.top_k) {
+        return resp;
+    }
+    let collection = match vector_search_preamble(&state, &name, &headers) {
         Ok(c) => c,
         Err(resp) => return resp,
     };
@@ -299,8 +400,8 @@ pub async fn hybrid_search(
 
     let start = std::time::Instant::now();
 
-    let expected_dimension = collection.config().dimension;
-    if let Err(error) = validate_query_dimension(&state, &name, expected_dimension, &req.vector) {
+    let config = collection.config();
+    if let Err(error) = validate_query_dimension(&state, &name, config.dimension, &req.vector) {
         state.operational_metrics.inc_errors();
         return (StatusCode::BAD_REQUEST, Json(error)).into_response();
     }
@@ -311,12 +412,20 @@ pub async fn hybrid_search(
     // runtime stays responsive.
     let state_for_work = Arc::clone(&state);
     let name_for_work = name.clone();
+    let rendering = HitRendering::new(req.with_vector, req.with_payload)
+        .with_vector_precision(req.vector_precision)
+        .with_explain(req.explain_scores.then_some(ScoreSource::Hybrid {
+            metric: config.metric,
+            vector_weight: req.vector_weight,
+        }))
+        .with_format(WireFormat::from_headers(&headers));
     let HybridSearchRequest {
         vector,
         query,
         top_k,
         vector_weight,
         filter,
+        ..
     } = req;
 
     // Route through the control-plane read gate (CORE-1/CORE-2). No observer ⇒
@@ -332,7 +441,7 @@ pub async fn hybrid_search(
         };
         Ok(state_for_work
             .db
-            .gated_search(&name_for_work, None, None, read))
+            .gated_search_with_context(&name_for_work, ctx.as_deref(), read))
     })
     .await;
 
@@ -344,7 +453,7 @@ pub async fn hybrid_search(
         }
     };
 
-    finish_search_with_cb(&state, &name, start, &collection, search_result)
+    finish_search_with_cb(&state, &name, start, &collection, rendering, search_result)
 }
 
 /// Lightweight search returning only IDs and scores (no payload hydration).
@@ -357,25 +466,34 @@ pub async fn hybrid_search(
     path = "/collections/{name}/search/ids",
     tag = "search",
     params(
-        ("name" = String, Path, description = "Collection name")
+        ("name" = String, Path, description = "Collection name"),
+        ("x-embedding-model" = Option<String>, Header, description = "Embedding model that produced the query vector (`model` or `model@version`)")
     ),
     request_body = SearchRequest,
     responses(
         (status = 200, description = "IDs-only search results", body = SearchIdsResponse),
+        (status = 400, description = "Invalid request", body = crate::types::ErrorResponse),
         (status = 404, description = "Collection not found", body = crate::types::ErrorResponse),
-        (status = 400, description = "Invalid request", body = crate::types::ErrorResponse)
+        (status = 408, description = "Search exceeded `timeout_ms` (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = crate::types::ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = crate::types::ErrorResponse)
     )
 )]
 #[allow(clippy::result_large_err)]
 pub async fn search_ids(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<SearchRequest>,
 ) -> impl IntoResponse {
+    if let Err(resp) = check_top_k(&state, req.top_k) {
+        return resp;
+    }
     let start = std::time::Instant::now();
 
-    let collection = match search_preamble(&state, &name) {
+    let collection = match vector_search_preamble(&state, &name, &headers) {
         Ok(c) => c,
         Err(resp) => return resp,
     };
@@ -403,6 +521,7 @@ pub async fn search_ids(
             &name_for_work,
             &collection_for_work,
             &mut owned_req,
+            ctx.as_deref(),
         )
     })
     .await;
@@ -421,5 +540,6 @@ pub async fn search_ids(
         }
     };
 
-    finish_search_ids_with_cb(&state, &name, start, &collection, search_result)
+    let format = WireFormat::from_headers(&headers);
+    finish_search_ids_with_cb(&state, &name, start, &collection, format, search_result)
 }
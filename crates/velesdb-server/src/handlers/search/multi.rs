@@ -4,19 +4,22 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use std::sync::Arc;
+use velesdb_core::RequestContext;
 
 use crate::types::{ErrorResponse, MultiQuerySearchRequest, SearchIdsResponse, SearchResponse};
+use crate::wire::WireFormat;
 use crate::AppState;
 
 use super::pipeline::{
     finish_search_ids_with_cb, finish_search_with_cb, id_score_results, parse_filter_or_400,
-    validate_query_dimension,
+    validate_query_dimension, HitRendering,
 };
 use super::workers::run_blocking_search;
 use crate::handlers::helpers::{apply_pre_check, extract_client_id, get_vector_collection_or_404};
+use crate::handlers::request_limits::{check_batch_queries, check_top_k};
 
 /// Parse the fusion strategy name into a `FusionStrategy`, returning a 400
 /// response (and bumping the error counter) for an unknown strategy.
@@ -50,6 +53,7 @@ fn parse_fusion_strategy(
                         req.strategy
                     ),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response())
@@ -74,6 +78,7 @@ fn validate_query_vectors(
                 Json(ErrorResponse {
                     error: format!("Invalid query vector at index {idx}: {}", error.error),
                     code: error.code.clone(),
+                    retryable: None,
                 }),
             )
                 .into_response());
@@ -100,6 +105,8 @@ fn prepare_multi_query(
     axum::response::Response,
 > {
     state.onboarding_metrics.record_search_request();
+    check_batch_queries(state, req.vectors.len())?;
+    check_top_k(state, req.top_k)?;
 
     let collection = get_vector_collection_or_404(state, name)?;
 
@@ -130,13 +137,20 @@ fn prepare_multi_query(
     request_body = MultiQuerySearchRequest,
     responses(
         (status = 200, description = "Multi-query search results", body = SearchResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Read denied by governance policy", body = ErrorResponse),
         (status = 404, description = "Collection not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 413, description = "More queries than `limits.max_batch_queries` (VELES-027)", body = ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = ErrorResponse)
     )
 )]
 #[allow(clippy::result_large_err)]
 pub async fn multi_query_search(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<MultiQuerySearchRequest>,
@@ -167,11 +181,10 @@ pub async fn multi_query_search(
     // Gate the read (CORE-2) before the off-thread fusion. A denied decision —
     // or a scope narrowing, which the multi-query fusion kernel has no channel
     // to apply — refuses the query (fail closed).
-    match state.db.authorize_read(
+    match state.db.authorize_read_with_context(
         &name,
         velesdb_core::observer::QueryOperationKind::VectorSearch,
-        None,
-        None,
+        ctx.as_deref(),
     ) {
         Ok(None) => {}
         Ok(Some(_)) | Err(_) => {
@@ -181,6 +194,7 @@ pub async fn multi_query_search(
                 Json(ErrorResponse {
                     error: "Read denied by governance policy".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response();
@@ -199,6 +213,9 @@ pub async fn multi_query_search(
     let collection_for_work = collection.clone();
     let vectors = req.vectors;
     let top_k = req.top_k;
+    let rendering = HitRendering::new(req.with_vector, req.with_payload)
+        .with_vector_precision(req.vector_precision)
+        .with_format(WireFormat::from_headers(&headers));
 
     let work_result = run_blocking_search(move || {
         let query_refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
@@ -214,7 +231,7 @@ pub async fn multi_query_search(
         }
     };
 
-    finish_search_with_cb(&state, &name, start, &collection, search_result)
+    finish_search_with_cb(&state, &name, start, &collection, rendering, search_result)
 }
 
 /// Multi-query fusion search returning only ids and scores (no payloads).
@@ -231,12 +248,18 @@ pub async fn multi_query_search(
     responses(
         (status = 200, description = "Multi-query ids-only results", body = SearchIdsResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Collection not found", body = ErrorResponse)
+        (status = 403, description = "Read denied by governance policy", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 413, description = "More queries than `limits.max_batch_queries` (VELES-027)", body = ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = ErrorResponse)
     )
 )]
 #[allow(clippy::result_large_err)]
 pub async fn multi_query_search_ids(
     State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
     headers: axum::http::HeaderMap,
     Path(name): Path<String>,
     Json(req): Json<MultiQuerySearchRequest>,
@@ -257,17 +280,17 @@ pub async fn multi_query_search_ids(
                         use /search/multi for filtered multi-query search."
                     .to_string(),
                 code: None,
+                retryable: None,
             }),
         )
             .into_response();
     }
 
     // Gate the read (CORE-2) before the off-thread ids-only fusion.
-    match state.db.authorize_read(
+    match state.db.authorize_read_with_context(
         &name,
         velesdb_core::observer::QueryOperationKind::VectorSearch,
-        None,
-        None,
+        ctx.as_deref(),
     ) {
         Ok(None) => {}
         Ok(Some(_)) | Err(_) => {
@@ -277,6 +300,7 @@ pub async fn multi_query_search_ids(
                 Json(ErrorResponse {
                     error: "Read denied by governance policy".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response();
@@ -304,5 +328,6 @@ pub async fn multi_query_search_ids(
         }
     };
 
-    finish_search_ids_with_cb(&state, &name, start, &collection, search_result)
+    let format = WireFormat::from_headers(&headers);
+    finish_search_ids_with_cb(&state, &name, start, &collection, format, search_result)
 }
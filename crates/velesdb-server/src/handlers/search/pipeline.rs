@@ -2,26 +2,121 @@
 //! and shared result handling.
 
 use axum::{http::StatusCode, response::IntoResponse, Json};
+use velesdb_core::collection::search::query::score_fusion::{ScoreBreakdown, ScoreSource};
 use velesdb_core::collection::VectorCollection;
 use velesdb_core::index::sparse::DEFAULT_SPARSE_INDEX_NAME;
+use velesdb_core::{PointProjection, RequestContext};
 
 use crate::types::{
-    mode_to_search_quality, ErrorResponse, IdScoreResult, SearchIdsResponse, SearchRequest,
-    SearchResponse, SearchResultResponse,
+    mode_to_search_quality, ErrorResponse, IdScoreResult, RerankPreviewRequest,
+    ResponseVectorPrecision, SearchIdsResponse, SearchRequest, SearchResponse,
+    SearchResultResponse,
 };
+use crate::wire::WireFormat;
 use crate::AppState;
 
-/// Convert a `Vec<SearchResult>` into a `SearchResponse`.
-pub(crate) fn build_search_response(results: Vec<velesdb_core::SearchResult>) -> SearchResponse {
+/// How each search hit is rendered: the parts of the point to keep, the
+/// precision of kept vectors, when the request set `explain_scores`, the
+/// retrieval its score breakdown is read against, the payload fields of a
+/// `rerank_preview`, and the wire format the response is encoded in.
+#[derive(Debug, Clone)]
+pub(crate) struct HitRendering {
+    projection: PointProjection,
+    precision: ResponseVectorPrecision,
+    explain: Option<ScoreSource>,
+    rerank_preview: Option<RerankPreviewRequest>,
+    format: WireFormat,
+}
+
+impl HitRendering {
+    /// Renders hits with the given projection flags and no score breakdown.
+    pub(crate) const fn new(with_vector: bool, with_payload: bool) -> Self {
+        Self {
+            projection: PointProjection::new(with_vector, with_payload),
+            precision: ResponseVectorPrecision::F32,
+            explain: None,
+            rerank_preview: None,
+            format: WireFormat::Json,
+        }
+    }
+
+    /// Returns kept vectors in `precision` instead of full `f32`.
+    pub(crate) const fn with_vector_precision(
+        mut self,
+        precision: ResponseVectorPrecision,
+    ) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Attaches a `score_breakdown` to each hit when `source` is `Some`.
+    pub(crate) const fn with_explain(mut self, source: Option<ScoreSource>) -> Self {
+        self.explain = source;
+        self
+    }
+
+    /// Adds a `candidates` array built by `preview` when it is `Some`.
+    pub(crate) fn with_rerank_preview(mut self, preview: Option<RerankPreviewRequest>) -> Self {
+        self.rerank_preview = preview;
+        self
+    }
+
+    /// Encodes the response in `format` instead of JSON.
+    pub(crate) const fn with_format(mut self, format: WireFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Convert a `Vec<SearchResult>` into a `SearchResponse`, keeping only the
+/// parts of each hit selected by `rendering`.
+pub(crate) fn build_search_response(
+    results: Vec<velesdb_core::SearchResult>,
+    rendering: HitRendering,
+) -> SearchResponse {
+    let HitRendering {
+        projection,
+        precision,
+        explain,
+        rerank_preview,
+        ..
+    } = rendering;
+    // Read before the projection below drops payloads the caller did not ask for.
+    let candidates = rerank_preview.map(|preview| {
+        results
+            .iter()
+            .map(|r| preview.candidate(r.point.id, r.point.payload.as_ref()))
+            .collect()
+    });
     SearchResponse {
         results: results
             .into_iter()
             .map(|r| SearchResultResponse {
+                score_breakdown: explain.and_then(|source| {
+                    serde_json::to_value(ScoreBreakdown::from_search_result(&r, source)).ok()
+                }),
                 id: r.point.id,
                 score: r.score,
-                payload: r.point.payload,
+                vector: projection
+                    .with_vector
+                    .then_some(r.point.vector)
+                    .and_then(|v| precision.render(v)),
+                payload: r.point.payload.filter(|_| projection.with_payload),
             })
             .collect(),
+        candidates,
+    }
+}
+
+/// Retrieval a `/search` request runs, read by its `explain_scores` breakdown.
+pub(crate) fn search_score_source(
+    req: &SearchRequest,
+    metric: velesdb_core::DistanceMetric,
+) -> ScoreSource {
+    match (req.vector.is_empty(), has_sparse_input(req)) {
+        (false, false) => ScoreSource::Dense(metric),
+        (true, _) => ScoreSource::Sparse,
+        (false, true) => ScoreSource::Fused,
     }
 }
 
@@ -35,7 +130,11 @@ pub(crate) fn parse_filter_or_400(
         onboarding_metrics.record_filter_parse_error();
         (
             StatusCode::BAD_REQUEST,
-            Json(ErrorResponse { error, code: None }),
+            Json(ErrorResponse {
+                error,
+                code: None,
+                retryable: None,
+            }),
         )
             .into_response()
     })
@@ -64,6 +163,7 @@ pub(crate) fn dimension_mismatch_error(
             "Vector dimension mismatch for collection '{collection_name}': expected {expected}, got {actual}. Hint: use embeddings with the same dimension as the collection or create a new collection with the target dimension."
         ),
         code: Some("VELES-004".to_string()),
+        retryable: Some(false),
     }
 }
 
@@ -101,6 +201,7 @@ pub(crate) fn actionable_search_error(error: &velesdb_core::Error) -> ErrorRespo
     ErrorResponse {
         error: format!("{base_error}{hint}"),
         code: Some(error.code().to_string()),
+        retryable: Some(error.is_retryable()),
     }
 }
 
@@ -127,6 +228,7 @@ pub(crate) fn resolve_sparse_input(
                         m.len()
                     ),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response());
@@ -148,6 +250,7 @@ pub(crate) fn resolve_sparse_input(
                 Json(ErrorResponse {
                     error: e,
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response()),
@@ -198,6 +301,7 @@ pub(crate) fn parse_fusion_strategy(
                     Json(ErrorResponse {
                         error: format!("Invalid RSF fusion weights: {e}"),
                         code: None,
+                        retryable: None,
                     }),
                 )
                     .into_response()
@@ -220,6 +324,7 @@ pub(crate) fn parse_fusion_strategy(
                      'maximum' (alias: 'max'), 'weighted'"
                 ),
                 code: None,
+                retryable: None,
             }),
         )
             .into_response()),
@@ -233,6 +338,7 @@ pub(crate) fn execute_dense_search(
     name: &str,
     collection: &VectorCollection,
     req: &SearchRequest,
+    ctx: Option<&RequestContext>,
 ) -> Result<velesdb_core::Result<Vec<velesdb_core::SearchResult>>, axum::response::Response> {
     let expected_dimension = collection.config().dimension;
     if let Err(error) = validate_query_dimension(state, name, expected_dimension, &req.vector) {
@@ -257,7 +363,7 @@ pub(crate) fn execute_dense_search(
     // single `Option` check followed by the same leaf search (zero overhead);
     // when a governance observer is present, RBAC / tenant / scope apply and a
     // denied read is refused and audited. The open-core server has no per-request
-    // principal, so principal/tenant are `None` and the observer decides.
+    // principal; the API key's request context drives row-security policies.
     let read = velesdb_core::GatedRead::Dense {
         query: &req.vector,
         k: req.top_k,
@@ -265,7 +371,7 @@ pub(crate) fn execute_dense_search(
         quality: quality_mode,
         filter: filter.as_ref(),
     };
-    let result = state.db.gated_search(name, None, None, read);
+    let result = state.db.gated_search_with_context(name, ctx, read);
     Ok(result)
 }
 
@@ -325,6 +431,7 @@ pub(crate) fn execute_dense_search_ids(
     name: &str,
     collection: &VectorCollection,
     req: &SearchRequest,
+    ctx: Option<&RequestContext>,
 ) -> Result<velesdb_core::Result<Vec<velesdb_core::SearchResult>>, axum::response::Response> {
     let expected_dimension = collection.config().dimension;
     if let Err(error) = validate_query_dimension(state, name, expected_dimension, &req.vector) {
@@ -333,11 +440,10 @@ pub(crate) fn execute_dense_search_ids(
     // Gate the read (CORE-2). The ids fast path is only taken for filter-free
     // dense requests, so a scope decision fails closed rather than silently
     // running unscoped.
-    let result = match state.db.authorize_read(
+    let result = match state.db.authorize_read_with_context(
         name,
         velesdb_core::observer::QueryOperationKind::VectorSearch,
-        None,
-        None,
+        ctx,
     ) {
         Ok(None) => collection
             .search_ids(&req.vector, req.top_k)
@@ -405,6 +511,7 @@ impl<'a> SearchMode<'a> {
                 Json(ErrorResponse {
                     error: "Either 'vector' or 'sparse_vector' must be provided".to_string(),
                     code: None,
+                    retryable: None,
                 }),
             )
                 .into_response()),
@@ -420,6 +527,7 @@ pub(crate) fn execute_search_request(
     name: &str,
     collection: &VectorCollection,
     req: &mut SearchRequest,
+    ctx: Option<&RequestContext>,
 ) -> Result<velesdb_core::Result<Vec<velesdb_core::SearchResult>>, axum::response::Response> {
     let sparse_vec = resolve_sparse_input(req)?;
     let has_dense = !req.vector.is_empty();
@@ -431,14 +539,13 @@ pub(crate) fn execute_search_request(
 
     match SearchMode::classify(has_dense, sparse_vec.as_ref())? {
         SearchMode::Hybrid { sparse } => {
-            execute_hybrid_sparse(state, name, collection, req, sparse, index_name)
+            execute_hybrid_sparse(state, name, collection, req, sparse, index_name, ctx)
         }
-        SearchMode::DenseOnly => execute_dense_search(state, name, collection, req),
-        SearchMode::SparseOnly { sparse } => match state.db.authorize_read(
+        SearchMode::DenseOnly => execute_dense_search(state, name, collection, req, ctx),
+        SearchMode::SparseOnly { sparse } => match state.db.authorize_read_with_context(
             name,
             velesdb_core::observer::QueryOperationKind::VectorSearch,
-            None,
-            None,
+            ctx,
         ) {
             Ok(None) => Ok(collection.sparse_search(sparse, req.top_k, index_name)),
             Ok(Some(_)) => Ok(Err(velesdb_core::Error::Config(
@@ -458,6 +565,7 @@ fn execute_hybrid_sparse(
     req: &SearchRequest,
     sparse_query: &velesdb_core::index::sparse::SparseVector,
     index_name: &str,
+    ctx: Option<&RequestContext>,
 ) -> Result<velesdb_core::Result<Vec<velesdb_core::SearchResult>>, axum::response::Response> {
     let expected_dimension = collection.config().dimension;
     if let Err(error) = validate_query_dimension(state, name, expected_dimension, &req.vector) {
@@ -466,11 +574,10 @@ fn execute_hybrid_sparse(
     let strategy = parse_fusion_strategy(req.fusion.as_ref())?;
     // Gate the read (CORE-2): hybrid dense+sparse has no metadata-filter leaf,
     // so a scope decision fails closed rather than running unfiltered.
-    match state.db.authorize_read(
+    match state.db.authorize_read_with_context(
         name,
         velesdb_core::observer::QueryOperationKind::HybridSearch,
-        None,
-        None,
+        ctx,
     ) {
         Ok(None) => Ok(collection.hybrid_sparse_search(
             &req.vector,
@@ -486,7 +593,8 @@ fn execute_hybrid_sparse(
     }
 }
 
-/// Record empty-results diagnostic and notify the query timing subsystem.
+/// Record empty-results diagnostic, notify the query timing subsystem and
+/// feed the collection's latency SLO.
 fn record_search_metrics(state: &AppState, name: &str, start: std::time::Instant, is_empty: bool) {
     if is_empty {
         state.onboarding_metrics.record_empty_search_results();
@@ -501,6 +609,7 @@ fn record_search_metrics(state: &AppState, name: &str, start: std::time::Instant
     state
         .db
         .notify_query(name, duration_us.min(u128::from(u64::MAX)) as u64);
+    state.db.record_search_latency(name, elapsed);
     // Record into Prometheus histogram (seconds).
     state
         .query_duration_histogram
@@ -509,7 +618,7 @@ fn record_search_metrics(state: &AppState, name: &str, start: std::time::Instant
 
 /// Core search result handler: records metrics, delegates success to `on_ok`,
 /// returns actionable error response on failure.
-fn finish_search_core(
+pub(crate) fn finish_search_core(
     state: &AppState,
     name: &str,
     start: std::time::Instant,
@@ -534,6 +643,7 @@ pub(crate) fn finish_search(
     state: &AppState,
     name: &str,
     start: std::time::Instant,
+    rendering: HitRendering,
     search_result: velesdb_core::Result<Vec<velesdb_core::SearchResult>>,
 ) -> axum::response::Response {
     finish_search_core(
@@ -542,7 +652,10 @@ pub(crate) fn finish_search(
         start,
         StatusCode::BAD_REQUEST,
         search_result,
-        |results| Json(build_search_response(results)).into_response(),
+        |results| {
+            let format = rendering.format;
+            format.respond(&build_search_response(results, rendering))
+        },
     )
 }
 
@@ -551,6 +664,7 @@ pub(crate) fn finish_search_ids(
     state: &AppState,
     name: &str,
     start: std::time::Instant,
+    format: WireFormat,
     search_result: velesdb_core::Result<Vec<velesdb_core::SearchResult>>,
 ) -> axum::response::Response {
     finish_search_core(
@@ -569,7 +683,7 @@ pub(crate) fn finish_search_ids(
                     })
                     .collect(),
             };
-            Json(response).into_response()
+            format.respond(&response)
         },
     )
 }
@@ -593,10 +707,11 @@ pub(crate) fn finish_search_with_cb(
     name: &str,
     start: std::time::Instant,
     collection: &VectorCollection,
+    rendering: HitRendering,
     search_result: velesdb_core::Result<Vec<velesdb_core::SearchResult>>,
 ) -> axum::response::Response {
     record_circuit_breaker(collection, &search_result);
-    finish_search(state, name, start, search_result)
+    finish_search(state, name, start, rendering, search_result)
 }
 
 /// Handles `Ok`/`Err` from a core search call: records circuit-breaker
@@ -606,10 +721,11 @@ pub(crate) fn finish_search_ids_with_cb(
     name: &str,
     start: std::time::Instant,
     collection: &VectorCollection,
+    format: WireFormat,
     search_result: velesdb_core::Result<Vec<velesdb_core::SearchResult>>,
 ) -> axum::response::Response {
     record_circuit_breaker(collection, &search_result);
-    finish_search_ids(state, name, start, search_result)
+    finish_search_ids(state, name, start, format, search_result)
 }
 
 /// Variant of [`finish_search_with_cb`] that uses a custom error status code
@@ -620,11 +736,13 @@ pub(crate) fn finish_search_with_status(
     start: std::time::Instant,
     collection: &VectorCollection,
     error_status: StatusCode,
+    rendering: HitRendering,
     search_result: velesdb_core::Result<Vec<velesdb_core::SearchResult>>,
 ) -> axum::response::Response {
     record_circuit_breaker(collection, &search_result);
     finish_search_core(state, name, start, error_status, search_result, |results| {
-        Json(build_search_response(results)).into_response()
+        let format = rendering.format;
+        format.respond(&build_search_response(results, rendering))
     })
 }
 
@@ -643,6 +761,7 @@ pub(crate) fn timeout_response(collection_name: &str, timeout_ms: u64) -> axum::
                  until completion.",
             ),
             code: Some("VELES-027".to_string()),
+            retryable: Some(false),
         }),
     )
         .into_response()
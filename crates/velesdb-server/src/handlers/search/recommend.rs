@@ -0,0 +1,148 @@
+//! Recommend handler: search from stored example points instead of a vector.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use std::sync::Arc;
+use velesdb_core::RecommendStrategy;
+use velesdb_core::RequestContext;
+
+use crate::types::{ErrorResponse, RecommendRequest, SearchResponse};
+use crate::wire::WireFormat;
+use crate::AppState;
+
+use super::pipeline::{finish_search_with_cb, parse_optional_filter, HitRendering};
+use super::workers::run_blocking_search;
+use crate::handlers::helpers::{
+    apply_pre_check, auto_core_error_response, error_response, extract_client_id,
+    get_vector_collection_or_404,
+};
+use crate::handlers::request_limits::check_top_k;
+
+/// Parse the strategy name, returning a 400 response for an unknown one.
+#[allow(clippy::result_large_err)]
+fn parse_recommend_strategy(strategy: &str) -> Result<RecommendStrategy, axum::response::Response> {
+    match strategy.to_lowercase().as_str() {
+        "average_vector" | "average" => Ok(RecommendStrategy::AverageVector),
+        "best_score" => Ok(RecommendStrategy::BestScore),
+        _ => Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Invalid strategy: {strategy}. Valid: average_vector, best_score"),
+        )),
+    }
+}
+
+/// Recommend points similar to `positive` example ids and unlike `negative` ones.
+///
+/// The collection looks up the examples' stored vectors itself, so clients
+/// don't have to fetch them and recompose a query. The examples are never
+/// part of the results.
+#[utoipa::path(
+    post,
+    path = "/collections/{name}/recommend",
+    tag = "search",
+    params(("name" = String, Path, description = "Collection name")),
+    request_body = RecommendRequest,
+    responses(
+        (status = 200, description = "Recommended points", body = SearchResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Read denied by governance policy", body = ErrorResponse),
+        (status = 404, description = "Collection or example point not found", body = ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = ErrorResponse)
+    )
+)]
+#[allow(clippy::result_large_err)]
+pub async fn recommend(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    headers: axum::http::HeaderMap,
+    Path(name): Path<String>,
+    Json(req): Json<RecommendRequest>,
+) -> impl IntoResponse {
+    if let Err(resp) = check_top_k(&state, req.top_k) {
+        return resp;
+    }
+    state.onboarding_metrics.record_search_request();
+    let collection = match get_vector_collection_or_404(&state, &name) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    state.operational_metrics.record_vector_query();
+
+    let client_id = extract_client_id(&headers);
+    if let Err(resp) = apply_pre_check(collection.guard_rails(), &client_id) {
+        state.operational_metrics.inc_rate_limited();
+        return resp;
+    }
+
+    let strategy = match parse_recommend_strategy(&req.strategy) {
+        Ok(s) => s,
+        Err(resp) => {
+            state.operational_metrics.inc_errors();
+            return resp;
+        }
+    };
+    let filter = match parse_optional_filter(req.filter.as_ref(), &state.onboarding_metrics) {
+        Ok(f) => f,
+        Err(resp) => {
+            state.operational_metrics.inc_errors();
+            return resp;
+        }
+    };
+
+    // Gate the read (CORE-2) like `/search/multi`: the recommend kernel has
+    // no channel to apply a scope narrowing, so anything but a plain allow
+    // refuses the query (fail closed).
+    match state.db.authorize_read_with_context(
+        &name,
+        velesdb_core::observer::QueryOperationKind::VectorSearch,
+        ctx.as_deref(),
+    ) {
+        Ok(None) => {}
+        Ok(Some(_)) | Err(_) => {
+            state.operational_metrics.inc_errors();
+            return error_response(
+                StatusCode::FORBIDDEN,
+                "Read denied by governance policy".to_string(),
+            );
+        }
+    }
+
+    let start = std::time::Instant::now();
+    let rendering = HitRendering::new(req.with_vector, req.with_payload)
+        .with_vector_precision(req.vector_precision)
+        .with_format(WireFormat::from_headers(&headers));
+    let collection_for_work = collection.clone();
+
+    let work_result = run_blocking_search(move || {
+        Ok(collection_for_work.recommend_with_strategy(
+            &req.positive,
+            &req.negative,
+            req.top_k,
+            filter.as_ref(),
+            strategy,
+        ))
+    })
+    .await;
+
+    let results = match work_result {
+        Ok(Ok(results)) => results,
+        // Unknown example ids and empty `positive` are client errors: map them
+        // by variant (404 / 400) instead of the generic search error status.
+        Ok(Err(e)) => {
+            state.operational_metrics.inc_errors();
+            return auto_core_error_response(&e);
+        }
+        Err(resp) => {
+            state.operational_metrics.inc_errors();
+            return resp;
+        }
+    };
+
+    finish_search_with_cb(&state, &name, start, &collection, rendering, Ok(results))
+}
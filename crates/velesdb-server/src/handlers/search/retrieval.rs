@@ -0,0 +1,250 @@
+//! OpenAI-compatible embeddings proxy and text-in retrieval endpoint.
+//!
+//! Both call the `[embedder]` service, so LangChain / LlamaIndex retrievers
+//! and OpenAI embedding clients can point at the server unchanged
+//! (`base_url = http://host:8080/v1`).
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Extension, Json};
+use base64::Engine;
+use std::sync::Arc;
+use velesdb_core::RequestContext;
+
+use crate::embedder::{Embedder, EmbedderError};
+use crate::types::{
+    EmbeddingData, EmbeddingsRequest, EmbeddingsResponse, EmbeddingsUsage, ErrorResponse,
+    RetrievalRequest, RetrievalResponse, RetrievedDocument,
+};
+use crate::AppState;
+
+use super::pipeline::{
+    finish_search_core, parse_optional_filter, record_circuit_breaker, validate_query_dimension,
+};
+use super::search_preamble;
+use super::workers::run_blocking_search;
+use crate::handlers::helpers::{apply_pre_check, error_response, extract_client_id};
+use crate::handlers::request_limits::{check_batch_queries, check_top_k};
+
+/// The configured embedder, or a 501 naming the missing `[embedder]` section.
+#[allow(clippy::result_large_err)]
+fn configured_embedder(state: &AppState) -> Result<Arc<Embedder>, axum::response::Response> {
+    state.embedder.read().clone().ok_or_else(|| {
+        error_response(
+            StatusCode::NOT_IMPLEMENTED,
+            "No embedder configured: set [embedder] url and model in velesdb.toml".to_string(),
+        )
+    })
+}
+
+/// 502 for a failed upstream embeddings call; the client may retry.
+fn upstream_error(e: &EmbedderError) -> axum::response::Response {
+    (
+        StatusCode::BAD_GATEWAY,
+        Json(ErrorResponse {
+            error: e.to_string(),
+            code: None,
+            retryable: Some(true),
+        }),
+    )
+        .into_response()
+}
+
+/// Splits a hit's payload into the document text and the remaining metadata.
+fn to_document(result: velesdb_core::SearchResult, text_field: &str) -> RetrievedDocument {
+    let mut metadata = match result.point.payload {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    let text = match metadata.remove(text_field) {
+        Some(serde_json::Value::String(text)) => text,
+        Some(serde_json::Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    };
+    RetrievedDocument {
+        id: result.point.id,
+        score: result.score,
+        text,
+        metadata: serde_json::Value::Object(metadata),
+    }
+}
+
+/// Embed text with the configured `[embedder]` (OpenAI-compatible).
+///
+/// `model` is accepted and ignored: the configured model is always used and
+/// reported back. Token usage is not forwarded and reads 0.
+#[utoipa::path(
+    post,
+    path = "/embeddings",
+    tag = "search",
+    request_body = EmbeddingsRequest,
+    responses(
+        (status = 200, description = "One embedding per input, in input order", body = EmbeddingsResponse),
+        (status = 400, description = "Empty input or unknown encoding_format", body = ErrorResponse),
+        (status = 413, description = "More inputs than `limits.max_batch_queries` (VELES-027)", body = ErrorResponse),
+        (status = 501, description = "No `[embedder]` configured", body = ErrorResponse),
+        (status = 502, description = "Embedder service failed", body = ErrorResponse)
+    )
+)]
+pub async fn create_embeddings(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<EmbeddingsRequest>,
+) -> impl IntoResponse {
+    let embedder = match configured_embedder(&state) {
+        Ok(embedder) => embedder,
+        Err(resp) => return resp,
+    };
+    let base64 = match req.encoding_format.as_deref() {
+        None | Some("float") => false,
+        Some("base64") => true,
+        Some(other) => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid encoding_format: {other}. Valid: float, base64"),
+            );
+        }
+    };
+    let texts = req.input.into_texts();
+    if texts.is_empty() {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "input must not be empty".to_string(),
+        );
+    }
+    if let Err(resp) = check_batch_queries(&state, texts.len()) {
+        return resp;
+    }
+
+    let embeddings = match embedder.embed(&texts).await {
+        Ok(embeddings) => embeddings,
+        Err(e) => return upstream_error(&e),
+    };
+    let data = embeddings
+        .into_iter()
+        .enumerate()
+        .map(|(index, embedding)| EmbeddingData {
+            object: "embedding",
+            index,
+            embedding: if base64 {
+                let bytes: Vec<u8> = embedding.iter().flat_map(|x| x.to_le_bytes()).collect();
+                serde_json::Value::String(base64::engine::general_purpose::STANDARD.encode(bytes))
+            } else {
+                serde_json::json!(embedding)
+            },
+        })
+        .collect();
+    Json(EmbeddingsResponse {
+        object: "list",
+        data,
+        model: embedder.model().to_string(),
+        usage: EmbeddingsUsage::default(),
+    })
+    .into_response()
+}
+
+/// Retrieve documents for a text query: embeds `query` with the configured
+/// `[embedder]`, runs a dense search on `collection` and returns each hit's
+/// `text_field` as the document text and the rest of its payload as metadata.
+#[utoipa::path(
+    post,
+    path = "/retrieval",
+    tag = "search",
+    request_body = RetrievalRequest,
+    responses(
+        (status = 200, description = "Documents, best match first", body = RetrievalResponse),
+        (status = 400, description = "Invalid filter or embedding dimension mismatch", body = ErrorResponse),
+        (status = 403, description = "Read denied by governance policy", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 422, description = "`top_k` over `limits.max_top_k` (VELES-027)", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded (VELES-027)", body = ErrorResponse),
+        (status = 501, description = "No `[embedder]` configured", body = ErrorResponse),
+        (status = 502, description = "Embedder service failed", body = ErrorResponse),
+        (status = 503, description = "Circuit breaker open (VELES-027)", body = ErrorResponse)
+    )
+)]
+pub async fn retrieval(
+    State(state): State<Arc<AppState>>,
+    ctx: Option<Extension<RequestContext>>,
+    headers: axum::http::HeaderMap,
+    Json(req): Json<RetrievalRequest>,
+) -> impl IntoResponse {
+    if let Err(resp) = check_top_k(&state, req.top_k) {
+        return resp;
+    }
+    let embedder = match configured_embedder(&state) {
+        Ok(embedder) => embedder,
+        Err(resp) => return resp,
+    };
+    let collection = match search_preamble(&state, &req.collection) {
+        Ok(c) => c,
+        Err(resp) => return resp,
+    };
+    state.operational_metrics.record_vector_query();
+
+    let client_id = extract_client_id(&headers);
+    if let Err(resp) = apply_pre_check(collection.guard_rails(), &client_id) {
+        state.operational_metrics.inc_rate_limited();
+        return resp;
+    }
+
+    let vector = match embedder.embed(std::slice::from_ref(&req.query)).await {
+        Ok(mut embeddings) => embeddings.pop().unwrap_or_default(),
+        Err(e) => {
+            state.operational_metrics.inc_errors();
+            return upstream_error(&e);
+        }
+    };
+    let expected_dimension = collection.config().dimension;
+    if let Err(error) =
+        validate_query_dimension(&state, &req.collection, expected_dimension, &vector)
+    {
+        state.operational_metrics.inc_errors();
+        return (StatusCode::BAD_REQUEST, Json(error)).into_response();
+    }
+
+    let start = std::time::Instant::now();
+    let RetrievalRequest {
+        collection: name,
+        top_k,
+        filter,
+        text_field,
+        ..
+    } = req;
+    let name_for_work = name.clone();
+    let state_for_work = Arc::clone(&state);
+    let work_result = run_blocking_search(move || {
+        let filter = parse_optional_filter(filter.as_ref(), &state_for_work.onboarding_metrics)?;
+        let read = velesdb_core::GatedRead::Dense {
+            query: &vector,
+            k: top_k,
+            ef: None,
+            quality: None,
+            filter: filter.as_ref(),
+        };
+        Ok(state_for_work
+            .db
+            .gated_search_with_context(&name_for_work, ctx.as_deref(), read))
+    })
+    .await;
+
+    let search_result = match work_result {
+        Ok(inner) => inner,
+        Err(resp) => {
+            state.operational_metrics.inc_errors();
+            return resp;
+        }
+    };
+    record_circuit_breaker(&collection, &search_result);
+    finish_search_core(
+        &state,
+        &name,
+        start,
+        StatusCode::BAD_REQUEST,
+        search_result,
+        |results| {
+            let documents = results
+                .into_iter()
+                .map(|result| to_document(result, &text_field))
+                .collect();
+            Json(RetrievalResponse { documents }).into_response()
+        },
+    )
+}
@@ -81,6 +81,7 @@ fn unwrap_join(join_result: Result<SearchOutcome, tokio::task::JoinError>) -> Se
             Json(ErrorResponse {
                 error: format!("Search worker task failed: {join_err}"),
                 code: Some("VELES-INTERNAL-WORKER-FAILURE".to_string()),
+                retryable: None,
             }),
         )
             .into_response()),
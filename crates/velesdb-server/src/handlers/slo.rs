@@ -0,0 +1,137 @@
+//! Latency SLO handlers.
+//!
+//! An SLO such as "p99 search latency under 20 ms" is tracked per collection
+//! over a sliding window. Sustained breaches and recoveries are logged and,
+//! with `[slo] webhook_url` configured, POSTed to that URL.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+use velesdb_core::{LatencySlo, SloState, SloStatus};
+
+use crate::types::{ErrorResponse, SetLatencySloRequest, SloStatusResponse};
+use crate::AppState;
+
+use super::helpers::{auto_core_error_response, error_response};
+
+/// Get a collection's latency SLO and its current status.
+#[utoipa::path(
+    get,
+    path = "/collections/{name}/slo",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    responses(
+        (status = 200, description = "SLO status", body = SloStatusResponse),
+        (status = 404, description = "Collection has no SLO", body = ErrorResponse)
+    )
+)]
+pub async fn get_latency_slo(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    match state.db.latency_slo_status(&name) {
+        Some(status) => Json(slo_status_to_response(name, status)).into_response(),
+        None => slo_not_found(&name),
+    }
+}
+
+/// Set (or replace) a collection's latency SLO.
+///
+/// Replacing an SLO restarts its window.
+#[utoipa::path(
+    put,
+    path = "/collections/{name}/slo",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    request_body = SetLatencySloRequest,
+    responses(
+        (status = 200, description = "SLO set", body = SloStatusResponse),
+        (status = 400, description = "Invalid SLO", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse)
+    )
+)]
+pub async fn set_latency_slo(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Json(req): Json<SetLatencySloRequest>,
+) -> impl IntoResponse {
+    let defaults = LatencySlo::new(req.percentile, req.threshold_ms);
+    let slo = LatencySlo {
+        window_secs: req.window_secs.unwrap_or(defaults.window_secs),
+        sustain_secs: req.sustain_secs.unwrap_or(defaults.sustain_secs),
+        min_samples: req.min_samples.unwrap_or(defaults.min_samples),
+        ..defaults
+    };
+    if let Err(e) = state.db.set_latency_slo(&name, slo) {
+        return auto_core_error_response(&e);
+    }
+    match state.db.latency_slo_status(&name) {
+        Some(status) => Json(slo_status_to_response(name, status)).into_response(),
+        // Collection deleted concurrently.
+        None => slo_not_found(&name),
+    }
+}
+
+/// Remove a collection's latency SLO.
+#[utoipa::path(
+    delete,
+    path = "/collections/{name}/slo",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "Collection name")
+    ),
+    responses(
+        (status = 200, description = "SLO removed", body = Object),
+        (status = 404, description = "Collection has no SLO", body = ErrorResponse)
+    )
+)]
+pub async fn delete_latency_slo(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    match state.db.remove_latency_slo(&name) {
+        Ok(true) => Json(serde_json::json!({
+            "message": "Latency SLO removed",
+            "collection": name
+        }))
+        .into_response(),
+        Ok(false) => slo_not_found(&name),
+        Err(e) => auto_core_error_response(&e),
+    }
+}
+
+/// Converts a core [`SloStatus`] into its API representation.
+pub(crate) fn slo_status_to_response(collection: String, status: SloStatus) -> SloStatusResponse {
+    let state = match status.state {
+        SloState::Ok => "ok",
+        SloState::Violating => "violating",
+        SloState::Breached => "breached",
+    };
+    SloStatusResponse {
+        collection,
+        percentile: status.slo.percentile,
+        threshold_ms: status.slo.threshold_ms,
+        window_secs: status.slo.window_secs,
+        sustain_secs: status.slo.sustain_secs,
+        min_samples: status.slo.min_samples,
+        state: state.to_string(),
+        observed_ms: status.observed_ms,
+        samples: status.samples,
+        violating_for_secs: status.violating_for_secs,
+    }
+}
+
+fn slo_not_found(name: &str) -> axum::response::Response {
+    error_response(
+        StatusCode::NOT_FOUND,
+        format!("Collection '{name}' has no latency SLO"),
+    )
+}
@@ -0,0 +1,128 @@
+//! Filtered view handlers (virtual collections over a base collection).
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+use crate::types::{CreateViewRequest, ErrorResponse, ViewResponse};
+use crate::AppState;
+
+use super::helpers::{auto_core_error_response, core_error_response};
+
+/// List all views.
+#[utoipa::path(
+    get,
+    path = "/views",
+    tag = "collections",
+    responses(
+        (status = 200, description = "Views, sorted by name", body = Vec<ViewResponse>)
+    )
+)]
+pub async fn list_views(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let views: Vec<ViewResponse> = state
+        .db
+        .list_views()
+        .into_iter()
+        .map(view_to_response)
+        .collect();
+    Json(views)
+}
+
+/// Create a view: a name that reads `base` filtered by a `VelesQL` predicate.
+///
+/// The view name can then be used in `VelesQL` `FROM` clauses and with the
+/// search endpoints.
+#[utoipa::path(
+    post,
+    path = "/views",
+    tag = "collections",
+    request_body = CreateViewRequest,
+    responses(
+        (status = 201, description = "View created", body = ViewResponse),
+        (status = 400, description = "Invalid view name or filter", body = ErrorResponse),
+        (status = 404, description = "Base collection not found", body = ErrorResponse),
+        (status = 409, description = "A collection or view with this name exists", body = ErrorResponse)
+    )
+)]
+pub async fn create_view(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<CreateViewRequest>,
+) -> impl IntoResponse {
+    if let Err(e) = state.db.create_view(&req.name, &req.base, &req.filter) {
+        return auto_core_error_response(&e);
+    }
+    match state.db.get_view(&req.name) {
+        Some(view) => (StatusCode::CREATED, Json(view_to_response(view))).into_response(),
+        // Dropped concurrently right after creation.
+        None => view_not_found(&req.name),
+    }
+}
+
+/// Get a view definition.
+#[utoipa::path(
+    get,
+    path = "/views/{name}",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "View name")
+    ),
+    responses(
+        (status = 200, description = "View definition", body = ViewResponse),
+        (status = 404, description = "View not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_view(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    match state.db.get_view(&name) {
+        Some(view) => Json(view_to_response(view)).into_response(),
+        None => view_not_found(&name),
+    }
+}
+
+/// Drop a view. The base collection is untouched.
+#[utoipa::path(
+    delete,
+    path = "/views/{name}",
+    tag = "collections",
+    params(
+        ("name" = String, Path, description = "View name")
+    ),
+    responses(
+        (status = 200, description = "View dropped", body = Object),
+        (status = 404, description = "View not found", body = ErrorResponse)
+    )
+)]
+pub async fn delete_view(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    match state.db.drop_view(&name) {
+        Ok(()) => Json(serde_json::json!({
+            "message": "View dropped",
+            "name": name
+        }))
+        .into_response(),
+        Err(e) => auto_core_error_response(&e),
+    }
+}
+
+fn view_not_found(name: &str) -> axum::response::Response {
+    core_error_response(
+        StatusCode::NOT_FOUND,
+        &velesdb_core::Error::CollectionNotFound(name.to_string()),
+    )
+}
+
+fn view_to_response(view: velesdb_core::CollectionView) -> ViewResponse {
+    ViewResponse {
+        name: view.name,
+        base: view.base,
+        filter: view.filter,
+    }
+}
@@ -0,0 +1,165 @@
+//! `Idempotency-Key` support for write endpoints.
+//!
+//! A client retrying a write after a timeout sends the same
+//! `Idempotency-Key` header with the same body. [`idempotency_middleware`]
+//! claims the key on the target collection before the write runs and records
+//! its successful JSON response; a replay returns that response with an
+//! `idempotent-replayed: true` header instead of writing again. The de-dup
+//! window and its persistence live in the collection (see
+//! [`velesdb_core::AnyCollection::claim_idempotency_key`]).
+//!
+//! Covered endpoints: point upserts (`/points`, `/points/raw`), point deletes
+//! (`/points/delete`, `DELETE /points/{id}`) and `POST /query` with an
+//! INSERT/UPSERT/UPDATE/DELETE statement. Other requests pass through, with
+//! or without the header.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use velesdb_core::{IdempotencyOutcome, IdempotentResponse};
+
+use crate::handlers::helpers::{auto_core_error_response, error_response};
+use crate::types::QueryRequest;
+use crate::AppState;
+
+/// Request header carrying the client-chosen idempotency key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Response header set to `true` on a replayed response.
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Largest request or response body buffered for fingerprinting/recording
+/// (matches the batch upload body limit).
+const MAX_BUFFERED_BYTES: usize = 100 * 1024 * 1024;
+
+/// De-duplicates write requests that carry an [`IDEMPOTENCY_KEY_HEADER`].
+///
+/// - first use of a key: runs the request; a 2xx JSON response is recorded
+/// - same key, same method, path and body: replays the recorded response
+/// - same key while the first request is still running: `409 Conflict`
+/// - same key with a different request: `422 Unprocessable Entity`
+///
+/// Failed requests are not recorded, so their retry runs again.
+pub async fn idempotency_middleware(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    let Ok(key) = key.to_str().map(str::to_owned) else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "Idempotency-Key must be printable ASCII".to_string(),
+        );
+    };
+
+    let (parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, MAX_BUFFERED_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return error_response(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!("Failed to read request body: {e}"),
+            )
+        }
+    };
+    let path = parts.uri.path();
+    let path = path.strip_prefix("/v1").unwrap_or(path).to_string();
+    let collection = write_target(&parts.method, &path, &body)
+        .and_then(|name| state.db.get_any_collection(&name));
+    let Some(collection) = collection else {
+        return next.run(Request::from_parts(parts, Body::from(body))).await;
+    };
+
+    // `/v1` and legacy paths are the same request.
+    let mut fingerprint = format!("{} {path}?", parts.method).into_bytes();
+    fingerprint.extend_from_slice(parts.uri.query().unwrap_or_default().as_bytes());
+    fingerprint.push(b'\n');
+    fingerprint.extend_from_slice(&body);
+
+    let claim = match collection.claim_idempotency_key(&key, &fingerprint) {
+        Ok(IdempotencyOutcome::Claimed(claim)) => claim,
+        Ok(IdempotencyOutcome::Replay(recorded)) => return replay(recorded),
+        Ok(IdempotencyOutcome::InFlight) => {
+            return error_response(
+                StatusCode::CONFLICT,
+                format!("A request with Idempotency-Key '{key}' is still in progress"),
+            )
+        }
+        Ok(IdempotencyOutcome::KeyReused) => {
+            return error_response(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("Idempotency-Key '{key}' was already used for a different request"),
+            )
+        }
+        Err(e) => return auto_core_error_response(&e),
+    };
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if !response.status().is_success() {
+        return response;
+    }
+    let (parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, MAX_BUFFERED_BYTES).await else {
+        return error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to buffer response body".to_string(),
+        );
+    };
+    if let Ok(json) = serde_json::from_slice(&bytes) {
+        let recorded = IdempotentResponse {
+            status: parts.status.as_u16(),
+            body: json,
+        };
+        // Recording fsyncs the log — keep it off the async runtime.
+        match tokio::task::spawn_blocking(move || claim.complete(recorded)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => tracing::warn!("Failed to record idempotent response: {e}"),
+            Err(e) => tracing::warn!("Idempotent response recording panicked: {e}"),
+        }
+    }
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Collection written by a request covered by idempotency keys.
+fn write_target(method: &Method, path: &str, body: &[u8]) -> Option<String> {
+    if *method == Method::POST && path == "/query" {
+        let request: QueryRequest = serde_json::from_slice(body).ok()?;
+        if request.dry_run {
+            return None;
+        }
+        let parsed = velesdb_core::velesql::Parser::parse(&request.query).ok()?;
+        return match parsed.dml? {
+            velesdb_core::velesql::DmlStatement::Insert(s)
+            | velesdb_core::velesql::DmlStatement::Upsert(s) => Some(s.table),
+            velesdb_core::velesql::DmlStatement::Update(s) => Some(s.table),
+            velesdb_core::velesql::DmlStatement::Delete(s) => Some(s.table),
+            _ => None,
+        };
+    }
+    let segments: Vec<&str> = path.strip_prefix("/collections/")?.split('/').collect();
+    match (method, segments.as_slice()) {
+        (&Method::POST, [name, "points"] | [name, "points", "raw" | "delete"])
+        | (&Method::DELETE, [name, "points", _]) => Some((*name).to_string()),
+        _ => None,
+    }
+}
+
+fn replay(recorded: IdempotentResponse) -> Response {
+    let status = StatusCode::from_u16(recorded.status).unwrap_or(StatusCode::OK);
+    let mut response = (status, Json(recorded.body)).into_response();
+    response.headers_mut().insert(
+        IDEMPOTENT_REPLAYED_HEADER,
+        axum::http::HeaderValue::from_static("true"),
+    );
+    response
+}
@@ -0,0 +1,95 @@
+//! Index build progress streamed by `GET /collections/{name}/events`.
+//!
+//! Each collection gets one broadcast channel, created by its first
+//! subscriber and fed by the collection's `on_progress` callback. Events are
+//! not replayed: a subscriber sees builds from the moment it connects, and
+//! one that falls behind skips the events it missed.
+
+use std::collections::HashMap;
+
+use futures::stream::{self, Stream};
+use parking_lot::Mutex;
+use tokio::sync::broadcast::{self, error::RecvError};
+use velesdb_core::VectorCollection;
+
+use crate::types::IndexProgressEvent;
+
+/// Events buffered per collection before slow subscribers start skipping.
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Progress channels by collection name.
+#[derive(Default)]
+pub struct IndexEventHub {
+    channels: Mutex<HashMap<String, broadcast::Sender<IndexProgressEvent>>>,
+}
+
+impl IndexEventHub {
+    /// Follows the progress events of `collection`, registered as `name`.
+    ///
+    /// Registers the callback feeding the channel on every call, so a
+    /// collection dropped and recreated under the same name is picked up
+    /// again.
+    pub fn subscribe(
+        &self,
+        name: &str,
+        collection: &VectorCollection,
+    ) -> impl Stream<Item = IndexProgressEvent> {
+        let sender = self
+            .channels
+            .lock()
+            .entry(name.to_string())
+            .or_insert_with(|| broadcast::channel(CHANNEL_CAPACITY).0)
+            .clone();
+        let rx = sender.subscribe();
+        collection.on_progress(move |progress| {
+            // No receiver is not an error: nobody is listening right now.
+            let _ = sender.send(progress.into());
+        });
+
+        stream::unfold(rx, |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => return Some((event, rx)),
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use velesdb_core::{DistanceMetric, Point, StorageMode};
+
+    #[tokio::test]
+    async fn test_subscribers_receive_bulk_upsert_progress() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let collection = VectorCollection::create(
+            dir.path().join("docs"),
+            "docs",
+            4,
+            DistanceMetric::Cosine,
+            StorageMode::Full,
+        )
+        .expect("create collection");
+        let hub = IndexEventHub::default();
+        let first = hub.subscribe("docs", &collection);
+        let second = hub.subscribe("docs", &collection);
+
+        let points: Vec<Point> = (0..50)
+            .map(|i| Point::without_payload(i, vec![1.0, 0.5, 0.25, i as f32]))
+            .collect();
+        collection.upsert_bulk(&points).expect("upsert");
+
+        for events in [first, second] {
+            let events: Vec<_> = events.take(2).collect().await;
+            assert_eq!(events[0].phase, "storing_vectors");
+            assert_eq!(events[0].percent, 0);
+            assert_eq!(events[1].phase, "storing_vectors");
+            assert_eq!((events[1].processed, events[1].total), (50, 50));
+        }
+    }
+}
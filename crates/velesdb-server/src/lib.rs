@@ -34,14 +34,25 @@
 //! - OpenAPI JSON: `GET /api-docs/openapi.json`
 
 pub mod auth;
+pub mod compression;
 pub mod config;
+pub mod embedded;
+pub mod embedder;
+pub mod fsck;
 mod handlers;
+pub mod idempotency;
+pub mod index_events;
 pub mod onboarding;
 pub mod rate_limit;
+pub mod reindex;
+pub mod reload;
 pub mod routes;
 mod security_addon;
+pub mod slo_webhook;
 pub mod tls;
+pub mod traversal_cursors;
 mod types;
+pub mod wire;
 
 use security_addon::SecurityAddon;
 use std::sync::atomic::AtomicBool;
@@ -51,29 +62,36 @@ use velesdb_core::{
     Database, DurationHistogram, OperationalMetrics, QueryLimits, TraversalMetrics,
 };
 
+pub use embedded::{build_router, build_router_with_state, RouterOptions};
 pub use onboarding::OnboardingMetrics;
 pub use types::*;
 
 pub use handlers::{
     aggregate, analyze_collection, batch_search, bulk_delete_points, collection_diagnostics,
-    collection_sanity, compact_collection, create_collection, create_index, delete_collection,
-    delete_index, delete_point, enable_streaming, explain, flush_collection, get_collection,
-    get_collection_config, get_collection_stats, get_guardrails, get_point, get_point_relations,
-    health_check, hybrid_search, is_empty, list_collections, list_indexes, match_query,
-    multi_query_search, multi_query_search_ids, query, readiness_check, rebuild_index,
-    relate_points, reorder_for_locality, scroll_points, search, search_ids, set_point_ttl,
-    stream_insert, stream_upsert_points, text_search, unrelate_points, update_guardrails,
+    collection_events, collection_sanity, compact_collection, count_points, create_collection,
+    create_embeddings, create_index, create_view, delete_collection, delete_index,
+    delete_latency_slo, delete_point, delete_row_security, delete_view, enable_streaming, explain,
+    flush_collection, get_access_stats, get_collection, get_collection_config,
+    get_collection_stats, get_guardrails, get_latency_slo, get_point, get_point_relations,
+    get_points, get_row_security, get_server_config, get_vector_stats, get_view, health_check,
+    health_details, hybrid_search, is_empty, list_collections, list_indexes, list_views,
+    match_query, multi_query_search, multi_query_search_ids, project_collection, query,
+    readiness_check, rebuild_index, recommend, reindex_status, relate_points, reload_config,
+    reorder_for_locality, retrieval, scroll_points, search, search_ids, set_embedding_info,
+    set_latency_slo, set_point_ttl, set_row_security, start_reindex, stream_insert,
+    stream_upsert_points, subscribe_collection, text_search, unrelate_points, update_guardrails,
     upsert_points, upsert_points_raw, vacuum_collection,
 };
 
 pub use handlers::graph::{
-    add_edge, add_edges_batch, get_edge_count, get_edges, get_node_degree, get_node_edges,
-    get_node_payload, graph_search, list_nodes, remove_edge, stream_traverse, traverse_graph,
-    traverse_parallel, upsert_node_payload, DegreeResponse, EdgeCountResponse, GraphSearchRequest,
-    GraphSearchResponse, NodeEdgeQueryParams, NodeListResponse, NodePayloadResponse,
-    ParallelTraverseRequest, StreamDoneEvent, StreamNodeEvent, StreamStatsEvent,
-    StreamTraverseParams, TraversalResultItem, TraversalStats, TraverseRequest, TraverseResponse,
-    UpsertNodePayloadRequest,
+    add_edge, add_edges_batch, export_graph, get_edge_count, get_edges, get_graph_stats,
+    get_node_degree, get_node_edges, get_node_payload, graph_search, import_graph, list_nodes,
+    remove_edge, stream_traverse, traverse_graph, traverse_parallel, upsert_node_payload,
+    DegreeResponse, EdgeCountResponse, GraphFormatParams, GraphImportResponse, GraphSearchRequest,
+    GraphSearchResponse, GraphStatsResponse, NodeEdgeQueryParams, NodeListResponse,
+    NodePayloadResponse, ParallelTraverseRequest, StreamDoneEvent, StreamNodeEvent,
+    StreamStatsEvent, StreamTraverseParams, TraversalResultItem, TraversalStats, TraverseRequest,
+    TraverseResponse, UpsertNodePayloadRequest,
 };
 
 #[cfg(feature = "prometheus")]
@@ -116,10 +134,12 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
         (name = "indexes", description = "Property index management (EPIC-009)"),
         (name = "graph", description = "Graph traversal and edge operations"),
         (name = "guardrails", description = "Query guard-rails configuration (EPIC-048)"),
+        (name = "config", description = "Effective server configuration"),
         (name = "metrics", description = "Prometheus operational metrics")
     ),
     paths(
         handlers::health::health_check,
+        handlers::health::health_details,
         handlers::health::readiness_check,
         handlers::collections::list_collections,
         handlers::collections::create_collection,
@@ -128,17 +148,34 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
         handlers::collections::collection_sanity,
         handlers::collections::is_empty,
         handlers::collections::flush_collection,
+        handlers::views::list_views,
+        handlers::views::create_view,
+        handlers::views::get_view,
+        handlers::views::delete_view,
+        handlers::row_security::get_row_security,
+        handlers::row_security::set_row_security,
+        handlers::row_security::delete_row_security,
+        handlers::slo::get_latency_slo,
+        handlers::slo::set_latency_slo,
+        handlers::slo::delete_latency_slo,
         handlers::admin::analyze_collection,
         handlers::admin::get_collection_stats,
+        handlers::admin::get_vector_stats,
+        handlers::admin::get_access_stats,
+        handlers::projection::project_collection,
         handlers::admin::collection_diagnostics,
         handlers::admin::get_guardrails,
         handlers::admin::update_guardrails,
+        handlers::admin::get_server_config,
+        handlers::admin::reload_config,
         handlers::points::upsert_points,
         handlers::points::raw::upsert_points_raw,
         handlers::points::stream_upsert_points,
         handlers::points::stream_insert,
         handlers::points::enable_streaming,
         handlers::points::get_point,
+        handlers::points::batch_get::get_points,
+        handlers::points::count::count_points,
         handlers::points::delete_point,
         handlers::points::scroll_points,
         handlers::search::search,
@@ -148,7 +185,11 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
         handlers::search::text_search,
         handlers::search::hybrid_search,
         handlers::search::search_ids,
+        handlers::search::recommend,
+        handlers::search::create_embeddings,
+        handlers::search::retrieval,
         handlers::admin::get_collection_config,
+        handlers::embedding::set_embedding_info,
         handlers::query::query,
         handlers::query::aggregate,
         handlers::query::explain,
@@ -160,6 +201,7 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
         handlers::graph::handlers::add_edges_batch,
         handlers::graph::handlers_extended::remove_edge,
         handlers::graph::handlers_extended::get_edge_count,
+        handlers::graph::handlers_extended::get_graph_stats,
         handlers::graph::handlers_extended::list_nodes,
         handlers::graph::handlers_extended::get_node_edges,
         handlers::graph::handlers_extended::get_node_payload,
@@ -169,11 +211,17 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
         handlers::graph::handlers::get_node_degree,
         handlers::graph::handlers_extended::graph_search,
         handlers::graph::stream::stream_traverse,
+        handlers::graph::interchange::export_graph,
+        handlers::graph::interchange::import_graph,
         handlers::match_query::match_query,
         handlers::admin::rebuild_index,
         handlers::admin::vacuum_collection,
         handlers::admin::compact_collection,
         handlers::admin::reorder_for_locality,
+        handlers::reindex::start_reindex,
+        handlers::reindex::reindex_status,
+        handlers::events::collection_events,
+        handlers::events::subscribe_collection,
         handlers::points::bulk_delete_points,
         handlers::points::relations::relate_points,
         handlers::points::relations::unrelate_points,
@@ -193,16 +241,30 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
             TextSearchRequest,
             HybridSearchRequest,
             MultiQuerySearchRequest,
+            RecommendRequest,
             SearchResponse,
             BatchSearchResponse,
             SearchResultResponse,
+            RerankPreviewRequest,
+            RerankCandidate,
+            EmbeddingsInput,
+            EmbeddingsRequest,
+            EmbeddingData,
+            EmbeddingsUsage,
+            EmbeddingsResponse,
+            RetrievalRequest,
+            RetrievedDocument,
+            RetrievalResponse,
             SearchIdsResponse,
             IdScoreResult,
             CollectionConfigResponse,
+            SetEmbeddingInfoRequest,
             ErrorResponse,
             QueryRequest,
             QueryResponse,
             QueryResponseMeta,
+            QueryDryRunResponse,
+            QueryResult,
             AggregationResponse,
             QueryErrorResponse,
             QueryErrorDetail,
@@ -221,11 +283,48 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
             CollectionStatsResponse,
             ColumnStatsResponse,
             IndexStatsResponse,
+            VectorStatsResponse,
+            AccessStatsResponse,
+            CreateViewRequest,
+            ViewResponse,
+            SetRowSecurityRequest,
+            RowSecurityResponse,
+            SetLatencySloRequest,
+            SloStatusResponse,
+            PointAccessCountResponse,
+            PayloadCacheStatsResponse,
+            Projection2dResponse,
+            ProjectedPointResponse,
             ScrollRequest,
             ScrollResponse,
             ScrollPoint,
+            CountPointsRequest,
+            CountPointsResponse,
+            GetPointsRequest,
+            GetPointsResponse,
+            RetrievedPoint,
             GuardRailsConfigRequest,
             GuardRailsConfigResponse,
+            EffectiveConfigResponse,
+            ServerSettingsResponse,
+            AuthSettingsResponse,
+            TlsSettingsResponse,
+            CorsSettingsResponse,
+            CompressionSettingsResponse,
+            ConfigReloadResponse,
+            ReindexRequest,
+            ReindexAcceptedResponse,
+            ReindexStatusEvent,
+            IndexProgressEvent,
+            PointChangeEvent,
+            HealthStatus,
+            HealthResponse,
+            HealthComponents,
+            CollectionsHealth,
+            DiskHealth,
+            WalHealth,
+            BackgroundJobsHealth,
+            CollectionHealthEntry,
             CollectionDiagnosticsResponse,
             handlers::graph::TraverseRequest,
             handlers::graph::TraverseResponse,
@@ -248,6 +347,11 @@ pub use handlers::metrics::{health_metrics, prometheus_metrics};
             handlers::graph::StreamNodeEvent,
             handlers::graph::StreamStatsEvent,
             handlers::graph::StreamDoneEvent,
+            handlers::graph::StreamErrorEvent,
+            handlers::graph::GraphImportResponse,
+            handlers::graph::GraphStatsResponse,
+            handlers::graph::DegreeBucketResponse,
+            handlers::graph::HubNodeResponse,
             handlers::match_query::MatchQueryRequest,
             handlers::match_query::MatchQueryResponse,
             handlers::match_query::MatchQueryResultItem,
@@ -297,14 +401,26 @@ pub struct AppState {
     pub onboarding_metrics: onboarding::OnboardingMetrics,
     /// Query guard-rails configuration (EPIC-048).
     pub query_limits: parking_lot::RwLock<QueryLimits>,
+    /// Configuration in force, for `GET /config` (updated by reloads).
+    pub effective_config: parking_lot::RwLock<config::EffectiveConfig>,
+    /// Applies `velesdb.toml` changes live; `None` disables reloading.
+    pub config_reloader: Option<Arc<reload::ConfigReloader>>,
+    /// Background reindex jobs (`POST /collections/{name}/reindex`).
+    pub reindex_jobs: reindex::ReindexJobs,
+    /// Index build progress channels (`GET /collections/{name}/events`).
+    pub index_events: index_events::IndexEventHub,
     /// Readiness flag — `true` once the database is fully loaded.
     pub ready: AtomicBool,
     /// Operational metrics: query throughput, connections, doc counts (EPIC-050).
     pub operational_metrics: Arc<OperationalMetrics>,
     /// Graph traversal metrics: nodes visited, depth, edges scanned.
     pub traversal_metrics: Arc<TraversalMetrics>,
+    /// Parked BFS cursors for paged `POST /collections/{name}/graph/traverse`.
+    pub traversal_cursors: traversal_cursors::TraversalCursors,
     /// Query duration histogram for Prometheus export.
     pub query_duration_histogram: Arc<DurationHistogram>,
+    /// Embeddings service behind `/embeddings` and `/retrieval` (`[embedder]`).
+    pub embedder: parking_lot::RwLock<Option<Arc<embedder::Embedder>>>,
 }
 
 // ============================================================================
@@ -613,6 +729,12 @@ mod tests {
             serde_json::from_str(json).expect("test: valid UpsertPointsRequest JSON");
         assert_eq!(req.points.len(), 1);
         assert_eq!(req.points[0].id, 1);
+        assert!(!req.wait_for_index);
+
+        let json = r#"{"points": [], "wait_for_index": true}"#;
+        let req: UpsertPointsRequest =
+            serde_json::from_str(json).expect("test: valid UpsertPointsRequest JSON");
+        assert!(req.wait_for_index);
     }
 
     #[test]
@@ -623,6 +745,7 @@ mod tests {
             metric: "cosine".to_string(),
             storage_mode: "full".to_string(),
             point_count: 100,
+            query_defaults: None,
         };
         let json = serde_json::to_string(&resp).expect("test: serialize CollectionResponse");
         assert!(json.contains("\"name\":\"test\""));
@@ -630,6 +753,7 @@ mod tests {
         assert!(json.contains("\"metric\":\"cosine\""));
         assert!(json.contains("\"storage_mode\":\"full\""));
         assert!(json.contains("\"point_count\":100"));
+        assert!(!json.contains("query_defaults"));
     }
 
     #[test]
@@ -638,8 +762,11 @@ mod tests {
             results: vec![SearchResultResponse {
                 id: 1,
                 score: 0.95,
+                vector: None,
                 payload: None,
+                score_breakdown: None,
             }],
+            candidates: None,
         };
         let json = serde_json::to_string(&resp).expect("test: serialize SearchResponse");
         assert!(json.contains("\"results\""));
@@ -652,6 +779,7 @@ mod tests {
         let resp = ErrorResponse {
             error: "Test error".to_string(),
             code: None,
+            retryable: None,
         };
         let json = serde_json::to_string(&resp).expect("test: serialize ErrorResponse");
         assert!(json.contains("\"error\":\"Test error\""));
@@ -710,10 +838,16 @@ mod tests {
             db,
             onboarding_metrics: OnboardingMetrics::default(),
             query_limits: parking_lot::RwLock::new(QueryLimits::default()),
+            effective_config: parking_lot::RwLock::default(),
+            config_reloader: None,
+            reindex_jobs: crate::reindex::ReindexJobs::default(),
+            index_events: crate::index_events::IndexEventHub::default(),
             ready: AtomicBool::new(true),
             operational_metrics: velesdb_core::metrics::OperationalMetrics::new_arc(),
             traversal_metrics: Arc::new(velesdb_core::metrics::TraversalMetrics::new()),
+            traversal_cursors: crate::traversal_cursors::TraversalCursors::default(),
             query_duration_histogram: Arc::new(velesdb_core::metrics::DurationHistogram::new()),
+            embedder: parking_lot::RwLock::default(),
         });
         (state, dir)
     }
@@ -770,4 +904,176 @@ mod tests {
             failures.join("\n  ")
         );
     }
+
+    // ========================================================================
+    // Router -> OpenAPI completeness (client SDK generation)
+    // ========================================================================
+
+    const HTTP_METHODS: [&str; 5] = ["get", "post", "put", "delete", "patch"];
+
+    /// Extracts every `(path, method)` registered in `routes.rs` by scanning
+    /// its `.route("path", get(..).post(..))` calls. The router exposes no
+    /// route table at runtime, so the source is the only list of them.
+    fn extract_router_operations() -> Vec<(String, String)> {
+        let source = include_str!("routes.rs");
+        let mut ops = Vec::new();
+        for call in source.split(".route(").skip(1) {
+            let Some((path, rest)) = call
+                .trim_start()
+                .strip_prefix('"')
+                .and_then(|s| s.split_once('"'))
+            else {
+                continue;
+            };
+            // The method router ends at the `)` closing `.route(`.
+            let mut depth = 0usize;
+            let end = rest
+                .char_indices()
+                .find(|&(_, c)| match c {
+                    '(' => {
+                        depth += 1;
+                        false
+                    }
+                    ')' if depth == 0 => true,
+                    ')' => {
+                        depth -= 1;
+                        false
+                    }
+                    _ => false,
+                })
+                .map_or(rest.len(), |(i, _)| i);
+            let methods = &rest[..end];
+            for method in HTTP_METHODS {
+                let registered = methods.match_indices(&format!("{method}(")).any(|(i, _)| {
+                    !methods[..i]
+                        .chars()
+                        .next_back()
+                        .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == ':')
+                });
+                if registered {
+                    ops.push((path.to_string(), method.to_string()));
+                }
+            }
+        }
+        ops
+    }
+
+    fn openapi_json() -> serde_json::Value {
+        serde_json::to_value(ApiDoc::openapi()).expect("test: serialize OpenAPI spec")
+    }
+
+    /// Completeness: every route registered on the router is documented, so
+    /// generated clients cover the whole API.
+    #[test]
+    fn test_router_routes_are_documented() {
+        let router_ops = extract_router_operations();
+        assert!(
+            router_ops.len() > 60,
+            "routes.rs scan found only {} routes",
+            router_ops.len()
+        );
+        let spec = openapi_json();
+        let missing: Vec<String> = router_ops
+            .iter()
+            .filter(|(path, _)| path != "/metrics" || cfg!(feature = "prometheus"))
+            .filter(|(path, method)| spec["paths"][path][method].is_null())
+            .map(|(path, method)| format!("{} {path}", method.to_uppercase()))
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "Router routes missing from the OpenAPI spec:\n  {}",
+            missing.join("\n  ")
+        );
+    }
+
+    /// Every `{param}` in a path template is declared as a path parameter,
+    /// and every error response documents its body.
+    #[test]
+    fn test_openapi_operations_declare_params_and_error_bodies() {
+        let spec = openapi_json();
+        let mut problems = Vec::new();
+        for (path, item) in spec["paths"].as_object().expect("test: paths object") {
+            let templated: Vec<&str> = path
+                .split('/')
+                .filter_map(|s| s.strip_prefix('{')?.strip_suffix('}'))
+                .collect();
+            for method in HTTP_METHODS {
+                let op = &item[method];
+                if op.is_null() {
+                    continue;
+                }
+                let declared: Vec<&str> = op["parameters"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|p| p["in"] == "path")
+                    .filter_map(|p| p["name"].as_str())
+                    .collect();
+                for param in &templated {
+                    if !declared.contains(param) {
+                        problems.push(format!("{method} {path}: undeclared path param {param}"));
+                    }
+                }
+                for (status, response) in op["responses"].as_object().into_iter().flatten() {
+                    let is_error = status.starts_with('4') || status.starts_with('5');
+                    if is_error && response["content"].is_null() {
+                        problems.push(format!("{method} {path}: {status} has no body schema"));
+                    }
+                }
+            }
+        }
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+
+    /// Query and graph request models carry examples and `/query` documents
+    /// every success shape, so generated clients are typed end to end.
+    #[test]
+    fn test_openapi_query_and_graph_models_are_typed() {
+        let spec = openapi_json();
+        let schemas = &spec["components"]["schemas"];
+        for (schema, field) in [
+            ("QueryRequest", "query"),
+            ("QueryRequest", "params"),
+            ("ExplainRequest", "query"),
+            ("ExplainRequest", "params"),
+            ("MatchQueryRequest", "query"),
+            ("TraverseRequest", "strategy"),
+            ("ParallelTraverseRequest", "rel_types"),
+            ("GraphSearchRequest", "vector"),
+            ("AddEdgeRequest", "label"),
+        ] {
+            assert!(
+                !schemas[schema]["properties"][field]["example"].is_null(),
+                "{schema}.{field} should have an example"
+            );
+        }
+        let params = &schemas["QueryRequest"]["properties"]["params"];
+        assert_eq!(
+            params["additionalProperties"]["oneOf"]
+                .as_array()
+                .map(Vec::len),
+            Some(5),
+            "params values should be a typed union: {params}"
+        );
+
+        let variants: Vec<&str> = schemas["QueryResult"]["oneOf"]
+            .as_array()
+            .expect("test: QueryResult is a oneOf")
+            .iter()
+            .filter_map(|v| v["$ref"].as_str())
+            .collect();
+        assert_eq!(
+            variants,
+            [
+                "#/components/schemas/QueryResponse",
+                "#/components/schemas/AggregationResponse",
+                "#/components/schemas/QueryDryRunResponse",
+            ]
+        );
+        assert_eq!(
+            spec["paths"]["/query"]["post"]["responses"]["200"]["content"]["application/json"]
+                ["schema"]["$ref"],
+            "#/components/schemas/QueryResult"
+        );
+    }
 }
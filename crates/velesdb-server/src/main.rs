@@ -1,7 +1,7 @@
 #![allow(clippy::doc_markdown)]
 //! `VelesDB` Server - REST API for the `VelesDB` vector database.
 
-use axum::{http::HeaderValue, middleware::Next, Router};
+use axum::Router;
 use clap::Parser;
 use std::future::IntoFuture;
 use std::net::SocketAddr;
@@ -9,21 +9,23 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use tokio_rustls::TlsAcceptor;
 use tower::ServiceExt;
-use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-#[cfg(feature = "swagger-ui")]
-use utoipa_swagger_ui::SwaggerUi;
 use velesdb_core::Database;
-#[cfg(feature = "swagger-ui")]
-use velesdb_server::ApiDoc;
 use velesdb_server::{
-    auth::{auth_middleware, AuthState},
+    auth::AuthState,
     config::{
-        build_cors_layer, load_core_config, parse_api_keys_env, CliOverrides, CorsConfig,
-        ServerConfig,
+        load_core_config, parse_api_keys_env, parse_cors_origins_env, CliOverrides, CorsConfig,
+        CorsState, EffectiveConfig, ServerConfig,
     },
-    routes::api_routes,
+    embedded::{router_for_state, RouterLayers},
+    fsck,
+    index_events::IndexEventHub,
+    rate_limit::{RateLimitState, UdsPeer},
+    reindex::ReindexJobs,
+    reload::ConfigReloader,
+    slo_webhook,
+    traversal_cursors::TraversalCursors,
     AppState, OnboardingMetrics,
 };
 
@@ -55,6 +57,10 @@ struct Args {
     #[arg(short, long, env = "VELESDB_PORT")]
     port: Option<u16>,
 
+    /// Listen on this Unix domain socket instead of host:port (Unix only)
+    #[arg(long, env = "VELESDB_UDS")]
+    uds: Option<String>,
+
     /// TLS certificate file (PEM)
     #[arg(long, env = "VELESDB_TLS_CERT")]
     tls_cert: Option<String>,
@@ -66,6 +72,28 @@ struct Args {
     /// Rate limit: max requests per second per IP (0 = disabled)
     #[arg(long, env = "VELESDB_RATE_LIMIT")]
     rate_limit: Option<u32>,
+
+    /// Seconds to wait for in-flight requests on shutdown
+    #[arg(long, env = "VELESDB_SHUTDOWN_TIMEOUT_SECS")]
+    shutdown_timeout_secs: Option<u64>,
+
+    /// Tokio worker threads (default: one per CPU core)
+    #[arg(long, env = "VELESDB_WORKER_THREADS")]
+    worker_threads: Option<usize>,
+
+    /// Maximum threads in the blocking pool used for searches and other
+    /// CPU-bound requests (default: 512)
+    #[arg(long, env = "VELESDB_MAX_BLOCKING_THREADS")]
+    max_blocking_threads: Option<usize>,
+
+    /// Check every collection in the data directory, print a recovery
+    /// report and exit (status 1 if issues remain) instead of serving
+    #[arg(long)]
+    fsck: bool,
+
+    /// With --fsck, also repair what can be rebuilt from intact data
+    #[arg(long, requires = "fsck")]
+    repair: bool,
 }
 
 fn configure_tracing() {
@@ -80,7 +108,10 @@ fn configure_tracing() {
 fn log_startup(cfg: &ServerConfig) {
     tracing::info!("Starting VelesDB server...");
     tracing::info!("Data directory: {}", cfg.data_dir);
-    tracing::info!("Bind address: {}:{}", cfg.host, cfg.port);
+    match &cfg.uds_path {
+        Some(path) => tracing::info!("Unix socket: {path}"),
+        None => tracing::info!("Bind address: {}:{}", cfg.host, cfg.port),
+    }
     if cfg.auth_enabled() {
         tracing::info!(
             "API key authentication enabled ({} key(s))",
@@ -109,6 +140,9 @@ fn log_startup(cfg: &ServerConfig) {
     } else {
         tracing::info!("Rate limiting disabled");
     }
+    if let Some(workers) = cfg.worker_threads {
+        tracing::info!("Worker threads: {workers}");
+    }
     log_cors_config(&cfg.cors);
 }
 
@@ -127,21 +161,38 @@ fn log_cors_config(cors: &CorsConfig) {
 }
 
 fn init_app_state(
-    data_dir: &str,
+    cfg: &ServerConfig,
     core_config: velesdb_core::config::VelesConfig,
+    reloader: ConfigReloader,
 ) -> anyhow::Result<Arc<AppState>> {
-    let db = Database::open_with_config(data_dir, core_config)?;
+    let db = Database::open_with_config(&cfg.data_dir, core_config.clone())?;
+    // Collections loaded from disk start on default limits.
+    db.update_guardrails(&cfg.guardrails);
     let state = Arc::new(AppState {
         db,
         onboarding_metrics: OnboardingMetrics::default(),
-        query_limits: parking_lot::RwLock::new(velesdb_core::guardrails::QueryLimits::default()),
+        query_limits: parking_lot::RwLock::new(cfg.guardrails.clone()),
+        effective_config: parking_lot::RwLock::new(EffectiveConfig {
+            server: cfg.clone(),
+            engine: core_config,
+        }),
+        config_reloader: Some(Arc::new(reloader)),
+        reindex_jobs: ReindexJobs::default(),
+        index_events: IndexEventHub::default(),
         ready: std::sync::atomic::AtomicBool::new(false),
         operational_metrics: velesdb_core::metrics::OperationalMetrics::new_arc(),
         traversal_metrics: std::sync::Arc::new(velesdb_core::metrics::TraversalMetrics::new()),
+        traversal_cursors: TraversalCursors::default(),
         query_duration_histogram: std::sync::Arc::new(
             velesdb_core::metrics::DurationHistogram::new(),
         ),
+        embedder: parking_lot::RwLock::new(
+            cfg.embedder
+                .as_ref()
+                .map(|config| Arc::new(velesdb_server::embedder::Embedder::new(config))),
+        ),
     });
+    slo_webhook::install(&state.db, cfg.slo_webhook_url.clone());
     // Database loaded successfully — mark server as ready
     state
         .ready
@@ -149,62 +200,38 @@ fn init_app_state(
     Ok(state)
 }
 
-/// Middleware that adds deprecation headers to responses served on
-/// unversioned (legacy) routes. Clients should migrate to `/v1/` prefix.
-async fn deprecation_header(
-    request: axum::extract::Request,
-    next: Next,
-) -> axum::response::Response {
-    let mut response = next.run(request).await;
-    let headers = response.headers_mut();
-    headers.insert("deprecation", HeaderValue::from_static("true"));
-    headers.insert(
-        "x-api-deprecated",
-        HeaderValue::from_static("Use /v1/ prefix"),
-    );
-    response
-}
-
-#[allow(clippy::similar_names)] // Reason: `routes` (handler tree) and `router` (final router) are distinct concepts.
-fn build_router(
-    state: Arc<AppState>,
-    auth_state: AuthState,
-    rate_limit: u32,
-    cors: &CorsConfig,
-) -> anyhow::Result<Router> {
-    let routes = api_routes();
-
-    // Canonical versioned API under /v1/
-    let versioned = Router::new().nest("/v1", routes.clone());
-
-    // Legacy unversioned routes with deprecation headers for backward compat
-    let legacy = routes.layer(axum::middleware::from_fn(deprecation_header));
-
-    let api_router = versioned.merge(legacy).with_state(state);
-
-    #[cfg(feature = "swagger-ui")]
-    let api_router = {
-        let swagger_ui =
-            SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi());
-        api_router.merge(Router::<()>::new().merge(swagger_ui))
-    };
-
-    let cors_layer = build_cors_layer(cors);
-
-    let router = api_router
-        .layer(axum::middleware::from_fn_with_state(
-            auth_state,
-            auth_middleware,
-        ))
-        .layer(cors_layer)
-        .layer(TraceLayer::new_for_http());
-
-    if rate_limit > 0 {
-        let config = velesdb_server::rate_limit::build_rate_limit_config(rate_limit)?;
-        Ok(router.layer(velesdb_server::rate_limit::GovernorLayer::new(config)))
-    } else {
-        Ok(router)
-    }
+/// Reloads the configuration on every `SIGHUP` (Unix only).
+fn spawn_sighup_reload(state: Arc<AppState>) {
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        let Ok(mut hangup) = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())
+        else {
+            tracing::warn!("Failed to install SIGHUP handler; config reload via signal disabled");
+            return;
+        };
+        while hangup.recv().await.is_some() {
+            let Some(reloader) = state.config_reloader.as_ref() else {
+                continue;
+            };
+            match reloader.reload(&state) {
+                Ok(outcome) => {
+                    tracing::info!(
+                        "Received SIGHUP, config reloaded: applied {:?}",
+                        outcome.applied
+                    );
+                    if !outcome.restart_required.is_empty() {
+                        tracing::warn!(
+                            "Config changes that need a restart were ignored: {:?}",
+                            outcome.restart_required
+                        );
+                    }
+                }
+                Err(e) => tracing::error!("Received SIGHUP, config reload failed: {e}"),
+            }
+        }
+    });
+    #[cfg(not(unix))]
+    let _ = state;
 }
 
 /// Returns a future that resolves when SIGTERM is received (Unix) or never (non-Unix).
@@ -240,17 +267,9 @@ async fn serve(
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     tracing::info!("VelesDB server listening on http://{}", addr);
 
-    // Create a notify to track when the shutdown signal fires
-    let shutdown_notify = Arc::new(tokio::sync::Notify::new());
-    let notify_clone = shutdown_notify.clone();
-
-    let graceful_shutdown = async move {
-        shutdown_signal().await;
-        notify_clone.notify_one();
-    };
-
+    let (graceful_shutdown, shutdown_notify) = graceful_shutdown();
     // into_make_service_with_connect_info provides peer IP to the
-    // rate limiter's SmartIpKeyExtractor.
+    // rate limiter's ClientKeyExtractor.
     let server = axum::serve(
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
@@ -259,8 +278,93 @@ async fn serve(
     .into_future();
 
     // Start server in a task so we can apply drain timeout after signal
-    let server_handle = tokio::spawn(server);
+    drain_server(
+        tokio::spawn(server),
+        &shutdown_notify,
+        shutdown_timeout_secs,
+    )
+    .await;
+    flush_and_exit(&state);
+    Ok(())
+}
+
+/// Serves plain HTTP on the Unix domain socket at `path`.
+#[cfg(unix)]
+async fn serve_uds(
+    path: &str,
+    app: Router,
+    state: Arc<AppState>,
+    shutdown_timeout_secs: u64,
+) -> anyhow::Result<()> {
+    remove_stale_socket(path)?;
+    let listener = tokio::net::UnixListener::bind(path)?;
+    tracing::info!("VelesDB server listening on unix:{}", path);
+
+    // Socket peers have no IP address: the rate limiter keys every request
+    // marked `UdsPeer` as loopback, ignoring forwarding headers, so all
+    // socket clients share one bucket.
+    let loopback = SocketAddr::from(([127, 0, 0, 1], 0));
+    let app = app
+        .layer(axum::Extension(axum::extract::ConnectInfo(loopback)))
+        .layer(axum::Extension(UdsPeer));
+    let (graceful_shutdown, shutdown_notify) = graceful_shutdown();
+    let server = axum::serve(listener, app.into_make_service())
+        .with_graceful_shutdown(graceful_shutdown)
+        .into_future();
+
+    drain_server(
+        tokio::spawn(server),
+        &shutdown_notify,
+        shutdown_timeout_secs,
+    )
+    .await;
+    let _ = std::fs::remove_file(path);
+    flush_and_exit(&state);
+    Ok(())
+}
+
+/// Removes a socket file left behind by a server that did not shut down
+/// cleanly. Refuses to touch anything else, or a socket still accepting
+/// connections.
+#[cfg(unix)]
+fn remove_stale_socket(path: &str) -> anyhow::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return Ok(());
+    };
+    if !metadata.file_type().is_socket() {
+        anyhow::bail!("uds_path {path} exists and is not a socket");
+    }
+    if std::os::unix::net::UnixStream::connect(path).is_ok() {
+        anyhow::bail!("another server is already listening on {path}");
+    }
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+/// Shutdown future for `with_graceful_shutdown`, and a notify fired when
+/// the shutdown signal arrives.
+fn graceful_shutdown() -> (
+    impl std::future::Future<Output = ()> + Send + 'static,
+    Arc<tokio::sync::Notify>,
+) {
+    let shutdown_notify = Arc::new(tokio::sync::Notify::new());
+    let notify_clone = shutdown_notify.clone();
+    let graceful_shutdown = async move {
+        shutdown_signal().await;
+        notify_clone.notify_one();
+    };
+    (graceful_shutdown, shutdown_notify)
+}
 
+/// Waits for the shutdown signal, then up to the drain timeout for the
+/// server task to finish its in-flight requests.
+async fn drain_server(
+    server_handle: tokio::task::JoinHandle<std::io::Result<()>>,
+    shutdown_notify: &tokio::sync::Notify,
+    shutdown_timeout_secs: u64,
+) {
     // Wait for the shutdown signal
     shutdown_notify.notified().await;
 
@@ -278,9 +382,6 @@ async fn serve(
             tracing::warn!("Drain timeout ({shutdown_timeout_secs}s) reached, forcing shutdown");
         }
     }
-
-    flush_and_exit(&state);
-    Ok(())
 }
 
 /// Accepts TLS connections until a shutdown signal is received.
@@ -411,28 +512,57 @@ fn build_cli_overrides(args: Args) -> CliOverrides {
         config_path: args.config,
         host: args.host,
         port: args.port,
+        uds_path: args.uds,
         data_dir: args.data_dir,
         api_keys: parse_api_keys_env(),
         tls_cert: args.tls_cert,
         tls_key: args.tls_key,
         rate_limit: args.rate_limit,
+        shutdown_timeout_secs: args.shutdown_timeout_secs,
+        worker_threads: args.worker_threads,
+        max_blocking_threads: args.max_blocking_threads,
+        cors_allowed_origins: parse_cors_origins_env(),
+    }
+}
+
+/// Builds the Tokio runtime, sized by `[server] worker_threads` /
+/// `max_blocking_threads` when set.
+fn build_runtime(cfg: &ServerConfig) -> std::io::Result<tokio::runtime::Runtime> {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder.enable_all();
+    if let Some(workers) = cfg.worker_threads {
+        builder.worker_threads(workers);
+    }
+    if let Some(blocking) = cfg.max_blocking_threads {
+        builder.max_blocking_threads(blocking);
     }
+    builder.build()
 }
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
+fn main() -> anyhow::Result<()> {
     configure_tracing();
 
     let args = Args::parse();
-    let config_path = args.config.clone();
+    let (fsck, repair) = (args.fsck, args.repair);
     let cli = build_cli_overrides(args);
-    let cfg = ServerConfig::load(cli)?;
+    let cfg = ServerConfig::load(cli.clone())?;
     cfg.validate()?;
 
+    if fsck {
+        let report = fsck::run(&cfg.data_dir, load_core_config(&cli.config_path)?, repair)?;
+        println!("{}", fsck::render(&report));
+        std::process::exit(i32::from(!report.is_clean()));
+    }
+
+    // The runtime is sized from the config, so it can't be `#[tokio::main]`.
+    build_runtime(&cfg)?.block_on(run(cfg, cli))
+}
+
+async fn run(cfg: ServerConfig, cli: CliOverrides) -> anyhow::Result<()> {
     // Fail-fast: an explicit `--config`/`VELESDB_CONFIG` path that is
     // missing or fails validation aborts startup here with the typed core
     // `ConfigError` — never a silent fallback to engine defaults.
-    let core_config = load_core_config(&config_path)?;
+    let core_config = load_core_config(&cli.config_path)?;
 
     log_startup(&cfg);
 
@@ -445,9 +575,19 @@ async fn main() -> anyhow::Result<()> {
         "core".to_string(),
     );
 
-    let state = init_app_state(&cfg.data_dir, core_config)?;
-    let auth_state = AuthState::new(cfg.api_keys.clone());
-    let app = build_router(state.clone(), auth_state, cfg.rate_limit, &cfg.cors)?;
+    let auth_state =
+        AuthState::new(cfg.api_keys.clone()).with_key_contexts(cfg.key_contexts.clone());
+    let rate_limit = RateLimitState::new(cfg.rate_limit)?;
+    let cors = CorsState::new(&cfg.cors);
+    let reloader = ConfigReloader::new(cli, auth_state.clone(), rate_limit.clone(), cors.clone());
+    let state = init_app_state(&cfg, core_config, reloader)?;
+    spawn_sighup_reload(state.clone());
+    let layers = RouterLayers {
+        auth: auth_state,
+        rate_limit,
+        cors: Some(cors),
+    };
+    let app = router_for_state(state.clone(), layers, &cfg.compression, true);
 
     if let (Some(cert), Some(key)) = (&cfg.tls.cert, &cfg.tls.key) {
         serve_tls(
@@ -460,6 +600,11 @@ async fn main() -> anyhow::Result<()> {
             cfg.shutdown_timeout_secs,
         )
         .await
+    } else if let Some(path) = &cfg.uds_path {
+        #[cfg(unix)]
+        return serve_uds(path, app, state, cfg.shutdown_timeout_secs).await;
+        #[cfg(not(unix))]
+        anyhow::bail!("uds_path {path} is only supported on Unix");
     } else {
         serve(&cfg.host, cfg.port, app, state, cfg.shutdown_timeout_secs).await
     }
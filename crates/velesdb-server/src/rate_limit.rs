@@ -4,11 +4,24 @@
 //! When the bucket is exhausted the server replies with `429 Too Many Requests`
 //! and standard rate-limit headers (`x-ratelimit-limit`, `x-ratelimit-remaining`,
 //! `retry-after`).
+//!
+//! [`RateLimitState`] + [`rate_limit_middleware`] wrap the governor layer so
+//! the limit can be changed (or disabled) by a config reload without
+//! rebuilding the router.
+//!
+//! Requests marked with [`UdsPeer`] (the Unix socket listener) share one
+//! bucket: socket peers have no IP, and their forwarding headers are not
+//! trusted.
 
-use std::sync::Arc;
+use axum::{extract::Request, middleware::Next, response::Response};
+use parking_lot::RwLock;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, Weak};
 use std::time::Duration;
+use tower::{Layer, ServiceExt};
 use tower_governor::governor::{GovernorConfig, GovernorConfigBuilder};
-use tower_governor::key_extractor::SmartIpKeyExtractor;
+use tower_governor::key_extractor::{KeyExtractor, SmartIpKeyExtractor};
+use tower_governor::GovernorError;
 
 /// Re-export so callers can build a `GovernorLayer` from the config.
 pub use tower_governor::GovernorLayer;
@@ -17,13 +30,36 @@ pub use tower_governor::GovernorLayer;
 type HeaderMiddleware = ::governor::middleware::StateInformationMiddleware;
 
 /// Concrete governor config type with per-IP keying and rate-limit headers.
-pub type RateLimitConfig = GovernorConfig<SmartIpKeyExtractor, HeaderMiddleware>;
+pub type RateLimitConfig = GovernorConfig<ClientKeyExtractor, HeaderMiddleware>;
+
+/// Request extension marking a request received on the Unix domain socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UdsPeer;
+
+/// Rate-limit key of a request.
+///
+/// TCP requests are keyed like [`SmartIpKeyExtractor`]: `x-forwarded-for`,
+/// `x-real-ip` and `forwarded` headers first, then the peer IP, which suits
+/// reverse proxies. Requests marked [`UdsPeer`] all get the loopback key and
+/// their headers are ignored, so a socket client cannot pick a fresh bucket
+/// per request by forging them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientKeyExtractor;
+
+impl KeyExtractor for ClientKeyExtractor {
+    type Key = IpAddr;
+
+    fn extract<T>(&self, req: &axum::http::Request<T>) -> Result<Self::Key, GovernorError> {
+        if req.extensions().get::<UdsPeer>().is_some() {
+            return Ok(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        }
+        SmartIpKeyExtractor.extract(req)
+    }
+}
 
 /// Build a [`GovernorConfig`] that enforces `burst` requests/second per IP.
 ///
-/// Uses [`SmartIpKeyExtractor`] which inspects `x-forwarded-for`,
-/// `x-real-ip`, `forwarded` headers before falling back to the peer IP,
-/// making it safe behind reverse proxies.
+/// Keys requests with [`ClientKeyExtractor`].
 ///
 /// A background thread periodically prunes stale entries from the
 /// governor limiter map (every 60 s).
@@ -35,7 +71,7 @@ pub fn build_rate_limit_config(burst: u32) -> anyhow::Result<Arc<RateLimitConfig
     let mut builder = GovernorConfigBuilder::default();
     builder.per_second(u64::from(burst));
     builder.burst_size(burst);
-    let mut builder = builder.key_extractor(SmartIpKeyExtractor);
+    let mut builder = builder.key_extractor(ClientKeyExtractor);
     let mut builder = builder.use_headers();
 
     let config = Arc::new(
@@ -50,16 +86,95 @@ pub fn build_rate_limit_config(burst: u32) -> anyhow::Result<Arc<RateLimitConfig
 }
 
 /// Spawns a background thread that prunes stale rate-limiter entries every 60 s.
+///
+/// The thread exits once the limiter is dropped, e.g. after a reload
+/// replaced it.
 fn spawn_limiter_cleanup(config: &Arc<RateLimitConfig>) {
-    let limiter = config.limiter().clone();
+    let limiter = Arc::downgrade(config.limiter());
     let interval = Duration::from_secs(60);
     std::thread::spawn(move || loop {
         std::thread::sleep(interval);
+        let Some(limiter) = Weak::upgrade(&limiter) else {
+            return;
+        };
         tracing::debug!("rate limiter cleanup: {} tracked IPs", limiter.len());
         limiter.retain_recent();
     });
 }
 
+/// Per-IP rate limit that can be replaced at runtime.
+///
+/// Clones share the current limiter. Changing the limit starts every IP
+/// with a fresh bucket; setting the same limit again keeps the existing
+/// buckets.
+#[derive(Clone, Default)]
+pub struct RateLimitState {
+    current: Arc<RwLock<Option<ActiveLimit>>>,
+}
+
+/// Limit in requests/second and the limiter enforcing it.
+type ActiveLimit = (u32, Arc<RateLimitConfig>);
+
+impl RateLimitState {
+    /// Creates a limiter allowing `rate_limit` requests/second per IP
+    /// (0 = disabled).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the governor configuration cannot be built.
+    pub fn new(rate_limit: u32) -> anyhow::Result<Self> {
+        let state = Self::default();
+        state.set_limit(rate_limit)?;
+        Ok(state)
+    }
+
+    /// Current limit in requests/second per IP (0 = disabled).
+    pub fn limit(&self) -> u32 {
+        self.current.read().as_ref().map_or(0, |(limit, _)| *limit)
+    }
+
+    /// Replaces the limit (0 disables rate limiting).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the governor configuration cannot be built; the
+    /// previous limit stays in force.
+    pub fn set_limit(&self, rate_limit: u32) -> anyhow::Result<()> {
+        if rate_limit == self.limit() {
+            return Ok(());
+        }
+        let next = if rate_limit == 0 {
+            None
+        } else {
+            Some((rate_limit, build_rate_limit_config(rate_limit)?))
+        };
+        *self.current.write() = next;
+        Ok(())
+    }
+}
+
+/// Axum middleware enforcing the current [`RateLimitState`] limit.
+///
+/// Use with `axum::middleware::from_fn_with_state`.
+pub async fn rate_limit_middleware(
+    axum::extract::State(state): axum::extract::State<RateLimitState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let config = state.current.read().as_ref().map(|(_, c)| Arc::clone(c));
+    match config {
+        Some(config) => match GovernorLayer::new(config)
+            .layer(next)
+            .oneshot(request)
+            .await
+        {
+            Ok(response) => response,
+            Err(never) => match never {},
+        },
+        None => next.run(request).await,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +188,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rate_limit_state_set_limit() {
+        let state = RateLimitState::new(0).expect("disabled state should build");
+        assert_eq!(state.limit(), 0);
+
+        state.set_limit(50).expect("limit should apply");
+        assert_eq!(state.limit(), 50);
+        let before = state.current.read().as_ref().map(|(_, c)| Arc::clone(c));
+
+        // Same limit: the limiter (and its buckets) is kept.
+        state.set_limit(50).expect("limit should apply");
+        let after = state.current.read().as_ref().map(|(_, c)| Arc::clone(c));
+        assert!(Arc::ptr_eq(
+            &before.expect("limiter"),
+            &after.expect("limiter")
+        ));
+
+        state.set_limit(0).expect("disable should apply");
+        assert_eq!(state.limit(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_middleware_follows_reloaded_limit() {
+        use axum::{body::Body, http::StatusCode, routing::get, Router};
+
+        let state = RateLimitState::new(1).expect("limit should apply");
+        let app = Router::new().route("/", get(|| async { "ok" })).layer(
+            axum::middleware::from_fn_with_state(state.clone(), rate_limit_middleware),
+        );
+        let call = || async {
+            let request = axum::http::Request::builder()
+                .uri("/")
+                .header("x-forwarded-for", "203.0.113.7")
+                .body(Body::empty())
+                .expect("request");
+            app.clone()
+                .oneshot(request)
+                .await
+                .expect("response")
+                .status()
+        };
+
+        assert_eq!(call().await, StatusCode::OK);
+        assert_eq!(call().await, StatusCode::TOO_MANY_REQUESTS);
+
+        state.set_limit(0).expect("disable should apply");
+        assert_eq!(call().await, StatusCode::OK);
+        assert_eq!(call().await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_uds_requests_share_one_bucket_despite_forwarding_headers() {
+        use axum::{body::Body, http::StatusCode, routing::get, Router};
+
+        let state = RateLimitState::new(1).expect("limit should apply");
+        let app = Router::new().route("/", get(|| async { "ok" })).layer(
+            axum::middleware::from_fn_with_state(state, rate_limit_middleware),
+        );
+        let call = |app: Router, forwarded_for: &'static str| async move {
+            let request = axum::http::Request::builder()
+                .uri("/")
+                .header("x-forwarded-for", forwarded_for)
+                .header("x-real-ip", forwarded_for)
+                .body(Body::empty())
+                .expect("request");
+            app.oneshot(request).await.expect("response").status()
+        };
+
+        // Over TCP (behind a proxy) each forwarded client has its own bucket.
+        assert_eq!(call(app.clone(), "203.0.113.7").await, StatusCode::OK);
+        assert_eq!(call(app.clone(), "203.0.113.8").await, StatusCode::OK);
+
+        // Over the socket a forged header does not buy a fresh bucket.
+        let uds = app.layer(axum::Extension(UdsPeer));
+        assert_eq!(call(uds.clone(), "198.51.100.1").await, StatusCode::OK);
+        assert_eq!(
+            call(uds, "198.51.100.2").await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
     #[test]
     fn test_build_rate_limit_config_burst_one() {
         let config = build_rate_limit_config(1);
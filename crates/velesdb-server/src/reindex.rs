@@ -0,0 +1,177 @@
+//! Background reindex jobs started by `POST /collections/{name}/reindex`.
+//!
+//! A collection runs at most one job at a time. A job's events are kept
+//! after it ends so `GET /collections/{name}/reindex/status` can replay the
+//! last run; starting a new job replaces them.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::stream::{self, Stream};
+use parking_lot::Mutex;
+use tokio::sync::watch;
+use velesdb_core::collection::auto_reindex::ReindexEvent;
+
+use crate::types::ReindexStatusEvent;
+
+/// Reindex jobs by collection name.
+#[derive(Default)]
+pub struct ReindexJobs {
+    jobs: Mutex<HashMap<String, Arc<ReindexJob>>>,
+}
+
+impl ReindexJobs {
+    /// Registers a new job for `collection`.
+    ///
+    /// Returns `None` while a previous job on the same collection is still
+    /// running.
+    pub fn start(&self, collection: &str) -> Option<Arc<ReindexJob>> {
+        let mut jobs = self.jobs.lock();
+        if jobs.get(collection).is_some_and(|job| !job.is_finished()) {
+            return None;
+        }
+        let job = Arc::new(ReindexJob::new());
+        jobs.insert(collection.to_string(), Arc::clone(&job));
+        Some(job)
+    }
+
+    /// The current or most recent job for `collection`.
+    pub fn get(&self, collection: &str) -> Option<Arc<ReindexJob>> {
+        self.jobs.lock().get(collection).cloned()
+    }
+}
+
+/// Event log of one reindex job.
+pub struct ReindexJob {
+    events: Mutex<Vec<ReindexStatusEvent>>,
+    /// Carries the event count so subscribers wake on every push.
+    notify: watch::Sender<usize>,
+}
+
+impl ReindexJob {
+    fn new() -> Self {
+        Self {
+            events: Mutex::new(Vec::new()),
+            notify: watch::Sender::new(0),
+        }
+    }
+
+    /// Appends an event; ignored once the job has ended.
+    pub fn push(&self, event: ReindexStatusEvent) {
+        let mut events = self.events.lock();
+        if events.last().is_some_and(ReindexStatusEvent::is_terminal) {
+            return;
+        }
+        events.push(event);
+        self.notify.send_replace(events.len());
+    }
+
+    /// Appends the server-side form of a core [`ReindexEvent`].
+    pub fn push_core(&self, event: &ReindexEvent) {
+        if let Some(event) = status_event(event) {
+            self.push(event);
+        }
+    }
+
+    /// Whether a terminal event has been recorded.
+    pub fn is_finished(&self) -> bool {
+        self.events
+            .lock()
+            .last()
+            .is_some_and(ReindexStatusEvent::is_terminal)
+    }
+
+    /// Replays the recorded events, then follows new ones until the job ends.
+    pub fn follow(self: &Arc<Self>) -> impl Stream<Item = ReindexStatusEvent> {
+        let rx = self.notify.subscribe();
+        stream::unfold(
+            (Arc::clone(self), rx, 0usize),
+            |(job, mut rx, cursor)| async move {
+                loop {
+                    let next = {
+                        let events = job.events.lock();
+                        if cursor > 0 && events[cursor - 1].is_terminal() {
+                            return None;
+                        }
+                        events.get(cursor).cloned()
+                    };
+                    if let Some(event) = next {
+                        return Some((event, (job, rx, cursor + 1)));
+                    }
+                    // The sender lives in `job`, so this only fails if the
+                    // job is dropped mid-stream — nothing more will come.
+                    if rx.changed().await.is_err() {
+                        return None;
+                    }
+                }
+            },
+        )
+    }
+}
+
+fn status_event(event: &ReindexEvent) -> Option<ReindexStatusEvent> {
+    Some(match event {
+        ReindexEvent::Started {
+            old_params,
+            new_params,
+            ..
+        } => ReindexStatusEvent::Started {
+            old_m: old_params.max_connections,
+            new_m: new_params.max_connections,
+            old_ef_construction: old_params.ef_construction,
+            new_ef_construction: new_params.ef_construction,
+        },
+        ReindexEvent::Progress { percent } => ReindexStatusEvent::Progress { percent: *percent },
+        ReindexEvent::Validating {
+            old_latency_p99_us,
+            new_latency_p99_us,
+        } => ReindexStatusEvent::Validating {
+            old_latency_p99_us: *old_latency_p99_us,
+            new_latency_p99_us: *new_latency_p99_us,
+        },
+        ReindexEvent::Completed { duration } => ReindexStatusEvent::Completed {
+            duration_ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+        },
+        ReindexEvent::RolledBack { reason } => ReindexStatusEvent::RolledBack {
+            reason: reason.clone(),
+        },
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[test]
+    fn test_second_job_rejected_until_first_finishes() {
+        let jobs = ReindexJobs::default();
+        let job = jobs.start("docs").expect("first job");
+        assert!(jobs.start("docs").is_none());
+        assert!(jobs.start("other").is_some());
+
+        job.push(ReindexStatusEvent::Failed {
+            error: "boom".to_string(),
+        });
+        assert!(jobs.start("docs").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_follow_replays_then_ends_on_terminal_event() {
+        let jobs = ReindexJobs::default();
+        let job = jobs.start("docs").unwrap();
+        job.push(ReindexStatusEvent::Progress { percent: 50 });
+
+        let follower = tokio::spawn({
+            let job = Arc::clone(&job);
+            async move { job.follow().collect::<Vec<_>>().await }
+        });
+        job.push(ReindexStatusEvent::Completed { duration_ms: 3 });
+        // Ignored: the job already ended.
+        job.push(ReindexStatusEvent::Progress { percent: 99 });
+
+        let names: Vec<_> = follower.await.unwrap().iter().map(|e| e.name()).collect();
+        assert_eq!(names, ["progress", "completed"]);
+    }
+}
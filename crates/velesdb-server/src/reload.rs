@@ -0,0 +1,138 @@
+//! Configuration reload without restart (`SIGHUP` / `POST /config/reload`).
+//!
+//! A reload re-reads `velesdb.toml` together with the CLI flags and
+//! environment variables the server was started with — so those still take
+//! precedence over the file — and swaps the settings that can change while
+//! serving: API keys, CORS policy, per-IP rate limit, query guard-rails and
+//! the SLO alert webhook.
+//! Requests already in flight finish under the settings they started with.
+//!
+//! Changes to the bind address, data directory, TLS files, compression,
+//! shutdown timeout or thread pools are reported as needing a restart and
+//! otherwise ignored; engine sections (`[hnsw]`, `[limits]`, ...) are not re-read.
+
+use parking_lot::Mutex;
+
+use crate::auth::AuthState;
+use crate::config::{CliOverrides, CorsState, ServerConfig};
+use crate::rate_limit::RateLimitState;
+use crate::AppState;
+
+/// What a reload changed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ReloadOutcome {
+    /// Settings now in force (`auth`, `cors`, `rate_limit`, `guardrails`,
+    /// `slo`, `embedder`).
+    pub applied: Vec<&'static str>,
+    /// Settings that differ from the file but only take effect on restart.
+    pub restart_required: Vec<&'static str>,
+}
+
+/// Re-applies the server configuration to the live middleware state.
+pub struct ConfigReloader {
+    overrides: CliOverrides,
+    auth: AuthState,
+    rate_limit: RateLimitState,
+    cors: CorsState,
+    /// Serialises concurrent reloads (signal + endpoint).
+    reloading: Mutex<()>,
+}
+
+impl ConfigReloader {
+    /// Creates a reloader that swaps settings in the given middleware states.
+    ///
+    /// `overrides` are the CLI/env values from startup, re-applied over the
+    /// file on every reload.
+    pub fn new(
+        overrides: CliOverrides,
+        auth: AuthState,
+        rate_limit: RateLimitState,
+        cors: CorsState,
+    ) -> Self {
+        Self {
+            overrides,
+            auth,
+            rate_limit,
+            cors,
+            reloading: Mutex::new(()),
+        }
+    }
+
+    /// Reloads the configuration and applies the live settings.
+    ///
+    /// All-or-nothing: if the file fails to load or validate, or the new
+    /// rate limiter cannot be built, nothing changes.
+    pub fn reload(&self, state: &AppState) -> anyhow::Result<ReloadOutcome> {
+        let _guard = self.reloading.lock();
+        let new = ServerConfig::load(self.overrides.clone())?;
+        new.validate()?;
+
+        let mut effective = state.effective_config.write();
+        let current = &mut effective.server;
+        let mut outcome = ReloadOutcome::default();
+
+        // The only fallible step goes first.
+        if new.rate_limit != current.rate_limit {
+            self.rate_limit.set_limit(new.rate_limit)?;
+            current.rate_limit = new.rate_limit;
+            outcome.applied.push("rate_limit");
+        }
+        if new.api_keys != current.api_keys || new.key_contexts != current.key_contexts {
+            self.auth.set_key_contexts(new.key_contexts.clone());
+            self.auth.set_api_keys(new.api_keys.clone());
+            current.api_keys = new.api_keys.clone();
+            current.key_contexts = new.key_contexts.clone();
+            outcome.applied.push("auth");
+        }
+        if new.cors != current.cors {
+            self.cors.set(&new.cors);
+            current.cors = new.cors.clone();
+            outcome.applied.push("cors");
+        }
+        if new.guardrails != current.guardrails {
+            *state.query_limits.write() = new.guardrails.clone();
+            state.db.update_guardrails(&new.guardrails);
+            current.guardrails = new.guardrails.clone();
+            outcome.applied.push("guardrails");
+        }
+        if new.slo_webhook_url != current.slo_webhook_url {
+            crate::slo_webhook::install(&state.db, new.slo_webhook_url.clone());
+            current.slo_webhook_url = new.slo_webhook_url.clone();
+            outcome.applied.push("slo");
+        }
+        if new.embedder != current.embedder {
+            *state.embedder.write() = new
+                .embedder
+                .as_ref()
+                .map(|config| std::sync::Arc::new(crate::embedder::Embedder::new(config)));
+            current.embedder = new.embedder.clone();
+            outcome.applied.push("embedder");
+        }
+
+        for (name, changed) in [
+            ("host", new.host != current.host),
+            ("port", new.port != current.port),
+            ("uds_path", new.uds_path != current.uds_path),
+            ("data_dir", new.data_dir != current.data_dir),
+            ("tls", new.tls != current.tls),
+            ("compression", new.compression != current.compression),
+            (
+                "shutdown_timeout_secs",
+                new.shutdown_timeout_secs != current.shutdown_timeout_secs,
+            ),
+            (
+                "worker_threads",
+                new.worker_threads != current.worker_threads,
+            ),
+            (
+                "max_blocking_threads",
+                new.max_blocking_threads != current.max_blocking_threads,
+            ),
+        ] {
+            if changed {
+                outcome.restart_required.push(name);
+            }
+        }
+        Ok(outcome)
+    }
+}
@@ -7,21 +7,26 @@ use std::sync::Arc;
 
 use axum::{
     extract::DefaultBodyLimit,
-    routing::{delete, get, patch, post},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 
 use crate::{
     add_edge, add_edges_batch, aggregate, analyze_collection, batch_search, bulk_delete_points,
-    collection_diagnostics, collection_sanity, compact_collection, create_collection, create_index,
-    delete_collection, delete_index, delete_point, enable_streaming, explain, flush_collection,
-    get_collection, get_collection_config, get_collection_stats, get_edge_count, get_edges,
-    get_guardrails, get_node_degree, get_node_edges, get_node_payload, get_point,
-    get_point_relations, graph_search, health_check, hybrid_search, is_empty, list_collections,
-    list_indexes, list_nodes, match_query, multi_query_search, multi_query_search_ids, query,
-    readiness_check, rebuild_index, relate_points, remove_edge, reorder_for_locality,
-    scroll_points, search, search_ids, set_point_ttl, stream_insert, stream_traverse,
-    stream_upsert_points, text_search, traverse_graph, traverse_parallel, unrelate_points,
+    collection_diagnostics, collection_events, collection_sanity, compact_collection, count_points,
+    create_collection, create_embeddings, create_index, create_view, delete_collection,
+    delete_index, delete_latency_slo, delete_point, delete_row_security, delete_view,
+    enable_streaming, explain, export_graph, flush_collection, get_access_stats, get_collection,
+    get_collection_config, get_collection_stats, get_edge_count, get_edges, get_graph_stats,
+    get_guardrails, get_latency_slo, get_node_degree, get_node_edges, get_node_payload, get_point,
+    get_point_relations, get_points, get_row_security, get_server_config, get_vector_stats,
+    get_view, graph_search, health_check, health_details, hybrid_search, import_graph, is_empty,
+    list_collections, list_indexes, list_nodes, list_views, match_query, multi_query_search,
+    multi_query_search_ids, project_collection, query, readiness_check, rebuild_index, recommend,
+    reindex_status, relate_points, reload_config, remove_edge, reorder_for_locality, retrieval,
+    scroll_points, search, search_ids, set_embedding_info, set_latency_slo, set_point_ttl,
+    set_row_security, start_reindex, stream_insert, stream_traverse, stream_upsert_points,
+    subscribe_collection, text_search, traverse_graph, traverse_parallel, unrelate_points,
     update_guardrails, upsert_node_payload, upsert_points, upsert_points_raw, vacuum_collection,
     AppState,
 };
@@ -30,6 +35,7 @@ use crate::{
 fn core_routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/health", get(health_check))
+        .route("/health/details", get(health_details))
         .route("/ready", get(readiness_check))
         .route(
             "/collections",
@@ -39,18 +45,42 @@ fn core_routes() -> Router<Arc<AppState>> {
             "/collections/{name}",
             get(get_collection).delete(delete_collection),
         )
+        .route("/views", get(list_views).post(create_view))
+        .route("/views/{name}", get(get_view).delete(delete_view))
+        .route(
+            "/collections/{name}/row-security",
+            get(get_row_security)
+                .put(set_row_security)
+                .delete(delete_row_security),
+        )
+        .route(
+            "/collections/{name}/slo",
+            get(get_latency_slo)
+                .put(set_latency_slo)
+                .delete(delete_latency_slo),
+        )
         .route("/collections/{name}/empty", get(is_empty))
         .route("/collections/{name}/config", get(get_collection_config))
+        .route("/collections/{name}/embedding", put(set_embedding_info))
         .route("/collections/{name}/sanity", get(collection_sanity))
         .route("/collections/{name}/flush", post(flush_collection))
         .route("/collections/{name}/analyze", post(analyze_collection))
         .route("/collections/{name}/index/rebuild", post(rebuild_index))
+        .route("/collections/{name}/reindex", post(start_reindex))
+        .route("/collections/{name}/reindex/status", get(reindex_status))
+        .route("/collections/{name}/events", get(collection_events))
+        .route("/collections/{name}/subscribe", get(subscribe_collection))
         .route("/collections/{name}/stats", get(get_collection_stats))
+        .route("/collections/{name}/stats/vectors", get(get_vector_stats))
+        .route("/collections/{name}/stats/access", get(get_access_stats))
+        .route("/collections/{name}/projection", get(project_collection))
         .route(
             "/collections/{name}/diagnostics",
             get(collection_diagnostics),
         )
         .route("/guardrails", get(get_guardrails).put(update_guardrails))
+        .route("/config", get(get_server_config))
+        .route("/config/reload", post(reload_config))
         // 100 MB limit scoped to batch vector upload routes only
         // (1000 vectors x 768D x 4 bytes = ~3 MB typical; 100 MB covers extreme cases)
         .merge(
@@ -70,6 +100,8 @@ fn core_routes() -> Router<Arc<AppState>> {
             get(get_point).delete(delete_point),
         )
         .route("/collections/{name}/points/scroll", post(scroll_points))
+        .route("/collections/{name}/points/get", post(get_points))
+        .route("/collections/{name}/points/count", post(count_points))
         // Bulk operations
         .route(
             "/collections/{name}/points/delete",
@@ -112,6 +144,9 @@ fn search_routes() -> Router<Arc<AppState>> {
         .route("/collections/{name}/search/text", post(text_search))
         .route("/collections/{name}/search/hybrid", post(hybrid_search))
         .route("/collections/{name}/search/ids", post(search_ids))
+        .route("/collections/{name}/recommend", post(recommend))
+        .route("/embeddings", post(create_embeddings))
+        .route("/retrieval", post(retrieval))
         .route(
             "/collections/{name}/indexes",
             get(list_indexes).post(create_index),
@@ -142,6 +177,7 @@ fn graph_routes() -> Router<Arc<AppState>> {
             delete(remove_edge),
         )
         .route("/collections/{name}/graph/edges/count", get(get_edge_count))
+        .route("/collections/{name}/graph/stats", get(get_graph_stats))
         .route("/collections/{name}/graph/nodes", get(list_nodes))
         .route(
             "/collections/{name}/graph/nodes/{node_id}/edges",
@@ -165,6 +201,12 @@ fn graph_routes() -> Router<Arc<AppState>> {
             get(stream_traverse),
         )
         .route("/collections/{name}/graph/search", post(graph_search))
+        .route("/collections/{name}/graph/export", get(export_graph))
+        // Same 100 MB ceiling as the bulk point upload routes.
+        .route(
+            "/collections/{name}/graph/import",
+            post(import_graph).layer(DefaultBodyLimit::max(100 * 1024 * 1024)),
+        )
 }
 
 /// All API routes merged into a single [`Router`].
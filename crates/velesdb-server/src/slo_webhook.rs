@@ -0,0 +1,42 @@
+//! Delivery of latency SLO alerts to a webhook (`[slo] webhook_url`).
+//!
+//! Every breach and recovery detected by the database is POSTed as JSON to
+//! the configured URL from a background task, so searches never wait on the
+//! webhook. Delivery is best effort: failures are logged, not retried.
+
+use std::time::Duration;
+
+use velesdb_core::{Database, SloAlert};
+
+/// Timeout for one webhook delivery.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Routes the database's SLO alerts to `url`, or stops delivering them when
+/// `url` is `None`.
+///
+/// Must be called from within a Tokio runtime; deliveries are spawned on it.
+pub fn install(db: &Database, url: Option<String>) {
+    let Some(url) = url else {
+        db.clear_slo_alert_hook();
+        return;
+    };
+    let runtime = tokio::runtime::Handle::current();
+    let client = reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .unwrap_or_default();
+    db.set_slo_alert_hook(std::sync::Arc::new(move |alert: &SloAlert| {
+        let request = client.post(&url).json(alert);
+        let collection = alert.collection.clone();
+        runtime.spawn(async move {
+            // Webhook URLs often embed a token: keep it out of the logs.
+            let sent = request.send().await.and_then(|r| r.error_for_status());
+            match sent.map_err(reqwest::Error::without_url) {
+                Ok(_) => tracing::debug!(collection = %collection, "SLO alert delivered"),
+                Err(e) => {
+                    tracing::warn!(collection = %collection, error = %e, "SLO alert webhook failed");
+                }
+            }
+        });
+    }));
+}
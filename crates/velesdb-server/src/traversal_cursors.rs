@@ -0,0 +1,159 @@
+//! Server-side cursors for paged `POST /collections/{name}/graph/traverse`.
+//!
+//! A BFS page that leaves results behind parks its traversal state (frontier,
+//! visited set, parent pointers) here under a random token returned as
+//! `next_cursor`. A request carrying that token resumes the BFS where the
+//! previous page stopped instead of restarting it. Tokens are single-use:
+//! each page parks the cursor again under a fresh token.
+//!
+//! Cursors live in memory only. They expire [`CURSOR_TTL`] after being
+//! parked, and at most [`MAX_CURSORS`] are kept; parking one more evicts the
+//! oldest.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use velesdb_core::collection::graph::BfsCursor;
+
+/// How long a parked cursor stays resumable.
+pub const CURSOR_TTL: Duration = Duration::from_secs(300);
+
+/// Maximum number of parked cursors across all collections.
+pub const MAX_CURSORS: usize = 256;
+
+/// Parked traversal cursors by token.
+#[derive(Default)]
+pub struct TraversalCursors {
+    cursors: Mutex<HashMap<String, ParkedCursor>>,
+    /// Cursors parked so far; orders evictions and keeps tokens distinct.
+    issued: AtomicU64,
+}
+
+struct ParkedCursor {
+    collection: String,
+    source: u64,
+    cursor: BfsCursor,
+    parked_at: Instant,
+    seq: u64,
+}
+
+impl TraversalCursors {
+    /// Parks `cursor`, a BFS from `source` in `collection`, and returns the
+    /// token that resumes it.
+    pub fn park(&self, collection: &str, source: u64, cursor: BfsCursor) -> String {
+        let seq = self.issued.fetch_add(1, Ordering::Relaxed);
+        let token = new_token(seq);
+        let now = Instant::now();
+        let mut cursors = self.cursors.lock();
+        cursors.retain(|_, parked| now.duration_since(parked.parked_at) < CURSOR_TTL);
+        if cursors.len() >= MAX_CURSORS {
+            let oldest = cursors
+                .iter()
+                .min_by_key(|(_, parked)| parked.seq)
+                .map(|(token, _)| token.clone());
+            if let Some(oldest) = oldest {
+                cursors.remove(&oldest);
+            }
+        }
+        cursors.insert(
+            token.clone(),
+            ParkedCursor {
+                collection: collection.to_string(),
+                source,
+                cursor,
+                parked_at: now,
+                seq,
+            },
+        );
+        token
+    }
+
+    /// Removes and returns the cursor parked under `token`.
+    ///
+    /// Returns `None` when the token is unknown or expired, or was issued for
+    /// another collection or source node; a mismatched cursor stays parked.
+    pub fn take(&self, collection: &str, source: u64, token: &str) -> Option<BfsCursor> {
+        let mut cursors = self.cursors.lock();
+        let parked = cursors.get(token)?;
+        if parked.collection != collection || parked.source != source {
+            return None;
+        }
+        let parked = cursors.remove(token)?;
+        (parked.parked_at.elapsed() < CURSOR_TTL).then_some(parked.cursor)
+    }
+
+    /// Number of parked cursors, expired ones included until the next park.
+    pub fn len(&self) -> usize {
+        self.cursors.lock().len()
+    }
+
+    /// Whether no cursor is parked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 128-bit hex token: `seq` hashed under two randomly keyed hashers, so
+/// tokens cannot be guessed from earlier ones.
+fn new_token(seq: u64) -> String {
+    let hi = RandomState::new().hash_one(seq);
+    let lo = RandomState::new().hash_one(seq);
+    format!("{hi:016x}{lo:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use velesdb_core::collection::graph::StreamingConfig;
+
+    fn cursor() -> BfsCursor {
+        BfsCursor::new(1, StreamingConfig::default())
+    }
+
+    #[test]
+    fn take_returns_parked_cursor_once() {
+        let cursors = TraversalCursors::default();
+        let token = cursors.park("kg", 1, cursor());
+        assert_eq!(token.len(), 32);
+        assert!(cursors.take("kg", 1, &token).is_some());
+        assert!(
+            cursors.take("kg", 1, &token).is_none(),
+            "tokens are single-use"
+        );
+    }
+
+    #[test]
+    fn take_rejects_other_collection_or_source() {
+        let cursors = TraversalCursors::default();
+        let token = cursors.park("kg", 1, cursor());
+        assert!(cursors.take("other", 1, &token).is_none());
+        assert!(cursors.take("kg", 2, &token).is_none());
+        assert!(
+            cursors.take("kg", 1, &token).is_some(),
+            "a mismatched take leaves the cursor parked"
+        );
+    }
+
+    #[test]
+    fn park_evicts_oldest_beyond_capacity() {
+        let cursors = TraversalCursors::default();
+        let first = cursors.park("kg", 1, cursor());
+        for _ in 0..MAX_CURSORS {
+            cursors.park("kg", 1, cursor());
+        }
+        assert_eq!(cursors.len(), MAX_CURSORS);
+        assert!(cursors.take("kg", 1, &first).is_none());
+    }
+
+    #[test]
+    fn tokens_are_distinct() {
+        let cursors = TraversalCursors::default();
+        let a = cursors.park("kg", 1, cursor());
+        let b = cursors.park("kg", 1, cursor());
+        assert_ne!(a, b);
+    }
+}
@@ -6,3 +6,510 @@
 // Re-export all canonical DTOs from core's api_types module.
 // The `openapi` feature is enabled in this crate, providing ToSchema derives.
 pub use velesdb_core::api_types::*;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use velesdb_core::collection::index_progress::IndexProgress;
+use velesdb_core::ChangeEvent;
+
+// ============================================================================
+// Effective server configuration (`GET /config`)
+// ============================================================================
+
+/// Effective configuration after merging defaults, `velesdb.toml`,
+/// environment variables and CLI flags. Secrets are redacted.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EffectiveConfigResponse {
+    /// Listener, storage and thread-pool settings.
+    pub server: ServerSettingsResponse,
+    /// API key authentication.
+    pub auth: AuthSettingsResponse,
+    /// TLS certificate and key paths.
+    pub tls: TlsSettingsResponse,
+    /// Cross-origin policy.
+    pub cors: CorsSettingsResponse,
+    /// Response compression.
+    pub compression: CompressionSettingsResponse,
+    /// Query guard-rails currently in force (including runtime updates).
+    pub guardrails: GuardRailsConfigResponse,
+    /// Core engine sections (`search`, `hnsw`, `storage`, `limits`,
+    /// `quantization`, `wal_batch`).
+    #[schema(value_type = Object)]
+    pub engine: serde_json::Value,
+}
+
+/// `[server]` section of the effective configuration.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ServerSettingsResponse {
+    pub host: String,
+    pub port: u16,
+    /// Unix domain socket served instead of `host:port` (`null` = TCP).
+    pub uds_path: Option<String>,
+    pub data_dir: String,
+    pub shutdown_timeout_secs: u64,
+    /// Requests per second per IP (0 = disabled).
+    pub rate_limit: u32,
+    /// Tokio worker threads (`null` = one per CPU core).
+    pub worker_threads: Option<usize>,
+    /// Blocking pool size limit (`null` = Tokio default).
+    pub max_blocking_threads: Option<usize>,
+}
+
+/// `[auth]` section of the effective configuration.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthSettingsResponse {
+    /// Whether API key authentication is enforced.
+    pub enabled: bool,
+    /// Number of configured API keys; the keys themselves are never returned.
+    pub api_key_count: usize,
+}
+
+/// `[tls]` section of the effective configuration.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TlsSettingsResponse {
+    pub enabled: bool,
+    pub cert: Option<String>,
+    pub key: Option<String>,
+}
+
+/// `[cors]` section of the effective configuration.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CorsSettingsResponse {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub allow_credentials: bool,
+    pub max_age_secs: u64,
+}
+
+/// `[compression]` section of the effective configuration.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompressionSettingsResponse {
+    pub enabled: bool,
+    /// Compression level (`null` = each algorithm's default).
+    pub level: Option<i32>,
+    /// Smallest response body compressed, in bytes.
+    pub min_size_bytes: u64,
+}
+
+/// Result of `POST /config/reload`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigReloadResponse {
+    /// Settings now in force (`auth`, `cors`, `rate_limit`, `guardrails`).
+    pub applied: Vec<String>,
+    /// Settings that changed in the file but only take effect on restart.
+    pub restart_required: Vec<String>,
+}
+
+// ============================================================================
+// Component health (`GET /health`, `GET /health/details`)
+// ============================================================================
+
+/// Status of the server or of one of its components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// Working normally.
+    Ok,
+    /// Serving, but needs attention (e.g. disk filling up).
+    Degraded,
+    /// A critical component failed; `/health` answers 503.
+    Unhealthy,
+}
+
+/// Body of `GET /health` and `GET /health/details`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HealthResponse {
+    /// Worst status across all components.
+    pub status: HealthStatus,
+    /// Server version.
+    pub version: String,
+    /// Per-component checks.
+    pub components: HealthComponents,
+    /// Per-collection state — only returned by `GET /health/details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collections: Option<Vec<CollectionHealthEntry>>,
+}
+
+/// Component checks reported by the health endpoints.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HealthComponents {
+    /// Collections found on disk; critical when any failed to load.
+    pub collections: CollectionsHealth,
+    /// Free space under the data directory; critical when nearly full.
+    pub disk: DiskHealth,
+    /// Write-ahead log bytes a restart would replay (informational).
+    pub wal: WalHealth,
+    /// Vectors waiting for background indexing (informational).
+    pub background_jobs: BackgroundJobsHealth,
+}
+
+/// Collection load state.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CollectionsHealth {
+    pub status: HealthStatus,
+    /// Collections loaded and serving.
+    pub loaded: usize,
+    /// Collections whose directory could not be opened at startup.
+    pub failed: usize,
+}
+
+/// Disk space on the data directory's filesystem.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DiskHealth {
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_bytes: Option<u64>,
+    /// Why the filesystem could not be queried.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// WAL backlog summed over all collections.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WalHealth {
+    pub backlog_bytes: u64,
+}
+
+/// Background indexing queue summed over all collections.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BackgroundJobsHealth {
+    /// Vectors buffered by the deferred indexer / async index builder.
+    pub queue_depth: usize,
+}
+
+/// State of one collection in `GET /health/details`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CollectionHealthEntry {
+    pub name: String,
+    /// `ok` when loaded, `unhealthy` when loading failed.
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub point_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_index_ops: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wal_backlog_bytes: Option<u64>,
+    /// Load error for failed collections.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+// ============================================================================
+// Online reindex (`POST /collections/{name}/reindex`)
+// ============================================================================
+
+/// Body of `POST /collections/{name}/reindex`. Every field is optional.
+///
+/// Unset HNSW parameters default to the values recommended for the
+/// collection's current size; unset tolerances come from the collection's
+/// auto-reindex configuration.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReindexRequest {
+    /// HNSW M parameter for the new graph.
+    pub hnsw_m: Option<usize>,
+    /// HNSW `ef_construction` for the new graph.
+    pub hnsw_ef_construction: Option<usize>,
+    /// VAMANA alpha for the new graph.
+    pub hnsw_alpha: Option<f32>,
+    /// Neighbor-selection heuristic for the new graph (`simple`,
+    /// `heuristic`, `heuristic_keep_pruned`).
+    pub hnsw_neighbor_selection: Option<String>,
+    /// Roll back if the new graph's p99 latency is this much worse (%).
+    pub max_latency_regression_percent: Option<f64>,
+    /// Roll back if the new graph's recall drops by more than this (%).
+    pub max_recall_regression_percent: Option<f64>,
+}
+
+/// Response of `POST /collections/{name}/reindex` (202 Accepted).
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReindexAcceptedResponse {
+    pub collection: String,
+    /// Parameters the new graph is being built with.
+    pub hnsw_m: usize,
+    pub hnsw_ef_construction: usize,
+    /// Where to follow progress.
+    pub status_url: String,
+}
+
+/// One event of `GET /collections/{name}/reindex/status`.
+///
+/// Sent as an SSE record whose event name is the `event` tag.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ReindexStatusEvent {
+    /// The rebuild started.
+    Started {
+        old_m: usize,
+        new_m: usize,
+        old_ef_construction: usize,
+        new_ef_construction: usize,
+    },
+    /// Share of vectors inserted into the new graph.
+    Progress { percent: u8 },
+    /// Both graphs were benchmarked; the swap is being decided.
+    Validating {
+        old_latency_p99_us: u64,
+        new_latency_p99_us: u64,
+    },
+    /// The new graph is live and persisted.
+    Completed { duration_ms: u64 },
+    /// The new graph regressed and was discarded.
+    RolledBack { reason: String },
+    /// The job stopped on an error; the old graph stays live.
+    Failed { error: String },
+}
+
+impl ReindexStatusEvent {
+    /// SSE event name.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Started { .. } => "started",
+            Self::Progress { .. } => "progress",
+            Self::Validating { .. } => "validating",
+            Self::Completed { .. } => "completed",
+            Self::RolledBack { .. } => "rolled_back",
+            Self::Failed { .. } => "failed",
+        }
+    }
+
+    /// Whether this event ends the job.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed { .. } | Self::RolledBack { .. } | Self::Failed { .. }
+        )
+    }
+}
+
+/// One event of `GET /collections/{name}/events`.
+///
+/// Sent as an SSE record named `progress`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct IndexProgressEvent {
+    /// Build phase: `storing_vectors`, `linking_layers` or `flushing`.
+    #[schema(example = "linking_layers")]
+    pub phase: String,
+    /// Units done in this phase (vectors, or flush steps for `flushing`).
+    pub processed: usize,
+    /// Units in this phase.
+    pub total: usize,
+    /// Completion of this phase (0-100).
+    pub percent: u8,
+}
+
+impl From<IndexProgress> for IndexProgressEvent {
+    fn from(p: IndexProgress) -> Self {
+        Self {
+            phase: p.phase.as_str().to_string(),
+            processed: p.processed,
+            total: p.total,
+            percent: p.percent,
+        }
+    }
+}
+
+/// One event of `GET /collections/{name}/subscribe`.
+///
+/// Sent as an SSE record named after `kind`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PointChangeEvent {
+    /// Change as seen through the subscription filter: `insert`, `update`
+    /// or `delete`.
+    #[schema(example = "update")]
+    pub kind: String,
+    /// Point id.
+    pub id: u64,
+    /// Payload after the change; for `delete`, the last matching payload.
+    pub payload: Option<serde_json::Value>,
+}
+
+impl From<ChangeEvent> for PointChangeEvent {
+    fn from(e: ChangeEvent) -> Self {
+        Self {
+            kind: e.kind.as_str().to_string(),
+            id: e.id,
+            payload: e.payload,
+        }
+    }
+}
+
+// ============================================================================
+// DML dry run (`POST /query` with `dry_run: true`)
+// ============================================================================
+
+/// What a DML statement would change; returned by `/query` when the request
+/// sets `dry_run`. Nothing is written.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QueryDryRunResponse {
+    /// Always `true`, to tell this body apart from a `QueryResponse`.
+    pub dry_run: bool,
+    /// Statement kind: `INSERT`, `UPSERT`, `UPDATE` or `DELETE`.
+    #[schema(example = "UPDATE")]
+    pub statement: String,
+    /// Target collection.
+    pub collection: String,
+    /// Points that would be created.
+    pub would_insert: usize,
+    /// Existing points that would be overwritten.
+    pub would_update: usize,
+    /// Existing points that would be deleted.
+    pub would_delete: usize,
+    /// Up to 10 affected point ids.
+    pub sample_ids: Vec<u64>,
+    /// Time spent validating and resolving the statement, in milliseconds.
+    pub timing_ms: f64,
+}
+
+impl QueryDryRunResponse {
+    /// Wraps a core report with the time it took to compute.
+    #[must_use]
+    pub fn new(report: velesdb_core::DryRunReport, timing_ms: f64) -> Self {
+        Self {
+            dry_run: true,
+            statement: report.statement.to_string(),
+            collection: report.collection,
+            would_insert: report.would_insert,
+            would_update: report.would_update,
+            would_delete: report.would_delete,
+            sample_ids: report.sample_ids,
+            timing_ms,
+        }
+    }
+}
+
+/// Body of a successful `POST /query`, documented as a union so generated
+/// clients can decode every shape the endpoint returns. Never constructed:
+/// handlers serialize the concrete variant directly.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum QueryResult {
+    /// Rows of a `SELECT`, `MATCH` or DML statement.
+    Rows(QueryResponse),
+    /// Groups of a `GROUP BY` / aggregate query.
+    Aggregation(AggregationResponse),
+    /// Preview of a DML statement sent with `dry_run: true`.
+    DryRun(QueryDryRunResponse),
+}
+
+// ============================================================================
+// OpenAI-compatible embeddings and retrieval (`POST /embeddings`, `/retrieval`)
+// ============================================================================
+
+/// Text to embed: one string or a list of strings.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum EmbeddingsInput {
+    /// A single text.
+    One(String),
+    /// Several texts, embedded in one upstream call.
+    Many(Vec<String>),
+}
+
+impl EmbeddingsInput {
+    /// The texts in request order.
+    #[must_use]
+    pub fn into_texts(self) -> Vec<String> {
+        match self {
+            Self::One(text) => vec![text],
+            Self::Many(texts) => texts,
+        }
+    }
+}
+
+/// Body of `POST /embeddings`, as sent by OpenAI clients.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EmbeddingsRequest {
+    /// Text(s) to embed.
+    pub input: EmbeddingsInput,
+    /// Accepted for compatibility; the `[embedder]` model is always used.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// `float` (default) or `base64` (little-endian f32).
+    #[serde(default)]
+    #[schema(example = "float")]
+    pub encoding_format: Option<String>,
+}
+
+/// One embedding of an [`EmbeddingsResponse`].
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmbeddingData {
+    /// Always `embedding`.
+    pub object: &'static str,
+    /// Position of the input this embedding belongs to.
+    pub index: usize,
+    /// Float array, or a base64 string for `encoding_format: "base64"`.
+    #[schema(value_type = Object)]
+    pub embedding: serde_json::Value,
+}
+
+/// Token counts of an [`EmbeddingsResponse`]. The upstream service's counts
+/// are not forwarded, so both are 0.
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct EmbeddingsUsage {
+    pub prompt_tokens: u64,
+    pub total_tokens: u64,
+}
+
+/// Response of `POST /embeddings`, shaped like OpenAI's.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmbeddingsResponse {
+    /// Always `list`.
+    pub object: &'static str,
+    pub data: Vec<EmbeddingData>,
+    /// The `[embedder]` model that produced the embeddings.
+    pub model: String,
+    pub usage: EmbeddingsUsage,
+}
+
+/// Body of `POST /retrieval`: query text in, documents out.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RetrievalRequest {
+    /// Collection to search; its dimension must match the embedder's.
+    #[schema(example = "docs")]
+    pub collection: String,
+    /// Query text, embedded with the `[embedder]` model.
+    #[schema(example = "how do I rotate api keys?")]
+    pub query: String,
+    /// Number of documents to return.
+    #[serde(default = "default_top_k")]
+    #[schema(example = 4)]
+    pub top_k: usize,
+    /// Optional metadata filter.
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub filter: Option<serde_json::Value>,
+    /// Payload field holding the document text (default: `text`).
+    #[serde(default = "default_retrieval_text_field")]
+    #[schema(example = "text")]
+    pub text_field: String,
+}
+
+fn default_retrieval_text_field() -> String {
+    "text".to_string()
+}
+
+/// One document of a [`RetrievalResponse`].
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RetrievedDocument {
+    /// Point id.
+    pub id: u64,
+    /// Similarity score.
+    pub score: f32,
+    /// The `text_field` value (empty when the point has none).
+    pub text: String,
+    /// The rest of the payload.
+    #[schema(value_type = Object)]
+    pub metadata: serde_json::Value,
+}
+
+/// Response of `POST /retrieval`, best match first.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RetrievalResponse {
+    pub documents: Vec<RetrievedDocument>,
+}
@@ -0,0 +1,160 @@
+//! Negotiated wire format for search and query responses.
+//!
+//! Encoding float vectors as JSON text dominates response time for large
+//! `top_k` with `with_vector`. A client sending `Accept: application/msgpack`
+//! or `Accept: application/cbor` gets the same document — same field names
+//! and shapes as the JSON body — encoded as MessagePack or CBOR, where each
+//! vector component is a 4-byte float instead of a decimal string. The format
+//! is chosen per request with [`WireFormat::from_headers`]; error responses
+//! stay JSON.
+
+use axum::{
+    http::{
+        header::{ACCEPT, CONTENT_TYPE},
+        HeaderMap, StatusCode,
+    },
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+
+use crate::handlers::helpers::error_response;
+
+/// Media type of MessagePack responses.
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// Media type of CBOR responses.
+pub const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+/// Encoding of a successful search or query response body.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WireFormat {
+    /// `application/json` (the default).
+    #[default]
+    Json,
+    /// `application/msgpack`, with struct fields encoded as named map keys.
+    MessagePack,
+    /// `application/cbor` (RFC 8949).
+    Cbor,
+}
+
+impl WireFormat {
+    /// Picks the format from the request's `Accept` header.
+    ///
+    /// The media range with the highest `q` wins, ties going to the first
+    /// listed; `application/x-msgpack` and `application/vnd.msgpack` are
+    /// accepted as MessagePack aliases. No header, `*/*`, or only
+    /// unsupported types select JSON.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let mut best: Option<(f32, Self)> = None;
+        let ranges = headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','));
+        for range in ranges {
+            let mut params = range.split(';');
+            let Some(format) = params
+                .next()
+                .and_then(|media| Self::from_media_type(media.trim()))
+            else {
+                continue;
+            };
+            let q = params
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if q > 0.0 && best.is_none_or(|(best_q, _)| q > best_q) {
+                best = Some((q, format));
+            }
+        }
+        best.map(|(_, format)| format).unwrap_or_default()
+    }
+
+    fn from_media_type(media: &str) -> Option<Self> {
+        match media.to_ascii_lowercase().as_str() {
+            "application/json" | "application/*" | "*/*" => Some(Self::Json),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::MessagePack)
+            }
+            "application/cbor" => Some(Self::Cbor),
+            _ => None,
+        }
+    }
+
+    /// `Content-Type` of a response in this format.
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::MessagePack => MSGPACK_CONTENT_TYPE,
+            Self::Cbor => CBOR_CONTENT_TYPE,
+        }
+    }
+
+    /// Encodes `body` as a `200 OK` response in this format.
+    ///
+    /// An encoding failure is reported as a JSON `500`.
+    pub fn respond<T: Serialize>(self, body: &T) -> Response {
+        let encoded = match self {
+            Self::Json => return Json(body).into_response(),
+            Self::MessagePack => rmp_serde::to_vec_named(body).map_err(|e| e.to_string()),
+            Self::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::into_writer(body, &mut bytes)
+                    .map(|()| bytes)
+                    .map_err(|e| e.to_string())
+            }
+        };
+        match encoded {
+            Ok(bytes) => ([(CONTENT_TYPE, self.content_type())], bytes).into_response(),
+            Err(e) => error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to encode response as {}: {e}", self.content_type()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn format_for(accept: &str) -> WireFormat {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_str(accept).expect("valid header"));
+        WireFormat::from_headers(&headers)
+    }
+
+    #[test]
+    fn test_from_headers_negotiates_binary_formats() {
+        assert_eq!(
+            WireFormat::from_headers(&HeaderMap::new()),
+            WireFormat::Json
+        );
+        assert_eq!(format_for("application/msgpack"), WireFormat::MessagePack);
+        assert_eq!(format_for("application/x-msgpack"), WireFormat::MessagePack);
+        assert_eq!(format_for("Application/CBOR"), WireFormat::Cbor);
+        assert_eq!(format_for("*/*"), WireFormat::Json);
+        assert_eq!(format_for("text/html, application/cbor"), WireFormat::Cbor);
+    }
+
+    #[test]
+    fn test_from_headers_honours_quality_values() {
+        assert_eq!(
+            format_for("application/json;q=0.5, application/msgpack"),
+            WireFormat::MessagePack
+        );
+        assert_eq!(
+            format_for("application/msgpack;q=0.2, application/json"),
+            WireFormat::Json
+        );
+        assert_eq!(
+            format_for("application/cbor, application/msgpack"),
+            WireFormat::Cbor
+        );
+        assert_eq!(format_for("application/cbor;q=0"), WireFormat::Json);
+    }
+}
@@ -0,0 +1,90 @@
+//! Integration tests for `GET /collections/{name}/stats/access`
+//! (`get_access_stats`).
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "access_stats";
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn test_access_stats_ranks_read_points() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/collections",
+        Some(json!({ "name": COLLECTION, "dimension": 2 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let points: Vec<Value> = (0..5)
+        .map(|i| json!({ "id": i, "vector": [1.0, f64::from(i)] }))
+        .collect();
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/points"),
+        Some(json!({ "points": points })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    for _ in 0..3 {
+        let uri = format!("/collections/{COLLECTION}/points/4");
+        let (status, _) = send(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+    let uri = format!("/collections/{COLLECTION}/points/2");
+    let (status, _) = send(&app, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let uri = format!("/collections/{COLLECTION}/stats/access?top=1");
+    let (status, body) = send(&app, "GET", &uri, None).await;
+
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["total_accesses"], 4);
+    assert_eq!(body["top"], json!([{ "id": 4, "count": 3 }]));
+    assert_eq!(body["payload_cache"]["capacity"], 0);
+}
+
+#[tokio::test]
+async fn test_access_stats_unknown_collection_returns_404() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+
+    let (status, _) = send(&app, "GET", "/collections/missing/stats/access", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
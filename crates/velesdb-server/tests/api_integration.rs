@@ -901,6 +901,94 @@ async fn test_text_search_collection_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn test_text_search_metadata_only_collection() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let app = create_test_app(&temp_dir);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/collections")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "catalog",
+                        "collection_type": "metadata_only"
+                    })
+                    .to_string(),
+                ))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Request failed");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    for (id, title, category) in [
+        (1, "Rust programming language", "books"),
+        (2, "Python cookbook", "books"),
+        (3, "Rust belt travel guide", "travel"),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/query")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": format!(
+                                "INSERT INTO catalog (id, title, category) VALUES ({id}, '{title}', '{category}')"
+                            )
+                        })
+                        .to_string(),
+                    ))
+                    .expect("Failed to build request"),
+            )
+            .await
+            .expect("Request failed");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/collections/catalog/search/text")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "query": "rust",
+                        "top_k": 10,
+                        "filter": {
+                            "condition": {
+                                "type": "eq",
+                                "field": "category",
+                                "value": "books"
+                            }
+                        }
+                    })
+                    .to_string(),
+                ))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Request failed");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let json: Value = serde_json::from_slice(&body).expect("Invalid JSON");
+    let results = json["results"].as_array().expect("Not an array");
+    assert_eq!(results.len(), 1, "only the rust book matches: {json}");
+    assert_eq!(results[0]["id"], "1");
+    assert_eq!(results[0]["payload"]["title"], "Rust programming language");
+}
+
 // =============================================================================
 // VelesQL MATCH clause tests
 // =============================================================================
@@ -2414,6 +2502,111 @@ async fn test_graph_traverse_invalid_strategy() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
+async fn post_traverse(app: &axum::Router, collection: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/collections/{collection}/graph/traverse"))
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Request failed");
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    (status, serde_json::from_slice(&body).expect("Invalid JSON"))
+}
+
+#[tokio::test]
+async fn test_graph_traverse_cursor_pages_through_bfs() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let app = create_test_app(&temp_dir);
+    create_graph_collection(&app, "paged").await;
+    for id in 1..=6 {
+        create_graph_node(&app, "paged", id).await;
+    }
+    // Chain 1 -> 2 -> ... -> 6
+    for id in 1..6u64 {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/collections/paged/graph/edges")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({"id": id, "source": id, "target": id + 1, "label": "NEXT"})
+                            .to_string(),
+                    ))
+                    .expect("Failed to build request"),
+            )
+            .await
+            .expect("Request failed");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let mut targets = Vec::new();
+    let mut cursor: Option<String> = None;
+    let mut pages = 0;
+    loop {
+        let mut body = json!({"source": 1, "max_depth": 10, "limit": 2});
+        if let Some(token) = &cursor {
+            body["cursor"] = json!(token);
+        }
+        let (status, json) = post_traverse(&app, "paged", body).await;
+        assert_eq!(status, StatusCode::OK);
+        pages += 1;
+        for result in json["results"].as_array().expect("Not an array") {
+            targets.push(result["target_id"].as_str().expect("string id").to_string());
+        }
+        assert_eq!(json["has_more"], !json["next_cursor"].is_null());
+        match json["next_cursor"].as_str() {
+            Some(token) => cursor = Some(token.to_string()),
+            None => break,
+        }
+    }
+    assert_eq!(pages, 3);
+    assert_eq!(targets, ["2", "3", "4", "5", "6"]);
+
+    // Cursors are single-use.
+    let (status, _) = post_traverse(
+        &app,
+        "paged",
+        json!({"source": 1, "limit": 2, "cursor": cursor.expect("resumed at least once")}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_graph_traverse_cursor_rejects_unknown_token_and_dfs() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let app = create_test_app(&temp_dir);
+    create_graph_collection(&app, "test").await;
+
+    let (status, json) = post_traverse(
+        &app,
+        "test",
+        json!({"source": 1, "cursor": "0123456789abcdef0123456789abcdef"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(json["error"].as_str().unwrap().contains("cursor"));
+
+    let (status, _) = post_traverse(
+        &app,
+        "test",
+        json!({"source": 1, "strategy": "dfs", "cursor": "abc"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn test_graph_node_degree() {
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -0,0 +1,135 @@
+//! Integration tests for `POST /collections/{name}/points/get`
+//! (`get_points`).
+//!
+//! Pins request-order results with unknown IDs omitted, the
+//! `with_vector` / `with_payload` projection flags, the batch size cap, and
+//! the 404 for an unknown collection.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "batch_get";
+
+async fn post_json(app: &axum::Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("test: build json request"),
+        )
+        .await
+        .expect("test: json request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seeded_app(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = post_json(
+        &app,
+        "/collections",
+        json!({ "name": COLLECTION, "dimension": 3 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/points"),
+        json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0, 0.0], "payload": { "title": "one" } },
+            { "id": 2, "vector": [0.0, 1.0, 0.0], "payload": { "title": "two" } },
+            { "id": 3, "vector": [0.0, 0.0, 1.0] }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+fn get_uri() -> String {
+    format!("/collections/{COLLECTION}/points/get")
+}
+
+#[tokio::test]
+async fn test_get_points_returns_found_points_in_request_order() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(&app, &get_uri(), json!({ "ids": [2, 99, "1"] })).await;
+    assert_eq!(status, StatusCode::OK);
+    let points = body["points"].as_array().expect("test: points array");
+    assert_eq!(points.len(), 2, "unknown id 99 must be omitted");
+    assert_eq!(points[0]["id"], "2");
+    assert_eq!(points[0]["vector"], json!([0.0, 1.0, 0.0]));
+    assert_eq!(points[0]["payload"]["title"], "two");
+    assert_eq!(points[1]["id"], "1");
+}
+
+#[tokio::test]
+async fn test_get_points_projection_flags_omit_fields() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &get_uri(),
+        json!({ "ids": [1, 3], "with_vector": false }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let points = body["points"].as_array().expect("test: points array");
+    assert_eq!(points.len(), 2, "points without payload are still found");
+    assert!(points[0].get("vector").is_none());
+    assert_eq!(points[0]["payload"]["title"], "one");
+
+    let (status, body) = post_json(
+        &app,
+        &get_uri(),
+        json!({ "ids": [1], "with_vector": false, "with_payload": false }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["points"], json!([{ "id": "1" }]));
+}
+
+#[tokio::test]
+async fn test_get_points_rejects_oversized_batch() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+    let ids: Vec<u64> = (0..10_001).collect();
+
+    let (status, _) = post_json(&app, &get_uri(), json!({ "ids": ids })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_get_points_unknown_collection_returns_404() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+
+    let (status, _) = post_json(
+        &app,
+        "/collections/missing/points/get",
+        json!({ "ids": [1] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
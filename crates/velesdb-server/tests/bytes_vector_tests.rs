@@ -0,0 +1,118 @@
+//! Integration tests for base64 `{"$bytes": ...}` vectors in upsert bodies
+//! and `VelesQL` params.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+use velesdb_core::wire::f32_base64;
+
+async fn post(app: &Router, uri: &str, body: &Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("request");
+    let response = app.clone().oneshot(request).await.expect("response");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    (status, serde_json::from_slice(&bytes).expect("json body"))
+}
+
+async fn app_with_collection(temp_dir: &TempDir) -> Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = post(
+        &app,
+        "/collections",
+        &json!({ "name": "docs", "dimension": 3, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    app
+}
+
+fn bytes(vector: &[f32]) -> Value {
+    json!({ "$bytes": f32_base64::encode(vector) })
+}
+
+#[tokio::test]
+async fn upsert_accepts_bytes_vectors_next_to_float_arrays() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_collection(&temp_dir).await;
+
+    let points = json!({ "points": [
+        { "id": 1, "vector": bytes(&[1.0, 0.0, 0.0]), "payload": { "title": "a" } },
+        { "id": 2, "vector": [0.0, 1.0, 0.0] },
+    ] });
+    let (status, body) = post(&app, "/collections/docs/points", &points).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["count"], 2);
+
+    let search = json!({ "vector": [1.0, 0.0, 0.0], "top_k": 1, "with_vector": true });
+    let (status, body) = post(&app, "/collections/docs/search", &search).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["results"][0]["id"], json!("1"));
+    assert_eq!(body["results"][0]["vector"], json!([1.0, 0.0, 0.0]));
+}
+
+#[tokio::test]
+async fn upsert_rejects_bytes_vectors_of_the_wrong_dimension() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_collection(&temp_dir).await;
+
+    // f64 bytes of a 3-d vector decode to 6 f32 values.
+    let f64_bytes: Vec<u8> = [1.0_f64, 0.0, 0.0]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    let as_f32: Vec<f32> = f64_bytes
+        .chunks_exact(4)
+        .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .collect();
+    let wrong = bytes(&as_f32);
+    let points = json!({ "points": [{ "id": 1, "vector": wrong }] });
+    let (status, body) = post(&app, "/collections/docs/points", &points).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let message = body["error"].as_str().expect("error message");
+    assert!(message.contains("Point 1"), "{message}");
+    assert!(message.contains("expected dimension 3"), "{message}");
+}
+
+#[tokio::test]
+async fn query_params_accept_bytes_vectors() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_collection(&temp_dir).await;
+    let points = json!({ "points": [
+        { "id": 1, "vector": [1.0, 0.0, 0.0] },
+        { "id": 2, "vector": [0.0, 1.0, 0.0] },
+    ] });
+    let (status, _) = post(&app, "/collections/docs/points", &points).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let query = json!({
+        "query": "SELECT * FROM docs WHERE vector NEAR $v LIMIT 1",
+        "params": { "v": bytes(&[0.0, 1.0, 0.0]) },
+    });
+    let (status, body) = post(&app, "/query", &query).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["results"][0]["id"], 2);
+
+    let bad = json!({
+        "query": "SELECT * FROM docs WHERE vector NEAR $v LIMIT 1",
+        "params": { "v": { "$bytes": "not base64!" } },
+    });
+    let (status, body) = post(&app, "/query", &bad).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "VELESQL_INVALID_PARAM");
+}
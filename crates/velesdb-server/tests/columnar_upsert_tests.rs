@@ -0,0 +1,103 @@
+//! Integration tests for the columnar upsert body
+//! (`{"ids": [...], "vectors": ..., "payloads": [...]}`).
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+use velesdb_core::wire::f32_base64;
+
+async fn send(app: &Router, method: &str, uri: &str, body: Option<&Value>) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("request");
+    let response = app.clone().oneshot(request).await.expect("response");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn app_with_collection(temp_dir: &TempDir) -> Router {
+    let app = create_test_app(temp_dir);
+    let body = json!({ "name": "docs", "dimension": 2, "metric": "cosine" });
+    let (status, _) = send(&app, "POST", "/collections", Some(&body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    app
+}
+
+#[tokio::test]
+async fn columnar_upsert_stores_vectors_and_payloads() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_collection(&temp_dir).await;
+
+    let body = json!({
+        "ids": [1, 2],
+        "vectors": [[1.0, 0.0], [0.0, 1.0]],
+        "payloads": [{ "title": "a" }, null],
+    });
+    let (status, body) = send(&app, "POST", "/collections/docs/points", Some(&body)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["count"], 2);
+
+    let encoded = f32_base64::encode(&[0.6, 0.8, 0.8, 0.6]);
+    let body = json!({ "ids": ["3", "4"], "vectors": encoded });
+    let (status, body) = send(&app, "POST", "/collections/docs/points", Some(&body)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["count"], 2);
+
+    let (status, point) = send(&app, "GET", "/collections/docs/points/1", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(point["payload"], json!({ "title": "a" }));
+    let (_, point) = send(&app, "GET", "/collections/docs/points/4", None).await;
+    assert_eq!(point["vector"], json!([0.8_f32, 0.6_f32]));
+    assert_eq!(point["payload"], Value::Null);
+}
+
+#[tokio::test]
+async fn columnar_upsert_rejects_misaligned_columns() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_collection(&temp_dir).await;
+
+    let cases = [
+        (
+            json!({ "ids": [1, 2], "vectors": [[1.0, 0.0]] }),
+            "expected one per id",
+        ),
+        (
+            json!({ "ids": [1], "vectors": [[1.0, 0.0]], "payloads": [{}, {}] }),
+            "payloads has 2 entries",
+        ),
+        (
+            json!({ "ids": [1], "vectors": f32_base64::encode(&[1.0]) }),
+            "decode to 1 floats",
+        ),
+        (json!({ "ids": [1] }), "requires `vectors`"),
+        (
+            json!({ "ids": [1], "vectors": [[1.0, 0.0]], "points": [{ "id": 2, "vector": [0.0, 1.0] }] }),
+            "not both",
+        ),
+    ];
+    for (body, expected) in cases {
+        let (status, response) = send(&app, "POST", "/collections/docs/points", Some(&body)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+        let message = response["error"].as_str().unwrap_or_default();
+        assert!(message.contains(expected), "{message}");
+    }
+}
@@ -2,7 +2,7 @@
 #![allow(dead_code)]
 
 use axum::{
-    routing::{get, patch, post},
+    routing::{get, patch, post, put},
     Router,
 };
 use std::path::Path;
@@ -11,21 +11,29 @@ use tempfile::TempDir;
 
 use velesdb_core::Database;
 use velesdb_server::{
-    add_edge, add_edges_batch, aggregate,
+    add_edge, add_edges_batch, aggregate, analyze_collection,
     auth::{auth_middleware, AuthState},
     batch_search, bulk_delete_points, collection_diagnostics, collection_sanity,
-    compact_collection, create_collection, delete_collection, delete_point, enable_streaming,
-    explain, get_collection, get_collection_config, get_edges, get_node_degree, get_node_payload,
-    get_point, health_check, hybrid_search, list_collections, list_nodes, match_query,
-    multi_query_search, multi_query_search_ids, query, readiness_check, rebuild_index,
-    relate_points, reorder_for_locality, scroll_points, search, search_ids, set_point_ttl,
-    stream_insert, stream_upsert_points, text_search, traverse_graph, upsert_node_payload,
-    upsert_points, upsert_points_raw, vacuum_collection, AppState, OnboardingMetrics,
+    compact_collection, count_points, create_collection, create_embeddings, create_view,
+    delete_collection, delete_latency_slo, delete_point, delete_row_security, delete_view,
+    enable_streaming, explain, export_graph, get_access_stats, get_collection,
+    get_collection_config, get_collection_stats, get_edge_count, get_edges, get_graph_stats,
+    get_guardrails, get_latency_slo, get_node_degree, get_node_edges, get_node_payload, get_point,
+    get_point_relations, get_points, get_row_security, get_server_config, get_vector_stats,
+    get_view, health_check, health_details, hybrid_search, import_graph, list_collections,
+    list_nodes, list_views, match_query, multi_query_search, multi_query_search_ids,
+    project_collection, query, readiness_check, rebuild_index, recommend, reindex_status,
+    relate_points, reload_config, reorder_for_locality, retrieval, scroll_points, search,
+    search_ids, set_embedding_info, set_latency_slo, set_point_ttl, set_row_security,
+    start_reindex, stream_insert, stream_traverse, stream_upsert_points, subscribe_collection,
+    text_search, traverse_graph, traverse_parallel, upsert_node_payload, upsert_points,
+    upsert_points_raw, vacuum_collection, AppState, OnboardingMetrics,
 };
 
 fn base_routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/health", get(health_check))
+        .route("/health/details", get(health_details))
         .route("/ready", get(readiness_check))
         .route(
             "/collections",
@@ -35,13 +43,39 @@ fn base_routes() -> Router<Arc<AppState>> {
             "/collections/{name}",
             get(get_collection).delete(delete_collection),
         )
+        .route("/views", get(list_views).post(create_view))
+        .route("/views/{name}", get(get_view).delete(delete_view))
+        .route(
+            "/collections/{name}/row-security",
+            get(get_row_security)
+                .put(set_row_security)
+                .delete(delete_row_security),
+        )
+        .route(
+            "/collections/{name}/slo",
+            get(get_latency_slo)
+                .put(set_latency_slo)
+                .delete(delete_latency_slo),
+        )
         .route("/collections/{name}/config", get(get_collection_config))
+        .route("/config", get(get_server_config))
+        .route("/config/reload", post(reload_config))
+        .route("/guardrails", get(get_guardrails))
+        .route("/collections/{name}/embedding", put(set_embedding_info))
         .route(
             "/collections/{name}/diagnostics",
             get(collection_diagnostics),
         )
         .route("/collections/{name}/index/rebuild", post(rebuild_index))
+        .route("/collections/{name}/reindex", post(start_reindex))
+        .route("/collections/{name}/reindex/status", get(reindex_status))
         .route("/collections/{name}/sanity", get(collection_sanity))
+        .route("/collections/{name}/analyze", post(analyze_collection))
+        .route("/collections/{name}/stats", get(get_collection_stats))
+        .route("/collections/{name}/subscribe", get(subscribe_collection))
+        .route("/collections/{name}/stats/vectors", get(get_vector_stats))
+        .route("/collections/{name}/stats/access", get(get_access_stats))
+        .route("/collections/{name}/projection", get(project_collection))
         .route("/collections/{name}/points", post(upsert_points))
         .route("/collections/{name}/points/raw", post(upsert_points_raw))
         .route(
@@ -55,6 +89,8 @@ fn base_routes() -> Router<Arc<AppState>> {
             get(get_point).delete(delete_point),
         )
         .route("/collections/{name}/points/scroll", post(scroll_points))
+        .route("/collections/{name}/points/get", post(get_points))
+        .route("/collections/{name}/points/count", post(count_points))
         .route("/collections/{name}/points/{id}/ttl", patch(set_point_ttl))
         .route("/collections/{name}/search", post(search))
         .route("/collections/{name}/search/batch", post(batch_search))
@@ -66,6 +102,9 @@ fn base_routes() -> Router<Arc<AppState>> {
         .route("/collections/{name}/search/text", post(text_search))
         .route("/collections/{name}/search/hybrid", post(hybrid_search))
         .route("/collections/{name}/search/ids", post(search_ids))
+        .route("/collections/{name}/recommend", post(recommend))
+        .route("/embeddings", post(create_embeddings))
+        .route("/retrieval", post(retrieval))
         .route("/collections/{name}/match", post(match_query))
         .route("/query", post(query))
         .route("/aggregate", post(aggregate))
@@ -84,6 +123,19 @@ fn graph_and_maintenance_routes() -> Router<Arc<AppState>> {
             post(add_edges_batch),
         )
         .route("/collections/{name}/graph/traverse", post(traverse_graph))
+        .route(
+            "/collections/{name}/graph/traverse/parallel",
+            post(traverse_parallel),
+        )
+        .route(
+            "/collections/{name}/graph/traverse/stream",
+            get(stream_traverse),
+        )
+        .route("/collections/{name}/graph/edges/count", get(get_edge_count))
+        .route(
+            "/collections/{name}/graph/nodes/{node_id}/edges",
+            get(get_node_edges),
+        )
         .route(
             "/collections/{name}/graph/nodes/{node_id}/degree",
             get(get_node_degree),
@@ -93,7 +145,14 @@ fn graph_and_maintenance_routes() -> Router<Arc<AppState>> {
             "/collections/{name}/graph/nodes/{node_id}/payload",
             get(get_node_payload).put(upsert_node_payload),
         )
+        .route("/collections/{name}/graph/export", get(export_graph))
+        .route("/collections/{name}/graph/stats", get(get_graph_stats))
+        .route("/collections/{name}/graph/import", post(import_graph))
         .route("/collections/{name}/relations", post(relate_points))
+        .route(
+            "/collections/{name}/points/{id}/relations",
+            get(get_point_relations),
+        )
         // Maintenance + bulk endpoints (PR #648)
         .route(
             "/collections/{name}/points/delete",
@@ -112,12 +171,18 @@ fn app_state_from_db(db: Database) -> Arc<AppState> {
         db,
         onboarding_metrics: OnboardingMetrics::default(),
         query_limits: parking_lot::RwLock::new(velesdb_core::guardrails::QueryLimits::default()),
+        effective_config: parking_lot::RwLock::default(),
+        config_reloader: None,
+        reindex_jobs: velesdb_server::reindex::ReindexJobs::default(),
+        index_events: velesdb_server::index_events::IndexEventHub::default(),
         ready: std::sync::atomic::AtomicBool::new(true),
         operational_metrics: velesdb_core::metrics::OperationalMetrics::new_arc(),
         traversal_metrics: std::sync::Arc::new(velesdb_core::metrics::TraversalMetrics::new()),
+        traversal_cursors: velesdb_server::traversal_cursors::TraversalCursors::default(),
         query_duration_histogram: std::sync::Arc::new(
             velesdb_core::metrics::DurationHistogram::new(),
         ),
+        embedder: parking_lot::RwLock::default(),
     })
 }
 
@@ -164,6 +229,72 @@ pub fn create_test_app_with_core_config(temp_dir: &TempDir, config_path: &Path)
     base_routes().with_state(app_state_from_db(db))
 }
 
+/// Helper to create a test app from a full `velesdb.toml` — server
+/// sections through [`velesdb_server::config::ServerConfig::load`] and
+/// engine sections through `load_core_config` — wired into the
+/// [`AppState`], auth, CORS and compression middleware and config reloader
+/// the same way `main` does. The rate limiter is not layered: test requests
+/// carry no peer address for it to key on.
+///
+/// # Panics
+///
+/// Panics if the config file fails to load or the database fails to open.
+pub fn create_test_app_with_config_file(temp_dir: &TempDir, config_path: &Path) -> Router {
+    use velesdb_server::config::{
+        cors_middleware, load_core_config, CliOverrides, CorsState, EffectiveConfig, ServerConfig,
+    };
+    use velesdb_server::rate_limit::RateLimitState;
+    use velesdb_server::reload::ConfigReloader;
+
+    let config_path = Some(config_path.to_path_buf());
+    let cli = CliOverrides {
+        config_path: config_path.clone(),
+        ..CliOverrides::default()
+    };
+    let server = ServerConfig::load(cli.clone()).expect("Failed to load server config");
+    let auth_state =
+        AuthState::new(server.api_keys.clone()).with_key_contexts(server.key_contexts.clone());
+    let cors = CorsState::new(&server.cors);
+    let compression = server.compression.clone();
+    let reloader = ConfigReloader::new(
+        cli,
+        auth_state.clone(),
+        RateLimitState::new(server.rate_limit).expect("Failed to build rate limiter"),
+        cors.clone(),
+    );
+    let engine = load_core_config(&config_path).expect("Failed to load core config");
+    let db = Database::open_with_config(temp_dir.path(), engine.clone())
+        .expect("Failed to open database with core config");
+    let state = Arc::new(AppState {
+        db,
+        onboarding_metrics: OnboardingMetrics::default(),
+        query_limits: parking_lot::RwLock::new(server.guardrails.clone()),
+        effective_config: parking_lot::RwLock::new(EffectiveConfig { server, engine }),
+        config_reloader: Some(Arc::new(reloader)),
+        reindex_jobs: velesdb_server::reindex::ReindexJobs::default(),
+        index_events: velesdb_server::index_events::IndexEventHub::default(),
+        ready: std::sync::atomic::AtomicBool::new(true),
+        operational_metrics: velesdb_core::metrics::OperationalMetrics::new_arc(),
+        traversal_metrics: std::sync::Arc::new(velesdb_core::metrics::TraversalMetrics::new()),
+        traversal_cursors: velesdb_server::traversal_cursors::TraversalCursors::default(),
+        query_duration_histogram: std::sync::Arc::new(
+            velesdb_core::metrics::DurationHistogram::new(),
+        ),
+        embedder: parking_lot::RwLock::default(),
+    });
+    base_routes()
+        .with_state(state)
+        .layer(axum::middleware::from_fn_with_state(
+            auth_state,
+            auth_middleware,
+        ))
+        .layer(axum::middleware::from_fn_with_state(cors, cors_middleware))
+        .layer(velesdb_server::compression::build_compression_layer(
+            &compression,
+        ))
+        .layer(velesdb_server::compression::build_decompression_layer())
+}
+
 /// Helper to create test app and return the shared state for direct manipulation.
 pub fn create_test_app_with_state(temp_dir: &TempDir) -> (Router, Arc<AppState>) {
     let state = create_app_state(temp_dir);
@@ -183,8 +314,20 @@ pub fn create_test_app_with_auth(temp_dir: &TempDir, api_keys: Vec<String>) -> R
         ))
 }
 
+/// Helper to create test app with the `Idempotency-Key` middleware, layered
+/// the way `build_router()` layers it.
+pub fn create_test_app_with_idempotency(temp_dir: &TempDir) -> Router {
+    let state = create_app_state(temp_dir);
+    base_routes()
+        .with_state(Arc::clone(&state))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            velesdb_server::idempotency::idempotency_middleware,
+        ))
+}
+
 /// Middleware that adds deprecation headers for unversioned legacy routes.
-/// Mirrors the production middleware in `main.rs`.
+/// Mirrors the production middleware in `embedded.rs`.
 async fn deprecation_header(
     request: axum::extract::Request,
     next: axum::middleware::Next,
@@ -205,8 +348,8 @@ async fn deprecation_header(
 }
 
 /// Helper to create test app with `/v1/` versioned routes and legacy
-/// unversioned routes (with deprecation headers). Mirrors `build_router()`
-/// from the production binary.
+/// unversioned routes (with deprecation headers). Mirrors `router_for_state()`
+/// used by the production binary.
 pub fn create_versioned_test_app(temp_dir: &TempDir) -> Router {
     let state = create_app_state(temp_dir);
     let routes = base_routes();
@@ -0,0 +1,149 @@
+//! Integration tests for gzip/zstd response compression and compressed
+//! request bodies (`[compression]`).
+
+mod common;
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+fn app_with_config(temp_dir: &TempDir, config_dir: &Path, toml: &str) -> Router {
+    let config_path = config_dir.join("velesdb.toml");
+    std::fs::write(&config_path, toml).expect("write config");
+    common::create_test_app_with_config_file(temp_dir, &config_path)
+}
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, Option<String>, Vec<u8>) {
+    let response = app.clone().oneshot(request).await.expect("request");
+    let status = response.status();
+    let encoding = response
+        .headers()
+        .get("content-encoding")
+        .map(|v| v.to_str().expect("ascii header").to_string());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body")
+        .to_vec();
+    (status, encoding, body)
+}
+
+async fn create_collection(app: &Router) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/collections")
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({ "name": "docs", "dimension": 2, "metric": "cosine" }).to_string(),
+        ))
+        .expect("request");
+    let (status, _, _) = send(app, request).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+fn list_collections(accept_encoding: &str) -> Request<Body> {
+    Request::builder()
+        .uri("/collections")
+        .header("Accept-Encoding", accept_encoding)
+        .body(Body::empty())
+        .expect("request")
+}
+
+#[tokio::test]
+async fn responses_are_compressed_for_clients_that_accept_it() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let config_dir = TempDir::new().expect("config dir");
+    let app = app_with_config(
+        &temp_dir,
+        config_dir.path(),
+        "[compression]\nmin_size_bytes = 0\n",
+    );
+    create_collection(&app).await;
+
+    let (status, encoding, body) = send(&app, list_collections("gzip")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(encoding.as_deref(), Some("gzip"));
+    let mut json = String::new();
+    flate2::read::GzDecoder::new(body.as_slice())
+        .read_to_string(&mut json)
+        .expect("gunzip");
+    assert!(json.contains("docs"), "{json}");
+
+    let (_, encoding, body) = send(&app, list_collections("zstd")).await;
+    assert_eq!(encoding.as_deref(), Some("zstd"));
+    let json: Value =
+        serde_json::from_slice(&zstd::decode_all(body.as_slice()).expect("unzstd")).expect("json");
+    assert!(json.to_string().contains("docs"));
+}
+
+#[tokio::test]
+async fn small_responses_and_disabled_compression_stay_plain() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let config_dir = TempDir::new().expect("config dir");
+    let app = app_with_config(
+        &temp_dir,
+        config_dir.path(),
+        "[compression]\nmin_size_bytes = 1000000\n",
+    );
+    let (_, encoding, _) = send(&app, list_collections("gzip")).await;
+    assert_eq!(encoding, None, "below the size threshold");
+
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_config(
+        &temp_dir,
+        config_dir.path(),
+        "[compression]\nenabled = false\nmin_size_bytes = 0\n",
+    );
+    let (_, encoding, _) = send(&app, list_collections("gzip, zstd")).await;
+    assert_eq!(encoding, None, "compression disabled");
+}
+
+#[tokio::test]
+async fn zstd_ndjson_stream_and_gzip_json_upserts_are_decompressed() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let config_dir = TempDir::new().expect("config dir");
+    let app = app_with_config(&temp_dir, config_dir.path(), "");
+    create_collection(&app).await;
+
+    let ndjson: String = (1..=50u16)
+        .map(|id| json!({ "id": id, "vector": [1.0, f32::from(id)] }).to_string() + "\n")
+        .collect();
+    let request = Request::builder()
+        .method("POST")
+        .uri("/collections/docs/points/stream")
+        .header("Content-Type", "application/x-ndjson")
+        .header("Content-Encoding", "zstd")
+        .body(Body::from(
+            zstd::encode_all(ndjson.as_bytes(), 3).expect("zstd"),
+        ))
+        .expect("request");
+    let (status, _, body) = send(&app, request).await;
+    assert_eq!(status, StatusCode::OK);
+    let ingest: Value = serde_json::from_slice(&body).expect("json");
+    assert_eq!(ingest["inserted"], 50, "{ingest}");
+    assert_eq!(ingest["malformed"], 0);
+
+    let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    gzip.write_all(
+        json!({ "points": [{ "id": 99, "vector": [0.0, 1.0] }] })
+            .to_string()
+            .as_bytes(),
+    )
+    .expect("gzip");
+    let request = Request::builder()
+        .method("POST")
+        .uri("/collections/docs/points")
+        .header("Content-Type", "application/json")
+        .header("Content-Encoding", "gzip")
+        .body(Body::from(gzip.finish().expect("gzip")))
+        .expect("request");
+    let (status, _, body) = send(&app, request).await;
+    assert_eq!(status, StatusCode::OK, "{}", String::from_utf8_lossy(&body));
+}
@@ -176,3 +176,134 @@ async fn test_explicit_invalid_value_fails_fast_with_typed_config_error() {
         "unexpected error: {err}"
     );
 }
+
+async fn send(app: axum::Router, method: &str, uri: &str, key: &str) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("Authorization", format!("Bearer {key}"))
+                .body(Body::empty())
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Request failed");
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+async fn get_json(app: axum::Router, uri: &str, key: &str) -> Value {
+    let (status, json) = send(app, "GET", uri, key).await;
+    assert_eq!(status, StatusCode::OK);
+    json
+}
+
+/// `GET /config` reports every section of the file — server, auth, CORS,
+/// compression, guard-rails and engine — with API keys reduced to a count.
+#[tokio::test]
+async fn test_config_endpoint_reports_effective_redacted_config() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let config_dir = TempDir::new().expect("Failed to create config dir");
+    let config_path = config_dir.path().join("velesdb.toml");
+    std::fs::write(
+        &config_path,
+        r#"
+[server]
+port = 9091
+worker_threads = 4
+
+[auth]
+api_keys = ["secret-key-one", "secret-key-two"]
+
+[cors]
+allowed_origins = ["https://app.example.com"]
+
+[compression]
+level = 6
+
+[guardrails]
+timeout_ms = 5000
+max_depth = 4
+
+[limits]
+max_collections = 7
+"#,
+    )
+    .expect("Failed to write config");
+
+    let app = common::create_test_app_with_config_file(&temp_dir, &config_path);
+    let json = get_json(app.clone(), "/config", "secret-key-one").await;
+
+    assert_eq!(json["server"]["port"], 9091);
+    assert_eq!(json["server"]["worker_threads"], 4);
+    assert!(json["server"]["max_blocking_threads"].is_null());
+    assert_eq!(json["auth"]["enabled"], true);
+    assert_eq!(json["auth"]["api_key_count"], 2);
+    assert!(
+        !json.to_string().contains("secret-key"),
+        "API keys must never be returned: {json}"
+    );
+    assert_eq!(
+        json["cors"]["allowed_origins"],
+        json!(["https://app.example.com"])
+    );
+    assert_eq!(json["compression"]["enabled"], true);
+    assert_eq!(json["compression"]["level"], 6);
+    assert_eq!(json["compression"]["min_size_bytes"], 1024);
+    assert_eq!(json["guardrails"]["timeout_ms"], 5000);
+    assert_eq!(json["guardrails"]["max_depth"], 4);
+    assert_eq!(json["engine"]["limits"]["max_collections"], 7);
+    assert!(json["engine"].get("server").is_none());
+
+    // `[guardrails]` also seeds the live limits.
+    let guardrails = get_json(app, "/guardrails", "secret-key-one").await;
+    assert_eq!(guardrails["timeout_ms"], 5000);
+    // Unset keys keep their defaults.
+    assert_eq!(
+        guardrails["max_cardinality"],
+        velesdb_core::guardrails::QueryLimits::default().max_cardinality
+    );
+}
+
+/// `POST /config/reload` rotates API keys and applies new guard-rails
+/// without a restart, and reports settings it cannot apply live.
+#[tokio::test]
+async fn test_config_reload_rotates_api_keys() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let config_dir = TempDir::new().expect("Failed to create config dir");
+    let config_path = config_dir.path().join("velesdb.toml");
+    std::fs::write(&config_path, "[auth]\napi_keys = [\"old-key\"]\n").expect("write config");
+
+    let app = common::create_test_app_with_config_file(&temp_dir, &config_path);
+    let (status, _) = send(app.clone(), "GET", "/collections", "old-key").await;
+    assert_eq!(status, StatusCode::OK);
+
+    std::fs::write(
+        &config_path,
+        "[server]\nport = 9999\n\n[auth]\napi_keys = [\"new-key\"]\n\n[guardrails]\ntimeout_ms = 1234\n",
+    )
+    .expect("rewrite config");
+    let (status, body) = send(app.clone(), "POST", "/config/reload", "old-key").await;
+    assert_eq!(status, StatusCode::OK, "reload failed: {body}");
+    assert_eq!(body["applied"], json!(["auth", "guardrails"]));
+    assert_eq!(body["restart_required"], json!(["port"]));
+
+    let (status, _) = send(app.clone(), "GET", "/collections", "old-key").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let config = get_json(app.clone(), "/config", "new-key").await;
+    assert_eq!(config["auth"]["api_key_count"], 1);
+    assert_eq!(config["guardrails"]["timeout_ms"], 1234);
+    // Not applied live, so still the startup value.
+    assert_eq!(config["server"]["port"], 8080);
+
+    // A broken file leaves everything as it was.
+    std::fs::write(&config_path, "[auth]\napi_keys = \"not-a-list\"\n").expect("write config");
+    let (status, _) = send(app.clone(), "POST", "/config/reload", "new-key").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = send(app, "GET", "/collections", "new-key").await;
+    assert_eq!(status, StatusCode::OK);
+}
@@ -0,0 +1,120 @@
+//! Integration tests for `POST /collections/{name}/points/count`
+//! (`count_points`) and the `SELECT COUNT(*)` route onto it.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "count_points";
+
+async fn post_json(app: &axum::Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("test: build json request"),
+        )
+        .await
+        .expect("test: json request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seeded_app(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = post_json(
+        &app,
+        "/collections",
+        json!({ "name": COLLECTION, "dimension": 2 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/points"),
+        json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0], "payload": { "lang": "rust" } },
+            { "id": 2, "vector": [0.0, 1.0], "payload": { "lang": "go" } },
+            { "id": 3, "vector": [1.0, 1.0], "payload": { "lang": "rust" } }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+fn count_uri() -> String {
+    format!("/collections/{COLLECTION}/points/count")
+}
+
+fn lang_filter(lang: &str) -> Value {
+    json!({ "condition": { "type": "eq", "field": "lang", "value": lang } })
+}
+
+#[tokio::test]
+async fn test_count_points_with_and_without_filter() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(&app, &count_uri(), json!({})).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["count"], 3);
+
+    let (status, body) =
+        post_json(&app, &count_uri(), json!({ "filter": lang_filter("rust") })).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["count"], 2);
+}
+
+#[tokio::test]
+async fn test_count_points_invalid_filter_returns_400() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, _) = post_json(&app, &count_uri(), json!({ "filter": { "bogus": true } })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_count_points_unknown_collection_returns_404() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+
+    let (status, _) = post_json(&app, "/collections/missing/points/count", json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_select_count_star_agrees_with_count_endpoint() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        "/query",
+        json!({
+            "query": format!("SELECT COUNT(*) FROM {COLLECTION} WHERE lang = 'rust'"),
+            "params": {}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["result"]["count"], 2, "{body}");
+}
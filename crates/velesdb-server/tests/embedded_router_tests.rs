@@ -0,0 +1,115 @@
+//! Integration tests for the embedded router (`velesdb_server::build_router`)
+//! mounted inside a host Axum application.
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::get,
+    Router,
+};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+use velesdb_core::Database;
+use velesdb_server::{build_router, RouterOptions};
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+    api_key: Option<&str>,
+) -> (StatusCode, Value) {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if let Some(key) = api_key {
+        builder = builder.header("Authorization", format!("Bearer {key}"));
+    }
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+fn host_app(temp_dir: &TempDir, options: RouterOptions) -> Router {
+    let db = Database::open(temp_dir.path()).expect("test: open database");
+    Router::new().route("/", get(|| async { "host" })).nest(
+        "/db",
+        build_router(db, options).expect("test: build router"),
+    )
+}
+
+#[tokio::test]
+async fn test_api_is_served_under_host_prefix() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = host_app(&temp_dir, RouterOptions::default());
+
+    let (status, _) = send(&app, "GET", "/", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = send(
+        &app,
+        "POST",
+        "/db/v1/collections",
+        Some(json!({ "name": "docs", "dimension": 2 })),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let (status, body) = send(
+        &app,
+        "POST",
+        "/db/v1/collections/docs/points",
+        Some(json!({ "points": [{ "id": 1, "vector": [1.0, 0.0] }] })),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let (status, body) = send(
+        &app,
+        "POST",
+        "/db/v1/collections/docs/search",
+        Some(json!({ "vector": [1.0, 0.0], "top_k": 1 })),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["results"].as_array().map(Vec::len), Some(1));
+
+    // Legacy unversioned routes are off by default.
+    let (status, _) = send(&app, "GET", "/db/collections", None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_options_enable_auth_and_legacy_routes() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = host_app(
+        &temp_dir,
+        RouterOptions {
+            api_keys: vec!["secret".to_string()],
+            legacy_routes: true,
+            ..RouterOptions::default()
+        },
+    );
+
+    let (status, _) = send(&app, "GET", "/db/v1/collections", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = send(&app, "GET", "/db/v1/collections", None, Some("secret")).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&app, "GET", "/db/collections", None, Some("secret")).await;
+    assert_eq!(status, StatusCode::OK);
+    // Host routes are outside the embedded API's auth layer.
+    let (status, _) = send(&app, "GET", "/", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+}
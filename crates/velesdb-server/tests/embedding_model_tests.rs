@@ -0,0 +1,182 @@
+//! Integration tests for collection-level embedding model metadata:
+//!
+//! - `embedding_model` on `POST /collections`
+//! - `PUT /collections/{name}/embedding`
+//! - `X-Embedding-Model` checks on upsert and search
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "embedding_model";
+
+async fn send(app: &Router, method: &str, uri: &str, model: Option<&str>, body: Value) -> Response {
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json");
+    if let Some(model) = model {
+        builder = builder.header("X-Embedding-Model", model);
+    }
+    let response = app
+        .clone()
+        .oneshot(
+            builder
+                .body(Body::from(body.to_string()))
+                .expect("test: build request"),
+        )
+        .await
+        .expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    Response { status, json }
+}
+
+struct Response {
+    status: StatusCode,
+    json: Value,
+}
+
+async fn create_with_model(app: &Router) {
+    let resp = send(
+        app,
+        "POST",
+        "/collections",
+        None,
+        json!({
+            "name": COLLECTION,
+            "dimension": 4,
+            "metric": "cosine",
+            "embedding_model": "bge-m3",
+            "embedding_model_version": "1.5"
+        }),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CREATED, "{}", resp.json);
+}
+
+fn upsert_body() -> Value {
+    json!({ "points": [{ "id": 1, "vector": [0.1, 0.2, 0.3, 0.4] }] })
+}
+
+#[tokio::test]
+async fn test_create_with_embedding_model_is_reported_in_config() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    create_with_model(&app).await;
+
+    let resp = send(
+        &app,
+        "GET",
+        &format!("/collections/{COLLECTION}/config"),
+        None,
+        Value::Null,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.json["embedding_info"]["model"], "bge-m3");
+    assert_eq!(resp.json["embedding_info"]["version"], "1.5");
+    assert_eq!(resp.json["embedding_info"]["dimension"], 4);
+}
+
+#[tokio::test]
+async fn test_upsert_with_matching_or_absent_model_succeeds() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    create_with_model(&app).await;
+    let uri = format!("/collections/{COLLECTION}/points");
+
+    for model in [None, Some("bge-m3"), Some("BGE-M3@1.5")] {
+        let resp = send(&app, "POST", &uri, model, upsert_body()).await;
+        assert_eq!(
+            resp.status,
+            StatusCode::OK,
+            "model {model:?}: {}",
+            resp.json
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_upsert_and_search_reject_mismatched_model() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    create_with_model(&app).await;
+
+    let resp = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/points"),
+        Some("text-embedding-3-small"),
+        upsert_body(),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+    assert_eq!(resp.json["code"], "VELES-017");
+
+    let resp = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/search"),
+        Some("bge-m3@2.0"),
+        json!({ "vector": [0.1, 0.2, 0.3, 0.4], "top_k": 1 }),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+    assert_eq!(resp.json["code"], "VELES-017");
+}
+
+#[tokio::test]
+async fn test_put_embedding_sets_and_clears_metadata() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    let resp = send(
+        &app,
+        "POST",
+        "/collections",
+        None,
+        json!({ "name": COLLECTION, "dimension": 4 }),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let uri = format!("/collections/{COLLECTION}/embedding");
+    let resp = send(&app, "PUT", &uri, None, json!({ "model": "e5-small" })).await;
+    assert_eq!(resp.status, StatusCode::OK, "{}", resp.json);
+    assert_eq!(resp.json["embedding_info"]["dimension"], 4);
+
+    let points = format!("/collections/{COLLECTION}/points");
+    let resp = send(&app, "POST", &points, Some("bge-m3"), upsert_body()).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+
+    let resp = send(&app, "PUT", &uri, None, json!({ "model": null })).await;
+    assert_eq!(resp.status, StatusCode::OK);
+    let resp = send(&app, "POST", &points, Some("bge-m3"), upsert_body()).await;
+    assert_eq!(resp.status, StatusCode::OK, "{}", resp.json);
+}
+
+#[tokio::test]
+async fn test_put_embedding_unknown_collection_returns_404() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    let resp = send(
+        &app,
+        "PUT",
+        "/collections/missing/embedding",
+        None,
+        json!({ "model": "bge-m3" }),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,145 @@
+//! Integration tests for the `explain_scores` flag on the search endpoints.
+//!
+//! Pins that `score_breakdown` is absent by default and that dense, text and
+//! hybrid searches report their score components when the flag is set.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "explain_scores";
+
+async fn post_json(app: &axum::Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("test: build json request"),
+        )
+        .await
+        .expect("test: json request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seeded_app(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = post_json(
+        &app,
+        "/collections",
+        json!({ "name": COLLECTION, "dimension": 3, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/points"),
+        json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0, 0.0], "payload": { "title": "rust vectors" } },
+            { "id": 2, "vector": [0.0, 1.0, 0.0], "payload": { "title": "graph walk" } }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+fn approx(value: &Value, expected: f64) -> bool {
+    value.as_f64().is_some_and(|v| (v - expected).abs() < 1e-5)
+}
+
+#[tokio::test]
+async fn test_dense_search_breakdown_only_when_requested() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+    let uri = format!("/collections/{COLLECTION}/search");
+
+    let (status, body) =
+        post_json(&app, &uri, json!({ "vector": [1.0, 0.0, 0.0], "top_k": 1 })).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(body["results"][0].get("score_breakdown").is_none());
+
+    let (status, body) = post_json(
+        &app,
+        &uri,
+        json!({ "vector": [1.0, 0.0, 0.0], "top_k": 1, "explain_scores": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let breakdown = &body["results"][0]["score_breakdown"];
+    assert!(approx(&breakdown["vector_similarity"], 1.0), "{breakdown}");
+    assert!(approx(&breakdown["raw_distance"], 0.0), "{breakdown}");
+    let score = body["results"][0]["score"].as_f64().unwrap_or_default();
+    assert!(approx(&breakdown["final_score"], score), "{breakdown}");
+}
+
+#[tokio::test]
+async fn test_text_search_breakdown_reports_bm25() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search/text"),
+        json!({ "query": "rust", "top_k": 1, "explain_scores": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let hit = &body["results"][0];
+    let score = hit["score"].as_f64().unwrap_or_default();
+    assert!(
+        approx(&hit["score_breakdown"]["bm25_score"], score),
+        "{hit}"
+    );
+    assert!(hit["score_breakdown"].get("vector_similarity").is_none());
+}
+
+#[tokio::test]
+async fn test_hybrid_search_breakdown_reports_fusion_components() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search/hybrid"),
+        json!({
+            "vector": [1.0, 0.0, 0.0],
+            "query": "rust",
+            "top_k": 1,
+            "vector_weight": 0.75,
+            "explain_scores": true
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let hit = &body["results"][0];
+    assert_eq!(hit["id"], "1");
+    let breakdown = &hit["score_breakdown"];
+    assert_eq!(breakdown["fusion_method"], "rrf");
+    assert!(approx(&breakdown["vector_weight"], 0.75), "{breakdown}");
+    assert!(approx(&breakdown["text_weight"], 0.25), "{breakdown}");
+    assert!(approx(&breakdown["vector_similarity"], 1.0), "{breakdown}");
+    assert!(breakdown["bm25_score"].as_f64().is_some_and(|s| s > 0.0));
+    let fused = breakdown["vector_contribution"]
+        .as_f64()
+        .unwrap_or_default()
+        + breakdown["text_contribution"].as_f64().unwrap_or_default();
+    assert!(approx(&hit["score"], fused), "{breakdown}");
+}
@@ -0,0 +1,133 @@
+//! Integration tests for `GET /collections/{name}/graph/export` and
+//! `POST /collections/{name}/graph/import`.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+async fn send(app: &axum::Router, method: &str, uri: &str, body: String) -> (StatusCode, String) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::from(body))
+                .expect("test: build request"),
+        )
+        .await
+        .expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (status, String::from_utf8_lossy(&bytes).into_owned())
+}
+
+async fn create_graph(app: &axum::Router, name: &str) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/collections")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    json!({ "name": name, "collection_type": "graph" }).to_string(),
+                ))
+                .expect("test: build create request"),
+        )
+        .await
+        .expect("test: create graph");
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+const SOCIAL_JSONL: &str = r#"{"type":"node","id":1,"labels":["Person"],"properties":{"name":"Alice"}}
+{"type":"node","id":2,"labels":["Person"],"properties":{"name":"Bob"}}
+{"type":"edge","id":7,"source":1,"target":2,"label":"KNOWS","properties":{"since":2020}}
+"#;
+
+#[tokio::test]
+async fn test_import_then_export_round_trips_between_formats() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    create_graph(&app, "social").await;
+    create_graph(&app, "copy").await;
+
+    let (status, body) = send(
+        &app,
+        "POST",
+        "/collections/social/graph/import?format=jsonl",
+        SOCIAL_JSONL.to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let counts: Value = serde_json::from_str(&body).expect("test: counts json");
+    assert_eq!(counts, json!({ "nodes": 2, "edges": 1 }));
+
+    let (status, graphml) = send(
+        &app,
+        "GET",
+        "/collections/social/graph/export?format=graphml",
+        String::new(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(
+        graphml.contains("<edge id=\"7\" source=\"1\" target=\"2\">"),
+        "{graphml}"
+    );
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/collections/copy/graph/import?format=graphml",
+        graphml,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, exported) = send(&app, "GET", "/collections/copy/graph/export", String::new()).await;
+    assert_eq!(exported.lines().count(), 3);
+    assert!(exported.contains(r#""since":2020"#), "{exported}");
+}
+
+#[tokio::test]
+async fn test_import_rejects_bad_format_and_malformed_documents() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    create_graph(&app, "g").await;
+
+    let (status, _) = send(
+        &app,
+        "GET",
+        "/collections/g/graph/export?format=csv",
+        String::new(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = send(
+        &app,
+        "POST",
+        "/collections/g/graph/import?format=graphml",
+        "<graphml><graph><node id=\"x\"/></graph></graphml>".to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/collections/missing/graph/import",
+        SOCIAL_JSONL.to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,100 @@
+//! Integration tests for `GET /collections/{name}/graph/stats`.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::{create_graph_collection, create_test_app};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+async fn send(app: &axum::Router, method: &str, uri: &str, body: String) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::from(body))
+                .expect("test: build request"),
+        )
+        .await
+        .expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn test_graph_stats_reports_labels_degrees_and_hubs() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    create_graph_collection(&app, "social").await;
+    let document = r#"{"type":"node","id":1,"labels":["Person"]}
+{"type":"node","id":2,"labels":["Person"]}
+{"type":"node","id":3,"labels":["Company"]}
+{"type":"node","id":4}
+{"type":"edge","id":10,"source":1,"target":2,"label":"KNOWS"}
+{"type":"edge","id":11,"source":1,"target":3,"label":"WORKS_AT"}
+"#;
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/collections/social/graph/import",
+        document.to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(
+        &app,
+        "GET",
+        "/collections/social/graph/stats",
+        String::new(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["node_count"], 4);
+    assert_eq!(body["edge_count"], 2);
+    assert_eq!(body["nodes_by_label"], json!({"Company": 1, "Person": 2}));
+    assert_eq!(body["edges_by_label"], json!({"KNOWS": 1, "WORKS_AT": 1}));
+    assert_eq!(
+        body["degree_histogram"],
+        json!([
+            {"min_degree": 0, "max_degree": 0, "nodes": 1},
+            {"min_degree": 1, "max_degree": 1, "nodes": 2},
+            {"min_degree": 2, "max_degree": 3, "nodes": 1},
+        ])
+    );
+    assert_eq!(body["max_degree"], 2);
+    assert_eq!(body["hub_count"], 0);
+    assert_eq!(
+        body["top_hubs"][0],
+        json!({"id": "1", "out_degree": 2, "in_degree": 0})
+    );
+}
+
+#[tokio::test]
+async fn test_graph_stats_unknown_collection_is_404() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+
+    let (status, _) = send(
+        &app,
+        "GET",
+        "/collections/missing/graph/stats",
+        String::new(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -13,7 +13,7 @@ use velesdb_core::Database;
 use velesdb_server::{AppState, OnboardingMetrics};
 
 // ============================================================================
-// GET /health — liveness probe
+// GET /health — component health
 // ============================================================================
 
 #[tokio::test]
@@ -40,6 +40,61 @@ async fn health_returns_200_with_status_ok() {
 
     assert_eq!(json["status"], "ok");
     assert!(json["version"].is_string(), "version should be present");
+    let components = &json["components"];
+    assert_eq!(components["collections"]["status"], "ok");
+    assert_eq!(components["collections"]["failed"], 0);
+    assert!(components["disk"]["available_bytes"].is_u64());
+    assert!(components["wal"]["backlog_bytes"].is_u64());
+    assert!(components["background_jobs"]["queue_depth"].is_u64());
+    assert!(
+        json.get("collections").is_none(),
+        "public /health must not list collections"
+    );
+}
+
+#[tokio::test]
+async fn health_returns_503_when_a_collection_fails_to_load() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    {
+        let db = Database::open(temp_dir.path()).expect("open db");
+        db.create_collection("docs", 4, velesdb_core::DistanceMetric::Cosine)
+            .expect("create collection");
+        db.flush_all();
+    }
+    let broken = temp_dir.path().join("broken");
+    std::fs::create_dir_all(&broken).unwrap();
+    std::fs::write(broken.join("config.json"), b"{ not json").unwrap();
+    let app = common::create_test_app(&temp_dir);
+
+    let (status, json) = get_json(app.clone(), "/health").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(json["status"], "unhealthy");
+    assert_eq!(json["components"]["collections"]["status"], "unhealthy");
+    assert_eq!(json["components"]["collections"]["loaded"], 1);
+    assert_eq!(json["components"]["collections"]["failed"], 1);
+
+    let (status, json) = get_json(app, "/health/details").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    let collections = json["collections"].as_array().expect("collections list");
+    assert_eq!(collections.len(), 2);
+    assert_eq!(collections[0]["name"], "broken");
+    assert_eq!(collections[0]["status"], "unhealthy");
+    assert!(collections[0]["error"].is_string());
+    assert_eq!(collections[1]["name"], "docs");
+    assert_eq!(collections[1]["status"], "ok");
+    assert_eq!(collections[1]["point_count"], 0);
+}
+
+async fn get_json(app: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
 }
 
 // ============================================================================
@@ -82,12 +137,18 @@ async fn ready_returns_503_when_not_ready() {
         db,
         onboarding_metrics: OnboardingMetrics::default(),
         query_limits: parking_lot::RwLock::new(velesdb_core::guardrails::QueryLimits::default()),
+        effective_config: parking_lot::RwLock::default(),
+        config_reloader: None,
+        reindex_jobs: velesdb_server::reindex::ReindexJobs::default(),
+        index_events: velesdb_server::index_events::IndexEventHub::default(),
         ready: std::sync::atomic::AtomicBool::new(false),
         operational_metrics: velesdb_core::metrics::OperationalMetrics::new_arc(),
         traversal_metrics: std::sync::Arc::new(velesdb_core::metrics::TraversalMetrics::new()),
+        traversal_cursors: velesdb_server::traversal_cursors::TraversalCursors::default(),
         query_duration_histogram: std::sync::Arc::new(
             velesdb_core::metrics::DurationHistogram::new(),
         ),
+        embedder: parking_lot::RwLock::default(),
     });
 
     let app = Router::new()
@@ -155,3 +216,21 @@ async fn ready_bypasses_auth() {
     // Should get 200 (not 401) even without auth header
     assert_eq!(response.status(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn health_details_requires_auth() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let app = common::create_test_app_with_auth(&temp_dir, vec!["secret-key".to_string()]);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/health/details")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,169 @@
+//! Integration tests for the `Idempotency-Key` header on write endpoints.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app_with_idempotency;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+/// Sends a JSON request, with an `Idempotency-Key` when `key` is set, and
+/// returns the status, the `idempotent-replayed` header and the JSON body.
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    key: Option<&str>,
+    body: &Value,
+) -> (StatusCode, bool, Value) {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json");
+    if let Some(key) = key {
+        request = request.header("Idempotency-Key", key);
+    }
+    let request = request.body(Body::from(body.to_string())).unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let replayed = response.headers().get("idempotent-replayed").is_some();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        replayed,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn app_with_collection(temp_dir: &TempDir) -> Router {
+    let app = create_test_app_with_idempotency(temp_dir);
+    let (status, _, _) = send(
+        &app,
+        "POST",
+        "/collections",
+        None,
+        &json!({ "name": "items", "dimension": 2, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    app
+}
+
+fn batch() -> Value {
+    json!({ "points": [
+        { "id": 1, "vector": [1.0, 0.0], "payload": { "stock": 1 } },
+        { "id": 2, "vector": [0.0, 1.0], "payload": { "stock": 2 } },
+    ] })
+}
+
+#[tokio::test]
+async fn retried_upsert_replays_the_original_response_without_writing() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = app_with_collection(&temp_dir).await;
+
+    let (status, replayed, first) = send(
+        &app,
+        "POST",
+        "/collections/items/points",
+        Some("b-1"),
+        &batch(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!replayed);
+
+    // Delete a point, then retry the batch: the replay must not re-insert it.
+    let (status, _, _) = send(
+        &app,
+        "DELETE",
+        "/collections/items/points/1",
+        None,
+        &json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, replayed, retry) = send(
+        &app,
+        "POST",
+        "/collections/items/points",
+        Some("b-1"),
+        &batch(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(replayed, "retry is served from the idempotency log");
+    assert_eq!(retry, first);
+    let (status, _, _) = send(&app, "GET", "/collections/items/points/1", None, &json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn reused_key_with_a_different_body_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = app_with_collection(&temp_dir).await;
+    send(
+        &app,
+        "POST",
+        "/collections/items/points",
+        Some("b-1"),
+        &batch(),
+    )
+    .await;
+
+    let other = json!({ "points": [{ "id": 3, "vector": [1.0, 1.0] }] });
+    let (status, replayed, _) = send(
+        &app,
+        "POST",
+        "/collections/items/points",
+        Some("b-1"),
+        &other,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!replayed);
+}
+
+#[tokio::test]
+async fn failed_request_is_not_recorded() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = app_with_collection(&temp_dir).await;
+    let bad = json!({ "points": [{ "id": 1, "vector": [1.0, 0.0, 0.0] }] });
+
+    for _ in 0..2 {
+        let (status, replayed, _) =
+            send(&app, "POST", "/collections/items/points", Some("bad"), &bad).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(!replayed);
+    }
+}
+
+#[tokio::test]
+async fn dml_query_is_replayed_and_select_passes_through() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = app_with_collection(&temp_dir).await;
+    send(&app, "POST", "/collections/items/points", None, &batch()).await;
+    let update = json!({ "query": "UPDATE items SET stock = 10 WHERE id = 1", "params": {} });
+
+    let (status, replayed, _) = send(&app, "POST", "/query", Some("u-1"), &update).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!replayed);
+    let (status, replayed, _) = send(&app, "POST", "/query", Some("u-1"), &update).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(replayed);
+
+    let select = json!({ "query": "SELECT * FROM items LIMIT 5", "params": {} });
+    for _ in 0..2 {
+        let (status, replayed, _) = send(&app, "POST", "/query", Some("s-1"), &select).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(!replayed, "reads are never replayed");
+    }
+}
@@ -0,0 +1,120 @@
+//! Integration tests for `GET /collections/{name}/projection`
+//! (`project_collection`).
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "projection";
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+/// Two groups of ten points, pointing along different axes.
+async fn seeded_app(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/collections",
+        Some(json!({ "name": COLLECTION, "dimension": 3, "metric": "euclidean" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let points: Vec<Value> = (0..20)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let jitter = f64::from(i) * 0.01;
+            let vector = if i < 10 {
+                json!([10.0 + jitter, 0.0, jitter])
+            } else {
+                json!([0.0, 10.0 + jitter, jitter])
+            };
+            json!({ "id": i, "vector": vector })
+        })
+        .collect();
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/points"),
+        Some(json!({ "points": points })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+#[tokio::test]
+async fn test_pca_projection_returns_labelled_layout() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let uri = format!("/collections/{COLLECTION}/projection?clusters=2");
+    let (status, body) = send(&app, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["method"], "pca");
+    assert_eq!(body["cluster_count"], 2);
+    let points = body["points"].as_array().expect("points array");
+    assert_eq!(points.len(), 20);
+    for point in points {
+        let id: u64 = point["id"]
+            .as_str()
+            .expect("string id")
+            .parse()
+            .expect("id");
+        let same_group: Vec<&Value> = points
+            .iter()
+            .filter(|p| (p["id"].as_str().unwrap().parse::<u64>().unwrap() < 10) == (id < 10))
+            .collect();
+        assert!(same_group.iter().all(|p| p["cluster"] == point["cluster"]));
+    }
+    assert!(body["explained_variance_ratio"][0].as_f64().expect("ratio") > 0.9);
+}
+
+#[tokio::test]
+async fn test_projection_rejects_unknown_method() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let uri = format!("/collections/{COLLECTION}/projection?method=tsne");
+    let (status, body) = send(&app, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+}
+
+#[tokio::test]
+async fn test_projection_unknown_collection_returns_404() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+
+    let (status, _) = send(&app, "GET", "/collections/missing/projection", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,67 @@
+//! Integration tests for collection-level query defaults over HTTP.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+async fn send(app: &Router, method: &str, uri: &str, body: Option<&Value>) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(body.map_or_else(Body::empty, |b| Body::from(b.to_string())))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn alter_collection_defaults_are_exposed_on_get_collection() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = create_test_app(&temp_dir);
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/collections",
+        Some(&json!({ "name": "docs", "dimension": 2, "metric": "cosine" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = send(&app, "GET", "/collections/docs", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.get("query_defaults").is_none(), "{body}");
+
+    let (status, body) = send(
+        &app,
+        "POST",
+        "/query",
+        Some(&json!({
+            "query": "ALTER COLLECTION docs SET (ef_search = 200, fusion = 'rrf')"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+
+    let (status, body) = send(&app, "GET", "/collections/docs", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["query_defaults"],
+        json!({ "ef_search": 200, "fusion": "rrf" })
+    );
+}
@@ -0,0 +1,124 @@
+//! Integration tests for `POST /query` with `dry_run: true`.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+async fn post(app: &Router, uri: &str, body: &Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seeded_app(temp_dir: &TempDir) -> Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = post(
+        &app,
+        "/collections",
+        &json!({ "name": "items", "dimension": 2, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let points: Vec<Value> = (1..=3)
+        .map(|id| json!({ "id": id, "vector": [1.0, 0.0], "payload": { "stock": id } }))
+        .collect();
+    let (status, _) = post(
+        &app,
+        "/collections/items/points",
+        &json!({ "points": points }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+#[tokio::test]
+async fn dry_run_delete_reports_without_deleting() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post(
+        &app,
+        "/query",
+        &json!({ "query": "DELETE FROM items WHERE id IN (1, 2, 42)", "dry_run": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["dry_run"], true);
+    assert_eq!(body["statement"], "DELETE");
+    assert_eq!(body["would_delete"], 2);
+    assert_eq!(body["sample_ids"], json!([1, 2]));
+
+    let (status, body) = post(
+        &app,
+        "/query",
+        &json!({ "query": "SELECT * FROM items LIMIT 10" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["rows_returned"], 3);
+}
+
+#[tokio::test]
+async fn dry_run_upsert_resolves_params() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post(
+        &app,
+        "/query",
+        &json!({
+            "query": "UPSERT INTO items (id, vector, stock) VALUES ($id, $v, 0)",
+            "params": { "id": 7, "v": [0.0, 1.0] },
+            "dry_run": true
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["would_insert"], 1);
+    assert_eq!(body["would_update"], 0);
+    assert_eq!(body["sample_ids"], json!([7]));
+}
+
+#[tokio::test]
+async fn dry_run_rejects_non_dml_and_unknown_collections() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post(
+        &app,
+        "/query",
+        &json!({ "query": "SELECT * FROM items LIMIT 1", "dry_run": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "VELESQL_DRY_RUN_ERROR", "{body}");
+
+    let (status, _) = post(
+        &app,
+        "/query",
+        &json!({ "query": "UPDATE nope SET a = 1", "dry_run": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,79 @@
+//! Integration tests for `limits.max_query_limit` enforcement over HTTP.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::{create_graph_collection, create_test_app};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+async fn post(app: &Router, uri: &str, body: &Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn select_limit_above_maximum_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = create_test_app(&temp_dir);
+    let (status, _) = post(
+        &app,
+        "/collections",
+        &json!({ "name": "items", "dimension": 2, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = post(
+        &app,
+        "/query",
+        &json!({ "query": "SELECT * FROM items LIMIT 100001" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert_eq!(body["error"]["code"], "VELESQL_LIMIT_EXCEEDED");
+    assert_eq!(body["error"]["details"]["requested"], 100_001);
+    assert_eq!(body["error"]["details"]["max"], 100_000);
+
+    let (status, _) = post(
+        &app,
+        "/query",
+        &json!({ "query": "SELECT * FROM items LIMIT 100000" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn traversal_limit_above_maximum_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = create_test_app(&temp_dir);
+    create_graph_collection(&app, "kg").await;
+
+    let (status, body) = post(
+        &app,
+        "/collections/kg/graph/traverse",
+        &json!({ "source": 1, "strategy": "bfs", "max_depth": 2, "limit": 100_001 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert_eq!(body["code"], "VELES-037");
+}
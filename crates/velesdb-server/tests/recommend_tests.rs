@@ -0,0 +1,134 @@
+//! Integration tests for `POST /collections/{name}/recommend`.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "recommend";
+
+async fn post_json(app: &axum::Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("test: build json request"),
+        )
+        .await
+        .expect("test: json request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seeded_app(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = post_json(
+        &app,
+        "/collections",
+        json!({ "name": COLLECTION, "dimension": 3, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/points"),
+        json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0, 0.0], "payload": { "cat": "a" } },
+            { "id": 2, "vector": [0.95, 0.3, 0.0], "payload": { "cat": "a" } },
+            { "id": 3, "vector": [0.9, 0.0, 0.44], "payload": { "cat": "b" } },
+            { "id": 4, "vector": [0.0, 1.0, 0.0], "payload": { "cat": "a" } }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+fn result_ids(body: &Value) -> Vec<String> {
+    body["results"]
+        .as_array()
+        .map(|hits| {
+            hits.iter()
+                .filter_map(|h| h["id"].as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_recommend_from_positive_and_negative_ids() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+    let uri = format!("/collections/{COLLECTION}/recommend");
+
+    let (status, body) = post_json(&app, &uri, json!({ "positive": [1], "top_k": 2 })).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(result_ids(&body), vec!["2", "3"]);
+
+    // String ids are accepted, and the negative example flips the order.
+    let (status, body) = post_json(
+        &app,
+        &uri,
+        json!({ "positive": ["1"], "negative": [4], "top_k": 2 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(result_ids(&body), vec!["3", "2"]);
+}
+
+#[tokio::test]
+async fn test_recommend_best_score_with_filter() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/recommend"),
+        json!({
+            "positive": [1],
+            "strategy": "best_score",
+            "filter": { "condition": { "type": "eq", "field": "cat", "value": "a" } }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(result_ids(&body), vec!["2", "4"]);
+}
+
+#[tokio::test]
+async fn test_recommend_rejects_bad_requests() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+    let uri = format!("/collections/{COLLECTION}/recommend");
+
+    let (status, body) = post_json(&app, &uri, json!({ "positive": [99] })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "{body}");
+    assert_eq!(body["code"], "VELES-003");
+
+    let (status, body) = post_json(&app, &uri, json!({ "positive": [] })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+
+    let (status, body) = post_json(
+        &app,
+        &uri,
+        json!({ "positive": [1], "strategy": "nearest" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+}
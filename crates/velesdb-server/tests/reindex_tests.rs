@@ -0,0 +1,197 @@
+//! Integration tests for `POST /collections/{name}/reindex` and the
+//! `GET /collections/{name}/reindex/status` progress stream.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "reindex_docs";
+
+async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, String) {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if body.is_some() {
+        builder = builder.header("Content-Type", "application/json");
+    }
+    let body = body.map_or_else(Body::empty, |b| Body::from(b.to_string()));
+    let response = app
+        .clone()
+        .oneshot(builder.body(body).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+async fn seed(app: &Router, n: u64) {
+    let (status, _) = send(
+        app,
+        "POST",
+        "/collections",
+        Some(json!({ "name": COLLECTION, "dimension": 4, "metric": "euclidean" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<Value> = (0..n)
+        .map(|i| {
+            let f = i as f32;
+            json!({ "id": i, "vector": [f, f * 0.5, 1.0, -f] })
+        })
+        .collect();
+    let (status, _) = send(
+        app,
+        "POST",
+        &format!("/collections/{COLLECTION}/points"),
+        Some(json!({ "points": points })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+/// SSE event names in stream order.
+fn event_names(sse: &str) -> Vec<&str> {
+    sse.lines()
+        .filter_map(|line| line.strip_prefix("event: "))
+        .collect()
+}
+
+#[tokio::test]
+async fn reindex_runs_in_background_and_streams_progress() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = create_test_app(&temp_dir);
+    seed(&app, 200).await;
+
+    let (status, body) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/reindex"),
+        // Latency on a 200-vector graph is noise; don't let it roll back.
+        Some(json!({ "hnsw_m": 8, "max_latency_regression_percent": 1.0e9 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED, "{body}");
+    let accepted: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(accepted["hnsw_m"], 8);
+    assert_eq!(
+        accepted["status_url"],
+        format!("/collections/{COLLECTION}/reindex/status")
+    );
+
+    // The stream replays from the start and closes on the terminal event.
+    let (status, sse) = send(
+        &app,
+        "GET",
+        &format!("/collections/{COLLECTION}/reindex/status"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let names = event_names(&sse);
+    assert_eq!(names.first(), Some(&"started"), "{sse}");
+    assert!(names.contains(&"progress"), "{sse}");
+    assert!(names.contains(&"validating"), "{sse}");
+    assert_eq!(names.last(), Some(&"completed"), "{sse}");
+
+    let (_, config) = send(
+        &app,
+        "GET",
+        &format!("/collections/{COLLECTION}/config"),
+        None,
+    )
+    .await;
+    assert!(config.contains("\"max_connections\":8"), "{config}");
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/search"),
+        Some(json!({ "vector": [42.0, 21.0, 1.0, -42.0], "top_k": 1 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn reindex_rejected_by_validation_streams_rolled_back() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = create_test_app(&temp_dir);
+    seed(&app, 200).await;
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/reindex"),
+        Some(json!({
+            "max_latency_regression_percent": 1.0e9,
+            "max_recall_regression_percent": -1.0
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+
+    let (_, sse) = send(
+        &app,
+        "GET",
+        &format!("/collections/{COLLECTION}/reindex/status"),
+        None,
+    )
+    .await;
+    assert_eq!(event_names(&sse).last(), Some(&"rolled_back"), "{sse}");
+}
+
+#[tokio::test]
+async fn reindex_without_body_uses_recommended_params() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = create_test_app(&temp_dir);
+    seed(&app, 10).await;
+
+    let (status, body) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/reindex"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED, "{body}");
+    let accepted: Value = serde_json::from_str(&body).unwrap();
+    assert!(accepted["hnsw_m"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn reindex_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = create_test_app(&temp_dir);
+
+    let (status, _) = send(&app, "POST", "/collections/missing/reindex", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    seed(&app, 10).await;
+    let (status, _) = send(
+        &app,
+        "GET",
+        &format!("/collections/{COLLECTION}/reindex/status"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "no job started yet");
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/reindex"),
+        Some(json!({ "hnsw_alpha": 0.5 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
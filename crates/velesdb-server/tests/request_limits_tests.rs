@@ -0,0 +1,157 @@
+//! Integration tests for the per-request size guardrails in `[limits]`:
+//! `max_points_per_upsert`, `max_payload_size`, `max_top_k` and
+//! `max_batch_queries`.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "limited";
+
+async fn post(app: &Router, uri: &str, body: &Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+/// App whose engine config caps upserts at 2 points, payloads at 64 bytes,
+/// `top_k` at 5 and batches at 2 queries, with an empty 2-d collection.
+async fn limited_app(temp_dir: &TempDir, config_dir: &TempDir) -> Router {
+    let config_path = config_dir.path().join("velesdb.toml");
+    std::fs::write(
+        &config_path,
+        "[limits]\nmax_points_per_upsert = 2\nmax_payload_size = 64\n\
+         max_top_k = 5\nmax_batch_queries = 2\n",
+    )
+    .expect("test: write config");
+    let app = common::create_test_app_with_core_config(temp_dir, &config_path);
+    let (status, _) = post(
+        &app,
+        "/collections",
+        &json!({ "name": COLLECTION, "dimension": 2, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    app
+}
+
+fn assert_guard_rail(body: &Value, field: &str) {
+    assert_eq!(body["code"], "VELES-027", "{body}");
+    assert_eq!(body["retryable"], false, "{body}");
+    let error = body["error"].as_str().unwrap_or_default();
+    assert!(error.contains(field), "{body}");
+}
+
+#[tokio::test]
+async fn test_oversized_upserts_are_rejected_with_413() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let config_dir = TempDir::new().expect("test: config dir");
+    let app = limited_app(&temp_dir, &config_dir).await;
+    let uri = format!("/collections/{COLLECTION}/points");
+
+    let (status, body) = post(
+        &app,
+        &uri,
+        &json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0] },
+            { "id": 2, "vector": [0.0, 1.0] },
+            { "id": 3, "vector": [1.0, 1.0] }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_guard_rail(&body, "limits.max_points_per_upsert");
+
+    let (status, body) = post(
+        &app,
+        &uri,
+        &json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0], "payload": { "text": "x".repeat(100) } }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_guard_rail(&body, "limits.max_payload_size");
+
+    let (status, body) = post(
+        &app,
+        &uri,
+        &json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0], "payload": { "text": "short" } },
+            { "id": 2, "vector": [0.0, 1.0] }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+}
+
+#[tokio::test]
+async fn test_search_top_k_and_batch_size_are_capped() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let config_dir = TempDir::new().expect("test: config dir");
+    let app = limited_app(&temp_dir, &config_dir).await;
+
+    let (status, body) = post(
+        &app,
+        &format!("/collections/{COLLECTION}/search"),
+        &json!({ "vector": [1.0, 0.0], "top_k": 6 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_guard_rail(&body, "limits.max_top_k");
+
+    let (status, body) = post(
+        &app,
+        &format!("/collections/{COLLECTION}/search"),
+        &json!({ "vector": [1.0, 0.0], "top_k": 5 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+
+    let query = json!({ "vector": [1.0, 0.0], "top_k": 1 });
+    let (status, body) = post(
+        &app,
+        &format!("/collections/{COLLECTION}/search/batch"),
+        &json!({ "searches": [query, query, query] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_guard_rail(&body, "limits.max_batch_queries");
+
+    let (status, body) = post(
+        &app,
+        &format!("/collections/{COLLECTION}/search/batch"),
+        &json!({ "searches": [query, { "vector": [1.0, 0.0], "top_k": 50 }] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_guard_rail(&body, "limits.max_top_k");
+
+    let (status, body) = post(
+        &app,
+        &format!("/collections/{COLLECTION}/search/multi"),
+        &json!({ "vectors": [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]], "top_k": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_guard_rail(&body, "limits.max_batch_queries");
+}
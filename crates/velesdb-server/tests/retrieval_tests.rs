@@ -0,0 +1,209 @@
+//! Integration tests for the OpenAI-compatible `/v1/embeddings` proxy and the
+//! text-in `/v1/retrieval` endpoint, against a local mock embeddings service.
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, Request, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use base64::Engine;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+use velesdb_core::Database;
+use velesdb_server::config::EmbedderConfig;
+use velesdb_server::{build_router, RouterOptions};
+
+/// Embeds texts mentioning "rust" as `[1, 0]` and everything else as
+/// `[0, 1]`, answering in reverse order to exercise the `index` sort.
+async fn mock_embeddings(headers: HeaderMap, Json(body): Json<Value>) -> (StatusCode, Json<Value>) {
+    if headers.get("authorization").and_then(|v| v.to_str().ok()) != Some("Bearer sk-test") {
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "error": "bad key" })),
+        );
+    }
+    let inputs = body["input"].as_array().cloned().unwrap_or_default();
+    let data: Vec<Value> = inputs
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(index, text)| {
+            let rusty = text.as_str().unwrap_or_default().contains("rust");
+            let embedding = if rusty { [1.0, 0.0] } else { [0.0, 1.0] };
+            json!({ "object": "embedding", "index": index, "embedding": embedding })
+        })
+        .collect();
+    (
+        StatusCode::OK,
+        Json(json!({ "object": "list", "data": data, "model": body["model"] })),
+    )
+}
+
+/// Serves [`mock_embeddings`] on an ephemeral port and returns its URL.
+async fn spawn_mock_embedder() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("test: bind mock embedder");
+    let addr = listener.local_addr().expect("test: mock address");
+    let app = Router::new().route("/v1/embeddings", post(mock_embeddings));
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.expect("test: mock server");
+    });
+    format!("http://{addr}/v1/embeddings")
+}
+
+async fn post_json(app: &Router, uri: &str, body: &Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+fn app(temp_dir: &TempDir, embedder: Option<EmbedderConfig>) -> Router {
+    let db = Database::open(temp_dir.path()).expect("test: open database");
+    build_router(
+        db,
+        RouterOptions {
+            embedder,
+            ..RouterOptions::default()
+        },
+    )
+    .expect("test: build router")
+}
+
+fn embedder_config(url: String) -> EmbedderConfig {
+    EmbedderConfig {
+        url,
+        model: "mock-embed".to_string(),
+        api_key: Some("sk-test".to_string()),
+        timeout_secs: 5,
+    }
+}
+
+#[tokio::test]
+async fn test_embeddings_proxy_is_openai_compatible() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = app(
+        &temp_dir,
+        Some(embedder_config(spawn_mock_embedder().await)),
+    );
+
+    let (status, body) = post_json(
+        &app,
+        "/v1/embeddings",
+        &json!({ "input": ["hello", "rust"], "model": "text-embedding-3-small" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["object"], "list");
+    assert_eq!(body["model"], "mock-embed");
+    assert_eq!(body["data"][0]["index"], 0);
+    assert_eq!(body["data"][0]["embedding"], json!([0.0, 1.0]));
+    assert_eq!(body["data"][1]["embedding"], json!([1.0, 0.0]));
+
+    let (status, body) = post_json(
+        &app,
+        "/v1/embeddings",
+        &json!({ "input": "rust", "encoding_format": "base64" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let encoded = body["data"][0]["embedding"]
+        .as_str()
+        .expect("base64 string");
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .expect("test: valid base64");
+    let expected: Vec<u8> = [1.0f32, 0.0].iter().flat_map(|x| x.to_le_bytes()).collect();
+    assert_eq!(bytes, expected);
+
+    let (status, _) = post_json(
+        &app,
+        "/v1/embeddings",
+        &json!({ "input": "rust", "encoding_format": "int8" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_retrieval_embeds_query_and_returns_documents() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = app(
+        &temp_dir,
+        Some(embedder_config(spawn_mock_embedder().await)),
+    );
+
+    let (status, body) = post_json(
+        &app,
+        "/v1/collections",
+        &json!({ "name": "docs", "dimension": 2, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let (status, body) = post_json(
+        &app,
+        "/v1/collections/docs/points",
+        &json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0], "payload": { "text": "Rust ownership", "lang": "en" } },
+            { "id": 2, "vector": [0.0, 1.0], "payload": { "text": "Gardening tips", "lang": "en" } }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+
+    let (status, body) = post_json(
+        &app,
+        "/v1/retrieval",
+        &json!({ "collection": "docs", "query": "rust borrow checker", "top_k": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let documents = body["documents"].as_array().expect("documents");
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0]["id"], 1);
+    assert_eq!(documents[0]["text"], "Rust ownership");
+    assert_eq!(documents[0]["metadata"], json!({ "lang": "en" }));
+
+    let (status, body) = post_json(
+        &app,
+        "/v1/retrieval",
+        &json!({ "collection": "missing", "query": "rust" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "{body}");
+}
+
+#[tokio::test]
+async fn test_endpoints_report_missing_or_failing_embedder() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let unconfigured = app(&temp_dir, None);
+    let (status, body) = post_json(&unconfigured, "/v1/embeddings", &json!({ "input": "x" })).await;
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    assert!(body["error"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("[embedder]"));
+    drop(unconfigured);
+
+    let other_dir = TempDir::new().expect("test: temp dir");
+    let mut config = embedder_config(spawn_mock_embedder().await);
+    config.api_key = Some("wrong".to_string());
+    let failing = app(&other_dir, Some(config));
+    let (status, body) = post_json(&failing, "/v1/embeddings", &json!({ "input": "x" })).await;
+    assert_eq!(status, StatusCode::BAD_GATEWAY, "{body}");
+    assert_eq!(body["retryable"], true);
+}
@@ -0,0 +1,589 @@
+//! Integration tests for row-security policies enforced per API key.
+
+mod common;
+
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use futures::StreamExt;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const ADMIN: &str = "admin-key";
+const ACME: &str = "acme-key";
+const GLOBEX: &str = "globex-key";
+const UNSCOPED: &str = "unscoped-key";
+
+const CONFIG: &str = r#"
+[auth]
+api_keys = ["admin-key", "acme-key", "globex-key", "unscoped-key"]
+
+[auth.key_contexts."admin-key"]
+
+[auth.key_contexts."acme-key"]
+tenant = "acme"
+
+[auth.key_contexts."globex-key"]
+tenant = "globex"
+"#;
+
+fn setup() -> (TempDir, TempDir, axum::Router) {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let config_dir = TempDir::new().expect("test: config dir");
+    let config_path = config_dir.path().join("velesdb.toml");
+    std::fs::write(&config_path, CONFIG).expect("test: write config");
+    let app = common::create_test_app_with_config_file(&temp_dir, &config_path);
+    (temp_dir, config_dir, app)
+}
+
+async fn send(
+    app: &axum::Router,
+    key: &str,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Authorization", format!("Bearer {key}"));
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+fn sorted_ids(body: &Value) -> Vec<u64> {
+    sorted_ids_in(body, "results")
+}
+
+fn sorted_ids_in(body: &Value, key: &str) -> Vec<u64> {
+    let mut ids: Vec<u64> = body[key]
+        .as_array()
+        .expect("test: id list")
+        .iter()
+        // `/query` rows carry numeric ids, search hits string ids.
+        .map(|hit| match &hit["id"] {
+            Value::String(id) => id.parse().expect("test: id"),
+            id => id.as_u64().expect("test: id"),
+        })
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+/// Creates `docs` with two `acme` rows and one `globex` row, protected by
+/// `tenant_id = $ctx.tenant`.
+async fn seed(app: &axum::Router) {
+    let (status, _) = send(
+        app,
+        ADMIN,
+        "POST",
+        "/collections",
+        Some(json!({ "name": "docs", "dimension": 2 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let points = json!([
+        { "id": 1, "vector": [1.0, 0.0], "payload": { "tenant_id": "acme" } },
+        { "id": 2, "vector": [0.9, 0.1], "payload": { "tenant_id": "globex" } },
+        { "id": 3, "vector": [0.1, 0.9], "payload": { "tenant_id": "acme" } },
+    ]);
+    let (status, _) = send(
+        app,
+        ADMIN,
+        "POST",
+        "/collections/docs/points",
+        Some(json!({ "points": points })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = send(
+        app,
+        ADMIN,
+        "PUT",
+        "/collections/docs/row-security",
+        Some(json!({ "filter": "tenant_id = $ctx.tenant" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(
+        body,
+        json!({ "collection": "docs", "filter": "tenant_id = $ctx.tenant" })
+    );
+}
+
+#[tokio::test]
+async fn test_search_and_query_are_scoped_to_the_key_tenant() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    let search = json!({ "vector": [1.0, 0.0], "top_k": 10 });
+    let (status, body) = send(
+        &app,
+        ACME,
+        "POST",
+        "/collections/docs/search",
+        Some(search.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids(&body), [1, 3]);
+    let (status, body) = send(
+        &app,
+        GLOBEX,
+        "POST",
+        "/collections/docs/search",
+        Some(search.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids(&body), [2]);
+
+    // The ids-only endpoint leaves its unfiltered fast path for protected
+    // collections.
+    let (status, body) = send(
+        &app,
+        GLOBEX,
+        "POST",
+        "/collections/docs/search/ids",
+        Some(search),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids(&body), [2]);
+
+    let query = json!({ "query": "SELECT * FROM docs LIMIT 10" });
+    let (status, body) = send(&app, ACME, "POST", "/query", Some(query)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids(&body), [1, 3]);
+
+    let count = json!({ "query": "SELECT COUNT(*) FROM docs" });
+    let (status, body) = send(&app, ACME, "POST", "/aggregate", Some(count)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["result"]["count"], json!(2));
+}
+
+#[tokio::test]
+async fn test_point_reads_are_scoped_to_the_key_tenant() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    let (status, body) = send(&app, ACME, "GET", "/collections/docs/points/1", None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    // A hidden point is indistinguishable from a missing one.
+    let (status, _) = send(&app, ACME, "GET", "/collections/docs/points/2", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let get = json!({ "ids": [1, 2, 3], "with_payload": false });
+    let (status, body) = send(
+        &app,
+        ACME,
+        "POST",
+        "/collections/docs/points/get",
+        Some(get),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids_in(&body, "points"), [1, 3]);
+    assert!(body["points"][0]["payload"].is_null(), "{body}");
+
+    let scroll = json!({ "batch_size": 10 });
+    let (status, body) = send(
+        &app,
+        GLOBEX,
+        "POST",
+        "/collections/docs/points/scroll",
+        Some(scroll),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids_in(&body, "points"), [2]);
+
+    // The caller's filter is AND-ed with the policy, not replaced by it.
+    let count = json!({ "filter": { "condition": {
+        "type": "eq", "field": "tenant_id", "value": "globex"
+    } } });
+    let (status, body) = send(
+        &app,
+        ACME,
+        "POST",
+        "/collections/docs/points/count",
+        Some(count),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["count"], json!(0));
+    let (status, body) = send(
+        &app,
+        ACME,
+        "POST",
+        "/collections/docs/points/count",
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["count"], json!(2));
+
+    let (status, body) = send(
+        &app,
+        ACME,
+        "GET",
+        "/collections/docs/projection?clusters=1",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids_in(&body, "points"), [1, 3]);
+}
+
+#[tokio::test]
+async fn test_point_reads_refuse_key_without_required_attribute() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    let reads = [
+        ("GET", "/collections/docs/points/1", None),
+        (
+            "POST",
+            "/collections/docs/points/get",
+            Some(json!({ "ids": [1] })),
+        ),
+        (
+            "POST",
+            "/collections/docs/points/scroll",
+            Some(json!({ "batch_size": 10 })),
+        ),
+        ("POST", "/collections/docs/points/count", Some(json!({}))),
+        ("GET", "/collections/docs/projection", None),
+    ];
+    for (method, uri, body) in reads {
+        let (status, response) = send(&app, UNSCOPED, method, uri, body).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{uri}: {response}");
+    }
+}
+
+#[tokio::test]
+async fn test_change_stream_is_scoped_to_the_key_tenant() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    let subscribe = |key: &str| {
+        Request::builder()
+            .uri("/collections/docs/subscribe")
+            .header("Authorization", format!("Bearer {key}"))
+            .body(Body::empty())
+            .expect("test: build request")
+    };
+    let response = app
+        .clone()
+        .oneshot(subscribe(UNSCOPED))
+        .await
+        .expect("test: request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = app
+        .clone()
+        .oneshot(subscribe(ACME))
+        .await
+        .expect("test: request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut stream = response.into_body().into_data_stream();
+
+    for (id, tenant) in [(4, "globex"), (5, "acme")] {
+        let point = json!({ "id": id, "vector": [0.5, 0.5], "payload": { "tenant_id": tenant } });
+        let (status, _) = send(
+            &app,
+            ADMIN,
+            "POST",
+            "/collections/docs/points",
+            Some(json!({ "points": [point] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Events arrive in write order, so the globex insert would come first.
+    let mut text = String::new();
+    while !text.contains("\n\n") {
+        let chunk = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("test: change event within 5s")
+            .expect("test: stream open")
+            .expect("test: chunk");
+        text.push_str(std::str::from_utf8(&chunk).expect("test: utf-8"));
+    }
+    let data = text
+        .lines()
+        .find_map(|line| line.strip_prefix("data:"))
+        .expect("test: data line");
+    let event: Value = serde_json::from_str(data.trim()).expect("test: event json");
+    assert_eq!(event["id"], 5, "{text}");
+    assert_eq!(event["payload"]["tenant_id"], "acme");
+}
+
+#[tokio::test]
+async fn test_delete_and_dry_runs_are_scoped_to_the_key_tenant() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    let dry_run = json!({ "query": "DELETE FROM docs WHERE id IN (1, 2, 3)", "dry_run": true });
+    let (status, body) = send(&app, ACME, "POST", "/query", Some(dry_run)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["would_delete"], 2, "{body}");
+    assert_eq!(body["sample_ids"], json!([1, 3]));
+
+    let update = json!({ "query": "UPDATE docs SET tag = 'x' WHERE id = 2", "dry_run": true });
+    let (status, body) = send(&app, ACME, "POST", "/query", Some(update)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["would_update"], 0, "{body}");
+
+    let delete = json!({ "query": "DELETE FROM docs WHERE id IN (1, 2)" });
+    let (status, body) = send(&app, ACME, "POST", "/query", Some(delete)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let (status, _) = send(&app, ACME, "GET", "/collections/docs/points/1", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "the caller's row is deleted");
+    let (status, _) = send(&app, GLOBEX, "GET", "/collections/docs/points/2", None).await;
+    assert_eq!(status, StatusCode::OK, "another tenant's row survives");
+
+    let delete = json!({ "query": "DELETE FROM docs WHERE id = 3" });
+    let (status, body) = send(&app, UNSCOPED, "POST", "/query", Some(delete)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+    assert_eq!(body["error"]["code"], "VELESQL_ROW_SECURITY");
+}
+
+#[tokio::test]
+async fn test_edge_and_node_statements_on_protected_collection_are_refused() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    for statement in [
+        "SELECT EDGES FROM docs",
+        "INSERT EDGE INTO docs (source = 1, target = 3, label = 'LINKS')",
+        "DELETE EDGE 1 FROM docs",
+        "INSERT NODE INTO docs (id = 9, payload = '{}')",
+    ] {
+        let (status, body) = send(
+            &app,
+            ACME,
+            "POST",
+            "/query",
+            Some(json!({ "query": statement })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{statement}: {body}");
+        assert_eq!(body["error"]["code"], "VELESQL_ROW_SECURITY", "{statement}");
+    }
+}
+
+#[tokio::test]
+async fn test_key_without_required_attribute_is_refused() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    let query = json!({ "query": "SELECT * FROM docs LIMIT 10" });
+    for key in [ADMIN, UNSCOPED] {
+        let (status, body) = send(&app, key, "POST", "/query", Some(query.clone())).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+        assert_eq!(body["error"]["code"], "VELESQL_ROW_SECURITY");
+    }
+
+    let search = json!({ "vector": [1.0, 0.0], "top_k": 10 });
+    let (status, _) = send(
+        &app,
+        UNSCOPED,
+        "POST",
+        "/collections/docs/search",
+        Some(search),
+    )
+    .await;
+    assert!(status.is_client_error(), "{status}");
+}
+
+#[tokio::test]
+async fn test_unscopable_reads_fail_closed() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+
+    let batch = json!({ "searches": [{ "vector": [1.0, 0.0], "top_k": 10 }] });
+    let (status, _) = send(
+        &app,
+        ACME,
+        "POST",
+        "/collections/docs/search/batch",
+        Some(batch),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let join = json!({
+        "query": "SELECT * FROM docs JOIN docs AS d2 ON d2.id = docs.id LIMIT 10"
+    });
+    let (status, _) = send(&app, ACME, "POST", "/query", Some(join)).await;
+    assert!(status.is_client_error(), "{status}");
+}
+
+#[tokio::test]
+async fn test_graph_relation_and_access_reads_fail_closed() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+    let (status, body) = send(
+        &app,
+        ADMIN,
+        "POST",
+        "/collections",
+        Some(json!({ "name": "kg", "collection_type": "graph" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    for (id, tenant) in [(1, "acme"), (2, "globex")] {
+        let (status, body) = send(
+            &app,
+            ADMIN,
+            "PUT",
+            &format!("/collections/kg/graph/nodes/{id}/payload"),
+            Some(json!({ "payload": { "tenant_id": tenant } })),
+        )
+        .await;
+        assert!(status.is_success(), "{body}");
+    }
+    let edge = json!({ "id": 10, "source": 1, "target": 2, "label": "LINKS" });
+    let (status, body) = send(
+        &app,
+        ADMIN,
+        "POST",
+        "/collections/kg/graph/edges",
+        Some(edge),
+    )
+    .await;
+    assert!(status.is_success(), "{body}");
+    let (status, body) = send(
+        &app,
+        ADMIN,
+        "PUT",
+        "/collections/kg/row-security",
+        Some(json!({ "filter": "tenant_id = $ctx.tenant" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+
+    let traverse = json!({ "source": 1 });
+    let parallel = json!({ "sources": [1] });
+    for (method, uri, body) in [
+        ("GET", "/collections/kg/graph/nodes", None),
+        ("GET", "/collections/kg/graph/nodes/2/payload", None),
+        ("GET", "/collections/kg/graph/nodes/1/edges", None),
+        ("GET", "/collections/kg/graph/nodes/1/degree", None),
+        ("GET", "/collections/kg/graph/edges?label=LINKS", None),
+        ("GET", "/collections/kg/graph/edges/count", None),
+        ("GET", "/collections/kg/graph/stats", None),
+        ("GET", "/collections/kg/graph/export", None),
+        ("POST", "/collections/kg/graph/traverse", Some(traverse)),
+        (
+            "POST",
+            "/collections/kg/graph/traverse/parallel",
+            Some(parallel),
+        ),
+        ("GET", "/collections/docs/points/1/relations", None),
+        ("GET", "/collections/docs/stats/access", None),
+    ] {
+        for key in [ACME, UNSCOPED] {
+            let (status, body) = send(&app, key, method, uri, body.clone()).await;
+            assert_eq!(
+                status,
+                StatusCode::FORBIDDEN,
+                "{key} {method} {uri}: {body}"
+            );
+        }
+    }
+
+    let request = Request::builder()
+        .uri("/collections/kg/graph/traverse/stream?start_node=1")
+        .header("Authorization", format!("Bearer {ACME}"))
+        .body(Body::empty())
+        .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    let events = String::from_utf8_lossy(&bytes);
+    assert!(events.contains("event: error"), "{events}");
+    assert!(!events.contains("event: node"), "{events}");
+
+    let (status, body) = send(&app, ADMIN, "DELETE", "/collections/kg/row-security", None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let (status, body) = send(
+        &app,
+        ACME,
+        "GET",
+        "/collections/kg/graph/nodes/2/payload",
+        None,
+    )
+    .await;
+    assert_eq!(
+        status,
+        StatusCode::OK,
+        "unprotected graphs stay readable: {body}"
+    );
+}
+
+#[tokio::test]
+async fn test_scoped_keys_cannot_manage_policies() {
+    let (_data, _config, app) = setup();
+    seed(&app).await;
+    let uri = "/collections/docs/row-security";
+
+    let (status, _) = send(&app, ACME, "DELETE", uri, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, ACME, "GET", uri, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = send(&app, UNSCOPED, "GET", uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["filter"], "tenant_id = $ctx.tenant");
+
+    let (status, _) = send(
+        &app,
+        ADMIN,
+        "PUT",
+        uri,
+        Some(json!({ "filter": "tenant_id = $tenant" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = send(
+        &app,
+        ADMIN,
+        "PUT",
+        "/collections/missing/row-security",
+        Some(json!({ "filter": "tenant_id = $ctx.tenant" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&app, ADMIN, "DELETE", uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&app, ADMIN, "DELETE", uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Without a policy every key reads every row.
+    let query = json!({ "query": "SELECT * FROM docs LIMIT 10" });
+    let (status, body) = send(&app, ACME, "POST", "/query", Some(query)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids(&body), [1, 2, 3]);
+}
@@ -0,0 +1,234 @@
+//! Integration tests for the `with_vector` / `with_payload` projection flags
+//! on the search endpoints.
+//!
+//! Pins the defaults (payload included, vector omitted) and that both flags
+//! are honoured by single, hybrid and batch search, along with the
+//! `rerank_preview` candidates array.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "search_projection";
+
+async fn post_json(app: &axum::Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("test: build json request"),
+        )
+        .await
+        .expect("test: json request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seeded_app(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = post_json(
+        &app,
+        "/collections",
+        json!({ "name": COLLECTION, "dimension": 3, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/points"),
+        json!({ "points": [
+            { "id": 1, "vector": [1.0, 0.0, 0.0], "payload": { "title": "rust vectors" } },
+            { "id": 2, "vector": [0.0, 1.0, 0.0], "payload": { "title": "graph walk" } }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+#[tokio::test]
+async fn test_search_defaults_include_payload_and_omit_vector() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search"),
+        json!({ "vector": [1.0, 0.0, 0.0], "top_k": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let hit = &body["results"][0];
+    assert_eq!(hit["id"], "1");
+    assert_eq!(hit["payload"]["title"], "rust vectors");
+    assert!(hit.get("vector").is_none());
+}
+
+#[tokio::test]
+async fn test_search_with_vector_and_without_payload() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search"),
+        json!({
+            "vector": [1.0, 0.0, 0.0],
+            "top_k": 1,
+            "with_vector": true,
+            "with_payload": false
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let hit = &body["results"][0];
+    assert_eq!(hit["vector"], json!([1.0, 0.0, 0.0]));
+    assert!(hit["payload"].is_null());
+}
+
+#[tokio::test]
+async fn test_hybrid_and_batch_search_honour_projection_flags() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search/hybrid"),
+        json!({
+            "vector": [1.0, 0.0, 0.0],
+            "query": "rust",
+            "top_k": 1,
+            "with_vector": true,
+            "with_payload": false
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let hit = &body["results"][0];
+    assert!(hit["vector"].is_array());
+    assert!(hit["payload"].is_null());
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search/batch"),
+        json!({ "searches": [
+            { "vector": [1.0, 0.0, 0.0], "top_k": 1, "with_vector": true },
+            { "vector": [0.0, 1.0, 0.0], "top_k": 1, "with_payload": false }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let first = &body["results"][0]["results"][0];
+    assert!(first["vector"].is_array());
+    assert_eq!(first["payload"]["title"], "rust vectors");
+    let second = &body["results"][1]["results"][0];
+    assert!(second.get("vector").is_none());
+    assert!(second["payload"].is_null());
+}
+
+#[tokio::test]
+async fn test_vector_precision_f16_and_none() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search"),
+        json!({
+            "vector": [1.0, 0.0, 0.0],
+            "top_k": 1,
+            "with_vector": true,
+            "vector_precision": "f16"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let encoded = body["results"][0]["vector"]["$f16"]
+        .as_str()
+        .expect("test: $f16 vector");
+    let decoded = velesdb_core::wire::f16_base64::decode(encoded).expect("test: decode f16");
+    assert_eq!(decoded, vec![1.0, 0.0, 0.0]);
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search/text"),
+        json!({
+            "query": "rust",
+            "top_k": 1,
+            "with_vector": true,
+            "vector_precision": "none"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let hit = &body["results"][0];
+    assert_eq!(hit["id"], "1");
+    assert!(hit.get("vector").is_none());
+}
+
+#[tokio::test]
+async fn test_rerank_preview_returns_truncated_candidates() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search"),
+        json!({ "vector": [1.0, 0.0, 0.0], "top_k": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(body.get("candidates").is_none());
+
+    // Candidates are built from the payload even when it is not returned.
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search"),
+        json!({
+            "vector": [1.0, 0.0, 0.0],
+            "top_k": 2,
+            "with_payload": false,
+            "rerank_preview": { "fields": ["title"], "max_chars": 4 }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(body["results"][0]["payload"].is_null());
+    assert_eq!(
+        body["candidates"],
+        json!([{ "id": "1", "text": "rust" }, { "id": "2", "text": "grap" }])
+    );
+
+    let (status, body) = post_json(
+        &app,
+        &format!("/collections/{COLLECTION}/search/batch"),
+        json!({ "searches": [
+            { "vector": [0.0, 1.0, 0.0], "top_k": 1, "rerank_preview": { "fields": ["title"] } },
+            { "vector": [1.0, 0.0, 0.0], "top_k": 1 }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(
+        body["results"][0]["candidates"],
+        json!([{ "id": "2", "text": "graph walk" }])
+    );
+    assert!(body["results"][1].get("candidates").is_none());
+}
@@ -0,0 +1,181 @@
+//! Integration tests for per-collection latency SLOs
+//! (`/collections/{name}/slo`, the stats `slo` field and webhook alerts).
+
+mod common;
+
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use common::{create_test_app, create_test_app_with_state};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seed(app: &Router) {
+    let (status, _) = send(
+        app,
+        "POST",
+        "/collections",
+        Some(json!({ "name": "docs", "dimension": 2 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = send(
+        app,
+        "POST",
+        "/collections/docs/points",
+        Some(json!({ "points": [{ "id": 1, "vector": [1.0, 0.0] }] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+async fn search(app: &Router) {
+    let (status, body) = send(
+        app,
+        "POST",
+        "/collections/docs/search",
+        Some(json!({ "vector": [1.0, 0.0], "top_k": 1 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+}
+
+#[tokio::test]
+async fn test_slo_crud_and_stats_field() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    seed(&app).await;
+    let uri = "/collections/docs/slo";
+
+    let (status, _) = send(&app, "GET", uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(
+        &app,
+        "PUT",
+        uri,
+        Some(json!({ "percentile": 150.0, "threshold_ms": 20.0 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = send(
+        &app,
+        "PUT",
+        "/collections/missing/slo",
+        Some(json!({ "percentile": 99.0, "threshold_ms": 20.0 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, body) = send(
+        &app,
+        "PUT",
+        uri,
+        Some(json!({ "percentile": 99.0, "threshold_ms": 10000.0, "window_secs": 30 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["state"], "ok");
+    assert_eq!(body["window_secs"], 30);
+    assert_eq!(body["sustain_secs"], 60);
+    assert_eq!(body["samples"], 0);
+
+    search(&app).await;
+    search(&app).await;
+    let (status, body) = send(&app, "GET", uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["samples"], 2);
+    assert_eq!(body["state"], "ok");
+
+    let (status, body) = send(&app, "POST", "/collections/docs/analyze", None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["slo"]["collection"], "docs");
+    assert_eq!(body["slo"]["percentile"], 99.0);
+
+    let (status, _) = send(&app, "DELETE", uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&app, "DELETE", uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, body) = send(&app, "GET", "/collections/docs/stats", None).await;
+    assert!(body.get("slo").is_none(), "{body}");
+}
+
+#[tokio::test]
+async fn test_breach_is_posted_to_webhook() {
+    let (alerts_tx, mut alerts_rx) = tokio::sync::mpsc::unbounded_channel::<Value>();
+    let receiver = Router::new().route(
+        "/hook",
+        post(move |Json(alert): Json<Value>| {
+            let alerts_tx = alerts_tx.clone();
+            async move {
+                let _ = alerts_tx.send(alert);
+                StatusCode::NO_CONTENT
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("test: bind webhook receiver");
+    let hook_url = format!(
+        "http://{}/hook",
+        listener.local_addr().expect("test: receiver address")
+    );
+    tokio::spawn(async move { axum::serve(listener, receiver).await });
+
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let (app, state) = create_test_app_with_state(&temp_dir);
+    velesdb_server::slo_webhook::install(&state.db, Some(hook_url));
+    seed(&app).await;
+    // Any search misses a 1 ns objective, and the breach is immediate.
+    let (status, body) = send(
+        &app,
+        "PUT",
+        "/collections/docs/slo",
+        Some(json!({
+            "percentile": 50.0,
+            "threshold_ms": 1e-6,
+            "sustain_secs": 0,
+            "min_samples": 1
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    search(&app).await;
+
+    let alert = tokio::time::timeout(Duration::from_secs(10), alerts_rx.recv())
+        .await
+        .expect("test: webhook called")
+        .expect("test: alert");
+    assert_eq!(alert["collection"], "docs");
+    assert_eq!(alert["kind"], "breached");
+    assert_eq!(alert["status"]["state"], "breached");
+    assert_eq!(alert["status"]["slo"]["percentile"], 50.0);
+
+    let (_, body) = send(&app, "GET", "/collections/docs/slo", None).await;
+    assert_eq!(body["state"], "breached");
+}
@@ -0,0 +1,138 @@
+//! Integration tests for `GET /collections/{name}/subscribe` (filtered
+//! change stream over SSE).
+
+mod common;
+
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use futures::StreamExt;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "tickets";
+
+async fn send(app: &axum::Router, method: &str, uri: &str, body: Value) -> StatusCode {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("test: build request"),
+        )
+        .await
+        .expect("test: request")
+        .status()
+}
+
+async fn get(app: &axum::Router, uri: &str) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("test: build request"),
+        )
+        .await
+        .expect("test: request")
+}
+
+async fn app_with_collection(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let status = send(
+        &app,
+        "POST",
+        "/collections",
+        json!({ "name": COLLECTION, "dimension": 2 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    app
+}
+
+/// `(event name, data)` of every SSE record in `text`.
+fn records(text: &str) -> Vec<(String, Value)> {
+    text.split("\n\n")
+        .filter_map(|record| {
+            let field = |name: &str| {
+                record
+                    .lines()
+                    .find_map(|l| l.strip_prefix(name).map(str::trim))
+            };
+            let event = field("event:")?;
+            let data = serde_json::from_str(field("data:")?).ok()?;
+            Some((event.to_string(), data))
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_subscribe_streams_filtered_changes() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = app_with_collection(&temp_dir).await;
+    let filter = r#"{"condition":{"type":"eq","field":"status","value":"open"}}"#;
+    let uri = format!(
+        "/collections/{COLLECTION}/subscribe?filter={}",
+        filter
+            .replace('{', "%7B")
+            .replace('}', "%7D")
+            .replace('"', "%22")
+    );
+    let response = get(&app, &uri).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body = response.into_body().into_data_stream();
+
+    let points = format!("/collections/{COLLECTION}/points");
+    let upsert = |id: u64, status: &str| json!({ "points": [{ "id": id, "vector": [1.0, 0.0], "payload": { "status": status } }] });
+    assert_eq!(
+        send(&app, "POST", &points, upsert(1, "open")).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        send(&app, "POST", &points, upsert(2, "closed")).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        send(&app, "POST", &points, upsert(1, "closed")).await,
+        StatusCode::OK
+    );
+
+    let mut text = String::new();
+    while records(&text).len() < 2 {
+        let chunk = tokio::time::timeout(Duration::from_secs(5), body.next())
+            .await
+            .expect("test: change event within 5s")
+            .expect("test: stream open")
+            .expect("test: chunk");
+        text.push_str(std::str::from_utf8(&chunk).expect("test: utf-8"));
+    }
+    let records = records(&text);
+    assert_eq!(records[0].0, "insert");
+    assert_eq!(records[0].1["id"], 1);
+    assert_eq!(records[0].1["payload"]["status"], "open");
+    // Point 2 never matched; point 1 left the filter.
+    assert_eq!(records[1].0, "delete");
+    assert_eq!(records[1].1["id"], 1);
+    assert_eq!(records[1].1["payload"]["status"], "open");
+}
+
+#[tokio::test]
+async fn test_subscribe_rejects_bad_filter_and_unknown_collection() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = app_with_collection(&temp_dir).await;
+    let response = get(
+        &app,
+        &format!("/collections/{COLLECTION}/subscribe?filter=nope"),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = get(&app, "/collections/missing/subscribe").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,92 @@
+//! Integration tests for `GET /collections/{name}/stats/vectors`
+//! (`get_vector_stats`).
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const COLLECTION: &str = "vector_stats";
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+async fn seeded_app(temp_dir: &TempDir) -> axum::Router {
+    let app = create_test_app(temp_dir);
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/collections",
+        Some(json!({ "name": COLLECTION, "dimension": 2 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let points: Vec<Value> = (0..20)
+        .map(|i| json!({ "id": i, "vector": [3.0, 4.0] }))
+        .collect();
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/collections/{COLLECTION}/points"),
+        Some(json!({ "points": points })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+#[tokio::test]
+async fn test_vector_stats_reports_sample_figures() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = seeded_app(&temp_dir).await;
+
+    let uri = format!("/collections/{COLLECTION}/stats/vectors");
+    let (status, body) = send(&app, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["sample_size"], 20);
+    assert_eq!(body["dimension"], 2);
+    assert_eq!(body["centroid"], json!([3.0, 4.0]));
+    assert_eq!(body["norm_p50"], 5.0);
+    assert!(body["intrinsic_dimension"].is_null(), "duplicates only");
+
+    let (status, body) = send(&app, "GET", &format!("{uri}?sample_size=5"), None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["sample_size"], 5);
+}
+
+#[tokio::test]
+async fn test_vector_stats_unknown_collection_returns_404() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+
+    let (status, _) = send(&app, "GET", "/collections/missing/stats/vectors", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,142 @@
+//! Integration tests for filtered views (`/views`) and reading through them.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test: build request");
+    let response = app.clone().oneshot(request).await.expect("test: request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("test: read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+fn sorted_ids(body: &Value) -> Vec<u64> {
+    let mut ids: Vec<u64> = body["results"]
+        .as_array()
+        .expect("test: results")
+        .iter()
+        // `/query` rows carry numeric ids, search hits string ids.
+        .map(|hit| match &hit["id"] {
+            Value::String(id) => id.parse().expect("test: id"),
+            id => id.as_u64().expect("test: id"),
+        })
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+async fn seed(app: &axum::Router) {
+    let (status, _) = send(
+        app,
+        "POST",
+        "/collections",
+        Some(json!({ "name": "articles", "dimension": 2 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let points = json!([
+        { "id": 1, "vector": [1.0, 0.0], "payload": { "status": "published" } },
+        { "id": 2, "vector": [0.9, 0.1], "payload": { "status": "draft" } },
+        { "id": 3, "vector": [0.1, 0.9], "payload": { "status": "published" } },
+    ]);
+    let (status, _) = send(
+        app,
+        "POST",
+        "/collections/articles/points",
+        Some(json!({ "points": points })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_view_is_readable_through_query_and_search() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    seed(&app).await;
+
+    let view = json!({
+        "name": "published",
+        "base": "articles",
+        "filter": "status = 'published'"
+    });
+    let (status, body) = send(&app, "POST", "/views", Some(view.clone())).await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body, view);
+
+    let query = json!({ "query": "SELECT * FROM published LIMIT 10" });
+    let (status, body) = send(&app, "POST", "/query", Some(query)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids(&body), [1, 3]);
+
+    let search = json!({ "vector": [1.0, 0.0], "top_k": 10 });
+    let (status, body) = send(&app, "POST", "/collections/published/search", Some(search)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(sorted_ids(&body), [1, 3]);
+
+    let (status, body) = send(&app, "GET", "/views", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!([view]));
+    let (status, _) = send(&app, "DELETE", "/views/published", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&app, "GET", "/views/published", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_view_rejects_conflicts_and_bad_filters() {
+    let temp_dir = TempDir::new().expect("test: temp dir");
+    let app = create_test_app(&temp_dir);
+    seed(&app).await;
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/views",
+        Some(json!({ "name": "articles", "base": "articles", "filter": "status = 'x'" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/views",
+        Some(json!({ "name": "v", "base": "missing", "filter": "status = 'x'" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/views",
+        Some(json!({ "name": "v", "base": "articles", "filter": "status = 'x' LIMIT 1" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,170 @@
+//! Integration tests for MessagePack/CBOR search and query responses
+//! negotiated with the `Accept` header.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::create_test_app;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+/// POSTs `body` with the given `Accept` header and returns the status,
+/// `Content-Type` and raw response bytes.
+async fn post(
+    app: &Router,
+    uri: &str,
+    accept: &str,
+    body: &Value,
+) -> (StatusCode, String, Vec<u8>) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("Accept", accept)
+        .body(Body::from(body.to_string()))
+        .expect("request");
+    let response = app.clone().oneshot(request).await.expect("response");
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .map(|v| v.to_str().expect("ascii header").to_string())
+        .unwrap_or_default();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body")
+        .to_vec();
+    (status, content_type, bytes)
+}
+
+async fn app_with_points(temp_dir: &TempDir) -> Router {
+    let app = create_test_app(temp_dir);
+    let (status, _, _) = post(
+        &app,
+        "/collections",
+        "application/json",
+        &json!({ "name": "docs", "dimension": 3, "metric": "cosine" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let points = json!({ "points": [
+        { "id": 1, "vector": [1.0, 0.0, 0.0], "payload": { "title": "a" } },
+        { "id": 2, "vector": [0.0, 1.0, 0.0], "payload": { "title": "b" } },
+    ] });
+    let (status, _, _) = post(
+        &app,
+        "/collections/docs/points",
+        "application/json",
+        &points,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+fn search_body() -> Value {
+    json!({ "vector": [1.0, 0.0, 0.0], "top_k": 2, "with_vector": true })
+}
+
+#[tokio::test]
+async fn search_responses_are_encoded_in_the_accepted_format() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_points(&temp_dir).await;
+
+    let (status, content_type, bytes) = post(
+        &app,
+        "/collections/docs/search",
+        "application/json",
+        &search_body(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/json");
+    let expected: Value = serde_json::from_slice(&bytes).expect("json");
+
+    let (status, content_type, bytes) = post(
+        &app,
+        "/collections/docs/search",
+        "application/msgpack",
+        &search_body(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/msgpack");
+    let msgpack: Value = rmp_serde::from_slice(&bytes).expect("msgpack");
+    assert_eq!(msgpack, expected);
+    assert_eq!(msgpack["results"][0]["vector"], json!([1.0, 0.0, 0.0]));
+
+    let (status, content_type, bytes) = post(
+        &app,
+        "/collections/docs/search",
+        "application/cbor",
+        &search_body(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/cbor");
+    let cbor: Value = ciborium::from_reader(bytes.as_slice()).expect("cbor");
+    assert_eq!(cbor, expected);
+}
+
+#[tokio::test]
+async fn ids_batch_and_query_endpoints_negotiate_too() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_points(&temp_dir).await;
+
+    let (_, content_type, bytes) = post(
+        &app,
+        "/collections/docs/search/ids",
+        "application/msgpack",
+        &search_body(),
+    )
+    .await;
+    assert_eq!(content_type, "application/msgpack");
+    let ids: Value = rmp_serde::from_slice(&bytes).expect("msgpack");
+    assert_eq!(ids["results"].as_array().map(Vec::len), Some(2));
+
+    let batch = json!({ "searches": [search_body(), search_body()] });
+    let (_, content_type, bytes) = post(
+        &app,
+        "/collections/docs/search/batch",
+        "application/cbor",
+        &batch,
+    )
+    .await;
+    assert_eq!(content_type, "application/cbor");
+    let batch: Value = ciborium::from_reader(bytes.as_slice()).expect("cbor");
+    assert_eq!(batch["results"].as_array().map(Vec::len), Some(2));
+
+    let query = json!({ "query": "SELECT * FROM docs LIMIT 10", "params": {} });
+    let (status, content_type, bytes) = post(&app, "/query", "application/msgpack", &query).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/msgpack");
+    let rows: Value = rmp_serde::from_slice(&bytes).expect("msgpack");
+    assert_eq!(rows["rows_returned"], 2);
+}
+
+#[tokio::test]
+async fn errors_stay_json() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let app = app_with_points(&temp_dir).await;
+    let wrong_dimension = json!({ "vector": [1.0, 0.0], "top_k": 2 });
+
+    let (status, content_type, bytes) = post(
+        &app,
+        "/collections/docs/search",
+        "application/msgpack",
+        &wrong_dimension,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(content_type, "application/json");
+    let error: Value = serde_json::from_slice(&bytes).expect("json");
+    assert!(error["error"].is_string());
+}
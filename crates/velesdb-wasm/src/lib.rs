@@ -77,6 +77,7 @@ mod store_get;
 mod store_insert;
 mod store_new;
 mod store_search;
+mod store_validator;
 mod text_search;
 mod vector_ops;
 mod vector_store;
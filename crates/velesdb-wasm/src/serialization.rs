@@ -218,6 +218,7 @@ fn import_v2(bytes: &[u8]) -> Result<VectorStore, JsValue> {
         metric,
         storage_mode,
         sparse_index: None,
+        upsert_validator: None,
     })
 }
 
@@ -305,6 +306,7 @@ fn import_v1(bytes: &[u8]) -> Result<VectorStore, JsValue> {
         metric,
         storage_mode: StorageMode::Full,
         sparse_index: None,
+        upsert_validator: None,
     })
 }
 
@@ -369,6 +371,7 @@ mod tests {
             metric: DistanceMetric::Cosine,
             storage_mode: StorageMode::Full,
             sparse_index: None,
+            upsert_validator: None,
         };
         let restored = import_from_bytes(&export_to_bytes(&store)).unwrap();
         assert_eq!(restored.ids, vec![1, 2]);
@@ -396,6 +399,7 @@ mod tests {
             metric: DistanceMetric::Euclidean,
             storage_mode: StorageMode::SQ8,
             sparse_index: None,
+            upsert_validator: None,
         };
         let restored = import_from_bytes(&export_to_bytes(&store)).unwrap();
         assert_eq!(
@@ -423,6 +427,7 @@ mod tests {
             metric: DistanceMetric::Hamming,
             storage_mode: StorageMode::Binary,
             sparse_index: None,
+            upsert_validator: None,
         };
         let restored = import_from_bytes(&export_to_bytes(&store)).unwrap();
         assert_eq!(
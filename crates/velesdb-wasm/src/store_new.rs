@@ -20,6 +20,7 @@ pub fn create_store(
         metric,
         storage_mode,
         sparse_index: None,
+        upsert_validator: None,
     }
 }
 
@@ -0,0 +1,68 @@
+//! User-defined upsert validation for `VectorStore`.
+//!
+//! The JS counterpart of core's `VectorCollection::set_upsert_validator`. A
+//! callback registered with `setUpsertValidator` receives every incoming
+//! point as `{id, vector, payload}` (the `get` shape) before it is stored.
+//! It rejects the point by throwing, returning `false` or returning a reason
+//! string; any other return value accepts it. A rejected batch stores
+//! nothing and fails with a structured `VELES-017` (`SchemaValidation`) JS
+//! `Error` naming the point.
+
+use velesdb_core::Error as CoreError;
+use wasm_bindgen::JsValue;
+
+use crate::wasm_error::WasmError;
+use crate::VectorStore;
+
+#[cfg(test)]
+#[path = "store_validator_tests.rs"]
+mod tests;
+
+impl VectorStore {
+    /// Runs the registered upsert validator on one point; always `Ok`
+    /// without a validator.
+    pub(crate) fn validate_upsert(
+        &self,
+        id: u64,
+        vector: &[f32],
+        payload: Option<&serde_json::Value>,
+    ) -> Result<(), JsValue> {
+        let Some(validator) = self.upsert_validator.as_ref() else {
+            return Ok(());
+        };
+        let point = serde_json::json!({
+            "id": id,
+            "vector": vector,
+            "payload": payload,
+        });
+        let point =
+            serde_wasm_bindgen::to_value(&point).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let reason = match validator.call1(&JsValue::NULL, &point) {
+            Err(thrown) => Some(thrown_message(&thrown)),
+            Ok(returned) if returned.as_bool() == Some(false) => {
+                Some("validator returned false".to_string())
+            }
+            Ok(returned) => returned.as_string(),
+        };
+        match reason {
+            Some(reason) => Err(rejection(id, &reason).into_js_value()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Message of a value thrown by the validator: an `Error`'s `message`, a
+/// thrown string, or its debug rendering.
+fn thrown_message(thrown: &JsValue) -> String {
+    if let Some(error) = wasm_bindgen::JsCast::dyn_ref::<js_sys::Error>(thrown) {
+        return String::from(error.message());
+    }
+    thrown.as_string().unwrap_or_else(|| format!("{thrown:?}"))
+}
+
+/// The `VELES-017` error reported for a point the validator rejected.
+fn rejection(id: u64, reason: &str) -> WasmError {
+    WasmError::from(CoreError::SchemaValidation(format!(
+        "point {id} rejected by upsert validator: {reason}"
+    )))
+}
@@ -0,0 +1,23 @@
+//! Native tests for `store_validator`. Calling the JS callback needs a JS
+//! runtime, so only the callback-free parts are covered here.
+
+use super::*;
+use crate::store_new::create_store;
+use crate::{DistanceMetric, StorageMode};
+
+#[test]
+fn test_rejection_is_a_schema_validation_error() {
+    let err = rejection(42, "missing 'source'");
+
+    assert_eq!(err.code(), "VELES-017");
+    assert!(err.message().contains("point 42"), "{}", err.message());
+    assert!(err.message().contains("missing 'source'"));
+}
+
+#[test]
+fn test_store_without_validator_accepts_every_point() {
+    let store = create_store(2, DistanceMetric::Cosine, StorageMode::Full);
+
+    assert!(store.upsert_validator.is_none());
+    assert!(store.validate_upsert(1, &[1.0, 0.0], None).is_ok());
+}
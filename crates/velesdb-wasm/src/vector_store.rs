@@ -50,6 +50,8 @@ pub struct VectorStore {
     pub(crate) storage_mode: StorageMode,
     /// Optional sparse index for sparse/hybrid search
     pub(crate) sparse_index: Option<sparse::SparseIndex>,
+    /// Optional JS upsert validator (`setUpsertValidator`); not serialized
+    pub(crate) upsert_validator: Option<js_sys::Function>,
 }
 
 impl VectorStore {
@@ -139,6 +141,7 @@ impl VectorStore {
     #[wasm_bindgen]
     pub fn insert(&mut self, id: u64, vector: &[f32]) -> Result<(), JsValue> {
         store_search::validate_dimension(vector.len(), self.dimension)?;
+        self.validate_upsert(id, vector, None)?;
         store_insert::insert_vector(self, id, vector);
         Ok(())
     }
@@ -161,6 +164,7 @@ impl VectorStore {
                         .map_err(|e| JsValue::from_str(&format!("Invalid payload: {e}")))?,
                 )
             };
+        self.validate_upsert(id, vector, parsed_payload.as_ref())?;
         store_insert::insert_with_payload(self, id, vector, parsed_payload);
         Ok(())
     }
@@ -596,6 +600,7 @@ impl VectorStore {
                     vector.len()
                 )));
             }
+            self.validate_upsert(*id, vector, None)?;
         }
         self.ids.reserve(batch.len());
         self.data.reserve(batch.len() * self.dimension);
@@ -620,9 +625,27 @@ impl VectorStore {
         vectors: &[f32],
         dimension: usize,
     ) -> Result<(), JsValue> {
+        if dimension > 0 && self.upsert_validator.is_some() {
+            for (id, vector) in ids.iter().zip(vectors.chunks_exact(dimension)) {
+                self.validate_upsert(*id, vector, None)?;
+            }
+        }
         store_insert::insert_batch_raw(self, ids, vectors, dimension)
             .map_err(|e| JsValue::from_str(&e))
     }
+
+    /// Registers a callback run on every point before `insert`,
+    /// `insert_with_payload`, `insert_batch` or `insertBatchRaw` stores it;
+    /// `null` removes it.
+    ///
+    /// The callback receives `{id, vector, payload}` and rejects the point by
+    /// throwing, returning `false` or returning a reason string. A rejected
+    /// batch stores nothing and fails with a `VELES-017` error. The callback
+    /// is not persisted.
+    #[wasm_bindgen(js_name = setUpsertValidator)]
+    pub fn set_upsert_validator(&mut self, validator: Option<js_sys::Function>) {
+        self.upsert_validator = validator;
+    }
 }
 
 // Native-testable internals (not part of the wasm-bindgen surface).
@@ -24,8 +24,9 @@
 use std::collections::BTreeMap;
 
 use velesdb_core::velesql::{
-    AggregateArg, AggregateFunction, AggregateType, CompareOp, DistinctMode, HavingClause,
-    HavingCondition, LogicalOp, OrderByExpr, SelectColumns, SelectOrderBy, SelectStatement, Value,
+    AggregateArg, AggregateFunction, AggregateType, CompareOp, DistinctMode, GroupByExpression,
+    HavingClause, HavingCondition, LogicalOp, OrderByExpr, SelectColumns, SelectOrderBy,
+    SelectStatement, Value,
 };
 
 use crate::velesql_result::QueryResultRow;
@@ -144,7 +145,11 @@ fn aggregate(
         .as_ref()
         .map(|g| g.columns.clone())
         .unwrap_or_default();
-    let groups = materialize_groups(&group_cols, rows, &stmt.columns);
+    let group_exprs = stmt
+        .group_by
+        .as_ref()
+        .map_or(&[][..], |g| g.expressions.as_slice());
+    let groups = materialize_groups(&group_cols, group_exprs, rows, &stmt.columns);
     let aggregates = extract_aggregates(&stmt.columns);
     let plain_cols = extract_plain_columns(&stmt.columns);
     let mut json_rows: Vec<serde_json::Value> = Vec::with_capacity(groups.len());
@@ -225,10 +230,11 @@ fn compare_aggregate_rows(
 /// group row when GROUP BY is absent and the SELECT contains aggregates.
 fn materialize_groups<'a>(
     group_cols: &[String],
+    group_exprs: &[GroupByExpression],
     rows: &[ScannedRow<'a>],
     columns: &SelectColumns,
 ) -> Vec<(Vec<serde_json::Value>, Vec<ScannedRow<'a>>)> {
-    let mut groups = partition_into_groups(group_cols, rows);
+    let mut groups = partition_into_groups(group_cols, group_exprs, rows);
     if groups.is_empty() && group_cols.is_empty() && has_aggregate_columns(columns) {
         groups.push((Vec::new(), Vec::new()));
     }
@@ -262,13 +268,14 @@ fn finalize_group(
 /// deterministic output.
 fn partition_into_groups<'a>(
     group_cols: &[String],
+    group_exprs: &[GroupByExpression],
     rows: &[ScannedRow<'a>],
 ) -> Vec<(Vec<serde_json::Value>, Vec<ScannedRow<'a>>)> {
     let mut ordered_keys: Vec<Vec<serde_json::Value>> = Vec::new();
     let mut buckets: BTreeMap<String, Vec<ScannedRow<'a>>> = BTreeMap::new();
     let mut dedup_keys: BTreeMap<String, Vec<serde_json::Value>> = BTreeMap::new();
     for &(id, score, payload) in rows {
-        let key = group_key(group_cols, id, payload);
+        let key = group_key(group_cols, group_exprs, id, payload);
         let key_str = serde_json::to_string(&key).unwrap_or_default();
         if !dedup_keys.contains_key(&key_str) {
             ordered_keys.push(key.clone());
@@ -290,14 +297,24 @@ fn partition_into_groups<'a>(
 }
 
 /// Returns the vector of column values that forms the grouping key.
+///
+/// Expression keys (`date_trunc('day', ts)`, `floor(price / 10) * 10`) are
+/// evaluated against the payload instead of read as columns.
 fn group_key(
     group_cols: &[String],
+    group_exprs: &[GroupByExpression],
     id: u64,
     payload: Option<&serde_json::Value>,
 ) -> Vec<serde_json::Value> {
     group_cols
         .iter()
-        .map(|c| extract_column(c, id, payload))
+        .map(
+            |c| match (group_exprs.iter().find(|e| &e.key == c), payload) {
+                (Some(e), Some(p)) => velesdb_core::filter::evaluate_expression(&e.expr, p),
+                (Some(_), None) => serde_json::Value::Null,
+                (None, _) => extract_column(c, id, payload),
+            },
+        )
         .collect()
 }
 
@@ -377,15 +394,7 @@ fn write_aggregates(
 }
 
 fn aggregate_default_name(agg: &AggregateFunction) -> String {
-    let fn_name = match agg.function_type {
-        AggregateType::Count => "count",
-        AggregateType::Sum => "sum",
-        AggregateType::Avg => "avg",
-        AggregateType::Min => "min",
-        AggregateType::Max => "max",
-        AggregateType::First => "first",
-        _ => "agg", // forward-compat for #[non_exhaustive]
-    };
+    let fn_name = agg.result_key_prefix();
     let arg_name = match &agg.argument {
         AggregateArg::Wildcard => "*".to_string(),
         AggregateArg::Column(c) => c.clone(),
@@ -406,6 +415,18 @@ pub(crate) fn compute_aggregate(
         AggregateType::Min => Ok(min_max(&agg.argument, rows, true)),
         AggregateType::Max => Ok(min_max(&agg.argument, rows, false)),
         AggregateType::First => Ok(first_value(&agg.argument, rows)),
+        AggregateType::StdDev => Ok(variance(&agg.argument, rows)
+            .map_or(serde_json::Value::Null, |v| serde_json::json!(v.sqrt()))),
+        AggregateType::Variance => {
+            Ok(variance(&agg.argument, rows)
+                .map_or(serde_json::Value::Null, |v| serde_json::json!(v)))
+        }
+        AggregateType::PercentileCont => Ok(percentile_cont(
+            &agg.argument,
+            rows,
+            agg.fraction.unwrap_or(0.5),
+        )),
+        AggregateType::CountDistinct => Ok(serde_json::json!(count_distinct(&agg.argument, rows))),
         _ => Err(format!(
             "Unsupported aggregate function in WASM: {:?}",
             agg.function_type
@@ -461,6 +482,51 @@ fn min_max(arg: &AggregateArg, rows: &[ScannedRow<'_>], pick_min: bool) -> serde
     best
 }
 
+/// Sample variance (`n - 1` denominator); `None` below two values.
+fn variance(arg: &AggregateArg, rows: &[ScannedRow<'_>]) -> Option<f64> {
+    let values = numeric_values(arg, rows);
+    if values.len() < 2 {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let m2: f64 = values.iter().map(|v| (v - mean) * (v - mean)).sum();
+    Some(m2 / (n - 1.0))
+}
+
+/// Exact PERCENTILE_CONT: linear interpolation between neighbouring ranks.
+fn percentile_cont(
+    arg: &AggregateArg,
+    rows: &[ScannedRow<'_>],
+    fraction: f64,
+) -> serde_json::Value {
+    let mut values = numeric_values(arg, rows);
+    if values.is_empty() {
+        return serde_json::Value::Null;
+    }
+    values.sort_by(f64::total_cmp);
+    #[allow(clippy::cast_precision_loss)]
+    let rank = fraction.clamp(0.0, 1.0) * (values.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    #[allow(clippy::cast_precision_loss)]
+    let weight = rank - lo as f64;
+    serde_json::json!(values[lo] + (values[hi] - values[lo]) * weight)
+}
+
+/// Exact COUNT(DISTINCT): numbers compare by value, NULLs are skipped.
+fn count_distinct(arg: &AggregateArg, rows: &[ScannedRow<'_>]) -> u64 {
+    let keys: std::collections::HashSet<String> = collect_values(arg, rows)
+        .into_iter()
+        .map(|v| match v.as_f64() {
+            Some(f) if v.is_number() => format!("n:{}", f + 0.0),
+            _ => v.to_string(),
+        })
+        .collect();
+    keys.len() as u64
+}
+
 fn first_value(arg: &AggregateArg, rows: &[ScannedRow<'_>]) -> serde_json::Value {
     collect_values(arg, rows)
         .into_iter()
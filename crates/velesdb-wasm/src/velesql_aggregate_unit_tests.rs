@@ -38,6 +38,7 @@ fn test_needs_pipeline_flags_group_by() {
     let mut s = base_select();
     s.group_by = Some(GroupByClause {
         columns: vec!["cat".to_string()],
+        expressions: Vec::new(),
     });
     assert!(needs_aggregation_pipeline(&s));
 }
@@ -61,6 +62,7 @@ fn test_count_star_global() {
         function_type: AggregateType::Count,
         argument: AggregateArg::Wildcard,
         alias: Some("total".to_string()),
+        fraction: None,
     }]);
     let out = apply(&s, &rows, &Params::new()).expect("test: agg");
     assert_eq!(out.len(), 1);
@@ -80,9 +82,11 @@ fn test_group_by_count() {
         function_type: AggregateType::Count,
         argument: AggregateArg::Wildcard,
         alias: Some("n".to_string()),
+        fraction: None,
     }]);
     s.group_by = Some(GroupByClause {
         columns: vec!["cat".to_string()],
+        expressions: Vec::new(),
     });
     let out = apply(&s, &rows, &Params::new()).expect("test: agg");
     assert_eq!(out.len(), 2);
@@ -100,6 +104,30 @@ fn test_group_by_count() {
     );
 }
 
+#[test]
+fn test_group_by_bucket_expression() {
+    let raw = vec![
+        row(1, 0.0, &serde_json::json!({"price": 3})),
+        row(2, 0.0, &serde_json::json!({"price": 9.5})),
+        row(3, 0.0, &serde_json::json!({"price": 14})),
+    ];
+    let rows = scanned(&raw);
+    let stmt = velesdb_core::velesql::Parser::parse(
+        "SELECT bucket(price, 10) AS band, COUNT(*) AS n FROM t GROUP BY band",
+    )
+    .expect("test: parse")
+    .select;
+    let out = apply(&stmt, &rows, &Params::new()).expect("test: agg");
+    let json: Vec<&str> = out.iter().map(|r| r.data_json_ref()).collect();
+    assert_eq!(out.len(), 2, "got {json:?}");
+    assert!(json
+        .iter()
+        .any(|j| j.contains("\"band\":0") && j.contains("\"n\":2")));
+    assert!(json
+        .iter()
+        .any(|j| j.contains("\"band\":10") && j.contains("\"n\":1")));
+}
+
 /// Helper: index of the first row whose JSON contains `needle`.
 fn pos(rows: &[crate::velesql_result::QueryResultRow], needle: &str) -> usize {
     rows.iter()
@@ -125,14 +153,17 @@ fn test_group_by_order_by_count_desc() {
         function_type: AggregateType::Count,
         argument: AggregateArg::Wildcard,
         alias: None,
+        fraction: None,
     };
     s.columns = SelectColumns::Aggregations(vec![count_star.clone()]);
     s.group_by = Some(GroupByClause {
         columns: vec!["cat".to_string()],
+        expressions: Vec::new(),
     });
     s.order_by = Some(vec![SelectOrderBy {
         expr: OrderByExpr::Aggregate(count_star),
         descending: true,
+        collation: velesdb_core::SortCollation::Binary,
     }]);
     let out = apply(&s, &rows, &Params::new()).expect("test: agg order by");
     assert_eq!(out.len(), 3);
@@ -155,13 +186,16 @@ fn test_group_by_order_by_group_key_asc() {
         function_type: AggregateType::Count,
         argument: AggregateArg::Wildcard,
         alias: Some("n".to_string()),
+        fraction: None,
     }]);
     s.group_by = Some(GroupByClause {
         columns: vec!["cat".to_string()],
+        expressions: Vec::new(),
     });
     s.order_by = Some(vec![SelectOrderBy {
         expr: OrderByExpr::Field("cat".to_string()),
         descending: false,
+        collation: velesdb_core::SortCollation::Binary,
     }]);
     let out = apply(&s, &rows, &Params::new()).expect("test: agg order by key");
     assert_eq!(out.len(), 3);
@@ -182,6 +216,7 @@ fn test_avg_numeric() {
         function_type: AggregateType::Avg,
         argument: AggregateArg::Column("price".to_string()),
         alias: Some("avg_p".to_string()),
+        fraction: None,
     }]);
     let out = apply(&s, &rows, &Params::new()).expect("test: avg");
     assert!(out[0].data_json_ref().contains("\"avg_p\":20"));
@@ -225,6 +260,7 @@ fn test_min_max() {
             function_type: AggregateType::Min,
             argument: AggregateArg::Column("p".to_string()),
             alias: None,
+            fraction: None,
         },
         &rows,
     )
@@ -234,6 +270,7 @@ fn test_min_max() {
             function_type: AggregateType::Max,
             argument: AggregateArg::Column("p".to_string()),
             alias: None,
+            fraction: None,
         },
         &rows,
     )
@@ -255,6 +292,7 @@ fn test_count_ignores_null_column() {
             function_type: AggregateType::Count,
             argument: AggregateArg::Column("x".to_string()),
             alias: None,
+            fraction: None,
         },
         &rows,
     )
@@ -275,9 +313,11 @@ fn test_having_filters_groups() {
         function_type: AggregateType::Count,
         argument: AggregateArg::Wildcard,
         alias: Some("n".to_string()),
+        fraction: None,
     }]);
     s.group_by = Some(GroupByClause {
         columns: vec!["cat".to_string()],
+        expressions: Vec::new(),
     });
     s.having = Some(HavingClause {
         conditions: vec![HavingCondition {
@@ -285,6 +325,7 @@ fn test_having_filters_groups() {
                 function_type: AggregateType::Count,
                 argument: AggregateArg::Wildcard,
                 alias: None,
+                fraction: None,
             },
             operator: CompareOp::Gt,
             value: Value::Integer(1),
@@ -295,3 +336,33 @@ fn test_having_filters_groups() {
     assert_eq!(out.len(), 1);
     assert!(out[0].data_json_ref().contains("\"cat\":\"a\""));
 }
+
+#[test]
+fn test_compute_statistical_aggregates() {
+    let raw = vec![
+        row(1, 0.0, &serde_json::json!({"v": 1, "tag": "x"})),
+        row(2, 0.0, &serde_json::json!({"v": 2, "tag": "y"})),
+        row(3, 0.0, &serde_json::json!({"v": 3, "tag": "x"})),
+        row(4, 0.0, &serde_json::json!({"v": 4})),
+    ];
+    let rows = scanned(&raw);
+    let agg = |function_type, column: &str, fraction| AggregateFunction {
+        function_type,
+        argument: AggregateArg::Column(column.to_string()),
+        alias: None,
+        fraction,
+    };
+
+    let variance =
+        compute_aggregate(&agg(AggregateType::Variance, "v", None), &rows).expect("test: variance");
+    assert!((variance.as_f64().unwrap() - 5.0 / 3.0).abs() < 1e-12);
+    let stddev =
+        compute_aggregate(&agg(AggregateType::StdDev, "v", None), &rows).expect("test: stddev");
+    assert!((stddev.as_f64().unwrap() - (5.0f64 / 3.0).sqrt()).abs() < 1e-12);
+    let q1 = compute_aggregate(&agg(AggregateType::PercentileCont, "v", Some(0.25)), &rows)
+        .expect("test: percentile");
+    assert_eq!(q1.as_f64(), Some(1.75));
+    let tags = compute_aggregate(&agg(AggregateType::CountDistinct, "tag", None), &rows)
+        .expect("test: count distinct");
+    assert_eq!(tags.as_u64(), Some(2));
+}
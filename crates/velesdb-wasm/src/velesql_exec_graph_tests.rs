@@ -1247,6 +1247,7 @@ fn test_bdd_match_multi_pattern_returns_clear_error() {
     let clause = parsed.match_clause.as_mut().expect("test: has match");
     let second = GraphPattern {
         name: None,
+        shortest_path: false,
         nodes: vec![NodePattern {
             alias: Some("c".to_string()),
             labels: vec!["Person".to_string()],
@@ -74,6 +74,8 @@ mod tests {
             graph_weight: None,
             dense_weight: None,
             sparse_weight: None,
+            boost_field: None,
+            boost_alpha: None,
         }
     }
 
@@ -105,6 +107,8 @@ mod tests {
             graph_weight: Some(2.0),
             dense_weight: None,
             sparse_weight: None,
+            boost_field: None,
+            boost_alpha: None,
         };
         let fused = apply(&bad, vec![vec![(1, 0.5)], vec![(2, 0.7)]]);
         assert!(!fused.is_empty());
@@ -119,6 +123,8 @@ mod tests {
             graph_weight: None,
             dense_weight: None,
             sparse_weight: None,
+            boost_field: None,
+            boost_alpha: None,
         };
         let b1 = vec![(1, 0.2), (2, 0.5)];
         let b2 = vec![(1, 0.9), (3, 0.1)];
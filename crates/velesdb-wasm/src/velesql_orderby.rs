@@ -23,6 +23,7 @@ use velesdb_core::point::SearchResult;
 use velesdb_core::velesql::{
     ArithmeticExpr, ArithmeticOp, OrderByExpr, SelectOrderBy, SelectStatement,
 };
+use velesdb_core::SortCollation;
 
 use crate::velesql_value::json_values_cmp;
 
@@ -88,7 +89,7 @@ fn compare_rows(a: &SearchResult, b: &SearchResult, specs: &[SelectOrderBy]) ->
 
 fn compare_with_spec(a: &SearchResult, b: &SearchResult, spec: &SelectOrderBy) -> Ordering {
     let ord = match &spec.expr {
-        OrderByExpr::Field(name) => compare_field(a, b, name),
+        OrderByExpr::Field(name) => compare_field(a, b, name, spec.collation),
         OrderByExpr::SimilarityBare => compare_scores(a, b),
         OrderByExpr::Arithmetic(expr) => {
             let va = eval_arithmetic(expr, a);
@@ -107,8 +108,14 @@ fn compare_with_spec(a: &SearchResult, b: &SearchResult, spec: &SelectOrderBy) -
 }
 
 /// Compares a pair of rows by the given column. `id` and `score` are
-/// resolved from their dedicated fields; everything else from the payload.
-fn compare_field(a: &SearchResult, b: &SearchResult, name: &str) -> Ordering {
+/// resolved from their dedicated fields; everything else from the payload,
+/// strings ordered under the key's `COLLATE` collation.
+fn compare_field(
+    a: &SearchResult,
+    b: &SearchResult,
+    name: &str,
+    collation: SortCollation,
+) -> Ordering {
     if name == "id" {
         return a.point.id.cmp(&b.point.id);
     }
@@ -117,6 +124,9 @@ fn compare_field(a: &SearchResult, b: &SearchResult, name: &str) -> Ordering {
     }
     let va = extract_payload_field(a, name);
     let vb = extract_payload_field(b, name);
+    if let (Some(serde_json::Value::String(sa)), Some(serde_json::Value::String(sb))) = (&va, &vb) {
+        return collation.compare(sa, sb);
+    }
     compare_json_with_nulls(va.as_ref(), vb.as_ref())
 }
 
@@ -251,6 +261,7 @@ mod tests {
         vec![SelectOrderBy {
             expr: OrderByExpr::Field(name.to_string()),
             descending: desc,
+            collation: SortCollation::Binary,
         }]
     }
 
@@ -307,6 +318,7 @@ mod tests {
             vec![SelectOrderBy {
                 expr: OrderByExpr::SimilarityBare,
                 descending: true,
+                collation: SortCollation::Binary,
             }],
             &mut rows,
         );
@@ -335,6 +347,7 @@ mod tests {
             vec![SelectOrderBy {
                 expr: OrderByExpr::Arithmetic(expr),
                 descending: false,
+                collation: SortCollation::Binary,
             }],
             &mut rows,
         );
@@ -354,6 +367,7 @@ mod tests {
                 vector: VectorExpr::Parameter("q".to_string()),
             }),
             descending: true,
+            collation: SortCollation::Binary,
         }]);
         let err = sort_rows(&stmt, &mut rows);
         assert!(err.is_err());
@@ -363,4 +377,22 @@ mod tests {
     fn test_division_by_zero_yields_zero() {
         assert_eq!(apply_op(ArithmeticOp::Div, 5.0, 0.0), 0.0);
     }
+
+    #[test]
+    fn test_sort_by_payload_column_collate_ci_ai() {
+        let mut rows = vec![
+            mk(1, 0.0, serde_json::json!({"name": "eric"})),
+            mk(2, 0.0, serde_json::json!({"name": "Émile"})),
+            mk(3, 0.0, serde_json::json!({"name": "adam"})),
+        ];
+        let mut order = by_field("name", false);
+        sort(order.clone(), &mut rows);
+        let ids: Vec<u64> = rows.iter().map(|r| r.point.id).collect();
+        assert_eq!(ids, [3, 1, 2]);
+
+        order[0].collation = SortCollation::CiAi;
+        sort(order, &mut rows);
+        let ids: Vec<u64> = rows.iter().map(|r| r.point.id).collect();
+        assert_eq!(ids, [3, 2, 1]);
+    }
 }
@@ -11,6 +11,9 @@
 //!   [`velesdb_core::velesql::window_evaluator::evaluate`], which injects each
 //!   window alias (`ROW_NUMBER`/`RANK`/`DENSE_RANK`) into the row payload
 //!   **before** ORDER BY/projection, matching the core pipeline position.
+//! - [`inject_select_expressions`] does the same for computed SELECT items
+//!   (`price * 1.2 AS price_with_tax`) via core's
+//!   [`velesdb_core::filter::evaluate_expression`].
 //! - [`project`] reproduces `projection::project_single`'s column extraction.
 //!   That function lives in `velesdb_core::collection`, which is gated behind
 //!   the `persistence` feature WASM never enables, so it cannot be called
@@ -20,7 +23,7 @@
 
 use velesdb_core::point::{Point, SearchResult};
 use velesdb_core::velesql::{
-    Column, SelectColumns, SelectStatement, SimilarityScoreExpr, WindowFunction,
+    Column, SelectColumns, SelectExpression, SelectStatement, SimilarityScoreExpr, WindowFunction,
 };
 
 use crate::velesql_result::QueryResultRow;
@@ -51,6 +54,35 @@ pub(crate) fn inject_window_functions(
         .map_err(|e| format!("Window function evaluation failed: {e}"))
 }
 
+/// Evaluates computed SELECT expressions and injects them into each row
+/// payload under their output names (mirrors core's
+/// `select_dispatch::inject_select_expressions`). No-op when there are none.
+pub(crate) fn inject_select_expressions(stmt: &SelectStatement, results: &mut [SearchResult]) {
+    let SelectColumns::Mixed { expressions, .. } = &stmt.columns else {
+        return;
+    };
+    if expressions.is_empty() {
+        return;
+    }
+    let names: Vec<String> = expressions
+        .iter()
+        .map(SelectExpression::output_name)
+        .collect();
+    for result in results.iter_mut() {
+        let payload = result
+            .point
+            .payload
+            .get_or_insert_with(|| serde_json::Value::Object(JsonMap::new()));
+        let values: Vec<serde_json::Value> = expressions
+            .iter()
+            .map(|e| velesdb_core::filter::evaluate_expression(&e.expr, payload))
+            .collect();
+        if let serde_json::Value::Object(map) = payload {
+            map.extend(names.iter().cloned().zip(values));
+        }
+    }
+}
+
 /// Returns the window functions in a `SELECT` list, if any (mirrors core's
 /// `Collection::extract_window_functions`).
 fn extract_window_functions(columns: &SelectColumns) -> Option<&[WindowFunction]> {
@@ -90,6 +122,7 @@ fn project_single(result: &SearchResult, columns: &SelectColumns) -> serde_json:
             similarity_scores,
             qualified_wildcards,
             window_functions,
+            expressions,
             ..
         } => project_mixed(
             result,
@@ -97,6 +130,7 @@ fn project_single(result: &SearchResult, columns: &SelectColumns) -> serde_json:
             similarity_scores,
             qualified_wildcards,
             window_functions,
+            expressions,
         ),
         // `SelectColumns` is non_exhaustive; an unknown future variant falls
         // back to the wildcard shape (id + payload) rather than dropping rows.
@@ -127,18 +161,28 @@ fn project_similarity_only(result: &SearchResult, expr: &SimilarityScoreExpr) ->
 }
 
 /// Mixed projection: qualified wildcards + columns + similarity scores +
-/// window functions (window aliases were injected into the payload upstream).
+/// window functions + expressions (window and expression values were injected
+/// into the payload upstream).
 fn project_mixed(
     result: &SearchResult,
     columns: &[Column],
     similarity_scores: &[SimilarityScoreExpr],
     qualified_wildcards: &[String],
     window_functions: &[WindowFunction],
+    expressions: &[SelectExpression],
 ) -> serde_json::Value {
     let mut map = JsonMap::new();
-    let window_aliases: Vec<&str> = window_functions.iter().map(window_alias).collect();
+    let expression_names: Vec<String> = expressions
+        .iter()
+        .map(SelectExpression::output_name)
+        .collect();
+    let computed_keys: Vec<&str> = window_functions
+        .iter()
+        .map(window_alias)
+        .chain(expression_names.iter().map(String::as_str))
+        .collect();
     if !qualified_wildcards.is_empty() {
-        insert_wildcard_fields(&mut map, result, &window_aliases);
+        insert_wildcard_fields(&mut map, result, &computed_keys);
     }
     insert_named_columns(&mut map, result, columns);
     for expr in similarity_scores {
@@ -150,11 +194,15 @@ fn project_mixed(
         let value = payload_field(result, alias).unwrap_or(serde_json::Value::Null);
         map.insert(alias.to_string(), value);
     }
+    for name in expression_names {
+        let value = payload_field(result, &name).unwrap_or(serde_json::Value::Null);
+        map.insert(name, value);
+    }
     serde_json::Value::Object(map)
 }
 
 /// Inserts `id` + every payload field, skipping any key shadowed by a
-/// window-function alias.
+/// window-function or expression alias.
 fn insert_wildcard_fields(map: &mut JsonMap, result: &SearchResult, skip: &[&str]) {
     map.insert("id".to_string(), serde_json::Value::from(result.point.id));
     if let Some(serde_json::Value::Object(payload)) = result.point.payload.as_ref() {
@@ -250,6 +298,20 @@ mod tests {
         assert!(body.get("title").is_none());
     }
 
+    #[test]
+    fn test_select_expressions_are_injected_and_projected() {
+        let query = velesdb_core::velesql::Parser::parse(
+            "SELECT title || '-' || cat AS label, cat FROM docs",
+        )
+        .expect("test: parse");
+        let mut results = to_search_results(rows());
+        inject_select_expressions(&query.select, &mut results);
+        let out = project(&query.select, &results).expect("test: project");
+        let body: serde_json::Value =
+            serde_json::from_str(out[1].data_json_ref()).expect("test: json");
+        assert_eq!(body, serde_json::json!({"cat": "b", "label": "Y-b"}));
+    }
+
     #[test]
     fn test_inject_window_functions_noop_without_window() {
         let stmt = SelectStatement::empty();
@@ -249,6 +249,9 @@ fn resolve_query_vector(
     store: &crate::vector_store::VectorStore,
     params: &Params,
 ) -> Result<Vec<f32>, String> {
+    if !vs.avoid.is_empty() {
+        return Err("NEAR ... AVOID is not supported in WASM".to_string());
+    }
     let q = resolve_vector(&vs.vector, params)?;
     if q.len() != store.dimension {
         return Err(format!(
@@ -421,7 +424,7 @@ fn finalize_aggregated(
     Ok(apply_limit_offset(stmt, out, u64::MAX))
 }
 
-/// Finalizes a non-aggregated SELECT: window functions, ORDER BY, column
+/// Finalizes a non-aggregated SELECT: computed expressions, window functions, ORDER BY, column
 /// projection (including `AS` aliases), then OFFSET/LIMIT.
 ///
 /// Routed through velesdb-core's single source of truth — `window_evaluator`
@@ -435,6 +438,7 @@ fn finalize_plain(
     rows: Vec<OwnedScanRow>,
 ) -> Result<Vec<QueryResultRow>, String> {
     let mut results = velesql_project::to_search_results(rows);
+    velesql_project::inject_select_expressions(stmt, &mut results);
     velesql_project::inject_window_functions(stmt, &mut results)?;
     velesql_orderby::sort_rows(stmt, &mut results)?;
     let projected = velesql_project::project(stmt, &results)?;
@@ -40,6 +40,12 @@ fn validate_assignments(assignments: &[UpdateAssignment]) -> Result<(), String>
         if a.column == "vector" {
             return Err("UPDATE cannot modify the 'vector' column (use UPSERT)".to_string());
         }
+        if a.expr.is_some() {
+            return Err(format!(
+                "UPDATE SET {} = <expression> is not supported in WASM; assign a value",
+                a.column
+            ));
+        }
     }
     Ok(())
 }
@@ -161,6 +167,15 @@ mod tests {
         assert!(err.expect_err("test: err").contains("'vector'"));
     }
 
+    #[test]
+    fn test_update_with_expression_is_rejected() {
+        let mut db = DatabaseInner::new();
+        seed_metadata_docs(&mut db);
+        let stmt = parse_update("UPDATE docs SET views = views + 1 WHERE id = 1");
+        let err = execute(&db, &stmt, &parse_params(None).expect("test: p"));
+        assert!(err.expect_err("test: err").contains("expression"));
+    }
+
     #[test]
     fn test_update_missing_collection_errors() {
         let db = DatabaseInner::new();
@@ -56,6 +56,12 @@ pub(crate) fn matches(
         Condition::GeoDistance(_) | Condition::GeoBbox(_) => {
             Err("Geospatial conditions are not supported in WASM".to_string())
         }
+        // Scalar expressions reuse core's filter evaluator (same coercion
+        // rules as the REST surface).
+        Condition::Expression(_) => {
+            Ok(payload
+                .is_some_and(|p| velesdb_core::filter::Condition::from(cond.clone()).matches(p)))
+        }
         // Defensive catch-all: `Condition` is `#[non_exhaustive]`; any new
         // variant added upstream is rejected until explicitly mapped here.
         _ => Err(format!(
@@ -43,7 +43,7 @@ _FALLBACK_CONDITION_TYPES = frozenset({
     "eq", "neq", "gt", "gte", "lt", "lte", "in", "contains",
     "is_null", "is_not_null", "and", "or", "not", "like", "ilike",
     "array_contains", "array_contains_any", "array_contains_all",
-    "geo_distance", "geo_bbox",
+    "geo_distance", "geo_bbox", "expression",
 })
 
 try:
@@ -16,7 +16,7 @@ _CANONICAL_CONDITION_TYPES = frozenset({
     "eq", "neq", "gt", "gte", "lt", "lte", "in", "contains",
     "is_null", "is_not_null", "and", "or", "not", "like", "ilike",
     "array_contains", "array_contains_any", "array_contains_all",
-    "geo_distance", "geo_bbox",
+    "geo_distance", "geo_bbox", "expression",
 })
 
 
@@ -34,7 +34,7 @@ import { VelesDBError } from './types';
 
 /**
  * Base class for every server-originated VelesDB error carrying a
- * `VELES-XXX` code. All 36 typed sub-classes extend this.
+ * `VELES-XXX` code. All 37 typed sub-classes extend this.
  *
  * Also a direct sub-class of `VelesDBError` so that legacy handlers
  * that catch `VelesDBError` continue to receive typed errors too.
@@ -47,7 +47,7 @@ export class VelesError extends VelesDBError {
 }
 
 // ============================================================================
-// The 36 typed sub-classes — one per VELES-XXX variant in velesdb-core
+// The 37 typed sub-classes — one per VELES-XXX variant in velesdb-core
 // ============================================================================
 
 /** Collection already exists (VELES-001). */
@@ -338,6 +338,14 @@ export class IncompatibleSchemaVersionError extends VelesError {
   }
 }
 
+/** Requested row count exceeds the per-query maximum (VELES-037). */
+export class QueryLimitExceededError extends VelesError {
+  constructor(message: string) {
+    super(message, 'VELES-037');
+    this.name = 'QueryLimitExceededError';
+  }
+}
+
 // ============================================================================
 // Code registry + factory discriminator
 // ============================================================================
@@ -345,7 +353,7 @@ export class IncompatibleSchemaVersionError extends VelesError {
 /**
  * Every VELES code known to this SDK version, in ascending order.
  *
- * Used by tests to verify the 36-code contract and by tooling to emit
+ * Used by tests to verify the 37-code contract and by tooling to emit
  * doc/type metadata.
  */
 export const VELES_ERROR_CODES = [
@@ -356,7 +364,7 @@ export const VELES_ERROR_CODES = [
   'VELES-021', 'VELES-022', 'VELES-023', 'VELES-024', 'VELES-025',
   'VELES-026', 'VELES-027', 'VELES-028', 'VELES-029', 'VELES-030',
   'VELES-031', 'VELES-032', 'VELES-033', 'VELES-034', 'VELES-035',
-  'VELES-036',
+  'VELES-036', 'VELES-037',
 ] as const;
 
 /** Union type of every known VELES code. */
@@ -365,7 +373,7 @@ export type VelesErrorCode = (typeof VELES_ERROR_CODES)[number];
 /**
  * Internal mapping from code → constructor. Kept in one place so that
  * `parseVelesError` dispatch is a single object lookup instead of a
- * 36-arm switch (lower cyclomatic complexity).
+ * 37-arm switch (lower cyclomatic complexity).
  */
 const CODE_TO_CLASS = new Map<string, new (message: string) => VelesError>([
   ['VELES-001', CollectionExistsError],
@@ -404,13 +412,14 @@ const CODE_TO_CLASS = new Map<string, new (message: string) => VelesError>([
   ['VELES-034', InvalidCollectionNameError],
   ['VELES-035', SnapshotBuildFailedError],
   ['VELES-036', IncompatibleSchemaVersionError],
+  ['VELES-037', QueryLimitExceededError],
 ]);
 
 /**
  * Instantiate the correct typed error class from a server-provided
  * VELES code and message.
  *
- * - If `code` matches one of the 36 known VELES-XXX codes, returns
+ * - If `code` matches one of the 37 known VELES-XXX codes, returns
  *   the matching typed sub-class.
  * - If `code` is an unknown VELES code (e.g. `VELES-999` from a
  *   newer server), returns a generic `VelesError` preserving the
@@ -90,6 +90,7 @@ export {
   InvalidCollectionNameError,
   SnapshotBuildFailedError,
   IncompatibleSchemaVersionError,
+  QueryLimitExceededError,
   parseVelesError,
   VELES_ERROR_CODES,
 } from './errors';
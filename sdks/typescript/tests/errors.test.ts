@@ -45,20 +45,21 @@ import {
   InvalidCollectionNameError,
   SnapshotBuildFailedError,
   IncompatibleSchemaVersionError,
+  QueryLimitExceededError,
   parseVelesError,
   VELES_ERROR_CODES,
 } from '../src/errors';
 import { VelesDBError } from '../src/types';
 
 // ============================================================================
-// Structural contract: 36 codes, 36 classes, all extend VelesError
+// Structural contract: 37 codes, 37 classes, all extend VelesError
 // ============================================================================
 
 describe('VELES error codes — structural contract', () => {
-  it('exports exactly 36 codes (VELES-001 to VELES-036)', () => {
-    expect(VELES_ERROR_CODES).toHaveLength(36);
+  it('exports exactly 37 codes (VELES-001 to VELES-037)', () => {
+    expect(VELES_ERROR_CODES).toHaveLength(37);
     expect(VELES_ERROR_CODES[0]).toBe('VELES-001');
-    expect(VELES_ERROR_CODES[35]).toBe('VELES-036');
+    expect(VELES_ERROR_CODES[36]).toBe('VELES-037');
   });
 
   it('every VELES error class extends VelesError (and VelesDBError)', () => {
@@ -73,6 +74,7 @@ describe('VELES error codes — structural contract', () => {
       new GraphNotSupportedError(sampleMessage),
       new GuardRailError(sampleMessage),
       new IncompatibleSchemaVersionError(sampleMessage),
+      new QueryLimitExceededError(sampleMessage),
     ];
     for (const err of sampleErrors) {
       expect(err).toBeInstanceOf(VelesError);
@@ -124,6 +126,7 @@ describe('VELES typed classes — verbatim code preservation', () => {
     [InvalidCollectionNameError, 'VELES-034', 'InvalidCollectionNameError'],
     [SnapshotBuildFailedError, 'VELES-035', 'SnapshotBuildFailedError'],
     [IncompatibleSchemaVersionError, 'VELES-036', 'IncompatibleSchemaVersionError'],
+    [QueryLimitExceededError, 'VELES-037', 'QueryLimitExceededError'],
   ];
 
   it.each(cases)('%s preserves code %s and name %s', (Cls, expectedCode, expectedName) => {